@@ -10,7 +10,7 @@ Docker builds.
 
 Version sources (in priority order):
 - Manifest (lintro/tools/manifest.json)
-- npm tools (prettier, oxlint, etc.): Read from package.json
+- npm tools (prettier, oxlint, biome, eslint, stylelint, etc.): Read from package.json
   (Renovate updates it natively)
 - Non-npm tools (hadolint, shellcheck, etc.): Defined in TOOL_VERSIONS below
   (Renovate updates via custom regex managers)
@@ -44,25 +44,46 @@ _MANIFEST_PATH = Path(__file__).parent / "tools" / "manifest.json"
 # Keys use ToolName enum values for type safety
 TOOL_VERSIONS: dict[ToolName | str, str] = {
     ToolName.ACTIONLINT: "1.7.10",
+    ToolName.ANSIBLE_LINT: "25.9.0",
+    ToolName.BUF: "1.57.0",
     ToolName.CARGO_AUDIT: "0.21.0",
     ToolName.CARGO_DENY: "0.19.0",
+    ToolName.CARGO_GEIGER: "0.12.0",
+    ToolName.CARGO_MACHETE: "0.9.1",
+    ToolName.CARGO_OUTDATED: "0.17.0",
+    ToolName.CHECKOV: "3.2.0",
+    ToolName.CLANG_FORMAT: "21.1.2",
+    ToolName.CLANG_TIDY: "21.1.1",
     ToolName.CLIPPY: "1.92.0",
     ToolName.GITLEAKS: "8.30.0",
+    ToolName.GOFMT: "1.25.1",
+    ToolName.GOLANGCI_LINT: "2.5.0",
     ToolName.HADOLINT: "2.14.0",
+    ToolName.KTLINT: "1.7.1",
+    ToolName.LUACHECK: "1.2.0",
     ToolName.PYTEST: "9.0.2",
+    ToolName.RUBOCOP: "1.81.1",
     ToolName.RUSTC: "1.92.0",
     ToolName.RUSTFMT: "1.8.0",
     ToolName.SEMGREP: "1.85.0",
     ToolName.SHELLCHECK: "0.11.0",
     ToolName.SHFMT: "3.12.0",
     ToolName.SQLFLUFF: "4.0.0",
+    ToolName.STYLUA: "2.3.0",
     ToolName.TAPLO: "0.13.0",
+    ToolName.TERRAFORM_FMT: "1.13.3",
+    ToolName.TFLINT: "0.59.1",
+    ToolName.TYPOS: "1.38.1",
+    ToolName.VALE: "3.12.0",
 }
 
 # Mapping from npm package names to ToolName for npm-managed tools
 # These versions are read from package.json at runtime
 _NPM_PACKAGE_TO_TOOL: dict[str, ToolName] = {
     "astro": ToolName.ASTRO_CHECK,
+    "@biomejs/biome": ToolName.BIOME,
+    "eslint": ToolName.ESLINT,
+    "stylelint": ToolName.STYLELINT,
     "svelte-check": ToolName.SVELTE_CHECK,
     "typescript": ToolName.TSC,
     "vue-tsc": ToolName.VUE_TSC,
@@ -82,6 +103,9 @@ _TOOL_TO_NPM_PACKAGE: dict[ToolName, str] = {
 # CI should verify these match package.json to prevent drift.
 _FALLBACK_NPM_VERSIONS: dict[ToolName, str] = {
     ToolName.ASTRO_CHECK: "5.17.1",
+    ToolName.BIOME: "2.3.8",
+    ToolName.ESLINT: "9.39.1",
+    ToolName.STYLELINT: "16.25.0",
     ToolName.SVELTE_CHECK: "4.3.6",
     ToolName.TSC: "5.9.3",
     ToolName.VUE_TSC: "3.2.4",
@@ -144,6 +168,39 @@ def _load_manifest_versions() -> tuple[dict[ToolName, str], dict[str, ToolName]]
     return versions, npm_map
 
 
+@lru_cache(maxsize=1)
+def get_install_methods() -> dict[str, dict[str, str]]:
+    """Load how each tool is installed from the manifest.
+
+    Returns:
+        Dictionary mapping tool names to their manifest ``install`` entry
+        (``type`` plus optional ``package``, ``bin`` and ``component``).
+    """
+    if not _MANIFEST_PATH.exists():
+        return {}
+
+    try:
+        data = json.loads(_MANIFEST_PATH.read_text())
+    except (json.JSONDecodeError, OSError) as exc:
+        _logger.debug("Failed to read manifest: %s", exc)
+        return {}
+
+    tools = data.get("tools", [])
+    if not isinstance(tools, list):
+        return {}
+
+    methods: dict[str, dict[str, str]] = {}
+    for entry in tools:
+        if not isinstance(entry, dict) or not entry.get("name"):
+            continue
+        install = entry.get("install")
+        if isinstance(install, dict) and install.get("type"):
+            methods[str(entry["name"])] = {
+                k: str(v) for k, v in install.items() if isinstance(v, str)
+            }
+    return methods
+
+
 @lru_cache(maxsize=1)
 def _load_package_json() -> dict[str, str]:
     """Load all npm package versions from package.json.
@@ -0,0 +1,26 @@
+"""Optional LLM-assisted fix suggestions.
+
+Issues that no tool can fix automatically are sent, with surrounding code,
+to a user-configured OpenAI-compatible endpoint (see ``ai`` in the Lintro
+config). Returned patches are shown as suggestions only and are never
+applied by Lintro.
+"""
+
+from lintro.ai.client import AIClientError, request_completion
+from lintro.ai.suggestions import (
+    FixSuggestion,
+    build_prompt,
+    extract_patch,
+    read_code_context,
+    suggest_fixes,
+)
+
+__all__ = [
+    "AIClientError",
+    "FixSuggestion",
+    "build_prompt",
+    "extract_patch",
+    "read_code_context",
+    "request_completion",
+    "suggest_fixes",
+]
@@ -0,0 +1,61 @@
+"""Minimal client for OpenAI-compatible chat completion endpoints."""
+
+from __future__ import annotations
+
+import os
+from typing import Any
+
+from lintro.config.ai_config import AIConfig
+from lintro.exceptions.errors import LintroError
+
+
+class AIClientError(LintroError):
+    """Raised when the suggestion endpoint cannot be used."""
+
+
+def request_completion(config: AIConfig, messages: list[dict[str, str]]) -> str:
+    """Send a chat completion request and return the reply text.
+
+    Args:
+        config: AI configuration with endpoint and model.
+        messages: Chat messages in OpenAI format.
+
+    Returns:
+        str: Content of the first choice.
+
+    Raises:
+        AIClientError: If suggestions are disabled, the endpoint is missing,
+            the request fails or the reply has an unexpected shape.
+    """
+    if not config.enabled or not config.endpoint:
+        raise AIClientError(
+            "AI suggestions are disabled; set ai.enabled and ai.endpoint",
+        )
+
+    import httpx
+
+    headers = {"User-Agent": "lintro-suggest"}
+    api_key = os.environ.get(config.api_key_env)
+    if api_key:
+        headers["Authorization"] = f"Bearer {api_key}"
+    payload: dict[str, Any] = {"messages": messages, "temperature": 0}
+    if config.model:
+        payload["model"] = config.model
+
+    url = f"{config.endpoint.rstrip('/')}/chat/completions"
+    try:
+        response = httpx.post(
+            url,
+            json=payload,
+            headers=headers,
+            timeout=config.timeout,
+        )
+        response.raise_for_status()
+        data = response.json()
+    except (httpx.HTTPError, ValueError) as e:
+        raise AIClientError(f"request to {url} failed: {e}") from e
+
+    try:
+        return str(data["choices"][0]["message"]["content"] or "")
+    except (KeyError, IndexError, TypeError) as e:
+        raise AIClientError(f"unexpected response from {url}") from e
@@ -0,0 +1,182 @@
+"""Build fix-suggestion requests for unfixable issues and parse the replies."""
+
+from __future__ import annotations
+
+import re
+from collections.abc import Callable, Sequence
+from dataclasses import dataclass
+from pathlib import Path
+
+from lintro.ai.client import AIClientError, request_completion
+from lintro.config.ai_config import AIConfig
+from lintro.utils.cleanup_plan import resolve_repo_paths
+from lintro.utils.effort import issue_code, issue_fixable
+
+SYSTEM_PROMPT: str = (
+    "You fix lint and type-check findings. Reply with a single unified diff "
+    "against the given file, using a/<path> and b/<path> headers, that fixes "
+    "only the reported issue. Keep unrelated code unchanged. If you cannot "
+    "fix it safely, reply with NO_PATCH."
+)
+
+_FENCED_DIFF = re.compile(r"```(?:diff|patch)?\s*\n(.*?)```", re.DOTALL)
+
+
+@dataclass
+class FixSuggestion:
+    """Suggested patch for one issue; unverified and never applied.
+
+    Attributes:
+        file: File containing the issue, relative to the current directory.
+        line: Line of the issue (0 when unknown).
+        tool: Tool that reported the issue.
+        code: Rule code, or an empty string if the tool has none.
+        message: Issue message.
+        patch: Unified diff returned by the endpoint, if any.
+        error: Why no patch is available, if none was returned.
+    """
+
+    file: str
+    line: int
+    tool: str
+    code: str
+    message: str
+    patch: str | None = None
+    error: str | None = None
+
+
+def read_code_context(path: str | Path, line: int, context_lines: int) -> str:
+    """Return the lines around an issue, prefixed with line numbers.
+
+    Args:
+        path: File containing the issue.
+        line: 1-based line of the issue; 0 sends the start of the file.
+        context_lines: Lines to include before and after the issue.
+
+    Returns:
+        str: Numbered source lines, or an empty string if the file cannot
+        be read.
+    """
+    try:
+        lines = Path(path).read_text(encoding="utf-8").splitlines()
+    except (OSError, UnicodeDecodeError):
+        return ""
+    center = max(line, 1)
+    start = max(center - context_lines, 1)
+    end = min(center + context_lines, len(lines))
+    return "\n".join(f"{n:>5} | {lines[n - 1]}" for n in range(start, end + 1))
+
+
+def build_prompt(
+    tool: str,
+    issue: object,
+    file_path: str,
+    context: str,
+) -> list[dict[str, str]]:
+    """Build the chat messages asking for a fix to one issue.
+
+    Args:
+        tool: Tool that reported the issue.
+        issue: Parsed issue object.
+        file_path: Path used in the diff headers.
+        context: Numbered code around the issue.
+
+    Returns:
+        list[dict[str, str]]: System and user messages.
+    """
+    code = issue_code(issue)
+    line = getattr(issue, "line", 0) or 0
+    rule = f"{tool} {code}".strip()
+    user = (
+        f"File: {file_path}\n"
+        f"Line: {line or 'unknown'}\n"
+        f"Rule: {rule}\n"
+        f"Message: {getattr(issue, 'message', '')}\n\n"
+        f"Code:\n{context}\n"
+    )
+    return [
+        {"role": "system", "content": SYSTEM_PROMPT},
+        {"role": "user", "content": user},
+    ]
+
+
+def extract_patch(reply: str) -> str | None:
+    """Extract a unified diff from an endpoint reply.
+
+    Accepts a fenced ``diff`` block or a bare diff starting with ``---``.
+
+    Args:
+        reply: Reply text from the endpoint.
+
+    Returns:
+        str | None: The diff, or None if the reply contains no usable diff.
+    """
+    match = _FENCED_DIFF.search(reply)
+    text = match.group(1) if match else reply
+    start = text.find("--- ")
+    if start < 0 or "\n+++ " not in text or "\n@@" not in text:
+        return None
+    return text[start:].rstrip() + "\n"
+
+
+def suggest_fixes(
+    tool_results: Sequence[object],
+    config: AIConfig,
+    roots: Sequence[str] = (".",),
+    request: Callable[[AIConfig, list[dict[str, str]]], str] = request_completion,
+) -> list[FixSuggestion]:
+    """Ask the configured endpoint for patches to issues no tool can fix.
+
+    At most ``config.max_issues`` issues are sent. Request failures are
+    recorded on the suggestion instead of stopping the run.
+
+    Args:
+        tool_results: Tool results from a check run.
+        config: AI configuration.
+        roots: Checked paths, used to locate the reported files.
+        request: Function sending one chat request; replaceable in tests.
+
+    Returns:
+        list[FixSuggestion]: One entry per issue sent.
+
+    Raises:
+        AIClientError: If suggestions are disabled or no endpoint is set.
+    """
+    if not config.enabled or not config.endpoint:
+        raise AIClientError(
+            "AI suggestions are disabled; set ai.enabled and ai.endpoint",
+        )
+
+    pending = [
+        (str(getattr(result, "name", "")), str(getattr(issue, "file", "")), issue)
+        for result in tool_results
+        for issue in getattr(result, "issues", None) or []
+        if not issue_fixable(issue) and getattr(issue, "file", "")
+    ][: config.max_issues]
+    paths = resolve_repo_paths((reported for _, reported, _ in pending), roots)
+
+    suggestions: list[FixSuggestion] = []
+    for tool, reported, issue in pending:
+        file_path = paths.get(reported, reported)
+        line = getattr(issue, "line", 0) or 0
+        suggestion = FixSuggestion(
+            file=file_path,
+            line=line,
+            tool=tool,
+            code=issue_code(issue),
+            message=str(getattr(issue, "message", "")),
+        )
+        suggestions.append(suggestion)
+        context = read_code_context(file_path, line, config.context_lines)
+        if not context:
+            suggestion.error = "source file could not be read"
+            continue
+        try:
+            reply = request(config, build_prompt(tool, issue, file_path, context))
+        except AIClientError as e:
+            suggestion.error = str(e)
+            continue
+        suggestion.patch = extract_patch(reply)
+        if suggestion.patch is None:
+            suggestion.error = "no patch returned"
+    return suggestions
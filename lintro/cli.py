@@ -1,12 +1,9 @@
 """Command-line interface for Lintro."""
 
+import importlib
 from typing import Any, cast
 
 import click
-from rich.console import Console
-from rich.panel import Panel
-from rich.table import Table
-from rich.text import Text
 
 from lintro import __version__
 from lintro.cli_utils.command_chainer import CommandChainer
@@ -16,18 +13,57 @@ from lintro.utils.logger_setup import setup_cli_logging
 # Only WARNING and above will show. DEBUG logs go to file when tool_executor runs.
 setup_cli_logging()
 
-# E402: Module level imports below setup_cli_logging() are intentional.
-# Logging must be configured BEFORE importing modules that use loguru,
-# otherwise log messages during import get silently dropped or misconfigured.
-from lintro.cli_utils.commands.check import check_command  # noqa: E402
-from lintro.cli_utils.commands.config import config_command  # noqa: E402
-from lintro.cli_utils.commands.doctor import doctor_command  # noqa: E402
-from lintro.cli_utils.commands.format import format_command  # noqa: E402
-from lintro.cli_utils.commands.init import init_command  # noqa: E402
-from lintro.cli_utils.commands.list_tools import list_tools_command  # noqa: E402
-from lintro.cli_utils.commands.test import test_command  # noqa: E402
-from lintro.cli_utils.commands.versions import versions_command  # noqa: E402
-from lintro.tools.core.runtime_discovery import clear_discovery_cache  # noqa: E402
+_COMMANDS_PACKAGE = "lintro.cli_utils.commands"
+
+# Commands by name, as (module in lintro.cli_utils.commands, attribute). They
+# are imported on first use so that running one command, e.g. fix-file from
+# an editor save hook, does not pay for importing all the others.
+LAZY_COMMANDS: dict[str, tuple[str, str]] = {
+    "audit": ("audit", "audit_command"),
+    "badge": ("badge", "badge_command"),
+    "check": ("check", "check_command"),
+    "config": ("config", "config_command"),
+    "daemon": ("daemon", "daemon_command"),
+    "diff": ("diff", "diff_command"),
+    "doctor": ("doctor", "doctor_command"),
+    "explain": ("explain", "explain_command"),
+    "fix-file": ("fix_file", "fix_file_command"),
+    "format": ("format", "format_command"),
+    "gate": ("gate", "gate_command"),
+    "generate": ("generate", "generate_command"),
+    "history": ("history", "history_command"),
+    "ingest": ("ingest", "ingest_command"),
+    "init": ("init", "init_command"),
+    "install-hooks": ("install_hooks", "install_hooks_command"),
+    "list-tools": ("list_tools", "list_tools_command"),
+    "merge-reports": ("merge_reports", "merge_reports_command"),
+    "org": ("org", "org_command"),
+    "outdated": ("outdated", "outdated_command"),
+    "plan": ("plan", "plan_command"),
+    "query": ("query", "query_command"),
+    "report": ("report", "report_command"),
+    "score": ("score", "score_command"),
+    "stats": ("stats", "stats_command"),
+    "suggest": ("suggest", "suggest_command"),
+    "suppress": ("suppress", "suppress_command"),
+    "suppressions": ("suppressions", "suppressions_command"),
+    "test": ("test", "test_command"),
+    "versions": ("versions", "versions_command"),
+}
+
+# Command aliases mapped to their canonical names
+COMMAND_ALIASES: dict[str, str] = {
+    "cfg": "config",
+    "chk": "check",
+    "fix": "format",
+    "fmt": "format",
+    "lint": "check",
+    "ls": "list-tools",
+    "tools": "list-tools",
+    "tst": "test",
+    "ver": "versions",
+    "version": "versions",
+}
 
 
 class LintroGroup(click.Group):
@@ -35,9 +71,49 @@ class LintroGroup(click.Group):
 
     This group prints command aliases alongside their canonical names to make
     the CLI help output more discoverable. It also supports command chaining
-    with comma-separated commands (e.g., lintro fmt , chk , tst).
+    with comma-separated commands (e.g., lintro fmt , chk , tst). Commands in
+    LAZY_COMMANDS are imported when first looked up.
     """
 
+    def list_commands(self, ctx: click.Context) -> list[str]:
+        """List registered, lazily loaded and alias command names.
+
+        Args:
+            ctx: click.Context: The Click context.
+
+        Returns:
+            list[str]: Sorted command names.
+        """
+        names = set(super().list_commands(ctx)) | set(LAZY_COMMANDS)
+        return sorted(names | set(COMMAND_ALIASES))
+
+    def get_command(
+        self,
+        ctx: click.Context,
+        cmd_name: str,
+    ) -> click.Command | None:
+        """Look up a command, importing it on first use.
+
+        Args:
+            ctx: click.Context: The Click context.
+            cmd_name: str: Command name or alias.
+
+        Returns:
+            click.Command | None: The command, or None if the name is unknown.
+        """
+        command = super().get_command(ctx, cmd_name)
+        if command is not None:
+            return command
+        canonical = COMMAND_ALIASES.get(cmd_name, cmd_name)
+        if canonical not in LAZY_COMMANDS:
+            return None
+        module_name, attribute = LAZY_COMMANDS[canonical]
+        module = importlib.import_module(f"{_COMMANDS_PACKAGE}.{module_name}")
+        command = cast(click.Command, getattr(module, attribute))
+        cast(Any, command)._canonical_name = canonical
+        self.add_command(command, name=cmd_name)
+        return command
+
     def format_help(
         self,
         ctx: click.Context,
@@ -49,6 +125,11 @@ class LintroGroup(click.Group):
             ctx: click.Context: The Click context.
             formatter: click.HelpFormatter: The help formatter (unused, we use Rich).
         """
+        from rich.console import Console
+        from rich.panel import Panel
+        from rich.table import Table
+        from rich.text import Text
+
         console = Console()
 
         # Header panel
@@ -115,6 +196,9 @@ class LintroGroup(click.Group):
         console.print()
         console.print("  [dim]# Show tool versions[/dim]")
         console.print("  lintro versions")
+        console.print()
+        console.print("  [dim]# Explain an error code[/dim]")
+        console.print("  lintro explain LINTRO012")
 
     def format_commands(
         self,
@@ -146,7 +230,12 @@ class LintroGroup(click.Group):
 
         Raises:
             SystemExit: If a command exits with a non-zero exit code.
+            UsageError: If --profile or LINTRO_PROFILE names an undefined
+                profile.
         """
+        from lintro.config.config_loader import UnknownProfileError
+        from lintro.tools.core.runtime_discovery import clear_discovery_cache
+
         # Clear tool discovery cache at start of each invocation to ensure
         # fresh tool detection (tools may have been installed/uninstalled)
         clear_discovery_cache()
@@ -167,8 +256,12 @@ class LintroGroup(click.Group):
                     raise SystemExit(final_exit_code)
                 return 0
 
-        # Normal single command execution
-        result = super().invoke(ctx)
+        # Normal single command execution. Commands load the configuration
+        # lazily, so an undefined profile surfaces here whichever command ran
+        try:
+            result = super().invoke(ctx)
+        except UnknownProfileError as e:
+            raise click.UsageError(str(e), ctx=ctx) from e
         return int(result) if isinstance(result, int) else 0
 
 
@@ -183,38 +276,6 @@ def cli() -> None:
     pass
 
 
-# Register canonical commands and set _canonical_name for help
-cast(Any, check_command)._canonical_name = "check"
-cast(Any, config_command)._canonical_name = "config"
-cast(Any, doctor_command)._canonical_name = "doctor"
-cast(Any, format_command)._canonical_name = "format"
-cast(Any, init_command)._canonical_name = "init"
-cast(Any, test_command)._canonical_name = "test"
-cast(Any, list_tools_command)._canonical_name = "list-tools"
-cast(Any, versions_command)._canonical_name = "versions"
-
-cli.add_command(check_command, name="check")
-cli.add_command(config_command, name="config")
-cli.add_command(doctor_command, name="doctor")
-cli.add_command(format_command, name="format")
-cli.add_command(init_command, name="init")
-cli.add_command(test_command, name="test")
-cli.add_command(list_tools_command, name="list-tools")
-cli.add_command(versions_command, name="versions")
-
-# Register aliases
-cli.add_command(check_command, name="chk")
-cli.add_command(check_command, name="lint")
-cli.add_command(config_command, name="cfg")
-cli.add_command(format_command, name="fmt")
-cli.add_command(format_command, name="fix")
-cli.add_command(test_command, name="tst")
-cli.add_command(list_tools_command, name="ls")
-cli.add_command(list_tools_command, name="tools")
-cli.add_command(versions_command, name="ver")
-cli.add_command(versions_command, name="version")
-
-
 def main() -> None:
     """Entry point for the CLI."""
     cli()
@@ -202,6 +202,9 @@ class CommandChainer:
 
         except SystemExit as e:
             return e.code if isinstance(e.code, int) else (0 if e.code is None else 1)
+        except click.ClickException as e:
+            e.show()
+            return e.exit_code
         except KeyboardInterrupt:
             # Re-raise KeyboardInterrupt to allow normal interruption
             raise
@@ -1,9 +1,23 @@
-"""CLI command modules for lintro."""
+"""CLI command modules for lintro.
 
-from .check import check_command
-from .format import format_code, format_code_legacy, format_command
-from .init import init_command
-from .list_tools import list_tools
+Commands are imported on first access so that importing one command module
+does not import all of them.
+"""
+
+from __future__ import annotations
+
+import importlib
+from typing import Any
+
+# Public names mapped to the module defining them
+_EXPORTS: dict[str, str] = {
+    "check_command": "check",
+    "format_command": "format",
+    "format_code": "format",
+    "format_code_legacy": "format",
+    "init_command": "init",
+    "list_tools": "list_tools",
+}
 
 __all__ = [
     "check_command",
@@ -13,3 +27,21 @@ __all__ = [
     "init_command",
     "list_tools",
 ]
+
+
+def __getattr__(name: str) -> Any:
+    """Import an exported command on first access.
+
+    Args:
+        name: Attribute name.
+
+    Returns:
+        Any: The exported object.
+
+    Raises:
+        AttributeError: If the name is not exported.
+    """
+    if name not in _EXPORTS:
+        raise AttributeError(f"module {__name__!r} has no attribute {name!r}")
+    module = importlib.import_module(f"{__name__}.{_EXPORTS[name]}")
+    return getattr(module, name)
@@ -0,0 +1,176 @@
+"""Audit command for querying the log of fix runs."""
+
+import datetime
+import json
+from dataclasses import asdict
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config import get_config
+from lintro.utils.execution.audit_log import (
+    AuditEntry,
+    filter_entries,
+    read_entries,
+    verify_chain,
+)
+from lintro.utils.timestamps import render_timestamp
+
+
+@click.group()
+def audit_command() -> None:
+    """Inspect the audit log of fix runs."""
+
+
+@audit_command.command("log")
+@click.option(
+    "--since",
+    type=click.DateTime(formats=["%Y-%m-%d", "%Y-%m-%dT%H:%M:%S"]),
+    default=None,
+    help="Only runs that finished at or after this UTC date or time.",
+)
+@click.option(
+    "--tool",
+    default=None,
+    help="Only runs in which this fixer changed files.",
+)
+@click.option(
+    "--file",
+    "file_path",
+    default=None,
+    help="Only runs that changed this file.",
+)
+@click.option(
+    "--user",
+    default=None,
+    help="Only runs by this OS user or git email.",
+)
+@click.option(
+    "--limit",
+    type=click.IntRange(min=1),
+    default=None,
+    help="Show only the most recent N matching runs.",
+)
+@click.option(
+    "--log",
+    "log_path",
+    type=click.Path(dir_okay=False),
+    default=None,
+    help="Audit log to read (default: execution.audit_log).",
+)
+@click.option(
+    "--verify",
+    is_flag=True,
+    help="Check that no entry was removed or edited, instead of listing runs.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output matching entries as JSON.",
+)
+def audit_log_command(
+    since: datetime.datetime | None,
+    tool: str | None,
+    file_path: str | None,
+    user: str | None,
+    limit: int | None,
+    log_path: str | None,
+    *,
+    verify: bool,
+    json_output: bool,
+) -> None:
+    """List recorded fix runs: who ran which fixer on which files, and when.
+
+    Args:
+        since: Earliest finish time to include.
+        tool: Fixer that must have changed a file.
+        file_path: File that must have changed.
+        user: OS user or git email of the run.
+        limit: Maximum number of runs, most recent last.
+        log_path: Audit log file, or None for the configured one.
+        verify: Check the hash chain instead of listing runs.
+        json_output: Output matching entries as JSON.
+
+    Raises:
+        SystemExit: If the log cannot be read or, with --verify, is broken.
+
+    Examples:
+        lintro audit log --since 2026-01-01 --tool ruff
+        lintro audit log --file src/app.py --json
+        lintro audit log --verify
+    """
+    console = Console()
+    try:
+        config = get_config()
+        path = log_path or config.execution.audit_log
+        if not path:
+            console.print("[yellow]The audit log is disabled (execution.audit_log).")
+            raise SystemExit(1)
+        if verify:
+            _verify(console, path)
+            return
+        entries = read_entries(path)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
+    entries = filter_entries(entries, since=since, tool=tool, file=file_path, user=user)
+    if limit is not None:
+        entries = entries[-limit:]
+
+    if json_output:
+        click.echo(json.dumps([asdict(entry) for entry in entries], indent=2))
+        return
+    if not entries:
+        console.print("No fix runs recorded.")
+        return
+    console.print(_entries_table(entries, local_time=config.output.local_time))
+
+
+def _verify(console: Console, path: str) -> None:
+    """Report whether the audit log's hash chain is intact.
+
+    Args:
+        console: Console to print to.
+        path: Audit log file.
+
+    Raises:
+        SystemExit: If an entry was removed or edited.
+    """
+    broken = verify_chain(path)
+    if broken:
+        lines = ", ".join(str(n) for n in broken)
+        console.print(f"[red]❌ Audit log {path} is broken at line(s) {lines}[/red]")
+        raise SystemExit(1)
+    console.print(f"[green]✅ Audit log {path} is intact[/green]")
+
+
+def _entries_table(entries: list[AuditEntry], local_time: bool = False) -> Table:
+    """Render audit entries as a table, one row per run.
+
+    Args:
+        entries: Entries to show.
+        local_time: Show finish times in the local timezone instead of UTC.
+
+    Returns:
+        Table: Rich table of the runs.
+    """
+    table = Table(title="Fix Runs")
+    table.add_column("Finished", no_wrap=True)
+    table.add_column("User")
+    table.add_column("Commit", no_wrap=True)
+    table.add_column("Changed Files")
+    for entry in entries:
+        changes = "\n".join(
+            f"{fix.file} ({fix.tool}, {(fix.before or 'new')[:8]} → "
+            f"{(fix.after or 'removed')[:8]})"
+            for fix in entry.files
+        )
+        table.add_row(
+            render_timestamp(entry.finished_at, local=local_time),
+            entry.git_user or entry.user,
+            (entry.commit or "-")[:10],
+            changes or "[dim]none[/dim]",
+        )
+    return table
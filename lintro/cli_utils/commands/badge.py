@@ -0,0 +1,112 @@
+"""Badge command for drawing README status badges from reports."""
+
+from pathlib import Path
+
+import click
+from rich.console import Console
+
+from lintro.utils.badge import (
+    badge_message,
+    load_report,
+    render_summary_badge,
+    summarize_report,
+    summarize_tools,
+)
+
+DEFAULT_OUTPUT: str = "lintro-badge.svg"
+DEFAULT_PER_TOOL_OUTPUT: str = "badges"
+
+
+@click.command("badge")
+@click.option(
+    "--input",
+    "input_path",
+    type=click.Path(exists=True, dir_okay=False),
+    required=True,
+    help="JSON report written with --output FILE --output-format json.",
+)
+@click.option(
+    "--output",
+    "-o",
+    type=click.Path(),
+    default=None,
+    help=(
+        f"SVG file to write (default: {DEFAULT_OUTPUT}); with --per-tool, the "
+        f"directory for the badges (default: {DEFAULT_PER_TOOL_OUTPUT})."
+    ),
+)
+@click.option(
+    "--status",
+    is_flag=True,
+    help="Show passing or failing instead of the issue count.",
+)
+@click.option(
+    "--tool",
+    default=None,
+    help="Only count the issues of this tool.",
+)
+@click.option(
+    "--per-tool",
+    is_flag=True,
+    help="Write one badge per tool in the report, named TOOL.svg.",
+)
+@click.option(
+    "--label",
+    default=None,
+    help="Left-hand text (default: the tool name, or 'lintro').",
+)
+def badge_command(
+    input_path: str,
+    output: str | None,
+    status: bool,
+    tool: str | None,
+    per_tool: bool,
+    label: str | None,
+) -> None:
+    """Draw an SVG status badge from a JSON report.
+
+    The badge shows the number of issues, coloured green, yellow or red by
+    their worst severity, or with --status just passing or failing. Commit
+    it or publish it from a scheduled workflow and link it in the README.
+
+    Args:
+        input_path: JSON report to summarize.
+        output: SVG file, or directory with --per-tool.
+        status: Show passing or failing instead of the issue count.
+        tool: Only count the issues of this tool.
+        per_tool: Write one badge per tool.
+        label: Left-hand text of the badge.
+
+    Raises:
+        click.UsageError: If --tool and --per-tool are combined.
+        SystemExit: If the report cannot be read or a badge written.
+
+    Examples:
+        lintro check --output lint.json --output-format json
+        lintro badge --input lint.json --output assets/lintro.svg
+        lintro badge --input lint.json --per-tool --status --output badges
+    """
+    if tool and per_tool:
+        raise click.UsageError("--tool cannot be combined with --per-tool")
+
+    console = Console()
+    try:
+        report = load_report(input_path)
+        if per_tool:
+            directory = Path(output or DEFAULT_PER_TOOL_OUTPUT)
+            summaries = summarize_tools(report)
+            targets = [(s, directory / f"{s.tool}.svg") for s in summaries]
+        else:
+            summary = summarize_report(report, tool)
+            targets = [(summary, Path(output or DEFAULT_OUTPUT))]
+        for summary, path in targets:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(
+                render_summary_badge(summary, label=label, status=status),
+                encoding="utf-8",
+            )
+            message, _ = badge_message(summary, status=status)
+            console.print(f"[green]✅ Wrote {path} ({message})[/green]")
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
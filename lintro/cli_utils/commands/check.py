@@ -8,16 +8,130 @@ Functions:
 """
 
 import sys
+from contextlib import ExitStack
 
 import click
 from click.testing import CliRunner
 
+from lintro.cli_utils.commands.query import parse_query_option
+from lintro.enums.display_column import parse_columns
+from lintro.plugins.extra_args import parse_extra_args_option
+from lintro.utils.execution.sharding import Shard, parse_shard
+from lintro.utils.issue_query import IssueQuery, restrict_to_owners
+from lintro.utils.logger_setup import LOG_FORMATS
+from lintro.utils.output.sinks import OutputSink, console_format, parse_output_sink
+from lintro.utils.rule_selection import RuleSelection
+from lintro.utils.stdin_input import StdinBuffer, stdin_buffer
 from lintro.utils.tool_executor import run_lint_tools_simple
+from lintro.utils.vcs import detect_vcs
 
 # Constants
 DEFAULT_PATHS: list[str] = ["."]
 DEFAULT_EXIT_CODE: int = 0
 DEFAULT_ACTION: str = "check"
+COMMIT_LINT_TOOL: str = "conventional_commits"
+TABLE_SORT_CHOICES: list[str] = ["file", "line", "code", "severity"]
+
+
+def parse_columns_option(
+    ctx: click.Context,
+    param: click.Parameter,
+    value: str | None,
+) -> list[str] | None:
+    """Validate a column selection given on the command line.
+
+    Args:
+        ctx: Click context.
+        param: Parameter holding the selection.
+        value: Comma-separated column names, or None if not given.
+
+    Returns:
+        list[str] | None: Lower-case column names in the order given, or
+        None if not given.
+
+    Raises:
+        click.BadParameter: If a name is not a column.
+    """
+    if value is None:
+        return None
+    try:
+        return [col.value.lower() for col in parse_columns(value)]
+    except ValueError as e:
+        raise click.BadParameter(str(e), ctx=ctx, param=param) from e
+
+
+def parse_output_option(
+    ctx: click.Context,
+    param: click.Parameter,
+    value: tuple[str, ...],
+) -> tuple[OutputSink, ...]:
+    """Validate the output sinks given on the command line.
+
+    Args:
+        ctx: Click context.
+        param: Parameter holding the sinks.
+        value: Values of every ``--output``.
+
+    Returns:
+        tuple[OutputSink, ...]: Parsed sinks in the order given.
+
+    Raises:
+        click.BadParameter: If a value is not a valid sink.
+    """
+    try:
+        return tuple(parse_output_sink(item) for item in value)
+    except ValueError as e:
+        raise click.BadParameter(str(e), ctx=ctx, param=param) from e
+
+
+def parse_extra_args_callback(
+    ctx: click.Context,
+    param: click.Parameter,
+    value: tuple[str, ...],
+) -> dict[str, list[str]]:
+    """Validate the extra tool arguments given on the command line.
+
+    Args:
+        ctx: Click context.
+        param: Parameter holding the arguments.
+        value: Values of every ``--extra-args``, as ``TOOL:ARGS``.
+
+    Returns:
+        dict[str, list[str]]: Arguments per tool name.
+
+    Raises:
+        click.BadParameter: If a value is not ``TOOL:ARGS``.
+    """
+    try:
+        return parse_extra_args_option(value)
+    except ValueError as e:
+        raise click.BadParameter(str(e), ctx=ctx, param=param) from e
+
+
+def parse_shard_option(
+    ctx: click.Context,
+    param: click.Parameter,
+    value: str | None,
+) -> Shard | None:
+    """Validate the shard given on the command line.
+
+    Args:
+        ctx: Click context.
+        param: Parameter holding the shard.
+        value: ``I/N``, or None if not given.
+
+    Returns:
+        Shard | None: Parsed shard, or None if not given.
+
+    Raises:
+        click.BadParameter: If the value is not a valid shard.
+    """
+    if value is None:
+        return None
+    try:
+        return parse_shard(value)
+    except ValueError as e:
+        raise click.BadParameter(str(e), ctx=ctx, param=param) from e
 
 
 @click.command("check")
@@ -32,6 +146,15 @@ DEFAULT_ACTION: str = "check"
     type=str,
     help="Tool-specific options in the format tool:option=value,tool:option=value",
 )
+@click.option(
+    "--extra-args",
+    multiple=True,
+    callback=parse_extra_args_callback,
+    help=(
+        "Extra arguments for a tool as TOOL:ARGS, e.g. "
+        "'clippy:--all-targets --all-features'; repeat for several tools"
+    ),
+)
 @click.option(
     "--exclude",
     type=str,
@@ -42,22 +165,68 @@ DEFAULT_ACTION: str = "check"
     is_flag=True,
     help="Include virtual environment directories in processing",
 )
+@click.option(
+    "--no-gitignore",
+    is_flag=True,
+    help="Also process files ignored by .gitignore and .git/info/exclude",
+)
+@click.option(
+    "--code-blocks",
+    is_flag=True,
+    help="Also check the fenced code blocks of Markdown files",
+)
 @click.option(
     "--output",
-    type=click.Path(),
-    help="Output file path for writing results",
+    multiple=True,
+    callback=parse_output_option,
+    help=(
+        "Where to send results; repeat for several sinks: console, "
+        "console=FORMAT, FORMAT=PATH (e.g. sarif=report.sarif) or a file "
+        "path written in --output-format"
+    ),
 )
 @click.option(
     "--output-format",
     type=click.Choice(["plain", "grid", "markdown", "html", "json", "csv", "github"]),
-    default="grid",
-    help="Output format for displaying results",
+    default=None,
+    help="Output format for displaying results [default: grid]",
 )
 @click.option(
     "--group-by",
-    type=click.Choice(["file", "code", "none", "auto"]),
-    default="file",
-    help="How to group issues in the output",
+    type=click.Choice(
+        [
+            "file",
+            "code",
+            "none",
+            "auto",
+            "rule",
+            "severity",
+            "dir",
+            "tool",
+            "owner",
+        ],
+    ),
+    default=None,
+    help=(
+        "How to group issues in the output; rule, severity, dir, tool and owner "
+        "(CODEOWNERS) add subtotal rows [default: file]"
+    ),
+)
+@click.option(
+    "--columns",
+    type=str,
+    default=None,
+    callback=parse_columns_option,
+    help=(
+        "Comma-separated table columns, e.g. file,line,code to drop the "
+        "message on narrow terminals"
+    ),
+)
+@click.option(
+    "--sort-by",
+    type=click.Choice(TABLE_SORT_CHOICES),
+    default=None,
+    help="Order of the issue table rows [default: as reported by the tool]",
 )
 @click.option(
     "--ignore-conflicts",
@@ -100,26 +269,199 @@ DEFAULT_ACTION: str = "check"
     is_flag=True,
     help="Enable debug output on console",
 )
+@click.option(
+    "--log-format",
+    type=click.Choice(LOG_FORMATS),
+    default="text",
+    help=(
+        "Log format on stderr; json writes one record per line, including "
+        "tool invocations, for CI log aggregation [default: text]"
+    ),
+)
 @click.option(
     "--auto-install",
     is_flag=True,
     help="Auto-install Node.js dependencies if node_modules is missing",
 )
+@click.option(
+    "--install-missing",
+    is_flag=True,
+    help="Install selected tools that are missing, at their pinned versions",
+)
 @click.option(
     "--yes",
     "-y",
     is_flag=True,
     help="Skip confirmation prompt and proceed immediately",
 )
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Named configuration profile to apply (e.g. ci, local, strict)",
+)
+@click.option(
+    "--fail-on",
+    type=click.Choice(["error", "warning", "info", "never"]),
+    default=None,
+    help="Lowest issue severity that fails the run [default: info]",
+)
+@click.option(
+    "--max-issues",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Number of failing issues tolerated before the run fails [default: 0]",
+)
+@click.option(
+    "--fail-fast",
+    is_flag=True,
+    help="Stop starting tools once the issues found so far fail the run",
+)
+@click.option(
+    "--summary",
+    type=click.Choice(["table", "prose"]),
+    default="table",
+    help="Execution summary style; prose suits CI logs and PR comments",
+)
+@click.option(
+    "--summary-only",
+    is_flag=True,
+    help="Only print the per-tool counts table, not the issues",
+)
+@click.option(
+    "--quiet",
+    "-q",
+    is_flag=True,
+    help="Print nothing on success and the per-tool counts table on failure",
+)
+@click.option(
+    "--profile-timing",
+    is_flag=True,
+    help="Report where the time went per tool phase and the slowest files",
+)
+@click.option(
+    "--sample-per-rule",
+    type=click.IntRange(min=1),
+    default=None,
+    help="Show N examples of each rule plus its total; JSON keeps every issue",
+)
+@click.option(
+    "--no-dedupe",
+    is_flag=True,
+    help="Keep issues that several tools report at the same location",
+)
+@click.option(
+    "--toolchain-matrix",
+    is_flag=True,
+    help="Run mypy and clippy once per version in execution.toolchain_matrix",
+)
+@click.option(
+    "--sign-report",
+    "sign_report",
+    type=click.Path(exists=True, dir_okay=False),
+    default=None,
+    help="PEM private key used to write a signed in-toto attestation of --output",
+)
+@click.option(
+    "--assert-read-only",
+    is_flag=True,
+    help="Fail if any tool creates, modifies or deletes a file in the workspace",
+)
+@click.option(
+    "--max-files",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Abort before running tools if they would process more than N files",
+)
+@click.option(
+    "--confirm-over",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Ask before running tools on more than N files; abort if non-interactive",
+)
+@click.option(
+    "--filter",
+    "issue_filter",
+    type=str,
+    default=None,
+    callback=parse_query_option,
+    help='Only show and count matching issues, e.g. "severity>=error and tool=ruff"',
+)
+@click.option(
+    "--owner",
+    "owners",
+    type=str,
+    multiple=True,
+    help=(
+        "Only show and count issues in files CODEOWNERS assigns to this owner "
+        "(repeatable)"
+    ),
+)
+@click.option(
+    "--select",
+    type=str,
+    multiple=True,
+    help='Only show and count these rules, e.g. "ruff:E5*,clippy::*" (repeatable)',
+)
+@click.option(
+    "--ignore",
+    type=str,
+    multiple=True,
+    help='Drop these rules from the results, e.g. "E501,*:W*" (repeatable)',
+)
+@click.option(
+    "--commit-range",
+    type=str,
+    default=None,
+    help="Lint the commit messages in a git range, e.g. origin/main..HEAD",
+)
+@click.option(
+    "--pr-title",
+    type=str,
+    default=None,
+    help="Lint a pull request title against Conventional Commits",
+)
+@click.option(
+    "--staged",
+    is_flag=True,
+    help=(
+        "Only check the files staged for the next commit (jj: the working-copy "
+        "commit; hg: uncommitted changes)"
+    ),
+)
+@click.option(
+    "--shard",
+    type=str,
+    default=None,
+    callback=parse_shard_option,
+    help="Check part I of N of the files, e.g. 2/4, to split a run across CI jobs",
+)
+@click.option(
+    "--stdin",
+    "use_stdin",
+    is_flag=True,
+    help="Check content read from stdin as the file named by --stdin-filename",
+)
+@click.option(
+    "--stdin-filename",
+    type=str,
+    default=None,
+    help="File the stdin content stands for; picks the tools and config",
+)
 def check_command(
     paths: tuple[str, ...],
     tools: str | None,
     tool_options: str | None,
+    extra_args: dict[str, list[str]],
     exclude: str | None,
     include_venv: bool,
-    output: str | None,
-    output_format: str,
-    group_by: str,
+    no_gitignore: bool,
+    code_blocks: bool,
+    output: tuple[OutputSink, ...],
+    output_format: str | None,
+    group_by: str | None,
+    columns: list[str] | None,
+    sort_by: str | None,
     ignore_conflicts: bool,
     verbose: bool,
     no_log: bool,
@@ -128,8 +470,35 @@ def check_command(
     no_cache: bool,
     stream: bool,
     debug: bool,
+    log_format: str,
     auto_install: bool,
+    install_missing: bool,
     yes: bool,
+    profile: str | None,
+    fail_on: str | None,
+    max_issues: int | None,
+    fail_fast: bool,
+    summary: str,
+    summary_only: bool,
+    quiet: bool,
+    profile_timing: bool,
+    sample_per_rule: int | None,
+    no_dedupe: bool,
+    toolchain_matrix: bool,
+    sign_report: str | None,
+    assert_read_only: bool,
+    max_files: int | None,
+    confirm_over: int | None,
+    issue_filter: IssueQuery | None,
+    owners: tuple[str, ...],
+    select: tuple[str, ...],
+    ignore: tuple[str, ...],
+    commit_range: str | None,
+    pr_title: str | None,
+    staged: bool,
+    shard: Shard | None,
+    use_stdin: bool,
+    stdin_filename: str | None,
 ) -> None:
     """Check files for issues using the specified tools.
 
@@ -137,11 +506,19 @@ def check_command(
         paths: tuple: List of file/directory paths to check.
         tools: str | None: Comma-separated list of tool names to run.
         tool_options: str | None: Tool-specific configuration options.
+        extra_args: dict[str, list[str]]: Extra command-line arguments per
+            tool.
         exclude: str | None: Comma-separated patterns of files/dirs to exclude.
         include_venv: bool: Whether to include virtual environment directories.
-        output: str | None: Path to output file for results.
-        output_format: str: Format for displaying results (table, json, etc).
-        group_by: str: How to group issues in output (tool, file, etc).
+        no_gitignore: bool: Whether to also process git-ignored files.
+        code_blocks: bool: Whether to also check the fenced code blocks of
+            Markdown files.
+        output: tuple[OutputSink, ...]: Console and files the results are
+            sent to.
+        output_format: str | None: Format for displaying results (table, json, etc).
+        group_by: str | None: How to group issues in output (tool, file, etc).
+        columns: list[str] | None: Columns of the issue tables, in order.
+        sort_by: str | None: Order of the issue table rows.
         ignore_conflicts: bool: Whether to ignore tool configuration conflicts.
         verbose: bool: Whether to show verbose output during execution.
         no_log: bool: Whether to disable logging to file.
@@ -150,12 +527,101 @@ def check_command(
         no_cache: bool: Whether to clear the incremental cache before running.
         stream: bool: Whether to stream tool output in real-time.
         debug: bool: Whether to enable debug output on console.
+        log_format: str: Log format on stderr, "text" or "json".
         auto_install: bool: Whether to auto-install Node.js deps if missing.
+        install_missing: bool: Whether to install missing tools before running.
         yes: bool: Skip confirmation prompt and proceed immediately.
+        profile: str | None: Named configuration profile to apply.
+        fail_on: str | None: Lowest issue severity that fails the run.
+        max_issues: int | None: Failing issues tolerated before the run fails.
+        fail_fast: bool: Whether to stop once the run is known to fail,
+            leaving the remaining tools unrun.
+        summary: str: Execution summary style, "table" or "prose".
+        summary_only: bool: Whether to print only the per-tool counts table.
+        quiet: bool: Whether to print nothing unless the run fails.
+        profile_timing: bool: Whether to report the time of each tool phase
+            and the slowest files.
+        sample_per_rule: int | None: Issues shown per rule on the console and
+            in Markdown and HTML output files.
+        no_dedupe: bool: Whether to keep duplicate issues from different tools.
+        toolchain_matrix: bool: Whether to run version-sensitive tools once
+            per configured toolchain.
+        sign_report: str | None: PEM private key used to sign the --output
+            report.
+        assert_read_only: bool: Whether to fail when a tool writes to the
+            workspace.
+        max_files: int | None: Abort if the tools would process more files.
+        confirm_over: int | None: Ask first if the tools would process more
+            files.
+        issue_filter: IssueQuery | None: Query selecting the issues that are
+            shown and counted.
+        owners: tuple[str, ...]: CODEOWNERS owners whose files' issues are
+            shown and counted.
+        select: tuple[str, ...]: tool:rule patterns of the rules to show and
+            count.
+        ignore: tuple[str, ...]: tool:rule patterns of the rules to drop.
+        commit_range: str | None: Git revision range whose commit messages
+            are linted by conventional_commits.
+        pr_title: str | None: Pull request title linted by
+            conventional_commits.
+        staged: bool: Whether to check only the files staged for the next
+            commit.
+        shard: Shard | None: Part of the files to check.
+        use_stdin: bool: Whether to check content read from stdin.
+        stdin_filename: str | None: Path of the file the stdin content
+            stands for.
 
     Raises:
+        click.BadParameter: If a --select or --ignore pattern names no rule,
+            or the directory of --stdin-filename does not exist.
+        click.UsageError: If --sign-report is given without an output file,
+            console formats conflict, --quiet is given with --summary-only,
+            --staged with paths, or --stdin
+            without --stdin-filename, with paths or with --staged.
         SystemExit: Process exit with the aggregated exit code from tools.
     """
+    file_sinks = [sink for sink in output if not sink.is_console]
+    if sign_report and not file_sinks:
+        raise click.UsageError("--sign-report requires --output")
+    try:
+        chosen_format = console_format(output)
+    except ValueError as e:
+        raise click.UsageError(str(e)) from e
+    if chosen_format:
+        if output_format and output_format != chosen_format:
+            raise click.UsageError(
+                f"--output console={chosen_format} conflicts with "
+                f"--output-format {output_format}",
+            )
+        output_format = chosen_format
+    if quiet and summary_only:
+        raise click.UsageError("--quiet and --summary-only cannot be combined")
+    issue_filter = restrict_to_owners(issue_filter, owners)
+    try:
+        rule_selection = RuleSelection.from_patterns(select, ignore)
+    except ValueError as e:
+        raise click.BadParameter(str(e), param_hint="--select/--ignore") from e
+    if use_stdin != (stdin_filename is not None):
+        raise click.UsageError("--stdin and --stdin-filename must be used together")
+    if use_stdin and (paths or staged):
+        raise click.UsageError("--stdin cannot be combined with paths or --staged")
+    if staged:
+        if paths:
+            raise click.UsageError("--staged cannot be combined with paths")
+        vcs = detect_vcs()
+        staged_paths = vcs.staged_files()
+        if staged_paths is None:
+            click.echo(
+                f"Warning: --staged found no staged files ({vcs.name} could not "
+                "list them); checking all files instead.",
+                err=True,
+            )
+        elif not staged_paths:
+            click.echo("No staged files to check.")
+            raise SystemExit(DEFAULT_EXIT_CODE)
+        else:
+            paths = tuple(staged_paths)
+
     # Handle cache clearing
     if no_cache:
         from lintro.utils.file_cache import clear_all_caches
@@ -174,26 +640,81 @@ def check_command(
         ",".join(tool_option_parts) if tool_option_parts else None
     )
 
-    # Run with simplified approach
-    exit_code: int = run_lint_tools_simple(
-        action=DEFAULT_ACTION,
-        paths=path_list,
-        tools=tools,
-        tool_options=combined_tool_options,
-        exclude=exclude,
-        include_venv=include_venv,
-        group_by=group_by,
-        output_format=output_format,
-        verbose=verbose,
-        raw_output=raw_output,
-        output_file=output,
-        incremental=incremental,
-        debug=debug,
-        stream=stream,
-        no_log=no_log,
-        auto_install=auto_install,
-        yes=yes,
-    )
+    # Commit linting flags select conventional_commits; without --tools it
+    # is the only tool run. Titles are passed as-is since they may hold commas.
+    commit_options: dict[str, object] = {}
+    if commit_range:
+        commit_options["commit_range"] = commit_range
+    if pr_title is not None:
+        commit_options["pr_title"] = pr_title
+    if commit_options:
+        selected = [t.strip().lower() for t in tools.split(",")] if tools else []
+        if COMMIT_LINT_TOOL not in selected:
+            tools = ",".join([*selected, COMMIT_LINT_TOOL])
+    extra_tool_options: dict[str, dict[str, object]] = {
+        tool: {"extra_args": args} for tool, args in extra_args.items()
+    }
+    if commit_options:
+        extra_tool_options.setdefault(COMMIT_LINT_TOOL, {}).update(commit_options)
+
+    # Editors read JSON diagnostics for a buffer given on stdin
+    with ExitStack() as stack:
+        buffer: StdinBuffer | None = None
+        if use_stdin and stdin_filename is not None:
+            content = click.get_binary_stream("stdin").read()
+            try:
+                buffer = stack.enter_context(stdin_buffer(content, stdin_filename))
+            except ValueError as e:
+                raise click.BadParameter(str(e), param_hint="--stdin-filename") from e
+            path_list = [str(buffer.copy)]
+            output_format = output_format or "json"
+
+        # Run with simplified approach
+        exit_code: int = run_lint_tools_simple(
+            action=DEFAULT_ACTION,
+            paths=path_list,
+            tools=tools,
+            tool_options=combined_tool_options,
+            exclude=exclude,
+            include_venv=include_venv,
+            gitignore=not no_gitignore,
+            code_blocks=code_blocks,
+            group_by=group_by,
+            output_format=output_format,
+            verbose=verbose,
+            raw_output=raw_output,
+            outputs=file_sinks,
+            incremental=incremental,
+            debug=debug,
+            log_format=log_format,
+            stream=stream,
+            no_log=no_log,
+            auto_install=auto_install,
+            install_missing=install_missing,
+            yes=yes,
+            profile=profile,
+            fail_on=fail_on,
+            max_issues=max_issues,
+            fail_fast=fail_fast,
+            summary=summary,
+            summary_only=summary_only,
+            quiet=quiet,
+            profile_timing=profile_timing,
+            sample_per_rule=sample_per_rule,
+            dedupe=not no_dedupe,
+            toolchain_matrix=toolchain_matrix,
+            sign_key=sign_report,
+            assert_read_only=assert_read_only,
+            max_files=max_files,
+            confirm_over=confirm_over,
+            issue_filter=issue_filter,
+            rule_selection=rule_selection,
+            extra_tool_options=extra_tool_options or None,
+            columns=columns,
+            sort_by=sort_by,
+            shard=shard,
+            stdin=buffer,
+        )
 
     # Exit with code only; CLI uses this as process exit code and avoids any
     # additional trailing output after the logger's ASCII art.
@@ -206,7 +727,7 @@ def check(
     tool_options: str | None,
     exclude: str | None,
     include_venv: bool,
-    output: str | None,
+    output: str | list[str] | None,
     output_format: str,
     group_by: str,
     ignore_conflicts: bool,
@@ -214,6 +735,33 @@ def check(
     no_log: bool,
     auto_install: bool = False,
     yes: bool = False,
+    install_missing: bool = False,
+    fail_on: str | None = None,
+    max_issues: int | None = None,
+    summary: str = "table",
+    log_format: str = "text",
+    summary_only: bool = False,
+    quiet: bool = False,
+    sample_per_rule: int | None = None,
+    no_dedupe: bool = False,
+    toolchain_matrix: bool = False,
+    sign_report: str | None = None,
+    assert_read_only: bool = False,
+    max_files: int | None = None,
+    confirm_over: int | None = None,
+    issue_filter: str | None = None,
+    owner: str | None = None,
+    select: str | None = None,
+    ignore: str | None = None,
+    commit_range: str | None = None,
+    pr_title: str | None = None,
+    staged: bool = False,
+    shard: str | None = None,
+    no_gitignore: bool = False,
+    code_blocks: bool = False,
+    fail_fast: bool = False,
+    profile_timing: bool = False,
+    extra_args: str | list[str] | None = None,
 ) -> None:
     """Programmatic check function for backward compatibility.
 
@@ -223,7 +771,8 @@ def check(
         tool_options: str | None: Tool-specific configuration options.
         exclude: str | None: Comma-separated patterns of files/dirs to exclude.
         include_venv: bool: Whether to include virtual environment directories.
-        output: str | None: Path to output file for results.
+        output: str | list[str] | None: Output sink, or several, such as
+            ``console`` or ``sarif=report.sarif``.
         output_format: str: Format for displaying results (table, json, etc).
         group_by: str: How to group issues in output (tool, file, etc).
         ignore_conflicts: bool: Whether to ignore tool configuration conflicts.
@@ -231,6 +780,45 @@ def check(
         no_log: bool: Whether to disable logging to file.
         auto_install: bool: Whether to auto-install Node.js deps if missing.
         yes: bool: Skip confirmation prompt and proceed immediately.
+        install_missing: bool: Whether to install missing tools before running.
+        fail_on: str | None: Lowest issue severity that fails the run.
+        max_issues: int | None: Failing issues tolerated before the run fails.
+        summary: str: Execution summary style, "table" or "prose".
+        log_format: str: Log format on stderr, "text" or "json".
+        summary_only: bool: Whether to print only the per-tool counts table.
+        quiet: bool: Whether to print nothing unless the run fails.
+        sample_per_rule: int | None: Issues shown per rule on the console and
+            in Markdown and HTML output files.
+        no_dedupe: bool: Whether to keep duplicate issues from different tools.
+        toolchain_matrix: bool: Whether to run version-sensitive tools once
+            per configured toolchain.
+        sign_report: str | None: PEM private key used to sign the output
+            report.
+        assert_read_only: bool: Whether to fail when a tool writes to the
+            workspace.
+        max_files: int | None: Abort if the tools would process more files.
+        confirm_over: int | None: Ask first if the tools would process more
+            files.
+        issue_filter: str | None: Query selecting the issues that are shown
+            and counted.
+        owner: str | None: Comma-separated CODEOWNERS owners whose files'
+            issues are shown and counted.
+        select: str | None: Comma-separated tool:rule patterns of the rules
+            to show and count.
+        ignore: str | None: Comma-separated tool:rule patterns of the rules
+            to drop.
+        commit_range: str | None: Git revision range whose commit messages
+            are linted.
+        pr_title: str | None: Pull request title to lint.
+        staged: bool: Whether to check only the files staged for the next
+            commit.
+        shard: str | None: Part of the files to check, as ``I/N``.
+        no_gitignore: bool: Whether to also check git-ignored files.
+        code_blocks: bool: Whether to also check Markdown code blocks.
+        fail_fast: bool: Whether to stop once the run is known to fail.
+        profile_timing: bool: Whether to report where the run's time went.
+        extra_args: str | list[str] | None: Extra tool arguments, or several,
+            as ``TOOL:ARGS``.
 
     Returns:
         None: This function does not return a value.
@@ -243,12 +831,18 @@ def check(
         args.extend(["--tools", tools])
     if tool_options:
         args.extend(["--tool-options", tool_options])
+    for item in [extra_args] if isinstance(extra_args, str) else extra_args or []:
+        args.extend(["--extra-args", item])
     if exclude:
         args.extend(["--exclude", exclude])
     if include_venv:
         args.append("--include-venv")
-    if output:
-        args.extend(["--output", output])
+    if no_gitignore:
+        args.append("--no-gitignore")
+    if code_blocks:
+        args.append("--code-blocks")
+    for sink in [output] if isinstance(output, str) else output or []:
+        args.extend(["--output", sink])
     if output_format:
         args.extend(["--output-format", output_format])
     if group_by:
@@ -263,6 +857,55 @@ def check(
         args.append("--auto-install")
     if yes:
         args.append("--yes")
+    if install_missing:
+        args.append("--install-missing")
+    if fail_on:
+        args.extend(["--fail-on", fail_on])
+    if max_issues is not None:
+        args.extend(["--max-issues", str(max_issues)])
+    if fail_fast:
+        args.append("--fail-fast")
+    if summary != "table":
+        args.extend(["--summary", summary])
+    if log_format != "text":
+        args.extend(["--log-format", log_format])
+    if summary_only:
+        args.append("--summary-only")
+    if quiet:
+        args.append("--quiet")
+    if profile_timing:
+        args.append("--profile-timing")
+    if sample_per_rule is not None:
+        args.extend(["--sample-per-rule", str(sample_per_rule)])
+    if no_dedupe:
+        args.append("--no-dedupe")
+    if toolchain_matrix:
+        args.append("--toolchain-matrix")
+    if sign_report:
+        args.extend(["--sign-report", sign_report])
+    if assert_read_only:
+        args.append("--assert-read-only")
+    if max_files is not None:
+        args.extend(["--max-files", str(max_files)])
+    if confirm_over is not None:
+        args.extend(["--confirm-over", str(confirm_over)])
+    if issue_filter:
+        args.extend(["--filter", issue_filter])
+    if owner:
+        for name in owner.split(","):
+            args.extend(["--owner", name.strip()])
+    if select:
+        args.extend(["--select", select])
+    if ignore:
+        args.extend(["--ignore", ignore])
+    if commit_range:
+        args.extend(["--commit-range", commit_range])
+    if pr_title is not None:
+        args.extend(["--pr-title", pr_title])
+    if staged:
+        args.append("--staged")
+    if shard:
+        args.extend(["--shard", shard])
 
     runner = CliRunner()
     result = runner.invoke(check_command, args)
@@ -1,6 +1,6 @@
-"""Config command for displaying Lintro configuration status."""
+"""Config command for displaying and validating Lintro configuration."""
 
-from dataclasses import asdict
+import json
 from pathlib import Path
 from typing import Any, cast
 
@@ -9,7 +9,15 @@ from rich.console import Console
 from rich.panel import Panel
 from rich.table import Table
 
-from lintro.config import LintroConfig, get_config
+from lintro.config import LintroConfig, get_config, set_active_profile
+from lintro.config.config_loader import build_config_from_data, load_config_data
+from lintro.config.config_schema import (
+    ConfigProblem,
+    find_config_conflicts,
+    load_config_schema,
+    validate_config_data,
+)
+from lintro.plugins.protocol import ToolDefinition
 from lintro.utils.unified_config import (
     _load_native_tool_config,
     get_ordered_tools,
@@ -33,7 +41,18 @@ def _get_all_tool_names() -> list[str]:
     return ToolRegistry.get_names()
 
 
-@click.command()
+def _get_tool_definitions() -> dict[str, ToolDefinition]:
+    """Get the definitions of all registered tools.
+
+    Returns:
+        dict[str, ToolDefinition]: Definitions keyed by tool name.
+    """
+    from lintro.plugins.registry import ToolRegistry
+
+    return ToolRegistry.get_definitions()
+
+
+@click.group(invoke_without_command=True)
 @click.option(
     "--verbose",
     "-v",
@@ -52,10 +71,19 @@ def _get_all_tool_names() -> list[str]:
     type=click.Path(),
     help="Export effective configuration as a .lintro-config.yaml file.",
 )
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Show the configuration with a named profile applied.",
+)
+@click.pass_context
 def config_command(
+    ctx: click.Context,
     verbose: bool,
     json_output: bool,
     export_path: str | None,
+    profile: str | None,
 ) -> None:
     """Display Lintro configuration status.
 
@@ -66,13 +94,30 @@ def config_command(
     - Per-tool effective configuration
     - Configuration warnings and inconsistencies
 
+    Use ``lintro config validate`` to check the config file against the
+    published JSON Schema.
+
     Args:
+        ctx: Click context.
         verbose: Show detailed configuration including native tool configs.
         json_output: Output configuration as JSON.
         export_path: Path to export effective configuration as YAML file.
+        profile: Named configuration profile to apply.
+
+    Raises:
+        SystemExit: If the configuration or profile cannot be loaded.
     """
+    if ctx.invoked_subcommand is not None:
+        return
+
     console = Console()
-    config = get_config(reload=True)
+    if profile is not None:
+        set_active_profile(profile)
+    try:
+        config = get_config(reload=True)
+    except ValueError as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
 
     if export_path:
         _export_yaml(config=config, export_path=export_path, console=console)
@@ -98,12 +143,26 @@ def _config_to_export_dict(config: LintroConfig) -> dict[str, Any]:
     Returns:
         dict[str, Any]: Dictionary suitable for YAML serialization.
     """
-    tools = {name: asdict(cfg) for name, cfg in config.tools.items()}
+    tools = {name: cfg.model_dump() for name, cfg in config.tools.items()}
     return {
-        "enforce": asdict(config.enforce),
-        "execution": asdict(config.execution),
+        "enforce": config.enforce.model_dump(),
+        "execution": config.execution.model_dump(),
         "defaults": config.defaults,
         "tools": tools,
+        "output": config.output.model_dump(exclude_none=True),
+        "severity": dict(config.severity),
+        "ai": config.ai.model_dump(exclude_none=True),
+        "gates": {
+            name: gate.model_dump(exclude_none=True)
+            for name, gate in config.gates.items()
+        },
+        "score": config.score.model_dump(exclude_none=True),
+        "labels": dict(config.labels),
+        "label_policies": {
+            label: policy.model_dump(exclude_none=True)
+            for label, policy in config.label_policies.items()
+        },
+        "overrides": [override.model_dump() for override in config.overrides],
     }
 
 
@@ -151,8 +210,6 @@ def _output_json(
         config: LintroConfig instance from get_config()
         verbose: Include native configs in output when True
     """
-    import json
-
     # Get tool order settings
     tool_order = config.execution.tool_order
     if isinstance(tool_order, list):
@@ -171,6 +228,8 @@ def _output_json(
 
     output: dict[str, Any] = {
         "config_source": config.config_path or "defaults",
+        "profile": config.active_profile,
+        "available_profiles": sorted(config.profiles),
         "global_settings": {
             "line_length": config.enforce.line_length,
             "target_python": config.enforce.target_python,
@@ -235,6 +294,11 @@ def _output_rich(
     # Config Source Section
     config_source = config.config_path or "[dim]No config file (using defaults)[/dim]"
     console.print(f"[bold]Config Source:[/bold] {config_source}")
+    if config.active_profile:
+        console.print(f"[bold]Profile:[/bold] {config.active_profile}")
+    elif config.profiles:
+        available = ", ".join(sorted(config.profiles))
+        console.print(f"[bold]Profiles:[/bold] [dim]{available}[/dim]")
     console.print()
 
     # Global Settings Section
@@ -303,11 +367,16 @@ def _output_rich(
     order_table.add_column("Type", style="green")
     order_table.add_column("Enabled", justify="center")
 
+    definitions = _get_tool_definitions()
     for idx, tool_name in enumerate(ordered_tools, 1):
         priority = get_tool_priority(tool_name)
         injectable = is_tool_injectable(tool_name)
         tool_type = "Syncable" if injectable else "Native only"
-        enabled = config.is_tool_enabled(tool_name)
+        definition = definitions.get(tool_name)
+        enabled = config.is_tool_enabled(
+            tool_name,
+            by_default=definition.enabled_by_default if definition else True,
+        )
         enabled_display = "[green]✓[/green]" if enabled else "[red]✗[/red]"
 
         order_table.add_row(
@@ -384,3 +453,139 @@ def _output_rich(
     console.print(
         '[dim]  tool_order: "priority" | "alphabetical" | ["tool1", "tool2"][/dim]',
     )
+
+
+@config_command.command("validate")
+@click.option(
+    "--config",
+    "config_path",
+    type=click.Path(exists=True, dir_okay=False),
+    default=None,
+    help="Config file to validate (default: the discovered config).",
+)
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Also apply a named profile when resolving the effective config.",
+)
+@click.option(
+    "--show-effective",
+    is_flag=True,
+    help="Print the fully-resolved effective configuration.",
+)
+@click.option(
+    "--strict",
+    is_flag=True,
+    help="Exit non-zero on warnings as well as errors.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output validation results as JSON.",
+)
+def config_validate_command(
+    config_path: str | None,
+    profile: str | None,
+    *,
+    show_effective: bool,
+    strict: bool,
+    json_output: bool,
+) -> None:
+    """Validate the configuration against the Lintro JSON Schema.
+
+    Reports unknown keys (with suggestions for likely typos), type errors,
+    invalid values and conflicting options such as a tool that is listed in
+    execution.enabled_tools but disabled under tools.
+
+    Args:
+        config_path: Explicit config file, or None to use the discovered one.
+        profile: Named profile to apply when resolving the effective config.
+        show_effective: Print the fully-resolved effective configuration.
+        strict: Treat warnings as errors for the exit code.
+        json_output: Output validation results as JSON.
+
+    Raises:
+        SystemExit: If errors (or, with --strict, warnings) are found.
+
+    Examples:
+        lintro config validate
+        lintro config validate --show-effective --profile ci
+        lintro config validate --config ci/.lintro-config.yaml --strict
+    """
+    console = Console()
+    data, source = load_config_data(config_path=config_path)
+
+    # pyproject.toml tool sections also carry tool options
+    is_pyproject = source is not None and source.endswith(".toml")
+    problems = validate_config_data(data, allow_tool_options=is_pyproject)
+    problems.extend(
+        find_config_conflicts(
+            data,
+            known_tools=_get_all_tool_names(),
+            base_dir=Path(source).parent if source else None,
+        ),
+    )
+
+    effective: LintroConfig | None = None
+    try:
+        effective = build_config_from_data(data, config_path=source, profile=profile)
+    except ValueError as e:
+        problems.append(ConfigProblem("profiles" if profile else "<root>", str(e)))
+
+    errors = [p for p in problems if p.level == "error"]
+    warnings = [p for p in problems if p.level == "warning"]
+
+    if json_output:
+        output: dict[str, Any] = {
+            "config_source": source or "defaults",
+            "valid": not errors,
+            "errors": [{"path": p.path, "message": p.message} for p in errors],
+            "warnings": [{"path": p.path, "message": p.message} for p in warnings],
+        }
+        if show_effective and effective is not None:
+            output["effective"] = _config_to_export_dict(effective)
+        click.echo(json.dumps(output, indent=2))
+    else:
+        source_display = source or "[dim]defaults[/dim]"
+        console.print(f"[bold]Config Source:[/bold] {source_display}")
+        for problem in errors:
+            console.print(f"  [red]✗[/red] {problem.path}: {problem.message}")
+        for problem in warnings:
+            console.print(
+                f"  [yellow]⚠️[/yellow]  {problem.path}: {problem.message}",
+            )
+
+        if errors:
+            console.print(
+                f"\n[red]❌ {len(errors)} error(s), {len(warnings)} warning(s)[/red]",
+            )
+        elif warnings:
+            console.print(
+                f"\n[yellow]Configuration is valid with "
+                f"{len(warnings)} warning(s)[/yellow]",
+            )
+        else:
+            console.print("\n[green]✅ Configuration is valid[/green]")
+
+        if show_effective and effective is not None:
+            import yaml
+
+            console.print("\n[bold cyan]Effective configuration:[/bold cyan]")
+            click.echo(
+                yaml.safe_dump(_config_to_export_dict(effective), sort_keys=False),
+            )
+
+    if errors or (strict and warnings):
+        raise SystemExit(1)
+
+
+@config_command.command("schema")
+def config_schema_command() -> None:
+    """Print the JSON Schema for .lintro-config.yaml.
+
+    Save the output to enable completion and validation in editors, e.g.
+    ``lintro config schema > lintro-config.schema.json``.
+    """
+    click.echo(json.dumps(load_config_schema(), indent=2))
@@ -0,0 +1,68 @@
+"""Daemon command for inspecting and stopping tool daemons."""
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.plugins.daemon_backend import (
+    DAEMON_CLIENTS,
+    daemon_statuses,
+    stop_daemons,
+)
+
+
+@click.command("daemon")
+@click.argument("action", type=click.Choice(["status", "stop"]), default="status")
+@click.option(
+    "--tools",
+    default=None,
+    help="Comma-separated tools to act on (default: every tool with a daemon).",
+)
+def daemon_command(action: str, tools: str | None) -> None:
+    """Show or stop the daemons of tools run with ``daemon: true``.
+
+    Daemons keep running between lintro runs so later runs skip the tool's
+    startup; ``stop`` shuts them down, for example after upgrading the tool.
+
+    Args:
+        action: ``status`` to list the daemons, ``stop`` to stop them.
+        tools: Comma-separated tools to act on, or None for all.
+
+    Raises:
+        click.BadParameter: If a tool has no daemon.
+
+    Examples:
+        lintro daemon
+        lintro daemon stop --tools eslint
+    """
+    selected = [t.strip() for t in tools.split(",") if t.strip()] if tools else None
+    unknown = [t for t in selected or [] if t not in DAEMON_CLIENTS]
+    if unknown:
+        raise click.BadParameter(
+            f"no daemon for {', '.join(unknown)}; "
+            f"supported: {', '.join(sorted(DAEMON_CLIENTS))}",
+            param_hint="--tools",
+        )
+
+    console = Console()
+    if action == "stop":
+        stopped = stop_daemons(selected)
+        if not stopped:
+            console.print("No daemon clients are installed.")
+        for tool in stopped:
+            console.print(f"Stopped the {DAEMON_CLIENTS[tool].client} daemon")
+        return
+
+    table = Table(title="Tool Daemons")
+    table.add_column("Tool", style="cyan", no_wrap=True)
+    table.add_column("Client")
+    table.add_column("Status", justify="center")
+    for status in daemon_statuses(selected):
+        if not status.installed:
+            state = "[dim]not installed[/dim]"
+        elif status.running:
+            state = "[green]running[/green]"
+        else:
+            state = "[yellow]stopped[/yellow]"
+        table.add_row(status.tool, status.client, state)
+    console.print(table)
@@ -0,0 +1,201 @@
+"""Diff command comparing the issues of two runs.
+
+Issues are matched by fingerprint, so ``lintro diff --fail-on-new`` can gate
+CI on the issues a change introduced while older issues are paid down over
+time.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.utils.badge import load_report
+from lintro.utils.execution.check_runner import run_checks
+from lintro.utils.execution.ref_workspace import GitRefWorkspace
+from lintro.utils.report_diff import (
+    DiffIssue,
+    ReportDiff,
+    diff_issues,
+    report_issues,
+    result_issues,
+)
+
+ReportPath = click.Path(exists=True, dir_okay=False, path_type=Path)
+
+
+def _load_issues(path: Path) -> list[DiffIssue]:
+    """Read the issues of a JSON report.
+
+    Args:
+        path: Report written with ``--output-format json``.
+
+    Returns:
+        list[DiffIssue]: Issues of the report.
+
+    Raises:
+        click.ClickException: If the file is not a Lintro JSON report.
+    """
+    try:
+        return report_issues(load_report(path))
+    except (OSError, ValueError) as e:
+        raise click.ClickException(f"Cannot read {path}: {e}") from e
+
+
+def _check_issues(tools: str | None, exclude: str | None) -> list[DiffIssue]:
+    """Run the checks in the working directory.
+
+    Args:
+        tools: Comma-separated tool names, or None for all enabled tools.
+        exclude: Comma-separated exclude patterns.
+
+    Returns:
+        list[DiffIssue]: Issues found.
+
+    Raises:
+        click.ClickException: If the checks cannot run.
+    """
+    try:
+        return result_issues(run_checks(["."], tools, exclude))
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+
+
+def _print_diff(diff: ReportDiff) -> None:
+    """Print the counts per tool and the new and fixed issues.
+
+    Args:
+        diff: Classified issues.
+    """
+    console = Console()
+    if not diff.fingerprinted:
+        console.print(
+            "[yellow]A report has no fingerprints; issues were matched by "
+            "tool, rule, path and message.[/yellow]",
+        )
+    tools = sorted({i.tool for i in (*diff.new, *diff.fixed, *diff.unchanged)})
+    table = Table(title="Issue Diff")
+    table.add_column("Tool", style="cyan")
+    table.add_column("New", justify="right")
+    table.add_column("Fixed", justify="right")
+    table.add_column("Unchanged", justify="right")
+    for tool in tools:
+        table.add_row(
+            tool,
+            str(sum(i.tool == tool for i in diff.new)),
+            str(sum(i.tool == tool for i in diff.fixed)),
+            str(sum(i.tool == tool for i in diff.unchanged)),
+        )
+    table.add_row(
+        "Total",
+        str(len(diff.new)),
+        str(len(diff.fixed)),
+        str(len(diff.unchanged)),
+        style="bold",
+    )
+    console.print(table)
+    for title, style, issues in (
+        ("New", "red", diff.new),
+        ("Fixed", "green", diff.fixed),
+    ):
+        if issues:
+            console.print(f"\n[{style}]{title} issues:[/{style}]")
+        for issue in issues:
+            location = f"{issue.file}:{issue.line}" if issue.line else issue.file
+            console.print(f"  {location} {issue.tool} {issue.code} {issue.message}")
+
+
+@click.command("diff")
+@click.argument("old_report", required=False, type=ReportPath)
+@click.argument("new_report", required=False, type=ReportPath)
+@click.option(
+    "--against",
+    "ref",
+    help="Git ref to run the checks at for the old side, instead of OLD.",
+)
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated list of tools to run (default: all enabled).",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--fail-on-new",
+    is_flag=True,
+    help="Exit with status 1 when the new side has issues the old side lacks.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output the diff as JSON.",
+)
+def diff_command(
+    old_report: Path | None,
+    new_report: Path | None,
+    ref: str | None,
+    tools: str | None,
+    exclude: str | None,
+    fail_on_new: bool,
+    json_output: bool,
+) -> None:
+    """Classify issues as new, fixed or unchanged between two runs.
+
+    Compares two JSON reports, or with --against the issues at a git ref
+    with NEW (default: the checks run on the working tree). Issues are
+    matched by fingerprint, so moved code keeps its issues unchanged.
+
+    Args:
+        old_report: JSON report of the old run.
+        new_report: JSON report of the new run.
+        ref: Git ref whose checks form the old side.
+        tools: Comma-separated tools for runs the command does itself.
+        exclude: Comma-separated exclude patterns for those runs.
+        fail_on_new: Whether new issues fail the command.
+        json_output: Whether to print JSON instead of tables.
+
+    Raises:
+        click.UsageError: If the reports and --against do not fit together.
+        click.ClickException: If a report cannot be read or a run fails.
+        SystemExit: With status 1 for new issues with --fail-on-new.
+
+    Examples:
+        lintro diff base.json head.json --fail-on-new
+        lintro diff --against origin/main --fail-on-new
+    """
+    if ref is not None:
+        if new_report is not None:
+            raise click.UsageError("--against takes at most one report (NEW)")
+        try:
+            with GitRefWorkspace(ref):
+                old = _check_issues(tools, exclude)
+        except ValueError as e:
+            raise click.ClickException(str(e)) from e
+        # With --against, the only report given is the new side
+        new = (
+            _load_issues(old_report)
+            if old_report is not None
+            else _check_issues(tools, exclude)
+        )
+    elif old_report is None or new_report is None:
+        raise click.UsageError("Give two reports (OLD NEW) or --against REF")
+    else:
+        old = _load_issues(old_report)
+        new = _load_issues(new_report)
+
+    diff = diff_issues(old, new)
+    if json_output:
+        click.echo(json.dumps(diff.to_dict(), indent=2))
+    else:
+        _print_diff(diff)
+
+    if fail_on_new and diff.new:
+        raise SystemExit(1)
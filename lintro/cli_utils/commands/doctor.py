@@ -3,27 +3,45 @@
 This command checks tools that users must install separately (not bundled with lintro).
 Bundled Python tools (ruff, black, bandit, mypy, yamllint) are installed
 as dependencies and managed via pyproject.toml - use `pip check` or `uv sync` for those.
+They are only checked when pinned with ``tools.<name>.version`` in the Lintro config.
 """
 
 from __future__ import annotations
 
 import json
+import os
+import platform
+import re
 import shutil
 import subprocess
 import sys
 from dataclasses import asdict, dataclass
+from pathlib import Path
 
 import click
 from rich.console import Console
 from rich.table import Table
 
 from lintro._tool_versions import TOOL_VERSIONS
+from lintro.tools.core.version_checking import get_install_hints
 from lintro.tools.core.version_parsing import extract_version_from_output
 from lintro.utils.environment import (
     EnvironmentReport,
     collect_full_environment,
     render_environment_report,
 )
+from lintro.utils.toolchains import (
+    ToolchainPin,
+    find_node_version,
+    find_python_environment,
+    find_python_version,
+    find_rust_toolchain,
+    node_toolchain_bin,
+    venv_python_version,
+    version_matches_pin,
+)
+from lintro.utils.vcs import UnavailableVcs, detect_vcs
+from lintro.utils.version_pins import get_version_pins, version_satisfies
 
 
 @dataclass
@@ -44,25 +62,238 @@ class VersionCheckResult:
     path: str | None = None
 
 
+@dataclass
+class ToolchainMismatch:
+    """A toolchain pin that tools will not run with.
+
+    Attributes:
+        toolchain: Toolchain name (rust, node or python).
+        pinned: Version the project pins.
+        source: File pinning the version.
+        active: Version tools run with, if known.
+        message: What is wrong and how to fix it.
+    """
+
+    toolchain: str
+    pinned: str
+    source: str
+    active: str | None
+    message: str
+
+
 # Map tool names to commands (external tools only)
 TOOL_COMMANDS: dict[str, list[str]] = {
     "actionlint": ["actionlint", "--version"],
+    "ansible_lint": ["ansible-lint", "--version"],
+    "biome": ["biome", "--version"],
+    "buf": ["buf", "--version"],
     "cargo_audit": ["cargo", "audit", "--version"],
+    "checkov": ["checkov", "--version"],
+    "clang_format": ["clang-format", "--version"],
+    "clang_tidy": ["clang-tidy", "--version"],
     "clippy": ["cargo", "clippy", "--version"],
+    "eslint": ["eslint", "--version"],
     "gitleaks": ["gitleaks", "version"],
+    "gofmt": ["go", "version"],
+    "golangci_lint": ["golangci-lint", "--version"],
     "hadolint": ["hadolint", "--version"],
+    "ktlint": ["ktlint", "--version"],
+    "luacheck": ["luacheck", "--version"],
     "markdownlint": ["markdownlint-cli2", "--version"],
     "oxfmt": ["oxfmt", "--version"],
     "oxlint": ["oxlint", "--version"],
     "pytest": [sys.executable, "-m", "pytest", "--version"],
+    "rubocop": ["rubocop", "--version"],
     "rustfmt": ["rustfmt", "--version"],
     "semgrep": ["semgrep", "--version"],
     "shellcheck": ["shellcheck", "--version"],
     "shfmt": ["shfmt", "--version"],
     "sqlfluff": ["sqlfluff", "--version"],
+    "stylelint": ["stylelint", "--version"],
+    "stylua": ["stylua", "--version"],
     "taplo": ["taplo", "--version"],
+    "terraform_fmt": ["terraform", "version"],
+    "tflint": ["tflint", "--version"],
+    "typos": ["typos", "--version"],
+    "vale": ["vale", "--version"],
+    # Bundled Python tools, checked only when pinned
+    "bandit": ["bandit", "--version"],
+    "black": ["black", "--version"],
+    "mypy": ["mypy", "--version"],
+    "pydoclint": ["pydoclint", "--version"],
+    "ruff": ["ruff", "--version"],
+    "yamllint": ["yamllint", "--version"],
 }
 
+# Install locations that are often missing from PATH
+COMMON_BIN_DIRS: tuple[str, ...] = (
+    "~/.cargo/bin",
+    "~/.local/bin",
+    "~/go/bin",
+    "~/.bun/bin",
+    "/opt/homebrew/bin",
+    "/usr/local/bin",
+    "node_modules/.bin",
+)
+
+
+_TOOLCHAIN_VERSION_PATTERN = re.compile(r"(\d+\.\d+(?:\.\d+)?)")
+
+
+def _toolchain_version(command: list[str], cwd: Path) -> str | None:
+    """Get the version a toolchain command reports.
+
+    Args:
+        command: Version command (e.g. ``["rustc", "--version"]``).
+        cwd: Directory to run it in, so version managers see the project.
+
+    Returns:
+        str | None: Version, or None if the command is missing or fails.
+    """
+    if shutil.which(command[0]) is None:
+        return None
+    try:
+        result = subprocess.run(
+            command,
+            capture_output=True,
+            text=True,
+            timeout=10,
+            cwd=cwd,
+        )
+    except (subprocess.TimeoutExpired, OSError):
+        return None
+    if result.returncode != 0:
+        return None
+    match = _TOOLCHAIN_VERSION_PATTERN.search(result.stdout + result.stderr)
+    return match.group(1) if match else None
+
+
+def _rustup_has_toolchain(toolchain: str, cwd: Path) -> bool:
+    """Check whether rustup has installed a toolchain.
+
+    Args:
+        toolchain: Toolchain name (e.g. ``1.80.0`` or ``stable``).
+        cwd: Directory to run rustup in.
+
+    Returns:
+        bool: True if installed, or if rustup cannot list its toolchains.
+    """
+    try:
+        result = subprocess.run(
+            ["rustup", "toolchain", "list"],
+            capture_output=True,
+            text=True,
+            timeout=10,
+            cwd=cwd,
+        )
+    except (subprocess.TimeoutExpired, OSError):
+        return True
+    if result.returncode != 0:
+        return True
+    names = [line.split()[0] for line in result.stdout.splitlines() if line.strip()]
+    return any(name == toolchain or name.startswith(f"{toolchain}-") for name in names)
+
+
+def _check_rust_toolchain(pin: ToolchainPin, cwd: Path) -> ToolchainMismatch | None:
+    """Check the Rust toolchain clippy and rustfmt run with.
+
+    Args:
+        pin: Toolchain pinned by a rust-toolchain file.
+        cwd: Project directory.
+
+    Returns:
+        ToolchainMismatch | None: The problem, or None if the pin is honored.
+    """
+    active = _toolchain_version(["rustc", "--version"], cwd)
+    message = None
+    if shutil.which("rustup") is None:
+        message = "rustup is not installed, so cargo ignores the pin"
+    elif not _rustup_has_toolchain(pin.version, cwd):
+        message = f"not installed; run 'rustup toolchain install {pin.version}'"
+    elif active and version_matches_pin(active, pin.version) is False:
+        message = (
+            f"rustc {active} on PATH is not rustup's proxy; put ~/.cargo/bin "
+            "first on PATH"
+        )
+    if message is None:
+        return None
+    return ToolchainMismatch("rust", pin.version, str(pin.source), active, message)
+
+
+def _check_node_toolchain(pin: ToolchainPin, cwd: Path) -> ToolchainMismatch | None:
+    """Check the Node.js release Node.js tools run with.
+
+    Args:
+        pin: Version pinned by ``.nvmrc``, ``.node-version`` or volta.
+        cwd: Project directory.
+
+    Returns:
+        ToolchainMismatch | None: The problem, or None if the pin is honored.
+    """
+    bin_dir = node_toolchain_bin(cwd)
+    node = str(bin_dir / "node") if bin_dir is not None else "node"
+    active = _toolchain_version([node, "--version"], cwd)
+    if active is not None and version_matches_pin(active, pin.version) is not False:
+        return None
+    used = f"node {active} is on PATH" if active else "node is not installed"
+    return ToolchainMismatch(
+        "node",
+        pin.version,
+        str(pin.source),
+        active,
+        f"{used}; install {pin.version} with nvm or volta",
+    )
+
+
+def _check_python_toolchain(
+    pin: ToolchainPin,
+    cwd: Path,
+) -> ToolchainMismatch | None:
+    """Check the Python version Python tools run with.
+
+    Args:
+        pin: Version pinned by ``.python-version``.
+        cwd: Project directory.
+
+    Returns:
+        ToolchainMismatch | None: The problem, or None if the pin is honored.
+    """
+    venv = find_python_environment(cwd)
+    active = venv_python_version(venv) if venv else platform.python_version()
+    if active is None or version_matches_pin(active, pin.version) is not False:
+        return None
+    where = f"the environment {venv}" if venv else "lintro's interpreter"
+    return ToolchainMismatch(
+        "python",
+        pin.version,
+        str(pin.source),
+        active,
+        f"{where} runs Python {active}; recreate it with "
+        f"'uv venv --python {pin.version}'",
+    )
+
+
+def _check_toolchains(cwd: Path) -> list[ToolchainMismatch]:
+    """Check the toolchains tools run with against the project's pins.
+
+    Args:
+        cwd: Project directory.
+
+    Returns:
+        list[ToolchainMismatch]: Pins the active toolchains do not match.
+    """
+    checks = (
+        (find_rust_toolchain(cwd), _check_rust_toolchain),
+        (find_node_version(cwd), _check_node_toolchain),
+        (find_python_version(cwd), _check_python_toolchain),
+    )
+    mismatches: list[ToolchainMismatch] = []
+    for pin, check in checks:
+        mismatch = check(pin, cwd) if pin is not None else None
+        if mismatch is not None:
+            mismatches.append(mismatch)
+    return mismatches
+
 
 def _check_tool_commands_coverage() -> list[str]:
     """Check for tools in TOOL_VERSIONS that don't have commands defined.
@@ -123,6 +354,124 @@ def _get_installed_version(tool_name: str) -> VersionCheckResult:
         return VersionCheckResult(error="os_error", details=str(e))
 
 
+def _find_off_path_install(executable: str) -> str | None:
+    """Find an install of an executable in a common directory not on PATH.
+
+    Args:
+        executable: Executable name (e.g. ``taplo``).
+
+    Returns:
+        str | None: Path of the install, or None if there is none.
+    """
+    path_dirs = {
+        os.path.realpath(d) for d in os.environ.get("PATH", "").split(os.pathsep) if d
+    }
+    for directory in COMMON_BIN_DIRS:
+        expanded = os.path.realpath(os.path.expanduser(directory))
+        if expanded in path_dirs:
+            continue
+        candidate = os.path.join(expanded, executable)
+        if os.path.isfile(candidate) and os.access(candidate, os.X_OK):
+            return candidate
+    return None
+
+
+def _pin_satisfied(version: str, pin: str) -> bool:
+    """Check an installed version against a pin.
+
+    Args:
+        version: Installed version.
+        pin: Version pin from the Lintro config.
+
+    Returns:
+        bool: True if the version matches; False otherwise or if unparsable.
+    """
+    try:
+        return version_satisfies(version, pin)
+    except ValueError:
+        return False
+
+
+def _find_path_problems(executable: str) -> list[str]:
+    """Find PATH problems for an executable.
+
+    Reports copies that shadow each other on PATH, copies that are not
+    executable, and installs in common locations that are not on PATH.
+
+    Args:
+        executable: Executable name (e.g. ``ruff``).
+
+    Returns:
+        list[str]: Human-readable problem descriptions.
+    """
+    problems: list[str] = []
+    path_dirs = [d for d in os.environ.get("PATH", "").split(os.pathsep) if d]
+    found: list[str] = []
+    seen: set[str] = set()
+    for directory in path_dirs:
+        candidate = os.path.join(directory, executable)
+        if not os.path.isfile(candidate):
+            continue
+        if not os.access(candidate, os.X_OK):
+            problems.append(f"{candidate} is not executable")
+            continue
+        real = os.path.realpath(candidate)
+        if real not in seen:
+            seen.add(real)
+            found.append(candidate)
+
+    if len(found) > 1:
+        problems.append(
+            f"{len(found)} copies on PATH, {found[0]} is used "
+            f"(also: {', '.join(found[1:])})",
+        )
+
+    if not found:
+        off_path = _find_off_path_install(executable)
+        if off_path:
+            problems.append(f"installed at {off_path}, which is not on PATH")
+
+    return problems
+
+
+def _remediation(
+    tool_name: str,
+    status: str,
+    expected: str | None,
+    pin: str | None,
+    path_problems: list[str],
+) -> str | None:
+    """Suggest how to resolve a tool's problem.
+
+    Args:
+        tool_name: Name of the tool.
+        status: Check status (ok, missing, outdated, mismatch, unknown).
+        expected: Minimum version Lintro expects.
+        pin: Version pin from the Lintro config, if any.
+        path_problems: Problems found by _find_path_problems.
+
+    Returns:
+        str | None: Remediation text, or None if nothing needs fixing.
+    """
+    hint = get_install_hints().get(tool_name, f"Install {tool_name}")
+    if status == "missing":
+        command = TOOL_COMMANDS.get(tool_name)
+        off_path = _find_off_path_install(command[0]) if command else None
+        if off_path:
+            return f"Add {os.path.dirname(off_path)} to PATH"
+        return hint
+    if status == "mismatch":
+        return (
+            f"Install a {tool_name} version matching {pin}, "
+            f"or update tools.{tool_name}.version in your Lintro config"
+        )
+    if status == "outdated":
+        return f"Upgrade to {expected} or newer. {hint}"
+    if path_problems:
+        return "Remove the extra copies or reorder PATH so the intended one wins"
+    return None
+
+
 def _compare_versions(installed: str, expected: str) -> str:
     """Compare installed version against expected.
 
@@ -208,6 +557,10 @@ def _generate_markdown_report(
         lines.append(f"Rust: {env.rust.rustc_version or 'installed'}")
     if env.ci:
         lines.append(f"CI: {env.ci.name}")
+    if env.project:
+        lines.append(
+            f"VCS: {env.project.vcs or f'unavailable ({env.project.vcs_fallback})'}",
+        )
     lines.append("```")
     lines.append("")
 
@@ -220,10 +573,13 @@ def _generate_markdown_report(
     for tool_name, info in sorted(tool_results.items()):
         version = info.get("installed") or "-"
         status = info.get("status") or "unknown"
-        status_icon = {"ok": "✓", "missing": "✗", "outdated": "⚠", "unknown": "?"}.get(
-            status,
-            "?",
-        )
+        status_icon = {
+            "ok": "✓",
+            "missing": "✗",
+            "outdated": "⚠",
+            "mismatch": "✗",
+            "unknown": "?",
+        }.get(status, "?")
         lines.append(f"| {tool_name} | {version} | {status_icon} {status} |")
 
     lines.append("")
@@ -271,7 +627,11 @@ def doctor_command(
     """Check external tool installation status and version compatibility.
 
     Checks tools that must be installed separately (hadolint, actionlint,
-    etc.). Bundled Python tools are managed via pip/uv.
+    etc.). Bundled Python tools are managed via pip/uv. Tools pinned with
+    ``tools.<name>.version`` are checked against their pin, and PATH problems
+    are reported with remediation steps. Toolchains the project pins
+    (``rust-toolchain.toml``, ``.nvmrc``, ``.python-version``) that tools
+    would not run with are reported as warnings.
 
     Args:
         json_output: If True, output results as JSON.
@@ -280,7 +640,7 @@ def doctor_command(
         report: If True, generate markdown report for GitHub issues.
 
     Raises:
-        SystemExit: If there are missing or outdated tools.
+        SystemExit: If there are missing, outdated, or off-pin tools.
 
     Examples:
         lintro doctor
@@ -305,25 +665,47 @@ def doctor_command(
             f"{', '.join(uncovered_tools)}[/yellow]",
         )
 
+    # Pinned tools are checked even when bundled with lintro
+    try:
+        pins = get_version_pins()
+    except (OSError, ValueError) as e:
+        console.print(f"[yellow]Warning: could not load version pins: {e}[/yellow]")
+        pins = {}
+
+    all_versions: dict[str, str | None] = {
+        str(name): version for name, version in TOOL_VERSIONS.items()
+    }
+    for pinned_tool in pins:
+        all_versions.setdefault(pinned_tool, None)
+
     # Filter tools if specified
     if tools:
         tool_list = [t.strip() for t in tools.split(",")]
-        versions_to_check = {k: v for k, v in TOOL_VERSIONS.items() if k in tool_list}
+        versions_to_check = {k: v for k, v in all_versions.items() if k in tool_list}
     else:
-        versions_to_check = TOOL_VERSIONS
+        versions_to_check = all_versions
 
     results: dict[str, dict[str, str | None]] = {}
+    path_problems_by_tool: dict[str, list[str]] = {}
     ok_count = 0
     missing_count = 0
     outdated_count = 0
+    mismatch_count = 0
     unknown_count = 0
 
     for tool_name, expected_version in sorted(versions_to_check.items()):
         check_result = _get_installed_version(tool_name)
+        pin = pins.get(tool_name)
 
         if check_result.version is None:
             status = "missing"
             missing_count += 1
+        elif pin and not _pin_satisfied(check_result.version, pin):
+            status = "mismatch"
+            mismatch_count += 1
+        elif expected_version is None:
+            status = "ok"
+            ok_count += 1
         else:
             status = _compare_versions(check_result.version, expected_version)
             if status == "ok":
@@ -333,14 +715,31 @@ def doctor_command(
             else:  # unknown
                 unknown_count += 1
 
+        command = TOOL_COMMANDS.get(tool_name)
+        path_problems = _find_path_problems(command[0]) if command else []
+        path_problems_by_tool[tool_name] = path_problems
+
         results[tool_name] = {
             "expected": expected_version,
+            "pinned": pin,
             "installed": check_result.version,
             "status": status,
             "error": check_result.error,
             "details": check_result.details,
             "path": check_result.path,
+            "path_problems": "; ".join(path_problems) or None,
+            "remediation": _remediation(
+                tool_name,
+                status,
+                expected_version,
+                pin,
+                path_problems,
+            ),
         }
+    failed = missing_count > 0 or outdated_count > 0 or mismatch_count > 0
+
+    # Pinned toolchains that tools would not run with
+    toolchain_mismatches = _check_toolchains(Path.cwd())
 
     # Markdown report mode
     if report:
@@ -375,15 +774,48 @@ def doctor_command(
                         "message": f"outdated ({installed} < {expected})",
                     },
                 )
+            elif info["status"] == "mismatch":
+                issues.append(
+                    {
+                        "tool": tool_name,
+                        "severity": "error",
+                        "message": (
+                            f"version {info['installed']} does not match "
+                            f"pinned {info['pinned']}"
+                        ),
+                    },
+                )
+            if info["path_problems"]:
+                issues.append(
+                    {
+                        "tool": tool_name,
+                        "severity": "warning",
+                        "message": f"PATH: {info['path_problems']}",
+                    },
+                )
+
+        for mismatch in toolchain_mismatches:
+            issues.append(
+                {
+                    "tool": mismatch.toolchain,
+                    "severity": "warning",
+                    "message": (
+                        f"{mismatch.pinned} pinned by {mismatch.source}: "
+                        f"{mismatch.message}"
+                    ),
+                },
+            )
 
         output: dict[str, object] = {
             "tools": results,
+            "toolchains": [asdict(mismatch) for mismatch in toolchain_mismatches],
             "issues": issues,
             "summary": {
                 "total": len(results),
                 "ok": ok_count,
                 "missing": missing_count,
                 "outdated": outdated_count,
+                "mismatch": mismatch_count,
                 "unknown": unknown_count,
             },
         }
@@ -405,8 +837,8 @@ def doctor_command(
                 "project": asdict(env_report.project) if env_report.project else None,
             }
         click.echo(json.dumps(output, indent=2))
-        # Exit non-zero if any tools are missing or outdated
-        if missing_count > 0 or outdated_count > 0:
+        # Exit non-zero if any tools are missing, outdated or off-pin
+        if failed:
             sys.exit(1)
         return
 
@@ -426,6 +858,8 @@ def doctor_command(
 
     for tool_name, info in results.items():
         expected = info["expected"] or "-"
+        if info["pinned"]:
+            expected = f"{info['pinned']} (pinned)"
         installed = info["installed"] or "-"
 
         if info["status"] == "ok":
@@ -434,6 +868,8 @@ def doctor_command(
             status = "[red]✗ Missing[/red]"
         elif info["status"] == "outdated":
             status = "[yellow]⚠ Outdated[/yellow]"
+        elif info["status"] == "mismatch":
+            status = "[red]✗ Pin mismatch[/red]"
         else:  # unknown
             status = "[dim]? Unknown[/dim]"
 
@@ -445,6 +881,8 @@ def doctor_command(
                 info.get("details"),
                 verbose=verbose,
             )
+        elif info["path_problems"]:
+            reason = "PATH problem"
 
         path_display = info.get("path") or "-"
         if verbose:
@@ -455,9 +893,45 @@ def doctor_command(
     display_console.print(table)
     display_console.print()
 
+    # Actionable remediation for every tool with a problem
+    remediations = [
+        (tool_name, info)
+        for tool_name, info in results.items()
+        if info["remediation"]
+    ]
+    if remediations:
+        display_console.print("[bold]How to fix:[/bold]")
+        for tool_name, info in remediations:
+            display_console.print(f"  [cyan]{tool_name}[/cyan]: {info['remediation']}")
+            for problem in path_problems_by_tool.get(tool_name, []):
+                display_console.print(f"    [dim]PATH: {problem}[/dim]")
+        display_console.print()
+
+    # Toolchain pins are warnings: tools still run, with other results
+    if toolchain_mismatches:
+        display_console.print("[bold]Toolchain pins:[/bold]")
+        for mismatch in toolchain_mismatches:
+            display_console.print(
+                f"  [yellow]⚠ {mismatch.toolchain} {mismatch.pinned}[/yellow] "
+                f"[dim]({mismatch.source})[/dim]: {mismatch.message}",
+            )
+        display_console.print()
+
+    # Commit and author details degrade to empty values without a repository
+    vcs = detect_vcs()
+    if isinstance(vcs, UnavailableVcs):
+        display_console.print(
+            f"[yellow]⚠ Version control unavailable: {vcs.reason}[/yellow]",
+        )
+        display_console.print(
+            "  [dim]Reports, attestations and the audit log omit the commit "
+            "and author.[/dim]",
+        )
+        display_console.print()
+
     # Summary
     total = len(results)
-    if missing_count == 0 and outdated_count == 0:
+    if not failed:
         if unknown_count > 0:
             display_console.print(
                 f"[green]✅ {ok_count} tool(s) OK[/green], "
@@ -474,6 +948,11 @@ def doctor_command(
             display_console.print(
                 f"[yellow]⚠ {outdated_count} tool(s) outdated[/yellow]",
             )
+        if mismatch_count > 0:
+            display_console.print(
+                f"[red]✗ {mismatch_count} tool(s) do not match their version pin"
+                "[/red]",
+            )
         if unknown_count > 0:
             display_console.print(
                 f"[dim]? {unknown_count} tool(s) with unknown version format[/dim]",
@@ -483,6 +962,6 @@ def doctor_command(
             "[dim]Run 'lintro versions --verbose' for installation instructions.[/dim]",
         )
 
-    # Exit with error if any tools are missing or outdated
-    if missing_count > 0 or outdated_count > 0:
+    # Exit with error if any tools are missing, outdated or off-pin
+    if failed:
         raise SystemExit(1)
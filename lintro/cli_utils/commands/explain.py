@@ -0,0 +1,131 @@
+"""Explain command for describing lintro error codes and tool rules."""
+
+import json
+from dataclasses import asdict
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.exceptions.errors import ERROR_CODES, ErrorCode, lookup_error
+from lintro.utils.rule_docs import RuleDoc, explain_rule
+
+
+@click.command()
+@click.argument("code", required=False)
+@click.option(
+    "--tool",
+    default=None,
+    help="Tool that reports the rule, for codes without a tool prefix.",
+)
+@click.option(
+    "--offline",
+    is_flag=True,
+    help="Only use the rule metadata bundled with lintro.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output the explanation as JSON.",
+)
+def explain_command(
+    code: str | None,
+    tool: str | None,
+    offline: bool,
+    json_output: bool,
+) -> None:
+    """Explain a lintro error code, such as LINTRO012, or a tool rule.
+
+    Without a code, lists every error code. Rules are described by the tool
+    itself when it is installed (ruff, clippy), otherwise from metadata
+    bundled with lintro, together with a link to their documentation.
+
+    Args:
+        code: Error code or rule to explain.
+        tool: Tool that reports the rule.
+        offline: Only use the bundled rule metadata.
+        json_output: Output the explanation as JSON.
+
+    Raises:
+        BadParameter: If the code is neither catalogued nor a known rule.
+
+    Examples:
+        lintro explain LINTRO012
+        lintro explain E501
+        lintro explain clippy::needless_return
+        lintro explain SC2086 --tool shellcheck --offline
+    """
+    if code is None:
+        errors = list(ERROR_CODES.values())
+    else:
+        error = lookup_error(code)
+        if error is None:
+            doc = explain_rule(code, tool=tool, offline=offline)
+            if doc is None:
+                raise click.BadParameter(
+                    f"unknown error code or rule {code!r}; run 'lintro explain' "
+                    "to list error codes, or name the tool with --tool",
+                    param_hint="CODE",
+                )
+            _print_rule(doc, json_output=json_output)
+            return
+        errors = [error]
+
+    if json_output:
+        data = [
+            {
+                "code": error.code,
+                "title": error.title,
+                "explanation": error.explanation,
+            }
+            for error in errors
+        ]
+        click.echo(json.dumps(data[0] if code else data, indent=2))
+        return
+
+    console = Console()
+    if code is None:
+        table = Table(title="Lintro Error Codes")
+        table.add_column("Code", style="cyan", no_wrap=True)
+        table.add_column("Title", style="white")
+        for error in errors:
+            table.add_row(error.code, error.title)
+        console.print(table)
+        console.print("[dim]Run 'lintro explain <CODE>' for details.[/dim]")
+        return
+
+    _print_error(console, errors[0])
+
+
+def _print_error(console: Console, error: ErrorCode) -> None:
+    """Print a catalogued error.
+
+    Args:
+        console: Console to print to.
+        error: Error to describe.
+    """
+    console.print(f"[bold cyan]{error.code}[/bold cyan]: {error.title}")
+    console.print()
+    console.print(error.explanation, markup=False)
+
+
+def _print_rule(doc: RuleDoc, json_output: bool) -> None:
+    """Print the description of a tool rule.
+
+    Args:
+        doc: Rule description.
+        json_output: Output the description as JSON.
+    """
+    if json_output:
+        click.echo(json.dumps(asdict(doc), indent=2))
+        return
+    console = Console()
+    title = f"[bold cyan]{doc.tool}:{doc.code}[/bold cyan]"
+    console.print(f"{title}: {doc.summary}" if doc.summary else title)
+    if doc.explanation:
+        console.print()
+        console.print(doc.explanation, markup=False)
+    if doc.url:
+        console.print()
+        console.print(f"Documentation: {doc.url}", markup=False, soft_wrap=True)
@@ -0,0 +1,61 @@
+"""Fix-file command for editor save hooks."""
+
+from __future__ import annotations
+
+import json
+
+import click
+
+from lintro.utils.execution.fix_file import fix_file, parse_duration
+
+
+@click.command()
+@click.argument("path", type=click.Path(exists=True, dir_okay=False))
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated fixers to run, e.g. rustfmt,ruff-format "
+    "(default: all enabled fixers for the file type).",
+)
+@click.option(
+    "--timeout",
+    type=str,
+    default="2s",
+    show_default=True,
+    help="Time allowed for the whole run, e.g. 2s or 500ms.",
+)
+def fix_file_command(path: str, tools: str | None, timeout: str) -> None:
+    """Fix a single file and print the result as JSON.
+
+    Meant for editor "fix on save" hooks: only tools that handle the file
+    type run, nothing else is discovered, and all tools share one deadline.
+    The JSON lists each tool's outcome (changed, unchanged, skipped, timeout
+    or error) and whether the file changed.
+
+    Args:
+        path: File to fix.
+        tools: Comma-separated tool names; ``ruff-format`` and ``ruff-fix``
+            run only one half of ruff.
+        timeout: Time allowed for the whole run.
+
+    Raises:
+        click.BadParameter: If the timeout is not a valid duration.
+        click.ClickException: If a tool is unknown or cannot fix files.
+        SystemExit: With 1 if a tool timed out or failed.
+
+    Examples:
+        lintro fix-file src/app.py --tools ruff-format
+        lintro fix-file src/main.rs --tools rustfmt --timeout 500ms
+    """
+    try:
+        seconds = parse_duration(timeout)
+    except ValueError as e:
+        raise click.BadParameter(str(e), param_hint="--timeout") from e
+    try:
+        result = fix_file(path, tools, seconds)
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+
+    click.echo(json.dumps(result.to_dict()))
+    if not result.ok:
+        raise SystemExit(1)
@@ -1,14 +1,43 @@
 """Format command implementation using simplified Loguru-based approach."""
 
+import hashlib
+import os
+from contextlib import nullcontext
+from pathlib import Path
+
 import click
 from click.testing import CliRunner
 
+from lintro.cli_utils.commands.check import (
+    TABLE_SORT_CHOICES,
+    parse_columns_option,
+    parse_extra_args_callback,
+)
+from lintro.config import get_config
+from lintro.enums.fix_safety import FixSafetyPolicy
+from lintro.enums.run_outcome import RunOutcome
+from lintro.utils.execution.audit_log import append_entry, build_entry
+from lintro.utils.execution.dry_run import DryRunWorkspace, FileChange, format_patch
+from lintro.utils.execution.fix_pipeline import FileFix
+from lintro.utils.execution.fix_safety import fix_safety_options
+from lintro.utils.execution.interactive_fix import review_changes, write_changes
+from lintro.utils.execution.rule_scoped_fix import parse_fix_only
+from lintro.utils.execution.worktree_guard import (
+    Autostash,
+    DirtyWorktreeError,
+    guard_worktree,
+)
+from lintro.utils.logger_setup import LOG_FORMATS
 from lintro.utils.tool_executor import run_lint_tools_simple
 
 # Constants
 DEFAULT_PATHS: list[str] = ["."]
 DEFAULT_EXIT_CODE: int = 0
 DEFAULT_ACTION: str = "fmt"
+# Outcomes of a run that did not finish its fixes
+_BROKEN_OUTCOMES: frozenset[RunOutcome] = frozenset(
+    {RunOutcome.TOOL_ERROR, RunOutcome.CONFIG_ERROR},
+)
 
 
 @click.command()
@@ -24,6 +53,15 @@ DEFAULT_ACTION: str = "fmt"
     default=None,
     help="Tool-specific options in format tool:option=value,tool2:option=value.",
 )
+@click.option(
+    "--extra-args",
+    multiple=True,
+    callback=parse_extra_args_callback,
+    help=(
+        "Extra arguments for a tool as TOOL:ARGS, e.g. "
+        "'ruff:--unsafe-fixes'; repeat for several tools."
+    ),
+)
 @click.option(
     "--exclude",
     default=None,
@@ -35,11 +73,43 @@ DEFAULT_ACTION: str = "fmt"
     default=False,
     help="Include virtual environment directories in formatting.",
 )
+@click.option(
+    "--no-gitignore",
+    is_flag=True,
+    help="Also format files ignored by .gitignore and .git/info/exclude.",
+)
 @click.option(
     "--group-by",
-    default="auto",
-    type=click.Choice(["file", "code", "none", "auto"]),
-    help="How to group issues in output.",
+    default=None,
+    type=click.Choice(
+        [
+            "file",
+            "code",
+            "none",
+            "auto",
+            "rule",
+            "severity",
+            "dir",
+            "tool",
+            "owner",
+        ],
+    ),
+    help=(
+        "How to group issues in output; rule, severity, dir, tool and owner "
+        "(CODEOWNERS) add subtotal rows. [default: auto]"
+    ),
+)
+@click.option(
+    "--columns",
+    default=None,
+    callback=parse_columns_option,
+    help="Comma-separated table columns, e.g. file,line,code.",
+)
+@click.option(
+    "--sort-by",
+    default=None,
+    type=click.Choice(TABLE_SORT_CHOICES),
+    help="Order of the issue table rows. [default: as reported by the tool]",
 )
 @click.option(
     "--output",
@@ -48,9 +118,9 @@ DEFAULT_ACTION: str = "fmt"
 )
 @click.option(
     "--output-format",
-    default="grid",
+    default=None,
     type=click.Choice(["plain", "grid", "markdown", "html", "json", "csv", "github"]),
-    help="Output format for displaying results.",
+    help="Output format for displaying results. [default: grid]",
 )
 @click.option(
     "--verbose",
@@ -71,6 +141,19 @@ DEFAULT_ACTION: str = "fmt"
     default=False,
     help="Show raw tool output instead of formatted output.",
 )
+@click.option(
+    "--summary-only",
+    is_flag=True,
+    default=False,
+    help="Only print the per-tool counts table, not the issues.",
+)
+@click.option(
+    "--quiet",
+    "-q",
+    is_flag=True,
+    default=False,
+    help="Print nothing on success and the per-tool counts table on failure.",
+)
 @click.option(
     "--stream/--no-stream",
     default=False,
@@ -81,34 +164,138 @@ DEFAULT_ACTION: str = "fmt"
     is_flag=True,
     help="Enable debug output on console",
 )
+@click.option(
+    "--log-format",
+    type=click.Choice(LOG_FORMATS),
+    default="text",
+    help=(
+        "Log format on stderr; json writes one record per line, including "
+        "tool invocations, for CI log aggregation [default: text]"
+    ),
+)
 @click.option(
     "--auto-install",
     is_flag=True,
     help="Auto-install Node.js dependencies if node_modules is missing",
 )
+@click.option(
+    "--install-missing",
+    is_flag=True,
+    help="Install selected tools that are missing, at their pinned versions",
+)
 @click.option(
     "--yes",
     "-y",
     is_flag=True,
     help="Skip confirmation prompt and proceed immediately",
 )
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Named configuration profile to apply (e.g. ci, local, strict).",
+)
+@click.option(
+    "--dry-run",
+    is_flag=True,
+    help="Print the changes as a unified diff instead of writing them.",
+)
+@click.option(
+    "--patch",
+    "patch_file",
+    type=click.Path(dir_okay=False),
+    default=None,
+    help="With --dry-run, also write the diff to this .patch file.",
+)
+@click.option(
+    "--emit-patch",
+    type=click.Path(dir_okay=False),
+    default=None,
+    metavar="FILE",
+    help="Write all fixes to FILE as one git-applyable patch, changing no files.",
+)
+@click.option(
+    "--interactive",
+    is_flag=True,
+    help="Review the fixes hunk by hunk (like git add -p) before applying them.",
+)
+@click.option(
+    "--fix-only",
+    "fix_only",
+    default=None,
+    metavar="RULES",
+    help=(
+        "Fix only these rules and report everything else "
+        "(e.g. clippy::needless_return,ruff:I001)."
+    ),
+)
+@click.option(
+    "--fix-safety",
+    type=click.Choice([policy.value for policy in FixSafetyPolicy]),
+    default=None,
+    help=(
+        "Apply only safe fixes, or all including unsafe ones that may change "
+        "behavior (default: execution.fix_safety, safe)."
+    ),
+)
+@click.option(
+    "--allow-dirty",
+    is_flag=True,
+    help="Fix files with uncommitted changes (default: execution.dirty_worktree).",
+)
+@click.option(
+    "--autostash",
+    is_flag=True,
+    help="Stash uncommitted changes before fixing and re-apply them afterwards.",
+)
+@click.option(
+    "--max-files",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Abort before running tools if they would process more than N files.",
+)
+@click.option(
+    "--confirm-over",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Ask before running tools on more than N files; abort if non-interactive.",
+)
 def format_command(
     ctx: click.Context,
     paths: tuple[str, ...],
     tools: str | None,
     tool_options: str | None,
+    extra_args: dict[str, list[str]],
     exclude: str | None,
     include_venv: bool,
+    no_gitignore: bool,
     output: str | None,
-    group_by: str,
-    output_format: str,
+    group_by: str | None,
+    columns: list[str] | None,
+    sort_by: str | None,
+    output_format: str | None,
     verbose: bool,
     no_log: bool,
     raw_output: bool,
+    summary_only: bool,
+    quiet: bool,
     stream: bool,
     debug: bool,
+    log_format: str,
     auto_install: bool,
+    install_missing: bool,
     yes: bool,
+    profile: str | None,
+    dry_run: bool,
+    patch_file: str | None,
+    emit_patch: str | None,
+    interactive: bool,
+    fix_only: str | None,
+    fix_safety: str | None,
+    allow_dirty: bool,
+    autostash: bool,
+    max_files: int | None,
+    confirm_over: int | None,
 ) -> None:
     """Format code using configured formatting tools.
 
@@ -121,48 +308,319 @@ def format_command(
             Paths to format (defaults to current directory if none provided).
         tools: str | None: Specific tools to run, or 'all' for all available tools.
         tool_options: str | None: Tool-specific configuration options.
+        extra_args: dict[str, list[str]]: Extra command-line arguments per
+            tool.
         exclude: str | None: Patterns to exclude from formatting.
         include_venv: bool: Whether to include virtual environment directories.
+        no_gitignore: bool: Whether to also process git-ignored files.
         output: str | None: Path to output file for results.
-        group_by: str: How to group issues in the output display.
-        output_format: str: Format for displaying results.
+        group_by: str | None: How to group issues in the output display.
+        columns: list[str] | None: Columns of the issue tables, in order.
+        sort_by: str | None: Order of the issue table rows.
+        output_format: str | None: Format for displaying results.
         verbose: bool: Enable detailed debug output.
         no_log: bool: Whether to disable logging to file.
         raw_output: bool: Show raw tool output instead of formatted output.
+        summary_only: bool: Print only the per-tool counts table.
+        quiet: bool: Print nothing unless the run fails.
         stream: bool: Whether to stream tool output in real-time.
         debug: bool: Whether to enable debug output on console.
+        log_format: str: Log format on stderr, "text" or "json".
         auto_install: bool: Whether to auto-install Node.js deps if missing.
+        install_missing: bool: Whether to install missing tools before running.
         yes: bool: Skip confirmation prompt and proceed immediately.
+        profile: str | None: Named configuration profile to apply.
+        dry_run: bool: Run the fixers on a temporary copy and print the diff.
+        patch_file: str | None: File to write the dry-run diff to.
+        emit_patch: str | None: File to write the fixes to as a patch
+            instead of applying them.
+        interactive: bool: Review the fixes hunk by hunk before applying them.
+        fix_only: str | None: Comma-separated tool:RULE items to fix alone.
+        fix_safety: str | None: Apply only ``safe`` fixes or ``all``;
+            overrides execution.fix_safety.
+        allow_dirty: bool: Fix files with uncommitted changes.
+        autostash: bool: Stash uncommitted changes around the run.
+        max_files: int | None: Abort if the tools would process more files.
+        confirm_over: int | None: Ask first if the tools would process more
+            files.
+
+    Raises:
+        click.BadParameter: If --fix-only is malformed or names a tool that
+            cannot fix selected rules.
+        click.UsageError: If --patch is given without --dry-run, --dry-run,
+            --emit-patch and --interactive are combined, --quiet is combined
+            with --summary-only, --allow-dirty with --autostash, or a path
+            lies outside the working directory in a dry run.
     """
+    if patch_file and not dry_run:
+        raise click.UsageError("--patch requires --dry-run")
+    if dry_run and interactive:
+        raise click.UsageError("--dry-run and --interactive cannot be combined")
+    if emit_patch and (dry_run or interactive):
+        raise click.UsageError(
+            "--emit-patch cannot be combined with --dry-run or --interactive",
+        )
+    if quiet and summary_only:
+        raise click.UsageError("--quiet and --summary-only cannot be combined")
+    if allow_dirty and autostash:
+        raise click.UsageError("--allow-dirty and --autostash cannot be combined")
+    rules: dict[str, list[str]] | None = None
+    if fix_only is not None:
+        try:
+            rules = parse_fix_only(fix_only)
+        except ValueError as e:
+            raise click.BadParameter(str(e), param_hint="--fix-only") from e
+
+    extra_tool_options: dict[str, dict[str, object]] = {
+        tool: {"extra_args": args} for tool, args in extra_args.items()
+    }
+    if fix_safety is not None:
+        for tool, options in fix_safety_options(fix_safety).items():
+            extra_tool_options.setdefault(tool, {}).update(options)
+
     # Default to current directory if no paths provided
     normalized_paths: list[str] = list(paths) if paths else list(DEFAULT_PATHS)
+    # These modes run the fixers on a copy and compare it with the project
+    use_workspace = dry_run or interactive or emit_patch is not None
+    if use_workspace and output:
+        # The run happens inside the copy, so anchor the output to the project
+        output = os.path.abspath(output)
 
-    # Run with simplified approach
-    exit_code: int = run_lint_tools_simple(
-        action=DEFAULT_ACTION,
-        paths=normalized_paths,
-        tools=tools,
-        tool_options=tool_options,
-        exclude=exclude,
-        include_venv=include_venv,
-        group_by=group_by,
-        output_format=output_format,
-        verbose=verbose,
-        raw_output=raw_output,
-        output_file=output,
-        debug=debug,
-        stream=stream,
-        no_log=no_log,
-        auto_install=auto_install,
-        yes=yes,
+    # Fixes written straight to the project must not mix with uncommitted
+    # edits; dry runs and interactive reviews only write reviewed changes
+    stash = (
+        None
+        if use_workspace
+        else _guard_worktree(normalized_paths, allow_dirty, autostash)
     )
 
+    changes: list[FileChange] = []
+    outcomes: list[RunOutcome] = []
+    root = os.getcwd()
+    try:
+        with DryRunWorkspace() if use_workspace else nullcontext() as workspace:
+            if workspace is not None:
+                # Change paths are relative to the copied project root
+                root = workspace.root
+                try:
+                    normalized_paths = [workspace.rebase(p) for p in normalized_paths]
+                except ValueError as e:
+                    raise click.UsageError(str(e)) from e
+
+            # Run with simplified approach
+            exit_code: int = run_lint_tools_simple(
+                action=DEFAULT_ACTION,
+                paths=normalized_paths,
+                tools=tools,
+                tool_options=tool_options,
+                extra_tool_options=extra_tool_options or None,
+                exclude=exclude,
+                include_venv=include_venv,
+                gitignore=not no_gitignore,
+                group_by=group_by,
+                columns=columns,
+                sort_by=sort_by,
+                output_format=output_format,
+                verbose=verbose,
+                raw_output=raw_output,
+                summary_only=summary_only,
+                quiet=quiet,
+                output_file=output,
+                debug=debug,
+                log_format=log_format,
+                stream=stream,
+                no_log=no_log,
+                auto_install=auto_install,
+                install_missing=install_missing,
+                yes=yes,
+                profile=profile,
+                # Fixes on the copy are not applied; reviewed ones are logged below
+                audit=not use_workspace,
+                fix_only=rules,
+                max_files=max_files,
+                confirm_over=confirm_over,
+                on_outcome=outcomes.append,
+            )
+            if workspace is not None:
+                changes = workspace.changes()
+    except BaseException:
+        if stash is not None:
+            stash.restore(failed=True)
+        raise
+    if stash is not None:
+        # Issues left unfixed are normal; only a run that broke loses its fixes
+        stash.restore(failed=not outcomes or outcomes[-1] in _BROKEN_OUTCOMES)
+
+    if dry_run:
+        _report_dry_run(changes, patch_file, root, quiet=output_format == "json")
+    elif interactive:
+        _apply_interactively(changes, exit_code, root)
+    elif emit_patch is not None:
+        _emit_patch(changes, emit_patch, root, quiet=output_format == "json")
+
     # Exit with code from tool execution
     # For fmt action, exit_code is 1 only if there were execution errors
     # (not if issues were found and fixed - that's success)
     ctx.exit(exit_code)
 
 
+def _guard_worktree(
+    paths: list[str],
+    allow_dirty: bool,
+    autostash: bool,
+) -> Autostash | None:
+    """Check the paths to fix for uncommitted changes.
+
+    Args:
+        paths: Paths about to be fixed.
+        allow_dirty: Whether --allow-dirty was given.
+        autostash: Whether --autostash was given.
+
+    Returns:
+        Autostash | None: Stashed changes to re-apply after the run, if any.
+
+    Raises:
+        click.ClickException: If the paths have uncommitted changes the
+            policy refuses to fix, or they cannot be stashed.
+    """
+    if allow_dirty:
+        policy = "allow"
+    elif autostash:
+        policy = "stash"
+    else:
+        policy = get_config().execution.dirty_worktree
+    try:
+        return guard_worktree(paths, policy)
+    except DirtyWorktreeError as e:
+        raise click.ClickException(str(e)) from e
+
+
+def _apply_hint(patch_file: str, root: str) -> str:
+    """Say how to apply a patch whose paths are relative to the project root.
+
+    Args:
+        patch_file: File the patch was written to.
+        root: Project root the patch paths are relative to.
+
+    Returns:
+        str: Command line to show.
+    """
+    if os.path.abspath(root) == os.path.abspath(os.getcwd()):
+        return f"Apply with: git apply {patch_file}"
+    return (
+        f"Apply with: git -C {os.path.relpath(root)} apply "
+        f"{os.path.abspath(patch_file)}"
+    )
+
+
+def _report_dry_run(
+    changes: list[FileChange],
+    patch_file: str | None,
+    root: str,
+    quiet: bool,
+) -> None:
+    """Print and optionally save the changes a dry run would make.
+
+    Args:
+        changes: Changes found by the dry run.
+        patch_file: File to write the patch to, if any.
+        root: Project root the change paths are relative to.
+        quiet: Whether to keep the diff off stdout (e.g. for JSON output).
+    """
+    patch = format_patch(changes)
+    if patch_file:
+        Path(patch_file).write_text(patch, encoding="utf-8")
+    if quiet:
+        return
+    if not changes:
+        click.echo("Dry run: no files would change.")
+        return
+    click.echo(patch, nl=False)
+    click.echo(f"Dry run: {len(changes)} file(s) would change.")
+    if patch_file:
+        click.echo(_apply_hint(patch_file, root))
+
+
+def _emit_patch(
+    changes: list[FileChange],
+    patch_file: str,
+    root: str,
+    quiet: bool,
+) -> None:
+    """Save the fixes as a patch and say what it contains.
+
+    The patch is written even when there is nothing to fix, so CI jobs can
+    always upload it and test for an empty file.
+
+    Args:
+        changes: Changes computed on the scratch copy.
+        patch_file: File to write the patch to.
+        root: Project root the change paths are relative to.
+        quiet: Whether to print nothing (e.g. for JSON output).
+    """
+    Path(patch_file).write_text(format_patch(changes), encoding="utf-8")
+    if quiet:
+        return
+    if not changes:
+        click.echo(f"No fixes; wrote an empty {patch_file}.")
+        return
+    click.echo(f"Wrote fixes for {len(changes)} file(s) to {patch_file}.")
+    click.echo(_apply_hint(patch_file, root))
+
+
+def _apply_interactively(
+    changes: list[FileChange],
+    exit_code: int,
+    root: str,
+) -> None:
+    """Review the proposed changes, write the accepted ones and log them.
+
+    Args:
+        changes: Changes computed on the scratch copy.
+        exit_code: Exit code of the fix run.
+        root: Project root the change paths are relative to.
+    """
+    if not changes:
+        click.echo("No fixes to review.")
+        return
+    accepted = review_changes(changes)
+    write_changes(accepted, root)
+    click.echo(f"Applied fixes to {len(accepted)} of {len(changes)} file(s).")
+
+    audit_log = get_config().execution.audit_log
+    if not audit_log:
+        return
+    # Reviewed hunks may mix several fixers' changes and manual edits
+    fixes = [
+        FileFix(
+            file=change.path,
+            tool="interactive",
+            before=_digest(change.before),
+            after=_digest(change.after),
+        )
+        for change in accepted
+    ]
+    try:
+        append_entry(
+            audit_log,
+            build_entry(tools=["interactive"], fixes=fixes, exit_code=exit_code),
+        )
+    except OSError as e:
+        click.echo(f"Warning: Failed to write audit log: {e}")
+
+
+def _digest(contents: bytes | None) -> str | None:
+    """Hash file contents for the audit log.
+
+    Args:
+        contents: File contents, or None for a missing file.
+
+    Returns:
+        str | None: SHA-256 hex digest, or None for a missing file.
+    """
+    return hashlib.sha256(contents).hexdigest() if contents is not None else None
+
+
 def format_code(
     paths: list[str] | None = None,
     tools: str | None = None,
@@ -174,6 +632,19 @@ def format_code(
     verbose: bool = False,
     auto_install: bool = False,
     yes: bool = False,
+    install_missing: bool = False,
+    dry_run: bool = False,
+    patch_file: str | None = None,
+    emit_patch: str | None = None,
+    interactive: bool = False,
+    fix_only: str | None = None,
+    fix_safety: str | None = None,
+    allow_dirty: bool = False,
+    autostash: bool = False,
+    max_files: int | None = None,
+    confirm_over: int | None = None,
+    no_gitignore: bool = False,
+    extra_args: str | list[str] | None = None,
 ) -> None:
     """Programmatic format function.
 
@@ -188,6 +659,22 @@ def format_code(
         verbose: bool: Whether to show verbose output during execution.
         auto_install: bool: Whether to auto-install Node.js deps if missing.
         yes: bool: Skip confirmation prompt and proceed immediately.
+        install_missing: bool: Whether to install missing tools before running.
+        dry_run: bool: Print the would-be changes instead of writing them.
+        patch_file: str | None: File to write the dry-run diff to.
+        emit_patch: str | None: File to write the fixes to as a patch
+            instead of applying them.
+        interactive: bool: Review the fixes hunk by hunk before applying them.
+        fix_only: str | None: Comma-separated tool:RULE items to fix alone.
+        fix_safety: str | None: Apply only ``safe`` fixes or ``all``.
+        allow_dirty: bool: Fix files with uncommitted changes.
+        autostash: bool: Stash uncommitted changes around the run.
+        max_files: int | None: Abort if the tools would process more files.
+        confirm_over: int | None: Ask first if the tools would process more
+            files.
+        no_gitignore: bool: Whether to also format git-ignored files.
+        extra_args: str | list[str] | None: Extra tool arguments, or several,
+            as ``TOOL:ARGS``.
 
     Returns:
         None: This function does not return a value.
@@ -202,10 +689,14 @@ def format_code(
         args.extend(["--tools", tools])
     if tool_options:
         args.extend(["--tool-options", tool_options])
+    for item in [extra_args] if isinstance(extra_args, str) else extra_args or []:
+        args.extend(["--extra-args", item])
     if exclude:
         args.extend(["--exclude", exclude])
     if include_venv:
         args.append("--include-venv")
+    if no_gitignore:
+        args.append("--no-gitignore")
     if group_by:
         args.extend(["--group-by", group_by])
     if output_format:
@@ -216,6 +707,28 @@ def format_code(
         args.append("--auto-install")
     if yes:
         args.append("--yes")
+    if install_missing:
+        args.append("--install-missing")
+    if dry_run:
+        args.append("--dry-run")
+    if patch_file:
+        args.extend(["--patch", patch_file])
+    if emit_patch:
+        args.extend(["--emit-patch", emit_patch])
+    if interactive:
+        args.append("--interactive")
+    if fix_only:
+        args.extend(["--fix-only", fix_only])
+    if fix_safety:
+        args.extend(["--fix-safety", fix_safety])
+    if allow_dirty:
+        args.append("--allow-dirty")
+    if autostash:
+        args.append("--autostash")
+    if max_files is not None:
+        args.extend(["--max-files", str(max_files)])
+    if confirm_over is not None:
+        args.extend(["--confirm-over", str(confirm_over)])
 
     runner = CliRunner()
     result = runner.invoke(format_command, args)
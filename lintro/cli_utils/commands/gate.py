@@ -0,0 +1,138 @@
+"""Gate command for running a named quality gate.
+
+A gate (``gates.<name>`` in the Lintro config) bundles a tool subset with its
+own failure thresholds and output, so separate CI jobs such as "security" and
+"style" can share one config file.
+"""
+
+from __future__ import annotations
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config.config_loader import get_config, set_active_profile
+from lintro.config.gate_config import GateConfig
+from lintro.utils.tool_executor import run_lint_tools_simple
+
+DEFAULT_PATHS: list[str] = ["."]
+
+
+def _print_gates(gates: dict[str, GateConfig]) -> None:
+    """Print the configured gates as a table.
+
+    Args:
+        gates: Gates keyed by name.
+    """
+    console = Console()
+    if not gates:
+        console.print("[yellow]No gates are defined under 'gates:'.[/yellow]")
+        return
+    table = Table(title="Quality Gates")
+    table.add_column("Gate", style="cyan")
+    table.add_column("Tools")
+    table.add_column("Fails on")
+    table.add_column("Max issues", justify="right")
+    table.add_column("Description", style="dim")
+    for name, gate in sorted(gates.items()):
+        table.add_row(
+            name,
+            ", ".join(gate.tools),
+            gate.fail_on or "",
+            "" if gate.max_issues is None else str(gate.max_issues),
+            gate.description or "",
+        )
+    console.print(table)
+
+
+@click.command("gate")
+@click.argument("name", required=False)
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--list",
+    "list_gates",
+    is_flag=True,
+    help="List the configured gates and exit.",
+)
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Named configuration profile to apply (e.g. ci, local, strict)",
+)
+@click.option(
+    "--verbose",
+    "-v",
+    is_flag=True,
+    help="Show verbose output",
+)
+@click.option(
+    "--yes",
+    "-y",
+    is_flag=True,
+    help="Skip confirmation prompt and proceed immediately",
+)
+def gate_command(
+    name: str | None,
+    paths: tuple[str, ...],
+    list_gates: bool,
+    profile: str | None,
+    verbose: bool,
+    yes: bool,
+) -> None:
+    """Run a named quality gate from the config.
+
+    Each gate runs its own tools with its own fail_on, max_issues and output
+    settings. Paths given on the command line replace the gate's paths.
+
+    Args:
+        name: Gate name, or None to list the gates.
+        paths: Paths to check (default: the gate's paths, else ".").
+        list_gates: If True, list the configured gates and exit.
+        profile: Named configuration profile to apply.
+        verbose: Whether to show verbose output during execution.
+        yes: Skip confirmation prompt and proceed immediately.
+
+    Raises:
+        click.ClickException: If the config is invalid or the gate is unknown.
+        SystemExit: Process exit with the gate's exit code.
+
+    Examples:
+        lintro gate security
+        lintro gate style src tests
+        lintro gate --list
+    """
+    if profile is not None:
+        set_active_profile(profile)
+    try:
+        gates = get_config().gates
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+
+    if list_gates or name is None:
+        _print_gates(gates)
+        return
+
+    if name not in gates:
+        available = ", ".join(sorted(gates)) or "none defined"
+        raise click.ClickException(f"Unknown gate '{name}' (available: {available})")
+    gate = gates[name]
+
+    exit_code: int = run_lint_tools_simple(
+        action="check",
+        paths=list(paths) or gate.paths or list(DEFAULT_PATHS),
+        tools=",".join(gate.tools),
+        tool_options=None,
+        exclude=",".join(gate.exclude) or None,
+        include_venv=False,
+        group_by=None,
+        output_format=gate.output_format,
+        verbose=verbose,
+        output_file=gate.output,
+        yes=yes,
+        profile=profile,
+        fail_on=gate.fail_on,
+        max_issues=gate.max_issues,
+        summary=gate.summary,
+    )
+    raise SystemExit(exit_code)
@@ -0,0 +1,84 @@
+"""Generate command for writing integration files from the lintro config."""
+
+from pathlib import Path
+
+import click
+from rich.console import Console
+
+from lintro.config import get_config
+from lintro.plugins.registry import ToolRegistry
+from lintro.utils.git_hooks import render_pre_commit_config
+
+
+@click.group("generate")
+def generate_command() -> None:
+    """Generate integration files from the current lintro configuration."""
+
+
+@generate_command.command("pre-commit-config")
+@click.option(
+    "--output",
+    "-o",
+    type=click.Path(dir_okay=False),
+    default=None,
+    help="Write to this file instead of printing (e.g. .pre-commit-config.yaml).",
+)
+@click.option(
+    "--command",
+    "lintro_command",
+    default="lintro",
+    show_default=True,
+    help="Command the hooks run lintro with, e.g. 'uv run lintro'.",
+)
+@click.option(
+    "--force",
+    "-f",
+    is_flag=True,
+    help="Overwrite the output file if it exists.",
+)
+def pre_commit_config_command(
+    output: str | None,
+    lintro_command: str,
+    force: bool,
+) -> None:
+    """Emit a .pre-commit-config.yaml entry mirroring the lintro config.
+
+    Each tool the configuration enables gets its own ``repo: local`` hook
+    running ``lintro check --tools TOOL`` on the staged files that match
+    the tool's file patterns.
+
+    Args:
+        output: File to write, or None to print the YAML.
+        lintro_command: Command that runs lintro.
+        force: Overwrite an existing output file.
+
+    Raises:
+        SystemExit: If the output file exists without --force, or cannot
+            be written.
+
+    Examples:
+        lintro generate pre-commit-config
+        lintro generate pre-commit-config -o .pre-commit-config.yaml
+    """
+    content = render_pre_commit_config(
+        get_config(),
+        ToolRegistry.get_definitions(),
+        command=lintro_command,
+    )
+    if output is None:
+        click.echo(content, nl=False)
+        return
+
+    console = Console()
+    path = Path(output)
+    if path.exists() and not force:
+        console.print(
+            f"[red]Error: {path} already exists. Use --force to overwrite.[/red]",
+        )
+        raise SystemExit(1)
+    try:
+        path.write_text(content, encoding="utf-8")
+    except OSError as e:
+        console.print(f"[red]Error: Failed to write {path}: {e}[/red]")
+        raise SystemExit(1) from e
+    console.print(f"[green]✅ Created {path}[/green]")
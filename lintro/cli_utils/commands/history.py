@@ -0,0 +1,198 @@
+"""History command for issue count trends across check runs."""
+
+import datetime
+import json
+from dataclasses import asdict
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config import get_config
+from lintro.utils.execution.run_history import (
+    GROUP_BY_FIELDS,
+    HistoryRun,
+    Regression,
+    find_regressions,
+    load_runs,
+)
+from lintro.utils.timestamps import parse_timestamp, render_timestamp
+
+
+@click.command()
+@click.option(
+    "--db",
+    "db_path",
+    type=click.Path(dir_okay=False),
+    default=None,
+    help="History database to read (default: execution.history).",
+)
+@click.option(
+    "--by",
+    "group_by",
+    type=click.Choice(GROUP_BY_FIELDS),
+    default="tool",
+    show_default=True,
+    help="Count issues per tool, per rule or per severity.",
+)
+@click.option(
+    "--limit",
+    type=click.IntRange(min=1),
+    default=10,
+    show_default=True,
+    help="Show the most recent N runs.",
+)
+@click.option(
+    "--since",
+    type=click.DateTime(formats=["%Y-%m-%d", "%Y-%m-%dT%H:%M:%S"]),
+    default=None,
+    help="Only runs that finished at or after this UTC date or time.",
+)
+@click.option(
+    "--fail-on-regression",
+    is_flag=True,
+    help="Exit 1 when the latest run has more issues than the one before.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output the runs and regressions as JSON.",
+)
+def history_command(
+    db_path: str | None,
+    group_by: str,
+    limit: int,
+    since: datetime.datetime | None,
+    *,
+    fail_on_regression: bool,
+    json_output: bool,
+) -> None:
+    """Show how issue counts moved over recorded check runs.
+
+    Runs are recorded when execution.history names a database file.
+
+    Args:
+        db_path: History database, or None for the configured one.
+        group_by: Count issues per tool, rule or severity.
+        limit: Maximum number of runs, most recent last.
+        since: Earliest finish time to include.
+        fail_on_regression: Exit 1 when the latest run regressed.
+        json_output: Output the runs and regressions as JSON.
+
+    Raises:
+        SystemExit: If the history cannot be read or, with
+            --fail-on-regression, the latest run regressed.
+
+    Examples:
+        lintro history
+        lintro history --by rule --limit 5
+        lintro history --since 2026-01-01 --fail-on-regression
+    """
+    console = Console()
+    try:
+        config = get_config()
+        path = db_path or config.execution.history
+        if not path:
+            console.print("[yellow]Run history is disabled (execution.history).")
+            raise SystemExit(1)
+        runs = load_runs(path, since=since, limit=limit)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
+    regressions = find_regressions(runs, group_by=group_by)
+
+    if json_output:
+        click.echo(
+            json.dumps(
+                {
+                    "runs": [asdict(run) for run in runs],
+                    "regressions": [asdict(r) for r in regressions],
+                },
+                indent=2,
+            ),
+        )
+    elif not runs:
+        console.print("No check runs recorded.")
+    else:
+        local_time = config.output.local_time
+        console.print(_trend_table(runs, group_by, local_time=local_time))
+        _print_regressions(console, regressions)
+
+    if fail_on_regression and regressions:
+        raise SystemExit(1)
+
+
+def _trend_table(
+    runs: list[HistoryRun],
+    group_by: str,
+    local_time: bool = False,
+) -> Table:
+    """Render issue counts as a table, one row per run.
+
+    Args:
+        runs: Runs to show, oldest first.
+        group_by: Count issues per tool, rule or severity.
+        local_time: Show finish times in the local timezone instead of UTC.
+
+    Returns:
+        Table: Rich table with a column per tool, rule or severity.
+    """
+    totals = [run.totals(group_by) for run in runs]
+    keys = sorted({key for counts in totals for key in counts})
+    table = Table(title=f"Issues per {group_by}")
+    table.add_column("Finished", no_wrap=True)
+    table.add_column("Commit", no_wrap=True)
+    for key in keys:
+        table.add_column(key, justify="right")
+    table.add_column("Total", justify="right")
+    table.add_column("Change", justify="right")
+    previous: int | None = None
+    for run, counts in zip(runs, totals, strict=True):
+        total = sum(counts.values())
+        table.add_row(
+            render_timestamp(parse_timestamp(run.timestamp), local=local_time),
+            (run.commit or "-")[:10],
+            *(str(counts[key]) if key in counts else "-" for key in keys),
+            str(total),
+            _change(previous, total),
+        )
+        previous = total
+    return table
+
+
+def _change(previous: int | None, current: int) -> str:
+    """Describe how a count changed since the previous run.
+
+    Args:
+        previous: Count of the previous run, or None for the first run.
+        current: Count of this run.
+
+    Returns:
+        str: Rich markup, red for more issues and green for fewer.
+    """
+    if previous is None:
+        return "-"
+    delta = current - previous
+    if delta > 0:
+        return f"[red]+{delta}[/red]"
+    if delta < 0:
+        return f"[green]{delta}[/green]"
+    return "0"
+
+
+def _print_regressions(console: Console, regressions: list[Regression]) -> None:
+    """Report what has more issues than in the previous run.
+
+    Args:
+        console: Console to print to.
+        regressions: Regressions of the latest run.
+    """
+    if not regressions:
+        console.print("[green]✅ No regressions since the previous run[/green]")
+        return
+    console.print("[red]❌ Regressions since the previous run:[/red]")
+    for regression in regressions:
+        console.print(
+            f"  {regression.key}: {regression.previous} → {regression.current}",
+        )
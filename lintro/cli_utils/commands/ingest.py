@@ -0,0 +1,146 @@
+"""Ingest command for reporting SARIF results produced outside lintro."""
+
+import click
+
+from lintro.cli_utils.commands.check import parse_output_option
+from lintro.utils.output.sinks import OutputSink, console_format
+from lintro.utils.sarif_ingest import load_sarif_results
+from lintro.utils.tool_executor import run_lint_tools_simple
+
+
+@click.command("ingest")
+@click.argument(
+    "reports",
+    nargs=-1,
+    required=True,
+    type=click.Path(exists=True, dir_okay=False),
+)
+@click.option(
+    "--tool",
+    "tool_name",
+    default=None,
+    help="Report every result under this tool name instead of the SARIF driver's",
+)
+@click.option(
+    "--output",
+    multiple=True,
+    callback=parse_output_option,
+    help=(
+        "Where to send results; repeat for several sinks: console, "
+        "console=FORMAT, FORMAT=PATH (e.g. json=report.json) or a file "
+        "path written in --output-format"
+    ),
+)
+@click.option(
+    "--output-format",
+    type=click.Choice(["plain", "grid", "markdown", "html", "json", "csv", "github"]),
+    default=None,
+    help="Output format for displaying results [default: grid]",
+)
+@click.option(
+    "--group-by",
+    type=click.Choice(
+        [
+            "file",
+            "code",
+            "none",
+            "auto",
+            "rule",
+            "severity",
+            "dir",
+            "tool",
+            "owner",
+        ],
+    ),
+    default=None,
+    help="How to group issues in the output [default: file]",
+)
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Named configuration profile to apply (e.g. ci, local, strict)",
+)
+@click.option(
+    "--fail-on",
+    type=click.Choice(["error", "warning", "info", "never"]),
+    default=None,
+    help="Lowest issue severity that fails the run [default: info]",
+)
+@click.option(
+    "--max-issues",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Number of failing issues tolerated before the run fails [default: 0]",
+)
+def ingest_command(
+    reports: tuple[str, ...],
+    tool_name: str | None,
+    output: tuple[OutputSink, ...],
+    output_format: str | None,
+    group_by: str | None,
+    profile: str | None,
+    fail_on: str | None,
+    max_issues: int | None,
+) -> None:
+    """Report the results of SARIF logs written by other scanners.
+
+    Each tool of the logs, such as CodeQL, is reported like a tool of
+    ``lintro check``: severity overrides, per-path overrides, suppressions
+    and ``output.min_severity`` apply, the exit code follows --fail-on and
+    --max-issues, and --output writes the same report files, which
+    ``lintro diff`` and ``lintro report`` accept.
+
+    Args:
+        reports: SARIF files to import.
+        tool_name: Name for every imported result, or None to name them
+            after the SARIF drivers.
+        output: Console and files the results are sent to.
+        output_format: Format for displaying results.
+        group_by: How to group issues in the output.
+        profile: Named configuration profile to apply.
+        fail_on: Lowest issue severity that fails the run.
+        max_issues: Failing issues tolerated before the run fails.
+
+    Raises:
+        click.ClickException: If a file is not a readable SARIF log.
+        click.UsageError: If the console formats given conflict.
+        SystemExit: Process exit with the run's exit code.
+
+    Examples:
+        lintro ingest codeql.sarif
+        lintro ingest vendor.sarif --tool vendor --output json=vendor.json
+    """
+    try:
+        results = load_sarif_results(reports, tool_name)
+    except (OSError, ValueError) as e:
+        raise click.ClickException(str(e)) from e
+    try:
+        chosen_format = console_format(output)
+    except ValueError as e:
+        raise click.UsageError(str(e)) from e
+    if chosen_format:
+        if output_format and output_format != chosen_format:
+            raise click.UsageError(
+                f"--output console={chosen_format} conflicts with "
+                f"--output-format {output_format}",
+            )
+        output_format = chosen_format
+
+    exit_code: int = run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools=None,
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by=group_by,
+        output_format=output_format,
+        verbose=False,
+        outputs=[sink for sink in output if not sink.is_console],
+        profile=profile,
+        fail_on=fail_on,
+        max_issues=max_issues,
+        ingested=results,
+    )
+    raise SystemExit(exit_code)
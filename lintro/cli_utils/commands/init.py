@@ -12,6 +12,13 @@ import click
 from loguru import logger
 from rich.console import Console
 from rich.panel import Panel
+from rich.table import Table
+
+from lintro.utils.project_detection import (
+    ProjectDetection,
+    detect_project,
+    render_detected_config,
+)
 
 # Default Lintro config template (project-recommended defaults)
 DEFAULT_CONFIG_TEMPLATE = """\
@@ -179,6 +186,45 @@ def _generate_native_configs(
     return created
 
 
+def _print_detection(detection: ProjectDetection, console: Console) -> None:
+    """Print the tools detected for the project.
+
+    Args:
+        detection: Result of project detection.
+        console: Rich console for output.
+    """
+    if not detection.tools:
+        console.print("[yellow]No supported project files detected.[/yellow]")
+        return
+
+    table = Table(title="Detected Tools")
+    table.add_column("Tool", style="cyan", no_wrap=True)
+    table.add_column("Ecosystem")
+    table.add_column("Detected From", style="dim")
+    table.add_column("Installed", justify="center")
+    for tool in detection.tools:
+        installed = "[green]✓[/green]" if tool.installed else "[red]✗[/red]"
+        table.add_row(tool.name, tool.ecosystem, tool.marker, installed)
+    console.print(table)
+
+
+def _prompt_enabled_tools(detection: ProjectDetection) -> list[str]:
+    """Ask which detected tools to enable.
+
+    Args:
+        detection: Result of project detection.
+
+    Returns:
+        list[str]: Names of the tools to enable.
+    """
+    enabled: list[str] = []
+    for tool in detection.tools:
+        suffix = "" if tool.installed else " (not installed)"
+        if click.confirm(f"Enable {tool.name}{suffix}?", default=tool.installed):
+            enabled.append(tool.name)
+    return enabled
+
+
 @click.command("init")
 @click.option(
     "--minimal",
@@ -204,11 +250,24 @@ def _generate_native_configs(
     is_flag=True,
     help="Also generate native tool configs (.markdownlint-cli2.jsonc, etc.).",
 )
+@click.option(
+    "--detect",
+    is_flag=True,
+    help="Inspect the repository and enable the applicable, installed tools.",
+)
+@click.option(
+    "--interactive",
+    "-i",
+    is_flag=True,
+    help="Like --detect, but ask which detected tools to enable.",
+)
 def init_command(
     minimal: bool,
     force: bool,
     output: str,
     with_native_configs: bool,
+    detect: bool,
+    interactive: bool,
 ) -> None:
     """Initialize Lintro configuration for your project.
 
@@ -219,11 +278,17 @@ def init_command(
     Use --with-native-configs to also generate native tool configuration
     files for IDE integration (e.g., markdownlint extension).
 
+    Use --detect to inspect the repository (pyproject.toml, Cargo.toml,
+    package.json, Dockerfiles, workflows, ...) and enable only the tools
+    that apply and are installed. --interactive asks about each tool.
+
     Args:
         minimal: Use minimal template with fewer comments.
         force: Overwrite existing config file if it exists.
         output: Output file path for the config file.
         with_native_configs: Also generate native tool config files.
+        detect: Generate the config from the detected project tools.
+        interactive: Prompt for each detected tool (implies detect).
 
     Raises:
         SystemExit: If file exists and --force not provided, or write fails.
@@ -241,7 +306,13 @@ def init_command(
         raise SystemExit(1)
 
     # Select template
-    template = MINIMAL_CONFIG_TEMPLATE if minimal else DEFAULT_CONFIG_TEMPLATE
+    if detect or interactive:
+        detection = detect_project(Path("."))
+        _print_detection(detection=detection, console=console)
+        enabled = _prompt_enabled_tools(detection) if interactive else None
+        template = render_detected_config(detection, enabled=enabled)
+    else:
+        template = MINIMAL_CONFIG_TEMPLATE if minimal else DEFAULT_CONFIG_TEMPLATE
 
     # Write main config file
     try:
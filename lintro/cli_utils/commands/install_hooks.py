@@ -0,0 +1,59 @@
+"""Install-hooks command for running lintro from git hooks."""
+
+import click
+from rich.console import Console
+
+from lintro.utils.git_hooks import HookInstallError, install_hook
+
+
+@click.command("install-hooks")
+@click.option(
+    "--pre-push",
+    is_flag=True,
+    help="Also install a pre-push hook that checks the whole project.",
+)
+@click.option(
+    "--command",
+    "lintro_command",
+    default="lintro",
+    show_default=True,
+    help="Command the hooks run lintro with, e.g. 'uv run lintro'.",
+)
+@click.option(
+    "--force",
+    "-f",
+    is_flag=True,
+    help="Replace existing hooks that lintro did not install.",
+)
+def install_hooks_command(
+    pre_push: bool,
+    lintro_command: str,
+    force: bool,
+) -> None:
+    """Install git hooks that run lintro.
+
+    The pre-commit hook runs ``lintro check --staged``, so only the files
+    being committed are checked. Running the command again updates hooks
+    lintro installed earlier.
+
+    Args:
+        pre_push: Also install the pre-push hook.
+        lintro_command: Command that runs lintro.
+        force: Replace hooks lintro did not install.
+
+    Raises:
+        SystemExit: If a hook cannot be installed.
+
+    Examples:
+        lintro install-hooks
+        lintro install-hooks --pre-push --command "uv run lintro"
+    """
+    console = Console()
+    hooks = ["pre-commit", "pre-push"] if pre_push else ["pre-commit"]
+    for hook in hooks:
+        try:
+            path = install_hook(hook, command=lintro_command, force=force)
+        except (OSError, HookInstallError) as e:
+            console.print(f"[red]Error: {e}[/red]")
+            raise SystemExit(1) from e
+        console.print(f"[green]✅ Installed {hook} hook: {path}[/green]")
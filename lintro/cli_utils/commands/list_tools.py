@@ -3,7 +3,9 @@
 This module provides the core logic for the 'list_tools' command.
 """
 
+import inspect
 import json as json_lib
+import shutil
 
 import click
 from rich.console import Console
@@ -40,6 +42,47 @@ def _resolve_conflicts(
     return conflict_names
 
 
+def _tool_options(plugin: BaseToolPlugin) -> list[str]:
+    """List the tool-specific options a plugin accepts.
+
+    These are the named parameters of the plugin's ``set_options``, which
+    ``--tool-options`` and the ``tools`` section of the config pass through.
+
+    Args:
+        plugin: The plugin instance.
+
+    Returns:
+        List of option names, in the order ``set_options`` declares them.
+    """
+    return [
+        name
+        for name, parameter in inspect.signature(plugin.set_options).parameters.items()
+        if parameter.kind
+        in (inspect.Parameter.POSITIONAL_OR_KEYWORD, inspect.Parameter.KEYWORD_ONLY)
+    ]
+
+
+def _required_binary(plugin: BaseToolPlugin) -> dict[str, object] | None:
+    """Describe the executable a tool needs.
+
+    Args:
+        plugin: The plugin instance.
+
+    Returns:
+        The binary's name, its path on PATH (None if not found) and the
+        minimum version, or None for tools implemented in Lintro itself.
+    """
+    definition = plugin.definition
+    if definition.native or not definition.version_command:
+        return None
+    name = definition.version_command[0]
+    return {
+        "name": name,
+        "path": shutil.which(name),
+        "min_version": definition.min_version,
+    }
+
+
 @click.command("list-tools")
 @click.option(
     "--output",
@@ -61,7 +104,10 @@ def _resolve_conflicts(
     "--verbose",
     "-v",
     is_flag=True,
-    help="Show verbose output including file extensions and patterns",
+    help=(
+        "Show verbose output including file extensions and patterns; "
+        "with --json, also detect installed versions"
+    ),
 )
 def list_tools_command(
     output: str | None,
@@ -75,7 +121,8 @@ def list_tools_command(
         output: Path to output file for writing results.
         show_conflicts: Whether to show potential conflicts between tools.
         json_output: Output tool list as JSON.
-        verbose: Show verbose output including file extensions and patterns.
+        verbose: Show verbose output including file extensions and patterns,
+            and detect installed versions for JSON output.
     """
     list_tools(
         output=output,
@@ -97,7 +144,8 @@ def list_tools(
         output: Output file path.
         show_conflicts: Whether to show potential conflicts between tools.
         json_output: Output tool list as JSON.
-        verbose: Show verbose output including file extensions and patterns.
+        verbose: Show verbose output including file extensions and patterns,
+            and detect installed versions for JSON output.
     """
     available_tools = tool_manager.get_all_tools()
     check_tools = tool_manager.get_check_tools()
@@ -116,14 +164,17 @@ def list_tools(
             tool_info: dict[str, object] = {
                 "description": plugin.definition.description,
                 "capabilities": capabilities,
+                # Version detection runs every tool, so it is verbose-only
+                "features": plugin.capabilities(detect_version=verbose).to_dict(),
                 "priority": get_tool_priority(tool_name),
                 "syncable": is_tool_injectable(tool_name),
+                "file_patterns": plugin.definition.file_patterns,
+                "shebang_interpreters": plugin.definition.shebang_interpreters,
+                "options": _tool_options(plugin),
+                "native_configs": plugin.definition.native_configs,
+                "binary": _required_binary(plugin),
             }
 
-            # Only include file_patterns in verbose mode (consistent with table output)
-            if verbose:
-                tool_info["file_patterns"] = plugin.definition.file_patterns
-
             if show_conflicts:
                 conflict_names = _resolve_conflicts(
                     plugin=plugin,
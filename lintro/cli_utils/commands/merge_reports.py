@@ -0,0 +1,65 @@
+"""Merge-reports command for combining the reports of a sharded run."""
+
+import json
+from pathlib import Path
+
+import click
+from rich.console import Console
+
+from lintro.utils.output.report_merge import merge_reports
+
+
+@click.command("merge-reports")
+@click.argument(
+    "reports",
+    nargs=-1,
+    required=True,
+    type=click.Path(exists=True, dir_okay=False),
+)
+@click.option(
+    "--output",
+    "-o",
+    type=click.Path(dir_okay=False),
+    default=None,
+    help="File to write the merged report to (default: standard output).",
+)
+def merge_reports_command(reports: tuple[str, ...], output: str | None) -> None:
+    """Combine the JSON or SARIF reports of ``lintro check --shard`` jobs.
+
+    Results are grouped by tool and the summary is recounted, so the merged
+    report reads like the report of one unsharded run. JSON reports must
+    come from distinct shards of the same run; missing shards are reported
+    as a warning.
+
+    Args:
+        reports: Report of each shard, all JSON or all SARIF.
+        output: File to write the merged report to, or None for stdout.
+
+    Raises:
+        click.ClickException: If a report cannot be read or the reports do
+            not fit together.
+
+    Examples:
+        lintro check --shard 1/2 --output json=lint-1.json
+        lintro check --shard 2/2 --output json=lint-2.json
+        lintro merge-reports lint-1.json lint-2.json --output lint.json
+    """
+    try:
+        merged = merge_reports(reports)
+    except (OSError, ValueError) as e:
+        raise click.ClickException(str(e)) from e
+
+    console = Console(stderr=True)
+    if merged.missing_shards:
+        console.print(
+            "[yellow]Warning: no report for shard "
+            f"{', '.join(merged.missing_shards)}[/yellow]",
+        )
+    text = json.dumps(merged.data, indent=2, ensure_ascii=False)
+    if output is None:
+        click.echo(text)
+        return
+    path = Path(output)
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(text, encoding="utf-8")
+    console.print(f"[green]✅ Merged {len(reports)} reports into {path}[/green]")
@@ -0,0 +1,173 @@
+"""Org command for auditing many repositories with one gate."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config.config_loader import get_config, set_active_profile
+from lintro.utils.org_runner import (
+    DEFAULT_JOBS,
+    DEFAULT_REPO_TIMEOUT,
+    STATUS_ERROR,
+    STATUS_FAILED,
+    STATUS_PASSED,
+    RepoOutcome,
+    load_org_config,
+    merge_reports,
+    run_org,
+)
+
+_STATUS_STYLES: dict[str, str] = {
+    STATUS_PASSED: "green",
+    STATUS_FAILED: "red",
+    STATUS_ERROR: "yellow",
+}
+
+
+@click.group()
+def org_command() -> None:
+    """Audit many repositories at once."""
+
+
+@org_command.command("run")
+@click.option(
+    "--repos",
+    "repos_file",
+    required=True,
+    type=click.Path(exists=True, dir_okay=False),
+    help="YAML file listing the repositories to audit.",
+)
+@click.option(
+    "--gate",
+    type=str,
+    help="Gate to run (default: the 'gate' of the repository list).",
+)
+@click.option(
+    "--output",
+    type=click.Path(dir_okay=False, path_type=Path),
+    help="Write the merged JSON report to this file.",
+)
+@click.option(
+    "--jobs",
+    type=click.IntRange(min=1),
+    default=DEFAULT_JOBS,
+    show_default=True,
+    help="Repositories processed at the same time.",
+)
+@click.option(
+    "--timeout",
+    type=click.IntRange(min=1),
+    default=DEFAULT_REPO_TIMEOUT,
+    show_default=True,
+    help="Seconds allowed for each repository's check.",
+)
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Named configuration profile to apply (e.g. ci, local, strict)",
+)
+def org_run_command(
+    repos_file: str,
+    gate: str | None,
+    output: Path | None,
+    jobs: int,
+    timeout: int,
+    profile: str | None,
+) -> None:
+    """Run a quality gate in every listed repository and merge the results.
+
+    Each repository is cloned shallowly (or updated) under the list's work
+    directory, checked with a gate from the current config, and reported
+    under its own name.
+
+    Args:
+        repos_file: Repository list.
+        gate: Gate overriding the list's gate.
+        output: File for the merged JSON report, if any.
+        jobs: Repositories processed at the same time.
+        timeout: Seconds allowed for each repository's check.
+        profile: Named configuration profile to apply.
+
+    Raises:
+        click.ClickException: If the list, the config or a gate is invalid,
+            or the report cannot be written.
+        SystemExit: With 1 if any repository failed or could not be checked.
+
+    Examples:
+        lintro org run --repos repos.yaml
+        lintro org run --repos repos.yaml --gate security --output org.json
+    """
+    if profile is not None:
+        set_active_profile(profile)
+    try:
+        config = load_org_config(repos_file)
+        gates = get_config().gates
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+
+    console = Console()
+
+    def done(outcome: RepoOutcome) -> None:
+        """Print a line as each repository finishes.
+
+        Args:
+            outcome: Outcome of the repository.
+        """
+        style = _STATUS_STYLES[outcome.status]
+        console.print(
+            f"[{style}]{outcome.status:>6}[/{style}] {outcome.name}"
+            + (f" [dim]{outcome.error}[/dim]" if outcome.error else ""),
+        )
+
+    try:
+        outcomes = run_org(
+            config,
+            gates,
+            gate=gate,
+            jobs=jobs,
+            timeout=timeout,
+            on_done=done,
+        )
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+
+    table = Table(title="Organization Audit")
+    table.add_column("Repository", style="cyan")
+    table.add_column("Gate")
+    table.add_column("Commit", style="dim")
+    table.add_column("Status")
+    table.add_column("Issues", justify="right")
+    for outcome in outcomes:
+        style = _STATUS_STYLES[outcome.status]
+        table.add_row(
+            outcome.name,
+            outcome.gate,
+            (outcome.commit or "")[:8],
+            f"[{style}]{outcome.status}[/{style}]",
+            "" if outcome.status == STATUS_ERROR else str(outcome.issues_count),
+        )
+    console.print(table)
+
+    report = merge_reports(outcomes)
+    summary = report["summary"]
+    console.print(
+        f"{summary['passed']} passed, {summary['failed']} failed, "
+        f"{summary['error']} error(s); {summary['total_issues']} issue(s) "
+        f"in {summary['repos']} repositories",
+    )
+    if output:
+        try:
+            output.parent.mkdir(parents=True, exist_ok=True)
+            output.write_text(json.dumps(report, indent=2) + "\n", encoding="utf-8")
+        except OSError as e:
+            raise click.ClickException(f"Cannot write {output}: {e}") from e
+        console.print(f"[dim]Wrote {output}[/dim]")
+
+    if any(outcome.status != STATUS_PASSED for outcome in outcomes):
+        raise SystemExit(1)
@@ -0,0 +1,145 @@
+"""Outdated command for listing dependency update candidates.
+
+Aggregates cargo, Python (uv) and npm outdated reports. The report is purely
+informational: the command exits 0 unless ``--fail-on`` is given.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.utils.outdated import (
+    DEFAULT_OUTDATED_TIMEOUT,
+    ECOSYSTEMS,
+    UPDATE_TYPES,
+    collect_outdated,
+    exceeds_threshold,
+    report_to_dict,
+)
+
+_UPDATE_STYLES: dict[str, str] = {
+    "major": "red",
+    "minor": "yellow",
+    "patch": "green",
+    "unknown": "dim",
+}
+
+
+@click.command()
+@click.argument(
+    "path",
+    type=click.Path(exists=True, file_okay=False, path_type=Path),
+    default=".",
+)
+@click.option(
+    "--ecosystem",
+    "ecosystems",
+    type=click.Choice(list(ECOSYSTEMS)),
+    multiple=True,
+    help="Ecosystem to check (repeatable, default: all detected).",
+)
+@click.option(
+    "--fail-on",
+    type=click.Choice(["none", *reversed(UPDATE_TYPES[1:])]),
+    default="none",
+    show_default=True,
+    help="Exit 1 if an update of this type or larger is available.",
+)
+@click.option(
+    "--timeout",
+    type=int,
+    default=DEFAULT_OUTDATED_TIMEOUT,
+    show_default=True,
+    help="Timeout in seconds for each package manager.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output as JSON.",
+)
+def outdated_command(
+    path: Path,
+    ecosystems: tuple[str, ...],
+    fail_on: str,
+    timeout: int,
+    *,
+    json_output: bool,
+) -> None:
+    """List dependency updates available from cargo, uv and npm.
+
+    Update candidates are classified as major, minor or patch. Nothing is
+    modified, and the command exits 0 unless --fail-on is given.
+
+    Args:
+        path: Project directory containing the manifests.
+        ecosystems: Ecosystems to check, or empty for all.
+        fail_on: Smallest update type that causes a non-zero exit.
+        timeout: Timeout in seconds for each package manager.
+        json_output: If True, output results as JSON.
+
+    Raises:
+        SystemExit: If an update meets the --fail-on threshold.
+
+    Examples:
+        lintro outdated
+        lintro outdated --ecosystem npm --ecosystem python
+        lintro outdated --fail-on major
+        lintro outdated --json
+    """
+    reports = collect_outdated(path, ecosystems or None, timeout=timeout)
+    packages = [p for r in reports for p in r.packages]
+
+    if json_output:
+        click.echo(json.dumps(report_to_dict(reports), indent=2))
+    else:
+        console = Console()
+        if packages:
+            table = Table(title="Dependency Updates")
+            table.add_column("Ecosystem", style="cyan", no_wrap=True)
+            table.add_column("Package", style="bold")
+            table.add_column("Current", style="yellow")
+            table.add_column("Latest", style="green")
+            table.add_column("Update", justify="center")
+
+            ranked = sorted(
+                packages,
+                key=lambda p: (-UPDATE_TYPES.index(p.update_type), p.ecosystem, p.name),
+            )
+            for package in ranked:
+                style = _UPDATE_STYLES[package.update_type]
+                table.add_row(
+                    package.ecosystem,
+                    package.name,
+                    package.current,
+                    package.latest,
+                    f"[{style}]{package.update_type}[/{style}]",
+                )
+            console.print(table)
+
+        for report in reports:
+            if report.error:
+                console.print(f"[red]✗ {report.ecosystem}: {report.error}[/red]")
+            elif report.skipped:
+                console.print(f"[dim]- {report.ecosystem}: {report.skipped}[/dim]")
+
+        summary = report_to_dict(reports)["summary"]
+        if packages:
+            console.print(
+                f"\n{summary['total']} update(s) available: "
+                f"[red]{summary['major']} major[/red], "
+                f"[yellow]{summary['minor']} minor[/yellow], "
+                f"[green]{summary['patch']} patch[/green]",
+            )
+        elif any(r.skipped is None and r.error is None for r in reports):
+            console.print("\n[green]✅ All dependencies are up to date.[/green]")
+        else:
+            console.print("\n[yellow]No package manager could be queried.[/yellow]")
+
+    if exceeds_threshold(packages, fail_on):
+        raise SystemExit(1)
@@ -0,0 +1,194 @@
+"""Plan command for prioritizing lint cleanup work.
+
+Runs the configured checks, estimates the effort of every issue and lists
+what to fix first within a time budget. Nothing is modified.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config.config_loader import get_config
+from lintro.utils.cleanup_plan import (
+    CleanupPlan,
+    build_cleanup_plan,
+    load_codeowners,
+    parse_budget,
+    write_owner_task_lists,
+)
+from lintro.utils.effort import format_effort
+from lintro.utils.execution.check_runner import run_checks
+
+_SEVERITY_STYLES: dict[str, str] = {
+    "ERROR": "red",
+    "WARNING": "yellow",
+    "INFO": "dim",
+}
+
+
+def _plan_to_dict(plan: CleanupPlan) -> dict[str, Any]:
+    """Convert a plan to JSON-serializable data.
+
+    Args:
+        plan: Cleanup plan.
+
+    Returns:
+        dict[str, Any]: Plan summary and tasks.
+    """
+    return {
+        "budget_minutes": plan.budget_minutes,
+        "planned_minutes": plan.planned_minutes,
+        "total_minutes": plan.total_minutes,
+        "deferred": len(plan.deferred),
+        "tasks": [
+            {
+                "file": item.file,
+                "tool": item.tool,
+                "code": item.code,
+                "severity": item.severity.value,
+                "fixable": item.fixable,
+                "issues": item.issues,
+                "minutes": item.minutes,
+                "owners": item.owners,
+            }
+            for item in plan.items
+        ],
+    }
+
+
+@click.command()
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated list of tools to run (default: all enabled).",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--budget",
+    type=str,
+    help="Effort budget such as 4h, 90m, 1h30m or 2d (default: no limit).",
+)
+@click.option(
+    "--owners-dir",
+    type=click.Path(file_okay=False, path_type=Path),
+    help="Write one Markdown task list per CODEOWNERS owner to this directory.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output as JSON.",
+)
+def plan_command(
+    paths: tuple[str, ...],
+    tools: str | None,
+    exclude: str | None,
+    budget: str | None,
+    owners_dir: Path | None,
+    *,
+    json_output: bool,
+) -> None:
+    """List which files and rules to clean up first within a time budget.
+
+    Runs the checks, estimates the effort of every issue (see output.effort)
+    and orders the work auto-fixable first, then by severity. Tasks are
+    taken in that order until the budget is spent.
+
+    Args:
+        paths: Paths to check (default: current directory).
+        tools: Comma-separated tool names.
+        exclude: Comma-separated exclude patterns.
+        budget: Effort budget, or None for no limit.
+        owners_dir: Directory for per-owner task lists, if requested.
+        json_output: If True, output the plan as JSON.
+
+    Raises:
+        click.BadParameter: If the budget cannot be parsed.
+        click.ClickException: If the tool selection is invalid.
+
+    Examples:
+        lintro plan --budget 4h
+        lintro plan src --tools ruff,mypy --budget 1d
+        lintro plan --budget 4h --owners-dir cleanup/
+        lintro plan --json
+    """
+    try:
+        budget_minutes = parse_budget(budget) if budget else None
+    except ValueError as e:
+        raise click.BadParameter(str(e), param_hint="--budget") from e
+
+    roots = list(paths) or ["."]
+    try:
+        results = run_checks(roots, tools, exclude)
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+
+    plan = build_cleanup_plan(
+        results,
+        budget_minutes=budget_minutes,
+        effort_overrides=get_config().output.effort,
+        codeowners=load_codeowners(),
+        roots=roots,
+    )
+    written = write_owner_task_lists(plan, owners_dir) if owners_dir else []
+
+    if json_output:
+        data = _plan_to_dict(plan)
+        data["task_lists"] = [str(path) for path in written]
+        click.echo(json.dumps(data, indent=2))
+        return
+
+    console = Console()
+    if not plan.items and not plan.deferred:
+        console.print("[green]✅ No issues found; nothing to plan.[/green]")
+        return
+
+    if plan.items:
+        table = Table(title="Cleanup Plan")
+        table.add_column("#", justify="right", style="dim")
+        table.add_column("File", style="cyan")
+        table.add_column("Rule")
+        table.add_column("Severity")
+        table.add_column("Issues", justify="right")
+        table.add_column("Effort", justify="right")
+        table.add_column("Auto-fix", justify="center")
+        for index, item in enumerate(plan.items, start=1):
+            style = _SEVERITY_STYLES.get(item.severity.value, "")
+            table.add_row(
+                str(index),
+                item.file,
+                f"{item.tool} {item.code}".strip(),
+                f"[{style}]{item.severity.value.lower()}[/{style}]",
+                str(item.issues),
+                format_effort(item.minutes),
+                "✓" if item.fixable else "",
+            )
+        console.print(table)
+
+    summary = (
+        f"\nPlanned {format_effort(plan.planned_minutes)} "
+        f"of {format_effort(plan.total_minutes)} estimated lint debt"
+    )
+    if plan.budget_minutes is not None:
+        summary += f" (budget {format_effort(plan.budget_minutes)})"
+    console.print(summary)
+    if plan.deferred:
+        console.print(
+            f"[yellow]{len(plan.deferred)} task(s) deferred; "
+            "raise --budget to include them.[/yellow]",
+        )
+    if any(item.fixable for item in plan.items):
+        console.print("[dim]Auto-fixable tasks are cleared by 'lintro format'.[/dim]")
+    for path in written:
+        console.print(f"[dim]Wrote {path}[/dim]")
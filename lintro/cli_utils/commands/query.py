@@ -0,0 +1,128 @@
+"""Query command for slicing a saved JSON report.
+
+Filters the issues of a report written by ``lintro check --output FILE
+--output-format json`` with the expression language also accepted by
+``lintro check --filter`` (see :mod:`lintro.utils.issue_query`).
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.utils.issue_query import IssueQuery, compile_query, report_records
+
+
+def parse_query_option(
+    ctx: click.Context,
+    param: click.Parameter,
+    value: str | None,
+) -> IssueQuery | None:
+    """Compile a filter expression given on the command line.
+
+    Args:
+        ctx: Click context.
+        param: Parameter holding the expression.
+        value: Expression, or None if not given.
+
+    Returns:
+        IssueQuery | None: Compiled query, or None if not given.
+
+    Raises:
+        click.BadParameter: If the expression is invalid.
+    """
+    if value is None:
+        return None
+    try:
+        return compile_query(value)
+    except ValueError as e:
+        raise click.BadParameter(str(e), ctx=ctx, param=param) from e
+
+
+def _print_matches(records: list[dict[str, Any]], total: int) -> None:
+    """Print matching issues as a table.
+
+    Args:
+        records: Matching issue records.
+        total: Number of issues in the report.
+    """
+    console = Console()
+    if records:
+        table = Table(title="Matching Issues")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Severity")
+        table.add_column("Location")
+        table.add_column("Code", style="yellow")
+        table.add_column("Message")
+        for record in records:
+            location = record["path"]
+            if record["line"]:
+                location += f":{record['line']}"
+            table.add_row(
+                record["tool"],
+                record["severity"].value.lower(),
+                location,
+                record["code"],
+                record["message"],
+            )
+        console.print(table)
+    console.print(f"{len(records)} of {total} issue(s) match.")
+
+
+@click.command("query")
+@click.argument("expression", callback=parse_query_option)
+@click.option(
+    "--input",
+    "input_path",
+    type=click.Path(exists=True, dir_okay=False, path_type=Path),
+    required=True,
+    help="JSON report written with --output FILE --output-format json.",
+)
+@click.option(
+    "--json",
+    "as_json",
+    is_flag=True,
+    help="Print the matching issues as JSON.",
+)
+def query_command(
+    expression: IssueQuery,
+    input_path: Path,
+    as_json: bool,
+) -> None:
+    """Filter the issues of a saved JSON report.
+
+    Expressions compare the fields tool, path, line, code, message and
+    severity with =, !=, ~ (glob), !~, <, <=, > and >=, joined with and, or,
+    not and parentheses. Severities are ordered info < warning < error.
+
+    Args:
+        expression: Compiled filter expression.
+        input_path: JSON report to read.
+        as_json: If True, print the matching issues as JSON.
+
+    Raises:
+        click.ClickException: If the file is not a Lintro JSON report.
+
+    Examples:
+        lintro query 'severity>=error and tool=clippy' --input report.json
+        lintro query 'path~"src/core/**" and not code~"E5*"' --input r.json
+    """
+    try:
+        report = json.loads(input_path.read_text(encoding="utf-8"))
+        if not isinstance(report, dict):
+            raise ValueError("Not a Lintro JSON report")
+        records = report_records(report)
+    except (OSError, ValueError, AttributeError) as e:
+        raise click.ClickException(f"Cannot read {input_path}: {e}") from e
+
+    matches = [record for record in records if expression.matches(record)]
+    if as_json:
+        issues = [{"tool": r["tool"], **r["issue"]} for r in matches]
+        click.echo(json.dumps(issues, indent=2, ensure_ascii=False))
+        return
+    _print_matches(matches, len(records))
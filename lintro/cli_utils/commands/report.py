@@ -0,0 +1,463 @@
+"""Report command for sharing report files with other services."""
+
+import json
+import os
+
+import click
+from rich.console import Console
+
+from lintro.config.config_loader import get_config
+from lintro.utils import github_review, gitlab_review
+from lintro.utils.badge import load_report
+from lintro.utils.execution.failure_policy import build_failure_policy
+from lintro.utils.github_checks import (
+    DEFAULT_CHECK_NAME,
+    detect_check_target,
+    failed_tools,
+    publish_check_run,
+)
+from lintro.utils.github_review import (
+    GitHubClient,
+    detect_pull_request,
+    publish_review,
+)
+from lintro.utils.gitlab_review import (
+    GitLabClient,
+    detect_merge_request,
+    publish_discussions,
+)
+from lintro.utils.issue_query import report_records
+from lintro.utils.notifications import (
+    DEFAULT_MAX_ISSUES,
+    NOTIFY_FORMATS,
+    WEBHOOK_ENV,
+    NotificationError,
+    build_notification,
+    default_report_url,
+    detect_format,
+    post_notification,
+    render_payload,
+)
+from lintro.utils.report_upload import (
+    DEFAULT_CHUNK_SIZE,
+    TOKEN_ENV,
+    ReportUploadError,
+    push_report,
+)
+from lintro.utils.review_comments import (
+    ReviewApiError,
+    ReviewResult,
+)
+from lintro.utils.vcs import detect_vcs
+
+
+@click.group(invoke_without_command=True)
+@click.option(
+    "--github-pr",
+    is_flag=True,
+    help="Post the report's issues as inline comments on the current GitHub PR.",
+)
+@click.option(
+    "--gitlab-mr",
+    is_flag=True,
+    help="Post the report's issues as discussions on the current GitLab MR.",
+)
+@click.option(
+    "--github-checks",
+    is_flag=True,
+    help="Create a GitHub check run annotating the report's issues.",
+)
+@click.option(
+    "--input",
+    "input_path",
+    type=click.Path(exists=True, dir_okay=False),
+    default=None,
+    help="JSON report written with --output FILE --output-format json.",
+)
+@click.option(
+    "--repo",
+    default=None,
+    help="Repository as owner/name (default: GITHUB_REPOSITORY).",
+)
+@click.option(
+    "--pr",
+    "pr_number",
+    type=click.IntRange(min=1),
+    default=None,
+    help="Pull request number (default: from the GitHub Actions event).",
+)
+@click.option(
+    "--sha",
+    "head_sha",
+    default=None,
+    help="Commit of the check run (default: PR head commit or GITHUB_SHA).",
+)
+@click.option(
+    "--check-name",
+    default=DEFAULT_CHECK_NAME,
+    show_default=True,
+    help="Name of the check run.",
+)
+@click.option(
+    "--fail-on",
+    type=click.Choice(["error", "warning", "info", "never"]),
+    default=None,
+    help="Lowest severity that concludes the check run as failed [default: info]",
+)
+@click.option(
+    "--max-issues",
+    type=click.IntRange(min=0),
+    default=None,
+    help="Failing issues tolerated before the check run fails [default: 0]",
+)
+@click.option(
+    "--github-token-env",
+    default=github_review.TOKEN_ENV,
+    show_default=True,
+    help="Environment variable holding the GitHub token.",
+)
+@click.option(
+    "--project",
+    default=None,
+    help="GitLab project ID or path (default: CI_PROJECT_ID).",
+)
+@click.option(
+    "--mr",
+    "mr_iid",
+    type=click.IntRange(min=1),
+    default=None,
+    help="Merge request IID (default: CI_MERGE_REQUEST_IID).",
+)
+@click.option(
+    "--gitlab-token-env",
+    default=gitlab_review.TOKEN_ENV,
+    show_default=True,
+    help="Environment variable holding the GitLab access token.",
+)
+@click.pass_context
+def report_command(
+    ctx: click.Context,
+    github_pr: bool,
+    gitlab_mr: bool,
+    github_checks: bool,
+    input_path: str | None,
+    repo: str | None,
+    pr_number: int | None,
+    head_sha: str | None,
+    check_name: str,
+    fail_on: str | None,
+    max_issues: int | None,
+    github_token_env: str,
+    project: str | None,
+    mr_iid: int | None,
+    gitlab_token_env: str,
+) -> None:
+    """Share lintro report files.
+
+    With --github-pr, the issues of a JSON report that fall on lines of the
+    pull request's diff are posted as inline comments in one review. With
+    --gitlab-mr, they start one discussion per line of the merge request's
+    diff. Running it again edits the comments whose issues changed and
+    deletes the ones whose issues are gone, so retried jobs post nothing
+    twice. With --github-checks, a check run on the commit annotates every
+    issue and concludes failure, neutral or success by the failure policy.
+
+    Args:
+        ctx: Click context.
+        github_pr: Publish the report as a GitHub pull request review.
+        gitlab_mr: Publish the report as GitLab merge request discussions.
+        github_checks: Publish the report as a GitHub check run.
+        input_path: JSON report to publish.
+        repo: Repository as ``owner/name``.
+        pr_number: Pull request number.
+        head_sha: Commit the check run is created on.
+        check_name: Name of the check run.
+        fail_on: Lowest severity that fails the check run.
+        max_issues: Failing issues tolerated before the check run fails.
+        github_token_env: Environment variable holding the GitHub token.
+        project: GitLab project ID or path.
+        mr_iid: Merge request IID.
+        gitlab_token_env: Environment variable holding the GitLab token.
+
+    Raises:
+        click.UsageError: If --github-pr, --gitlab-mr or --github-checks is
+            given without --input, or several are given.
+        SystemExit: If the comments or check run cannot be published.
+
+    Examples:
+        lintro check --output lint.json --output-format json
+        lintro report --github-pr --input lint.json
+        lintro report --gitlab-mr --input lint.json
+        lintro report --github-checks --input lint.json --fail-on error
+    """
+    if ctx.invoked_subcommand is not None:
+        return
+    flags = [
+        flag
+        for flag, given in (
+            ("--github-pr", github_pr),
+            ("--gitlab-mr", gitlab_mr),
+            ("--github-checks", github_checks),
+        )
+        if given
+    ]
+    if not flags:
+        click.echo(ctx.get_help())
+        return
+    if len(flags) > 1:
+        raise click.UsageError(f"{' and '.join(flags)} cannot be combined")
+    if not input_path:
+        raise click.UsageError(f"{flags[0]} needs --input REPORT")
+
+    console = Console()
+    token_env = gitlab_token_env if gitlab_mr else github_token_env
+    token = os.environ.get(token_env)
+    try:
+        if not token:
+            raise ReviewApiError(f"{token_env} is not set")
+        report = load_report(input_path)
+        records = report_records(report)
+        root = detect_vcs().root
+        if github_checks:
+            target = detect_check_target(repo, head_sha)
+            check_run = publish_check_run(
+                records,
+                target,
+                GitHubClient(token, target.api_url),
+                build_failure_policy(get_config(), fail_on, max_issues),
+                root=root,
+                name=check_name,
+                failed=failed_tools(report),
+            )
+            console.print(
+                f"[green]✅ Created check run {check_name} on "
+                f"{target.repo}@{target.head_sha[:7]}: {check_run.conclusion}, "
+                f"{check_run.annotations} annotation(s)[/green]",
+            )
+            if check_run.url:
+                console.print(check_run.url)
+            return
+        result: ReviewResult
+        if github_pr:
+            pull = detect_pull_request(repo, pr_number)
+            target = f"{pull.repo}#{pull.number}"
+            result = publish_review(
+                records,
+                pull,
+                GitHubClient(token, pull.api_url),
+                root=root,
+            )
+        else:
+            merge_request = detect_merge_request(project, mr_iid)
+            target = f"{merge_request.project}!{merge_request.iid}"
+            result = publish_discussions(
+                records,
+                merge_request,
+                GitLabClient(token, merge_request.api_url),
+                root=root,
+            )
+    except (OSError, ValueError, ReviewApiError) as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
+
+    console.print(
+        f"[green]✅ Reviewed {target}: {result.created} new, "
+        f"{result.updated} updated, {result.deleted} deleted, "
+        f"{result.unchanged} unchanged comment(s)[/green]",
+    )
+    if result.outside_diff:
+        console.print(
+            f"{result.outside_diff} issue(s) outside the diff were not commented.",
+        )
+
+
+@report_command.command("push")
+@click.argument("report", type=click.Path(exists=True, dir_okay=False))
+@click.option(
+    "--endpoint",
+    required=True,
+    envvar="LINTRO_REPORT_ENDPOINT",
+    help="Base URL of the results service (or LINTRO_REPORT_ENDPOINT).",
+)
+@click.option(
+    "--token-env",
+    default=TOKEN_ENV,
+    show_default=True,
+    help="Environment variable holding the API token.",
+)
+@click.option(
+    "--chunk-size",
+    type=click.IntRange(min=1024),
+    default=DEFAULT_CHUNK_SIZE,
+    show_default=True,
+    help="Compressed bytes sent per request.",
+)
+@click.option(
+    "--timeout",
+    type=click.FloatRange(min=1),
+    default=30.0,
+    show_default=True,
+    help="Seconds to wait for each response.",
+)
+def report_push_command(
+    report: str,
+    endpoint: str,
+    token_env: str,
+    chunk_size: int,
+    timeout: float,
+) -> None:
+    """Upload a report file to a central lint-results service.
+
+    The report is gzip-compressed and sent in chunks; an interrupted upload
+    resumes where it stopped when the command is run again.
+
+    Args:
+        report: Report file to upload.
+        endpoint: Base URL of the results service.
+        token_env: Environment variable holding the API token.
+        chunk_size: Compressed bytes per chunk.
+        timeout: Seconds to wait for each response.
+
+    Raises:
+        SystemExit: If the upload fails.
+
+    Examples:
+        lintro check --output lint.json --output-format json
+        lintro report push lint.json --endpoint https://lint.example.com/api
+    """
+    console = Console()
+    try:
+        result = push_report(
+            report,
+            endpoint,
+            token=os.environ.get(token_env),
+            chunk_size=chunk_size,
+            timeout=timeout,
+        )
+    except (OSError, ReportUploadError) as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
+
+    resumed = (
+        f", resumed after {result.resumed_from} bytes" if result.resumed_from else ""
+    )
+    console.print(
+        f"[green]✅ Uploaded {report} as report {result.report_id}"
+        f" ({result.chunks} chunk(s){resumed})[/green]",
+    )
+    if result.url:
+        console.print(result.url)
+
+
+@report_command.command("notify")
+@click.argument("report", type=click.Path(exists=True, dir_okay=False))
+@click.option(
+    "--webhook",
+    envvar=WEBHOOK_ENV,
+    default=None,
+    help=f"Webhook URL to post to (or {WEBHOOK_ENV}).",
+)
+@click.option(
+    "--format",
+    "fmt",
+    type=click.Choice(NOTIFY_FORMATS),
+    default="auto",
+    show_default=True,
+    help="Payload format; auto picks Slack or Teams by the webhook's host.",
+)
+@click.option(
+    "--baseline",
+    type=click.Path(exists=True, dir_okay=False),
+    default=None,
+    help="Earlier JSON report; the message lists issues that are new since.",
+)
+@click.option(
+    "--report-url",
+    default=None,
+    help="Link to the report artifact (default: the CI run, if known).",
+)
+@click.option(
+    "--title",
+    default="lintro",
+    show_default=True,
+    help="Heading of the message.",
+)
+@click.option(
+    "--max-issues",
+    type=click.IntRange(min=0),
+    default=DEFAULT_MAX_ISSUES,
+    show_default=True,
+    help="New issues to list at most.",
+)
+@click.option(
+    "--timeout",
+    type=click.FloatRange(min=1),
+    default=30.0,
+    show_default=True,
+    help="Seconds to wait for the response.",
+)
+@click.option(
+    "--dry-run",
+    is_flag=True,
+    help="Print the payload instead of posting it.",
+)
+def report_notify_command(
+    report: str,
+    webhook: str | None,
+    fmt: str,
+    baseline: str | None,
+    report_url: str | None,
+    title: str,
+    max_issues: int,
+    timeout: float,
+    dry_run: bool,
+) -> None:
+    """Post a summary of a report to a Slack, Teams or JSON webhook.
+
+    The message gives the issue counts per tool, the issues that are new
+    since --baseline, and a link to the report. Run it at the end of a
+    scheduled report job.
+
+    Args:
+        report: JSON report of the run.
+        webhook: Webhook URL.
+        fmt: Payload format, or "auto".
+        baseline: Earlier JSON report to compare with.
+        report_url: Link to the report artifact.
+        title: Heading of the message.
+        max_issues: New issues to list at most.
+        timeout: Seconds to wait for the response.
+        dry_run: Print the payload instead of posting it.
+
+    Raises:
+        click.UsageError: If no webhook is given outside a dry run.
+        SystemExit: If a report cannot be read or the webhook fails.
+
+    Examples:
+        lintro check --output lint.json --output-format json
+        lintro report notify lint.json --baseline main.json
+        lintro report notify lint.json --format slack --dry-run
+    """
+    if not webhook and not dry_run:
+        raise click.UsageError(f"--webhook or {WEBHOOK_ENV} is required")
+    if fmt == "auto":
+        fmt = detect_format(webhook or "")
+
+    console = Console()
+    try:
+        notification = build_notification(
+            load_report(report),
+            baseline=load_report(baseline) if baseline else None,
+            title=title,
+            report_url=report_url or default_report_url(),
+        )
+        payload = render_payload(notification, fmt, max_issues)
+        if dry_run:
+            click.echo(json.dumps(payload, indent=2))
+            return
+        post_notification(webhook or "", payload, timeout=timeout)
+    except (OSError, ValueError, NotificationError) as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise SystemExit(1) from e
+
+    console.print(f"[green]✅ Posted {notification.headline}[/green]")
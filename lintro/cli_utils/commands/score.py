@@ -0,0 +1,213 @@
+"""Score command grading a run from 0 to 100 and A to F.
+
+Runs the configured checks, or reads a saved JSON report, weighs the issues
+by severity and rule category, and scores them against the lines of code
+checked, overall and per directory. ``--fail-under`` and ``--min-grade``
+turn the score into a CI gate. Nothing is modified.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config.config_loader import get_config
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.utils.badge import load_report
+from lintro.utils.execution.check_runner import run_checks
+from lintro.utils.issue_stats import result_records, saved_records
+from lintro.utils.quality_score import (
+    ScoreModel,
+    ScoreReport,
+    compute_score,
+    count_lines,
+)
+
+
+@click.command("score")
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated list of tools to run (default: all enabled).",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--input",
+    "input_path",
+    type=click.Path(exists=True, dir_okay=False, path_type=Path),
+    help="Score a saved JSON report instead of running the checks.",
+)
+@click.option(
+    "--depth",
+    type=click.IntRange(min=1),
+    default=1,
+    show_default=True,
+    help="Directory levels scored separately.",
+)
+@click.option(
+    "--top",
+    type=click.IntRange(min=1),
+    default=10,
+    show_default=True,
+    help="Lowest-scoring directories listed.",
+)
+@click.option(
+    "--fail-under",
+    type=click.FloatRange(min=0, max=100),
+    default=None,
+    help="Exit with status 1 below this score (default: score.fail_under).",
+)
+@click.option(
+    "--min-grade",
+    type=str,
+    default=None,
+    help="Exit with status 1 below this grade (default: score.min_grade).",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output the scores as JSON.",
+)
+def score_command(
+    paths: tuple[str, ...],
+    tools: str | None,
+    exclude: str | None,
+    input_path: Path | None,
+    depth: int,
+    top: int,
+    fail_under: float | None,
+    min_grade: str | None,
+    json_output: bool,
+) -> None:
+    """Score the code from 0 to 100 with a letter grade.
+
+    Every issue costs its severity weight (error 10, warning 3, info 1)
+    times its rule category's weight (security 3, formatting 0.5, else 1).
+    The penalty per thousand non-blank lines of the checked files sets the
+    score, overall and per directory; weights, grades and thresholds are
+    set under score: in the config. With --input, the report's issues are
+    scored against the lines under PATHS (default: current directory).
+
+    Args:
+        paths: Paths to check and count lines in (default: current directory).
+        tools: Comma-separated tool names.
+        exclude: Comma-separated exclude patterns.
+        input_path: Saved JSON report to score instead of running checks.
+        depth: Directory levels scored separately.
+        top: Number of lowest-scoring directories to list.
+        fail_under: Lowest passing score; overrides score.fail_under.
+        min_grade: Lowest passing grade; overrides score.min_grade.
+        json_output: Whether to output the scores as JSON.
+
+    Raises:
+        click.ClickException: If the config, tool selection or report is
+            invalid.
+        click.BadParameter: If --min-grade names no configured grade.
+        click.UsageError: If --input is combined with --tools.
+        SystemExit: With status 1 when the score or grade is below the
+            threshold.
+
+    Examples:
+        lintro score
+        lintro score src --depth 2
+        lintro score --input lint.json --json
+        lintro score --fail-under 80 --min-grade B
+    """
+    try:
+        config = get_config().score
+        model = ScoreModel.from_config(config)
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+    fail_under = config.fail_under if fail_under is None else fail_under
+    min_grade = min_grade or config.min_grade
+    try:
+        min_rank = None if min_grade is None else model.grade_rank(min_grade)
+    except ValueError as e:
+        raise click.BadParameter(str(e), param_hint="--min-grade") from e
+
+    roots = list(paths) or ["."]
+    if input_path is not None:
+        if tools:
+            raise click.UsageError("--input cannot be combined with --tools")
+        try:
+            records = saved_records(load_report(input_path))
+        except (OSError, ValueError) as e:
+            raise click.ClickException(f"Cannot read {input_path}: {e}") from e
+    else:
+        try:
+            records = result_records(run_checks(roots, tools, exclude))
+        except ValueError as e:
+            raise click.ClickException(str(e)) from e
+
+    excludes = list(DEFAULT_EXCLUDE_PATTERNS)
+    if exclude:
+        excludes.extend(p.strip() for p in exclude.split(",") if p.strip())
+    report = compute_score(records, count_lines(roots, excludes), model, depth)
+
+    failures: list[str] = []
+    if fail_under is not None and report.total.score < fail_under:
+        failures.append(f"score {report.total.score} is below {fail_under:g}")
+    if min_rank is not None and model.grade_rank(report.total.grade) > min_rank:
+        failures.append(f"grade {report.total.grade} is below {min_grade}")
+
+    if json_output:
+        data: dict[str, Any] = report.to_dict()
+        data["passed"] = not failures
+        click.echo(json.dumps(data, indent=2))
+    else:
+        _print_score(Console(), report, top)
+        for failure in failures:
+            click.echo(f"Quality gate failed: {failure}", err=True)
+    if failures:
+        raise SystemExit(1)
+
+
+def _print_score(console: Console, report: ScoreReport, top: int) -> None:
+    """Print the total score and the lowest-scoring directories.
+
+    Args:
+        console: Console to print to.
+        report: Scores of the run.
+        top: Number of directories to list.
+    """
+    rows = [row for row in report.directories if row.issues][:top]
+    if rows:
+        table = Table(title="Lowest-Scoring Directories")
+        table.add_column("Directory", style="cyan")
+        table.add_column("Lines", justify="right")
+        table.add_column("Issues", justify="right")
+        table.add_column("Penalty", justify="right")
+        table.add_column("Score", justify="right")
+        table.add_column("Grade", justify="center")
+        for row in rows:
+            table.add_row(
+                row.path,
+                str(row.lines),
+                str(row.issues),
+                f"{row.penalty:g}",
+                f"{row.score:.1f}",
+                row.grade,
+            )
+        console.print(table)
+    if report.categories:
+        console.print(
+            "Penalty by category: "
+            + ", ".join(f"{k} {v:g}" for k, v in report.categories.items()),
+        )
+    total = report.total
+    console.print(
+        f"[bold]Quality score {total.score:.1f} ({total.grade})[/bold]: "
+        f"{total.issues} issue(s), penalty {total.penalty:g} over "
+        f"{total.lines} line(s)",
+    )
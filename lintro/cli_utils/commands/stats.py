@@ -0,0 +1,374 @@
+"""Stats command showing where lint debt concentrates.
+
+Runs the configured checks, or reads a saved JSON report, and summarizes
+issues per tool, rule, file and directory, optionally with an SVG treemap.
+With ``--rule-usage`` it reads past runs instead and lists configuration
+that never produced an issue. Nothing is modified.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import sqlite3
+from pathlib import Path
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.config import get_config
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.utils.badge import load_report
+from lintro.utils.execution.check_runner import run_checks
+from lintro.utils.issue_stats import (
+    CountRow,
+    IssueStats,
+    compute_stats,
+    result_records,
+    saved_records,
+)
+from lintro.utils.path_filtering import walk_files_with_excludes
+from lintro.utils.rule_usage import (
+    DEFAULT_RUNS,
+    RuleUsageReport,
+    analyze_rule_usage,
+    load_configured_rules,
+    load_run_history,
+    load_stored_history,
+)
+from lintro.utils.treemap import (
+    DEFAULT_DEPTH,
+    TreemapNode,
+    build_tree,
+    iter_directories,
+    render_treemap_svg,
+)
+
+
+@click.command()
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated list of tools to run (default: all enabled).",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--treemap",
+    type=click.Path(dir_okay=False, path_type=Path),
+    help="Write a directory treemap sized by files and coloured by issue density.",
+)
+@click.option(
+    "--depth",
+    type=click.IntRange(min=1),
+    default=DEFAULT_DEPTH,
+    show_default=True,
+    help="Directory levels to break down.",
+)
+@click.option(
+    "--top",
+    type=click.IntRange(min=1),
+    default=10,
+    show_default=True,
+    help="Directories listed in the summary table.",
+)
+@click.option(
+    "--input",
+    "input_path",
+    type=click.Path(exists=True, dir_okay=False, path_type=Path),
+    help="Summarize a saved JSON report instead of running the checks.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output the metrics as JSON.",
+)
+@click.option(
+    "--rule-usage",
+    is_flag=True,
+    help="List tools, rules and suppressions that did nothing in past runs.",
+)
+@click.option(
+    "--runs",
+    type=click.IntRange(min=1),
+    default=DEFAULT_RUNS,
+    show_default=True,
+    help="Past runs examined by --rule-usage.",
+)
+def stats_command(
+    paths: tuple[str, ...],
+    tools: str | None,
+    exclude: str | None,
+    treemap: Path | None,
+    depth: int,
+    top: int,
+    input_path: Path | None,
+    json_output: bool,
+    rule_usage: bool,
+    runs: int,
+) -> None:
+    """Show which tools, rules, files and directories hold the most lint debt.
+
+    Runs the checks, or reads a report given with --input, and lists the
+    issues per tool, the top rules and files, and how many issues the tools
+    can fix. Directories are weighed by severity (error 3, warning 1, info
+    0.5) and listed with their density per file. With --rule-usage, no
+    checks run: the last --runs runs are searched for configuration that
+    never fired, read from the execution.history database when one is set
+    and from the summaries kept in .lintro otherwise.
+
+    Args:
+        paths: Paths to check (default: current directory).
+        tools: Comma-separated tool names.
+        exclude: Comma-separated exclude patterns.
+        treemap: SVG file to write the treemap to, if requested.
+        depth: Directory levels to break down.
+        top: Number of rules, files and directories to list.
+        input_path: Saved JSON report to summarize instead of running checks.
+        json_output: Whether to output the metrics as JSON.
+        rule_usage: Whether to report unused configuration from past runs.
+        runs: Number of past runs to examine for --rule-usage.
+
+    Raises:
+        click.ClickException: If the tool selection is invalid, the report
+            cannot be read, the treemap cannot be written, or --rule-usage
+            finds no past runs or cannot read the run history.
+        click.UsageError: If --input is combined with paths, --tools or
+            --treemap.
+
+    Examples:
+        lintro stats
+        lintro stats src --tools ruff,mypy --depth 2
+        lintro stats --treemap lint-debt.svg
+        lintro stats --input lint.json --json
+        lintro stats --rule-usage --runs 20
+    """
+    if rule_usage:
+        latest = load_run_history(limit=runs)
+        store = get_config().execution.history
+        try:
+            stored = load_stored_history(store, limit=runs) if store else []
+        except (sqlite3.Error, ValueError) as e:
+            raise click.ClickException(f"Cannot read {store}: {e}") from e
+        history = stored or latest
+        if not history:
+            raise click.ClickException(
+                "No past runs found in .lintro; run lintro check first.",
+            )
+        report = analyze_rule_usage(history, load_configured_rules(), latest=latest)
+        console = Console()
+        _print_rule_usage(console, report)
+        if len(history) < runs and not store:
+            console.print(
+                f"[dim]Only {len(history)} run(s) are kept in .lintro; set "
+                "execution.history to look further back.[/dim]",
+            )
+        return
+
+    if input_path is not None:
+        if paths or tools or treemap:
+            raise click.UsageError(
+                "--input cannot be combined with paths, --tools or --treemap",
+            )
+        stats = _report_stats(input_path, top=top, depth=depth)
+        if json_output:
+            click.echo(json.dumps(stats.to_dict(), indent=2))
+            return
+        console = Console()
+        _print_stats(console, stats)
+        if stats.directories:
+            console.print(
+                _rows_table("Issues by Directory", "Directory", stats.directories),
+            )
+        return
+
+    roots = list(paths) or ["."]
+    try:
+        results = run_checks(roots, tools, exclude)
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+    stats = compute_stats(
+        result_records(results),
+        tools=[r.name for r in results if not r.skipped],
+        top=top,
+        depth=depth,
+    )
+
+    excludes = list(DEFAULT_EXCLUDE_PATTERNS)
+    if exclude:
+        excludes.extend(p.strip() for p in exclude.split(",") if p.strip())
+    files = [
+        os.path.relpath(path)
+        for path in walk_files_with_excludes(roots, ["*"], excludes)
+    ]
+    root = build_tree(files, results, roots)
+
+    console = Console()
+    if json_output:
+        click.echo(json.dumps(stats.to_dict(), indent=2))
+    else:
+        _print_stats(console, stats)
+        _print_directories(console, root, depth=depth, top=top)
+
+    if treemap:
+        try:
+            treemap.parent.mkdir(parents=True, exist_ok=True)
+            treemap.write_text(
+                render_treemap_svg(root, max_depth=depth),
+                encoding="utf-8",
+            )
+        except OSError as e:
+            raise click.ClickException(f"Cannot write {treemap}: {e}") from e
+        if not json_output:
+            console.print(f"[dim]Wrote {treemap}[/dim]")
+
+
+def _print_directories(
+    console: Console,
+    root: TreemapNode,
+    depth: int,
+    top: int,
+) -> None:
+    """Print the directories with the most weighted issues.
+
+    Args:
+        console: Console to print to.
+        root: Root of the directory tree of the run.
+        depth: Directory levels to break down.
+        top: Number of directories to list.
+    """
+    directories = sorted(
+        iter_directories(root, depth),
+        key=lambda node: (-node.weight, node.path),
+    )
+    if root.issues:
+        table = Table(title="Lint Debt by Directory")
+        table.add_column("Directory", style="cyan")
+        table.add_column("Files", justify="right")
+        table.add_column("Issues", justify="right")
+        table.add_column("Errors", justify="right")
+        table.add_column("Density", justify="right")
+        for node in [node for node in directories if node.issues][:top]:
+            table.add_row(
+                f"{node.path}/",
+                str(node.files),
+                str(node.issues),
+                str(node.errors),
+                f"{node.density:.2f}",
+            )
+        console.print(table)
+    console.print(
+        f"{root.issues} issue(s) in {root.files} file(s); "
+        f"density {root.density:.2f} weighted issues per file",
+    )
+
+
+def _rows_table(title: str, column: str, rows: list[CountRow]) -> Table:
+    """Render issue counts per key as a table.
+
+    Args:
+        title: Table title.
+        column: Heading of the key column.
+        rows: Rows to show.
+
+    Returns:
+        Table: Rich table with issues, fixable issues and errors per key.
+    """
+    table = Table(title=title)
+    table.add_column(column, style="cyan")
+    table.add_column("Issues", justify="right")
+    table.add_column("Fixable", justify="right")
+    table.add_column("Errors", justify="right")
+    for row in rows:
+        table.add_row(row.key, str(row.issues), str(row.fixable), str(row.errors))
+    return table
+
+
+def _report_stats(path: Path, top: int, depth: int) -> IssueStats:
+    """Compute the metrics of a saved JSON report.
+
+    Args:
+        path: Report written with ``--output FILE --output-format json``.
+        top: Number of rules, files and directories to list.
+        depth: Directory levels issues are grouped under.
+
+    Returns:
+        IssueStats: Metrics of the report.
+
+    Raises:
+        click.ClickException: If the file is not a readable JSON report.
+    """
+    try:
+        report = load_report(path)
+        records = saved_records(report)
+    except (OSError, ValueError) as e:
+        raise click.ClickException(f"Cannot read {path}: {e}") from e
+    ran = [
+        str(r.get("tool") or "")
+        for r in report["results"]
+        if r.get("tool") and not r.get("skipped")
+    ]
+    return compute_stats(records, tools=ran, top=top, depth=depth)
+
+
+def _print_stats(console: Console, stats: IssueStats) -> None:
+    """Print issue counts per tool, rule and file.
+
+    Args:
+        console: Console to print to.
+        stats: Metrics of the run.
+    """
+    if stats.tools:
+        console.print(_rows_table("Issues by Tool", "Tool", stats.tools))
+    if stats.rules:
+        console.print(_rows_table("Top Rules", "Rule", stats.rules))
+    if stats.files:
+        console.print(_rows_table("Top Files", "File", stats.files))
+    console.print(
+        f"{stats.fixable} of {stats.issues} issue(s) fixable automatically "
+        f"({stats.fixable_ratio:.0%})",
+    )
+
+
+def _print_rule_usage(console: Console, report: RuleUsageReport) -> None:
+    """Print the configuration that did nothing in past runs.
+
+    Args:
+        console: Console to print to.
+        report: Rule usage found in the run history.
+    """
+    if report.silent_tools:
+        table = Table(title="Tools Without Issues")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Runs", justify="right")
+        for tool, tool_runs in report.silent_tools:
+            table.add_row(tool, str(tool_runs))
+        console.print(table)
+    if report.unused_rules:
+        table = Table(title="Selected Rules That Never Fired")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Rule")
+        for tool, rule in report.unused_rules:
+            table.add_row(tool, rule)
+        console.print(table)
+    if report.unused_suppressions:
+        table = Table(title="Unused Suppressions")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Location")
+        table.add_column("Message")
+        for issue in report.unused_suppressions:
+            table.add_row(issue.tool, f"{issue.file}:{issue.line}", issue.message)
+        console.print(table)
+    if report.is_clean:
+        console.print(
+            f"Every tool and selected rule fired in the last {report.runs} run(s); "
+            "no unused suppressions were reported.",
+        )
+    else:
+        console.print(f"[dim]Based on the last {report.runs} run(s).[/dim]")
@@ -0,0 +1,198 @@
+"""Suggest command for LLM-assisted fixes of unfixable issues.
+
+Runs the configured checks and sends issues that no tool can fix, with the
+surrounding code, to the OpenAI-compatible endpoint set under ``ai`` in the
+Lintro config. Returned patches are shown as unverified suggestions for
+review and can be saved, but they are never applied.
+"""
+
+from __future__ import annotations
+
+import re
+import sys
+from pathlib import Path
+
+import click
+from rich.console import Console
+from rich.panel import Panel
+from rich.text import Text
+
+from lintro.ai.client import AIClientError
+from lintro.ai.suggestions import FixSuggestion, suggest_fixes
+from lintro.config.config_loader import get_config
+from lintro.utils.execution.check_runner import run_checks
+
+DEFAULT_SUGGESTIONS_DIR: str = ".lintro/suggestions"
+
+_DIFF_STYLES: tuple[tuple[str, str], ...] = (
+    ("+++", "bold"),
+    ("---", "bold"),
+    ("@@", "cyan"),
+    ("+", "green"),
+    ("-", "red"),
+)
+
+
+def _patch_path(directory: Path, index: int, suggestion: FixSuggestion) -> Path:
+    """Return the file name for a saved patch.
+
+    Args:
+        directory: Directory for saved patches.
+        index: 1-based position of the suggestion.
+        suggestion: Suggestion being saved.
+
+    Returns:
+        Path: Patch file path.
+    """
+    stem = re.sub(r"[^A-Za-z0-9._-]+", "-", f"{suggestion.file}-{suggestion.code}")
+    return directory / f"{index:03d}-{stem.strip('-')}.patch"
+
+
+def _render_patch(patch: str) -> Text:
+    """Color a unified diff for the terminal.
+
+    Args:
+        patch: Unified diff.
+
+    Returns:
+        Text: Styled diff.
+    """
+    text = Text()
+    for index, line in enumerate(patch.splitlines()):
+        if index:
+            text.append("\n")
+        style = next((s for prefix, s in _DIFF_STYLES if line.startswith(prefix)), "")
+        text.append(line, style=style)
+    return text
+
+
+def _show_suggestion(console: Console, suggestion: FixSuggestion) -> None:
+    """Print one suggestion with its diff.
+
+    Args:
+        console: Rich console.
+        suggestion: Suggestion to show.
+    """
+    rule = f"{suggestion.tool} {suggestion.code}".strip()
+    location = suggestion.file
+    if suggestion.line:
+        location += f":{suggestion.line}"
+    title = f"{location} {rule} - suggested (unverified)"
+    console.print(
+        Panel(
+            _render_patch(suggestion.patch or ""),
+            title=title,
+            subtitle=suggestion.message,
+            border_style="yellow",
+        ),
+    )
+
+
+@click.command()
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated list of tools to run (default: all enabled).",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--max-issues",
+    type=click.IntRange(min=1),
+    help="Maximum number of issues to send (default: ai.max_issues).",
+)
+@click.option(
+    "--save-dir",
+    type=click.Path(file_okay=False, path_type=Path),
+    help="Save every suggested patch to this directory without prompting.",
+)
+def suggest_command(
+    paths: tuple[str, ...],
+    tools: str | None,
+    exclude: str | None,
+    max_issues: int | None,
+    save_dir: Path | None,
+) -> None:
+    """Ask an LLM endpoint for patches to issues no tool can fix.
+
+    Requires ai.enabled and ai.endpoint in the Lintro config. Each patch is
+    shown as a suggested (unverified) change; in a terminal you can save or
+    skip it. Nothing is applied: review saved patches and apply them with
+    git apply.
+
+    Args:
+        paths: Paths to check (default: current directory).
+        tools: Comma-separated tool names.
+        exclude: Comma-separated exclude patterns.
+        max_issues: Override for ai.max_issues.
+        save_dir: Directory to save all patches to without prompting.
+
+    Raises:
+        click.ClickException: If suggestions are disabled or the tool
+            selection is invalid.
+
+    Examples:
+        lintro suggest
+        lintro suggest src --tools mypy --max-issues 5
+        lintro suggest --save-dir patches/
+    """
+    ai_config = get_config().ai
+    if max_issues is not None:
+        ai_config = ai_config.model_copy(update={"max_issues": max_issues})
+    if not ai_config.enabled or not ai_config.endpoint:
+        raise click.ClickException(
+            "AI suggestions are disabled; set ai.enabled and ai.endpoint in "
+            ".lintro-config.yaml",
+        )
+
+    roots = list(paths) or ["."]
+    try:
+        results = run_checks(roots, tools, exclude)
+        suggestions = suggest_fixes(results, ai_config, roots=roots)
+    except (ValueError, AIClientError) as e:
+        raise click.ClickException(str(e)) from e
+
+    console = Console()
+    if not suggestions:
+        console.print("[green]✅ No issues without an automatic fix.[/green]")
+        return
+
+    interactive = save_dir is None and sys.stdin.isatty()
+    directory = save_dir or Path(DEFAULT_SUGGESTIONS_DIR)
+    saved: list[Path] = []
+    for index, suggestion in enumerate(suggestions, start=1):
+        if suggestion.patch is None:
+            console.print(
+                f"[dim]{suggestion.file}: {suggestion.tool} {suggestion.code} - "
+                f"{suggestion.error}[/dim]",
+            )
+            continue
+        _show_suggestion(console, suggestion)
+        if interactive:
+            choice = click.prompt(
+                "Save this patch? [s]ave, [k]skip, [q]uit",
+                type=click.Choice(["s", "k", "q"]),
+                default="k",
+                show_choices=False,
+            )
+            if choice == "q":
+                break
+            if choice == "k":
+                continue
+        elif save_dir is None:
+            continue
+        directory.mkdir(parents=True, exist_ok=True)
+        path = _patch_path(directory, index, suggestion)
+        path.write_text(suggestion.patch, encoding="utf-8")
+        saved.append(path)
+
+    for path in saved:
+        console.print(f"[dim]Saved {path}[/dim]")
+    console.print(
+        "[yellow]Suggestions are unverified and were not applied. Review them, "
+        "apply with 'git apply <patch>', then re-run lintro check.[/yellow]",
+    )
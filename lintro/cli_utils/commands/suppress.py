@@ -0,0 +1,126 @@
+"""Suppress command inserting suppression comments at current violations."""
+
+from __future__ import annotations
+
+from datetime import datetime
+
+import click
+from rich.console import Console
+
+from lintro.utils.execution.check_runner import run_checks
+from lintro.utils.execution.suppression_writer import (
+    NATIVE_SUPPRESSIONS,
+    collect_sites,
+    insert_suppressions,
+)
+
+
+@click.command("suppress")
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--rule",
+    required=True,
+    help="Rule to suppress, bare (E501) or tool-qualified (ruff:E501).",
+)
+@click.option(
+    "--tools",
+    type=str,
+    help="Comma-separated list of tools to run (default: all enabled).",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--native",
+    is_flag=True,
+    help="Use each tool's own suppression syntax instead of lintro's.",
+)
+@click.option(
+    "--until",
+    type=click.DateTime(formats=["%Y-%m-%d"]),
+    help="Expiry date written into lintro comments (YYYY-MM-DD).",
+)
+@click.option("--reason", default="", help="Reason written into lintro comments.")
+@click.option(
+    "--dry-run",
+    is_flag=True,
+    help="List the lines that would get a comment without changing files.",
+)
+def suppress_command(
+    paths: tuple[str, ...],
+    rule: str,
+    tools: str | None,
+    exclude: str | None,
+    native: bool,
+    until: datetime | None,
+    reason: str,
+    dry_run: bool,
+) -> None:
+    """Insert suppression comments at every current violation of a rule.
+
+    Runs the checks and adds one comment per flagged line, so a new rule can
+    be enabled at once and its existing violations cleaned up over time.
+    Comments use lintro's ``lintro: ignore[RULE]`` syntax on the line above
+    unless --native is given, which writes the tool's own syntax, such as
+    ``# noqa`` for ruff or ``#[allow(...)]`` for clippy.
+
+    Args:
+        paths: Paths to check (default: current directory).
+        rule: Rule to suppress.
+        tools: Comma-separated tool names, or None for all enabled tools.
+        exclude: Comma-separated exclude patterns.
+        native: Whether to write native suppression comments.
+        until: Expiry of lintro comments.
+        reason: Reason of lintro comments.
+        dry_run: Whether to only list the lines.
+
+    Raises:
+        click.BadParameter: If lintro-only options are combined with
+            --native or the reason contains a double quote.
+        click.ClickException: If the checks cannot run.
+
+    Examples:
+        lintro suppress --rule clippy::too_many_arguments
+        lintro suppress --rule E501 --until 2026-06-30 --reason "legacy" src
+        lintro suppress --rule ruff:F401 --native
+    """
+    if native and (until or reason):
+        raise click.BadParameter(
+            "--until and --reason only apply to lintro comments",
+            param_hint="--native",
+        )
+    if '"' in reason:
+        raise click.BadParameter("must not contain '\"'", param_hint="--reason")
+
+    try:
+        results = run_checks(list(paths) or ["."], tools, exclude)
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+    sites = collect_sites(results, rule)
+    edits = insert_suppressions(
+        sites,
+        rule,
+        native=native,
+        until=until.date() if until else None,
+        reason=reason,
+        dry_run=dry_run,
+    )
+
+    console = Console()
+    if not sites:
+        console.print(f"No violations of {rule} found.")
+        return
+    verb = "Would suppress" if dry_run else "Suppressed"
+    for location in edits.inserted:
+        console.print(f"{verb} {rule} at {location}")
+    for location, why in edits.skipped:
+        console.print(f"[yellow]Skipped {location}: {why}[/yellow]")
+    if native and edits.skipped:
+        supported = ", ".join(sorted(NATIVE_SUPPRESSIONS))
+        console.print(f"[dim]Native syntax is known for: {supported}[/dim]")
+    console.print(
+        f"{verb} {len(edits.inserted)} violation(s) in "
+        f"{len({loc.rsplit(':', 1)[0] for loc in edits.inserted})} file(s).",
+    )
@@ -0,0 +1,112 @@
+"""Suppressions command listing ``lintro: ignore`` comments about to expire."""
+
+from __future__ import annotations
+
+import json
+import os
+from datetime import date
+
+import click
+from rich.console import Console
+from rich.table import Table
+
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.utils.execution.suppressions import (
+    expiring_suppressions,
+    find_suppressions,
+)
+from lintro.utils.path_filtering import walk_files_with_excludes
+
+
+@click.command("suppressions")
+@click.argument("paths", nargs=-1, type=click.Path(exists=True))
+@click.option(
+    "--within",
+    "within_days",
+    type=click.IntRange(min=0),
+    default=30,
+    show_default=True,
+    help="List suppressions expiring within this many days.",
+)
+@click.option(
+    "--exclude",
+    type=str,
+    help="Comma-separated list of patterns to exclude.",
+)
+@click.option(
+    "--json",
+    "json_output",
+    is_flag=True,
+    help="Output the suppressions as JSON.",
+)
+def suppressions_command(
+    paths: tuple[str, ...],
+    within_days: int,
+    exclude: str | None,
+    json_output: bool,
+) -> None:
+    """List ``lintro: ignore`` suppressions that expired or expire soon.
+
+    Expired suppressions no longer hide their issues, which check runs
+    report as errors; this lists them, and those expiring within --within
+    days, so they can be resolved or extended ahead of time.
+
+    Args:
+        paths: Files or directories to search (default: current directory).
+        within_days: Days ahead to look for expirations.
+        exclude: Comma-separated patterns to exclude.
+        json_output: Whether to print JSON instead of a table.
+
+    Raises:
+        SystemExit: With status 1 when a suppression has expired.
+
+    Examples:
+        lintro suppressions
+        lintro suppressions src --within 90
+    """
+    excludes = list(DEFAULT_EXCLUDE_PATTERNS)
+    if exclude:
+        excludes.extend(p.strip() for p in exclude.split(",") if p.strip())
+    files = walk_files_with_excludes(list(paths) or ["."], ["*"], excludes)
+    today = date.today()
+    expiring = expiring_suppressions(
+        find_suppressions(os.path.relpath(path) for path in files),
+        within_days,
+        today,
+    )
+    expired = [s for s in expiring if s.expired(today)]
+
+    if json_output:
+        records = [
+            {
+                "file": s.file,
+                "line": s.line,
+                "rules": list(s.rules),
+                "until": s.until.isoformat() if s.until else None,
+                "reason": s.reason,
+                "expired": s.expired(today),
+            }
+            for s in expiring
+        ]
+        click.echo(json.dumps(records, indent=2))
+    elif not expiring:
+        Console().print(f"No suppressions expire within {within_days} days.")
+    else:
+        table = Table(title="Expiring Suppressions")
+        table.add_column("Location", style="cyan", no_wrap=True)
+        table.add_column("Rules")
+        table.add_column("Until", justify="center")
+        table.add_column("Reason")
+        for s in expiring:
+            days = (s.until - today).days if s.until else 0
+            until = f"{s.until} ({'expired' if days < 0 else f'{days}d left'})"
+            table.add_row(
+                f"{s.file}:{s.line}",
+                ", ".join(s.rules) or "all",
+                f"[red]{until}[/red]" if days < 0 else until,
+                s.reason,
+            )
+        Console().print(table)
+
+    if expired:
+        raise SystemExit(1)
@@ -5,6 +5,7 @@ from typing import Any, cast
 import click
 from click.testing import CliRunner
 
+from lintro.utils.logger_setup import LOG_FORMATS
 from lintro.utils.tool_executor import run_lint_tools_simple
 
 # Constants
@@ -45,6 +46,11 @@ def _ensure_pytest_prefix(option_fragment: str) -> str:
     is_flag=True,
     help="Include virtual environment directories in testing",
 )
+@click.option(
+    "--no-gitignore",
+    is_flag=True,
+    help="Also test files ignored by .gitignore and .git/info/exclude",
+)
 @click.option(
     "--output",
     type=click.Path(),
@@ -53,14 +59,14 @@ def _ensure_pytest_prefix(option_fragment: str) -> str:
 @click.option(
     "--output-format",
     type=click.Choice(["plain", "grid", "markdown", "html", "json", "csv", "github"]),
-    default="grid",
-    help="Output format for displaying results",
+    default=None,
+    help="Output format for displaying results [default: grid]",
 )
 @click.option(
     "--group-by",
     type=click.Choice(["file", "code", "none", "auto"]),
-    default="file",
-    help="How to group issues in the output",
+    default=None,
+    help="How to group issues in the output [default: file]",
 )
 @click.option(
     "--verbose",
@@ -134,19 +140,35 @@ def _ensure_pytest_prefix(option_fragment: str) -> str:
     is_flag=True,
     help="Enable debug output on console",
 )
+@click.option(
+    "--log-format",
+    type=click.Choice(LOG_FORMATS),
+    default="text",
+    help=(
+        "Log format on stderr; json writes one record per line, including "
+        "tool invocations, for CI log aggregation [default: text]"
+    ),
+)
 @click.option(
     "--yes",
     "-y",
     is_flag=True,
     help="Skip confirmation prompt and proceed immediately",
 )
+@click.option(
+    "--profile",
+    type=str,
+    default=None,
+    help="Named configuration profile to apply (e.g. ci, local, strict)",
+)
 def test_command(
     paths: tuple[str, ...],
     exclude: str | None,
     include_venv: bool,
+    no_gitignore: bool,
     output: str | None,
-    output_format: str,
-    group_by: str,
+    output_format: str | None,
+    group_by: str | None,
     verbose: bool,
     raw_output: bool,
     tool_options: str | None,
@@ -159,7 +181,9 @@ def test_command(
     parametrize_help: bool,
     coverage: bool,
     debug: bool,
+    log_format: str,
     yes: bool,
+    profile: str | None,
 ) -> None:
     """Run tests using pytest.
 
@@ -169,6 +193,7 @@ def test_command(
         paths: Paths to test files or directories.
         exclude: Pattern to exclude paths.
         include_venv: Whether to include virtual environment directories.
+        no_gitignore: Whether to also process git-ignored files.
         output: Output file path.
         output_format: Output format for displaying results.
         group_by: How to group issues in the output.
@@ -184,7 +209,9 @@ def test_command(
         parametrize_help: Show help for parametrized tests.
         coverage: Generate test coverage report with missing lines.
         debug: Enable debug output on console.
+        log_format: Log format on stderr, "text" or "json".
         yes: Skip confirmation prompt and proceed immediately.
+        profile: Named configuration profile to apply.
 
     Raises:
         SystemExit: Process exit with the aggregated exit code.
@@ -259,13 +286,16 @@ def test_command(
         tool_options=combined_tool_options,
         exclude=exclude,
         include_venv=include_venv,
+        gitignore=not no_gitignore,
         group_by=group_by,
         output_format=output_format,
         verbose=verbose,
         raw_output=raw_output,
         output_file=output,
         debug=debug,
+        log_format=log_format,
         yes=yes,
+        profile=profile,
     )
 
     # Exit with code only
@@ -287,6 +317,7 @@ def test(
     raw_output: bool = False,
     tool_options: str | None = None,
     yes: bool = False,
+    no_gitignore: bool = False,
 ) -> None:
     """Programmatic test function for backward compatibility.
 
@@ -301,6 +332,7 @@ def test(
         raw_output: bool: Whether to show raw tool output instead of formatted output.
         tool_options: str | None: Tool-specific options.
         yes: bool: Skip confirmation prompt and proceed immediately.
+        no_gitignore: bool: Whether to also test git-ignored files.
 
     Returns:
         None: This function does not return a value.
@@ -313,6 +345,8 @@ def test(
         args.extend(["--exclude", exclude])
     if include_venv:
         args.append("--include-venv")
+    if no_gitignore:
+        args.append("--no-gitignore")
     if output:
         args.extend(["--output", output])
     if output_format:
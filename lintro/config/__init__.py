@@ -13,17 +13,24 @@ Key components:
 - ToolConfigGenerator: CLI injection and defaults generation
 """
 
+from lintro.config.config_hierarchy import load_config_for_path
 from lintro.config.config_loader import (
     clear_config_cache,
+    get_active_profile,
     get_config,
     get_default_config,
     load_config,
+    set_active_profile,
 )
 from lintro.config.lintro_config import (
+    AIConfig,
     EnforceConfig,
     ExecutionConfig,
+    GateConfig,
+    LabelPolicy,
     LintroConfig,
     LintroToolConfig,
+    OutputConfig,
 )
 from lintro.config.tool_config_generator import (
     generate_defaults_config,
@@ -34,15 +41,22 @@ from lintro.config.tool_config_generator import (
 
 __all__ = [
     # Config dataclasses
+    "AIConfig",
     "EnforceConfig",
     "ExecutionConfig",
+    "GateConfig",
+    "LabelPolicy",
     "LintroConfig",
     "LintroToolConfig",
+    "OutputConfig",
     # Config loading
     "clear_config_cache",
+    "get_active_profile",
     "get_config",
     "get_default_config",
     "load_config",
+    "load_config_for_path",
+    "set_active_profile",
     # New tiered model functions
     "get_enforce_cli_args",
     "has_native_config",
@@ -0,0 +1,33 @@
+"""AI fix-suggestion configuration model."""
+
+from pydantic import BaseModel, ConfigDict, Field, PositiveInt
+
+
+class AIConfig(BaseModel):
+    """Settings for optional LLM fix suggestions (``lintro suggest``).
+
+    Suggestions are off unless ``enabled`` is set and an endpoint is given.
+    Issue details and surrounding code are sent to that endpoint, so only
+    point it at a service you are allowed to share the code with.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        enabled: Allow sending issues to the endpoint.
+        endpoint: Base URL of an OpenAI-compatible API (e.g.
+            ``http://localhost:11434/v1``); ``/chat/completions`` is appended.
+        model: Model name passed to the endpoint.
+        api_key_env: Environment variable holding the API key, if any.
+        context_lines: Lines of code sent before and after each issue.
+        max_issues: Maximum number of issues sent per run.
+        timeout: Request timeout in seconds.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    enabled: bool = False
+    endpoint: str | None = None
+    model: str | None = None
+    api_key_env: str = "LINTRO_AI_API_KEY"
+    context_lines: int = Field(default=10, ge=0, le=200)
+    max_issues: PositiveInt = 20
+    timeout: PositiveInt = 60
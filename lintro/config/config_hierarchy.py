@@ -0,0 +1,368 @@
+"""Hierarchical configuration discovery for monorepos.
+
+A repository may contain several .lintro-config.yaml files, one per
+subproject. The config nearest to a linted file wins, and settings merge
+upward: a nested config only needs to declare what differs from its
+ancestors.
+
+Example layout::
+
+    repo/
+        .lintro-config.yaml          # enables ruff, prettier
+        services/api/                # inherits repo config
+        crates/engine/
+            .lintro-config.yaml      # tools: {ruff: false, clippy: true}
+
+Running ``lintro check .`` from ``repo/`` runs ruff everywhere except
+``crates/engine`` and clippy only inside ``crates/engine``.
+
+Besides toggling tools, a nested config applies its tool settings (such as
+``timeout`` or ``extra_args``), its ``severity`` overrides and its
+``overrides`` section to its own subtree. A subtree that changes a tool's
+settings is run separately with its config, and the results are merged.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro.config.config_loader import (
+    LINTRO_CONFIG_FILENAMES,
+    _load_yaml_file,
+    build_config_from_data,
+    get_active_profile,
+    merge_config_data,
+)
+from lintro.config.lintro_config import LintroConfig
+
+# Directories never searched for nested config files
+NESTED_CONFIG_SKIP_DIRS: frozenset[str] = frozenset(
+    {
+        ".git",
+        ".hg",
+        ".svn",
+        ".venv",
+        "venv",
+        "node_modules",
+        "target",
+        "__pycache__",
+        "dist",
+        "build",
+    },
+)
+
+
+@dataclass
+class ToolScopePlan:
+    """Paths a tool should run on after applying nested configs.
+
+    Attributes:
+        paths: Paths to pass to the tool.
+        exclude_patterns: Extra exclude patterns for subtrees that disable
+            the tool or run it separately.
+        config: Effective config the tool is configured from, or None for
+            the run's config.
+        subtrees: Subtrees whose nested config changes the tool's settings;
+            each is run separately with its own config and paths.
+    """
+
+    paths: list[str] = field(default_factory=list)
+    exclude_patterns: list[str] = field(default_factory=list)
+    config: LintroConfig | None = None
+    subtrees: list[ToolScopePlan] = field(default_factory=list)
+
+    @property
+    def is_empty(self) -> bool:
+        """Check whether the tool has nothing to run on.
+
+        Returns:
+            bool: True if no paths remain for the tool.
+        """
+        return not self.paths
+
+
+def _config_file_in(directory: Path) -> Path | None:
+    """Return the lintro config file located directly in a directory.
+
+    Args:
+        directory: Directory to inspect.
+
+    Returns:
+        Path | None: Config file path, or None if the directory has none.
+    """
+    for filename in LINTRO_CONFIG_FILENAMES:
+        candidate = directory / filename
+        if candidate.is_file():
+            return candidate
+    return None
+
+
+def find_config_chain(start_dir: Path) -> list[Path]:
+    """Collect config files from the filesystem root down to start_dir.
+
+    Args:
+        start_dir: Directory whose effective configuration is wanted.
+
+    Returns:
+        list[Path]: Config files ordered outermost first.
+    """
+    chain: list[Path] = []
+    current = start_dir.resolve()
+    while True:
+        config_file = _config_file_in(current)
+        if config_file is not None:
+            chain.append(config_file)
+        parent = current.parent
+        if parent == current:
+            break
+        current = parent
+    chain.reverse()
+    return chain
+
+
+def load_config_for_path(path: Path | str) -> LintroConfig:
+    """Load the effective configuration for a file or directory.
+
+    Every config file between the filesystem root and ``path`` is merged,
+    with nearer files taking precedence. The active profile is applied when
+    the merged data defines it.
+
+    Args:
+        path: File or directory being linted.
+
+    Returns:
+        LintroConfig: Merged configuration. ``config_path`` points at the
+            nearest config file.
+    """
+    target = Path(path)
+    start_dir = target if target.is_dir() else target.parent
+
+    merged: dict[str, Any] = {}
+    nearest: Path | None = None
+    for config_file in find_config_chain(start_dir):
+        try:
+            merged = merge_config_data(merged, _load_yaml_file(config_file))
+            nearest = config_file
+        except (OSError, ValueError) as e:
+            logger.warning(f"Skipping unreadable config {config_file}: {e}")
+
+    profile = get_active_profile()
+    if profile not in (merged.get("profiles") or {}):
+        profile = None
+
+    return build_config_from_data(
+        merged,
+        config_path=str(nearest.resolve()) if nearest else None,
+        profile=profile,
+    )
+
+
+def load_own_config(directory: Path) -> LintroConfig | None:
+    """Load the config file of a directory without merging its ancestors.
+
+    Args:
+        directory: Directory holding a config file.
+
+    Returns:
+        LintroConfig | None: The file's own settings, or None when the
+            directory has no readable config file.
+    """
+    config_file = _config_file_in(directory)
+    if config_file is None:
+        return None
+    try:
+        data = _load_yaml_file(config_file)
+    except (OSError, ValueError) as e:
+        logger.warning(f"Skipping unreadable config {config_file}: {e}")
+        return None
+    return build_config_from_data(data, config_path=str(config_file.resolve()))
+
+
+def nested_severity(nested_dirs: list[Path]) -> list[tuple[Path, dict[str, str]]]:
+    """Collect the effective severity overrides of nested config subtrees.
+
+    Args:
+        nested_dirs: Result of find_nested_config_dirs().
+
+    Returns:
+        list[tuple[Path, dict[str, str]]]: Directory and overrides pairs,
+        deepest directories first, so the first directory containing a
+        file holds the overrides for it.
+    """
+    return [
+        (directory, dict(load_config_for_path(directory).severity))
+        for directory in sorted(nested_dirs, key=lambda d: len(d.parts), reverse=True)
+    ]
+
+
+def find_nested_config_dirs(paths: list[str]) -> list[Path]:
+    """Find directories below the given paths that hold their own config.
+
+    The input directories themselves are not included; their configuration
+    is covered by the regular upward search.
+
+    Args:
+        paths: Input paths passed to lintro.
+
+    Returns:
+        list[Path]: Resolved directories with a nested config, sorted.
+    """
+    found: set[Path] = set()
+    for raw in paths:
+        root = Path(raw).resolve()
+        if not root.is_dir():
+            continue
+        for dirpath, dirnames, _ in os.walk(root):
+            dirnames[:] = [d for d in dirnames if d not in NESTED_CONFIG_SKIP_DIRS]
+            current = Path(dirpath)
+            if current != root and _config_file_in(current) is not None:
+                found.add(current)
+    return sorted(found)
+
+
+def _to_exclude_pattern(directory: Path) -> str:
+    """Convert an absolute directory into an anchored exclude pattern.
+
+    Args:
+        directory: Absolute directory path.
+
+    Returns:
+        str: Gitignore-style pattern matching everything below directory.
+    """
+    return directory.as_posix().lstrip("/") + "/"
+
+
+def _tool_settings(config: LintroConfig, tool_name: str) -> dict[str, Any]:
+    """Return the settings a config gives a tool, apart from enabling it.
+
+    Args:
+        config: Effective configuration.
+        tool_name: Tool name.
+
+    Returns:
+        dict[str, Any]: The tool's ``tools`` entry without ``enabled``.
+    """
+    return config.get_tool_config(tool_name).model_dump(exclude={"enabled"})
+
+
+def plan_tool_scopes(
+    tool_name: str,
+    paths: list[str],
+    nested_dirs: list[Path],
+    root_enabled: bool,
+    by_default: bool = True,
+) -> ToolScopePlan:
+    """Work out where a tool should run given nested configs.
+
+    Walks each input path together with the nested config directories below
+    it. A subtree whose effective config disables the tool is excluded; a
+    subtree that enables a tool disabled further up is added as its own path.
+    A subtree that changes the tool's settings is excluded and planned as one
+    of the plan's ``subtrees``, with its own config.
+
+    Args:
+        tool_name: Name of the tool being planned.
+        paths: Input paths passed to lintro.
+        nested_dirs: Result of find_nested_config_dirs(paths).
+        root_enabled: Whether the top-level config enables the tool.
+        by_default: Whether the tool runs where config does not mention it;
+            False for opt-in tools.
+
+    Returns:
+        ToolScopePlan: Paths and extra exclude patterns for the tool. When
+            the tool only runs in subtrees with their own settings, the
+            first of them is the plan and the others its subtrees.
+    """
+    plan = ToolScopePlan()
+    configs: dict[Path, LintroConfig] = {}
+
+    def config_for(directory: Path) -> LintroConfig:
+        """Return the effective config of a directory.
+
+        Args:
+            directory: Directory to evaluate.
+
+        Returns:
+            LintroConfig: Merged configuration, loaded once per directory.
+        """
+        if directory not in configs:
+            configs[directory] = load_config_for_path(directory)
+        return configs[directory]
+
+    def children_of(directory: Path) -> list[Path]:
+        """Return nested config directories directly below a directory.
+
+        Args:
+            directory: Directory to look below.
+
+        Returns:
+            list[Path]: Nearest nested config directories.
+        """
+        below = [d for d in nested_dirs if directory in d.parents]
+        return [d for d in below if not any(o in d.parents for o in below)]
+
+    def walk(
+        directory: Path,
+        enabled: bool,
+        covered: bool,
+        target: ToolScopePlan,
+        settings: dict[str, Any],
+    ) -> None:
+        """Record scope changes for nested configs below a directory.
+
+        Args:
+            directory: Directory whose nested configs are visited.
+            enabled: Whether the tool is enabled in directory.
+            covered: Whether a path of target already reaches directory.
+            target: Plan of the run directory belongs to.
+            settings: Tool settings of that run.
+        """
+        for child in children_of(directory):
+            child_config = config_for(child)
+            child_enabled = child_config.is_tool_enabled(
+                tool_name,
+                by_default=by_default,
+            )
+            child_settings = _tool_settings(child_config, tool_name)
+            if child_enabled and child_settings != settings:
+                # The subtree runs separately with its own settings
+                if covered and enabled:
+                    target.exclude_patterns.append(_to_exclude_pattern(child))
+                subtree = ToolScopePlan(paths=[str(child)], config=child_config)
+                plan.subtrees.append(subtree)
+                walk(child, True, True, subtree, child_settings)
+            elif child_enabled == enabled:
+                walk(child, child_enabled, covered, target, settings)
+            elif covered:
+                # An enclosing path already reaches this subtree: toggle it
+                # with an exclude (or re-include) pattern.
+                prefix = "!" if child_enabled else ""
+                target.exclude_patterns.append(prefix + _to_exclude_pattern(child))
+                walk(child, child_enabled, True, target, settings)
+            else:
+                target.paths.append(str(child))
+                walk(child, child_enabled, True, target, settings)
+
+    for raw in paths:
+        if root_enabled:
+            plan.paths.append(raw)
+        resolved = Path(raw).resolve()
+        if resolved.is_dir():
+            walk(
+                resolved,
+                root_enabled,
+                root_enabled,
+                plan,
+                _tool_settings(config_for(resolved), tool_name),
+            )
+
+    if not plan.paths and plan.subtrees:
+        first, *others = plan.subtrees
+        first.subtrees = others
+        return first
+    return plan
@@ -8,23 +8,43 @@ Supports the new tiered configuration model:
 2. enforce: Cross-cutting settings (replaces 'global')
 3. defaults: Fallback config when no native config exists
 4. tools: Per-tool enable/disable and config source
+
+Named profiles under ``profiles:`` (or ``profile:``, matching the
+``[tool.lintro.profile.<name>]`` tables of pyproject.toml) are overlaid on top
+of these sections when selected with ``--profile`` or the LINTRO_PROFILE
+environment variable.
 """
 
 from __future__ import annotations
 
+import os
 import tomllib
 from pathlib import Path
 from typing import Any
 
 from loguru import logger
 
+from lintro.config.execution_config import (
+    CacheConfig,
+    ExitCodesConfig,
+    RemoteConfig,
+)
 from lintro.config.lintro_config import (
+    AIConfig,
     EnforceConfig,
     ExecutionConfig,
+    GateConfig,
+    LabelPolicy,
     LintroConfig,
     LintroToolConfig,
+    OutputConfig,
+    PathOverride,
+    ScoreConfig,
 )
 from lintro.enums.config_key import ConfigKey
+from lintro.plugins.extra_args import split_extra_args
+from lintro.utils.path_filtering import DEFAULT_PRUNE_DIRS
+from lintro.utils.version_pins import normalize_version_spec
 
 try:
     import yaml
@@ -40,6 +60,13 @@ LINTRO_CONFIG_FILENAMES = [
     "lintro-config.yml",
 ]
 
+# Environment variable selecting a profile when --profile is not given
+LINTRO_PROFILE_ENV = "LINTRO_PROFILE"
+
+
+class UnknownProfileError(ValueError):
+    """Raised when --profile or LINTRO_PROFILE names an undefined profile."""
+
 
 def _find_config_file(start_dir: Path | None = None) -> Path | None:
     """Find .lintro-config.yaml by searching upward from start_dir.
@@ -90,7 +117,24 @@ def _load_yaml_file(path: Path) -> dict[str, Any]:
     with path.open(encoding="utf-8") as f:
         content = yaml.safe_load(f)
 
-    return content if isinstance(content, dict) else {}
+    return _merge_profile_sections(content) if isinstance(content, dict) else {}
+
+
+def _merge_profile_sections(data: dict[str, Any]) -> dict[str, Any]:
+    """Fold a singular ``profile:`` section into ``profiles:``.
+
+    Args:
+        data: Raw configuration mapping.
+
+    Returns:
+        dict[str, Any]: The data with every profile under ``profiles``.
+    """
+    section = data.get("profile")
+    if not isinstance(section, dict):
+        return data
+    result = {key: value for key, value in data.items() if key != "profile"}
+    result["profiles"] = {**section, **(data.get("profiles") or {})}
+    return result
 
 
 def _load_pyproject_fallback() -> tuple[dict[str, Any], Path | None]:
@@ -167,9 +211,251 @@ def _parse_execution_config(data: dict[str, Any]) -> ExecutionConfig:
         fail_fast=data.get("fail_fast", False),
         parallel=data.get("parallel", True),
         auto_install_deps=data.get("auto_install_deps"),
+        backend=data.get("backend", "host"),
+        container_runtime=data.get("container_runtime"),
+        container_image=data.get("container_image"),
+        fail_on=data.get("fail_on"),
+        max_issues=data.get("max_issues"),
+        exit_codes=ExitCodesConfig(**(data.get("exit_codes") or {})),
+        fix_passes=data.get("fix_passes", 3),
+        fix_safety=data.get("fix_safety", "safe"),
+        dirty_worktree=data.get("dirty_worktree", "refuse"),
+        toolchain_matrix=data.get("toolchain_matrix") or {},
+        remote=_parse_remote_config(data.get("remote") or {}),
+        audit_log=data.get("audit_log") or None,
+        history=data.get("history") or None,
+        runtimes=data.get("runtimes", ".lintro/runtimes.json") or None,
+        slow_tool_seconds=data.get("slow_tool_seconds", 10),
+        cache=_parse_cache_config(data.get("cache") or {}),
+        prune_dirs=data.get("prune_dirs", list(DEFAULT_PRUNE_DIRS)),
+        gitignore=data.get("gitignore", True),
+        symlinks=data.get("symlinks", "follow-within-repo"),
+        code_blocks=data.get("code_blocks", False),
+    )
+
+
+def _parse_cache_config(data: dict[str, Any]) -> CacheConfig:
+    """Parse the result cache section.
+
+    Args:
+        data: Raw 'execution.cache' section.
+
+    Returns:
+        CacheConfig: Parsed cache settings.
+    """
+    settings = {k.replace("-", "_"): v for k, v in data.items()}
+    # Accept comma-separated strings like execution.enabled_tools
+    if isinstance(settings.get("tools"), str):
+        settings["tools"] = [
+            t.strip() for t in settings["tools"].split(",") if t.strip()
+        ]
+    return CacheConfig(**settings)
+
+
+def _parse_remote_config(data: dict[str, Any]) -> RemoteConfig:
+    """Parse the remote execution section.
+
+    Args:
+        data: Raw 'execution.remote' section.
+
+    Returns:
+        RemoteConfig: Parsed remote settings.
+    """
+    settings = {k.replace("-", "_"): v for k, v in data.items()}
+    # Accept comma-separated strings like execution.enabled_tools
+    if isinstance(settings.get("tools"), str):
+        settings["tools"] = [
+            t.strip() for t in settings["tools"].split(",") if t.strip()
+        ]
+    return RemoteConfig(**settings)
+
+
+def _parse_output_config(data: dict[str, Any]) -> OutputConfig:
+    """Parse output configuration section.
+
+    Args:
+        data: Raw 'output' section from config.
+
+    Returns:
+        OutputConfig: Parsed output configuration.
+    """
+    return OutputConfig(
+        format=data.get("format"),
+        group_by=data.get("group_by"),
+        min_severity=data.get("min_severity"),
+        effort=data.get("effort") or {},
+        sort_by=data.get("sort_by"),
+        columns=data.get("columns"),
+        table_sort_by=data.get("table_sort_by"),
+        local_time=bool(data.get("local_time", False)),
+        script=data.get("script"),
     )
 
 
+def _parse_severity_config(data: dict[str, Any]) -> dict[str, str]:
+    """Parse the severity override section.
+
+    Args:
+        data: Raw 'severity' section mapping rules to levels.
+
+    Returns:
+        dict[str, str]: Lower-cased levels keyed by rule.
+    """
+    return {
+        str(rule): value.lower() if isinstance(value, str) else value
+        for rule, value in data.items()
+    }
+
+
+def _parse_budgets_config(data: dict[str, Any]) -> dict[str, int]:
+    """Parse the rule budget section.
+
+    Args:
+        data: Raw 'budgets' section mapping rule patterns to issue counts.
+
+    Returns:
+        dict[str, int]: Budgets keyed by stripped rule pattern.
+
+    Raises:
+        ValueError: If a budget is not a non-negative whole number.
+    """
+    budgets: dict[str, int] = {}
+    for rule, limit in data.items():
+        if isinstance(limit, bool) or not isinstance(limit, int) or limit < 0:
+            raise ValueError(
+                f"Budget for '{rule}' must be a non-negative number of issues",
+            )
+        budgets[str(rule).strip()] = limit
+    return budgets
+
+
+def _parse_ai_config(data: dict[str, Any]) -> AIConfig:
+    """Parse AI fix-suggestion configuration section.
+
+    Args:
+        data: Raw 'ai' section from config.
+
+    Returns:
+        AIConfig: Parsed AI configuration.
+    """
+    return AIConfig(**{k.replace("-", "_"): v for k, v in data.items()})
+
+
+def _parse_score_config(data: dict[str, Any]) -> ScoreConfig:
+    """Parse the quality score section.
+
+    Args:
+        data: Raw 'score' section from config.
+
+    Returns:
+        ScoreConfig: Parsed score settings.
+    """
+    return ScoreConfig(**{k.replace("-", "_"): v for k, v in data.items()})
+
+
+def _parse_gates_config(data: dict[str, Any]) -> dict[str, GateConfig]:
+    """Parse the quality gate section.
+
+    Args:
+        data: Raw 'gates' section mapping gate names to settings.
+
+    Returns:
+        dict[str, GateConfig]: Parsed gates keyed by name.
+
+    Raises:
+        ValueError: If a gate is not a mapping.
+    """
+    gates: dict[str, GateConfig] = {}
+    for name, gate_data in data.items():
+        if not isinstance(gate_data, dict):
+            raise ValueError(f"Gate '{name}' must be a mapping of settings")
+        settings = {k.replace("-", "_"): v for k, v in gate_data.items()}
+        for key in ("tools", "paths", "exclude"):
+            # Accept comma-separated strings like execution.enabled_tools
+            if isinstance(settings.get(key), str):
+                settings[key] = [
+                    item.strip() for item in settings[key].split(",") if item.strip()
+                ]
+        gates[str(name)] = GateConfig(**settings)
+    return gates
+
+
+def _parse_labels_config(data: dict[str, Any]) -> dict[str, list[str]]:
+    """Parse the directory label section.
+
+    Args:
+        data: Raw 'labels' section mapping directories to labels.
+
+    Returns:
+        dict[str, list[str]]: Labels keyed by directory without trailing
+        slashes.
+    """
+    labels: dict[str, list[str]] = {}
+    for directory, value in data.items():
+        # Accept a single label or comma-separated labels
+        items = value.split(",") if isinstance(value, str) else value
+        key = str(directory).rstrip("/") or "."
+        labels[key] = [str(item).strip() for item in items if str(item).strip()]
+    return labels
+
+
+def _parse_label_policies_config(data: dict[str, Any]) -> dict[str, LabelPolicy]:
+    """Parse the label policy section.
+
+    Args:
+        data: Raw 'label_policies' section mapping labels to settings.
+
+    Returns:
+        dict[str, LabelPolicy]: Parsed policies keyed by label.
+
+    Raises:
+        ValueError: If a policy is not a mapping.
+    """
+    policies: dict[str, LabelPolicy] = {}
+    for label, policy_data in data.items():
+        if not isinstance(policy_data, dict):
+            raise ValueError(f"Label policy '{label}' must be a mapping of settings")
+        settings = {k.replace("-", "_"): v for k, v in policy_data.items()}
+        if isinstance(settings.get("tools"), str):
+            settings["tools"] = [
+                t.strip() for t in settings["tools"].split(",") if t.strip()
+            ]
+        if isinstance(settings.get("severity"), dict):
+            settings["severity"] = _parse_severity_config(settings["severity"])
+        policies[str(label)] = LabelPolicy(**settings)
+    return policies
+
+
+def _parse_overrides_config(data: list[Any]) -> list[PathOverride]:
+    """Parse the per-path override section.
+
+    Args:
+        data: Raw 'overrides' section, a list of override mappings.
+
+    Returns:
+        list[PathOverride]: Parsed overrides in config order.
+
+    Raises:
+        ValueError: If the section is not a list or an override is not a
+            mapping.
+    """
+    if not isinstance(data, list):
+        raise ValueError("'overrides' must be a list of mappings")
+    overrides: list[PathOverride] = []
+    for index, override_data in enumerate(data):
+        if not isinstance(override_data, dict):
+            raise ValueError(f"Override {index + 1} must be a mapping of settings")
+        settings = dict(override_data)
+        for key in ("paths", "disable", "ignore"):
+            # Accept comma-separated strings like execution.enabled_tools
+            if isinstance(settings.get(key), str):
+                settings[key] = [
+                    item.strip() for item in settings[key].split(",") if item.strip()
+                ]
+        overrides.append(PathOverride(**settings))
+    return overrides
+
+
 def _parse_tool_config(data: dict[str, Any]) -> LintroToolConfig:
     """Parse a single tool configuration.
 
@@ -182,7 +468,9 @@ def _parse_tool_config(data: dict[str, Any]) -> LintroToolConfig:
         LintroToolConfig: Parsed tool configuration.
 
     Raises:
-        ValueError: If auto_install is not a boolean.
+        ValueError: If auto_install is not a boolean, version is not a
+            valid version pin, image is not a string, or extra_args is not
+            a string or a list of strings.
     """
     enabled = data.get("enabled", True)
     config_source = data.get("config_source")
@@ -196,10 +484,53 @@ def _parse_tool_config(data: dict[str, Any]) -> LintroToolConfig:
             f"tools.<name>.auto_install must be a boolean, got {type_name}",
         )
 
+    version_raw = data.get("version")
+    version: str | None = None
+    if isinstance(version_raw, (str, int, float)) and not isinstance(
+        version_raw,
+        bool,
+    ):
+        version = str(version_raw).strip()
+        try:
+            normalize_version_spec(version)
+        except ValueError as e:
+            raise ValueError(f"tools.<name>.version: {e}") from e
+    elif version_raw is not None:
+        type_name = type(version_raw).__name__
+        raise ValueError(
+            f"tools.<name>.version must be a string, got {type_name}",
+        )
+
+    image = data.get("image")
+    if image is not None and not isinstance(image, str):
+        raise ValueError(
+            f"tools.<name>.image must be a string, got {type(image).__name__}",
+        )
+
+    extra_args_raw = data.get("extra_args", [])
+    if not isinstance(extra_args_raw, (str, list)):
+        type_name = type(extra_args_raw).__name__
+        raise ValueError(
+            f"tools.<name>.extra_args must be a string or a list, got {type_name}",
+        )
+    try:
+        extra_args = split_extra_args(extra_args_raw)
+    except ValueError as e:
+        raise ValueError(f"tools.<name>.extra_args: {e}") from e
+
     return LintroToolConfig(
         enabled=enabled,
         config_source=config_source,
         auto_install=auto_install,
+        version=version,
+        image=image,
+        fail_on=data.get("fail_on"),
+        max_issues=data.get("max_issues"),
+        timeout=data.get("timeout"),
+        memory_limit_mb=data.get("memory_limit_mb"),
+        niceness=data.get("niceness"),
+        daemon=data.get("daemon", False),
+        extra_args=extra_args,
     )
 
 
@@ -260,6 +591,7 @@ def _convert_pyproject_to_config(data: dict[str, Any]) -> dict[str, Any]:
         "defaults": {},
         "tools": {},
     }
+    profiles: dict[str, Any] = {}
 
     # Known tool names to separate from enforce settings
     # Hardcoded list of supported tools for reliable config parsing
@@ -287,6 +619,26 @@ def _convert_pyproject_to_config(data: dict[str, Any]) -> dict[str, Any]:
         "fail_fast",
         "parallel",
         "auto_install_deps",
+        "backend",
+        "container_runtime",
+        "container_image",
+        "fail_on",
+        "max_issues",
+        "exit_codes",
+        "fix_passes",
+        "fix_safety",
+        "dirty_worktree",
+        "toolchain_matrix",
+        "remote",
+        "audit_log",
+        "history",
+        "runtimes",
+        "slow_tool_seconds",
+        "cache",
+        "prune_dirs",
+        "gitignore",
+        "symlinks",
+        "code_blocks",
     }
 
     # Known enforce settings (formerly global)
@@ -314,21 +666,112 @@ def _convert_pyproject_to_config(data: dict[str, Any]) -> dict[str, Any]:
         elif key_lower == ConfigKey.DEFAULTS.value.lower() and isinstance(value, dict):
             # Defaults section
             result["defaults"] = value
+        elif key_lower == "output" and isinstance(value, dict):
+            # Output section ([tool.lintro.output])
+            result["output"] = {k.replace("-", "_"): v for k, v in value.items()}
+        elif key_lower == "severity" and isinstance(value, dict):
+            # Severity overrides ([tool.lintro.severity])
+            result["severity"] = value
+        elif key_lower == "budgets" and isinstance(value, dict):
+            # Rule budgets ([tool.lintro.budgets])
+            result["budgets"] = value
+        elif key_lower == "ai" and isinstance(value, dict):
+            # Fix suggestions ([tool.lintro.ai])
+            result["ai"] = value
+        elif key_lower == "gates" and isinstance(value, dict):
+            # Quality gates ([tool.lintro.gates.security])
+            result["gates"] = value
+        elif key_lower == "score" and isinstance(value, dict):
+            # Quality score ([tool.lintro.score])
+            result["score"] = value
+        elif key_lower == "labels" and isinstance(value, dict):
+            # Directory labels ([tool.lintro.labels])
+            result["labels"] = value
+        elif key_lower in ("label_policies", "label-policies") and isinstance(
+            value,
+            dict,
+        ):
+            # Label policies ([tool.lintro.label_policies.critical])
+            result["label_policies"] = value
+        elif key_lower in ("overrides", "override") and isinstance(value, list):
+            # Per-path overrides ([[tool.lintro.override]])
+            result["overrides"] = value
+        elif key_lower in ("profiles", "profile") and isinstance(value, dict):
+            # Profiles use the same flat layout ([tool.lintro.profile.ci])
+            profiles.update(
+                (name, _convert_pyproject_to_config(profile))
+                for name, profile in value.items()
+                if isinstance(profile, dict)
+            )
+
+    if profiles:
+        result["profiles"] = profiles
 
     return result
 
 
-def load_config(
+def merge_config_data(
+    base: dict[str, Any],
+    override: dict[str, Any],
+) -> dict[str, Any]:
+    """Deep-merge two raw config mappings.
+
+    Nested mappings are merged recursively; any other value (including
+    lists) in ``override`` replaces the one in ``base``.
+
+    Args:
+        base: Base config data.
+        override: Config data taking precedence over base.
+
+    Returns:
+        dict[str, Any]: Merged configuration data.
+    """
+    merged: dict[str, Any] = dict(base)
+    for key, value in override.items():
+        existing = merged.get(key)
+        if isinstance(existing, dict) and isinstance(value, dict):
+            merged[key] = merge_config_data(existing, value)
+        else:
+            merged[key] = value
+    return merged
+
+
+def apply_profile(data: dict[str, Any], profile: str) -> dict[str, Any]:
+    """Overlay a named profile onto raw config data.
+
+    Args:
+        data: Raw configuration mapping containing a 'profiles' section.
+        profile: Name of the profile to apply.
+
+    Returns:
+        dict[str, Any]: Config data with the profile merged in.
+
+    Raises:
+        UnknownProfileError: If the profile is not defined.
+        ValueError: If the profile is not a mapping.
+    """
+    profiles = data.get("profiles") or {}
+    if profile not in profiles:
+        available = ", ".join(sorted(profiles)) or "none defined"
+        raise UnknownProfileError(
+            f"Unknown profile '{profile}' (available: {available})",
+        )
+    overlay = profiles[profile]
+    if not isinstance(overlay, dict):
+        raise ValueError(f"Profile '{profile}' must be a mapping")
+    if "profiles" in overlay:
+        raise ValueError(f"Profile '{profile}' cannot define nested profiles")
+    return merge_config_data(data, overlay)
+
+
+def load_config_data(
     config_path: Path | str | None = None,
     allow_pyproject_fallback: bool = True,
-) -> LintroConfig:
-    """Load Lintro configuration.
+) -> tuple[dict[str, Any], str | None]:
+    """Load raw configuration data without parsing it.
 
-    Priority:
-    1. Explicit config_path if provided
-    2. .lintro-config.yaml found by searching upward
-    3. [tool.lintro] in pyproject.toml fallback
-    4. Default empty configuration
+    Uses the same search order as load_config. Data from pyproject.toml is
+    converted to the .lintro-config.yaml layout.
 
     Args:
         config_path: Explicit path to config file. If None, searches for
@@ -337,7 +780,8 @@ def load_config(
             if no .lintro-config.yaml is found.
 
     Returns:
-        LintroConfig: Loaded configuration.
+        tuple[dict[str, Any], str | None]: Raw configuration data and the
+            resolved path it was loaded from (None when using defaults).
     """
     data: dict[str, Any] = {}
     resolved_path: str | None = None
@@ -371,20 +815,97 @@ def load_config(
                 "Consider migrating to .lintro-config.yaml",
             )
 
-    # Parse enforce config
-    enforce_data = data.get("enforce", {})
+    return data, resolved_path
 
-    enforce_config = _parse_enforce_config(enforce_data)
-    execution_config = _parse_execution_config(data.get("execution", {}))
-    defaults = _parse_defaults(data.get("defaults", {}))
-    tools_config = _parse_tools_config(data.get("tools", {}))
+
+def load_config(
+    config_path: Path | str | None = None,
+    allow_pyproject_fallback: bool = True,
+    profile: str | None = None,
+) -> LintroConfig:
+    """Load Lintro configuration.
+
+    Priority:
+    1. Explicit config_path if provided
+    2. .lintro-config.yaml found by searching upward
+    3. [tool.lintro] in pyproject.toml fallback
+    4. Default empty configuration
+
+    Args:
+        config_path: Explicit path to config file. If None, searches for
+            .lintro-config.yaml.
+        allow_pyproject_fallback: Whether to fall back to pyproject.toml
+            if no .lintro-config.yaml is found.
+        profile: Optional profile to overlay on the loaded configuration.
+
+    Returns:
+        LintroConfig: Loaded configuration.
+    """
+    data, resolved_path = load_config_data(
+        config_path=config_path,
+        allow_pyproject_fallback=allow_pyproject_fallback,
+    )
+    return build_config_from_data(data, config_path=resolved_path, profile=profile)
+
+
+def build_config_from_data(
+    data: dict[str, Any],
+    config_path: str | None = None,
+    profile: str | None = None,
+) -> LintroConfig:
+    """Build a LintroConfig from raw .lintro-config.yaml data.
+
+    Args:
+        data: Raw configuration mapping in .lintro-config.yaml format.
+        config_path: Path of the file the data was loaded from, if any.
+        profile: Optional profile to overlay before parsing.
+
+    Returns:
+        LintroConfig: Parsed configuration.
+    """
+    if profile:
+        data = apply_profile(data, profile)
+        logger.debug(f"Applied config profile: {profile}")
+
+    raw_profiles = data.get("profiles", {}) or {}
+    profiles = {
+        str(name): overlay
+        for name, overlay in raw_profiles.items()
+        if isinstance(overlay, dict)
+    }
+    enforce_config = _parse_enforce_config(data.get("enforce", {}) or {})
+    execution_config = _parse_execution_config(data.get("execution", {}) or {})
+    defaults = _parse_defaults(data.get("defaults", {}) or {})
+    tools_config = _parse_tools_config(data.get("tools", {}) or {})
+    output_config = _parse_output_config(data.get("output", {}) or {})
+    severity = _parse_severity_config(data.get("severity", {}) or {})
+    budgets = _parse_budgets_config(data.get("budgets", {}) or {})
+    ai_config = _parse_ai_config(data.get("ai", {}) or {})
+    gates = _parse_gates_config(data.get("gates", {}) or {})
+    score = _parse_score_config(data.get("score", {}) or {})
+    labels = _parse_labels_config(data.get("labels", {}) or {})
+    label_policies = _parse_label_policies_config(
+        data.get("label_policies", {}) or {},
+    )
+    overrides = _parse_overrides_config(data.get("overrides", []) or [])
 
     return LintroConfig(
         execution=execution_config,
         enforce=enforce_config,
         defaults=defaults,
         tools=tools_config,
-        config_path=resolved_path,
+        output=output_config,
+        severity=severity,
+        budgets=budgets,
+        ai=ai_config,
+        gates=gates,
+        score=score,
+        labels=labels,
+        label_policies=label_policies,
+        overrides=overrides,
+        profiles=profiles,
+        active_profile=profile or None,
+        config_path=config_path,
     )
 
 
@@ -408,6 +929,32 @@ def get_default_config() -> LintroConfig:
 # Global singleton for loaded config
 _loaded_config: LintroConfig | None = None
 
+# Profile selected via --profile for this process
+_active_profile: str | None = None
+
+
+def set_active_profile(profile: str | None) -> None:
+    """Select the profile applied by get_config().
+
+    Clears the cached configuration so the next get_config() call
+    reloads it with the profile applied.
+
+    Args:
+        profile: Profile name, or None to fall back to LINTRO_PROFILE.
+    """
+    global _active_profile
+    _active_profile = profile or None
+    clear_config_cache()
+
+
+def get_active_profile() -> str | None:
+    """Get the profile selected for this run.
+
+    Returns:
+        str | None: Profile from --profile, else LINTRO_PROFILE, else None.
+    """
+    return _active_profile or os.environ.get(LINTRO_PROFILE_ENV) or None
+
 
 def get_config(reload: bool = False) -> LintroConfig:
     """Get the loaded configuration singleton.
@@ -421,7 +968,7 @@ def get_config(reload: bool = False) -> LintroConfig:
     global _loaded_config
 
     if _loaded_config is None or reload:
-        _loaded_config = load_config()
+        _loaded_config = load_config(profile=get_active_profile())
 
     return _loaded_config
 
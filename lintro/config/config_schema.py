@@ -0,0 +1,521 @@
+"""JSON Schema validation for Lintro configuration files.
+
+The published schema (``lintro-config.schema.json``) describes
+.lintro-config.yaml. Validation reports unknown keys (with "did you mean"
+suggestions), type errors and invalid values, plus semantic conflicts the
+schema cannot express, such as a tool listed in ``enabled_tools`` but
+disabled under ``tools``.
+
+The validator is also used for user-supplied schemas (see the frontmatter
+tool). It supports ``type``, ``properties``, ``additionalProperties``,
+``required``, ``items``, ``enum``, ``const``, ``pattern``, ``minimum``,
+``maximum``, ``minLength``, ``maxLength``, ``minItems``, ``maxItems``,
+``anyOf`` and local ``$ref``; other keywords are ignored.
+"""
+
+from __future__ import annotations
+
+import difflib
+import json
+import re
+from collections.abc import Iterable
+from dataclasses import dataclass
+from functools import lru_cache
+from pathlib import Path
+from typing import Any
+
+CONFIG_SCHEMA_PATH: Path = Path(__file__).with_name("lintro-config.schema.json")
+
+# Keys a tool entry may have in .lintro-config.yaml
+TOOL_ENTRY_KEYS: frozenset[str] = frozenset(
+    {
+        "enabled",
+        "config_source",
+        "auto_install",
+        "version",
+        "image",
+        "timeout",
+        "memory_limit_mb",
+        "niceness",
+        "daemon",
+        "extra_args",
+    },
+)
+
+_JSON_TYPES: dict[str, tuple[type, ...]] = {
+    "object": (dict,),
+    "array": (list, tuple),
+    "string": (str,),
+    "integer": (int,),
+    "number": (int, float),
+    "boolean": (bool,),
+    "null": (type(None),),
+}
+
+
+@dataclass(frozen=True)
+class ConfigProblem:
+    """A problem found while validating configuration.
+
+    Attributes:
+        path: Dotted location of the offending key (e.g. ``tools.ruff``).
+        message: Description of the problem.
+        level: ``error`` for schema violations, ``warning`` for conflicts.
+    """
+
+    path: str
+    message: str
+    level: str = "error"
+
+
+@lru_cache(maxsize=1)
+def load_config_schema() -> dict[str, Any]:
+    """Load the published configuration schema.
+
+    Returns:
+        dict[str, Any]: Parsed JSON Schema.
+    """
+    with CONFIG_SCHEMA_PATH.open(encoding="utf-8") as f:
+        schema: dict[str, Any] = json.load(f)
+    return schema
+
+
+def _join(path: str, key: str) -> str:
+    """Append a key to a dotted path.
+
+    Args:
+        path: Parent path, or empty for the root.
+        key: Key to append.
+
+    Returns:
+        str: Combined dotted path.
+    """
+    return f"{path}.{key}" if path else key
+
+
+def _suggest(key: str, candidates: Iterable[str]) -> str:
+    """Build a "did you mean" hint for a misspelled key.
+
+    Args:
+        key: Key that was not recognized.
+        candidates: Valid keys at the same location.
+
+    Returns:
+        str: Hint text, or empty string if nothing is close.
+    """
+    matches = difflib.get_close_matches(key, list(candidates), n=1, cutoff=0.6)
+    return f" (did you mean '{matches[0]}'?)" if matches else ""
+
+
+def _type_matches(value: Any, type_name: str) -> bool:
+    """Check a value against a JSON Schema type name.
+
+    Args:
+        value: Value to check.
+        type_name: JSON Schema type.
+
+    Returns:
+        bool: True if the value has the given type.
+    """
+    if isinstance(value, bool) and type_name in ("integer", "number"):
+        return False
+    return isinstance(value, _JSON_TYPES.get(type_name, ()))
+
+
+def _describe(value: Any) -> str:
+    """Return the JSON type name of a value for messages.
+
+    Args:
+        value: Value to describe.
+
+    Returns:
+        str: JSON type name.
+    """
+    for type_name in ("boolean", "integer", "number", "string", "array", "object"):
+        if _type_matches(value, type_name):
+            return type_name
+    return "null" if value is None else type(value).__name__
+
+
+def _resolve(schema: dict[str, Any], root: dict[str, Any]) -> dict[str, Any]:
+    """Resolve a local ``$ref``.
+
+    Args:
+        schema: Schema that may contain a ``$ref``.
+        root: Root schema holding ``$defs``.
+
+    Returns:
+        dict[str, Any]: Referenced schema, or the schema itself.
+    """
+    ref = schema.get("$ref")
+    if not ref:
+        return schema
+    target: Any = root
+    for part in ref.removeprefix("#/").split("/"):
+        target = target[part]
+    return _resolve(target, root)
+
+
+def _validate(
+    value: Any,
+    schema: dict[str, Any],
+    root: dict[str, Any],
+    path: str,
+) -> list[ConfigProblem]:
+    """Validate a value against a schema node.
+
+    Args:
+        value: Value to validate.
+        schema: Schema node.
+        root: Root schema, used to resolve references.
+        path: Dotted path of the value.
+
+    Returns:
+        list[ConfigProblem]: Problems found.
+    """
+    schema = _resolve(schema, root)
+    location = path or "<root>"
+
+    if "anyOf" in schema:
+        branches = [_resolve(s, root) for s in schema["anyOf"]]
+        branch_problems = [_validate(value, b, root, path) for b in branches]
+        if any(not problems for problems in branch_problems):
+            return []
+        # Report the errors of the branch whose type matches the value
+        allowed: list[str] = []
+        for branch, problems in zip(branches, branch_problems, strict=True):
+            branch_type = branch.get("type", [])
+            types = branch_type if isinstance(branch_type, list) else [branch_type]
+            if any(_type_matches(value, t) for t in types):
+                return problems
+            allowed.extend(t for t in types if t not in allowed)
+        return [
+            ConfigProblem(
+                location,
+                f"expected {' or '.join(allowed)}, got {_describe(value)}",
+            ),
+        ]
+
+    expected = schema.get("type")
+    if expected:
+        types = expected if isinstance(expected, list) else [expected]
+        if not any(_type_matches(value, t) for t in types):
+            return [
+                ConfigProblem(
+                    location,
+                    f"expected {' or '.join(types)}, got {_describe(value)}",
+                ),
+            ]
+
+    problems: list[ConfigProblem] = []
+    if "enum" in schema and value not in schema["enum"]:
+        choices = ", ".join(repr(c) for c in schema["enum"])
+        problems.append(
+            ConfigProblem(location, f"{value!r} is not one of {choices}"),
+        )
+    if "pattern" in schema and isinstance(value, str):
+        if not re.search(schema["pattern"], value):
+            problems.append(
+                ConfigProblem(
+                    location,
+                    f"{value!r} does not match pattern {schema['pattern']}",
+                ),
+            )
+    if "const" in schema and value != schema["const"]:
+        problems.append(
+            ConfigProblem(location, f"{value!r} is not {schema['const']!r}"),
+        )
+    if isinstance(value, str):
+        if "minLength" in schema and len(value) < schema["minLength"]:
+            problems.append(
+                ConfigProblem(
+                    location,
+                    f"{value!r} is shorter than {schema['minLength']} characters",
+                ),
+            )
+        if "maxLength" in schema and len(value) > schema["maxLength"]:
+            problems.append(
+                ConfigProblem(
+                    location,
+                    f"{value!r} is longer than {schema['maxLength']} characters",
+                ),
+            )
+    if isinstance(value, list | tuple):
+        if "minItems" in schema and len(value) < schema["minItems"]:
+            problems.append(
+                ConfigProblem(
+                    location,
+                    f"expected at least {schema['minItems']} item(s), "
+                    f"got {len(value)}",
+                ),
+            )
+        if "maxItems" in schema and len(value) > schema["maxItems"]:
+            problems.append(
+                ConfigProblem(
+                    location,
+                    f"expected at most {schema['maxItems']} item(s), "
+                    f"got {len(value)}",
+                ),
+            )
+    if _type_matches(value, "number"):
+        if "minimum" in schema and value < schema["minimum"]:
+            problems.append(
+                ConfigProblem(location, f"{value} is less than {schema['minimum']}"),
+            )
+        if "maximum" in schema and value > schema["maximum"]:
+            problems.append(
+                ConfigProblem(
+                    location,
+                    f"{value} is greater than {schema['maximum']}",
+                ),
+            )
+
+    if isinstance(value, dict):
+        properties: dict[str, Any] = schema.get("properties", {})
+        additional = schema.get("additionalProperties", True)
+        for key in schema.get("required", []):
+            if key not in value:
+                problems.append(
+                    ConfigProblem(location, f"missing required key '{key}'"),
+                )
+        for key, item in value.items():
+            item_path = _join(path, str(key))
+            if key in properties:
+                problems.extend(_validate(item, properties[key], root, item_path))
+            elif additional is False:
+                problems.append(
+                    ConfigProblem(
+                        item_path,
+                        f"unknown key '{key}'{_suggest(str(key), properties)}",
+                    ),
+                )
+            elif isinstance(additional, dict):
+                problems.extend(_validate(item, additional, root, item_path))
+    elif isinstance(value, list | tuple) and "items" in schema:
+        for index, item in enumerate(value):
+            problems.extend(
+                _validate(item, schema["items"], root, f"{location}[{index}]"),
+            )
+    return problems
+
+
+def validate_against_schema(
+    value: Any,
+    schema: dict[str, Any],
+) -> list[ConfigProblem]:
+    """Validate a value against a JSON Schema.
+
+    Args:
+        value: Parsed JSON or YAML data.
+        schema: JSON Schema using the supported keywords.
+
+    Returns:
+        list[ConfigProblem]: Schema violations, in document order. Problems
+        at the top level have the path ``<root>``.
+    """
+    return _validate(value, schema, schema, "")
+
+
+def validate_config_data(
+    data: dict[str, Any],
+    allow_tool_options: bool = False,
+) -> list[ConfigProblem]:
+    """Validate raw configuration data against the published schema.
+
+    Args:
+        data: Configuration in .lintro-config.yaml layout.
+        allow_tool_options: Ignore extra keys in tool entries. pyproject.toml
+            ``[tool.lintro.<tool>]`` sections also hold tool options.
+
+    Returns:
+        list[ConfigProblem]: Schema violations, in document order.
+    """
+    if allow_tool_options:
+        data = _strip_tool_options(data)
+    schema = load_config_schema()
+    return _validate(data, schema, schema, "") + _validate_version_pins(data)
+
+
+def _validate_version_pins(data: dict[str, Any]) -> list[ConfigProblem]:
+    """Check that ``tools.<name>.version`` pins are valid specifiers.
+
+    Args:
+        data: Configuration in .lintro-config.yaml layout.
+
+    Returns:
+        list[ConfigProblem]: One error per invalid pin.
+    """
+    from lintro.utils.version_pins import normalize_version_spec
+
+    problems: list[ConfigProblem] = []
+    tools = data.get("tools")
+    if not isinstance(tools, dict):
+        return problems
+    for name, entry in tools.items():
+        pin = entry.get("version") if isinstance(entry, dict) else None
+        if not isinstance(pin, str):
+            continue
+        try:
+            normalize_version_spec(pin)
+        except ValueError as e:
+            problems.append(ConfigProblem(f"tools.{name}.version", str(e)))
+    return problems
+
+
+def _strip_tool_options(data: dict[str, Any]) -> dict[str, Any]:
+    """Drop tool option keys from tool entries, including in profiles.
+
+    Args:
+        data: Configuration in .lintro-config.yaml layout.
+
+    Returns:
+        dict[str, Any]: Copy of data with only known tool entry keys.
+    """
+    result = dict(data)
+    tools = data.get("tools")
+    if isinstance(tools, dict):
+        result["tools"] = {
+            name: (
+                {k: v for k, v in entry.items() if k in TOOL_ENTRY_KEYS}
+                if isinstance(entry, dict)
+                else entry
+            )
+            for name, entry in tools.items()
+        }
+    profiles = data.get("profiles")
+    if isinstance(profiles, dict):
+        result["profiles"] = {
+            name: _strip_tool_options(overlay) if isinstance(overlay, dict) else overlay
+            for name, overlay in profiles.items()
+        }
+    return result
+
+
+def _line_length_setting(defaults: Any) -> int | None:
+    """Read a line length from a tool's defaults section.
+
+    Args:
+        defaults: Defaults mapping for one tool.
+
+    Returns:
+        int | None: Line length if set.
+    """
+    if not isinstance(defaults, dict):
+        return None
+    for key in ("line_length", "line-length"):
+        value = defaults.get(key)
+        if isinstance(value, int) and not isinstance(value, bool):
+            return value
+    return None
+
+
+def find_config_conflicts(
+    data: dict[str, Any],
+    known_tools: Iterable[str],
+    base_dir: Path | None = None,
+) -> list[ConfigProblem]:
+    """Find semantic problems the schema cannot express.
+
+    Reports unknown tool names, tools listed in ``enabled_tools`` but
+    disabled under ``tools``, ``defaults`` line lengths that
+    ``enforce.line_length`` overrides, and missing ``config_source`` files.
+
+    Args:
+        data: Configuration in .lintro-config.yaml layout.
+        known_tools: Names of registered tools.
+        base_dir: Directory config_source paths are relative to.
+
+    Returns:
+        list[ConfigProblem]: Warnings, in document order.
+    """
+    known = sorted({t.lower() for t in known_tools})
+    problems: list[ConfigProblem] = []
+
+    def check_tool_name(name: Any, path: str) -> None:
+        """Warn when a tool name is not registered.
+
+        Args:
+            name: Tool name found in the config.
+            path: Dotted location of the name.
+        """
+        if isinstance(name, str) and name.lower() not in known:
+            problems.append(
+                ConfigProblem(
+                    path,
+                    f"unknown tool '{name}'{_suggest(name.lower(), known)}",
+                    level="warning",
+                ),
+            )
+
+    execution = data.get("execution") if isinstance(data.get("execution"), dict) else {}
+    tools = data.get("tools") if isinstance(data.get("tools"), dict) else {}
+    defaults = data.get("defaults") if isinstance(data.get("defaults"), dict) else {}
+    enforce = data.get("enforce") if isinstance(data.get("enforce"), dict) else {}
+
+    enabled_tools = execution.get("enabled_tools") or []
+    if isinstance(enabled_tools, str):
+        enabled_tools = [enabled_tools]
+    for index, name in enumerate(enabled_tools):
+        check_tool_name(name, f"execution.enabled_tools[{index}]")
+    tool_order = execution.get("tool_order")
+    if isinstance(tool_order, list):
+        for index, name in enumerate(tool_order):
+            check_tool_name(name, f"execution.tool_order[{index}]")
+    cache = execution.get("cache") if isinstance(execution.get("cache"), dict) else {}
+    cache_tools = cache.get("tools") or []
+    if isinstance(cache_tools, list):
+        for index, name in enumerate(cache_tools):
+            check_tool_name(name, f"execution.cache.tools[{index}]")
+    for name in tools:
+        check_tool_name(name, f"tools.{name}")
+    for name in defaults:
+        check_tool_name(name, f"defaults.{name}")
+
+    # Enabled explicitly but switched off per tool
+    for name in enabled_tools:
+        if not isinstance(name, str):
+            continue
+        entry = tools.get(name, tools.get(name.lower()))
+        enabled = entry.get("enabled", True) if isinstance(entry, dict) else entry
+        if enabled is False:
+            problems.append(
+                ConfigProblem(
+                    f"tools.{name}",
+                    f"'{name}' is listed in execution.enabled_tools but "
+                    "disabled here, so it will not run",
+                    level="warning",
+                ),
+            )
+
+    # Tool defaults shadowed by the enforced line length
+    enforced = enforce.get("line_length")
+    if isinstance(enforced, int):
+        for name, tool_defaults in defaults.items():
+            value = _line_length_setting(tool_defaults)
+            if value is not None and value != enforced:
+                problems.append(
+                    ConfigProblem(
+                        f"defaults.{name}",
+                        f"line length {value} is overridden by "
+                        f"enforce.line_length={enforced}",
+                        level="warning",
+                    ),
+                )
+
+    # Explicit native config files that do not exist
+    for name, entry in tools.items():
+        source = entry.get("config_source") if isinstance(entry, dict) else None
+        if isinstance(source, str):
+            source_path = Path(source)
+            if base_dir is not None and not source_path.is_absolute():
+                source_path = base_dir / source_path
+            if not source_path.exists():
+                problems.append(
+                    ConfigProblem(
+                        f"tools.{name}.config_source",
+                        f"file not found: {source}",
+                        level="warning",
+                    ),
+                )
+
+    return problems
@@ -1,8 +1,12 @@
 """Execution configuration model."""
 
 import os
+import posixpath
+from typing import Literal
 
-from pydantic import BaseModel, ConfigDict, Field
+from pydantic import BaseModel, ConfigDict, Field, model_validator
+
+from lintro.utils.path_filtering import DEFAULT_PRUNE_DIRS
 
 
 def _get_default_max_workers() -> int:
@@ -15,6 +19,150 @@ def _get_default_max_workers() -> int:
     return max(1, min(cpu_count, 32))
 
 
+class ExitCodesConfig(BaseModel):
+    """Exit code for each run outcome.
+
+    When several outcomes apply, the first in this order wins: config_error,
+    tool_error, tool_missing, issues, clean.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        clean: No failing issues.
+        issues: Issues found (or left after fixing) beyond the failure policy.
+        tool_error: A tool crashed or could not run, including a missing
+            container runtime or an unmet version pin.
+        tool_missing: A selected tool is not installed and was skipped.
+            Skipping is not a failure, so this is 0 by default.
+        config_error: Invalid tool selection or configuration.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    clean: int = Field(default=0, ge=0, le=255)
+    issues: int = Field(default=1, ge=0, le=255)
+    tool_error: int = Field(default=1, ge=0, le=255)
+    tool_missing: int = Field(default=0, ge=0, le=255)
+    config_error: int = Field(default=1, ge=0, le=255)
+
+
+# Top-level remote directories that may hold the lintro-owned sync directories
+_SHALLOW_WORKDIRS: frozenset[str] = frozenset({"/tmp", "/var/tmp"})  # nosec B108
+# Remote directories holding home directories
+_HOME_ROOTS: frozenset[str] = frozenset({"home", "Users"})
+
+
+def _unsafe_workdir(workdir: str) -> str | None:
+    """Explain why a remote workdir must not be used.
+
+    Args:
+        workdir: Configured remote directory.
+
+    Returns:
+        str | None: Reason the directory is unsafe, or None if it is safe.
+    """
+    if not workdir.startswith("/"):
+        return "must be an absolute path"
+    normalized = posixpath.normpath(workdir)
+    parts = [p for p in normalized.split("/") if p]
+    if not parts:
+        return "must not be the root directory"
+    if normalized == "/root" or (parts[0] in _HOME_ROOTS and len(parts) <= 2):
+        return "must not be a home directory"
+    if len(parts) == 1 and normalized not in _SHALLOW_WORKDIRS:
+        return "must be at least two levels deep"
+    return None
+
+
+class RemoteConfig(BaseModel):
+    """Remote host that selected tools run on over SSH.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        host: SSH destination (``user@host`` or a ``~/.ssh/config`` alias).
+        port: SSH port. None uses the SSH default.
+        workdir: Absolute remote directory under which Lintro creates its
+            own sync directory, named after the project and a hash of its
+            local path. None uses ``/tmp/lintro``. ``/``, home directories
+            and other top-level directories are rejected.
+        tools: Tools that run on the remote host; all others run locally.
+        sync: How files reach the host. ``rsync`` copies changed files,
+            ``stream`` sends a tar archive over SSH, and ``auto`` uses rsync
+            when it is installed.
+        ssh_options: Extra ``ssh`` arguments (e.g. ``["-i", "~/.ssh/ci"]``).
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    host: str | None = None
+    port: int | None = Field(default=None, ge=1, le=65535)
+    workdir: str | None = None
+    tools: list[str] = Field(default_factory=list)
+    sync: Literal["auto", "rsync", "stream"] = "auto"
+    ssh_options: list[str] = Field(default_factory=list)
+
+    @model_validator(mode="after")
+    def _check_host(self) -> "RemoteConfig":
+        """Require a host and an absolute workdir for remote tools.
+
+        Returns:
+            RemoteConfig: The validated config.
+
+        Raises:
+            ValueError: If tools are listed without a host, or the workdir
+                is relative, the root, a home or another top-level directory.
+        """
+        if self.tools and not self.host:
+            raise ValueError("execution.remote.tools requires execution.remote.host")
+        if self.workdir is not None:
+            reason = _unsafe_workdir(self.workdir)
+            if reason:
+                raise ValueError(f"execution.remote.workdir {reason}")
+        return self
+
+
+class CacheConfig(BaseModel):
+    """Per-file result cache, optionally shared through a remote store.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        tools: Tools whose results are cached. Only list tools whose
+            findings in a file depend on that file alone.
+        remote: ``s3://``, ``gs://``, ``http://`` or ``https://`` URL of the
+            store shared by CI jobs. None keeps the cache local.
+        read: Whether cached results are used.
+        write: Whether new results are stored.
+        token_env: Environment variable holding the bearer token sent to
+            HTTP stores.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    tools: list[str] = Field(default_factory=list)
+    remote: str | None = None
+    read: bool = True
+    write: bool = True
+    token_env: str = "LINTRO_CACHE_TOKEN"
+
+    @model_validator(mode="after")
+    def _check_remote(self) -> "CacheConfig":
+        """Require a supported URL scheme for the remote store.
+
+        Returns:
+            CacheConfig: The validated config.
+
+        Raises:
+            ValueError: If the remote URL has an unsupported scheme.
+        """
+        if self.remote is not None:
+            scheme = self.remote.partition("://")[0].lower()
+            if "://" not in self.remote or scheme not in {"s3", "gs", "http", "https"}:
+                raise ValueError(
+                    "execution.cache.remote must be an s3://, gs://, http:// "
+                    "or https:// URL",
+                )
+        return self
+
+
 class ExecutionConfig(BaseModel):
     """Execution control settings.
 
@@ -25,12 +173,58 @@ class ExecutionConfig(BaseModel):
             - "priority": Use default priority (formatters before linters)
             - "alphabetical": Alphabetical order
             - list[str]: Custom order as explicit list
-        fail_fast: Stop on first tool failure.
+        fail_fast: Stop starting tools once a check run is known to fail,
+            as with ``--fail-fast``.
         parallel: Run tools in parallel where possible.
         max_workers: Maximum number of parallel workers (default: CPU count).
         auto_install_deps: Auto-install Node.js dependencies if node_modules
             is missing. None means unset (falls back to container detection),
             True/False explicitly enables/disables.
+        backend: Where external tools run. ``host`` uses installed binaries;
+            ``container`` runs each tool in a pinned container image.
+        container_runtime: Container runtime for the container backend
+            (``docker`` or ``podman``). None picks whichever is installed.
+        container_image: Default image for the container backend. None uses
+            the Lintro tools image matching this release.
+        fail_on: Lowest severity that fails a check run; ``never`` reports
+            issues without failing. None means ``info`` (every issue fails).
+        max_issues: Issues at or above ``fail_on`` tolerated before a check
+            run fails. None means zero.
+        exit_codes: Exit code for each run outcome.
+        fix_passes: Maximum fix passes when several fixers run in one
+            ``lintro format``; 1 runs each fixer once without coordination.
+        fix_safety: Fixes ``lintro format`` applies: ``safe`` ones only, or
+            ``all`` including unsafe fixes that may change behavior (ruff
+            unsafe fixes, Clippy maybe-incorrect suggestions, Biome and
+            RuboCop unsafe corrections).
+        dirty_worktree: What ``lintro format`` does when the paths it fixes
+            have uncommitted changes: ``refuse`` to run, ``allow`` fixing
+            them, or ``stash`` them and re-apply them after the fixes.
+        toolchain_matrix: Versions per toolchain (``python``, ``rust``) that
+            version-sensitive tools run on with ``--toolchain-matrix``.
+        remote: Remote host for tools that only run on another OS or
+            architecture.
+        audit_log: JSON Lines file each ``lintro format`` run is recorded
+            in. None (the default) disables the audit log; while it is on,
+            fixers run one at a time.
+        history: SQLite file each ``lintro check`` run records its issue
+            counts in, read by ``lintro history``. None disables it.
+        runtimes: JSON file each ``lintro check`` run records its tools'
+            runtimes in, used to start quick tools first. None disables
+            runtime scheduling.
+        slow_tool_seconds: Recorded runtime from which a tool counts as
+            slow and runs after the quick ones.
+        cache: Per-file result cache of selected tools.
+        prune_dirs: Directory names (fnmatch patterns) file discovery never
+            descends into, such as ``node_modules`` and ``target``. Paths
+            passed on the command line are always searched.
+        gitignore: Whether file discovery skips files ignored by the
+            ``.gitignore`` files and ``.git/info/exclude`` of a git work tree.
+        symlinks: How file discovery treats symbolic links: ``skip`` them,
+            ``follow`` them, or ``follow-within-repo`` only when they point
+            inside the work tree (the default).
+        code_blocks: Whether ``lintro check`` also checks the fenced code
+            blocks of Markdown files, as with ``--code-blocks``.
     """
 
     model_config = ConfigDict(frozen=False, extra="forbid")
@@ -41,3 +235,25 @@ class ExecutionConfig(BaseModel):
     parallel: bool = True
     max_workers: int = Field(default_factory=_get_default_max_workers, ge=1, le=32)
     auto_install_deps: bool | None = None
+    backend: Literal["host", "container"] = "host"
+    container_runtime: Literal["docker", "podman"] | None = None
+    container_image: str | None = None
+    fail_on: Literal["error", "warning", "info", "never"] | None = None
+    max_issues: int | None = Field(default=None, ge=0)
+    exit_codes: ExitCodesConfig = Field(default_factory=ExitCodesConfig)
+    fix_passes: int = Field(default=3, ge=1, le=10)
+    fix_safety: Literal["safe", "all"] = "safe"
+    dirty_worktree: Literal["refuse", "allow", "stash"] = "refuse"
+    toolchain_matrix: dict[Literal["python", "rust"], list[str]] = Field(
+        default_factory=dict,
+    )
+    remote: RemoteConfig = Field(default_factory=RemoteConfig)
+    audit_log: str | None = None
+    history: str | None = None
+    runtimes: str | None = ".lintro/runtimes.json"
+    slow_tool_seconds: float = Field(default=10, gt=0)
+    cache: CacheConfig = Field(default_factory=CacheConfig)
+    prune_dirs: list[str] = Field(default_factory=lambda: list(DEFAULT_PRUNE_DIRS))
+    gitignore: bool = True
+    symlinks: Literal["skip", "follow", "follow-within-repo"] = "follow-within-repo"
+    code_blocks: bool = False
@@ -0,0 +1,41 @@
+"""Quality gate configuration model."""
+
+from typing import Literal
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class GateConfig(BaseModel):
+    """A named quality gate, run with ``lintro gate <name>``.
+
+    A gate is a tool subset with its own failure thresholds and output, so
+    several CI jobs can share one install and one config file.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        description: Short description shown by ``lintro gate --list``.
+        tools: Tools the gate runs.
+        paths: Paths to check when none are given on the command line.
+        exclude: Extra exclude patterns for this gate.
+        fail_on: Lowest issue severity that fails the gate. None uses
+            execution.fail_on.
+        max_issues: Failing issues tolerated before the gate fails. None uses
+            execution.max_issues.
+        output_format: Output format for this gate. None uses output.format.
+        output: File path the gate's results are written to, if any.
+        summary: Execution summary style, ``table`` or ``prose``.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    description: str | None = None
+    tools: list[str] = Field(min_length=1)
+    paths: list[str] = Field(default_factory=list)
+    exclude: list[str] = Field(default_factory=list)
+    fail_on: Literal["error", "warning", "info", "never"] | None = None
+    max_issues: int | None = Field(default=None, ge=0)
+    output_format: (
+        Literal["plain", "grid", "markdown", "html", "json", "csv", "github"] | None
+    ) = None
+    output: str | None = None
+    summary: Literal["table", "prose"] = "table"
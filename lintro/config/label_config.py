@@ -0,0 +1,33 @@
+"""Directory label policy configuration model."""
+
+from typing import Literal
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class LabelPolicy(BaseModel):
+    """What applies to directories tagged with a label.
+
+    Directories are tagged in the top-level ``labels`` section; a policy in
+    ``label_policies`` makes checks stricter for every file below them.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        tools: Tools that run on labelled directories even when disabled
+            for the rest of the repository.
+        severity: Severity overrides keyed by ``tool:code`` or rule code,
+            applied to issues in labelled directories on top of
+            ``severity``.
+        fail_on: Lowest failing severity for issues in labelled
+            directories. It only ever tightens the run's threshold.
+        max_issues: Failing issues tolerated in labelled directories.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    tools: list[str] = Field(default_factory=list)
+    severity: dict[str, Literal["error", "warning", "info"]] = Field(
+        default_factory=dict,
+    )
+    fail_on: Literal["error", "warning", "info"] | None = None
+    max_issues: int | None = Field(default=None, ge=0)
@@ -1,14 +1,31 @@
 """Main Lintro configuration model."""
 
-from typing import Any
+from typing import Any, Literal
 
-from pydantic import BaseModel, ConfigDict, Field
+from pydantic import BaseModel, ConfigDict, Field, NonNegativeInt
 
+from lintro.config.ai_config import AIConfig
 from lintro.config.enforce_config import EnforceConfig
 from lintro.config.execution_config import ExecutionConfig
+from lintro.config.gate_config import GateConfig
+from lintro.config.label_config import LabelPolicy
+from lintro.config.output_config import OutputConfig
+from lintro.config.override_config import PathOverride
+from lintro.config.score_config import ScoreConfig
 from lintro.config.tool_config import LintroToolConfig
 
-__all__ = ["EnforceConfig", "ExecutionConfig", "LintroConfig", "LintroToolConfig"]
+__all__ = [
+    "AIConfig",
+    "EnforceConfig",
+    "ExecutionConfig",
+    "GateConfig",
+    "LabelPolicy",
+    "LintroConfig",
+    "LintroToolConfig",
+    "OutputConfig",
+    "PathOverride",
+    "ScoreConfig",
+]
 
 
 class LintroConfig(BaseModel):
@@ -22,12 +39,36 @@ class LintroConfig(BaseModel):
     3. defaults: Fallback config when no native config exists
     4. tools: Per-tool enable/disable and config source
 
+    Named profiles (``profiles:``) overlay any of these sections plus
+    ``output``, ``severity``, ``budgets``, ``ai``, ``gates``, ``score``,
+    ``labels``, ``label_policies`` and ``overrides`` and are selected with
+    ``--profile``.
+
     Attributes:
         model_config: Pydantic model configuration.
         execution: Execution control settings.
         enforce: Cross-cutting settings enforced via CLI flags.
         defaults: Fallback configs for tools without native configs.
         tools: Per-tool configuration, keyed by tool name.
+        output: Default output format, grouping and severity threshold.
+        severity: Severity overrides keyed by ``tool:code`` or rule code
+            (e.g. ``E501`` or ``clippy::too_many_arguments``). They replace
+            the tool's native severity everywhere, including min_severity
+            filtering.
+        budgets: Issue budgets keyed by ``tool:rule`` pattern (e.g.
+            ``clippy::todo`` or ``ruff:T201``). Issues of a budgeted rule
+            fail a check run only when there are more than its budget.
+        ai: Optional LLM fix-suggestion settings, off by default.
+        gates: Named quality gates run with ``lintro gate <name>``.
+        score: Weights, grades and thresholds of ``lintro score``.
+        labels: Labels per directory, e.g. ``{"services/payments":
+            ["critical"]}``. Directories are relative to the config file.
+        label_policies: Tools, severity overrides and thresholds applied
+            to labelled directories, keyed by label.
+        overrides: Tools disabled and rules ignored for the files matching
+            given paths, e.g. test or vendored code.
+        profiles: Raw profile overlays, keyed by profile name.
+        active_profile: Name of the profile applied to this config, if any.
         config_path: Path to the config file (set by loader).
     """
 
@@ -37,6 +78,19 @@ class LintroConfig(BaseModel):
     enforce: EnforceConfig = Field(default_factory=EnforceConfig)
     defaults: dict[str, dict[str, Any]] = Field(default_factory=dict)
     tools: dict[str, LintroToolConfig] = Field(default_factory=dict)
+    output: OutputConfig = Field(default_factory=OutputConfig)
+    severity: dict[str, Literal["error", "warning", "info"]] = Field(
+        default_factory=dict,
+    )
+    budgets: dict[str, NonNegativeInt] = Field(default_factory=dict)
+    ai: AIConfig = Field(default_factory=AIConfig)
+    gates: dict[str, GateConfig] = Field(default_factory=dict)
+    score: ScoreConfig = Field(default_factory=ScoreConfig)
+    labels: dict[str, list[str]] = Field(default_factory=dict)
+    label_policies: dict[str, LabelPolicy] = Field(default_factory=dict)
+    overrides: list[PathOverride] = Field(default_factory=list)
+    profiles: dict[str, dict[str, Any]] = Field(default_factory=dict)
+    active_profile: str | None = None
     config_path: str | None = None
 
     def get_tool_config(self, tool_name: str) -> LintroToolConfig:
@@ -51,7 +105,7 @@ class LintroConfig(BaseModel):
         """
         return self.tools.get(tool_name.lower(), LintroToolConfig())
 
-    def is_tool_enabled(self, tool_name: str) -> bool:
+    def is_tool_enabled(self, tool_name: str, by_default: bool = True) -> bool:
         """Check if a tool is enabled.
 
         A tool is enabled if:
@@ -59,8 +113,12 @@ class LintroConfig(BaseModel):
         2. tool_name is in execution.enabled_tools, AND
         3. The tool's config has enabled=True (default)
 
+        Opt-in tools (``by_default=False``) must also be listed in
+        execution.enabled_tools or under ``tools``.
+
         Args:
             tool_name: Name of the tool.
+            by_default: Whether the tool runs when config does not mention it.
 
         Returns:
             bool: True if tool should run.
@@ -68,10 +126,15 @@ class LintroConfig(BaseModel):
         tool_lower = tool_name.lower()
 
         # Check execution.enabled_tools filter
+        listed = False
         if self.execution.enabled_tools:
             enabled_lower = [t.lower() for t in self.execution.enabled_tools]
             if tool_lower not in enabled_lower:
                 return False
+            listed = True
+
+        if not by_default and not listed and tool_lower not in self.tools:
+            return False
 
         # Check tool-specific enabled flag
         tool_config = self.get_tool_config(tool_lower)
@@ -0,0 +1,116 @@
+"""Repository list model for ``lintro org run``."""
+
+from __future__ import annotations
+
+import re
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field, field_validator, model_validator
+
+DEFAULT_ORG_WORKDIR: str = ".lintro/org"
+
+_NAME_RE = re.compile(r"^[A-Za-z0-9._-]+$")
+
+
+def repo_name_from_url(url: str) -> str:
+    """Derive a repository name from its clone URL.
+
+    Args:
+        url: Clone URL or local path.
+
+    Returns:
+        str: Last path component without ``.git``.
+    """
+    name = re.split(r"[/:]", url.rstrip("/"))[-1]
+    return name.removesuffix(".git")
+
+
+class OrgRepo(BaseModel):
+    """One repository audited by ``lintro org run``.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        url: Clone URL.
+        name: Name used for the checkout and in the merged report; defaults
+            to the last component of the URL.
+        ref: Branch or tag to check; defaults to the remote's default branch.
+        gate: Gate to run in this repository instead of the list's gate.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    url: str = Field(min_length=1)
+    name: str | None = None
+    ref: str | None = None
+    gate: str | None = None
+
+    @model_validator(mode="after")
+    def _default_name(self) -> OrgRepo:
+        """Derive the name from the URL and check it is a safe path.
+
+        Returns:
+            OrgRepo: The validated repository.
+
+        Raises:
+            ValueError: If the name is not a plain directory name.
+        """
+        if not self.name:
+            self.name = repo_name_from_url(self.url)
+        if not _NAME_RE.match(self.name) or self.name in (".", ".."):
+            raise ValueError(
+                f"repository name '{self.name}' may only contain letters, digits, "
+                "'.', '_' and '-'",
+            )
+        return self
+
+
+class OrgConfig(BaseModel):
+    """Repositories audited by ``lintro org run``, read from ``repos.yaml``.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        gate: Gate run in every repository without its own gate.
+        workdir: Directory holding the shallow checkouts and their reports.
+        repos: Repositories to audit.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    gate: str | None = None
+    workdir: str = DEFAULT_ORG_WORKDIR
+    repos: list[OrgRepo] = Field(min_length=1)
+
+    @field_validator("repos", mode="before")
+    @classmethod
+    def _expand_urls(cls, value: Any) -> Any:
+        """Accept plain URLs as shorthand for ``{url: ...}``.
+
+        Args:
+            value: Raw ``repos`` list.
+
+        Returns:
+            Any: List with every plain URL expanded.
+        """
+        if isinstance(value, list):
+            return [{"url": item} if isinstance(item, str) else item for item in value]
+        return value
+
+    @model_validator(mode="after")
+    def _unique_names(self) -> OrgConfig:
+        """Reject repositories that would share a checkout.
+
+        Returns:
+            OrgConfig: The validated list.
+
+        Raises:
+            ValueError: If two repositories have the same name.
+        """
+        seen: set[str] = set()
+        for repo in self.repos:
+            if repo.name in seen:
+                raise ValueError(
+                    f"repository name '{repo.name}' is used twice; set 'name' "
+                    "to tell them apart",
+                )
+            seen.add(str(repo.name))
+        return self
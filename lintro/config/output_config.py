@@ -0,0 +1,66 @@
+"""Output configuration model."""
+
+from typing import Literal
+
+from pydantic import BaseModel, ConfigDict, Field, NonNegativeInt
+
+
+class OutputConfig(BaseModel):
+    """Default output settings.
+
+    Each value is used only when the matching CLI flag is not given, which
+    lets profiles switch between e.g. grid output locally and GitHub
+    annotations in CI.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        format: Output format used when --output-format is omitted.
+        group_by: Issue grouping used when --group-by is omitted.
+        min_severity: Issues below this severity are dropped from results
+            and do not count towards the exit code. None keeps everything.
+        effort: Estimated minutes to fix one issue, keyed by ``tool:code``,
+            ``code``, tool name or severity. Overrides the built-in estimates.
+        sort_by: Order of issues in the HTML and Markdown run reports.
+            ``effort`` lists files with the most estimated effort first.
+        columns: Issue table columns, in order, used when --columns is
+            omitted. None shows every column.
+        table_sort_by: Order of the issue table rows used when --sort-by
+            is omitted. None keeps the order the tools reported.
+        local_time: Show times in console tables and HTML reports in the
+            local timezone. JSON reports and the audit log stay in UTC.
+        script: Lua script, relative to the config file, whose ``process``
+            function transforms or drops each issue before filtering and
+            formatting (see issue_script).
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    format: (
+        Literal["plain", "grid", "markdown", "html", "json", "csv", "github"] | None
+    ) = None
+    group_by: (
+        Literal[
+            "file",
+            "code",
+            "none",
+            "auto",
+            "rule",
+            "severity",
+            "dir",
+            "tool",
+            "owner",
+        ]
+        | None
+    ) = None
+    min_severity: Literal["error", "warning", "info"] | None = None
+    effort: dict[str, NonNegativeInt] = Field(default_factory=dict)
+    sort_by: Literal["tool", "effort"] | None = None
+    columns: (
+        list[
+            Literal["file", "line", "column", "code", "severity", "fixable", "message"]
+        ]
+        | None
+    ) = Field(default=None, min_length=1)
+    table_sort_by: Literal["file", "line", "code", "severity"] | None = None
+    local_time: bool = False
+    script: str | None = None
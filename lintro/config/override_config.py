@@ -0,0 +1,25 @@
+"""Per-path override configuration model."""
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class PathOverride(BaseModel):
+    """Tools and rules relaxed for the files matching some paths.
+
+    Listed under the top-level ``overrides`` section, so test, generated or
+    vendored code can follow looser rules in the same run as the rest of
+    the repository.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        paths: Gitignore-style patterns, relative to the config file.
+        disable: Tools that do not run on the matching files.
+        ignore: Rules, bare or ``tool:code``, whose issues in the matching
+            files are dropped.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    paths: list[str] = Field(min_length=1)
+    disable: list[str] = Field(default_factory=list)
+    ignore: list[str] = Field(default_factory=list)
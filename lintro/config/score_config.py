@@ -0,0 +1,43 @@
+"""Quality score configuration model."""
+
+from typing import Literal
+
+from pydantic import BaseModel, ConfigDict, Field, NonNegativeFloat, PositiveFloat
+
+
+class ScoreConfig(BaseModel):
+    """Settings of the quality score reported by ``lintro score``.
+
+    Every issue costs penalty points: its severity weight times the weight
+    of its rule's category. The penalty per thousand lines of code sets the
+    score from 0 to 100, which maps to a letter grade. Values given here
+    replace the built-in ones key by key.
+
+    Attributes:
+        model_config: Pydantic model configuration.
+        severity_weights: Penalty points per issue, keyed by severity.
+        categories: ``tool:rule`` patterns per rule category, e.g.
+            ``{"security": ["bandit:*", "ruff:S*"]}``. Replace the built-in
+            patterns of the same category.
+        category_weights: Multiplier per category. Rules in no category
+            weigh 1.
+        half_density: Penalty points per thousand lines that score 50.
+        grades: Lowest score of each letter grade, e.g. ``{"A": 90}``. Given
+            grades replace all built-in ones; lower scores get ``F``.
+        fail_under: Score below which ``lintro score`` fails. None never
+            fails on the score.
+        min_grade: Lowest grade ``lintro score`` passes with. None never
+            fails on the grade.
+    """
+
+    model_config = ConfigDict(frozen=False, extra="forbid")
+
+    severity_weights: dict[Literal["error", "warning", "info"], NonNegativeFloat] = (
+        Field(default_factory=dict)
+    )
+    categories: dict[str, list[str]] = Field(default_factory=dict)
+    category_weights: dict[str, NonNegativeFloat] = Field(default_factory=dict)
+    half_density: PositiveFloat | None = None
+    grades: dict[str, float] = Field(default_factory=dict)
+    fail_under: float | None = Field(default=None, ge=0, le=100)
+    min_grade: str | None = None
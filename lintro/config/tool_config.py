@@ -1,6 +1,8 @@
 """Tool configuration model."""
 
-from pydantic import BaseModel, ConfigDict
+from typing import Literal
+
+from pydantic import BaseModel, ConfigDict, Field, PositiveInt
 
 
 class LintroToolConfig(BaseModel):
@@ -17,6 +19,26 @@ class LintroToolConfig(BaseModel):
             If not set, tool uses its own config discovery.
         auto_install: Per-tool auto-install override.
             None means inherit from global setting.
+        version: Optional version pin (e.g. ``>=0.6.0`` or ``0.14.2``).
+            A bare version requires that exact release.
+        image: Container image for this tool when ``execution.backend`` is
+            ``container``. None uses ``execution.container_image``.
+        fail_on: Lowest severity of this tool's issues that fails a check
+            run. None uses ``execution.fail_on``.
+        max_issues: Issues from this tool tolerated before the run fails;
+            tolerated issues do not count towards ``execution.max_issues``.
+            None counts them towards the run-wide limit.
+        timeout: Seconds the tool may run before it is stopped and reported
+            as timed out. Overrides the tool's ``timeout`` option unless
+            --tool-options sets it. None keeps the tool's default.
+        memory_limit_mb: Largest data segment (heap) of the tool's
+            processes in MiB (Linux). None sets no limit.
+        niceness: Added to the nice value of the tool's processes (0-19) so
+            it yields the CPU to other work. None keeps lintro's priority.
+        daemon: Run the tool through its daemon client (e.g. ``eslint_d``)
+            so that its server is started once and reused.
+        extra_args: Arguments added to the tool's command line after its
+            executable and subcommands (see lintro.plugins.extra_args).
     """
 
     model_config = ConfigDict(frozen=False, extra="forbid")
@@ -24,3 +46,12 @@ class LintroToolConfig(BaseModel):
     enabled: bool = True
     config_source: str | None = None
     auto_install: bool | None = None
+    version: str | None = None
+    image: str | None = None
+    fail_on: Literal["error", "warning", "info", "never"] | None = None
+    max_issues: int | None = Field(default=None, ge=0)
+    timeout: PositiveInt | None = None
+    memory_limit_mb: PositiveInt | None = None
+    niceness: int | None = Field(default=None, ge=0, le=19)
+    daemon: bool = False
+    extra_args: list[str] = Field(default_factory=list)
@@ -33,3 +33,32 @@ STANDARD_COLUMNS: list[DisplayColumn] = [
     DisplayColumn.FIXABLE,
     DisplayColumn.MESSAGE,
 ]
+
+
+def parse_columns(value: str | list[str]) -> list[DisplayColumn]:
+    """Parse a column selection such as ``file,line,code``.
+
+    Names are case-insensitive and keep the order given.
+
+    Args:
+        value: Comma-separated column names, or a list of names.
+
+    Returns:
+        list[DisplayColumn]: Selected columns.
+
+    Raises:
+        ValueError: If a name is not a column or no column is selected.
+    """
+    names = value.split(",") if isinstance(value, str) else value
+    by_name = {col.value.lower(): col for col in DisplayColumn}
+    columns: list[DisplayColumn] = []
+    for name in (n.strip().lower() for n in names):
+        if not name:
+            continue
+        if name not in by_name:
+            supported = ", ".join(by_name)
+            raise ValueError(f"Unknown column: {name}. Supported: {supported}")
+        columns.append(by_name[name])
+    if not columns:
+        raise ValueError("At least one column must be selected")
+    return columns
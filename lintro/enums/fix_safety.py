@@ -0,0 +1,49 @@
+"""Fix safety enum definitions.
+
+Tools rate their fixes differently: ruff marks each fix ``safe``,
+``unsafe`` or ``display-only``, rustc marks Clippy suggestions
+``MachineApplicable``, ``MaybeIncorrect`` or ``HasPlaceholders``. This module
+folds them into safe fixes, which keep the code's behavior, and unsafe ones,
+which may change it and are only applied with ``--fix-safety all``.
+"""
+
+from __future__ import annotations
+
+from enum import StrEnum, auto
+
+
+class FixSafety(StrEnum):
+    """Whether applying a fix keeps the code's behavior."""
+
+    SAFE = auto()
+    UNSAFE = auto()
+
+
+class FixSafetyPolicy(StrEnum):
+    """Which fixes ``lintro format`` applies."""
+
+    SAFE = auto()
+    ALL = auto()
+
+
+# Native applicability values (lower-cased) that mark a fix as safe
+_SAFE_APPLICABILITY: frozenset[str] = frozenset({"safe", "machineapplicable"})
+
+
+def normalize_fix_safety(value: str | FixSafety) -> FixSafety:
+    """Normalize a native fix applicability to a FixSafety.
+
+    Anything not known to be safe, including ``display-only`` and
+    ``HasPlaceholders``, is unsafe.
+
+    Args:
+        value: str or FixSafety to normalize.
+
+    Returns:
+        FixSafety: Normalized enum value.
+    """
+    if isinstance(value, FixSafety):
+        return value
+    if value.lower() in _SAFE_APPLICABILITY:
+        return FixSafety.SAFE
+    return FixSafety.UNSAFE
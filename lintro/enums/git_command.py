@@ -11,6 +11,13 @@ from lintro.enums.hyphenated_str_enum import HyphenatedStrEnum
 class GitCommand(HyphenatedStrEnum):
     """Supported git command identifiers."""
 
+    BLAME = auto()
+    CHECKOUT = auto()
+    CONFIG = auto()
     DESCRIBE = auto()
+    DIFF = auto()
     REV_PARSE = auto()
     LOG = auto()
+    STASH = auto()
+    STATUS = auto()
+    WORKTREE = auto()
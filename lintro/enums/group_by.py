@@ -8,12 +8,23 @@ from loguru import logger
 
 
 class GroupBy(StrEnum):
-    """Supported grouping strategies for presenting issues."""
+    """Supported grouping strategies for presenting issues.
+
+    RULE, SEVERITY, DIR, TOOL and OWNER order the issue tables by group,
+    largest group first, with a subtotal row after each group. OWNER groups
+    by the owners CODEOWNERS assigns each file. CODE is kept as an alias of
+    RULE.
+    """
 
     FILE = auto()
     CODE = auto()
     NONE = auto()
     AUTO = auto()
+    RULE = auto()
+    SEVERITY = auto()
+    DIR = auto()
+    TOOL = auto()
+    OWNER = auto()
 
 
 def normalize_group_by(value: str | GroupBy) -> GroupBy:
@@ -0,0 +1,17 @@
+"""Run outcome definitions.
+
+Provides the outcomes a run can end with, each mapped to an exit code via
+``execution.exit_codes``.
+"""
+
+from enum import StrEnum, auto
+
+
+class RunOutcome(StrEnum):
+    """Overall outcome of a run, most severe first."""
+
+    CONFIG_ERROR = auto()
+    TOOL_ERROR = auto()
+    TOOL_MISSING = auto()
+    ISSUES = auto()
+    CLEAN = auto()
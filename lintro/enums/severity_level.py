@@ -42,6 +42,20 @@ _SEVERITY_ALIASES: dict[str, SeverityLevel] = {
     "LOW": SeverityLevel.INFO,
     # Semgrep / Svelte-check
     "WARN": SeverityLevel.WARNING,
+    # Biome
+    "FATAL": SeverityLevel.ERROR,
+    "INFORMATION": SeverityLevel.INFO,
+    # clang-tidy
+    "REMARK": SeverityLevel.INFO,
+    # Vale
+    "SUGGESTION": SeverityLevel.INFO,
+    # ansible-lint (Code Climate)
+    "BLOCKER": SeverityLevel.ERROR,
+    "MAJOR": SeverityLevel.ERROR,
+    "MINOR": SeverityLevel.WARNING,
+    # RuboCop
+    "CONVENTION": SeverityLevel.INFO,
+    "REFACTOR": SeverityLevel.INFO,
     # Pytest outcomes
     "FAILED": SeverityLevel.ERROR,
     "SKIPPED": SeverityLevel.INFO,
@@ -0,0 +1,41 @@
+"""Table sort order enum definitions."""
+
+from __future__ import annotations
+
+from enum import StrEnum, auto
+
+
+class TableSort(StrEnum):
+    """Orders of the rows in issue tables.
+
+    FILE sorts by file, then line and column. LINE sorts by line number
+    across files. CODE sorts by rule code and SEVERITY lists errors first.
+    """
+
+    FILE = auto()
+    LINE = auto()
+    CODE = auto()
+    SEVERITY = auto()
+
+
+def normalize_table_sort(value: str | TableSort) -> TableSort:
+    """Normalize a raw value to TableSort enum.
+
+    Args:
+        value: str or TableSort to normalize.
+
+    Returns:
+        TableSort: Normalized enum value.
+
+    Raises:
+        ValueError: If the value is not a supported sort order.
+    """
+    if isinstance(value, TableSort):
+        return value
+    try:
+        return TableSort[value.upper()]
+    except KeyError as e:
+        supported = ", ".join(s.value for s in TableSort)
+        raise ValueError(
+            f"Unsupported sort order: {value}. Supported: {supported}",
+        ) from e
@@ -12,21 +12,44 @@ class ToolName(StrEnum):
     """Supported tool identifiers in lower-case values."""
 
     ACTIONLINT = auto()
+    ANSIBLE_LINT = auto()
+    ASSET_AUDIT = auto()
     ASTRO_CHECK = auto()
     BANDIT = auto()
+    BIOME = auto()
     BLACK = auto()
+    BUF = auto()
     CARGO_AUDIT = auto()
     CARGO_DENY = auto()
+    CARGO_GEIGER = auto()
+    CARGO_MACHETE = auto()
+    CARGO_OUTDATED = auto()
+    CHECKOV = auto()
+    CLANG_FORMAT = auto()
+    CLANG_TIDY = auto()
     CLIPPY = auto()
+    CONVENTIONAL_COMMITS = auto()
+    EDITORCONFIG = auto()
+    ESLINT = auto()
+    FRONTMATTER = auto()
     GITLEAKS = auto()
+    GOFMT = auto()
+    GOLANGCI_LINT = auto()
     HADOLINT = auto()
+    KTLINT = auto()
+    LICENSE_HEADER = auto()
+    LINKCHECK = auto()
+    LUACHECK = auto()
     MARKDOWNLINT = auto()
     MYPY = auto()
     OXFMT = auto()
     OXLINT = auto()
+    PACKAGE_JSON = auto()
     PRETTIER = auto()
     PYDOCLINT = auto()
+    PYPROJECT = auto()
     PYTEST = auto()
+    RUBOCOP = auto()
     RUFF = auto()
     RUSTC = auto()
     RUSTFMT = auto()
@@ -34,10 +57,17 @@ class ToolName(StrEnum):
     SHELLCHECK = auto()
     SHFMT = auto()
     SQLFLUFF = auto()
+    STYLELINT = auto()
+    STYLUA = auto()
     SVELTE_CHECK = auto()
     TAPLO = auto()
+    TERRAFORM_FMT = auto()
+    TFLINT = auto()
     TSC = auto()
+    TYPOS = auto()
+    VALE = auto()
     VUE_TSC = auto()
+    WHITESPACE = auto()
     YAMLLINT = auto()
 
 
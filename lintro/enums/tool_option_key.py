@@ -12,3 +12,6 @@ class ToolOptionKey(StrEnum):
     TIMEOUT = auto()
     EXCLUDE_PATTERNS = auto()
     INCLUDE_VENV = auto()
+    PRUNE_DIRS = auto()
+    GITIGNORE = auto()
+    SYMLINKS = auto()
@@ -0,0 +1,15 @@
+"""Tool run state enum definitions."""
+
+from __future__ import annotations
+
+from enum import StrEnum, auto
+
+
+class ToolState(StrEnum):
+    """States of a tool in the live progress display."""
+
+    QUEUED = auto()
+    RUNNING = auto()
+    DONE = auto()
+    FAILED = auto()
+    SKIPPED = auto()
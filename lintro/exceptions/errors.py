@@ -1,35 +1,276 @@
-"""Custom exception types for Lintro."""
+"""Custom exception types and the error catalog for Lintro.
+
+Every runtime error lintro reports carries a stable code such as
+``LINTRO001``. Codes appear in console messages, log files and JSON output,
+and ``lintro explain LINTRO001`` describes them, so CI scripts can branch on
+the cause of a failure instead of matching message text. Codes are never
+reused or renumbered; a retired error keeps its entry.
+"""
 
 from __future__ import annotations
 
+import re
+import subprocess  # nosec B404 - only used to recognise timeouts
+from dataclasses import dataclass
+from typing import ClassVar
+
+
+@dataclass(frozen=True)
+class ErrorCode:
+    """A catalogued runtime error.
+
+    Attributes:
+        code: Stable identifier, such as ``LINTRO001``.
+        title: Short name of the error.
+        explanation: What causes the error and how to resolve it.
+    """
+
+    code: str
+    title: str
+    explanation: str
+
+    def format(self, message: str) -> str:
+        """Format a console message for this error.
+
+        Args:
+            message: Details of this occurrence.
+
+        Returns:
+            str: Message such as ``Error: [LINTRO001] unknown key 'x'``.
+        """
+        return f"Error: [{self.code}] {message}"
+
+
+CONFIG_INVALID = ErrorCode(
+    code="LINTRO001",
+    title="Invalid configuration",
+    explanation=(
+        "The Lintro config file could not be parsed or failed validation, or "
+        "the selected --profile does not exist. The message names the file "
+        "and key; `lintro config` shows the configuration lintro loads."
+    ),
+)
+UNKNOWN_TOOL = ErrorCode(
+    code="LINTRO002",
+    title="Unknown tool",
+    explanation=(
+        "--tools named a tool lintro does not know, or none of the selected "
+        "tools supports the command. `lintro list-tools` shows the tool names."
+    ),
+)
+CONTAINER_RUNTIME_MISSING = ErrorCode(
+    code="LINTRO003",
+    title="Container runtime missing",
+    explanation=(
+        "execution.backend is 'container' but neither docker nor podman (or "
+        "the configured execution.container_runtime) is installed. Install "
+        "one or switch back to the host backend."
+    ),
+)
+TOOL_NOT_INSTALLED = ErrorCode(
+    code="LINTRO004",
+    title="Tool not installed",
+    explanation=(
+        "A selected tool's executable was not found on PATH, so the tool was "
+        "skipped. Install it, or run with --install-missing; `lintro doctor` "
+        "shows what is missing."
+    ),
+)
+TOOL_VERSION_TOO_OLD = ErrorCode(
+    code="LINTRO005",
+    title="Tool version too old",
+    explanation=(
+        "The installed tool is older than the minimum version lintro supports, "
+        "so it was skipped. Upgrade it; `lintro versions` shows the minimums."
+    ),
+)
+TOOL_VERSION_MISMATCH = ErrorCode(
+    code="LINTRO006",
+    title="Tool version does not match its pin",
+    explanation=(
+        "tools.<name>.version in the config pins a version the installed tool "
+        "does not satisfy. Install the pinned version or update the pin."
+    ),
+)
+TOOL_TIMEOUT = ErrorCode(
+    code="LINTRO007",
+    title="Tool timed out",
+    explanation=(
+        "A tool ran longer than its timeout and was stopped. Raise the limit "
+        "with --tool-options <tool>:timeout=N or tools.<tool>.timeout, or "
+        "check fewer files."
+    ),
+)
+TOOL_EXECUTION_FAILED = ErrorCode(
+    code="LINTRO008",
+    title="Tool failed to run",
+    explanation=(
+        "A tool could not be started or crashed before producing results, for "
+        "example because of a permission error or a broken installation. The "
+        "debug log in .lintro/ has the full traceback."
+    ),
+)
+OUTPUT_PARSE_FAILED = ErrorCode(
+    code="LINTRO009",
+    title="Tool output could not be parsed",
+    explanation=(
+        "A tool printed output lintro could not read, usually because the "
+        "installed version changed its output format. The raw output is shown "
+        "instead; please report it with the tool's version."
+    ),
+)
+INVALID_TOOL_OPTION = ErrorCode(
+    code="LINTRO010",
+    title="Invalid tool option",
+    explanation=(
+        "A value given with --tool-options or under tools.<name> in the config "
+        "has the wrong type or is out of range. The message names the option."
+    ),
+)
+FILE_ACCESS_FAILED = ErrorCode(
+    code="LINTRO011",
+    title="File access failed",
+    explanation=(
+        "Lintro could not read or write a file it needed, such as a checked "
+        "file or its cache. Check permissions and free disk space."
+    ),
+)
+REPORT_WRITE_FAILED = ErrorCode(
+    code="LINTRO012",
+    title="Report could not be written",
+    explanation=(
+        "The results were computed but a report file could not be written or "
+        "signed, for example because the --output directory is not writable "
+        "or the --sign-report key is invalid. A report that cannot be written "
+        "leaves the run's outcome unchanged; one that cannot be signed ends "
+        "the run with the config_error exit code, since the requested "
+        "attestation is missing."
+    ),
+)
+WORKLOAD_TOO_LARGE = ErrorCode(
+    code="LINTRO013",
+    title="Too many files to process",
+    explanation=(
+        "The selected tools would process more files than --max-files allows, "
+        "or more than --confirm-over allows and the run was not confirmed. "
+        "This usually means a vendored or generated directory is included. "
+        "Exclude it with --exclude or .lintro-ignore, or raise the limit."
+    ),
+)
+OUTPUT_NOT_DECODABLE = ErrorCode(
+    code="LINTRO014",
+    title="Tool output is not valid text",
+    explanation=(
+        "A tool printed bytes that are neither UTF-8 nor in the locale's "
+        "encoding, typically a file name in another encoding. Lintro replaced "
+        "them with U+FFFD and kept going, so an affected file name may not "
+        "match the file on disk. Rename the file or set the tool's output "
+        "encoding, for example PYTHONIOENCODING=utf-8 for Python tools."
+    ),
+)
+
+ERROR_CODES: dict[str, ErrorCode] = {
+    error.code: error
+    for error in (
+        CONFIG_INVALID,
+        UNKNOWN_TOOL,
+        CONTAINER_RUNTIME_MISSING,
+        TOOL_NOT_INSTALLED,
+        TOOL_VERSION_TOO_OLD,
+        TOOL_VERSION_MISMATCH,
+        TOOL_TIMEOUT,
+        TOOL_EXECUTION_FAILED,
+        OUTPUT_PARSE_FAILED,
+        INVALID_TOOL_OPTION,
+        FILE_ACCESS_FAILED,
+        REPORT_WRITE_FAILED,
+        WORKLOAD_TOO_LARGE,
+        OUTPUT_NOT_DECODABLE,
+    )
+}
+
+_CODE_RE = re.compile(r"^(?:LINTRO)?(\d{1,3})$", re.IGNORECASE)
+
+
+def lookup_error(code: str) -> ErrorCode | None:
+    """Find a catalogued error by its code.
+
+    Args:
+        code: Code such as ``LINTRO012``; case and the ``LINTRO`` prefix are
+            optional, so ``lintro012`` and ``12`` also match.
+
+    Returns:
+        ErrorCode | None: The error, or None if the code is unknown.
+    """
+    match = _CODE_RE.match(code.strip())
+    if match is None:
+        return None
+    return ERROR_CODES.get(f"LINTRO{int(match.group(1)):03d}")
+
+
+def error_code_for(error: BaseException, default: ErrorCode) -> ErrorCode:
+    """Return the catalogued error an exception reports.
+
+    Args:
+        error: Exception raised while running lintro.
+        default: Error to report when the exception carries no code.
+
+    Returns:
+        ErrorCode: The exception's code, or default. A subprocess that ran
+        past its timeout reports TOOL_TIMEOUT.
+    """
+    if isinstance(error, subprocess.TimeoutExpired):
+        return TOOL_TIMEOUT
+    return getattr(error, "error_code", None) or default
+
 
 class LintroError(Exception):
-    """Base exception for all Lintro-related errors."""
+    """Base exception for all Lintro-related errors.
+
+    Attributes:
+        error_code: Catalogued error the exception reports, if any.
+    """
+
+    error_code: ClassVar[ErrorCode | None] = None
 
 
 class InvalidToolConfigError(LintroError):
     """Raised when a tool's configuration is invalid."""
 
+    error_code = CONFIG_INVALID
+
 
 class InvalidToolOptionError(LintroError):
     """Raised when invalid options are provided to a tool."""
 
+    error_code = INVALID_TOOL_OPTION
+
 
 class ToolExecutionError(LintroError):
     """Raised when a tool fails to execute properly."""
 
+    error_code = TOOL_EXECUTION_FAILED
+
 
 class ToolTimeoutError(LintroError):
     """Raised when a tool execution times out."""
 
+    error_code = TOOL_TIMEOUT
+
 
 class ParserError(LintroError):
     """Raised when parsing tool output fails."""
 
+    error_code = OUTPUT_PARSE_FAILED
+
 
 class ConfigurationError(LintroError):
     """Raised when configuration loading or validation fails."""
 
+    error_code = CONFIG_INVALID
+
 
 class FileAccessError(LintroError):
     """Raised when file access operations fail."""
+
+    error_code = FILE_ACCESS_FAILED
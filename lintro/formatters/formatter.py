@@ -18,14 +18,25 @@ Example:
 
 from __future__ import annotations
 
+import posixpath
+from collections import defaultdict
 from collections.abc import Sequence
 
 from lintro.enums.display_column import STANDARD_COLUMNS, DisplayColumn
+from lintro.enums.group_by import GroupBy, normalize_group_by
 from lintro.enums.output_format import OutputFormat, normalize_output_format
+from lintro.enums.severity_level import SeverityLevel
+from lintro.enums.table_sort import TableSort, normalize_table_sort
 from lintro.formatters.core.format_registry import TableDescriptor, get_style
 from lintro.parsers.base_issue import BaseIssue
+from lintro.utils.codeowners import UNOWNED, owner_label
 from lintro.utils.path_utils import normalize_file_path_for_display
 
+# Formats that list the diff previews some issues carry below the table
+_PREVIEW_FORMATS: frozenset[OutputFormat] = frozenset(
+    {OutputFormat.GRID, OutputFormat.HTML},
+)
+
 # Map DisplayColumn enum to row dict keys
 _COLUMN_KEY_MAP: dict[DisplayColumn, str] = {
     DisplayColumn.FILE: "file",
@@ -37,6 +48,27 @@ _COLUMN_KEY_MAP: dict[DisplayColumn, str] = {
     DisplayColumn.FIXABLE: "fixable",
 }
 
+# Groupings that reorder issue tables and add subtotal rows
+SUBTOTAL_GROUPS: frozenset[GroupBy] = frozenset(
+    {
+        GroupBy.CODE,
+        GroupBy.RULE,
+        GroupBy.SEVERITY,
+        GroupBy.DIR,
+        GroupBy.TOOL,
+        GroupBy.OWNER,
+    },
+)
+# Formats that render tables for people; machine formats keep one row per issue
+_TABLE_FORMATS: frozenset[OutputFormat] = frozenset(
+    {OutputFormat.GRID, OutputFormat.PLAIN, OutputFormat.MARKDOWN, OutputFormat.HTML},
+)
+_SEVERITY_RANK: dict[SeverityLevel, int] = {
+    SeverityLevel.ERROR: 0,
+    SeverityLevel.WARNING: 1,
+    SeverityLevel.INFO: 2,
+}
+
 
 class UnifiedTableDescriptor(TableDescriptor):
     """Table descriptor that works with any BaseIssue subclass.
@@ -94,6 +126,126 @@ class UnifiedTableDescriptor(TableDescriptor):
 
         return rows
 
+    def get_previews(self, issues: Sequence[BaseIssue]) -> list[tuple[str, str]]:
+        """Extract the diff previews of issues that carry one.
+
+        Args:
+            issues: List of issues (any BaseIssue subclass).
+
+        Returns:
+            List of (location, diff) pairs, where the location is the file
+            and the line or line range the diff rewrites.
+        """
+        previews: list[tuple[str, str]] = []
+        for issue in issues:
+            diff = getattr(issue, "diff", None)
+            if not isinstance(diff, str) or not diff.strip():
+                continue
+            location = normalize_file_path_for_display(issue.file)
+            end_line = getattr(issue, "end_line", None)
+            if isinstance(end_line, int) and end_line > issue.line:
+                location = f"{location}:{issue.line}-{end_line}"
+            elif issue.line:
+                location = f"{location}:{issue.line}"
+            previews.append((location, diff))
+        return previews
+
+    def get_grouped_rows(
+        self,
+        issues: Sequence[BaseIssue],
+        group_by: GroupBy,
+        tool_name: str | None = None,
+    ) -> list[list[str]]:
+        """Extract row data grouped by rule, severity, directory, tool or owner.
+
+        Groups are ordered by size, largest first, and each group is
+        followed by a subtotal row.
+
+        Args:
+            issues: List of issues (any BaseIssue subclass).
+            group_by: Grouping, one of SUBTOTAL_GROUPS.
+            tool_name: Tool that reported the issues, for tool grouping.
+
+        Returns:
+            List of rows, with a subtotal row after each group.
+        """
+        groups: dict[str, list[list[str]]] = defaultdict(list)
+        for issue, row in zip(issues, self.get_rows(issues), strict=True):
+            groups[_group_key(issue, group_by, tool_name)].append(row)
+        rows: list[list[str]] = []
+        for key, group in sorted(groups.items(), key=lambda g: (-len(g[1]), g[0])):
+            rows.extend(group)
+            noun = "issue" if len(group) == 1 else "issues"
+            subtotal = [""] * len(self._columns)
+            subtotal[0] = f"Subtotal {key}: {len(group)} {noun}"
+            rows.append(subtotal)
+        return rows
+
+
+def _group_key(issue: BaseIssue, group_by: GroupBy, tool_name: str | None) -> str:
+    """Find the group an issue is listed under.
+
+    Args:
+        issue: Issue to place.
+        group_by: Grouping, one of SUBTOTAL_GROUPS.
+        tool_name: Tool that reported the issue.
+
+    Returns:
+        str: Rule code, severity, directory, tool name or owners.
+    """
+    if group_by == GroupBy.TOOL:
+        return tool_name or "unknown"
+    if group_by == GroupBy.OWNER:
+        return owner_label(issue.file) if issue.file else UNOWNED
+    if group_by == GroupBy.SEVERITY:
+        return str(issue.get_severity()).lower()
+    if group_by == GroupBy.DIR:
+        path = normalize_file_path_for_display(issue.file) if issue.file else ""
+        directory = posixpath.dirname(path.replace("\\", "/")).removeprefix("./")
+        return f"{directory}/" if directory not in ("", ".") else "./"
+    return issue.to_display_row()["code"] or "-"
+
+
+def sort_issues(
+    issues: Sequence[BaseIssue],
+    sort_by: TableSort | str,
+) -> list[BaseIssue]:
+    """Order issues for display.
+
+    Args:
+        issues: Issues to order.
+        sort_by: Sort order; ties are broken by file, line and column.
+
+    Returns:
+        list[BaseIssue]: Issues in the requested order.
+    """
+    order = normalize_table_sort(sort_by)
+
+    def position(issue: BaseIssue) -> tuple[str, int, int]:
+        """Locate an issue for tie-breaking.
+
+        Args:
+            issue: Issue to locate.
+
+        Returns:
+            tuple[str, int, int]: File, line and column.
+        """
+        return (issue.file, issue.line, issue.column)
+
+    if order == TableSort.LINE:
+        return sorted(issues, key=lambda i: (i.line, *position(i)))
+    if order == TableSort.CODE:
+        return sorted(
+            issues,
+            key=lambda i: (i.to_display_row()["code"], *position(i)),
+        )
+    if order == TableSort.SEVERITY:
+        return sorted(
+            issues,
+            key=lambda i: (_SEVERITY_RANK[i.get_severity()], *position(i)),
+        )
+    return sorted(issues, key=position)
+
 
 def format_issues(
     issues: Sequence[BaseIssue],
@@ -101,6 +253,8 @@ def format_issues(
     *,
     columns: list[DisplayColumn] | None = None,
     tool_name: str | None = None,
+    group_by: GroupBy | str | None = None,
+    sort_by: TableSort | str | None = None,
 ) -> str:
     """Format any issues using unified display.
 
@@ -110,8 +264,14 @@ def format_issues(
     Args:
         issues: List of issues (any BaseIssue subclass).
         output_format: Output format (grid, json, plain, etc.).
-        columns: Custom column list (defaults to STANDARD_COLUMNS).
-        tool_name: Tool name for JSON output.
+        columns: Custom column list for table formats (defaults to
+            STANDARD_COLUMNS).
+        tool_name: Tool name for JSON output and tool grouping.
+        group_by: Grouping of the rows. Rule, severity, dir, tool and owner
+            group the rows of table formats with subtotals; other values keep
+            the issues in the order given.
+        sort_by: Order of the rows of table formats, applied within each
+            group. None keeps the order the tool reported.
 
     Returns:
         Formatted string.
@@ -124,13 +284,31 @@ def format_issues(
         return "No issues found."
 
     normalized_format = normalize_output_format(output_format)
-    descriptor = UnifiedTableDescriptor(columns=columns)
+    # Machine formats keep every field so their consumers see a fixed layout
+    table = normalized_format in _TABLE_FORMATS
+    descriptor = UnifiedTableDescriptor(columns=columns if table else None)
 
     style = get_style(normalized_format)
     cols = descriptor.get_columns()
-    rows = descriptor.get_rows(list(issues))
+    grouping = normalize_group_by(group_by) if group_by else GroupBy.NONE
+    if sort_by and table:
+        issues = sort_issues(issues, sort_by)
+    if grouping in SUBTOTAL_GROUPS and table:
+        rows = descriptor.get_grouped_rows(list(issues), grouping, tool_name)
+    else:
+        rows = descriptor.get_rows(list(issues))
+    previews = (
+        descriptor.get_previews(issues)
+        if normalized_format in _PREVIEW_FORMATS
+        else []
+    )
 
-    return style.format(columns=cols, rows=rows, tool_name=tool_name)
+    return style.format(
+        columns=cols,
+        rows=rows,
+        tool_name=tool_name,
+        previews=previews,
+    )
 
 
 def format_issues_with_sections(
@@ -138,7 +316,10 @@ def format_issues_with_sections(
     output_format: OutputFormat | str = OutputFormat.GRID,
     *,
     group_by_fixable: bool = True,
+    columns: list[DisplayColumn] | None = None,
     tool_name: str | None = None,
+    group_by: GroupBy | str | None = None,
+    sort_by: TableSort | str | None = None,
 ) -> str:
     """Format issues with optional fixable/non-fixable sections.
 
@@ -149,7 +330,10 @@ def format_issues_with_sections(
         issues: List of issues (any BaseIssue subclass).
         output_format: Output format (grid, json, plain, etc.).
         group_by_fixable: Whether to group by fixable status.
+        columns: Custom column list (defaults to STANDARD_COLUMNS).
         tool_name: Tool name for JSON output.
+        group_by: Grouping of the rows within each section.
+        sort_by: Order of the rows within each section.
 
     Returns:
         Formatted string with sections.
@@ -175,7 +359,10 @@ def format_issues_with_sections(
         return format_issues(
             issues,
             output_format=normalized_format,
+            columns=columns,
             tool_name=tool_name,
+            group_by=group_by,
+            sort_by=sort_by,
         )
 
     # Partition issues by fixable status
@@ -191,11 +378,25 @@ def format_issues_with_sections(
     sections: list[str] = []
 
     if fixable:
-        fixable_output = format_issues(fixable, output_format=normalized_format)
+        fixable_output = format_issues(
+            fixable,
+            output_format=normalized_format,
+            columns=columns,
+            tool_name=tool_name,
+            group_by=group_by,
+            sort_by=sort_by,
+        )
         sections.append("Auto-fixable issues\n" + fixable_output)
 
     if non_fixable:
-        non_fixable_output = format_issues(non_fixable, output_format=normalized_format)
+        non_fixable_output = format_issues(
+            non_fixable,
+            output_format=normalized_format,
+            columns=columns,
+            tool_name=tool_name,
+            group_by=group_by,
+            sort_by=sort_by,
+        )
         sections.append("Not auto-fixable issues\n" + non_fixable_output)
 
     if not sections:
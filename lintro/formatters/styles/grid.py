@@ -1,5 +1,6 @@
 """Grid output style implementation."""
 
+from collections.abc import Sequence
 from typing import Any
 
 from lintro.formatters.core.format_registry import OutputStyle
@@ -25,6 +26,7 @@ class GridStyle(OutputStyle):
         columns: list[str],
         rows: list[list[Any]],
         tool_name: str | None = None,
+        previews: Sequence[tuple[str, str]] = (),
         **kwargs: Any,
     ) -> str:
         """Format a table given columns and rows as a grid.
@@ -33,6 +35,8 @@ class GridStyle(OutputStyle):
             columns: List of column header names.
             rows: List of row values (each row is a list of cell values).
             tool_name: Optional tool name to include in context.
+            previews: Location and diff of issues carrying a diff preview,
+                listed below the table.
             **kwargs: Extra options ignored by this formatter.
 
         Returns:
@@ -41,6 +45,22 @@ class GridStyle(OutputStyle):
         if not rows:
             return ""
 
+        table = self._table(columns, rows)
+        if not previews:
+            return table
+        blocks = [f"{location}\n{diff.rstrip()}" for location, diff in previews]
+        return "\n\n".join([table, "Diff previews:", *blocks])
+
+    def _table(self, columns: list[str], rows: list[list[Any]]) -> str:
+        """Render the rows as a grid table.
+
+        Args:
+            columns: List of column header names.
+            rows: List of row values (each row is a list of cell values).
+
+        Returns:
+            Formatted data as grid table string.
+        """
         # Use tabulate if available
         if TABULATE_AVAILABLE:
             # Provide sane defaults for alignment and column widths to avoid
@@ -1,5 +1,7 @@
 """HTML output style implementation."""
 
+import html
+from collections.abc import Sequence
 from typing import Any
 
 from lintro.formatters.core.format_registry import OutputStyle
@@ -13,6 +15,7 @@ class HtmlStyle(OutputStyle):
         columns: list[str],
         rows: list[list[Any]],
         tool_name: str | None = None,
+        previews: Sequence[tuple[str, str]] = (),
         **kwargs: Any,
     ) -> str:
         """Format a table given columns and rows as HTML.
@@ -21,6 +24,8 @@ class HtmlStyle(OutputStyle):
             columns: List of column header names.
             rows: List of row values (each row is a list of cell values).
             tool_name: Optional tool name to include in context.
+            previews: Location and diff of issues carrying a diff preview,
+                shown below the table as collapsible blocks.
             **kwargs: Extra options ignored by this formatter.
 
         Returns:
@@ -51,4 +56,9 @@ class HtmlStyle(OutputStyle):
 
         # Combine all parts
         table_content = header + "".join(formatted_rows)
-        return f"<table>{table_content}</table>"
+        blocks = "".join(
+            f"<details><summary>{html.escape(location)}</summary>"
+            f"<pre>{html.escape(diff.rstrip())}</pre></details>"
+            for location, diff in previews
+        )
+        return f"<table>{table_content}</table>{blocks}"
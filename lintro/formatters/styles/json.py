@@ -1,10 +1,10 @@
 """JSON output style implementation."""
 
 import json
-from datetime import datetime
 from typing import Any
 
 from lintro.formatters.core.format_registry import OutputStyle
+from lintro.utils.timestamps import utc_timestamp
 
 
 class JsonStyle(OutputStyle):
@@ -44,7 +44,7 @@ class JsonStyle(OutputStyle):
         # Create the final JSON structure
         result: dict[str, Any] = {
             "tool": tool_name,
-            "timestamp": datetime.now().isoformat(),
+            "timestamp": utc_timestamp(),
             "total_issues": len(issues),
             "issues": issues,
         }
@@ -51,6 +51,14 @@ class ToolResult:
     # Skip tracking for tools that didn't execute
     skipped: bool = field(default=False)
     skip_reason: str | None = field(default=None)
+    # Set on skipped results when the tool executable is not installed
+    missing: bool = field(default=False)
+    # Catalogued code (lintro.exceptions.errors) when the tool could not run
+    error_code: str | None = field(default=None)
+    # Wall time of the tool run from the monotonic clock, in milliseconds
+    duration_ms: int | None = field(default=None)
+    # Arguments added to the tool's commands (tools.<name>.extra_args)
+    extra_args: list[str] = field(default_factory=list)
 
     def __post_init__(self) -> None:
         """Validate that the issue counts and skip state are consistent.
@@ -1,20 +1,27 @@
 """Parser for actionlint CLI output.
 
-This module parses the default text output produced by the ``actionlint``
-binary into structured ``ActionlintIssue`` objects so that Lintro can render
-uniform tables and reports across styles.
+This module parses the JSON report of ``actionlint -format '{{json .}}'``, or
+the default ``file:line:col: message [kind]`` text output, into structured
+``ActionlintIssue`` objects so that Lintro can render uniform tables and
+reports across styles.
 """
 
 from __future__ import annotations
 
+import json
 import re
 from collections.abc import Iterable
+from typing import Any
+
+from loguru import logger
 
 from lintro.parsers.actionlint.actionlint_issue import ActionlintIssue
-from lintro.parsers.base_parser import strip_ansi_codes
+from lintro.parsers.base_parser import FILE_PATH_PATTERN, strip_ansi_codes
 
 _LINE_RE: re.Pattern[str] = re.compile(
-    r"^(?P<file>[^:]+):(?P<line>\d+):(?P<col>\d+):\s*(?:(?P<level>error|warning):\s*)?(?P<msg>.*?)(?:\s*\[(?P<code>[A-Za-z0-9_\-\.]+)\])?$",
+    rf"^(?P<file>{FILE_PATH_PATTERN}):(?P<line>\d+):(?P<col>\d+):\s*"
+    r"(?:(?P<level>error|warning):\s*)?(?P<msg>.*?)"
+    r"(?:\s*\[(?P<code>[A-Za-z0-9_\-\.]+)\])?$",
 )
 
 
@@ -33,6 +40,9 @@ def parse_actionlint_output(output: str | None) -> list[ActionlintIssue]:
     # Strip ANSI codes for consistent parsing across environments
     output = strip_ansi_codes(output)
 
+    if output.lstrip().startswith("["):
+        return _parse_json_output(output)
+
     issues: list[ActionlintIssue] = []
     for line in _iter_nonempty_lines(output):
         m = _LINE_RE.match(line.strip())
@@ -57,6 +67,40 @@ def parse_actionlint_output(output: str | None) -> list[ActionlintIssue]:
     return issues
 
 
+def _parse_json_output(output: str) -> list[ActionlintIssue]:
+    """Parse the JSON array printed by ``-format '{{json .}}'``.
+
+    Args:
+        output: Raw actionlint output starting with a JSON array.
+
+    Returns:
+        list[ActionlintIssue]: Parsed issues, empty if the JSON is invalid.
+    """
+    try:
+        data: Any = json.loads(output[: output.rfind("]") + 1])
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse actionlint JSON output: {e}")
+        return []
+    if not isinstance(data, list):
+        return []
+
+    issues: list[ActionlintIssue] = []
+    for error in data:
+        if not isinstance(error, dict):
+            continue
+        issues.append(
+            ActionlintIssue(
+                file=str(error.get("filepath", "")),
+                line=int(error.get("line", 0) or 0),
+                column=int(error.get("column", 0) or 0),
+                level="error",
+                code=str(error.get("kind", "") or ""),
+                message=str(error.get("message", "")).strip(),
+            ),
+        )
+    return issues
+
+
 def _iter_nonempty_lines(text: str) -> Iterable[str]:
     """Iterate non-empty lines from a text block.
 
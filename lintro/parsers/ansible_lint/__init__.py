@@ -0,0 +1,6 @@
+"""Parsing utilities and types for ansible-lint output."""
+
+from lintro.parsers.ansible_lint.ansible_lint_issue import AnsibleLintIssue
+from lintro.parsers.ansible_lint.ansible_lint_parser import parse_ansible_lint_output
+
+__all__ = ["AnsibleLintIssue", "parse_ansible_lint_output"]
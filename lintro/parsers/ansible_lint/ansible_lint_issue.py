@@ -0,0 +1,25 @@
+"""Typed structure representing a single ansible-lint finding."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class AnsibleLintIssue(BaseIssue):
+    """Simple container for ansible-lint findings.
+
+    Attributes:
+        code: Rule that reported the issue, with its sub-rule when there is
+            one, such as ``name[missing]`` or ``yaml[truthy]``.
+        severity: Code Climate severity; ansible-lint uses ``major`` for
+            violations, ``minor`` for rules listed in ``warn_list`` and
+            ``blocker`` for files it cannot load.
+        categories: Tags of the rule, such as ``idiom`` or ``security``.
+        url: Documentation URL of the rule.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    categories: list[str] = field(default_factory=list)
+    url: str = field(default="")
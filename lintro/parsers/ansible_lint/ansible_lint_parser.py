@@ -0,0 +1,107 @@
+"""Parser for ansible-lint Code Climate output.
+
+Handles the output of ``ansible-lint --format=codeclimate``: a JSON list of
+Code Climate issues, each with a ``check_name``, a ``description`` and a
+``location``. The location gives either a bare line::
+
+    {"path": "site.yml", "lines": {"begin": 4}}
+
+or, when ansible-lint knows the column, a position::
+
+    {"path": "site.yml", "positions": {"begin": {"line": 4, "column": 7}}}
+
+ansible-lint logs progress and a summary to stderr around the report.
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.ansible_lint.ansible_lint_issue import AnsibleLintIssue
+
+
+def _load_json(output: str) -> Any:
+    """Load the JSON list ansible-lint printed, ignoring surrounding logs.
+
+    Args:
+        output: The raw output from ``ansible-lint --format=codeclimate``.
+
+    Returns:
+        Any: The decoded document, or None if there is none.
+    """
+    decoder = json.JSONDecoder()
+    for index, char in enumerate(output):
+        if char != "[" or (index and output[index - 1] != "\n"):
+            continue
+        try:
+            data, _ = decoder.raw_decode(output, index)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Failed to parse ansible-lint JSON output: {e}")
+            continue
+        return data
+    return None
+
+
+def parse_ansible_lint_output(output: str | None) -> list[AnsibleLintIssue] | None:
+    """Parse ansible-lint Code Climate output.
+
+    Args:
+        output: The raw output from ``ansible-lint --format=codeclimate``.
+
+    Returns:
+        list[AnsibleLintIssue] | None: The parsed issues, or None if the
+        output holds no JSON report.
+    """
+    if not output:
+        return None
+
+    data = _load_json(output)
+    if not isinstance(data, list):
+        return None
+    return [_parse_issue(item) for item in data if isinstance(item, dict)]
+
+
+def _parse_location(location: dict[str, Any]) -> tuple[int, int]:
+    """Read the line and column of a Code Climate location.
+
+    Args:
+        location: The ``location`` object of an issue.
+
+    Returns:
+        Tuple of (line, column), 0 when unknown.
+    """
+    positions = location.get("positions")
+    if isinstance(positions, dict):
+        begin = positions.get("begin") or {}
+        return int(begin.get("line") or 0), int(begin.get("column") or 0)
+    lines = location.get("lines")
+    if isinstance(lines, dict):
+        return int(lines.get("begin") or 0), 0
+    return 0, 0
+
+
+def _parse_issue(item: dict[str, Any]) -> AnsibleLintIssue:
+    """Parse a single Code Climate issue.
+
+    Args:
+        item: Entry of the report list.
+
+    Returns:
+        AnsibleLintIssue for the entry.
+    """
+    location = item.get("location") or {}
+    line, column = _parse_location(location)
+    categories = item.get("categories") or []
+    return AnsibleLintIssue(
+        file=str(location.get("path", "")),
+        line=line,
+        column=column,
+        message=str(item.get("description", "")),
+        code=str(item.get("check_name", "")),
+        severity=str(item.get("severity") or ""),
+        categories=[str(c) for c in categories],
+        url=str(item.get("url") or ""),
+    )
@@ -0,0 +1,9 @@
+"""Asset audit issue module.
+
+This module provides the issue model for Lintro's native image and asset
+optimization audit.
+"""
+
+from lintro.parsers.asset_audit.asset_audit_issue import AssetAuditIssue
+
+__all__ = ["AssetAuditIssue"]
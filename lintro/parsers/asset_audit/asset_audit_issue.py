@@ -0,0 +1,35 @@
+"""Asset audit issue model.
+
+This module defines the AssetAuditIssue dataclass for representing
+unoptimized or oversized images found by Lintro's native asset audit.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class AssetAuditIssue(BaseIssue):
+    """Represents an unoptimized or oversized image.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., IA001).
+        size: File size in bytes.
+        fixable: Whether the issue is fixed by ``lintro format``.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="warning")
+    code: str = field(default="")
+    size: int = field(default=0)
+    fixable: bool = field(default=False)
@@ -7,6 +7,8 @@ The base class includes:
 - Common fields (file, line, column, message)
 - A to_display_row() method for unified formatting with configurable field mapping
 - A get_severity() method for normalized severity access
+- A get_fix_safety() method classifying fixes as safe or unsafe
+- A fingerprint() method identifying the issue across runs
 """
 
 from __future__ import annotations
@@ -14,7 +16,15 @@ from __future__ import annotations
 from dataclasses import dataclass, field
 from typing import ClassVar
 
+from lintro.enums.fix_safety import FixSafety, normalize_fix_safety
 from lintro.enums.severity_level import SeverityLevel, normalize_severity_level
+from lintro.utils.fingerprint import issue_fingerprint
+
+# Fixable column text; unsafe fixes are only applied with --fix-safety all
+_FIXABLE_LABELS: dict[FixSafety, str] = {
+    FixSafety.SAFE: "Yes",
+    FixSafety.UNSAFE: "Unsafe",
+}
 
 
 @dataclass
@@ -31,7 +41,8 @@ class BaseIssue:
 
     Attributes:
         DISPLAY_FIELD_MAP: Maps display keys to attribute names for custom fields.
-            Default mappings: code->code, severity->severity, fixable->fixable.
+            Default mappings: code->code, severity->severity, fixable->fixable,
+            fix_safety->fix_safety.
             Example: {"severity": "level"} to map self.level to severity output.
         DEFAULT_SEVERITY: Fallback severity when the issue has no native value.
             Override in subclasses (e.g. INFO for pure-formatting tools).
@@ -39,6 +50,18 @@ class BaseIssue:
         line: Line number where the issue was found (1-based, 0 means unknown).
         column: Column number where the issue was found (1-based, 0 means unknown).
         message: Human-readable description of the issue.
+        cell: Jupyter notebook cell the line is counted in (1-based, 0 for
+            files that are not notebooks).
+        severity_override: Severity set by the ``severity`` config section,
+            taking precedence over the tool's native severity.
+        also_reported_by: Other tools that reported the same defect at the
+            same location; their duplicates are removed from the run.
+        toolchains: Toolchains that report the issue in a toolchain-matrix
+            run, when not all of them do.
+        expired_suppression: Expiry date of the ``lintro: ignore`` comment
+            that suppressed the issue until it expired.
+        script_fields: Extra fields added by the ``output.script`` hook,
+            written to JSON reports.
     """
 
     # Default field mapping - subclasses can override specific keys
@@ -46,6 +69,7 @@ class BaseIssue:
         "code": "code",
         "severity": "severity",
         "fixable": "fixable",
+        "fix_safety": "fix_safety",
         "message": "message",
     }
 
@@ -55,18 +79,53 @@ class BaseIssue:
     line: int = field(default=0)
     column: int = field(default=0)
     message: str = field(default="")
+    cell: int = field(default=0, kw_only=True)
+    severity_override: SeverityLevel | None = field(
+        default=None,
+        kw_only=True,
+        repr=False,
+        compare=False,
+    )
+    also_reported_by: list[str] = field(
+        default_factory=list,
+        kw_only=True,
+        repr=False,
+        compare=False,
+    )
+    toolchains: list[str] = field(
+        default_factory=list,
+        kw_only=True,
+        repr=False,
+        compare=False,
+    )
+    expired_suppression: str = field(
+        default="",
+        kw_only=True,
+        repr=False,
+        compare=False,
+    )
+    script_fields: dict[str, str] = field(
+        default_factory=dict,
+        kw_only=True,
+        repr=False,
+        compare=False,
+    )
 
     def get_severity(self) -> SeverityLevel:
         """Return the normalized severity for this issue.
 
-        Reads the native severity value via DISPLAY_FIELD_MAP (handles
-        tools that store it as ``level``, ``issue_severity``, etc.),
-        passes it through ``normalize_severity_level()``, and falls back
-        to ``DEFAULT_SEVERITY`` when the value is empty/None.
+        A configured ``severity_override`` wins. Otherwise reads the native
+        severity value via DISPLAY_FIELD_MAP (handles tools that store it
+        as ``level``, ``issue_severity``, etc.), passes it through
+        ``normalize_severity_level()``, and falls back to
+        ``DEFAULT_SEVERITY`` when the value is empty/None.
 
         Returns:
             SeverityLevel: Normalized severity enum value.
         """
+        if self.severity_override is not None:
+            return self.severity_override
+
         attr_name = self.DISPLAY_FIELD_MAP.get("severity", "severity")
         raw = getattr(self, attr_name, None)
 
@@ -81,6 +140,42 @@ class BaseIssue:
         except ValueError:
             return self.DEFAULT_SEVERITY
 
+    def get_fix_safety(self) -> FixSafety | None:
+        """Return whether the issue's fix keeps the code's behavior.
+
+        Reads the native applicability via DISPLAY_FIELD_MAP (ruff's
+        ``fix_applicability``, Clippy's suggestion applicability) and
+        normalizes it with ``normalize_fix_safety()``. Fixable issues of
+        tools that only make safe fixes have no such value and are safe.
+
+        Returns:
+            FixSafety | None: Safety of the fix, or None if the issue is not
+            fixable.
+        """
+        if not getattr(self, self.DISPLAY_FIELD_MAP.get("fixable", "fixable"), False):
+            return None
+        attr_name = self.DISPLAY_FIELD_MAP.get("fix_safety", "fix_safety")
+        raw = getattr(self, attr_name, None)
+        if not raw:
+            return FixSafety.SAFE
+        return normalize_fix_safety(str(raw))
+
+    def fingerprint(self, tool_name: str) -> str:
+        """Return a fingerprint that survives line number shifts.
+
+        Hashes the tool, rule, normalized path and the flagged line's text,
+        so the same issue keeps its fingerprint when lines are added or
+        removed elsewhere in the file. Use ``fingerprint_issues()`` for all
+        issues of a result so repeats on identical lines stay distinct.
+
+        Args:
+            tool_name: Name of the tool that reported the issue.
+
+        Returns:
+            str: Hex fingerprint.
+        """
+        return issue_fingerprint(tool_name, self)
+
     def to_display_row(self) -> dict[str, str]:
         """Convert issue to unified display format.
 
@@ -98,19 +193,26 @@ class BaseIssue:
 
         # Resolve each mapped field
         code_attr = field_map.get("code", "code")
-        fixable_attr = field_map.get("fixable", "fixable")
         message_attr = field_map.get("message", "message")
 
         code_val = getattr(self, code_attr, None) or ""
-        fixable_val = getattr(self, fixable_attr, False)
+        fix_safety = self.get_fix_safety()
         message_val = getattr(self, message_attr, "") or ""
-
+        if self.also_reported_by:
+            message_val += f" (also reported by {', '.join(self.also_reported_by)})"
+        if self.toolchains:
+            message_val += f" [only on {', '.join(self.toolchains)}]"
+        if self.expired_suppression:
+            message_val += f" (suppression expired {self.expired_suppression})"
+
+        line = str(self.line) if self.line else "-"
         return {
             "file": self.file,
-            "line": str(self.line) if self.line else "-",
+            # Notebook lines are counted per cell, shown the way ruff does
+            "line": f"cell {self.cell}:{line}" if self.cell else line,
             "column": str(self.column) if self.column else "-",
             "code": str(code_val) if code_val else "",
             "message": message_val,
             "severity": str(self.get_severity()),
-            "fixable": "Yes" if fixable_val else "",
+            "fixable": "" if fix_safety is None else _FIXABLE_LABELS[fix_safety],
         }
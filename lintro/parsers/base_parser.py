@@ -9,13 +9,17 @@ The utilities include:
 - Type validation with logging
 - Multi-line message collection
 - Safe item parsing with error handling
+- A file path pattern that accepts Windows drive letters
+- Incremental parsing of line-oriented output as it streams
 """
 
 from __future__ import annotations
 
+import json
 import re
 from collections.abc import Callable
-from typing import TYPE_CHECKING, TypeVar
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any, Generic, TypeVar
 
 from loguru import logger
 
@@ -27,6 +31,10 @@ IssueT = TypeVar("IssueT", bound="BaseIssue")
 # Pre-compiled regex for ANSI escape sequences
 _ANSI_ESCAPE_PATTERN: re.Pattern[str] = re.compile(r"\x1b\[[0-9;]*m")
 
+# File path in "file:line" output; the colon of a Windows drive letter ("C:")
+# belongs to the path instead of ending it
+FILE_PATH_PATTERN: str = r"(?:[A-Za-z]:(?=[\\/]))?[^:]+"
+
 
 def extract_int_field(
     data: dict[str, object],
@@ -317,3 +325,61 @@ def safe_parse_items(
             continue
 
     return results
+
+
+def parse_json_line(line: str) -> dict[str, Any] | None:
+    """Decode one line of JSON Lines output.
+
+    Args:
+        line: Line of output, possibly with ANSI codes.
+
+    Returns:
+        The decoded object, or None if the line is not a JSON object.
+
+    Examples:
+        >>> parse_json_line('{"reason": "compiler-message"}')
+        {'reason': 'compiler-message'}
+        >>> parse_json_line("   Compiling demo v0.1.0") is None
+        True
+    """
+    line = strip_ansi_codes(line).strip()
+    if not line.startswith("{"):
+        return None
+    try:
+        data = json.loads(line)
+    except json.JSONDecodeError:
+        return None
+    return data if isinstance(data, dict) else None
+
+
+@dataclass
+class IssueCollector(Generic[IssueT]):
+    """Line handler that parses output into issues while it streams.
+
+    Passed as the ``line_handler`` of a streaming subprocess run, it parses
+    each line as it arrives, so only the issues are held in memory instead
+    of the whole output. Lines that fail to parse are logged and skipped.
+
+    Attributes:
+        parse_line: Function that parses one line into an issue, or None.
+        tool_name: Name of the tool for log messages.
+        issues: Issues parsed so far.
+    """
+
+    parse_line: Callable[[str], IssueT | None]
+    tool_name: str = "tool"
+    issues: list[IssueT] = field(default_factory=list)
+
+    def __call__(self, line: str) -> None:
+        """Parse a line of output.
+
+        Args:
+            line: Line of output, without its line break.
+        """
+        try:
+            parsed = self.parse_line(line)
+        except (KeyError, TypeError, ValueError) as e:
+            logger.debug(f"Failed to parse {self.tool_name} line: {e}")
+            return
+        if parsed is not None:
+            self.issues.append(parsed)
@@ -0,0 +1,6 @@
+"""Parsing utilities and types for Biome output."""
+
+from lintro.parsers.biome.biome_issue import BiomeIssue
+from lintro.parsers.biome.biome_parser import parse_biome_output
+
+__all__ = ["BiomeIssue", "parse_biome_output"]
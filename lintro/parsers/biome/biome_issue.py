@@ -0,0 +1,21 @@
+"""Typed structure representing a single Biome diagnostic."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class BiomeIssue(BaseIssue):
+    """Simple container for Biome findings.
+
+    Attributes:
+        code: Diagnostic category (e.g., 'lint/suspicious/noDebugger' or
+            'format').
+        severity: Severity level ('fatal', 'error', 'warning', 'information').
+        fixable: Whether Biome can fix this issue with ``--write``.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="error")
+    fixable: bool = field(default=False)
@@ -0,0 +1,119 @@
+"""Parser for Biome JSON output.
+
+Handles the output of ``biome check --reporter=json``. Diagnostics carry a
+byte span into the file rather than a line and column, so positions are
+computed from the source code included with each diagnostic.
+"""
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.biome.biome_issue import BiomeIssue
+
+
+def parse_biome_output(output: str) -> list[BiomeIssue]:
+    """Parse Biome JSON output into a list of BiomeIssue objects.
+
+    Args:
+        output: The raw JSON output from Biome.
+
+    Returns:
+        List of BiomeIssue objects.
+    """
+    issues: list[BiomeIssue] = []
+
+    if not output:
+        return issues
+
+    try:
+        # Biome may print warnings around the JSON report
+        json_start = output.find("{")
+        json_end = output.rfind("}") + 1
+        if json_start == -1 or json_end == 0:
+            return issues
+        biome_data: dict[str, Any] = json.loads(output[json_start:json_end])
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse Biome JSON output: {e}")
+        return issues
+
+    if not isinstance(biome_data, dict):
+        logger.debug("Biome output is not a dictionary")
+        return issues
+
+    diagnostics = biome_data.get("diagnostics", [])
+    if not isinstance(diagnostics, list):
+        logger.debug("Biome diagnostics is not a list")
+        return issues
+
+    for diagnostic in diagnostics:
+        if not isinstance(diagnostic, dict):
+            continue
+        try:
+            issue = _parse_diagnostic(diagnostic)
+            if issue is not None:
+                issues.append(issue)
+        except (KeyError, TypeError, ValueError) as e:
+            logger.debug(f"Failed to parse Biome diagnostic: {e}")
+            continue
+
+    return issues
+
+
+def _offset_to_position(source: str, offset: int) -> tuple[int, int]:
+    """Convert a byte offset into a 1-based line and column.
+
+    Args:
+        source: Source code of the file.
+        offset: Byte offset into the UTF-8 encoded source.
+
+    Returns:
+        Tuple of (line, column).
+    """
+    prefix = source.encode("utf-8")[:offset].decode("utf-8", errors="ignore")
+    line = prefix.count("\n") + 1
+    column = len(prefix) - (prefix.rfind("\n") + 1) + 1
+    return line, column
+
+
+def _parse_diagnostic(diagnostic: dict[str, Any]) -> BiomeIssue | None:
+    """Parse a single Biome diagnostic into a BiomeIssue.
+
+    Args:
+        diagnostic: A single diagnostic dictionary from Biome output.
+
+    Returns:
+        BiomeIssue if parsing succeeds, None otherwise.
+    """
+    location = diagnostic.get("location")
+    if not isinstance(location, dict):
+        return None
+
+    # location.path is {"file": "..."} for file diagnostics
+    path = location.get("path")
+    file_path = path.get("file", "") if isinstance(path, dict) else ""
+    if not file_path:
+        return None
+
+    # Default to 1 for 1-based line/column numbering; format diagnostics
+    # cover the whole file and have no span
+    line = 1
+    column = 1
+    span = location.get("span")
+    source = location.get("sourceCode")
+    if isinstance(span, list) and span and isinstance(source, str):
+        line, column = _offset_to_position(source, int(span[0]))
+
+    tags = diagnostic.get("tags", [])
+    fixable = isinstance(tags, list) and "fixable" in tags
+
+    return BiomeIssue(
+        file=file_path,
+        line=line,
+        column=column,
+        message=str(diagnostic.get("description", "")).strip(),
+        code=str(diagnostic.get("category", "")),
+        severity=str(diagnostic.get("severity", "error")),
+        fixable=fixable,
+    )
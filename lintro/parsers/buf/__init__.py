@@ -0,0 +1,10 @@
+"""Parsing utilities and types for buf output."""
+
+from lintro.parsers.buf.buf_issue import BufIssue
+from lintro.parsers.buf.buf_parser import (
+    BufReport,
+    parse_buf_format_output,
+    parse_buf_output,
+)
+
+__all__ = ["BufIssue", "BufReport", "parse_buf_format_output", "parse_buf_output"]
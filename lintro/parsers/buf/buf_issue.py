@@ -0,0 +1,30 @@
+"""Typed structure representing a single buf finding."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class BufIssue(BaseIssue):
+    """Simple container for buf lint, format and breaking findings.
+
+    Attributes:
+        code: buf rule that reported the issue, such as
+            ``FIELD_LOWER_SNAKE_CASE`` or ``FIELD_NO_DELETE``; ``COMPILE``
+            for files that do not compile and ``FORMAT`` for formatting
+            hunks.
+        severity: 'error' for compile errors and breaking changes, 'info'
+            for formatting hunks, empty for lint findings.
+        fixable: Whether ``buf format -w`` fixes the issue.
+        end_line: Last line of the reported range, if known.
+        end_column: Last column of the reported range, if known.
+        diff: Preview of a formatting hunk in unified diff style.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    fixable: bool = field(default=False)
+    end_line: int | None = field(default=None)
+    end_column: int | None = field(default=None)
+    diff: str = field(default="", repr=False)
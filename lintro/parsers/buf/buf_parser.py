@@ -0,0 +1,122 @@
+"""Parser for buf output.
+
+``buf lint`` and ``buf breaking`` with ``--error-format=json`` print one
+JSON object per finding::
+
+    {"path":"acme/v1/pet.proto","start_line":12,"start_column":3,
+     "end_line":12,"end_column":20,"type":"FIELD_LOWER_SNAKE_CASE",
+     "message":"Field name \"petID\" should be lower_snake_case."}
+
+Files that fail to compile are reported the same way with the ``COMPILE``
+type. Anything else, such as ``Failure: ...`` lines for an invalid
+``buf.yaml`` or an unknown ``--against`` input, is collected as an error.
+
+``buf format --diff`` prints a unified diff in the same shape as
+``gofmt -d``, so its hunks are read with the gofmt parser.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any
+
+from lintro.parsers.buf.buf_issue import BufIssue
+from lintro.parsers.gofmt.gofmt_parser import parse_gofmt_output
+
+# Finding type buf uses for files that do not compile
+COMPILE_TYPE: str = "COMPILE"
+
+
+@dataclass
+class BufReport:
+    """Parsed buf lint or breaking report.
+
+    Attributes:
+        issues: Findings for the checked files.
+        errors: Lines buf printed that are not findings.
+    """
+
+    issues: list[BufIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def parse_buf_output(output: str | None, breaking: bool = False) -> BufReport:
+    """Parse ``buf lint`` or ``buf breaking`` JSON output.
+
+    Args:
+        output: Raw stdout/stderr from buf with ``--error-format=json``.
+        breaking: Whether the output comes from ``buf breaking``, whose
+            findings are reported as errors.
+
+    Returns:
+        BufReport: Parsed findings and errors.
+    """
+    report = BufReport()
+    if not output:
+        return report
+
+    for raw_line in output.splitlines():
+        line = raw_line.strip()
+        if not line:
+            continue
+        data: Any = None
+        if line.startswith("{"):
+            try:
+                data = json.loads(line)
+            except json.JSONDecodeError:
+                data = None
+        if isinstance(data, dict) and "path" in data:
+            report.issues.append(_parse_finding(data, breaking))
+        else:
+            report.errors.append(line)
+    return report
+
+
+def _parse_finding(data: dict[str, Any], breaking: bool) -> BufIssue:
+    """Parse a single buf JSON finding.
+
+    Args:
+        data: Decoded JSON object of the finding.
+        breaking: Whether the finding comes from ``buf breaking``.
+
+    Returns:
+        BufIssue for the finding.
+    """
+    code = str(data.get("type", ""))
+    return BufIssue(
+        file=str(data.get("path", "")),
+        line=int(data.get("start_line") or 0),
+        column=int(data.get("start_column") or 0),
+        message=str(data.get("message", "")),
+        code=code,
+        severity="error" if breaking or code == COMPILE_TYPE else "",
+        end_line=int(data["end_line"]) if data.get("end_line") else None,
+        end_column=int(data["end_column"]) if data.get("end_column") else None,
+    )
+
+
+def parse_buf_format_output(output: str | None) -> list[BufIssue]:
+    """Parse ``buf format --diff`` output into issues.
+
+    Args:
+        output: Raw stdout from ``buf format --diff``.
+
+    Returns:
+        List of fixable issues, one per diff hunk.
+    """
+    return [
+        BufIssue(
+            file=hunk.file,
+            line=hunk.line,
+            column=hunk.column,
+            message=hunk.message,
+            code="FORMAT",
+            severity="info",
+            fixable=True,
+            end_line=hunk.end_line,
+            diff=hunk.diff,
+        )
+        for hunk in parse_gofmt_output(output)
+        if hunk.code == "FORMAT"
+    ]
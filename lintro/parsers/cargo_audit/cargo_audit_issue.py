@@ -19,6 +19,8 @@ class CargoAuditIssue(BaseIssue):
         title: Short title of the vulnerability.
         description: Detailed description of the vulnerability.
         url: URL with more information about the advisory.
+        patched_versions: Version requirements that fix the vulnerability;
+            empty if no fixed release exists.
     """
 
     DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
@@ -35,6 +37,7 @@ class CargoAuditIssue(BaseIssue):
     title: str = field(default="")
     description: str = field(default="")
     url: str = field(default="")
+    patched_versions: list[str] = field(default_factory=list)
 
     def __post_init__(self) -> None:
         """Initialize the inherited fields."""
@@ -50,7 +53,12 @@ class CargoAuditIssue(BaseIssue):
         Returns:
             str: Formatted issue message.
         """
+        patched = (
+            f"upgrade to {' or '.join(self.patched_versions)}"
+            if self.patched_versions
+            else "no patched version"
+        )
         return (
             f"[{self.advisory_id}] {self.package_name}@{self.package_version}: "
-            f"{self.title}"
+            f"{self.title} ({patched})"
         )
@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 import json
+import math
 from typing import Any
 
 from loguru import logger
@@ -81,6 +82,112 @@ def _normalize_severity(severity: str | None) -> str:
     return severity_map.get(normalized, "UNKNOWN")
 
 
+# CVSS v3 base metric weights
+_CVSS_WEIGHTS: dict[str, dict[str, float]] = {
+    "AV": {"N": 0.85, "A": 0.62, "L": 0.55, "P": 0.2},
+    "AC": {"L": 0.77, "H": 0.44},
+    "UI": {"N": 0.85, "R": 0.62},
+    "C": {"H": 0.56, "L": 0.22, "N": 0.0},
+    "I": {"H": 0.56, "L": 0.22, "N": 0.0},
+    "A": {"H": 0.56, "L": 0.22, "N": 0.0},
+}
+# Privileges required weigh more when the scope changes
+_CVSS_PR_WEIGHTS: dict[str, dict[str, float]] = {
+    "U": {"N": 0.85, "L": 0.62, "H": 0.27},
+    "C": {"N": 0.85, "L": 0.68, "H": 0.5},
+}
+
+
+def _cvss_roundup(value: float) -> float:
+    """Round up to one decimal as the CVSS v3.1 specification defines.
+
+    Args:
+        value: Score to round.
+
+    Returns:
+        float: Smallest one-decimal number not below the value.
+    """
+    scaled = round(value * 100000)
+    if scaled % 10000 == 0:
+        return scaled / 100000.0
+    return (math.floor(scaled / 10000) + 1) / 10.0
+
+
+def cvss_base_score(vector: str) -> float | None:
+    """Compute the base score of a CVSS v3 vector.
+
+    RustSec advisories usually carry a CVSS vector instead of a severity.
+
+    Args:
+        vector: Vector such as ``CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H``.
+
+    Returns:
+        float | None: Base score from 0.0 to 10.0, or None if the vector is
+        not a complete CVSS v3 vector.
+    """
+    parts = vector.strip().split("/")
+    if not parts or not parts[0].startswith("CVSS:3"):
+        return None
+    metrics = dict(part.split(":", 1) for part in parts[1:] if ":" in part)
+    try:
+        scope = metrics["S"]
+        weights = {key: _CVSS_WEIGHTS[key][metrics[key]] for key in _CVSS_WEIGHTS}
+        privileges = _CVSS_PR_WEIGHTS[scope][metrics["PR"]]
+    except KeyError:
+        return None
+
+    iss = 1 - (1 - weights["C"]) * (1 - weights["I"]) * (1 - weights["A"])
+    if scope == "U":
+        impact = 6.42 * iss
+    else:
+        impact = 7.52 * (iss - 0.029) - 3.25 * (iss - 0.02) ** 15
+    exploitability = 8.22 * weights["AV"] * weights["AC"] * privileges * weights["UI"]
+    if impact <= 0:
+        return 0.0
+    if scope == "U":
+        return _cvss_roundup(min(impact + exploitability, 10))
+    return _cvss_roundup(min(1.08 * (impact + exploitability), 10))
+
+
+def _cvss_severity(vector: str | None) -> str:
+    """Map a CVSS v3 vector to its qualitative severity rating.
+
+    Args:
+        vector: CVSS vector of the advisory, if any.
+
+    Returns:
+        str: LOW, MEDIUM, HIGH or CRITICAL; UNKNOWN without a usable vector.
+    """
+    score = cvss_base_score(vector) if isinstance(vector, str) else None
+    if score is None:
+        return "UNKNOWN"
+    if score >= 9.0:
+        return "CRITICAL"
+    if score >= 7.0:
+        return "HIGH"
+    if score >= 4.0:
+        return "MEDIUM"
+    return "LOW"
+
+
+def _patched_versions(vuln: dict[str, Any]) -> list[str]:
+    """Read the version requirements that fix a vulnerability.
+
+    Args:
+        vuln: Vulnerability entry from cargo-audit.
+
+    Returns:
+        list[str]: Requirements such as ``>=0.7.4``; empty if none exist.
+    """
+    versions = vuln.get("versions")
+    if not isinstance(versions, dict):
+        return []
+    patched = versions.get("patched")
+    if not isinstance(patched, list):
+        return []
+    return [str(version) for version in patched if version]
+
+
 def parse_cargo_audit_output(
     output: str | None,
 ) -> list[CargoAuditIssue]:
@@ -139,7 +246,10 @@ def parse_cargo_audit_output(
 
             title = validate_str_field(advisory.get("title"), "title")
             description = validate_str_field(advisory.get("description"), "description")
-            severity = _normalize_severity(advisory.get("severity"))
+            if advisory.get("severity"):
+                severity = _normalize_severity(advisory.get("severity"))
+            else:
+                severity = _cvss_severity(advisory.get("cvss"))
             url = validate_str_field(advisory.get("url"), "url")
 
             # Extract package information
@@ -164,6 +274,7 @@ def parse_cargo_audit_output(
                 title=title,
                 description=description,
                 url=url,
+                patched_versions=_patched_versions(vuln),
             )
             issues.append(issue)
 
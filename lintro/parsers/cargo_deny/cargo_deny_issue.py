@@ -22,6 +22,8 @@ class CargoDenyIssue(BaseIssue):
     Attributes:
         DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
         code: Issue code (e.g., L001, A001, B001), empty string if not present.
+        check: cargo-deny check that raised the issue (advisories, bans,
+            licenses or sources), if known.
         severity: Severity level (error, warning).
         crate_name: Name of the affected crate.
         crate_version: Version of the affected crate.
@@ -37,6 +39,7 @@ class CargoDenyIssue(BaseIssue):
     }
 
     code: str = field(default="")
+    check: str | None = field(default=None)
     severity: str | None = field(default=None)
     crate_name: str | None = field(default=None)
     crate_version: str | None = field(default=None)
@@ -1,13 +1,226 @@
-"""Parser for cargo-deny JSON output."""
+"""Parser for cargo-deny JSON output.
+
+cargo-deny reports crates, not file positions. When the project root is
+known, each issue is placed on the crate's entry in ``Cargo.toml`` (direct
+dependencies) or its ``[[package]]`` stanza in ``Cargo.lock``; issues about
+the configuration itself go to ``deny.toml``.
+"""
 
 from __future__ import annotations
 
 import json
+import os
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
 from typing import Any
 
 from loguru import logger
 
 from lintro.parsers.cargo_deny.cargo_deny_issue import CargoDenyIssue
+from lintro.utils.cargo_manifest import dependency_lines
+
+CARGO_DENY_CHECKS: tuple[str, ...] = ("advisories", "bans", "licenses", "sources")
+
+# Diagnostic codes of cargo-deny mapped to the check that emits them
+_CHECK_BY_CODE: dict[str, str] = {
+    **dict.fromkeys(
+        (
+            "vulnerability",
+            "notice",
+            "unmaintained",
+            "unsound",
+            "yanked",
+            "index-failure",
+            "advisory-not-detected",
+            "unknown-advisory",
+        ),
+        "advisories",
+    ),
+    **dict.fromkeys(
+        (
+            "banned",
+            "allowed",
+            "not-allowed",
+            "duplicate",
+            "skipped",
+            "wildcard",
+            "unmatched-skip",
+            "unmatched-skip-root",
+            "build-script-not-allowed",
+            "exact-features-mismatch",
+            "feature-banned",
+            "unknown-feature",
+            "default-feature-enabled",
+            "workspace-duplicate",
+            "unused-workspace-dependency",
+        ),
+        "bans",
+    ),
+    **dict.fromkeys(
+        (
+            "rejected",
+            "accepted",
+            "unlicensed",
+            "skipped-private-workspace-crate",
+            "license-not-encountered",
+            "license-exception-not-encountered",
+            "missing-clarification-file",
+            "parse-error",
+            "empty-license-field",
+            "no-license-field",
+            "gather-failure",
+        ),
+        "licenses",
+    ),
+    **dict.fromkeys(
+        (
+            "git-source-underspecified",
+            "allowed-source",
+            "allowed-by-organization",
+            "source-not-allowed",
+            "unmatched-source",
+            "unmatched-organization",
+        ),
+        "sources",
+    ),
+}
+
+# Single-letter prefixes of the older numbered codes (L001, A001, ...)
+_CHECK_BY_PREFIX: dict[str, str] = {
+    "A": "advisories",
+    "B": "bans",
+    "D": "bans",
+    "L": "licenses",
+    "S": "sources",
+}
+
+_STRING_VALUE_RE = re.compile(r'^\s*(name|version)\s*=\s*"([^"]*)"')
+
+
+def check_for_code(code: str) -> str | None:
+    """Return the cargo-deny check that emits a diagnostic code.
+
+    Args:
+        code: Diagnostic code, such as ``rejected`` or ``L001``.
+
+    Returns:
+        str | None: ``advisories``, ``bans``, ``licenses`` or ``sources``, or
+        None for codes that are not tied to one check.
+    """
+    if code in _CHECK_BY_CODE:
+        return _CHECK_BY_CODE[code]
+    if re.fullmatch(r"[A-Z]\d+", code):
+        return _CHECK_BY_PREFIX.get(code[0])
+    return None
+
+
+def _lock_lines(path: Path) -> dict[tuple[str, str | None], int]:
+    """Find the ``[[package]]`` stanza of every crate in a lock file.
+
+    Args:
+        path: Cargo.lock to read.
+
+    Returns:
+        dict[tuple[str, str | None], int]: Line of each stanza's ``name``,
+        keyed by (name, version) and by (name, None) for its first version.
+    """
+    lines: dict[tuple[str, str | None], int] = {}
+    name: str | None = None
+    name_line = 0
+    for number, text in enumerate(path.read_text(encoding="utf-8").splitlines(), 1):
+        if text.strip() == "[[package]]":
+            name = None
+            continue
+        value = _STRING_VALUE_RE.match(text)
+        if not value:
+            continue
+        if value.group(1) == "name":
+            name, name_line = value.group(2), number
+            lines.setdefault((name, None), number)
+        elif name is not None:
+            lines.setdefault((name, value.group(2)), name_line)
+    return lines
+
+
+@dataclass
+class CrateLocator:
+    """Places cargo-deny issues on lines of a Cargo project's files.
+
+    Attributes:
+        root: Directory containing Cargo.toml.
+        manifest: Declaration line of each direct dependency.
+        lock: Stanza line of each locked crate.
+    """
+
+    root: Path
+    manifest: dict[str, int] = field(default_factory=dict)
+    lock: dict[tuple[str, str | None], int] = field(default_factory=dict)
+
+    @classmethod
+    def load(cls, root: Path) -> CrateLocator:
+        """Read the manifest and lock file of a Cargo project.
+
+        Missing or unreadable files leave their lookup empty.
+
+        Args:
+            root: Directory containing Cargo.toml.
+
+        Returns:
+            CrateLocator: Locator for the project.
+        """
+        locator = cls(root=root)
+        try:
+            locator.manifest = dependency_lines(root / "Cargo.toml")
+        except (OSError, UnicodeDecodeError) as e:
+            logger.debug(f"Cannot read {root / 'Cargo.toml'}: {e}")
+        try:
+            locator.lock = _lock_lines(root / "Cargo.lock")
+        except (OSError, UnicodeDecodeError) as e:
+            logger.debug(f"Cannot read {root / 'Cargo.lock'}: {e}")
+        return locator
+
+    def _path(self, name: str) -> str:
+        """Return a project file's path relative to the working directory.
+
+        Args:
+            name: File name inside the project root.
+
+        Returns:
+            str: Relative path, or absolute when on another drive.
+        """
+        try:
+            return os.path.relpath(self.root / name)
+        except ValueError:
+            return str(self.root / name)
+
+    def locate(
+        self,
+        crate_name: str | None,
+        crate_version: str | None,
+        label_line: int | None = None,
+    ) -> tuple[str, int]:
+        """Find the file and line an issue belongs to.
+
+        Args:
+            crate_name: Affected crate, if any.
+            crate_version: Affected version, if known.
+            label_line: Line cargo-deny pointed at, used for deny.toml.
+
+        Returns:
+            tuple[str, int]: File path and line (0 when unknown).
+        """
+        if crate_name:
+            if crate_name in self.manifest:
+                return self._path("Cargo.toml"), self.manifest[crate_name]
+            line = self.lock.get((crate_name, crate_version)) or self.lock.get(
+                (crate_name, None),
+            )
+            if line:
+                return self._path("Cargo.lock"), line
+        elif (self.root / "deny.toml").is_file():
+            return self._path("deny.toml"), label_line or 0
+        return self._path("Cargo.toml"), 0
 
 
 def _extract_crate_info(labels: list[dict[str, Any]]) -> tuple[str | None, str | None]:
@@ -33,11 +246,51 @@ def _extract_crate_info(labels: list[dict[str, Any]]) -> tuple[str | None, str |
     return None, None
 
 
-def _parse_diagnostic(item: dict[str, Any]) -> CargoDenyIssue | None:
+def _graph_crate(graphs: Any) -> tuple[str | None, str | None]:
+    """Extract the crate a diagnostic's dependency graph starts from.
+
+    Args:
+        graphs: ``graphs`` list of the diagnostic.
+
+    Returns:
+        Tuple of (crate_name, crate_version).
+    """
+    if not isinstance(graphs, list) or not graphs:
+        return None, None
+    first = graphs[0]
+    krate = first.get("Krate") if isinstance(first, dict) else None
+    if not isinstance(krate, dict) or not krate.get("name"):
+        return None, None
+    version = krate.get("version")
+    return str(krate["name"]), str(version) if version else None
+
+
+def _label_line(labels: Any) -> int | None:
+    """Return the first line number among a diagnostic's labels.
+
+    Args:
+        labels: ``labels`` list of the diagnostic.
+
+    Returns:
+        int | None: Line number, if any label has one.
+    """
+    if isinstance(labels, list):
+        for label in labels:
+            line = label.get("line") if isinstance(label, dict) else None
+            if isinstance(line, int) and line > 0:
+                return line
+    return None
+
+
+def _parse_diagnostic(
+    item: dict[str, Any],
+    locator: CrateLocator | None = None,
+) -> CargoDenyIssue | None:
     """Parse a diagnostic message from cargo-deny output.
 
     Args:
         item: A diagnostic JSON object.
+        locator: Places the issue in the project's files, if available.
 
     Returns:
         CargoDenyIssue or None if parsing fails.
@@ -58,31 +311,48 @@ def _parse_diagnostic(item: dict[str, Any]) -> CargoDenyIssue | None:
         # Normalize severity to lowercase
         severity = severity.lower()
 
-        # Extract crate info from labels
+        # Extract crate info from labels, falling back to the dependency graph
         crate_name, crate_version = None, None
         if isinstance(labels, list):
             crate_name, crate_version = _extract_crate_info(labels)
+        if crate_name is None:
+            crate_name, crate_version = _graph_crate(fields.get("graphs"))
+
+        advisory = fields.get("advisory")
+        advisory_id = advisory.get("id") if isinstance(advisory, dict) else None
+        check = check_for_code(str(code)) or ("advisories" if advisory_id else None)
+
+        file, line = "Cargo.toml", 0
+        if locator is not None:
+            label_line = _label_line(labels)
+            file, line = locator.locate(crate_name, crate_version, label_line)
 
         return CargoDenyIssue(
-            file="Cargo.toml",  # cargo-deny operates at project level
-            line=0,  # No line information available
+            file=file,
+            line=line,
             column=0,
             code=str(code) if code else "",
+            check=check,
             severity=severity,
             message=str(message) if message else "",
             crate_name=crate_name,
             crate_version=crate_version,
+            advisory_id=str(advisory_id) if advisory_id else None,
         )
     except (KeyError, TypeError, ValueError) as e:
         logger.debug(f"Failed to parse cargo-deny diagnostic: {e}")
         return None
 
 
-def _parse_advisory(item: dict[str, Any]) -> CargoDenyIssue | None:
+def _parse_advisory(
+    item: dict[str, Any],
+    locator: CrateLocator | None = None,
+) -> CargoDenyIssue | None:
     """Parse an advisory message from cargo-deny output.
 
     Args:
         item: An advisory JSON object.
+        locator: Places the issue in the project's files, if available.
 
     Returns:
         CargoDenyIssue or None if parsing fails.
@@ -114,15 +384,22 @@ def _parse_advisory(item: dict[str, Any]) -> CargoDenyIssue | None:
         if isinstance(package, dict):
             crate_name = package.get("name")
             crate_version = package.get("version")
+        crate_name = str(crate_name) if crate_name else None
+        crate_version = str(crate_version) if crate_version else None
+
+        file, line = "Cargo.toml", 0
+        if locator is not None:
+            file, line = locator.locate(crate_name, crate_version)
 
         return CargoDenyIssue(
-            file="Cargo.toml",
-            line=0,
+            file=file,
+            line=line,
             column=0,
             code=str(advisory_id) if advisory_id else "ADVISORY",
+            check="advisories",
             severity="error",  # Advisories are treated as errors
-            crate_name=str(crate_name) if crate_name else None,
-            crate_version=str(crate_version) if crate_version else None,
+            crate_name=crate_name,
+            crate_version=crate_version,
             advisory_id=str(advisory_id) if advisory_id else None,
             advisory_severity=str(advisory_severity) if advisory_severity else None,
             patched_versions=patched,
@@ -132,7 +409,10 @@ def _parse_advisory(item: dict[str, Any]) -> CargoDenyIssue | None:
         return None
 
 
-def parse_cargo_deny_output(output: str) -> list[CargoDenyIssue]:
+def parse_cargo_deny_output(
+    output: str,
+    cargo_root: Path | None = None,
+) -> list[CargoDenyIssue]:
     """Parse cargo-deny JSON Lines output into CargoDenyIssue objects.
 
     cargo-deny outputs JSON Lines format (one JSON object per line) when
@@ -142,6 +422,9 @@ def parse_cargo_deny_output(output: str) -> list[CargoDenyIssue]:
 
     Args:
         output: Raw stdout emitted by cargo deny check --format json.
+        cargo_root: Directory containing Cargo.toml. When given, issues are
+            placed on crate lines in Cargo.toml, Cargo.lock or deny.toml
+            instead of line 0 of Cargo.toml.
 
     Returns:
         A list of CargoDenyIssue instances. Returns an empty list when
@@ -151,6 +434,7 @@ def parse_cargo_deny_output(output: str) -> list[CargoDenyIssue]:
         return []
 
     issues: list[CargoDenyIssue] = []
+    locator = CrateLocator.load(cargo_root) if cargo_root is not None else None
 
     for line in output.splitlines():
         line = line.strip()
@@ -165,11 +449,11 @@ def parse_cargo_deny_output(output: str) -> list[CargoDenyIssue]:
             item_type = data.get("type", "")
 
             if item_type == "diagnostic":
-                parsed = _parse_diagnostic(data)
+                parsed = _parse_diagnostic(data, locator)
                 if parsed is not None:
                     issues.append(parsed)
             elif item_type == "advisory":
-                parsed = _parse_advisory(data)
+                parsed = _parse_advisory(data, locator)
                 if parsed is not None:
                     issues.append(parsed)
             # Ignore other types like "summary", "build", etc.
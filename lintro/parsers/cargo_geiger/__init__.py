@@ -0,0 +1,8 @@
+"""cargo-geiger parser module."""
+
+from lintro.parsers.cargo_geiger.cargo_geiger_issue import CargoGeigerIssue
+from lintro.parsers.cargo_geiger.cargo_geiger_parser import (
+    parse_cargo_geiger_output,
+)
+
+__all__ = ["CargoGeigerIssue", "parse_cargo_geiger_output"]
@@ -0,0 +1,75 @@
+"""Models for cargo-geiger issues."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+CARGO_GEIGER_USAGE_CODE: str = "unsafe-usage"
+CARGO_GEIGER_BUDGET_CODE: str = "unsafe-budget"
+
+
+@dataclass
+class CargoGeigerIssue(BaseIssue):
+    """Represents unsafe code used by a crate, or an exceeded unsafe budget.
+
+    Attributes:
+        code: ``unsafe-usage`` for a crate's counts, ``unsafe-budget`` when
+            the configured budget is exceeded.
+        crate_name: Crate the counts belong to; empty for the budget.
+        version: Version of the crate.
+        local: Whether the crate belongs to the project rather than being
+            a dependency.
+        functions: Unsafe functions used.
+        exprs: Unsafe expressions used.
+        item_impls: Unsafe trait implementations used.
+        item_traits: Unsafe traits used.
+        methods: Unsafe methods used.
+        severity: ``info`` for usage, ``error`` for an exceeded budget.
+    """
+
+    code: str = field(default=CARGO_GEIGER_USAGE_CODE)
+    crate_name: str = field(default="")
+    version: str = field(default="")
+    local: bool = field(default=False)
+    functions: int = field(default=0)
+    exprs: int = field(default=0)
+    item_impls: int = field(default=0)
+    item_traits: int = field(default=0)
+    methods: int = field(default=0)
+    severity: str = field(default="info")
+
+    @property
+    def unsafe_count(self) -> int:
+        """Return the unsafe items used by the crate.
+
+        Returns:
+            int: Sum of every unsafe category.
+        """
+        return (
+            self.functions
+            + self.exprs
+            + self.item_impls
+            + self.item_traits
+            + self.methods
+        )
+
+    def __post_init__(self) -> None:
+        """Initialize the message field from issue details."""
+        if not self.message:
+            parts = [
+                f"{count} {label}"
+                for count, label in (
+                    (self.functions, "functions"),
+                    (self.exprs, "expressions"),
+                    (self.methods, "methods"),
+                    (self.item_impls, "impls"),
+                    (self.item_traits, "traits"),
+                )
+                if count
+            ]
+            self.message = (
+                f"{self.crate_name} {self.version} uses unsafe "
+                f"{self.unsafe_count} times ({', '.join(parts)})"
+            )
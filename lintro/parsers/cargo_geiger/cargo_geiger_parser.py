@@ -0,0 +1,220 @@
+"""Parser for cargo-geiger JSON output.
+
+``cargo geiger --output-format Json`` prints one JSON report::
+
+    {"packages": [{"package": {"id": {"name": "libc", "version": "0.2.155",
+      "source": {"Registry": {"name": "crates.io", "url": "..."}}}},
+      "unsafety": {"used": {"functions": {"safe": 1, "unsafe_": 0},
+      "exprs": {"safe": 10, "unsafe_": 4}, ...}, "unused": {...},
+      "forbids_unsafe": false}}], ...}
+
+Every crate that uses unsafe code becomes an informational issue. The
+report holds counts per crate, not source locations, so a project crate's
+issue points at its manifest and a dependency's at the line declaring it.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+from pathlib import Path
+from typing import Any
+from urllib.parse import unquote, urlparse
+
+from loguru import logger
+
+from lintro.parsers.cargo_geiger.cargo_geiger_issue import (
+    CARGO_GEIGER_BUDGET_CODE,
+    CargoGeigerIssue,
+)
+from lintro.utils.cargo_manifest import dependency_lines
+
+_CATEGORIES: tuple[str, ...] = (
+    "functions",
+    "exprs",
+    "item_impls",
+    "item_traits",
+    "methods",
+)
+
+
+def _relative(path: Path) -> str:
+    """Return a path relative to the working directory when possible.
+
+    Args:
+        path: Absolute path.
+
+    Returns:
+        str: Relative path, or the path itself when on another drive.
+    """
+    try:
+        return os.path.relpath(path)
+    except ValueError:
+        return str(path)
+
+
+def _report(output: str) -> dict[str, Any] | None:
+    """Extract the JSON report from cargo-geiger output.
+
+    Args:
+        output: Raw output; build progress and warnings around the report
+            are ignored.
+
+    Returns:
+        dict[str, Any] | None: The report, or None if there is none.
+    """
+    for text in output.splitlines():
+        text = text.strip()
+        if not text.startswith("{"):
+            continue
+        try:
+            data = json.loads(text)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Skipping unparseable cargo-geiger line: {e}")
+            continue
+        if isinstance(data, dict) and "packages" in data:
+            return data
+    return None
+
+
+def _local_directory(source: Any) -> Path | None:
+    """Return the directory of a crate built from a local path.
+
+    Args:
+        source: ``source`` of a cargo-geiger package id, such as
+            ``{"Path": "file:///src/app"}``.
+
+    Returns:
+        Path | None: Crate directory, or None for registry and git crates.
+    """
+    if not isinstance(source, dict) or not isinstance(source.get("Path"), str):
+        return None
+    url = urlparse(source["Path"])
+    return Path(unquote(url.path)) if url.scheme == "file" else None
+
+
+def _used_unsafe(unsafety: Any) -> dict[str, int]:
+    """Read the unsafe counts of the code a crate actually compiles.
+
+    Args:
+        unsafety: ``unsafety`` entry of a package.
+
+    Returns:
+        dict[str, int]: Unsafe items used per category.
+    """
+    used = unsafety.get("used") if isinstance(unsafety, dict) else None
+    counts: dict[str, int] = {}
+    for category in _CATEGORIES:
+        count = used.get(category) if isinstance(used, dict) else None
+        value = count.get("unsafe_") if isinstance(count, dict) else None
+        counts[category] = value if isinstance(value, int) else 0
+    return counts
+
+
+def _declarations(manifests: list[Path]) -> dict[str, tuple[Path, int]]:
+    """Find where the project's manifests declare each dependency.
+
+    Args:
+        manifests: Manifests of the project's crates, root first.
+
+    Returns:
+        dict[str, tuple[Path, int]]: Manifest and line per dependency.
+    """
+    declared: dict[str, tuple[Path, int]] = {}
+    for manifest in manifests:
+        try:
+            lines = dependency_lines(manifest)
+        except (OSError, UnicodeDecodeError) as e:
+            logger.debug(f"Cannot read {manifest}: {e}")
+            continue
+        for name, line in lines.items():
+            declared.setdefault(name, (manifest, line))
+    return declared
+
+
+def parse_cargo_geiger_output(
+    output: str,
+    cargo_root: Path | None = None,
+) -> list[CargoGeigerIssue]:
+    """Parse cargo-geiger JSON output into CargoGeigerIssue objects.
+
+    Args:
+        output: Raw output emitted by ``cargo geiger --output-format Json``.
+        cargo_root: Directory cargo-geiger ran in; when given, dependency
+            issues are placed on the line declaring the dependency.
+
+    Returns:
+        A list of CargoGeigerIssue instances for crates that use unsafe code,
+        empty when none do or the output cannot be parsed.
+    """
+    if not output or not output.strip():
+        return []
+    report = _report(output)
+    if report is None:
+        return []
+
+    packages = [p for p in report.get("packages") or [] if isinstance(p, dict)]
+    ids = [(p.get("package") or {}).get("id") or {} for p in packages]
+    local_dirs = [_local_directory(package_id.get("source")) for package_id in ids]
+    root_manifest = cargo_root / "Cargo.toml" if cargo_root is not None else None
+    manifests = [root_manifest] if root_manifest is not None else []
+    manifests += [d / "Cargo.toml" for d in local_dirs if d is not None]
+    declared = _declarations(manifests)
+
+    issues: list[CargoGeigerIssue] = []
+    for package, package_id, local_dir in zip(packages, ids, local_dirs, strict=True):
+        counts = _used_unsafe(package.get("unsafety"))
+        if not any(counts.values()):
+            continue
+        name = str(package_id.get("name") or "")
+        # Transitive dependencies have no declaration in the project
+        manifest, line = declared.get(name, (root_manifest, 0))
+        if local_dir is not None:
+            manifest, line = local_dir / "Cargo.toml", 0
+        issues.append(
+            CargoGeigerIssue(
+                file=_relative(manifest) if manifest else "Cargo.toml",
+                line=line,
+                crate_name=name,
+                version=str(package_id.get("version") or ""),
+                local=local_dir is not None,
+                **counts,
+            ),
+        )
+    return issues
+
+
+def budget_issue(
+    issues: list[CargoGeigerIssue],
+    max_unsafe: int,
+    count_dependencies: bool = False,
+    cargo_root: Path | None = None,
+) -> CargoGeigerIssue | None:
+    """Check the unsafe usage of a project against its budget.
+
+    Args:
+        issues: Usage issues from parse_cargo_geiger_output().
+        max_unsafe: Unsafe items the project may use.
+        count_dependencies: Whether dependencies count towards the budget;
+            by default only the project's own crates do.
+        cargo_root: Directory cargo-geiger ran in, where the issue is placed.
+
+    Returns:
+        CargoGeigerIssue | None: Error issue if the budget is exceeded.
+    """
+    counted = [issue for issue in issues if count_dependencies or issue.local]
+    total = sum(issue.unsafe_count for issue in counted)
+    if total <= max_unsafe:
+        return None
+    scope = "crates and dependencies" if count_dependencies else "project crates"
+    manifest = cargo_root / "Cargo.toml" if cargo_root is not None else None
+    return CargoGeigerIssue(
+        file=_relative(manifest) if manifest else "Cargo.toml",
+        line=0,
+        code=CARGO_GEIGER_BUDGET_CODE,
+        severity="error",
+        message=(
+            f"unsafe budget exceeded: {total} unsafe items used in {scope}, "
+            f"budget is {max_unsafe}"
+        ),
+    )
@@ -0,0 +1,8 @@
+"""cargo-machete parser module."""
+
+from lintro.parsers.cargo_machete.cargo_machete_issue import CargoMacheteIssue
+from lintro.parsers.cargo_machete.cargo_machete_parser import (
+    parse_cargo_machete_output,
+)
+
+__all__ = ["CargoMacheteIssue", "parse_cargo_machete_output"]
@@ -0,0 +1,33 @@
+"""Models for cargo-machete issues."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+CARGO_MACHETE_UNUSED_CODE: str = "unused-dependency"
+
+
+@dataclass
+class CargoMacheteIssue(BaseIssue):
+    """Represents a dependency cargo-machete found unused.
+
+    Attributes:
+        code: Always ``unused-dependency``.
+        crate_name: Crate whose manifest declares the dependency.
+        dependency: Name of the unused dependency in the manifest.
+        fixable: Whether ``cargo machete --fix`` can remove it. Always True.
+    """
+
+    code: str = field(default=CARGO_MACHETE_UNUSED_CODE)
+    crate_name: str = field(default="")
+    dependency: str = field(default="")
+    fixable: bool = field(default=True)
+
+    def __post_init__(self) -> None:
+        """Initialize the message field from issue details."""
+        if not self.message:
+            self.message = f"unused dependency '{self.dependency}'"
+            if self.crate_name:
+                self.message += f" in crate {self.crate_name}"
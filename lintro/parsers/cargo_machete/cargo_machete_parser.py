@@ -0,0 +1,100 @@
+"""Parser for cargo-machete output.
+
+cargo-machete has no machine-readable format. It lists each crate with
+unused dependencies followed by the dependencies, one per indented line::
+
+    cargo-machete found the following unused dependencies in this directory:
+    app -- ./Cargo.toml:
+            serde
+            log
+
+Each dependency becomes an issue on the line of the manifest declaring it.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from pathlib import Path
+
+from loguru import logger
+
+from lintro.parsers.cargo_machete.cargo_machete_issue import CargoMacheteIssue
+from lintro.utils.cargo_manifest import dependency_lines
+
+_CRATE_RE = re.compile(r"^(?P<crate>\S+) -- (?P<manifest>.*Cargo\.toml):\s*$")
+_DEPENDENCY_RE = re.compile(r"^\s+(?P<dependency>[A-Za-z0-9_-]+)\s*$")
+
+
+def _relative(path: Path) -> str:
+    """Return a path relative to the working directory when possible.
+
+    Args:
+        path: Absolute path.
+
+    Returns:
+        str: Relative path, or the path itself when on another drive.
+    """
+    try:
+        return os.path.relpath(path)
+    except ValueError:
+        return str(path)
+
+
+def parse_cargo_machete_output(
+    output: str,
+    cargo_root: Path | None = None,
+) -> list[CargoMacheteIssue]:
+    """Parse cargo-machete output into CargoMacheteIssue objects.
+
+    Args:
+        output: Raw stdout emitted by cargo machete.
+        cargo_root: Directory cargo-machete ran in. Manifest paths in the
+            output are relative to it; when given, issues are placed on the
+            dependency's line in the manifest.
+
+    Returns:
+        A list of CargoMacheteIssue instances, empty when no dependency is
+        unused or the output cannot be parsed.
+    """
+    if not output or not output.strip():
+        return []
+
+    issues: list[CargoMacheteIssue] = []
+    crate: str | None = None
+    manifest = ""
+    lines: dict[str, int] = {}
+
+    for text in output.splitlines():
+        header = _CRATE_RE.match(text)
+        if header:
+            crate = header.group("crate")
+            path = Path(header.group("manifest"))
+            lines = {}
+            if cargo_root is not None:
+                path = (cargo_root / path).resolve()
+                try:
+                    lines = dependency_lines(path)
+                except (OSError, UnicodeDecodeError) as e:
+                    logger.debug(f"Cannot read {path}: {e}")
+                manifest = _relative(path)
+            else:
+                manifest = os.path.normpath(path)
+            continue
+
+        dependency = _DEPENDENCY_RE.match(text) if crate else None
+        if dependency is None:
+            # A blank line or any other text ends the crate's list
+            crate = None
+            continue
+        name = dependency.group("dependency")
+        issues.append(
+            CargoMacheteIssue(
+                file=manifest,
+                line=lines.get(name, 0),
+                crate_name=str(crate),
+                dependency=name,
+            ),
+        )
+
+    return issues
@@ -0,0 +1,8 @@
+"""cargo-outdated parser module."""
+
+from lintro.parsers.cargo_outdated.cargo_outdated_issue import CargoOutdatedIssue
+from lintro.parsers.cargo_outdated.cargo_outdated_parser import (
+    parse_cargo_outdated_output,
+)
+
+__all__ = ["CargoOutdatedIssue", "parse_cargo_outdated_output"]
@@ -0,0 +1,54 @@
+"""Models for cargo-outdated issues."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+CARGO_OUTDATED_MAJOR_CODE: str = "outdated-major"
+CARGO_OUTDATED_MINOR_CODE: str = "outdated-minor"
+
+
+@dataclass
+class CargoOutdatedIssue(BaseIssue):
+    """Represents a dependency with a newer release.
+
+    Attributes:
+        code: ``outdated-major`` when a breaking release is available,
+            otherwise ``outdated-minor``.
+        crate_name: Crate whose manifest declares the dependency.
+        dependency: Name of the outdated dependency.
+        current: Version the project uses.
+        compatible: Newest release the version requirement allows, if any.
+        latest: Newest release.
+        kind: Dependency kind, e.g. ``Normal``, ``Development`` or ``Build``.
+        majors_behind: Breaking releases between ``current`` and ``latest``.
+        severity: ``error``, ``warning`` or ``info``, from the configured
+            majors-behind thresholds.
+    """
+
+    code: str = field(default=CARGO_OUTDATED_MINOR_CODE)
+    crate_name: str = field(default="")
+    dependency: str = field(default="")
+    current: str = field(default="")
+    compatible: str | None = field(default=None)
+    latest: str = field(default="")
+    kind: str = field(default="")
+    majors_behind: int = field(default=0)
+    severity: str = field(default="info")
+
+    def __post_init__(self) -> None:
+        """Initialize the message field from issue details."""
+        if not self.message:
+            behind = (
+                f"{self.majors_behind} major version"
+                f"{'s' if self.majors_behind != 1 else ''} behind"
+                if self.majors_behind
+                else "behind"
+            )
+            self.message = (
+                f"{self.dependency} {self.current} is {behind} {self.latest}"
+            )
+            if self.compatible and self.compatible != self.current:
+                self.message += f" (compatible: {self.compatible})"
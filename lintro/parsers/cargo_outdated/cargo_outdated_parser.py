@@ -0,0 +1,237 @@
+"""Parser for cargo-outdated JSON output.
+
+``cargo outdated --format json`` prints one JSON object per crate::
+
+    {"crate_name": "app", "dependencies": [{"name": "clap",
+      "project": "3.2.25", "compat": "3.2.25", "latest": "4.5.4",
+      "kind": "Normal", "platform": null}]}
+
+Each outdated dependency becomes an issue whose severity depends on how many
+breaking releases it is behind. For ``0.x`` crates a minor bump is breaking,
+as in Cargo's own version resolution.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import re
+import tomllib
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.cargo_outdated.cargo_outdated_issue import (
+    CARGO_OUTDATED_MAJOR_CODE,
+    CARGO_OUTDATED_MINOR_CODE,
+    CargoOutdatedIssue,
+)
+from lintro.utils.cargo_manifest import dependency_lines
+
+DEFAULT_WARNING_MAJORS: int = 1
+DEFAULT_ERROR_MAJORS: int = 2
+
+_VERSION_RE = re.compile(r"^\s*(\d+)(?:\.(\d+))?(?:\.(\d+))?")
+_SKIP_DIRS: frozenset[str] = frozenset({"target", "node_modules"})
+
+
+def _version(value: str | None) -> tuple[int, int, int] | None:
+    """Parse the numeric part of a version.
+
+    Args:
+        value: Version as printed by cargo-outdated; ``---`` and
+            ``Removed`` are not versions.
+
+    Returns:
+        tuple[int, int, int] | None: Major, minor and patch, or None.
+    """
+    match = _VERSION_RE.match(value or "")
+    if not match:
+        return None
+    return (
+        int(match.group(1)),
+        int(match.group(2) or 0),
+        int(match.group(3) or 0),
+    )
+
+
+def majors_behind(current: str, latest: str) -> int:
+    """Count the breaking releases between two versions.
+
+    Args:
+        current: Version in use.
+        latest: Newest release.
+
+    Returns:
+        int: Breaking releases behind; 0 when only compatible releases are
+        newer or either version cannot be parsed.
+    """
+    used, newest = _version(current), _version(latest)
+    if used is None or newest is None:
+        return 0
+    if used[0] or newest[0]:
+        # Leaving 0.x counts as one breaking release per major reached
+        return max(0, newest[0] - used[0])
+    if used[1] or newest[1]:
+        return max(0, newest[1] - used[1])
+    return max(0, newest[2] - used[2])
+
+
+def classify(behind: int, warning_majors: int, error_majors: int) -> str:
+    """Map breaking releases behind to a severity.
+
+    Args:
+        behind: Breaking releases behind.
+        warning_majors: Releases behind from which a dependency is a warning.
+        error_majors: Releases behind from which a dependency is an error.
+
+    Returns:
+        str: ``error``, ``warning`` or ``info``.
+    """
+    if behind >= error_majors:
+        return "error"
+    if behind >= warning_majors:
+        return "warning"
+    return "info"
+
+
+def _package_manifests(cargo_root: Path) -> dict[str, Path]:
+    """Find the manifest of every package below a Cargo project.
+
+    Args:
+        cargo_root: Directory cargo-outdated ran in.
+
+    Returns:
+        dict[str, Path]: Manifest of each package, keyed by package name.
+    """
+    manifests: dict[str, Path] = {}
+    for dirpath, dirnames, filenames in os.walk(cargo_root):
+        dirnames[:] = sorted(
+            d for d in dirnames if d not in _SKIP_DIRS and not d.startswith(".")
+        )
+        if "Cargo.toml" not in filenames:
+            continue
+        path = Path(dirpath) / "Cargo.toml"
+        try:
+            data = tomllib.loads(path.read_text(encoding="utf-8"))
+        except (OSError, UnicodeDecodeError, tomllib.TOMLDecodeError) as e:
+            logger.debug(f"Cannot read {path}: {e}")
+            continue
+        name = (data.get("package") or {}).get("name")
+        if isinstance(name, str):
+            manifests.setdefault(name, path)
+    return manifests
+
+
+def _relative(path: Path) -> str:
+    """Return a path relative to the working directory when possible.
+
+    Args:
+        path: Absolute path.
+
+    Returns:
+        str: Relative path, or the path itself when on another drive.
+    """
+    try:
+        return os.path.relpath(path)
+    except ValueError:
+        return str(path)
+
+
+def _crate_reports(output: str) -> list[dict[str, Any]]:
+    """Extract the per-crate JSON objects from cargo-outdated output.
+
+    Args:
+        output: Raw stdout; lines that are not JSON objects are ignored.
+
+    Returns:
+        list[dict[str, Any]]: One report per crate.
+    """
+    reports: list[dict[str, Any]] = []
+    for text in output.splitlines():
+        text = text.strip()
+        if not text.startswith("{"):
+            continue
+        try:
+            data = json.loads(text)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Skipping unparseable cargo-outdated line: {e}")
+            continue
+        if isinstance(data, dict):
+            reports.append(data)
+    return reports
+
+
+def parse_cargo_outdated_output(
+    output: str,
+    cargo_root: Path | None = None,
+    warning_majors: int = DEFAULT_WARNING_MAJORS,
+    error_majors: int = DEFAULT_ERROR_MAJORS,
+) -> list[CargoOutdatedIssue]:
+    """Parse cargo-outdated JSON output into CargoOutdatedIssue objects.
+
+    Args:
+        output: Raw stdout emitted by ``cargo outdated --format json``.
+        cargo_root: Directory cargo-outdated ran in; when given, issues are
+            placed on the dependency's line in the crate's manifest.
+        warning_majors: Breaking releases behind from which a dependency is
+            a warning.
+        error_majors: Breaking releases behind from which a dependency is
+            an error.
+
+    Returns:
+        A list of CargoOutdatedIssue instances, empty when every dependency
+        is up to date or the output cannot be parsed.
+    """
+    if not output or not output.strip():
+        return []
+
+    manifests = _package_manifests(cargo_root) if cargo_root is not None else {}
+    issues: list[CargoOutdatedIssue] = []
+
+    for report in _crate_reports(output):
+        crate = str(report.get("crate_name") or "")
+        manifest = manifests.get(crate)
+        if manifest is None and cargo_root is not None:
+            manifest = cargo_root / "Cargo.toml"
+        lines: dict[str, int] = {}
+        if manifest is not None:
+            try:
+                lines = dependency_lines(manifest)
+            except (OSError, UnicodeDecodeError) as e:
+                logger.debug(f"Cannot read {manifest}: {e}")
+
+        for dependency in report.get("dependencies") or []:
+            if not isinstance(dependency, dict):
+                continue
+            current = str(dependency.get("project") or "")
+            latest = str(dependency.get("latest") or "")
+            if _version(latest) is None or latest == current:
+                # "---" (no newer release) and "Removed" are not upgrades
+                continue
+            name = str(dependency.get("name") or "")
+            compat = str(dependency.get("compat") or "")
+            behind = majors_behind(current, latest)
+            issues.append(
+                CargoOutdatedIssue(
+                    file=_relative(manifest) if manifest else "Cargo.toml",
+                    # Transitive dependencies are reported as "parent->child"
+                    line=lines.get(name.split("->")[0], 0),
+                    code=(
+                        CARGO_OUTDATED_MAJOR_CODE
+                        if behind
+                        else CARGO_OUTDATED_MINOR_CODE
+                    ),
+                    crate_name=crate,
+                    dependency=name,
+                    current=current,
+                    compatible=compat if _version(compat) else None,
+                    latest=latest,
+                    kind=str(dependency.get("kind") or ""),
+                    majors_behind=behind,
+                    severity=classify(behind, warning_majors, error_majors),
+                ),
+            )
+
+    return issues
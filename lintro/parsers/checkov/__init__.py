@@ -0,0 +1,6 @@
+"""Parsing utilities and types for Checkov output."""
+
+from lintro.parsers.checkov.checkov_issue import CheckovIssue
+from lintro.parsers.checkov.checkov_parser import CheckovReport, parse_checkov_output
+
+__all__ = ["CheckovIssue", "CheckovReport", "parse_checkov_output"]
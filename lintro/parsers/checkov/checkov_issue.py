@@ -0,0 +1,29 @@
+"""Typed structure representing a single Checkov finding."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class CheckovIssue(BaseIssue):
+    """Simple container for failed Checkov checks.
+
+    Attributes:
+        code: Check ID, such as ``CKV_AWS_20``.
+        severity: Check severity (``CRITICAL`` to ``LOW``), only known when
+            Checkov is connected to a Prisma Cloud account.
+        end_line: Last line of the flagged resource, if known.
+        resource: Resource the check failed for, such as
+            ``aws_s3_bucket.logs`` or ``Deployment.default.web``.
+        framework: Checkov framework that ran the check, such as
+            ``terraform``, ``kubernetes`` or ``dockerfile``.
+        guideline: URL of the check's remediation guideline, if known.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    end_line: int | None = field(default=None)
+    resource: str = field(default="")
+    framework: str = field(default="")
+    guideline: str = field(default="")
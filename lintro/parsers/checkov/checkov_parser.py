@@ -0,0 +1,113 @@
+"""Parser for Checkov JSON output.
+
+Handles the output of ``checkov -o json``: one report object when a single
+framework ran, or a list of report objects, one per framework. Each report
+holds ``results.failed_checks`` and the ``results.parsing_errors`` of files
+Checkov could not read. When no scanned file holds a resource, Checkov
+prints only a summary object without ``results``.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.checkov.checkov_issue import CheckovIssue
+
+
+@dataclass
+class CheckovReport:
+    """Parsed Checkov report.
+
+    Attributes:
+        issues: Failed checks of every framework.
+        errors: Files Checkov could not parse.
+    """
+
+    issues: list[CheckovIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def _load_json(output: str) -> Any:
+    """Load the JSON document Checkov printed, ignoring surrounding logs.
+
+    Args:
+        output: The raw output from ``checkov -o json``.
+
+    Returns:
+        Any: The decoded document, or None if there is none.
+    """
+    decoder = json.JSONDecoder()
+    for index, char in enumerate(output):
+        if char not in "[{" or (index and output[index - 1] != "\n"):
+            continue
+        try:
+            data, _ = decoder.raw_decode(output, index)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Failed to parse Checkov JSON output: {e}")
+            continue
+        return data
+    return None
+
+
+def parse_checkov_output(output: str | None) -> CheckovReport | None:
+    """Parse Checkov JSON output.
+
+    Args:
+        output: The raw output from ``checkov -o json``.
+
+    Returns:
+        CheckovReport | None: The parsed report, or None if the output holds
+        no JSON report.
+    """
+    if not output:
+        return None
+
+    data = _load_json(output)
+    if isinstance(data, dict):
+        data = [data]
+    if not isinstance(data, list):
+        return None
+
+    report = CheckovReport()
+    for framework_report in data:
+        if not isinstance(framework_report, dict):
+            continue
+        framework = str(framework_report.get("check_type", ""))
+        results = framework_report.get("results") or {}
+        for item in results.get("failed_checks") or []:
+            if isinstance(item, dict):
+                report.issues.append(_parse_check(item, framework))
+        for path in results.get("parsing_errors") or []:
+            report.errors.append(f"Failed to parse {path}")
+    return report
+
+
+def _parse_check(item: dict[str, Any], framework: str) -> CheckovIssue:
+    """Parse a single failed Checkov check.
+
+    Args:
+        item: Entry of the ``failed_checks`` list.
+        framework: Framework of the report the entry belongs to.
+
+    Returns:
+        CheckovIssue for the failed check.
+    """
+    line_range = item.get("file_line_range") or []
+    start = int(line_range[0]) if len(line_range) > 0 else 0
+    end = int(line_range[1]) if len(line_range) > 1 else None
+    return CheckovIssue(
+        file=str(item.get("file_abs_path") or item.get("file_path") or ""),
+        line=start,
+        column=0,
+        message=str(item.get("check_name", "")),
+        code=str(item.get("check_id", "")),
+        severity=str(item.get("severity") or ""),
+        end_line=end,
+        resource=str(item.get("resource", "")),
+        framework=framework,
+        guideline=str(item.get("guideline") or ""),
+    )
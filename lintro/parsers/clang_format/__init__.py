@@ -0,0 +1,8 @@
+"""Parsing utilities and types for clang-format output."""
+
+from lintro.parsers.clang_format.clang_format_issue import ClangFormatIssue
+from lintro.parsers.clang_format.clang_format_parser import (
+    parse_clang_format_output,
+)
+
+__all__ = ["ClangFormatIssue", "parse_clang_format_output"]
@@ -0,0 +1,30 @@
+"""Typed structure representing a single clang-format finding."""
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class ClangFormatIssue(BaseIssue):
+    """Simple container for clang-format findings.
+
+    Each issue is one hunk of the diff between a file and clang-format's
+    output: the lines clang-format would rewrite and a preview of the change.
+
+    Attributes:
+        DEFAULT_SEVERITY: Defaults to INFO (pure formatter).
+        code: Always 'FORMAT'.
+        fixable: Whether the issue can be auto-fixed (always True).
+        end_line: Last line clang-format would rewrite.
+        diff: Preview of the hunk in unified diff style.
+    """
+
+    DEFAULT_SEVERITY: ClassVar[SeverityLevel] = SeverityLevel.INFO
+
+    code: str = field(default="FORMAT")
+    fixable: bool = field(default=True)
+    end_line: int | None = field(default=None)
+    diff: str = field(default="", repr=False)
@@ -0,0 +1,98 @@
+"""Parser for clang-format output.
+
+clang-format prints the formatted file to stdout when run without ``-i``.
+The parser diffs that output against the file on disk and turns every
+hunk into a ClangFormatIssue covering the original lines that change,
+with a preview of the hunk in unified diff style.
+"""
+
+from __future__ import annotations
+
+from difflib import SequenceMatcher
+
+from lintro.parsers.clang_format.clang_format_issue import ClangFormatIssue
+
+# Unchanged lines of context around each hunk
+DIFF_CONTEXT_LINES: int = 3
+# Lines of a hunk kept in the issue's diff preview
+DIFF_PREVIEW_LINES: int = 20
+
+
+def _preview(body: list[str]) -> str:
+    """Trim a hunk body to the preview length.
+
+    Args:
+        body: Context, removed and added lines of the hunk.
+
+    Returns:
+        str: Preview of the hunk.
+    """
+    preview = body[:DIFF_PREVIEW_LINES]
+    if len(body) > DIFF_PREVIEW_LINES:
+        preview.append(f"... ({len(body) - DIFF_PREVIEW_LINES} more lines)")
+    return "\n".join(preview)
+
+
+def parse_clang_format_output(
+    file_path: str,
+    original: str,
+    formatted: str | None,
+) -> list[ClangFormatIssue]:
+    """Diff a file against clang-format's output.
+
+    Args:
+        file_path: Path of the checked file.
+        original: Current contents of the file.
+        formatted: Output of clang-format for the file.
+
+    Returns:
+        List of issues, one per diff hunk.
+    """
+    if formatted is None or formatted == original:
+        return []
+
+    before = original.splitlines()
+    after = formatted.splitlines()
+    if before == after:
+        # Only the newline at the end of the file differs
+        last = max(len(before), 1)
+        return [
+            ClangFormatIssue(
+                file=file_path,
+                line=last,
+                column=0,
+                message="Formatting differs at end of file",
+                end_line=last,
+            ),
+        ]
+
+    issues: list[ClangFormatIssue] = []
+    matcher = SequenceMatcher(None, before, after, autojunk=False)
+    for group in matcher.get_grouped_opcodes(DIFF_CONTEXT_LINES):
+        body: list[str] = []
+        changed: list[int] = []
+        for tag, i1, i2, j1, j2 in group:
+            if tag == "equal":
+                body.extend(f" {text}" for text in before[i1:i2])
+                continue
+            if tag in ("replace", "delete"):
+                body.extend(f"-{text}" for text in before[i1:i2])
+                changed.extend(range(i1 + 1, i2 + 1))
+            if tag in ("replace", "insert"):
+                body.extend(f"+{text}" for text in after[j1:j2])
+            if tag == "insert":
+                # A pure insertion goes in before the next original line
+                changed.append(min(i1 + 1, max(len(before), 1)))
+        start, end = min(changed), max(changed)
+        lines = f"line {start}" if start == end else f"lines {start}-{end}"
+        issues.append(
+            ClangFormatIssue(
+                file=file_path,
+                line=start,
+                column=0,
+                message=f"Formatting differs on {lines}",
+                end_line=end,
+                diff=_preview(body),
+            ),
+        )
+    return issues
@@ -0,0 +1,9 @@
+"""Parsing utilities and types for clang-tidy output."""
+
+from lintro.parsers.clang_tidy.clang_tidy_issue import ClangTidyIssue
+from lintro.parsers.clang_tidy.clang_tidy_parser import (
+    parse_clang_tidy_export,
+    parse_clang_tidy_output,
+)
+
+__all__ = ["ClangTidyIssue", "parse_clang_tidy_export", "parse_clang_tidy_output"]
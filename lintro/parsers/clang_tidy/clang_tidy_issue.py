@@ -0,0 +1,21 @@
+"""Typed structure representing a single clang-tidy diagnostic."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class ClangTidyIssue(BaseIssue):
+    """Simple container for clang-tidy diagnostics.
+
+    Attributes:
+        code: Check name, such as ``bugprone-use-after-move``, or a
+            ``clang-diagnostic-*`` name for compiler diagnostics.
+        severity: clang-tidy level (``warning``, ``error`` or ``remark``).
+        fixable: Whether clang-tidy suggests replacements for the diagnostic.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    fixable: bool = field(default=False)
@@ -0,0 +1,162 @@
+"""Parser for clang-tidy output.
+
+clang-tidy writes its diagnostics to the file given by ``--export-fixes``
+as a YAML document (JSON is valid YAML, so JSON exports load as well)::
+
+    Diagnostics:
+      - DiagnosticName:  bugprone-use-after-move
+        DiagnosticMessage:
+          Message:         "'v' used after it was moved"
+          FilePath:        '/src/main.cpp'
+          FileOffset:      120
+          Replacements:    []
+        Level:           Warning
+        BuildDirectory:  '/src/build'
+
+Positions are byte offsets into the file, so they are converted to lines
+and columns by reading the file. When no export is available the console
+output (``file:line:col: warning: message [check]``) is parsed instead.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from typing import Any
+
+import yaml
+from loguru import logger
+
+from lintro.parsers.clang_tidy.clang_tidy_issue import ClangTidyIssue
+
+# Pattern for "file:line:col: level: message [check]" console diagnostics
+_DIAGNOSTIC_RE = re.compile(
+    r"^(?P<file>.+?):(?P<line>\d+):(?P<column>\d+): "
+    r"(?P<level>warning|error|remark): (?P<message>.*?)"
+    r"(?: \[(?P<code>[^\]]+)\])?$",
+)
+
+
+def offset_to_position(file_path: str, offset: int) -> tuple[int, int]:
+    """Convert a byte offset in a file to a line and column.
+
+    Args:
+        file_path: Path of the file the offset points into.
+        offset: Byte offset from the start of the file.
+
+    Returns:
+        tuple[int, int]: 1-based line and column, or (0, 0) if the file
+        cannot be read.
+    """
+    try:
+        with open(file_path, "rb") as handle:
+            data = handle.read(offset)
+    except OSError as e:
+        logger.debug(f"Cannot read {file_path} to locate clang-tidy offset: {e}")
+        return 0, 0
+    line_start = data.rfind(b"\n") + 1
+    return data.count(b"\n") + 1, len(data) - line_start + 1
+
+
+def _issue_from_diagnostic(diagnostic: dict[str, Any]) -> ClangTidyIssue | None:
+    """Convert one exported diagnostic to an issue.
+
+    Args:
+        diagnostic: Entry of the export's ``Diagnostics`` list.
+
+    Returns:
+        ClangTidyIssue | None: The issue, or None for diagnostics without a
+        file, such as those about the command line.
+    """
+    message = diagnostic.get("DiagnosticMessage") or {}
+    file_path = str(message.get("FilePath") or "")
+    if not file_path:
+        return None
+    build_dir = str(diagnostic.get("BuildDirectory") or "")
+    if build_dir and not os.path.isabs(file_path):
+        file_path = os.path.normpath(os.path.join(build_dir, file_path))
+
+    line, column = offset_to_position(file_path, int(message.get("FileOffset", 0)))
+    return ClangTidyIssue(
+        file=file_path,
+        line=line,
+        column=column,
+        message=str(message.get("Message", "")),
+        code=str(diagnostic.get("DiagnosticName", "")),
+        severity=str(diagnostic.get("Level", "warning")).lower(),
+        fixable=bool(message.get("Replacements")),
+    )
+
+
+def parse_clang_tidy_export(content: str | None) -> list[ClangTidyIssue] | None:
+    """Parse a clang-tidy ``--export-fixes`` file.
+
+    Diagnostics reported more than once, such as those in a header
+    included by several checked sources, are kept once.
+
+    Args:
+        content: Contents of the export file.
+
+    Returns:
+        list[ClangTidyIssue] | None: Parsed issues, or None if the content
+        is not a clang-tidy export.
+    """
+    if not content or not content.strip():
+        return None
+    try:
+        data: Any = yaml.safe_load(content)
+    except yaml.YAMLError as e:
+        logger.debug(f"Failed to parse clang-tidy export: {e}")
+        return None
+    if not isinstance(data, dict):
+        return None
+
+    issues: list[ClangTidyIssue] = []
+    seen: set[tuple[str, int, int, str]] = set()
+    for diagnostic in data.get("Diagnostics") or []:
+        if not isinstance(diagnostic, dict):
+            continue
+        issue = _issue_from_diagnostic(diagnostic)
+        if issue is None:
+            continue
+        key = (issue.file, issue.line, issue.column, issue.code)
+        if key not in seen:
+            seen.add(key)
+            issues.append(issue)
+    return issues
+
+
+def parse_clang_tidy_output(output: str | None) -> list[ClangTidyIssue]:
+    """Parse clang-tidy console diagnostics.
+
+    Notes attached to a diagnostic and the source excerpts clang-tidy
+    prints are skipped.
+
+    Args:
+        output: Raw stdout/stderr from clang-tidy.
+
+    Returns:
+        List of parsed issues.
+    """
+    if not output:
+        return []
+
+    issues: list[ClangTidyIssue] = []
+    seen: set[tuple[str, int, int, str]] = set()
+    for raw_line in output.splitlines():
+        m = _DIAGNOSTIC_RE.match(raw_line.strip())
+        if not m:
+            continue
+        issue = ClangTidyIssue(
+            file=m.group("file"),
+            line=int(m.group("line")),
+            column=int(m.group("column")),
+            message=m.group("message"),
+            code=m.group("code") or "",
+            severity=m.group("level"),
+        )
+        key = (issue.file, issue.line, issue.column, issue.code)
+        if key not in seen:
+            seen.add(key)
+            issues.append(issue)
+    return issues
@@ -1,6 +1,19 @@
 """Clippy parser package."""
 
-from lintro.parsers.clippy.clippy_issue import ClippyIssue
-from lintro.parsers.clippy.clippy_parser import parse_clippy_output
+from lintro.parsers.clippy.clippy_issue import (
+    ClippyIssue,
+    ClippyReplacement,
+    ClippySuggestion,
+)
+from lintro.parsers.clippy.clippy_parser import (
+    parse_clippy_line,
+    parse_clippy_output,
+)
 
-__all__ = ["ClippyIssue", "parse_clippy_output"]
+__all__ = [
+    "ClippyIssue",
+    "ClippyReplacement",
+    "ClippySuggestion",
+    "parse_clippy_line",
+    "parse_clippy_output",
+]
@@ -7,6 +7,65 @@ from typing import ClassVar
 
 from lintro.parsers.base_issue import BaseIssue
 
+MACHINE_APPLICABLE: str = "MachineApplicable"
+# Applies cleanly but may change behavior; an unsafe fix
+MAYBE_INCORRECT: str = "MaybeIncorrect"
+
+
+@dataclass(frozen=True)
+class ClippyReplacement:
+    """Replacement of a byte range proposed by a Clippy suggestion.
+
+    Attributes:
+        file: File path as reported by cargo.
+        byte_start: First byte replaced.
+        byte_end: Byte after the replaced range.
+        text: Replacement text.
+    """
+
+    file: str
+    byte_start: int
+    byte_end: int
+    text: str
+
+
+@dataclass(frozen=True)
+class ClippySuggestion:
+    """Suggested fix attached to a Clippy diagnostic.
+
+    Attributes:
+        message: Help text of the suggestion.
+        applicability: rustc applicability, e.g. ``MachineApplicable`` or
+            ``MaybeIncorrect``.
+        replacements: Edits that make up the suggestion; applied together.
+    """
+
+    message: str
+    applicability: str
+    replacements: tuple[ClippyReplacement, ...]
+
+    @property
+    def machine_applicable(self) -> bool:
+        """Return whether the suggestion can be applied without review.
+
+        Returns:
+            bool: True for ``MachineApplicable`` suggestions.
+        """
+        return self.applicability == MACHINE_APPLICABLE
+
+    @property
+    def applicable(self) -> bool:
+        """Return whether the suggestion can be applied as it is.
+
+        ``HasPlaceholders`` and ``Unspecified`` suggestions need editing
+        first and are never applied.
+
+        Returns:
+            bool: True for ``MachineApplicable`` and ``MaybeIncorrect``
+            suggestions.
+        """
+        return self.applicability in (MACHINE_APPLICABLE, MAYBE_INCORRECT)
+
 
 @dataclass
 class ClippyIssue(BaseIssue):
@@ -18,14 +77,52 @@ class ClippyIssue(BaseIssue):
         level: Severity level (e.g., warning, error).
         end_line: Optional end line number.
         end_column: Optional end column number.
+        fixable: Whether an applicable suggestion fixes the issue.
+        fix_applicability: Applicability of the best applicable suggestion;
+            ``MachineApplicable`` fixes are safe, ``MaybeIncorrect`` ones
+            unsafe.
+        crate_name: Workspace member the file belongs to, if known.
+        suggestions: Suggested fixes reported with the diagnostic.
+        configurations: Entries of the clippy target/feature matrix that
+            report the issue, when not all of them do.
+        target_count: Cargo targets (lib, bins, tests, ...) compiled with
+            the diagnostic; a file shared by several targets reports it once
+            per target, and the duplicates are merged into one issue.
     """
 
     DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
         **BaseIssue.DISPLAY_FIELD_MAP,
         "severity": "level",
+        "fix_safety": "fix_applicability",
     }
 
     code: str = field(default="")
     level: str | None = field(default=None)
     end_line: int | None = field(default=None)
     end_column: int | None = field(default=None)
+    fixable: bool = field(default=False)
+    fix_applicability: str | None = field(default=None)
+    crate_name: str | None = field(default=None)
+    suggestions: list[ClippySuggestion] = field(
+        default_factory=list,
+        repr=False,
+        compare=False,
+    )
+    configurations: list[str] = field(
+        default_factory=list,
+        repr=False,
+        compare=False,
+    )
+    target_count: int = field(default=1, repr=False, compare=False)
+
+    def to_display_row(self) -> dict[str, str]:
+        """Convert the issue to the unified display format.
+
+        Returns:
+            dict[str, str]: Display row; the message names the matrix
+            entries that report the issue when not all of them do.
+        """
+        row = super().to_display_row()
+        if self.configurations:
+            row["message"] += f" [only with {'; '.join(self.configurations)}]"
+        return row
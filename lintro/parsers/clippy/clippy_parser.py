@@ -2,13 +2,79 @@
 
 from __future__ import annotations
 
-import json
 from typing import Any
 
 from loguru import logger
 
-from lintro.parsers.base_parser import strip_ansi_codes
-from lintro.parsers.clippy.clippy_issue import ClippyIssue
+from lintro.parsers.base_parser import parse_json_line
+from lintro.parsers.clippy.clippy_issue import (
+    MACHINE_APPLICABLE,
+    ClippyIssue,
+    ClippyReplacement,
+    ClippySuggestion,
+)
+
+
+def _primary_span(spans: list[Any]) -> dict[str, Any] | None:
+    """Pick the span a diagnostic points at.
+
+    Multi-span diagnostics mark one span as primary; the others only add
+    context (e.g. "first defined here").
+
+    Args:
+        spans: Spans of the diagnostic.
+
+    Returns:
+        The primary span, the first span if none is marked, or ``None``.
+    """
+    candidates = [span for span in spans if isinstance(span, dict)]
+    for span in candidates:
+        if span.get("is_primary"):
+            return span
+    return candidates[0] if candidates else None
+
+
+def _parse_suggestions(message: dict[str, Any]) -> list[ClippySuggestion]:
+    """Collect the suggested fixes of a diagnostic.
+
+    rustc reports a suggestion as a child message (usually ``help``) whose
+    spans carry ``suggested_replacement``; all of them belong together.
+
+    Args:
+        message: The diagnostic's ``message`` object.
+
+    Returns:
+        Suggestions in the order reported.
+    """
+    suggestions: list[ClippySuggestion] = []
+    for child in [message, *message.get("children", [])]:
+        if not isinstance(child, dict):
+            continue
+        spans = [
+            span
+            for span in child.get("spans", [])
+            if isinstance(span, dict) and span.get("suggested_replacement") is not None
+        ]
+        if not spans:
+            continue
+        suggestions.append(
+            ClippySuggestion(
+                message=str(child.get("message", "")).strip(),
+                applicability=str(
+                    spans[0].get("suggestion_applicability") or "Unspecified",
+                ),
+                replacements=tuple(
+                    ClippyReplacement(
+                        file=str(span["file_name"]),
+                        byte_start=int(span["byte_start"]),
+                        byte_end=int(span["byte_end"]),
+                        text=str(span["suggested_replacement"]),
+                    )
+                    for span in spans
+                ),
+            ),
+        )
+    return suggestions
 
 
 def _parse_issue(item: dict[str, Any]) -> ClippyIssue | None:
@@ -31,10 +97,22 @@ def _parse_issue(item: dict[str, Any]) -> ClippyIssue | None:
         #     "message": "unneeded `return` statement",
         #     "spans": [{
         #       "file_name": "src/lib.rs",
+        #       "is_primary": true,
         #       "line_start": 42,
         #       "line_end": 42,
         #       "column_start": 5,
         #       "column_end": 15
+        #     }],
+        #     "children": [{
+        #       "level": "help",
+        #       "message": "remove `return`",
+        #       "spans": [{
+        #         "file_name": "src/lib.rs",
+        #         "byte_start": 812,
+        #         "byte_end": 822,
+        #         "suggested_replacement": "x",
+        #         "suggestion_applicability": "MachineApplicable"
+        #       }]
         #     }]
         #   }
         # }
@@ -72,9 +150,8 @@ def _parse_issue(item: dict[str, Any]) -> ClippyIssue | None:
         if not spans or not isinstance(spans, list):
             return None
 
-        # Use the primary span (first one)
-        primary_span = spans[0]
-        if not isinstance(primary_span, dict):
+        primary_span = _primary_span(spans)
+        if primary_span is None:
             return None
 
         file_name = primary_span.get("file_name")
@@ -90,6 +167,8 @@ def _parse_issue(item: dict[str, Any]) -> ClippyIssue | None:
         column = int(column_start) if column_start is not None else 0
         end_line = int(line_end) if line_end is not None else line
         end_column = int(column_end) if column_end is not None else column
+        suggestions = _parse_suggestions(message)
+        applicable = [s.applicability for s in suggestions if s.applicable]
 
         return ClippyIssue(
             file=file_name,
@@ -100,12 +179,65 @@ def _parse_issue(item: dict[str, Any]) -> ClippyIssue | None:
             level=str(level) if level else None,
             end_line=end_line if end_line != line else None,
             end_column=end_column if end_column != column else None,
+            fixable=bool(applicable),
+            fix_applicability=(
+                MACHINE_APPLICABLE
+                if MACHINE_APPLICABLE in applicable
+                else next(iter(applicable), None)
+            ),
+            suggestions=suggestions,
         )
     except (KeyError, TypeError, ValueError) as e:
         logger.debug(f"Failed to parse clippy diagnostic: {e}")
         return None
 
 
+def parse_clippy_line(line: str) -> ClippyIssue | None:
+    """Parse one line of Clippy JSON Lines output.
+
+    Used as the line handler of a streaming run, so that cargo's output is
+    parsed as it arrives instead of being buffered.
+
+    Args:
+        line: A line emitted by cargo clippy using ``--message-format=json``.
+
+    Returns:
+        The issue the line reports, or ``None`` for other lines.
+    """
+    data = parse_json_line(line)
+    return _parse_issue(data) if data is not None else None
+
+
+def dedupe_clippy_issues(issues: list[ClippyIssue]) -> list[ClippyIssue]:
+    """Merge diagnostics repeated by each target a file is compiled into.
+
+    A source file shared by the library, binaries and tests is linted once
+    per target, so cargo prints the same diagnostic several times. Issues
+    with the same lint and span are merged into the first one, counting
+    the targets in ``target_count``.
+
+    Args:
+        issues: Issues in the order cargo reported them.
+
+    Returns:
+        list[ClippyIssue]: One issue per lint and span.
+    """
+    merged: dict[tuple[object, ...], ClippyIssue] = {}
+    for issue in issues:
+        key = (
+            issue.code,
+            issue.file,
+            issue.line,
+            issue.column,
+            issue.end_line,
+            issue.end_column,
+        )
+        first = merged.setdefault(key, issue)
+        if first is not issue:
+            first.target_count += issue.target_count
+    return list(merged.values())
+
+
 def parse_clippy_output(output: str) -> list[ClippyIssue]:
     """Parse Clippy JSON Lines output into ``ClippyIssue`` objects.
 
@@ -113,30 +245,17 @@ def parse_clippy_output(output: str) -> list[ClippyIssue]:
         output: Raw stdout emitted by cargo clippy using ``--message-format=json``.
 
     Returns:
-        A list of ``ClippyIssue`` instances parsed from the output. Returns an
-        empty list when no issues are present or the output cannot be decoded.
+        A list of ``ClippyIssue`` instances parsed from the output, one per
+        lint and span. Returns an empty list when no issues are present or
+        the output cannot be decoded.
     """
     if not output or not output.strip():
         return []
 
-    # Strip ANSI codes for consistent parsing across environments
-    output = strip_ansi_codes(output)
-
-    issues: list[ClippyIssue] = []
-
     # Clippy outputs JSON Lines (one object per line)
+    issues: list[ClippyIssue] = []
     for line in output.splitlines():
-        line = line.strip()
-        if not line or not line.startswith("{"):
-            continue
-        try:
-            data = json.loads(line)
-            if not isinstance(data, dict):
-                continue
-            parsed = _parse_issue(data)
-            if parsed is not None:
-                issues.append(parsed)
-        except json.JSONDecodeError:
-            continue
-
-    return issues
+        parsed = parse_clippy_line(line)
+        if parsed is not None:
+            issues.append(parsed)
+    return dedupe_clippy_issues(issues)
@@ -0,0 +1,11 @@
+"""Conventional Commits issue module.
+
+This module provides the issue model for Lintro's native commit message
+linter.
+"""
+
+from lintro.parsers.conventional_commits.conventional_commits_issue import (
+    ConventionalCommitsIssue,
+)
+
+__all__ = ["ConventionalCommitsIssue"]
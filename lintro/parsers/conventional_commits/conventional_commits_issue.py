@@ -0,0 +1,36 @@
+"""Conventional Commits issue model.
+
+This module defines the ConventionalCommitsIssue dataclass for representing
+commit messages and PR titles that break the Conventional Commits format.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class ConventionalCommitsIssue(BaseIssue):
+    """Represents a commit message problem.
+
+    The ``file`` field names the message source, such as ``commit 1a2b3c4d``
+    or ``PR title``, and ``line`` is the line within the message.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., CC001).
+        commit: Full hash of the offending commit, empty for PR titles.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
+    commit: str = field(default="")
@@ -0,0 +1,9 @@
+"""EditorConfig issue module.
+
+This module provides the issue model for Lintro's native EditorConfig
+checker.
+"""
+
+from lintro.parsers.editorconfig.editorconfig_issue import EditorconfigIssue
+
+__all__ = ["EditorconfigIssue"]
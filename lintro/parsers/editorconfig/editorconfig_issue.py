@@ -0,0 +1,33 @@
+"""EditorConfig issue model.
+
+This module defines the EditorconfigIssue dataclass for representing lines
+that break the properties a project's ``.editorconfig`` sets for a file.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class EditorconfigIssue(BaseIssue):
+    """Represents an EditorConfig violation found in a file.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., EC001).
+        fixable: Whether the issue is fixed by ``lintro format``.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
+    fixable: bool = field(default=False)
@@ -0,0 +1,6 @@
+"""Parsing utilities and types for ESLint output."""
+
+from lintro.parsers.eslint.eslint_issue import EslintIssue
+from lintro.parsers.eslint.eslint_parser import parse_eslint_output
+
+__all__ = ["EslintIssue", "parse_eslint_output"]
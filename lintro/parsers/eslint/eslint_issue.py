@@ -0,0 +1,22 @@
+"""Typed structure representing a single ESLint message."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class EslintIssue(BaseIssue):
+    """Simple container for ESLint findings.
+
+    Attributes:
+        code: Rule ID (e.g., 'no-unused-vars' or
+            '@typescript-eslint/no-explicit-any'). Empty for parsing errors,
+            which have no rule.
+        severity: Severity level ('error', 'warning').
+        fixable: Whether ``eslint --fix`` can fix this issue.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="error")
+    fixable: bool = field(default=False)
@@ -0,0 +1,94 @@
+"""Parser for ESLint JSON output.
+
+Handles the output of ``eslint --format json``: one entry per file, each with
+a list of messages. ESLint reports severity as a number (1 for warnings, 2 for
+errors), which is mapped to lintro's severity names here.
+"""
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.eslint.eslint_issue import EslintIssue
+
+# ESLint's numeric rule severities
+ESLINT_SEVERITIES: dict[int, str] = {
+    1: "warning",
+    2: "error",
+}
+
+
+def parse_eslint_output(output: str) -> list[EslintIssue]:
+    """Parse ESLint JSON output into a list of EslintIssue objects.
+
+    Args:
+        output: The raw JSON output from ESLint.
+
+    Returns:
+        List of EslintIssue objects.
+    """
+    issues: list[EslintIssue] = []
+
+    if not output:
+        return issues
+
+    try:
+        # Deprecation notices and plugin warnings may surround the JSON report
+        json_start = output.find("[")
+        json_end = output.rfind("]") + 1
+        if json_start == -1 or json_end == 0:
+            return issues
+        eslint_data: list[Any] = json.loads(output[json_start:json_end])
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse ESLint JSON output: {e}")
+        return issues
+
+    if not isinstance(eslint_data, list):
+        logger.debug("ESLint output is not a list")
+        return issues
+
+    for file_result in eslint_data:
+        if not isinstance(file_result, dict):
+            continue
+        file_path = str(file_result.get("filePath", ""))
+        messages = file_result.get("messages", [])
+        if not isinstance(messages, list):
+            continue
+        for message in messages:
+            if not isinstance(message, dict):
+                continue
+            try:
+                issues.append(_parse_message(file_path, message))
+            except (KeyError, TypeError, ValueError) as e:
+                logger.debug(f"Failed to parse ESLint message: {e}")
+                continue
+
+    return issues
+
+
+def _parse_message(file_path: str, message: dict[str, Any]) -> EslintIssue:
+    """Parse a single ESLint message into an EslintIssue.
+
+    Args:
+        file_path: File the message belongs to.
+        message: Message dictionary from ESLint JSON output.
+
+    Returns:
+        EslintIssue for the message.
+    """
+    # Parsing errors are fatal and have no rule ID
+    if message.get("fatal"):
+        severity = "error"
+    else:
+        severity = ESLINT_SEVERITIES.get(int(message.get("severity", 2)), "error")
+
+    return EslintIssue(
+        file=file_path,
+        line=int(message.get("line") or 0),
+        column=int(message.get("column") or 0),
+        message=str(message.get("message", "")).strip(),
+        code=str(message.get("ruleId") or ""),
+        severity=severity,
+        fixable="fix" in message,
+    )
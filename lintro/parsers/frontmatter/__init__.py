@@ -0,0 +1,9 @@
+"""Frontmatter issue module.
+
+This module provides the issue model for Lintro's native frontmatter
+schema validator.
+"""
+
+from lintro.parsers.frontmatter.frontmatter_issue import FrontmatterIssue
+
+__all__ = ["FrontmatterIssue"]
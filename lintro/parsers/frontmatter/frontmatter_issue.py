@@ -0,0 +1,33 @@
+"""Frontmatter issue model.
+
+This module defines the FrontmatterIssue dataclass for representing YAML
+frontmatter that fails schema validation in Markdown documents.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class FrontmatterIssue(BaseIssue):
+    """Represents a frontmatter problem found in a document.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., FM001).
+        key: Dotted path of the offending frontmatter key, if any.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
+    key: str = field(default="")
@@ -8,6 +8,9 @@ from typing import ClassVar
 from lintro.enums.severity_level import SeverityLevel
 from lintro.parsers.base_issue import BaseIssue
 
+# Placeholder gitleaks itself writes for secrets when run with --redact
+REDACTED: str = "REDACTED"
+
 
 @dataclass
 class GitleaksIssue(BaseIssue):
@@ -18,13 +21,13 @@ class GitleaksIssue(BaseIssue):
         DEFAULT_SEVERITY: Defaults to ERROR (security tool).
         rule_id: The rule ID that triggered the detection (e.g., aws-access-key-id).
         description: Description of the secret type detected.
-        secret: The detected secret value (should be redacted for display).
+        secret: The detected secret, always replaced with ``REDACTED``.
         entropy: Shannon entropy of the detected secret.
         tags: List of tags associated with the rule.
         fingerprint: Unique identifier for this finding.
         end_line: End line number of the finding.
         end_column: End column number of the finding.
-        match: The matched pattern string.
+        match: The matched text, with the secret replaced by ``REDACTED``.
         commit: Git commit hash if scanning git history.
         author: Git author if scanning git history.
         email: Git author email if scanning git history.
@@ -56,7 +59,15 @@ class GitleaksIssue(BaseIssue):
     commit_message: str = field(default="")
 
     def __post_init__(self) -> None:
-        """Initialize the inherited message field."""
+        """Redact the secret and initialize the inherited message field.
+
+        Gitleaks only redacts its report when run with ``--redact``, so the
+        secret is masked here as well and never reaches console output,
+        reports or ``output.script`` hooks.
+        """
+        if self.secret and self.secret != REDACTED:
+            self.match = self.match.replace(self.secret, REDACTED)
+            self.secret = REDACTED
         self.message = self._get_message()
 
     def _get_message(self) -> str:
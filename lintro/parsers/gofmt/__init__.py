@@ -0,0 +1,6 @@
+"""Parsing utilities and types for gofmt output."""
+
+from lintro.parsers.gofmt.gofmt_issue import GofmtIssue
+from lintro.parsers.gofmt.gofmt_parser import parse_gofmt_output
+
+__all__ = ["GofmtIssue", "parse_gofmt_output"]
@@ -0,0 +1,33 @@
+"""Typed structure representing a single gofmt finding."""
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class GofmtIssue(BaseIssue):
+    """Simple container for gofmt and gofumpt findings.
+
+    Each formatting issue is one hunk of the ``-d`` diff: the lines the
+    formatter would rewrite and a preview of the change. Files the
+    formatter cannot parse give one ``SYNTAX`` issue per error.
+
+    Attributes:
+        DEFAULT_SEVERITY: Defaults to INFO (pure formatter).
+        code: 'FORMAT' for formatting hunks, 'SYNTAX' for parse errors.
+        severity: 'error' for parse errors, empty otherwise.
+        fixable: Whether the issue can be auto-fixed.
+        end_line: Last line the formatter would rewrite, if known.
+        diff: Preview of the hunk in unified diff style.
+    """
+
+    DEFAULT_SEVERITY: ClassVar[SeverityLevel] = SeverityLevel.INFO
+
+    code: str = field(default="FORMAT")
+    severity: str = field(default="")
+    fixable: bool = field(default=True)
+    end_line: int | None = field(default=None)
+    diff: str = field(default="", repr=False)
@@ -0,0 +1,145 @@
+"""Parser for gofmt and gofumpt output.
+
+With ``-d`` both formatters print a unified diff for every file they would
+rewrite::
+
+    diff main.go.orig main.go
+    --- main.go.orig
+    +++ main.go
+    @@ -3,7 +3,7 @@
+     ...
+
+Every hunk becomes a GofmtIssue covering the original lines that change,
+with a preview of the hunk. Files that fail to parse are reported as
+``file.go:line:column: message`` lines instead, which become SYNTAX
+issues.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass, field
+
+from lintro.parsers.gofmt.gofmt_issue import GofmtIssue
+
+# "+++ main.go" names the file the following hunks belong to
+_NEW_FILE_RE = re.compile(r"^\+\+\+ (?P<file>\S+)")
+# "@@ -3,7 +3,7 @@" starts a hunk at line 3 of the original file
+_HUNK_RE = re.compile(r"^@@ -(?P<line>\d+)(?:,\d+)? \+\d+(?:,\d+)? @@")
+# "main.go:4:2: expected declaration, found foo" reports a parse error
+_SYNTAX_RE = re.compile(
+    r"^(?P<file>.+\.go):(?P<line>\d+):(?P<column>\d+): (?P<message>.+)$",
+)
+
+# Lines of a hunk kept in the issue's diff preview
+DIFF_PREVIEW_LINES: int = 20
+
+
+@dataclass
+class _Hunk:
+    """Diff hunk being read.
+
+    Attributes:
+        file: File the hunk belongs to.
+        line: First line of the hunk in the original file.
+        body: Context, removed and added lines of the hunk.
+    """
+
+    file: str
+    line: int
+    body: list[str] = field(default_factory=list)
+
+    def to_issue(self) -> GofmtIssue:
+        """Convert the hunk to an issue spanning the lines that change.
+
+        Returns:
+            GofmtIssue: Issue for the hunk.
+        """
+        original = self.line
+        changed: list[int] = []
+        replacing = False
+        for text in self.body:
+            if text.startswith("-"):
+                changed.append(original)
+                original += 1
+                replacing = True
+            elif text.startswith("+"):
+                if not replacing:
+                    # A pure insertion goes in before the current line
+                    changed.append(original)
+            else:
+                original += 1
+                replacing = False
+        start = min(changed, default=self.line)
+        end = max(changed, default=self.line)
+        lines = f"line {start}" if start == end else f"lines {start}-{end}"
+        preview = self.body[:DIFF_PREVIEW_LINES]
+        if len(self.body) > DIFF_PREVIEW_LINES:
+            preview.append(f"... ({len(self.body) - DIFF_PREVIEW_LINES} more lines)")
+        return GofmtIssue(
+            file=self.file,
+            line=start,
+            column=0,
+            message=f"Formatting differs on {lines}",
+            end_line=end,
+            diff="\n".join(preview),
+        )
+
+
+def parse_gofmt_output(output: str | None) -> list[GofmtIssue]:
+    """Parse gofmt or gofumpt ``-d`` output into issues.
+
+    Args:
+        output: Raw stdout/stderr from ``gofmt -d`` or ``gofumpt -d``.
+
+    Returns:
+        List of parsed issues, one per diff hunk or parse error.
+    """
+    if not output:
+        return []
+
+    issues: list[GofmtIssue] = []
+    current_file: str | None = None
+    hunk: _Hunk | None = None
+
+    for raw_line in output.splitlines():
+        m = _NEW_FILE_RE.match(raw_line)
+        if m:
+            if hunk is not None:
+                issues.append(hunk.to_issue())
+                hunk = None
+            current_file = m.group("file")
+            continue
+
+        m = _HUNK_RE.match(raw_line)
+        if m and current_file is not None:
+            if hunk is not None:
+                issues.append(hunk.to_issue())
+            hunk = _Hunk(file=current_file, line=int(m.group("line")))
+            continue
+
+        if hunk is not None and raw_line[:1] in (" ", "+", "-"):
+            hunk.body.append(raw_line)
+            continue
+
+        if hunk is not None:
+            issues.append(hunk.to_issue())
+            hunk = None
+
+        m = _SYNTAX_RE.match(raw_line.strip())
+        if m:
+            issues.append(
+                GofmtIssue(
+                    file=m.group("file"),
+                    line=int(m.group("line")),
+                    column=int(m.group("column")),
+                    message=m.group("message"),
+                    code="SYNTAX",
+                    severity="error",
+                    fixable=False,
+                ),
+            )
+
+    if hunk is not None:
+        issues.append(hunk.to_issue())
+    return issues
@@ -0,0 +1,9 @@
+"""Parsing utilities and types for golangci-lint output."""
+
+from lintro.parsers.golangci_lint.golangci_lint_issue import GolangciLintIssue
+from lintro.parsers.golangci_lint.golangci_lint_parser import (
+    GolangciLintReport,
+    parse_golangci_lint_output,
+)
+
+__all__ = ["GolangciLintIssue", "GolangciLintReport", "parse_golangci_lint_output"]
@@ -0,0 +1,22 @@
+"""Typed structure representing a single golangci-lint finding."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class GolangciLintIssue(BaseIssue):
+    """Simple container for golangci-lint findings.
+
+    Attributes:
+        code: Linter that reported the issue, such as ``errcheck`` or
+            ``typecheck`` for code that does not compile.
+        severity: Severity assigned by the ``severity`` section of the
+            golangci-lint config, empty when none is configured.
+        fixable: Whether the linter suggests a replacement for the issue.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    fixable: bool = field(default=False)
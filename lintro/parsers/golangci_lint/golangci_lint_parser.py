@@ -0,0 +1,92 @@
+"""Parser for golangci-lint JSON output.
+
+Handles the JSON printed by ``golangci-lint run`` with JSON output enabled
+(``--out-format json`` before v2, ``--output.json.path=stdout`` since): an
+object with an ``Issues`` list and a ``Report`` whose ``Error`` field holds
+problems that stopped the run, such as packages that fail to load.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.golangci_lint.golangci_lint_issue import GolangciLintIssue
+
+
+@dataclass
+class GolangciLintReport:
+    """Parsed golangci-lint report.
+
+    Attributes:
+        issues: Issues reported by the enabled linters.
+        errors: Messages of errors that stopped the run.
+    """
+
+    issues: list[GolangciLintIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def parse_golangci_lint_output(output: str | None) -> GolangciLintReport | None:
+    """Parse golangci-lint JSON output.
+
+    golangci-lint logs to stderr, so the JSON report is looked for on its
+    own line.
+
+    Args:
+        output: The raw output from ``golangci-lint run``.
+
+    Returns:
+        GolangciLintReport | None: The parsed report, or None if the output
+        holds no JSON report.
+    """
+    if not output:
+        return None
+
+    data: Any = None
+    for line in output.splitlines():
+        line = line.strip()
+        if not line.startswith("{"):
+            continue
+        try:
+            data = json.loads(line)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Failed to parse golangci-lint JSON output: {e}")
+            continue
+        if isinstance(data, dict):
+            break
+    if not isinstance(data, dict):
+        return None
+
+    report = GolangciLintReport()
+    for item in data.get("Issues") or []:
+        if isinstance(item, dict):
+            report.issues.append(_parse_issue(item))
+    error = (data.get("Report") or {}).get("Error")
+    if error:
+        report.errors.append(str(error))
+    return report
+
+
+def _parse_issue(item: dict[str, Any]) -> GolangciLintIssue:
+    """Parse a single golangci-lint issue.
+
+    Args:
+        item: Entry of the ``Issues`` list.
+
+    Returns:
+        GolangciLintIssue for the entry.
+    """
+    position = item.get("Pos") or {}
+    return GolangciLintIssue(
+        file=str(position.get("Filename", "")),
+        line=int(position.get("Line") or 0),
+        column=int(position.get("Column") or 0),
+        message=str(item.get("Text", "")),
+        code=str(item.get("FromLinter", "")),
+        severity=str(item.get("Severity") or ""),
+        fixable=bool(item.get("Replacement") or item.get("SuggestedFixes")),
+    )
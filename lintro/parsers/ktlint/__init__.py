@@ -0,0 +1,6 @@
+"""Parsing utilities and types for ktlint output."""
+
+from lintro.parsers.ktlint.ktlint_issue import KtlintIssue
+from lintro.parsers.ktlint.ktlint_parser import parse_ktlint_output
+
+__all__ = ["KtlintIssue", "parse_ktlint_output"]
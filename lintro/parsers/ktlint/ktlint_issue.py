@@ -0,0 +1,18 @@
+"""Typed structure representing a single ktlint violation."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class KtlintIssue(BaseIssue):
+    """Simple container for ktlint violations.
+
+    Attributes:
+        code: Rule that reported the violation, qualified by its rule set,
+            such as ``standard:no-wildcard-imports``; empty for files
+            ktlint cannot parse.
+    """
+
+    code: str = field(default="")
@@ -0,0 +1,90 @@
+"""Parser for ktlint JSON reporter output.
+
+Handles the output of ``ktlint --reporter=json``: a JSON list with one
+entry per file that has violations::
+
+    [{"file": "src/main/kotlin/App.kt", "errors": [
+        {"line": 3, "column": 1, "message": "Wildcard import",
+         "rule": "standard:no-wildcard-imports"}]}]
+
+ktlint logs warnings, such as unknown ``.editorconfig`` properties, to
+stderr around the report.
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.ktlint.ktlint_issue import KtlintIssue
+
+
+def _load_json(output: str) -> Any:
+    """Load the JSON list ktlint printed, ignoring surrounding logs.
+
+    Args:
+        output: The raw output from ``ktlint --reporter=json``.
+
+    Returns:
+        Any: The decoded document, or None if there is none.
+    """
+    decoder = json.JSONDecoder()
+    for index, char in enumerate(output):
+        if char != "[" or (index and output[index - 1] != "\n"):
+            continue
+        try:
+            data, _ = decoder.raw_decode(output, index)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Failed to parse ktlint JSON output: {e}")
+            continue
+        return data
+    return None
+
+
+def parse_ktlint_output(output: str | None) -> list[KtlintIssue] | None:
+    """Parse ktlint JSON reporter output.
+
+    Args:
+        output: The raw output from ``ktlint --reporter=json``.
+
+    Returns:
+        list[KtlintIssue] | None: The parsed violations, or None if the
+        output holds no JSON report.
+    """
+    if not output:
+        return None
+
+    data = _load_json(output)
+    if not isinstance(data, list):
+        return None
+
+    issues: list[KtlintIssue] = []
+    for file_entry in data:
+        if not isinstance(file_entry, dict):
+            continue
+        path = str(file_entry.get("file", ""))
+        for error in file_entry.get("errors") or []:
+            if isinstance(error, dict):
+                issues.append(_parse_error(path, error))
+    return issues
+
+
+def _parse_error(path: str, error: dict[str, Any]) -> KtlintIssue:
+    """Parse a single ktlint violation.
+
+    Args:
+        path: File the violation belongs to.
+        error: Entry of the file's ``errors`` list.
+
+    Returns:
+        KtlintIssue for the violation.
+    """
+    return KtlintIssue(
+        file=path,
+        line=int(error.get("line") or 0),
+        column=int(error.get("column") or 0),
+        message=str(error.get("message") or ""),
+        code=str(error.get("rule") or ""),
+    )
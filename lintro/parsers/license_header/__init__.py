@@ -0,0 +1,9 @@
+"""License header issue module.
+
+This module provides the issue model for Lintro's native license header
+checker.
+"""
+
+from lintro.parsers.license_header.license_header_issue import LicenseHeaderIssue
+
+__all__ = ["LicenseHeaderIssue"]
@@ -0,0 +1,33 @@
+"""License header issue model.
+
+This module defines the LicenseHeaderIssue dataclass for representing files
+whose license or copyright header is missing or differs from the template.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class LicenseHeaderIssue(BaseIssue):
+    """Represents a license header problem found in a file.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., LH001).
+        fixable: Whether the issue is fixed by ``lintro format``.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
+    fixable: bool = field(default=False)
@@ -0,0 +1,9 @@
+"""Link check issue module.
+
+This module provides the issue model for Lintro's native dead-link
+checker.
+"""
+
+from lintro.parsers.linkcheck.linkcheck_issue import LinkcheckIssue
+
+__all__ = ["LinkcheckIssue"]
@@ -0,0 +1,33 @@
+"""Link check issue model.
+
+This module defines the LinkcheckIssue dataclass for representing broken
+links found in Markdown and HTML documents by Lintro's native checker.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class LinkcheckIssue(BaseIssue):
+    """Represents a broken link found in a document.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., LC001).
+        url: Link target as written in the document.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
+    url: str = field(default="")
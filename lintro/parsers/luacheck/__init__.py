@@ -0,0 +1,9 @@
+"""Parsing utilities and types for luacheck output."""
+
+from lintro.parsers.luacheck.luacheck_issue import LuacheckIssue
+from lintro.parsers.luacheck.luacheck_parser import (
+    LuacheckReport,
+    parse_luacheck_output,
+)
+
+__all__ = ["LuacheckIssue", "LuacheckReport", "parse_luacheck_output"]
@@ -0,0 +1,21 @@
+"""Typed structure representing a single luacheck warning or error."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class LuacheckIssue(BaseIssue):
+    """Simple container for luacheck findings.
+
+    Attributes:
+        code: luacheck code, such as ``W211`` for an unused variable or
+            ``E011`` for a syntax error.
+        severity: ``error`` for ``E`` codes, ``warning`` otherwise.
+        end_column: Last column of the reported range, if known.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="warning")
+    end_column: int | None = field(default=None)
@@ -0,0 +1,75 @@
+"""Parser for luacheck plain formatter output.
+
+``luacheck --formatter=plain --codes --ranges`` prints one line per
+finding::
+
+    lua/init.lua:3:7-9: (W211) unused variable 'foo'
+    lua/broken.lua:1:5-5: (E011) expected '=' near 'x'
+
+Codes starting with ``E`` are syntax errors and invalid inline options;
+the others are warnings. Lines for files luacheck could not check at all,
+such as ``lua/gone.lua: I/O error (couldn't read: ...)``, are collected as
+errors.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass, field
+
+from lintro.parsers.luacheck.luacheck_issue import LuacheckIssue
+
+_FINDING_RE = re.compile(
+    r"^(?P<file>.+?):(?P<line>\d+):(?P<column>\d+)(?:-(?P<end_column>\d+))?:"
+    r"\s+\((?P<code>[EW]\d+)\)\s+(?P<message>.*)$",
+)
+
+
+@dataclass
+class LuacheckReport:
+    """Parsed luacheck report.
+
+    Attributes:
+        issues: Warnings and errors in the checked files.
+        errors: Lines for files luacheck could not check.
+    """
+
+    issues: list[LuacheckIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def parse_luacheck_output(output: str | None) -> LuacheckReport:
+    """Parse luacheck plain formatter output.
+
+    Args:
+        output: Raw stdout/stderr from luacheck.
+
+    Returns:
+        LuacheckReport: Parsed findings and errors.
+    """
+    report = LuacheckReport()
+    if not output:
+        return report
+
+    for raw_line in output.splitlines():
+        line = raw_line.strip()
+        if not line:
+            continue
+        match = _FINDING_RE.match(line)
+        if match is None:
+            report.errors.append(line)
+            continue
+        code = match.group("code")
+        end_column = match.group("end_column")
+        report.issues.append(
+            LuacheckIssue(
+                file=match.group("file"),
+                line=int(match.group("line")),
+                column=int(match.group("column")),
+                message=match.group("message"),
+                code=code,
+                severity="error" if code.startswith("E") else "warning",
+                end_column=int(end_column) if end_column else None,
+            ),
+        )
+    return report
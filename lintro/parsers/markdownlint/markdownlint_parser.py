@@ -2,7 +2,11 @@
 
 import re
 
-from lintro.parsers.base_parser import collect_continuation_lines, strip_ansi_codes
+from lintro.parsers.base_parser import (
+    FILE_PATH_PATTERN,
+    collect_continuation_lines,
+    strip_ansi_codes,
+)
 from lintro.parsers.markdownlint.markdownlint_issue import MarkdownlintIssue
 
 
@@ -59,7 +63,8 @@ def parse_markdownlint_output(output: str) -> list[MarkdownlintIssue]:
     # Also handles variations like: file:line MD### Message
     # [Expected: ...; Actual: ...]
     pattern: re.Pattern[str] = re.compile(
-        r"^([^:]+):(\d+)(?::(\d+))?\s+(?:error\s+)?(MD\d+)(?:/[^:\s]+)?(?::\s*)?"
+        rf"^({FILE_PATH_PATTERN}):(\d+)(?::(\d+))?\s+(?:error\s+)?"
+        r"(MD\d+)(?:/[^:\s]+)?(?::\s*)?"
         r"(.+?)(?:\s+\[(?:Context|Expected|Actual):.*?\])?$",
     )
 
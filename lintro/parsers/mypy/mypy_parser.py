@@ -1,8 +1,14 @@
-"""Parser for mypy JSON output."""
+"""Parser for mypy output.
+
+Lintro runs mypy with ``--output json``. Mypy releases before 1.11 do not
+have that flag and print the standard ``path:line:col: severity: message
+[code]`` lines instead, which are parsed as a fallback.
+"""
 
 from __future__ import annotations
 
 import json
+import re
 from typing import Any
 
 from loguru import logger
@@ -10,6 +16,14 @@ from loguru import logger
 from lintro.parsers.base_parser import extract_int_field, extract_str_field
 from lintro.parsers.mypy.mypy_issue import MypyIssue
 
+# src/app.py:12:5: error: Incompatible return value type  [return-value]
+_TEXT_LINE_RE = re.compile(
+    r"^(?P<path>.+?):(?P<line>\d+):(?:(?P<column>\d+):)?"
+    r"(?:(?P<end_line>\d+):(?P<end_column>\d+):)?"
+    r" (?P<severity>error|warning|note): (?P<message>.*?)"
+    r"(?:  \[(?P<code>[a-z0-9-]+)\])?$",
+)
+
 
 def _parse_issue(item: dict[str, Any]) -> MypyIssue | None:
     """Convert a mypy JSON error object into a ``MypyIssue``.
@@ -82,11 +96,42 @@ def _extract_errors(data: Any) -> list[dict[str, Any]]:
     return []
 
 
+def _parse_text_output(output: str) -> list[MypyIssue]:
+    """Parse mypy's standard text output.
+
+    Args:
+        output: Raw stdout emitted by mypy without ``--output json``.
+
+    Returns:
+        A list of ``MypyIssue`` instances, one per error, warning or note line.
+    """
+    issues: list[MypyIssue] = []
+    for line in output.splitlines():
+        match = _TEXT_LINE_RE.match(line.rstrip())
+        if not match:
+            continue
+        end_line = match.group("end_line")
+        end_column = match.group("end_column")
+        issues.append(
+            MypyIssue(
+                file=match.group("path"),
+                line=int(match.group("line")),
+                column=int(match.group("column") or 0),
+                code=match.group("code") or "",
+                message=match.group("message").strip(),
+                severity=match.group("severity"),
+                end_line=int(end_line) if end_line else None,
+                end_column=int(end_column) if end_column else None,
+            ),
+        )
+    return issues
+
+
 def parse_mypy_output(output: str) -> list[MypyIssue]:
-    """Parse mypy JSON or JSON-lines output into ``MypyIssue`` objects.
+    """Parse mypy JSON, JSON-lines or text output into ``MypyIssue`` objects.
 
     Args:
-        output: Raw stdout emitted by mypy using ``--output json``.
+        output: Raw stdout emitted by mypy, preferably using ``--output json``.
 
     Returns:
         A list of ``MypyIssue`` instances parsed from the output. Returns an
@@ -127,4 +172,6 @@ def parse_mypy_output(output: str) -> list[MypyIssue]:
             logger.debug(f"Error extracting mypy errors: {e}")
             continue
 
+    if not issues:
+        issues = _parse_text_output(output)
     return issues
@@ -0,0 +1,9 @@
+"""Package.json issue module.
+
+This module provides the issue model for Lintro's native package.json
+checker.
+"""
+
+from lintro.parsers.package_json.package_json_issue import PackageJsonIssue
+
+__all__ = ["PackageJsonIssue"]
@@ -0,0 +1,33 @@
+"""Package.json issue model.
+
+This module defines the PackageJsonIssue dataclass for representing problems
+found in package.json manifests by Lintro's native checker.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class PackageJsonIssue(BaseIssue):
+    """Represents an issue found in a package.json manifest.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., PJ001).
+        fixable: Whether the issue is fixed by ``lintro format``.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
+    fixable: bool = field(default=False)
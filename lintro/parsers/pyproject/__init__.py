@@ -0,0 +1,9 @@
+"""Pyproject issue module.
+
+This module provides the issue model for Lintro's native pyproject.toml
+metadata checker.
+"""
+
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+
+__all__ = ["PyprojectIssue"]
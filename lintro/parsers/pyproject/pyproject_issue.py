@@ -0,0 +1,31 @@
+"""Pyproject issue model.
+
+This module defines the PyprojectIssue dataclass for representing problems
+found in pyproject.toml metadata by Lintro's native checker.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class PyprojectIssue(BaseIssue):
+    """Represents an issue found in pyproject.toml metadata.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        level: Severity level (error, warning, info).
+        code: Rule code (e.g., PP001).
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "severity": "level",
+    }
+
+    level: str = field(default="error")
+    code: str = field(default="")
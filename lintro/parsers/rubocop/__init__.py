@@ -0,0 +1,6 @@
+"""Parsing utilities and types for RuboCop output."""
+
+from lintro.parsers.rubocop.rubocop_issue import RubocopIssue
+from lintro.parsers.rubocop.rubocop_parser import parse_rubocop_output
+
+__all__ = ["RubocopIssue", "parse_rubocop_output"]
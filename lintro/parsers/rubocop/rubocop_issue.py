@@ -0,0 +1,28 @@
+"""Typed structure representing a single RuboCop offense."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class RubocopIssue(BaseIssue):
+    """Simple container for RuboCop offenses.
+
+    Attributes:
+        code: Cop that reported the offense, such as
+            ``Style/StringLiterals`` or ``Lint/UselessAssignment``.
+        severity: RuboCop severity: ``info``, ``refactor``, ``convention``,
+            ``warning``, ``error`` or ``fatal``.
+        fixable: Whether ``rubocop --autocorrect`` can fix the offense.
+        corrected: Whether the offense was fixed by an autocorrect run.
+        end_line: Last line of the offending range, if known.
+        end_column: Last column of the offending range, if known.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    fixable: bool = field(default=False)
+    corrected: bool = field(default=False)
+    end_line: int | None = field(default=None)
+    end_column: int | None = field(default=None)
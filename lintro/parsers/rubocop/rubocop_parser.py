@@ -0,0 +1,107 @@
+"""Parser for RuboCop JSON output.
+
+Handles the output of ``rubocop --format json``: a single document listing
+the inspected files, each with its offenses::
+
+    {"files": [{"path": "lib/app.rb", "offenses": [{
+        "severity": "convention",
+        "message": "Style/StringLiterals: Prefer single-quoted strings...",
+        "cop_name": "Style/StringLiterals",
+        "corrected": false,
+        "correctable": true,
+        "location": {"start_line": 3, "start_column": 8,
+                     "last_line": 3, "last_column": 14}}]}]}
+
+RuboCop prefixes messages with the cop name, which is stripped since it is
+reported as the issue code. Warnings about the configuration may be
+printed before the document.
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.rubocop.rubocop_issue import RubocopIssue
+
+
+def _load_json(output: str) -> Any:
+    """Load the JSON document RuboCop printed, ignoring preceding warnings.
+
+    Args:
+        output: The raw output from ``rubocop --format json``.
+
+    Returns:
+        Any: The decoded document, or None if there is none.
+    """
+    start = output.find("{")
+    if start == -1:
+        return None
+    try:
+        data, _ = json.JSONDecoder().raw_decode(output, start)
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse RuboCop JSON output: {e}")
+        return None
+    return data
+
+
+def parse_rubocop_output(output: str | None) -> list[RubocopIssue] | None:
+    """Parse RuboCop JSON output.
+
+    Args:
+        output: The raw output from ``rubocop --format json``.
+
+    Returns:
+        list[RubocopIssue] | None: The parsed offenses, including corrected
+        ones, or None if the output holds no JSON report.
+    """
+    if not output:
+        return None
+
+    data = _load_json(output)
+    if not isinstance(data, dict) or not isinstance(data.get("files"), list):
+        return None
+
+    issues: list[RubocopIssue] = []
+    for file_entry in data["files"]:
+        if not isinstance(file_entry, dict):
+            continue
+        path = str(file_entry.get("path", ""))
+        for offense in file_entry.get("offenses") or []:
+            if isinstance(offense, dict):
+                issues.append(_parse_offense(path, offense))
+    return issues
+
+
+def _parse_offense(path: str, offense: dict[str, Any]) -> RubocopIssue:
+    """Parse a single RuboCop offense.
+
+    Args:
+        path: File the offense belongs to.
+        offense: Entry of the file's ``offenses`` list.
+
+    Returns:
+        RubocopIssue for the offense.
+    """
+    location = offense.get("location") or {}
+    code = str(offense.get("cop_name") or "")
+    message = str(offense.get("message") or "")
+    prefix = f"{code}: "
+    if code and message.startswith(prefix):
+        message = message[len(prefix) :]
+    end_line = location.get("last_line")
+    end_column = location.get("last_column")
+    return RubocopIssue(
+        file=path,
+        line=int(location.get("start_line") or location.get("line") or 0),
+        column=int(location.get("start_column") or location.get("column") or 0),
+        message=message,
+        code=code,
+        severity=str(offense.get("severity") or ""),
+        fixable=bool(offense.get("correctable")),
+        corrected=bool(offense.get("corrected")),
+        end_line=int(end_line) if end_line is not None else None,
+        end_column=int(end_column) if end_column is not None else None,
+    )
@@ -1,6 +1,7 @@
 """Model for ruff linting issues."""
 
 from dataclasses import dataclass, field
+from typing import ClassVar
 
 from lintro.parsers.base_issue import BaseIssue
 
@@ -10,6 +11,7 @@ class RuffIssue(BaseIssue):
     """Represents a ruff linting issue.
 
     Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
         code: Ruff error code (e.g., E401, F401).
         url: Optional URL to documentation for this error.
         end_line: End line number for multi-line issues.
@@ -18,6 +20,11 @@ class RuffIssue(BaseIssue):
         fix_applicability: Whether the fix is safe or unsafe (safe, unsafe, or None).
     """
 
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "fix_safety": "fix_applicability",
+    }
+
     code: str = field(default="")
     url: str | None = field(default=None)
     end_line: int | None = field(default=None)
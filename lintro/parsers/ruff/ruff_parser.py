@@ -42,6 +42,9 @@ def _parse_ruff_item(item: dict[str, object]) -> RuffIssue | None:
     end_line = extract_int_field(end_loc, ["row", "line"], default=line) or line
     end_column = extract_int_field(end_loc, ["column", "col"], default=column) or column
 
+    # Notebook rows are counted from the start of this cell
+    cell = extract_int_field(item, ["cell"], default=0) or 0
+
     code = extract_str_field(item, ["code", "rule"])
     message = extract_str_field(item, ["message"])
     url_candidate = item.get("url")
@@ -65,6 +68,7 @@ def _parse_ruff_item(item: dict[str, object]) -> RuffIssue | None:
         end_column=end_column,
         fixable=fixable,
         fix_applicability=fix_applicability,
+        cell=cell,
     )
 
 
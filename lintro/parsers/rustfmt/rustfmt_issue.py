@@ -13,13 +13,21 @@ from lintro.parsers.base_issue import BaseIssue
 class RustfmtIssue(BaseIssue):
     """Represents a rustfmt formatting issue.
 
-    Rustfmt reports files that need formatting. Each issue represents
-    a file that would be reformatted.
+    Each issue is one hunk of the ``--check`` diff: the lines rustfmt would
+    rewrite and a preview of the change. Output that only names a file gives
+    one issue for the whole file. Settings cargo fmt drops, such as
+    nightly-only options on stable, are reported as warnings with a ``code``
+    and point at the rustfmt config.
 
     Attributes:
         DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
         DEFAULT_SEVERITY: Defaults to INFO (pure formatter).
-        fixable: Whether the issue can be auto-fixed (always True for rustfmt).
+        fixable: Whether the issue can be auto-fixed (False for settings).
+        code: Kind of settings issue, empty for formatting differences.
+        severity: Severity of settings issues, None for formatting ones.
+        end_line: Last line rustfmt would rewrite, if known.
+        diff: Preview of the hunk in unified diff style, if known.
+        crate_name: Workspace member the file belongs to, if known.
     """
 
     DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
@@ -29,3 +37,8 @@ class RustfmtIssue(BaseIssue):
     DEFAULT_SEVERITY: ClassVar[SeverityLevel] = SeverityLevel.INFO
 
     fixable: bool = field(default=True)
+    code: str = field(default="")
+    severity: str | None = field(default=None)
+    end_line: int | None = field(default=None)
+    diff: str = field(default="", repr=False)
+    crate_name: str | None = field(default=None)
@@ -1,27 +1,104 @@
 """Parser for rustfmt output.
 
-Rustfmt with --check outputs diff-style format when files need formatting:
-- "Diff in /path/to/file.rs at line N:" followed by diff content
-- Or simply lists files that would be reformatted
+Rustfmt with --check prints a diff when files need formatting. Each hunk
+starts with a header naming the file and the first line of the hunk:
 
-We parse this output to extract file paths and create RustfmtIssue objects.
+- "Diff in /path/to/file.rs:N:" (current rustfmt)
+- "Diff in /path/to/file.rs at line N:" (older rustfmt)
+
+followed by context lines (" "), removed lines ("-") and added lines ("+").
+Some output modes simply list files that would be reformatted.
+
+Every hunk becomes a RustfmtIssue covering the original lines that change,
+with a preview of the hunk; a listed file becomes one issue for the file.
+
+Stable rustfmt also warns about each nightly-only option it ignores:
+
+- "Warning: can't set `imports_granularity = Crate`, unstable features are
+  only available in nightly channel."
+
+These are read separately, by parse_unstable_options.
 """
 
 from __future__ import annotations
 
 import re
+from dataclasses import dataclass, field
 
 from loguru import logger
 
 from lintro.parsers.rustfmt.rustfmt_issue import RustfmtIssue
 
-# Pattern to match "Diff in <file>:<line>:" format (actual rustfmt output)
-_DIFF_IN_RE = re.compile(r"^Diff in (?P<file>.+?):(?P<line>\d+):?$")
+# Pattern to match "Diff in <file>:<line>:" and "Diff in <file> at line <line>:"
+_DIFF_IN_RE = re.compile(
+    r"^Diff in (?P<file>.+?)(?::| at line )(?P<line>\d+):?$",
+)
 
 # Pattern to match file paths that would be reformatted
 # cargo fmt -- --check may output just the file path when using certain options
 _FILE_PATH_RE = re.compile(r"^(?P<file>.+\.rs)$")
 
+# Pattern to match the warning for a nightly-only option stable rustfmt ignores
+_UNSTABLE_OPTION_RE = re.compile(
+    r"^Warning: can't set `(?P<option>\w+) = (?P<value>.*)`, "
+    r"unstable features are only available in nightly channel\.?$",
+)
+
+# Lines of a hunk kept in the issue's diff preview
+DIFF_PREVIEW_LINES: int = 20
+
+
+@dataclass
+class _Hunk:
+    """Diff hunk being read.
+
+    Attributes:
+        file: File the hunk belongs to.
+        line: First line of the hunk in the original file.
+        body: Context, removed and added lines of the hunk.
+    """
+
+    file: str
+    line: int
+    body: list[str] = field(default_factory=list)
+
+    def to_issue(self) -> RustfmtIssue:
+        """Convert the hunk to an issue spanning the lines that change.
+
+        Returns:
+            RustfmtIssue: Issue for the hunk.
+        """
+        original = self.line
+        changed: list[int] = []
+        replacing = False
+        for text in self.body:
+            if text.startswith("-"):
+                changed.append(original)
+                original += 1
+                replacing = True
+            elif text.startswith("+"):
+                if not replacing:
+                    # A pure insertion goes in before the current line
+                    changed.append(original)
+            else:
+                original += 1
+                replacing = False
+        start = min(changed, default=self.line)
+        end = max(changed, default=self.line)
+        lines = f"line {start}" if start == end else f"lines {start}-{end}"
+        preview = self.body[:DIFF_PREVIEW_LINES]
+        if len(self.body) > DIFF_PREVIEW_LINES:
+            preview.append(f"... ({len(self.body) - DIFF_PREVIEW_LINES} more lines)")
+        return RustfmtIssue(
+            file=self.file,
+            line=start,
+            column=0,
+            message=f"Formatting differs on {lines}",
+            fixable=True,
+            end_line=end,
+            diff="\n".join(preview),
+        )
+
 
 def parse_rustfmt_output(output: str | None) -> list[RustfmtIssue]:
     """Parse rustfmt output into issues.
@@ -30,58 +107,77 @@ def parse_rustfmt_output(output: str | None) -> list[RustfmtIssue]:
         output: Raw stdout/stderr from rustfmt/cargo fmt --check.
 
     Returns:
-        List of parsed issues, one per file that needs formatting.
+        List of parsed issues, one per diff hunk or listed file.
     """
     if not output:
         return []
 
     issues: list[RustfmtIssue] = []
-    seen_files: set[str] = set()
+    seen: set[tuple[str, int]] = set()
+    hunk: _Hunk | None = None
+
+    def finish() -> None:
+        """Turn the hunk being read into an issue, once per location."""
+        if hunk is None:
+            return
+        issue = hunk.to_issue()
+        if (issue.file, issue.line) not in seen:
+            seen.add((issue.file, issue.line))
+            issues.append(issue)
 
     try:
-        for line in output.splitlines():
-            line = line.strip()
+        for raw_line in output.splitlines():
+            if hunk is not None and raw_line[:1] in (" ", "+", "-"):
+                hunk.body.append(raw_line)
+                continue
+
+            line = raw_line.strip()
             if not line:
                 continue
 
-            # Try to match "Diff in <file> at line <n>:" format
             m = _DIFF_IN_RE.match(line)
             if m:
-                file_path = m.group("file")
-                line_num = int(m.group("line"))
-
-                # Only add first occurrence per file to avoid duplicates
-                if file_path not in seen_files:
-                    seen_files.add(file_path)
-                    issues.append(
-                        RustfmtIssue(
-                            file=file_path,
-                            line=line_num,
-                            column=0,
-                            message="File needs formatting",
-                            fixable=True,
-                        ),
-                    )
+                finish()
+                hunk = _Hunk(file=m.group("file"), line=int(m.group("line")))
                 continue
 
+            finish()
+            hunk = None
+
             # Try to match standalone file paths (some rustfmt output modes)
             m = _FILE_PATH_RE.match(line)
-            if m:
-                file_path = m.group("file")
-                if file_path not in seen_files:
-                    seen_files.add(file_path)
-                    issues.append(
-                        RustfmtIssue(
-                            file=file_path,
-                            line=0,
-                            column=0,
-                            message="File needs formatting",
-                            fixable=True,
-                        ),
-                    )
-                continue
+            if m and (m.group("file"), 0) not in seen:
+                seen.add((m.group("file"), 0))
+                issues.append(
+                    RustfmtIssue(
+                        file=m.group("file"),
+                        line=0,
+                        column=0,
+                        message="File needs formatting",
+                        fixable=True,
+                    ),
+                )
+        finish()
 
     except (ValueError, TypeError, AttributeError) as e:
         logger.debug(f"Error parsing rustfmt output: {e}")
 
     return issues
+
+
+def parse_unstable_options(output: str | None) -> dict[str, str]:
+    """Parse the nightly-only options stable rustfmt ignored.
+
+    Args:
+        output: Raw stdout/stderr from rustfmt/cargo fmt.
+
+    Returns:
+        dict[str, str]: Configured value of each ignored option, in the
+        order rustfmt first warned about them.
+    """
+    options: dict[str, str] = {}
+    for raw_line in (output or "").splitlines():
+        m = _UNSTABLE_OPTION_RE.match(raw_line.strip())
+        if m:
+            options.setdefault(m.group("option"), m.group("value"))
+    return options
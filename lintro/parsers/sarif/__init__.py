@@ -0,0 +1,6 @@
+"""SARIF parser module."""
+
+from lintro.parsers.sarif.sarif_issue import SarifIssue
+from lintro.parsers.sarif.sarif_parser import parse_sarif_log
+
+__all__ = ["SarifIssue", "parse_sarif_log"]
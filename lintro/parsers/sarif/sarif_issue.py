@@ -0,0 +1,34 @@
+"""SARIF issue model for results imported from external scanners."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class SarifIssue(BaseIssue):
+    """Represents a result read from a SARIF log.
+
+    Attributes:
+        DISPLAY_FIELD_MAP: Mapping of display field names to attribute names.
+        rule_id: Rule that produced the result.
+        level: SARIF level: error, warning or note.
+        end_line: Ending line number of the result.
+        end_column: Ending column number of the result.
+        help_uri: Documentation of the rule, if the log names one.
+    """
+
+    DISPLAY_FIELD_MAP: ClassVar[dict[str, str]] = {
+        **BaseIssue.DISPLAY_FIELD_MAP,
+        "code": "rule_id",
+        "severity": "level",
+    }
+
+    rule_id: str = field(default="")
+    level: str = field(default="warning")
+    end_line: int = field(default=0)
+    end_column: int = field(default=0)
+    help_uri: str = field(default="")
@@ -0,0 +1,247 @@
+"""SARIF log parser for results produced outside lintro.
+
+Scanners such as CodeQL write SARIF 2.1.0: a log of runs, each naming its
+tool (``tool.driver``) and listing results with a rule, level, message and
+location. Results that do not describe an open problem are left out: those
+of kind ``pass`` or ``notApplicable``, those with an accepted suppression,
+and those a baseline comparison marked ``absent``.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+from urllib.parse import unquote, urlparse
+
+from loguru import logger
+
+from lintro.parsers.base_parser import (
+    extract_dict_field,
+    extract_int_field,
+    extract_str_field,
+)
+from lintro.parsers.sarif.sarif_issue import SarifIssue
+
+# Result kinds that report a check passing rather than a problem
+_PASSING_KINDS: frozenset[str] = frozenset({"pass", "notApplicable"})
+
+
+def _text(message: object) -> str:
+    """Return the text of a SARIF message object.
+
+    Args:
+        message: Message object, such as a result's ``message``.
+
+    Returns:
+        str: Plain text, else the Markdown text, else an empty string.
+    """
+    if not isinstance(message, dict):
+        return ""
+    return extract_str_field(data=message, candidates=["text", "markdown"])
+
+
+def _uri_to_path(uri: str, base: str) -> str:
+    """Turn an artifact URI into a file path.
+
+    Args:
+        uri: Artifact URI, absolute (``file:///src/a.py``) or relative.
+        base: Path the URI's base id stands for, or an empty string.
+
+    Returns:
+        str: File path.
+    """
+    if uri.startswith("file:"):
+        return unquote(urlparse(uri).path)
+    path = unquote(uri)
+    if base:
+        return f"{base.rstrip('/')}/{path}"
+    return path
+
+
+def _position(region: dict[str, object], key: str) -> int:
+    """Read a line or column of a region.
+
+    Args:
+        region: SARIF region.
+        key: Field name, such as ``startLine``.
+
+    Returns:
+        int: The 1-based position, or 0 if the region does not give it.
+    """
+    return extract_int_field(data=region, candidates=[key], default=0) or 0
+
+
+def _base_paths(run: dict[str, Any]) -> dict[str, str]:
+    """Resolve the ``originalUriBaseIds`` of a run to paths.
+
+    Args:
+        run: SARIF run.
+
+    Returns:
+        dict[str, str]: Path of each base id whose URI is a file URI.
+    """
+    bases = run.get("originalUriBaseIds")
+    if not isinstance(bases, dict):
+        return {}
+    return {
+        name: unquote(urlparse(uri).path)
+        for name, location in bases.items()
+        if isinstance(location, dict)
+        and isinstance(uri := location.get("uri"), str)
+        and uri.startswith("file:")
+    }
+
+
+def _is_open(result: dict[str, Any]) -> bool:
+    """Tell whether a result reports an open problem.
+
+    Args:
+        result: SARIF result.
+
+    Returns:
+        bool: False for passing results, accepted suppressions and results
+        a baseline comparison found fixed.
+    """
+    if result.get("kind") in _PASSING_KINDS:
+        return False
+    if result.get("baselineState") == "absent":
+        return False
+    suppressions = result.get("suppressions")
+    statuses = [
+        suppression.get("status", "accepted")
+        for suppression in (suppressions if isinstance(suppressions, list) else [])
+        if isinstance(suppression, dict)
+    ]
+    return "accepted" not in statuses
+
+
+def _rule_for(
+    result: dict[str, Any],
+    rules: list[dict[str, Any]],
+) -> tuple[str, dict[str, Any]]:
+    """Find the rule of a result.
+
+    Args:
+        result: SARIF result.
+        rules: Rules of the run's driver.
+
+    Returns:
+        tuple[str, dict[str, Any]]: Rule id, and the rule's metadata or an
+        empty dict when the driver does not describe it.
+    """
+    rule_id = extract_str_field(data=result, candidates=["ruleId"])
+    if not rule_id:
+        reference = extract_dict_field(data=result, candidates=["rule"])
+        rule_id = extract_str_field(data=reference, candidates=["id"])
+    index = result.get("ruleIndex")
+    if isinstance(index, int) and 0 <= index < len(rules):
+        rule = rules[index]
+        return rule_id or extract_str_field(data=rule, candidates=["id"]), rule
+    for rule in rules:
+        if rule.get("id") == rule_id:
+            return rule_id, rule
+    return rule_id, {}
+
+
+def _parse_result(
+    result: dict[str, Any],
+    rules: list[dict[str, Any]],
+    bases: dict[str, str],
+) -> SarifIssue | None:
+    """Parse one SARIF result.
+
+    Args:
+        result: SARIF result.
+        rules: Rules of the run's driver.
+        bases: Paths of the run's URI base ids.
+
+    Returns:
+        SarifIssue | None: The issue, or None if the result has no file
+        location.
+    """
+    rule_id, rule = _rule_for(result, rules)
+    locations = result.get("locations")
+    if not isinstance(locations, list) or not locations:
+        logger.debug(f"Skipping SARIF result {rule_id or '?'} without a location")
+        return None
+    physical = extract_dict_field(
+        data=locations[0] if isinstance(locations[0], dict) else {},
+        candidates=["physicalLocation"],
+    )
+    artifact = extract_dict_field(data=physical, candidates=["artifactLocation"])
+    uri = extract_str_field(data=artifact, candidates=["uri"])
+    if not uri:
+        logger.debug(f"Skipping SARIF result {rule_id or '?'} without a file")
+        return None
+    base = bases.get(extract_str_field(data=artifact, candidates=["uriBaseId"]), "")
+    region = extract_dict_field(data=physical, candidates=["region"])
+
+    default = extract_dict_field(data=rule, candidates=["defaultConfiguration"])
+    level = extract_str_field(
+        data=result,
+        candidates=["level"],
+        default=extract_str_field(
+            data=default,
+            candidates=["level"],
+            default="warning",
+        ),
+    )
+    message = (
+        _text(result.get("message"))
+        or _text(rule.get("shortDescription"))
+        or _text(rule.get("fullDescription"))
+        or rule_id
+    )
+    return SarifIssue(
+        file=_uri_to_path(uri, base),
+        line=_position(region, "startLine"),
+        column=_position(region, "startColumn"),
+        message=message,
+        rule_id=rule_id,
+        # "none" marks results that are not problems in themselves
+        level="note" if level == "none" else level,
+        end_line=_position(region, "endLine"),
+        end_column=_position(region, "endColumn"),
+        help_uri=extract_str_field(data=rule, candidates=["helpUri"]),
+    )
+
+
+def parse_sarif_log(data: object) -> dict[str, list[SarifIssue]]:
+    """Parse a SARIF log into issues per tool.
+
+    Args:
+        data: Parsed SARIF JSON.
+
+    Returns:
+        dict[str, list[SarifIssue]]: Issues keyed by the driver name of
+        their run; runs of the same tool are combined.
+
+    Raises:
+        ValueError: If the data is not a SARIF log.
+    """
+    if not isinstance(data, dict) or not isinstance(data.get("runs"), list):
+        raise ValueError("not a SARIF log: expected an object with a 'runs' list")
+    issues: dict[str, list[SarifIssue]] = {}
+    for run in data["runs"]:
+        if not isinstance(run, dict):
+            continue
+        tool = extract_dict_field(data=run, candidates=["tool"])
+        driver = extract_dict_field(data=tool, candidates=["driver"])
+        name = extract_str_field(data=driver, candidates=["name"])
+        if not name:
+            raise ValueError("SARIF run without a tool.driver.name")
+        raw_rules = driver.get("rules")
+        rules = [
+            rule
+            for rule in (raw_rules if isinstance(raw_rules, list) else [])
+            if isinstance(rule, dict)
+        ]
+        bases = _base_paths(run)
+        run_issues = issues.setdefault(name, [])
+        results = run.get("results")
+        for result in results if isinstance(results, list) else []:
+            if not isinstance(result, dict) or not _is_open(result):
+                continue
+            issue = _parse_result(result, rules, bases)
+            if issue is not None:
+                run_issues.append(issue)
+    return issues
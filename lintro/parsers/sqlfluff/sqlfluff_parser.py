@@ -15,7 +15,8 @@ SQLFluff JSON output has a nested structure with a "violations" array per file:
         "end_line_pos": 6,
         "code": "L010",
         "description": "Keywords must be upper case.",
-        "name": "capitalisation.keywords"
+        "name": "capitalisation.keywords",
+        "fixes": [{"type": "replace", "edit": "SELECT", ...}]
       }
     ]
   }
@@ -57,6 +58,8 @@ def _parse_sqlfluff_violation(
     code = extract_str_field(violation, ["code"]) or ""
     rule_name = extract_str_field(violation, ["name", "rule_name"]) or ""
     message = extract_str_field(violation, ["description", "message"]) or ""
+    # Only violations SQLFluff can fix carry a non-empty "fixes" list
+    fixes = violation.get("fixes")
 
     return SqlfluffIssue(
         file=filepath,
@@ -67,6 +70,7 @@ def _parse_sqlfluff_violation(
         message=message,
         end_line=end_line,
         end_column=end_column,
+        fixable=isinstance(fixes, list) and bool(fixes),
     )
 
 
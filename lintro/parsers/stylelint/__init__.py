@@ -0,0 +1,6 @@
+"""Parsing utilities and types for Stylelint output."""
+
+from lintro.parsers.stylelint.stylelint_issue import StylelintIssue
+from lintro.parsers.stylelint.stylelint_parser import parse_stylelint_output
+
+__all__ = ["StylelintIssue", "parse_stylelint_output"]
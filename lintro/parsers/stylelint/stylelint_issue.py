@@ -0,0 +1,19 @@
+"""Typed structure representing a single Stylelint warning."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class StylelintIssue(BaseIssue):
+    """Simple container for Stylelint findings.
+
+    Attributes:
+        code: Rule name (e.g., 'block-no-empty' or 'scss/at-rule-no-unknown'),
+            or 'CssSyntaxError' for style sheets that cannot be parsed.
+        severity: Severity level ('error', 'warning').
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="error")
@@ -0,0 +1,90 @@
+"""Parser for Stylelint JSON output.
+
+Handles the output of ``stylelint --formatter json``: one entry per style
+sheet, each with a list of warnings. Stylelint 16 writes the report to
+stderr, so it is located in the combined output.
+"""
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.stylelint.stylelint_issue import StylelintIssue
+
+
+def parse_stylelint_output(output: str) -> list[StylelintIssue]:
+    """Parse Stylelint JSON output into a list of StylelintIssue objects.
+
+    Args:
+        output: The raw JSON output from Stylelint.
+
+    Returns:
+        List of StylelintIssue objects.
+    """
+    issues: list[StylelintIssue] = []
+
+    if not output:
+        return issues
+
+    try:
+        # Deprecation notices may surround the JSON report
+        json_start = output.find("[")
+        json_end = output.rfind("]") + 1
+        if json_start == -1 or json_end == 0:
+            return issues
+        stylelint_data: list[Any] = json.loads(output[json_start:json_end])
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse Stylelint JSON output: {e}")
+        return issues
+
+    if not isinstance(stylelint_data, list):
+        logger.debug("Stylelint output is not a list")
+        return issues
+
+    for file_result in stylelint_data:
+        if not isinstance(file_result, dict):
+            continue
+        file_path = str(file_result.get("source", ""))
+        warnings = file_result.get("warnings", [])
+        if not isinstance(warnings, list):
+            continue
+        for warning in warnings:
+            if not isinstance(warning, dict):
+                continue
+            try:
+                issues.append(_parse_warning(file_path, warning))
+            except (KeyError, TypeError, ValueError) as e:
+                logger.debug(f"Failed to parse Stylelint warning: {e}")
+                continue
+
+    return issues
+
+
+def _parse_warning(file_path: str, warning: dict[str, Any]) -> StylelintIssue:
+    """Parse a single Stylelint warning into a StylelintIssue.
+
+    Args:
+        file_path: Style sheet the warning belongs to.
+        warning: Warning dictionary from Stylelint JSON output.
+
+    Returns:
+        StylelintIssue for the warning.
+    """
+    rule = str(warning.get("rule") or "")
+    text = str(warning.get("text", "")).strip()
+    # Stylelint appends the rule name to every message
+    suffix = f"({rule})"
+    if rule and text.endswith(suffix):
+        text = text[: -len(suffix)].rstrip()
+
+    severity = str(warning.get("severity") or "error").lower()
+
+    return StylelintIssue(
+        file=file_path,
+        line=int(warning.get("line") or 0),
+        column=int(warning.get("column") or 0),
+        message=text,
+        code=rule,
+        severity=severity if severity in ("error", "warning") else "error",
+    )
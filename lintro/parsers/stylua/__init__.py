@@ -0,0 +1,6 @@
+"""Parsing utilities and types for StyLua output."""
+
+from lintro.parsers.stylua.stylua_issue import StyluaIssue
+from lintro.parsers.stylua.stylua_parser import StyluaReport, parse_stylua_output
+
+__all__ = ["StyluaIssue", "StyluaReport", "parse_stylua_output"]
@@ -0,0 +1,31 @@
+"""Typed structure representing a single StyLua formatting mismatch."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class StyluaIssue(BaseIssue):
+    """Represents a range of a Lua file that StyLua would reformat.
+
+    Attributes:
+        DEFAULT_SEVERITY: Defaults to INFO (pure formatter).
+        code: Always ``FORMAT``.
+        fixable: Whether ``stylua`` can fix the mismatch; always True.
+        end_line: Last line of the range StyLua would rewrite.
+        original: The current source of the range.
+        expected: The source StyLua would write instead.
+    """
+
+    DEFAULT_SEVERITY: ClassVar[SeverityLevel] = SeverityLevel.INFO
+
+    code: str = field(default="FORMAT")
+    fixable: bool = field(default=True)
+    end_line: int | None = field(default=None)
+    original: str = field(default="")
+    expected: str = field(default="")
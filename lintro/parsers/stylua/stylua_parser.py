@@ -0,0 +1,95 @@
+"""Parser for StyLua check output.
+
+``stylua --check --output-format=json`` prints one JSON object per file
+that is not formatted::
+
+    {"file":"lua/init.lua","mismatches":[
+        {"original_start_line":3,"original_end_line":5,
+         "expected_start_line":3,"expected_end_line":4,
+         "original":"local x = {1,2}\\n","expected":"local x = { 1, 2 }\\n"}]}
+
+The line numbers are 0-based and the end lines exclusive. Files StyLua
+cannot parse are reported on stderr as ``error: ...`` lines, which are
+collected as errors.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any
+
+from lintro.parsers.stylua.stylua_issue import StyluaIssue
+
+
+@dataclass
+class StyluaReport:
+    """Parsed StyLua check report.
+
+    Attributes:
+        issues: Formatting mismatches in the checked files.
+        errors: Errors StyLua printed, such as files it cannot parse.
+    """
+
+    issues: list[StyluaIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def parse_stylua_output(output: str | None) -> StyluaReport:
+    """Parse ``stylua --check --output-format=json`` output.
+
+    Args:
+        output: Raw stdout/stderr from StyLua.
+
+    Returns:
+        StyluaReport: Parsed mismatches and errors.
+    """
+    report = StyluaReport()
+    if not output:
+        return report
+
+    for raw_line in output.splitlines():
+        line = raw_line.strip()
+        if not line:
+            continue
+        if line.startswith("{"):
+            try:
+                data = json.loads(line)
+            except json.JSONDecodeError:
+                data = None
+            if isinstance(data, dict):
+                report.issues.extend(_parse_file(data))
+                continue
+        if line.lower().startswith("error"):
+            report.errors.append(line)
+    return report
+
+
+def _parse_file(data: dict[str, Any]) -> list[StyluaIssue]:
+    """Parse the mismatches StyLua reported for one file.
+
+    Args:
+        data: Decoded JSON object for the file.
+
+    Returns:
+        list[StyluaIssue]: One issue per mismatched range.
+    """
+    path = str(data.get("file") or "")
+    issues: list[StyluaIssue] = []
+    for mismatch in data.get("mismatches") or []:
+        if not isinstance(mismatch, dict):
+            continue
+        first = int(mismatch.get("original_start_line") or 0) + 1
+        last = max(int(mismatch.get("original_end_line") or 0), first)
+        lines = f"line {first}" if first == last else f"lines {first}-{last}"
+        issues.append(
+            StyluaIssue(
+                file=path,
+                line=first,
+                message=f"Formatting differs on {lines}",
+                end_line=last,
+                original=str(mismatch.get("original") or ""),
+                expected=str(mismatch.get("expected") or ""),
+            ),
+        )
+    return issues
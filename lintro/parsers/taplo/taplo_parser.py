@@ -13,21 +13,29 @@ from lintro.parsers.taplo.taplo_issue import TaploIssue
 
 # Pre-compiled regex patterns for taplo output parsing
 # Pattern for taplo error header: error[code]: message or warning[code]: message
+# taplo lint reports syntax and schema errors without a code: error: message
 _HEADER_PATTERN: re.Pattern[str] = re.compile(
-    r"^(error|warning)\[([^\]]+)\]:\s*(.+)$",
+    r"^(error|warning)(?:\[([^\]]+)\])?:\s*(.+)$",
 )
 
-# Pattern for location line:   --> file:line:column
+# Pattern for location line:   --> file:line:column  or  ┌─ file:line:column
 _LOCATION_PATTERN: re.Pattern[str] = re.compile(
-    r"^\s*-->\s*(.+):(\d+):(\d+)\s*$",
+    r"^\s*(?:-->|┌─|╭─)\s*(.+):(\d+):(\d+)\s*$",
 )
 
+# Codes for taplo lint diagnostics that carry none; any other uncoded
+# diagnostic is a schema validation error
+_UNCODED_MESSAGE_CODES: dict[str, str] = {
+    "invalid toml": "invalid_toml",
+    "conflicting keys": "conflicting_keys",
+}
+
 # Pattern for taplo fmt --check output:
 # ERROR taplo:format_files: the file is not properly formatted path="..."
 # Also handles RUST_LOG=error format:
 # ERROR the file is not properly formatted path="..."
 _FMT_CHECK_PATTERN: re.Pattern[str] = re.compile(
-    r'^ERROR\s+(?:taplo:format_files:\s*)?(.+?)\s+path="([^"]+)"',
+    r'^ERROR\s+(?:taplo:format_files:\s*)?(.+?formatted)\s+path="([^"]+)"',
 )
 
 
@@ -48,6 +56,10 @@ def parse_taplo_output(output: str | None) -> list[TaploIssue]:
      5 | version =
        |          ^ expected a value
 
+    taplo lint reports syntax and schema validation errors without a code:
+    error: "2030" is not one of ["2015", "2018", "2021", "2024"]
+      ┌─ Cargo.toml:4:11
+
     Args:
         output: The raw output from taplo, or None.
 
@@ -91,8 +103,11 @@ def parse_taplo_output(output: str | None) -> list[TaploIssue]:
         header_match: re.Match[str] | None = _HEADER_PATTERN.match(line)
         if header_match:
             level: str = header_match.group(1)
-            code: str = header_match.group(2)
             message = header_match.group(3).strip()
+            code: str = header_match.group(2) or _UNCODED_MESSAGE_CODES.get(
+                message.lower(),
+                "schema",
+            )
 
             # Look for location in next lines
             file_path = ""
@@ -0,0 +1,8 @@
+"""Parsing utilities and types for terraform fmt output."""
+
+from lintro.parsers.terraform_fmt.terraform_fmt_issue import TerraformFmtIssue
+from lintro.parsers.terraform_fmt.terraform_fmt_parser import (
+    parse_terraform_fmt_output,
+)
+
+__all__ = ["TerraformFmtIssue", "parse_terraform_fmt_output"]
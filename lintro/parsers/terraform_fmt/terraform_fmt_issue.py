@@ -0,0 +1,26 @@
+"""Typed structure representing a single terraform fmt finding."""
+
+from dataclasses import dataclass, field
+from typing import ClassVar
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class TerraformFmtIssue(BaseIssue):
+    """Simple container for terraform fmt findings.
+
+    ``terraform fmt -check`` only names the files it would rewrite, so each
+    issue covers a whole file.
+
+    Attributes:
+        DEFAULT_SEVERITY: Defaults to INFO (pure formatter).
+        code: Always 'FORMAT'.
+        fixable: Whether the issue can be auto-fixed (always True).
+    """
+
+    DEFAULT_SEVERITY: ClassVar[SeverityLevel] = SeverityLevel.INFO
+
+    code: str = field(default="FORMAT")
+    fixable: bool = field(default=True)
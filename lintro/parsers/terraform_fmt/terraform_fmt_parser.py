@@ -0,0 +1,49 @@
+"""Parser for terraform fmt output.
+
+Handles ``terraform fmt -check -list=true`` output, which names one file
+per line for each file that needs formatting. Diagnostics for files
+terraform cannot parse are not file names and are skipped.
+"""
+
+import re
+
+from loguru import logger
+
+from lintro.parsers.base_parser import FILE_PATH_PATTERN, strip_ansi_codes
+from lintro.parsers.terraform_fmt.terraform_fmt_issue import TerraformFmtIssue
+
+# A bare path to a file terraform fmt handles; diagnostic lines contain
+# colons or start with whitespace
+_FILE_LINE_RE = re.compile(rf"^(?!\s){FILE_PATH_PATTERN}\.(?:tf|tfvars|tftest\.hcl)$")
+
+
+def parse_terraform_fmt_output(output: str | None) -> list[TerraformFmtIssue]:
+    """Parse terraform fmt output into a list of TerraformFmtIssue objects.
+
+    Args:
+        output: The raw output from ``terraform fmt -check -list=true``.
+
+    Returns:
+        List of TerraformFmtIssue objects, one per unformatted file.
+    """
+    issues: list[TerraformFmtIssue] = []
+
+    if not output:
+        return issues
+
+    for line in strip_ansi_codes(output).splitlines():
+        line = line.rstrip()
+        if not _FILE_LINE_RE.match(line):
+            if line.strip():
+                logger.debug(f"Skipping non-file line from terraform fmt: '{line}'")
+            continue
+        issues.append(
+            TerraformFmtIssue(
+                file=line,
+                line=1,
+                column=1,
+                message="File is not formatted",
+            ),
+        )
+
+    return issues
@@ -0,0 +1,6 @@
+"""Parsing utilities and types for TFLint output."""
+
+from lintro.parsers.tflint.tflint_issue import TflintIssue
+from lintro.parsers.tflint.tflint_parser import TflintReport, parse_tflint_output
+
+__all__ = ["TflintIssue", "TflintReport", "parse_tflint_output"]
@@ -0,0 +1,24 @@
+"""Typed structure representing a single TFLint finding."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class TflintIssue(BaseIssue):
+    """Simple container for TFLint findings.
+
+    Attributes:
+        code: Rule name, such as ``terraform_unused_declarations``, or
+            ``tflint_error`` for errors TFLint reports at a location, such
+            as invalid HCL.
+        severity: TFLint severity (``error``, ``warning`` or ``info``).
+        end_line: Last line of the flagged range, if known.
+        link: Rule documentation URL, if known.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    end_line: int | None = field(default=None)
+    link: str = field(default="")
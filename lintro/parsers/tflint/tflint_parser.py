@@ -0,0 +1,128 @@
+"""Parser for TFLint JSON output.
+
+Handles the output of ``tflint --format=json``: an object with an
+``issues`` list of rule violations and an ``errors`` list. Errors that
+point at a range, such as HCL syntax errors, become issues; the others,
+such as an invalid ``.tflint.hcl``, are returned as messages because they
+stop TFLint from checking the module at all.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.tflint.tflint_issue import TflintIssue
+
+TFLINT_ERROR_CODE: str = "tflint_error"
+
+
+@dataclass
+class TflintReport:
+    """Parsed TFLint report.
+
+    Attributes:
+        issues: Rule violations and located errors.
+        errors: Messages of errors without a location.
+    """
+
+    issues: list[TflintIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def parse_tflint_output(output: str | None) -> TflintReport | None:
+    """Parse TFLint JSON output.
+
+    Args:
+        output: The raw output from ``tflint --format=json``.
+
+    Returns:
+        TflintReport | None: The parsed report, or None if the output holds
+        no JSON report.
+    """
+    if not output:
+        return None
+
+    start = output.find("{")
+    if start == -1:
+        return None
+    try:
+        data: Any = json.loads(output[start:])
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse TFLint JSON output: {e}")
+        return None
+    if not isinstance(data, dict):
+        return None
+
+    report = TflintReport()
+    for item in data.get("issues") or []:
+        if isinstance(item, dict):
+            report.issues.append(_parse_issue(item))
+    for item in data.get("errors") or []:
+        if not isinstance(item, dict):
+            continue
+        if isinstance(item.get("range"), dict):
+            report.issues.append(_parse_error(item))
+        else:
+            report.errors.append(str(item.get("message", "")))
+    return report
+
+
+def _range_fields(item: dict[str, Any]) -> dict[str, Any]:
+    """Extract location fields from a TFLint issue or error.
+
+    Args:
+        item: Issue or error dictionary carrying a ``range``.
+
+    Returns:
+        dict[str, Any]: ``file``, ``line``, ``column`` and ``end_line``
+        keyword arguments for TflintIssue.
+    """
+    location = item.get("range") or {}
+    start = location.get("start") or {}
+    end = location.get("end") or {}
+    return {
+        "file": str(location.get("filename", "")),
+        "line": int(start.get("line") or 0),
+        "column": int(start.get("column") or 0),
+        "end_line": end.get("line"),
+    }
+
+
+def _parse_issue(item: dict[str, Any]) -> TflintIssue:
+    """Parse a single TFLint rule violation.
+
+    Args:
+        item: Entry of the ``issues`` list.
+
+    Returns:
+        TflintIssue for the violation.
+    """
+    rule = item.get("rule") or {}
+    return TflintIssue(
+        message=str(item.get("message", "")),
+        code=str(rule.get("name", "")),
+        severity=str(rule.get("severity", "")),
+        link=str(rule.get("link", "")),
+        **_range_fields(item),
+    )
+
+
+def _parse_error(item: dict[str, Any]) -> TflintIssue:
+    """Parse a TFLint error that points at a range.
+
+    Args:
+        item: Entry of the ``errors`` list with a ``range``.
+
+    Returns:
+        TflintIssue for the error.
+    """
+    return TflintIssue(
+        message=str(item.get("message", "")),
+        code=TFLINT_ERROR_CODE,
+        severity=str(item.get("severity") or "error"),
+        **_range_fields(item),
+    )
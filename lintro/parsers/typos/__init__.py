@@ -0,0 +1,6 @@
+"""Parsing utilities and types for typos output."""
+
+from lintro.parsers.typos.typos_issue import TyposIssue
+from lintro.parsers.typos.typos_parser import parse_typos_output
+
+__all__ = ["TyposIssue", "parse_typos_output"]
@@ -0,0 +1,23 @@
+"""Typed structure representing a single typos finding."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class TyposIssue(BaseIssue):
+    """Simple container for typos findings.
+
+    Attributes:
+        code: Always 'typo'; typos has no rule identifiers.
+        typo: The misspelled word as it appears in the file.
+        corrections: Suggested replacements, empty for disallowed words.
+        fixable: Whether ``typos --write-changes`` corrects the word, which
+            it only does when there is a single correction.
+    """
+
+    code: str = field(default="typo")
+    typo: str = field(default="")
+    corrections: list[str] = field(default_factory=list)
+    fixable: bool = field(default=False)
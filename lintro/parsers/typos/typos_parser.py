@@ -0,0 +1,81 @@
+"""Parser for typos JSON output.
+
+Handles the output of ``typos --format json``: one JSON object per line.
+Objects of type ``typo`` are misspellings, either in a file's contents
+(with ``line_num`` and ``byte_offset``) or in its path (without them);
+other message types, such as binary files being skipped, are ignored.
+"""
+
+import json
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.typos.typos_issue import TyposIssue
+
+
+def parse_typos_output(output: str) -> list[TyposIssue]:
+    """Parse typos JSON lines output into a list of TyposIssue objects.
+
+    Args:
+        output: The raw JSON lines output from typos.
+
+    Returns:
+        List of TyposIssue objects.
+    """
+    issues: list[TyposIssue] = []
+
+    if not output:
+        return issues
+
+    for line in output.splitlines():
+        line = line.strip()
+        if not line.startswith("{"):
+            continue
+        try:
+            message: Any = json.loads(line)
+        except json.JSONDecodeError as e:
+            logger.debug(f"Failed to parse typos JSON line: {e}")
+            continue
+        if not isinstance(message, dict) or message.get("type") != "typo":
+            continue
+        issues.append(_parse_typo(message))
+
+    return issues
+
+
+def _parse_typo(message: dict[str, Any]) -> TyposIssue:
+    """Parse a single typos message into a TyposIssue.
+
+    Args:
+        message: Message dictionary of type ``typo``.
+
+    Returns:
+        TyposIssue for the misspelling.
+    """
+    typo = str(message.get("typo", ""))
+    raw_corrections = message.get("corrections")
+    # Disallowed words carry a status string instead of a list
+    corrections = (
+        [str(c) for c in raw_corrections] if isinstance(raw_corrections, list) else []
+    )
+    if corrections:
+        suggestions = ", ".join(f"`{c}`" for c in corrections)
+        text = f"`{typo}` should be {suggestions}"
+    else:
+        text = f"`{typo}` is disallowed"
+
+    line = int(message.get("line_num") or 0)
+    byte_offset = message.get("byte_offset")
+    # Path typos have no line; their offset points into the file name
+    column = int(byte_offset) + 1 if line and isinstance(byte_offset, int) else 0
+
+    return TyposIssue(
+        file=str(message.get("path", "")),
+        line=line,
+        column=column,
+        message=text,
+        typo=typo,
+        corrections=corrections,
+        fixable=len(corrections) == 1,
+    )
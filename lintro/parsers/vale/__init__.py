@@ -0,0 +1,6 @@
+"""Parsing utilities and types for Vale output."""
+
+from lintro.parsers.vale.vale_issue import ValeIssue
+from lintro.parsers.vale.vale_parser import ValeReport, parse_vale_output
+
+__all__ = ["ValeIssue", "ValeReport", "parse_vale_output"]
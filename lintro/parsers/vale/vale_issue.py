@@ -0,0 +1,25 @@
+"""Typed structure representing a single Vale alert."""
+
+from dataclasses import dataclass, field
+
+from lintro.parsers.base_issue import BaseIssue
+
+
+@dataclass
+class ValeIssue(BaseIssue):
+    """Simple container for Vale alerts.
+
+    Attributes:
+        code: Rule that raised the alert, as ``Style.Rule`` (for example
+            ``Microsoft.Passive`` or ``Vale.Terms``).
+        severity: Vale alert level (``suggestion``, ``warning`` or ``error``).
+        end_column: Last column of the flagged text, if known.
+        match: Text the rule matched.
+        link: Rule documentation URL, if known.
+    """
+
+    code: str = field(default="")
+    severity: str = field(default="")
+    end_column: int | None = field(default=None)
+    match: str = field(default="")
+    link: str = field(default="")
@@ -0,0 +1,92 @@
+"""Parser for Vale JSON output.
+
+Handles the output of ``vale --output=JSON``: an object mapping each file
+with alerts to its list of alerts. Runtime errors, such as an invalid
+``.vale.ini`` or a style that was never synced, are printed as a single
+object with ``Code`` and ``Text`` instead.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from typing import Any
+
+from loguru import logger
+
+from lintro.parsers.vale.vale_issue import ValeIssue
+
+
+@dataclass
+class ValeReport:
+    """Parsed Vale report.
+
+    Attributes:
+        issues: Alerts raised for the checked files.
+        errors: Messages of runtime errors that stopped Vale.
+    """
+
+    issues: list[ValeIssue] = field(default_factory=list)
+    errors: list[str] = field(default_factory=list)
+
+
+def parse_vale_output(output: str | None) -> ValeReport | None:
+    """Parse Vale JSON output.
+
+    Args:
+        output: The raw output from ``vale --output=JSON``.
+
+    Returns:
+        ValeReport | None: The parsed report, or None if the output holds no
+        JSON report.
+    """
+    if not output:
+        return None
+
+    start = output.find("{")
+    if start == -1:
+        return None
+    try:
+        data: Any = json.loads(output[start:])
+    except json.JSONDecodeError as e:
+        logger.debug(f"Failed to parse Vale JSON output: {e}")
+        return None
+    if not isinstance(data, dict):
+        return None
+
+    report = ValeReport()
+    if isinstance(data.get("Code"), str) and "Text" in data:
+        report.errors.append(f"{data['Code']}: {data['Text']}".strip())
+        return report
+
+    for file_path, alerts in data.items():
+        if not isinstance(alerts, list):
+            continue
+        for alert in alerts:
+            if isinstance(alert, dict):
+                report.issues.append(_parse_alert(str(file_path), alert))
+    return report
+
+
+def _parse_alert(file_path: str, alert: dict[str, Any]) -> ValeIssue:
+    """Parse a single Vale alert.
+
+    Args:
+        file_path: File the alert was raised for.
+        alert: Entry of the file's alert list.
+
+    Returns:
+        ValeIssue for the alert.
+    """
+    span = alert.get("Span") or []
+    return ValeIssue(
+        file=file_path,
+        line=int(alert.get("Line") or 0),
+        column=int(span[0]) if len(span) > 0 else 0,
+        message=str(alert.get("Message", "")),
+        code=str(alert.get("Check", "")),
+        severity=str(alert.get("Severity", "")),
+        end_column=int(span[1]) if len(span) > 1 else None,
+        match=str(alert.get("Match", "")),
+        link=str(alert.get("Link", "")),
+    )
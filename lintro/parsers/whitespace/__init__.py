@@ -0,0 +1,9 @@
+"""Whitespace issue module.
+
+This module provides the issue model for Lintro's native whitespace
+hygiene checker.
+"""
+
+from lintro.parsers.whitespace.whitespace_issue import WhitespaceIssue
+
+__all__ = ["WhitespaceIssue"]
@@ -5,12 +5,13 @@ import re
 from loguru import logger
 
 from lintro.enums.severity_level import normalize_severity_level
-from lintro.parsers.base_parser import strip_ansi_codes
+from lintro.parsers.base_parser import FILE_PATH_PATTERN, strip_ansi_codes
 from lintro.parsers.yamllint.yamllint_issue import YamllintIssue
 
 # Pattern for yamllint parsable format: "filename:line:column: [level] message (rule)"
 _YAMLLINT_PATTERN: re.Pattern[str] = re.compile(
-    r"^([^:]+):(\d+):(\d+):\s*\[(error|warning)\]\s+(.+?)(?:\s+\(([^)]+)\))?$",
+    rf"^({FILE_PATH_PATTERN}):(\d+):(\d+):\s*\[(error|warning)\]\s+"
+    r"(.+?)(?:\s+\(([^)]+)\))?$",
 )
 
 
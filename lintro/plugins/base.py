@@ -21,6 +21,7 @@ Example:
 
 from __future__ import annotations
 
+import os
 from abc import ABC, abstractmethod
 from dataclasses import dataclass, field
 from typing import TYPE_CHECKING
@@ -30,9 +31,16 @@ from loguru import logger
 
 from lintro.config.lintro_config import LintroConfig
 from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.container_backend import ContainerSpec, build_container_command
+from lintro.plugins.daemon_backend import (
+    DaemonSpec,
+    build_daemon_command,
+    ensure_daemon_started,
+)
 from lintro.plugins.execution_preparation import (
     DEFAULT_TIMEOUT,
     build_config_args,
+    detect_tool_version,
     get_defaults_config_args,
     get_effective_timeout,
     get_enforce_cli_args,
@@ -43,6 +51,7 @@ from lintro.plugins.execution_preparation import (
     should_use_lintro_config,
     verify_tool_version,
 )
+from lintro.plugins.extra_args import insert_extra_args
 from lintro.plugins.file_discovery import (
     DEFAULT_EXCLUDE_PATTERNS,
     discover_files,
@@ -50,17 +59,25 @@ from lintro.plugins.file_discovery import (
     setup_exclude_patterns,
     validate_paths,
 )
-from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.protocol import ToolCapabilities, ToolDefinition
+from lintro.plugins.remote_backend import RemoteSpec, run_remote
+from lintro.plugins.resource_limits import ResourceLimits
 from lintro.plugins.subprocess_executor import (
     run_subprocess,
     run_subprocess_streaming,
     validate_subprocess_command,
 )
+from lintro.utils.path_filtering import (
+    DEFAULT_PRUNE_DIRS,
+    DEFAULT_SYMLINK_POLICY,
+    SYMLINK_POLICIES,
+)
 
 if TYPE_CHECKING:
     from collections.abc import Callable
 
     from lintro.plugins.file_processor import AggregatedResult, FileProcessingResult
+    from lintro.utils.result_cache import ResultCache
 
 
 @dataclass
@@ -110,18 +127,51 @@ class BaseToolPlugin(ABC):
     - check() method: Check files for issues
 
     Optionally override:
-    - fix() method: Fix issues (only if definition.can_fix=True)
+    - fix() method: Fix issues (only if capabilities().supports_fix)
     - set_options() method: Custom option validation
 
     Attributes:
         options: Current tool options (merged from defaults and runtime).
         exclude_patterns: Patterns to exclude from file discovery.
         include_venv: Whether to include virtual environment files.
+        prune_dirs: Directory names (fnmatch patterns) file discovery does
+            not descend into.
+        gitignore: Whether file discovery skips files git ignores.
+        symlinks: How file discovery treats symbolic links (see
+            SYMLINK_POLICIES).
+        container: Container settings when the tool runs in a container
+            (``execution.backend: container``), or None to run on the host.
+        remote: Remote settings when the tool runs on another host over SSH
+            (``execution.remote``), or None to run locally.
+        limits: Memory and priority limits of the tool's processes on this
+            host (see resource_limits), or None for no limits.
+        daemon: Daemon client the tool's commands run through
+            (``tools.<name>.daemon``), or None to run the tool directly.
+        result_cache: Per-file result cache used by ``lintro check``
+            (``execution.cache``), or None to check every file.
+        extra_args: Arguments added to the tool's commands
+            (``tools.<name>.extra_args`` and ``--extra-args``).
+        toolchain_bin: Bin directory of the Node.js release the project pins
+            (see toolchains), put first on PATH of the tool's commands on
+            this host, or None to use PATH as is.
     """
 
     options: dict[str, object] = field(default_factory=dict, init=False)
     exclude_patterns: list[str] = field(default_factory=list, init=False)
     include_venv: bool = field(default=False, init=False)
+    prune_dirs: list[str] = field(
+        default_factory=lambda: list(DEFAULT_PRUNE_DIRS),
+        init=False,
+    )
+    gitignore: bool = field(default=True, init=False)
+    symlinks: str = field(default=DEFAULT_SYMLINK_POLICY, init=False)
+    container: ContainerSpec | None = field(default=None, init=False)
+    remote: RemoteSpec | None = field(default=None, init=False)
+    limits: ResourceLimits | None = field(default=None, init=False)
+    daemon: DaemonSpec | None = field(default=None, init=False)
+    result_cache: ResultCache | None = field(default=None, init=False)
+    extra_args: list[str] = field(default_factory=list, init=False)
+    toolchain_bin: str | None = field(default=None, init=False)
 
     def __post_init__(self) -> None:
         """Initialize plugin with defaults from definition."""
@@ -152,6 +202,19 @@ class BaseToolPlugin(ABC):
         """
         return self.definition.name
 
+    def capabilities(self, detect_version: bool = False) -> ToolCapabilities:
+        """Describe what the tool supports.
+
+        Args:
+            detect_version: Whether to run the tool's version command to
+                fill in ``version_detected``.
+
+        Returns:
+            ToolCapabilities: Capability flags of the tool.
+        """
+        version = detect_tool_version(self.definition) if detect_version else None
+        return self.definition.capabilities(version_detected=version)
+
     # -------------------------------------------------------------------------
     # Public API
     # -------------------------------------------------------------------------
@@ -179,6 +242,14 @@ class BaseToolPlugin(ABC):
                 raise ValueError("Exclude patterns must be a list")
             if key == ToolOptionKey.INCLUDE_VENV.value and not isinstance(value, bool):
                 raise ValueError("Include venv must be a boolean")
+            if key == ToolOptionKey.PRUNE_DIRS.value and not isinstance(value, list):
+                raise ValueError("Prune dirs must be a list")
+            if key == ToolOptionKey.GITIGNORE.value and not isinstance(value, bool):
+                raise ValueError("Gitignore must be a boolean")
+            if key == ToolOptionKey.SYMLINKS.value and value not in SYMLINK_POLICIES:
+                raise ValueError(
+                    f"Symlinks must be one of: {', '.join(SYMLINK_POLICIES)}",
+                )
 
         self.options.update(kwargs)
 
@@ -194,6 +265,14 @@ class BaseToolPlugin(ABC):
                         seen.add(p)
         if ToolOptionKey.INCLUDE_VENV.value in kwargs:
             self.include_venv = bool(kwargs[ToolOptionKey.INCLUDE_VENV.value])
+        if ToolOptionKey.PRUNE_DIRS.value in kwargs:
+            prune_dirs = kwargs[ToolOptionKey.PRUNE_DIRS.value]
+            if isinstance(prune_dirs, list):
+                self.prune_dirs = [str(name) for name in prune_dirs]
+        if ToolOptionKey.GITIGNORE.value in kwargs:
+            self.gitignore = bool(kwargs[ToolOptionKey.GITIGNORE.value])
+        if ToolOptionKey.SYMLINKS.value in kwargs:
+            self.symlinks = str(kwargs[ToolOptionKey.SYMLINKS.value])
 
     @abstractmethod
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
@@ -211,7 +290,7 @@ class BaseToolPlugin(ABC):
     def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
         """Fix issues in files.
 
-        Default implementation raises NotImplementedError if can_fix=False.
+        Default implementation raises NotImplementedError if supports_fix is False.
         Override in subclasses that support fixing.
 
         Args:
@@ -224,7 +303,7 @@ class BaseToolPlugin(ABC):
         Raises:
             NotImplementedError: If the tool doesn't support fixing.
         """
-        if not self.definition.can_fix:
+        if not self.capabilities().supports_fix:
             raise NotImplementedError(
                 f"{self.definition.name} does not support fixing issues",
             )
@@ -262,6 +341,9 @@ class BaseToolPlugin(ABC):
             exclude_patterns=self.exclude_patterns,
             include_venv=self.include_venv,
             show_progress=show_progress,
+            prune_dirs=self.prune_dirs,
+            gitignore=self.gitignore,
+            symlinks=self.symlinks,
         )
 
     def _run_subprocess(
@@ -283,7 +365,13 @@ class BaseToolPlugin(ABC):
             Tuple of (success, output) where success indicates return code 0.
         """
         effective_timeout = self._get_effective_timeout(timeout)
-        return run_subprocess(cmd, effective_timeout, cwd, env)
+        cmd = self._with_extra_args(cmd)
+        if self.remote is not None:
+            return run_remote(cmd, self.remote, effective_timeout, cwd, env)
+        cmd = self._daemon_command(cmd)
+        cmd = self._container_command(cmd, cwd, env)
+        env = self._toolchain_env(env)
+        return run_subprocess(cmd, effective_timeout, cwd, env, limits=self._limits())
 
     def _run_subprocess_streaming(
         self,
@@ -292,6 +380,7 @@ class BaseToolPlugin(ABC):
         cwd: str | None = None,
         env: dict[str, str] | None = None,
         line_handler: Callable[[str], None] | None = None,
+        max_output_chars: int | None = None,
     ) -> tuple[bool, str]:
         """Run a subprocess command with optional line-by-line streaming.
 
@@ -304,12 +393,116 @@ class BaseToolPlugin(ABC):
             cwd: Working directory for command execution.
             env: Environment variables for the subprocess.
             line_handler: Optional callback called for each line of output.
+            max_output_chars: Characters of output to return, or None for all;
+                the handler still receives every line.
 
         Returns:
             Tuple of (success, output) where success indicates return code 0.
         """
         effective_timeout = self._get_effective_timeout(timeout)
-        return run_subprocess_streaming(cmd, effective_timeout, cwd, env, line_handler)
+        cmd = self._with_extra_args(cmd)
+        if self.remote is not None:
+            return run_remote(
+                cmd,
+                self.remote,
+                effective_timeout,
+                cwd,
+                env,
+                line_handler=line_handler,
+                max_output_chars=max_output_chars,
+            )
+        cmd = self._daemon_command(cmd)
+        cmd = self._container_command(cmd, cwd, env)
+        env = self._toolchain_env(env)
+        return run_subprocess_streaming(
+            cmd,
+            effective_timeout,
+            cwd,
+            env,
+            line_handler,
+            limits=self._limits(),
+            max_output_chars=max_output_chars,
+        )
+
+    def _limits(self) -> ResourceLimits | None:
+        """Return the resource limits of processes started on this host.
+
+        Returns:
+            ResourceLimits | None: Configured limits, or None when the tool
+            runs in a container, whose runtime client must not be limited.
+        """
+        return self.limits if self.container is None else None
+
+    def _with_extra_args(self, cmd: list[str]) -> list[str]:
+        """Add the tool's extra arguments to a command that runs the tool.
+
+        Args:
+            cmd: Command and arguments as built by the plugin.
+
+        Returns:
+            list[str]: Command to run; unchanged without extra arguments or
+            when it runs another program.
+        """
+        if not self.extra_args:
+            return cmd
+        executable = self._get_executable_command(tool_name=self.definition.name)
+        binaries = {os.path.basename(executable[0])}
+        if self.definition.version_command:
+            binaries.add(os.path.basename(self.definition.version_command[0]))
+        return insert_extra_args(cmd, self.extra_args, executable, binaries)
+
+    def _toolchain_env(self, env: dict[str, str] | None) -> dict[str, str] | None:
+        """Put the project's pinned toolchain first on a command's PATH.
+
+        Args:
+            env: Environment variables for the subprocess, if any.
+
+        Returns:
+            dict[str, str] | None: Environment to run with; unchanged without
+            a pinned toolchain or when the tool runs in a container.
+        """
+        if self.toolchain_bin is None or self.container is not None:
+            return env
+        env = dict(env or {})
+        path = env.get("PATH", os.environ.get("PATH", ""))
+        env["PATH"] = os.pathsep.join(p for p in (self.toolchain_bin, path) if p)
+        return env
+
+    def _daemon_command(self, cmd: list[str]) -> list[str]:
+        """Route a command through the tool's daemon when one is enabled.
+
+        Args:
+            cmd: Command and arguments as built for the host.
+
+        Returns:
+            list[str]: Command to run; unchanged without a daemon.
+        """
+        if self.daemon is None:
+            return cmd
+        routed = build_daemon_command(cmd, self.daemon)
+        if routed is not cmd:
+            ensure_daemon_started(self.daemon)
+        return routed
+
+    def _container_command(
+        self,
+        cmd: list[str],
+        cwd: str | None = None,
+        env: dict[str, str] | None = None,
+    ) -> list[str]:
+        """Wrap a command for the container backend when it is enabled.
+
+        Args:
+            cmd: Command and arguments as built for the host.
+            cwd: Working directory for command execution.
+            env: Environment variables for the subprocess.
+
+        Returns:
+            Command to run; unchanged when the tool runs on the host.
+        """
+        if self.container is None:
+            return cmd
+        return build_container_command(cmd, self.container, cwd, env)
 
     def _get_effective_timeout(self, timeout: int | float | None = None) -> float:
         """Get the effective timeout value.
@@ -396,6 +589,10 @@ class BaseToolPlugin(ABC):
             include_venv=self.include_venv,
             current_options=self.options,
             no_files_message=no_files_message,
+            verify_version=self.container is None and self.remote is None,
+            prune_dirs=self.prune_dirs,
+            gitignore=self.gitignore,
+            symlinks=self.symlinks,
         )
 
         if "early_result" in result:
@@ -505,6 +702,9 @@ class BaseToolPlugin(ABC):
         Returns:
             None if version check passes, or a skip result if it fails.
         """
+        if self.container is not None or self.remote is not None:
+            # The pinned image or the remote host decides the version
+            return None
         return verify_tool_version(self.definition)
 
     # -------------------------------------------------------------------------
@@ -0,0 +1,211 @@
+"""Container execution backend for external tools.
+
+With ``execution.backend: container`` each external tool runs inside a
+pinned container image instead of using host binaries. The workspace is
+mounted at the same path inside the container, so file paths in tool
+commands and output need no translation. Checks mount it read-only; fixes
+mount it read-write.
+"""
+
+from __future__ import annotations
+
+import os
+import shutil
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from lintro import __version__
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+
+# Lintro tools image matching this release; includes every external tool
+DEFAULT_CONTAINER_IMAGE: str = f"ghcr.io/lgtm-hq/py-lintro:{__version__}"
+
+# Supported container runtimes, in order of preference
+CONTAINER_RUNTIMES: tuple[str, ...] = ("docker", "podman")
+
+# Execution backends accepted by execution.backend
+EXECUTION_BACKENDS: tuple[str, ...] = ("host", "container")
+
+# Launchers that only locate a tool on the host; the image has it on PATH
+_HOST_LAUNCHERS: frozenset[str] = frozenset({"bunx", "npx", "uvx"})
+
+# Caches and build output redirected out of a read-only workspace
+_READ_ONLY_CACHE_ENV: dict[str, str] = {
+    "CARGO_TARGET_DIR": "/tmp/cargo-target",
+    "MYPY_CACHE_DIR": "/tmp/.mypy_cache",
+    "PYTHONPYCACHEPREFIX": "/tmp/.pycache",
+    "RUFF_CACHE_DIR": "/tmp/.ruff_cache",
+    "XDG_CACHE_HOME": "/tmp/.cache",
+}
+
+
+@dataclass(frozen=True)
+class ContainerSpec:
+    """How to run a tool inside a container.
+
+    Attributes:
+        image: Container image, ideally pinned to a tag or digest.
+        runtime: Container runtime executable (``docker`` or ``podman``).
+        workspace: Host directory mounted into the container.
+        writable: Whether the workspace is mounted read-write.
+    """
+
+    image: str
+    runtime: str
+    workspace: str
+    writable: bool = False
+
+
+def detect_container_runtime(preferred: str | None = None) -> str | None:
+    """Find an installed container runtime.
+
+    Args:
+        preferred: Runtime to use if installed; otherwise docker, then podman.
+
+    Returns:
+        str | None: Runtime executable name, or None if none is installed.
+    """
+    candidates = (preferred,) if preferred else CONTAINER_RUNTIMES
+    for runtime in candidates:
+        if runtime and shutil.which(runtime):
+            return runtime
+    return None
+
+
+def resolve_container_spec(
+    tool_name: str,
+    lintro_config: LintroConfig,
+    writable: bool,
+    workspace: str | None = None,
+) -> ContainerSpec | None:
+    """Decide whether and how a tool runs in a container.
+
+    Args:
+        tool_name: Name of the tool.
+        lintro_config: Loaded Lintro configuration.
+        writable: Whether the tool modifies files (``lintro format``).
+        workspace: Host directory to mount. Defaults to the current directory.
+
+    Returns:
+        ContainerSpec | None: Container settings, or None to run on the host.
+
+    Raises:
+        RuntimeError: If the container backend is selected but no container
+            runtime is installed.
+    """
+    execution = lintro_config.execution
+    if execution.backend != "container":
+        return None
+    runtime = detect_container_runtime(execution.container_runtime)
+    if runtime is None:
+        wanted = execution.container_runtime or " or ".join(CONTAINER_RUNTIMES)
+        raise RuntimeError(
+            f"execution.backend is 'container' but {wanted} is not installed",
+        )
+    image = (
+        lintro_config.get_tool_config(tool_name).image
+        or execution.container_image
+        or DEFAULT_CONTAINER_IMAGE
+    )
+    return ContainerSpec(
+        image=image,
+        runtime=runtime,
+        workspace=workspace or os.getcwd(),
+        writable=writable,
+    )
+
+
+def portable_tool_command(cmd: list[str]) -> list[str]:
+    """Rewrite a host command so it runs from another machine's PATH.
+
+    Used for container images and remote hosts, where host launchers and
+    absolute executable paths do not apply.
+
+    Args:
+        cmd: Command as built for the host.
+
+    Returns:
+        list[str]: Command with host-specific launchers and paths removed.
+    """
+    executable = os.path.basename(cmd[0])
+    if executable.startswith("python") and cmd[1:2] == ["-m"] and len(cmd) > 2:
+        return cmd[2:]
+    if executable in _HOST_LAUNCHERS and len(cmd) > 1:
+        return cmd[1:]
+    return [executable, *cmd[1:]]
+
+
+def outside_paths(args: list[str], workspace: str) -> list[str]:
+    """Find existing host paths outside the workspace referenced by args.
+
+    Generated config files usually live in the system temp directory, so
+    they are mounted read-only alongside the workspace (or uploaded next to
+    it for remote tools).
+
+    Args:
+        args: Command arguments.
+        workspace: Host directory already mounted.
+
+    Returns:
+        list[str]: Absolute paths to mount, in first-seen order.
+    """
+    root = Path(workspace).resolve()
+    found: list[str] = []
+    for arg in args:
+        value = arg.split("=", 1)[1] if arg.startswith("-") and "=" in arg else arg
+        if not os.path.isabs(value) or not os.path.exists(value):
+            continue
+        resolved = Path(value).resolve()
+        if resolved == root or root in resolved.parents:
+            continue
+        if value not in found:
+            found.append(value)
+    return found
+
+
+def build_container_command(
+    cmd: list[str],
+    spec: ContainerSpec,
+    cwd: str | None = None,
+    env: dict[str, str] | None = None,
+) -> list[str]:
+    """Wrap a tool command in a container runtime invocation.
+
+    Args:
+        cmd: Command as built for the host.
+        spec: Container settings for the tool.
+        cwd: Working directory for the tool. Defaults to the workspace.
+        env: Extra environment variables for the tool.
+
+    Returns:
+        list[str]: Command that runs the tool inside the container.
+    """
+    tool_cmd = portable_tool_command(cmd)
+    mode = "rw" if spec.writable else "ro"
+    wrapped = [
+        spec.runtime,
+        "run",
+        "--rm",
+        "--entrypoint",
+        tool_cmd[0],
+        "-v",
+        f"{spec.workspace}:{spec.workspace}:{mode}",
+        "-w",
+        os.path.abspath(cwd) if cwd else spec.workspace,
+    ]
+    for path in outside_paths(tool_cmd[1:], spec.workspace):
+        wrapped.extend(["-v", f"{path}:{path}:ro"])
+    # Files written by fixes should belong to the host user, not root
+    if spec.writable and hasattr(os, "getuid"):
+        wrapped.extend(["--user", f"{os.getuid()}:{os.getgid()}"])
+    container_env = {} if spec.writable else dict(_READ_ONLY_CACHE_ENV)
+    # Only forward overrides; the host environment (PATH, HOME) stays outside
+    container_env.update(
+        {k: v for k, v in (env or {}).items() if os.environ.get(k) != v},
+    )
+    for key, value in container_env.items():
+        wrapped.extend(["-e", f"{key}={value}"])
+    return [*wrapped, spec.image, *tool_cmd[1:]]
@@ -0,0 +1,260 @@
+"""Daemon execution strategy for tools with a resident server.
+
+Most of a short eslint run is spent starting Node and loading plugins. With
+``tools.<name>.daemon: true``, a tool that has a daemon client accepting its
+own command line runs through that client instead: the first invocation of
+a run starts the server, later invocations in the run reuse it, and it keeps
+running between runs (editor saves, ``lintro fix-file``, repeated checks)
+until it idles out or ``lintro daemon stop`` stops it.
+
+Only clients that accept the tool's arguments unchanged are used, so the
+output and exit codes stay those of the tool. prettier's daemon
+(``prettierd``) formats a single file read from stdin and ``ruff server``
+only speaks the language server protocol, so neither stands in for their
+command lines; ruff starts in milliseconds anyway.
+"""
+
+from __future__ import annotations
+
+import os
+import shutil
+import subprocess  # nosec B404 - used safely with shell disabled
+import threading
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from loguru import logger
+
+from lintro.plugins.container_backend import portable_tool_command
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+
+# Seconds allowed for a daemon client to start, stop or report status
+DAEMON_CONTROL_TIMEOUT: float = 30.0
+
+
+@dataclass(frozen=True)
+class DaemonClient:
+    """A daemon client that accepts a tool's command line.
+
+    Attributes:
+        executable: Executable the tool's commands start with.
+        client: Executable of the daemon client.
+        start: Arguments that start the server.
+        stop: Arguments that stop the server.
+        status: Arguments that report whether the server runs.
+    """
+
+    executable: str
+    client: str
+    start: tuple[str, ...] = ("start",)
+    stop: tuple[str, ...] = ("stop",)
+    status: tuple[str, ...] = ("status",)
+
+
+# Tools whose daemon client is a drop-in replacement for their CLI
+DAEMON_CLIENTS: dict[str, DaemonClient] = {
+    "eslint": DaemonClient(executable="eslint", client="eslint_d"),
+}
+
+
+@dataclass(frozen=True)
+class DaemonSpec:
+    """How to run a tool through its daemon.
+
+    Attributes:
+        tool: Name of the tool.
+        client: The tool's daemon client.
+        path: Resolved path of the client executable.
+    """
+
+    tool: str
+    client: DaemonClient
+    path: str
+
+
+# Servers started by this process; each is started once per run
+_started: set[str] = set()
+_start_lock = threading.Lock()
+
+
+def find_daemon_client(client: DaemonClient, cwd: str | None = None) -> str | None:
+    """Locate a daemon client on PATH or in the project's node_modules.
+
+    Args:
+        client: Daemon client to look for.
+        cwd: Project directory. Defaults to the current directory.
+
+    Returns:
+        str | None: Path of the client, or None if it is not installed.
+    """
+    local_bin = Path(cwd or os.getcwd()) / "node_modules" / ".bin"
+    search = os.pathsep.join([str(local_bin), os.environ.get("PATH", "")])
+    return shutil.which(client.client, path=search)
+
+
+def resolve_daemon_spec(
+    tool_name: str,
+    lintro_config: LintroConfig,
+) -> DaemonSpec | None:
+    """Decide whether a tool runs through its daemon.
+
+    A daemon is only an optimization: when the tool has no daemon client or
+    the client is not installed, a warning is logged and the tool runs as
+    usual.
+
+    Args:
+        tool_name: Name of the tool.
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        DaemonSpec | None: Daemon settings, or None to run the tool directly.
+    """
+    if not lintro_config.get_tool_config(tool_name).daemon:
+        return None
+    client = DAEMON_CLIENTS.get(tool_name)
+    if client is None:
+        supported = ", ".join(sorted(DAEMON_CLIENTS))
+        logger.warning(
+            f"tools.{tool_name}.daemon is set but {tool_name} has no daemon "
+            f"client; daemons are supported for: {supported}",
+        )
+        return None
+    path = find_daemon_client(client)
+    if path is None:
+        logger.warning(
+            f"tools.{tool_name}.daemon is set but {client.client} is not "
+            f"installed; running {tool_name} without a daemon",
+        )
+        return None
+    return DaemonSpec(tool=tool_name, client=client, path=path)
+
+
+def build_daemon_command(cmd: list[str], spec: DaemonSpec) -> list[str]:
+    """Route a tool command through the daemon client.
+
+    Commands that do not run the tool itself, such as helper programs a
+    plugin calls, are returned unchanged.
+
+    Args:
+        cmd: Command as built for the host, possibly behind ``npx`` or
+            ``bunx``.
+        spec: Daemon settings for the tool.
+
+    Returns:
+        list[str]: Command that runs through the daemon client.
+    """
+    tool_cmd = portable_tool_command(cmd)
+    if tool_cmd[0] != spec.client.executable:
+        return cmd
+    return [spec.path, *tool_cmd[1:]]
+
+
+def _control(path: str, args: tuple[str, ...]) -> tuple[bool, str]:
+    """Run a daemon control command.
+
+    Args:
+        path: Path of the daemon client.
+        args: Control arguments, such as ``("stop",)``.
+
+    Returns:
+        tuple[bool, str]: Whether it exited with 0, and its output.
+    """
+    try:
+        result = subprocess.run(  # nosec B603 - args list, shell=False
+            [path, *args],
+            capture_output=True,
+            text=True,
+            timeout=DAEMON_CONTROL_TIMEOUT,
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        return False, str(e)
+    return result.returncode == 0, (result.stdout + result.stderr).strip()
+
+
+def ensure_daemon_started(spec: DaemonSpec) -> None:
+    """Start the tool's server once per run.
+
+    Tools running in parallel would otherwise race to start it on their
+    first invocation. A server left running by an earlier run is reused.
+
+    Args:
+        spec: Daemon settings for the tool.
+    """
+    with _start_lock:
+        if spec.path in _started:
+            return
+        ok, output = _control(spec.path, spec.client.start)
+        logger.debug(
+            f"Started {spec.client.client} for {spec.tool}"
+            if ok
+            else f"{spec.client.client} start exited with an error: {output}",
+        )
+        _started.add(spec.path)
+
+
+@dataclass(frozen=True)
+class DaemonStatus:
+    """State of a tool's daemon.
+
+    Attributes:
+        tool: Name of the tool.
+        client: Daemon client executable.
+        installed: Whether the client is installed.
+        running: Whether the client reports a running server.
+        detail: Output of the client's status command.
+    """
+
+    tool: str
+    client: str
+    installed: bool
+    running: bool = False
+    detail: str = ""
+
+
+def daemon_statuses(tools: list[str] | None = None) -> list[DaemonStatus]:
+    """Report the daemons of the supported tools.
+
+    Args:
+        tools: Tools to report on. Defaults to every supported tool.
+
+    Returns:
+        list[DaemonStatus]: One status per tool, in name order.
+    """
+    statuses: list[DaemonStatus] = []
+    for tool in sorted(tools or DAEMON_CLIENTS):
+        client = DAEMON_CLIENTS[tool]
+        path = find_daemon_client(client)
+        if path is None:
+            statuses.append(DaemonStatus(tool, client.client, installed=False))
+            continue
+        ok, output = _control(path, client.status)
+        running = ok and "not running" not in output.lower()
+        statuses.append(
+            DaemonStatus(tool, client.client, True, running=running, detail=output),
+        )
+    return statuses
+
+
+def stop_daemons(tools: list[str] | None = None) -> list[str]:
+    """Stop the servers of the supported tools.
+
+    Args:
+        tools: Tools whose servers to stop. Defaults to every supported tool.
+
+    Returns:
+        list[str]: Tools whose installed client was told to stop.
+    """
+    stopped: list[str] = []
+    for tool in sorted(tools or DAEMON_CLIENTS):
+        client = DAEMON_CLIENTS[tool]
+        path = find_daemon_client(client)
+        if path is None:
+            continue
+        _control(path, client.stop)
+        with _start_lock:
+            _started.discard(path)
+        stopped.append(tool)
+    return stopped
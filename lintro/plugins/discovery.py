@@ -71,6 +71,34 @@ def discover_builtin_tools() -> int:
     return loaded_count
 
 
+def discover_builtin_tool(name: str) -> bool:
+    """Load the builtin definition of a single tool.
+
+    Importing every definition dominates the startup of short runs, so
+    callers that need only named tools (e.g. ``lintro fix-file --tools
+    rustfmt``) load just those.
+
+    Args:
+        name: Tool name (case-insensitive); hyphens become underscores in
+            the module name.
+
+    Returns:
+        bool: True if the tool is registered afterwards, False if it has no
+        builtin definition.
+    """
+    stem = name.lower().replace("-", "_")
+    if stem.startswith("_") or not (BUILTIN_DEFINITIONS_PATH / f"{stem}.py").is_file():
+        return False
+    module_name = f"lintro.tools.definitions.{stem}"
+    try:
+        # Safe: module_name checked against internal definition files
+        importlib.import_module(module_name)  # nosemgrep: non-literal-import
+    except ImportError as e:
+        logger.warning(f"Failed to import {module_name}: {e}")
+        return False
+    return ToolRegistry.is_registered(name)
+
+
 def discover_external_plugins() -> int:
     """Load external plugins via entry points.
 
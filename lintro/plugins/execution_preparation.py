@@ -6,14 +6,24 @@ This module provides execution preparation, version checking, and config injecti
 from __future__ import annotations
 
 import os
-from typing import Any
+from collections.abc import Sequence
+from typing import TYPE_CHECKING, Any
 
 from loguru import logger
 
 from lintro.config.lintro_config import LintroConfig
+from lintro.exceptions.errors import (
+    TOOL_NOT_INSTALLED,
+    TOOL_VERSION_MISMATCH,
+    TOOL_VERSION_TOO_OLD,
+)
 from lintro.models.core.tool_result import ToolResult
 from lintro.plugins.file_discovery import discover_files, get_cwd, validate_paths
 from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.path_filtering import DEFAULT_PRUNE_DIRS, DEFAULT_SYMLINK_POLICY
+
+if TYPE_CHECKING:
+    from lintro.tools.core.version_parsing import ToolVersionInfo
 
 # Constants for default values
 DEFAULT_TIMEOUT: int = 30
@@ -74,6 +84,57 @@ def get_executable_command(tool_name: str) -> list[str]:
     return result
 
 
+def pin_mismatch_result(
+    tool_name: str,
+    version_info: ToolVersionInfo,
+) -> ToolResult | None:
+    """Build a failing result when a tool does not match its version pin.
+
+    Unlike a too-old tool, which is skipped, a pin mismatch fails the run so
+    local and CI results cannot silently drift apart.
+
+    Args:
+        tool_name: Name of the tool.
+        version_info: Result of the version check.
+
+    Returns:
+        ToolResult | None: Failing result, or None if the pin is satisfied.
+    """
+    if not version_info.pin_mismatch:
+        return None
+
+    return ToolResult(
+        name=tool_name,
+        success=False,
+        output=(
+            f"{tool_name}: {version_info.error_message}. Install a matching "
+            f"version or update tools.{tool_name}.version in your Lintro config. "
+            "Run 'lintro doctor' for details."
+        ),
+        issues_count=0,
+        error_code=TOOL_VERSION_MISMATCH.code,
+    )
+
+
+def detect_tool_version(definition: ToolDefinition) -> str | None:
+    """Detect the installed version of a tool.
+
+    Args:
+        definition: Tool definition with name.
+
+    Returns:
+        str | None: Installed version, or None for native tools and tools
+        whose version cannot be determined.
+    """
+    if definition.native:
+        return None
+
+    from lintro.tools.core.version_requirements import check_tool_version
+
+    command = get_executable_command(definition.name)
+    return check_tool_version(definition.name, command).current_version
+
+
 def verify_tool_version(definition: ToolDefinition) -> ToolResult | None:
     """Verify that the tool meets minimum version requirements.
 
@@ -91,6 +152,10 @@ def verify_tool_version(definition: ToolDefinition) -> ToolResult | None:
     if version_info.version_check_passed:
         return None
 
+    pin_result = pin_mismatch_result(definition.name, version_info)
+    if pin_result is not None:
+        return pin_result
+
     skip_message = (
         f"Skipping {definition.name}: {version_info.error_message}. "
         f"Minimum required: {version_info.min_version}. "
@@ -104,6 +169,10 @@ def verify_tool_version(definition: ToolDefinition) -> ToolResult | None:
         issues_count=0,
         skipped=True,
         skip_reason=version_info.error_message,
+        missing=version_info.not_found,
+        error_code=(
+            TOOL_NOT_INSTALLED if version_info.not_found else TOOL_VERSION_TOO_OLD
+        ).code,
     )
 
 
@@ -115,6 +184,10 @@ def prepare_execution(
     include_venv: bool,
     current_options: dict[str, object],
     no_files_message: str = "No files to check.",
+    verify_version: bool = True,
+    prune_dirs: Sequence[str] = DEFAULT_PRUNE_DIRS,
+    gitignore: bool = True,
+    symlinks: str = DEFAULT_SYMLINK_POLICY,
 ) -> dict[str, Any]:
     """Prepare execution context with common boilerplate steps.
 
@@ -134,6 +207,11 @@ def prepare_execution(
         include_venv: Whether to include venv files.
         current_options: Current plugin options.
         no_files_message: Message when no files are found.
+        verify_version: Whether to check the host tool version. Disabled
+            when the tool runs in a container image.
+        prune_dirs: Directory names (fnmatch patterns) not to descend into.
+        gitignore: Whether files git ignores are skipped.
+        symlinks: How symbolic links are treated (see SYMLINK_POLICIES).
 
     Returns:
         Dictionary with files, rel_files, cwd, timeout, and optional early_result.
@@ -160,6 +238,9 @@ def prepare_execution(
         definition=definition,
         exclude_patterns=exclude_patterns,
         include_venv=include_venv,
+        prune_dirs=prune_dirs,
+        gitignore=gitignore,
+        symlinks=symlinks,
     )
 
     if not files:
@@ -179,10 +260,12 @@ def prepare_execution(
             ),
         }
 
-    # Check version requirements (only when files exist to check)
-    version_result = verify_tool_version(definition)
-    if version_result is not None:
-        return {"early_result": version_result}
+    # Check version requirements (only when files exist to check). Native
+    # tools run inside Lintro and have no external binary to verify.
+    if verify_version and not definition.native:
+        version_result = verify_tool_version(definition)
+        if version_result is not None:
+            return {"early_result": version_result}
 
     logger.debug(f"Files to process: {files}")
 
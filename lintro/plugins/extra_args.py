@@ -0,0 +1,129 @@
+"""Extra command-line arguments passed through to a tool.
+
+``tools.<name>.extra_args`` and ``--extra-args TOOL:ARGS`` add flags lintro
+has no option for, such as ``--all-targets`` for clippy. They go right after
+the tool's executable and subcommands (``cargo clippy``, ``ruff check``), so
+they precede lintro's own flags and the file paths. Commands a plugin runs
+through other programs, such as git, are left alone.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+import shlex
+from collections.abc import Sequence
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+
+# Words after the executable that name a subcommand or a rustup toolchain
+# (``check``, ``+nightly``) rather than a flag or a file
+_SUBCOMMAND_PATTERN = re.compile(r"^(\+[\w.-]+|[a-z][a-z0-9_-]*)$")
+
+
+def split_extra_args(value: str | Sequence[str]) -> list[str]:
+    """Turn configured extra arguments into a list.
+
+    Args:
+        value: Arguments as a list, or as one string split like a shell
+            command line.
+
+    Returns:
+        list[str]: The arguments.
+
+    Raises:
+        ValueError: If the value is not a string or a list of strings, or
+            the string has unbalanced quotes.
+    """
+    if isinstance(value, str):
+        return shlex.split(value)
+    if not all(isinstance(arg, str) for arg in value):
+        raise ValueError("extra_args must be a string or a list of strings")
+    return list(value)
+
+
+def parse_extra_args_option(values: Sequence[str]) -> dict[str, list[str]]:
+    """Parse ``--extra-args`` values given on the command line.
+
+    Args:
+        values: ``TOOL:ARGS`` items, e.g. ``clippy:--all-targets --all-features``.
+
+    Returns:
+        dict[str, list[str]]: Arguments per lower-case tool name; items for
+        the same tool are combined in order.
+
+    Raises:
+        ValueError: If an item has no tool name or no arguments.
+    """
+    parsed: dict[str, list[str]] = {}
+    for value in values:
+        tool, sep, args = value.partition(":")
+        tool = tool.strip().lower()
+        if not sep or not tool or not args.strip():
+            raise ValueError(f"expected TOOL:ARGS, got {value!r}")
+        parsed.setdefault(tool, []).extend(split_extra_args(args))
+    return parsed
+
+
+def resolve_extra_args(
+    tool_name: str,
+    lintro_config: LintroConfig,
+    cli_args: object = None,
+) -> list[str]:
+    """Return the extra arguments of a tool for a run.
+
+    Args:
+        tool_name: Name of the tool.
+        lintro_config: Loaded Lintro configuration.
+        cli_args: Arguments given on the command line (``--extra-args`` or
+            ``--tool-options TOOL:extra_args=A|B``), appended after the
+            configured ones.
+
+    Returns:
+        list[str]: Configured arguments followed by command-line ones.
+
+    Raises:
+        ValueError: If the command-line arguments are not a string or a
+            list of strings.
+    """
+    configured = list(lintro_config.get_tool_config(tool_name).extra_args)
+    if cli_args is None:
+        return configured
+    if not isinstance(cli_args, (str, list)):
+        raise ValueError(
+            f"{tool_name}: extra_args must be a string or a list of strings",
+        )
+    return configured + split_extra_args(cli_args)
+
+
+def insert_extra_args(
+    cmd: list[str],
+    extra_args: Sequence[str],
+    executable: Sequence[str],
+    binaries: set[str],
+) -> list[str]:
+    """Add a tool's extra arguments to one of its commands.
+
+    Args:
+        cmd: Command and arguments as built by the plugin.
+        extra_args: Arguments to add.
+        executable: Command prefix that launches the tool, such as
+            ``["python", "-m", "ruff"]``.
+        binaries: Executable names that run the tool, such as ``cargo``.
+
+    Returns:
+        list[str]: Command with the arguments after the executable and its
+        subcommands; unchanged if it does not run the tool.
+    """
+    if not extra_args or not cmd or os.path.basename(cmd[0]) not in binaries:
+        return cmd
+    start = len(executable) if cmd[: len(executable)] == list(executable) else 1
+    while (
+        start < len(cmd)
+        and _SUBCOMMAND_PATTERN.match(cmd[start])
+        and not os.path.exists(cmd[start])
+    ):
+        start += 1
+    return [*cmd[:start], *extra_args, *cmd[start:]]
@@ -7,12 +7,16 @@ from __future__ import annotations
 
 import os
 import sys
+from collections.abc import Sequence
 
 from loguru import logger
-from rich.progress import Progress, SpinnerColumn, TextColumn
 
 from lintro.plugins.protocol import ToolDefinition
-from lintro.utils.path_filtering import walk_files_with_excludes
+from lintro.utils.path_filtering import (
+    DEFAULT_PRUNE_DIRS,
+    DEFAULT_SYMLINK_POLICY,
+    walk_files_with_excludes,
+)
 from lintro.utils.path_utils import find_lintro_ignore
 
 # Default exclude patterns for file discovery
@@ -20,6 +24,8 @@ DEFAULT_EXCLUDE_PATTERNS: list[str] = [
     ".git",
     ".hg",
     ".svn",
+    # Lintro's own reports and audit log
+    ".lintro",
     "__pycache__",
     "*.pyc",
     "*.pyo",
@@ -74,6 +80,9 @@ def discover_files(
     exclude_patterns: list[str],
     include_venv: bool = False,
     show_progress: bool = True,
+    prune_dirs: Sequence[str] = DEFAULT_PRUNE_DIRS,
+    gitignore: bool = True,
+    symlinks: str = DEFAULT_SYMLINK_POLICY,
 ) -> list[str]:
     """Discover files matching the tool's patterns.
 
@@ -83,10 +92,16 @@ def discover_files(
         exclude_patterns: Patterns to exclude.
         include_venv: Whether to include virtual environment files.
         show_progress: Whether to show a progress spinner during discovery.
+        prune_dirs: Directory names (fnmatch patterns) not to descend into.
+        gitignore: Whether files git ignores are skipped.
+        symlinks: How symbolic links are treated (see SYMLINK_POLICIES).
 
     Returns:
         List of matching file paths.
     """
+    # Imported here: rich is slow to import and single-file runs skip it
+    from rich.progress import Progress, SpinnerColumn, TextColumn
+
     # Disable progress when not in a TTY or when show_progress is False
     disable_progress = not show_progress or not sys.stdout.isatty()
 
@@ -102,6 +117,10 @@ def discover_files(
             file_patterns=definition.file_patterns,
             exclude_patterns=exclude_patterns,
             include_venv=include_venv,
+            shebang_interpreters=definition.shebang_interpreters,
+            prune_dirs=prune_dirs,
+            gitignore=gitignore,
+            symlinks=symlinks,
         )
         progress.update(task, description=f"Found {len(files)} files")
 
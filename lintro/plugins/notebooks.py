@@ -0,0 +1,281 @@
+"""Jupyter notebook support for Python tools without it.
+
+Ruff reads ``.ipynb`` files itself: it reports issues by cell and writes
+its fixes back into the notebook JSON. Tools that only read ``.py`` files,
+such as mypy and Bandit, check notebooks through this module instead:
+
+1. The code cells of each notebook are joined into one Python script in a
+   temporary directory. IPython magics (``%time``, ``!pip``, ``x?``) become
+   ``pass`` and cell magics (``%%bash``) blank out their cell, so line
+   numbers stay put.
+2. The tool checks the scripts like any other files.
+3. Its issues are mapped back to the notebook, the cell (numbered like
+   Jupyter numbers them, counting Markdown cells) and the line in the cell.
+
+Notebooks whose kernel is not Python are skipped.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import json
+import os
+import re
+import tempfile
+from collections.abc import Callable, Iterator
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from loguru import logger
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.plugins.file_discovery import discover_files
+
+if TYPE_CHECKING:
+    from lintro.plugins.base import BaseToolPlugin
+
+NOTEBOOK_PATTERN: str = "*.ipynb"
+# Lines IPython runs as magics, shell commands or help (``?obj``, ``obj.attr??``)
+# rather than Python
+_MAGIC_RE: re.Pattern[str] = re.compile(r"\s*(?:[%!?]|[\w.]+\?{1,2}\s*$)")
+
+
+@dataclass
+class NotebookScript:
+    """The code cells of a notebook as one Python script.
+
+    Attributes:
+        notebook: Path of the notebook, as reported in issues.
+        source: Script text.
+        cells: Cell number and line in the cell of each script line.
+    """
+
+    notebook: str
+    source: str
+    cells: list[tuple[int, int]] = field(default_factory=list)
+
+    def locate(self, line: int) -> tuple[int, int] | None:
+        """Find the cell a script line came from.
+
+        Args:
+            line: 1-based line in the script.
+
+        Returns:
+            tuple[int, int] | None: The 1-based cell number and line in the
+            cell, or None for lines outside the script.
+        """
+        if 1 <= line <= len(self.cells):
+            return self.cells[line - 1]
+        return None
+
+
+def _cell_lines(cell: dict[str, Any]) -> list[str]:
+    """Read the source lines of a notebook cell.
+
+    Args:
+        cell: Cell from the notebook JSON.
+
+    Returns:
+        list[str]: Lines without line endings.
+    """
+    source = cell.get("source", "")
+    text = "".join(source) if isinstance(source, list) else str(source)
+    return text.splitlines()
+
+
+def _is_python(data: dict[str, Any]) -> bool:
+    """Tell whether a notebook runs Python.
+
+    Args:
+        data: Notebook JSON.
+
+    Returns:
+        bool: True unless the notebook's metadata names another language.
+    """
+    metadata = data.get("metadata") or {}
+    language = (metadata.get("language_info") or {}).get("name") or (
+        metadata.get("kernelspec") or {}
+    ).get("language")
+    return not language or str(language).lower() == "python"
+
+
+def read_notebook(path: str, display_path: str) -> NotebookScript | None:
+    """Turn a notebook's code cells into a Python script.
+
+    Args:
+        path: Notebook file.
+        display_path: Path reported in issues.
+
+    Returns:
+        NotebookScript | None: The script, or None for notebooks that are
+        not Python.
+
+    Raises:
+        ValueError: If the file is not a valid notebook.
+    """
+    try:
+        data = json.loads(Path(path).read_text(encoding="utf-8"))
+    except (OSError, UnicodeDecodeError, json.JSONDecodeError) as e:
+        raise ValueError(f"Cannot read notebook {display_path}: {e}") from e
+    if not isinstance(data, dict) or not isinstance(data.get("cells"), list):
+        raise ValueError(f"{display_path} is not a Jupyter notebook")
+    if not _is_python(data):
+        return None
+
+    lines: list[str] = []
+    cells: list[tuple[int, int]] = []
+    for number, cell in enumerate(data["cells"], start=1):
+        if not isinstance(cell, dict) or cell.get("cell_type") != "code":
+            continue
+        cell_lines = _cell_lines(cell)
+        cell_magic = bool(cell_lines) and cell_lines[0].lstrip().startswith("%%")
+        for line_number, line in enumerate(cell_lines, start=1):
+            if cell_magic:
+                line = ""
+            elif _MAGIC_RE.match(line):
+                line = line[: len(line) - len(line.lstrip())] + "pass"
+            lines.append(line)
+            cells.append((number, line_number))
+    source = "\n".join(lines) + "\n" if lines else ""
+    return NotebookScript(notebook=display_path, source=source, cells=cells)
+
+
+def find_notebooks(tool: BaseToolPlugin, paths: list[str]) -> list[str]:
+    """Find the notebooks a tool should check.
+
+    Args:
+        tool: Tool about to check the paths.
+        paths: Paths given to the tool.
+
+    Returns:
+        list[str]: Notebook files, honoring the tool's excludes.
+    """
+    return discover_files(
+        paths=paths or ["."],
+        definition=dataclasses.replace(
+            tool.definition,
+            file_patterns=[NOTEBOOK_PATTERN],
+        ),
+        exclude_patterns=tool.exclude_patterns,
+        include_venv=tool.include_venv,
+        show_progress=False,
+        prune_dirs=tool.prune_dirs,
+        gitignore=tool.gitignore,
+        symlinks=tool.symlinks,
+    )
+
+
+@contextmanager
+def notebook_scripts(
+    notebooks: list[str],
+) -> Iterator[tuple[str, dict[str, NotebookScript]]]:
+    """Write notebooks as scripts into a temporary directory.
+
+    Args:
+        notebooks: Notebook files.
+
+    Yields:
+        tuple[str, dict[str, NotebookScript]]: The directory, and the script
+        of each file written to it, keyed by its resolved path.
+    """
+    with tempfile.TemporaryDirectory(prefix="lintro-notebooks-") as directory:
+        scripts: dict[str, NotebookScript] = {}
+        for index, notebook in enumerate(notebooks):
+            display_path = os.path.relpath(notebook)
+            try:
+                script = read_notebook(notebook, display_path)
+            except ValueError as e:
+                logger.warning(str(e))
+                continue
+            if script is None or not script.source:
+                continue
+            # Unique, importable module names; notebook names rarely are
+            stem = re.sub(r"\W", "_", Path(notebook).stem)
+            target = Path(directory) / f"nb{index}_{stem}.py"
+            target.write_text(script.source, encoding="utf-8")
+            scripts[str(target.resolve())] = script
+        yield directory, scripts
+
+
+def map_notebook_issues(
+    issues: list[BaseIssue],
+    scripts: dict[str, NotebookScript],
+) -> list[BaseIssue]:
+    """Point issues in generated scripts at their notebook cells.
+
+    Issues outside the scripts, such as errors mypy found in imported
+    modules, are dropped; the regular run reports those.
+
+    Args:
+        issues: Issues the tool reported for the scripts.
+        scripts: Scripts keyed by their resolved path.
+
+    Returns:
+        list[BaseIssue]: Issues with the notebook as file and ``cell`` set.
+    """
+    mapped: list[BaseIssue] = []
+    for issue in issues:
+        script = scripts.get(str(Path(issue.file).resolve()))
+        location = script.locate(issue.line) if script else None
+        if script is None or location is None:
+            continue
+        issue.file = script.notebook
+        issue.cell, issue.line = location
+        mapped.append(issue)
+    return mapped
+
+
+def check_notebooks(
+    tool: BaseToolPlugin,
+    paths: list[str],
+    options: dict[str, object],
+    result: ToolResult,
+    check_files: Callable[[list[str], dict[str, object]], ToolResult],
+) -> ToolResult:
+    """Check the notebooks under some paths and add their issues to a result.
+
+    Args:
+        tool: Tool that checked the paths.
+        paths: Paths given to the tool.
+        options: Runtime options for the tool.
+        result: Result of checking the Python files.
+        check_files: The tool's check of Python files, given the directory
+            of generated scripts.
+
+    Returns:
+        ToolResult: The result with the notebooks' issues added.
+    """
+    if result.skipped:
+        return result
+    notebooks = find_notebooks(tool, paths)
+    if not notebooks:
+        return result
+    with notebook_scripts(notebooks) as (directory, scripts):
+        if not scripts:
+            return result
+        logger.debug(
+            f"[{tool.definition.name}] Checking {len(scripts)} notebook(s)",
+        )
+        # The notebooks were filtered already; excludes such as "tmp" and
+        # .gitignore rules must not drop the temporary scripts
+        exclude_patterns, gitignore = tool.exclude_patterns, tool.gitignore
+        tool.exclude_patterns, tool.gitignore = [], False
+        try:
+            notebook_result = check_files([directory], options)
+        finally:
+            tool.exclude_patterns, tool.gitignore = exclude_patterns, gitignore
+        mapped = map_notebook_issues(list(notebook_result.issues or []), scripts)
+
+    failed_run = not notebook_result.success and not notebook_result.issues
+    issues = [*(result.issues or []), *mapped]
+    outputs = [result.output, notebook_result.output if failed_run else None]
+    return dataclasses.replace(
+        result,
+        success=result.success and not mapped and not failed_run,
+        output="\n".join(o for o in outputs if o) or None,
+        issues=issues,
+        issues_count=len(issues),
+    )
@@ -2,6 +2,7 @@
 
 This module defines the core abstractions for Lintro's plugin system:
 - ToolDefinition: Metadata describing what a tool IS
+- ToolCapabilities: What a tool can do, as the runner sees it
 - LintroPlugin: Protocol contract that all tools must satisfy
 
 Example:
@@ -16,7 +17,7 @@ Example:
 
 from __future__ import annotations
 
-from dataclasses import dataclass, field
+from dataclasses import asdict, dataclass, field
 from typing import TYPE_CHECKING, Protocol, runtime_checkable
 
 from lintro.enums.tool_type import ToolType
@@ -25,6 +26,28 @@ if TYPE_CHECKING:
     from lintro.models.core.tool_result import ToolResult
 
 
+@dataclass(frozen=True)
+class ToolCapabilities:
+    """What a tool supports, as the runner and ``list-tools`` see it.
+
+    Attributes:
+        supports_fix: Whether Lintro can run the tool to fix files.
+        version_detected: Installed version, or None if it was not checked
+            or could not be determined.
+    """
+
+    supports_fix: bool = False
+    version_detected: str | None = None
+
+    def to_dict(self) -> dict[str, object]:
+        """Return the capabilities as JSON-ready data.
+
+        Returns:
+            dict[str, object]: Capability flags and the detected version.
+        """
+        return asdict(self)
+
+
 @dataclass(frozen=True)
 class ToolDefinition:
     """Metadata describing a Lintro tool.
@@ -38,6 +61,8 @@ class ToolDefinition:
         can_fix: Whether the tool can auto-fix issues.
         tool_type: Bitmask of ToolType flags describing capabilities.
         file_patterns: Glob patterns for files this tool operates on.
+        shebang_interpreters: Interpreters whose extensionless scripts the
+            tool also operates on, detected from the shebang line.
         priority: Execution priority (lower = runs first). Default is 50.
         conflicts_with: Names of tools that conflict with this one.
         native_configs: Config files the tool respects natively
@@ -47,6 +72,17 @@ class ToolDefinition:
         min_version: Minimum required version string.
         default_options: Default tool-specific options.
         default_timeout: Default execution timeout in seconds.
+        native: Whether the tool is implemented in Lintro itself rather than
+            wrapping an external binary. Native tools skip version checks.
+        enabled_by_default: Whether the tool runs without being enabled in
+            config. Opt-in tools only run when listed under ``tools`` or in
+            ``execution.enabled_tools``, or when named with ``--tools``.
+        default_fail_on: Failure threshold used when ``tools.<name>.fail_on``
+            is not set, so tools with informational findings can report them
+            without failing the run. None follows the run's threshold.
+        per_file: Whether the tool's findings in a file depend on that file
+            alone. Whole-project tools (type checkers, cargo tools, test
+            runners) are not split across ``--shard`` jobs.
     """
 
     # Identity
@@ -59,6 +95,7 @@ class ToolDefinition:
 
     # File targeting
     file_patterns: list[str] = field(default_factory=list)
+    shebang_interpreters: list[str] = field(default_factory=list)
 
     # Execution
     priority: int = 50
@@ -75,6 +112,18 @@ class ToolDefinition:
     default_options: dict[str, object] = field(default_factory=dict)
     default_timeout: int = 30
 
+    # Implemented in Lintro itself (no external binary)
+    native: bool = False
+
+    # Opt-in tools are skipped unless enabled in config or requested
+    enabled_by_default: bool = True
+
+    # Per-tool failure threshold unless the config sets one
+    default_fail_on: str | None = None
+
+    # Whole-project tools run whole in one shard
+    per_file: bool = True
+
     def __post_init__(self) -> None:
         """Validate tool definition.
 
@@ -86,6 +135,20 @@ class ToolDefinition:
         if self.priority < 0:
             raise ValueError(f"Tool priority must be non-negative, got {self.priority}")
 
+    def capabilities(self, version_detected: str | None = None) -> ToolCapabilities:
+        """Describe what the tool supports.
+
+        Args:
+            version_detected: Installed version, if it was checked.
+
+        Returns:
+            ToolCapabilities: Capability flags of this definition.
+        """
+        return ToolCapabilities(
+            supports_fix=self.can_fix,
+            version_detected=version_detected,
+        )
+
 
 @runtime_checkable
 class LintroPlugin(Protocol):
@@ -212,7 +212,9 @@ class ToolRegistry:
         """
         all_tools = cls.get_all()
         return {
-            name: tool for name, tool in all_tools.items() if tool.definition.can_fix
+            name: tool
+            for name, tool in all_tools.items()
+            if tool.capabilities().supports_fix
         }
 
 
@@ -0,0 +1,508 @@
+"""Remote execution backend for tools that only run on another host.
+
+Tools listed in ``execution.remote.tools`` run on ``execution.remote.host``
+over SSH, which lets one coordinator job validate code with tools that only
+exist for another OS or architecture. Before a tool runs, the workspace is
+mirrored to a remote directory (with rsync, or as a tar stream over SSH).
+Paths in the command are translated to the remote directory, and paths in
+the tool's output are translated back, so issues point at local files.
+Fixes are copied back to the workspace after the tool finishes.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import io
+import os
+import posixpath
+import re
+import shlex
+import shutil
+import socket
+import subprocess  # nosec B404 - subprocess used safely with shell=False
+import tarfile
+import threading
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING
+
+from lintro.plugins.container_backend import outside_paths, portable_tool_command
+from lintro.plugins.subprocess_executor import (
+    run_subprocess,
+    run_subprocess_streaming,
+)
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+
+# Directories never copied to or from the remote host
+REMOTE_SYNC_EXCLUDES: tuple[str, ...] = (
+    ".git",
+    ".lintro",
+    "node_modules",
+    ".venv",
+    "venv",
+    "target",
+    "__pycache__",
+)
+
+# Remote directory under which the sync directories are created
+DEFAULT_REMOTE_WORKDIR: str = "/tmp/lintro"  # nosec B108
+
+# Remote subdirectory holding generated config files from outside the workspace
+EXTERNAL_DIR: str = ".lintro-external"
+
+# Workspaces already mirrored for read-only runs: (host, workdir, workspace)
+_synced: set[tuple[str, str, str]] = set()
+_sync_lock = threading.Lock()
+
+
+@dataclass(frozen=True)
+class RemoteSpec:
+    """How to run a tool on a remote host.
+
+    Attributes:
+        host: SSH destination.
+        workdir: Lintro-owned remote directory mirroring the workspace.
+        workspace: Local directory that is mirrored.
+        port: SSH port, or None for the default.
+        ssh_options: Extra ``ssh`` arguments.
+        sync: File transfer method, ``rsync`` or ``stream``.
+        writable: Whether the tool's changes are copied back.
+    """
+
+    host: str
+    workdir: str
+    workspace: str
+    port: int | None = None
+    ssh_options: tuple[str, ...] = field(default_factory=tuple)
+    sync: str = "rsync"
+    writable: bool = False
+
+    def ssh_command(self) -> list[str]:
+        """Build the ssh invocation, without the remote command.
+
+        Returns:
+            list[str]: ssh executable, options and destination.
+        """
+        cmd = ["ssh", "-o", "BatchMode=yes"]
+        if self.port is not None:
+            cmd.extend(["-p", str(self.port)])
+        return [*cmd, *self.ssh_options, self.host]
+
+    def remote_path(self, path: str) -> str:
+        """Translate a local path inside the workspace to the remote host.
+
+        Args:
+            path: Absolute local path.
+
+        Returns:
+            str: Remote path, or ``path`` unchanged if it is outside the
+            workspace.
+        """
+        relative = os.path.relpath(path, self.workspace)
+        if relative == os.curdir:
+            return self.workdir
+        if relative.startswith(os.pardir):
+            return path
+        return f"{self.workdir}/{relative.replace(os.sep, '/')}"
+
+
+def sync_dir_name(workspace: str) -> str:
+    """Name the lintro-owned remote directory mirroring a workspace.
+
+    The name includes a hash of the local machine and path, so projects with
+    the same directory name never share a remote directory.
+
+    Args:
+        workspace: Absolute local directory.
+
+    Returns:
+        str: Directory name, e.g. ``lintro-app-1a2b3c4d5e6f``.
+    """
+    origin = f"{socket.gethostname()}:{workspace}"
+    digest = hashlib.sha256(origin.encode()).hexdigest()[:12]
+    return f"lintro-{os.path.basename(workspace) or 'root'}-{digest}"
+
+
+def resolve_remote_spec(
+    tool_name: str,
+    lintro_config: LintroConfig,
+    writable: bool,
+    workspace: str | None = None,
+) -> RemoteSpec | None:
+    """Decide whether and how a tool runs on the remote host.
+
+    The workspace is mirrored to a lintro-owned subdirectory of
+    ``execution.remote.workdir``; the configured directory itself is never
+    synced with ``--delete`` or removed.
+
+    Args:
+        tool_name: Name of the tool.
+        lintro_config: Loaded Lintro configuration.
+        writable: Whether the tool modifies files (``lintro format``).
+        workspace: Local directory to mirror. Defaults to the current directory.
+
+    Returns:
+        RemoteSpec | None: Remote settings, or None to run locally.
+
+    Raises:
+        RuntimeError: If the tool is configured to run remotely but ssh (or
+            rsync, when selected) is not installed.
+    """
+    remote = lintro_config.execution.remote
+    remote_tools = {t.lower() for t in remote.tools}
+    if not remote.host or tool_name.lower() not in remote_tools:
+        return None
+    if shutil.which("ssh") is None:
+        raise RuntimeError(
+            f"{tool_name} runs on {remote.host} but ssh is not installed",
+        )
+    sync = remote.sync
+    if sync == "auto":
+        sync = "rsync" if shutil.which("rsync") else "stream"
+    elif sync == "rsync" and shutil.which("rsync") is None:
+        raise RuntimeError(
+            "execution.remote.sync is 'rsync' but rsync is not installed",
+        )
+    root = os.path.abspath(workspace or os.getcwd())
+    base = posixpath.normpath(remote.workdir or DEFAULT_REMOTE_WORKDIR)
+    return RemoteSpec(
+        host=remote.host,
+        workdir=f"{base}/{sync_dir_name(root)}",
+        workspace=root,
+        port=remote.port,
+        ssh_options=tuple(remote.ssh_options),
+        sync=sync,
+        writable=writable,
+    )
+
+
+def _external_path(spec: RemoteSpec, path: str) -> str:
+    """Choose the remote location of a file from outside the workspace.
+
+    Args:
+        spec: Remote settings.
+        path: Absolute local path.
+
+    Returns:
+        str: Remote path under the external-files directory.
+    """
+    digest = hashlib.sha256(path.encode()).hexdigest()[:8]
+    return f"{spec.workdir}/{EXTERNAL_DIR}/{digest}-{os.path.basename(path)}"
+
+
+def _translate_arg(arg: str, spec: RemoteSpec, externals: dict[str, str]) -> str:
+    """Translate local paths in one command argument.
+
+    Args:
+        arg: Argument, possibly of the form ``--option=value``.
+        spec: Remote settings.
+        externals: Remote paths of uploaded files outside the workspace.
+
+    Returns:
+        str: Argument as it must appear on the remote host.
+    """
+    prefix, value = "", arg
+    if arg.startswith("-") and "=" in arg:
+        prefix, value = arg.split("=", 1)
+        prefix += "="
+    if value in externals:
+        return prefix + externals[value]
+    if os.path.isabs(value):
+        return prefix + spec.remote_path(value)
+    return arg
+
+
+def build_remote_command(
+    cmd: list[str],
+    spec: RemoteSpec,
+    cwd: str | None = None,
+    env: dict[str, str] | None = None,
+    externals: dict[str, str] | None = None,
+) -> list[str]:
+    """Wrap a tool command in an ssh invocation.
+
+    Args:
+        cmd: Command as built for the local host.
+        spec: Remote settings for the tool.
+        cwd: Working directory for the tool. Defaults to the workspace.
+        env: Extra environment variables for the tool.
+        externals: Remote paths of uploaded files outside the workspace.
+
+    Returns:
+        list[str]: Command that runs the tool on the remote host.
+    """
+    externals = externals or {}
+    tool_cmd = [
+        _translate_arg(arg, spec, externals) for arg in portable_tool_command(cmd)
+    ]
+    remote_cwd = spec.remote_path(os.path.abspath(cwd) if cwd else spec.workspace)
+    # Only forward overrides; the local environment (PATH, HOME) stays here
+    overrides = [
+        f"{key}={value}"
+        for key, value in (env or {}).items()
+        if os.environ.get(key) != value
+    ]
+    script = f"cd {shlex.quote(remote_cwd)} && "
+    if overrides:
+        script += f"env {shlex.join(overrides)} "
+    return [*spec.ssh_command(), script + shlex.join(tool_cmd)]
+
+
+def localize_output(
+    output: str,
+    spec: RemoteSpec,
+    externals: dict[str, str] | None = None,
+) -> str:
+    """Translate remote paths in tool output back to local paths.
+
+    Args:
+        output: Tool output from the remote host.
+        spec: Remote settings for the tool.
+        externals: Remote paths of uploaded files outside the workspace.
+
+    Returns:
+        str: Output referring to local files.
+    """
+    for local, remote in (externals or {}).items():
+        output = output.replace(remote, local)
+    # Match the workdir only as a whole path component
+    pattern = re.escape(spec.workdir) + r"(?![\w.-])"
+    return re.sub(pattern, lambda _: spec.workspace, output)
+
+
+def _transfer(
+    cmd: list[str],
+    timeout: float,
+    data: bytes | None = None,
+) -> bytes:
+    """Run a file transfer command.
+
+    Args:
+        cmd: Transfer command.
+        timeout: Timeout in seconds.
+        data: Bytes to send on stdin, if any.
+
+    Returns:
+        bytes: The command's stdout.
+
+    Raises:
+        RuntimeError: If the transfer fails or times out.
+    """
+    try:
+        result = subprocess.run(  # nosec B603 - args list, shell=False
+            cmd,
+            input=data,
+            capture_output=True,
+            timeout=timeout,
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        raise RuntimeError(f"Remote transfer failed: {e}") from e
+    if result.returncode != 0:
+        stderr = result.stderr.decode(errors="replace").strip()
+        raise RuntimeError(f"Remote transfer failed: {stderr or result.returncode}")
+    return result.stdout
+
+
+def _remote_script(spec: RemoteSpec, script: str) -> list[str]:
+    """Build an ssh command that runs a shell script remotely.
+
+    Args:
+        spec: Remote settings.
+        script: Remote shell script.
+
+    Returns:
+        list[str]: ssh command.
+    """
+    return [*spec.ssh_command(), script]
+
+
+def _tar_workspace(workspace: str) -> bytes:
+    """Archive the workspace without the excluded directories.
+
+    Args:
+        workspace: Local directory.
+
+    Returns:
+        bytes: Uncompressed tar archive.
+    """
+
+    def _filter(info: tarfile.TarInfo) -> tarfile.TarInfo | None:
+        """Drop excluded directories from the archive.
+
+        Args:
+            info: Archive member.
+
+        Returns:
+            tarfile.TarInfo | None: The member, or None to skip it.
+        """
+        parts = info.name.split("/")
+        return None if any(p in REMOTE_SYNC_EXCLUDES for p in parts) else info
+
+    buffer = io.BytesIO()
+    with tarfile.open(fileobj=buffer, mode="w") as archive:
+        archive.add(workspace, arcname=".", filter=_filter)
+    return buffer.getvalue()
+
+
+def sync_to_remote(spec: RemoteSpec, timeout: float) -> None:
+    """Mirror the workspace to the remote directory.
+
+    Args:
+        spec: Remote settings.
+        timeout: Timeout in seconds per transfer.
+
+    Raises:
+        RuntimeError: If the remote directory is not one Lintro created, since
+            syncing deletes everything else in it.
+    """
+    if not posixpath.basename(spec.workdir).startswith("lintro-"):
+        raise RuntimeError(
+            f"Refusing to sync into {spec.workdir}: not a lintro sync directory",
+        )
+    workdir = shlex.quote(spec.workdir)
+    if spec.sync == "rsync":
+        _transfer(_remote_script(spec, f"mkdir -p {workdir}"), timeout)
+        _transfer(
+            [
+                "rsync",
+                "-az",
+                "--delete",
+                *(f"--exclude={name}" for name in REMOTE_SYNC_EXCLUDES),
+                "-e",
+                shlex.join(spec.ssh_command()[:-1]),
+                f"{spec.workspace}/",
+                f"{spec.host}:{spec.workdir}/",
+            ],
+            timeout,
+        )
+        return
+    _transfer(
+        _remote_script(
+            spec,
+            f"rm -rf {workdir} && mkdir -p {workdir} && tar -xf - -C {workdir}",
+        ),
+        timeout,
+        data=_tar_workspace(spec.workspace),
+    )
+
+
+def sync_from_remote(spec: RemoteSpec, timeout: float) -> None:
+    """Copy the tool's changes from the remote directory back.
+
+    Args:
+        spec: Remote settings.
+        timeout: Timeout in seconds per transfer.
+    """
+    excludes = [*REMOTE_SYNC_EXCLUDES, EXTERNAL_DIR]
+    if spec.sync == "rsync":
+        _transfer(
+            [
+                "rsync",
+                "-az",
+                *(f"--exclude={name}" for name in excludes),
+                "-e",
+                shlex.join(spec.ssh_command()[:-1]),
+                f"{spec.host}:{spec.workdir}/",
+                f"{spec.workspace}/",
+            ],
+            timeout,
+        )
+        return
+    exclude_args = " ".join(shlex.quote(f"--exclude={name}") for name in excludes)
+    archive = _transfer(
+        _remote_script(
+            spec,
+            f"tar -cf - {exclude_args} -C {shlex.quote(spec.workdir)} .",
+        ),
+        timeout,
+    )
+    with tarfile.open(fileobj=io.BytesIO(archive), mode="r") as tar:
+        tar.extractall(spec.workspace, filter="data")  # nosec B202
+
+
+def upload_externals(
+    spec: RemoteSpec,
+    paths: list[str],
+    timeout: float,
+) -> dict[str, str]:
+    """Upload files from outside the workspace that a command refers to.
+
+    Args:
+        spec: Remote settings.
+        paths: Absolute local file paths.
+        timeout: Timeout in seconds per transfer.
+
+    Returns:
+        dict[str, str]: Remote path per local path.
+    """
+    externals: dict[str, str] = {}
+    for path in paths:
+        if not os.path.isfile(path):
+            continue
+        remote = _external_path(spec, path)
+        with open(path, "rb") as f:
+            data = f.read()
+        directory = shlex.quote(os.path.dirname(remote))
+        _transfer(
+            _remote_script(
+                spec,
+                f"mkdir -p {directory} && cat > {shlex.quote(remote)}",
+            ),
+            timeout,
+            data=data,
+        )
+        externals[path] = remote
+    return externals
+
+
+def run_remote(
+    cmd: list[str],
+    spec: RemoteSpec,
+    timeout: float,
+    cwd: str | None = None,
+    env: dict[str, str] | None = None,
+    line_handler: Callable[[str], None] | None = None,
+    max_output_chars: int | None = None,
+) -> tuple[bool, str]:
+    """Run a tool command on the remote host.
+
+    Args:
+        cmd: Command as built for the local host.
+        spec: Remote settings for the tool.
+        timeout: Timeout in seconds for the tool and each transfer.
+        cwd: Working directory for the tool.
+        env: Extra environment variables for the tool.
+        line_handler: Optional callback for each output line (streaming).
+        max_output_chars: Characters of streamed output to return, or None.
+
+    Returns:
+        tuple[bool, str]: Success flag and output with local paths.
+    """
+    key = (spec.host, spec.workdir, spec.workspace)
+    with _sync_lock:
+        if spec.writable or key not in _synced:
+            sync_to_remote(spec, timeout)
+            _synced.add(key)
+    externals = upload_externals(
+        spec,
+        outside_paths(portable_tool_command(cmd)[1:], spec.workspace),
+        timeout,
+    )
+    remote_cmd = build_remote_command(cmd, spec, cwd, env, externals)
+    if line_handler is None:
+        success, output = run_subprocess(remote_cmd, timeout)
+    else:
+        handler = line_handler
+        success, output = run_subprocess_streaming(
+            remote_cmd,
+            timeout,
+            line_handler=lambda line: handler(localize_output(line, spec, externals)),
+            max_output_chars=max_output_chars,
+        )
+    if spec.writable:
+        sync_from_remote(spec, timeout)
+        # Other fixers may change the workspace before the next remote run
+        _synced.discard(key)
+    return success, localize_output(output, spec, externals)
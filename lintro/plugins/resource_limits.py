@@ -0,0 +1,90 @@
+"""Resource limits for tool processes.
+
+``tools.<name>.memory_limit_mb`` and ``tools.<name>.niceness`` bound what a
+tool may use, so a runaway eslint or clippy build cannot starve the rest of
+the machine. The limits are applied to the tool's process as soon as it has
+started, from the parent with ``setpriority`` and ``prlimit``, and are
+inherited by everything it launches. ``preexec_fn`` is not used since tools
+start from a thread pool, where it can deadlock. They are POSIX-only; the
+memory limit caps the data segment (heap), not the address space that
+V8-based tools reserve up front, and is enforced on Linux.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+from loguru import logger
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+
+# Highest nice value, the lowest CPU priority
+MAX_NICENESS: int = 19
+
+
+@dataclass(frozen=True)
+class ResourceLimits:
+    """Limits applied to a tool's processes.
+
+    Attributes:
+        memory_limit_mb: Largest data segment in MiB, or None for no limit.
+        niceness: Added to the process's nice value (0-19), or None to keep
+            lintro's priority.
+    """
+
+    memory_limit_mb: int | None = None
+    niceness: int | None = None
+
+    def apply(self, pid: int) -> None:
+        """Apply the limits to a process that has just started.
+
+        Does nothing on platforms without POSIX process limits. A process
+        that already exited is ignored.
+
+        Args:
+            pid: Process id of the tool.
+        """
+        if os.name != "posix":
+            return
+        try:
+            if self.niceness:
+                current = os.getpriority(os.PRIO_PROCESS, pid)
+                os.setpriority(
+                    os.PRIO_PROCESS,
+                    pid,
+                    min(current + self.niceness, MAX_NICENESS),
+                )
+            if self.memory_limit_mb:
+                import resource
+
+                # prlimit only exists on Linux
+                if hasattr(resource, "prlimit"):
+                    limit = self.memory_limit_mb * 1024 * 1024
+                    resource.prlimit(pid, resource.RLIMIT_DATA, (limit, limit))
+        except (OSError, ValueError) as e:
+            logger.debug(f"Could not limit process {pid}: {e}")
+
+
+def resolve_resource_limits(
+    tool_name: str,
+    lintro_config: LintroConfig,
+) -> ResourceLimits | None:
+    """Return the limits configured for a tool.
+
+    Args:
+        tool_name: Name of the tool.
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        ResourceLimits | None: Limits, or None if the tool has none.
+    """
+    tool_config = lintro_config.get_tool_config(tool_name)
+    if tool_config.memory_limit_mb is None and not tool_config.niceness:
+        return None
+    return ResourceLimits(
+        memory_limit_mb=tool_config.memory_limit_mb,
+        niceness=tool_config.niceness,
+    )
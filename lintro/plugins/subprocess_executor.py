@@ -1,6 +1,9 @@
 """Subprocess execution utilities for tool plugins.
 
 This module provides safe subprocess execution with validation and streaming.
+Output is captured as bytes and decoded leniently (see
+:mod:`lintro.utils.text_decoding`), so bytes that are not valid text are
+replaced and reported instead of aborting the run.
 """
 
 from __future__ import annotations
@@ -10,16 +13,30 @@ import os
 import subprocess  # nosec B404 - subprocess used safely with shell=False
 import sys
 import threading
+import time
 from typing import TYPE_CHECKING
 
 from loguru import logger
 
+from lintro.utils.logger_setup import log_event
+from lintro.utils.text_decoding import decode_output, report_undecodable
+from lintro.utils.timestamps import elapsed_ms
+from lintro.utils.timing_profile import record_invocation
+
 if TYPE_CHECKING:
     from collections.abc import Callable
 
+    from lintro.plugins.resource_limits import ResourceLimits
+
 # Cache for compiled binary detection
 _IS_COMPILED_BINARY: bool | None = None
 
+# Output kept by default when a line handler parses the lines as they arrive
+DEFAULT_MAX_OUTPUT_CHARS: int = 1_000_000
+
+# Appended to output that was cut at the cap
+OUTPUT_TRUNCATED_MARKER: str = "... [output truncated: {omitted} more characters]"
+
 
 def is_compiled_binary() -> bool:
     """Detect if lintro is running as a Nuitka-compiled binary.
@@ -130,11 +147,99 @@ def validate_subprocess_command(cmd: list[str]) -> None:
         raise ValueError("Unsafe character detected in command name")
 
 
+def _run_limited(
+    cmd: list[str],
+    timeout: float,
+    cwd: str | None,
+    env: dict[str, str] | None,
+    limits: ResourceLimits,
+) -> subprocess.CompletedProcess[bytes]:
+    """Run a command like ``subprocess.run``, limiting it once it starts.
+
+    Args:
+        cmd: Command and arguments to run.
+        timeout: Timeout in seconds.
+        cwd: Working directory for command execution.
+        env: Full environment of the process, or None to inherit it.
+        limits: Limits applied to the process.
+
+    Returns:
+        subprocess.CompletedProcess[bytes]: Exit code and captured output.
+
+    Raises:
+        subprocess.TimeoutExpired: If the command times out; it is killed.
+    """
+    with subprocess.Popen(  # nosec B603 - args list, shell=False
+        cmd,
+        stdout=subprocess.PIPE,
+        stderr=subprocess.PIPE,
+        cwd=cwd,
+        env=env,
+    ) as process:
+        limits.apply(process.pid)
+        try:
+            stdout, stderr = process.communicate(timeout=timeout)
+        except subprocess.TimeoutExpired as e:
+            process.kill()
+            e.output, e.stderr = process.communicate()
+            raise
+    return subprocess.CompletedProcess(cmd, process.returncode, stdout, stderr)
+
+
+def _log_invocation(
+    cmd: list[str],
+    cwd: str | None,
+    started: float,
+    exit_code: int | None,
+) -> None:
+    """Log a finished subprocess as a structured event.
+
+    Args:
+        cmd: Command and arguments that ran.
+        cwd: Working directory of the command.
+        started: Monotonic clock reading when the command started.
+        exit_code: Exit code, or None if the command timed out.
+    """
+    duration = elapsed_ms(started)
+    outcome = "timed out" if exit_code is None else f"exited with {exit_code}"
+    log_event(
+        "subprocess",
+        f"{cmd[0]} {outcome} after {duration} ms",
+        argv=cmd,
+        cwd=cwd,
+        duration_ms=duration,
+        exit_code=exit_code,
+        timed_out=exit_code is None,
+    )
+    record_invocation(cmd, cwd, started, duration)
+
+
+def _decode(data: bytes | str | None, cmd: list[str]) -> str:
+    """Decode captured output, reporting bytes that are not valid text.
+
+    Args:
+        data: Captured output; text is returned unchanged.
+        cmd: Command that printed the output.
+
+    Returns:
+        str: Decoded output, empty if there was none.
+    """
+    if not data:
+        return ""
+    if isinstance(data, str):
+        return data
+    decoded = decode_output(data)
+    if decoded.replaced:
+        report_undecodable(cmd[0], decoded.replaced, argv=cmd)
+    return decoded.text
+
+
 def run_subprocess(
     cmd: list[str],
     timeout: float,
     cwd: str | None = None,
     env: dict[str, str] | None = None,
+    limits: ResourceLimits | None = None,
 ) -> tuple[bool, str]:
     """Run a subprocess command safely.
 
@@ -144,6 +249,7 @@ def run_subprocess(
         cwd: Working directory for command execution.
         env: Environment variables for the subprocess. These are merged with
             os.environ to preserve PATH and other essential variables.
+        limits: Memory and priority limits applied to the process, or None.
 
     Returns:
         Tuple of (success, output) where success indicates return code 0.
@@ -163,42 +269,36 @@ def run_subprocess(
     if env is not None:
         effective_env = {**os.environ, **env}
 
+    started = time.monotonic()
     try:
-        result = subprocess.run(  # nosec B603 - args list, shell=False
-            cmd,
-            capture_output=True,
-            text=True,
-            timeout=timeout,
-            cwd=cwd,
-            env=effective_env,
-        )
+        if limits is not None:
+            result = _run_limited(cmd, timeout, cwd, effective_env, limits)
+        else:
+            result = subprocess.run(  # nosec B603 - args list, shell=False
+                cmd,
+                capture_output=True,
+                timeout=timeout,
+                cwd=cwd,
+                env=effective_env,
+            )
+        stdout = _decode(result.stdout, cmd)
+        stderr = _decode(result.stderr, cmd)
 
         if result.returncode != 0:
-            stderr_preview = (result.stderr or "")[:500]
+            stderr_preview = stderr[:500]
             if stderr_preview:
                 logger.debug(
                     f"Subprocess {cmd[0]} exited with code {result.returncode}, "
                     f"stderr: {stderr_preview}",
                 )
 
-        return result.returncode == 0, result.stdout + result.stderr
+        _log_invocation(cmd, cwd, started, result.returncode)
+        return result.returncode == 0, stdout + stderr
     except subprocess.TimeoutExpired as e:
+        _log_invocation(cmd, cwd, started, None)
         logger.warning(f"Subprocess {cmd[0]} timed out after {timeout}s")
         # Preserve partial output from the original exception
-        partial_output = ""
-        if e.output:
-            partial_output = (
-                e.output
-                if isinstance(e.output, str)
-                else e.output.decode(errors="replace")
-            )
-        if e.stderr:
-            stderr = (
-                e.stderr
-                if isinstance(e.stderr, str)
-                else e.stderr.decode(errors="replace")
-            )
-            partial_output = partial_output + stderr if partial_output else stderr
+        partial_output = _decode(e.output, cmd) + _decode(e.stderr, cmd)
         raise subprocess.TimeoutExpired(
             cmd=cmd,
             timeout=timeout,
@@ -220,6 +320,8 @@ def run_subprocess_streaming(
     cwd: str | None = None,
     env: dict[str, str] | None = None,
     line_handler: Callable[[str], None] | None = None,
+    limits: ResourceLimits | None = None,
+    max_output_chars: int | None = None,
 ) -> tuple[bool, str]:
     """Run a subprocess command with optional line-by-line streaming.
 
@@ -229,6 +331,11 @@ def run_subprocess_streaming(
     The timeout is enforced during both output reading and process completion,
     preventing indefinite blocking on slow or hanging processes.
 
+    With ``max_output_chars``, only the first lines of the output are kept
+    and the returned output ends with a truncation marker; every line still
+    reaches ``line_handler``, so a handler that parses lines as they arrive
+    sees all of them while memory stays bounded.
+
     Args:
         cmd: Command and arguments to run.
         timeout: Timeout in seconds.
@@ -236,6 +343,8 @@ def run_subprocess_streaming(
         env: Environment variables for the subprocess. These are merged with
             os.environ to preserve PATH and other essential variables.
         line_handler: Optional callback called for each line of output.
+        limits: Memory and priority limits applied to the process, or None.
+        max_output_chars: Characters of output to keep, or None to keep all.
 
     Returns:
         Tuple of (success, output) where success indicates return code 0.
@@ -257,28 +366,58 @@ def run_subprocess_streaming(
     if env is not None:
         effective_env = {**os.environ, **env}
 
+    started = time.monotonic()
     try:
         # Use Popen for streaming output  # nosec B603
         process = subprocess.Popen(
             cmd,
             stdout=subprocess.PIPE,
             stderr=subprocess.STDOUT,
-            text=True,
             cwd=cwd,
             env=effective_env,
-            bufsize=1,  # Line buffering
         )
+        if limits is not None:
+            limits.apply(process.pid)
 
         output_lines: list[str] = []
+        replaced: list[int] = []
+        # Characters kept and dropped, for the cap and its marker
+        sizes = {"kept": 0, "omitted": 0}
 
         def read_output() -> None:
             """Read output lines in a separate thread."""
             if process.stdout:
-                for line in process.stdout:
-                    stripped = line.rstrip("\n")
-                    output_lines.append(stripped)
-                    if line_handler:
-                        line_handler(stripped)
+                for raw in process.stdout:
+                    if isinstance(raw, bytes):
+                        decoded = decode_output(raw)
+                        replaced.append(decoded.replaced)
+                        raw = decoded.text
+                    # A lone carriage return breaks the line, as in text mode
+                    for stripped in raw.rstrip("\n").split("\n"):
+                        size = len(stripped) + 1
+                        # Once a line is dropped, so are the ones after it
+                        if not sizes["omitted"] and (
+                            max_output_chars is None
+                            or sizes["kept"] + size <= max_output_chars
+                        ):
+                            output_lines.append(stripped)
+                            sizes["kept"] += size
+                        else:
+                            sizes["omitted"] += size
+                        if line_handler:
+                            line_handler(stripped)
+
+        def joined_output() -> str:
+            """Join the kept lines, marking output dropped at the cap.
+
+            Returns:
+                str: Output kept from the process.
+            """
+            output = "\n".join(output_lines)
+            if sizes["omitted"]:
+                marker = OUTPUT_TRUNCATED_MARKER.format(omitted=sizes["omitted"])
+                output = f"{output}\n{marker}" if output else marker
+            return output
 
         # Use a thread to read output so we can enforce timeout
         reader_thread = threading.Thread(target=read_output, daemon=True)
@@ -287,6 +426,7 @@ def run_subprocess_streaming(
 
         if reader_thread.is_alive():
             # Timeout occurred during reading - kill the process
+            _log_invocation(cmd, cwd, started, None)
             logger.warning(
                 f"Subprocess {cmd[0]} timed out after {timeout}s (reading output)",
             )
@@ -297,13 +437,14 @@ def run_subprocess_streaming(
             raise subprocess.TimeoutExpired(
                 cmd=cmd,
                 timeout=timeout,
-                output="\n".join(output_lines),
+                output=joined_output(),
             )
 
         # Reading completed, now wait for process to finish
         try:
             returncode = process.wait(timeout=timeout)
         except subprocess.TimeoutExpired as e:
+            _log_invocation(cmd, cwd, started, None)
             logger.warning(
                 f"Subprocess {cmd[0]} timed out after {timeout}s (during wait)",
             )
@@ -312,18 +453,27 @@ def run_subprocess_streaming(
             raise subprocess.TimeoutExpired(
                 cmd=cmd,
                 timeout=timeout,
-                output="\n".join(output_lines),
+                output=joined_output(),
             ) from e
 
+        _log_invocation(cmd, cwd, started, returncode)
+        if sum(replaced):
+            report_undecodable(cmd[0], sum(replaced), argv=cmd)
+        output = joined_output()
+        if sizes["omitted"]:
+            logger.debug(
+                f"Kept {sizes['kept']} characters of {cmd[0]} output, "
+                f"dropped {sizes['omitted']}",
+            )
         if returncode != 0:
-            output_preview = "\n".join(output_lines)[:500]
+            output_preview = output[:500]
             if output_preview:
                 logger.debug(
                     f"Subprocess {cmd[0]} exited with code {returncode}, "
                     f"output: {output_preview}",
                 )
 
-        return returncode == 0, "\n".join(output_lines)
+        return returncode == 0, output
 
     except FileNotFoundError as e:
         logger.warning(
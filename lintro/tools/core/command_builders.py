@@ -25,6 +25,7 @@ Example:
 
 from __future__ import annotations
 
+import os
 import shutil
 import sys
 from abc import ABC, abstractmethod
@@ -180,9 +181,11 @@ class PythonBundledBuilder(CommandBuilder):
 
     Handles: ruff, black, bandit, yamllint, mypy.
 
-    Prefers PATH-based discovery to support various installation methods
-    (Homebrew, system packages, pipx, uv tool). Falls back to Python module
-    execution for pip installs where the binary isn't in PATH.
+    Runs the tool from the project's virtual environment when it is installed
+    there. Otherwise prefers PATH-based discovery to support various
+    installation methods (Homebrew, system packages, pipx, uv tool). Falls
+    back to Python module execution for pip installs where the binary isn't
+    in PATH.
     """
 
     _tools: frozenset[ToolName] | None = None
@@ -226,7 +229,8 @@ class PythonBundledBuilder(CommandBuilder):
     ) -> list[str]:
         """Get command for Python bundled tool.
 
-        When running in a virtual environment, always uses python -m to ensure
+        Uses the project's virtual environment when it has the tool. When
+        running in a virtual environment, always uses python -m to ensure
         the tool runs with the same packages as lintro. Otherwise, prefers
         PATH binary (works with Homebrew, system packages, pipx, uv tool, etc.).
 
@@ -237,6 +241,14 @@ class PythonBundledBuilder(CommandBuilder):
         Returns:
             Command list to execute the tool.
         """
+        from lintro.utils.toolchains import project_python
+
+        # The project's own environment (VIRTUAL_ENV or uv's .venv) wins
+        project_exe = project_python(os.getcwd(), tool_name)
+        if project_exe:
+            logger.debug(f"Using {tool_name} from the project environment")
+            return [project_exe, "-m", tool_name]
+
         # Skip python -m fallback when compiled (sys.executable is the lintro binary)
         if _is_compiled_binary():
             tool_path = shutil.which(tool_name)
@@ -300,7 +312,8 @@ class PytestBuilder(CommandBuilder):
     ) -> list[str]:
         """Get command for pytest.
 
-        When running in a virtual environment, always uses python -m pytest to
+        Uses the project's virtual environment when it has pytest. When
+        running in a virtual environment, always uses python -m pytest to
         ensure pytest runs with the same packages as lintro. Otherwise, prefers
         PATH binary (works with Homebrew, system packages, pipx, uv tool, etc.).
 
@@ -311,6 +324,14 @@ class PytestBuilder(CommandBuilder):
         Returns:
             Command list to execute pytest.
         """
+        from lintro.utils.toolchains import project_python
+
+        # The project's own environment (VIRTUAL_ENV or uv's .venv) wins
+        project_exe = project_python(os.getcwd(), "pytest")
+        if project_exe:
+            logger.debug("Using pytest from the project environment")
+            return [project_exe, "-m", "pytest"]
+
         # Skip python -m fallback when compiled (sys.executable is the lintro binary)
         if _is_compiled_binary():
             tool_path = shutil.which("pytest")
@@ -349,7 +370,7 @@ class PytestBuilder(CommandBuilder):
 
 @register_command_builder
 class NodeJSBuilder(CommandBuilder):
-    """Builder for Node.js tools (Astro, Markdownlint, TypeScript, Vue-tsc).
+    """Builder for Node.js tools (Astro, ESLint, Markdownlint, Stylelint, TypeScript).
 
     Uses bunx to run Node.js tools when available, falling back to
     direct tool invocation if bunx is not found.
@@ -370,7 +391,9 @@ class NodeJSBuilder(CommandBuilder):
 
             self._package_names = {
                 ToolName.ASTRO_CHECK: "astro",
+                ToolName.ESLINT: "eslint",
                 ToolName.MARKDOWNLINT: "markdownlint-cli2",
+                ToolName.STYLELINT: "stylelint",
                 ToolName.SVELTE_CHECK: "svelte-check",
                 ToolName.TSC: "typescript",
                 ToolName.VUE_TSC: "vue-tsc",
@@ -439,7 +462,7 @@ class NodeJSBuilder(CommandBuilder):
 
 @register_command_builder
 class CargoBuilder(CommandBuilder):
-    """Builder for Cargo/Rust tools (Clippy, cargo-audit, cargo-deny).
+    """Builder for Cargo/Rust tools (Clippy and the cargo-* subcommands).
 
     Invokes Rust tools via cargo subcommands.
     """
@@ -459,6 +482,9 @@ class CargoBuilder(CommandBuilder):
             ToolName.CLIPPY,
             ToolName.CARGO_AUDIT,
             ToolName.CARGO_DENY,
+            ToolName.CARGO_GEIGER,
+            ToolName.CARGO_MACHETE,
+            ToolName.CARGO_OUTDATED,
         }
 
     def get_command(
@@ -484,12 +510,58 @@ class CargoBuilder(CommandBuilder):
         cargo_subcommands: dict[ToolName, str] = {
             ToolName.CARGO_AUDIT: "audit",
             ToolName.CARGO_DENY: "deny",
+            ToolName.CARGO_GEIGER: "geiger",
+            ToolName.CARGO_MACHETE: "machete",
+            ToolName.CARGO_OUTDATED: "outdated",
             ToolName.CLIPPY: "clippy",
         }
         subcommand = cargo_subcommands.get(tool_name_enum, "clippy")
         return ["cargo", subcommand]
 
 
+@register_command_builder
+class RubyBuilder(CommandBuilder):
+    """Builder for Ruby tools (RuboCop).
+
+    Uses ``bundle exec`` when the Gemfile of the current directory locks
+    the tool, so the project's pinned version runs, falling back to direct
+    tool invocation otherwise.
+    """
+
+    def can_handle(self, tool_name_enum: ToolName | None) -> bool:
+        """Check if this builder handles the tool.
+
+        Args:
+            tool_name_enum: Tool name enum to check.
+
+        Returns:
+            True if tool is a Ruby gem.
+        """
+        from lintro.enums.tool_name import ToolName
+
+        return tool_name_enum in {ToolName.RUBOCOP}
+
+    def get_command(
+        self,
+        tool_name: str,
+        tool_name_enum: ToolName | None,
+    ) -> list[str]:
+        """Get command for Ruby tool.
+
+        Args:
+            tool_name: String name of the tool.
+            tool_name_enum: Tool name enum.
+
+        Returns:
+            Command list to execute the tool via bundle exec or directly.
+        """
+        from lintro.utils.ruby_bundler import get_bundled_gem_dir
+
+        if get_bundled_gem_dir(os.getcwd(), tool_name) is not None:
+            return ["bundle", "exec", tool_name]
+        return [tool_name]
+
+
 @register_command_builder
 class StandaloneBuilder(CommandBuilder):
     """Builder for standalone binary tools (Hadolint, Actionlint).
@@ -97,6 +97,20 @@ def validate_list(value: Any, name: str) -> None:
         raise ValueError(f"{name} must be a list")
 
 
+def validate_dict(value: Any, name: str) -> None:
+    """Validate that value is a dictionary if not None.
+
+    Args:
+        value: Value to validate.
+        name: Parameter name for error message.
+
+    Raises:
+        ValueError: If value is not None and not a dictionary.
+    """
+    if value is not None and not isinstance(value, dict):
+        raise ValueError(f"{name} must be a dictionary")
+
+
 def normalize_str_or_list(value: Any, name: str) -> list[str] | None:
     """Normalize a string or list value to a list.
 
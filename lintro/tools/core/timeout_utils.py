@@ -5,11 +5,15 @@ ensuring consistent behavior and error messages for subprocess timeouts.
 """
 
 import subprocess  # nosec B404 - used safely with shell disabled
+from collections.abc import Callable
 from dataclasses import dataclass, field
 from typing import Any
 
 from loguru import logger
 
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.plugins.subprocess_executor import DEFAULT_MAX_OUTPUT_CHARS
+
 
 @dataclass
 class TimeoutResult:
@@ -21,6 +25,7 @@ class TimeoutResult:
     issues: list[Any] = field(default_factory=list)
     timed_out: bool = True
     timeout_seconds: int = 0
+    error_code: str = TOOL_TIMEOUT.code
 
 
 def run_subprocess_with_timeout(
@@ -29,18 +34,25 @@ def run_subprocess_with_timeout(
     timeout: int | None = None,
     cwd: str | None = None,
     tool_name: str | None = None,
+    line_handler: Callable[[str], None] | None = None,
 ) -> tuple[bool, str]:
     """Run a subprocess command with timeout handling.
 
     This is a wrapper around tool._run_subprocess that provides consistent
     timeout error handling and messaging across different tools.
 
+    With a ``line_handler`` the output is streamed to it line by line (see
+    tool._run_subprocess_streaming), and only the first
+    ``DEFAULT_MAX_OUTPUT_CHARS`` characters are returned, so a parser that
+    handles the lines as they arrive keeps memory bounded on huge outputs.
+
     Args:
         tool: Tool instance with _run_subprocess method.
         cmd: Command to run.
         timeout: Timeout in seconds. If None, uses tool's default timeout.
         cwd: Working directory for command execution.
         tool_name: Name of the tool for error messages. If None, uses tool.name.
+        line_handler: Callback for each line of output, or None to buffer it.
 
     Returns:
         tuple[bool, str]: (success, output) where success is True if command
@@ -53,7 +65,16 @@ def run_subprocess_with_timeout(
     tool_name = tool_name or tool.definition.name
 
     try:
-        success, output = tool._run_subprocess(cmd=cmd, timeout=timeout, cwd=cwd)
+        if line_handler is None:
+            success, output = tool._run_subprocess(cmd=cmd, timeout=timeout, cwd=cwd)
+        else:
+            success, output = tool._run_subprocess_streaming(
+                cmd=cmd,
+                timeout=timeout,
+                cwd=cwd,
+                line_handler=line_handler,
+                max_output_chars=DEFAULT_MAX_OUTPUT_CHARS,
+            )
         return bool(success), str(output)
     except subprocess.TimeoutExpired as e:
         # Re-raise with more context for the calling tool
@@ -65,7 +86,7 @@ def run_subprocess_with_timeout(
             "  - Need to increase timeout via --tool-options timeout=N\n"
             "  - Command hanging due to external dependencies\n"
         )
-        logger.warning(timeout_msg)
+        logger.warning(f"[{TOOL_TIMEOUT.code}] {timeout_msg}")
 
         # Create a new TimeoutExpired with enhanced message
         raise subprocess.TimeoutExpired(
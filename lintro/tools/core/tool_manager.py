@@ -11,7 +11,7 @@ from typing import TYPE_CHECKING, Any
 
 from loguru import logger
 
-from lintro.plugins.discovery import discover_all_tools
+from lintro.plugins.discovery import discover_all_tools, discover_builtin_tool
 from lintro.plugins.registry import ToolRegistry
 from lintro.utils.unified_config import get_ordered_tools
 
@@ -55,6 +55,24 @@ class ToolManager:
         self._ensure_initialized()
         return ToolRegistry.get(name)
 
+    def load_tool(self, name: str) -> BaseToolPlugin | None:
+        """Get a tool, importing only its own definition if it is builtin.
+
+        Until full discovery runs, the registry holds just the tools loaded
+        this way, so this suits short runs that name their tools, such as
+        ``lintro fix-file --tools rustfmt``.
+
+        Args:
+            name: The name of the tool (case-insensitive).
+
+        Returns:
+            The tool/plugin instance, or None if no tool has that name.
+        """
+        if self._initialized or not discover_builtin_tool(name):
+            if not self.is_tool_registered(name):
+                return None
+        return ToolRegistry.get(name)
+
     def get_tool_execution_order(
         self,
         tool_names: list[str],
@@ -140,9 +140,11 @@ def get_install_hints() -> dict[str, str]:
         ),
         "markdownlint": "Install via: bun add -d markdownlint-cli2@>={version}",
         "markdownlint-cli2": "Install via: bun add -d markdownlint-cli2@>={version}",
+        "eslint": "Install via: bun add -d eslint@>={version}",
         "oxfmt": "Install via: bun add -d oxfmt@>={version}",
         "oxlint": "Install via: bun add -d oxlint@>={version}",
         "prettier": "Install via: bun add -d prettier@>={version}",
+        "stylelint": "Install via: bun add -d stylelint@>={version}",
         "tsc": (
             "Install via: bun add -g typescript@{version}, "
             "npm install -g typescript@{version}, or brew install typescript"
@@ -154,6 +156,18 @@ def get_install_hints() -> dict[str, str]:
         "hadolint": (
             "Install via: https://github.com/hadolint/hadolint/releases (v{version}+)"
         ),
+        "ktlint": (
+            "Install via: brew install ktlint or "
+            "https://github.com/pinterest/ktlint/releases (v{version}+, needs Java)"
+        ),
+        "stylua": (
+            "Install via: cargo install stylua, brew install stylua or "
+            "https://github.com/JohnnyMorganz/StyLua/releases (v{version}+)"
+        ),
+        "luacheck": (
+            "Install via: luarocks install luacheck or brew install luacheck "
+            "(v{version}+)"
+        ),
         "actionlint": (
             "Install via: https://github.com/rhysd/actionlint/releases (v{version}+)"
         ),
@@ -165,16 +179,45 @@ def get_install_hints() -> dict[str, str]:
         "rustfmt": "Install via: rustup component add rustfmt (v{version}+)",
         "cargo_audit": "Install via: cargo install cargo-audit (v{version}+)",
         "cargo_deny": "Install via: cargo install cargo-deny (v{version}+)",
+        "cargo_geiger": "Install via: cargo install cargo-geiger (v{version}+)",
+        "cargo_machete": "Install via: cargo install cargo-machete (v{version}+)",
+        "cargo_outdated": "Install via: cargo install cargo-outdated (v{version}+)",
+        "clang_format": (
+            "Install via: pip install clang-format>={version} or brew install llvm"
+        ),
+        "clang_tidy": (
+            "Install via: pip install clang-tidy>={version} or brew install llvm"
+        ),
         "biome": "Install via: bun add -d @biomejs/biome@>={version}",
         "semgrep": (
             "Install via: pip install semgrep>={version} or brew install semgrep"
         ),
+        "ansible_lint": (
+            "Install via: pip install ansible-lint>={version} "
+            "or brew install ansible-lint"
+        ),
+        "rubocop": (
+            "Install via: gem install rubocop -v '>= {version}' or add it to the "
+            "project's Gemfile"
+        ),
+        "checkov": (
+            "Install via: pipx install checkov>={version} or brew install checkov"
+        ),
+        "buf": (
+            "Install via: brew install bufbuild/buf/buf or download from "
+            "https://github.com/bufbuild/buf/releases (v{version}+)"
+        ),
         "gitleaks": (
             "Install via: https://github.com/gitleaks/gitleaks/releases (v{version}+)"
         ),
         "shellcheck": (
             "Install via: https://github.com/koalaman/shellcheck/releases (v{version}+)"
         ),
+        "gofmt": "Install Go from https://go.dev/dl/ (v{version}+)",
+        "golangci_lint": (
+            "Install via: brew install golangci-lint or download from "
+            "https://github.com/golangci/golangci-lint/releases (v{version}+)"
+        ),
         "shfmt": "Install via: https://github.com/mvdan/sh/releases (v{version}+)",
         "sqlfluff": (
             "Install via: pip install sqlfluff>={version} or uv add sqlfluff>={version}"
@@ -183,6 +226,22 @@ def get_install_hints() -> dict[str, str]:
             "Install via: cargo install taplo-cli "
             "or download from https://github.com/tamasfe/taplo/releases (v{version}+)"
         ),
+        "typos": (
+            "Install via: cargo install typos-cli "
+            "or download from https://github.com/crate-ci/typos/releases (v{version}+)"
+        ),
+        "vale": (
+            "Install via: brew install vale "
+            "or download from https://github.com/errata-ai/vale/releases (v{version}+)"
+        ),
+        "terraform_fmt": (
+            "Install Terraform from "
+            "https://developer.hashicorp.com/terraform/install (v{version}+)"
+        ),
+        "tflint": (
+            "Install via: brew install tflint or download from "
+            "https://github.com/terraform-linters/tflint/releases (v{version}+)"
+        ),
         "astro_check": (
             "Install via: bun add astro@>={version} or npm install astro@>={version}"
         ),
@@ -35,22 +35,43 @@ TOOLS_WITH_SIMPLE_VERSION_PATTERN: set[ToolName] = {
     ToolName.ACTIONLINT,
     ToolName.ASTRO_CHECK,
     ToolName.BANDIT,
+    ToolName.ANSIBLE_LINT,
+    ToolName.BIOME,
+    ToolName.BUF,
     ToolName.CARGO_AUDIT,
     ToolName.CARGO_DENY,
+    ToolName.CARGO_GEIGER,
+    ToolName.CARGO_MACHETE,
+    ToolName.CARGO_OUTDATED,
+    ToolName.CHECKOV,
+    ToolName.CLANG_FORMAT,
+    ToolName.CLANG_TIDY,
+    ToolName.ESLINT,
     ToolName.GITLEAKS,
+    ToolName.GOFMT,
+    ToolName.GOLANGCI_LINT,
     ToolName.HADOLINT,
+    ToolName.KTLINT,
+    ToolName.LUACHECK,
     ToolName.OXFMT,
     ToolName.OXLINT,
     ToolName.PRETTIER,
     ToolName.PYDOCLINT,
+    ToolName.RUBOCOP,
     ToolName.RUSTC,
     ToolName.RUSTFMT,
     ToolName.SEMGREP,
     ToolName.SHELLCHECK,
     ToolName.SHFMT,
     ToolName.SQLFLUFF,
+    ToolName.STYLELINT,
+    ToolName.STYLUA,
     ToolName.SVELTE_CHECK,
     ToolName.TAPLO,
+    ToolName.TERRAFORM_FMT,
+    ToolName.TFLINT,
+    ToolName.TYPOS,
+    ToolName.VALE,
     ToolName.VUE_TSC,
 }
 
@@ -107,6 +128,9 @@ class ToolVersionInfo:
     current_version: str | None = field(default=None)
     version_check_passed: bool = field(default=False)
     error_message: str | None = field(default=None)
+    pinned_version: str | None = field(default=None)
+    pin_mismatch: bool = field(default=False)
+    not_found: bool = field(default=False)
 
 
 def parse_version(version_str: str) -> Version:
@@ -178,6 +202,49 @@ def compare_versions(version1: str, version2: str) -> int:
     return (v1 > v2) - (v1 < v2)
 
 
+def _get_configured_pin(lookup_names: list[str]) -> str | None:
+    """Return the version pin configured for any of a tool's names.
+
+    Args:
+        lookup_names: Tool names to look up, in priority order.
+
+    Returns:
+        str | None: Configured pin, or None if the tool is not pinned or the
+            config cannot be loaded.
+    """
+    from lintro.utils.version_pins import get_version_pins
+
+    try:
+        pins = get_version_pins()
+    except (OSError, ValueError) as e:
+        logger.debug(f"[VersionCheck] Could not load version pins: {e}")
+        return None
+    for name in lookup_names:
+        pin = pins.get(name.lower())
+        if pin:
+            return pin
+    return None
+
+
+def _pin_satisfied(version: str, pin: str) -> bool:
+    """Check an installed version against a pin, failing closed.
+
+    Args:
+        version: Installed version.
+        pin: Configured pin.
+
+    Returns:
+        bool: True if the version matches; False if it does not or either
+            value cannot be parsed.
+    """
+    from lintro.utils.version_pins import version_satisfies
+
+    try:
+        return version_satisfies(version, pin)
+    except ValueError:
+        return False
+
+
 def check_tool_version(tool_name: str, command: list[str]) -> ToolVersionInfo:
     """Check if a tool meets minimum version requirements.
 
@@ -278,7 +345,24 @@ def check_tool_version(tool_name: str, command: list[str]) -> ToolVersionInfo:
                 f"{info.error_message}",
             )
 
+        info.pinned_version = _get_configured_pin(lookup_names)
+        if info.pinned_version and not _pin_satisfied(
+            info.current_version,
+            info.pinned_version,
+        ):
+            info.version_check_passed = False
+            info.pin_mismatch = True
+            info.error_message = (
+                f"Version {info.current_version} does not match pinned version "
+                f"{info.pinned_version}"
+            )
+            logger.debug(
+                f"[VersionCheck] Version pin not satisfied for {tool_name}: "
+                f"{info.error_message}",
+            )
+
     except (subprocess.TimeoutExpired, OSError) as e:
+        info.not_found = isinstance(e, FileNotFoundError)
         info.error_message = f"Failed to run version check: {e}"
         logger.debug(f"[VersionCheck] Exception checking version for {tool_name}: {e}")
 
@@ -43,6 +43,7 @@ def get_all_tool_versions() -> dict[str, ToolVersionInfo]:
         "yamllint": ["yamllint"],
         "sqlfluff": ["sqlfluff"],
         "pydoclint": ["pydoclint"],
+        "ansible_lint": ["ansible-lint"],
         # Python user tools - require module-based invocation
         "mypy": ["python", "-m", "mypy"],
         "pytest": ["python", "-m", "pytest"],
@@ -53,16 +54,36 @@ def get_all_tool_versions() -> dict[str, ToolVersionInfo]:
         "hadolint": ["hadolint"],
         "actionlint": ["actionlint"],
         "taplo": ["taplo"],
+        "typos": ["typos"],
+        "vale": ["vale"],
+        "terraform_fmt": ["terraform"],
+        "tflint": ["tflint"],
+        # Go tools
+        "gofmt": ["go"],
+        "golangci_lint": ["golangci-lint"],
+        # Protobuf tools
+        "buf": ["buf"],
+        # C/C++ tools
+        "clang_format": ["clang-format"],
+        "clang_tidy": ["clang-tidy"],
         # Security tools
         "gitleaks": ["gitleaks"],
         # Rust/Cargo tools
         "clippy": ["cargo", "clippy"],
         "rustfmt": ["rustfmt"],
+        # Kotlin tools
+        "ktlint": ["ktlint"],
+        # Lua tools
+        "stylua": ["stylua"],
+        "luacheck": ["luacheck"],
+        # Ruby tools
+        "rubocop": ["rubocop"],
         # Shell tools
         "shellcheck": ["shellcheck"],
         "shfmt": ["shfmt"],
         # Security tools
         "semgrep": ["semgrep"],
+        "checkov": ["checkov"],
         "cargo_audit": ["cargo", "audit"],
     }
 
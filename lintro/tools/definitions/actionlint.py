@@ -26,6 +26,7 @@ from lintro.plugins.registry import register_tool
 ACTIONLINT_DEFAULT_TIMEOUT: int = 30
 ACTIONLINT_DEFAULT_PRIORITY: int = 40
 ACTIONLINT_FILE_PATTERNS: list[str] = ["*.yml", "*.yaml"]
+ACTIONLINT_JSON_FORMAT: str = "{{json .}}"
 
 
 @register_tool
@@ -75,16 +76,14 @@ class ActionlintPlugin(BaseToolPlugin):
     def _build_command(self) -> list[str]:
         """Build the base actionlint command.
 
-        We intentionally avoid flags here for maximum portability across
-        platforms and actionlint versions. The tool's default text output
-        follows the conventional ``file:line:col: message [CODE]`` format,
-        which our parser handles directly without requiring a custom format
-        switch.
+        Errors are requested as a JSON array so that expression errors,
+        shellcheck findings in ``run:`` scripts and invalid action inputs
+        keep their kind as the issue code.
 
         Returns:
             The base command list for invoking actionlint.
         """
-        return ["actionlint"]
+        return ["actionlint", "-format", ACTIONLINT_JSON_FORMAT]
 
     def _process_single_file(
         self,
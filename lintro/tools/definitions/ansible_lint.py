@@ -0,0 +1,294 @@
+"""ansible-lint tool definition.
+
+ansible-lint checks Ansible playbooks, roles and collections for deprecated
+syntax, risky practices and style issues. Its rules are grouped into
+profiles, from ``min`` to ``production``, selected with the ``profile``
+option. Only Ansible content should be linted, so lintro skips it in
+projects without an ansible-lint or Ansible config.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ansible_lint.ansible_lint_parser import parse_ansible_lint_output
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for ansible-lint configuration
+ANSIBLE_LINT_DEFAULT_TIMEOUT: int = 300
+ANSIBLE_LINT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("ansible_lint", 50)
+ANSIBLE_LINT_FILE_PATTERNS: list[str] = ["*.yml", "*.yaml"]
+# Config files of ansible-lint itself
+ANSIBLE_LINT_CONFIG_NAMES: tuple[str, ...] = (
+    ".ansible-lint",
+    ".ansible-lint.yml",
+    ".ansible-lint.yaml",
+    ".config/ansible-lint.yml",
+    ".config/ansible-lint.yaml",
+)
+# Files marking an Ansible project when there is no ansible-lint config
+ANSIBLE_PROJECT_MARKERS: tuple[str, ...] = ("ansible.cfg", "galaxy.yml")
+# Profiles in increasing strictness, each including the rules of the previous
+ANSIBLE_LINT_PROFILES: tuple[str, ...] = (
+    "min",
+    "basic",
+    "moderate",
+    "safety",
+    "shared",
+    "production",
+)
+
+
+def find_ansible_root(start: str | Path) -> Path | None:
+    """Find the Ansible project a directory belongs to.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        Nearest directory holding an ansible-lint config, ``ansible.cfg`` or
+        ``galaxy.yml``, or None if there is none.
+    """
+    directory = Path(start).resolve()
+    for candidate in (directory, *directory.parents):
+        for name in (*ANSIBLE_LINT_CONFIG_NAMES, *ANSIBLE_PROJECT_MARKERS):
+            if (candidate / name).is_file():
+                return candidate
+    return None
+
+
+@register_tool
+@dataclass
+class AnsibleLintPlugin(BaseToolPlugin):
+    """ansible-lint Ansible linter plugin.
+
+    This plugin runs ``ansible-lint --format=codeclimate`` from the Ansible
+    project root on the discovered YAML files.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="ansible_lint",
+            description="Linter for Ansible playbooks, roles and collections",
+            can_fix=False,
+            tool_type=ToolType.LINTER | ToolType.INFRASTRUCTURE,
+            file_patterns=ANSIBLE_LINT_FILE_PATTERNS,
+            priority=ANSIBLE_LINT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=list(ANSIBLE_LINT_CONFIG_NAMES),
+            version_command=["ansible-lint", "--version"],
+            min_version=get_min_version(ToolName.ANSIBLE_LINT),
+            default_options={
+                "timeout": ANSIBLE_LINT_DEFAULT_TIMEOUT,
+                "profile": None,
+                "config": None,
+                "offline": True,
+            },
+            default_timeout=ANSIBLE_LINT_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        profile: str | None = None,
+        config: str | None = None,
+        offline: bool | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set ansible-lint-specific options.
+
+        Args:
+            profile: Rule profile to apply (``min``, ``basic``, ``moderate``,
+                ``safety``, ``shared`` or ``production``), overriding the
+                ``profile`` of the ansible-lint config.
+            config: Path to the ansible-lint config, instead of the one
+                ansible-lint finds.
+            offline: Skip installing the roles and collections listed in
+                ``requirements.yml`` (default: True).
+            timeout: Timeout in seconds (default: 300).
+            **kwargs: Additional base options.
+
+        Raises:
+            ValueError: If profile is not an ansible-lint profile.
+        """
+        validate_str(profile, "profile")
+        validate_str(config, "config")
+        validate_bool(offline, "offline")
+        validate_positive_int(timeout, "timeout")
+        if profile is not None:
+            profile = profile.lower()
+            if profile not in ANSIBLE_LINT_PROFILES:
+                raise ValueError(
+                    f"profile must be one of {list(ANSIBLE_LINT_PROFILES)}",
+                )
+
+        options = filter_none_options(
+            profile=profile,
+            config=config,
+            offline=offline,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _create_no_project_result(self) -> ToolResult:
+        """Create a skipped result for projects without Ansible content.
+
+        Returns:
+            ToolResult: Skipped result explaining how to enable ansible-lint.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            output=(
+                "No ansible-lint config (.ansible-lint), ansible.cfg or galaxy.yml "
+                "found. Add one, or point to a config with --tool-options "
+                "ansible_lint:config=PATH."
+            ),
+            issues_count=0,
+            skipped=True,
+            skip_reason="no Ansible project",
+        )
+
+    def _build_command(self, files: list[str]) -> list[str]:
+        """Build the ansible-lint command.
+
+        Args:
+            files: Files to check, relative to the working directory.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="ansible-lint")
+        cmd.extend(["--format=codeclimate", "--nocolor"])
+        if self.options.get("offline", True):
+            cmd.append("--offline")
+        profile = self.options.get("profile")
+        if profile:
+            cmd.append(f"--profile={profile}")
+        config = self.options.get("config")
+        if config:
+            cmd.append(f"--config-file={config}")
+        cmd.extend(files)
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"ansible-lint execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options ansible_lint:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint Ansible content with ansible-lint.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Ansible files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        cwd = ctx.cwd or os.getcwd()
+        root = find_ansible_root(cwd)
+        if root is None and not self.options.get("config"):
+            return self._create_no_project_result()
+        run_dir = str(root) if root is not None else cwd
+        files = [os.path.relpath(f, run_dir) for f in ctx.files]
+
+        cmd = self._build_command(files)
+        logger.debug(
+            f"[AnsibleLintPlugin] Running: {' '.join(cmd[:8])}... (cwd={run_dir})",
+        )
+        try:
+            run_success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=run_dir,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        issues = parse_ansible_lint_output(output=output)
+        if issues is None:
+            # ansible-lint exits 2 for violations, so only fail without a report
+            message = output.strip() or "ansible-lint execution failed."
+            return ToolResult(
+                name=self.definition.name,
+                success=run_success,
+                output=None if run_success else message,
+                issues_count=0,
+            )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """ansible-lint fixes are not applied by lintro, only reported.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: ansible-lint fixing is not supported.
+        """
+        raise NotImplementedError(
+            "ansible-lint fixes cannot be applied by lintro. Run 'lintro check' to "
+            "see issues.",
+        )
@@ -0,0 +1,299 @@
+"""Asset audit tool definition.
+
+Native image and asset optimization audit. Flags images over per-format
+size thresholds, PNGs that would be smaller as WebP, and SVGs carrying
+editor metadata. Runs inside Lintro; ``lintro format`` optimizes flagged
+SVGs with svgo when it is installed.
+"""
+
+from __future__ import annotations
+
+import shutil
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.asset_audit.asset_audit_issue import AssetAuditIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_int,
+)
+from lintro.tools.implementations.asset_audit import (
+    DEFAULT_MAX_SIZE_KB,
+    DEFAULT_WEBP_MIN_SAVINGS,
+    check_asset_bytes,
+    webp_encoder_available,
+)
+
+# Constants for asset audit configuration
+ASSET_AUDIT_DEFAULT_TIMEOUT: int = 60
+ASSET_AUDIT_DEFAULT_PRIORITY: int = 30
+ASSET_AUDIT_FILE_PATTERNS: list[str] = [
+    "*.avif",
+    "*.gif",
+    "*.jpeg",
+    "*.jpg",
+    "*.png",
+    "*.svg",
+    "*.webp",
+]
+
+
+@register_tool
+@dataclass
+class AssetAuditPlugin(BaseToolPlugin):
+    """Native image and asset optimization audit plugin.
+
+    This plugin reports oversized and unoptimized images without invoking
+    any external tool, and uses svgo (if installed) to fix SVGs.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="asset_audit",
+            description="Image size, WebP and SVG metadata audit",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=ASSET_AUDIT_FILE_PATTERNS,
+            priority=ASSET_AUDIT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": ASSET_AUDIT_DEFAULT_TIMEOUT,
+                "max_size_kb": dict(DEFAULT_MAX_SIZE_KB),
+                "check_webp": True,
+                "webp_min_savings": DEFAULT_WEBP_MIN_SAVINGS,
+            },
+            default_timeout=ASSET_AUDIT_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        max_size_kb: dict[str, int] | None = None,
+        check_webp: bool | None = None,
+        webp_min_savings: int | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set asset audit options with validation.
+
+        Args:
+            max_size_kb: Size thresholds in KiB per format (``png``, ``jpg``,
+                ``gif``, ``webp``, ``avif``, ``svg``). 0 disables a format.
+            check_webp: Whether to compare PNGs against lossless WebP.
+            webp_min_savings: Minimum WebP saving in percent worth reporting.
+            **kwargs: Additional base options.
+
+        Raises:
+            ValueError: If max_size_kb is not a mapping of format to KiB.
+        """
+        if max_size_kb is not None:
+            if not isinstance(max_size_kb, dict):
+                raise ValueError("max_size_kb must be a mapping of format to KiB")
+            for fmt, limit in max_size_kb.items():
+                validate_int(limit, f"max_size_kb.{fmt}", min_value=0)
+            max_size_kb = {**DEFAULT_MAX_SIZE_KB, **max_size_kb}
+        validate_bool(check_webp, "check_webp")
+        validate_int(
+            webp_min_savings,
+            "webp_min_savings",
+            min_value=0,
+            max_value=100,
+        )
+
+        options = filter_none_options(
+            max_size_kb=max_size_kb,
+            check_webp=check_webp,
+            webp_min_savings=webp_min_savings,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _check_file(
+        self,
+        file_path: str,
+        rel_path: str,
+        options: dict[str, object],
+    ) -> list[AssetAuditIssue]:
+        """Run the asset checks with the effective options.
+
+        Args:
+            file_path: Absolute path of the image.
+            rel_path: Path used when reporting issues.
+            options: Runtime options that override defaults.
+
+        Returns:
+            list[AssetAuditIssue]: Issues found in the file.
+        """
+        merged_options = {**self.options, **options}
+        raw_limits = merged_options.get("max_size_kb")
+        limits = (
+            {str(k): int(v) for k, v in raw_limits.items()}
+            if isinstance(raw_limits, dict)
+            else None
+        )
+        raw_savings = merged_options.get("webp_min_savings")
+        with open(file_path, "rb") as f:
+            data = f.read()
+        return check_asset_bytes(
+            data,
+            rel_path,
+            max_size_kb=limits,
+            check_webp=bool(merged_options.get("check_webp", True)),
+            webp_min_savings=(
+                raw_savings
+                if isinstance(raw_savings, int)
+                else DEFAULT_WEBP_MIN_SAVINGS
+            ),
+        )
+
+    def _warn_webp_skipped(self, files: list[str], options: dict[str, object]) -> None:
+        """Warn that IA002 was skipped for lack of Pillow.
+
+        Args:
+            files: Files being audited.
+            options: Runtime options that override defaults.
+        """
+        if not {**self.options, **options}.get("check_webp", True):
+            return
+        pngs = sum(1 for file_path in files if file_path.lower().endswith(".png"))
+        if pngs and not webp_encoder_available():
+            logger.warning(
+                f"[asset_audit] Pillow not installed; skipped the WebP check "
+                f"(IA002) for {pngs} PNG file(s) (pip install 'lintro[images]')",
+            )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check images for optimization issues.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        self._warn_webp_skipped(ctx.files, options)
+        all_issues: list[AssetAuditIssue] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                all_issues.extend(self._check_file(file_path, rel_path, options))
+            except OSError as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+
+        logger.debug(
+            f"[AssetAuditPlugin] Checked {len(ctx.files)} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Optimize SVGs with svgo and report remaining issues.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with fix results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        self._warn_webp_skipped(ctx.files, options)
+        initial_issues: list[AssetAuditIssue] = []
+        errors: list[str] = []
+        svg_files: list[str] = []
+        checked: list[tuple[str, str]] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                issues = self._check_file(file_path, rel_path, options)
+            except OSError as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            checked.append((file_path, rel_path))
+            initial_issues.extend(issues)
+            if any(issue.fixable for issue in issues):
+                svg_files.append(file_path)
+
+        summary_parts: list[str] = []
+        if svg_files:
+            svgo = shutil.which("svgo")
+            if svgo is None:
+                summary_parts.append(
+                    "svgo not found; install it to optimize "
+                    f"{len(svg_files)} SVG file(s) (npm install -g svgo)",
+                )
+            else:
+                success, output = self._run_subprocess(
+                    [svgo, "--multipass", "--quiet", *svg_files],
+                )
+                if success:
+                    summary_parts.append(f"Optimized {len(svg_files)} SVG file(s)")
+                else:
+                    errors.append(f"svgo failed: {output.strip()}")
+
+        remaining_issues: list[AssetAuditIssue] = []
+        for file_path, rel_path in checked:
+            try:
+                remaining_issues.extend(
+                    self._check_file(file_path, rel_path, options),
+                )
+            except OSError as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+
+        initial_total = len(initial_issues)
+        remaining = len(remaining_issues)
+        fixed = max(initial_total - remaining, 0)
+        if remaining > 0:
+            summary_parts.append(
+                f"Found {remaining} issue(s) that could not be fixed",
+            )
+        summary_parts.extend(errors)
+
+        logger.debug(
+            f"[AssetAuditPlugin] Fix complete: initial={initial_total}, "
+            f"fixed={fixed}, remaining={remaining}",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and remaining == 0,
+            output="\n".join(summary_parts) if summary_parts else "No fixes needed.",
+            issues_count=remaining,
+            issues=remaining_issues,
+            initial_issues_count=initial_total,
+            fixed_issues_count=fixed,
+            remaining_issues_count=remaining,
+        )
@@ -21,9 +21,11 @@ from lintro.enums.bandit_levels import (
     normalize_bandit_severity_level,
 )
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.bandit.bandit_parser import parse_bandit_output
 from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.notebooks import check_notebooks
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
 from lintro.utils.config import load_bandit_config
@@ -32,6 +34,7 @@ from lintro.utils.config import load_bandit_config
 BANDIT_DEFAULT_TIMEOUT: int = 30
 BANDIT_DEFAULT_PRIORITY: int = 90  # High priority for security tool
 BANDIT_FILE_PATTERNS: list[str] = ["*.py", "*.pyi"]
+BANDIT_SHEBANG_INTERPRETERS: list[str] = ["python", "pypy"]
 BANDIT_OUTPUT_FORMAT: str = "json"
 
 
@@ -96,6 +99,7 @@ class BanditPlugin(BaseToolPlugin):
             can_fix=False,
             tool_type=ToolType.SECURITY,
             file_patterns=BANDIT_FILE_PATTERNS,
+            shebang_interpreters=BANDIT_SHEBANG_INTERPRETERS,
             priority=BANDIT_DEFAULT_PRIORITY,
             conflicts_with=[],
             native_configs=["pyproject.toml", ".bandit", "bandit.yaml"],
@@ -301,6 +305,26 @@ class BanditPlugin(BaseToolPlugin):
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
         """Check files with Bandit for security issues.
 
+        Jupyter notebooks under the paths are checked as scripts made of
+        their code cells, with issues reported by cell.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        result = self._check_files(paths, options)
+        return check_notebooks(self, paths, options, result, self._check_files)
+
+    def _check_files(
+        self,
+        paths: list[str],
+        options: dict[str, object],
+    ) -> ToolResult:
+        """Check Python files with Bandit for security issues.
+
         Args:
             paths: List of file or directory paths to check.
             options: Runtime options that override defaults.
@@ -331,7 +355,7 @@ class BanditPlugin(BaseToolPlugin):
             # Bandit outputs JSON to stdout, but stderr may contain info/warning
             # messages that would corrupt JSON parsing if combined.
             result = subprocess.run(  # nosec B603 - cmd is validated
-                cmd,
+                self._container_command(self._with_extra_args(cmd)),
                 capture_output=True,
                 text=True,
                 timeout=ctx.timeout,
@@ -355,6 +379,7 @@ class BanditPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=0,
             )
         except (OSError, ValueError, RuntimeError) as e:
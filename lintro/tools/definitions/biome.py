@@ -0,0 +1,392 @@
+"""Biome tool definition.
+
+Biome is a fast linter and formatter for JavaScript, TypeScript and JSON.
+A single ``biome check`` run covers lint rules, formatting and import
+sorting, so it can replace an ESLint and Prettier pair on large frontends.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.biome.biome_issue import BiomeIssue
+from lintro.parsers.biome.biome_parser import parse_biome_output
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_list,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for Biome configuration
+BIOME_DEFAULT_TIMEOUT: int = 60
+BIOME_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("biome", 25)
+BIOME_FILE_PATTERNS: list[str] = [
+    "*.js",
+    "*.mjs",
+    "*.cjs",
+    "*.jsx",
+    "*.ts",
+    "*.mts",
+    "*.cts",
+    "*.tsx",
+    "*.json",
+    "*.jsonc",
+]
+
+
+@register_tool
+@dataclass
+class BiomePlugin(BaseToolPlugin):
+    """Biome JavaScript/TypeScript/JSON linter and formatter plugin.
+
+    This plugin runs ``biome check`` with the JSON reporter for checks and
+    ``biome check --write`` for fixes.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="biome",
+            description="Fast linter and formatter for JavaScript, TypeScript and JSON",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=BIOME_FILE_PATTERNS,
+            priority=BIOME_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=["biome.json", "biome.jsonc"],
+            version_command=["biome", "--version"],
+            min_version=get_min_version(ToolName.BIOME),
+            default_options={
+                "timeout": BIOME_DEFAULT_TIMEOUT,
+                "unsafe": False,
+            },
+            default_timeout=BIOME_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        exclude_patterns: list[str] | None = None,
+        include_venv: bool = False,
+        timeout: int | None = None,
+        config_path: str | None = None,
+        unsafe: bool | None = None,
+        verbose_fix_output: bool | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set Biome-specific options.
+
+        Args:
+            exclude_patterns: List of patterns to exclude.
+            include_venv: Whether to include virtual environment directories.
+            timeout: Timeout in seconds (default: 60).
+            config_path: Path to a biome.json file or the directory holding
+                it (--config-path).
+            unsafe: If True, fix() also applies unsafe fixes (--unsafe).
+            verbose_fix_output: If True, include raw Biome output in fix().
+            **kwargs: Additional options (ignored for compatibility).
+        """
+        validate_list(exclude_patterns, "exclude_patterns")
+        validate_positive_int(timeout, "timeout")
+        validate_str(config_path, "config_path")
+        validate_bool(unsafe, "unsafe")
+        validate_bool(verbose_fix_output, "verbose_fix_output")
+
+        if exclude_patterns is not None:
+            self.exclude_patterns = exclude_patterns.copy()
+        self.include_venv = include_venv
+
+        options = filter_none_options(
+            timeout=timeout,
+            config_path=config_path,
+            unsafe=unsafe,
+            verbose_fix_output=verbose_fix_output,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_issues: list[BiomeIssue] | None = None,
+        initial_count: int = 0,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_issues: Optional list of issues found before timeout.
+            initial_count: Optional count of initial issues.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"Biome execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "This may indicate:\n"
+            "  - Large codebase taking too long to process\n"
+            "  - Need to increase timeout via --tool-options biome:timeout=N"
+        )
+        timeout_issue = BiomeIssue(
+            file="execution",
+            line=1,
+            column=1,
+            code="TIMEOUT",
+            message=timeout_msg,
+            severity="error",
+            fixable=False,
+        )
+        combined_issues = (initial_issues or []) + [timeout_issue]
+        remaining_count = len(combined_issues)
+        # Keep initial = fixed + remaining when nothing was counted yet
+        effective_initial = initial_count if initial_count > 0 else remaining_count
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=remaining_count,
+            issues=combined_issues,
+            initial_issues_count=effective_initial,
+            fixed_issues_count=0,
+            remaining_issues_count=remaining_count,
+        )
+
+    def _create_failure_result(self, output: str) -> ToolResult:
+        """Create a failed result for a run that reported no diagnostics.
+
+        Biome exits non-zero without a JSON report when its configuration
+        cannot be read or it is given invalid arguments.
+
+        Args:
+            output: Output of the failed run.
+
+        Returns:
+            ToolResult: Failed result carrying Biome's message.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=output.strip() or "Biome execution failed.",
+            issues_count=0,
+        )
+
+    def _build_biome_args(self, options: dict[str, object]) -> list[str]:
+        """Build CLI arguments shared by check and fix runs.
+
+        Args:
+            options: Options dict to build args from (use merged_options).
+
+        Returns:
+            List of CLI arguments to pass to ``biome check``.
+        """
+        # Explicit file lists may include files ignored by biome.json
+        args: list[str] = ["--no-errors-on-unmatched"]
+
+        config_path = options.get("config_path")
+        if config_path:
+            args.extend(["--config-path", str(config_path)])
+
+        return args
+
+    def _build_check_command(self, options: dict[str, object]) -> list[str]:
+        """Build the command that reports diagnostics as JSON.
+
+        Args:
+            options: Options dict to build args from (use merged_options).
+
+        Returns:
+            Command without file arguments.
+        """
+        # Lift the default cap of 20 diagnostics so every issue is counted
+        return (
+            self._get_executable_command(tool_name="biome")
+            + ["check", "--reporter=json", "--max-diagnostics=none"]
+            + self._build_biome_args(options)
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check files with Biome without making changes.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        merged_options = dict(self.options)
+        merged_options.update(options)
+
+        # Use shared preparation for version check, path validation, file discovery
+        ctx = self._prepare_execution(
+            paths,
+            merged_options,
+            no_files_message="No files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        logger.debug(
+            f"[BiomePlugin] Discovered {len(ctx.files)} files matching patterns: "
+            f"{self.definition.file_patterns}",
+        )
+        logger.debug(f"[BiomePlugin] Working directory: {ctx.cwd}")
+
+        cmd = self._build_check_command(merged_options) + ctx.rel_files
+        logger.debug(f"[BiomePlugin] Running: {' '.join(cmd)} (cwd={ctx.cwd})")
+
+        try:
+            run_success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        issues: list[BiomeIssue] = parse_biome_output(output=output)
+        issues_count: int = len(issues)
+        if not run_success and issues_count == 0:
+            return self._create_failure_result(output)
+        success: bool = issues_count == 0
+
+        return ToolResult(
+            name=self.definition.name,
+            success=success,
+            output=None if success else output,
+            issues_count=issues_count,
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Apply Biome's lint fixes, formatting and import sorting.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        merged_options = dict(self.options)
+        merged_options.update(options)
+
+        # Use shared preparation for version check, path validation, file discovery
+        ctx = self._prepare_execution(
+            paths,
+            merged_options,
+            no_files_message="No files to fix.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        check_cmd = self._build_check_command(merged_options) + ctx.rel_files
+
+        # Check for initial issues
+        try:
+            check_success, check_output = self._run_subprocess(
+                cmd=check_cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        initial_issues: list[BiomeIssue] = parse_biome_output(output=check_output)
+        initial_count: int = len(initial_issues)
+        if not check_success and initial_count == 0:
+            return self._create_failure_result(check_output)
+
+        # Now fix the issues
+        fix_cmd: list[str] = self._get_executable_command(tool_name="biome") + [
+            "check",
+            "--write",
+        ]
+        if merged_options.get("unsafe", False):
+            fix_cmd.append("--unsafe")
+        fix_cmd.extend(self._build_biome_args(merged_options))
+        fix_cmd.extend(ctx.rel_files)
+        logger.debug(f"[BiomePlugin] Fixing: {' '.join(fix_cmd)} (cwd={ctx.cwd})")
+
+        try:
+            _, fix_output = self._run_subprocess(
+                cmd=fix_cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_issues=initial_issues,
+                initial_count=initial_count,
+            )
+
+        # Check for remaining issues after fixing
+        try:
+            _, final_output = self._run_subprocess(
+                cmd=check_cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_issues=initial_issues,
+                initial_count=initial_count,
+            )
+
+        remaining_issues: list[BiomeIssue] = parse_biome_output(output=final_output)
+        remaining_count: int = len(remaining_issues)
+        fixed_count: int = max(0, initial_count - remaining_count)
+
+        # Build output message
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+            for issue in remaining_issues[:5]:
+                output_lines.append(f"  {issue.file} - {issue.message}")
+            if len(remaining_issues) > 5:
+                output_lines.append(f"  ... and {len(remaining_issues) - 5} more")
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        # Add verbose raw fix output only when explicitly requested
+        if merged_options.get("verbose_fix_output", False) and fix_output.strip():
+            output_lines.append(f"Fix output:\n{fix_output}")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -14,6 +14,7 @@ from typing import Any
 from loguru import logger
 
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.black.black_issue import BlackIssue
 from lintro.parsers.black.black_parser import parse_black_output
@@ -31,6 +32,7 @@ from lintro.tools.core.option_validators import (
 BLACK_DEFAULT_TIMEOUT: int = 30
 BLACK_DEFAULT_PRIORITY: int = 90  # Prefer Black ahead of Ruff formatting
 BLACK_FILE_PATTERNS: list[str] = ["*.py", "*.pyi"]
+BLACK_SHEBANG_INTERPRETERS: list[str] = ["python", "pypy"]
 
 
 @register_tool
@@ -54,6 +56,7 @@ class BlackPlugin(BaseToolPlugin):
             can_fix=True,
             tool_type=ToolType.FORMATTER,
             file_patterns=BLACK_FILE_PATTERNS,
+            shebang_interpreters=BLACK_SHEBANG_INTERPRETERS,
             priority=BLACK_DEFAULT_PRIORITY,
             conflicts_with=[],
             native_configs=["pyproject.toml"],
@@ -213,6 +216,7 @@ class BlackPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=max(initial_count, 1),
                 issues=[],
                 initial_issues_count=(
@@ -225,6 +229,7 @@ class BlackPlugin(BaseToolPlugin):
             name=self.definition.name,
             success=False,
             output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
             issues_count=1,
             issues=[],
         )
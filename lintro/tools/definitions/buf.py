@@ -0,0 +1,374 @@
+"""buf tool definition.
+
+buf is the Protobuf toolchain from Buf. lintro runs ``buf lint`` with the
+rules from the project's ``buf.yaml``, checks formatting with
+``buf format --diff`` and, when a baseline is configured through the
+``against`` option, detects breaking changes with ``buf breaking``.
+``lintro format`` rewrites the files with ``buf format -w``.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.buf.buf_issue import BufIssue
+from lintro.parsers.buf.buf_parser import parse_buf_format_output, parse_buf_output
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for buf configuration
+BUF_DEFAULT_TIMEOUT: int = 120
+BUF_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("buf", 25)
+BUF_FILE_PATTERNS: list[str] = ["*.proto"]
+BUF_MODULE_CONFIG: str = "buf.yaml"
+# Workspace config of buf v1, listing the modules below it
+BUF_WORKSPACE_CONFIG: str = "buf.work.yaml"
+
+
+def find_buf_root(start: str | Path) -> Path | None:
+    """Find the directory buf should run from for a path.
+
+    The outermost ``buf.work.yaml`` wins, so every module of a v1 workspace
+    is built together; otherwise the nearest ``buf.yaml`` is used.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        Directory holding the workspace or module config, or None if there
+        is none.
+    """
+    directory = Path(start).resolve()
+    root: Path | None = None
+    for candidate in (directory, *directory.parents):
+        if (candidate / BUF_WORKSPACE_CONFIG).is_file():
+            root = candidate
+        elif root is None and (candidate / BUF_MODULE_CONFIG).is_file():
+            root = candidate
+    return root
+
+
+@register_tool
+@dataclass
+class BufPlugin(BaseToolPlugin):
+    """buf Protobuf linter and formatter plugin.
+
+    This plugin runs buf from the directory holding ``buf.yaml`` (or the
+    checked files' directory without one), limiting every command to the
+    discovered files with ``--path``.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="buf",
+            description="Protobuf linter, formatter and breaking change detector",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=BUF_FILE_PATTERNS,
+            priority=BUF_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[BUF_MODULE_CONFIG, BUF_WORKSPACE_CONFIG],
+            version_command=["buf", "--version"],
+            min_version=get_min_version(ToolName.BUF),
+            default_options={
+                "timeout": BUF_DEFAULT_TIMEOUT,
+                "lint": True,
+                "format": True,
+                "against": None,
+            },
+            default_timeout=BUF_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        lint: bool | None = None,
+        format: bool | None = None,
+        against: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set buf-specific options.
+
+        Args:
+            lint: Run ``buf lint`` (default: True).
+            format: Check formatting with ``buf format`` (default: True).
+            against: Baseline input for ``buf breaking``, such as
+                ``.git#branch=main``; breaking changes are not checked
+                without one.
+            timeout: Timeout in seconds per buf command (default: 120).
+            **kwargs: Additional base options.
+        """
+        validate_bool(lint, "lint")
+        validate_bool(format, "format")
+        validate_str(against, "against")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(
+            lint=lint,
+            format=format,
+            against=against,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _resolve_run_dir(self, ctx: ExecutionContext) -> tuple[str, list[str]]:
+        """Choose the directory buf runs in and the files relative to it.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+
+        Returns:
+            Tuple of (working directory, files relative to it).
+        """
+        cwd = ctx.cwd or os.getcwd()
+        root = find_buf_root(cwd)
+        if root is None:
+            return cwd, ctx.rel_files
+        run_dir = str(root)
+        return run_dir, [os.path.relpath(f, run_dir) for f in ctx.files]
+
+    def _build_command(self, subcommand: list[str], files: list[str]) -> list[str]:
+        """Build a buf command limited to the given files.
+
+        Args:
+            subcommand: buf subcommand and its flags, e.g. ``["lint"]``.
+            files: Files to check, relative to the working directory.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="buf")
+        cmd.extend(subcommand)
+        for file_path in files:
+            cmd.extend(["--path", file_path])
+        return cmd
+
+    def _run_buf(
+        self,
+        subcommand: list[str],
+        files: list[str],
+        run_dir: str,
+        timeout: int,
+    ) -> tuple[bool, str]:
+        """Run a buf subcommand on the discovered files.
+
+        Args:
+            subcommand: buf subcommand and its flags.
+            files: Files to check, relative to run_dir.
+            run_dir: Directory buf runs in.
+            timeout: Timeout in seconds for the run.
+
+        Returns:
+            Tuple of (success, output).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        cmd = self._build_command(subcommand, files)
+        logger.debug(f"[BufPlugin] Running: {' '.join(cmd[:8])}... (cwd={run_dir})")
+        return self._run_subprocess(cmd=cmd, timeout=timeout, cwd=run_dir)
+
+    def _collect_issues(
+        self,
+        files: list[str],
+        run_dir: str,
+        timeout: int,
+    ) -> tuple[list[BufIssue], list[str]]:
+        """Run the enabled buf checks and collect their findings.
+
+        Args:
+            files: Files to check, relative to run_dir.
+            run_dir: Directory buf runs in.
+            timeout: Timeout in seconds per buf command.
+
+        Returns:
+            Tuple of (issues, errors of failed commands).
+
+        Raises:
+            subprocess.TimeoutExpired: If a command exceeds the timeout.
+        """
+        issues: list[BufIssue] = []
+        errors: list[str] = []
+        against = self.options.get("against")
+
+        checks: list[tuple[str, list[str]]] = []
+        if self.options.get("lint", True):
+            checks.append(("lint", ["lint", "--error-format=json"]))
+        if against:
+            checks.append(
+                (
+                    "breaking",
+                    ["breaking", f"--against={against}", "--error-format=json"],
+                ),
+            )
+        for name, subcommand in checks:
+            success, output = self._run_buf(subcommand, files, run_dir, timeout)
+            report = parse_buf_output(output, breaking=name == "breaking")
+            issues.extend(report.issues)
+            if not success and not report.issues:
+                errors.append(
+                    "\n".join(report.errors) or f"buf {name} execution failed.",
+                )
+
+        if self.options.get("format", True):
+            success, output = self._run_buf(
+                ["format", "--diff"],
+                files,
+                run_dir,
+                timeout,
+            )
+            if success:
+                issues.extend(parse_buf_format_output(output))
+            else:
+                errors.append(output.strip() or "buf format execution failed.")
+        return issues, errors
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"buf execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options buf:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint and format-check Protobuf files with buf.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        run_dir, files = self._resolve_run_dir(ctx)
+        try:
+            issues, errors = self._collect_issues(files, run_dir, ctx.timeout)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues and not errors,
+            output="\n".join(errors) if errors else None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Format Protobuf files in place with buf.
+
+        Args:
+            paths: List of file or directory paths to format.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        run_dir, files = self._resolve_run_dir(ctx)
+        try:
+            initial_issues, errors = self._collect_issues(files, run_dir, ctx.timeout)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        initial_count = len(initial_issues)
+        try:
+            if any(issue.fixable for issue in initial_issues):
+                self._run_buf(["format", "-w"], files, run_dir, ctx.timeout)
+                remaining_issues, errors = self._collect_issues(
+                    files,
+                    run_dir,
+                    ctx.timeout,
+                )
+            else:
+                remaining_issues = initial_issues
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_count=initial_count,
+            )
+
+        remaining_count = len(remaining_issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+        output_lines.extend(errors)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0 and not errors,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
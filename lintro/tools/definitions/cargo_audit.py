@@ -15,11 +15,13 @@ from typing import Any
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.cargo_audit.cargo_audit_parser import parse_cargo_audit_output
 from lintro.plugins.base import BaseToolPlugin
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import normalize_str_or_list
 
 # Constants for cargo-audit configuration
 CARGO_AUDIT_DEFAULT_TIMEOUT: int = 120  # Network operations can be slow
@@ -79,18 +81,22 @@ class CargoAuditPlugin(BaseToolPlugin):
             min_version=get_min_version(ToolName.CARGO_AUDIT),
             default_options={
                 "timeout": CARGO_AUDIT_DEFAULT_TIMEOUT,
+                "ignore": None,
             },
             default_timeout=CARGO_AUDIT_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(self, **kwargs: Any) -> None:
         """Set tool-specific options.
 
         Args:
-            **kwargs: Options to set, including timeout.
+            **kwargs: Options to set, including timeout and ignore (RustSec
+                advisory IDs to leave out of the report).
 
         Raises:
-            ValueError: If timeout is negative or not a number.
+            ValueError: If timeout is negative or not a number, or ignore is
+                not an advisory ID or a list of them.
         """
         if "timeout" in kwargs:
             timeout = kwargs["timeout"]
@@ -99,6 +105,11 @@ class CargoAuditPlugin(BaseToolPlugin):
                     raise ValueError("timeout must be a number")
                 if timeout < 0:
                     raise ValueError("timeout must be non-negative")
+        if kwargs.get("ignore") is not None:
+            ignore = normalize_str_or_list(kwargs["ignore"], "ignore") or []
+            if not all(item.strip() for item in ignore):
+                raise ValueError("ignore must be a string or list of advisory IDs")
+            kwargs["ignore"] = ignore
         super().set_options(**kwargs)
 
     def _build_command(self) -> list[str]:
@@ -107,7 +118,10 @@ class CargoAuditPlugin(BaseToolPlugin):
         Returns:
             Command list for running cargo-audit with JSON output.
         """
-        return ["cargo", "audit", "--json"]
+        cmd = ["cargo", "audit", "--json"]
+        for advisory_id in self.options.get("ignore") or []:
+            cmd.extend(["--ignore", str(advisory_id).strip()])
+        return cmd
 
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
         """Check Rust dependencies for security vulnerabilities.
@@ -146,6 +160,7 @@ class CargoAuditPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=False,
                 output=f"cargo-audit timed out after {ctx.timeout}s",
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=0,
             )
 
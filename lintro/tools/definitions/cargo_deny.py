@@ -2,7 +2,8 @@
 
 cargo-deny is a Rust tool that checks licenses, advisories, bans, and duplicate
 dependencies in Cargo projects. It requires a Cargo.toml file and optionally
-uses deny.toml for configuration.
+uses deny.toml for configuration. The ``checks`` option limits which of its
+checks run.
 """
 
 from __future__ import annotations
@@ -15,12 +16,16 @@ from typing import Any
 
 from lintro.enums.tool_type import ToolType
 from lintro.models.core.tool_result import ToolResult
-from lintro.parsers.cargo_deny.cargo_deny_parser import parse_cargo_deny_output
+from lintro.parsers.cargo_deny.cargo_deny_parser import (
+    CARGO_DENY_CHECKS,
+    parse_cargo_deny_output,
+)
 from lintro.plugins.base import BaseToolPlugin
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
 from lintro.tools.core.option_validators import (
     filter_none_options,
+    normalize_str_or_list,
     validate_positive_int,
 )
 from lintro.tools.core.timeout_utils import (
@@ -73,9 +78,12 @@ def _find_cargo_root(paths: list[str]) -> Path | None:
     return common if manifest.exists() else None
 
 
-def _build_cargo_deny_command() -> list[str]:
+def _build_cargo_deny_command(checks: list[str] | None = None) -> list[str]:
     """Build the cargo deny check command.
 
+    Args:
+        checks: Checks to run; all of them when None or empty.
+
     Returns:
         List of command arguments.
     """
@@ -85,6 +93,7 @@ def _build_cargo_deny_command() -> list[str]:
         "check",
         "--format",
         "json",
+        *(checks or []),
     ]
 
 
@@ -120,24 +129,41 @@ class CargoDenyPlugin(BaseToolPlugin):
             min_version="0.14.0",
             default_options={
                 "timeout": CARGO_DENY_DEFAULT_TIMEOUT,
+                "checks": None,
             },
             default_timeout=CARGO_DENY_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
         self,
         timeout: int | None = None,
+        checks: str | list[str] | None = None,
         **kwargs: Any,
     ) -> None:
         """Set cargo-deny-specific options.
 
         Args:
             timeout: Timeout in seconds (default: 60).
+            checks: Checks to run, any of advisories, bans, licenses and
+                sources (default: all).
             **kwargs: Additional options.
+
+        Raises:
+            ValueError: If checks names an unknown check.
         """
         validate_positive_int(timeout, "timeout")
-
-        options = filter_none_options(timeout=timeout)
+        checks_list = normalize_str_or_list(checks, "checks")
+        if checks_list is not None:
+            checks_list = [check.strip().lower() for check in checks_list]
+            unknown = [check for check in checks_list if check not in CARGO_DENY_CHECKS]
+            if unknown:
+                raise ValueError(
+                    f"Unknown cargo-deny check(s): {', '.join(unknown)}; "
+                    f"expected any of {', '.join(CARGO_DENY_CHECKS)}",
+                )
+
+        options = filter_none_options(timeout=timeout, checks=checks_list)
         super().set_options(**options, **kwargs)
 
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
@@ -168,7 +194,8 @@ class CargoDenyPlugin(BaseToolPlugin):
                 issues_count=0,
             )
 
-        cmd = _build_cargo_deny_command()
+        checks = self.options.get("checks")
+        cmd = _build_cargo_deny_command(checks if isinstance(checks, list) else None)
 
         try:
             _, output = run_subprocess_with_timeout(
@@ -189,11 +216,12 @@ class CargoDenyPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
             )
 
-        issues = parse_cargo_deny_output(output=output)
+        issues = parse_cargo_deny_output(output=output, cargo_root=cargo_root)
         issues_count = len(issues)
 
         # cargo-deny returns non-zero on any issues found
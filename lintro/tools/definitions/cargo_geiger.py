@@ -0,0 +1,279 @@
+"""cargo-geiger tool definition.
+
+cargo-geiger counts the unsafe functions, expressions, impls, traits and
+methods each crate in the dependency tree uses. Every crate using unsafe
+code is reported as an informational issue; an optional budget caps the
+unsafe code the project may use and fails the run when it is exceeded.
+cargo-geiger builds the project to find the code that is compiled, so the
+tool is opt-in.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.cargo_geiger.cargo_geiger_parser import (
+    budget_issue,
+    parse_cargo_geiger_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
+    validate_int,
+    validate_positive_int,
+)
+from lintro.tools.core.timeout_utils import (
+    create_timeout_result,
+    run_subprocess_with_timeout,
+)
+
+# Constants for cargo-geiger configuration
+CARGO_GEIGER_DEFAULT_TIMEOUT: int = 600  # Builds the whole dependency tree
+CARGO_GEIGER_DEFAULT_PRIORITY: int = 95
+CARGO_GEIGER_FILE_PATTERNS: list[str] = ["*.rs", "Cargo.toml"]
+
+
+def _find_cargo_root(paths: list[str]) -> Path | None:
+    """Return the nearest directory containing Cargo.toml for given paths.
+
+    Args:
+        paths: List of file paths to search from.
+
+    Returns:
+        Path to Cargo.toml directory, or None if not found.
+    """
+    roots: list[Path] = []
+    for raw_path in paths:
+        current = Path(raw_path).resolve()
+        if current.is_file():
+            current = current.parent
+        for candidate in [current, *list(current.parents)]:
+            if (candidate / "Cargo.toml").exists():
+                roots.append(candidate)
+                break
+
+    if not roots:
+        return None
+
+    unique_roots = set(roots)
+    if len(unique_roots) == 1:
+        return roots[0]
+
+    try:
+        common = Path(os.path.commonpath([str(r) for r in unique_roots]))
+    except ValueError:
+        return None
+    return common if (common / "Cargo.toml").exists() else None
+
+
+@register_tool
+@dataclass
+class CargoGeigerPlugin(BaseToolPlugin):
+    """cargo-geiger unsafe usage reporter plugin.
+
+    Reports the unsafe code used by every crate as info issues. With
+    ``max_unsafe`` set, using more unsafe items than the budget adds an
+    error issue; only the project's own crates count unless
+    ``count_dependencies`` is enabled.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="cargo_geiger",
+            description="Reports unsafe Rust usage per crate against a budget",
+            can_fix=False,
+            tool_type=ToolType.SECURITY,
+            file_patterns=CARGO_GEIGER_FILE_PATTERNS,
+            priority=CARGO_GEIGER_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=["Cargo.toml"],
+            version_command=["cargo", "geiger", "--version"],
+            min_version=get_min_version(ToolName.CARGO_GEIGER),
+            default_options={
+                "timeout": CARGO_GEIGER_DEFAULT_TIMEOUT,
+                "max_unsafe": None,
+                "count_dependencies": False,
+                "all_features": False,
+                "features": None,
+                "include_tests": False,
+            },
+            default_timeout=CARGO_GEIGER_DEFAULT_TIMEOUT,
+            enabled_by_default=False,
+            # Usage is informational; only an exceeded budget fails the run
+            default_fail_on="error",
+            per_file=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        timeout: int | None = None,
+        max_unsafe: int | None = None,
+        count_dependencies: bool | None = None,
+        all_features: bool | None = None,
+        features: str | list[str] | None = None,
+        include_tests: bool | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set cargo-geiger-specific options.
+
+        Args:
+            timeout: Timeout in seconds (default: 600).
+            max_unsafe: Unsafe items the project may use; None disables the
+                budget.
+            count_dependencies: Count dependencies towards the budget
+                (default: False).
+            all_features: Build with every feature enabled (default: False).
+            features: Features to build with.
+            include_tests: Also scan tests (default: False).
+            **kwargs: Additional options.
+        """
+        validate_positive_int(timeout, "timeout")
+        validate_int(max_unsafe, "max_unsafe", min_value=0)
+        validate_bool(count_dependencies, "count_dependencies")
+        validate_bool(all_features, "all_features")
+        features_list = normalize_str_or_list(features, "features")
+        validate_bool(include_tests, "include_tests")
+
+        options = filter_none_options(
+            timeout=timeout,
+            max_unsafe=max_unsafe,
+            count_dependencies=count_dependencies,
+            all_features=all_features,
+            features=features_list,
+            include_tests=include_tests,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self) -> list[str]:
+        """Build the cargo geiger command.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = ["cargo", "geiger", "--output-format", "Json"]
+        if self.options.get("all_features"):
+            cmd.append("--all-features")
+        features = self.options.get("features")
+        if isinstance(features, list) and features:
+            cmd.extend(["--features", ",".join(features)])
+        if self.options.get("include_tests"):
+            cmd.append("--include-tests")
+        return cmd
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Run `cargo geiger` and report unsafe usage.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Rust files found to check.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        cargo_root = _find_cargo_root(ctx.files)
+        if cargo_root is None:
+            return ToolResult(
+                name=self.definition.name,
+                success=True,
+                output="No Cargo.toml found; skipping cargo-geiger.",
+                issues_count=0,
+            )
+
+        cmd = self._build_command()
+        try:
+            success, output = run_subprocess_with_timeout(
+                tool=self,
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=str(cargo_root),
+                tool_name="cargo-geiger",
+            )
+        except subprocess.TimeoutExpired:
+            timeout_result = create_timeout_result(
+                tool=self,
+                timeout=ctx.timeout,
+                cmd=cmd,
+                tool_name="cargo-geiger",
+            )
+            return ToolResult(
+                name=self.definition.name,
+                success=timeout_result.success,
+                output=timeout_result.output,
+                error_code=timeout_result.error_code,
+                issues_count=timeout_result.issues_count,
+                issues=timeout_result.issues,
+            )
+
+        issues = parse_cargo_geiger_output(output, cargo_root=cargo_root)
+        if not success and not issues:
+            # No report: the project failed to build or resolve
+            return ToolResult(
+                name=self.definition.name,
+                success=False,
+                output=output,
+                issues_count=0,
+            )
+
+        max_unsafe = self.options.get("max_unsafe")
+        over_budget = (
+            budget_issue(
+                issues,
+                max_unsafe,
+                count_dependencies=bool(self.options.get("count_dependencies")),
+                cargo_root=cargo_root,
+            )
+            if isinstance(max_unsafe, int)
+            else None
+        )
+        if over_budget is not None:
+            issues.append(over_budget)
+        return ToolResult(
+            name=self.definition.name,
+            success=over_budget is None,
+            issues_count=len(issues),
+            issues=issues or None,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Cargo-geiger cannot fix issues.
+
+        Args:
+            paths: List of file or directory paths (unused).
+            options: Runtime options (unused).
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: Always; unsafe code must be rewritten by hand.
+        """
+        raise NotImplementedError(
+            "cargo-geiger cannot fix issues; replace unsafe code or "
+            "dependencies that use it by hand.",
+        )
@@ -0,0 +1,300 @@
+"""cargo-machete tool definition.
+
+cargo-machete finds dependencies declared in Cargo.toml that no source file
+uses. It reads the sources directly, so it is fast and needs no build; with
+``with_metadata`` it asks ``cargo metadata`` for more accurate crate names at
+the cost of speed. Unused dependencies can be removed with ``lintro format``.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.cargo_machete.cargo_machete_issue import CargoMacheteIssue
+from lintro.parsers.cargo_machete.cargo_machete_parser import (
+    parse_cargo_machete_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_positive_int,
+)
+from lintro.tools.core.timeout_utils import (
+    create_timeout_result,
+    run_subprocess_with_timeout,
+)
+
+# Constants for cargo-machete configuration
+CARGO_MACHETE_DEFAULT_TIMEOUT: int = 60
+CARGO_MACHETE_DEFAULT_PRIORITY: int = 85
+CARGO_MACHETE_FILE_PATTERNS: list[str] = ["Cargo.toml"]
+
+
+def _find_cargo_root(paths: list[str]) -> Path | None:
+    """Return the nearest directory containing Cargo.toml for given paths.
+
+    Args:
+        paths: List of file paths to search from.
+
+    Returns:
+        Path to Cargo.toml directory, or None if not found.
+    """
+    roots: list[Path] = []
+    for raw_path in paths:
+        current = Path(raw_path).resolve()
+        if current.is_file():
+            current = current.parent
+        for candidate in [current, *list(current.parents)]:
+            if (candidate / "Cargo.toml").exists():
+                roots.append(candidate)
+                break
+
+    if not roots:
+        return None
+
+    unique_roots = set(roots)
+    if len(unique_roots) == 1:
+        return roots[0]
+
+    try:
+        common = Path(os.path.commonpath([str(r) for r in unique_roots]))
+    except ValueError:
+        return None
+    return common if (common / "Cargo.toml").exists() else None
+
+
+@register_tool
+@dataclass
+class CargoMachetePlugin(BaseToolPlugin):
+    """cargo-machete unused dependency detector plugin.
+
+    Reports every unused dependency on its line in Cargo.toml and removes
+    them with ``cargo machete --fix``.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="cargo_machete",
+            description="Finds and removes unused Rust dependencies",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.INFRASTRUCTURE,
+            file_patterns=CARGO_MACHETE_FILE_PATTERNS,
+            priority=CARGO_MACHETE_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=["Cargo.toml"],
+            version_command=["cargo", "machete", "--version"],
+            min_version=get_min_version(ToolName.CARGO_MACHETE),
+            default_options={
+                "timeout": CARGO_MACHETE_DEFAULT_TIMEOUT,
+                "with_metadata": False,
+            },
+            default_timeout=CARGO_MACHETE_DEFAULT_TIMEOUT,
+            per_file=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        timeout: int | None = None,
+        with_metadata: bool | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set cargo-machete-specific options.
+
+        Args:
+            timeout: Timeout in seconds (default: 60).
+            with_metadata: Use ``cargo metadata`` to resolve crate names;
+                slower but fewer false positives (default: False).
+            **kwargs: Additional options.
+        """
+        validate_positive_int(timeout, "timeout")
+        validate_bool(with_metadata, "with_metadata")
+
+        options = filter_none_options(timeout=timeout, with_metadata=with_metadata)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, fix: bool = False) -> list[str]:
+        """Build the cargo machete command.
+
+        Args:
+            fix: Remove the unused dependencies instead of only listing them.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = ["cargo", "machete"]
+        if self.options.get("with_metadata"):
+            cmd.append("--with-metadata")
+        if fix:
+            cmd.append("--fix")
+        return cmd
+
+    def _run_machete(
+        self,
+        cargo_root: Path,
+        timeout: int,
+        fix: bool = False,
+    ) -> tuple[bool, str]:
+        """Run cargo machete in a Cargo project.
+
+        Args:
+            cargo_root: Directory containing Cargo.toml.
+            timeout: Timeout in seconds.
+            fix: Remove the unused dependencies.
+
+        Returns:
+            tuple[bool, str]: Whether it exited cleanly, and its output.
+        """
+        return run_subprocess_with_timeout(
+            tool=self,
+            cmd=self._build_command(fix=fix),
+            timeout=timeout,
+            cwd=str(cargo_root),
+            tool_name="cargo-machete",
+        )
+
+    def _timeout_result(self, timeout: int) -> ToolResult:
+        """Build the result of a run that timed out.
+
+        Args:
+            timeout: Timeout that was exceeded.
+
+        Returns:
+            ToolResult describing the timeout.
+        """
+        timeout_result = create_timeout_result(
+            tool=self,
+            timeout=timeout,
+            cmd=self._build_command(),
+            tool_name="cargo-machete",
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=timeout_result.success,
+            output=timeout_result.output,
+            error_code=timeout_result.error_code,
+            issues_count=timeout_result.issues_count,
+            issues=timeout_result.issues,
+        )
+
+    def _prepare(
+        self,
+        paths: list[str],
+        options: dict[str, object],
+    ) -> tuple[Path | None, int, ToolResult | None]:
+        """Find the Cargo project to run in.
+
+        Args:
+            paths: List of file or directory paths.
+            options: Runtime options that override defaults.
+
+        Returns:
+            tuple[Path | None, int, ToolResult | None]: Project root, timeout
+            and, when there is nothing to run, the result to return instead.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Cargo files found to check.",
+        )
+        if ctx.should_skip:
+            return None, ctx.timeout, ctx.early_result
+        cargo_root = _find_cargo_root(ctx.files)
+        if cargo_root is None:
+            return (
+                None,
+                ctx.timeout,
+                ToolResult(
+                    name=self.definition.name,
+                    success=True,
+                    output="No Cargo.toml found; skipping cargo-machete.",
+                    issues_count=0,
+                ),
+            )
+        return cargo_root, ctx.timeout, None
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Run `cargo machete` and report unused dependencies.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        cargo_root, timeout, early_result = self._prepare(paths, options)
+        if cargo_root is None:
+            return early_result  # type: ignore[return-value]
+
+        try:
+            success, output = self._run_machete(cargo_root, timeout)
+        except subprocess.TimeoutExpired:
+            return self._timeout_result(timeout)
+
+        issues = parse_cargo_machete_output(output, cargo_root=cargo_root)
+        # cargo-machete exits with 1 when it finds unused dependencies; a
+        # failure without any parsed issue is an execution error
+        return ToolResult(
+            name=self.definition.name,
+            success=success and not issues,
+            output=output if issues or not success else None,
+            issues_count=len(issues),
+            issues=issues or None,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Remove unused dependencies with `cargo machete --fix`.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with the dependencies removed and those left over.
+        """
+        cargo_root, timeout, early_result = self._prepare(paths, options)
+        if cargo_root is None:
+            return early_result  # type: ignore[return-value]
+
+        try:
+            _, before = self._run_machete(cargo_root, timeout)
+            initial = parse_cargo_machete_output(before, cargo_root=cargo_root)
+            if initial:
+                self._run_machete(cargo_root, timeout, fix=True)
+                success, after = self._run_machete(cargo_root, timeout)
+            else:
+                success, after = True, before
+        except subprocess.TimeoutExpired:
+            return self._timeout_result(timeout)
+
+        remaining: list[CargoMacheteIssue] = (
+            parse_cargo_machete_output(after, cargo_root=cargo_root) if initial else []
+        )
+        fixed = max(0, len(initial) - len(remaining))
+        return ToolResult(
+            name=self.definition.name,
+            success=success and not remaining,
+            output=f"Removed {fixed} unused dependencies" if fixed else None,
+            issues_count=len(remaining),
+            issues=remaining or None,
+            initial_issues_count=len(initial),
+            fixed_issues_count=fixed,
+            remaining_issues_count=len(remaining),
+        )
@@ -0,0 +1,279 @@
+"""cargo-outdated tool definition.
+
+cargo-outdated lists Rust dependencies with newer releases on crates.io. It
+needs the registry index, so it is slow and its results change without any
+code change; the tool is therefore opt-in and suited to scheduled reports
+rather than every check run. How far behind a dependency may fall before it
+is a warning or an error is measured in breaking releases.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.cargo_outdated.cargo_outdated_parser import (
+    DEFAULT_ERROR_MAJORS,
+    DEFAULT_WARNING_MAJORS,
+    parse_cargo_outdated_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
+    validate_positive_int,
+)
+from lintro.tools.core.timeout_utils import (
+    create_timeout_result,
+    run_subprocess_with_timeout,
+)
+
+# Constants for cargo-outdated configuration
+CARGO_OUTDATED_DEFAULT_TIMEOUT: int = 180  # Fetches the registry index
+CARGO_OUTDATED_DEFAULT_PRIORITY: int = 95
+CARGO_OUTDATED_FILE_PATTERNS: list[str] = ["Cargo.toml"]
+
+
+def _find_cargo_root(paths: list[str]) -> Path | None:
+    """Return the nearest directory containing Cargo.toml for given paths.
+
+    Args:
+        paths: List of file paths to search from.
+
+    Returns:
+        Path to Cargo.toml directory, or None if not found.
+    """
+    roots: list[Path] = []
+    for raw_path in paths:
+        current = Path(raw_path).resolve()
+        if current.is_file():
+            current = current.parent
+        for candidate in [current, *list(current.parents)]:
+            if (candidate / "Cargo.toml").exists():
+                roots.append(candidate)
+                break
+
+    if not roots:
+        return None
+
+    unique_roots = set(roots)
+    if len(unique_roots) == 1:
+        return roots[0]
+
+    try:
+        common = Path(os.path.commonpath([str(r) for r in unique_roots]))
+    except ValueError:
+        return None
+    return common if (common / "Cargo.toml").exists() else None
+
+
+@register_tool
+@dataclass
+class CargoOutdatedPlugin(BaseToolPlugin):
+    """cargo-outdated stale dependency reporter plugin.
+
+    Reports every dependency with a newer release on its line in
+    Cargo.toml. Dependencies ``warning_majors`` breaking releases behind are
+    warnings and ``error_majors`` behind are errors; the rest are info.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="cargo_outdated",
+            description="Reports Rust dependencies behind their latest release",
+            can_fix=False,
+            tool_type=ToolType.INFRASTRUCTURE,
+            file_patterns=CARGO_OUTDATED_FILE_PATTERNS,
+            priority=CARGO_OUTDATED_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=["Cargo.toml"],
+            version_command=["cargo", "outdated", "--version"],
+            min_version=get_min_version(ToolName.CARGO_OUTDATED),
+            default_options={
+                "timeout": CARGO_OUTDATED_DEFAULT_TIMEOUT,
+                "root_deps_only": True,
+                "workspace": False,
+                "ignore": None,
+                "warning_majors": DEFAULT_WARNING_MAJORS,
+                "error_majors": DEFAULT_ERROR_MAJORS,
+            },
+            default_timeout=CARGO_OUTDATED_DEFAULT_TIMEOUT,
+            enabled_by_default=False,
+            per_file=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        timeout: int | None = None,
+        root_deps_only: bool | None = None,
+        workspace: bool | None = None,
+        ignore: str | list[str] | None = None,
+        warning_majors: int | None = None,
+        error_majors: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set cargo-outdated-specific options.
+
+        Args:
+            timeout: Timeout in seconds (default: 180).
+            root_deps_only: Only report direct dependencies (default: True).
+            workspace: Report every member of a workspace (default: False).
+            ignore: Dependencies never reported.
+            warning_majors: Breaking releases behind from which a dependency
+                is a warning (default: 1).
+            error_majors: Breaking releases behind from which a dependency
+                is an error (default: 2).
+            **kwargs: Additional options.
+
+        Raises:
+            ValueError: If error_majors is lower than warning_majors.
+        """
+        validate_positive_int(timeout, "timeout")
+        validate_bool(root_deps_only, "root_deps_only")
+        validate_bool(workspace, "workspace")
+        ignore_list = normalize_str_or_list(ignore, "ignore")
+        validate_positive_int(warning_majors, "warning_majors")
+        validate_positive_int(error_majors, "error_majors")
+        warn = warning_majors or self.options.get("warning_majors")
+        error = error_majors or self.options.get("error_majors")
+        if isinstance(warn, int) and isinstance(error, int) and error < warn:
+            raise ValueError(
+                f"error_majors ({error}) must not be lower than "
+                f"warning_majors ({warn})",
+            )
+
+        options = filter_none_options(
+            timeout=timeout,
+            root_deps_only=root_deps_only,
+            workspace=workspace,
+            ignore=ignore_list,
+            warning_majors=warning_majors,
+            error_majors=error_majors,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self) -> list[str]:
+        """Build the cargo outdated command.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = ["cargo", "outdated", "--format", "json"]
+        if self.options.get("root_deps_only", True):
+            cmd.append("--root-deps-only")
+        if self.options.get("workspace"):
+            cmd.append("--workspace")
+        ignore = self.options.get("ignore")
+        if isinstance(ignore, list) and ignore:
+            cmd.extend(["--ignore", ",".join(ignore)])
+        return cmd
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Run `cargo outdated` and report stale dependencies.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Cargo files found to check.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        cargo_root = _find_cargo_root(ctx.files)
+        if cargo_root is None:
+            return ToolResult(
+                name=self.definition.name,
+                success=True,
+                output="No Cargo.toml found; skipping cargo-outdated.",
+                issues_count=0,
+            )
+
+        cmd = self._build_command()
+        try:
+            success, output = run_subprocess_with_timeout(
+                tool=self,
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=str(cargo_root),
+                tool_name="cargo-outdated",
+            )
+        except subprocess.TimeoutExpired:
+            timeout_result = create_timeout_result(
+                tool=self,
+                timeout=ctx.timeout,
+                cmd=cmd,
+                tool_name="cargo-outdated",
+            )
+            return ToolResult(
+                name=self.definition.name,
+                success=timeout_result.success,
+                output=timeout_result.output,
+                error_code=timeout_result.error_code,
+                issues_count=timeout_result.issues_count,
+                issues=timeout_result.issues,
+            )
+
+        warning_majors = self.options.get("warning_majors", DEFAULT_WARNING_MAJORS)
+        error_majors = self.options.get("error_majors", DEFAULT_ERROR_MAJORS)
+        issues = parse_cargo_outdated_output(
+            output,
+            cargo_root=cargo_root,
+            warning_majors=(
+                warning_majors
+                if isinstance(warning_majors, int)
+                else DEFAULT_WARNING_MAJORS
+            ),
+            error_majors=(
+                error_majors if isinstance(error_majors, int) else DEFAULT_ERROR_MAJORS
+            ),
+        )
+        # cargo-outdated exits 0 when dependencies are outdated; a failure
+        # means it could not resolve the project or reach the registry
+        return ToolResult(
+            name=self.definition.name,
+            success=success and not issues,
+            output=output if not success else None,
+            issues_count=len(issues),
+            issues=issues or None,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Cargo-outdated cannot fix issues.
+
+        Args:
+            paths: List of file or directory paths (unused).
+            options: Runtime options (unused).
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: Always; upgrades need ``cargo upgrade``.
+        """
+        raise NotImplementedError(
+            "cargo-outdated cannot fix issues; upgrade dependencies with "
+            "`cargo upgrade` or by editing Cargo.toml.",
+        )
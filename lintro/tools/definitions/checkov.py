@@ -0,0 +1,235 @@
+"""Checkov tool definition.
+
+Checkov scans infrastructure as code for security and compliance
+misconfigurations, such as public S3 buckets, containers running as root or
+Dockerfiles without a ``USER``. Lintro runs it on Terraform, Kubernetes and
+Dockerfile sources by default. Its policy set is large and mostly relevant
+to deployed infrastructure, so the tool is opt-in.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.checkov.checkov_parser import parse_checkov_output
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_positive_int,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for Checkov configuration
+CHECKOV_DEFAULT_TIMEOUT: int = 300
+CHECKOV_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("checkov", 50)
+CHECKOV_DEFAULT_FRAMEWORKS: list[str] = ["terraform", "kubernetes", "dockerfile"]
+CHECKOV_FILE_PATTERNS: list[str] = [
+    "*.tf",
+    "*.tf.json",
+    "*.yaml",
+    "*.yml",
+    "Dockerfile",
+    "Dockerfile.*",
+    "*.Dockerfile",
+]
+
+
+@register_tool
+@dataclass
+class CheckovPlugin(BaseToolPlugin):
+    """Checkov infrastructure-as-code scanner plugin.
+
+    This plugin runs ``checkov -o json`` on the discovered files and reports
+    every failed check with its check ID and remediation guideline.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="checkov",
+            description="Infrastructure-as-code misconfiguration scanner",
+            can_fix=False,
+            tool_type=ToolType.SECURITY | ToolType.INFRASTRUCTURE,
+            file_patterns=CHECKOV_FILE_PATTERNS,
+            priority=CHECKOV_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".checkov.yaml", ".checkov.yml"],
+            version_command=["checkov", "--version"],
+            min_version=get_min_version(ToolName.CHECKOV),
+            default_options={
+                "timeout": CHECKOV_DEFAULT_TIMEOUT,
+                "frameworks": CHECKOV_DEFAULT_FRAMEWORKS,
+                "checks": None,
+                "skip_checks": None,
+            },
+            default_timeout=CHECKOV_DEFAULT_TIMEOUT,
+            enabled_by_default=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        frameworks: list[str] | str | None = None,
+        checks: list[str] | str | None = None,
+        skip_checks: list[str] | str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set Checkov-specific options.
+
+        Args:
+            frameworks: Checkov frameworks to run (default: terraform,
+                kubernetes and dockerfile).
+            checks: Check IDs to run, skipping all others (``--check``).
+            skip_checks: Check IDs to skip (``--skip-check``).
+            timeout: Timeout in seconds (default: 300).
+            **kwargs: Additional base options.
+        """
+        frameworks = normalize_str_or_list(frameworks, "frameworks")
+        checks = normalize_str_or_list(checks, "checks")
+        skip_checks = normalize_str_or_list(skip_checks, "skip_checks")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(
+            frameworks=frameworks,
+            checks=checks,
+            skip_checks=skip_checks,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _list_option(self, name: str) -> list[str]:
+        """Read a list option.
+
+        Args:
+            name: Option name.
+
+        Returns:
+            list[str]: The option's values, empty when unset.
+        """
+        value = self.options.get(name)
+        return [str(v) for v in value] if isinstance(value, list) else []
+
+    def _build_command(self, files: list[str]) -> list[str]:
+        """Build the Checkov command.
+
+        Args:
+            files: Files to scan, relative to the working directory.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="checkov")
+        cmd.extend(["-o", "json", "--quiet", "--compact"])
+        frameworks = self._list_option("frameworks")
+        if frameworks:
+            cmd.extend(["--framework", *frameworks])
+        checks = self._list_option("checks")
+        if checks:
+            cmd.extend(["--check", ",".join(checks)])
+        skip_checks = self._list_option("skip_checks")
+        if skip_checks:
+            cmd.extend(["--skip-check", ",".join(skip_checks)])
+        for file_path in files:
+            cmd.extend(["-f", file_path])
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"Checkov execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options checkov:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Scan infrastructure-as-code files with Checkov.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        cmd = self._build_command(ctx.rel_files)
+        logger.debug(f"[CheckovPlugin] Running: {' '.join(cmd[:8])}... (cwd={ctx.cwd})")
+        try:
+            run_success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        report = parse_checkov_output(output=output)
+        if report is None:
+            # Checkov exits 1 for failed checks, so only fail without a report
+            message = output.strip() or "Checkov execution failed."
+            return ToolResult(
+                name=self.definition.name,
+                success=run_success,
+                output=None if run_success else message,
+                issues_count=0,
+            )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not report.issues and not report.errors,
+            output="\n".join(report.errors) if report.errors else None,
+            issues_count=len(report.issues),
+            issues=report.issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Checkov cannot fix issues, only report them.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: Checkov does not support fixing issues.
+        """
+        raise NotImplementedError(
+            "Checkov cannot automatically fix issues. Run 'lintro check' to see "
+            "issues.",
+        )
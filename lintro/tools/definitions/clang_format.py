@@ -0,0 +1,355 @@
+"""clang-format tool definition.
+
+clang-format formats C, C++ and Objective-C sources according to the
+nearest ``.clang-format`` file or a named style. Checks run it on each file
+without ``-i`` and diff its output against the file, so every changed
+region becomes an issue with a preview of the change; ``lintro format``
+lets it rewrite the files in place.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.clang_format.clang_format_issue import ClangFormatIssue
+from lintro.parsers.clang_format.clang_format_parser import (
+    parse_clang_format_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.file_processor import FileProcessingResult
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for clang-format configuration
+CLANG_FORMAT_DEFAULT_TIMEOUT: int = 30
+CLANG_FORMAT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get(
+    "clang_format",
+    25,
+)
+CLANG_FORMAT_FILE_PATTERNS: list[str] = [
+    "*.c",
+    "*.cc",
+    "*.cpp",
+    "*.cxx",
+    "*.c++",
+    "*.h",
+    "*.hh",
+    "*.hpp",
+    "*.hxx",
+    "*.inl",
+    "*.ipp",
+]
+
+
+@register_tool
+@dataclass
+class ClangFormatPlugin(BaseToolPlugin):
+    """clang-format C/C++ formatter plugin.
+
+    This plugin runs clang-format once per file, reporting the regions its
+    output changes in check mode and formatting files with ``-i`` in fix
+    mode.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="clang_format",
+            description="Formatter for C and C++ sources and headers",
+            can_fix=True,
+            tool_type=ToolType.FORMATTER,
+            file_patterns=CLANG_FORMAT_FILE_PATTERNS,
+            priority=CLANG_FORMAT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".clang-format", "_clang-format"],
+            version_command=["clang-format", "--version"],
+            min_version=get_min_version(ToolName.CLANG_FORMAT),
+            default_options={
+                "timeout": CLANG_FORMAT_DEFAULT_TIMEOUT,
+                "style": None,
+            },
+            default_timeout=CLANG_FORMAT_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        style: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set clang-format-specific options.
+
+        Args:
+            style: Style passed as ``--style``, such as ``file``, ``LLVM`` or
+                ``Google`` (default: clang-format's own, ``file``).
+            timeout: Timeout in seconds per file (default: 30).
+            **kwargs: Additional base options.
+        """
+        validate_str(style, "style")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(style=style, timeout=timeout)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, file_path: str, in_place: bool = False) -> list[str]:
+        """Build the clang-format command for one file.
+
+        Args:
+            file_path: File to format.
+            in_place: Whether to rewrite the file instead of printing it.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="clang-format")
+        style = self.options.get("style")
+        if style:
+            cmd.append(f"--style={style}")
+        if in_place:
+            cmd.append("-i")
+        cmd.append(file_path)
+        return cmd
+
+    def _check_file(self, file_path: str, timeout: int) -> list[ClangFormatIssue]:
+        """Diff one file against clang-format's output.
+
+        Args:
+            file_path: File to check.
+            timeout: Timeout in seconds for the clang-format command.
+
+        Returns:
+            Issues for the regions clang-format would change.
+
+        Raises:
+            RuntimeError: If clang-format fails on the file.
+        """
+        with open(file_path, encoding="utf-8", errors="replace") as handle:
+            original = handle.read()
+        success, output = self._run_subprocess(
+            cmd=self._build_command(file_path),
+            timeout=timeout,
+        )
+        if not success:
+            raise RuntimeError(output.strip() or "clang-format failed")
+        return parse_clang_format_output(file_path, original, output)
+
+    def _process_single_file(
+        self,
+        file_path: str,
+        timeout: int,
+    ) -> FileProcessingResult:
+        """Process a single file in check mode.
+
+        Args:
+            file_path: Path to the file to check.
+            timeout: Timeout in seconds for the clang-format command.
+
+        Returns:
+            FileProcessingResult with processing outcome.
+        """
+        try:
+            issues = self._check_file(file_path, timeout)
+            return FileProcessingResult(success=True, output="", issues=issues)
+        except subprocess.TimeoutExpired:
+            return FileProcessingResult(
+                success=False,
+                output="",
+                issues=[],
+                skipped=True,
+            )
+        except (OSError, ValueError, RuntimeError) as e:
+            return FileProcessingResult(
+                success=False,
+                output="",
+                issues=[],
+                error=str(e),
+            )
+
+    def _process_single_file_fix(
+        self,
+        file_path: str,
+        timeout: int,
+    ) -> tuple[FileProcessingResult, int, int]:
+        """Process a single file in fix mode.
+
+        Args:
+            file_path: Path to the file to format.
+            timeout: Timeout in seconds for the clang-format command.
+
+        Returns:
+            Tuple of (FileProcessingResult, initial_issues_count, fixed_issues_count).
+        """
+        try:
+            initial_issues = self._check_file(file_path, timeout)
+            if not initial_issues:
+                return (
+                    FileProcessingResult(success=True, output="", issues=[]),
+                    0,
+                    0,
+                )
+
+            fix_success, fix_output = self._run_subprocess(
+                cmd=self._build_command(file_path, in_place=True),
+                timeout=timeout,
+            )
+            if not fix_success:
+                raise RuntimeError(fix_output.strip() or "clang-format failed")
+
+            remaining_issues = self._check_file(file_path, timeout)
+            initial = len(initial_issues)
+            return (
+                FileProcessingResult(
+                    success=not remaining_issues,
+                    output="",
+                    issues=remaining_issues,
+                ),
+                initial,
+                max(0, initial - len(remaining_issues)),
+            )
+        except subprocess.TimeoutExpired:
+            return (
+                FileProcessingResult(
+                    success=False,
+                    output="",
+                    issues=[],
+                    skipped=True,
+                ),
+                0,
+                0,
+            )
+        except (OSError, ValueError, RuntimeError) as e:
+            return (
+                FileProcessingResult(
+                    success=False,
+                    output="",
+                    issues=[],
+                    error=str(e),
+                ),
+                0,
+                0,
+            )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check C/C++ files for formatting with clang-format.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        result = self._process_files_with_progress(
+            files=ctx.files,
+            processor=lambda f: self._process_single_file(f, ctx.timeout),
+            timeout=ctx.timeout,
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=result.all_success and result.total_issues == 0,
+            output=result.build_output(timeout=ctx.timeout),
+            issues_count=result.total_issues,
+            issues=result.all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Format C/C++ files in place with clang-format.
+
+        Args:
+            paths: List of file or directory paths to format.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        initial_count = 0
+        fixed_count = 0
+
+        def process_fix(file_path: str) -> FileProcessingResult:
+            """Format a single file and record its counts.
+
+            Args:
+                file_path: Path to the file to format.
+
+            Returns:
+                FileProcessingResult with processing outcome.
+            """
+            nonlocal initial_count, fixed_count
+            result, initial, fixed = self._process_single_file_fix(
+                file_path=file_path,
+                timeout=ctx.timeout,
+            )
+            initial_count += initial
+            fixed_count += fixed
+            return result
+
+        result = self._process_files_with_progress(
+            files=ctx.files,
+            processor=process_fix,
+            timeout=ctx.timeout,
+            label="Formatting files",
+        )
+
+        remaining_count = initial_count - fixed_count
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+        failure_output = result.build_output(timeout=ctx.timeout)
+        if failure_output:
+            output_lines.append(failure_output)
+
+        logger.debug(
+            f"[ClangFormatPlugin] Fix complete: initial={initial_count}, "
+            f"fixed={fixed_count}, remaining={remaining_count}",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=result.all_success and remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=result.all_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -0,0 +1,313 @@
+"""clang-tidy tool definition.
+
+clang-tidy is a clang-based C/C++ linter covering bug-prone patterns,
+modernization, performance and style checks. It needs the compiler flags of
+each source file, which it reads from a ``compile_commands.json``
+compilation database; lintro finds the nearest one for every checked file
+and falls back to default flags when there is none.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+import tempfile
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.clang_tidy.clang_tidy_issue import ClangTidyIssue
+from lintro.parsers.clang_tidy.clang_tidy_parser import (
+    parse_clang_tidy_export,
+    parse_clang_tidy_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.text_decoding import read_output_file
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for clang-tidy configuration
+CLANG_TIDY_DEFAULT_TIMEOUT: int = 300
+CLANG_TIDY_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("clang_tidy", 50)
+# Headers are checked through the sources that include them
+CLANG_TIDY_FILE_PATTERNS: list[str] = ["*.c", "*.cc", "*.cpp", "*.cxx", "*.c++"]
+COMPILE_COMMANDS_FILE: str = "compile_commands.json"
+# Build directories searched for a compilation database next to each parent
+COMPILE_COMMANDS_SUBDIRS: tuple[str, ...] = ("", "build")
+
+
+def find_compile_commands_dir(file_path: str | Path) -> Path | None:
+    """Find the compilation database for a source file.
+
+    Looks in the file's directory and each parent, both directly and in a
+    ``build`` subdirectory, where CMake and Meson usually write it.
+
+    Args:
+        file_path: Source file to find the database for.
+
+    Returns:
+        Directory holding the nearest ``compile_commands.json``, or None if
+        there is none.
+    """
+    directory = Path(file_path).resolve().parent
+    for candidate in (directory, *directory.parents):
+        for subdir in COMPILE_COMMANDS_SUBDIRS:
+            build_dir = candidate / subdir if subdir else candidate
+            if (build_dir / COMPILE_COMMANDS_FILE).is_file():
+                return build_dir
+    return None
+
+
+def group_by_compile_commands(
+    files: list[str],
+    compile_commands_dir: str | None = None,
+) -> dict[str | None, list[str]]:
+    """Group source files by the compilation database that covers them.
+
+    Args:
+        files: Source files to check.
+        compile_commands_dir: Directory to use for every file instead of
+            searching for one.
+
+    Returns:
+        dict[str | None, list[str]]: Files keyed by database directory, with
+        None for files that have no database.
+    """
+    if compile_commands_dir:
+        return {compile_commands_dir: list(files)}
+    groups: dict[str | None, list[str]] = {}
+    for file_path in files:
+        build_dir = find_compile_commands_dir(file_path)
+        key = str(build_dir) if build_dir is not None else None
+        groups.setdefault(key, []).append(file_path)
+    return groups
+
+
+@register_tool
+@dataclass
+class ClangTidyPlugin(BaseToolPlugin):
+    """clang-tidy C/C++ linter plugin.
+
+    This plugin runs clang-tidy once per compilation database, reading the
+    diagnostics from an ``--export-fixes`` file.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="clang_tidy",
+            description="Clang-based C/C++ linter and static analyzer",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=CLANG_TIDY_FILE_PATTERNS,
+            priority=CLANG_TIDY_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".clang-tidy"],
+            version_command=["clang-tidy", "--version"],
+            min_version=get_min_version(ToolName.CLANG_TIDY),
+            default_options={
+                "timeout": CLANG_TIDY_DEFAULT_TIMEOUT,
+                "checks": None,
+                "compile_commands_dir": None,
+            },
+            default_timeout=CLANG_TIDY_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        checks: str | None = None,
+        compile_commands_dir: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set clang-tidy-specific options.
+
+        Args:
+            checks: Check globs passed as ``--checks``, added to those in
+                ``.clang-tidy`` (e.g. ``-*,bugprone-*``).
+            compile_commands_dir: Directory holding ``compile_commands.json``
+                for all files, instead of searching for one per file.
+            timeout: Timeout in seconds per run (default: 300).
+            **kwargs: Additional base options.
+        """
+        validate_str(checks, "checks")
+        validate_str(compile_commands_dir, "compile_commands_dir")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(
+            checks=checks,
+            compile_commands_dir=compile_commands_dir,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _build_command(
+        self,
+        files: list[str],
+        export_path: str,
+        build_dir: str | None,
+    ) -> list[str]:
+        """Build the clang-tidy command for files sharing a database.
+
+        Args:
+            files: Source files to check.
+            export_path: File clang-tidy writes its diagnostics to.
+            build_dir: Directory of the compilation database, or None to
+                check the files with default compiler flags.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="clang-tidy")
+        cmd.extend(["--quiet", f"--export-fixes={export_path}"])
+        checks = self.options.get("checks")
+        if checks:
+            cmd.append(f"--checks={checks}")
+        if build_dir is not None:
+            cmd.extend(["-p", build_dir])
+        cmd.extend(files)
+        if build_dir is None:
+            # Without a database, "--" tells clang-tidy not to look for one
+            cmd.append("--")
+        return cmd
+
+    def _run_group(
+        self,
+        files: list[str],
+        build_dir: str | None,
+        timeout: int,
+    ) -> tuple[bool, str, list[ClangTidyIssue]]:
+        """Run clang-tidy on files sharing a compilation database.
+
+        Args:
+            files: Source files to check.
+            build_dir: Directory of the compilation database, if any.
+            timeout: Timeout in seconds for the run.
+
+        Returns:
+            Tuple of (success, output, issues).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        with tempfile.NamedTemporaryFile(
+            mode="w",
+            suffix=".yaml",
+            delete=False,
+        ) as export_file:
+            export_path = export_file.name
+
+        try:
+            cmd = self._build_command(files, export_path, build_dir)
+            logger.debug(f"[ClangTidyPlugin] Running: {' '.join(cmd[:8])}...")
+            success, output = self._run_subprocess(cmd=cmd, timeout=timeout)
+            export = read_output_file(export_path, "clang-tidy")
+        finally:
+            if os.path.exists(export_path):
+                os.unlink(export_path)
+
+        issues = parse_clang_tidy_export(export)
+        if issues is None:
+            issues = parse_clang_tidy_output(output)
+        return success, output, issues
+
+    def _create_timeout_result(self, timeout_val: int) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"clang-tidy execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options clang_tidy:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint C/C++ sources with clang-tidy.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        compile_commands_dir = self.options.get("compile_commands_dir")
+        groups = group_by_compile_commands(
+            ctx.files,
+            str(compile_commands_dir) if compile_commands_dir else None,
+        )
+        issues: list[ClangTidyIssue] = []
+        errors: list[str] = []
+
+        for build_dir, files in groups.items():
+            try:
+                run_success, output, group_issues = self._run_group(
+                    files,
+                    build_dir,
+                    ctx.timeout,
+                )
+            except subprocess.TimeoutExpired:
+                return self._create_timeout_result(ctx.timeout)
+            issues.extend(group_issues)
+            if not run_success and not group_issues:
+                errors.append(output.strip() or "clang-tidy execution failed.")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues and not errors,
+            output="\n".join(errors) if errors else None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """clang-tidy fixes are not applied by lintro, only reported.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: clang-tidy fixing is not supported.
+        """
+        raise NotImplementedError(
+            "clang-tidy fixes cannot be applied by lintro. Run 'lintro check' to "
+            "see issues.",
+        )
@@ -2,7 +2,16 @@
 
 Clippy is Rust's official linter with hundreds of lint rules for correctness,
 style, complexity, and performance. It runs via `cargo clippy` and requires
-a Cargo.toml file in the project.
+a Cargo.toml file in the project. In a workspace it runs from the workspace
+root on the members containing the checked files. Fixes apply the
+machine-applicable suggestions of clippy's JSON diagnostics directly.
+
+By default clippy builds every target with every feature. The ``matrix``
+option checks a list of target/feature selections instead, such as
+``--lib --no-default-features`` or ``--all-targets --features serde``, to
+reach code behind ``cfg(not(feature = ...))`` or mutually exclusive
+features; the runs are merged and issues not reported by every entry are
+tagged with the entries that report them.
 """
 
 # mypy: ignore-errors
@@ -12,6 +21,7 @@ a Cargo.toml file in the project.
 from __future__ import annotations
 
 import os
+import shlex
 import subprocess  # nosec B404 - used safely with shell disabled
 from dataclasses import dataclass
 from pathlib import Path
@@ -21,23 +31,56 @@ from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
 from lintro.models.core.tool_result import ToolResult
-from lintro.parsers.clippy.clippy_parser import parse_clippy_output
+from lintro.parsers.base_parser import IssueCollector
+from lintro.parsers.clippy.clippy_issue import ClippyIssue
+from lintro.parsers.clippy.clippy_parser import (
+    dedupe_clippy_issues,
+    parse_clippy_line,
+)
 from lintro.plugins.base import BaseToolPlugin
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
 from lintro.tools.core.option_validators import (
     filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
     validate_positive_int,
 )
 from lintro.tools.core.timeout_utils import (
     create_timeout_result,
     run_subprocess_with_timeout,
 )
+from lintro.utils.cargo_workspace import CargoTarget, resolve_target
+from lintro.utils.execution.toolchain_matrix import merge_matrix_results
+from lintro.utils.toolchains import rustup_toolchain
 
 # Constants for Clippy configuration
 CLIPPY_DEFAULT_TIMEOUT: int = 120
 CLIPPY_DEFAULT_PRIORITY: int = 85
 CLIPPY_FILE_PATTERNS: list[str] = ["*.rs", "Cargo.toml"]
+# Rounds of applying suggestions and re-checking in one fix
+CLIPPY_FIX_PASSES: int = 4
+# Targets and features clippy builds when no matrix is configured
+CLIPPY_DEFAULT_SELECTION: list[str] = ["--all-targets", "--all-features"]
+
+# Cargo flags a matrix entry may select targets and features with
+_SELECTION_FLAGS: frozenset[str] = frozenset(
+    {
+        "--all-targets",
+        "--all-features",
+        "--no-default-features",
+        "--lib",
+        "--bins",
+        "--tests",
+        "--benches",
+        "--examples",
+    },
+)
+_SELECTION_VALUE_FLAGS: frozenset[str] = frozenset(
+    {"--features", "-F", "--bin", "--test", "--bench", "--example"},
+)
+# Matrix entry for cargo's own defaults: library and binaries, default features
+_DEFAULT_ENTRY: str = "default"
 
 
 def _find_cargo_root(paths: list[str]) -> Path | None:
@@ -79,27 +122,212 @@ def _find_cargo_root(paths: list[str]) -> Path | None:
     return common if manifest.exists() else None
 
 
-def _build_clippy_command(fix: bool = False) -> list[str]:
+def _toolchain(options: dict[str, object], root: Path) -> str | None:
+    """Return the rustup toolchain to run clippy with, if any.
+
+    Args:
+        options: Tool options.
+        root: Cargo root clippy runs from.
+
+    Returns:
+        str | None: Toolchain name; the ``toolchain`` option overrides the
+        project's rust-toolchain file.
+    """
+    toolchain = options.get("toolchain")
+    return str(toolchain) if toolchain else rustup_toolchain(root)
+
+
+def parse_matrix_entry(entry: str) -> list[str]:
+    """Parse a matrix entry into the cargo flags it selects.
+
+    Args:
+        entry: Space-separated cargo flags such as
+            ``--all-targets --features serde``, or ``default``.
+
+    Returns:
+        list[str]: Cargo flags; ``default`` selects none.
+
+    Raises:
+        ValueError: If the entry uses a flag that does not select targets
+            or features, or a flag misses its value.
+    """
+    tokens = shlex.split(entry)
+    if tokens == [_DEFAULT_ENTRY]:
+        return []
+    flags: list[str] = []
+    pending: str | None = None
+    for token in tokens:
+        if pending is not None:
+            flags += [pending, token]
+            pending = None
+        elif token in _SELECTION_FLAGS:
+            flags.append(token)
+        elif token in _SELECTION_VALUE_FLAGS:
+            pending = token
+        elif token.split("=", 1)[0] in _SELECTION_VALUE_FLAGS and "=" in token:
+            flags.append(token)
+        else:
+            raise ValueError(
+                f"clippy matrix entry '{entry}' may only select targets and "
+                f"features, not '{token}'",
+            )
+    if pending is not None:
+        raise ValueError(f"clippy matrix entry '{entry}' misses a value for {pending}")
+    return flags
+
+
+def _build_clippy_command(
+    toolchain: str | None = None,
+    packages: tuple[str, ...] | None = None,
+    selection: list[str] | None = None,
+) -> list[str]:
     """Build the cargo clippy command.
 
     Args:
-        fix: Whether to include --fix flag.
+        toolchain: Rustup toolchain (e.g. "stable" or "1.80"), if any.
+        packages: Workspace members to lint, or None for the whole workspace.
+        selection: Target and feature flags; None builds every target with
+            every feature.
 
     Returns:
         List of command arguments.
     """
-    cmd = [
-        "cargo",
-        "clippy",
-        "--all-targets",
-        "--all-features",
-        "--message-format=json",
-    ]
-    if fix:
-        cmd.extend(["--fix", "--allow-dirty", "--allow-staged"])
+    cmd = ["cargo"]
+    if toolchain:
+        cmd.append(f"+{toolchain}")
+    cmd.append("clippy")
+    if packages is None:
+        cmd.append("--workspace")
+    else:
+        for package in packages:
+            cmd.extend(["-p", package])
+    cmd += CLIPPY_DEFAULT_SELECTION if selection is None else selection
+    cmd.append("--message-format=json")
     return cmd
 
 
+def _suggested_edits(
+    issues: list[ClippyIssue],
+    lints: set[str],
+    include_unsafe: bool = False,
+) -> dict[str, list[tuple[int, int, str]]]:
+    """Collect the machine-applicable suggestions of selected lints.
+
+    Args:
+        issues: Parsed clippy diagnostics.
+        lints: Lint codes whose suggestions are wanted.
+        include_unsafe: Also collect ``MaybeIncorrect`` suggestions.
+
+    Returns:
+        dict[str, list[tuple[int, int, str]]]: Per file, non-overlapping
+        ``(byte_start, byte_end, replacement)`` edits sorted by position.
+        A suggestion is dropped whole if any of its edits overlaps one
+        already taken.
+    """
+    edits: dict[str, list[tuple[int, int, str]]] = {}
+    for issue in issues:
+        if issue.code not in lints:
+            continue
+        for suggestion in issue.suggestions:
+            if not (
+                suggestion.machine_applicable
+                or (include_unsafe and suggestion.applicable)
+            ):
+                continue
+            replacements = suggestion.replacements
+            if any(
+                _overlaps((r.byte_start, r.byte_end, r.text), edits.get(r.file, []))
+                for r in replacements
+            ):
+                continue
+            for r in replacements:
+                edits.setdefault(r.file, []).append((r.byte_start, r.byte_end, r.text))
+    return {file: sorted(set(found)) for file, found in edits.items()}
+
+
+def _overlaps(
+    edit: tuple[int, int, str],
+    taken: list[tuple[int, int, str]],
+) -> bool:
+    """Check whether an edit conflicts with edits already taken.
+
+    The same edit reported twice (e.g. once per build target) does not
+    conflict with itself.
+
+    Args:
+        edit: ``(byte_start, byte_end, replacement)`` of the new edit.
+        taken: Edits already taken in the file.
+
+    Returns:
+        bool: True if the byte ranges intersect.
+    """
+    start, end, _ = edit
+    return edit not in taken and any(
+        start < other_end and other_start < end for other_start, other_end, _ in taken
+    )
+
+
+def _apply_suggestions(
+    cargo_root: Path,
+    issues: list[ClippyIssue],
+    lints: set[str],
+    include_unsafe: bool = False,
+) -> None:
+    """Apply the machine-applicable suggestions of selected lints.
+
+    Suggestions in files outside the cargo root, such as the sources of
+    dependencies or the standard library, are never applied.
+
+    Args:
+        cargo_root: Directory cargo ran in; diagnostic paths are relative to it.
+        issues: Parsed clippy diagnostics.
+        lints: Lint codes to fix.
+        include_unsafe: Also apply ``MaybeIncorrect`` suggestions.
+    """
+    root = cargo_root.resolve()
+    for file_name, edits in _suggested_edits(issues, lints, include_unsafe).items():
+        path = (cargo_root / file_name).resolve()
+        if not path.is_file() or not path.is_relative_to(root):
+            continue
+        data = path.read_bytes()
+        # Apply from the end so earlier offsets stay valid
+        for start, end, replacement in reversed(edits):
+            data = data[:start] + replacement.encode("utf-8") + data[end:]
+        path.write_bytes(data)
+
+
+def _issue_collector() -> IssueCollector[ClippyIssue]:
+    """Return a line handler that parses clippy output as it streams.
+
+    cargo can print hundreds of MB of JSON on a large workspace, so its
+    output is parsed line by line instead of being buffered.
+
+    Returns:
+        IssueCollector[ClippyIssue]: Collector of the issues of one run.
+    """
+    return IssueCollector(parse_clippy_line, tool_name="clippy")
+
+
+def _attribute_issues(
+    issues: list[ClippyIssue],
+    target: CargoTarget,
+) -> list[ClippyIssue]:
+    """Merge repeated diagnostics and attribute each issue to its crate.
+
+    Args:
+        issues: Issues parsed from cargo clippy output.
+        target: Where clippy ran and on which packages.
+
+    Returns:
+        list[ClippyIssue]: One issue per lint and span, with ``crate_name``
+        set when the workspace is known.
+    """
+    issues = dedupe_clippy_issues(issues)
+    for issue in issues:
+        issue.crate_name = target.crate_for(issue.file)
+    return issues
+
+
 @register_tool
 @dataclass
 class ClippyPlugin(BaseToolPlugin):
@@ -129,8 +357,10 @@ class ClippyPlugin(BaseToolPlugin):
             min_version=get_min_version(ToolName.CLIPPY),
             default_options={
                 "timeout": CLIPPY_DEFAULT_TIMEOUT,
+                "unsafe_fixes": False,
             },
             default_timeout=CLIPPY_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def _verify_tool_version(self) -> ToolResult | None:
@@ -142,6 +372,10 @@ class ClippyPlugin(BaseToolPlugin):
             Optional[ToolResult]: None if version check passes, or a skip result
                 if it fails.
         """
+        if self.container is not None:
+            return None
+        from lintro.exceptions.errors import TOOL_NOT_INSTALLED, TOOL_VERSION_TOO_OLD
+        from lintro.plugins.execution_preparation import pin_mismatch_result
         from lintro.tools.core.version_requirements import check_tool_version
 
         # Check Rust version instead of clippy version
@@ -150,6 +384,10 @@ class ClippyPlugin(BaseToolPlugin):
         if version_info.version_check_passed:
             return None  # Version check passed
 
+        pin_result = pin_mismatch_result(self.definition.name, version_info)
+        if pin_result is not None:
+            return pin_result
+
         # Version check failed - return skip result with warning
         skip_message = (
             f"Skipping {self.definition.name}: {version_info.error_message}. "
@@ -162,27 +400,62 @@ class ClippyPlugin(BaseToolPlugin):
             success=True,  # Not an error, just skipping
             output=skip_message,
             issues_count=0,
+            error_code=(
+                TOOL_NOT_INSTALLED if version_info.not_found else TOOL_VERSION_TOO_OLD
+            ).code,
         )
 
     def set_options(  # type: ignore[override]
         self,
         timeout: int | None = None,
+        toolchain: str | None = None,
+        fixable: list[str] | None = None,
+        unsafe_fixes: bool | None = None,
+        matrix: list[str] | None = None,
         **kwargs: Any,
     ) -> None:
         """Set Clippy-specific options.
 
         Args:
             timeout: Timeout in seconds (default: 120).
+            toolchain: Rustup toolchain to run with (``cargo +<toolchain>``).
+            fixable: Lints (e.g. ``clippy::needless_return``) whose
+                machine-applicable suggestions fix applies; None applies
+                those of every lint.
+            unsafe_fixes: Whether fix also applies ``MaybeIncorrect``
+                suggestions, which may change behavior.
+            matrix: Target/feature selections checked one run each, e.g.
+                ``["--all-targets --all-features", "--lib
+                --no-default-features"]``.
             **kwargs: Additional options.
+
+        Raises:
+            ValueError: If toolchain is not a string.
         """
         validate_positive_int(timeout, "timeout")
-
-        options = filter_none_options(timeout=timeout)
+        if toolchain is not None and not isinstance(toolchain, str):
+            raise ValueError("toolchain must be a string")
+        fixable = normalize_str_or_list(fixable, "fixable")
+        validate_bool(unsafe_fixes, "unsafe_fixes")
+        matrix = normalize_str_or_list(matrix, "matrix")
+        for entry in matrix or []:
+            parse_matrix_entry(entry)
+
+        options = filter_none_options(
+            timeout=timeout,
+            toolchain=toolchain,
+            fixable=fixable,
+            unsafe_fixes=unsafe_fixes,
+            matrix=matrix,
+        )
         super().set_options(**options, **kwargs)
 
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
         """Run `cargo clippy` and parse linting issues.
 
+        With a ``matrix``, clippy runs once per entry and the results are
+        merged.
+
         Args:
             paths: List of file or directory paths to check.
             options: Runtime options that override defaults.
@@ -207,21 +480,64 @@ class ClippyPlugin(BaseToolPlugin):
                 output="No Cargo.toml found; skipping clippy.",
                 issues_count=0,
             )
+        target = resolve_target(ctx.files, cargo_root)
+
+        matrix = self.options.get("matrix")
+        if not matrix:
+            return self._check_selection(target, ctx.timeout)
+        runs = [
+            (
+                str(entry).strip(),
+                self._check_selection(
+                    target,
+                    ctx.timeout,
+                    parse_matrix_entry(str(entry)),
+                ),
+            )
+            for entry in matrix
+        ]
+        return merge_matrix_results(
+            self.definition.name,
+            runs,
+            tag_field="configurations",
+        )
+
+    def _check_selection(
+        self,
+        target: CargoTarget,
+        timeout: int,
+        selection: list[str] | None = None,
+    ) -> ToolResult:
+        """Run `cargo clippy` once on a target/feature selection.
 
-        cmd = _build_clippy_command(fix=False)
+        Args:
+            target: Where clippy runs and on which packages.
+            timeout: Timeout in seconds.
+            selection: Target and feature flags; None uses the defaults.
 
+        Returns:
+            ToolResult with the issues of the run.
+        """
+        cmd = _build_clippy_command(
+            toolchain=_toolchain(self.options, target.root),
+            packages=target.packages,
+            selection=selection,
+        )
+
+        collector = _issue_collector()
         try:
             success_cmd, output = run_subprocess_with_timeout(
                 tool=self,
                 cmd=cmd,
-                timeout=ctx.timeout,
-                cwd=str(cargo_root),
+                timeout=timeout,
+                cwd=str(target.root),
                 tool_name="clippy",
+                line_handler=collector,
             )
         except subprocess.TimeoutExpired:
             timeout_result = create_timeout_result(
                 tool=self,
-                timeout=ctx.timeout,
+                timeout=timeout,
                 cmd=cmd,
                 tool_name="clippy",
             )
@@ -229,11 +545,12 @@ class ClippyPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
             )
 
-        issues = parse_clippy_output(output=output)
+        issues = _attribute_issues(collector.issues, target)
         issues_count = len(issues)
 
         # Preserve output when command fails with no parsed issues for debugging
@@ -249,7 +566,14 @@ class ClippyPlugin(BaseToolPlugin):
         )
 
     def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
-        """Run `cargo clippy --fix` then re-check for remaining issues.
+        """Apply clippy's suggestions, then re-check for remaining issues.
+
+        The suggestions are applied from the JSON diagnostics of the check
+        instead of with ``cargo clippy --fix``, which refuses to run on a
+        dirty working tree or outside version control and cannot be limited
+        to some lints.
+        Fixes can reveal new suggestions, so suggestions are applied and
+        checked again up to ``CLIPPY_FIX_PASSES`` times.
 
         Args:
             paths: List of file or directory paths to fix.
@@ -278,17 +602,23 @@ class ClippyPlugin(BaseToolPlugin):
                 fixed_issues_count=0,
                 remaining_issues_count=0,
             )
+        target = resolve_target(ctx.files, cargo_root)
 
-        check_cmd = _build_clippy_command(fix=False)
+        check_cmd = _build_clippy_command(
+            toolchain=_toolchain(self.options, target.root),
+            packages=target.packages,
+        )
 
         # First, count issues before fixing
+        initial_collector = _issue_collector()
         try:
-            success_check, output_check = run_subprocess_with_timeout(
+            success_after, output_after = run_subprocess_with_timeout(
                 tool=self,
                 cmd=check_cmd,
                 timeout=ctx.timeout,
-                cwd=str(cargo_root),
+                cwd=str(target.root),
                 tool_name="clippy",
+                line_handler=initial_collector,
             )
         except subprocess.TimeoutExpired:
             timeout_result = create_timeout_result(
@@ -301,6 +631,7 @@ class ClippyPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
                 initial_issues_count=0,
@@ -308,65 +639,53 @@ class ClippyPlugin(BaseToolPlugin):
                 remaining_issues_count=1,
             )
 
-        initial_issues = parse_clippy_output(output=output_check)
+        initial_issues = _attribute_issues(initial_collector.issues, target)
         initial_count = len(initial_issues)
 
-        # Run fix
-        fix_cmd = _build_clippy_command(fix=True)
-        try:
-            success_fix, output_fix = run_subprocess_with_timeout(
-                tool=self,
-                cmd=fix_cmd,
-                timeout=ctx.timeout,
-                cwd=str(cargo_root),
-                tool_name="clippy",
-            )
-        except subprocess.TimeoutExpired:
-            timeout_result = create_timeout_result(
-                tool=self,
-                timeout=ctx.timeout,
-                cmd=fix_cmd,
-                tool_name="clippy",
-            )
-            return ToolResult(
-                name=self.definition.name,
-                success=timeout_result.success,
-                output=timeout_result.output,
-                issues_count=timeout_result.issues_count,
-                issues=initial_issues,
-                initial_issues_count=initial_count,
-                fixed_issues_count=0,
-                remaining_issues_count=1,
-            )
-
-        # Re-check after fix to count remaining issues
-        try:
-            success_after, output_after = run_subprocess_with_timeout(
-                tool=self,
-                cmd=check_cmd,
-                timeout=ctx.timeout,
-                cwd=str(cargo_root),
-                tool_name="clippy",
-            )
-        except subprocess.TimeoutExpired:
-            timeout_result = create_timeout_result(
-                tool=self,
-                timeout=ctx.timeout,
-                cmd=check_cmd,
-                tool_name="clippy",
-            )
-            return ToolResult(
-                name=self.definition.name,
-                success=timeout_result.success,
-                output=timeout_result.output,
-                issues_count=timeout_result.issues_count,
-                issues=initial_issues,
-                initial_issues_count=initial_count,
-                fixed_issues_count=0,
-                remaining_issues_count=1,
+        fixable = self.options.get("fixable")
+        unsafe_fixes = bool(self.options.get("unsafe_fixes"))
+        remaining_issues = initial_issues
+        for _ in range(CLIPPY_FIX_PASSES):
+            lints = (
+                set(fixable)
+                if fixable
+                else {issue.code for issue in remaining_issues}
             )
+            if not _suggested_edits(remaining_issues, lints, unsafe_fixes):
+                break
+            _apply_suggestions(target.root, remaining_issues, lints, unsafe_fixes)
+
+            # Re-check after fix to count remaining issues
+            remaining_collector = _issue_collector()
+            try:
+                success_after, output_after = run_subprocess_with_timeout(
+                    tool=self,
+                    cmd=check_cmd,
+                    timeout=ctx.timeout,
+                    cwd=str(target.root),
+                    tool_name="clippy",
+                    line_handler=remaining_collector,
+                )
+            except subprocess.TimeoutExpired:
+                timeout_result = create_timeout_result(
+                    tool=self,
+                    timeout=ctx.timeout,
+                    cmd=check_cmd,
+                    tool_name="clippy",
+                )
+                return ToolResult(
+                    name=self.definition.name,
+                    success=timeout_result.success,
+                    output=timeout_result.output,
+                    error_code=timeout_result.error_code,
+                    issues_count=timeout_result.issues_count,
+                    issues=initial_issues,
+                    initial_issues_count=initial_count,
+                    fixed_issues_count=0,
+                    remaining_issues_count=1,
+                )
+            remaining_issues = _attribute_issues(remaining_collector.issues, target)
 
-        remaining_issues = parse_clippy_output(output=output_after)
         remaining_count = len(remaining_issues)
         fixed_count = max(0, initial_count - remaining_count)
 
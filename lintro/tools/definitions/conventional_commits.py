@@ -0,0 +1,227 @@
+"""Conventional Commits tool definition.
+
+Native linter for commit messages and pull request titles. semantic-release
+computes versions and changelogs from Conventional Commits headers, so this
+tool gives contributors the same verdict locally before CI does. It checks
+the commits of a git revision range and/or a PR title string rather than
+files, and runs inside Lintro, so no external binary is required.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.conventional_commits.conventional_commits_issue import (
+    ConventionalCommitsIssue,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
+    validate_int,
+    validate_str,
+)
+from lintro.tools.implementations.conventional_commits import (
+    DEFAULT_TYPES,
+    check_commit_message,
+    read_commit_messages,
+)
+
+# Constants for Conventional Commits configuration
+CONVENTIONAL_COMMITS_DEFAULT_TIMEOUT: int = 30
+CONVENTIONAL_COMMITS_DEFAULT_PRIORITY: int = 30
+CONVENTIONAL_COMMITS_DEFAULT_MAX_HEADER_LENGTH: int = 100
+PR_TITLE_SOURCE: str = "PR title"
+
+
+@register_tool
+@dataclass
+class ConventionalCommitsPlugin(BaseToolPlugin):
+    """Native Conventional Commits linting plugin.
+
+    This plugin validates commit messages from git and PR titles without
+    invoking any external tool. It is selected by ``lintro check
+    --commit-range`` and ``--pr-title``.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="conventional_commits",
+            description="Commit message and PR title linting (Conventional Commits)",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=[],
+            priority=CONVENTIONAL_COMMITS_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": CONVENTIONAL_COMMITS_DEFAULT_TIMEOUT,
+                "commit_range": None,
+                "pr_title": None,
+                "types": list(DEFAULT_TYPES),
+                "require_scope": False,
+                "max_header_length": CONVENTIONAL_COMMITS_DEFAULT_MAX_HEADER_LENGTH,
+            },
+            default_timeout=CONVENTIONAL_COMMITS_DEFAULT_TIMEOUT,
+            enabled_by_default=False,
+            native=True,
+            per_file=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        commit_range: str | None = None,
+        pr_title: str | None = None,
+        types: list[str] | str | None = None,
+        require_scope: bool | None = None,
+        max_header_length: int | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set Conventional Commits options with validation.
+
+        Args:
+            commit_range: Git revision range whose commits are checked, such
+                as ``origin/main..HEAD``.
+            pr_title: Pull request title to check.
+            types: Allowed commit types (default: the angular types).
+            require_scope: Require a ``(scope)`` in every header.
+            max_header_length: Longest allowed header, 0 for no limit
+                (default: 100).
+            **kwargs: Additional base options.
+        """
+        validate_str(commit_range, "commit_range")
+        validate_str(pr_title, "pr_title")
+        types = normalize_str_or_list(types, "types")
+        validate_bool(require_scope, "require_scope")
+        validate_int(max_header_length, "max_header_length", min_value=0)
+
+        options = filter_none_options(
+            commit_range=commit_range,
+            pr_title=pr_title,
+            types=types,
+            require_scope=require_scope,
+            max_header_length=max_header_length,
+        )
+        super().set_options(**options, **kwargs)
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check commit messages and the PR title.
+
+        Args:
+            paths: Unused; commits are read from the current repository.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        merged_options = {**self.options, **options}
+        commit_range = merged_options.get("commit_range")
+        pr_title = merged_options.get("pr_title")
+        if not commit_range and pr_title is None:
+            return ToolResult(
+                name=self.definition.name,
+                output=(
+                    "Nothing to check. Pass --commit-range (e.g. "
+                    "origin/main..HEAD) or --pr-title."
+                ),
+                issues_count=0,
+                skipped=True,
+                skip_reason="no commit range or PR title",
+            )
+
+        raw_types = merged_options.get("types")
+        types = (
+            [str(t) for t in raw_types]
+            if isinstance(raw_types, list)
+            else list(DEFAULT_TYPES)
+        )
+        require_scope = bool(merged_options.get("require_scope", False))
+        raw_length = merged_options.get("max_header_length")
+        max_header_length = (
+            raw_length
+            if isinstance(raw_length, int)
+            else CONVENTIONAL_COMMITS_DEFAULT_MAX_HEADER_LENGTH
+        )
+
+        issues: list[ConventionalCommitsIssue] = []
+        checked = 0
+        if pr_title is not None:
+            checked += 1
+            issues.extend(
+                check_commit_message(
+                    str(pr_title),
+                    PR_TITLE_SOURCE,
+                    types=types,
+                    require_scope=require_scope,
+                    max_header_length=max_header_length,
+                ),
+            )
+        if commit_range:
+            try:
+                commits = read_commit_messages(str(commit_range), cwd=os.getcwd())
+            except ValueError as e:
+                return ToolResult(
+                    name=self.definition.name,
+                    success=False,
+                    output=str(e),
+                    issues_count=0,
+                )
+            checked += len(commits)
+            for commit in commits:
+                issues.extend(
+                    check_commit_message(
+                        commit.message,
+                        f"commit {commit.sha[:8]}",
+                        types=types,
+                        require_scope=require_scope,
+                        max_header_length=max_header_length,
+                        commit=commit.sha,
+                    ),
+                )
+
+        logger.debug(
+            f"[ConventionalCommitsPlugin] Checked {checked} message(s), "
+            f"found {len(issues)} issue(s)",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Commit messages cannot be rewritten automatically.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: Commit messages are never rewritten.
+        """
+        raise NotImplementedError(
+            "conventional_commits cannot rewrite commit messages. Run "
+            "'lintro check --commit-range' to see issues.",
+        )
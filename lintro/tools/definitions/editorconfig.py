@@ -0,0 +1,228 @@
+"""EditorConfig tool definition.
+
+Native checker for ``.editorconfig`` compliance. Verifies indentation style,
+trailing whitespace, final newlines, line endings and maximum line length
+for every file a section of the project's ``.editorconfig`` applies to, and
+rewrites all but over-long lines when fixing. Runs inside Lintro, so no
+external binary is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.editorconfig.editorconfig_issue import EditorconfigIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.implementations.editorconfig import (
+    EditorconfigResolver,
+    check_text,
+    fix_text,
+)
+
+# Constants for EditorConfig configuration
+EDITORCONFIG_DEFAULT_TIMEOUT: int = 60
+# Runs before the formatters, which keep the final say on layout
+EDITORCONFIG_DEFAULT_PRIORITY: int = 5
+EDITORCONFIG_FILE_PATTERNS: list[str] = ["*"]
+# Bytes sniffed for a NUL byte to recognize binary files
+BINARY_SNIFF_BYTES: int = 8192
+
+
+def read_text_file(file_path: str) -> str | None:
+    """Read a file as text, keeping its line endings.
+
+    Args:
+        file_path: File to read.
+
+    Returns:
+        str | None: The contents, or None for binary and non-UTF-8 files.
+
+    Raises:
+        OSError: If the file cannot be read.
+    """
+    with open(file_path, "rb") as f:
+        data = f.read()
+    if b"\0" in data[:BINARY_SNIFF_BYTES]:
+        return None
+    try:
+        return data.decode("utf-8")
+    except UnicodeDecodeError:
+        return None
+
+
+@register_tool
+@dataclass
+class EditorconfigPlugin(BaseToolPlugin):
+    """Native EditorConfig compliance plugin.
+
+    This plugin checks files against their ``.editorconfig`` properties and
+    fixes the mechanical violations without invoking any external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="editorconfig",
+            description="EditorConfig compliance: indentation, whitespace, newlines",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=EDITORCONFIG_FILE_PATTERNS,
+            priority=EDITORCONFIG_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".editorconfig"],
+            version_command=None,
+            min_version=None,
+            default_options={"timeout": EDITORCONFIG_DEFAULT_TIMEOUT},
+            default_timeout=EDITORCONFIG_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def _no_config_result(self) -> ToolResult:
+        """Create a skipped result for projects without an .editorconfig.
+
+        Returns:
+            ToolResult: Skipped result explaining what enables the tool.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            output="No .editorconfig applies to the checked files.",
+            issues_count=0,
+            skipped=True,
+            skip_reason="no .editorconfig",
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check files against their .editorconfig properties.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        resolver = EditorconfigResolver()
+        all_issues: list[EditorconfigIssue] = []
+        errors: list[str] = []
+        checked = 0
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            properties = resolver.properties_for(file_path)
+            if not properties:
+                continue
+            try:
+                text = read_text_file(file_path)
+            except OSError as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            if text is None:
+                continue
+            checked += 1
+            all_issues.extend(check_text(text, rel_path, properties))
+
+        if not resolver.found_config:
+            return self._no_config_result()
+
+        logger.debug(
+            f"[EditorconfigPlugin] Checked {checked} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Fix mechanical EditorConfig violations and report the rest.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with fix results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        resolver = EditorconfigResolver()
+        initial_total = 0
+        remaining_issues: list[EditorconfigIssue] = []
+        fixed_files: list[str] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            properties = resolver.properties_for(file_path)
+            if not properties:
+                continue
+            try:
+                text = read_text_file(file_path)
+                if text is None:
+                    continue
+                initial_total += len(check_text(text, rel_path, properties))
+                fixed_text = fix_text(text, properties)
+                if fixed_text != text:
+                    with open(file_path, "w", encoding="utf-8", newline="") as f:
+                        f.write(fixed_text)
+                    fixed_files.append(rel_path)
+                    text = fixed_text
+            except OSError as e:
+                errors.append(f"Error processing {rel_path}: {e}")
+                continue
+            remaining_issues.extend(check_text(text, rel_path, properties))
+
+        if not resolver.found_config:
+            return self._no_config_result()
+
+        remaining = len(remaining_issues)
+        fixed = initial_total - remaining
+
+        summary_parts: list[str] = []
+        if fixed_files:
+            summary_parts.append(
+                f"Fixed {fixed} issue(s) in {len(fixed_files)} file(s)",
+            )
+        if remaining > 0:
+            summary_parts.append(
+                f"Found {remaining} issue(s) that cannot be auto-fixed",
+            )
+        summary_parts.extend(errors)
+
+        logger.debug(
+            f"[EditorconfigPlugin] Fix complete: initial={initial_total}, "
+            f"fixed={fixed}, remaining={remaining}",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and remaining == 0,
+            output="\n".join(summary_parts) if summary_parts else "No fixes needed.",
+            issues_count=remaining,
+            issues=remaining_issues,
+            initial_issues_count=initial_total,
+            fixed_issues_count=fixed,
+            remaining_issues_count=remaining,
+        )
@@ -0,0 +1,433 @@
+"""ESLint tool definition.
+
+ESLint is the standard pluggable linter for JavaScript and TypeScript. Lintro
+runs the project's own ESLint install with its flat config
+(``eslint.config.*``), so shared configs and plugins resolve as they do in
+the editor.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.eslint.eslint_issue import EslintIssue
+from lintro.parsers.eslint.eslint_parser import parse_eslint_output
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_list,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for ESLint configuration
+ESLINT_DEFAULT_TIMEOUT: int = 60
+ESLINT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("eslint", 50)
+ESLINT_FILE_PATTERNS: list[str] = [
+    "*.js",
+    "*.mjs",
+    "*.cjs",
+    "*.jsx",
+    "*.ts",
+    "*.mts",
+    "*.cts",
+    "*.tsx",
+]
+# Flat config files, in the order ESLint looks for them
+ESLINT_FLAT_CONFIGS: list[str] = [
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    "eslint.config.ts",
+    "eslint.config.mts",
+    "eslint.config.cts",
+]
+
+
+def find_flat_config(start: str | Path) -> Path | None:
+    """Find the flat config ESLint would use from a directory.
+
+    ESLint searches the directory it runs in and then each parent.
+
+    Args:
+        start: Directory to start searching from.
+
+    Returns:
+        Path to the nearest flat config, or None if there is none.
+    """
+    directory = Path(start).resolve()
+    for candidate in (directory, *directory.parents):
+        for name in ESLINT_FLAT_CONFIGS:
+            config = candidate / name
+            if config.is_file():
+                return config
+    return None
+
+
+@register_tool
+@dataclass
+class EslintPlugin(BaseToolPlugin):
+    """ESLint JavaScript/TypeScript linter plugin.
+
+    This plugin runs ESLint with the JSON formatter for checks and with
+    ``--fix`` for fixes. Rule severities from the config are kept: rules set
+    to ``"warn"`` become warnings and rules set to ``"error"`` become errors.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="eslint",
+            description="Pluggable linter for JavaScript and TypeScript",
+            can_fix=True,
+            tool_type=ToolType.LINTER,
+            file_patterns=ESLINT_FILE_PATTERNS,
+            priority=ESLINT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=list(ESLINT_FLAT_CONFIGS),
+            version_command=["eslint", "--version"],
+            min_version=get_min_version(ToolName.ESLINT),
+            default_options={
+                "timeout": ESLINT_DEFAULT_TIMEOUT,
+                "quiet": False,
+            },
+            default_timeout=ESLINT_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        exclude_patterns: list[str] | None = None,
+        include_venv: bool = False,
+        timeout: int | None = None,
+        config: str | None = None,
+        quiet: bool | None = None,
+        verbose_fix_output: bool | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set ESLint-specific options.
+
+        Args:
+            exclude_patterns: List of patterns to exclude.
+            include_venv: Whether to include virtual environment directories.
+            timeout: Timeout in seconds (default: 60).
+            config: Path to a flat config file (--config).
+            quiet: If True, report errors only (--quiet).
+            verbose_fix_output: If True, include raw ESLint output in fix().
+            **kwargs: Additional options (ignored for compatibility).
+        """
+        validate_list(exclude_patterns, "exclude_patterns")
+        validate_positive_int(timeout, "timeout")
+        validate_str(config, "config")
+        validate_bool(quiet, "quiet")
+        validate_bool(verbose_fix_output, "verbose_fix_output")
+
+        if exclude_patterns is not None:
+            self.exclude_patterns = exclude_patterns.copy()
+        self.include_venv = include_venv
+
+        options = filter_none_options(
+            timeout=timeout,
+            config=config,
+            quiet=quiet,
+            verbose_fix_output=verbose_fix_output,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_issues: list[EslintIssue] | None = None,
+        initial_count: int = 0,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_issues: Optional list of issues found before timeout.
+            initial_count: Optional count of initial issues.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"ESLint execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "This may indicate:\n"
+            "  - Large codebase or type-aware rules taking too long\n"
+            "  - Need to increase timeout via --tool-options eslint:timeout=N"
+        )
+        timeout_issue = EslintIssue(
+            file="execution",
+            line=1,
+            column=1,
+            code="TIMEOUT",
+            message=timeout_msg,
+            severity="error",
+            fixable=False,
+        )
+        combined_issues = (initial_issues or []) + [timeout_issue]
+        remaining_count = len(combined_issues)
+        # Keep initial = fixed + remaining when nothing was counted yet
+        effective_initial = initial_count if initial_count > 0 else remaining_count
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=remaining_count,
+            issues=combined_issues,
+            initial_issues_count=effective_initial,
+            fixed_issues_count=0,
+            remaining_issues_count=remaining_count,
+        )
+
+    def _create_no_config_result(self) -> ToolResult:
+        """Create a skipped result for projects without a flat config.
+
+        Returns:
+            ToolResult: Skipped result explaining how to configure ESLint.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            output=(
+                "No ESLint flat config (eslint.config.js, .mjs, .cjs, .ts, .mts "
+                "or .cts) found. Add one, or point to it with "
+                "--tool-options eslint:config=PATH."
+            ),
+            issues_count=0,
+            skipped=True,
+            skip_reason="no flat config",
+        )
+
+    def _create_failure_result(self, output: str) -> ToolResult:
+        """Create a failed result for a run that reported no problems.
+
+        ESLint exits with status 2 and no JSON report when its config is
+        invalid or a plugin cannot be loaded.
+
+        Args:
+            output: Output of the failed run.
+
+        Returns:
+            ToolResult: Failed result carrying ESLint's message.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=output.strip() or "ESLint execution failed.",
+            issues_count=0,
+        )
+
+    def _lacks_flat_config(self, options: dict[str, object], cwd: str | None) -> bool:
+        """Check whether ESLint would run without any flat config.
+
+        Args:
+            options: Options dict (use merged_options).
+            cwd: Directory ESLint runs in.
+
+        Returns:
+            True if no config is given and none is found from ``cwd`` upward.
+        """
+        if options.get("config"):
+            return False
+        return find_flat_config(cwd or ".") is None
+
+    def _build_command(self, options: dict[str, object]) -> list[str]:
+        """Build the ESLint command with the JSON formatter.
+
+        Args:
+            options: Options dict to build args from (use merged_options).
+
+        Returns:
+            Command without ``--fix`` and file arguments.
+        """
+        # Explicit file lists may include files the flat config ignores
+        cmd: list[str] = self._get_executable_command(tool_name="eslint") + [
+            "--format",
+            "json",
+            "--no-warn-ignored",
+            "--no-error-on-unmatched-pattern",
+        ]
+
+        config = options.get("config")
+        if config:
+            cmd.extend(["--config", str(config)])
+
+        if options.get("quiet", False):
+            cmd.append("--quiet")
+
+        return cmd
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check files with ESLint without making changes.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        merged_options = dict(self.options)
+        merged_options.update(options)
+
+        # Use shared preparation for version check, path validation, file discovery
+        ctx = self._prepare_execution(
+            paths,
+            merged_options,
+            no_files_message="No files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        if self._lacks_flat_config(merged_options, ctx.cwd):
+            return self._create_no_config_result()
+
+        logger.debug(
+            f"[EslintPlugin] Discovered {len(ctx.files)} files matching patterns: "
+            f"{self.definition.file_patterns}",
+        )
+        logger.debug(f"[EslintPlugin] Working directory: {ctx.cwd}")
+
+        cmd = self._build_command(merged_options) + ctx.rel_files
+        logger.debug(f"[EslintPlugin] Running: {' '.join(cmd)} (cwd={ctx.cwd})")
+
+        try:
+            run_success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        issues: list[EslintIssue] = parse_eslint_output(output=output)
+        issues_count: int = len(issues)
+        if not run_success and issues_count == 0:
+            return self._create_failure_result(output)
+        success: bool = issues_count == 0
+
+        return ToolResult(
+            name=self.definition.name,
+            success=success,
+            output=None if success else output,
+            issues_count=issues_count,
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Fix auto-fixable issues in files with ESLint.
+
+        ``eslint --fix`` reports the issues left after fixing, so one check
+        before and one fix run are enough to count both.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        merged_options = dict(self.options)
+        merged_options.update(options)
+
+        # Use shared preparation for version check, path validation, file discovery
+        ctx = self._prepare_execution(
+            paths,
+            merged_options,
+            no_files_message="No files to fix.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        if self._lacks_flat_config(merged_options, ctx.cwd):
+            return self._create_no_config_result()
+
+        base_cmd = self._build_command(merged_options)
+
+        # Check for initial issues
+        try:
+            check_success, check_output = self._run_subprocess(
+                cmd=base_cmd + ctx.rel_files,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        initial_issues: list[EslintIssue] = parse_eslint_output(output=check_output)
+        initial_count: int = len(initial_issues)
+        if not check_success and initial_count == 0:
+            return self._create_failure_result(check_output)
+
+        # Fix and collect what remains in the same run
+        fix_cmd: list[str] = base_cmd + ["--fix"] + ctx.rel_files
+        logger.debug(f"[EslintPlugin] Fixing: {' '.join(fix_cmd)} (cwd={ctx.cwd})")
+
+        try:
+            _, fix_output = self._run_subprocess(
+                cmd=fix_cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_issues=initial_issues,
+                initial_count=initial_count,
+            )
+
+        remaining_issues: list[EslintIssue] = parse_eslint_output(output=fix_output)
+        remaining_count: int = len(remaining_issues)
+        fixed_count: int = max(0, initial_count - remaining_count)
+
+        # Build output message
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+            for issue in remaining_issues[:5]:
+                output_lines.append(f"  {issue.file} - {issue.message}")
+            if len(remaining_issues) > 5:
+                output_lines.append(f"  ... and {len(remaining_issues) - 5} more")
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        # Add verbose raw fix output only when explicitly requested
+        if merged_options.get("verbose_fix_output", False) and fix_output.strip():
+            output_lines.append(f"Fix output:\n{fix_output}")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -0,0 +1,181 @@
+"""Frontmatter tool definition.
+
+Native validator for the YAML frontmatter of Markdown pages used by static
+site generators such as Hugo, Jekyll and Docusaurus. Frontmatter is checked
+against a user-supplied JSON Schema and violations point at the offending
+key. Runs inside Lintro, so no external binary is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.frontmatter.frontmatter_issue import FrontmatterIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_str,
+)
+from lintro.tools.implementations.frontmatter import check_frontmatter, load_schema
+
+# Constants for frontmatter configuration
+FRONTMATTER_DEFAULT_TIMEOUT: int = 60
+FRONTMATTER_DEFAULT_PRIORITY: int = 30
+FRONTMATTER_FILE_PATTERNS: list[str] = ["*.md", "*.markdown", "*.mdx"]
+
+
+@register_tool
+@dataclass
+class FrontmatterPlugin(BaseToolPlugin):
+    """Native frontmatter schema validation plugin.
+
+    This plugin validates Markdown frontmatter against a JSON Schema without
+    invoking any external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="frontmatter",
+            description="Markdown frontmatter validation against a JSON Schema",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=FRONTMATTER_FILE_PATTERNS,
+            priority=FRONTMATTER_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": FRONTMATTER_DEFAULT_TIMEOUT,
+                "schema": None,
+                "require_frontmatter": False,
+            },
+            default_timeout=FRONTMATTER_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        schema: str | None = None,
+        require_frontmatter: bool | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set frontmatter-specific options with validation.
+
+        Args:
+            schema: Path to the JSON Schema file, relative to the directory
+                Lintro runs in.
+            require_frontmatter: Report Markdown files without frontmatter.
+            **kwargs: Additional base options.
+        """
+        validate_str(schema, "schema")
+        validate_bool(require_frontmatter, "require_frontmatter")
+
+        options = filter_none_options(
+            schema=schema,
+            require_frontmatter=require_frontmatter,
+        )
+        super().set_options(**options, **kwargs)
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check Markdown frontmatter against the configured schema.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        merged_options = {**self.options, **options}
+        schema_path = merged_options.get("schema")
+        if not schema_path:
+            return ToolResult(
+                name=self.definition.name,
+                output=(
+                    "No frontmatter schema configured. "
+                    "Set tools.frontmatter.schema to a JSON Schema file."
+                ),
+                issues_count=0,
+                skipped=True,
+                skip_reason="no schema configured",
+            )
+        try:
+            schema: dict[str, Any] = load_schema(str(schema_path))
+        except ValueError as e:
+            return ToolResult(
+                name=self.definition.name,
+                success=False,
+                output=str(e),
+                issues_count=0,
+            )
+
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        require_frontmatter = bool(merged_options.get("require_frontmatter", False))
+        all_issues: list[FrontmatterIssue] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                with open(file_path, encoding="utf-8") as f:
+                    text = f.read()
+            except (OSError, UnicodeDecodeError) as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            all_issues.extend(
+                check_frontmatter(
+                    text,
+                    rel_path,
+                    schema,
+                    require_frontmatter=require_frontmatter,
+                ),
+            )
+
+        logger.debug(
+            f"[FrontmatterPlugin] Checked {len(ctx.files)} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Frontmatter problems cannot be fixed automatically.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: The frontmatter validator does not support
+                fixing.
+        """
+        raise NotImplementedError(
+            "frontmatter cannot automatically fix issues. Run 'lintro check' to "
+            "see issues.",
+        )
@@ -3,6 +3,7 @@
 Gitleaks is a SAST tool for detecting and preventing hardcoded secrets like
 passwords, API keys, and tokens in git repos. It scans for patterns that match
 known secret formats and reports findings with detailed location information.
+Scanning the whole tree is slow on large repositories, so the tool is opt-in.
 """
 
 from __future__ import annotations
@@ -20,6 +21,7 @@ from loguru import logger
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.gitleaks.gitleaks_parser import parse_gitleaks_output
 from lintro.plugins.base import BaseToolPlugin
@@ -31,6 +33,7 @@ from lintro.tools.core.option_validators import (
     validate_positive_int,
     validate_str,
 )
+from lintro.utils.text_decoding import read_output_file
 
 # Constants for Gitleaks configuration
 GITLEAKS_DEFAULT_TIMEOUT: int = 60
@@ -72,17 +75,21 @@ class GitleaksPlugin(BaseToolPlugin):
             default_options={
                 "timeout": GITLEAKS_DEFAULT_TIMEOUT,
                 "no_git": True,  # Default to scanning files without git history
+                "staged": False,
                 "config": None,
                 "baseline_path": None,
                 "redact": True,
                 "max_target_megabytes": None,
             },
             default_timeout=GITLEAKS_DEFAULT_TIMEOUT,
+            enabled_by_default=False,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
         self,
         no_git: bool | None = None,
+        staged: bool | None = None,
         config: str | None = None,
         baseline_path: str | None = None,
         redact: bool | None = None,
@@ -93,6 +100,7 @@ class GitleaksPlugin(BaseToolPlugin):
 
         Args:
             no_git: Scan without git history (files only).
+            staged: Scan only the changes staged for commit.
             config: Path to gitleaks config file.
             baseline_path: Path to baseline file (ignore known secrets).
             redact: Redact secrets in output.
@@ -100,6 +108,7 @@ class GitleaksPlugin(BaseToolPlugin):
             **kwargs: Other tool options.
         """
         validate_bool(value=no_git, name="no_git")
+        validate_bool(value=staged, name="staged")
         validate_str(value=config, name="config")
         validate_str(value=baseline_path, name="baseline_path")
         validate_bool(value=redact, name="redact")
@@ -107,6 +116,7 @@ class GitleaksPlugin(BaseToolPlugin):
 
         options = filter_none_options(
             no_git=no_git,
+            staged=staged,
             config=config,
             baseline_path=baseline_path,
             redact=redact,
@@ -124,13 +134,16 @@ class GitleaksPlugin(BaseToolPlugin):
         Returns:
             List of command arguments.
         """
-        cmd: list[str] = ["gitleaks", "detect"]
+        staged = bool(self.options.get("staged", False))
+        cmd: list[str] = (
+            ["gitleaks", "protect", "--staged"] if staged else ["gitleaks", "detect"]
+        )
 
         # Source path
         cmd.extend(["--source", source_path])
 
-        # Scan without git history by default
-        if self.options.get("no_git", True):
+        # Scan without git history by default; staged scans read the index
+        if not staged and self.options.get("no_git", True):
             cmd.append("--no-git")
 
         # Config file
@@ -180,7 +193,10 @@ class GitleaksPlugin(BaseToolPlugin):
         # Determine source path based on provided paths
         # Gitleaks can scan both directories and individual files
         cwd_path = Path(ctx.cwd) if ctx.cwd else Path.cwd()
-        if paths and len(paths) == 1:
+        if self.options.get("staged", False):
+            # Staged changes belong to the repository, not to a path
+            source_path = str(cwd_path)
+        elif paths and len(paths) == 1:
             # Single path provided - use it directly
             source_path = paths[0]
         elif paths and len(paths) > 1:
@@ -230,7 +246,7 @@ class GitleaksPlugin(BaseToolPlugin):
                     cwd=ctx.cwd,
                 )
                 # Read the report from the temp file
-                output = Path(report_path).read_text(encoding="utf-8").strip()
+                output = read_output_file(report_path, "gitleaks").strip()
             except subprocess.TimeoutExpired:
                 timeout_msg = (
                     f"Gitleaks execution timed out ({ctx.timeout}s limit exceeded)."
@@ -242,6 +258,7 @@ class GitleaksPlugin(BaseToolPlugin):
                     name=self.definition.name,
                     success=False,
                     output=timeout_msg,
+                    error_code=TOOL_TIMEOUT.code,
                     issues_count=0,
                 )
             except (OSError, ValueError, RuntimeError) as e:
@@ -0,0 +1,284 @@
+"""gofmt tool definition.
+
+gofmt is the canonical formatter shipped with Go. Checks run it with ``-d``
+and report every hunk of the diff; ``lintro format`` rewrites the files
+with ``-w``. The stricter gofumpt, a drop-in replacement, can be used
+instead through the ``gofumpt`` option.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.gofmt.gofmt_issue import GofmtIssue
+from lintro.parsers.gofmt.gofmt_parser import parse_gofmt_output
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_positive_int,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for gofmt configuration
+GOFMT_DEFAULT_TIMEOUT: int = 60
+GOFMT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("gofmt", 25)
+GOFMT_FILE_PATTERNS: list[str] = ["*.go"]
+
+
+@register_tool
+@dataclass
+class GofmtPlugin(BaseToolPlugin):
+    """gofmt Go formatter plugin.
+
+    This plugin runs ``gofmt -d`` (or ``gofumpt -d``) for checks and
+    ``-w`` for fixes from the project root, passing the discovered files
+    directly so generated or vendored directories outside the checked paths
+    are left alone.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="gofmt",
+            description="Canonical Go formatter, with optional gofumpt rules",
+            can_fix=True,
+            tool_type=ToolType.FORMATTER,
+            file_patterns=GOFMT_FILE_PATTERNS,
+            priority=GOFMT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=["go", "version"],
+            min_version=get_min_version(ToolName.GOFMT),
+            default_options={
+                "timeout": GOFMT_DEFAULT_TIMEOUT,
+                "gofumpt": False,
+                "simplify": False,
+            },
+            default_timeout=GOFMT_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        gofumpt: bool | None = None,
+        simplify: bool | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set gofmt-specific options.
+
+        Args:
+            gofumpt: Format with gofumpt instead of gofmt.
+            simplify: Simplify code with ``-s`` (gofmt only; gofumpt always
+                simplifies).
+            timeout: Timeout in seconds (default: 60).
+            **kwargs: Additional base options.
+        """
+        validate_bool(gofumpt, "gofumpt")
+        validate_bool(simplify, "simplify")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(
+            gofumpt=gofumpt,
+            simplify=simplify,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, files: list[str], check_only: bool) -> list[str]:
+        """Build the gofmt or gofumpt command.
+
+        Args:
+            files: Files to format, relative to the working directory.
+            check_only: Whether to print a diff instead of rewriting files.
+
+        Returns:
+            List of command arguments.
+        """
+        if self.options.get("gofumpt"):
+            cmd = self._get_executable_command(tool_name="gofumpt")
+        else:
+            cmd = self._get_executable_command(tool_name="gofmt")
+            if self.options.get("simplify"):
+                cmd.append("-s")
+        cmd.append("-d" if check_only else "-w")
+        cmd.extend(files)
+        return cmd
+
+    def _run_fmt(
+        self,
+        ctx: ExecutionContext,
+        check_only: bool = True,
+    ) -> tuple[bool, str]:
+        """Run the formatter on the discovered files.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+            check_only: Whether to print a diff instead of rewriting files.
+
+        Returns:
+            Tuple of (success, output).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        cmd = self._build_command(ctx.rel_files, check_only=check_only)
+        logger.debug(f"[GofmtPlugin] Running: {' '.join(cmd[:8])}... (cwd={ctx.cwd})")
+        return self._run_subprocess(cmd=cmd, timeout=ctx.timeout, cwd=ctx.cwd)
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"gofmt execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options gofmt:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def _create_failure_result(self, output: str) -> ToolResult:
+        """Create a failed result for a run that reported nothing.
+
+        Args:
+            output: Output of the failed run.
+
+        Returns:
+            ToolResult: Failed result carrying the formatter's output.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=output.strip() or "gofmt execution failed.",
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check Go files for formatting with gofmt.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            run_success, output = self._run_fmt(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        issues: list[GofmtIssue] = parse_gofmt_output(output=output)
+        if not run_success and not issues:
+            return self._create_failure_result(output)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Format Go files in place with gofmt.
+
+        Args:
+            paths: List of file or directory paths to format.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            check_success, check_output = self._run_fmt(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        initial_issues = parse_gofmt_output(output=check_output)
+        initial_count = len(initial_issues)
+        if not check_success and initial_count == 0:
+            return self._create_failure_result(check_output)
+
+        try:
+            if any(issue.fixable for issue in initial_issues):
+                self._run_fmt(ctx, check_only=False)
+            _, remaining_output = self._run_fmt(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_count=initial_count,
+            )
+
+        remaining_issues = parse_gofmt_output(output=remaining_output)
+        remaining_count = len(remaining_issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -0,0 +1,278 @@
+"""golangci-lint tool definition.
+
+golangci-lint runs many Go linters (staticcheck, errcheck, govet, ...) in
+one pass over type-checked packages. Packages are loaded through the Go
+module they belong to, so lintro runs golangci-lint from each module root
+holding checked files, passing the packages of those files.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.golangci_lint.golangci_lint_issue import GolangciLintIssue
+from lintro.parsers.golangci_lint.golangci_lint_parser import (
+    parse_golangci_lint_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_positive_int,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for golangci-lint configuration
+GOLANGCI_LINT_DEFAULT_TIMEOUT: int = 300
+GOLANGCI_LINT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get(
+    "golangci_lint",
+    50,
+)
+GOLANGCI_LINT_FILE_PATTERNS: list[str] = ["*.go"]
+GO_MOD_FILE: str = "go.mod"
+
+
+def find_go_module_root(file_path: str | Path) -> Path | None:
+    """Find the root of the Go module a file belongs to.
+
+    Args:
+        file_path: Go source file.
+
+    Returns:
+        Directory holding the nearest ``go.mod``, or None if the file is not
+        part of a module.
+    """
+    directory = Path(file_path).resolve().parent
+    for candidate in (directory, *directory.parents):
+        if (candidate / GO_MOD_FILE).is_file():
+            return candidate
+    return None
+
+
+def group_by_module(files: list[str]) -> dict[Path, list[Path]]:
+    """Group Go files by the module golangci-lint has to run from.
+
+    Files outside any module are grouped by their own directory, where
+    golangci-lint checks them as a single package.
+
+    Args:
+        files: Go source files.
+
+    Returns:
+        dict[Path, list[Path]]: Resolved files keyed by module root.
+    """
+    groups: dict[Path, list[Path]] = {}
+    for file_path in files:
+        resolved = Path(file_path).resolve()
+        root = find_go_module_root(resolved) or resolved.parent
+        groups.setdefault(root, []).append(resolved)
+    return groups
+
+
+def package_patterns(root: Path, files: list[Path]) -> list[str]:
+    """Build the package arguments for files within one module.
+
+    Args:
+        root: Module root golangci-lint runs from.
+        files: Resolved Go files inside the module.
+
+    Returns:
+        list[str]: Sorted, distinct package directories relative to the
+        root, each prefixed with ``./`` as Go expects.
+    """
+    packages = {Path(os.path.relpath(f.parent, root)).as_posix() for f in files}
+    return sorted("." if p == "." else f"./{p}" for p in packages)
+
+
+@register_tool
+@dataclass
+class GolangciLintPlugin(BaseToolPlugin):
+    """golangci-lint Go linter plugin.
+
+    This plugin runs ``golangci-lint run`` with JSON output in each module
+    holding checked files and reports the issues found in those files.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="golangci_lint",
+            description="Fast Go linters runner",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=GOLANGCI_LINT_FILE_PATTERNS,
+            priority=GOLANGCI_LINT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[
+                ".golangci.yml",
+                ".golangci.yaml",
+                ".golangci.toml",
+                ".golangci.json",
+            ],
+            version_command=["golangci-lint", "--version"],
+            min_version=get_min_version(ToolName.GOLANGCI_LINT),
+            default_options={
+                "timeout": GOLANGCI_LINT_DEFAULT_TIMEOUT,
+                "build_tags": None,
+            },
+            default_timeout=GOLANGCI_LINT_DEFAULT_TIMEOUT,
+            per_file=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        build_tags: list[str] | str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set golangci-lint-specific options.
+
+        Args:
+            build_tags: Build tags to load packages with (``--build-tags``).
+            timeout: Timeout in seconds per module (default: 300).
+            **kwargs: Additional base options.
+        """
+        build_tags = normalize_str_or_list(build_tags, "build_tags")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(build_tags=build_tags, timeout=timeout)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, packages: list[str]) -> list[str]:
+        """Build the golangci-lint command for packages of one module.
+
+        Args:
+            packages: Package directories relative to the module root.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="golangci-lint")
+        cmd.extend(
+            [
+                "run",
+                "--output.json.path=stdout",
+                "--show-stats=false",
+                "--path-mode=abs",
+                "--max-issues-per-linter=0",
+                "--max-same-issues=0",
+            ],
+        )
+        build_tags = self.options.get("build_tags")
+        if isinstance(build_tags, list) and build_tags:
+            cmd.append(f"--build-tags={','.join(build_tags)}")
+        cmd.extend(packages)
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int, module: Path) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            module: Module root being linted.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"golangci-lint execution timed out in {module} "
+            f"({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options golangci_lint:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint Go packages with golangci-lint.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        issues: list[GolangciLintIssue] = []
+        errors: list[str] = []
+
+        for root, files in group_by_module(ctx.files).items():
+            cmd = self._build_command(package_patterns(root, files))
+            logger.debug(f"[GolangciLintPlugin] Running: {' '.join(cmd)} (cwd={root})")
+            try:
+                run_success, output = self._run_subprocess(
+                    cmd=cmd,
+                    timeout=ctx.timeout,
+                    cwd=str(root),
+                )
+            except subprocess.TimeoutExpired:
+                return self._create_timeout_result(ctx.timeout, root)
+
+            report = parse_golangci_lint_output(output=output)
+            if report is None:
+                if not run_success:
+                    message = output.strip() or "golangci-lint failed"
+                    errors.append(f"{root}: {message}")
+                continue
+
+            errors.extend(f"{root}: {message}" for message in report.errors)
+            checked = set(files)
+            for issue in report.issues:
+                resolved = (root / issue.file).resolve()
+                # Other files in the checked packages were linted too
+                if resolved in checked:
+                    issue.file = str(resolved)
+                    issues.append(issue)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues and not errors,
+            output="\n".join(errors) if errors else None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """golangci-lint fixes are not applied by lintro, only reported.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: golangci-lint fixing is not supported.
+        """
+        raise NotImplementedError(
+            "golangci-lint fixes cannot be applied by lintro. Run 'lintro check' "
+            "to see issues.",
+        )
@@ -0,0 +1,299 @@
+"""ktlint tool definition.
+
+ktlint is the Kotlin linter and formatter from Pinterest, enforcing the
+Kotlin coding conventions with the rules configured in ``.editorconfig``.
+lintro runs it with the JSON reporter and maps ``lintro format`` to
+``ktlint --format``.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ktlint.ktlint_issue import KtlintIssue
+from lintro.parsers.ktlint.ktlint_parser import parse_ktlint_output
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for ktlint configuration
+KTLINT_DEFAULT_TIMEOUT: int = 120
+KTLINT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("ktlint", 50)
+KTLINT_FILE_PATTERNS: list[str] = ["*.kt", "*.kts"]
+
+
+@register_tool
+@dataclass
+class KtlintPlugin(BaseToolPlugin):
+    """ktlint Kotlin linter and formatter plugin.
+
+    This plugin runs ``ktlint --reporter=json`` on the discovered Kotlin
+    sources and scripts.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="ktlint",
+            description="Kotlin linter and formatter",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=KTLINT_FILE_PATTERNS,
+            priority=KTLINT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".editorconfig"],
+            version_command=["ktlint", "--version"],
+            min_version=get_min_version(ToolName.KTLINT),
+            default_options={
+                "timeout": KTLINT_DEFAULT_TIMEOUT,
+                "editorconfig": None,
+                "baseline": None,
+            },
+            default_timeout=KTLINT_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        editorconfig: str | None = None,
+        baseline: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set ktlint-specific options.
+
+        Args:
+            editorconfig: Path to a default ``.editorconfig``, used for
+                properties the project's ``.editorconfig`` files leave unset.
+            baseline: Path to a ktlint baseline file; violations listed in
+                it are not reported.
+            timeout: Timeout in seconds (default: 120).
+            **kwargs: Additional base options.
+        """
+        validate_str(editorconfig, "editorconfig")
+        validate_str(baseline, "baseline")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(
+            editorconfig=editorconfig,
+            baseline=baseline,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, files: list[str], fix: bool = False) -> list[str]:
+        """Build the ktlint command.
+
+        Args:
+            files: Files to check, relative to the working directory.
+            fix: Whether to format the files in place.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="ktlint")
+        cmd.extend(["--reporter=json", "--relative"])
+        editorconfig = self.options.get("editorconfig")
+        if editorconfig:
+            cmd.append(f"--editorconfig={editorconfig}")
+        baseline = self.options.get("baseline")
+        if baseline:
+            cmd.append(f"--baseline={baseline}")
+        if fix:
+            cmd.append("--format")
+        cmd.extend(files)
+        return cmd
+
+    def _run_ktlint(
+        self,
+        ctx: ExecutionContext,
+        fix: bool = False,
+    ) -> tuple[list[KtlintIssue] | None, str]:
+        """Run ktlint on the discovered files.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+            fix: Whether to format the files in place.
+
+        Returns:
+            Tuple of (parsed issues, or None if a failed run printed no
+            report, output).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        cmd = self._build_command(ctx.rel_files, fix=fix)
+        logger.debug(f"[KtlintPlugin] Running: {' '.join(cmd[:8])}... (cwd={ctx.cwd})")
+        success, output = self._run_subprocess(
+            cmd=cmd,
+            timeout=ctx.timeout,
+            cwd=ctx.cwd,
+        )
+        issues = parse_ktlint_output(output=output)
+        if issues is None and success:
+            # A clean run may print no report at all
+            issues = []
+        return issues, output
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"ktlint execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options ktlint:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def _create_failure_result(self, output: str) -> ToolResult:
+        """Create a ToolResult for failed runs that produced no report.
+
+        Args:
+            output: Output of the run.
+
+        Returns:
+            ToolResult: Result failing with ktlint's output.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=output.strip() or "ktlint execution failed.",
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint Kotlin files with ktlint.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Kotlin files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            issues, output = self._run_ktlint(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        if issues is None:
+            return self._create_failure_result(output)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Format Kotlin files in place with ktlint.
+
+        Args:
+            paths: List of file or directory paths to format.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Kotlin files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            initial_issues, output = self._run_ktlint(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+        if initial_issues is None:
+            return self._create_failure_result(output)
+
+        initial_count = len(initial_issues)
+        remaining_issues = initial_issues
+        if initial_issues:
+            try:
+                issues, output = self._run_ktlint(ctx, fix=True)
+            except subprocess.TimeoutExpired:
+                return self._create_timeout_result(
+                    timeout_val=ctx.timeout,
+                    initial_count=initial_count,
+                )
+            if issues is None:
+                return self._create_failure_result(output)
+            remaining_issues = issues
+
+        remaining_count = len(remaining_issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -0,0 +1,289 @@
+"""License header tool definition.
+
+Native checker for license and copyright headers. Verifies that every file
+with a known comment syntax starts with the header rendered from a
+configurable template, and inserts the header when fixing. Runs inside
+Lintro, so no external binary is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.license_header.license_header_issue import LicenseHeaderIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_dict,
+    validate_str,
+)
+from lintro.tools.definitions.editorconfig import read_text_file
+from lintro.tools.implementations.license_header import (
+    LicenseHeader,
+    check_text,
+    fix_text,
+    load_license_header,
+    parse_comment_styles,
+)
+
+# Constants for license header configuration
+LICENSE_HEADER_DEFAULT_TIMEOUT: int = 60
+# Inserts headers before the formatters run
+LICENSE_HEADER_DEFAULT_PRIORITY: int = 5
+# Files are filtered by comment style, which users can extend
+LICENSE_HEADER_FILE_PATTERNS: list[str] = ["*"]
+
+
+@register_tool
+@dataclass
+class LicenseHeaderPlugin(BaseToolPlugin):
+    """Native license header plugin.
+
+    This plugin checks files for the configured license header and inserts
+    it where it is missing without invoking any external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="license_header",
+            description="License and copyright header check and insertion",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=LICENSE_HEADER_FILE_PATTERNS,
+            priority=LICENSE_HEADER_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": LICENSE_HEADER_DEFAULT_TIMEOUT,
+                "template": None,
+                "template_file": None,
+                "owner": None,
+                "year": None,
+                "comment_styles": None,
+            },
+            default_timeout=LICENSE_HEADER_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        template: str | None = None,
+        template_file: str | None = None,
+        owner: str | None = None,
+        year: str | int | None = None,
+        comment_styles: dict[str, object] | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set license header options with validation.
+
+        Args:
+            template: Header text with ``{year}`` and ``{owner}``
+                placeholders.
+            template_file: File holding the header text, relative to the
+                directory Lintro runs in.
+            owner: Copyright holder for ``{owner}``.
+            year: Year for ``{year}`` in inserted headers.
+            comment_styles: File suffixes or names mapped to a line comment
+                prefix or to ``[start, prefix, end]``.
+            **kwargs: Additional base options.
+        """
+        if isinstance(year, int) and not isinstance(year, bool):
+            year = str(year)
+        validate_str(template, "template")
+        validate_str(template_file, "template_file")
+        validate_str(owner, "owner")
+        validate_str(year, "year")
+        validate_dict(comment_styles, "comment_styles")
+        if comment_styles:
+            parse_comment_styles(comment_styles)
+
+        options = filter_none_options(
+            template=template,
+            template_file=template_file,
+            owner=owner,
+            year=year,
+            comment_styles=comment_styles,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _load_header(
+        self,
+        options: dict[str, object],
+    ) -> LicenseHeader | ToolResult:
+        """Build the license header from the merged options.
+
+        Args:
+            options: Runtime options that override defaults.
+
+        Returns:
+            LicenseHeader | ToolResult: The header, or the result to return
+            when no template is configured or the options are invalid.
+        """
+        merged_options = {**self.options, **options}
+        comment_styles = merged_options.get("comment_styles")
+        try:
+            header = load_license_header(
+                template=str(merged_options.get("template") or "") or None,
+                template_file=str(merged_options.get("template_file") or "") or None,
+                owner=str(merged_options.get("owner") or "") or None,
+                year=str(merged_options.get("year") or "") or None,
+                comment_styles=(
+                    comment_styles if isinstance(comment_styles, dict) else None
+                ),
+            )
+        except ValueError as e:
+            return ToolResult(
+                name=self.definition.name,
+                success=False,
+                output=str(e),
+                issues_count=0,
+            )
+        if header is None:
+            return ToolResult(
+                name=self.definition.name,
+                output=(
+                    "No license header configured. Set tools.license_header."
+                    "template or template_file."
+                ),
+                issues_count=0,
+                skipped=True,
+                skip_reason="no template configured",
+            )
+        return header
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check files for the configured license header.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        header = self._load_header(options)
+        if isinstance(header, ToolResult):
+            return header
+
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        all_issues: list[LicenseHeaderIssue] = []
+        errors: list[str] = []
+        checked = 0
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            style = header.style_for(file_path)
+            if style is None:
+                continue
+            try:
+                text = read_text_file(file_path)
+            except OSError as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            if text is None:
+                continue
+            checked += 1
+            all_issues.extend(check_text(text, rel_path, header, style))
+
+        logger.debug(
+            f"[LicenseHeaderPlugin] Checked {checked} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Insert missing license headers and report mismatched ones.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with fix results.
+        """
+        header = self._load_header(options)
+        if isinstance(header, ToolResult):
+            return header
+
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        initial_total = 0
+        remaining_issues: list[LicenseHeaderIssue] = []
+        fixed_files: list[str] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            style = header.style_for(file_path)
+            if style is None:
+                continue
+            try:
+                text = read_text_file(file_path)
+                if text is None:
+                    continue
+                initial_total += len(check_text(text, rel_path, header, style))
+                fixed_text = fix_text(text, header, style)
+                if fixed_text != text:
+                    with open(file_path, "w", encoding="utf-8", newline="") as f:
+                        f.write(fixed_text)
+                    fixed_files.append(rel_path)
+                    text = fixed_text
+            except OSError as e:
+                errors.append(f"Error processing {rel_path}: {e}")
+                continue
+            remaining_issues.extend(check_text(text, rel_path, header, style))
+
+        remaining = len(remaining_issues)
+        fixed = initial_total - remaining
+
+        summary_parts: list[str] = []
+        if fixed_files:
+            summary_parts.append(f"Added license headers to {len(fixed_files)} file(s)")
+        if remaining > 0:
+            summary_parts.append(
+                f"Found {remaining} issue(s) that cannot be auto-fixed",
+            )
+        summary_parts.extend(errors)
+
+        logger.debug(
+            f"[LicenseHeaderPlugin] Fix complete: initial={initial_total}, "
+            f"fixed={fixed}, remaining={remaining}",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and remaining == 0,
+            output="\n".join(summary_parts) if summary_parts else "No fixes needed.",
+            issues_count=remaining,
+            issues=remaining_issues,
+            initial_issues_count=initial_total,
+            fixed_issues_count=fixed,
+            remaining_issues_count=remaining,
+        )
@@ -0,0 +1,249 @@
+"""Linkcheck tool definition.
+
+Native dead-link checker for Markdown and HTML documentation. Relative links
+and anchors are checked against the repository; external URLs are requested
+over HTTP unless offline mode is enabled. Runs inside Lintro, so no external
+binary is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from pathlib import Path
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.linkcheck.linkcheck_issue import LinkcheckIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
+    validate_int,
+    validate_positive_int,
+)
+from lintro.tools.implementations.linkcheck import (
+    DEFAULT_CACHE_TTL,
+    DEFAULT_MAX_CONCURRENCY,
+    DEFAULT_REQUEST_TIMEOUT,
+    ExternalLink,
+    UrlCache,
+    check_document_links,
+    check_external_links,
+)
+
+# Constants for linkcheck configuration
+LINKCHECK_DEFAULT_TIMEOUT: int = 120
+LINKCHECK_DEFAULT_PRIORITY: int = 30
+LINKCHECK_FILE_PATTERNS: list[str] = ["*.md", "*.markdown", "*.html", "*.htm"]
+
+
+def _int_option(options: dict[str, object], name: str, default: int) -> int:
+    """Read an integer option, falling back to a default.
+
+    Args:
+        options: Merged tool options.
+        name: Option name.
+        default: Value used when the option is unset or not an integer.
+
+    Returns:
+        int: Option value.
+    """
+    value = options.get(name)
+    if isinstance(value, int) and not isinstance(value, bool):
+        return value
+    return default
+
+
+@register_tool
+@dataclass
+class LinkcheckPlugin(BaseToolPlugin):
+    """Native dead-link checker plugin.
+
+    This plugin validates relative links, anchors and external URLs in
+    documentation without invoking any external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="linkcheck",
+            description="Dead-link checker for Markdown and HTML docs",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=LINKCHECK_FILE_PATTERNS,
+            priority=LINKCHECK_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": LINKCHECK_DEFAULT_TIMEOUT,
+                "offline": False,
+                "allow_domains": [],
+                "request_timeout": DEFAULT_REQUEST_TIMEOUT,
+                "cache": True,
+                "cache_ttl": DEFAULT_CACHE_TTL,
+                "check_anchors": True,
+                "max_concurrency": DEFAULT_MAX_CONCURRENCY,
+            },
+            default_timeout=LINKCHECK_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        offline: bool | None = None,
+        allow_domains: str | list[str] | None = None,
+        request_timeout: int | None = None,
+        cache: bool | None = None,
+        cache_ttl: int | None = None,
+        check_anchors: bool | None = None,
+        max_concurrency: int | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set linkcheck-specific options with validation.
+
+        Args:
+            offline: Only check relative links and anchors.
+            allow_domains: Domains (and their subdomains) that are never
+                requested.
+            request_timeout: Timeout in seconds for each HTTP request.
+            cache: Whether to cache reachable URLs between runs.
+            cache_ttl: Seconds a cached URL stays valid.
+            check_anchors: Whether to verify ``#fragment`` targets.
+            max_concurrency: Maximum number of concurrent HTTP requests.
+            **kwargs: Additional base options.
+        """
+        validate_bool(offline, "offline")
+        domains = normalize_str_or_list(allow_domains, "allow_domains")
+        validate_positive_int(request_timeout, "request_timeout")
+        validate_bool(cache, "cache")
+        validate_int(cache_ttl, "cache_ttl", min_value=0)
+        validate_bool(check_anchors, "check_anchors")
+        validate_positive_int(max_concurrency, "max_concurrency")
+
+        options = filter_none_options(
+            offline=offline,
+            allow_domains=domains,
+            request_timeout=request_timeout,
+            cache=cache,
+            cache_ttl=cache_ttl,
+            check_anchors=check_anchors,
+            max_concurrency=max_concurrency,
+        )
+        super().set_options(**options, **kwargs)
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check documents for broken links.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        merged_options = {**self.options, **options}
+        offline = bool(merged_options.get("offline", False))
+        check_anchors = bool(merged_options.get("check_anchors", True))
+        raw_domains = merged_options.get("allow_domains")
+        allow_domains = (
+            [str(d) for d in raw_domains] if isinstance(raw_domains, list) else []
+        )
+
+        root = Path.cwd()
+        anchor_cache: dict[Path, set[str]] = {}
+        all_issues: list[LinkcheckIssue] = []
+        external: list[ExternalLink] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                with open(file_path, encoding="utf-8") as f:
+                    text = f.read()
+            except (OSError, UnicodeDecodeError) as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            issues, links = check_document_links(
+                text,
+                Path(file_path).resolve(),
+                rel_path,
+                root=root,
+                check_anchors=check_anchors,
+                anchor_cache=anchor_cache,
+            )
+            all_issues.extend(issues)
+            external.extend(links)
+
+        if external and not offline:
+            url_cache = (
+                UrlCache.load(
+                    ttl=_int_option(merged_options, "cache_ttl", DEFAULT_CACHE_TTL),
+                )
+                if merged_options.get("cache", True)
+                else None
+            )
+            all_issues.extend(
+                check_external_links(
+                    external,
+                    allow_domains=allow_domains,
+                    timeout=_int_option(
+                        merged_options,
+                        "request_timeout",
+                        DEFAULT_REQUEST_TIMEOUT,
+                    ),
+                    cache=url_cache,
+                    max_concurrency=_int_option(
+                        merged_options,
+                        "max_concurrency",
+                        DEFAULT_MAX_CONCURRENCY,
+                    ),
+                ),
+            )
+            if url_cache is not None:
+                url_cache.save()
+
+        logger.debug(
+            f"[LinkcheckPlugin] Checked {len(ctx.files)} file(s) and "
+            f"{len(external)} external link(s), found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Broken links cannot be fixed automatically.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: The link checker does not support fixing.
+        """
+        raise NotImplementedError(
+            "linkcheck cannot automatically fix issues. Run 'lintro check' to see "
+            "issues.",
+        )
@@ -0,0 +1,202 @@
+"""luacheck tool definition.
+
+luacheck is a static analyzer and linter for Lua, reporting unused and
+undefined variables, shadowing, unreachable code and syntax errors. lintro
+runs it with the plain formatter and warning codes. luacheck reads its
+settings from the nearest ``.luacheckrc``.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.luacheck.luacheck_parser import parse_luacheck_output
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for luacheck configuration
+LUACHECK_DEFAULT_TIMEOUT: int = 60
+LUACHECK_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("luacheck", 50)
+LUACHECK_FILE_PATTERNS: list[str] = ["*.lua"]
+
+
+@register_tool
+@dataclass
+class LuacheckPlugin(BaseToolPlugin):
+    """luacheck Lua linter plugin.
+
+    This plugin runs ``luacheck --formatter=plain --codes --ranges`` on the
+    discovered Lua files.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="luacheck",
+            description="Lua linter and static analyzer",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=LUACHECK_FILE_PATTERNS,
+            priority=LUACHECK_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".luacheckrc"],
+            version_command=["luacheck", "--version"],
+            min_version=get_min_version(ToolName.LUACHECK),
+            default_options={
+                "timeout": LUACHECK_DEFAULT_TIMEOUT,
+                "config": None,
+                "std": None,
+            },
+            default_timeout=LUACHECK_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        config: str | None = None,
+        std: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set luacheck-specific options.
+
+        Args:
+            config: Path to a luacheck config, instead of the
+                ``.luacheckrc`` luacheck finds.
+            std: Set of standard globals, such as ``luajit``, ``lua54``
+                or ``max+busted``.
+            timeout: Timeout in seconds (default: 60).
+            **kwargs: Additional base options.
+        """
+        validate_str(config, "config")
+        validate_str(std, "std")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(config=config, std=std, timeout=timeout)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, files: list[str]) -> list[str]:
+        """Build the luacheck command.
+
+        Args:
+            files: Files to check, relative to the working directory.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="luacheck")
+        cmd.extend(["--formatter=plain", "--codes", "--ranges", "--no-color"])
+        config = self.options.get("config")
+        if config:
+            cmd.extend(["--config", str(config)])
+        std = self.options.get("std")
+        if std:
+            cmd.extend(["--std", str(std)])
+        cmd.extend(files)
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"luacheck execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options luacheck:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint Lua files with luacheck.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Lua files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        cmd = self._build_command(ctx.rel_files)
+        logger.debug(
+            f"[LuacheckPlugin] Running: {' '.join(cmd[:8])}... (cwd={ctx.cwd})",
+        )
+        try:
+            run_success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        # luacheck exits 1 for warnings and 2 for errors, so only the
+        # lines it could not attribute to a finding mean the run failed
+        report = parse_luacheck_output(output)
+        errors = report.errors
+        if not run_success and not report.issues and not errors:
+            errors = ["luacheck execution failed."]
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not report.issues and not errors,
+            output="\n".join(errors) if errors else None,
+            issues_count=len(report.issues),
+            issues=report.issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """luacheck cannot fix issues.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: luacheck does not support fixing issues.
+        """
+        raise NotImplementedError(
+            "luacheck cannot automatically fix issues. Run 'lintro check' to see "
+            "issues.",
+        )
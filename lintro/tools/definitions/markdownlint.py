@@ -2,7 +2,8 @@
 
 Markdownlint-cli2 is a linter for Markdown files that checks for style
 issues and best practices. It helps maintain consistent formatting
-across documentation.
+across documentation, and ``lintro format`` applies the fixes the rules
+support.
 """
 
 from __future__ import annotations
@@ -18,14 +19,18 @@ from typing import Any
 from loguru import logger
 
 from lintro._tool_versions import get_min_version
+from lintro.config.tool_config_generator import has_native_config
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.markdownlint.markdownlint_parser import parse_markdownlint_output
-from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
-from lintro.tools.core.option_validators import validate_positive_int
+from lintro.tools.core.option_validators import (
+    validate_dict,
+    validate_positive_int,
+)
 from lintro.tools.core.timeout_utils import create_timeout_result
 from lintro.utils.config import get_central_line_length
 from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
@@ -55,7 +60,7 @@ class MarkdownlintPlugin(BaseToolPlugin):
         return ToolDefinition(
             name="markdownlint",
             description=("Markdown linter for style checking and best practices"),
-            can_fix=False,
+            can_fix=True,
             tool_type=ToolType.LINTER,
             file_patterns=MARKDOWNLINT_FILE_PATTERNS,
             priority=MARKDOWNLINT_DEFAULT_PRIORITY,
@@ -72,6 +77,7 @@ class MarkdownlintPlugin(BaseToolPlugin):
             default_options={
                 "timeout": MARKDOWNLINT_DEFAULT_TIMEOUT,
                 "line_length": None,
+                "rules": None,
             },
             default_timeout=MARKDOWNLINT_DEFAULT_TIMEOUT,
         )
@@ -85,6 +91,10 @@ class MarkdownlintPlugin(BaseToolPlugin):
             Optional[ToolResult]: None if version check passes, or a skip result
                 if it fails.
         """
+        if self.container is not None:
+            return None
+        from lintro.exceptions.errors import TOOL_NOT_INSTALLED, TOOL_VERSION_TOO_OLD
+        from lintro.plugins.execution_preparation import pin_mismatch_result
         from lintro.tools.core.version_requirements import check_tool_version
 
         # Use the correct command for markdownlint-cli2
@@ -94,6 +104,10 @@ class MarkdownlintPlugin(BaseToolPlugin):
         if version_info.version_check_passed:
             return None  # Version check passed
 
+        pin_result = pin_mismatch_result(self.definition.name, version_info)
+        if pin_result is not None:
+            return pin_result
+
         # Version check failed - return skip result with warning
         skip_message = (
             f"Skipping {self.definition.name}: {version_info.error_message}. "
@@ -106,12 +120,16 @@ class MarkdownlintPlugin(BaseToolPlugin):
             success=True,  # Not an error, just skipping
             output=skip_message,
             issues_count=0,
+            error_code=(
+                TOOL_NOT_INSTALLED if version_info.not_found else TOOL_VERSION_TOO_OLD
+            ).code,
         )
 
     def set_options(  # type: ignore[override]
         self,
         timeout: int | None = None,
         line_length: int | None = None,
+        rules: dict[str, Any] | None = None,
         **kwargs: Any,
     ) -> None:
         """Set Markdownlint-specific options.
@@ -121,13 +139,19 @@ class MarkdownlintPlugin(BaseToolPlugin):
             line_length: Line length for MD013 rule. If not provided, uses
                 central line_length from [tool.lintro] or falls back to Ruff's
                 line-length setting.
+            rules: Rule configuration keyed by rule name or alias, e.g.
+                ``{"MD041": False}``. Overrides the line_length setting and
+                the ``defaults.markdownlint`` tier.
             **kwargs: Other tool options.
         """
         validate_positive_int(timeout, "timeout")
+        validate_dict(rules, "rules")
 
         set_kwargs = dict(kwargs)
         if timeout is not None:
             set_kwargs["timeout"] = timeout
+        if rules is not None:
+            set_kwargs["rules"] = rules
 
         # Use provided line_length, or get from central config
         if line_length is None:
@@ -156,28 +180,33 @@ class MarkdownlintPlugin(BaseToolPlugin):
 
     def _create_temp_markdownlint_config(
         self,
-        line_length: int,
+        line_length: int | None = None,
+        rules: dict[str, Any] | None = None,
+        defaults: dict[str, Any] | None = None,
     ) -> str | None:
-        """Create a temporary markdownlint-cli2 config with the specified line length.
+        """Create a temporary markdownlint-cli2 config for the run.
 
-        Creates a temp file with MD013 rule configured. This avoids modifying
-        the user's project files.
+        Rule settings are layered: the ``defaults.markdownlint`` tier, then
+        the MD013 line length, then the ``rules`` option. This avoids
+        modifying the user's project files.
 
         Args:
             line_length: Line length to configure for MD013 rule.
+            rules: Rule configuration from the ``rules`` option.
+            defaults: Rule configuration from the ``defaults.markdownlint`` tier.
 
         Returns:
             Path to the temporary config file, or None if creation failed.
         """
-        config_wrapper: dict[str, object] = {
-            "config": {
-                "MD013": {
-                    "line_length": line_length,
-                    "code_blocks": False,
-                    "tables": False,
-                },
-            },
-        }
+        rule_config: dict[str, Any] = dict(defaults or {})
+        if line_length is not None:
+            rule_config["MD013"] = {
+                "line_length": line_length,
+                "code_blocks": False,
+                "tables": False,
+            }
+        rule_config.update(rules or {})
+        config_wrapper: dict[str, object] = {"config": rule_config}
 
         try:
             # Create a temp file that persists until explicitly deleted
@@ -193,7 +222,7 @@ class MarkdownlintPlugin(BaseToolPlugin):
 
             logger.debug(
                 f"[MarkdownlintPlugin] Created temp config at {temp_path} "
-                f"with line_length={line_length}",
+                f"with rules {sorted(rule_config)}",
             )
             return temp_path
 
@@ -203,57 +232,87 @@ class MarkdownlintPlugin(BaseToolPlugin):
             )
             return None
 
-    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
-        """Check files with Markdownlint.
-
-        Args:
-            paths: List of file or directory paths to check.
-            options: Runtime options that override defaults.
+    def _get_line_length(self) -> int | None:
+        """Get the configured MD013 line length.
 
         Returns:
-            ToolResult with check results.
+            The line length, or None if not set.
         """
-        # Use shared preparation for version check, path validation, file discovery
-        ctx = self._prepare_execution(paths, options)
-        if ctx.should_skip:
-            return ctx.early_result  # type: ignore[return-value]
+        line_length_opt = self.options.get("line_length")
+        if line_length_opt is None:
+            return None
+        if isinstance(line_length_opt, int):
+            return line_length_opt
+        return int(str(line_length_opt))
 
-        logger.debug(
-            f"[MarkdownlintPlugin] Discovered {len(ctx.files)} files matching "
-            f"patterns: {self.definition.file_patterns}",
+    def _build_markdownlint_config_args(self) -> tuple[list[str], str | None]:
+        """Build the config arguments for a markdownlint-cli2 run.
+
+        Without a ``rules`` option, Lintro config injection is used when it
+        applies, falling back to a temp config carrying the line length.
+        With ``rules``, a single temp config merges the ``defaults`` tier
+        (when the project has no native config), the line length and the
+        rules, so the rules win.
+
+        Returns:
+            The config arguments and the temp config to delete after the
+            run, if one was created.
+        """
+        rules_opt = self.options.get("rules")
+        rules = rules_opt if isinstance(rules_opt, dict) else {}
+        line_length = self._get_line_length()
+
+        defaults: dict[str, Any] = {}
+        if rules:
+            if not has_native_config(self.definition.name):
+                tool_defaults = self._get_lintro_config().get_tool_defaults(
+                    self.definition.name,
+                )
+                nested = tool_defaults.get("config")
+                defaults = nested if isinstance(nested, dict) else tool_defaults
+                if defaults:
+                    # The defaults carry their own MD013 setting
+                    line_length = None
+        else:
+            config_args = self._build_config_args()
+            if config_args:
+                logger.debug("[MarkdownlintPlugin] Using Lintro config injection")
+                return config_args, None
+            if line_length is None:
+                return [], None
+
+        temp_config_path = self._create_temp_markdownlint_config(
+            line_length=line_length,
+            rules=rules,
+            defaults=defaults,
         )
-        if ctx.files:
-            logger.debug(
-                f"[MarkdownlintPlugin] Files to check (first 10): {ctx.files[:10]}",
-            )
-        logger.debug(f"[MarkdownlintPlugin] Working directory: {ctx.cwd}")
+        if temp_config_path is None:
+            return [], None
+        return ["--config", temp_config_path], temp_config_path
 
-        # Build command
-        cmd: list[str] = self._get_markdownlint_command()
+    def _run_markdownlint(
+        self,
+        ctx: ExecutionContext,
+        fix: bool = False,
+    ) -> tuple[bool, str]:
+        """Run markdownlint-cli2 on the discovered files.
 
-        # Track temp config for cleanup
-        temp_config_path: str | None = None
+        Args:
+            ctx: Execution context from _prepare_execution.
+            fix: Whether to pass ``--fix`` so fixable issues are rewritten.
 
-        # Try Lintro config injection first
-        config_args = self._build_config_args()
-        if config_args:
-            cmd.extend(config_args)
-            logger.debug("[MarkdownlintPlugin] Using Lintro config injection")
-        else:
-            # Fallback: Apply line_length configuration if set
-            line_length_opt = self.options.get("line_length")
-            if line_length_opt is not None:
-                line_length_val = (
-                    int(line_length_opt)
-                    if isinstance(line_length_opt, int)
-                    else int(str(line_length_opt))
-                )
-                temp_config_path = self._create_temp_markdownlint_config(
-                    line_length=line_length_val,
-                )
-                if temp_config_path:
-                    cmd.extend(["--config", temp_config_path])
+        Returns:
+            Tuple of (success, output). With ``fix``, the output lists the
+            issues left after fixing.
 
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        cmd: list[str] = self._get_markdownlint_command()
+        config_args, temp_config_path = self._build_markdownlint_config_args()
+        cmd.extend(config_args)
+        if fix:
+            cmd.append("--fix")
         cmd.extend(ctx.rel_files)
 
         logger.debug(
@@ -261,23 +320,11 @@ class MarkdownlintPlugin(BaseToolPlugin):
         )
 
         try:
-            success, output = self._run_subprocess(
+            return self._run_subprocess(
                 cmd=cmd,
                 timeout=ctx.timeout,
                 cwd=ctx.cwd,
             )
-        except subprocess.TimeoutExpired:
-            timeout_result = create_timeout_result(
-                tool=self,
-                timeout=ctx.timeout,
-                cmd=cmd,
-            )
-            return ToolResult(
-                name=self.definition.name,
-                success=timeout_result.success,
-                output=timeout_result.output,
-                issues_count=timeout_result.issues_count,
-            )
         finally:
             # Clean up temp config file if created
             if temp_config_path:
@@ -292,6 +339,66 @@ class MarkdownlintPlugin(BaseToolPlugin):
                         f"[MarkdownlintPlugin] Failed to clean up temp config: {e}",
                     )
 
+    def _timeout_result(
+        self,
+        ctx: ExecutionContext,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Build the result for a run that timed out.
+
+        Args:
+            ctx: Execution context of the run.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult describing the timeout.
+        """
+        timeout_result = create_timeout_result(
+            tool=self,
+            timeout=ctx.timeout,
+            cmd=self._get_markdownlint_command(),
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=timeout_result.success,
+            output=timeout_result.output,
+            error_code=timeout_result.error_code,
+            issues_count=timeout_result.issues_count,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check files with Markdownlint.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        # Use shared preparation for version check, path validation, file discovery
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        logger.debug(
+            f"[MarkdownlintPlugin] Discovered {len(ctx.files)} files matching "
+            f"patterns: {self.definition.file_patterns}",
+        )
+        if ctx.files:
+            logger.debug(
+                f"[MarkdownlintPlugin] Files to check (first 10): {ctx.files[:10]}",
+            )
+        logger.debug(f"[MarkdownlintPlugin] Working directory: {ctx.cwd}")
+
+        try:
+            success, output = self._run_markdownlint(ctx)
+        except subprocess.TimeoutExpired:
+            return self._timeout_result(ctx)
+
         # Parse output
         issues = parse_markdownlint_output(output=output)
         issues_count: int = len(issues)
@@ -311,19 +418,63 @@ class MarkdownlintPlugin(BaseToolPlugin):
         )
 
     def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
-        """Markdownlint cannot fix issues, only report them.
+        """Fix Markdown files with markdownlint-cli2 ``--fix``.
+
+        Rules without fix support, such as MD013 line length, are reported
+        as remaining issues.
 
         Args:
             paths: List of file or directory paths to fix.
             options: Runtime options that override defaults.
 
         Returns:
-            ToolResult: Never returns, always raises NotImplementedError.
-
-        Raises:
-            NotImplementedError: Markdownlint is a linter only and cannot fix issues.
+            ToolResult with fix results.
         """
-        raise NotImplementedError(
-            "Markdownlint cannot fix issues; use a Markdown formatter"
-            " for formatting.",
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Markdown files to fix.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        # Count initial issues
+        try:
+            _, initial_output = self._run_markdownlint(ctx)
+        except subprocess.TimeoutExpired:
+            return self._timeout_result(ctx, initial_count=0)
+
+        initial_count = len(parse_markdownlint_output(output=initial_output))
+
+        # Apply fixes; the output lists what is left
+        try:
+            fix_success, fix_output = self._run_markdownlint(ctx, fix=True)
+        except subprocess.TimeoutExpired:
+            return self._timeout_result(ctx, initial_count=initial_count)
+
+        remaining_issues = parse_markdownlint_output(output=fix_output)
+        remaining_count = len(remaining_issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        # Build summary
+        summary: list[str] = []
+        if fixed_count > 0:
+            summary.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            summary.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            summary.append("All issues were successfully auto-fixed")
+        final_summary = "\n".join(summary) if summary else "No fixes applied."
+
+        return ToolResult(
+            name=self.definition.name,
+            success=fix_success and remaining_count == 0,
+            output=final_summary,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
         )
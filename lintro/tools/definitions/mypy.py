@@ -19,6 +19,7 @@ from lintro.enums.tool_type import ToolType
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.mypy.mypy_parser import parse_mypy_output
 from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.notebooks import check_notebooks
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
 from lintro.tools.core.timeout_utils import create_timeout_result
@@ -111,13 +112,15 @@ class MypyPlugin(BaseToolPlugin):
             min_version="1.0.0",
             default_options={
                 "timeout": MYPY_DEFAULT_TIMEOUT,
-                "strict": True,
-                "ignore_missing_imports": True,
+                # None: on unless the project's mypy config sets it
+                "strict": None,
+                "ignore_missing_imports": None,
                 "python_version": None,
                 "config_file": None,
                 "cache_dir": None,
             },
             default_timeout=MYPY_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
@@ -127,6 +130,7 @@ class MypyPlugin(BaseToolPlugin):
         python_version: str | None = None,
         config_file: str | None = None,
         cache_dir: str | None = None,
+        toolchain: str | None = None,
         **kwargs: Any,
     ) -> None:
         """Set Mypy-specific options.
@@ -137,6 +141,8 @@ class MypyPlugin(BaseToolPlugin):
             python_version: Python version target (e.g., "3.10").
             config_file: Path to mypy config file.
             cache_dir: Path to mypy cache directory.
+            toolchain: Python version for a toolchain-matrix run; overrides
+                python_version and enforce.target_python.
             **kwargs: Other tool options.
 
         Raises:
@@ -155,6 +161,8 @@ class MypyPlugin(BaseToolPlugin):
             raise ValueError("config_file must be a string path")
         if cache_dir is not None and not isinstance(cache_dir, str):
             raise ValueError("cache_dir must be a string path")
+        if toolchain is not None and not isinstance(toolchain, str):
+            raise ValueError("toolchain must be a string")
 
         options: dict[str, object] = {
             "strict": strict,
@@ -162,6 +170,7 @@ class MypyPlugin(BaseToolPlugin):
             "python_version": python_version,
             "config_file": config_file,
             "cache_dir": cache_dir,
+            "toolchain": toolchain,
         }
         options = {k: v for k, v in options.items() if v is not None}
         super().set_options(**options, **kwargs)
@@ -199,6 +208,23 @@ class MypyPlugin(BaseToolPlugin):
 
         return regex
 
+    def _flag_enabled(self, option: str) -> bool:
+        """Decide whether Lintro passes a boolean mypy flag.
+
+        An explicit option wins. Otherwise the flag is on unless the project's
+        mypy config sets it, so the config's own choice is kept.
+
+        Args:
+            option: Option named like the mypy setting (e.g. ``strict``).
+
+        Returns:
+            bool: True if the flag should be added to the command.
+        """
+        value = self.options.get(option)
+        if value is not None:
+            return bool(value)
+        return option not in (self._config_data or {})
+
     def _build_command(
         self,
         files: list[str],
@@ -235,12 +261,15 @@ class MypyPlugin(BaseToolPlugin):
         if config_args:
             cmd.extend(config_args)
 
-        if self.options.get("strict") is True:
+        if self._flag_enabled("strict"):
             cmd.append("--strict")
-        if self.options.get("ignore_missing_imports", True):
+        if self._flag_enabled("ignore_missing_imports"):
             cmd.append("--ignore-missing-imports")
 
-        if self.options.get("python_version") and "target_python" not in enforced:
+        if self.options.get("toolchain"):
+            # Comes after the config args, so it wins over an enforced target
+            cmd.extend(["--python-version", str(self.options["toolchain"])])
+        elif self.options.get("python_version") and "target_python" not in enforced:
             cmd.extend(["--python-version", str(self.options["python_version"])])
         if self.options.get("config_file") and "--config-file" not in config_args:
             cmd.extend(["--config-file", str(self.options["config_file"])])
@@ -301,6 +330,26 @@ class MypyPlugin(BaseToolPlugin):
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
         """Check files with Mypy.
 
+        Jupyter notebooks under the paths are checked as scripts made of
+        their code cells, with issues reported by cell.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        result = self._check_files(paths, options)
+        return check_notebooks(self, paths, options, result, self._check_files)
+
+    def _check_files(
+        self,
+        paths: list[str],
+        options: dict[str, object],
+    ) -> ToolResult:
+        """Check Python files with Mypy.
+
         Args:
             paths: List of file or directory paths to check.
             options: Runtime options that override defaults.
@@ -435,6 +484,7 @@ class MypyPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
             )
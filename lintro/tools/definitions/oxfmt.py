@@ -15,6 +15,7 @@ from loguru import logger
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.oxfmt.oxfmt_issue import OxfmtIssue
 from lintro.parsers.oxfmt.oxfmt_parser import parse_oxfmt_output
@@ -156,6 +157,7 @@ class OxfmtPlugin(BaseToolPlugin):
             name=self.definition.name,
             success=False,
             output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
             issues_count=combined_count,
             issues=combined_issues,
             initial_issues_count=combined_count,
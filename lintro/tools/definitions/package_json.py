@@ -0,0 +1,241 @@
+"""Package.json tool definition.
+
+Native checker for package.json manifests. Validates package name and
+version, field types, engines pinning and script naming, and sorts keys in
+sort-package-json order when fixing. Runs inside Lintro, so no external
+binary is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.package_json.package_json_issue import PackageJsonIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
+    validate_str,
+)
+from lintro.tools.implementations.package_json import (
+    DEFAULT_REQUIRED_ENGINES,
+    DEFAULT_SCRIPT_NAME_PATTERN,
+    check_package_json_text,
+    sort_package_json_text,
+)
+
+# Constants for package.json configuration
+PACKAGE_JSON_DEFAULT_TIMEOUT: int = 30
+# Lower priorities run first: before prettier (80), so prettier reformats
+# the sorted output
+PACKAGE_JSON_DEFAULT_PRIORITY: int = 79
+PACKAGE_JSON_FILE_PATTERNS: list[str] = ["package.json"]
+
+
+@register_tool
+@dataclass
+class PackageJsonPlugin(BaseToolPlugin):
+    """Native package.json manifest checker plugin.
+
+    This plugin validates package.json files and sorts their keys without
+    invoking any external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="package_json",
+            description="package.json validation, engines pinning and key sorting",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=PACKAGE_JSON_FILE_PATTERNS,
+            priority=PACKAGE_JSON_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": PACKAGE_JSON_DEFAULT_TIMEOUT,
+                "required_engines": list(DEFAULT_REQUIRED_ENGINES),
+                "script_name_pattern": DEFAULT_SCRIPT_NAME_PATTERN,
+                "check_sorting": True,
+            },
+            default_timeout=PACKAGE_JSON_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        required_engines: str | list[str] | None = None,
+        script_name_pattern: str | None = None,
+        check_sorting: bool | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set package.json-specific options with validation.
+
+        Args:
+            required_engines: Engines that must declare a version range.
+            script_name_pattern: Regex script names must match. An empty
+                string disables the check.
+            check_sorting: Whether to report unsorted keys.
+            **kwargs: Additional base options.
+        """
+        engines = normalize_str_or_list(required_engines, "required_engines")
+        validate_str(script_name_pattern, "script_name_pattern")
+        validate_bool(check_sorting, "check_sorting")
+
+        options = filter_none_options(
+            required_engines=engines,
+            script_name_pattern=script_name_pattern,
+            check_sorting=check_sorting,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _check_text(
+        self,
+        text: str,
+        file_path: str,
+        options: dict[str, object],
+    ) -> list[PackageJsonIssue]:
+        """Run the manifest checks with the effective options.
+
+        Args:
+            text: Raw file content.
+            file_path: Path used when reporting issues.
+            options: Runtime options that override defaults.
+
+        Returns:
+            list[PackageJsonIssue]: Issues found in the file.
+        """
+        merged_options = {**self.options, **options}
+        raw_engines = merged_options.get("required_engines")
+        engines = (
+            [str(e) for e in raw_engines]
+            if isinstance(raw_engines, list)
+            else list(DEFAULT_REQUIRED_ENGINES)
+        )
+        pattern = merged_options.get("script_name_pattern")
+        return check_package_json_text(
+            text,
+            file_path,
+            required_engines=engines,
+            script_name_pattern=str(pattern) if pattern else None,
+            check_sorting=bool(merged_options.get("check_sorting", True)),
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check package.json files for manifest issues.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        all_issues: list[PackageJsonIssue] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                with open(file_path, encoding="utf-8") as f:
+                    text = f.read()
+            except (OSError, UnicodeDecodeError) as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            all_issues.extend(self._check_text(text, rel_path, options))
+
+        logger.debug(
+            f"[PackageJsonPlugin] Checked {len(ctx.files)} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Sort package.json keys and report remaining issues.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with fix results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        initial_total = 0
+        remaining_issues: list[PackageJsonIssue] = []
+        fixed_files: list[str] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                with open(file_path, encoding="utf-8") as f:
+                    text = f.read()
+                initial_total += len(self._check_text(text, rel_path, options))
+                sorted_text = sort_package_json_text(text)
+                if sorted_text is not None and sorted_text != text:
+                    with open(file_path, "w", encoding="utf-8") as f:
+                        f.write(sorted_text)
+                    fixed_files.append(rel_path)
+                    text = sorted_text
+            except (OSError, UnicodeDecodeError) as e:
+                errors.append(f"Error processing {rel_path}: {e}")
+                continue
+            remaining_issues.extend(self._check_text(text, rel_path, options))
+
+        remaining = len(remaining_issues)
+        fixed = initial_total - remaining
+
+        summary_parts: list[str] = []
+        if fixed_files:
+            summary_parts.append(f"Sorted keys in {len(fixed_files)} file(s)")
+        if remaining > 0:
+            summary_parts.append(
+                f"Found {remaining} issue(s) that could not be fixed",
+            )
+        summary_parts.extend(errors)
+
+        logger.debug(
+            f"[PackageJsonPlugin] Fix complete: initial={initial_total}, "
+            f"fixed={fixed}, remaining={remaining}",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and remaining == 0,
+            output="\n".join(summary_parts) if summary_parts else "No fixes needed.",
+            issues_count=remaining,
+            issues=remaining_issues,
+            initial_issues_count=initial_total,
+            fixed_issues_count=fixed,
+            remaining_issues_count=remaining,
+        )
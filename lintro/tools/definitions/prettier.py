@@ -19,6 +19,7 @@ from loguru import logger
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.prettier.prettier_issue import PrettierIssue
 from lintro.parsers.prettier.prettier_parser import parse_prettier_output
@@ -264,6 +265,7 @@ class PrettierPlugin(BaseToolPlugin):
             name=self.definition.name,
             success=False,
             output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
             issues_count=len(combined_issues),
             issues=combined_issues,
             initial_issues_count=initial_count,
@@ -25,6 +25,7 @@ from lintro.plugins.registry import register_tool
 PYDOCLINT_DEFAULT_TIMEOUT: int = 30
 PYDOCLINT_DEFAULT_PRIORITY: int = 45
 PYDOCLINT_FILE_PATTERNS: list[str] = ["*.py", "*.pyi"]
+PYDOCLINT_SHEBANG_INTERPRETERS: list[str] = ["python", "pypy"]
 
 
 @register_tool
@@ -49,6 +50,7 @@ class PydoclintPlugin(BaseToolPlugin):
             can_fix=False,
             tool_type=ToolType.LINTER | ToolType.DOCUMENTATION,
             file_patterns=PYDOCLINT_FILE_PATTERNS,
+            shebang_interpreters=PYDOCLINT_SHEBANG_INTERPRETERS,
             priority=PYDOCLINT_DEFAULT_PRIORITY,
             conflicts_with=[],
             native_configs=["pyproject.toml", ".pydoclint.toml"],
@@ -0,0 +1,169 @@
+"""Pyproject tool definition.
+
+Native checker for pyproject.toml metadata. Validates PEP 621 ``[project]``
+completeness, trove classifiers, PEP 508 dependency specifiers, and the
+consistency between optional-dependency groups and ``[tool.*]`` configs.
+Runs inside Lintro, so no external binary is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    normalize_str_or_list,
+    validate_bool,
+)
+from lintro.tools.implementations.pyproject import (
+    DEFAULT_RECOMMENDED_FIELDS,
+    check_pyproject_text,
+)
+
+# Constants for pyproject configuration
+PYPROJECT_DEFAULT_TIMEOUT: int = 30
+PYPROJECT_DEFAULT_PRIORITY: int = 45
+PYPROJECT_FILE_PATTERNS: list[str] = ["pyproject.toml"]
+
+
+@register_tool
+@dataclass
+class PyprojectPlugin(BaseToolPlugin):
+    """Native pyproject.toml metadata checker plugin.
+
+    This plugin validates PEP 621 project metadata without invoking any
+    external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="pyproject",
+            description="PEP 621 metadata and dependency specifier checks",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=PYPROJECT_FILE_PATTERNS,
+            priority=PYPROJECT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": PYPROJECT_DEFAULT_TIMEOUT,
+                "recommended_fields": list(DEFAULT_RECOMMENDED_FIELDS),
+                "check_tool_dependencies": True,
+            },
+            default_timeout=PYPROJECT_DEFAULT_TIMEOUT,
+            native=True,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        recommended_fields: str | list[str] | None = None,
+        check_tool_dependencies: bool | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set pyproject-specific options with validation.
+
+        Args:
+            recommended_fields: Metadata fields reported when missing.
+            check_tool_dependencies: Whether to report [tool.*] sections whose
+                tool is not declared in any dependency group.
+            **kwargs: Additional base options.
+        """
+        fields = normalize_str_or_list(recommended_fields, "recommended_fields")
+        validate_bool(check_tool_dependencies, "check_tool_dependencies")
+
+        options = filter_none_options(
+            recommended_fields=fields,
+            check_tool_dependencies=check_tool_dependencies,
+        )
+        super().set_options(**options, **kwargs)
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check pyproject.toml files for metadata issues.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        merged_options = {**self.options, **options}
+        raw_fields = merged_options.get("recommended_fields")
+        recommended_fields = (
+            [str(f) for f in raw_fields]
+            if isinstance(raw_fields, list)
+            else list(DEFAULT_RECOMMENDED_FIELDS)
+        )
+        check_tool_dependencies = bool(
+            merged_options.get("check_tool_dependencies", True),
+        )
+
+        all_issues: list[PyprojectIssue] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            try:
+                with open(file_path, encoding="utf-8") as f:
+                    text = f.read()
+            except (OSError, UnicodeDecodeError) as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            all_issues.extend(
+                check_pyproject_text(
+                    text,
+                    rel_path,
+                    recommended_fields=recommended_fields,
+                    check_tool_dependencies=check_tool_dependencies,
+                ),
+            )
+
+        logger.debug(
+            f"[PyprojectPlugin] Checked {len(ctx.files)} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Pyproject metadata issues cannot be fixed automatically.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: The pyproject checker does not support fixing.
+        """
+        raise NotImplementedError(
+            "pyproject cannot automatically fix issues. Run 'lintro check' to see "
+            "issues.",
+        )
@@ -16,6 +16,7 @@ from loguru import logger
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.plugins.base import BaseToolPlugin
 from lintro.plugins.protocol import ToolDefinition
@@ -135,6 +136,7 @@ class PytestPlugin(BaseToolPlugin):
                 "collect_only": False,
             },
             default_timeout=PYTEST_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(self, **kwargs: Any) -> None:
@@ -333,6 +335,7 @@ class PytestPlugin(BaseToolPlugin):
                     name=self.definition.name,
                     success=False,
                     output=f"Pytest execution timed out ({timeout_val}s)",
+                    error_code=TOOL_TIMEOUT.code,
                     issues_count=0,
                 )
 
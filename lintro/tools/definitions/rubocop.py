@@ -0,0 +1,311 @@
+"""RuboCop tool definition.
+
+RuboCop is the Ruby linter and formatter. lintro runs it with
+``--format json`` and maps ``lintro format`` to ``--autocorrect``. When the
+project's Gemfile locks rubocop, it runs through ``bundle exec`` from the
+Gemfile's directory, so the pinned version and the project's RuboCop
+plugins are used.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.rubocop.rubocop_parser import parse_rubocop_output
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_bool,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.ruby_bundler import get_bundled_gem_dir
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for RuboCop configuration
+RUBOCOP_DEFAULT_TIMEOUT: int = 120
+RUBOCOP_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("rubocop", 50)
+RUBOCOP_FILE_PATTERNS: list[str] = [
+    "*.rb",
+    "*.rake",
+    "*.gemspec",
+    "Gemfile",
+    "Rakefile",
+]
+RUBOCOP_SHEBANG_INTERPRETERS: list[str] = ["ruby"]
+
+
+@register_tool
+@dataclass
+class RubocopPlugin(BaseToolPlugin):
+    """RuboCop Ruby linter and formatter plugin.
+
+    This plugin runs ``rubocop --format json`` on the discovered Ruby files,
+    through ``bundle exec`` when the project's Gemfile locks rubocop.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="rubocop",
+            description="Ruby linter and formatter with autocorrect",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=RUBOCOP_FILE_PATTERNS,
+            shebang_interpreters=RUBOCOP_SHEBANG_INTERPRETERS,
+            priority=RUBOCOP_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[".rubocop.yml"],
+            version_command=["rubocop", "--version"],
+            min_version=get_min_version(ToolName.RUBOCOP),
+            default_options={
+                "timeout": RUBOCOP_DEFAULT_TIMEOUT,
+                "config": None,
+                "unsafe": False,
+            },
+            default_timeout=RUBOCOP_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        config: str | None = None,
+        unsafe: bool | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set RuboCop-specific options.
+
+        Args:
+            config: Path to a RuboCop config, instead of the ``.rubocop.yml``
+                RuboCop finds.
+            unsafe: Also apply unsafe corrections when formatting, using
+                ``--autocorrect-all`` (default: False).
+            timeout: Timeout in seconds (default: 120).
+            **kwargs: Additional base options.
+        """
+        validate_str(config, "config")
+        validate_bool(unsafe, "unsafe")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(
+            config=config,
+            unsafe=unsafe,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _resolve_run_dir(
+        self,
+        ctx: ExecutionContext,
+    ) -> tuple[list[str], str, list[str]]:
+        """Choose how and where RuboCop runs.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+
+        Returns:
+            Tuple of (command prefix, working directory, files relative to
+            it).
+        """
+        cwd = ctx.cwd or os.getcwd()
+        bundle_dir = get_bundled_gem_dir(cwd, "rubocop")
+        if bundle_dir is None:
+            return ["rubocop"], cwd, ctx.rel_files
+        run_dir = str(bundle_dir)
+        files = [os.path.relpath(f, run_dir) for f in ctx.files]
+        return ["bundle", "exec", "rubocop"], run_dir, files
+
+    def _build_command(
+        self,
+        prefix: list[str],
+        files: list[str],
+        fix: bool = False,
+    ) -> list[str]:
+        """Build the RuboCop command.
+
+        Args:
+            prefix: Command running rubocop, directly or via bundle exec.
+            files: Files to check, relative to the working directory.
+            fix: Whether to autocorrect the offenses.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = [*prefix, "--format", "json", "--force-exclusion"]
+        config = self.options.get("config")
+        if config:
+            cmd.extend(["--config", str(config)])
+        if fix:
+            unsafe = self.options.get("unsafe", False)
+            cmd.append("--autocorrect-all" if unsafe else "--autocorrect")
+        cmd.extend(files)
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"RuboCop execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options rubocop:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def _run_rubocop(
+        self,
+        ctx: ExecutionContext,
+        fix: bool,
+    ) -> tuple[bool, str]:
+        """Run RuboCop on the discovered files.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+            fix: Whether to autocorrect the offenses.
+
+        Returns:
+            Tuple of (success, output).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        prefix, run_dir, files = self._resolve_run_dir(ctx)
+        cmd = self._build_command(prefix, files, fix=fix)
+        logger.debug(
+            f"[RubocopPlugin] Running: {' '.join(cmd[:8])}... (cwd={run_dir})",
+        )
+        return self._run_subprocess(cmd=cmd, timeout=ctx.timeout, cwd=run_dir)
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint Ruby files with RuboCop.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Ruby files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            run_success, output = self._run_rubocop(ctx, fix=False)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        issues = parse_rubocop_output(output=output)
+        if issues is None:
+            # RuboCop exits 1 for offenses, so only fail without a report
+            message = output.strip() or "RuboCop execution failed."
+            return ToolResult(
+                name=self.definition.name,
+                success=run_success,
+                output=None if run_success else message,
+                issues_count=0,
+            )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Autocorrect Ruby files in place with RuboCop.
+
+        RuboCop reports every offense it found with a ``corrected`` flag,
+        so a single autocorrect run gives both the fixed and the remaining
+        offenses.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Ruby files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            run_success, output = self._run_rubocop(ctx, fix=True)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        issues = parse_rubocop_output(output=output)
+        if issues is None:
+            message = output.strip() or "RuboCop execution failed."
+            return ToolResult(
+                name=self.definition.name,
+                success=run_success,
+                output=None if run_success else message,
+                issues_count=0,
+            )
+
+        remaining_issues = [issue for issue in issues if not issue.corrected]
+        initial_count = len(issues)
+        remaining_count = len(remaining_issues)
+        fixed_count = initial_count - remaining_count
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
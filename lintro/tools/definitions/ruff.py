@@ -28,7 +28,9 @@ from lintro.utils.path_utils import load_lintro_ignore
 # Constants for Ruff configuration
 RUFF_DEFAULT_TIMEOUT: int = 30
 RUFF_DEFAULT_PRIORITY: int = 85
-RUFF_FILE_PATTERNS: list[str] = ["*.py", "*.pyi"]
+# Ruff reads notebooks itself, reporting by cell and fixing in place
+RUFF_FILE_PATTERNS: list[str] = ["*.py", "*.pyi", "*.ipynb"]
+RUFF_SHEBANG_INTERPRETERS: list[str] = ["python", "pypy"]
 RUFF_OUTPUT_FORMAT: str = "json"
 RUFF_TEST_MODE_ENV: str = "LINTRO_TEST_MODE"
 RUFF_TEST_MODE_VALUE: str = "1"
@@ -56,6 +58,7 @@ class RuffPlugin(BaseToolPlugin):
             can_fix=True,
             tool_type=ToolType.LINTER | ToolType.FORMATTER,
             file_patterns=RUFF_FILE_PATTERNS,
+            shebang_interpreters=RUFF_SHEBANG_INTERPRETERS,
             priority=RUFF_DEFAULT_PRIORITY,
             conflicts_with=[],
             native_configs=["pyproject.toml", "ruff.toml", ".ruff.toml"],
@@ -75,6 +78,7 @@ class RuffPlugin(BaseToolPlugin):
                 "format_check": True,
                 "format": True,
                 "lint_fix": True,
+                "fixable": None,
             },
             default_timeout=RUFF_DEFAULT_TIMEOUT,
         )
@@ -105,11 +109,6 @@ class RuffPlugin(BaseToolPlugin):
                 if key in ruff_config:
                     self.options[key] = ruff_config[key]
 
-        # Allow environment variable override for unsafe fixes
-        env_unsafe_fixes = os.environ.get("RUFF_UNSAFE_FIXES", "").lower()
-        if env_unsafe_fixes in ("true", "1", "yes", "on"):
-            self.options["unsafe_fixes"] = True
-
     def set_options(  # type: ignore[override]
         self,
         select: list[str] | None = None,
@@ -124,6 +123,7 @@ class RuffPlugin(BaseToolPlugin):
         format: bool | None = None,
         lint_fix: bool | None = None,
         format_check: bool | None = None,
+        fixable: list[str] | None = None,
         **kwargs: Any,
     ) -> None:
         """Set Ruff-specific options.
@@ -141,6 +141,8 @@ class RuffPlugin(BaseToolPlugin):
             format: Whether to run `ruff format` during fix.
             lint_fix: Whether to run `ruff check --fix` during fix.
             format_check: Whether to run `ruff format --check` in check.
+            fixable: Rules `ruff check --fix` may fix; formatting is skipped
+                when set. None fixes every fixable rule.
             **kwargs: Other tool options.
         """
         # Normalize string-or-list parameters
@@ -148,6 +150,7 @@ class RuffPlugin(BaseToolPlugin):
         ignore = normalize_str_or_list(ignore, "ignore")
         extend_select = normalize_str_or_list(extend_select, "extend_select")
         extend_ignore = normalize_str_or_list(extend_ignore, "extend_ignore")
+        fixable = normalize_str_or_list(fixable, "fixable")
 
         # Validate types
         validate_positive_int(line_length, "line_length")
@@ -172,6 +175,7 @@ class RuffPlugin(BaseToolPlugin):
             format=format,
             lint_fix=lint_fix,
             format_check=format_check,
+            fixable=fixable,
         )
         super().set_options(**options, **kwargs)
 
@@ -2,7 +2,16 @@
 
 Rustfmt is Rust's official code formatter. It enforces a consistent style
 by parsing Rust code and re-printing it with its own rules. It runs via
-`cargo fmt` and requires a Cargo.toml file in the project.
+`cargo fmt` and requires a Cargo.toml file in the project. In a workspace it
+runs from the workspace root on the members containing the checked files.
+
+cargo fmt hands rustfmt each crate's edition from Cargo.toml, and rustfmt
+finds the nearest rustfmt.toml or .rustfmt.toml itself. Lintro leaves both
+to them: passing ``--edition`` again makes rustfmt reject the duplicate, and
+an explicit ``--config-path`` would override per-crate configs. It reads the
+same settings instead to report the ones cargo fmt drops silently: an
+``edition`` in the rustfmt config that the crate's overrides, and
+nightly-only options stable rustfmt ignores.
 """
 
 from __future__ import annotations
@@ -19,7 +28,11 @@ from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
 from lintro.models.core.tool_result import ToolResult
-from lintro.parsers.rustfmt.rustfmt_parser import parse_rustfmt_output
+from lintro.parsers.rustfmt.rustfmt_issue import RustfmtIssue
+from lintro.parsers.rustfmt.rustfmt_parser import (
+    parse_rustfmt_output,
+    parse_unstable_options,
+)
 from lintro.plugins.base import BaseToolPlugin
 from lintro.plugins.protocol import ToolDefinition
 from lintro.plugins.registry import register_tool
@@ -31,6 +44,9 @@ from lintro.tools.core.timeout_utils import (
     create_timeout_result,
     run_subprocess_with_timeout,
 )
+from lintro.utils.cargo_workspace import CargoTarget, resolve_target
+from lintro.utils.rustfmt_settings import config_option_line, rustfmt_settings
+from lintro.utils.toolchains import rustup_toolchain
 
 # Constants for Rustfmt configuration
 RUSTFMT_DEFAULT_TIMEOUT: int = 60
@@ -89,22 +105,147 @@ def _find_cargo_root(paths: list[str]) -> Path | None:
     return None
 
 
-def _build_rustfmt_check_command() -> list[str]:
+def _package_args(packages: tuple[str, ...] | None) -> list[str]:
+    """Build the package selection of a cargo fmt command.
+
+    Args:
+        packages: Workspace members to format, or None for all of them.
+
+    Returns:
+        List of command arguments.
+    """
+    if packages is None:
+        return ["--all"]
+    return [arg for package in packages for arg in ("-p", package)]
+
+
+def _cargo(toolchain: str | None) -> list[str]:
+    """Build the cargo invocation of a command.
+
+    Args:
+        toolchain: Rustup toolchain to run with, if any.
+
+    Returns:
+        List of command arguments.
+    """
+    return ["cargo", f"+{toolchain}"] if toolchain else ["cargo"]
+
+
+def _build_rustfmt_check_command(
+    packages: tuple[str, ...] | None = None,
+    toolchain: str | None = None,
+) -> list[str]:
     """Build the cargo fmt check command.
 
+    Args:
+        packages: Workspace members to check, or None for all of them.
+        toolchain: Rustup toolchain pinned by the project, if any.
+
     Returns:
         List of command arguments.
     """
-    return ["cargo", "fmt", "--all", "--", "--check"]
+    return [*_cargo(toolchain), "fmt", *_package_args(packages), "--", "--check"]
 
 
-def _build_rustfmt_fix_command() -> list[str]:
+def _build_rustfmt_fix_command(
+    packages: tuple[str, ...] | None = None,
+    toolchain: str | None = None,
+) -> list[str]:
     """Build the cargo fmt fix command.
 
+    Args:
+        packages: Workspace members to format, or None for all of them.
+        toolchain: Rustup toolchain pinned by the project, if any.
+
     Returns:
         List of command arguments.
     """
-    return ["cargo", "fmt", "--all"]
+    return [*_cargo(toolchain), "fmt", *_package_args(packages)]
+
+
+def _settings_issues(output: str, target: CargoTarget) -> list[RustfmtIssue]:
+    """Report settings cargo fmt drops without failing.
+
+    Args:
+        output: Raw cargo fmt output.
+        target: Where cargo fmt ran and on which packages.
+
+    Returns:
+        list[RustfmtIssue]: Warnings for rustfmt config editions the crate's
+        overrides and for nightly-only options stable rustfmt ignored.
+    """
+    if target.workspace is None:
+        crates = {target.root: None}
+    else:
+        crates = {
+            member.root: member.name
+            for member in target.workspace.members
+            if target.packages is None or member.name in target.packages
+        }
+    issues: list[RustfmtIssue] = []
+    configs: list[Path] = []
+    for crate_dir, crate_name in crates.items():
+        settings = rustfmt_settings(crate_dir)
+        if settings.config_path is None:
+            continue
+        if settings.config_path not in configs:
+            configs.append(settings.config_path)
+        if settings.edition_mismatch:
+            issues.append(
+                RustfmtIssue(
+                    file=str(settings.config_path),
+                    line=config_option_line(settings.config_path, "edition"),
+                    message=(
+                        f"{settings.config_path.name} sets edition "
+                        f"{settings.config_edition}, but cargo fmt formats "
+                        f"{crate_name or crate_dir.name} with edition "
+                        f"{settings.edition} from its Cargo.toml"
+                    ),
+                    fixable=False,
+                    code="edition_mismatch",
+                    severity="warning",
+                    crate_name=crate_name,
+                ),
+            )
+
+    for option, value in parse_unstable_options(output).items():
+        message = (
+            f"Nightly-only option {option} = {value} is ignored by stable rustfmt"
+        )
+        locations = [
+            (str(config), line)
+            for config in configs
+            if (line := config_option_line(config, option))
+        ]
+        for file, line in locations or [("", 0)]:
+            issues.append(
+                RustfmtIssue(
+                    file=file,
+                    line=line,
+                    message=message,
+                    fixable=False,
+                    code="unstable_option",
+                    severity="warning",
+                ),
+            )
+    return issues
+
+
+def _parse_issues(output: str, target: CargoTarget) -> list[RustfmtIssue]:
+    """Parse rustfmt output and attribute each issue to its crate.
+
+    Args:
+        output: Raw cargo fmt output.
+        target: Where cargo fmt ran and on which packages.
+
+    Returns:
+        list[RustfmtIssue]: Parsed issues with ``crate_name`` set when the
+        workspace is known, followed by warnings about dropped settings.
+    """
+    issues = parse_rustfmt_output(output=output)
+    for issue in issues:
+        issue.crate_name = target.crate_for(issue.file)
+    return [*issues, *_settings_issues(output, target)]
 
 
 @register_tool
@@ -138,6 +279,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 "timeout": RUSTFMT_DEFAULT_TIMEOUT,
             },
             default_timeout=RUSTFMT_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
@@ -183,15 +325,19 @@ class RustfmtPlugin(BaseToolPlugin):
                 output="No Cargo.toml found; skipping rustfmt.",
                 issues_count=0,
             )
+        target = resolve_target(ctx.files, cargo_root)
 
-        cmd = _build_rustfmt_check_command()
+        cmd = _build_rustfmt_check_command(
+            target.packages,
+            rustup_toolchain(target.root),
+        )
 
         try:
             success_cmd, output = run_subprocess_with_timeout(
                 tool=self,
                 cmd=cmd,
                 timeout=ctx.timeout,
-                cwd=str(cargo_root),
+                cwd=str(target.root),
                 tool_name="rustfmt",
             )
         except subprocess.TimeoutExpired:
@@ -205,11 +351,12 @@ class RustfmtPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
             )
 
-        issues = parse_rustfmt_output(output=output)
+        issues = _parse_issues(output, target)
         issues_count = len(issues)
 
         # Preserve output when command failed, even if no issues were parsed
@@ -224,7 +371,7 @@ class RustfmtPlugin(BaseToolPlugin):
         )
 
     def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
-        """Run `cargo fmt --all` then re-check for remaining issues.
+        """Run `cargo fmt` then re-check for remaining issues.
 
         Args:
             paths: List of file or directory paths to fix.
@@ -253,8 +400,12 @@ class RustfmtPlugin(BaseToolPlugin):
                 fixed_issues_count=0,
                 remaining_issues_count=0,
             )
+        target = resolve_target(ctx.files, cargo_root)
 
-        check_cmd = _build_rustfmt_check_command()
+        check_cmd = _build_rustfmt_check_command(
+            target.packages,
+            rustup_toolchain(target.root),
+        )
 
         # First, count issues before fixing
         try:
@@ -262,7 +413,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 tool=self,
                 cmd=check_cmd,
                 timeout=ctx.timeout,
-                cwd=str(cargo_root),
+                cwd=str(target.root),
                 tool_name="rustfmt",
             )
         except subprocess.TimeoutExpired:
@@ -277,6 +428,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
                 initial_issues_count=0,
@@ -284,17 +436,20 @@ class RustfmtPlugin(BaseToolPlugin):
                 remaining_issues_count=0,
             )
 
-        initial_issues = parse_rustfmt_output(output=output_check)
+        initial_issues = _parse_issues(output_check, target)
         initial_count = len(initial_issues)
 
         # Run fix
-        fix_cmd = _build_rustfmt_fix_command()
+        fix_cmd = _build_rustfmt_fix_command(
+            target.packages,
+            rustup_toolchain(target.root),
+        )
         try:
             fix_success, fix_output = run_subprocess_with_timeout(
                 tool=self,
                 cmd=fix_cmd,
                 timeout=ctx.timeout,
-                cwd=str(cargo_root),
+                cwd=str(target.root),
                 tool_name="rustfmt",
             )
         except subprocess.TimeoutExpired:
@@ -308,6 +463,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=initial_count,
                 issues=initial_issues,
                 initial_issues_count=initial_count,
@@ -334,7 +490,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 tool=self,
                 cmd=check_cmd,
                 timeout=ctx.timeout,
-                cwd=str(cargo_root),
+                cwd=str(target.root),
                 tool_name="rustfmt",
             )
         except subprocess.TimeoutExpired:
@@ -348,6 +504,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=initial_count,
                 issues=initial_issues,
                 initial_issues_count=initial_count,
@@ -355,7 +512,7 @@ class RustfmtPlugin(BaseToolPlugin):
                 remaining_issues_count=initial_count,
             )
 
-        remaining_issues = parse_rustfmt_output(output=output_after)
+        remaining_issues = _parse_issues(output_after, target)
         remaining_count = len(remaining_issues)
         fixed_count = max(0, initial_count - remaining_count)
 
@@ -18,6 +18,7 @@ from lintro._tool_versions import get_min_version
 from lintro.enums.semgrep_enums import SemgrepSeverity, normalize_semgrep_severity
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.semgrep.semgrep_parser import parse_semgrep_output
 from lintro.plugins.base import BaseToolPlugin
@@ -285,6 +286,7 @@ class SemgrepPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=0,
             )
         except (OSError, ValueError, RuntimeError) as e:
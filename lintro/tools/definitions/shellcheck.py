@@ -102,6 +102,7 @@ class ShellcheckPlugin(BaseToolPlugin):
             can_fix=False,
             tool_type=ToolType.LINTER,
             file_patterns=SHELLCHECK_FILE_PATTERNS,
+            shebang_interpreters=list(SHELLCHECK_SHELL_DIALECTS),
             priority=SHELLCHECK_DEFAULT_PRIORITY,
             conflicts_with=[],
             native_configs=[".shellcheckrc"],
@@ -33,6 +33,8 @@ from lintro.tools.core.option_validators import (
 SHFMT_DEFAULT_TIMEOUT: int = 30
 SHFMT_DEFAULT_PRIORITY: int = 50
 SHFMT_FILE_PATTERNS: list[str] = ["*.sh", "*.bash", "*.ksh"]
+# Interpreters whose extensionless scripts shfmt formats, found by shebang
+SHFMT_SHEBANG_INTERPRETERS: list[str] = ["sh", "bash", "dash", "ksh", "mksh"]
 
 
 @register_tool
@@ -59,6 +61,7 @@ class ShfmtPlugin(BaseToolPlugin):
             can_fix=True,
             tool_type=ToolType.FORMATTER,
             file_patterns=SHFMT_FILE_PATTERNS,
+            shebang_interpreters=SHFMT_SHEBANG_INTERPRETERS,
             priority=SHFMT_DEFAULT_PRIORITY,
             conflicts_with=[],
             native_configs=[".editorconfig"],
@@ -70,6 +73,7 @@ class ShfmtPlugin(BaseToolPlugin):
                 "binary_next_line": False,
                 "switch_case_indent": False,
                 "space_redirects": False,
+                "func_next_line": False,
                 "language_dialect": None,
                 "simplify": False,
             },
@@ -82,6 +86,7 @@ class ShfmtPlugin(BaseToolPlugin):
         binary_next_line: bool | None = None,
         switch_case_indent: bool | None = None,
         space_redirects: bool | None = None,
+        func_next_line: bool | None = None,
         language_dialect: str | None = None,
         simplify: bool | None = None,
         **kwargs: Any,
@@ -93,6 +98,7 @@ class ShfmtPlugin(BaseToolPlugin):
             binary_next_line: Binary ops like && and | may start a line.
             switch_case_indent: Indent switch cases.
             space_redirects: Redirect operators followed by space.
+            func_next_line: Function opening braces on the next line.
             language_dialect: Shell language dialect (bash, posix, mksh, bats).
             simplify: Simplify code where possible.
             **kwargs: Other tool options.
@@ -104,6 +110,7 @@ class ShfmtPlugin(BaseToolPlugin):
         validate_bool(binary_next_line, "binary_next_line")
         validate_bool(switch_case_indent, "switch_case_indent")
         validate_bool(space_redirects, "space_redirects")
+        validate_bool(func_next_line, "func_next_line")
         validate_str(language_dialect, "language_dialect")
         validate_bool(simplify, "simplify")
 
@@ -123,6 +130,7 @@ class ShfmtPlugin(BaseToolPlugin):
             binary_next_line=binary_next_line,
             switch_case_indent=switch_case_indent,
             space_redirects=space_redirects,
+            func_next_line=func_next_line,
             language_dialect=language_dialect,
             simplify=simplify,
         )
@@ -153,6 +161,10 @@ class ShfmtPlugin(BaseToolPlugin):
         if self.options.get("space_redirects"):
             args.append("-sr")
 
+        # Function opening braces on the next line
+        if self.options.get("func_next_line"):
+            args.append("-fn")
+
         # Language dialect
         language_dialect = self.options.get("language_dialect")
         if language_dialect is not None:
@@ -1,19 +1,26 @@
 """SQLFluff tool definition.
 
 SQLFluff is a SQL linter and formatter with support for many SQL dialects.
-It parses SQL into an AST and performs linting rules on top of it.
+It parses SQL into an AST and performs linting rules on top of it. Models
+in dbt projects are linted from the dbt project directory when the dbt
+templater is in use, as the templater requires.
 """
 
 from __future__ import annotations
 
+import configparser
 import subprocess  # nosec B404 - used safely with shell disabled
 from dataclasses import dataclass
+from pathlib import Path
 from typing import Any
 
+from loguru import logger
+
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
 from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.sqlfluff.sqlfluff_issue import SqlfluffIssue
 from lintro.parsers.sqlfluff.sqlfluff_parser import parse_sqlfluff_output
 from lintro.plugins.base import BaseToolPlugin
 from lintro.plugins.file_processor import FileProcessingResult
@@ -30,6 +37,56 @@ SQLFLUFF_DEFAULT_TIMEOUT: int = 60
 SQLFLUFF_DEFAULT_PRIORITY: int = 50
 SQLFLUFF_FILE_PATTERNS: list[str] = ["*.sql"]
 SQLFLUFF_DEFAULT_FORMAT: str = "json"
+SQLFLUFF_CONFIG: str = ".sqlfluff"
+DBT_PROJECT_FILE: str = "dbt_project.yml"
+SQLFLUFF_NO_DIALECT_HINT: str = (
+    "no SQL dialect configured; set 'dialect' in .sqlfluff or use "
+    "--tool-options sqlfluff:dialect=<name>"
+)
+
+
+def find_dbt_project_root(file_path: str | Path) -> Path | None:
+    """Find the dbt project a SQL file belongs to.
+
+    Args:
+        file_path: Path to a SQL file.
+
+    Returns:
+        Directory containing the nearest dbt_project.yml, or None if the
+        file is not part of a dbt project.
+    """
+    directory = Path(file_path).resolve().parent
+    for candidate in (directory, *directory.parents):
+        if (candidate / DBT_PROJECT_FILE).is_file():
+            return candidate
+    return None
+
+
+def read_config_templater(file_path: str | Path) -> str | None:
+    """Read the templater from the nearest .sqlfluff config of a file.
+
+    Args:
+        file_path: Path to a SQL file.
+
+    Returns:
+        The ``templater`` of the ``[sqlfluff]`` section, or None if no
+        .sqlfluff config sets one.
+    """
+    directory = Path(file_path).resolve().parent
+    for candidate in (directory, *directory.parents):
+        config_path = candidate / SQLFLUFF_CONFIG
+        if not config_path.is_file():
+            continue
+        parser = configparser.ConfigParser()
+        try:
+            parser.read(config_path, encoding="utf-8")
+        except configparser.Error as e:
+            logger.debug(f"[SqlfluffPlugin] Could not read {config_path}: {e}")
+            continue
+        templater = parser.get("sqlfluff", "templater", fallback=None)
+        if templater:
+            return templater.strip()
+    return None
 
 
 @register_tool
@@ -84,7 +141,8 @@ class SqlfluffPlugin(BaseToolPlugin):
                 sqlite, etc.).
             exclude_rules: List of rules to exclude.
             rules: List of rules to include.
-            templater: Templater to use (raw, jinja, python, placeholder).
+            templater: Templater to use (raw, jinja, python, placeholder,
+                dbt).
             **kwargs: Other tool options.
         """
         validate_str(dialect, "dialect")
@@ -178,6 +236,27 @@ class SqlfluffPlugin(BaseToolPlugin):
 
         return cmd
 
+    def _get_file_cwd(self, file_path: str) -> str | None:
+        """Return the directory to run SQLFluff from for a file.
+
+        The dbt templater loads the dbt project from the working directory,
+        so dbt models are linted from their project root.
+
+        Args:
+            file_path: Path to the SQL file.
+
+        Returns:
+            The dbt project root when the dbt templater applies to the file,
+            otherwise None to run from the current directory.
+        """
+        templater = self.options.get("templater") or read_config_templater(
+            file_path,
+        )
+        if templater != "dbt":
+            return None
+        project_root = find_dbt_project_root(file_path)
+        return str(project_root) if project_root is not None else None
+
     def _process_single_file_check(
         self,
         file_path: str,
@@ -194,8 +273,19 @@ class SqlfluffPlugin(BaseToolPlugin):
         """
         cmd = self._build_lint_command(files=[str(file_path)])
         try:
-            success, output = self._run_subprocess(cmd=cmd, timeout=timeout)
+            success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=timeout,
+                cwd=self._get_file_cwd(file_path),
+            )
             issues = parse_sqlfluff_output(output=output)
+            if not success and not issues and "No dialect was specified" in output:
+                return FileProcessingResult(
+                    success=False,
+                    output=output,
+                    issues=[],
+                    error=SQLFLUFF_NO_DIALECT_HINT,
+                )
             # success is False if issues exist or tool failed
             final_success = success and len(issues) == 0
             return FileProcessingResult(
@@ -222,39 +312,62 @@ class SqlfluffPlugin(BaseToolPlugin):
         self,
         file_path: str,
         timeout: int,
-    ) -> FileProcessingResult:
+    ) -> tuple[FileProcessingResult, int, int]:
         """Process a single SQL file with sqlfluff fix.
 
+        The file is linted before and after fixing so that the result
+        reports how many violations were fixed and which remain.
+
         Args:
             file_path: Path to the SQL file to fix.
-            timeout: Timeout in seconds for the sqlfluff command.
+            timeout: Timeout in seconds for each sqlfluff command.
 
         Returns:
-            FileProcessingResult with fix results for this file.
+            Tuple of (FileProcessingResult with the remaining issues,
+            initial_issues_count, fixed_issues_count).
         """
+        check_result = self._process_single_file_check(file_path, timeout)
+        if check_result.skipped or check_result.error or not check_result.issues:
+            return check_result, 0, 0
+        initial_count = len(check_result.issues)
+
         cmd = self._build_fix_command(files=[str(file_path)])
         try:
-            success, output = self._run_subprocess(cmd=cmd, timeout=timeout)
-            return FileProcessingResult(
-                success=success,
-                output=output,
-                issues=[],
+            self._run_subprocess(
+                cmd=cmd,
+                timeout=timeout,
+                cwd=self._get_file_cwd(file_path),
             )
         except subprocess.TimeoutExpired:
-            return FileProcessingResult(
-                success=False,
-                output="",
-                issues=[],
-                skipped=True,
+            return (
+                FileProcessingResult(
+                    success=False,
+                    output="",
+                    issues=check_result.issues,
+                    skipped=True,
+                ),
+                initial_count,
+                0,
             )
         except (OSError, ValueError, RuntimeError) as e:
-            return FileProcessingResult(
-                success=False,
-                output="",
-                issues=[],
-                error=str(e),
+            return (
+                FileProcessingResult(
+                    success=False,
+                    output="",
+                    issues=check_result.issues,
+                    error=str(e),
+                ),
+                initial_count,
+                0,
             )
 
+        # Unparsable files and rules without fixes are left for a person
+        remaining_result = self._process_single_file_check(file_path, timeout)
+        if remaining_result.skipped or remaining_result.error:
+            return remaining_result, initial_count, 0
+        fixed_count = max(0, initial_count - len(remaining_result.issues))
+        return remaining_result, initial_count, fixed_count
+
     def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
         """Check files with SQLFluff.
 
@@ -304,9 +417,32 @@ class SqlfluffPlugin(BaseToolPlugin):
         if ctx.should_skip:
             return ctx.early_result  # type: ignore[return-value]
 
+        initial_issues_total = 0
+        fixed_issues_total = 0
+        failures: list[str] = []
+
         # Process files with progress bar support
         def processor(file_path: str) -> FileProcessingResult:
-            return self._process_single_file_fix(file_path, ctx.timeout)
+            """Fix a single file and track the fix counts.
+
+            Args:
+                file_path: Path to the file to fix.
+
+            Returns:
+                FileProcessingResult with the file's remaining issues.
+            """
+            nonlocal initial_issues_total, fixed_issues_total
+            result, initial, fixed = self._process_single_file_fix(
+                file_path,
+                ctx.timeout,
+            )
+            initial_issues_total += initial
+            fixed_issues_total += fixed
+            if result.skipped:
+                failures.append(f"{file_path}: timed out ({ctx.timeout}s limit)")
+            elif result.error:
+                failures.append(f"{file_path}: {result.error}")
+            return result
 
         result = self._process_files_with_progress(
             files=ctx.files,
@@ -315,10 +451,31 @@ class SqlfluffPlugin(BaseToolPlugin):
             label="Fixing files",
         )
 
+        remaining_issues: list[SqlfluffIssue] = [
+            issue for issue in result.all_issues if isinstance(issue, SqlfluffIssue)
+        ]
+        remaining_count = len(remaining_issues)
+
+        output_lines: list[str] = []
+        if fixed_issues_total > 0:
+            output_lines.append(f"Fixed {fixed_issues_total} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_issues_total > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+        if failures:
+            output_lines.append(f"Failed to process {len(failures)} file(s):")
+            output_lines.extend(f"  - {failure}" for failure in failures)
+
         return ToolResult(
             name=self.definition.name,
-            success=result.all_success,
-            output=result.build_output(timeout=ctx.timeout),
-            issues_count=0,
-            issues=[],
+            success=result.all_success and remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_issues_total,
+            fixed_issues_count=fixed_issues_total,
+            remaining_issues_count=remaining_count,
         )
@@ -0,0 +1,270 @@
+"""StyLua tool definition.
+
+StyLua is an opinionated Lua formatter. lintro runs
+``stylua --check --output-format=json`` to report the ranges StyLua would
+rewrite, and maps ``lintro format`` to formatting the files in place.
+StyLua reads its settings from ``stylua.toml`` or ``.stylua.toml``.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.stylua.stylua_parser import StyluaReport, parse_stylua_output
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for StyLua configuration
+STYLUA_DEFAULT_TIMEOUT: int = 60
+STYLUA_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("stylua", 25)
+STYLUA_FILE_PATTERNS: list[str] = ["*.lua"]
+
+
+@register_tool
+@dataclass
+class StyluaPlugin(BaseToolPlugin):
+    """StyLua Lua formatter plugin.
+
+    This plugin runs ``stylua --check`` on the discovered Lua files and
+    formats them in place for ``lintro format``.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="stylua",
+            description="Opinionated Lua formatter",
+            can_fix=True,
+            tool_type=ToolType.FORMATTER,
+            file_patterns=STYLUA_FILE_PATTERNS,
+            priority=STYLUA_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=["stylua.toml", ".stylua.toml"],
+            version_command=["stylua", "--version"],
+            min_version=get_min_version(ToolName.STYLUA),
+            default_options={
+                "timeout": STYLUA_DEFAULT_TIMEOUT,
+                "config": None,
+            },
+            default_timeout=STYLUA_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        config: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set StyLua-specific options.
+
+        Args:
+            config: Path to a StyLua config, instead of the ``stylua.toml``
+                StyLua finds.
+            timeout: Timeout in seconds (default: 60).
+            **kwargs: Additional base options.
+        """
+        validate_str(config, "config")
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(config=config, timeout=timeout)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, files: list[str], fix: bool = False) -> list[str]:
+        """Build the StyLua command.
+
+        Args:
+            files: Files to format, relative to the working directory.
+            fix: Whether to format the files in place instead of checking.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="stylua")
+        # Honour .styluaignore for the explicitly passed files too
+        cmd.append("--respect-ignores")
+        config = self.options.get("config")
+        if config:
+            cmd.extend(["--config-path", str(config)])
+        if not fix:
+            cmd.extend(["--check", "--output-format=json"])
+        cmd.extend(files)
+        return cmd
+
+    def _run_stylua(self, ctx: ExecutionContext, fix: bool = False) -> str:
+        """Run StyLua on the discovered files.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+            fix: Whether to format the files in place instead of checking.
+
+        Returns:
+            str: Output of the run.
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        cmd = self._build_command(ctx.rel_files, fix=fix)
+        logger.debug(f"[StyluaPlugin] Running: {' '.join(cmd[:8])}... (cwd={ctx.cwd})")
+        _, output = self._run_subprocess(
+            cmd=cmd,
+            timeout=ctx.timeout,
+            cwd=ctx.cwd,
+        )
+        return output
+
+    def _check_files(self, ctx: ExecutionContext) -> StyluaReport:
+        """Check the discovered files and parse the report.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+
+        Returns:
+            StyluaReport: Mismatches and errors StyLua reported.
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        return parse_stylua_output(self._run_stylua(ctx))
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"StyLua execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options stylua:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check Lua formatting with StyLua.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Lua files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            report = self._check_files(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not report.issues and not report.errors,
+            output="\n".join(report.errors) if report.errors else None,
+            issues_count=len(report.issues),
+            issues=report.issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Format Lua files in place with StyLua.
+
+        Args:
+            paths: List of file or directory paths to format.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No Lua files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            initial = self._check_files(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        initial_count = len(initial.issues)
+        remaining = initial
+        if initial.issues:
+            try:
+                self._run_stylua(ctx, fix=True)
+                remaining = self._check_files(ctx)
+            except subprocess.TimeoutExpired:
+                return self._create_timeout_result(
+                    timeout_val=ctx.timeout,
+                    initial_count=initial_count,
+                )
+
+        remaining_count = len(remaining.issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+        output_lines.extend(remaining.errors)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0 and not remaining.errors,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining.issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -77,6 +77,7 @@ class SvelteCheckPlugin(BaseToolPlugin):
                 "tsconfig": None,
             },
             default_timeout=SVELTE_CHECK_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
@@ -298,6 +299,7 @@ class SvelteCheckPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=timeout_result.issues_count,
                 issues=timeout_result.issues,
             )
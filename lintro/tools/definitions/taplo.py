@@ -14,6 +14,7 @@ from loguru import logger
 from lintro._tool_versions import get_min_version
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 from lintro.parsers.taplo.taplo_issue import TaploIssue
 from lintro.parsers.taplo.taplo_parser import parse_taplo_output
@@ -62,6 +63,7 @@ class TaploPlugin(BaseToolPlugin):
             default_options={
                 "timeout": TAPLO_DEFAULT_TIMEOUT,
                 "schema": None,
+                "default_schema_catalogs": None,
                 "aligned_arrays": None,
                 "aligned_entries": None,
                 "array_trailing_comma": None,
@@ -74,6 +76,7 @@ class TaploPlugin(BaseToolPlugin):
     def set_options(  # type: ignore[override]
         self,
         schema: str | None = None,
+        default_schema_catalogs: bool | None = None,
         aligned_arrays: bool | None = None,
         aligned_entries: bool | None = None,
         array_trailing_comma: bool | None = None,
@@ -85,6 +88,9 @@ class TaploPlugin(BaseToolPlugin):
 
         Args:
             schema: Path or URL to JSON schema for validation.
+            default_schema_catalogs: Validate well-known files such as
+                Cargo.toml and pyproject.toml against their SchemaStore
+                schemas (fetched over the network).
             aligned_arrays: Align array entries.
             aligned_entries: Align table entries.
             array_trailing_comma: Add trailing comma in arrays.
@@ -93,6 +99,7 @@ class TaploPlugin(BaseToolPlugin):
             **kwargs: Additional base options.
         """
         validate_str(schema, "schema")
+        validate_bool(default_schema_catalogs, "default_schema_catalogs")
         validate_bool(aligned_arrays, "aligned_arrays")
         validate_bool(aligned_entries, "aligned_entries")
         validate_bool(array_trailing_comma, "array_trailing_comma")
@@ -101,6 +108,7 @@ class TaploPlugin(BaseToolPlugin):
 
         options = filter_none_options(
             schema=schema,
+            default_schema_catalogs=default_schema_catalogs,
             aligned_arrays=aligned_arrays,
             aligned_entries=aligned_entries,
             array_trailing_comma=array_trailing_comma,
@@ -140,6 +148,8 @@ class TaploPlugin(BaseToolPlugin):
 
         if self.options.get("schema"):
             args.extend(["--schema", str(self.options["schema"])])
+        elif self.options.get("default_schema_catalogs"):
+            args.append("--default-schema-catalogs")
 
         return args
 
@@ -179,6 +189,7 @@ class TaploPlugin(BaseToolPlugin):
                 name=self.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=len(combined_issues),
                 issues=combined_issues,
                 initial_issues_count=initial_count,
@@ -189,6 +200,7 @@ class TaploPlugin(BaseToolPlugin):
             name=self.definition.name,
             success=False,
             output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
             issues_count=1,
             issues=[timeout_issue],
         )
@@ -0,0 +1,271 @@
+"""Terraform fmt tool definition.
+
+``terraform fmt`` rewrites Terraform configuration, variable and test files
+into the canonical HCL style. Checks run it with ``-check`` and report each
+file it would rewrite; ``lintro format`` lets it rewrite them.
+"""
+
+from __future__ import annotations
+
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.terraform_fmt.terraform_fmt_issue import TerraformFmtIssue
+from lintro.parsers.terraform_fmt.terraform_fmt_parser import (
+    parse_terraform_fmt_output,
+)
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for terraform fmt configuration
+TERRAFORM_FMT_DEFAULT_TIMEOUT: int = 60
+TERRAFORM_FMT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get(
+    "terraform_fmt",
+    25,
+)
+TERRAFORM_FMT_FILE_PATTERNS: list[str] = ["*.tf", "*.tfvars", "*.tftest.hcl"]
+
+
+@register_tool
+@dataclass
+class TerraformFmtPlugin(BaseToolPlugin):
+    """Terraform fmt formatter plugin.
+
+    This plugin runs ``terraform fmt -check -list=true`` for checks and
+    ``terraform fmt -list=true`` for fixes, passing the discovered files
+    directly so directories outside the checked paths are left alone.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="terraform_fmt",
+            description="Canonical formatter for Terraform configuration",
+            can_fix=True,
+            tool_type=ToolType.FORMATTER,
+            file_patterns=TERRAFORM_FMT_FILE_PATTERNS,
+            priority=TERRAFORM_FMT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=["terraform", "version"],
+            min_version=get_min_version(ToolName.TERRAFORM_FMT),
+            default_options={
+                "timeout": TERRAFORM_FMT_DEFAULT_TIMEOUT,
+            },
+            default_timeout=TERRAFORM_FMT_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set terraform fmt-specific options.
+
+        Args:
+            timeout: Timeout in seconds (default: 60).
+            **kwargs: Additional base options.
+        """
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(timeout=timeout)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, files: list[str], check_only: bool) -> list[str]:
+        """Build the terraform fmt command.
+
+        Args:
+            files: Files to format, relative to the working directory.
+            check_only: Whether to report files instead of rewriting them.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd: list[str] = ["terraform", "fmt", "-list=true", "-no-color"]
+        if check_only:
+            cmd.append("-check")
+        cmd.extend(files)
+        return cmd
+
+    def _run_fmt(
+        self,
+        ctx: ExecutionContext,
+        check_only: bool = True,
+    ) -> tuple[bool, str]:
+        """Run terraform fmt on the discovered files.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+            check_only: Whether to report files instead of rewriting them.
+
+        Returns:
+            Tuple of (success, output).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        cmd = self._build_command(ctx.rel_files, check_only=check_only)
+        logger.debug(f"[TerraformFmtPlugin] Running: {' '.join(cmd)} (cwd={ctx.cwd})")
+        return self._run_subprocess(cmd=cmd, timeout=ctx.timeout, cwd=ctx.cwd)
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"terraform fmt execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options terraform_fmt:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def _create_failure_result(self, output: str) -> ToolResult:
+        """Create a failed result for a run that listed no files.
+
+        terraform fmt exits non-zero without listing files when it cannot
+        parse one of them.
+
+        Args:
+            output: Output of the failed run.
+
+        Returns:
+            ToolResult: Failed result carrying terraform's diagnostics.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=output.strip() or "terraform fmt execution failed.",
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check Terraform files for formatting with terraform fmt.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            run_success, output = self._run_fmt(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        issues: list[TerraformFmtIssue] = parse_terraform_fmt_output(output=output)
+        if not run_success and not issues:
+            return self._create_failure_result(output)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Format Terraform files in place with terraform fmt.
+
+        Args:
+            paths: List of file or directory paths to format.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            check_success, check_output = self._run_fmt(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        initial_count = len(parse_terraform_fmt_output(output=check_output))
+        if not check_success and initial_count == 0:
+            return self._create_failure_result(check_output)
+
+        try:
+            if initial_count:
+                self._run_fmt(ctx, check_only=False)
+            _, remaining_output = self._run_fmt(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_count=initial_count,
+            )
+
+        remaining_issues = parse_terraform_fmt_output(output=remaining_output)
+        remaining_count = len(remaining_issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
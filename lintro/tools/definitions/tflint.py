@@ -0,0 +1,242 @@
+"""TFLint tool definition.
+
+TFLint is a pluggable Terraform linter that catches mistakes ``terraform
+validate`` does not, such as unused declarations, deprecated syntax and,
+with provider rulesets, invalid instance types. TFLint lints one module
+(directory) at a time, so lintro runs it once per directory holding
+checked ``.tf`` files.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.tflint.tflint_issue import TflintIssue
+from lintro.parsers.tflint.tflint_parser import (
+    TFLINT_ERROR_CODE,
+    parse_tflint_output,
+)
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for TFLint configuration
+TFLINT_DEFAULT_TIMEOUT: int = 120
+TFLINT_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("tflint", 50)
+TFLINT_FILE_PATTERNS: list[str] = ["*.tf", "*.tf.json"]
+TFLINT_CONFIG: str = ".tflint.hcl"
+
+
+def find_module_dirs(files: list[str]) -> list[str]:
+    """Group Terraform files into the module directories TFLint lints.
+
+    Every directory holding ``.tf`` files is a Terraform module, whether it
+    is a root module or one called from elsewhere.
+
+    Args:
+        files: Terraform file paths.
+
+    Returns:
+        list[str]: Sorted, distinct directories containing the files, with
+        ``.`` for files in the current directory.
+    """
+    return sorted({os.path.dirname(os.path.normpath(f)) or "." for f in files})
+
+
+def find_tflint_config(start: str | Path) -> Path | None:
+    """Find the nearest ``.tflint.hcl`` from a module directory upwards.
+
+    TFLint only reads the config in the directory it runs in (or the home
+    directory), so a repository-wide config has to be passed explicitly
+    when linting nested modules.
+
+    Args:
+        start: Directory to start searching from.
+
+    Returns:
+        Path to the nearest config, or None if there is none.
+    """
+    directory = Path(start).resolve()
+    for candidate in (directory, *directory.parents):
+        config = candidate / TFLINT_CONFIG
+        if config.is_file():
+            return config
+    return None
+
+
+@register_tool
+@dataclass
+class TflintPlugin(BaseToolPlugin):
+    """TFLint Terraform linter plugin.
+
+    This plugin runs ``tflint --format=json`` in each module directory that
+    holds checked files and reports rule violations for those files.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="tflint",
+            description="Pluggable Terraform linter",
+            can_fix=False,
+            tool_type=ToolType.LINTER | ToolType.INFRASTRUCTURE,
+            file_patterns=TFLINT_FILE_PATTERNS,
+            priority=TFLINT_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[TFLINT_CONFIG],
+            version_command=["tflint", "--version"],
+            min_version=get_min_version(ToolName.TFLINT),
+            default_options={
+                "timeout": TFLINT_DEFAULT_TIMEOUT,
+            },
+            default_timeout=TFLINT_DEFAULT_TIMEOUT,
+            per_file=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set TFLint-specific options.
+
+        Args:
+            timeout: Timeout in seconds per module (default: 120).
+            **kwargs: Additional base options.
+        """
+        validate_positive_int(timeout, "timeout")
+
+        options = filter_none_options(timeout=timeout)
+        super().set_options(**options, **kwargs)
+
+    def _build_command(self, module_dir: Path) -> list[str]:
+        """Build the TFLint command for one module.
+
+        Args:
+            module_dir: Absolute path of the module directory.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd: list[str] = ["tflint", "--format=json", "--no-color"]
+        config = find_tflint_config(module_dir)
+        if config is not None:
+            cmd.extend(["--config", str(config)])
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int, module: str) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            module: Module directory being linted.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"TFLint execution timed out in {module} "
+            f"({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options tflint:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint Terraform modules with TFLint.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        base_dir = Path(ctx.cwd or ".")
+        checked = {os.path.normpath(f) for f in ctx.rel_files}
+        issues: list[TflintIssue] = []
+        errors: list[str] = []
+
+        for module in find_module_dirs(ctx.rel_files):
+            module_dir = (base_dir / module).resolve()
+            cmd = self._build_command(module_dir)
+            logger.debug(f"[TflintPlugin] Running: {' '.join(cmd)} (cwd={module_dir})")
+            try:
+                run_success, output = self._run_subprocess(
+                    cmd=cmd,
+                    timeout=ctx.timeout,
+                    cwd=str(module_dir),
+                )
+            except subprocess.TimeoutExpired:
+                return self._create_timeout_result(ctx.timeout, module)
+
+            report = parse_tflint_output(output=output)
+            if report is None:
+                if not run_success:
+                    errors.append(f"{module}: {output.strip() or 'TFLint failed'}")
+                continue
+
+            errors.extend(f"{module}: {message}" for message in report.errors)
+            for issue in report.issues:
+                # TFLint reports paths relative to the module directory
+                issue.file = os.path.normpath(os.path.join(module, issue.file))
+                # Other files in the module were excluded from the run
+                if issue.code == TFLINT_ERROR_CODE or issue.file in checked:
+                    issues.append(issue)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues and not errors,
+            output="\n".join(errors) if errors else None,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """TFLint cannot fix issues, only report them.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: TFLint does not support fixing issues.
+        """
+        raise NotImplementedError(
+            "TFLint cannot automatically fix issues. Run 'lintro check' to see "
+            "issues.",
+        )
@@ -106,6 +106,7 @@ class TscPlugin(BaseToolPlugin):
                 "use_project_files": False,
             },
             default_timeout=TSC_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
@@ -560,6 +561,7 @@ class TscPlugin(BaseToolPlugin):
                     name=self.definition.name,
                     success=timeout_result.success,
                     output=timeout_result.output,
+                    error_code=timeout_result.error_code,
                     issues_count=timeout_result.issues_count,
                     issues=timeout_result.issues,
                 )
@@ -0,0 +1,333 @@
+"""Typos tool definition.
+
+Typos is a fast source code spell checker that looks for known
+misspellings rather than unknown words, so it has few false positives
+across code and docs. ``lintro format`` applies its corrections.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+import tempfile
+from dataclasses import dataclass
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.typos.typos_issue import TyposIssue
+from lintro.parsers.typos.typos_parser import parse_typos_output
+from lintro.plugins.base import BaseToolPlugin, ExecutionContext
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_list,
+    validate_positive_int,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for typos configuration
+TYPOS_DEFAULT_TIMEOUT: int = 60
+TYPOS_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("typos", 60)
+TYPOS_FILE_PATTERNS: list[str] = ["*"]  # Checks code and docs alike
+TYPOS_CONFIGS: list[str] = ["_typos.toml", "typos.toml", ".typos.toml"]
+
+
+@register_tool
+@dataclass
+class TyposPlugin(BaseToolPlugin):
+    """Typos spell checker plugin.
+
+    This plugin runs typos with JSON output for checks and with
+    ``--write-changes`` for fixes. Words listed in ``accepted_words`` are
+    passed to typos as valid, on top of the project's typos config.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="typos",
+            description="Source code spell checker for known misspellings",
+            can_fix=True,
+            tool_type=ToolType.LINTER,
+            file_patterns=TYPOS_FILE_PATTERNS,
+            priority=TYPOS_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=list(TYPOS_CONFIGS),
+            version_command=["typos", "--version"],
+            min_version=get_min_version(ToolName.TYPOS),
+            default_options={
+                "timeout": TYPOS_DEFAULT_TIMEOUT,
+                "accepted_words": None,
+            },
+            default_timeout=TYPOS_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        accepted_words: list[str] | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set typos-specific options.
+
+        Args:
+            accepted_words: Words typos must treat as correctly spelled,
+                such as product names and project jargon.
+            timeout: Timeout in seconds (default: 60).
+            **kwargs: Additional base options.
+
+        Raises:
+            ValueError: If accepted_words contains non-string entries.
+        """
+        validate_list(accepted_words, "accepted_words")
+        validate_positive_int(timeout, "timeout")
+        if accepted_words is not None and not all(
+            isinstance(word, str) for word in accepted_words
+        ):
+            raise ValueError("accepted_words must be a list of strings")
+
+        options = filter_none_options(
+            accepted_words=accepted_words,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _create_accepted_words_config(self, words: list[str]) -> str | None:
+        """Write a temporary typos config accepting the given words.
+
+        typos layers a ``--config`` file over the config it discovers in
+        the project, so the project's own settings still apply.
+
+        Args:
+            words: Words to accept.
+
+        Returns:
+            Path to the temporary config file, or None if creation failed.
+        """
+        lines = ["[default.extend-words]"]
+        # JSON strings are valid TOML basic strings
+        lines += [f"{json.dumps(word)} = {json.dumps(word)}" for word in words]
+
+        try:
+            with tempfile.NamedTemporaryFile(
+                mode="w",
+                suffix=".toml",
+                prefix="lintro-typos-",
+                delete=False,
+                encoding="utf-8",
+            ) as f:
+                f.write("\n".join(lines) + "\n")
+                temp_path = f.name
+        except OSError as e:
+            logger.warning(f"[TyposPlugin] Could not create temp config file: {e}")
+            return None
+
+        logger.debug(
+            f"[TyposPlugin] Created temp config at {temp_path} "
+            f"accepting {len(words)} word(s)",
+        )
+        return temp_path
+
+    def _run_typos(
+        self,
+        ctx: ExecutionContext,
+        write_changes: bool = False,
+    ) -> tuple[bool, str]:
+        """Run typos on the discovered files.
+
+        Args:
+            ctx: Execution context from _prepare_execution.
+            write_changes: Whether to apply corrections instead of reporting.
+
+        Returns:
+            Tuple of (success, output).
+
+        Raises:
+            subprocess.TimeoutExpired: If the run exceeds the timeout.
+        """
+        # Explicit files would otherwise bypass the config's excludes
+        cmd: list[str] = ["typos", "--format", "json", "--force-exclude"]
+
+        accepted = self.options.get("accepted_words")
+        temp_config_path: str | None = None
+        if isinstance(accepted, list) and accepted:
+            temp_config_path = self._create_accepted_words_config(
+                [str(word) for word in accepted],
+            )
+            if temp_config_path:
+                cmd.extend(["--config", temp_config_path])
+
+        if write_changes:
+            cmd.append("--write-changes")
+        cmd.extend(ctx.rel_files)
+
+        logger.debug(f"[TyposPlugin] Running: {' '.join(cmd)} (cwd={ctx.cwd})")
+
+        try:
+            return self._run_subprocess(cmd=cmd, timeout=ctx.timeout, cwd=ctx.cwd)
+        finally:
+            if temp_config_path:
+                try:
+                    os.unlink(temp_config_path)
+                except OSError as e:
+                    logger.debug(f"[TyposPlugin] Failed to clean up temp config: {e}")
+
+    def _create_timeout_result(
+        self,
+        timeout_val: int,
+        initial_count: int | None = None,
+    ) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+            initial_count: Issues found before fixing, for fix runs.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"typos execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options typos:timeout=N or exclude "
+            "generated files in the typos config."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+            initial_issues_count=initial_count,
+            fixed_issues_count=None if initial_count is None else 0,
+            remaining_issues_count=initial_count,
+        )
+
+    def _create_failure_result(self, output: str) -> ToolResult:
+        """Create a failed result for a run that reported no typos.
+
+        typos exits non-zero without findings when its config is invalid.
+
+        Args:
+            output: Output of the failed run.
+
+        Returns:
+            ToolResult: Failed result carrying typos' message.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=output.strip() or "typos execution failed.",
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check files for misspellings with typos.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            run_success, output = self._run_typos(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        issues: list[TyposIssue] = parse_typos_output(output=output)
+        if not run_success and not issues:
+            return self._create_failure_result(output)
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not issues,
+            output=None if not issues else output,
+            issues_count=len(issues),
+            issues=issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Correct misspellings in place with ``typos --write-changes``.
+
+        Words with several possible corrections are left for a person to
+        choose and reported as remaining issues.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult: Result object with counts and messages.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to fix.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        try:
+            check_success, check_output = self._run_typos(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(timeout_val=ctx.timeout)
+
+        initial_count = len(parse_typos_output(output=check_output))
+        if not check_success and initial_count == 0:
+            return self._create_failure_result(check_output)
+
+        try:
+            if initial_count:
+                self._run_typos(ctx, write_changes=True)
+            # --write-changes does not report what it left behind
+            _, remaining_output = self._run_typos(ctx)
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(
+                timeout_val=ctx.timeout,
+                initial_count=initial_count,
+            )
+
+        remaining_issues = parse_typos_output(output=remaining_output)
+        remaining_count = len(remaining_issues)
+        fixed_count = max(0, initial_count - remaining_count)
+
+        output_lines: list[str] = []
+        if fixed_count > 0:
+            output_lines.append(f"Fixed {fixed_count} issue(s)")
+        if remaining_count > 0:
+            output_lines.append(
+                f"Found {remaining_count} issue(s) that cannot be auto-fixed",
+            )
+        elif fixed_count > 0:
+            output_lines.append("All issues were successfully auto-fixed")
+
+        return ToolResult(
+            name=self.definition.name,
+            success=remaining_count == 0,
+            output="\n".join(output_lines) if output_lines else None,
+            issues_count=remaining_count,
+            issues=remaining_issues,
+            initial_issues_count=initial_count,
+            fixed_issues_count=fixed_count,
+            remaining_issues_count=remaining_count,
+        )
@@ -0,0 +1,273 @@
+"""Vale tool definition.
+
+Vale is a prose linter that checks documentation against style guides, such
+as passive voice, banned words or project terminology. Its rules come from
+the styles configured in the project's ``.vale.ini``, so lintro skips Vale
+in projects without one instead of running it with no rules.
+"""
+
+from __future__ import annotations
+
+import os
+import subprocess  # nosec B404 - used safely with shell disabled
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro._tool_versions import get_min_version
+from lintro.enums.tool_name import ToolName
+from lintro.enums.tool_type import ToolType
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.vale.vale_parser import parse_vale_output
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import (
+    filter_none_options,
+    validate_positive_int,
+    validate_str,
+)
+from lintro.utils.unified_config import DEFAULT_TOOL_PRIORITIES
+
+# Constants for Vale configuration
+VALE_DEFAULT_TIMEOUT: int = 120
+VALE_DEFAULT_PRIORITY: int = DEFAULT_TOOL_PRIORITIES.get("vale", 60)
+VALE_FILE_PATTERNS: list[str] = ["*.md", "*.mdx", "*.rst"]
+# Config file names Vale looks for in each directory, in order
+VALE_CONFIG_NAMES: tuple[str, ...] = (".vale.ini", "_vale.ini", "vale.ini")
+VALE_ALERT_LEVELS: tuple[str, ...] = ("suggestion", "warning", "error")
+
+
+def find_vale_config(start: str | Path) -> Path | None:
+    """Find the Vale config Vale would use when run from a directory.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        Path to the nearest Vale config, or None if there is none.
+    """
+    directory = Path(start).resolve()
+    for candidate in (directory, *directory.parents):
+        for name in VALE_CONFIG_NAMES:
+            if (candidate / name).is_file():
+                return candidate / name
+    return None
+
+
+@register_tool
+@dataclass
+class ValePlugin(BaseToolPlugin):
+    """Vale prose linter plugin.
+
+    This plugin runs ``vale --output=JSON`` on Markdown and reStructuredText
+    files with the project's Vale config.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="vale",
+            description="Prose linter enforcing documentation style guides",
+            can_fix=False,
+            tool_type=ToolType.LINTER,
+            file_patterns=VALE_FILE_PATTERNS,
+            priority=VALE_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=list(VALE_CONFIG_NAMES),
+            version_command=["vale", "--version"],
+            min_version=get_min_version(ToolName.VALE),
+            default_options={
+                "timeout": VALE_DEFAULT_TIMEOUT,
+                "config": None,
+                "min_alert_level": None,
+            },
+            default_timeout=VALE_DEFAULT_TIMEOUT,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        config: str | None = None,
+        min_alert_level: str | None = None,
+        timeout: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Set Vale-specific options.
+
+        Args:
+            config: Path to the Vale config, instead of the nearest
+                ``.vale.ini``.
+            min_alert_level: Lowest alert level to report (``suggestion``,
+                ``warning`` or ``error``), overriding ``MinAlertLevel``.
+            timeout: Timeout in seconds (default: 120).
+            **kwargs: Additional base options.
+
+        Raises:
+            ValueError: If min_alert_level is not a Vale alert level.
+        """
+        validate_str(config, "config")
+        validate_str(min_alert_level, "min_alert_level")
+        validate_positive_int(timeout, "timeout")
+        if min_alert_level is not None:
+            min_alert_level = min_alert_level.lower()
+            if min_alert_level not in VALE_ALERT_LEVELS:
+                raise ValueError(
+                    f"min_alert_level must be one of {list(VALE_ALERT_LEVELS)}",
+                )
+
+        options = filter_none_options(
+            config=config,
+            min_alert_level=min_alert_level,
+            timeout=timeout,
+        )
+        super().set_options(**options, **kwargs)
+
+    def _lacks_config(self, cwd: str | None) -> bool:
+        """Check whether Vale would run without any config.
+
+        Args:
+            cwd: Directory Vale runs in.
+
+        Returns:
+            bool: True if no config option, ``VALE_CONFIG_PATH`` or config
+            file applies.
+        """
+        if self.options.get("config") or os.environ.get("VALE_CONFIG_PATH"):
+            return False
+        return find_vale_config(cwd or os.getcwd()) is None
+
+    def _create_no_config_result(self) -> ToolResult:
+        """Create a skipped result for projects without a Vale config.
+
+        Returns:
+            ToolResult: Skipped result explaining how to configure Vale.
+        """
+        return ToolResult(
+            name=self.definition.name,
+            output=(
+                "No Vale config (.vale.ini, _vale.ini or vale.ini) found. Add "
+                "one, or point to it with --tool-options vale:config=PATH."
+            ),
+            issues_count=0,
+            skipped=True,
+            skip_reason="no .vale.ini",
+        )
+
+    def _build_command(self, files: list[str]) -> list[str]:
+        """Build the Vale command.
+
+        Args:
+            files: Files to check, relative to the working directory.
+
+        Returns:
+            List of command arguments.
+        """
+        cmd = self._get_executable_command(tool_name="vale")
+        cmd.append("--output=JSON")
+        config = self.options.get("config")
+        if config:
+            cmd.append(f"--config={config}")
+        min_alert_level = self.options.get("min_alert_level")
+        if min_alert_level:
+            cmd.append(f"--minAlertLevel={min_alert_level}")
+        cmd.extend(files)
+        return cmd
+
+    def _create_timeout_result(self, timeout_val: int) -> ToolResult:
+        """Create a ToolResult for timeout scenarios.
+
+        Args:
+            timeout_val: The timeout value that was exceeded.
+
+        Returns:
+            ToolResult: ToolResult instance representing timeout failure.
+        """
+        timeout_msg = (
+            f"Vale execution timed out ({timeout_val}s limit exceeded).\n\n"
+            "Increase the timeout via --tool-options vale:timeout=N."
+        )
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            output=timeout_msg,
+            error_code=TOOL_TIMEOUT.code,
+            issues_count=0,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Lint documentation prose with Vale.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No documentation files to check.",
+        )
+        if ctx.should_skip:
+            assert ctx.early_result is not None
+            return ctx.early_result
+
+        if self._lacks_config(ctx.cwd):
+            return self._create_no_config_result()
+
+        cmd = self._build_command(ctx.rel_files)
+        logger.debug(f"[ValePlugin] Running: {' '.join(cmd[:6])}... (cwd={ctx.cwd})")
+        try:
+            run_success, output = self._run_subprocess(
+                cmd=cmd,
+                timeout=ctx.timeout,
+                cwd=ctx.cwd,
+            )
+        except subprocess.TimeoutExpired:
+            return self._create_timeout_result(ctx.timeout)
+
+        report = parse_vale_output(output=output)
+        if report is None:
+            # Vale exits 1 for error-level alerts, so only fail without a report
+            message = output.strip() or "Vale execution failed."
+            return ToolResult(
+                name=self.definition.name,
+                success=run_success,
+                output=None if run_success else message,
+                issues_count=0,
+            )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not report.issues and not report.errors,
+            output="\n".join(report.errors) if report.errors else None,
+            issues_count=len(report.issues),
+            issues=report.issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Vale cannot fix issues, only report them.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Tool-specific options.
+
+        Returns:
+            ToolResult: Never returns, always raises NotImplementedError.
+
+        Raises:
+            NotImplementedError: Vale does not support fixing issues.
+        """
+        raise NotImplementedError(
+            "Vale cannot automatically fix issues. Run 'lintro check' to see "
+            "issues.",
+        )
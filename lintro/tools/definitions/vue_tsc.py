@@ -82,6 +82,7 @@ class VueTscPlugin(BaseToolPlugin):
                 "use_project_files": False,
             },
             default_timeout=VUE_TSC_DEFAULT_TIMEOUT,
+            per_file=False,
         )
 
     def set_options(  # type: ignore[override]
@@ -470,6 +471,7 @@ class VueTscPlugin(BaseToolPlugin):
                     name=self.definition.name,
                     success=timeout_result.success,
                     output=timeout_result.output,
+                    error_code=timeout_result.error_code,
                     issues_count=timeout_result.issues_count,
                     issues=timeout_result.issues,
                 )
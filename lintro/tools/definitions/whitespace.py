@@ -0,0 +1,223 @@
+"""Whitespace tool definition.
+
+Native checker for whitespace and end-of-file hygiene. Finds trailing
+whitespace, missing final newlines, blank lines at the end of files, spaces
+before tabs in indentation and byte order marks in every text file, and
+rewrites all but the indentation when fixing. Runs inside Lintro, so no
+external binary or hook framework is required.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from pathlib import PurePath
+
+from loguru import logger
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.whitespace.whitespace_issue import WhitespaceIssue
+from lintro.plugins.base import BaseToolPlugin
+from lintro.plugins.protocol import ToolDefinition
+from lintro.plugins.registry import register_tool
+from lintro.tools.core.option_validators import filter_none_options, validate_bool
+from lintro.tools.definitions.editorconfig import read_text_file
+from lintro.tools.implementations.whitespace import check_text, fix_text
+
+# Constants for whitespace configuration
+WHITESPACE_DEFAULT_TIMEOUT: int = 60
+# Runs before the formatters, which keep the final say on layout
+WHITESPACE_DEFAULT_PRIORITY: int = 5
+WHITESPACE_FILE_PATTERNS: list[str] = ["*"]
+# Files where two trailing spaces are a hard line break
+MARKDOWN_SUFFIXES: frozenset[str] = frozenset({".markdown", ".md", ".mdx"})
+# Files whose whitespace is content, such as blank context lines in diffs
+SKIPPED_SUFFIXES: frozenset[str] = frozenset({".diff", ".patch"})
+
+
+@register_tool
+@dataclass
+class WhitespacePlugin(BaseToolPlugin):
+    """Native whitespace hygiene plugin.
+
+    This plugin checks text files for stray whitespace and byte order marks
+    and fixes them without invoking any external tool.
+    """
+
+    @property
+    def definition(self) -> ToolDefinition:
+        """Return the tool definition.
+
+        Returns:
+            ToolDefinition containing tool metadata.
+        """
+        return ToolDefinition(
+            name="whitespace",
+            description="Trailing whitespace, final newlines, indentation and BOMs",
+            can_fix=True,
+            tool_type=ToolType.LINTER | ToolType.FORMATTER,
+            file_patterns=WHITESPACE_FILE_PATTERNS,
+            priority=WHITESPACE_DEFAULT_PRIORITY,
+            conflicts_with=[],
+            native_configs=[],
+            version_command=None,
+            min_version=None,
+            default_options={
+                "timeout": WHITESPACE_DEFAULT_TIMEOUT,
+                "markdown_linebreaks": True,
+            },
+            default_timeout=WHITESPACE_DEFAULT_TIMEOUT,
+            native=True,
+            # Would report long-standing whitespace in every existing project
+            enabled_by_default=False,
+        )
+
+    def set_options(  # type: ignore[override]
+        self,
+        markdown_linebreaks: bool | None = None,
+        **kwargs: object,
+    ) -> None:
+        """Set whitespace-specific options with validation.
+
+        Args:
+            markdown_linebreaks: Keep two or more trailing spaces in
+                Markdown files, where they are a hard line break.
+            **kwargs: Additional base options.
+        """
+        validate_bool(markdown_linebreaks, "markdown_linebreaks")
+
+        options = filter_none_options(markdown_linebreaks=markdown_linebreaks)
+        super().set_options(**options, **kwargs)
+
+    def _is_markdown(self, file_path: str, options: dict[str, object]) -> bool:
+        """Tell whether trailing spaces in a file may be hard line breaks.
+
+        Args:
+            file_path: File being checked.
+            options: Runtime options that override defaults.
+
+        Returns:
+            bool: True for Markdown files unless ``markdown_linebreaks`` is off.
+        """
+        merged_options = {**self.options, **options}
+        return bool(
+            merged_options.get("markdown_linebreaks", True)
+            and PurePath(file_path).suffix.lower() in MARKDOWN_SUFFIXES,
+        )
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Check text files for whitespace problems.
+
+        Args:
+            paths: List of file or directory paths to check.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with check results.
+        """
+        ctx = self._prepare_execution(paths, options)
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        all_issues: list[WhitespaceIssue] = []
+        errors: list[str] = []
+        checked = 0
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            if PurePath(file_path).suffix.lower() in SKIPPED_SUFFIXES:
+                continue
+            try:
+                text = read_text_file(file_path)
+            except OSError as e:
+                errors.append(f"Error reading {rel_path}: {e}")
+                continue
+            if text is None:
+                continue
+            checked += 1
+            markdown = self._is_markdown(file_path, options)
+            all_issues.extend(check_text(text, rel_path, markdown=markdown))
+
+        logger.debug(
+            f"[WhitespacePlugin] Checked {checked} file(s), "
+            f"found {len(all_issues)} issue(s)",
+        )
+
+        count = len(all_issues)
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and count == 0,
+            output="\n".join(errors) if errors else None,
+            issues_count=count,
+            issues=all_issues,
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Fix whitespace problems and report mixed indentation.
+
+        Args:
+            paths: List of file or directory paths to fix.
+            options: Runtime options that override defaults.
+
+        Returns:
+            ToolResult with fix results.
+        """
+        ctx = self._prepare_execution(
+            paths,
+            options,
+            no_files_message="No files to format.",
+        )
+        if ctx.should_skip:
+            return ctx.early_result  # type: ignore[return-value]
+
+        initial_total = 0
+        remaining_issues: list[WhitespaceIssue] = []
+        fixed_files: list[str] = []
+        errors: list[str] = []
+        for file_path, rel_path in zip(ctx.files, ctx.rel_files, strict=True):
+            if PurePath(file_path).suffix.lower() in SKIPPED_SUFFIXES:
+                continue
+            markdown = self._is_markdown(file_path, options)
+            try:
+                text = read_text_file(file_path)
+                if text is None:
+                    continue
+                initial_total += len(check_text(text, rel_path, markdown=markdown))
+                fixed_text = fix_text(text, markdown=markdown)
+                if fixed_text != text:
+                    with open(file_path, "w", encoding="utf-8", newline="") as f:
+                        f.write(fixed_text)
+                    fixed_files.append(rel_path)
+                    text = fixed_text
+            except OSError as e:
+                errors.append(f"Error processing {rel_path}: {e}")
+                continue
+            remaining_issues.extend(check_text(text, rel_path, markdown=markdown))
+
+        remaining = len(remaining_issues)
+        fixed = initial_total - remaining
+
+        summary_parts: list[str] = []
+        if fixed_files:
+            summary_parts.append(
+                f"Fixed {fixed} issue(s) in {len(fixed_files)} file(s)",
+            )
+        if remaining > 0:
+            summary_parts.append(
+                f"Found {remaining} issue(s) that cannot be auto-fixed",
+            )
+        summary_parts.extend(errors)
+
+        logger.debug(
+            f"[WhitespacePlugin] Fix complete: initial={initial_total}, "
+            f"fixed={fixed}, remaining={remaining}",
+        )
+
+        return ToolResult(
+            name=self.definition.name,
+            success=not errors and remaining == 0,
+            output="\n".join(summary_parts) if summary_parts else "No fixes needed.",
+            issues_count=remaining,
+            issues=remaining_issues,
+            initial_issues_count=initial_total,
+            fixed_issues_count=fixed,
+            remaining_issues_count=remaining,
+        )
@@ -0,0 +1,23 @@
+"""Asset audit tool implementation helpers.
+
+This package provides the image size, WebP and SVG metadata checks used by
+the native asset_audit plugin.
+"""
+
+from lintro.tools.implementations.asset_audit.asset_checks import (
+    DEFAULT_MAX_SIZE_KB,
+    DEFAULT_WEBP_MIN_SAVINGS,
+    check_asset_bytes,
+    find_svg_editor_metadata,
+    image_format,
+    webp_encoder_available,
+)
+
+__all__ = [
+    "DEFAULT_MAX_SIZE_KB",
+    "DEFAULT_WEBP_MIN_SAVINGS",
+    "check_asset_bytes",
+    "find_svg_editor_metadata",
+    "image_format",
+    "webp_encoder_available",
+]
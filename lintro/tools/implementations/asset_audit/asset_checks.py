@@ -0,0 +1,199 @@
+"""Image and asset optimization checks.
+
+Checks image files without invoking any external tool:
+
+- IA001: File is larger than the size threshold for its format
+- IA002: PNG would be noticeably smaller as lossless WebP
+- IA003: SVG contains editor metadata that an optimizer would strip
+
+IA002 needs Pillow (``pip install 'lintro[images]'``) to encode the WebP
+candidate and is skipped when Pillow is not installed. IA003 is fixable with
+svgo.
+"""
+
+from __future__ import annotations
+
+import importlib.util
+import io
+import re
+from pathlib import Path
+
+from loguru import logger
+
+from lintro.parsers.asset_audit.asset_audit_issue import AssetAuditIssue
+
+# Size thresholds in KiB per normalized format
+DEFAULT_MAX_SIZE_KB: dict[str, int] = {
+    "avif": 300,
+    "gif": 1000,
+    "jpg": 500,
+    "png": 500,
+    "svg": 100,
+    "webp": 300,
+}
+# Minimum WebP saving, in percent of the PNG size, worth reporting
+DEFAULT_WEBP_MIN_SAVINGS: int = 20
+
+_FORMAT_ALIASES: dict[str, str] = {"jpeg": "jpg"}
+
+# (editor, pattern) pairs identifying metadata left behind by editors
+_SVG_EDITOR_MARKERS: tuple[tuple[str, re.Pattern[str]], ...] = (
+    ("Inkscape", re.compile(r"\b(?:xmlns:)?(?:inkscape|sodipodi)[:=]")),
+    ("Sketch", re.compile(r"\b(?:xmlns:sketch|sketch:type)\b")),
+    (
+        "Adobe Illustrator",
+        re.compile(r"Generator: Adobe Illustrator|\bi:pgf\b|&ns_ai;"),
+    ),
+    ("Figma", re.compile(r"\bxmlns:figma\b|\bfigma:")),
+    ("<metadata>", re.compile(r"<metadata\b")),
+)
+
+
+def image_format(path: str | Path) -> str:
+    """Return the normalized image format for a path.
+
+    Args:
+        path: Image file path.
+
+    Returns:
+        str: Lowercase format such as ``png`` or ``jpg``.
+    """
+    suffix = Path(path).suffix.lower().lstrip(".")
+    return _FORMAT_ALIASES.get(suffix, suffix)
+
+
+def webp_encoder_available() -> bool:
+    """Check whether Pillow is installed to encode WebP candidates.
+
+    Returns:
+        bool: True if IA002 can run.
+    """
+    return importlib.util.find_spec("PIL") is not None
+
+
+def estimate_webp_size(data: bytes) -> int | None:
+    """Encode an image as lossless WebP and return the encoded size.
+
+    Args:
+        data: Raw image bytes.
+
+    Returns:
+        int | None: Size in bytes, or None if Pillow is missing or the image
+            cannot be encoded.
+    """
+    try:
+        from PIL import Image
+    except ImportError:
+        logger.debug("[asset_audit] Pillow not installed, skipping WebP check")
+        return None
+
+    buffer = io.BytesIO()
+    try:
+        with Image.open(io.BytesIO(data)) as image:
+            image.save(buffer, format="WEBP", lossless=True)
+    except (OSError, ValueError) as e:
+        logger.debug(f"[asset_audit] Could not encode WebP candidate: {e}")
+        return None
+    return buffer.tell()
+
+
+def find_svg_editor_metadata(text: str) -> list[str]:
+    """List the editors whose metadata an SVG still carries.
+
+    Args:
+        text: SVG document.
+
+    Returns:
+        list[str]: Editor names in a stable order.
+    """
+    return [name for name, pattern in _SVG_EDITOR_MARKERS if pattern.search(text)]
+
+
+def _format_size(size: int) -> str:
+    """Format a byte count for messages.
+
+    Args:
+        size: Size in bytes.
+
+    Returns:
+        str: Size in KiB or MiB.
+    """
+    if size >= 1024 * 1024:
+        return f"{size / (1024 * 1024):.1f} MiB"
+    return f"{size / 1024:.0f} KiB"
+
+
+def check_asset_bytes(
+    data: bytes,
+    file_path: str,
+    max_size_kb: dict[str, int] | None = None,
+    check_webp: bool = True,
+    webp_min_savings: int = DEFAULT_WEBP_MIN_SAVINGS,
+) -> list[AssetAuditIssue]:
+    """Check an image for optimization opportunities.
+
+    Args:
+        data: Raw file content.
+        file_path: Path used when reporting issues.
+        max_size_kb: Size thresholds in KiB per format, merged over the
+            defaults. A threshold of 0 disables IA001 for that format.
+        check_webp: Whether to compare PNGs against lossless WebP (IA002).
+        webp_min_savings: Minimum saving in percent reported by IA002.
+
+    Returns:
+        list[AssetAuditIssue]: Issues found in the file.
+    """
+    fmt = image_format(file_path)
+    size = len(data)
+    thresholds = {**DEFAULT_MAX_SIZE_KB, **(max_size_kb or {})}
+    issues: list[AssetAuditIssue] = []
+
+    def add(code: str, message: str, level: str = "warning") -> None:
+        """Record an issue for this file.
+
+        Args:
+            code: Rule code.
+            message: Issue description.
+            level: Severity level.
+        """
+        issues.append(
+            AssetAuditIssue(
+                file=file_path,
+                level=level,
+                code=code,
+                message=message,
+                size=size,
+                fixable=code == "IA003",
+            ),
+        )
+
+    limit_kb = thresholds.get(fmt, 0)
+    if limit_kb > 0 and size > limit_kb * 1024:
+        add(
+            "IA001",
+            f"{fmt.upper()} is {_format_size(size)}, over the {limit_kb} KiB "
+            "limit",
+        )
+
+    if fmt == "png" and check_webp and size > 0:
+        webp_size = estimate_webp_size(data)
+        if webp_size is not None:
+            savings = round(100 * (size - webp_size) / size)
+            if savings >= webp_min_savings:
+                add(
+                    "IA002",
+                    f"Would be {savings}% smaller as lossless WebP "
+                    f"({_format_size(size)} -> {_format_size(webp_size)})",
+                    level="info",
+                )
+
+    if fmt == "svg":
+        editors = find_svg_editor_metadata(data.decode("utf-8", errors="replace"))
+        if editors:
+            add(
+                "IA003",
+                f"SVG contains editor metadata ({', '.join(editors)}); "
+                "optimize it with svgo",
+            )
+
+    return issues
@@ -0,0 +1,19 @@
+"""Conventional Commits tool implementation helpers.
+
+This package provides the git history reader and message checks used by the
+native conventional_commits plugin.
+"""
+
+from lintro.tools.implementations.conventional_commits.commit_checks import (
+    DEFAULT_TYPES,
+    CommitMessage,
+    check_commit_message,
+    read_commit_messages,
+)
+
+__all__ = [
+    "DEFAULT_TYPES",
+    "CommitMessage",
+    "check_commit_message",
+    "read_commit_messages",
+]
@@ -0,0 +1,198 @@
+"""Conventional Commits checks for commit messages and PR titles.
+
+semantic-release derives the next version from commit headers such as
+``feat(cli): add --group-by``, so a malformed header silently drops a change
+from the release notes. These helpers read commit messages from git and
+validate each header against the Conventional Commits format:
+
+Codes:
+    CC001: Header is not ``type(scope)!: subject``.
+    CC002: Type is not one of the allowed types.
+    CC003: Scope is missing but required.
+    CC004: Header is longer than the allowed length.
+    CC005: Body is not separated from the header by a blank line.
+"""
+
+from __future__ import annotations
+
+import re
+import subprocess  # nosec B404 - used safely with shell disabled
+from collections.abc import Iterable
+from dataclasses import dataclass
+
+from lintro.parsers.conventional_commits.conventional_commits_issue import (
+    ConventionalCommitsIssue,
+)
+
+# Types understood by the angular commit parser semantic-release uses
+DEFAULT_TYPES: tuple[str, ...] = (
+    "build",
+    "chore",
+    "ci",
+    "docs",
+    "feat",
+    "fix",
+    "perf",
+    "refactor",
+    "revert",
+    "style",
+    "test",
+)
+
+# Headers git writes for autosquash and merges, which never reach a release
+_IGNORED_PREFIXES: tuple[str, ...] = ("fixup! ", "squash! ", "amend! ", "Merge ")
+
+_HEADER_RE: re.Pattern[str] = re.compile(
+    r"^(?P<type>[A-Za-z]+)(?:\((?P<scope>[^()]*)\))?(?P<breaking>!)?: "
+    r"(?P<subject>\S.*)$",
+)
+
+# Separators between commits and between hash and message in git log output,
+# with the escapes git's --format uses to print them
+_RECORD_SEPARATOR = "\x1e"
+_FIELD_SEPARATOR = "\x00"
+_LOG_FORMAT = "%H%x00%B%x1e"
+
+
+@dataclass(frozen=True)
+class CommitMessage:
+    """A commit message read from git.
+
+    Attributes:
+        sha: Full commit hash.
+        message: Raw commit message.
+    """
+
+    sha: str
+    message: str
+
+
+def read_commit_messages(
+    commit_range: str,
+    cwd: str | None = None,
+) -> list[CommitMessage]:
+    """Read the messages of the non-merge commits in a revision range.
+
+    Args:
+        commit_range: Git revision range, such as ``origin/main..HEAD``.
+        cwd: Repository directory; the current directory if None.
+
+    Returns:
+        list[CommitMessage]: Commits in the range, newest first.
+
+    Raises:
+        ValueError: If git is missing or cannot resolve the range.
+    """
+    cmd = [
+        "git",
+        "log",
+        "--no-merges",
+        f"--format={_LOG_FORMAT}",
+        commit_range,
+        "--",
+    ]
+    try:
+        result = subprocess.run(  # nosec B603 - fixed git command, no shell
+            cmd,
+            capture_output=True,
+            text=True,
+            cwd=cwd,
+            check=False,
+        )
+    except FileNotFoundError as e:
+        raise ValueError("git is not installed") from e
+    if result.returncode != 0:
+        detail = result.stderr.strip() or f"git exited with {result.returncode}"
+        raise ValueError(f"Cannot read commits in {commit_range!r}: {detail}")
+
+    commits: list[CommitMessage] = []
+    for record in result.stdout.split(_RECORD_SEPARATOR):
+        record = record.lstrip("\n")
+        if _FIELD_SEPARATOR not in record:
+            continue
+        sha, message = record.split(_FIELD_SEPARATOR, 1)
+        commits.append(CommitMessage(sha=sha.strip(), message=message))
+    return commits
+
+
+def check_commit_message(
+    message: str,
+    source: str,
+    *,
+    types: Iterable[str] = DEFAULT_TYPES,
+    require_scope: bool = False,
+    max_header_length: int = 100,
+    commit: str = "",
+) -> list[ConventionalCommitsIssue]:
+    """Check a commit message or PR title against Conventional Commits.
+
+    Args:
+        message: Commit message or PR title.
+        source: Label reported as the issue file, such as ``PR title``.
+        types: Allowed commit types.
+        require_scope: Whether the header must have a ``(scope)``.
+        max_header_length: Longest allowed header; 0 disables the check.
+        commit: Full hash of the commit, if the message is one.
+
+    Returns:
+        list[ConventionalCommitsIssue]: Issues found in the message.
+    """
+    lines = message.strip("\n").splitlines()
+    header = lines[0].strip() if lines else ""
+    if header.startswith(_IGNORED_PREFIXES):
+        return []
+
+    def issue(code: str, text: str, line: int = 1) -> ConventionalCommitsIssue:
+        """Build an issue for the message.
+
+        Args:
+            code: Rule code.
+            text: Issue description.
+            line: Line within the message.
+
+        Returns:
+            ConventionalCommitsIssue: The issue.
+        """
+        return ConventionalCommitsIssue(
+            file=source,
+            line=line,
+            message=text,
+            code=code,
+            commit=commit,
+        )
+
+    issues: list[ConventionalCommitsIssue] = []
+    match = _HEADER_RE.match(header)
+    if match is None:
+        issues.append(
+            issue(
+                "CC001",
+                f"Header {header!r} does not match 'type(scope): subject'",
+            ),
+        )
+    else:
+        allowed = list(types)
+        if match.group("type") not in allowed:
+            issues.append(
+                issue(
+                    "CC002",
+                    f"Type {match.group('type')!r} is not one of: "
+                    f"{', '.join(allowed)}",
+                ),
+            )
+        if require_scope and not (match.group("scope") or "").strip():
+            issues.append(issue("CC003", "Header has no scope, e.g. 'fix(cli): ...'"))
+
+    if max_header_length and len(header) > max_header_length:
+        issues.append(
+            issue(
+                "CC004",
+                f"Header is {len(header)} characters long "
+                f"(max {max_header_length})",
+            ),
+        )
+    if len(lines) > 1 and lines[1].strip():
+        issues.append(
+            issue("CC005", "Body must be separated from the header by a blank line", 2),
+        )
+    return issues
@@ -0,0 +1,25 @@
+"""EditorConfig tool implementation helpers.
+
+This package provides the ``.editorconfig`` resolution and the compliance
+checks and fixes used by the native editorconfig plugin.
+"""
+
+from lintro.tools.implementations.editorconfig.editorconfig_checks import (
+    check_text,
+    fix_text,
+)
+from lintro.tools.implementations.editorconfig.editorconfig_rules import (
+    EDITORCONFIG_FILE,
+    EditorconfigResolver,
+    compile_glob,
+    parse_editorconfig,
+)
+
+__all__ = [
+    "EDITORCONFIG_FILE",
+    "EditorconfigResolver",
+    "check_text",
+    "compile_glob",
+    "fix_text",
+    "parse_editorconfig",
+]
@@ -0,0 +1,280 @@
+"""EditorConfig compliance checks and fixes.
+
+Checks a file's text against the properties its ``.editorconfig`` sets:
+
+- EC001: Indentation does not use ``indent_style``
+- EC002: Line has trailing whitespace (``trim_trailing_whitespace``)
+- EC003: Final newline is missing or unwanted (``insert_final_newline``)
+- EC004: Line ending is not ``end_of_line``
+- EC005: Line is longer than ``max_line_length``
+
+Every violation except EC005 is mechanical and rewritten by
+``fix_text``.
+"""
+
+from __future__ import annotations
+
+import re
+
+from lintro.parsers.editorconfig.editorconfig_issue import EditorconfigIssue
+
+_LINE_RE: re.Pattern[str] = re.compile(r"([^\r\n]*)(\r\n|\r|\n|$)")
+_LINE_ENDINGS: dict[str, str] = {"lf": "\n", "crlf": "\r\n", "cr": "\r"}
+_DEFAULT_TAB_WIDTH: int = 4
+
+
+def split_lines(text: str) -> list[tuple[str, str]]:
+    """Split text into lines, keeping each line's terminator.
+
+    Args:
+        text: File contents.
+
+    Returns:
+        list[tuple[str, str]]: (content, terminator) pairs; the last line
+        has an empty terminator when the text does not end with a newline.
+    """
+    lines: list[tuple[str, str]] = []
+    for match in _LINE_RE.finditer(text):
+        content, ending = match.group(1), match.group(2)
+        if not content and not ending:
+            break
+        lines.append((content, ending))
+    return lines
+
+
+def _int_property(properties: dict[str, str], name: str) -> int | None:
+    """Read a positive integer property.
+
+    Args:
+        properties: Effective EditorConfig properties.
+        name: Property name.
+
+    Returns:
+        int | None: The value, or None when unset or not a positive integer.
+    """
+    value = properties.get(name, "")
+    return int(value) if value.isdigit() and int(value) > 0 else None
+
+
+def _tab_width(properties: dict[str, str]) -> int:
+    """Return the width of a tab for indentation conversions.
+
+    Args:
+        properties: Effective EditorConfig properties.
+
+    Returns:
+        int: ``tab_width``, else ``indent_size``, else 4.
+    """
+    return (
+        _int_property(properties, "tab_width")
+        or _int_property(properties, "indent_size")
+        or _DEFAULT_TAB_WIDTH
+    )
+
+
+def _leading_whitespace(content: str) -> str:
+    """Return the indentation of a line.
+
+    Args:
+        content: Line content.
+
+    Returns:
+        str: Leading spaces and tabs.
+    """
+    return content[: len(content) - len(content.lstrip(" \t"))]
+
+
+def _indent_mismatch(indent: str, style: str | None, tab_width: int) -> bool:
+    """Check whether an indentation breaks ``indent_style``.
+
+    Args:
+        indent: Leading whitespace of a line.
+        style: ``indent_style`` value.
+        tab_width: Width of a tab.
+
+    Returns:
+        bool: True if the indentation uses the other style.
+    """
+    if style == "space":
+        return "\t" in indent
+    if style == "tab":
+        # Fewer spaces than a tab are alignment, e.g. " * " in block comments
+        return " " * tab_width in indent
+    return False
+
+
+def _reindent(indent: str, style: str, tab_width: int) -> str:
+    """Rewrite an indentation in the configured style.
+
+    Args:
+        indent: Leading whitespace of a line.
+        style: ``indent_style`` value.
+        tab_width: Width of a tab.
+
+    Returns:
+        str: Indentation of the same width in the configured style.
+    """
+    width = 0
+    for char in indent:
+        width = (width // tab_width + 1) * tab_width if char == "\t" else width + 1
+    if style == "space":
+        return " " * width
+    return "\t" * (width // tab_width) + " " * (width % tab_width)
+
+
+def check_text(
+    text: str,
+    file_path: str,
+    properties: dict[str, str],
+) -> list[EditorconfigIssue]:
+    """Check file contents against EditorConfig properties.
+
+    Args:
+        text: File contents, with line endings preserved.
+        file_path: Path used when reporting issues.
+        properties: Effective EditorConfig properties for the file.
+
+    Returns:
+        list[EditorconfigIssue]: Issues found in the file.
+    """
+    issues: list[EditorconfigIssue] = []
+    lines = split_lines(text)
+    style = properties.get("indent_style")
+    tab_width = _tab_width(properties)
+    trim = properties.get("trim_trailing_whitespace") == "true"
+    max_length = _int_property(properties, "max_line_length")
+    expected_ending = _LINE_ENDINGS.get(properties.get("end_of_line", ""))
+    ending_reported = False
+
+    for number, (content, ending) in enumerate(lines, start=1):
+        if _indent_mismatch(_leading_whitespace(content), style, tab_width):
+            issues.append(
+                EditorconfigIssue(
+                    file=file_path,
+                    line=number,
+                    column=1,
+                    message=f"Indentation should use {style}s",
+                    code="EC001",
+                    fixable=True,
+                ),
+            )
+        stripped = content.rstrip(" \t")
+        if trim and stripped != content:
+            issues.append(
+                EditorconfigIssue(
+                    file=file_path,
+                    line=number,
+                    column=len(stripped) + 1,
+                    message="Trailing whitespace",
+                    code="EC002",
+                    fixable=True,
+                ),
+            )
+        if (
+            expected_ending
+            and ending
+            and ending != expected_ending
+            and not ending_reported
+        ):
+            # One issue per file; a file usually has one line ending style
+            ending_reported = True
+            issues.append(
+                EditorconfigIssue(
+                    file=file_path,
+                    line=number,
+                    column=len(content) + 1,
+                    message=(
+                        f"Line endings should be {properties['end_of_line']}, "
+                        f"found {ending!r}"
+                    ),
+                    code="EC004",
+                    fixable=True,
+                ),
+            )
+        if max_length is not None and len(content) > max_length:
+            issues.append(
+                EditorconfigIssue(
+                    file=file_path,
+                    line=number,
+                    column=max_length + 1,
+                    message=f"Line is {len(content)} characters (max {max_length})",
+                    code="EC005",
+                ),
+            )
+
+    final_newline = properties.get("insert_final_newline")
+    if lines and final_newline in ("true", "false"):
+        ends_with_newline = bool(lines[-1][1])
+        if final_newline == "true" and not ends_with_newline:
+            message = "File does not end with a newline"
+        elif final_newline == "false" and ends_with_newline:
+            message = "File ends with a newline"
+        else:
+            message = ""
+        if message:
+            issues.append(
+                EditorconfigIssue(
+                    file=file_path,
+                    line=len(lines),
+                    column=len(lines[-1][0]) + 1,
+                    message=message,
+                    code="EC003",
+                    fixable=True,
+                ),
+            )
+    return issues
+
+
+def fix_text(text: str, properties: dict[str, str]) -> str:
+    """Rewrite the mechanical EditorConfig violations in file contents.
+
+    Args:
+        text: File contents, with line endings preserved.
+        properties: Effective EditorConfig properties for the file.
+
+    Returns:
+        str: Fixed contents; long lines are left as they are.
+    """
+    lines = split_lines(text)
+    if not lines:
+        return text
+    style = properties.get("indent_style")
+    tab_width = _tab_width(properties)
+    trim = properties.get("trim_trailing_whitespace") == "true"
+    expected_ending = _LINE_ENDINGS.get(properties.get("end_of_line", ""))
+
+    fixed: list[tuple[str, str]] = []
+    for content, ending in lines:
+        indent = _leading_whitespace(content)
+        if style and _indent_mismatch(indent, style, tab_width):
+            content = _reindent(indent, style, tab_width) + content[len(indent) :]
+        if trim:
+            content = content.rstrip(" \t")
+        if expected_ending and ending:
+            ending = expected_ending
+        fixed.append((content, ending))
+
+    final_newline = properties.get("insert_final_newline")
+    if final_newline == "true" and not fixed[-1][1]:
+        fixed[-1] = (fixed[-1][0], expected_ending or _dominant_ending(fixed))
+    elif final_newline == "false":
+        while fixed and fixed[-1][1]:
+            content, _ = fixed[-1]
+            if content or len(fixed) == 1:
+                fixed[-1] = (content, "")
+                break
+            fixed.pop()
+    return "".join(content + ending for content, ending in fixed)
+
+
+def _dominant_ending(lines: list[tuple[str, str]]) -> str:
+    """Return the most common line terminator in a file.
+
+    Args:
+        lines: (content, terminator) pairs.
+
+    Returns:
+        str: The most common terminator, ``\\n`` if there is none.
+    """
+    endings = [ending for _, ending in lines if ending]
+    return max(set(endings), key=endings.count) if endings else "\n"
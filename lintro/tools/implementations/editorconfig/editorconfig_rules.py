@@ -0,0 +1,291 @@
+"""Resolution of ``.editorconfig`` properties for a file.
+
+Implements the lookup described by the EditorConfig specification: every
+``.editorconfig`` from the file's directory up to the first one declaring
+``root = true`` is read, and the sections whose glob matches the file are
+applied from the outermost file to the nearest, later sections winning.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+
+EDITORCONFIG_FILE: str = ".editorconfig"
+
+# Properties whose values are case-insensitive keywords
+_KEYWORD_PROPERTIES: frozenset[str] = frozenset(
+    {
+        "indent_style",
+        "indent_size",
+        "tab_width",
+        "end_of_line",
+        "charset",
+        "trim_trailing_whitespace",
+        "insert_final_newline",
+        "max_line_length",
+        "root",
+    },
+)
+
+# Numeric brace ranges larger than this match any integer
+_MAX_EXPANDED_RANGE: int = 1000
+
+
+@dataclass
+class EditorconfigSection:
+    """A ``[glob]`` section of an ``.editorconfig`` file.
+
+    Attributes:
+        glob: Section glob as written.
+        pattern: Compiled glob, matched against paths relative to the
+            directory holding the file.
+        properties: Properties the section sets.
+    """
+
+    glob: str
+    pattern: re.Pattern[str]
+    properties: dict[str, str] = field(default_factory=dict)
+
+
+@dataclass
+class EditorconfigFile:
+    """A parsed ``.editorconfig`` file.
+
+    Attributes:
+        directory: Directory holding the file.
+        root: Whether the file declares ``root = true``.
+        sections: Sections in file order.
+    """
+
+    directory: Path
+    root: bool = False
+    sections: list[EditorconfigSection] = field(default_factory=list)
+
+
+def _translate_braces(body: str) -> str:
+    """Translate the inside of a ``{...}`` group to a regex.
+
+    Args:
+        body: Text between the braces.
+
+    Returns:
+        str: Regex for the group.
+    """
+    numeric = re.fullmatch(r"([+-]?\d+)\.\.([+-]?\d+)", body)
+    if numeric:
+        low, high = sorted((int(numeric.group(1)), int(numeric.group(2))))
+        if high - low > _MAX_EXPANDED_RANGE:
+            return r"[+-]?\d+"
+        return "(?:" + "|".join(str(n) for n in range(low, high + 1)) + ")"
+
+    options: list[str] = []
+    depth = 0
+    current = ""
+    for char in body:
+        if char == "," and depth == 0:
+            options.append(current)
+            current = ""
+            continue
+        depth += {"{": 1, "}": -1}.get(char, 0)
+        current += char
+    options.append(current)
+    if len(options) == 1:
+        return re.escape("{") + _translate(body) + re.escape("}")
+    return "(?:" + "|".join(_translate(option) for option in options) + ")"
+
+
+def _translate(glob: str) -> str:
+    """Translate an EditorConfig glob to a regex.
+
+    Args:
+        glob: Glob using ``*``, ``**``, ``?``, ``[...]`` and ``{...}``.
+
+    Returns:
+        str: Equivalent regex, without anchors.
+    """
+    regex = ""
+    i = 0
+    while i < len(glob):
+        char = glob[i]
+        if char == "\\" and i + 1 < len(glob):
+            regex += re.escape(glob[i + 1])
+            i += 2
+            continue
+        if char == "*":
+            if glob.startswith("**", i):
+                regex += ".*"
+                i += 2
+            else:
+                regex += "[^/]*"
+                i += 1
+            continue
+        if char == "?":
+            regex += "[^/]"
+        elif char == "[":
+            end = glob.find("]", i + 1)
+            if end == -1:
+                regex += re.escape(char)
+            else:
+                chars = glob[i + 1 : end]
+                negate = chars.startswith("!")
+                chars = chars[1:] if negate else chars
+                escaped = chars.replace("\\", "\\\\")
+                regex += "[" + ("^" if negate else "") + escaped + "]"
+                i = end
+        elif char == "{":
+            depth = 0
+            end = -1
+            for j in range(i, len(glob)):
+                depth += {"{": 1, "}": -1}.get(glob[j], 0)
+                if depth == 0:
+                    end = j
+                    break
+            if end == -1:
+                regex += re.escape(char)
+            else:
+                regex += _translate_braces(glob[i + 1 : end])
+                i = end
+        else:
+            regex += re.escape(char)
+        i += 1
+    return regex
+
+
+def compile_glob(glob: str) -> re.Pattern[str]:
+    """Compile a section glob.
+
+    Globs without a ``/`` match files in any subdirectory; globs with one
+    are relative to the directory of the ``.editorconfig``.
+
+    Args:
+        glob: Section glob.
+
+    Returns:
+        re.Pattern[str]: Pattern matched against ``/``-separated relative
+        paths.
+    """
+    if "/" in glob:
+        return re.compile(_translate(glob.removeprefix("/")), re.DOTALL)
+    return re.compile(r"(?:.*/)?" + _translate(glob), re.DOTALL)
+
+
+def parse_editorconfig(text: str, directory: Path) -> EditorconfigFile:
+    """Parse the contents of an ``.editorconfig`` file.
+
+    Args:
+        text: File contents.
+        directory: Directory holding the file.
+
+    Returns:
+        EditorconfigFile: The parsed file.
+    """
+    config = EditorconfigFile(directory=directory)
+    section: EditorconfigSection | None = None
+    for raw_line in text.splitlines():
+        line = raw_line.strip()
+        if not line or line.startswith(("#", ";")):
+            continue
+        if line.startswith("[") and line.endswith("]"):
+            glob = line[1:-1]
+            section = EditorconfigSection(glob=glob, pattern=compile_glob(glob))
+            config.sections.append(section)
+            continue
+        if "=" not in line:
+            continue
+        key, value = (part.strip() for part in line.split("=", 1))
+        key = key.lower()
+        if key in _KEYWORD_PROPERTIES:
+            value = value.lower()
+        if section is None:
+            if key == "root":
+                config.root = value == "true"
+            continue
+        section.properties[key] = value
+    return config
+
+
+class EditorconfigResolver:
+    """Resolves and caches ``.editorconfig`` properties for files."""
+
+    def __init__(self) -> None:
+        """Initialize an empty cache of parsed files."""
+        self._files: dict[Path, EditorconfigFile | None] = {}
+
+    @property
+    def found_config(self) -> bool:
+        """Whether any ``.editorconfig`` was found so far.
+
+        Returns:
+            bool: True once a looked-up directory had an ``.editorconfig``.
+        """
+        return any(config is not None for config in self._files.values())
+
+    def _load(self, directory: Path) -> EditorconfigFile | None:
+        """Load the ``.editorconfig`` of a directory, if it has one.
+
+        Args:
+            directory: Directory to look in.
+
+        Returns:
+            EditorconfigFile | None: The parsed file, or None.
+        """
+        if directory not in self._files:
+            path = directory / EDITORCONFIG_FILE
+            try:
+                text = path.read_text(encoding="utf-8")
+            except (OSError, UnicodeDecodeError):
+                self._files[directory] = None
+            else:
+                self._files[directory] = parse_editorconfig(text, directory)
+        return self._files[directory]
+
+    def properties_for(self, file_path: str | Path) -> dict[str, str]:
+        """Resolve the properties that apply to a file.
+
+        Args:
+            file_path: File to resolve properties for.
+
+        Returns:
+            dict[str, str]: Effective properties, empty when no section
+            matches the file.
+        """
+        path = Path(file_path).resolve()
+        configs: list[EditorconfigFile] = []
+        for directory in path.parents:
+            config = self._load(directory)
+            if config is None:
+                continue
+            configs.append(config)
+            if config.root:
+                break
+
+        properties: dict[str, str] = {}
+        for config in reversed(configs):
+            relative = path.relative_to(config.directory).as_posix()
+            for section in config.sections:
+                if section.pattern.fullmatch(relative):
+                    properties.update(section.properties)
+        return _apply_defaults(
+            {key: value for key, value in properties.items() if value != "unset"},
+        )
+
+
+def _apply_defaults(properties: dict[str, str]) -> dict[str, str]:
+    """Fill in the indentation properties the specification derives.
+
+    Args:
+        properties: Properties set by matching sections.
+
+    Returns:
+        dict[str, str]: Properties with derived indent_size and tab_width.
+    """
+    if properties.get("indent_style") == "tab" and "indent_size" not in properties:
+        properties["indent_size"] = "tab"
+    indent_size = properties.get("indent_size")
+    if indent_size and indent_size != "tab" and "tab_width" not in properties:
+        properties["tab_width"] = indent_size
+    if indent_size == "tab" and "tab_width" in properties:
+        properties["indent_size"] = properties["tab_width"]
+    return properties
@@ -0,0 +1,19 @@
+"""Frontmatter tool implementation helpers.
+
+This package provides the frontmatter extraction and JSON Schema checks used
+by the native frontmatter plugin.
+"""
+
+from lintro.tools.implementations.frontmatter.frontmatter_checks import (
+    Frontmatter,
+    check_frontmatter,
+    extract_frontmatter,
+    load_schema,
+)
+
+__all__ = [
+    "Frontmatter",
+    "check_frontmatter",
+    "extract_frontmatter",
+    "load_schema",
+]
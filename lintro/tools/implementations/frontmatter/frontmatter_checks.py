@@ -0,0 +1,261 @@
+"""YAML frontmatter extraction and schema validation.
+
+Static site generators (Hugo, Jekyll, Docusaurus, ...) read page metadata
+from a YAML block delimited by ``---`` at the top of each Markdown file.
+These helpers parse that block, validate it against a JSON Schema and map
+every violation back to the line of the offending key.
+
+Codes:
+    FM001: Frontmatter does not match the schema.
+    FM002: Frontmatter is not valid YAML or is never closed.
+    FM003: Document has no frontmatter but one is required.
+"""
+
+from __future__ import annotations
+
+import datetime
+import json
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from lintro.config.config_schema import validate_against_schema
+from lintro.parsers.frontmatter.frontmatter_issue import FrontmatterIssue
+
+try:
+    import yaml
+except ImportError:
+    yaml = None  # type: ignore[assignment]
+
+_DELIMITER = "---"
+_CLOSING_DELIMITERS = ("---", "...")
+_ROOT = "<root>"
+
+
+@dataclass(frozen=True)
+class Frontmatter:
+    """A YAML frontmatter block.
+
+    Attributes:
+        text: YAML source between the delimiters.
+        first_line: 1-based document line of the first YAML line.
+        closed: Whether a closing delimiter was found.
+    """
+
+    text: str
+    first_line: int
+    closed: bool = True
+
+
+def extract_frontmatter(text: str) -> Frontmatter | None:
+    """Return the frontmatter block at the top of a document.
+
+    Args:
+        text: Document contents.
+
+    Returns:
+        Frontmatter | None: The block, or None if the document does not start
+        with ``---``.
+    """
+    lines = text.lstrip("\ufeff").splitlines()
+    if not lines or lines[0].rstrip() != _DELIMITER:
+        return None
+    for index in range(1, len(lines)):
+        if lines[index].rstrip() in _CLOSING_DELIMITERS:
+            return Frontmatter("\n".join(lines[1:index]), first_line=2)
+    return Frontmatter("\n".join(lines[1:]), first_line=2, closed=False)
+
+
+def load_schema(path: str | Path) -> dict[str, Any]:
+    """Load a JSON Schema file.
+
+    Args:
+        path: Path to the schema.
+
+    Returns:
+        dict[str, Any]: Parsed schema.
+
+    Raises:
+        ValueError: If the file cannot be read or is not a JSON object.
+    """
+    try:
+        with open(path, encoding="utf-8") as f:
+            schema = json.load(f)
+    except OSError as e:
+        raise ValueError(f"cannot read frontmatter schema {path}: {e}") from e
+    except json.JSONDecodeError as e:
+        raise ValueError(f"frontmatter schema {path} is not valid JSON: {e}") from e
+    if not isinstance(schema, dict):
+        raise ValueError(f"frontmatter schema {path} must be a JSON object")
+    return schema
+
+
+def _to_json(value: Any) -> Any:
+    """Convert YAML-specific values to their JSON equivalents.
+
+    Dates become ISO strings, matching how site generators expose them.
+
+    Args:
+        value: Parsed YAML value.
+
+    Returns:
+        Any: JSON-compatible value.
+    """
+    if isinstance(value, dict):
+        return {str(k): _to_json(v) for k, v in value.items()}
+    if isinstance(value, list):
+        return [_to_json(v) for v in value]
+    if isinstance(value, datetime.date):
+        return value.isoformat()
+    return value
+
+
+def _key_positions(node: Any, first_line: int) -> dict[str, tuple[int, int]]:
+    """Map dotted key paths to their document line and column.
+
+    Args:
+        node: Root YAML node from ``yaml.compose``.
+        first_line: Document line of the first YAML line.
+
+    Returns:
+        dict[str, tuple[int, int]]: 1-based (line, column) per key path.
+    """
+    positions: dict[str, tuple[int, int]] = {}
+
+    def mark(path: str, start: Any) -> None:
+        """Record where a key or item starts.
+
+        Args:
+            path: Dotted key path.
+            start: YAML start mark.
+        """
+        positions[path] = (start.line + first_line, start.column + 1)
+
+    def walk(current: Any, path: str) -> None:
+        """Visit a node and its children.
+
+        Args:
+            current: YAML node.
+            path: Dotted key path of the node.
+        """
+        if isinstance(current, yaml.MappingNode):
+            for key_node, value_node in current.value:
+                child = f"{path}.{key_node.value}" if path else str(key_node.value)
+                mark(child, key_node.start_mark)
+                walk(value_node, child)
+        elif isinstance(current, yaml.SequenceNode):
+            for index, item in enumerate(current.value):
+                child = f"{path or _ROOT}[{index}]"
+                mark(child, item.start_mark)
+                walk(item, child)
+
+    if node is not None:
+        walk(node, "")
+    return positions
+
+
+def _position_for(
+    path: str,
+    positions: dict[str, tuple[int, int]],
+) -> tuple[int, int]:
+    """Find the closest known position for a key path.
+
+    Args:
+        path: Dotted key path reported by the validator.
+        positions: Known key positions.
+
+    Returns:
+        tuple[int, int]: Line and column; the opening delimiter for
+        top-level problems.
+    """
+    current = path
+    while current and current != _ROOT:
+        if current in positions:
+            return positions[current]
+        cut = max(current.rfind("."), current.rfind("["))
+        current = current[:cut] if cut > 0 else ""
+    return 1, 1
+
+
+def check_frontmatter(
+    text: str,
+    file_path: str,
+    schema: dict[str, Any],
+    require_frontmatter: bool = False,
+) -> list[FrontmatterIssue]:
+    """Validate a document's frontmatter against a JSON Schema.
+
+    Args:
+        text: Document contents.
+        file_path: Path used when reporting issues.
+        schema: JSON Schema the frontmatter must match.
+        require_frontmatter: Report documents without frontmatter.
+
+    Returns:
+        list[FrontmatterIssue]: Issues found, in document order.
+
+    Raises:
+        RuntimeError: If PyYAML is not installed.
+    """
+    if yaml is None:
+        raise RuntimeError("PyYAML is required to validate frontmatter")
+
+    block = extract_frontmatter(text)
+    if block is None:
+        if not require_frontmatter:
+            return []
+        return [
+            FrontmatterIssue(
+                file=file_path,
+                line=1,
+                column=1,
+                code="FM003",
+                message="Document has no frontmatter",
+            ),
+        ]
+    if not block.closed:
+        return [
+            FrontmatterIssue(
+                file=file_path,
+                line=1,
+                column=1,
+                code="FM002",
+                message=f"Frontmatter is missing its closing '{_DELIMITER}'",
+            ),
+        ]
+
+    try:
+        node = yaml.compose(block.text, Loader=yaml.SafeLoader)
+        data = yaml.safe_load(block.text)
+    except yaml.YAMLError as e:
+        problem_mark = getattr(e, "problem_mark", None)
+        line = problem_mark.line + block.first_line if problem_mark else 1
+        problem = getattr(e, "problem", None) or str(e)
+        return [
+            FrontmatterIssue(
+                file=file_path,
+                line=line,
+                column=problem_mark.column + 1 if problem_mark else 1,
+                code="FM002",
+                message=f"Invalid YAML in frontmatter: {problem}",
+            ),
+        ]
+
+    positions = _key_positions(node, block.first_line)
+    value = _to_json(data) if data is not None else {}
+    issues: list[FrontmatterIssue] = []
+    for problem in validate_against_schema(value, schema):
+        line, column = _position_for(problem.path, positions)
+        key = "" if problem.path == _ROOT else problem.path
+        issues.append(
+            FrontmatterIssue(
+                file=file_path,
+                line=line,
+                column=column,
+                code="FM001",
+                message=f"{key}: {problem.message}" if key else problem.message,
+                key=key,
+            ),
+        )
+    issues.sort(key=lambda issue: (issue.line, issue.column))
+    return issues
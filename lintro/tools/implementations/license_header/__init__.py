@@ -0,0 +1,27 @@
+"""License header tool implementation helpers.
+
+This package provides the header templates, comment styles and the checks
+and fixes used by the native license_header plugin.
+"""
+
+from lintro.tools.implementations.license_header.license_header_checks import (
+    check_text,
+    fix_text,
+)
+from lintro.tools.implementations.license_header.license_header_template import (
+    DEFAULT_COMMENT_STYLES,
+    CommentStyle,
+    LicenseHeader,
+    load_license_header,
+    parse_comment_styles,
+)
+
+__all__ = [
+    "DEFAULT_COMMENT_STYLES",
+    "CommentStyle",
+    "LicenseHeader",
+    "check_text",
+    "fix_text",
+    "load_license_header",
+    "parse_comment_styles",
+]
@@ -0,0 +1,155 @@
+"""License header checks and fixes.
+
+Checks that a file starts with the header rendered from the license
+template:
+
+- LH001: File has no license header
+- LH002: License header does not match the template
+
+Lines that must stay first, such as a shebang, a Python encoding
+declaration or an XML declaration, may come before the header. Files that
+are empty or only whitespace need no header. LH001 is fixed by
+``fix_text``; an LH002 header was written by hand and is left for a person
+to reconcile.
+"""
+
+from __future__ import annotations
+
+import re
+
+from lintro.parsers.license_header.license_header_issue import LicenseHeaderIssue
+from lintro.tools.implementations.license_header.license_header_template import (
+    CommentStyle,
+    LicenseHeader,
+)
+
+_LINE_RE: re.Pattern[str] = re.compile(r"[^\n]*\n|[^\n]+$")
+_PREAMBLE_RE: re.Pattern[str] = re.compile(
+    r"#!|#.*coding[:=]|<\?xml\b|<!DOCTYPE\b",
+    re.IGNORECASE,
+)
+_OTHER_HEADER_RE: re.Pattern[str] = re.compile(
+    r"copyright|SPDX-License-Identifier",
+    re.IGNORECASE,
+)
+# Lines after the preamble searched for a header that differs from the template
+_HEADER_SCAN_LINES: int = 20
+
+
+def _preamble_length(lines: list[str]) -> int:
+    """Count the leading lines that must stay above the header.
+
+    Args:
+        lines: File lines.
+
+    Returns:
+        int: Number of preamble lines.
+    """
+    count = 0
+    while count < len(lines) and _PREAMBLE_RE.match(lines[count]):
+        count += 1
+    return count
+
+
+def _has_header(
+    lines: list[str],
+    header: LicenseHeader,
+    style: CommentStyle,
+) -> bool:
+    """Tell whether the lines after the preamble start with the header.
+
+    Args:
+        lines: File lines after the preamble, without line endings.
+        header: License header to look for.
+        style: Comment style of the file.
+
+    Returns:
+        bool: True if every header line matches.
+    """
+    while lines and not lines[0].strip():
+        lines = lines[1:]
+    patterns = header.patterns(style)
+    if len(lines) < len(patterns):
+        return False
+    return all(
+        pattern.fullmatch(line.rstrip())
+        for pattern, line in zip(patterns, lines, strict=False)
+    )
+
+
+def check_text(
+    text: str,
+    file_path: str,
+    header: LicenseHeader,
+    style: CommentStyle,
+) -> list[LicenseHeaderIssue]:
+    """Check that a file starts with the license header.
+
+    Args:
+        text: File contents.
+        file_path: Path reported in issues.
+        header: License header the file should start with.
+        style: Comment style of the file.
+
+    Returns:
+        list[LicenseHeaderIssue]: At most one issue.
+    """
+    if not text.strip():
+        return []
+    lines = [line.rstrip("\r\n") for line in _LINE_RE.findall(text)]
+    skip = _preamble_length(lines)
+    if _has_header(lines[skip:], header, style):
+        return []
+    for number, line in enumerate(
+        lines[skip : skip + _HEADER_SCAN_LINES],
+        start=skip + 1,
+    ):
+        if _OTHER_HEADER_RE.search(line):
+            return [
+                LicenseHeaderIssue(
+                    file=file_path,
+                    line=number,
+                    column=1,
+                    message="License header does not match the template",
+                    code="LH002",
+                ),
+            ]
+    return [
+        LicenseHeaderIssue(
+            file=file_path,
+            line=skip + 1,
+            column=1,
+            message="File has no license header",
+            code="LH001",
+            fixable=True,
+        ),
+    ]
+
+
+def fix_text(text: str, header: LicenseHeader, style: CommentStyle) -> str:
+    """Insert the license header into a file that has none.
+
+    The header goes after the preamble, using the file's line endings, and
+    is separated from the code by a blank line.
+
+    Args:
+        text: File contents.
+        header: License header to insert.
+        style: Comment style of the file.
+
+    Returns:
+        str: The fixed contents; unchanged unless the file has LH001.
+    """
+    issues = check_text(text, "", header, style)
+    if not issues or issues[0].code != "LH001":
+        return text
+    newline = "\r\n" if "\r\n" in text else "\n"
+    lines = _LINE_RE.findall(text)
+    skip = _preamble_length([line.rstrip("\r\n") for line in lines])
+    preamble, rest = lines[:skip], lines[skip:]
+    if preamble and not preamble[-1].endswith("\n"):
+        preamble[-1] += newline
+    inserted = [line + newline for line in header.render(style)]
+    if rest and rest[0].strip():
+        inserted.append(newline)
+    return "".join([*preamble, *inserted, *rest])
@@ -0,0 +1,269 @@
+"""License header templates and comment styles.
+
+A header template is plain text with ``{year}`` and ``{owner}``
+placeholders, for example::
+
+    Copyright (c) {year} {owner}
+    SPDX-License-Identifier: MIT
+
+Each file type writes it in its own comment syntax, looked up by file name
+(``Dockerfile``) and then by suffix (``.py``). ``comment_styles`` adds or
+replaces entries: a string is a line comment prefix, and a list of three
+strings is a block comment's opening line, line prefix and closing line.
+"""
+
+from __future__ import annotations
+
+import datetime
+import re
+from dataclasses import dataclass
+from pathlib import Path, PurePath
+
+YEAR_PLACEHOLDER: str = "{year}"
+OWNER_PLACEHOLDER: str = "{owner}"
+# A year, year range or list of years, such as 2024, 2019-2024 or 2019, 2021
+_YEAR_PATTERN: str = r"\d{4}(?:\s*[-,]\s*\d{4})*"
+
+
+@dataclass(frozen=True)
+class CommentStyle:
+    """How a file type writes a header as a comment.
+
+    Attributes:
+        prefix: Text before every header line (e.g. ``#``).
+        start: Line that opens a block comment, if any.
+        end: Line that closes a block comment, if any.
+    """
+
+    prefix: str
+    start: str = ""
+    end: str = ""
+
+    def wrap(self, lines: list[str]) -> list[str]:
+        """Turn header lines into comment lines.
+
+        Args:
+            lines: Header lines without comment syntax.
+
+        Returns:
+            list[str]: The lines as they appear in a file.
+        """
+        body = [
+            f"{self.prefix} {line}".rstrip() if self.prefix else line
+            for line in lines
+        ]
+        opening = [self.start] if self.start else []
+        closing = [self.end] if self.end else []
+        return [*opening, *body, *closing]
+
+
+_HASH = CommentStyle("#")
+_SLASHES = CommentStyle("//")
+_DASHES = CommentStyle("--")
+_C_BLOCK = CommentStyle(" *", start="/*", end=" */")
+_HTML_BLOCK = CommentStyle("", start="<!--", end="-->")
+
+DEFAULT_COMMENT_STYLES: dict[str, CommentStyle] = {
+    **dict.fromkeys(
+        [
+            ".bash",
+            ".cmake",
+            ".pl",
+            ".py",
+            ".pyi",
+            ".r",
+            ".rb",
+            ".sh",
+            ".tf",
+            ".toml",
+            ".yaml",
+            ".yml",
+            ".zsh",
+            "CMakeLists.txt",
+            "Dockerfile",
+            "Makefile",
+        ],
+        _HASH,
+    ),
+    **dict.fromkeys(
+        [
+            ".c",
+            ".cc",
+            ".cjs",
+            ".cpp",
+            ".cs",
+            ".cts",
+            ".cxx",
+            ".dart",
+            ".go",
+            ".h",
+            ".hpp",
+            ".java",
+            ".js",
+            ".jsx",
+            ".kt",
+            ".kts",
+            ".mjs",
+            ".mts",
+            ".proto",
+            ".rs",
+            ".scala",
+            ".swift",
+            ".ts",
+            ".tsx",
+        ],
+        _SLASHES,
+    ),
+    **dict.fromkeys([".hs", ".lua", ".sql"], _DASHES),
+    **dict.fromkeys([".css", ".less", ".scss"], _C_BLOCK),
+    **dict.fromkeys([".htm", ".html", ".svelte", ".vue", ".xml"], _HTML_BLOCK),
+}
+
+
+def parse_comment_styles(value: dict[str, object]) -> dict[str, CommentStyle]:
+    """Parse the ``comment_styles`` option.
+
+    Args:
+        value: File suffixes or names mapped to a line comment prefix or to
+            ``[start, prefix, end]`` for block comments.
+
+    Returns:
+        dict[str, CommentStyle]: Parsed comment styles.
+
+    Raises:
+        ValueError: If a style is neither a string nor three strings.
+    """
+    styles: dict[str, CommentStyle] = {}
+    for key, style in value.items():
+        if isinstance(style, str) and style.strip():
+            styles[key] = CommentStyle(style)
+        elif (
+            isinstance(style, list)
+            and len(style) == 3
+            and all(isinstance(part, str) for part in style)
+        ):
+            styles[key] = CommentStyle(style[1], start=style[0], end=style[2])
+        else:
+            raise ValueError(
+                f"comment_styles[{key!r}] must be a comment prefix or "
+                "[start, prefix, end]",
+            )
+    return styles
+
+
+@dataclass(frozen=True)
+class LicenseHeader:
+    """A license header template with its placeholder values.
+
+    Attributes:
+        lines: Template lines, still containing the placeholders.
+        owner: Value of ``{owner}``.
+        year: Value of ``{year}`` in inserted headers.
+        comment_styles: Comment style of each file suffix or name.
+    """
+
+    lines: tuple[str, ...]
+    owner: str
+    year: str
+    comment_styles: dict[str, CommentStyle]
+
+    def style_for(self, file_path: str) -> CommentStyle | None:
+        """Find the comment style of a file.
+
+        Args:
+            file_path: File to look up.
+
+        Returns:
+            CommentStyle | None: The style, or None for file types without
+            a known comment syntax.
+        """
+        path = PurePath(file_path)
+        return self.comment_styles.get(path.name) or self.comment_styles.get(
+            path.suffix.lower(),
+        )
+
+    def render(self, style: CommentStyle) -> list[str]:
+        """Render the header for insertion into a file.
+
+        Args:
+            style: Comment style of the file.
+
+        Returns:
+            list[str]: Comment lines with the placeholders filled in.
+        """
+        return [
+            line.replace(YEAR_PLACEHOLDER, self.year).replace(
+                OWNER_PLACEHOLDER,
+                self.owner,
+            )
+            for line in style.wrap(list(self.lines))
+        ]
+
+    def patterns(self, style: CommentStyle) -> list[re.Pattern[str]]:
+        """Build patterns that match the header in a file.
+
+        ``{year}`` matches any year, year range or list of years, so existing
+        headers need no update when the year changes.
+
+        Args:
+            style: Comment style of the file.
+
+        Returns:
+            list[re.Pattern[str]]: One pattern per comment line.
+        """
+        return [
+            re.compile(
+                re.escape(line)
+                .replace(re.escape(YEAR_PLACEHOLDER), _YEAR_PATTERN)
+                .replace(re.escape(OWNER_PLACEHOLDER), re.escape(self.owner)),
+            )
+            for line in style.wrap(list(self.lines))
+        ]
+
+
+def load_license_header(
+    template: str | None,
+    template_file: str | None,
+    owner: str | None,
+    year: str | None,
+    comment_styles: dict[str, object] | None,
+) -> LicenseHeader | None:
+    """Build the license header from the tool options.
+
+    Args:
+        template: Header text.
+        template_file: File holding the header text, relative to the
+            directory Lintro runs in.
+        owner: Copyright holder for ``{owner}``.
+        year: Year for ``{year}`` in inserted headers; defaults to the
+            current year.
+        comment_styles: Extra or replacement comment styles.
+
+    Returns:
+        LicenseHeader | None: The header, or None when no template is set.
+
+    Raises:
+        ValueError: If the options conflict, the template file cannot be
+            read, or ``{owner}`` is used without ``owner``.
+    """
+    if template and template_file:
+        raise ValueError("Set either template or template_file, not both")
+    if template_file:
+        try:
+            template = Path(template_file).read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError) as e:
+            raise ValueError(f"Cannot read license template {template_file}: {e}")
+    if not template or not template.strip():
+        return None
+    if OWNER_PLACEHOLDER in template and not owner:
+        raise ValueError("The license template uses {owner}; set owner as well")
+    lines = [line.rstrip() for line in template.strip("\n").splitlines()]
+    return LicenseHeader(
+        lines=tuple(lines),
+        owner=owner or "",
+        year=year or str(datetime.date.today().year),
+        comment_styles={
+            **DEFAULT_COMMENT_STYLES,
+            **parse_comment_styles(comment_styles or {}),
+        },
+    )
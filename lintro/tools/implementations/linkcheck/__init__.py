@@ -0,0 +1,39 @@
+"""Linkcheck tool implementation helpers.
+
+This package provides the link extraction and dead-link checks used by the
+native linkcheck plugin.
+"""
+
+from lintro.tools.implementations.linkcheck.link_checker import (
+    DEFAULT_CACHE_TTL,
+    DEFAULT_MAX_CONCURRENCY,
+    DEFAULT_REQUEST_TIMEOUT,
+    ExternalLink,
+    UrlCache,
+    check_document_links,
+    check_external_links,
+    is_allowed_domain,
+)
+from lintro.tools.implementations.linkcheck.link_extraction import (
+    DocumentLink,
+    extract_anchors,
+    extract_html_links,
+    extract_markdown_links,
+    github_slug,
+)
+
+__all__ = [
+    "DEFAULT_CACHE_TTL",
+    "DEFAULT_MAX_CONCURRENCY",
+    "DEFAULT_REQUEST_TIMEOUT",
+    "DocumentLink",
+    "ExternalLink",
+    "UrlCache",
+    "check_document_links",
+    "check_external_links",
+    "extract_anchors",
+    "extract_html_links",
+    "extract_markdown_links",
+    "github_slug",
+    "is_allowed_domain",
+]
@@ -0,0 +1,410 @@
+"""Dead-link checks for Markdown and HTML documents.
+
+Checks links without invoking any external tool:
+
+- LC001: Relative link points to a file that does not exist
+- LC002: Anchor does not exist in the target document
+- LC003: External URL returned an HTTP error status
+- LC004: External URL could not be reached (timeout, DNS, TLS, ...)
+
+Relative links are always checked. External URLs are skipped in offline
+mode and for allowlisted domains. Successful external checks are cached
+for ``cache_ttl`` seconds so repeated runs stay fast.
+"""
+
+from __future__ import annotations
+
+import json
+import tempfile
+import time
+from collections.abc import Callable, Iterable
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass, field
+from pathlib import Path
+from urllib.parse import unquote, urlsplit
+
+from loguru import logger
+
+from lintro.parsers.linkcheck.linkcheck_issue import LinkcheckIssue
+from lintro.tools.implementations.linkcheck.link_extraction import (
+    DocumentLink,
+    extract_anchors,
+    extract_html_links,
+    extract_markdown_links,
+)
+from lintro.utils.file_cache import CACHE_DIR
+
+DEFAULT_REQUEST_TIMEOUT: int = 10
+DEFAULT_CACHE_TTL: int = 86400
+DEFAULT_MAX_CONCURRENCY: int = 8
+
+URL_CACHE_FILE: Path = CACHE_DIR / "linkcheck-urls.json"
+
+MARKDOWN_SUFFIXES: frozenset[str] = frozenset({".md", ".markdown", ".mdx"})
+
+# Schemes that are never checked
+_SKIPPED_SCHEMES: frozenset[str] = frozenset(
+    {"mailto", "tel", "javascript", "data", "ftp", "file"},
+)
+# Status codes that mean "exists, but refused this client"
+_ACCEPTED_ERROR_STATUSES: frozenset[int] = frozenset({429})
+# Status codes after which HEAD is retried with GET
+_RETRY_WITH_GET_STATUSES: frozenset[int] = frozenset({403, 404, 405, 501})
+
+# (status code, error message) for one URL
+UrlStatus = tuple[int | None, str | None]
+UrlFetcher = Callable[[str, int], UrlStatus]
+
+
+@dataclass
+class ExternalLink:
+    """An external URL occurrence awaiting a network check.
+
+    Attributes:
+        url: Absolute http(s) URL.
+        file: Display path of the document.
+        line: 1-based line number.
+        column: 1-based column.
+    """
+
+    url: str
+    file: str
+    line: int
+    column: int
+
+
+@dataclass
+class UrlCache:
+    """Timestamps of URLs that were recently reachable.
+
+    Attributes:
+        path: Cache file location.
+        ttl: Seconds a successful check stays valid.
+        entries: Mapping of URL to the time it was last reachable.
+    """
+
+    path: Path = URL_CACHE_FILE
+    ttl: int = DEFAULT_CACHE_TTL
+    entries: dict[str, float] = field(default_factory=dict)
+
+    @classmethod
+    def load(
+        cls,
+        path: Path = URL_CACHE_FILE,
+        ttl: int = DEFAULT_CACHE_TTL,
+    ) -> UrlCache:
+        """Load the cache from disk.
+
+        Args:
+            path: Cache file location.
+            ttl: Seconds a successful check stays valid.
+
+        Returns:
+            UrlCache: Loaded cache, or an empty one if unreadable.
+        """
+        try:
+            with path.open(encoding="utf-8") as f:
+                data = json.load(f)
+            entries = {str(k): float(v) for k, v in data.items()}
+        except (OSError, ValueError, TypeError, AttributeError):
+            entries = {}
+        return cls(path=path, ttl=ttl, entries=entries)
+
+    def is_fresh(self, url: str) -> bool:
+        """Check whether a URL was reachable within the TTL.
+
+        Args:
+            url: URL to look up.
+
+        Returns:
+            bool: True if the cached success is still valid.
+        """
+        checked_at = self.entries.get(url)
+        return checked_at is not None and time.time() - checked_at < self.ttl
+
+    def record(self, url: str) -> None:
+        """Remember that a URL is reachable.
+
+        Args:
+            url: URL that was checked successfully.
+        """
+        self.entries[url] = time.time()
+
+    def save(self) -> None:
+        """Persist the cache, dropping expired entries."""
+        now = time.time()
+        data = {
+            url: checked_at
+            for url, checked_at in self.entries.items()
+            if now - checked_at < self.ttl
+        }
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            with tempfile.NamedTemporaryFile(
+                mode="w",
+                encoding="utf-8",
+                dir=self.path.parent,
+                suffix=".tmp",
+                delete=False,
+            ) as tmp_file:
+                json.dump(data, tmp_file, indent=2)
+                tmp_path = Path(tmp_file.name)
+            tmp_path.replace(self.path)
+        except OSError as e:
+            logger.warning(f"Could not save link check cache: {e}")
+
+
+def is_allowed_domain(url: str, allow_domains: Iterable[str]) -> bool:
+    """Check whether a URL's host is allowlisted.
+
+    A domain also matches its subdomains.
+
+    Args:
+        url: Absolute URL.
+        allow_domains: Allowlisted domains.
+
+    Returns:
+        bool: True if the URL should not be checked.
+    """
+    host = (urlsplit(url).hostname or "").lower()
+    for domain in allow_domains:
+        domain = domain.lower().lstrip(".")
+        if host == domain or host.endswith(f".{domain}"):
+            return True
+    return False
+
+
+def fetch_url_status(url: str, timeout: int) -> UrlStatus:
+    """Request a URL and return its final HTTP status.
+
+    Uses HEAD first and falls back to GET for servers that reject HEAD.
+
+    Args:
+        url: Absolute http(s) URL.
+        timeout: Request timeout in seconds.
+
+    Returns:
+        UrlStatus: Status code, or None with an error message when the URL
+            could not be reached.
+    """
+    import httpx
+
+    headers = {"User-Agent": "lintro-linkcheck"}
+    try:
+        with httpx.Client(
+            follow_redirects=True,
+            timeout=timeout,
+            headers=headers,
+        ) as client:
+            response = client.head(url)
+            if response.status_code in _RETRY_WITH_GET_STATUSES:
+                response = client.get(url)
+            return response.status_code, None
+    except httpx.HTTPError as e:
+        return None, str(e) or type(e).__name__
+
+
+def _local_issue(
+    code: str,
+    message: str,
+    link: DocumentLink,
+    display_path: str,
+    level: str = "error",
+) -> LinkcheckIssue:
+    """Build an issue for a link in a document.
+
+    Args:
+        code: Rule code.
+        message: Issue description.
+        link: Offending link.
+        display_path: Path used when reporting the issue.
+        level: Severity level.
+
+    Returns:
+        LinkcheckIssue: The issue.
+    """
+    return LinkcheckIssue(
+        file=display_path,
+        line=link.line,
+        column=link.column,
+        level=level,
+        code=code,
+        message=message,
+        url=link.url,
+    )
+
+
+def _anchors_for(path: Path, cache: dict[Path, set[str]]) -> set[str]:
+    """Return the anchors a document defines, reading it at most once.
+
+    Args:
+        path: Document path.
+        cache: Anchors already computed, keyed by path.
+
+    Returns:
+        set[str]: Anchors, or an empty set if the file is unreadable.
+    """
+    if path not in cache:
+        try:
+            text = path.read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError):
+            text = ""
+        is_markdown = path.suffix.lower() in MARKDOWN_SUFFIXES
+        cache[path] = extract_anchors(text, is_markdown)
+    return cache[path]
+
+
+def check_document_links(
+    text: str,
+    file_path: Path,
+    display_path: str,
+    root: Path,
+    check_anchors: bool = True,
+    anchor_cache: dict[Path, set[str]] | None = None,
+) -> tuple[list[LinkcheckIssue], list[ExternalLink]]:
+    """Check the relative links of a document and collect external ones.
+
+    Args:
+        text: Document content.
+        file_path: Absolute path of the document.
+        display_path: Path used when reporting issues.
+        root: Repository root used for links starting with ``/``.
+        check_anchors: Whether to verify ``#fragment`` targets (LC002).
+        anchor_cache: Shared cache of anchors per document.
+
+    Returns:
+        tuple[list[LinkcheckIssue], list[ExternalLink]]: Issues for
+            relative links and the external links still to check.
+    """
+    anchors = anchor_cache if anchor_cache is not None else {}
+    is_markdown = file_path.suffix.lower() in MARKDOWN_SUFFIXES
+    links = (
+        extract_markdown_links(text) if is_markdown else extract_html_links(text)
+    )
+
+    issues: list[LinkcheckIssue] = []
+    external: list[ExternalLink] = []
+    for link in links:
+        url = link.url.strip()
+        if not url or "{{" in url or "{%" in url:
+            continue
+        parts = urlsplit(url)
+        scheme = parts.scheme.lower()
+        if scheme in ("http", "https"):
+            external.append(
+                ExternalLink(url, display_path, link.line, link.column),
+            )
+            continue
+        if scheme or parts.netloc or url.startswith("//"):
+            if scheme not in _SKIPPED_SCHEMES:
+                logger.debug(f"[linkcheck] Skipping unsupported link {url}")
+            continue
+
+        # Relative link
+        if parts.path:
+            relative = unquote(parts.path)
+            target = (
+                root / relative.lstrip("/")
+                if relative.startswith("/")
+                else file_path.parent / relative
+            )
+            if not target.exists():
+                issues.append(
+                    _local_issue(
+                        "LC001",
+                        f"Broken link: {relative} does not exist",
+                        link,
+                        display_path,
+                    ),
+                )
+                continue
+        else:
+            target = file_path
+
+        fragment = unquote(parts.fragment)
+        if check_anchors and fragment and target.is_file():
+            suffix = target.suffix.lower()
+            if suffix in MARKDOWN_SUFFIXES or suffix in (".html", ".htm"):
+                if fragment not in _anchors_for(target, anchors):
+                    where = "this file" if target == file_path else parts.path
+                    issues.append(
+                        _local_issue(
+                            "LC002",
+                            f"Anchor #{fragment} not found in {where}",
+                            link,
+                            display_path,
+                            level="warning",
+                        ),
+                    )
+    return issues, external
+
+
+def check_external_links(
+    links: list[ExternalLink],
+    allow_domains: Iterable[str] = (),
+    timeout: int = DEFAULT_REQUEST_TIMEOUT,
+    cache: UrlCache | None = None,
+    fetcher: UrlFetcher = fetch_url_status,
+    max_concurrency: int = DEFAULT_MAX_CONCURRENCY,
+) -> list[LinkcheckIssue]:
+    """Check external URLs, each unique URL at most once.
+
+    Args:
+        links: External link occurrences.
+        allow_domains: Domains that are never checked.
+        timeout: Request timeout in seconds.
+        cache: Cache of recently reachable URLs, or None to disable caching.
+        fetcher: Function returning the status of a URL.
+        max_concurrency: Maximum number of concurrent requests.
+
+    Returns:
+        list[LinkcheckIssue]: Issues for every occurrence of a broken URL.
+    """
+    allow = list(allow_domains)
+    pending = sorted(
+        {
+            link.url
+            for link in links
+            if not is_allowed_domain(link.url, allow)
+            and not (cache is not None and cache.is_fresh(link.url))
+        },
+    )
+    if not pending:
+        return []
+
+    logger.debug(f"[linkcheck] Checking {len(pending)} external URL(s)")
+    with ThreadPoolExecutor(max_workers=max(1, max_concurrency)) as executor:
+        statuses = dict(
+            zip(
+                pending,
+                executor.map(lambda url: fetcher(url, timeout), pending),
+                strict=True,
+            ),
+        )
+
+    issues: list[LinkcheckIssue] = []
+    for url, (status, error) in statuses.items():
+        if error is None and status is not None:
+            if status < 400 or status in _ACCEPTED_ERROR_STATUSES:
+                if cache is not None:
+                    cache.record(url)
+                continue
+        for link in (lk for lk in links if lk.url == url):
+            if error is not None or status is None:
+                code, level = "LC004", "warning"
+                message = f"Unreachable URL: {error or 'no response'}"
+            else:
+                code, level = "LC003", "error"
+                message = f"URL returned HTTP {status}"
+            issues.append(
+                LinkcheckIssue(
+                    file=link.file,
+                    line=link.line,
+                    column=link.column,
+                    level=level,
+                    code=code,
+                    message=message,
+                    url=url,
+                ),
+            )
+    return issues
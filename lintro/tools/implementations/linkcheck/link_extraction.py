@@ -0,0 +1,180 @@
+"""Link and anchor extraction for Markdown and HTML documents.
+
+Links inside fenced code blocks and inline code spans are ignored. Anchors
+follow GitHub's heading slug rules, plus explicit ``id``/``name``
+attributes.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+
+_FENCE_RE: re.Pattern[str] = re.compile(r"^\s{0,3}(`{3,}|~{3,})")
+_CODE_SPAN_RE: re.Pattern[str] = re.compile(r"(`+)(?:(?!\1).)+?\1")
+# [text](url "title") and ![alt](url); one level of parentheses in the URL
+_INLINE_LINK_RE: re.Pattern[str] = re.compile(
+    r"!?\[(?:[^\]\\]|\\.)*\]\(\s*<?((?:[^()\s<>]|\([^()\s]*\))+)>?"
+    r"(?:\s+(?:\"[^\"]*\"|'[^']*'|\([^)]*\)))?\s*\)",
+)
+_REFERENCE_DEF_RE: re.Pattern[str] = re.compile(
+    r"^\s{0,3}\[[^\]]+\]:\s*<?([^\s>]+)>?",
+)
+_AUTOLINK_RE: re.Pattern[str] = re.compile(r"<(https?://[^>\s]+)>")
+_HTML_LINK_RE: re.Pattern[str] = re.compile(
+    r"""\b(?:href|src)\s*=\s*["']([^"']+)["']""",
+    re.IGNORECASE,
+)
+_HTML_ANCHOR_RE: re.Pattern[str] = re.compile(
+    r"""\b(?:id|name)\s*=\s*["']([^"']+)["']""",
+    re.IGNORECASE,
+)
+_ATX_HEADING_RE: re.Pattern[str] = re.compile(r"^\s{0,3}#{1,6}\s+(.*?)(?:\s+#+)?\s*$")
+_SETEXT_UNDERLINE_RE: re.Pattern[str] = re.compile(r"^\s{0,3}(?:=+|-+)\s*$")
+_MD_LINK_TEXT_RE: re.Pattern[str] = re.compile(r"!?\[([^\]]*)\]\([^)]*\)")
+
+
+@dataclass(frozen=True)
+class DocumentLink:
+    """A link found in a document.
+
+    Attributes:
+        url: Link target as written.
+        line: 1-based line number.
+        column: 1-based column of the target.
+    """
+
+    url: str
+    line: int
+    column: int
+
+
+def _mask_code_spans(line: str) -> str:
+    """Blank out inline code spans, keeping column positions.
+
+    Args:
+        line: Line of Markdown.
+
+    Returns:
+        str: Line with code spans replaced by spaces.
+    """
+    return _CODE_SPAN_RE.sub(lambda m: " " * len(m.group(0)), line)
+
+
+def _markdown_lines(text: str, mask_code: bool = True) -> list[tuple[int, str]]:
+    """Return Markdown lines outside fenced code blocks.
+
+    Args:
+        text: Markdown document.
+        mask_code: Whether to blank out inline code spans.
+
+    Returns:
+        list[tuple[int, str]]: 1-based line numbers and lines.
+    """
+    lines: list[tuple[int, str]] = []
+    fence: str | None = None
+    for number, line in enumerate(text.splitlines(), start=1):
+        match = _FENCE_RE.match(line)
+        if match:
+            marker = match.group(1)
+            if fence is None:
+                fence = marker
+                continue
+            if marker[0] == fence[0] and len(marker) >= len(fence):
+                fence = None
+                continue
+        if fence is None:
+            lines.append((number, _mask_code_spans(line) if mask_code else line))
+    return lines
+
+
+def extract_markdown_links(text: str) -> list[DocumentLink]:
+    """Extract links from a Markdown document.
+
+    Finds inline links and images, reference definitions, autolinks and
+    ``href``/``src`` attributes of embedded HTML.
+
+    Args:
+        text: Markdown document.
+
+    Returns:
+        list[DocumentLink]: Links in document order.
+    """
+    links: list[DocumentLink] = []
+    for number, line in _markdown_lines(text):
+        found: list[tuple[int, str]] = []
+        for pattern in (_INLINE_LINK_RE, _AUTOLINK_RE, _HTML_LINK_RE):
+            found.extend((m.start(1), m.group(1)) for m in pattern.finditer(line))
+        reference = _REFERENCE_DEF_RE.match(line)
+        if reference:
+            found.append((reference.start(1), reference.group(1)))
+        links.extend(
+            DocumentLink(url=url, line=number, column=start + 1)
+            for start, url in sorted(found)
+        )
+    return links
+
+
+def extract_html_links(text: str) -> list[DocumentLink]:
+    """Extract ``href`` and ``src`` targets from an HTML document.
+
+    Args:
+        text: HTML document.
+
+    Returns:
+        list[DocumentLink]: Links in document order.
+    """
+    return [
+        DocumentLink(url=m.group(1), line=number, column=m.start(1) + 1)
+        for number, line in enumerate(text.splitlines(), start=1)
+        for m in _HTML_LINK_RE.finditer(line)
+    ]
+
+
+def github_slug(heading: str) -> str:
+    """Convert heading text to a GitHub-style anchor.
+
+    Args:
+        heading: Heading text (Markdown allowed).
+
+    Returns:
+        str: Anchor without the leading ``#``.
+    """
+    text = _MD_LINK_TEXT_RE.sub(r"\1", heading)
+    text = re.sub(r"<[^>]+>", "", text).strip().lower()
+    text = re.sub(r"[^\w\- ]", "", text)
+    return text.replace(" ", "-")
+
+
+def extract_anchors(text: str, is_markdown: bool) -> set[str]:
+    """Collect the anchors a document defines.
+
+    Args:
+        text: Document content.
+        is_markdown: Whether to derive anchors from Markdown headings.
+
+    Returns:
+        set[str]: Anchor names without the leading ``#``.
+    """
+    anchors = {m.group(1) for m in _HTML_ANCHOR_RE.finditer(text)}
+    if not is_markdown:
+        return anchors
+
+    lines = _markdown_lines(text, mask_code=False)
+    seen: dict[str, int] = {}
+    for index, (number, line) in enumerate(lines):
+        heading: str | None = None
+        atx = _ATX_HEADING_RE.match(line)
+        if atx:
+            heading = atx.group(1)
+        elif line.strip() and index + 1 < len(lines):
+            next_number, next_line = lines[index + 1]
+            if next_number == number + 1 and _SETEXT_UNDERLINE_RE.match(next_line):
+                heading = line
+        if heading is None:
+            continue
+        slug = github_slug(heading)
+        count = seen.get(slug, 0)
+        seen[slug] = count + 1
+        anchors.add(slug if count == 0 else f"{slug}-{count}")
+    return anchors
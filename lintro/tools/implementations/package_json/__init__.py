@@ -0,0 +1,19 @@
+"""Package.json tool implementation helpers.
+
+This package provides the manifest checks and key sorting used by the
+native package_json plugin.
+"""
+
+from lintro.tools.implementations.package_json.package_json_checks import (
+    DEFAULT_REQUIRED_ENGINES,
+    DEFAULT_SCRIPT_NAME_PATTERN,
+    check_package_json_text,
+    sort_package_json_text,
+)
+
+__all__ = [
+    "DEFAULT_REQUIRED_ENGINES",
+    "DEFAULT_SCRIPT_NAME_PATTERN",
+    "check_package_json_text",
+    "sort_package_json_text",
+]
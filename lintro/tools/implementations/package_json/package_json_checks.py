@@ -0,0 +1,424 @@
+"""Manifest checks and key sorting for package.json files.
+
+Validates a package.json without invoking any external tool:
+
+- PJ000: File is not valid JSON
+- PJ001: Top-level value is not an object
+- PJ002: ``name`` is missing or not a valid npm package name
+- PJ003: ``version`` is missing or not valid semver
+- PJ004: Field has the wrong type
+- PJ005: Package appears in both dependencies and devDependencies
+- PJ006: Required engine is missing or not pinned to a version range
+- PJ007: Keys are not sorted (fixable, sort-package-json order)
+- PJ008: Script name does not follow the naming convention
+
+``name`` and ``version`` are only required when the package is not
+``"private": true``.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from collections.abc import Iterable
+from typing import Any
+
+from lintro.parsers.package_json.package_json_issue import PackageJsonIssue
+
+# Engines reported as PJ006 when not declared
+DEFAULT_REQUIRED_ENGINES: tuple[str, ...] = ("node",)
+
+# Lowercase words joined by ':', '-' or '.' (e.g. "test:unit", "build-docs")
+DEFAULT_SCRIPT_NAME_PATTERN: str = r"^[a-z0-9]+([:.-][a-z0-9]+)*$"
+
+# Top-level key order, following sort-package-json. Unknown keys keep their
+# relative order after all known keys.
+PACKAGE_JSON_KEY_ORDER: tuple[str, ...] = (
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "categories",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "svelte",
+    "umd:main",
+    "jsdelivr",
+    "unpkg",
+    "module",
+    "source",
+    "jsnext:main",
+    "browser",
+    "react-native",
+    "types",
+    "typesVersions",
+    "typings",
+    "style",
+    "example",
+    "examplestyle",
+    "assets",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "binary",
+    "scripts",
+    "betterScripts",
+    "contributes",
+    "activationEvents",
+    "husky",
+    "simple-git-hooks",
+    "pre-commit",
+    "commitlint",
+    "lint-staged",
+    "nano-staged",
+    "config",
+    "nodemonConfig",
+    "browserify",
+    "babel",
+    "browserslist",
+    "xo",
+    "prettier",
+    "eslintConfig",
+    "eslintIgnore",
+    "npmpackagejsonlint",
+    "release",
+    "remarkConfig",
+    "stylelint",
+    "ava",
+    "jest",
+    "mocha",
+    "nyc",
+    "c8",
+    "tap",
+    "oclif",
+    "resolutions",
+    "overrides",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundledDependencies",
+    "bundleDependencies",
+    "extensionPack",
+    "extensionDependencies",
+    "flat",
+    "packageManager",
+    "engines",
+    "engineStrict",
+    "volta",
+    "languageName",
+    "os",
+    "cpu",
+    "preferGlobal",
+    "publishConfig",
+    "icon",
+    "badges",
+    "galleryBanner",
+    "preview",
+    "markdown",
+    "pnpm",
+)
+
+_KEY_RANK: dict[str, int] = {key: i for i, key in enumerate(PACKAGE_JSON_KEY_ORDER)}
+
+# Objects whose keys are sorted alphabetically
+DEPENDENCY_FIELDS: tuple[str, ...] = (
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+)
+_ALPHABETICAL_FIELDS: frozenset[str] = frozenset(
+    {*DEPENDENCY_FIELDS, "dependenciesMeta", "peerDependenciesMeta", "engines"},
+)
+
+# Fields that must map names to strings
+_STRING_MAP_FIELDS: tuple[str, ...] = (*DEPENDENCY_FIELDS, "scripts", "engines")
+
+_NAME_RE: re.Pattern[str] = re.compile(
+    r"^(?:@[a-z0-9-*~][a-z0-9-*._~]*/)?[a-z0-9-~][a-z0-9-._~]*$",
+)
+_SEMVER_RE: re.Pattern[str] = re.compile(
+    r"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)"
+    r"(?:-[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?"
+    r"(?:\+[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?$",
+)
+_UNPINNED_RANGES: frozenset[str] = frozenset({"", "*", "x", "latest"})
+_MAX_NAME_LENGTH: int = 214
+
+
+def _find_line(lines: list[str], needle: str) -> int:
+    """Find the first 1-based line number containing needle.
+
+    Args:
+        lines: File content split into lines.
+        needle: Text to search for.
+
+    Returns:
+        int: Line number, or 0 if not found.
+    """
+    for index, line in enumerate(lines, start=1):
+        if needle in line:
+            return index
+    return 0
+
+
+def sort_package_json(data: dict[str, Any]) -> dict[str, Any]:
+    """Return a copy of a manifest with keys in canonical order.
+
+    Args:
+        data: Parsed package.json object.
+
+    Returns:
+        dict[str, Any]: Manifest with top-level keys in sort-package-json
+            order and dependency maps sorted alphabetically.
+    """
+    known = sorted((k for k in data if k in _KEY_RANK), key=_KEY_RANK.__getitem__)
+    unknown = [k for k in data if k not in _KEY_RANK]
+    result: dict[str, Any] = {}
+    for key in known + unknown:
+        value = data[key]
+        if key in _ALPHABETICAL_FIELDS and isinstance(value, dict):
+            value = dict(sorted(value.items()))
+        result[key] = value
+    return result
+
+
+def _detect_indent(text: str) -> str | int:
+    """Detect the indentation used by a JSON document.
+
+    Args:
+        text: Raw file content.
+
+    Returns:
+        str | int: Tab character or number of spaces (defaults to 2).
+    """
+    for line in text.splitlines()[1:]:
+        stripped = line.lstrip(" \t")
+        if stripped and len(stripped) != len(line):
+            prefix = line[: len(line) - len(stripped)]
+            return "\t" if prefix.startswith("\t") else len(prefix)
+    return 2
+
+
+def sort_package_json_text(text: str) -> str | None:
+    """Sort the keys of a package.json document.
+
+    Indentation and the trailing newline are preserved.
+
+    Args:
+        text: Raw file content.
+
+    Returns:
+        str | None: Sorted content, or None if the file is not a JSON object.
+    """
+    try:
+        data = json.loads(text)
+    except json.JSONDecodeError:
+        return None
+    if not isinstance(data, dict):
+        return None
+    sorted_text = json.dumps(
+        sort_package_json(data),
+        indent=_detect_indent(text),
+        ensure_ascii=False,
+    )
+    return sorted_text + "\n" if text.endswith("\n") else sorted_text
+
+
+def check_package_json_text(
+    text: str,
+    file_path: str,
+    required_engines: Iterable[str] = DEFAULT_REQUIRED_ENGINES,
+    script_name_pattern: str | None = DEFAULT_SCRIPT_NAME_PATTERN,
+    check_sorting: bool = True,
+) -> list[PackageJsonIssue]:
+    """Check the contents of a package.json file.
+
+    Args:
+        text: Raw file content.
+        file_path: Path used when reporting issues.
+        required_engines: Engines reported as PJ006 when missing or unpinned.
+        script_name_pattern: Regex script names must match, or None to skip
+            the PJ008 check.
+        check_sorting: Whether to report unsorted keys (PJ007).
+
+    Returns:
+        list[PackageJsonIssue]: Issues found in the file.
+    """
+    try:
+        data = json.loads(text)
+    except json.JSONDecodeError as e:
+        return [
+            PackageJsonIssue(
+                file=file_path,
+                line=e.lineno,
+                column=e.colno,
+                level="error",
+                code="PJ000",
+                message=f"Invalid JSON: {e.msg}",
+            ),
+        ]
+    if not isinstance(data, dict):
+        return [
+            PackageJsonIssue(
+                file=file_path,
+                line=1,
+                column=1,
+                level="error",
+                code="PJ001",
+                message="package.json must contain a JSON object",
+            ),
+        ]
+
+    lines = text.splitlines()
+    issues: list[PackageJsonIssue] = []
+
+    def add(
+        code: str,
+        message: str,
+        key: str = "",
+        level: str = "error",
+        fixable: bool = False,
+    ) -> None:
+        """Record an issue located at the first line mentioning key.
+
+        Args:
+            code: Rule code.
+            message: Issue description.
+            key: JSON key used to locate the line.
+            level: Severity level.
+            fixable: Whether ``lintro format`` fixes the issue.
+        """
+        line = _find_line(lines, f'"{key}"') if key else 0
+        issues.append(
+            PackageJsonIssue(
+                file=file_path,
+                line=line or 1,
+                column=1,
+                level=level,
+                code=code,
+                message=message,
+                fixable=fixable,
+            ),
+        )
+
+    is_private = data.get("private") is True
+
+    # Name and version
+    name = data.get("name")
+    if name is None:
+        if not is_private:
+            add("PJ002", "Missing 'name' (required unless \"private\": true)")
+    elif (
+        not isinstance(name, str)
+        or len(name) > _MAX_NAME_LENGTH
+        or not _NAME_RE.match(name)
+    ):
+        add("PJ002", f"Invalid package name {name!r}", key="name")
+
+    version = data.get("version")
+    if version is None:
+        if not is_private:
+            add("PJ003", "Missing 'version' (required unless \"private\": true)")
+    elif not isinstance(version, str) or not _SEMVER_RE.match(version):
+        add("PJ003", f"Version {version!r} is not valid semver", key="version")
+
+    # Field types
+    for field_name in _STRING_MAP_FIELDS:
+        value = data.get(field_name)
+        if value is None:
+            continue
+        if not isinstance(value, dict) or not all(
+            isinstance(v, str) for v in value.values()
+        ):
+            add(
+                "PJ004",
+                f"'{field_name}' must be an object of strings",
+                key=field_name,
+            )
+    for field_name in ("files", "keywords"):
+        value = data.get(field_name)
+        if value is not None and not (
+            isinstance(value, list) and all(isinstance(v, str) for v in value)
+        ):
+            add("PJ004", f"'{field_name}' must be an array of strings", key=field_name)
+    if "private" in data and not isinstance(data["private"], bool):
+        add("PJ004", "'private' must be a boolean", key="private")
+    if "type" in data and data["type"] not in ("module", "commonjs"):
+        add("PJ004", "'type' must be \"module\" or \"commonjs\"", key="type")
+
+    # Duplicate dependencies
+    deps = data.get("dependencies")
+    dev_deps = data.get("devDependencies")
+    if isinstance(deps, dict) and isinstance(dev_deps, dict):
+        for package in sorted(set(deps) & set(dev_deps)):
+            add(
+                "PJ005",
+                f"'{package}' is listed in both dependencies and devDependencies",
+                key=package,
+                level="warning",
+            )
+
+    # Engines pinning
+    engines = data.get("engines")
+    engines_map = engines if isinstance(engines, dict) else {}
+    for engine in required_engines:
+        constraint = engines_map.get(engine)
+        if constraint is None:
+            add(
+                "PJ006",
+                f"Missing 'engines.{engine}' version range",
+                key="engines",
+                level="warning",
+            )
+        elif isinstance(constraint, str) and constraint.strip() in _UNPINNED_RANGES:
+            add(
+                "PJ006",
+                f"'engines.{engine}' ({constraint!r}) does not constrain versions",
+                key=engine,
+                level="warning",
+            )
+
+    # Script names
+    scripts = data.get("scripts")
+    if script_name_pattern and isinstance(scripts, dict):
+        script_re = re.compile(script_name_pattern)
+        for script in scripts:
+            if not script_re.match(script):
+                add(
+                    "PJ008",
+                    f"Script name '{script}' does not match {script_name_pattern}",
+                    key=script,
+                    level="warning",
+                )
+
+    # Key order
+    if check_sorting and json.dumps(data) != json.dumps(sort_package_json(data)):
+        add(
+            "PJ007",
+            "Keys are not sorted (run 'lintro format' to fix)",
+            level="warning",
+            fixable=True,
+        )
+
+    return issues
@@ -0,0 +1,12 @@
+"""Pyproject tool implementation helpers.
+
+This package provides the PEP 621 metadata checks used by the native
+pyproject plugin.
+"""
+
+from lintro.tools.implementations.pyproject.pyproject_checks import (
+    DEFAULT_RECOMMENDED_FIELDS,
+    check_pyproject_text,
+)
+
+__all__ = ["DEFAULT_RECOMMENDED_FIELDS", "check_pyproject_text"]
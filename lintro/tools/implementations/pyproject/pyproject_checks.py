@@ -0,0 +1,325 @@
+"""PEP 621 metadata checks for pyproject.toml files.
+
+Validates the ``[project]`` table of a pyproject.toml without invoking any
+external tool:
+
+- PP000: File is not valid TOML
+- PP001: Required ``name`` field is missing
+- PP002: ``version`` is neither set nor declared dynamic
+- PP003: Recommended metadata field is missing
+- PP004: Classifier is not a valid trove classifier
+- PP005: Dependency is not a valid PEP 508 specifier
+- PP006: ``requires-python`` is not a valid version specifier
+- PP007: Self-referencing extra is not defined in optional-dependencies
+- PP008: Optional-dependencies group name is not normalized (PEP 685)
+- PP009: Tool is configured under ``[tool.*]`` but not declared as a dependency
+- PP010: Field is both set statically and listed in ``dynamic``
+"""
+
+from __future__ import annotations
+
+import re
+import tomllib
+from collections.abc import Iterable
+from typing import Any
+
+from packaging.requirements import InvalidRequirement, Requirement
+from packaging.specifiers import InvalidSpecifier, SpecifierSet
+from packaging.utils import canonicalize_name
+
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+
+# Metadata fields reported as missing (PP003) by default
+DEFAULT_RECOMMENDED_FIELDS: tuple[str, ...] = (
+    "description",
+    "readme",
+    "requires-python",
+    "license",
+    "authors",
+)
+
+# Top-level trove classifier categories, used when the trove-classifiers
+# package is not installed
+TROVE_CATEGORIES: frozenset[str] = frozenset(
+    {
+        "Development Status",
+        "Environment",
+        "Framework",
+        "Intended Audience",
+        "License",
+        "Natural Language",
+        "Operating System",
+        "Private",
+        "Programming Language",
+        "Topic",
+        "Typing",
+    },
+)
+
+# [tool.<section>] -> distribution providing that tool
+TOOL_SECTION_PACKAGES: dict[str, str] = {
+    "bandit": "bandit",
+    "black": "black",
+    "codespell": "codespell",
+    "coverage": "coverage",
+    "isort": "isort",
+    "mypy": "mypy",
+    "pydoclint": "pydoclint",
+    "pylint": "pylint",
+    "pyright": "pyright",
+    "pytest": "pytest",
+    "ruff": "ruff",
+    "sqlfluff": "sqlfluff",
+}
+
+# Packages that also satisfy a tool section (plugins pulling the tool in)
+_TOOL_PROVIDERS: dict[str, frozenset[str]] = {
+    "coverage": frozenset({"coverage", "pytest-cov"}),
+}
+
+_NORMALIZED_EXTRA_RE: re.Pattern[str] = re.compile(r"^[a-z0-9]+(-[a-z0-9]+)*$")
+
+
+def _load_trove_classifiers() -> frozenset[str] | None:
+    """Load the official classifier list when trove-classifiers is installed.
+
+    Returns:
+        frozenset[str] | None: Known classifiers, or None if unavailable.
+    """
+    try:
+        from trove_classifiers import classifiers  # type: ignore[import-not-found]
+    except ImportError:
+        return None
+    return frozenset(classifiers)
+
+
+def _find_line(lines: list[str], needle: str) -> int:
+    """Find the first 1-based line number containing needle.
+
+    Args:
+        lines: File content split into lines.
+        needle: Text to search for.
+
+    Returns:
+        int: Line number, or 0 if not found.
+    """
+    for index, line in enumerate(lines, start=1):
+        if needle in line:
+            return index
+    return 0
+
+
+def _iter_dependency_lists(data: dict[str, Any]) -> Iterable[tuple[str, list[Any]]]:
+    """Yield every dependency list declared in pyproject.toml.
+
+    Args:
+        data: Parsed pyproject.toml content.
+
+    Yields:
+        tuple[str, list[Any]]: Label and raw entries of each dependency list.
+    """
+    project = data.get("project", {})
+    if isinstance(project.get("dependencies"), list):
+        yield "dependencies", project["dependencies"]
+    optional = project.get("optional-dependencies", {})
+    if isinstance(optional, dict):
+        for group, entries in optional.items():
+            if isinstance(entries, list):
+                yield f"optional-dependencies.{group}", entries
+    # PEP 735 dependency groups may also contain {include-group = "..."} tables
+    groups = data.get("dependency-groups", {})
+    if isinstance(groups, dict):
+        for group, entries in groups.items():
+            if isinstance(entries, list):
+                yield f"dependency-groups.{group}", entries
+
+
+def check_pyproject_text(
+    text: str,
+    file_path: str,
+    recommended_fields: Iterable[str] = DEFAULT_RECOMMENDED_FIELDS,
+    check_tool_dependencies: bool = True,
+) -> list[PyprojectIssue]:
+    """Check the contents of a pyproject.toml file.
+
+    Files without a ``[project]`` table (e.g. tool-only configs) only get
+    the TOML syntax check.
+
+    Args:
+        text: Raw file content.
+        file_path: Path used when reporting issues.
+        recommended_fields: Metadata fields reported as PP003 when missing.
+        check_tool_dependencies: Whether to run the PP009 consistency check.
+
+    Returns:
+        list[PyprojectIssue]: Issues found in the file.
+    """
+    try:
+        data = tomllib.loads(text)
+    except tomllib.TOMLDecodeError as e:
+        return [
+            PyprojectIssue(
+                file=file_path,
+                line=getattr(e, "lineno", 0) or 0,
+                column=getattr(e, "colno", 0) or 0,
+                level="error",
+                code="PP000",
+                message=f"Invalid TOML: {e}",
+            ),
+        ]
+
+    project = data.get("project")
+    if not isinstance(project, dict):
+        return []
+
+    lines = text.splitlines()
+    issues: list[PyprojectIssue] = []
+
+    def add(code: str, message: str, needle: str = "", level: str = "error") -> None:
+        """Record an issue located at the first line containing needle.
+
+        Args:
+            code: Rule code.
+            message: Issue description.
+            needle: Text used to locate the line.
+            level: Severity level.
+        """
+        line = _find_line(lines, needle) if needle else 0
+        if not line:
+            line = _find_line(lines, "[project]")
+        issues.append(
+            PyprojectIssue(
+                file=file_path,
+                line=line,
+                column=1 if line else 0,
+                level=level,
+                code=code,
+                message=message,
+            ),
+        )
+
+    dynamic = project.get("dynamic", [])
+    dynamic_fields = set(dynamic) if isinstance(dynamic, list) else set()
+
+    # Required and recommended metadata
+    if "name" not in project:
+        add("PP001", "Missing required field 'project.name'")
+    if "version" not in project and "version" not in dynamic_fields:
+        add("PP002", "'project.version' must be set or listed in 'project.dynamic'")
+    for field_name in recommended_fields:
+        if field_name not in project and field_name not in dynamic_fields:
+            add(
+                "PP003",
+                f"Missing recommended field 'project.{field_name}'",
+                level="warning",
+            )
+    for field_name in sorted(dynamic_fields):
+        if field_name in project:
+            add(
+                "PP010",
+                f"'{field_name}' is set statically and also listed in "
+                "'project.dynamic'",
+                needle="dynamic",
+            )
+
+    # Classifiers
+    classifiers = project.get("classifiers", [])
+    known = _load_trove_classifiers()
+    seen_classifiers: set[str] = set()
+    for classifier in classifiers if isinstance(classifiers, list) else []:
+        text_value = str(classifier)
+        parts = [p.strip() for p in text_value.split("::")]
+        if known is not None:
+            valid = text_value in known or parts[0] == "Private"
+        else:
+            valid = (
+                len(parts) >= 2
+                and parts[0] in TROVE_CATEGORIES
+                and all(parts)
+                and " :: ".join(parts) == text_value
+            )
+        if not valid:
+            add("PP004", f"Invalid classifier '{text_value}'", needle=text_value)
+        elif text_value in seen_classifiers:
+            add(
+                "PP004",
+                f"Duplicate classifier '{text_value}'",
+                needle=text_value,
+                level="warning",
+            )
+        seen_classifiers.add(text_value)
+
+    # requires-python
+    requires_python = project.get("requires-python")
+    if isinstance(requires_python, str):
+        try:
+            SpecifierSet(requires_python)
+        except InvalidSpecifier:
+            add(
+                "PP006",
+                f"Invalid 'requires-python' specifier '{requires_python}'",
+                needle="requires-python",
+            )
+
+    # Dependency specifiers and self-referencing extras
+    project_name = project.get("name")
+    canonical_project = (
+        canonicalize_name(project_name) if isinstance(project_name, str) else None
+    )
+    optional = project.get("optional-dependencies", {})
+    extras = set(optional) if isinstance(optional, dict) else set()
+    declared_packages: set[str] = set()
+
+    for label, entries in _iter_dependency_lists(data):
+        for entry in entries:
+            if not isinstance(entry, str):
+                continue
+            try:
+                requirement = Requirement(entry)
+            except InvalidRequirement as e:
+                add(
+                    "PP005",
+                    f"Invalid dependency '{entry}' in {label}: {e}",
+                    needle=entry,
+                )
+                continue
+            package = canonicalize_name(requirement.name)
+            declared_packages.add(package)
+            if package == canonical_project:
+                for extra in sorted(requirement.extras - extras):
+                    add(
+                        "PP007",
+                        f"'{entry}' in {label} references undefined extra '{extra}'",
+                        needle=entry,
+                    )
+
+    for group in sorted(extras):
+        if not _NORMALIZED_EXTRA_RE.match(group):
+            add(
+                "PP008",
+                f"Optional-dependencies group '{group}' is not normalized "
+                f"(expected '{canonicalize_name(group)}')",
+                needle=group,
+                level="warning",
+            )
+
+    # Tool configs vs declared dependencies. Only meaningful when the project
+    # declares its development dependencies in pyproject.toml at all.
+    has_dev_groups = bool(extras) or bool(data.get("dependency-groups"))
+    tool_table = data.get("tool", {})
+    if check_tool_dependencies and has_dev_groups and isinstance(tool_table, dict):
+        for section in sorted(tool_table):
+            package = TOOL_SECTION_PACKAGES.get(section)
+            if package is None:
+                continue
+            providers = _TOOL_PROVIDERS.get(section, frozenset({package}))
+            if declared_packages.isdisjoint(providers):
+                add(
+                    "PP009",
+                    f"[tool.{section}] is configured but '{package}' is not "
+                    "declared in any dependency group",
+                    needle=f"[tool.{section}",
+                    level="warning",
+                )
+
+    return issues
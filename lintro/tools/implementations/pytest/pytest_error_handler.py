@@ -9,6 +9,7 @@ from dataclasses import dataclass, field
 
 from loguru import logger
 
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 
 
@@ -67,6 +68,7 @@ class PytestErrorHandler:
             success=False,
             issues=[],
             output=error_msg,
+            error_code=TOOL_TIMEOUT.code,
             issues_count=max(initial_count, 1),  # Count timeout as execution failure
         )
 
@@ -68,6 +68,10 @@ def execute_ruff_check(
         file_patterns=tool.definition.file_patterns,
         exclude_patterns=tool.exclude_patterns,
         include_venv=tool.include_venv,
+        shebang_interpreters=tool.definition.shebang_interpreters,
+        prune_dirs=tool.prune_dirs,
+        gitignore=tool.gitignore,
+        symlinks=tool.symlinks,
     )
 
     if not python_files:
@@ -120,6 +124,7 @@ def execute_ruff_check(
             name=tool.definition.name,
             success=timeout_result.success,
             output=timeout_result.output,
+            error_code=timeout_result.error_code,
             issues_count=timeout_result.issues_count,
             issues=timeout_result.issues,
         )
@@ -164,6 +169,7 @@ def execute_ruff_check(
                 name=tool.definition.name,
                 success=timeout_result.success,
                 output=timeout_result.output,
+                error_code=timeout_result.error_code,
                 issues_count=lint_issues_count + timeout_result.issues_count,
                 issues=lint_issues + timeout_result.issues,
             )
@@ -121,12 +121,17 @@ def build_ruff_check_command(
     # Fix options
     if fix:
         cmd.append("--fix")
-        if tool.options.get("unsafe_fixes"):
-            cmd.append("--unsafe-fixes")
+        # Explicit either way, so unsafe-fixes in the Ruff config cannot
+        # apply unsafe fixes the fix safety policy leaves out
+        unsafe_fixes = bool(tool.options.get("unsafe_fixes"))
+        cmd.append("--unsafe-fixes" if unsafe_fixes else "--no-unsafe-fixes")
         if tool.options.get("show_fixes"):
             cmd.append("--show-fixes")
         if tool.options.get("fix_only"):
             cmd.append("--fix-only")
+        fixable_rules = _get_list_option(tool.options, "fixable")
+        if fixable_rules:
+            cmd.extend(["--fixable", ",".join(fixable_rules)])
 
     # Output format
     cmd.extend(["--output-format", RUFF_OUTPUT_FORMAT])
@@ -10,6 +10,8 @@ from typing import TYPE_CHECKING
 
 from loguru import logger
 
+from lintro.enums.fix_safety import FixSafety
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.parsers.ruff.ruff_parser import (
     parse_ruff_format_check_output,
     parse_ruff_output,
@@ -108,6 +110,10 @@ def execute_ruff_fix(
         file_patterns=tool.definition.file_patterns,
         exclude_patterns=tool.exclude_patterns,
         include_venv=tool.include_venv,
+        shebang_interpreters=tool.definition.shebang_interpreters,
+        prune_dirs=tool.prune_dirs,
+        gitignore=tool.gitignore,
+        symlinks=tool.symlinks,
     )
 
     if not python_files:
@@ -123,6 +129,10 @@ def execute_ruff_fix(
 
     # Track unsafe fixes for internal decisioning; do not emit as user-facing noise
     unsafe_fixes_enabled: bool = bool(tool.options.get("unsafe_fixes", False))
+    # Formatting is not a rule, so rule-scoped fixing leaves it alone
+    run_format: bool = bool(tool.options.get("format", False)) and not (
+        tool.options.get("fixable")
+    )
 
     # First, count issues before fixing
     cmd_check: list[str] = build_ruff_check_command(
@@ -147,6 +157,7 @@ def execute_ruff_fix(
             name=tool.definition.name,
             success=timeout_result.success,
             output=timeout_result.output,
+            error_code=timeout_result.error_code,
             issues_count=timeout_result.issues_count,
             issues=timeout_result.issues,
             initial_issues_count=None,
@@ -159,7 +170,7 @@ def execute_ruff_fix(
     # Also check formatting issues before fixing
     initial_format_count: int = 0
     format_files: list[str] = []
-    if tool.options.get("format", False):
+    if run_format:
         format_cmd_check: list[str] = build_ruff_format_command(
             tool=tool,
             files=python_files,
@@ -183,6 +194,7 @@ def execute_ruff_fix(
                 name=tool.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=1,  # Count timeout as execution failure
                 # Include any lint issues found before timeout
                 issues=initial_issues,
@@ -220,6 +232,7 @@ def execute_ruff_fix(
                 name=tool.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=1,  # Count timeout as execution failure
                 issues=initial_issues,  # Include initial issues found
                 initial_issues_count=total_initial_count,
@@ -236,40 +249,16 @@ def execute_ruff_fix(
     fixed_lint_count: int = max(0, initial_count - remaining_count)
     fixed_count: int = fixed_lint_count
 
-    # If there are remaining issues, check if any are fixable with unsafe fixes
-    # If unsafe fixes are disabled, check if any remaining issues are
-    # fixable with unsafe fixes
-    if remaining_count > 0 and not unsafe_fixes_enabled:
-        # Try running ruff with unsafe fixes in dry-run mode to see if it
-        # would fix more
-        # Use context manager to safely temporarily enable unsafe_fixes
-        remaining_unsafe = remaining_issues
-        success_unsafe: bool = False
-        output_unsafe: str = ""
-        with _temporary_option(tool, "unsafe_fixes", True):
-            # Build command with unsafe_fixes temporarily enabled
-            cmd_unsafe: list[str] = build_ruff_check_command(
-                tool=tool,
-                files=python_files,
-                fix=True,
-            )
-            # Command is built, option will be restored by context manager
-        # Run the command (option already restored)
-        try:
-            success_unsafe, output_unsafe = tool._run_subprocess(
-                cmd=cmd_unsafe,
-                timeout=timeout,
-            )
-        except subprocess.TimeoutExpired:
-            # If unsafe check times out, just continue with current results
-            # Don't fail the entire operation for this optional check
-            pass
-        else:
-            remaining_unsafe = parse_ruff_output(output=output_unsafe)
-        if len(remaining_unsafe) < remaining_count:
+    # Ruff reports each fix's applicability, so the remaining issues tell
+    # whether unsafe fixes would fix more without running them
+    if not unsafe_fixes_enabled:
+        unsafe_count = sum(
+            issue.get_fix_safety() == FixSafety.UNSAFE for issue in remaining_issues
+        )
+        if unsafe_count:
             logger.warning(
-                "Some remaining issues could be fixed by enabling unsafe "
-                "fixes (use --tool-options ruff:unsafe_fixes=True)",
+                f"{unsafe_count} remaining issue(s) have only unsafe fixes "
+                "(apply them with --fix-safety all)",
             )
     # Log remaining issues for debugging (if verbose)
     # Note: Issue details are already included in the ToolResult.issues list
@@ -278,7 +267,7 @@ def execute_ruff_fix(
         overall_success = False
 
     # Run ruff format if enabled (default: True)
-    if tool.options.get("format", False):
+    if run_format:
         format_cmd: list[str] = build_ruff_format_command(
             tool=tool,
             files=python_files,
@@ -306,6 +295,7 @@ def execute_ruff_fix(
                 name=tool.definition.name,
                 success=False,
                 output=timeout_msg,
+                error_code=TOOL_TIMEOUT.code,
                 issues_count=1,  # Count timeout as execution failure
                 issues=remaining_issues,  # Include any issues found before timeout
                 initial_issues_count=total_initial_count,
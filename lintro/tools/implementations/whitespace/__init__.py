@@ -0,0 +1,13 @@
+"""Whitespace tool implementation helpers.
+
+This package provides the whitespace and end-of-file checks and fixes used
+by the native whitespace plugin.
+"""
+
+from lintro.tools.implementations.whitespace.whitespace_checks import (
+    BYTE_ORDER_MARK,
+    check_text,
+    fix_text,
+)
+
+__all__ = ["BYTE_ORDER_MARK", "check_text", "fix_text"]
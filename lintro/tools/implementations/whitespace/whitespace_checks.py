@@ -0,0 +1,160 @@
+"""Whitespace and end-of-file hygiene checks and fixes.
+
+Checks every text file for:
+
+- WS001: Line has trailing whitespace
+- WS002: File does not end with a newline
+- WS003: File ends with blank lines
+- WS004: Indentation has a space before a tab
+- WS005: File starts with a byte order mark
+
+Every violation except WS004 is rewritten by ``fix_text``; which of the
+two indentation styles was intended is for a person to decide.
+"""
+
+from __future__ import annotations
+
+from lintro.parsers.whitespace.whitespace_issue import WhitespaceIssue
+from lintro.tools.implementations.editorconfig.editorconfig_checks import split_lines
+
+BYTE_ORDER_MARK: str = "\ufeff"
+
+
+def _trim(content: str, markdown: bool) -> str:
+    """Strip trailing whitespace from a line.
+
+    Args:
+        content: Line content.
+        markdown: Whether two or more trailing spaces are a Markdown hard
+            line break to keep.
+
+    Returns:
+        str: The line without trailing whitespace.
+    """
+    stripped = content.rstrip(" \t")
+    trailing = content[len(stripped) :]
+    if markdown and stripped and len(trailing) >= 2 and not trailing.strip(" "):
+        return content
+    return stripped
+
+
+def _trailing_blank_lines(lines: list[tuple[str, str]]) -> int:
+    """Count the blank lines at the end of a file.
+
+    Args:
+        lines: (content, terminator) pairs.
+
+    Returns:
+        int: Number of trailing lines with nothing but whitespace.
+    """
+    count = 0
+    for content, _ in reversed(lines):
+        if content.strip(" \t"):
+            break
+        count += 1
+    return count
+
+
+def check_text(
+    text: str,
+    file_path: str,
+    markdown: bool = False,
+) -> list[WhitespaceIssue]:
+    """Check file contents for whitespace problems.
+
+    Args:
+        text: File contents, with line endings preserved.
+        file_path: Path used when reporting issues.
+        markdown: Whether to keep Markdown hard line breaks.
+
+    Returns:
+        list[WhitespaceIssue]: Issues found in the file.
+    """
+    issues: list[WhitespaceIssue] = []
+    if text.startswith(BYTE_ORDER_MARK):
+        issues.append(
+            WhitespaceIssue(
+                file=file_path,
+                line=1,
+                column=1,
+                message="File starts with a byte order mark",
+                code="WS005",
+                fixable=True,
+            ),
+        )
+        text = text[len(BYTE_ORDER_MARK) :]
+    lines = split_lines(text)
+    blank_tail = _trailing_blank_lines(lines)
+    body_length = len(lines) - blank_tail
+
+    for number, (content, _) in enumerate(lines[:body_length], start=1):
+        indent = content[: len(content) - len(content.lstrip(" \t"))]
+        if " \t" in indent:
+            issues.append(
+                WhitespaceIssue(
+                    file=file_path,
+                    line=number,
+                    column=indent.index(" \t") + 1,
+                    message="Indentation has a space before a tab",
+                    code="WS004",
+                ),
+            )
+        trimmed = _trim(content, markdown)
+        if trimmed != content:
+            issues.append(
+                WhitespaceIssue(
+                    file=file_path,
+                    line=number,
+                    column=len(trimmed) + 1,
+                    message="Trailing whitespace",
+                    code="WS001",
+                    fixable=True,
+                ),
+            )
+
+    if blank_tail:
+        issues.append(
+            WhitespaceIssue(
+                file=file_path,
+                line=body_length + 1,
+                column=1,
+                message=f"File ends with {blank_tail} blank line(s)",
+                code="WS003",
+                fixable=True,
+            ),
+        )
+    elif lines and not lines[-1][1]:
+        issues.append(
+            WhitespaceIssue(
+                file=file_path,
+                line=len(lines),
+                column=len(lines[-1][0]) + 1,
+                message="File does not end with a newline",
+                code="WS002",
+                fixable=True,
+            ),
+        )
+    return issues
+
+
+def fix_text(text: str, markdown: bool = False) -> str:
+    """Rewrite the fixable whitespace problems in file contents.
+
+    Args:
+        text: File contents, with line endings preserved.
+        markdown: Whether to keep Markdown hard line breaks.
+
+    Returns:
+        str: Fixed contents; files with nothing but whitespace become empty.
+    """
+    if text.startswith(BYTE_ORDER_MARK):
+        text = text[len(BYTE_ORDER_MARK) :]
+    lines = split_lines(text)
+    lines = lines[: len(lines) - _trailing_blank_lines(lines)]
+    if not lines:
+        return ""
+    endings = [ending for _, ending in lines if ending]
+    newline = max(set(endings), key=endings.count) if endings else "\n"
+    fixed = [(_trim(content, markdown), ending) for content, ending in lines]
+    fixed[-1] = (fixed[-1][0], fixed[-1][1] or newline)
+    return "".join(content + ending for content, ending in fixed)
@@ -8,17 +8,26 @@ from __future__ import annotations
 
 import asyncio
 import os
+import time
 from collections.abc import Callable
 from concurrent.futures import ThreadPoolExecutor
 from dataclasses import dataclass, field
+from functools import partial
 from typing import TYPE_CHECKING, Any
 
 from loguru import logger
 
+from lintro.exceptions.errors import TOOL_EXECUTION_FAILED, error_code_for
+from lintro.utils.execution.fail_fast import cancelled_result
+from lintro.utils.result_cache import check_with_cache
+from lintro.utils.timestamps import elapsed_ms
+from lintro.utils.timing_profile import measure_tool
+
 if TYPE_CHECKING:
     from lintro.enums.action import Action
     from lintro.models.core.tool_result import ToolResult
     from lintro.plugins.base import BaseToolPlugin
+    from lintro.utils.timing_profile import TimingProfile
 
 
 def _get_default_max_workers() -> int:
@@ -78,6 +87,8 @@ class AsyncToolExecutor:
         paths: list[str],
         action: Action,
         options: dict[str, Any] | None = None,
+        cancelled: Callable[[], bool] | None = None,
+        timing_profile: TimingProfile | None = None,
     ) -> ToolResult:
         """Run a single tool asynchronously.
 
@@ -86,6 +97,10 @@ class AsyncToolExecutor:
             paths: List of file paths to process.
             action: The action to perform (check or fix).
             options: Additional options to pass to the tool.
+            cancelled: Checked when a worker picks the tool up; if it returns
+                True the tool is not run and is reported as cancelled.
+            timing_profile: Profile the tool's phases are recorded in, or
+                None when not profiling.
 
         Returns:
             ToolResult: The result of tool execution.
@@ -101,10 +116,28 @@ class AsyncToolExecutor:
         loop = asyncio.get_running_loop()
         opts = options or {}
 
-        func = tool.fix if action == Action.FIX else tool.check
+        func = (
+            tool.fix if action == Action.FIX else partial(check_with_cache, tool)
+        )
+
+        def timed_run() -> ToolResult:
+            """Run the tool in a worker thread and record its duration.
+
+            Returns:
+                ToolResult: The tool's result with duration_ms and
+                extra_args set.
+            """
+            if cancelled is not None and cancelled():
+                return cancelled_result(tool.definition.name)
+            started = time.monotonic()
+            with measure_tool(timing_profile, tool.definition.name):
+                result = func(paths, opts)
+            result.duration_ms = elapsed_ms(started)
+            result.extra_args = list(tool.extra_args)
+            return result
 
         logger.debug(f"Starting async execution of {tool.definition.name}")
-        result = await loop.run_in_executor(self._executor, func, paths, opts)
+        result = await loop.run_in_executor(self._executor, timed_run)
         logger.debug(f"Completed async execution of {tool.definition.name}")
 
         return result
@@ -116,6 +149,9 @@ class AsyncToolExecutor:
         action: Action,
         options_per_tool: dict[str, dict[str, Any]] | None = None,
         on_result: Callable[[str, ToolResult], None] | None = None,
+        paths_per_tool: dict[str, list[str]] | None = None,
+        cancelled: Callable[[], bool] | None = None,
+        timing_profile: TimingProfile | None = None,
     ) -> list[tuple[str, ToolResult]]:
         """Run multiple tools in parallel.
 
@@ -125,11 +161,18 @@ class AsyncToolExecutor:
             action: The action to perform.
             options_per_tool: Optional dict mapping tool names to their options.
             on_result: Optional callback called when each tool completes.
+            paths_per_tool: Optional dict mapping tool names to the paths they
+                should run on instead of ``paths``.
+            cancelled: Optional check that skips the tools still waiting
+                for a worker once it returns True (see run_tool_async).
+            timing_profile: Profile the tools' phases are recorded in, or
+                None when not profiling.
 
         Returns:
             List of (tool_name, ToolResult) tuples in completion order.
         """
         options = options_per_tool or {}
+        tool_paths = paths_per_tool or {}
 
         async def run_with_name(
             name: str,
@@ -145,7 +188,15 @@ class AsyncToolExecutor:
                 Tuple of (tool_name, ToolResult).
             """
             tool_opts = options.get(name, {})
-            result = await self.run_tool_async(tool, paths, action, tool_opts)
+            run_paths = tool_paths.get(name, paths)
+            result = await self.run_tool_async(
+                tool,
+                run_paths,
+                action,
+                tool_opts,
+                cancelled=cancelled,
+                timing_profile=timing_profile,
+            )
             if on_result:
                 on_result(name, result)
             return (name, result)
@@ -158,7 +209,11 @@ class AsyncToolExecutor:
         for i, result in enumerate(results):
             tool_name = tools[i][0]
             if isinstance(result, Exception):
-                logger.error(f"Tool {tool_name} failed with exception: {result}")
+                error = error_code_for(result, TOOL_EXECUTION_FAILED)
+                logger.error(
+                    f"[{error.code}] Tool {tool_name} failed with exception: "
+                    f"{result}",
+                )
                 # Create a failed result
                 from lintro.models.core.tool_result import ToolResult
 
@@ -167,6 +222,7 @@ class AsyncToolExecutor:
                     success=False,
                     output=f"Parallel execution failed: {result}",
                     issues_count=0,
+                    error_code=error.code,
                 )
                 processed_results.append((tool_name, failed_result))
             else:
@@ -0,0 +1,236 @@
+"""README status badges for lintro reports, rendered as SVG.
+
+``lintro badge --input lint.json`` draws a flat, shields.io style badge from
+a JSON report: either the number of issues, green when there are none,
+yellow for warnings and red once an issue is an error, or with ``--status``
+just passing or failing. Badges can cover the whole report or one tool, and
+``--per-tool`` writes one badge per tool that ran.
+
+The badge is a static file, so a scheduled workflow can commit it or upload
+it to GitHub Pages and the README links to it without calling any service.
+"""
+
+from __future__ import annotations
+
+import html
+import json
+from collections.abc import Mapping
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.issue_query import report_records
+
+DEFAULT_LABEL: str = "lintro"
+LABEL_COLOR: str = "#555"
+PASS_COLOR: str = "#4c1"
+WARN_COLOR: str = "#dfb317"
+FAIL_COLOR: str = "#e05d44"
+
+_HEIGHT = 20
+_PADDING = 6
+# Average advance of Verdana 11px; textLength fits the text exactly
+_CHAR_WIDTH = 7.0
+
+
+@dataclass(frozen=True)
+class BadgeSummary:
+    """What a badge shows about a report.
+
+    Attributes:
+        tool: Tool the badge covers, or None for the whole report.
+        issues: Issues found.
+        errors: Issues with error severity.
+        failed: Whether any covered tool failed.
+    """
+
+    tool: str | None
+    issues: int
+    errors: int
+    failed: bool
+
+
+def load_report(path: str | Path) -> dict[str, Any]:
+    """Read a JSON report.
+
+    Args:
+        path: Report written with ``--output FILE --output-format json``.
+
+    Returns:
+        dict[str, Any]: The report.
+
+    Raises:
+        ValueError: If the file is not a Lintro JSON report.
+        OSError: If the file cannot be read.
+    """
+    report = json.loads(Path(path).read_text(encoding="utf-8"))
+    if not isinstance(report, dict) or not isinstance(report.get("results"), list):
+        raise ValueError("Not a Lintro JSON report: missing 'results'")
+    return report
+
+
+def summarize_report(
+    report: Mapping[str, Any],
+    tool: str | None = None,
+) -> BadgeSummary:
+    """Count the issues of a report, or of one tool in it.
+
+    Args:
+        report: JSON report.
+        tool: Tool to cover, or None for every tool.
+
+    Returns:
+        BadgeSummary: Issue counts and outcome.
+
+    Raises:
+        ValueError: If the tool did not run in the report.
+    """
+    wanted = tool.lower() if tool else None
+    results = [
+        r
+        for r in report.get("results") or []
+        if wanted is None or str(r.get("tool") or "").lower() == wanted
+    ]
+    if wanted is not None and not results:
+        raise ValueError(f"'{tool}' did not run in the report")
+    errors = [
+        record
+        for record in report_records({"results": results})
+        if record["severity"] == SeverityLevel.ERROR
+    ]
+    issues = sum(
+        max(int(r.get("issues_count") or 0), len(r.get("issues") or []))
+        for r in results
+    )
+    return BadgeSummary(
+        tool=wanted,
+        issues=issues,
+        errors=len(errors),
+        failed=any(not r.get("success", True) for r in results),
+    )
+
+
+def summarize_tools(report: Mapping[str, Any]) -> list[BadgeSummary]:
+    """Count the issues of each tool in a report.
+
+    Args:
+        report: JSON report.
+
+    Returns:
+        list[BadgeSummary]: One summary per tool, sorted by name.
+    """
+    tools = {
+        str(r.get("tool") or "").lower()
+        for r in report.get("results") or []
+        if r.get("tool")
+    }
+    return [summarize_report(report, tool) for tool in sorted(tools)]
+
+
+def badge_message(summary: BadgeSummary, status: bool = False) -> tuple[str, str]:
+    """Choose the text and colour of a badge's right half.
+
+    Args:
+        summary: Summary the badge shows.
+        status: Show passing or failing instead of the issue count.
+
+    Returns:
+        tuple[str, str]: Message and fill colour.
+    """
+    if status:
+        if summary.failed or summary.issues:
+            return "failing", FAIL_COLOR
+        return "passing", PASS_COLOR
+    if summary.issues == 0:
+        color = FAIL_COLOR if summary.failed else PASS_COLOR
+        return ("failed" if summary.failed else "0 issues"), color
+    noun = "issue" if summary.issues == 1 else "issues"
+    # Tools fail on warnings too, so the count is coloured by severity alone
+    color = FAIL_COLOR if summary.errors else WARN_COLOR
+    return f"{summary.issues} {noun}", color
+
+
+def _text_width(text: str) -> int:
+    """Estimate the rendered width of badge text.
+
+    Args:
+        text: Text to measure.
+
+    Returns:
+        int: Width in pixels.
+    """
+    return round(len(text) * _CHAR_WIDTH)
+
+
+def render_badge_svg(label: str, message: str, color: str) -> str:
+    """Render a flat two-part badge.
+
+    Args:
+        label: Text of the grey left half.
+        message: Text of the coloured right half.
+        color: Fill of the right half.
+
+    Returns:
+        str: SVG document.
+    """
+    label_text = _text_width(label)
+    message_text = _text_width(message)
+    left = label_text + 2 * _PADDING
+    right = message_text + 2 * _PADDING
+    width = left + right
+    title = html.escape(f"{label}: {message}")
+    parts = []
+    for text, x, length in (
+        (label, left / 2, label_text),
+        (message, left + right / 2, message_text),
+    ):
+        escaped = html.escape(text)
+        for y, shadow in ((15, ' fill="#010101" fill-opacity=".3"'), (14, "")):
+            parts.append(
+                f'<text x="{x * 10:.0f}" y="{y * 10}"{shadow} '
+                f'transform="scale(.1)" textLength="{length * 10}">'
+                f"{escaped}</text>",
+            )
+    texts = "\n    ".join(parts)
+    return f"""\
+<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{_HEIGHT}" \
+role="img" aria-label="{title}">
+  <title>{title}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r">
+    <rect width="{width}" height="{_HEIGHT}" rx="3" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{left}" height="{_HEIGHT}" fill="{LABEL_COLOR}"/>
+    <rect x="{left}" width="{right}" height="{_HEIGHT}" fill="{color}"/>
+    <rect width="{width}" height="{_HEIGHT}" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" \
+font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="110">
+    {texts}
+  </g>
+</svg>
+"""
+
+
+def render_summary_badge(
+    summary: BadgeSummary,
+    label: str | None = None,
+    status: bool = False,
+) -> str:
+    """Render the badge of a summary.
+
+    Args:
+        summary: Summary the badge shows.
+        label: Left-hand text; defaults to the tool name, or ``lintro``.
+        status: Show passing or failing instead of the issue count.
+
+    Returns:
+        str: SVG document.
+    """
+    message, color = badge_message(summary, status=status)
+    return render_badge_svg(label or summary.tool or DEFAULT_LABEL, message, color)
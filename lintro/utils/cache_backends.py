@@ -0,0 +1,316 @@
+"""Storage backends of the shared result cache.
+
+The result cache keeps one entry per file and tool. Entries live in a
+local directory and, with ``execution.cache.remote``, in a store shared by
+every CI job:
+
+- ``s3://bucket/prefix`` through the ``aws`` CLI
+- ``gs://bucket/prefix`` through the ``gcloud`` CLI
+- ``http://`` or ``https://`` URLs: ``GET {url}/{key}`` answers the entry
+  or 404, ``PUT {url}/{key}`` stores it. Requests carry
+  ``Authorization: Bearer <token>`` when the token variable is set.
+
+Remote stores are best effort: a failing store counts as a miss and never
+fails the run.
+"""
+
+from __future__ import annotations
+
+import os
+import shutil
+import subprocess  # nosec B404 - subprocess used safely with shell=False
+import tempfile
+from pathlib import Path
+from typing import Protocol
+
+from loguru import logger
+
+# Seconds a remote store may take for one entry
+REMOTE_TIMEOUT: float = 10.0
+TOKEN_ENV: str = "LINTRO_CACHE_TOKEN"
+
+
+class CacheBackend(Protocol):
+    """Store of cache entries addressed by key."""
+
+    @property
+    def name(self) -> str:
+        """Describe the store in log messages.
+
+        Returns:
+            str: Location of the store.
+        """
+        ...
+
+    def get(self, key: str) -> bytes | None:
+        """Read an entry.
+
+        Args:
+            key: Entry key, a relative ``/``-separated path.
+
+        Returns:
+            bytes | None: Entry, or None if the store does not have it.
+        """
+        ...
+
+    def put(self, key: str, data: bytes) -> None:
+        """Store an entry.
+
+        Args:
+            key: Entry key, a relative ``/``-separated path.
+            data: Entry contents.
+        """
+        ...
+
+
+class LocalCacheBackend:
+    """Cache entries in a local directory.
+
+    Attributes:
+        root: Directory holding the entries.
+    """
+
+    def __init__(self, root: Path) -> None:
+        """Create the backend.
+
+        Args:
+            root: Directory holding the entries.
+        """
+        self.root = root
+
+    @property
+    def name(self) -> str:
+        """Describe the store in log messages.
+
+        Returns:
+            str: Directory of the store.
+        """
+        return str(self.root)
+
+    def get(self, key: str) -> bytes | None:
+        """Read an entry.
+
+        Args:
+            key: Entry key.
+
+        Returns:
+            bytes | None: Entry, or None if it is missing or unreadable.
+        """
+        try:
+            return (self.root / key).read_bytes()
+        except OSError:
+            return None
+
+    def put(self, key: str, data: bytes) -> None:
+        """Store an entry with an atomic rename.
+
+        Args:
+            key: Entry key.
+            data: Entry contents.
+        """
+        path = self.root / key
+        try:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            with tempfile.NamedTemporaryFile(
+                dir=path.parent,
+                suffix=".tmp",
+                delete=False,
+            ) as tmp_file:
+                tmp_file.write(data)
+                tmp_path = Path(tmp_file.name)
+            tmp_path.replace(path)
+        except OSError as e:
+            logger.debug(f"Could not write cache entry {path}: {e}")
+
+
+class HttpCacheBackend:
+    """Cache entries on an HTTP service.
+
+    Attributes:
+        url: Base URL; keys are appended to it.
+        token: Bearer token, or None to send none.
+    """
+
+    def __init__(self, url: str, token: str | None = None) -> None:
+        """Create the backend.
+
+        Args:
+            url: Base URL of the service.
+            token: Bearer token, or None to send none.
+        """
+        self.url = url.rstrip("/")
+        self.token = token
+
+    @property
+    def name(self) -> str:
+        """Describe the store in log messages.
+
+        Returns:
+            str: Base URL of the service.
+        """
+        return self.url
+
+    def _headers(self) -> dict[str, str]:
+        """Build the request headers.
+
+        Returns:
+            dict[str, str]: Authorization header when a token is set.
+        """
+        return {"Authorization": f"Bearer {self.token}"} if self.token else {}
+
+    def get(self, key: str) -> bytes | None:
+        """Fetch an entry.
+
+        Args:
+            key: Entry key.
+
+        Returns:
+            bytes | None: Entry, or None on 404 or any failure.
+        """
+        import httpx
+
+        try:
+            response = httpx.request(
+                "GET",
+                f"{self.url}/{key}",
+                headers=self._headers(),
+                timeout=REMOTE_TIMEOUT,
+            )
+        except httpx.TransportError as e:
+            logger.debug(f"Cache GET {key} failed: {e}")
+            return None
+        if response.status_code != 200:
+            if response.status_code != 404:
+                logger.debug(f"Cache GET {key} answered HTTP {response.status_code}")
+            return None
+        return bytes(response.content)
+
+    def put(self, key: str, data: bytes) -> None:
+        """Upload an entry.
+
+        Args:
+            key: Entry key.
+            data: Entry contents.
+        """
+        import httpx
+
+        try:
+            response = httpx.request(
+                "PUT",
+                f"{self.url}/{key}",
+                headers={**self._headers(), "Content-Type": "application/json"},
+                content=data,
+                timeout=REMOTE_TIMEOUT,
+            )
+        except httpx.TransportError as e:
+            logger.debug(f"Cache PUT {key} failed: {e}")
+            return
+        if response.status_code >= 400:
+            logger.debug(f"Cache PUT {key} answered HTTP {response.status_code}")
+
+
+class CliCacheBackend:
+    """Cache entries in an object store through its copy command.
+
+    ``aws s3 cp`` and ``gcloud storage cp`` both copy between an object URL
+    and ``-`` (stdin or stdout), and use the credentials the CLI is
+    configured with.
+
+    Attributes:
+        command: Copy command, e.g. ``["aws", "s3", "cp"]``.
+        url: Object URL prefix, e.g. ``s3://bucket/lintro``.
+    """
+
+    def __init__(self, command: list[str], url: str) -> None:
+        """Create the backend.
+
+        Args:
+            command: Copy command.
+            url: Object URL prefix.
+        """
+        self.command = command
+        self.url = url.rstrip("/")
+
+    @property
+    def name(self) -> str:
+        """Describe the store in log messages.
+
+        Returns:
+            str: Object URL prefix.
+        """
+        return self.url
+
+    def _copy(self, source: str, target: str, data: bytes | None) -> bytes | None:
+        """Run the copy command.
+
+        Args:
+            source: Object URL or ``-``.
+            target: Object URL or ``-``.
+            data: Bytes sent on stdin, if any.
+
+        Returns:
+            bytes | None: Standard output, or None if the copy failed.
+        """
+        if shutil.which(self.command[0]) is None:
+            logger.debug(f"Cache store {self.url} needs {self.command[0]}")
+            return None
+        try:
+            completed = subprocess.run(  # nosec B603 - fixed command, no shell
+                [*self.command, source, target],
+                input=data,
+                capture_output=True,
+                timeout=REMOTE_TIMEOUT,
+                check=False,
+            )
+        except (OSError, subprocess.TimeoutExpired) as e:
+            logger.debug(f"Cache copy {source} -> {target} failed: {e}")
+            return None
+        if completed.returncode != 0:
+            return None
+        return completed.stdout
+
+    def get(self, key: str) -> bytes | None:
+        """Download an entry.
+
+        Args:
+            key: Entry key.
+
+        Returns:
+            bytes | None: Entry, or None if it is missing or the copy failed.
+        """
+        return self._copy(f"{self.url}/{key}", "-", None) or None
+
+    def put(self, key: str, data: bytes) -> None:
+        """Upload an entry.
+
+        Args:
+            key: Entry key.
+            data: Entry contents.
+        """
+        if self._copy("-", f"{self.url}/{key}", data) is None:
+            logger.debug(f"Could not upload cache entry {key} to {self.url}")
+
+
+def create_cache_backend(url: str, token_env: str = TOKEN_ENV) -> CacheBackend:
+    """Create the backend of a remote cache URL.
+
+    Args:
+        url: ``s3://``, ``gs://``, ``http://`` or ``https://`` URL.
+        token_env: Environment variable holding the HTTP bearer token.
+
+    Returns:
+        CacheBackend: Backend storing entries under the URL.
+
+    Raises:
+        ValueError: If the URL scheme is not supported.
+    """
+    scheme = url.partition("://")[0].lower()
+    if scheme == "s3":
+        return CliCacheBackend(["aws", "s3", "cp", "--only-show-errors"], url)
+    if scheme == "gs":
+        return CliCacheBackend(["gcloud", "storage", "cp", "--quiet"], url)
+    if scheme in {"http", "https"}:
+        return HttpCacheBackend(url, token=os.environ.get(token_env) or None)
+    raise ValueError(
+        f"Unsupported cache URL: {url}. Use s3://, gs://, http:// or https://",
+    )
@@ -0,0 +1,71 @@
+"""Locating dependency declarations in Cargo.toml.
+
+Cargo tools report crates by name; these helpers find the line declaring a
+crate so issues can point at it. Only the plain layout Cargo itself writes
+is understood: ``[dependencies]``-style tables (including ``dev-``,
+``build-``, ``workspace.`` and ``target.*.`` variants) and
+``[dependencies.<name>]`` tables.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+
+_SECTION_RE = re.compile(r"^\s*\[+\s*([^\]]+?)\s*\]+")
+_KEY_RE = re.compile(r'^\s*("[^"]+"|[A-Za-z0-9_-]+)\s*=')
+_PACKAGE_RE = re.compile(r'\bpackage\s*=\s*"([^"]+)"')
+_DEPENDENCY_TABLES: tuple[str, ...] = (
+    "dependencies",
+    "dev-dependencies",
+    "build-dependencies",
+)
+
+
+def _dependency_table(section: str) -> tuple[bool, str | None]:
+    """Classify a Cargo.toml table header.
+
+    Args:
+        section: Table name, such as ``dependencies`` or
+            ``target.'cfg(unix)'.dependencies.libc``.
+
+    Returns:
+        tuple[bool, str | None]: Whether the table lists dependencies, and
+        the dependency named by a ``[dependencies.<name>]`` header.
+    """
+    for table in _DEPENDENCY_TABLES:
+        if section == table or section.endswith(f".{table}"):
+            return True, None
+        head, sep, name = section.rpartition(f"{table}.")
+        if sep and (not head or head.endswith(".")):
+            return False, name.strip('"')
+    return False, None
+
+
+def dependency_lines(path: Path) -> dict[str, int]:
+    """Find where each direct dependency is declared in a manifest.
+
+    Args:
+        path: Cargo.toml to read.
+
+    Returns:
+        dict[str, int]: First line declaring each dependency, keyed by its
+        name in the manifest and, for renamed dependencies, also by the
+        crate's package name.
+    """
+    lines: dict[str, int] = {}
+    in_dependencies = False
+    for number, text in enumerate(path.read_text(encoding="utf-8").splitlines(), 1):
+        section = _SECTION_RE.match(text)
+        if section:
+            in_dependencies, named = _dependency_table(section.group(1))
+            if named:
+                lines.setdefault(named, number)
+            continue
+        key = _KEY_RE.match(text)
+        if in_dependencies and key:
+            lines.setdefault(key.group(1).strip('"'), number)
+            renamed = _PACKAGE_RE.search(text)
+            if renamed:
+                lines.setdefault(renamed.group(1), number)
+    return lines
@@ -0,0 +1,195 @@
+"""Cargo workspace discovery via ``cargo metadata``.
+
+Pointing lintro at part of a workspace used to run Cargo tools in whichever
+directory held the nearest Cargo.toml: a member directory reported paths
+relative to the wrong root, and sibling members had no common manifest at
+all. These helpers ask Cargo for the workspace root and its members so tools
+run from the root and select the members containing the checked files.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess  # nosec B404 - used safely with shell disabled
+from collections.abc import Iterable
+from dataclasses import dataclass
+from pathlib import Path
+
+from loguru import logger
+
+METADATA_TIMEOUT: int = 30
+
+
+@dataclass(frozen=True)
+class CargoPackage:
+    """A workspace member.
+
+    Attributes:
+        name: Package name, as accepted by ``-p``.
+        root: Directory containing the package's Cargo.toml.
+    """
+
+    name: str
+    root: Path
+
+
+@dataclass(frozen=True)
+class CargoWorkspace:
+    """A Cargo workspace and its members.
+
+    Attributes:
+        root: Workspace root, where Cargo tools should run.
+        members: Workspace members; a single package outside any workspace
+            is its own only member.
+    """
+
+    root: Path
+    members: tuple[CargoPackage, ...]
+
+    def package_for(self, path: str | Path) -> CargoPackage | None:
+        """Return the member a file belongs to.
+
+        Args:
+            path: File path, absolute or relative to the workspace root.
+
+        Returns:
+            CargoPackage | None: Member with the deepest directory containing
+            the file, or None if no member does.
+        """
+        resolved = (self.root / path).resolve()
+        owners = [
+            member
+            for member in self.members
+            if resolved == member.root or member.root in resolved.parents
+        ]
+        return max(owners, key=lambda m: len(m.root.parts), default=None)
+
+    def packages_for(self, paths: Iterable[str]) -> list[CargoPackage]:
+        """Return the members containing any of the given files.
+
+        Args:
+            paths: File paths, absolute or relative to the working directory.
+
+        Returns:
+            list[CargoPackage]: Members in workspace order, without
+            duplicates; every member if a file belongs to none, such as the
+            root manifest of a virtual workspace.
+        """
+        owners = {self.package_for(Path(path).resolve()) for path in paths}
+        if None in owners:
+            return list(self.members)
+        return [member for member in self.members if member in owners]
+
+
+@dataclass(frozen=True)
+class CargoTarget:
+    """Where to run a Cargo tool and which packages to select.
+
+    Attributes:
+        root: Directory to run in.
+        packages: Names to pass with ``-p``, or None to run on every package.
+        workspace: Workspace, or None when ``cargo metadata`` was unavailable.
+    """
+
+    root: Path
+    packages: tuple[str, ...] | None = None
+    workspace: CargoWorkspace | None = None
+
+    def crate_for(self, path: str) -> str | None:
+        """Return the name of the crate a reported file belongs to.
+
+        Args:
+            path: File path as reported by cargo, relative to the root.
+
+        Returns:
+            str | None: Package name, or None if unknown.
+        """
+        if self.workspace is None:
+            return None
+        package = self.workspace.package_for(self.root / path)
+        return package.name if package else None
+
+
+def resolve_target(files: list[str], cargo_root: Path) -> CargoTarget:
+    """Select the workspace members containing the files to check.
+
+    Args:
+        files: Files the tool should check.
+        cargo_root: Nearest directory with a Cargo.toml above the files.
+
+    Returns:
+        CargoTarget: The workspace root and the members to select, or
+        cargo_root and every package when the workspace is unknown.
+    """
+    workspace = load_workspace(cargo_root)
+    if workspace is None:
+        return CargoTarget(root=cargo_root)
+    packages = workspace.packages_for(files)
+    if set(packages) == set(workspace.members):
+        return CargoTarget(root=workspace.root, workspace=workspace)
+    return CargoTarget(
+        root=workspace.root,
+        packages=tuple(package.name for package in packages),
+        workspace=workspace,
+    )
+
+
+def load_workspace(directory: Path) -> CargoWorkspace | None:
+    """Ask Cargo for the workspace containing a directory.
+
+    Args:
+        directory: Directory with a Cargo.toml, or below one.
+
+    Returns:
+        CargoWorkspace | None: Workspace, or None if cargo is unavailable or
+        cannot read the manifests.
+    """
+    try:
+        result = subprocess.run(
+            ["cargo", "metadata", "--format-version", "1", "--no-deps"],
+            capture_output=True,
+            text=True,
+            timeout=METADATA_TIMEOUT,
+            cwd=directory,
+        )
+    except (FileNotFoundError, OSError, subprocess.TimeoutExpired) as e:
+        logger.debug(f"cargo metadata unavailable in {directory}: {e}")
+        return None
+    if result.returncode != 0:
+        logger.debug(f"cargo metadata failed in {directory}: {result.stderr}")
+        return None
+    return parse_metadata(result.stdout)
+
+
+def parse_metadata(output: str) -> CargoWorkspace | None:
+    """Build a workspace from ``cargo metadata`` JSON.
+
+    Args:
+        output: Output of ``cargo metadata --format-version 1``.
+
+    Returns:
+        CargoWorkspace | None: Workspace, or None if the output is not
+        metadata.
+    """
+    try:
+        data = json.loads(output)
+    except json.JSONDecodeError:
+        return None
+    if not isinstance(data, dict) or not data.get("workspace_root"):
+        return None
+    member_ids = set(data.get("workspace_members") or [])
+    members = tuple(
+        CargoPackage(
+            name=str(package["name"]),
+            root=Path(package["manifest_path"]).resolve().parent,
+        )
+        for package in data.get("packages") or []
+        if isinstance(package, dict)
+        and package.get("id") in member_ids
+        and package.get("name")
+        and package.get("manifest_path")
+    )
+    return CargoWorkspace(
+        root=Path(data["workspace_root"]).resolve(),
+        members=members,
+    )
@@ -0,0 +1,388 @@
+"""Prioritized cleanup plans built from check results.
+
+Backs ``lintro plan``. Issues are grouped by file and rule, then ordered so
+that auto-fixable and high-severity work comes first. Groups are taken in
+that order until the effort budget is spent. Plans can be split by owner
+using the repository's CODEOWNERS file.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from collections.abc import Iterable, Mapping, Sequence
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.utils.codeowners import (
+    CODEOWNERS_LOCATIONS,
+    compile_pattern,
+    parse_codeowners,
+)
+from lintro.utils.effort import (
+    format_effort,
+    issue_code,
+    issue_effort_minutes,
+    issue_fixable,
+)
+from lintro.utils.path_filtering import walk_files_with_excludes
+
+# Owner used for files without a CODEOWNERS entry
+UNOWNED: str = "unowned"
+
+_SEVERITY_RANK: dict[SeverityLevel, int] = {
+    SeverityLevel.ERROR: 0,
+    SeverityLevel.WARNING: 1,
+    SeverityLevel.INFO: 2,
+}
+
+_BUDGET_PART = re.compile(r"(\d+(?:\.\d+)?)\s*([dhm])", re.IGNORECASE)
+_BUDGET_UNIT_MINUTES: dict[str, int] = {"d": 8 * 60, "h": 60, "m": 1}
+
+
+@dataclass
+class PlanItem:
+    """Issues of one rule in one file, planned as a single task.
+
+    Attributes:
+        file: File containing the issues.
+        tool: Tool that reported them.
+        code: Rule code, or an empty string if the tool has none.
+        severity: Highest severity among the issues.
+        fixable: Whether every issue can be fixed automatically.
+        issues: Number of issues.
+        minutes: Estimated minutes to fix all of them.
+        owners: CODEOWNERS owners of the file.
+    """
+
+    file: str
+    tool: str
+    code: str
+    severity: SeverityLevel
+    fixable: bool
+    issues: int = 0
+    minutes: int = 0
+    owners: list[str] = field(default_factory=list)
+
+
+@dataclass
+class CleanupPlan:
+    """Ordered cleanup tasks that fit an effort budget.
+
+    Attributes:
+        items: Planned tasks, highest priority first.
+        deferred: Tasks left out because they did not fit the budget.
+        budget_minutes: Effort budget, or None for no limit.
+    """
+
+    items: list[PlanItem] = field(default_factory=list)
+    deferred: list[PlanItem] = field(default_factory=list)
+    budget_minutes: int | None = None
+
+    @property
+    def planned_minutes(self) -> int:
+        """Return the estimated effort of the planned tasks.
+
+        Returns:
+            int: Minutes.
+        """
+        return sum(item.minutes for item in self.items)
+
+    @property
+    def total_minutes(self) -> int:
+        """Return the estimated effort of every task, planned or not.
+
+        Returns:
+            int: Minutes.
+        """
+        return self.planned_minutes + sum(item.minutes for item in self.deferred)
+
+    def by_owner(self) -> dict[str, list[PlanItem]]:
+        """Group planned tasks by owner, keeping plan order.
+
+        Tasks with several owners appear under each of them.
+
+        Returns:
+            dict[str, list[PlanItem]]: Tasks keyed by owner.
+        """
+        grouped: dict[str, list[PlanItem]] = {}
+        for item in self.items:
+            for owner in item.owners or [UNOWNED]:
+                grouped.setdefault(owner, []).append(item)
+        return grouped
+
+
+def parse_budget(text: str) -> int:
+    """Parse an effort budget such as ``4h``, ``90m``, ``1h30m`` or ``2d``.
+
+    A bare number is read as minutes. A day is eight working hours.
+
+    Args:
+        text: Budget as given on the command line.
+
+    Returns:
+        int: Budget in minutes.
+
+    Raises:
+        ValueError: If the budget cannot be parsed or is not positive.
+    """
+    value = text.strip().replace(" ", "")
+    if value.isdigit():
+        minutes = int(value)
+    else:
+        parts = _BUDGET_PART.findall(value)
+        if not parts or "".join(n + u for n, u in parts) != value:
+            raise ValueError(
+                f"invalid budget {text!r}; use e.g. 4h, 90m, 1h30m or 2d",
+            )
+        minutes = round(
+            sum(float(n) * _BUDGET_UNIT_MINUTES[u.lower()] for n, u in parts),
+        )
+    if minutes <= 0:
+        raise ValueError(f"budget must be positive, got {text!r}")
+    return minutes
+
+
+def load_codeowners(root: str | Path = ".") -> list[tuple[str, list[str]]]:
+    """Read ownership rules from the first CODEOWNERS file found.
+
+    Args:
+        root: Repository root.
+
+    Returns:
+        list[tuple[str, list[str]]]: (pattern, owners) rules in file order;
+        empty if there is no CODEOWNERS file.
+    """
+    for location in CODEOWNERS_LOCATIONS:
+        path = Path(root) / location
+        if not path.is_file():
+            continue
+        parsed = parse_codeowners(path.read_text(encoding="utf-8"), root)
+        return [(rule.pattern, list(rule.owners)) for rule in parsed.rules]
+    return []
+
+
+def _codeowners_match(pattern: str, path: str) -> bool:
+    """Check whether a CODEOWNERS pattern matches a file.
+
+    Patterns follow GitHub's rules, as in ``lintro check --group-by owner``
+    (see :mod:`lintro.utils.codeowners`).
+
+    Args:
+        pattern: CODEOWNERS pattern.
+        path: File path relative to the repository root.
+
+    Returns:
+        bool: True if the pattern covers the file.
+    """
+    relative = Path(path).as_posix().removeprefix("./")
+    return compile_pattern(pattern).match(relative) is not None
+
+
+def owners_for(path: str, rules: Sequence[tuple[str, list[str]]]) -> list[str]:
+    """Return the owners of a file; the last matching rule wins.
+
+    Args:
+        path: File path relative to the repository root.
+        rules: Rules from :func:`load_codeowners`.
+
+    Returns:
+        list[str]: Owners, or an empty list if no rule matches.
+    """
+    for pattern, owners in reversed(rules):
+        if _codeowners_match(pattern, path):
+            return list(owners)
+    return []
+
+
+def resolve_repo_paths(files: Iterable[str], roots: Sequence[str]) -> dict[str, str]:
+    """Map tool-reported file paths to paths relative to the current directory.
+
+    Tools report paths relative to their own working directory, which is the
+    common parent of the files they checked. Such paths are matched by
+    suffix against the files under ``roots``; ambiguous paths are kept as-is.
+
+    Args:
+        files: File paths reported by tools.
+        roots: Paths that were checked.
+
+    Returns:
+        dict[str, str]: Resolved path for each reported path.
+    """
+    resolved: dict[str, str] = {}
+    unresolved: list[str] = []
+    for file_path in set(files):
+        if not file_path:
+            continue
+        if os.path.isabs(file_path):
+            resolved[file_path] = os.path.relpath(file_path)
+        elif os.path.exists(file_path):
+            resolved[file_path] = os.path.normpath(file_path)
+        else:
+            unresolved.append(file_path)
+    if not unresolved:
+        return resolved
+
+    candidates = [
+        os.path.relpath(path)
+        for path in walk_files_with_excludes(
+            list(roots),
+            sorted({os.path.basename(f) for f in unresolved}),
+            DEFAULT_EXCLUDE_PATTERNS,
+        )
+    ]
+    for file_path in unresolved:
+        suffix = os.sep + os.path.normpath(file_path)
+        matches = [c for c in candidates if (os.sep + c).endswith(suffix)]
+        resolved[file_path] = matches[0] if len(matches) == 1 else file_path
+    return resolved
+
+
+def _priority(item: PlanItem) -> tuple[bool, int, float, str, str]:
+    """Sort key putting auto-fixable, severe and cheap tasks first.
+
+    Args:
+        item: Task to rank.
+
+    Returns:
+        tuple[bool, int, float, str, str]: Sort key.
+    """
+    per_issue = item.minutes / item.issues if item.issues else 0.0
+    return (
+        not item.fixable,
+        _SEVERITY_RANK[item.severity],
+        per_issue,
+        item.file,
+        item.code,
+    )
+
+
+def build_cleanup_plan(
+    tool_results: Sequence[object],
+    budget_minutes: int | None = None,
+    effort_overrides: Mapping[str, int] | None = None,
+    codeowners: Sequence[tuple[str, list[str]]] | None = None,
+    roots: Sequence[str] | None = None,
+) -> CleanupPlan:
+    """Group issues into tasks and pick the most valuable ones within budget.
+
+    Tasks are ordered auto-fixable first, then by severity, then by effort
+    per issue. Tasks that do not fit the remaining budget are deferred, and
+    smaller tasks further down the list may still be planned.
+
+    Args:
+        tool_results: Tool results from a check run.
+        budget_minutes: Effort budget, or None to plan everything.
+        effort_overrides: Configured ``output.effort`` minutes.
+        codeowners: Ownership rules used to assign tasks.
+        roots: Checked paths, used to report files relative to the current
+            directory. None keeps the paths tools reported.
+
+    Returns:
+        CleanupPlan: Planned and deferred tasks.
+    """
+    issues_by_tool = [
+        (str(getattr(result, "name", "")), getattr(result, "issues", None) or [])
+        for result in tool_results
+    ]
+    reported = [
+        str(getattr(issue, "file", "") or "")
+        for _, issues in issues_by_tool
+        for issue in issues
+    ]
+    paths = resolve_repo_paths(reported, roots) if roots else {}
+
+    tasks: dict[tuple[str, str, str], PlanItem] = {}
+    for tool, issues in issues_by_tool:
+        for issue in issues:
+            file_path = str(getattr(issue, "file", "") or "")
+            file_path = paths.get(file_path, file_path)
+            code = issue_code(issue)
+            get_severity = getattr(issue, "get_severity", None)
+            severity = get_severity() if get_severity else SeverityLevel.WARNING
+            fixable = issue_fixable(issue)
+            key = (file_path, tool, code)
+            item = tasks.get(key)
+            if item is None:
+                item = tasks[key] = PlanItem(
+                    file=file_path,
+                    tool=tool,
+                    code=code,
+                    severity=severity,
+                    fixable=fixable,
+                    owners=owners_for(file_path, codeowners or []),
+                )
+            elif _SEVERITY_RANK[severity] < _SEVERITY_RANK[item.severity]:
+                item.severity = severity
+            item.fixable = item.fixable and fixable
+            item.issues += 1
+            item.minutes += issue_effort_minutes(tool, issue, effort_overrides)
+
+    plan = CleanupPlan(budget_minutes=budget_minutes)
+    remaining = budget_minutes
+    for item in sorted(tasks.values(), key=_priority):
+        if remaining is None or item.minutes <= remaining:
+            plan.items.append(item)
+            if remaining is not None:
+                remaining -= item.minutes
+        else:
+            plan.deferred.append(item)
+    return plan
+
+
+def _owner_slug(owner: str) -> str:
+    """Turn an owner into a file name stem.
+
+    Args:
+        owner: Owner such as ``@org/team`` or ``dev@example.com``.
+
+    Returns:
+        str: Safe file name stem.
+    """
+    slug = re.sub(r"[^A-Za-z0-9._-]+", "-", owner.lstrip("@")).strip("-.")
+    return slug or UNOWNED
+
+
+def render_task_list(owner: str, items: Sequence[PlanItem]) -> str:
+    """Render planned tasks as a Markdown task list.
+
+    Args:
+        owner: Owner the list is for.
+        items: Tasks in plan order.
+
+    Returns:
+        str: Markdown document.
+    """
+    total = format_effort(sum(item.minutes for item in items))
+    lines = [f"# Cleanup plan: {owner}", "", f"Estimated effort: {total}", ""]
+    for item in items:
+        rule = f"{item.tool} {item.code}".strip()
+        noun = "issue" if item.issues == 1 else "issues"
+        detail = f"{item.issues} {noun}, {format_effort(item.minutes)}"
+        if item.fixable:
+            detail += ", auto-fixable with `lintro format`"
+        lines.append(f"- [ ] `{item.file}`: {rule} ({detail})")
+    lines.append("")
+    return "\n".join(lines)
+
+
+def write_owner_task_lists(plan: CleanupPlan, directory: str | Path) -> list[Path]:
+    """Write one Markdown task list per owner.
+
+    Args:
+        plan: Cleanup plan to split.
+        directory: Directory for the task lists; created if missing.
+
+    Returns:
+        list[Path]: Written files, in owner order.
+    """
+    out_dir = Path(directory)
+    out_dir.mkdir(parents=True, exist_ok=True)
+    written: list[Path] = []
+    for owner, items in sorted(plan.by_owner().items()):
+        path = out_dir / f"{_owner_slug(owner)}.md"
+        path.write_text(render_task_list(owner, items), encoding="utf-8")
+        written.append(path)
+    return written
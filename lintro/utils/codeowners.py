@@ -0,0 +1,229 @@
+"""Owners of files according to the repository's CODEOWNERS file.
+
+Large organisations route lint debt to the teams that own the code:
+``lintro check --group-by owner`` subtotals issues per owning team, and
+``--owner @org/backend`` or ``--filter 'owner=@org/backend'`` keeps only the
+issues in files a team owns.
+
+The file is looked up where GitHub looks for it (``.github/CODEOWNERS``,
+then ``CODEOWNERS``, then ``docs/CODEOWNERS``) in the working directory or
+the nearest parent that has one. Patterns follow GitHub's rules: the last
+matching line wins, a line without owners leaves files unowned, patterns
+without a slash match at any depth, and ``docs/*`` covers only the files
+directly in ``docs``. GitLab section headers (``[Section]``) are skipped, so
+their rules apply as if unsectioned.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from dataclasses import dataclass
+from functools import lru_cache
+from pathlib import Path
+
+# Locations of the CODEOWNERS file, in GitHub's order of precedence
+CODEOWNERS_LOCATIONS: tuple[str, ...] = (
+    ".github/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+)
+
+# Group label of files no rule assigns an owner
+UNOWNED = "(unowned)"
+
+_SECTION_RE = re.compile(r"^\^?\[[^\]]*\]")
+
+
+@dataclass(frozen=True)
+class OwnerRule:
+    """One line of a CODEOWNERS file.
+
+    Attributes:
+        pattern: Path pattern as written.
+        owners: Owners of matching files; empty to leave them unowned.
+        regex: Compiled pattern, matched against root-relative POSIX paths.
+    """
+
+    pattern: str
+    owners: tuple[str, ...]
+    regex: re.Pattern[str]
+
+
+def compile_pattern(pattern: str) -> re.Pattern[str]:
+    """Compile a CODEOWNERS pattern.
+
+    Args:
+        pattern: Pattern such as ``*.js``, ``/docs/``, ``apps/`` or
+            ``**/logs``.
+
+    Returns:
+        re.Pattern[str]: Regex matching root-relative POSIX paths.
+    """
+    directory = pattern.endswith("/")
+    body = pattern.strip("/")
+    anchored = pattern.startswith("/") or "/" in body
+    parts: list[str] = []
+    i = 0
+    while i < len(body):
+        if body.startswith("**/", i):
+            parts.append("(?:.*/)?")
+            i += 3
+        elif body.startswith("**", i):
+            parts.append(".*")
+            i += 2
+        elif body[i] == "*":
+            parts.append("[^/]*")
+            i += 1
+        elif body[i] == "?":
+            parts.append("[^/]")
+            i += 1
+        else:
+            parts.append(re.escape(body[i]))
+            i += 1
+    prefix = "" if anchored else "(?:.*/)?"
+    if directory:
+        suffix = "/.*"
+    elif body.endswith("/*"):
+        # docs/* owns the files in docs, not those in its subdirectories
+        suffix = ""
+    else:
+        suffix = "(?:/.*)?"
+    return re.compile(f"^{prefix}{''.join(parts)}{suffix}$")
+
+
+@dataclass(frozen=True)
+class CodeOwners:
+    """Parsed CODEOWNERS file.
+
+    Attributes:
+        root: Directory the patterns are relative to.
+        rules: Rules in file order.
+    """
+
+    root: Path
+    rules: tuple[OwnerRule, ...]
+
+    def owners_of(self, path: str) -> tuple[str, ...]:
+        """Find the owners of a file.
+
+        Args:
+            path: File path, absolute or relative to the working directory.
+
+        Returns:
+            tuple[str, ...]: Owners from the last matching rule; empty if no
+            rule matches, the rule names no owners or the file lies outside
+            the root.
+        """
+        relative = os.path.relpath(os.path.abspath(path), self.root)
+        relative = relative.replace(os.sep, "/")
+        if relative.startswith("../"):
+            return ()
+        for rule in reversed(self.rules):
+            if rule.regex.match(relative):
+                return rule.owners
+        return ()
+
+
+def parse_codeowners(text: str, root: str | Path) -> CodeOwners:
+    """Parse the contents of a CODEOWNERS file.
+
+    Args:
+        text: File contents.
+        root: Directory the patterns are relative to.
+
+    Returns:
+        CodeOwners: Rules of the file.
+
+    Examples:
+        >>> owners = parse_codeowners("*.py @org/python\\n", "/repo")
+        >>> owners.owners_of("/repo/src/app.py")
+        ('@org/python',)
+    """
+    rules: list[OwnerRule] = []
+    for raw in text.splitlines():
+        line = raw.split(" #", 1)[0].strip()
+        if not line or line.startswith("#") or _SECTION_RE.match(line):
+            continue
+        pattern, *owners = line.split()
+        rules.append(OwnerRule(pattern, tuple(owners), compile_pattern(pattern)))
+    return CodeOwners(root=Path(root).resolve(), rules=tuple(rules))
+
+
+def _locate(start: str | Path) -> tuple[Path, Path] | None:
+    """Find the CODEOWNERS file that applies to a directory, with its root.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        tuple[Path, Path] | None: Directory the patterns are relative to and
+        the file, or None if no directory has one.
+    """
+    directory = Path(start).resolve()
+    for candidate in (directory, *directory.parents):
+        for location in CODEOWNERS_LOCATIONS:
+            path = candidate / location
+            if path.is_file():
+                return candidate, path
+    return None
+
+
+def find_codeowners(start: str | Path) -> Path | None:
+    """Find the CODEOWNERS file that applies to a directory.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        Path | None: The file, or None if no directory has one.
+    """
+    found = _locate(start)
+    return found[1] if found else None
+
+
+@lru_cache(maxsize=8)
+def load_codeowners(start: str) -> CodeOwners | None:
+    """Read the CODEOWNERS file that applies to a directory.
+
+    Args:
+        start: Directory to search from.
+
+    Returns:
+        CodeOwners | None: Parsed rules, or None without a readable file.
+    """
+    found = _locate(start)
+    if found is None:
+        return None
+    root, path = found
+    try:
+        text = path.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError):
+        return None
+    return parse_codeowners(text, root)
+
+
+def file_owners(path: str) -> tuple[str, ...]:
+    """Find the owners of a file from the working directory's CODEOWNERS.
+
+    Args:
+        path: File path, absolute or relative to the working directory.
+
+    Returns:
+        tuple[str, ...]: Owners of the file; empty without a CODEOWNERS file
+        or an owning rule.
+    """
+    owners = load_codeowners(os.getcwd())
+    return owners.owners_of(path) if owners else ()
+
+
+def owner_label(path: str) -> str:
+    """Name the group a file is listed under when grouping by owner.
+
+    Args:
+        path: File path, absolute or relative to the working directory.
+
+    Returns:
+        str: Owners separated by spaces, or UNOWNED.
+    """
+    return " ".join(file_owners(path)) or UNOWNED
@@ -246,13 +246,43 @@ def load_ruff_config() -> dict[str, Any]:
     return config
 
 
-def load_bandit_config() -> dict[str, Any]:
-    """Load bandit configuration from pyproject.toml.
+def load_bandit_config(base_dir: Path | None = None) -> dict[str, Any]:
+    """Load bandit configuration from pyproject.toml or a .bandit file.
+
+    Bandit only reads ``.bandit`` when scanning directories recursively, so
+    the ``[bandit]`` section is loaded here to apply its thresholds to the
+    file lists lintro passes.
+
+    Args:
+        base_dir: Directory to search for ``.bandit``.
+            Defaults to the current working directory.
 
     Returns:
         dict[str, Any]: Bandit configuration dictionary.
     """
-    return load_tool_config_from_pyproject("bandit")
+    config = load_tool_config_from_pyproject("bandit")
+    if config:
+        return config
+
+    config_path = (base_dir or Path.cwd()) / ".bandit"
+    if not config_path.is_file():
+        return {}
+    try:
+        parser = configparser.ConfigParser()
+        parser.read(config_path)
+    except (OSError, configparser.Error) as e:
+        logger.warning(f"Failed to load bandit config from .bandit: {e}")
+        return {}
+    if "bandit" not in parser:
+        return {}
+
+    ini_config: dict[str, Any] = dict(parser["bandit"])
+    exclude = ini_config.pop("exclude", None)
+    if exclude:
+        ini_config["exclude_dirs"] = [
+            part.strip().rstrip("/") for part in exclude.split(",") if part.strip()
+        ]
+    return ini_config
 
 
 def load_pydoclint_config() -> dict[str, Any]:
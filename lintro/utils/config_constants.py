@@ -92,13 +92,31 @@ GLOBAL_SETTINGS: dict[str, dict[str, Any]] = {
 DEFAULT_TOOL_PRIORITIES: dict[str, int] = {
     ToolName.BLACK: 15,
     ToolName.RUFF: 20,
+    ToolName.BIOME: 25,
     ToolName.OXFMT: 25,
+    ToolName.CLANG_FORMAT: 25,
+    ToolName.GOFMT: 25,
+    ToolName.BUF: 25,
+    ToolName.TERRAFORM_FMT: 25,
+    ToolName.STYLUA: 25,
     ToolName.MARKDOWNLINT: 30,
     ToolName.YAMLLINT: 35,
     ToolName.BANDIT: 45,
     ToolName.HADOLINT: 50,
     ToolName.OXLINT: 50,
+    ToolName.ESLINT: 50,
+    ToolName.STYLELINT: 50,
+    ToolName.TFLINT: 50,
+    ToolName.CLANG_TIDY: 50,
+    ToolName.GOLANGCI_LINT: 50,
+    ToolName.CHECKOV: 50,
+    ToolName.ANSIBLE_LINT: 50,
+    ToolName.RUBOCOP: 50,
+    ToolName.KTLINT: 50,
+    ToolName.LUACHECK: 50,
     ToolName.ACTIONLINT: 55,
+    ToolName.TYPOS: 60,
+    ToolName.VALE: 60,
     ToolName.MYPY: 82,
     ToolName.TSC: 82,
     ToolName.PYTEST: 100,
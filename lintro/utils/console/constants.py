@@ -25,6 +25,24 @@ TOOL_EMOJIS: dict[str, str] = {
     "bandit": "🔧",
     "oxlint": "⚡",
     "oxfmt": "✨",
+    "biome": "🌿",
+    "eslint": "🔎",
+    "stylelint": "🎨",
+    "typos": "🔤",
+    "vale": "📜",
+    "terraform_fmt": "🟪",
+    "tflint": "🧱",
+    "clang_format": "🔩",
+    "clang_tidy": "🔬",
+    "gofmt": "🐹",
+    "golangci_lint": "🦫",
+    "buf": "🧬",
+    "checkov": "🔐",
+    "ansible_lint": "🐮",
+    "rubocop": "💎",
+    "ktlint": "🟣",
+    "stylua": "🌙",
+    "luacheck": "🌘",
     "prettier": "💅",
 }
 DEFAULT_EMOJI: str = "🔧"
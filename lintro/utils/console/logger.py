@@ -10,7 +10,7 @@ import re
 import threading
 from collections.abc import Sequence
 from pathlib import Path
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import click
 from loguru import logger
@@ -30,6 +30,9 @@ from lintro.utils.display_helpers import (
     print_final_status_format,
 )
 
+if TYPE_CHECKING:
+    from lintro.utils.effort import EffortEstimate
+
 
 class ThreadSafeConsoleLogger:
     """Thread-safe logger for console output formatting and display.
@@ -45,22 +48,23 @@ class ThreadSafeConsoleLogger:
             run_dir: Optional run directory path for output location display.
         """
         self.run_dir = run_dir
+        # While muted, output is only tracked for console.log
+        self.muted = False
         self._messages: list[str] = []
         self._lock = threading.Lock()
 
     def console_output(self, text: str, color: str | None = None) -> None:
         """Display text on console and track for console.log.
 
-        Thread-safe: Uses lock when appending to message list.
+        Thread-safe: Uses lock when appending to message list. While the
+        logger is muted, the text is only tracked.
 
         Args:
             text: Text to display.
             color: Optional color for the text.
         """
-        if color:
-            click.echo(click.style(text, fg=color))
-        else:
-            click.echo(text)
+        if not self.muted:
+            click.echo(click.style(text, fg=color) if color else text)
 
         # Track for console.log (thread-safe)
         with self._lock:
@@ -156,12 +160,14 @@ class ThreadSafeConsoleLogger:
         self,
         action: Action,
         tool_results: Sequence[object],
+        effort: EffortEstimate | None = None,
     ) -> None:
         """Print the execution summary for all tools.
 
         Args:
             action: The action being performed.
             tool_results: The list of tool results.
+            effort: Remediation-effort estimate shown in the totals table.
         """
         # Add separation before Execution Summary
         self.console_output(text="")
@@ -261,6 +267,7 @@ class ThreadSafeConsoleLogger:
                 severity_errors=sev_errors,
                 severity_warnings=sev_warnings,
                 severity_info=sev_info,
+                effort_minutes=effort.total_minutes if effort else None,
             )
             self._print_ascii_art(total_issues=total_for_art)
             logger.debug(
@@ -268,6 +275,21 @@ class ThreadSafeConsoleLogger:
                 + (" and failures" if any_failed else ""),
             )
 
+    def print_summary_only(
+        self,
+        action: Action,
+        tool_results: Sequence[object],
+    ) -> None:
+        """Print the per-tool counts table without totals or ASCII art.
+
+        Used by ``--summary-only`` and by ``--quiet`` when a run fails.
+
+        Args:
+            action: The action being performed.
+            tool_results: The list of tool results.
+        """
+        self._print_summary_table(action=action, tool_results=tool_results)
+
     def _print_summary_table(
         self,
         action: Action | str,
@@ -298,6 +320,7 @@ class ThreadSafeConsoleLogger:
         severity_errors: int = 0,
         severity_warnings: int = 0,
         severity_info: int = 0,
+        effort_minutes: int | None = None,
     ) -> None:
         """Print the totals summary table for the run.
 
@@ -310,6 +333,7 @@ class ThreadSafeConsoleLogger:
             severity_errors: Number of issues at ERROR severity.
             severity_warnings: Number of issues at WARNING severity.
             severity_info: Number of issues at INFO severity.
+            effort_minutes: Estimated minutes to fix all issues.
         """
         from lintro.utils.summary_tables import print_totals_table
 
@@ -323,6 +347,7 @@ class ThreadSafeConsoleLogger:
             severity_errors=severity_errors,
             severity_warnings=severity_warnings,
             severity_info=severity_info,
+            effort_minutes=effort_minutes,
         )
 
     def _print_final_status(
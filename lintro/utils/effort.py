@@ -0,0 +1,192 @@
+"""Remediation-effort estimation for reported issues.
+
+Each issue is assigned an estimated number of minutes to fix, which is summed
+per file and per tool so a run can be reported as e.g. "~14 hours of lint
+debt". Estimates come from ``output.effort`` in the Lintro config, looked up
+in this order:
+
+1. ``<tool>:<code>`` (e.g. ``ruff:E501``)
+2. ``<code>`` (e.g. ``E501``)
+3. ``<tool>`` (e.g. ``mypy``)
+4. Built-in defaults: auto-fixable issues, then per-tool defaults
+5. ``error`` / ``warning`` / ``info``, falling back to built-in severity
+   defaults
+"""
+
+from __future__ import annotations
+
+from collections.abc import Mapping, Sequence
+from dataclasses import dataclass, field
+
+from lintro.enums.severity_level import SeverityLevel
+
+# Minutes per issue by severity when nothing more specific applies
+DEFAULT_SEVERITY_EFFORT_MINUTES: dict[SeverityLevel, int] = {
+    SeverityLevel.ERROR: 15,
+    SeverityLevel.WARNING: 5,
+    SeverityLevel.INFO: 2,
+}
+
+# Minutes per issue that the tool can fix automatically
+FIXABLE_EFFORT_MINUTES: int = 1
+
+# Built-in per-tool defaults: formatter output is mechanical, while
+# security findings usually need investigation
+DEFAULT_TOOL_EFFORT_MINUTES: dict[str, int] = {
+    "bandit": 30,
+    "black": 1,
+    "cargo_audit": 30,
+    "cargo_deny": 30,
+    "cargo_geiger": 30,
+    "cargo_machete": 1,
+    "cargo_outdated": 30,
+    "checkov": 30,
+    "gitleaks": 30,
+    "oxfmt": 1,
+    "prettier": 1,
+    "rustfmt": 1,
+    "semgrep": 30,
+    "shfmt": 1,
+}
+
+
+@dataclass
+class EffortEstimate:
+    """Estimated remediation effort for a run.
+
+    Attributes:
+        total_minutes: Estimated minutes to fix every issue.
+        by_file: Estimated minutes per file path.
+        by_tool: Estimated minutes per tool name.
+        issues_by_file: Number of issues per file path.
+    """
+
+    total_minutes: int = 0
+    by_file: dict[str, int] = field(default_factory=dict)
+    by_tool: dict[str, int] = field(default_factory=dict)
+    issues_by_file: dict[str, int] = field(default_factory=dict)
+
+    def files_by_effort(self) -> list[tuple[str, int]]:
+        """Return files ordered by estimated effort, largest first.
+
+        Returns:
+            list[tuple[str, int]]: (file, minutes) pairs; ties sort by path.
+        """
+        return sorted(self.by_file.items(), key=lambda item: (-item[1], item[0]))
+
+
+def issue_code(issue: object) -> str:
+    """Return an issue's rule code, honoring its display field mapping.
+
+    Args:
+        issue: Parsed issue object.
+
+    Returns:
+        str: Rule code, or an empty string if the issue has none.
+    """
+    field_map = getattr(issue, "DISPLAY_FIELD_MAP", {})
+    code = getattr(issue, field_map.get("code", "code"), None)
+    return str(code) if code else ""
+
+
+def issue_fixable(issue: object) -> bool:
+    """Return whether the tool can fix an issue automatically.
+
+    Args:
+        issue: Parsed issue object.
+
+    Returns:
+        bool: True if the issue is marked fixable.
+    """
+    field_map = getattr(issue, "DISPLAY_FIELD_MAP", {})
+    return bool(getattr(issue, field_map.get("fixable", "fixable"), False))
+
+
+def issue_effort_minutes(
+    tool_name: str,
+    issue: object,
+    overrides: Mapping[str, int] | None = None,
+) -> int:
+    """Estimate the minutes needed to fix a single issue.
+
+    Args:
+        tool_name: Name of the tool that reported the issue.
+        issue: Parsed issue object.
+        overrides: Configured ``output.effort`` minutes keyed by rule, tool
+            or severity.
+
+    Returns:
+        int: Estimated minutes.
+    """
+    overrides = overrides or {}
+    tool = tool_name.lower()
+    code = issue_code(issue)
+    keys = [f"{tool}:{code}", code] if code else []
+    for key in [*keys, tool]:
+        if key in overrides:
+            return overrides[key]
+
+    if issue_fixable(issue):
+        return FIXABLE_EFFORT_MINUTES
+    if tool in DEFAULT_TOOL_EFFORT_MINUTES:
+        return DEFAULT_TOOL_EFFORT_MINUTES[tool]
+
+    get_severity = getattr(issue, "get_severity", None)
+    severity = get_severity() if get_severity else SeverityLevel.WARNING
+    return overrides.get(
+        severity.value.lower(),
+        DEFAULT_SEVERITY_EFFORT_MINUTES[severity],
+    )
+
+
+def estimate_effort(
+    tool_results: Sequence[object],
+    overrides: Mapping[str, int] | None = None,
+) -> EffortEstimate:
+    """Estimate remediation effort for every issue in a run.
+
+    Args:
+        tool_results: Tool results from the run.
+        overrides: Configured ``output.effort`` minutes.
+
+    Returns:
+        EffortEstimate: Totals per run, file and tool.
+    """
+    estimate = EffortEstimate()
+    for result in tool_results:
+        tool_name = str(getattr(result, "name", ""))
+        for issue in getattr(result, "issues", None) or []:
+            minutes = issue_effort_minutes(tool_name, issue, overrides)
+            file_path = str(getattr(issue, "file", "") or "")
+            estimate.total_minutes += minutes
+            estimate.by_tool[tool_name] = estimate.by_tool.get(tool_name, 0) + minutes
+            if file_path:
+                estimate.by_file[file_path] = (
+                    estimate.by_file.get(file_path, 0) + minutes
+                )
+                estimate.issues_by_file[file_path] = (
+                    estimate.issues_by_file.get(file_path, 0) + 1
+                )
+    return estimate
+
+
+def format_effort(minutes: int) -> str:
+    """Format a number of minutes as a rough human-readable duration.
+
+    Args:
+        minutes: Estimated minutes.
+
+    Returns:
+        str: Duration such as ``~45 minutes``, ``~1.5 hours`` or ``~14 hours``.
+    """
+    if minutes < 60:
+        unit = "minute" if minutes == 1 else "minutes"
+        return f"~{minutes} {unit}"
+    hours = minutes / 60
+    if hours < 10:
+        rounded = round(hours * 2) / 2
+        value = f"{rounded:g}"
+    else:
+        value = str(round(hours))
+    unit = "hour" if value == "1" else "hours"
+    return f"~{value} {unit}"
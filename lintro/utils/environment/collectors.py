@@ -205,8 +205,12 @@ def _find_git_root(start_dir: Path | None = None) -> Path | None:
 
 def collect_project_info() -> ProjectInfo:
     """Collect project detection information."""
+    # Imported here: lintro.utils.vcs imports from this package
+    from lintro.utils.vcs import UnavailableVcs, detect_vcs
+
     cwd = Path.cwd()
     git_root = _find_git_root(cwd)
+    vcs = detect_vcs(cwd)
 
     languages: list[str] = []
     package_managers: dict[str, str] = {}
@@ -271,6 +275,8 @@ def collect_project_info() -> ProjectInfo:
         git_root=str(git_root) if git_root else None,
         languages=sorted(set(languages)),
         package_managers=package_managers,
+        vcs=None if isinstance(vcs, UnavailableVcs) else vcs.name,
+        vcs_fallback=vcs.reason if isinstance(vcs, UnavailableVcs) else None,
     )
 
 
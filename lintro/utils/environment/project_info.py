@@ -13,6 +13,8 @@ class ProjectInfo:
     git_root: str | None
     languages: list[str]
     package_managers: dict[str, str]
+    vcs: str | None = None
+    vcs_fallback: str | None = None
 
     @property
     def section_title(self) -> str:
@@ -24,6 +26,7 @@ class ProjectInfo:
         rows: list[tuple[str, str]] = [
             ("Working Dir", self.working_dir),
             ("Git Root", self.git_root or "(not a git repo)"),
+            ("VCS", self.vcs or f"(unavailable: {self.vcs_fallback or 'unknown'})"),
             ("Languages", ", ".join(self.languages) or "(none detected)"),
         ]
         for pm, manifest in sorted(self.package_managers.items()):
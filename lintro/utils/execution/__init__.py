@@ -1,23 +1,28 @@
 """Execution utilities for tool execution.
 
 This package provides utilities for tool execution including exit codes,
-tool configuration, and parallel execution.
+tool configuration, and parallel execution. Exports are imported on first
+access so that single-file runs do not load the parallel executor.
 """
 
-from lintro.utils.execution.exit_codes import (
-    DEFAULT_EXIT_CODE_FAILURE,
-    DEFAULT_EXIT_CODE_SUCCESS,
-    DEFAULT_REMAINING_COUNT,
-    aggregate_tool_results,
-    determine_exit_code,
-)
-from lintro.utils.execution.parallel_executor import run_tools_parallel
-from lintro.utils.execution.tool_configuration import (
-    configure_tool_for_execution,
-    get_tool_display_name,
-    get_tool_lookup_keys,
-    get_tools_to_run,
-)
+from __future__ import annotations
+
+import importlib
+from typing import Any
+
+# Public names mapped to the module defining them
+_EXPORTS: dict[str, str] = {
+    "DEFAULT_EXIT_CODE_FAILURE": "exit_codes",
+    "DEFAULT_EXIT_CODE_SUCCESS": "exit_codes",
+    "DEFAULT_REMAINING_COUNT": "exit_codes",
+    "aggregate_tool_results": "exit_codes",
+    "configure_tool_for_execution": "tool_configuration",
+    "determine_exit_code": "exit_codes",
+    "get_tool_display_name": "tool_configuration",
+    "get_tool_lookup_keys": "tool_configuration",
+    "get_tools_to_run": "tool_configuration",
+    "run_tools_parallel": "parallel_executor",
+}
 
 __all__ = [
     "DEFAULT_EXIT_CODE_FAILURE",
@@ -31,3 +36,21 @@ __all__ = [
     "get_tools_to_run",
     "run_tools_parallel",
 ]
+
+
+def __getattr__(name: str) -> Any:
+    """Import an exported name on first access.
+
+    Args:
+        name: Attribute name.
+
+    Returns:
+        Any: The exported object.
+
+    Raises:
+        AttributeError: If the name is not exported.
+    """
+    if name not in _EXPORTS:
+        raise AttributeError(f"module {__name__!r} has no attribute {name!r}")
+    module = importlib.import_module(f"{__name__}.{_EXPORTS[name]}")
+    return getattr(module, name)
@@ -0,0 +1,319 @@
+"""Audit log of fix runs.
+
+When ``execution.audit_log`` is set, every ``lintro format`` run that writes
+to the project appends one JSON line to it recording who
+ran it, when, on which commit, and which fixer changed which file, with the
+file's SHA-256 before and after. Each line also stores the SHA-256 of the
+line before it, so removing or editing an earlier entry breaks the chain
+and is reported by ``lintro audit log --verify``.
+"""
+
+from __future__ import annotations
+
+import datetime
+import getpass
+import hashlib
+import json
+import os
+import platform
+import sys
+import uuid
+from collections.abc import Iterator, Sequence
+from contextlib import contextmanager
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import IO, Any
+
+from lintro import __version__
+from lintro.utils.environment.collectors import detect_ci_environment
+from lintro.utils.execution.fix_pipeline import FileFix
+from lintro.utils.timestamps import parse_timestamp, utc_timestamp
+from lintro.utils.vcs import detect_vcs
+
+
+@dataclass
+class AuditEntry:
+    """One fix run in the audit log.
+
+    Attributes:
+        id: Unique run identifier.
+        timestamp: When the run finished, in UTC ISO 8601.
+        user: Operating system user that ran lintro.
+        git_user: Configured git user email, if any.
+        host: Machine the run happened on.
+        ci: CI platform and job details, if run in CI.
+        command: Command line of the run.
+        commit: Commit checked out during the run, if any.
+        lintro_version: Lintro version.
+        tools: Fixers that ran.
+        exit_code: Exit code of the run.
+        files: Changes made, one per fixer and file.
+        previous: SHA-256 of the preceding log line; None for the first.
+    """
+
+    id: str
+    timestamp: str
+    user: str
+    git_user: str | None
+    host: str
+    ci: dict[str, str] | None
+    command: list[str]
+    commit: str | None
+    lintro_version: str
+    tools: list[str]
+    exit_code: int
+    files: list[FileFix] = field(default_factory=list)
+    previous: str | None = None
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> AuditEntry:
+        """Build an entry from its logged form.
+
+        Args:
+            data: Decoded log line.
+
+        Returns:
+            AuditEntry: Parsed entry.
+        """
+        files = [FileFix(**fix) for fix in data.get("files", [])]
+        return cls(**{**data, "files": files})
+
+    @property
+    def finished_at(self) -> datetime.datetime:
+        """Return the timestamp as a timezone-aware datetime.
+
+        Returns:
+            datetime.datetime: Finish time in UTC.
+        """
+        return parse_timestamp(self.timestamp)
+
+
+def _current_user() -> str:
+    """Return the name of the user running lintro.
+
+    Returns:
+        str: Login name, or "unknown" if it cannot be determined.
+    """
+    try:
+        return getpass.getuser()
+    except (KeyError, OSError):
+        # No passwd entry and no LOGNAME/USER, e.g. in some containers
+        return "unknown"
+
+
+def _project_path(path: str) -> str:
+    """Show a path relative to the working directory when it lies inside it.
+
+    Args:
+        path: File path.
+
+    Returns:
+        str: Relative POSIX path, or the absolute path for outside files.
+    """
+    absolute = os.path.abspath(path)
+    root = os.getcwd()
+    if os.path.commonpath([absolute, root]) != root:
+        return absolute
+    return Path(os.path.relpath(absolute, root)).as_posix()
+
+
+def build_entry(
+    *,
+    tools: Sequence[str],
+    fixes: Sequence[FileFix],
+    exit_code: int,
+) -> AuditEntry:
+    """Describe a finished fix run.
+
+    Args:
+        tools: Fixers that ran.
+        fixes: File changes recorded during the run.
+        exit_code: Exit code of the run.
+
+    Returns:
+        AuditEntry: Entry ready to append.
+    """
+    ci = detect_ci_environment()
+    vcs = detect_vcs()
+    return AuditEntry(
+        id=uuid.uuid4().hex,
+        timestamp=utc_timestamp(),
+        user=_current_user(),
+        git_user=vcs.user_email(),
+        host=platform.node(),
+        ci={"name": ci.name, **ci.details} if ci else None,
+        command=list(sys.argv),
+        commit=vcs.head_commit(),
+        lintro_version=__version__,
+        tools=list(tools),
+        exit_code=exit_code,
+        files=[
+            FileFix(
+                file=_project_path(fix.file),
+                tool=fix.tool,
+                before=fix.before,
+                after=fix.after,
+            )
+            for fix in fixes
+        ],
+    )
+
+
+def _line_digest(line: str) -> str:
+    """Hash one log line as stored.
+
+    Args:
+        line: Log line without its newline.
+
+    Returns:
+        str: SHA-256 hex digest.
+    """
+    return hashlib.sha256(line.encode("utf-8")).hexdigest()
+
+
+def _read_lines(path: str) -> list[str]:
+    """Read the non-empty lines of a log file.
+
+    Args:
+        path: Log file.
+
+    Returns:
+        list[str]: Lines without newlines; empty if the file does not exist.
+    """
+    try:
+        text = Path(path).read_text(encoding="utf-8")
+    except FileNotFoundError:
+        return []
+    return [line for line in text.splitlines() if line.strip()]
+
+
+@contextmanager
+def _exclusive(f: IO[str]) -> Iterator[None]:
+    """Hold an exclusive lock on an open log file.
+
+    Runs appending to the same log wait for each other, so two entries never
+    chain to the same line.
+
+    Args:
+        f: Open log file.
+
+    Yields:
+        None: While the lock is held.
+    """
+    if sys.platform == "win32":
+        import msvcrt
+
+        f.seek(0)
+        msvcrt.locking(f.fileno(), msvcrt.LK_LOCK, 1)
+        try:
+            yield
+        finally:
+            f.seek(0)
+            msvcrt.locking(f.fileno(), msvcrt.LK_UNLCK, 1)
+    else:
+        import fcntl
+
+        fcntl.flock(f.fileno(), fcntl.LOCK_EX)
+        try:
+            yield
+        finally:
+            fcntl.flock(f.fileno(), fcntl.LOCK_UN)
+
+
+def append_entry(path: str, entry: AuditEntry) -> AuditEntry:
+    """Append an entry, chaining it to the last line of the log.
+
+    The log is locked from reading its last line until the entry is written.
+
+    Args:
+        path: Log file; created with its directory if missing.
+        entry: Entry to append.
+
+    Returns:
+        AuditEntry: The entry with ``previous`` set.
+    """
+    Path(path).parent.mkdir(parents=True, exist_ok=True)
+    with open(path, "a+", encoding="utf-8") as f, _exclusive(f):
+        f.seek(0)
+        lines = [line for line in f.read().splitlines() if line.strip()]
+        entry.previous = _line_digest(lines[-1]) if lines else None
+        f.write(json.dumps(asdict(entry), sort_keys=True) + "\n")
+        f.flush()
+    return entry
+
+
+def read_entries(path: str) -> list[AuditEntry]:
+    """Read every entry of a log, oldest first.
+
+    Args:
+        path: Log file.
+
+    Returns:
+        list[AuditEntry]: Entries; empty if the log does not exist.
+
+    Raises:
+        ValueError: If a line is not a valid entry.
+    """
+    entries: list[AuditEntry] = []
+    for number, line in enumerate(_read_lines(path), start=1):
+        try:
+            entries.append(AuditEntry.from_dict(json.loads(line)))
+        except (json.JSONDecodeError, TypeError) as e:
+            raise ValueError(f"{path}:{number}: invalid audit entry: {e}") from e
+    return entries
+
+
+def verify_chain(path: str) -> list[int]:
+    """Find entries whose link to the previous line is broken.
+
+    Args:
+        path: Log file.
+
+    Returns:
+        list[int]: 1-based line numbers that do not follow the line before
+        them; empty if the log is intact.
+    """
+    broken: list[int] = []
+    previous: str | None = None
+    for number, line in enumerate(_read_lines(path), start=1):
+        try:
+            intact = json.loads(line).get("previous") == previous
+        except (json.JSONDecodeError, AttributeError):
+            intact = False
+        if not intact:
+            broken.append(number)
+        previous = _line_digest(line)
+    return broken
+
+
+def filter_entries(
+    entries: Sequence[AuditEntry],
+    *,
+    since: datetime.datetime | None = None,
+    tool: str | None = None,
+    file: str | None = None,
+    user: str | None = None,
+) -> list[AuditEntry]:
+    """Select entries matching every given criterion.
+
+    Args:
+        entries: Entries to search.
+        since: Keep runs that finished at or after this time.
+        tool: Keep runs in which this fixer changed a file.
+        file: Keep runs that changed this file (relative to the project).
+        user: Keep runs by this OS user or git email.
+
+    Returns:
+        list[AuditEntry]: Matching entries in their original order.
+    """
+    if since is not None and since.tzinfo is None:
+        since = since.replace(tzinfo=datetime.UTC)
+    target = _project_path(file) if file else None
+    return [
+        entry
+        for entry in entries
+        if (since is None or entry.finished_at >= since)
+        and (tool is None or any(f.tool == tool for f in entry.files))
+        and (target is None or any(f.file == target for f in entry.files))
+        and (user is None or user in (entry.user, entry.git_user))
+    ]
@@ -0,0 +1,93 @@
+"""Quiet check runs for commands that post-process results.
+
+Commands such as ``lintro plan`` and ``lintro suggest`` need check results
+without the console output of ``lintro check``.
+"""
+
+from __future__ import annotations
+
+from lintro.config.config_hierarchy import find_nested_config_dirs, nested_severity
+from lintro.config.config_loader import get_config
+from lintro.enums.action import Action
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.labels import DirectoryLabels
+from lintro.utils.execution.parallel_executor import run_tools_parallel
+from lintro.utils.execution.path_overrides import (
+    PathOverrides,
+    apply_path_overrides,
+)
+from lintro.utils.execution.severity_filter import (
+    apply_label_severity,
+    apply_min_severity,
+    apply_severity_overrides,
+)
+from lintro.utils.execution.suppressions import apply_suppressions
+from lintro.utils.execution.tool_configuration import (
+    apply_label_scopes,
+    apply_nested_config_scopes,
+    apply_override_scopes,
+    get_tools_to_run,
+)
+from lintro.utils.unified_config import UnifiedConfigManager
+
+
+def run_checks(
+    paths: list[str],
+    tools: str | None,
+    exclude: str | None,
+) -> list[ToolResult]:
+    """Run the selected checks without printing their output.
+
+    Per-path overrides, directory labels, severity overrides,
+    ``lintro: ignore`` suppressions and ``output.min_severity`` are applied
+    as in ``lintro check``.
+
+    Args:
+        paths: Paths to check.
+        tools: Comma-separated tool names, or None for all enabled tools.
+        exclude: Comma-separated exclude patterns.
+
+    Returns:
+        list[ToolResult]: Results of the tools that ran.
+    """
+    lintro_config = get_config()
+    tools_result = get_tools_to_run(tools, Action.CHECK)
+    nested_dirs = find_nested_config_dirs(paths)
+    tool_scopes = apply_nested_config_scopes(tools_result, paths, nested_dirs)
+    apply_label_scopes(tools_result, paths, tool_scopes)
+    apply_override_scopes(tools_result, paths, tool_scopes, nested_dirs)
+    if not tools_result.to_run:
+        return []
+    results = run_tools_parallel(
+        tools_to_run=tools_result.to_run,
+        paths=paths,
+        action=Action.CHECK,
+        config_manager=UnifiedConfigManager(),
+        tool_option_dict={},
+        exclude=exclude,
+        include_venv=False,
+        post_tools=set(),
+        max_workers=lintro_config.execution.max_workers,
+        tool_scopes=tool_scopes,
+    )
+    min_severity = lintro_config.output.min_severity
+    directory_labels = DirectoryLabels.from_config(lintro_config)
+    path_overrides = PathOverrides.from_config(lintro_config, nested_dirs)
+    subtree_severity = nested_severity(nested_dirs)
+    return [
+        apply_min_severity(
+            apply_suppressions(
+                apply_label_severity(
+                    apply_severity_overrides(
+                        apply_path_overrides(r, path_overrides),
+                        lintro_config.severity,
+                        subtree_severity,
+                    ),
+                    directory_labels,
+                    lintro_config.label_policies,
+                ),
+            ),
+            min_severity,
+        )
+        for r in results
+    ]
@@ -0,0 +1,328 @@
+"""Checking the fenced code blocks of Markdown files.
+
+With ``lintro check --code-blocks`` (or ``execution.code_blocks``), the
+examples in documentation are checked by the tools that check the same
+languages in source files:
+
+1. Fenced code blocks (```` ```python ````, ``~~~rust``) are extracted from
+   Markdown files. Their language picks a file suffix, such as ``.py`` for
+   ``python``, ``py`` and ``python3``.
+2. Each block is written to a temporary file that tools check like any
+   other file matching their patterns.
+3. Issues are mapped back to the Markdown file and the line in it.
+
+Blocks in unknown languages are skipped, as are blocks whose info string
+has an ``ignore`` or ``no-lint`` attribute (```` ```rust,ignore ````).
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import fnmatch
+import os
+import re
+import tempfile
+from collections.abc import Iterator
+from contextlib import contextmanager
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from loguru import logger
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.plugins.file_discovery import discover_files
+
+if TYPE_CHECKING:
+    from lintro.plugins.base import BaseToolPlugin
+
+MARKDOWN_PATTERNS: list[str] = ["*.md", "*.markdown", "*.mdx"]
+
+# File suffix of each code block language
+LANGUAGE_SUFFIXES: dict[str, str] = {
+    "python": ".py",
+    "python3": ".py",
+    "py": ".py",
+    "rust": ".rs",
+    "rs": ".rs",
+    "bash": ".sh",
+    "sh": ".sh",
+    "shell": ".sh",
+    "zsh": ".sh",
+    "javascript": ".js",
+    "js": ".js",
+    "jsx": ".jsx",
+    "typescript": ".ts",
+    "ts": ".ts",
+    "tsx": ".tsx",
+    "json": ".json",
+    "yaml": ".yaml",
+    "yml": ".yaml",
+    "toml": ".toml",
+    "go": ".go",
+    "ruby": ".rb",
+    "rb": ".rb",
+    "kotlin": ".kt",
+    "kt": ".kt",
+    "sql": ".sql",
+}
+
+# Info string attributes that keep a block from being checked
+SKIP_ATTRIBUTES: frozenset[str] = frozenset({"ignore", "no-lint"})
+
+_FENCE_RE: re.Pattern[str] = re.compile(r"^( {0,3})(`{3,}|~{3,})(.*)$")
+
+
+@dataclass
+class CodeBlock:
+    """A fenced code block of a Markdown file.
+
+    Attributes:
+        markdown: Path of the Markdown file, as reported in issues.
+        language: Language named by the info string, in lower case.
+        line: Line of the opening fence in the Markdown file.
+        source: Code in the block.
+    """
+
+    markdown: str
+    language: str
+    line: int
+    source: str
+
+    @property
+    def suffix(self) -> str | None:
+        """File suffix of the block's language.
+
+        Returns:
+            str | None: The suffix, or None for languages no tool checks.
+        """
+        return LANGUAGE_SUFFIXES.get(self.language)
+
+    def locate(self, line: int) -> int:
+        """Find the Markdown line of a line in the block.
+
+        Args:
+            line: 1-based line in the block; 0 for the block as a whole.
+
+        Returns:
+            int: Line in the Markdown file; the opening fence for line 0.
+        """
+        return self.line + max(line, 0)
+
+
+def _info_words(info: str) -> list[str]:
+    """Split a fence info string into its language and attributes.
+
+    Args:
+        info: Text after the fence, e.g. ``rust,ignore`` or ``{.python}``.
+
+    Returns:
+        list[str]: Lower-case words, the language first.
+    """
+    return [word.lstrip(".").lower() for word in re.split(r"[\s,{}]+", info) if word]
+
+
+def extract_code_blocks(text: str, markdown: str) -> list[CodeBlock]:
+    """Extract the fenced code blocks of a Markdown document.
+
+    A block without a closing fence runs to the end of the document, as in
+    CommonMark.
+
+    Args:
+        text: Markdown text.
+        markdown: Path reported in issues.
+
+    Returns:
+        list[CodeBlock]: Blocks in languages some tool checks, in order.
+    """
+    blocks: list[CodeBlock] = []
+    lines = text.splitlines()
+    index = 0
+    while index < len(lines):
+        opening = _FENCE_RE.match(lines[index])
+        index += 1
+        if opening is None:
+            continue
+        indent, fence, info = opening.groups()
+        if fence.startswith("`") and "`" in info:
+            continue  # Inline code spanning a line, not a fence
+        start = index
+        closing = re.compile(rf"^ {{0,3}}{fence[0]}{{{len(fence)},}}\s*$")
+        while index < len(lines) and not closing.match(lines[index]):
+            index += 1
+        # Lines lose up to as many leading spaces as the fence is indented
+        body = [
+            line[min(len(indent), len(line) - len(line.lstrip(" "))) :]
+            for line in lines[start:index]
+        ]
+        index += 1
+        words = _info_words(info)
+        if not words or SKIP_ATTRIBUTES.intersection(words[1:]):
+            continue
+        block = CodeBlock(
+            markdown=markdown,
+            language=words[0],
+            line=start,
+            source="\n".join(body) + "\n" if body else "",
+        )
+        if block.suffix is not None and block.source.strip():
+            blocks.append(block)
+    return blocks
+
+
+def checks_block(tool: BaseToolPlugin, block: CodeBlock) -> bool:
+    """Tell whether a tool checks a code block.
+
+    Catch-all patterns such as ``*`` do not count: tools that check every
+    file, like the whitespace checker, already checked the Markdown file.
+
+    Args:
+        tool: Tool about to check the blocks.
+        block: Code block.
+
+    Returns:
+        bool: True if one of the tool's patterns matches the block's suffix.
+    """
+    name = f"block{block.suffix}"
+    return any(
+        pattern != "*" and fnmatch.fnmatch(name, pattern)
+        for pattern in tool.definition.file_patterns
+    )
+
+
+def find_code_blocks(tool: BaseToolPlugin, paths: list[str]) -> list[CodeBlock]:
+    """Find the code blocks a tool should check.
+
+    Args:
+        tool: Tool about to check the paths.
+        paths: Paths given to the tool.
+
+    Returns:
+        list[CodeBlock]: Blocks of the Markdown files under the paths, in
+        languages the tool checks, honoring the tool's excludes.
+    """
+    markdown_files = discover_files(
+        paths=paths or ["."],
+        definition=dataclasses.replace(
+            tool.definition,
+            file_patterns=MARKDOWN_PATTERNS,
+        ),
+        exclude_patterns=tool.exclude_patterns,
+        include_venv=tool.include_venv,
+        show_progress=False,
+        prune_dirs=tool.prune_dirs,
+        gitignore=tool.gitignore,
+        symlinks=tool.symlinks,
+    )
+    blocks: list[CodeBlock] = []
+    for markdown in markdown_files:
+        try:
+            text = Path(markdown).read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError) as e:
+            logger.warning(f"Cannot read {markdown}: {e}")
+            continue
+        blocks.extend(
+            block
+            for block in extract_code_blocks(text, os.path.relpath(markdown))
+            if checks_block(tool, block)
+        )
+    return blocks
+
+
+@contextmanager
+def code_block_files(
+    blocks: list[CodeBlock],
+) -> Iterator[tuple[str, dict[str, CodeBlock]]]:
+    """Write code blocks as files into a temporary directory.
+
+    Args:
+        blocks: Code blocks.
+
+    Yields:
+        tuple[str, dict[str, CodeBlock]]: The directory, and the block of
+        each file written to it, keyed by its resolved path.
+    """
+    with tempfile.TemporaryDirectory(prefix="lintro-code-blocks-") as directory:
+        files: dict[str, CodeBlock] = {}
+        for index, block in enumerate(blocks):
+            stem = re.sub(r"\W", "_", Path(block.markdown).stem)
+            target = Path(directory) / f"block{index}_{stem}_{block.line}"
+            target = target.with_name(f"{target.name}{block.suffix}")
+            target.write_text(block.source, encoding="utf-8")
+            files[str(target.resolve())] = block
+        yield directory, files
+
+
+def map_code_block_issues(
+    issues: list[BaseIssue],
+    files: dict[str, CodeBlock],
+) -> list[BaseIssue]:
+    """Point issues in code block files at their Markdown lines.
+
+    Issues in other files are dropped; the regular run reports those.
+
+    Args:
+        issues: Issues the tool reported for the files.
+        files: Code blocks keyed by the resolved path of their file.
+
+    Returns:
+        list[BaseIssue]: Issues with the Markdown file and line set.
+    """
+    mapped: list[BaseIssue] = []
+    for issue in issues:
+        block = files.get(str(Path(issue.file).resolve())) if issue.file else None
+        if block is None:
+            continue
+        issue.file = block.markdown
+        issue.line = block.locate(issue.line)
+        mapped.append(issue)
+    return mapped
+
+
+def check_code_blocks(
+    tool: BaseToolPlugin,
+    paths: list[str],
+    result: ToolResult,
+) -> ToolResult:
+    """Check the code blocks under some paths and add their issues to a result.
+
+    Args:
+        tool: Configured tool that checked the paths.
+        paths: Paths given to the tool.
+        result: Result of checking the paths.
+
+    Returns:
+        ToolResult: The result with the code blocks' issues added.
+    """
+    if result.skipped:
+        return result
+    blocks = find_code_blocks(tool, paths)
+    if not blocks:
+        return result
+    logger.debug(
+        f"[{tool.definition.name}] Checking {len(blocks)} Markdown code block(s)",
+    )
+    with code_block_files(blocks) as (directory, files):
+        # The Markdown files were filtered already; excludes such as "tmp"
+        # and .gitignore rules must not drop the temporary files
+        exclude_patterns, gitignore = tool.exclude_patterns, tool.gitignore
+        tool.exclude_patterns, tool.gitignore = [], False
+        try:
+            block_result = tool.check([directory], {})
+        finally:
+            tool.exclude_patterns, tool.gitignore = exclude_patterns, gitignore
+        mapped = map_code_block_issues(list(block_result.issues or []), files)
+
+    failed_run = not (
+        block_result.skipped or block_result.success or block_result.issues
+    )
+    issues = [*(result.issues or []), *mapped]
+    outputs = [result.output, block_result.output if failed_run else None]
+    return dataclasses.replace(
+        result,
+        success=result.success and not mapped and not failed_run,
+        output="\n".join(o for o in outputs if o) or None,
+        issues=issues,
+        issues_count=len(issues),
+    )
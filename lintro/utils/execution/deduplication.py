@@ -0,0 +1,113 @@
+"""Cross-tool deduplication of reported issues.
+
+Several tools can report the same defect: ruff's ``S`` rules re-implement
+bandit, and tsc, vue-tsc, svelte-check and astro check share TypeScript
+diagnostics. Issues from different tools at the same file and line with the
+same rule are collapsed into the first one reported, which lists the other
+tools in ``also_reported_by``. ``--no-dedupe`` turns this off.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from dataclasses import replace
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.exit_codes import success_after_filter
+
+# ruff's flake8-bandit rules use bandit's test numbers (S101 is B101)
+_RUFF_BANDIT_CODE = re.compile(r"^S(\d{3})$")
+
+
+def canonical_rule(tool_name: str, code: str) -> str:
+    """Normalize a rule code so equivalent rules from different tools match.
+
+    Args:
+        tool_name: Name of the tool that reported the rule.
+        code: Rule code as reported.
+
+    Returns:
+        str: Canonical rule code, e.g. ``B101`` for ruff ``S101`` and
+        ``TS2322`` for svelte-check ``ts-2322``.
+    """
+    normalized = re.sub(r"[^A-Z0-9]", "", code.upper())
+    if tool_name.lower() == "ruff":
+        match = _RUFF_BANDIT_CODE.match(normalized)
+        if match:
+            return f"B{match.group(1)}"
+    return normalized
+
+
+class IssueDeduplicator:
+    """Remove issues already reported by another tool in the same run.
+
+    Results are added in run order; the first tool to report a defect keeps
+    the issue and later duplicates are dropped from their results.
+    """
+
+    def __init__(self) -> None:
+        """Initialize with no issues seen."""
+        self._seen: dict[tuple[str, int, str], tuple[str, BaseIssue]] = {}
+
+    def add(self, result: ToolResult) -> ToolResult:
+        """Record a result's issues and drop those already reported.
+
+        Args:
+            result: Result returned by a tool.
+
+        Returns:
+            ToolResult: Result without duplicate issues, or the original
+            result if it had none.
+        """
+        if result.skipped or not result.issues:
+            return result
+
+        tool = result.name.lower()
+        kept: list[BaseIssue] = []
+        for issue in result.issues:
+            code = issue_code(issue)
+            file_path = getattr(issue, "file", "") or ""
+            line = getattr(issue, "line", 0) or 0
+            if not (code and file_path and line):
+                kept.append(issue)
+                continue
+            key = (
+                os.path.normcase(os.path.abspath(file_path)),
+                line,
+                canonical_rule(tool, code),
+            )
+            first = self._seen.get(key)
+            if first is None:
+                self._seen[key] = (tool, issue)
+                kept.append(issue)
+            elif first[0] == tool:
+                # Repeats within one tool are separate findings
+                kept.append(issue)
+            elif tool not in first[1].also_reported_by:
+                first[1].also_reported_by.append(tool)
+        if len(kept) == len(result.issues):
+            return result
+
+        removed = len(result.issues) - len(kept)
+        issues_count = max(result.issues_count - removed, len(kept))
+        return replace(
+            result,
+            issues=kept,
+            issues_count=issues_count,
+            success=success_after_filter(result, issues_count),
+            formatted_output=None,
+        )
+
+    def add_all(self, results: list[ToolResult]) -> list[ToolResult]:
+        """Deduplicate a list of results in order.
+
+        Args:
+            results: Results in run order.
+
+        Returns:
+            list[ToolResult]: Results without duplicate issues.
+        """
+        return [self.add(result) for result in results]
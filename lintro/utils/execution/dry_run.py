@@ -0,0 +1,423 @@
+"""Dry-run fixing in a scratch copy of the project.
+
+``lintro format --dry-run`` runs every fixer as usual, but inside a temporary
+copy of the project: the repository root, or outside a repository the
+directory holding the Lintro configuration, so the copy keeps the settings
+and every path a run from a subdirectory may reach. Afterwards the copy is
+compared with the project and the differences are reported as a unified diff
+with paths relative to that root (and optionally written to a ``.patch``
+file), so proposed fixes can be reviewed across all tools without touching
+any file.
+
+When a fixer also converts a file's line endings, as formatters on Windows
+runners often do between CRLF and LF, the diff is shown in the file's
+original line endings with a note about the conversion, instead of every
+line showing as changed.
+"""
+
+from __future__ import annotations
+
+import difflib
+import fnmatch
+import os
+import shutil
+import tempfile
+from dataclasses import dataclass
+from types import TracebackType
+
+from lintro.config.config_loader import get_config
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.utils.path_filtering import find_repo_root
+
+# Dependency and build directories linked into the copy instead of copied
+LINKED_DIRS: frozenset[str] = frozenset({"node_modules", ".venv", "venv", "target"})
+
+# Path components that are neither copied nor compared
+SKIPPED_PATTERNS: tuple[str, ...] = (*DEFAULT_EXCLUDE_PATTERNS, ".lintro")
+
+NO_NEWLINE_MARKER: str = "\\ No newline at end of file\n"
+
+# Ends a final line that has no newline, so it differs from one that does
+_NO_NEWLINE_SENTINEL: str = "\x00\n"
+
+# Names of line endings, as editors show them
+LINE_ENDING_NAMES: dict[str, str] = {"\r\n": "CRLF", "\n": "LF", "\r": "CR"}
+
+
+def _skipped(relative_path: str) -> bool:
+    """Check whether a path lies in a directory that is never compared.
+
+    Args:
+        relative_path: Path relative to the project root.
+
+    Returns:
+        bool: True if any path component matches a skipped pattern.
+    """
+    return any(
+        fnmatch.fnmatch(part, pattern)
+        for part in relative_path.split(os.sep)
+        for pattern in SKIPPED_PATTERNS
+    )
+
+
+def _contains(directory: str, path: str) -> bool:
+    """Check whether a path lies inside a directory.
+
+    Args:
+        directory: Absolute directory.
+        path: Absolute path.
+
+    Returns:
+        bool: True if ``path`` is ``directory`` or below it.
+    """
+    return os.path.commonpath([path, directory]) == directory
+
+
+def project_root(directory: str) -> str:
+    """Find the directory a dry run copies for a working directory.
+
+    Args:
+        directory: Absolute working directory.
+
+    Returns:
+        str: Repository root; outside a repository the directory of the
+        Lintro configuration if it lies above, otherwise ``directory``.
+    """
+    repo = find_repo_root(directory)
+    if repo is not None:
+        return repo
+    config_path = get_config().config_path
+    if config_path:
+        config_dir = os.path.dirname(os.path.abspath(config_path))
+        if _contains(config_dir, directory):
+            return config_dir
+    return directory
+
+
+def _read(path: str) -> bytes | None:
+    """Read a file's bytes.
+
+    Args:
+        path: File path.
+
+    Returns:
+        bytes | None: Contents, or None if the file does not exist.
+    """
+    try:
+        with open(path, "rb") as f:
+            return f.read()
+    except OSError:
+        return None
+
+
+def line_ending(text: str) -> str | None:
+    """Find the line ending a text uses most.
+
+    Args:
+        text: File contents.
+
+    Returns:
+        str | None: ``"\\r\\n"``, ``"\\n"`` or ``"\\r"``, or None if the text
+        has no line breaks.
+    """
+    crlf = text.count("\r\n")
+    counts = {
+        "\r\n": crlf,
+        "\n": text.count("\n") - crlf,
+        "\r": text.count("\r") - crlf,
+    }
+    ending = max(counts, key=counts.__getitem__)
+    return ending if counts[ending] else None
+
+
+def convert_line_endings(text: str, ending: str) -> str:
+    """Give every line break of a text the same line ending.
+
+    Args:
+        text: File contents.
+        ending: Line ending to use.
+
+    Returns:
+        str: Text with converted line endings.
+    """
+    return "".join(
+        line.rstrip("\r\n") + ending if line.endswith(("\n", "\r")) else line
+        for line in text.splitlines(keepends=True)
+    )
+
+
+def ending_change(before: str, after: str) -> tuple[str, str] | None:
+    """Check whether a fix converts the line endings of a file.
+
+    Args:
+        before: Original contents.
+        after: Fixed contents.
+
+    Returns:
+        tuple[str, str] | None: Original and new line ending, or None if the
+        line endings stay the same.
+    """
+    old, new = line_ending(before), line_ending(after)
+    if old is None or new is None or old == new:
+        return None
+    return old, new
+
+
+def _diff_lines(text: str) -> list[str]:
+    """Split text into diff lines, each ending with a newline.
+
+    Args:
+        text: File contents.
+
+    Returns:
+        list[str]: Lines; a final line without newline ends in a sentinel.
+    """
+    lines = text.splitlines(keepends=True)
+    if lines and not lines[-1].endswith("\n"):
+        lines[-1] += _NO_NEWLINE_SENTINEL
+    return lines
+
+
+@dataclass(frozen=True)
+class FileChange:
+    """A change a fix run would make to one file.
+
+    Attributes:
+        path: Path relative to the project root, with forward slashes.
+        before: Original contents, or None for a file the fixers create.
+        after: New contents, or None for a file the fixers delete.
+    """
+
+    path: str
+    before: bytes | None
+    after: bytes | None
+
+    def diff(self) -> str:
+        """Render the change as a unified diff.
+
+        Returns:
+            str: Diff in ``git apply`` format; binary files get a one-line note,
+            and a note precedes the diff of a file whose line endings change.
+        """
+        try:
+            before = (self.before or b"").decode("utf-8")
+            after = (self.after or b"").decode("utf-8")
+        except UnicodeDecodeError:
+            return f"Binary files a/{self.path} and b/{self.path} differ\n"
+        note = ""
+        endings = ending_change(before, after)
+        if endings is not None:
+            old, new = endings
+            note = (
+                f"Line endings of {self.path} change from "
+                f"{LINE_ENDING_NAMES[old]} to {LINE_ENDING_NAMES[new]}\n"
+            )
+            after = convert_line_endings(after, old)
+        from_file = f"a/{self.path}" if self.before is not None else "/dev/null"
+        to_file = f"b/{self.path}" if self.after is not None else "/dev/null"
+        lines = difflib.unified_diff(
+            _diff_lines(before),
+            _diff_lines(after),
+            fromfile=from_file,
+            tofile=to_file,
+        )
+        return note + "".join(
+            line.replace(_NO_NEWLINE_SENTINEL, "\n" + NO_NEWLINE_MARKER)
+            for line in lines
+        )
+
+
+def format_patch(changes: list[FileChange]) -> str:
+    """Join file changes into one patch.
+
+    Args:
+        changes: Changes in path order.
+
+    Returns:
+        str: Patch text, empty when nothing would change.
+    """
+    return "".join(change.diff() for change in changes)
+
+
+class DryRunWorkspace:
+    """Temporary copy of the project that fixers run in.
+
+    Entering copies the project root to a temporary directory (linking
+    dependency directories such as ``node_modules`` rather than copying
+    them) and changes into the copy of the working directory. Run reports
+    still go to the ``.lintro`` directory of the working directory. Exiting
+    changes back and deletes the copy.
+    """
+
+    def __init__(self, root: str | None = None) -> None:
+        """Prepare a workspace for a project.
+
+        Args:
+            root: Project root; defaults to the one found by
+                ``project_root`` for the working directory.
+        """
+        self.cwd = os.path.abspath(os.getcwd())
+        self.root = os.path.abspath(root) if root else project_root(self.cwd)
+        self.copy = ""
+        self._temp_dir = ""
+        self._previous_cwd = ""
+        self._previous_log_dir: str | None = None
+        self._links: list[str] = []
+        self._files: set[str] = set()
+
+    def _ignore(self, directory: str, names: list[str]) -> set[str]:
+        """Choose the entries copytree leaves out, remembering linked ones.
+
+        Args:
+            directory: Directory being copied.
+            names: Entries in the directory.
+
+        Returns:
+            set[str]: Entries not to copy.
+        """
+        relative = os.path.relpath(directory, self.root)
+        ignored: set[str] = set()
+        for name in names:
+            if name in LINKED_DIRS:
+                self._links.append(os.path.normpath(os.path.join(relative, name)))
+                ignored.add(name)
+            elif any(fnmatch.fnmatch(name, p) for p in SKIPPED_PATTERNS):
+                ignored.add(name)
+        return ignored
+
+    def _walk(self, base: str) -> set[str]:
+        """List the compared files under a tree.
+
+        Args:
+            base: Project root or its copy.
+
+        Returns:
+            set[str]: File paths relative to ``base``.
+        """
+        files: set[str] = set()
+        for directory, dirs, names in os.walk(base):
+            relative = os.path.relpath(directory, base)
+            dirs[:] = [
+                d
+                for d in dirs
+                if not os.path.islink(os.path.join(directory, d))
+                and not _skipped(d)
+            ]
+            for name in names:
+                path = os.path.normpath(os.path.join(relative, name))
+                if not _skipped(path) and not os.path.islink(
+                    os.path.join(directory, name),
+                ):
+                    files.add(path)
+        return files
+
+    def __enter__(self) -> DryRunWorkspace:
+        """Copy the project and change into the copy.
+
+        Returns:
+            DryRunWorkspace: This workspace.
+
+        Raises:
+            ValueError: If the working directory is outside the project root.
+        """
+        if not _contains(self.root, self.cwd):
+            raise ValueError(f"{self.cwd} is outside the project root {self.root}")
+        self._temp_dir = tempfile.mkdtemp(prefix="lintro-dry-run-")
+        self.copy = os.path.join(
+            self._temp_dir,
+            os.path.basename(self.root) or "project",
+        )
+        shutil.copytree(self.root, self.copy, symlinks=True, ignore=self._ignore)
+        for link in self._links:
+            os.symlink(
+                os.path.join(self.root, link),
+                os.path.join(self.copy, link),
+                target_is_directory=True,
+            )
+        self._files = self._walk(self.copy)
+        self._previous_cwd = os.getcwd()
+        self._previous_log_dir = os.environ.get("LINTRO_LOG_DIR")
+        if self._previous_log_dir is None:
+            os.environ["LINTRO_LOG_DIR"] = os.path.join(self.cwd, ".lintro")
+        os.chdir(self._in_copy(self.cwd))
+        return self
+
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None:
+        """Change back to the project and delete the copy.
+
+        Args:
+            exc_type: Exception type, if one was raised.
+            exc: Exception, if one was raised.
+            traceback: Traceback, if an exception was raised.
+        """
+        os.chdir(self._previous_cwd)
+        if self._previous_log_dir is None:
+            os.environ.pop("LINTRO_LOG_DIR", None)
+        shutil.rmtree(self._temp_dir, ignore_errors=True)
+
+    def _in_copy(self, path: str) -> str:
+        """Map an absolute project path to the same path in the copy.
+
+        Args:
+            path: Absolute path inside the project root.
+
+        Returns:
+            str: Absolute path inside the copy.
+        """
+        return os.path.normpath(
+            os.path.join(self.copy, os.path.relpath(path, self.root)),
+        )
+
+    def rebase(self, path: str) -> str:
+        """Map a project path to the same path in the copy.
+
+        Relative paths, including ones climbing out of the working directory
+        with ``..``, resolve the same way inside the copy and stay relative.
+
+        Args:
+            path: File or directory path, absolute or relative to the
+                working directory.
+
+        Returns:
+            str: Path to use while the workspace is active.
+
+        Raises:
+            ValueError: If the path lies outside the project root.
+        """
+        resolved = os.path.normpath(os.path.join(self.cwd, path))
+        if not _contains(self.root, resolved):
+            raise ValueError(
+                f"{path} is outside {self.root}; dry runs only cover the "
+                "project",
+            )
+        if not os.path.isabs(path):
+            return path
+        return self._in_copy(resolved)
+
+    def changes(self) -> list[FileChange]:
+        """Compare the copy with the project.
+
+        Returns:
+            list[FileChange]: Files the fix run changed, created or deleted,
+            with paths relative to the project root, sorted by path.
+        """
+        result: list[FileChange] = []
+        for path in sorted(self._files | self._walk(self.copy)):
+            before = (
+                _read(os.path.join(self.root, path)) if path in self._files else None
+            )
+            after = _read(os.path.join(self.copy, path))
+            if before != after:
+                result.append(
+                    FileChange(
+                        path=path.replace(os.sep, "/"),
+                        before=before,
+                        after=after,
+                    ),
+                )
+        return result
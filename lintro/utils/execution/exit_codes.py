@@ -1,28 +1,92 @@
 """Exit code determination and result aggregation utilities.
 
-This module provides functions for determining exit codes and aggregating
-tool results from linting operations.
+This module provides functions for classifying a run's outcome, mapping it
+to an exit code (``execution.exit_codes``) and aggregating tool results from
+linting operations.
 """
 
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
+from lintro.config.execution_config import ExitCodesConfig
 from lintro.enums.action import Action
+from lintro.enums.run_outcome import RunOutcome
+from lintro.exceptions.errors import TOOL_TIMEOUT
 from lintro.models.core.tool_result import ToolResult
 
+if TYPE_CHECKING:
+    from lintro.utils.execution.failure_policy import FailurePolicy
+
 # Constants
 DEFAULT_EXIT_CODE_SUCCESS: int = 0
 DEFAULT_EXIT_CODE_FAILURE: int = 1
 DEFAULT_REMAINING_COUNT: str = "?"
 
 
-def determine_exit_code(
+def _has_issues(result: ToolResult) -> bool:
+    """Check whether a failed result failed because of issues.
+
+    Args:
+        result: Tool result.
+
+    Returns:
+        bool: True if the result reports issues or remaining issues.
+    """
+    remaining = getattr(result, "remaining_issues_count", None) or 0
+    return bool(getattr(result, "issues_count", 0) or remaining)
+
+
+def is_infrastructure_failure(result: ToolResult) -> bool:
+    """Check whether a tool failed to run rather than reporting issues.
+
+    A tool that timed out is an infrastructure failure even though many
+    tools report the timeout as an issue.
+
+    Args:
+        result: Tool result.
+
+    Returns:
+        bool: True if the tool errored or timed out.
+    """
+    if getattr(result, "skipped", False) or getattr(result, "success", True):
+        return False
+    if getattr(result, "error_code", None) == TOOL_TIMEOUT.code:
+        return True
+    return not _has_issues(result)
+
+
+def success_after_filter(result: ToolResult, remaining: int) -> bool:
+    """Decide whether a result succeeds once some of its issues are dropped.
+
+    Dropping issues (suppressions, severity, rule, path and query filters)
+    only turns a failure into a success when the tool failed because of its
+    issues alone: no error code, issues reported (see
+    is_infrastructure_failure) and none left. A crash or config error stays
+    a failure even when every issue parsed from its partial output is dropped.
+
+    Args:
+        result: Result before its issues were dropped.
+        remaining: Number of issues left.
+
+    Returns:
+        bool: Success flag for the filtered result.
+    """
+    if result.success:
+        return True
+    reported = _has_issues(result) or bool(result.issues)
+    return remaining == 0 and result.error_code is None and reported
+
+
+def determine_outcome(
     action: Action,
     all_results: list[ToolResult],
     total_issues: int,
     total_remaining: int,
     main_phase_empty_due_to_filter: bool,
-) -> int:
-    """Determine final exit code based on results.
+    policy: FailurePolicy | None = None,
+) -> RunOutcome:
+    """Classify how a run ended.
 
     Args:
         action: The action performed (check/fix/test).
@@ -30,34 +94,87 @@ def determine_exit_code(
         total_issues: Total issues found.
         total_remaining: Remaining issues after fix.
         main_phase_empty_due_to_filter: Whether main phase was empty due to filtering.
+        policy: Failure policy for check runs. None fails on any issue.
 
     Returns:
-        Exit code (0=success, 1=failure).
+        RunOutcome: The most severe outcome that applies.
     """
-    exit_code = DEFAULT_EXIT_CODE_SUCCESS
+    # If all tools were filtered to post-checks but nothing ran, the
+    # configuration left nothing to do
+    if main_phase_empty_due_to_filter and not all_results:
+        return RunOutcome.CONFIG_ERROR
+
+    # Tool failures come first (applies to all actions). Results that failed
+    # because of issues are judged by the issue rules below.
+    ran = [r for r in all_results if not getattr(r, "skipped", False)]
+    if any(is_infrastructure_failure(r) for r in ran):
+        return RunOutcome.TOOL_ERROR
+    if any(getattr(r, "missing", False) for r in all_results):
+        return RunOutcome.TOOL_MISSING
 
-    # Check for tool failures first (applies to all actions)
-    # Exclude skipped tools — they didn't fail, they just didn't run
-    if any(
-        not getattr(r, "success", True)
-        for r in all_results
-        if not getattr(r, "skipped", False)
-    ):
-        exit_code = DEFAULT_EXIT_CODE_FAILURE
+    # Any remaining failed result failed because of its issues
+    failed_with_issues = any(not getattr(r, "success", True) for r in ran)
 
     # Then check for issues based on action
     if action == Action.FIX:
-        if total_remaining > 0:
-            exit_code = DEFAULT_EXIT_CODE_FAILURE
+        failed = total_remaining > 0 or failed_with_issues
+    elif policy is not None:
+        failed = policy.exceeded(all_results)
     else:  # check
-        if total_issues > 0:
-            exit_code = DEFAULT_EXIT_CODE_FAILURE
+        failed = total_issues > 0 or failed_with_issues
+    return RunOutcome.ISSUES if failed else RunOutcome.CLEAN
 
-    # If all tools were filtered to post-checks but nothing ran, return failure
-    if main_phase_empty_due_to_filter and not all_results:
-        exit_code = DEFAULT_EXIT_CODE_FAILURE
 
-    return exit_code
+def determine_exit_code(
+    action: Action,
+    all_results: list[ToolResult],
+    total_issues: int,
+    total_remaining: int,
+    main_phase_empty_due_to_filter: bool,
+    policy: FailurePolicy | None = None,
+    exit_codes: ExitCodesConfig | None = None,
+) -> int:
+    """Determine final exit code based on results.
+
+    Args:
+        action: The action performed (check/fix/test).
+        all_results: List of all tool results.
+        total_issues: Total issues found.
+        total_remaining: Remaining issues after fix.
+        main_phase_empty_due_to_filter: Whether main phase was empty due to filtering.
+        policy: Failure policy for check runs. None fails on any issue.
+        exit_codes: Exit code per outcome. None uses the defaults (0 for
+            clean and tool_missing, 1 otherwise).
+
+    Returns:
+        int: Exit code for the run's outcome.
+    """
+    outcome = determine_outcome(
+        action=action,
+        all_results=all_results,
+        total_issues=total_issues,
+        total_remaining=total_remaining,
+        main_phase_empty_due_to_filter=main_phase_empty_due_to_filter,
+        policy=policy,
+    )
+    return exit_code_for(outcome, exit_codes)
+
+
+def exit_code_for(
+    outcome: RunOutcome,
+    exit_codes: ExitCodesConfig | None = None,
+) -> int:
+    """Look up the exit code for an outcome.
+
+    Args:
+        outcome: Run outcome.
+        exit_codes: Configured exit codes, or None for the defaults.
+
+    Returns:
+        int: Exit code.
+    """
+    codes = exit_codes or ExitCodesConfig()
+    return int(getattr(codes, outcome.value))
 
 
 def aggregate_tool_results(
@@ -0,0 +1,102 @@
+"""Stopping a check run once its outcome is known to be a failure.
+
+With ``--fail-fast`` the run stops as soon as the issues reported so far
+exceed the failure policy (``--fail-on`` and ``--max-issues``): tools that
+have not started are not run, and are reported as skipped with
+``CANCELLED_REASON`` so the summary shows the results are partial. Tools
+already running finish, since their subprocesses are not interrupted.
+"""
+
+from __future__ import annotations
+
+import threading
+from collections.abc import Callable
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.failure_policy import FailurePolicy
+
+CANCELLED_REASON = "cancelled by --fail-fast"
+
+
+def cancelled_result(name: str) -> ToolResult:
+    """Build the result of a tool that was not run because the run stopped.
+
+    Args:
+        name: Tool name.
+
+    Returns:
+        ToolResult: Skipped result naming the cancellation.
+    """
+    return ToolResult(
+        name=name,
+        skipped=True,
+        skip_reason=CANCELLED_REASON,
+        issues_count=0,
+    )
+
+
+class FailFast:
+    """Tracks finished results and signals when the run should stop.
+
+    Results may be recorded from the event loop of a parallel run while
+    worker threads poll ``stopped()``, so the stop signal is an Event.
+    """
+
+    def __init__(
+        self,
+        policy: FailurePolicy,
+        judge: Callable[[ToolResult], ToolResult] | None = None,
+    ) -> None:
+        """Start tracking a run.
+
+        Args:
+            policy: Policy deciding when the run has failed.
+            judge: Post-processing applied to a result before it is counted,
+                for results recorded as the tools return them. None counts
+                results as recorded.
+        """
+        self.policy = policy
+        self.judge = judge
+        self.results: list[ToolResult] = []
+        self.trigger: str | None = None
+        self._stop = threading.Event()
+
+    def stopped(self) -> bool:
+        """Return whether the run should stop.
+
+        Returns:
+            bool: True once the recorded results exceed the policy.
+        """
+        return self._stop.is_set()
+
+    def record(self, result: ToolResult) -> bool:
+        """Count a finished tool's result.
+
+        Args:
+            result: Result of the tool.
+
+        Returns:
+            bool: True if the run should stop.
+        """
+        if result.skipped or self.stopped():
+            return self.stopped()
+        self.results.append(self.judge(result) if self.judge else result)
+        if self.policy.exceeded(self.results):
+            self.trigger = result.name
+            self._stop.set()
+        return self.stopped()
+
+    def describe(self, cancelled: int) -> str:
+        """Describe why the run stopped early.
+
+        Args:
+            cancelled: Number of tools that were not run.
+
+        Returns:
+            str: Message for the console.
+        """
+        plural = "tool" if cancelled == 1 else "tools"
+        return (
+            f"Stopped early (--fail-fast): {self.trigger} exceeded the failure "
+            f"threshold; {cancelled} {plural} not run, results are partial"
+        )
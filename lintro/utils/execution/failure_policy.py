@@ -0,0 +1,320 @@
+"""Threshold-based failure policy for check runs.
+
+By default any reported issue fails a check run. ``--fail-on`` (or
+``execution.fail_on``) raises the lowest severity that counts, and
+``--max-issues`` (or ``execution.max_issues``) tolerates a bounded number
+of counted issues. Both can be set per tool under ``tools.<name>``, and per
+directory label under ``label_policies.<label>``.
+
+``budgets`` tolerate a number of issues per ``tool:rule`` pattern, so the
+count of a rule can be ratcheted down over time: a budgeted rule's issues
+count against its budget instead of the thresholds above, fail the run
+when there are more than the budget, and only warn otherwise.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from dataclasses import dataclass, field
+
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.severity_level import SeverityLevel, normalize_severity_level
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.registry import ToolRegistry
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.labels import DirectoryLabels
+from lintro.utils.rule_selection import RulePattern, parse_rule_pattern
+
+# Setting that reports issues without ever failing on them
+FAIL_ON_NEVER: str = "never"
+
+_SEVERITY_RANK: dict[SeverityLevel, int] = {
+    SeverityLevel.INFO: 0,
+    SeverityLevel.WARNING: 1,
+    SeverityLevel.ERROR: 2,
+}
+
+
+def _parse_fail_on(value: str) -> SeverityLevel | None:
+    """Convert a fail-on setting to a severity threshold.
+
+    Args:
+        value: ``error``, ``warning``, ``info`` or ``never``.
+
+    Returns:
+        SeverityLevel | None: Threshold, or None for ``never``.
+    """
+    if value.lower() == FAIL_ON_NEVER:
+        return None
+    return normalize_severity_level(value)
+
+
+@dataclass(frozen=True)
+class BudgetUsage:
+    """Issues of a budgeted rule in a run.
+
+    Attributes:
+        pattern: Rule pattern as configured.
+        issues: Issues of the run matching the pattern.
+        budget: Issues the budget tolerates.
+    """
+
+    pattern: str
+    issues: int
+    budget: int
+
+    @property
+    def exceeded(self) -> bool:
+        """Whether the rule has more issues than its budget.
+
+        Returns:
+            bool: True if the budget is exceeded.
+        """
+        return self.issues > self.budget
+
+    def describe(self) -> str:
+        """Summarize the usage for the console.
+
+        Returns:
+            str: One line naming the rule, its issues and its budget.
+        """
+        if self.exceeded:
+            return (
+                f"Budget exceeded for {self.pattern}: {self.issues} issue(s), "
+                f"budget {self.budget}"
+            )
+        text = f"Within budget for {self.pattern}: {self.issues} of {self.budget}"
+        if self.issues < self.budget:
+            text += f" issue(s); the budget can be lowered to {self.issues}"
+        else:
+            text += " issue(s)"
+        return text
+
+
+@dataclass(frozen=True)
+class FailurePolicy:
+    """Which issues fail a check run and how many are tolerated.
+
+    Attributes:
+        fail_on: Lowest failing severity; None never fails on issues.
+        max_issues: Counted issues tolerated across the run.
+        tool_fail_on: Per-tool thresholds overriding ``fail_on``.
+        tool_max_issues: Per-tool tolerances; issues within them do not
+            count towards ``max_issues``.
+        labels: Labelled directories the label settings apply to.
+        label_fail_on: Per-label thresholds; an issue in a labelled
+            directory counts at the lowest of its tool's and labels'.
+        label_max_issues: Per-label tolerances, checked in addition to
+            the tool and run limits.
+        budgets: Rule patterns with the issues they tolerate. An issue
+            counts against the first budget matching its rule, whatever
+            its severity, and towards no other limit.
+    """
+
+    fail_on: SeverityLevel | None = SeverityLevel.INFO
+    max_issues: int = 0
+    tool_fail_on: dict[str, SeverityLevel | None] = field(default_factory=dict)
+    tool_max_issues: dict[str, int] = field(default_factory=dict)
+    labels: DirectoryLabels = field(default_factory=DirectoryLabels)
+    label_fail_on: dict[str, SeverityLevel] = field(default_factory=dict)
+    label_max_issues: dict[str, int] = field(default_factory=dict)
+    budgets: tuple[tuple[RulePattern, int], ...] = ()
+
+    def _counted_by_label(
+        self,
+        result: ToolResult,
+    ) -> tuple[int, dict[str, int], dict[str, int]]:
+        """Count a result's failing issues, in total, per label and per budget.
+
+        Args:
+            result: Tool result.
+
+        Returns:
+            tuple[int, dict[str, int], dict[str, int]]: Counted issues, how
+            many of them lie in directories with each label, and the
+            budgeted issues keyed by budget pattern.
+        """
+        name = result.name.lower()
+        threshold = self.tool_fail_on.get(name, self.fail_on)
+        if not result.issues:
+            return (result.issues_count if threshold is not None else 0), {}, {}
+        tool_rank = _SEVERITY_RANK[threshold] if threshold is not None else None
+        counted = 0
+        per_label: dict[str, int] = {}
+        per_budget: dict[str, int] = {}
+        for issue in result.issues:
+            budget = self._budget_for(name, issue_code(issue))
+            if budget is not None:
+                per_budget[budget] = per_budget.get(budget, 0) + 1
+                continue
+            issue_labels = self.labels.labels_for(issue.file) if self.labels else ()
+            ranks = [
+                _SEVERITY_RANK[self.label_fail_on[label]]
+                for label in issue_labels
+                if label in self.label_fail_on
+            ]
+            if tool_rank is not None:
+                ranks.append(tool_rank)
+            if not ranks or _SEVERITY_RANK[issue.get_severity()] < min(ranks):
+                continue
+            counted += 1
+            for label in issue_labels:
+                per_label[label] = per_label.get(label, 0) + 1
+        return counted, per_label, per_budget
+
+    def _budget_for(self, tool: str, code: str) -> str | None:
+        """Find the budget an issue counts against.
+
+        Args:
+            tool: Tool that reported the issue.
+            code: Rule code of the issue.
+
+        Returns:
+            str | None: Pattern of the first matching budget, if any.
+        """
+        for pattern, _budget in self.budgets:
+            if pattern.matches(tool, code):
+                return pattern.text
+        return None
+
+    def counted_issues(self, result: ToolResult) -> int:
+        """Count a result's issues at or above its failing severity.
+
+        Results with only a raw issue count (no parsed issues) count in full.
+
+        Args:
+            result: Tool result.
+
+        Returns:
+            int: Number of issues that count towards failure.
+        """
+        return self._counted_by_label(result)[0]
+
+    def exceeded(self, results: Sequence[ToolResult]) -> bool:
+        """Check whether the issues in a run break the policy.
+
+        Args:
+            results: Results of the run; skipped tools are ignored.
+
+        Returns:
+            bool: True if a budget, a tool or the run as a whole has too many
+            issues.
+        """
+        if any(usage.exceeded for usage in self.budget_usage(results)):
+            return True
+        run_total = 0
+        label_totals: dict[str, int] = {}
+        for result in results:
+            if result.skipped:
+                continue
+            counted, per_label, _per_budget = self._counted_by_label(result)
+            for label, count in per_label.items():
+                label_totals[label] = label_totals.get(label, 0) + count
+            tool_limit = self.tool_max_issues.get(result.name.lower())
+            if tool_limit is None:
+                run_total += counted
+            elif counted > tool_limit:
+                return True
+        if any(
+            label_totals.get(label, 0) > limit
+            for label, limit in self.label_max_issues.items()
+        ):
+            return True
+        return run_total > self.max_issues
+
+    def budget_usage(self, results: Sequence[ToolResult]) -> list[BudgetUsage]:
+        """Count the issues of a run against each budget.
+
+        Args:
+            results: Results of the run; skipped tools are ignored.
+
+        Returns:
+            list[BudgetUsage]: Usage of every budget, in config order.
+        """
+        totals: dict[str, int] = {}
+        if self.budgets:
+            for result in results:
+                if result.skipped:
+                    continue
+                for pattern, count in self._counted_by_label(result)[2].items():
+                    totals[pattern] = totals.get(pattern, 0) + count
+        return [
+            BudgetUsage(
+                pattern=pattern.text,
+                issues=totals.get(pattern.text, 0),
+                budget=budget,
+            )
+            for pattern, budget in self.budgets
+        ]
+
+
+def build_failure_policy(
+    lintro_config: LintroConfig,
+    fail_on: str | None = None,
+    max_issues: int | None = None,
+) -> FailurePolicy:
+    """Combine CLI flags with the configured failure policy.
+
+    Flags win over ``execution.fail_on`` and ``execution.max_issues``;
+    per-tool and per-label settings apply to their tool or directories
+    either way. A tool's configured ``fail_on`` wins over the run's
+    threshold; the default threshold of its definition only applies when
+    neither the run nor the tool sets one. Rule budgets come from
+    ``budgets``.
+
+    Args:
+        lintro_config: Loaded Lintro configuration.
+        fail_on: Value of ``--fail-on``, if given.
+        max_issues: Value of ``--max-issues``, if given.
+
+    Returns:
+        FailurePolicy: Effective policy.
+
+    Raises:
+        ValueError: If a budget's pattern names no rule.
+    """
+    execution = lintro_config.execution
+    run_fail_on = fail_on or execution.fail_on
+    # A definition's default only stands in when no threshold was chosen
+    tool_fail_on = (
+        {}
+        if run_fail_on
+        else {
+            name.lower(): _parse_fail_on(definition.default_fail_on)
+            for name, definition in ToolRegistry.get_definitions().items()
+            if definition.default_fail_on is not None
+        }
+    )
+    tool_fail_on.update(
+        {
+            name.lower(): _parse_fail_on(cfg.fail_on)
+            for name, cfg in lintro_config.tools.items()
+            if cfg.fail_on is not None
+        },
+    )
+    run_max = max_issues if max_issues is not None else execution.max_issues
+    return FailurePolicy(
+        fail_on=_parse_fail_on(run_fail_on or SeverityLevel.INFO.value),
+        max_issues=run_max or 0,
+        tool_fail_on=tool_fail_on,
+        tool_max_issues={
+            name.lower(): cfg.max_issues
+            for name, cfg in lintro_config.tools.items()
+            if cfg.max_issues is not None
+        },
+        labels=DirectoryLabels.from_config(lintro_config),
+        label_fail_on={
+            label: normalize_severity_level(policy.fail_on)
+            for label, policy in lintro_config.label_policies.items()
+            if policy.fail_on is not None
+        },
+        label_max_issues={
+            label: policy.max_issues
+            for label, policy in lintro_config.label_policies.items()
+            if policy.max_issues is not None
+        },
+        budgets=tuple(
+            (parse_rule_pattern(pattern), budget)
+            for pattern, budget in lintro_config.budgets.items()
+        ),
+    )
@@ -0,0 +1,315 @@
+"""Fast single-file fixing for editor save hooks.
+
+``lintro fix-file`` runs the fixers for one file only: tools are picked by
+the file's name instead of walking the project, tools named with ``--tools``
+are loaded without discovering the others, tools that do not handle the file
+type are skipped before they are configured, and the whole run shares one
+deadline. The result is a small JSON document an editor can act on.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import re
+import subprocess  # nosec B404 - only used for its TimeoutExpired exception
+import time
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any
+
+from lintro.enums.action import Action
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.base import BaseToolPlugin
+from lintro.tools import tool_manager
+from lintro.utils.execution.exit_codes import is_infrastructure_failure
+from lintro.utils.execution.tool_configuration import (
+    configure_tool_for_execution,
+    get_tools_to_run,
+)
+from lintro.utils.path_filtering import walk_files_with_excludes
+from lintro.utils.timestamps import elapsed_ms
+from lintro.utils.unified_config import UnifiedConfigManager
+
+DEFAULT_FIX_FILE_TIMEOUT: float = 2.0
+
+# Names that select one half of a tool that both lints and formats
+TOOL_VARIANTS: dict[str, tuple[str, dict[str, object]]] = {
+    "ruff-format": ("ruff", {"format": True, "lint_fix": False}),
+    "ruff-fix": ("ruff", {"format": False, "lint_fix": True}),
+}
+
+STATUS_CHANGED = "changed"
+STATUS_UNCHANGED = "unchanged"
+STATUS_SKIPPED = "skipped"
+STATUS_TIMEOUT = "timeout"
+STATUS_ERROR = "error"
+
+_DURATION_RE = re.compile(r"^\s*(\d+(?:\.\d+)?)\s*(ms|s)?\s*$")
+
+
+def parse_duration(value: str) -> float:
+    """Parse a duration such as ``2s``, ``500ms`` or ``1.5``.
+
+    Args:
+        value: Duration; plain numbers are seconds.
+
+    Returns:
+        float: Seconds.
+
+    Raises:
+        ValueError: If the value is not a positive duration.
+    """
+    match = _DURATION_RE.match(value)
+    if not match:
+        raise ValueError(f"Invalid duration '{value}'; use e.g. 2s or 500ms")
+    seconds = float(match.group(1)) / (1000 if match.group(2) == "ms" else 1)
+    if seconds <= 0:
+        raise ValueError(f"Duration must be positive, got '{value}'")
+    return seconds
+
+
+@dataclass
+class ToolFixOutcome:
+    """What one tool did to the file.
+
+    Attributes:
+        tool: Tool name as requested.
+        status: "changed", "unchanged", "skipped", "timeout" or "error".
+        duration_ms: Time the tool took.
+        remaining: Issues the tool could not fix, if it reports them.
+        message: Why the tool was skipped or failed.
+    """
+
+    tool: str
+    status: str
+    duration_ms: int = 0
+    remaining: int | None = None
+    message: str | None = None
+
+
+@dataclass
+class FixFileResult:
+    """Result of fixing one file.
+
+    Attributes:
+        path: File that was fixed.
+        changed: Whether its contents changed.
+        duration_ms: Time the whole run took.
+        tools: Outcome of each requested tool, in run order.
+    """
+
+    path: str
+    changed: bool = False
+    duration_ms: int = 0
+    tools: list[ToolFixOutcome] = field(default_factory=list)
+
+    @property
+    def ok(self) -> bool:
+        """Whether every tool finished without a timeout or error.
+
+        Returns:
+            bool: True when no tool timed out or failed.
+        """
+        return all(
+            outcome.status not in (STATUS_TIMEOUT, STATUS_ERROR)
+            for outcome in self.tools
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the result as JSON-ready data.
+
+        Returns:
+            dict[str, Any]: Path, change flag, timing and tool outcomes.
+        """
+        return {**asdict(self), "ok": self.ok}
+
+
+def _digest(path: Path) -> str | None:
+    """Hash a file's contents.
+
+    Args:
+        path: File to hash.
+
+    Returns:
+        str | None: SHA-256 hex digest, or None if the file cannot be read.
+    """
+    try:
+        return hashlib.sha256(path.read_bytes()).hexdigest()
+    except OSError:
+        return None
+
+
+def resolve_fix_tools(tools: str | None) -> list[tuple[str, str, dict[str, object]]]:
+    """Resolve requested tool names to registered fixers.
+
+    Args:
+        tools: Comma-separated tool names, possibly variants such as
+            ``ruff-format``; None for every enabled tool that can fix.
+
+    Returns:
+        list[tuple[str, str, dict[str, object]]]: Requested name, registered
+        tool name and options selecting the variant, in execution order.
+
+    Raises:
+        ValueError: If a tool is unknown or cannot fix files.
+    """
+    if not tools:
+        return [
+            (name, name, {})
+            for name in get_tools_to_run(None, Action.FIX).to_run
+            if tool_manager.get_tool(name).capabilities().supports_fix
+        ]
+    resolved: list[tuple[str, str, dict[str, object]]] = []
+    for raw in tools.split(","):
+        requested = raw.strip().lower()
+        if not requested:
+            continue
+        name, options = TOOL_VARIANTS.get(requested, (requested, {}))
+        tool = tool_manager.load_tool(name)
+        if tool is None:
+            raise ValueError(f"Unknown tool '{requested}'")
+        if not tool.capabilities().supports_fix:
+            raise ValueError(f"{requested} cannot fix files")
+        resolved.append((requested, name, options))
+    return resolved
+
+
+def _routes_to(
+    tool: BaseToolPlugin,
+    path: Path,
+    exclude_patterns: list[str] | None = None,
+) -> bool:
+    """Check whether a tool handles a file, honouring its excludes.
+
+    Args:
+        tool: Configured tool.
+        path: File to fix.
+        exclude_patterns: Patterns to exclude; None for the tool's own.
+
+    Returns:
+        bool: True if the tool's file patterns or interpreters match the
+        file.
+    """
+    if exclude_patterns is None:
+        exclude_patterns = tool.exclude_patterns
+    return bool(
+        walk_files_with_excludes(
+            [str(path)],
+            tool.definition.file_patterns,
+            exclude_patterns,
+            shebang_interpreters=tool.definition.shebang_interpreters,
+        ),
+    )
+
+
+def _failure_message(result: ToolResult) -> str:
+    """Summarize why a fixer failed to run.
+
+    Args:
+        result: Failed tool result.
+
+    Returns:
+        str: First line of the tool's output, or a generic note.
+    """
+    for line in (result.output or "").splitlines():
+        if line.strip():
+            return line.strip()
+    return "failed to run"
+
+
+def fix_file(
+    path: str | Path,
+    tools: str | None = None,
+    timeout: float = DEFAULT_FIX_FILE_TIMEOUT,
+) -> FixFileResult:
+    """Run the fixers that handle one file, within a shared deadline.
+
+    Tools run one after another so each sees the previous tool's output.
+    Tools that do not handle the file type are skipped without running;
+    once the deadline passes, the remaining tools are reported as timed out.
+
+    Args:
+        path: File to fix.
+        tools: Comma-separated tool names, or None for all enabled fixers.
+        timeout: Seconds allowed for the whole run.
+
+    Returns:
+        FixFileResult: Per-tool outcomes and whether the file changed.
+    """
+    started = time.monotonic()
+    deadline = started + timeout
+    target = Path(path)
+    result = FixFileResult(path=str(target))
+    before = _digest(target)
+    config_manager = UnifiedConfigManager()
+
+    for requested, name, variant in resolve_fix_tools(tools):
+        remaining_time = deadline - time.monotonic()
+        if remaining_time <= 0:
+            result.tools.append(
+                ToolFixOutcome(requested, STATUS_TIMEOUT, message="deadline passed"),
+            )
+            continue
+
+        tool = tool_manager.load_tool(name) or tool_manager.get_tool(name)
+        if not _routes_to(tool, target, exclude_patterns=[]):
+            result.tools.append(
+                ToolFixOutcome(requested, STATUS_SKIPPED, message="not handled"),
+            )
+            continue
+
+        configure_tool_for_execution(
+            tool=tool,
+            tool_name=name,
+            config_manager=config_manager,
+            tool_option_dict={name: variant} if variant else {},
+            exclude=None,
+            include_venv=False,
+            incremental=False,
+            action=Action.FIX,
+            post_tools=set(),
+        )
+        if not _routes_to(tool, target):
+            result.tools.append(
+                ToolFixOutcome(requested, STATUS_SKIPPED, message="excluded"),
+            )
+            continue
+
+        tool_started = time.monotonic()
+        digest = _digest(target)
+        # Tools take fractional timeouts; rounding up would overshoot
+        options: dict[str, object] = {"timeout": remaining_time}
+        try:
+            tool_result = tool.fix([str(target)], options)
+        except subprocess.TimeoutExpired:
+            outcome = ToolFixOutcome(requested, STATUS_TIMEOUT, message="timed out")
+        except (OSError, RuntimeError, ValueError) as e:
+            outcome = ToolFixOutcome(requested, STATUS_ERROR, message=str(e))
+        else:
+            if tool_result.skipped:
+                outcome = ToolFixOutcome(
+                    requested,
+                    STATUS_SKIPPED,
+                    message=tool_result.skip_reason,
+                )
+            elif is_infrastructure_failure(tool_result):
+                timed_out = tool_result.error_code == TOOL_TIMEOUT.code
+                outcome = ToolFixOutcome(
+                    requested,
+                    STATUS_TIMEOUT if timed_out else STATUS_ERROR,
+                    message=_failure_message(tool_result),
+                )
+            else:
+                changed = _digest(target) != digest
+                outcome = ToolFixOutcome(
+                    requested,
+                    STATUS_CHANGED if changed else STATUS_UNCHANGED,
+                    remaining=tool_result.remaining_issues_count,
+                )
+        outcome.duration_ms = elapsed_ms(tool_started)
+        result.tools.append(outcome)
+
+    result.changed = _digest(target) != before
+    result.duration_ms = elapsed_ms(started)
+    return result
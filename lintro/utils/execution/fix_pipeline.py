@@ -0,0 +1,397 @@
+"""Coordination of several fixers running on the same files.
+
+``lintro format`` runs fixers one after another. A lint fixer such as
+``ruff --fix`` or ``clippy --fix`` can leave code that a formatter then
+rewrites, and two fixers with different opinions can undo each other. This
+module:
+
+1. Orders fixers so lint fixers run before pure formatters.
+2. Tracks file contents between fixers and records when one fixer reverts
+   another's change.
+3. Re-runs the fixers' checks on the modified files and fixes again until
+   nothing is left to fix (a stable point) or ``execution.fix_passes`` is
+   reached, which is reported as an oscillation.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import os
+import time
+from collections.abc import Callable, Mapping, Sequence
+from dataclasses import dataclass, field
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.effort import issue_fixable
+from lintro.utils.path_filtering import walk_files_with_excludes
+
+# Fix passes over modified files before giving up on a stable point
+DEFAULT_FIX_PASSES: int = 3
+
+# Files modified this shortly before a snapshot are re-hashed even if their
+# stat is unchanged, since a later write can land in the same timestamp tick
+_RACY_WINDOW_NS: int = 1_000_000_000
+
+
+def is_pure_formatter(definition: ToolDefinition) -> bool:
+    """Check whether a tool only formats and reports no lint rules.
+
+    Args:
+        definition: Tool definition.
+
+    Returns:
+        bool: True for formatters such as black, prettier or rustfmt.
+    """
+    tool_type = definition.tool_type
+    return ToolType.FORMATTER in tool_type and ToolType.LINTER not in tool_type
+
+
+def order_fixers(
+    tool_names: Sequence[str],
+    definitions: Mapping[str, ToolDefinition],
+) -> list[str]:
+    """Order fixers so formatters run after the lint fixers they depend on.
+
+    Lint fixes can change layout, so pure formatters run last. The relative
+    order within each group is kept.
+
+    Args:
+        tool_names: Fixers in their configured order.
+        definitions: Tool definitions keyed by tool name.
+
+    Returns:
+        list[str]: Fixers in run order.
+    """
+
+    def _is_formatter(name: str) -> bool:
+        """Check whether a named fixer is a pure formatter.
+
+        Args:
+            name: Tool name.
+
+        Returns:
+            bool: True if the tool is a known pure formatter.
+        """
+        definition = definitions.get(name)
+        return definition is not None and is_pure_formatter(definition)
+
+    return sorted(tool_names, key=_is_formatter)
+
+
+def hash_files(files: Sequence[str]) -> dict[str, str]:
+    """Hash the contents of files.
+
+    Args:
+        files: File paths.
+
+    Returns:
+        dict[str, str]: SHA-256 digest per path; unreadable files are omitted.
+    """
+    digests: dict[str, str] = {}
+    for path in files:
+        try:
+            with open(path, "rb") as f:
+                digests[path] = hashlib.sha256(f.read()).hexdigest()
+        except OSError:
+            continue
+    return digests
+
+
+def _stat_signature(path: str) -> tuple[int, int, int] | None:
+    """Summarize what a file's stat says about its contents.
+
+    Args:
+        path: File path.
+
+    Returns:
+        tuple[int, int, int] | None: Modification time, size and inode, or
+        None if the file cannot be stat'ed.
+    """
+    try:
+        st = os.stat(path)
+    except OSError:
+        return None
+    return (st.st_mtime_ns, st.st_size, st.st_ino)
+
+
+def files_for_fixers(
+    definitions: Sequence[ToolDefinition],
+    paths: list[str],
+    exclude: str | None,
+    include_venv: bool,
+) -> list[str]:
+    """List the files any of the fixers may modify.
+
+    Args:
+        definitions: Definitions of the fixers.
+        paths: Paths passed to lintro.
+        exclude: Comma-separated exclude patterns, if any.
+        include_venv: Whether virtual environments are included.
+
+    Returns:
+        list[str]: Matching files, sorted.
+    """
+    patterns = sorted({p for d in definitions for p in d.file_patterns})
+    interpreters = sorted({i for d in definitions for i in d.shebang_interpreters})
+    excludes = list(DEFAULT_EXCLUDE_PATTERNS)
+    if exclude:
+        excludes.extend(p.strip() for p in exclude.split(",") if p.strip())
+    return walk_files_with_excludes(
+        paths,
+        patterns,
+        excludes,
+        include_venv,
+        shebang_interpreters=interpreters,
+    )
+
+
+def files_under(files: Sequence[str], roots: Sequence[str]) -> list[str]:
+    """Keep the files that lie under any of the given roots.
+
+    Args:
+        files: File paths.
+        roots: Files or directories.
+
+    Returns:
+        list[str]: Files under a root, in their original order.
+    """
+    abs_roots = [os.path.abspath(root) for root in roots]
+    return [
+        path
+        for path in files
+        if any(
+            os.path.commonpath([os.path.abspath(path), root]) == root
+            for root in abs_roots
+        )
+    ]
+
+
+@dataclass(frozen=True)
+class FixConflict:
+    """One fixer reverting another fixer's change to a file.
+
+    Attributes:
+        file: File that was reverted.
+        tool: Fixer that reverted the change.
+        undone_tool: Fixer whose change was reverted.
+    """
+
+    file: str
+    tool: str
+    undone_tool: str
+
+
+@dataclass(frozen=True)
+class FileFix:
+    """One fixer changing one file.
+
+    Attributes:
+        file: File that changed.
+        tool: Fixer that changed it.
+        before: SHA-256 of the contents before the fixer ran.
+        after: SHA-256 of the contents after it ran; None if it was removed.
+    """
+
+    file: str
+    tool: str
+    before: str | None
+    after: str | None
+
+
+@dataclass
+class FixPipelineReport:
+    """Outcome of coordinating several fixers.
+
+    Attributes:
+        order: Fixers in run order.
+        passes: Fix passes run, including the first.
+        modified_files: Files changed by any fixer.
+        conflicts: Fixers reverting each other's changes.
+        stable: Whether a pass left nothing to fix.
+    """
+
+    order: list[str] = field(default_factory=list)
+    passes: int = 0
+    modified_files: set[str] = field(default_factory=set)
+    conflicts: list[FixConflict] = field(default_factory=list)
+    stable: bool = False
+
+    def describe(self) -> str:
+        """Describe the outcome in one line.
+
+        Returns:
+            str: Stable-point or oscillation message.
+        """
+        if self.conflicts:
+            pairs = sorted({(c.tool, c.undone_tool) for c in self.conflicts})
+            files = sorted({c.file for c in self.conflicts})
+            undone = "; ".join(f"{tool} undoes {other}" for tool, other in pairs)
+            return (
+                f"Fixers oscillate: {undone} in {', '.join(files)}. "
+                "Align their configuration or disable one of them."
+            )
+        if not self.stable:
+            return (
+                f"No stable point after {self.passes} fix passes over "
+                f"{len(self.modified_files)} file(s)."
+            )
+        unit = "pass" if self.passes == 1 else "passes"
+        return (
+            f"Fixers reached a stable point after {self.passes} {unit} "
+            f"({' -> '.join(self.order)})."
+        )
+
+
+class FixTracker:
+    """Track file contents between fixers to find modifications and reverts."""
+
+    def __init__(self, files: Sequence[str]) -> None:
+        """Take the initial snapshot.
+
+        Args:
+            files: Files the fixers may modify.
+        """
+        self._files = list(files)
+        self._stated_at = time.time_ns()
+        self._stats = {path: _stat_signature(path) for path in self._files}
+        self._state = hash_files(self._files)
+        # Per file: (fixer, digest before its change) for the last change
+        self._last_change: dict[str, tuple[str, str | None]] = {}
+        self.modified_files: set[str] = set()
+        self.conflicts: list[FixConflict] = []
+        self.fixes: list[FileFix] = []
+
+    def _suspects(self) -> list[str]:
+        """Find the files that may have changed since the last snapshot.
+
+        Only these are re-hashed: files whose stat changed, and files modified
+        so recently before the snapshot that a change could share their
+        timestamp.
+
+        Returns:
+            list[str]: Files to re-hash, in tracking order.
+        """
+        stated_at = time.time_ns()
+        stats = {path: _stat_signature(path) for path in self._files}
+        racy_before = self._stated_at - _RACY_WINDOW_NS
+        suspects: list[str] = []
+        for path in self._files:
+            signature = stats[path]
+            if signature != self._stats[path] or (
+                signature is not None and signature[0] >= racy_before
+            ):
+                suspects.append(path)
+        self._stated_at, self._stats = stated_at, stats
+        return suspects
+
+    def record(self, tool: str) -> list[str]:
+        """Record the files a fixer just changed.
+
+        Args:
+            tool: Fixer that just ran.
+
+        Returns:
+            list[str]: Files the fixer changed.
+        """
+        suspects = set(self._suspects())
+        state = {p: d for p, d in self._state.items() if p not in suspects}
+        state.update(hash_files(sorted(suspects)))
+        changed = [
+            path for path in self._files if state.get(path) != self._state.get(path)
+        ]
+        for path in changed:
+            previous = self._last_change.get(path)
+            if (
+                previous is not None
+                and previous[0] != tool
+                and state.get(path) == previous[1]
+            ):
+                conflict = FixConflict(file=path, tool=tool, undone_tool=previous[0])
+                if conflict not in self.conflicts:
+                    self.conflicts.append(conflict)
+            self._last_change[path] = (tool, self._state.get(path))
+            self.fixes.append(
+                FileFix(
+                    file=path,
+                    tool=tool,
+                    before=self._state.get(path),
+                    after=state.get(path),
+                ),
+            )
+        self.modified_files.update(changed)
+        self._state = state
+        return changed
+
+
+def needs_another_pass(result: ToolResult, definition: ToolDefinition | None) -> bool:
+    """Check whether a re-check found something the fixer could still fix.
+
+    Args:
+        result: Check result on the modified files.
+        definition: Definition of the tool, if known.
+
+    Returns:
+        bool: True if the result reports fixable issues.
+    """
+    if result.skipped or not result.issues_count:
+        return False
+    if definition is not None and is_pure_formatter(definition):
+        return True
+    return any(issue_fixable(issue) for issue in result.issues or [])
+
+
+def stabilize_fixes(
+    tracker: FixTracker,
+    fixers: Sequence[str],
+    check: Callable[[str, list[str]], ToolResult],
+    fix: Callable[[str, list[str]], ToolResult],
+    definitions: Mapping[str, ToolDefinition],
+    max_passes: int = DEFAULT_FIX_PASSES,
+) -> tuple[FixPipelineReport, list[ToolResult]]:
+    """Re-check modified files and fix again until nothing is left to fix.
+
+    The first pass has already run and been recorded in ``tracker``. Each
+    further pass re-runs every fixer's check on the files modified so far
+    and fixes again with the fixers that still find fixable issues. It stops
+    early when fixers revert each other.
+
+    Args:
+        tracker: Tracker holding the first pass's changes.
+        fixers: Fixers in run order.
+        check: Runs a tool's check on files.
+        fix: Runs a tool's fix on files.
+        definitions: Tool definitions keyed by tool name.
+        max_passes: Maximum fix passes, including the first.
+
+    Returns:
+        tuple[FixPipelineReport, list[ToolResult]]: The report and the fix
+        results of the extra passes.
+    """
+    report = FixPipelineReport(order=list(fixers), passes=1)
+    extra_results: list[ToolResult] = []
+    while not tracker.conflicts:
+        files = sorted(tracker.modified_files)
+        pending = (
+            [
+                name
+                for name in fixers
+                if needs_another_pass(check(name, files), definitions.get(name))
+            ]
+            if files
+            else []
+        )
+        if not pending:
+            report.stable = True
+            break
+        if report.passes >= max_passes:
+            break
+        report.passes += 1
+        for name in pending:
+            extra_results.append(fix(name, files))
+            tracker.record(name)
+    report.modified_files = set(tracker.modified_files)
+    report.conflicts = list(tracker.conflicts)
+    return report, extra_results
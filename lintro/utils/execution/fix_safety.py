@@ -0,0 +1,63 @@
+"""Fix safety policy for ``lintro format``.
+
+Safe fixes keep the code's behavior; unsafe ones (ruff's unsafe fixes,
+Clippy's maybe-incorrect suggestions, Biome's and RuboCop's unsafe
+corrections) may change it. ``execution.fix_safety`` and ``--fix-safety``
+decide whether those are applied by setting each tool's own unsafe option,
+so a plain ``lintro format`` only makes safe fixes.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from lintro.enums.fix_safety import FixSafetyPolicy
+
+if TYPE_CHECKING:
+    from lintro.plugins.base import BaseToolPlugin
+
+# Option of each tool that turns on its unsafe fixes
+UNSAFE_FIX_OPTIONS: dict[str, str] = {
+    "biome": "unsafe",
+    "clippy": "unsafe_fixes",
+    "rubocop": "unsafe",
+    "ruff": "unsafe_fixes",
+}
+
+
+def fix_safety_options(policy: str) -> dict[str, dict[str, object]]:
+    """Build the per-tool options that apply a fix safety policy.
+
+    Args:
+        policy: ``safe`` or ``all``.
+
+    Returns:
+        dict[str, dict[str, object]]: Unsafe option of every tool that has
+        one, turned on only for ``all``.
+    """
+    unsafe = FixSafetyPolicy(policy) == FixSafetyPolicy.ALL
+    return {tool: {option: unsafe} for tool, option in UNSAFE_FIX_OPTIONS.items()}
+
+
+def apply_fix_safety(
+    tool: BaseToolPlugin,
+    tool_name: str,
+    policy: str,
+    overrides: dict[str, object],
+) -> None:
+    """Set a tool's unsafe option from the configured policy.
+
+    The policy replaces the option from the tool's config section; an
+    option given on the command line (``--fix-safety`` or
+    ``--tool-options``) is left alone.
+
+    Args:
+        tool: Tool plugin about to fix.
+        tool_name: Name of the tool.
+        policy: ``execution.fix_safety``, ``safe`` or ``all``.
+        overrides: Options given on the command line for the tool.
+    """
+    option = UNSAFE_FIX_OPTIONS.get(tool_name.lower())
+    if option is None or option in overrides:
+        return
+    tool.set_options(**fix_safety_options(policy)[tool_name.lower()])
@@ -0,0 +1,284 @@
+"""Reviewing proposed fixes hunk by hunk before applying them.
+
+``lintro format --interactive`` computes every fix in a scratch copy of the
+project (see :mod:`lintro.utils.execution.dry_run`) and then walks through
+the changes one hunk at a time, like ``git add -p``. Each hunk can be
+accepted, rejected or edited; only the accepted result is written to the
+project. Reviewed files keep their line endings: when a fixer converts them,
+for example from CRLF to LF, the hunks show only the other changes.
+"""
+
+from __future__ import annotations
+
+import difflib
+import os
+from collections.abc import Callable, Sequence
+from dataclasses import dataclass
+
+import click
+
+from lintro.utils.execution.dry_run import (
+    LINE_ENDING_NAMES,
+    FileChange,
+    convert_line_endings,
+    ending_change,
+    line_ending,
+)
+
+# Context lines around each hunk, as in ``git diff``
+HUNK_CONTEXT: int = 3
+
+REVIEW_HELP: str = """\
+y - apply this hunk
+n - skip this hunk
+e - edit the fixed lines, then apply them
+a - apply this hunk and the rest of the file
+d - skip this hunk and the rest of the file
+q - skip this hunk and everything after it
+? - show this help"""
+
+
+@dataclass(frozen=True)
+class Hunk:
+    """One group of nearby changed lines within a file.
+
+    Attributes:
+        start: Index of the first original line the hunk covers.
+        after_start: Index of the first fixed line of the hunk.
+        before: Original lines covered by the hunk, including context.
+        after: Fixed lines replacing them, including context.
+    """
+
+    start: int
+    after_start: int
+    before: list[str]
+    after: list[str]
+
+    def diff(self) -> str:
+        """Render the hunk for review.
+
+        Returns:
+            str: Hunk header and lines in unified diff format.
+        """
+        lines = [
+            f"@@ -{self.start + 1},{len(self.before)} "
+            f"+{self.after_start + 1},{len(self.after)} @@",
+        ]
+        matcher = difflib.SequenceMatcher(a=self.before, b=self.after, autojunk=False)
+        for tag, i1, i2, j1, j2 in matcher.get_opcodes():
+            if tag == "equal":
+                lines.extend(f" {line}" for line in self.before[i1:i2])
+                continue
+            lines.extend(f"-{line}" for line in self.before[i1:i2])
+            lines.extend(f"+{line}" for line in self.after[j1:j2])
+        return "\n".join(line.rstrip("\n") for line in lines)
+
+
+def split_hunks(before: str, after: str, context: int = HUNK_CONTEXT) -> list[Hunk]:
+    """Split a file change into independently applicable hunks.
+
+    Args:
+        before: Original text.
+        after: Fixed text.
+        context: Unchanged lines kept around each hunk.
+
+    Returns:
+        list[Hunk]: Hunks in file order; they never overlap.
+    """
+    a = before.splitlines(keepends=True)
+    b = after.splitlines(keepends=True)
+    matcher = difflib.SequenceMatcher(a=a, b=b, autojunk=False)
+    hunks: list[Hunk] = []
+    for group in matcher.get_grouped_opcodes(context):
+        i1, j1 = group[0][1], group[0][3]
+        i2, j2 = group[-1][2], group[-1][4]
+        hunks.append(
+            Hunk(start=i1, after_start=j1, before=a[i1:i2], after=b[j1:j2]),
+        )
+    return hunks
+
+
+def apply_hunks(
+    before: str,
+    hunks: Sequence[Hunk],
+    replacements: Sequence[list[str] | None],
+) -> str:
+    """Apply the chosen replacement for each hunk to the original text.
+
+    Args:
+        before: Original text.
+        hunks: Hunks from :func:`split_hunks`.
+        replacements: Lines replacing each hunk, or None to keep the original.
+
+    Returns:
+        str: Resulting text.
+    """
+    lines = before.splitlines(keepends=True)
+    # Apply from the end so earlier hunk positions stay valid
+    for hunk, replacement in reversed(list(zip(hunks, replacements, strict=True))):
+        if replacement is not None:
+            lines[hunk.start : hunk.start + len(hunk.before)] = replacement
+    return "".join(lines)
+
+
+def _edit_lines(
+    hunk: Hunk,
+    edit: Callable[[str], str | None],
+) -> list[str] | None:
+    """Let the user edit the fixed lines of a hunk.
+
+    Args:
+        hunk: Hunk being reviewed.
+        edit: Opens text in an editor; returns None if nothing was saved.
+
+    Returns:
+        list[str] | None: Edited lines, or None if the edit was abandoned.
+    """
+    edited = edit("".join(hunk.after))
+    if edited is None:
+        return None
+    ending = line_ending("".join(hunk.after)) or "\n"
+    lines = [
+        line if line.endswith(("\n", "\r")) else f"{line}{ending}"
+        for line in edited.splitlines(keepends=True)
+    ]
+    # Keep a missing final newline when the fixer produced one
+    if lines and hunk.after and not hunk.after[-1].endswith(("\n", "\r")):
+        lines[-1] = lines[-1].rstrip("\r\n")
+    return lines
+
+
+def _review_file(
+    change: FileChange,
+    prompt: Callable[[str], str],
+    edit: Callable[[str], str | None],
+    echo: Callable[[str], None],
+) -> tuple[bytes | None, bool]:
+    """Review one changed file hunk by hunk.
+
+    Args:
+        change: Change proposed for the file.
+        prompt: Asks a question and returns the answer.
+        edit: Opens text in an editor.
+        echo: Prints a line.
+
+    Returns:
+        tuple[bytes | None, bool]: Reviewed contents (the original contents
+        if nothing was accepted, None for an accepted deletion) and whether
+        the user stopped the review.
+    """
+    try:
+        before = (change.before or b"").decode("utf-8")
+        after = (change.after or b"").decode("utf-8")
+        binary = False
+    except UnicodeDecodeError:
+        before = after = ""
+        binary = True
+    if binary or change.before is None or change.after is None:
+        # Created, deleted and binary files are reviewed as a whole
+        echo(change.diff().rstrip("\n"))
+        answer = ""
+        while answer not in ("y", "n", "q"):
+            answer = prompt(f"Apply change to {change.path} [y,n,q]?").lower()
+        return (change.after if answer == "y" else change.before), answer == "q"
+
+    endings = ending_change(before, after)
+    if endings is not None:
+        old, new = endings
+        echo(
+            f"Keeping the {LINE_ENDING_NAMES[old]} line endings of {change.path} "
+            f"(the fixers use {LINE_ENDING_NAMES[new]})",
+        )
+        after = convert_line_endings(after, old)
+    hunks = split_hunks(before, after)
+    if not hunks:
+        return change.before, False
+    replacements: list[list[str] | None] = [None] * len(hunks)
+    echo(f"--- a/{change.path}\n+++ b/{change.path}")
+    index = 0
+    stopped = False
+    while index < len(hunks):
+        hunk = hunks[index]
+        echo(hunk.diff())
+        answer = prompt(
+            f"({index + 1}/{len(hunks)}) Apply this hunk [y,n,e,a,d,q,?]?",
+        ).lower()
+        if answer == "y":
+            replacements[index] = hunk.after
+        elif answer == "n":
+            pass
+        elif answer == "e":
+            edited = _edit_lines(hunk, edit)
+            if edited is None:
+                continue
+            replacements[index] = edited
+        elif answer == "a":
+            for rest in range(index, len(hunks)):
+                replacements[rest] = hunks[rest].after
+            break
+        elif answer == "d":
+            break
+        elif answer == "q":
+            stopped = True
+            break
+        else:
+            echo(REVIEW_HELP)
+            continue
+        index += 1
+    if all(r is None for r in replacements):
+        return change.before, stopped
+    return apply_hunks(before, hunks, replacements).encode("utf-8"), stopped
+
+
+def review_changes(
+    changes: Sequence[FileChange],
+    prompt: Callable[[str], str] | None = None,
+    edit: Callable[[str], str | None] | None = None,
+    echo: Callable[[str], None] = click.echo,
+) -> list[FileChange]:
+    """Walk through proposed changes and collect what the user accepts.
+
+    Args:
+        changes: Changes from a dry run, in path order.
+        prompt: Asks a question and returns the answer; defaults to a
+            terminal prompt.
+        edit: Opens text in an editor; defaults to ``$EDITOR``.
+        echo: Prints a line.
+
+    Returns:
+        list[FileChange]: Accepted changes, with ``after`` holding the
+        reviewed contents.
+    """
+    prompt = prompt or (
+        lambda text: str(
+            click.prompt(text, default="", show_default=False, prompt_suffix=" "),
+        )
+    )
+    edit = edit or (lambda text: click.edit(text, extension=".txt"))
+    accepted: list[FileChange] = []
+    for change in changes:
+        result, stopped = _review_file(change, prompt, edit, echo)
+        if result != change.before:
+            accepted.append(
+                FileChange(path=change.path, before=change.before, after=result),
+            )
+        if stopped:
+            break
+    return accepted
+
+
+def write_changes(changes: Sequence[FileChange], root: str) -> None:
+    """Write accepted changes to the project.
+
+    Args:
+        changes: Accepted changes.
+        root: Project root the change paths are relative to.
+    """
+    for change in changes:
+        path = os.path.join(root, *change.path.split("/"))
+        if change.after is None:
+            os.remove(path)
+            continue
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        with open(path, "wb") as f:
+            f.write(change.after)
@@ -0,0 +1,228 @@
+"""User scripts that post-process the issue stream.
+
+``output.script`` names a Lua file that defines a global ``process(issue)``
+function. It is called once per issue, after the ``severity`` and label
+overrides and before ``min_severity`` filtering, deduplication and
+formatting, with a table holding the issue's ``tool``, ``file``, ``line``,
+``column``, ``code``, ``message``, ``severity`` (``error``, ``warning`` or
+``info``) and ``fixable`` fields.
+
+Returning ``nil`` or ``false`` drops the issue. Returning a table keeps it:
+a changed ``severity`` or ``message`` replaces the issue's own, and keys
+that are not issue fields are kept as computed fields in JSON reports. The
+other fields are read-only.
+
+Scripts run in Lua 5.4 through the optional ``lupa`` package (``pip install
+'lintro[scripting]'``), without access to Python objects.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+from dataclasses import dataclass, replace
+from pathlib import Path
+from typing import Any
+
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.severity_level import normalize_severity_level
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code, issue_fixable
+from lintro.utils.execution.exit_codes import success_after_filter
+
+ENTRY_POINT: str = "process"
+ISSUE_FIELDS: tuple[str, ...] = (
+    "tool",
+    "file",
+    "line",
+    "column",
+    "code",
+    "message",
+    "severity",
+    "fixable",
+)
+
+
+@dataclass(frozen=True)
+class IssueScript:
+    """A loaded ``output.script`` hook.
+
+    Attributes:
+        path: Script file.
+        process: Calls the script's ``process`` function with an issue's
+            fields and returns the fields to keep, or None to drop it.
+    """
+
+    path: Path
+    process: Callable[[dict[str, Any]], dict[str, Any] | None]
+
+
+def _lupa() -> Any:
+    """Import the Lua runtime.
+
+    Returns:
+        Any: The ``lupa`` module.
+
+    Raises:
+        RuntimeError: If lupa is not installed.
+    """
+    try:
+        import lupa
+    except ImportError as e:
+        raise RuntimeError(
+            "output.script requires lupa; "
+            "install it with: pip install 'lintro[scripting]'",
+        ) from e
+    return lupa
+
+
+def load_issue_script(path: str | Path) -> IssueScript:
+    """Load a Lua script and find its ``process`` function.
+
+    Args:
+        path: Lua script file.
+
+    Returns:
+        IssueScript: Hook calling the script.
+
+    Raises:
+        RuntimeError: If lupa is missing or the script cannot be loaded.
+    """
+    script_path = Path(path)
+    lupa = _lupa()
+    try:
+        source = script_path.read_text(encoding="utf-8")
+    except OSError as e:
+        raise RuntimeError(f"Cannot read output.script {script_path}: {e}") from e
+
+    # Scripts see plain Lua only, not Python builtins or eval
+    lua = lupa.LuaRuntime(register_eval=False, register_builtins=False)
+    try:
+        lua.execute(source)
+    except lupa.LuaError as e:
+        raise RuntimeError(f"Error in output.script {script_path}: {e}") from e
+    function = lua.globals()[ENTRY_POINT]
+    if lupa.lua_type(function) != "function":
+        raise RuntimeError(
+            f"output.script {script_path} does not define a "
+            f"function {ENTRY_POINT}(issue)",
+        )
+
+    def process(fields: dict[str, Any]) -> dict[str, Any] | None:
+        """Call the script with an issue's fields.
+
+        Args:
+            fields: Issue fields.
+
+        Returns:
+            dict[str, Any] | None: Fields returned by the script, or None
+            if it dropped the issue.
+
+        Raises:
+            RuntimeError: If the script fails or returns something other
+                than a table, nil or false.
+        """
+        try:
+            returned = function(lua.table_from(fields))
+        except lupa.LuaError as e:
+            raise RuntimeError(f"Error in output.script {script_path}: {e}") from e
+        if returned is None or returned is False:
+            return None
+        if lupa.lua_type(returned) != "table":
+            raise RuntimeError(
+                f"output.script {script_path}: {ENTRY_POINT}() must return "
+                "a table, nil or false",
+            )
+        return {str(key): value for key, value in returned.items()}
+
+    return IssueScript(path=script_path, process=process)
+
+
+def load_configured_script(lintro_config: LintroConfig) -> IssueScript | None:
+    """Load the script named by ``output.script``.
+
+    The path is relative to the config file, or to the working directory
+    when no config file was found.
+
+    Args:
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        IssueScript | None: Loaded hook, or None if no script is configured.
+    """
+    script = lintro_config.output.script
+    if not script:
+        return None
+    root = (
+        Path(lintro_config.config_path).parent
+        if lintro_config.config_path
+        else Path.cwd()
+    )
+    return load_issue_script(root / script)
+
+
+def apply_issue_script(
+    result: ToolResult,
+    script: IssueScript | None,
+) -> ToolResult:
+    """Run each issue of a result through the script.
+
+    A result whose issues are all dropped is marked successful, since
+    issues were the reason it failed.
+
+    Args:
+        result: Result after the severity and label overrides.
+        script: Loaded hook, or None to keep the issues unchanged.
+
+    Returns:
+        ToolResult: Result with the kept, updated issues.
+
+    Raises:
+        RuntimeError: If the script fails or returns an invalid severity.
+    """
+    if script is None or result.skipped or not result.issues:
+        return result
+
+    tool = result.name.lower()
+    kept = []
+    for issue in result.issues:
+        fields: dict[str, Any] = {
+            "tool": tool,
+            "file": issue.file,
+            "line": issue.line,
+            "column": issue.column,
+            "code": issue_code(issue),
+            "message": getattr(issue, "message", "") or "",
+            "severity": issue.get_severity().value.lower(),
+            "fixable": issue_fixable(issue),
+        }
+        returned = script.process(dict(fields))
+        if returned is None:
+            continue
+
+        changes: dict[str, Any] = {}
+        severity = returned.get("severity")
+        if severity != fields["severity"] and severity is not None:
+            try:
+                changes["severity_override"] = normalize_severity_level(str(severity))
+            except ValueError as e:
+                raise RuntimeError(f"output.script {script.path}: {e}") from e
+        message = returned.get("message")
+        if message != fields["message"] and message is not None:
+            message_attr = issue.DISPLAY_FIELD_MAP.get("message", "message")
+            changes[message_attr] = str(message)
+        computed = {
+            key: str(value)
+            for key, value in returned.items()
+            if key not in ISSUE_FIELDS and value is not None
+        }
+        if computed:
+            changes["script_fields"] = {**issue.script_fields, **computed}
+        kept.append(replace(issue, **changes) if changes else issue)
+
+    return replace(
+        result,
+        issues=kept,
+        issues_count=len(kept),
+        success=success_after_filter(result, len(kept)),
+        formatted_output=None,
+    )
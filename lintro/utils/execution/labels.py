@@ -0,0 +1,96 @@
+"""Directory labels from the ``labels`` config section.
+
+Directories are tagged with labels such as ``critical``; each label may have
+a policy under ``label_policies``. Tool selection runs a policy's tools on
+its labelled directories, the severity filter applies its overrides to
+issues found there, and the failure policy holds those issues to its
+stricter thresholds.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from dataclasses import dataclass
+from pathlib import Path
+
+from lintro.config.lintro_config import LintroConfig
+
+
+@dataclass(frozen=True)
+class DirectoryLabels:
+    """Labels attached to directories.
+
+    Attributes:
+        directories: Resolved directories and their labels, in config order.
+    """
+
+    directories: tuple[tuple[Path, tuple[str, ...]], ...] = ()
+
+    @classmethod
+    def from_config(cls, lintro_config: LintroConfig) -> DirectoryLabels:
+        """Resolve the labelled directories of a config.
+
+        Directories are relative to the config file, or to the working
+        directory when no config file was found.
+
+        Args:
+            lintro_config: Loaded Lintro configuration.
+
+        Returns:
+            DirectoryLabels: Labels by resolved directory.
+        """
+        root = (
+            Path(lintro_config.config_path).parent
+            if lintro_config.config_path
+            else Path.cwd()
+        )
+        return cls(
+            tuple(
+                ((root / directory).resolve(), tuple(labels))
+                for directory, labels in lintro_config.labels.items()
+                if labels
+            ),
+        )
+
+    def __bool__(self) -> bool:
+        """Check whether any directory is labelled.
+
+        Returns:
+            bool: True if at least one directory has labels.
+        """
+        return bool(self.directories)
+
+    def labels_for(self, path: str) -> tuple[str, ...]:
+        """Return the labels of every labelled directory containing a path.
+
+        Args:
+            path: File path as reported by a tool, relative to the working
+                directory or absolute.
+
+        Returns:
+            tuple[str, ...]: Labels without duplicates, in config order.
+        """
+        if not path or not self.directories:
+            return ()
+        resolved = Path(path).resolve()
+        found: list[str] = []
+        for directory, labels in self.directories:
+            if resolved == directory or directory in resolved.parents:
+                found.extend(label for label in labels if label not in found)
+        return tuple(found)
+
+    def directories_for(self, labels: Iterable[str]) -> list[Path]:
+        """Return the directories tagged with any of the given labels.
+
+        Args:
+            labels: Labels to look for.
+
+        Returns:
+            list[Path]: Matching directories, in config order.
+        """
+        wanted = set(labels)
+        return [
+            directory
+            for directory, directory_labels in self.directories
+            if wanted.intersection(directory_labels)
+        ]
@@ -6,25 +6,46 @@ This module provides functions for running tools in parallel using async executi
 from __future__ import annotations
 
 import asyncio
-import sys
+from collections.abc import Callable
+from functools import partial
 from typing import TYPE_CHECKING
 
-from rich.progress import (
-    BarColumn,
-    Progress,
-    SpinnerColumn,
-    TaskProgressColumn,
-    TextColumn,
-)
-
 from lintro.enums.action import Action
 from lintro.models.core.tool_result import ToolResult
 from lintro.tools import tool_manager
-from lintro.utils.execution.tool_configuration import configure_tool_for_execution
+from lintro.utils.execution.fail_fast import FailFast, cancelled_result
+from lintro.utils.execution.run_progress import RunProgress, live_progress
+from lintro.utils.execution.tool_configuration import (
+    configure_tool_for_execution,
+    run_subtree_scopes,
+)
+from lintro.utils.result_cache import check_with_cache
 from lintro.utils.unified_config import UnifiedConfigManager
 
 if TYPE_CHECKING:
+    from lintro.config.config_hierarchy import ToolScopePlan
     from lintro.plugins.base import BaseToolPlugin
+    from lintro.utils.timing_profile import TimingProfile
+
+
+def _run_scope(
+    tool: BaseToolPlugin,
+    action: Action,
+    paths: list[str],
+) -> ToolResult:
+    """Check or fix a subtree as the parallel workers do.
+
+    Args:
+        tool: Configured tool plugin.
+        action: Check or fix.
+        paths: Paths of the subtree.
+
+    Returns:
+        ToolResult: Result of the run.
+    """
+    if action == Action.FIX:
+        return tool.fix(paths, {})
+    return check_with_cache(tool, paths, {})
 
 
 def run_tools_parallel(
@@ -39,6 +60,12 @@ def run_tools_parallel(
     max_workers: int,
     incremental: bool = False,
     auto_install: bool = False,
+    tool_scopes: dict[str, ToolScopePlan] | None = None,
+    progress: RunProgress | None = None,
+    gitignore: bool = True,
+    fail_fast: FailFast | None = None,
+    timing_profile: TimingProfile | None = None,
+    slow_tools: set[str] | None = None,
 ) -> list[ToolResult]:
     """Run tools in parallel using async executor.
 
@@ -54,6 +81,19 @@ def run_tools_parallel(
         max_workers: Maximum parallel workers.
         incremental: Whether to only check changed files.
         auto_install: Whether to auto-install Node.js deps if missing.
+        tool_scopes: Optional per-tool scope plans from nested configs.
+        progress: Tool states shown live while the batches run, or None to
+            show nothing.
+        gitignore: False when ``--no-gitignore`` turns off
+            execution.gitignore.
+        fail_fast: Tracker that stops the run once its results exceed the
+            failure policy; tools not yet started are reported as
+            cancelled. None runs every tool.
+        timing_profile: Profile the tools' phases are recorded in, or None
+            when not profiling.
+        slow_tools: Tools expected to run long (see scheduling). They start
+            first, holding their own workers while the quick tools share the
+            rest; with fail_fast they start after the quick tools instead.
 
     Returns:
         List of ToolResult objects.
@@ -66,40 +106,40 @@ def run_tools_parallel(
     )
 
     # Group tools into batches that can run in parallel
-    batches = get_parallel_batches(tools_to_run, tool_manager)
+    slow = [name for name in tools_to_run if name in (slow_tools or set())]
+    quick = [name for name in tools_to_run if name not in slow]
+    if slow and fail_fast is not None:
+        # A failure found by a quick tool cancels the slow ones before they start
+        batches = [
+            *get_parallel_batches(quick, tool_manager),
+            *get_parallel_batches(slow, tool_manager),
+        ]
+    else:
+        batches = get_parallel_batches([*slow, *quick], tool_manager)
     logger.debug(f"Parallel execution batches: {batches}")
 
     all_results: list[ToolResult] = []
     executor = AsyncToolExecutor(max_workers=max_workers)
-    total_tools = len(tools_to_run)
-
-    # Disable progress when not in a TTY
-    disable_progress = not sys.stdout.isatty()
+    scopes = tool_scopes or {}
+    paths_per_tool = {name: plan.paths for name, plan in scopes.items()}
 
     try:
-        with Progress(
-            SpinnerColumn(),
-            TextColumn("[progress.description]{task.description}"),
-            BarColumn(),
-            TaskProgressColumn(),
-            transient=True,
-            disable=disable_progress,
-        ) as progress:
-            task = progress.add_task(
-                f"Running {total_tools} tools...",
-                total=total_tools,
-            )
-            completed_count = 0
-
+        with live_progress(progress):
             for batch in batches:
+                if fail_fast is not None and fail_fast.stopped():
+                    all_results.extend(cancelled_result(name) for name in batch)
+                    continue
+
                 # Prepare tools in batch
                 tools_with_instances: list[tuple[str, BaseToolPlugin]] = []
+                configures: dict[str, Callable[..., None]] = {}
 
                 for tool_name in batch:
                     tool = tool_manager.get_tool(tool_name)
 
                     # Configure tool using shared helper
-                    configure_tool_for_execution(
+                    configures[tool_name] = partial(
+                        configure_tool_for_execution,
                         tool=tool,
                         tool_name=tool_name,
                         config_manager=config_manager,
@@ -110,37 +150,28 @@ def run_tools_parallel(
                         action=action,
                         post_tools=post_tools,
                         auto_install=auto_install,
+                        gitignore=gitignore,
                     )
+                    configures[tool_name](scope=scopes.get(tool_name))
 
                     tools_with_instances.append((tool_name, tool))
+                    if progress is not None:
+                        progress.start(tool_name)
 
-                # Update progress description for this batch
-                batch_names = ", ".join(batch)
-                progress.update(
-                    task,
-                    description=f"Running: {batch_names}",
-                )
-
-                # Create callback to update progress on completion
                 def on_tool_complete(
                     name: str,
                     result: ToolResult,
                 ) -> None:
-                    """Update progress when a tool completes.
+                    """Update progress and fail-fast when a tool completes.
 
                     Args:
                         name: Name of the completed tool.
                         result: Result from the tool execution.
                     """
-                    nonlocal completed_count
-                    completed_count += 1
-                    status = "✓" if result.success else "✗"
-                    desc = f"{status} {name} done ({completed_count}/{total_tools})"
-                    progress.update(
-                        task,
-                        completed=completed_count,
-                        description=desc,
-                    )
+                    if progress is not None:
+                        progress.finish(name, result)
+                    if fail_fast is not None:
+                        fail_fast.record(result)
 
                 # Run batch in parallel with progress callback
                 batch_results = asyncio.run(
@@ -149,12 +180,25 @@ def run_tools_parallel(
                         paths=paths,
                         action=action,
                         on_result=on_tool_complete,
+                        paths_per_tool=paths_per_tool,
+                        cancelled=fail_fast.stopped if fail_fast else None,
+                        timing_profile=timing_profile,
                     ),
                 )
 
-                # Collect results
-                for _, result in batch_results:
-                    all_results.append(result)
+                # Collect results; subtrees with their own settings run after
+                # the batch, since each tool instance runs one scope at a time
+                for name, result in batch_results:
+                    tool = tool_manager.get_tool(name)
+                    all_results.append(
+                        run_subtree_scopes(
+                            tool,
+                            result,
+                            scopes.get(name),
+                            configure=configures[name],
+                            run=partial(_run_scope, tool, action),
+                        ),
+                    )
 
     finally:
         executor.shutdown()
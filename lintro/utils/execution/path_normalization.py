@@ -0,0 +1,56 @@
+"""Normalization of the file paths in tool results.
+
+Tools print the files of their issues in different shapes, especially on
+Windows runners: ``\\`` or ``/`` separators (sometimes mixed in one path),
+``c:`` or ``C:`` drive letters, absolute or relative paths. Before any other
+stage looks at the issues, each issue's file is rewritten relative to the
+project root with forward slashes, so that directory labels, deduplication
+and reports attribute issues to the same file whichever tool reported them.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Mapping
+from dataclasses import replace
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.path_utils import relative_to_root
+
+
+def normalize_issue_paths(
+    result: ToolResult,
+    root: str | None = None,
+    aliases: Mapping[str, str] | None = None,
+) -> ToolResult:
+    """Rewrite the file of each issue relative to the project root.
+
+    Args:
+        result: Result returned by a tool.
+        root: Absolute project root; defaults to the working directory.
+        aliases: Normalized paths reported under another name, such as the
+            temporary copy of a buffer checked with ``--stdin``.
+
+    Returns:
+        ToolResult: Result with normalized issue paths, or the original
+        result if every path was normalized already.
+    """
+    if not result.issues:
+        return result
+
+    base = root or os.getcwd()
+    changed = False
+    issues = []
+    for issue in result.issues:
+        file_path = getattr(issue, "file", "") or ""
+        normalized = relative_to_root(file_path, base)
+        if aliases:
+            normalized = aliases.get(normalized, normalized)
+        if normalized != file_path:
+            issue = replace(issue, file=normalized)
+            changed = True
+        issues.append(issue)
+    if not changed:
+        return result
+
+    return replace(result, issues=issues, formatted_output=None)
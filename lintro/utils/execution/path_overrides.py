@@ -0,0 +1,212 @@
+"""Per-path tool and rule overrides from the ``overrides`` config section.
+
+Each override relaxes checks for the files matching its gitignore-style
+patterns, relative to the config file::
+
+    overrides:
+      - paths: ["tests/**"]
+        disable: [bandit]
+        ignore: ["clippy::unwrap_used"]
+
+The overrides of a nested config are relative to that config and only
+cover its subtree. Tool selection gives disabled tools the patterns as
+extra excludes, so they do not process those files. Issues still reported
+there, as whole-project tools such as mypy may, are dropped afterwards
+together with the issues of ignored rules.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from dataclasses import dataclass, replace
+from pathlib import Path
+
+from lintro.config.lintro_config import LintroConfig
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.exit_codes import success_after_filter
+from lintro.utils.execution.suppressions import rule_matches
+from lintro.utils.path_filtering import should_exclude_path
+
+
+def _anchor(root: Path, pattern: str) -> str:
+    """Anchor a pattern at the config directory.
+
+    A pattern without a slash matches at any depth below the directory, as
+    in a ``.gitignore`` placed there.
+
+    Args:
+        root: Directory holding the config file.
+        pattern: Pattern relative to root.
+
+    Returns:
+        str: Gitignore-style pattern matched against absolute paths.
+    """
+    base = root.as_posix().strip("/")
+    if "/" not in pattern.rstrip("/"):
+        return f"{base}/**/{pattern}"
+    return f"{base}/{pattern.lstrip('/')}"
+
+
+@dataclass(frozen=True)
+class ResolvedOverride:
+    """An override with its patterns anchored at the config directory.
+
+    Attributes:
+        patterns: Anchored patterns of the matching files.
+        disable: Lower-cased names of the disabled tools.
+        ignore: Ignored rules, bare or ``tool:code``.
+    """
+
+    patterns: tuple[str, ...]
+    disable: frozenset[str] = frozenset()
+    ignore: tuple[str, ...] = ()
+
+    def covers(self, path: str) -> bool:
+        """Return whether the override applies to a file.
+
+        Args:
+            path: File path as reported by a tool.
+
+        Returns:
+            bool: True if the file matches a pattern.
+        """
+        return bool(path) and should_exclude_path(path, list(self.patterns))
+
+
+@dataclass(frozen=True)
+class PathOverrides:
+    """The overrides of a config.
+
+    Attributes:
+        overrides: Resolved overrides, in config order.
+    """
+
+    overrides: tuple[ResolvedOverride, ...] = ()
+
+    @classmethod
+    def from_config(
+        cls,
+        lintro_config: LintroConfig,
+        nested_dirs: Sequence[Path] = (),
+    ) -> PathOverrides:
+        """Resolve the overrides of a config and of nested configs.
+
+        Patterns are relative to the config file, or to the working
+        directory when no config file was found.
+
+        Args:
+            lintro_config: Loaded Lintro configuration.
+            nested_dirs: Directories holding nested config files (see
+                find_nested_config_dirs), whose own overrides are added.
+
+        Returns:
+            PathOverrides: Overrides with anchored patterns.
+        """
+        from lintro.config.config_hierarchy import load_own_config
+
+        configs = [lintro_config]
+        for directory in nested_dirs:
+            own = load_own_config(directory)
+            if own is not None:
+                configs.append(own)
+        resolved: list[ResolvedOverride] = []
+        for config in configs:
+            root = (
+                Path(config.config_path).parent.resolve()
+                if config.config_path
+                else Path.cwd()
+            )
+            resolved.extend(
+                ResolvedOverride(
+                    patterns=tuple(_anchor(root, p) for p in override.paths),
+                    disable=frozenset(t.lower() for t in override.disable),
+                    ignore=tuple(override.ignore),
+                )
+                for override in config.overrides
+            )
+        return cls(tuple(resolved))
+
+    def __bool__(self) -> bool:
+        """Check whether any override is configured.
+
+        Returns:
+            bool: True if at least one override exists.
+        """
+        return bool(self.overrides)
+
+    def excludes_for(self, tool_name: str) -> list[str]:
+        """Return the patterns of the files a tool is disabled for.
+
+        Args:
+            tool_name: Tool name.
+
+        Returns:
+            list[str]: Anchored exclude patterns.
+        """
+        name = tool_name.lower()
+        return [
+            pattern
+            for override in self.overrides
+            if name in override.disable
+            for pattern in override.patterns
+        ]
+
+    def drops(self, tool_name: str, code: str, path: str) -> bool:
+        """Return whether an override removes an issue.
+
+        Args:
+            tool_name: Tool reporting the issue.
+            code: Rule code of the issue.
+            path: File of the issue.
+
+        Returns:
+            bool: True if the tool is disabled or the rule ignored there.
+        """
+        name = tool_name.lower()
+        return any(
+            (
+                name in override.disable
+                or any(rule_matches(rule, name, code) for rule in override.ignore)
+            )
+            and override.covers(path)
+            for override in self.overrides
+        )
+
+
+def apply_path_overrides(result: ToolResult, overrides: PathOverrides) -> ToolResult:
+    """Drop the issues that per-path overrides disable or ignore.
+
+    A result whose issues are all dropped is marked successful, since
+    issues were the reason it failed.
+
+    Args:
+        result: Result returned by a tool.
+        overrides: Overrides of the config.
+
+    Returns:
+        ToolResult: Result without the dropped issues, or the original
+        result if none was dropped.
+    """
+    if not overrides or result.skipped or not result.issues:
+        return result
+    kept = [
+        issue
+        for issue in result.issues
+        if not overrides.drops(
+            result.name,
+            issue_code(issue),
+            getattr(issue, "file", "") or "",
+        )
+    ]
+    removed = len(result.issues) - len(kept)
+    if not removed:
+        return result
+    issues_count = max(result.issues_count - removed, len(kept))
+    return replace(
+        result,
+        issues=kept,
+        issues_count=issues_count,
+        success=success_after_filter(result, issues_count),
+        formatted_output=None,
+    )
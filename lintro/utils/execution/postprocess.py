@@ -0,0 +1,90 @@
+"""The issue handling every tool result goes through before it is counted.
+
+A result has its paths normalized, then per-path overrides, severity and
+label overrides, suppressions, the ``output.script`` hook, the minimum
+severity, the issue filter and the rule selection applied, in that order.
+Sequential and parallel runs, re-checks between fix passes and the
+``--fail-fast`` judge of a parallel run all use this one chain, so a result
+counts the same wherever it was produced.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Mapping, Sequence
+from pathlib import Path
+
+from lintro.config.label_config import LabelPolicy
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.issue_script import IssueScript, apply_issue_script
+from lintro.utils.execution.labels import DirectoryLabels
+from lintro.utils.execution.path_normalization import normalize_issue_paths
+from lintro.utils.execution.path_overrides import PathOverrides, apply_path_overrides
+from lintro.utils.execution.severity_filter import (
+    apply_label_severity,
+    apply_min_severity,
+    apply_severity_overrides,
+)
+from lintro.utils.execution.suppressions import apply_suppressions
+from lintro.utils.issue_query import IssueQuery, apply_issue_filter
+from lintro.utils.rule_selection import RuleSelection, apply_rule_selection
+
+# The result is displayed and counted; script errors are reported
+STAGE_REPORT = "report"
+# The result only decides whether --fail-fast stops the run; the report
+# stage runs the script again and reports its errors
+STAGE_JUDGE = "judge"
+
+
+def postprocess_result(
+    result: ToolResult,
+    *,
+    aliases: Mapping[str, str] | None,
+    path_overrides: PathOverrides,
+    severity_overrides: Mapping[str, str] | None,
+    directory_labels: DirectoryLabels,
+    label_policies: Mapping[str, LabelPolicy],
+    issue_script: IssueScript | None,
+    min_severity: str | SeverityLevel | None,
+    issue_filter: IssueQuery | None,
+    rule_selection: RuleSelection | None,
+    nested_severity: Sequence[tuple[Path, Mapping[str, str]]] = (),
+    stage: str = STAGE_REPORT,
+) -> ToolResult:
+    """Apply the run's issue handling to a tool result.
+
+    Args:
+        result: Result returned by a tool.
+        aliases: Paths to report issues under, as for --stdin-filename.
+        path_overrides: Per-path rule overrides.
+        severity_overrides: Configured per-rule severities.
+        directory_labels: Labels of the project's directories.
+        label_policies: Severity overrides per label.
+        issue_script: Loaded ``output.script`` hook, or None.
+        min_severity: Lowest severity kept, or None to keep all.
+        issue_filter: Query selecting the issues kept, or None.
+        rule_selection: Rules kept, from --select and --ignore, or None.
+        nested_severity: Severity overrides of nested config subtrees (see
+            nested_severity in config_hierarchy).
+        stage: STAGE_REPORT, or STAGE_JUDGE to keep the issues the script
+            fails on instead of raising.
+
+    Returns:
+        ToolResult: Result as the run displays and counts it.
+
+    Raises:
+        RuntimeError: If the issue script fails in the report stage.
+    """
+    result = normalize_issue_paths(result, aliases=aliases)
+    result = apply_path_overrides(result, path_overrides)
+    result = apply_severity_overrides(result, severity_overrides, nested_severity)
+    result = apply_label_severity(result, directory_labels, label_policies)
+    result = apply_suppressions(result)
+    try:
+        result = apply_issue_script(result, issue_script)
+    except RuntimeError:
+        if stage != STAGE_JUDGE:
+            raise
+    result = apply_min_severity(result, min_severity)
+    result = apply_issue_filter(result, issue_filter)
+    return apply_rule_selection(result, rule_selection)
@@ -0,0 +1,170 @@
+"""Guard that detects tools writing to the workspace during a check.
+
+``lintro check --assert-read-only`` snapshots the workspace before the first
+tool runs and compares it after every tool. A tool that creates, modifies
+(even with identical contents) or deletes a file is reported and fails the
+run, which catches tools that are misconfigured to reformat during a check.
+
+The snapshot covers every file under the checked paths except the default
+excludes, so tool caches (``*cache*``), build output and lintro's own
+``.lintro`` directory do not count as writes.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Sequence
+from dataclasses import dataclass, replace
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.file_discovery import DEFAULT_EXCLUDE_PATTERNS
+from lintro.utils.path_filtering import walk_files_with_excludes
+
+# Directories tools and lintro write to by design
+GUARD_EXCLUDE_PATTERNS: list[str] = [".lintro", "target", "node_modules"]
+
+
+@dataclass(frozen=True)
+class WorkspaceWrite:
+    """One file a tool wrote during a check.
+
+    Attributes:
+        tool: Tool that was running.
+        file: File that was written.
+        kind: "created", "modified" or "deleted".
+    """
+
+    tool: str
+    file: str
+    kind: str
+
+
+def snapshot_files(files: Sequence[str]) -> dict[str, tuple[int, int]]:
+    """Record the modification time and size of files.
+
+    Args:
+        files: File paths.
+
+    Returns:
+        dict[str, tuple[int, int]]: ``(mtime_ns, size)`` per path; files that
+        vanished while walking are omitted.
+    """
+    state: dict[str, tuple[int, int]] = {}
+    for path in files:
+        try:
+            stat = os.stat(path)
+        except OSError:
+            continue
+        state[path] = (stat.st_mtime_ns, stat.st_size)
+    return state
+
+
+class ReadOnlyGuard:
+    """Detect workspace writes between tools."""
+
+    def __init__(
+        self,
+        paths: list[str],
+        exclude: str | None = None,
+        include_venv: bool = False,
+    ) -> None:
+        """Take the initial snapshot.
+
+        Args:
+            paths: Paths being checked.
+            exclude: Comma-separated exclude patterns, if any.
+            include_venv: Whether virtual environments are included.
+        """
+        self._paths = list(paths)
+        self._excludes = [*DEFAULT_EXCLUDE_PATTERNS, *GUARD_EXCLUDE_PATTERNS]
+        if exclude:
+            self._excludes.extend(p.strip() for p in exclude.split(",") if p.strip())
+        self._include_venv = include_venv
+        self._state = self._snapshot()
+        self.writes: list[WorkspaceWrite] = []
+
+    def _snapshot(self) -> dict[str, tuple[int, int]]:
+        """Snapshot every guarded file.
+
+        Returns:
+            dict[str, tuple[int, int]]: ``(mtime_ns, size)`` per path.
+        """
+        files = walk_files_with_excludes(
+            self._paths,
+            ["*"],
+            self._excludes,
+            self._include_venv,
+        )
+        return snapshot_files(files)
+
+    def record(self, tool: str) -> list[WorkspaceWrite]:
+        """Record the files a tool wrote since the previous snapshot.
+
+        Args:
+            tool: Tool that just ran.
+
+        Returns:
+            list[WorkspaceWrite]: Writes attributed to the tool.
+        """
+        state = self._snapshot()
+        writes = [
+            WorkspaceWrite(
+                tool=tool,
+                file=path,
+                kind=(
+                    "created"
+                    if path not in self._state
+                    else "deleted" if path not in state else "modified"
+                ),
+            )
+            for path in sorted(set(state) | set(self._state))
+            if state.get(path) != self._state.get(path)
+        ]
+        self.writes.extend(writes)
+        self._state = state
+        return writes
+
+    def describe(self) -> str:
+        """Summarize the writes in one line.
+
+        Returns:
+            str: Message naming the tools and the number of files.
+        """
+        tools = sorted({write.tool for write in self.writes})
+        files = {write.file for write in self.writes}
+        return (
+            f"Read-only check failed: {', '.join(tools)} wrote "
+            f"{len(files)} file(s) in the workspace."
+        )
+
+
+def format_writes(writes: Sequence[WorkspaceWrite]) -> str:
+    """List a tool's writes for the console and reports.
+
+    Args:
+        writes: Writes attributed to one tool.
+
+    Returns:
+        str: Heading followed by one line per file.
+    """
+    listing = "\n".join(
+        f"  {write.kind}: {os.path.relpath(write.file)}" for write in writes
+    )
+    return f"Wrote to the workspace during a read-only check:\n{listing}"
+
+
+def flag_writes(result: ToolResult, writes: Sequence[WorkspaceWrite]) -> ToolResult:
+    """Fail a tool result whose tool wrote to the workspace.
+
+    Args:
+        result: Check result of the tool.
+        writes: Writes attributed to the tool.
+
+    Returns:
+        ToolResult: The result, failed and listing the writes if there are any.
+    """
+    if not writes:
+        return result
+    message = format_writes(writes)
+    output = f"{result.output}\n{message}" if result.output else message
+    return replace(result, success=False, output=output)
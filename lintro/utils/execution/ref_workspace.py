@@ -0,0 +1,164 @@
+"""Checking out a git ref to run the checks of an earlier commit.
+
+``lintro diff --against REF`` needs the issues the project had at ``REF``.
+The ref is checked out into a temporary git worktree, so the working tree
+and index are left alone, and the checks run there with the ref's own
+configuration. Dependency directories such as ``node_modules`` are linked
+from the project instead of being installed again.
+"""
+
+from __future__ import annotations
+
+import os
+import shutil
+import subprocess  # nosec B404 - used safely with shell disabled
+import tempfile
+from pathlib import Path
+from types import TracebackType
+
+from lintro.config.config_loader import clear_config_cache
+from lintro.enums.git_command import GitCommand
+from lintro.utils.execution.dry_run import LINKED_DIRS
+from lintro.utils.git_info import git_output
+from lintro.utils.vcs import UnavailableVcs, VcsProvider, detect_vcs
+
+# Seconds allowed for git to check out or remove the worktree
+WORKTREE_TIMEOUT: float = 300.0
+
+
+def _git(*args: str, cwd: str) -> subprocess.CompletedProcess[str]:
+    """Run a git worktree command.
+
+    Args:
+        *args: Arguments after ``git worktree``.
+        cwd: Directory inside the repository.
+
+    Returns:
+        subprocess.CompletedProcess[str]: The finished command.
+    """
+    return subprocess.run(  # nosec B603 - args list, shell=False
+        ["git", str(GitCommand.WORKTREE), *args],
+        capture_output=True,
+        text=True,
+        timeout=WORKTREE_TIMEOUT,
+        cwd=cwd,
+    )
+
+
+def _describe(vcs: VcsProvider) -> str:
+    """Name the repository a ref cannot be checked out from.
+
+    Args:
+        vcs: Detected version control provider.
+
+    Returns:
+        str: Short description for an error message.
+    """
+    if isinstance(vcs, UnavailableVcs):
+        return f"a directory without a git repository ({vcs.reason})"
+    return f"this {vcs.name} repository"
+
+
+class GitRefWorkspace:
+    """Temporary checkout of a git ref that the checks run in.
+
+    Entering checks the ref out into a temporary worktree and changes into
+    the directory matching the current one, so paths relative to it, and
+    the fingerprints computed from them, line up with the project. Run
+    reports still go to the project's ``.lintro`` directory. Exiting changes
+    back and removes the worktree.
+    """
+
+    def __init__(self, ref: str, cwd: str | None = None) -> None:
+        """Prepare a workspace for a ref.
+
+        Args:
+            ref: Commit, branch or tag to check out.
+            cwd: Directory inside the repository; defaults to the working
+                directory.
+        """
+        self.ref = ref
+        self.cwd = os.path.abspath(cwd or os.getcwd())
+        self.checkout = ""
+        self._temp_dir = ""
+        self._previous_cwd = ""
+        self._previous_log_dir: str | None = None
+
+    def __enter__(self) -> GitRefWorkspace:
+        """Check out the ref and change into it.
+
+        Returns:
+            GitRefWorkspace: This workspace.
+
+        Raises:
+            ValueError: If the directory is not in a git repository (a jj
+                repository colocated with git counts), the ref does not name
+                a commit or git cannot check it out.
+        """
+        vcs = detect_vcs(Path(self.cwd))
+        # A jj repository colocated with git still has git's worktrees
+        if vcs.root is None or not (vcs.root / ".git").exists():
+            raise ValueError(
+                f"--against checks the ref out with git worktree, which "
+                f"{_describe(vcs)} does not support; compare two saved reports "
+                "(lintro diff OLD NEW) instead",
+            )
+        top = git_output(GitCommand.REV_PARSE, "--show-toplevel", cwd=Path(self.cwd))
+        if top is None:
+            raise ValueError("--against needs a git repository")
+        commit = git_output(
+            GitCommand.REV_PARSE,
+            "--verify",
+            "--quiet",
+            f"{self.ref}^{{commit}}",
+            cwd=Path(self.cwd),
+        )
+        if commit is None:
+            raise ValueError(f"Unknown git ref: {self.ref}")
+
+        self._temp_dir = tempfile.mkdtemp(prefix="lintro-diff-")
+        self.checkout = os.path.join(self._temp_dir, "tree")
+        result = _git("add", "--detach", self.checkout, commit, cwd=self.cwd)
+        if result.returncode != 0:
+            shutil.rmtree(self._temp_dir, ignore_errors=True)
+            raise ValueError(
+                f"Cannot check out {self.ref}: {result.stderr.strip()}",
+            )
+
+        relative = os.path.relpath(os.path.realpath(self.cwd), os.path.realpath(top))
+        target = os.path.join(self.checkout, relative)
+        os.makedirs(target, exist_ok=True)
+        for name in LINKED_DIRS:
+            source = os.path.join(self.cwd, name)
+            link = os.path.join(target, name)
+            if os.path.isdir(source) and not os.path.lexists(link):
+                os.symlink(source, link, target_is_directory=True)
+
+        self._previous_cwd = os.getcwd()
+        self._previous_log_dir = os.environ.get("LINTRO_LOG_DIR")
+        if self._previous_log_dir is None:
+            os.environ["LINTRO_LOG_DIR"] = os.path.join(self.cwd, ".lintro")
+        os.chdir(target)
+        clear_config_cache()
+        return self
+
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None:
+        """Change back to the project and remove the worktree.
+
+        Args:
+            exc_type: Exception type, if one was raised.
+            exc: Exception, if one was raised.
+            traceback: Traceback, if an exception was raised.
+        """
+        os.chdir(self._previous_cwd)
+        if self._previous_log_dir is None:
+            os.environ.pop("LINTRO_LOG_DIR", None)
+        clear_config_cache()
+        _git("remove", "--force", self.checkout, cwd=self.cwd)
+        shutil.rmtree(self._temp_dir, ignore_errors=True)
+        _git("prune", cwd=self.cwd)
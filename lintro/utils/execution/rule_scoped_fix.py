@@ -0,0 +1,127 @@
+"""Fixing only selected rules.
+
+``lintro format --fix-only clippy::needless_return,ruff:I001`` applies the
+fixes for the listed rules and reports everything else without changing it,
+so a cleanup can land as one reviewable commit per rule. Tools with listed
+rules get them in their ``fixable`` option; every other tool only checks.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from dataclasses import replace
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.base import BaseToolPlugin
+
+# Tools whose fixers can be limited to selected rules
+RULE_SCOPED_FIXERS: frozenset[str] = frozenset({"clippy", "ruff"})
+
+
+def parse_fix_only(spec: str) -> dict[str, list[str]]:
+    """Parse a ``--fix-only`` value into rules per tool.
+
+    Items are ``tool:RULE``. Rust lint paths name their tool themselves, so
+    ``clippy::needless_return`` is the ``clippy::needless_return`` rule of
+    clippy.
+
+    Args:
+        spec: Comma-separated rules.
+
+    Returns:
+        dict[str, list[str]]: Rules to fix, keyed by tool name.
+
+    Raises:
+        ValueError: If an item has no tool or rule, or names a tool that
+            cannot fix selected rules.
+    """
+    rules: dict[str, list[str]] = {}
+    for item in (part.strip() for part in spec.split(",")):
+        if not item:
+            continue
+        if "::" in item:
+            tool, rule = item.split("::", 1)[0], item
+        elif ":" in item:
+            tool, rule = item.split(":", 1)
+        else:
+            raise ValueError(f"'{item}' is not tool:RULE (e.g. ruff:I001)")
+        tool = tool.strip().lower()
+        rule = rule.strip()
+        if not tool or not rule or rule.endswith("::"):
+            raise ValueError(f"'{item}' is not tool:RULE (e.g. ruff:I001)")
+        if tool not in RULE_SCOPED_FIXERS:
+            supported = ", ".join(sorted(RULE_SCOPED_FIXERS))
+            raise ValueError(
+                f"{tool} cannot fix selected rules; --fix-only supports {supported}",
+            )
+        if rule not in rules.setdefault(tool, []):
+            rules[tool].append(rule)
+    if not rules:
+        raise ValueError("no rules given")
+    return rules
+
+
+def report_only(result: ToolResult) -> ToolResult:
+    """Present a check result as a fix result that fixed nothing.
+
+    Args:
+        result: Check result.
+
+    Returns:
+        ToolResult: Result whose issues all count as remaining.
+    """
+    return replace(
+        result,
+        initial_issues_count=result.issues_count,
+        fixed_issues_count=0,
+        remaining_issues_count=result.issues_count,
+    )
+
+
+def run_rule_scoped_fix(
+    tool: BaseToolPlugin,
+    paths: list[str],
+    rules: Sequence[str],
+) -> ToolResult:
+    """Fix only the given rules, reporting the remaining issues.
+
+    Args:
+        tool: Configured tool plugin.
+        paths: Paths to fix.
+        rules: Rules the fixer may fix.
+
+    Returns:
+        ToolResult: Fix result.
+    """
+    previous = tool.options.get("fixable")
+    try:
+        tool.set_options(fixable=list(rules))
+        return tool.fix(paths, {})
+    finally:
+        if previous is None:
+            tool.options.pop("fixable", None)
+        else:
+            tool.options["fixable"] = previous
+
+
+def fix_selected(
+    tool: BaseToolPlugin,
+    tool_name: str,
+    paths: list[str],
+    fix_only: dict[str, list[str]],
+) -> ToolResult:
+    """Run one tool of a ``--fix-only`` run.
+
+    Args:
+        tool: Configured tool plugin.
+        tool_name: Name of the tool.
+        paths: Paths to fix.
+        fix_only: Rules to fix, keyed by tool name.
+
+    Returns:
+        ToolResult: Fix result for tools with listed rules, otherwise the
+        check result with nothing fixed.
+    """
+    if tool_name in fix_only:
+        return run_rule_scoped_fix(tool, paths, fix_only[tool_name])
+    return report_only(tool.check(paths, {}))
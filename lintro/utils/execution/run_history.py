@@ -0,0 +1,308 @@
+"""Issue count history of check runs, stored in SQLite.
+
+When ``execution.history`` names a database file, every ``lintro check``
+run records how many issues each tool found per rule and severity, along
+with the commit it ran on. ``lintro history`` reads the runs back to show
+how the counts move over time and which tools or rules regressed since the
+previous run.
+
+Run directories under ``.lintro`` are pruned to the last few runs; the
+history database keeps one small row per counted rule and run, so it can be
+cached or committed by scheduled workflows and grow for years.
+"""
+
+from __future__ import annotations
+
+import datetime
+import sqlite3
+from collections import Counter
+from collections.abc import Iterable, Sequence
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from lintro.utils.timestamps import utc_timestamp
+from lintro.utils.vcs import detect_vcs
+
+if TYPE_CHECKING:
+    from lintro.models.core.tool_result import ToolResult
+
+SCHEMA_VERSION: int = 1
+GROUP_BY_FIELDS: tuple[str, ...] = ("tool", "rule", "severity")
+
+_SCHEMA = """
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    timestamp TEXT NOT NULL,
+    commit_id TEXT,
+    exit_code INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS tool_runs (
+    run_id INTEGER NOT NULL REFERENCES runs(id) ON DELETE CASCADE,
+    tool TEXT NOT NULL,
+    success INTEGER NOT NULL,
+    issues INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS issue_counts (
+    run_id INTEGER NOT NULL REFERENCES runs(id) ON DELETE CASCADE,
+    tool TEXT NOT NULL,
+    code TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    count INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS issue_counts_run ON issue_counts(run_id);
+CREATE INDEX IF NOT EXISTS tool_runs_run ON tool_runs(run_id);
+"""
+
+
+@dataclass(frozen=True)
+class IssueCount:
+    """Issues of one rule and severity in a run.
+
+    Attributes:
+        tool: Tool that reported them.
+        code: Rule code; empty for issues without one.
+        severity: Severity, lower case.
+        count: Number of issues.
+    """
+
+    tool: str
+    code: str
+    severity: str
+    count: int
+
+
+@dataclass
+class HistoryRun:
+    """One recorded check run.
+
+    Attributes:
+        id: Row id, increasing with each run.
+        timestamp: When the run finished, as an RFC 3339 UTC timestamp.
+        commit: Commit checked out during the run, if any.
+        exit_code: Exit code of the run.
+        tools: Issues found by each tool that ran, including clean ones.
+        counts: Issues per tool, rule and severity.
+        failed: Tools that failed to run, recorded as unsuccessful without
+            any issue.
+    """
+
+    id: int
+    timestamp: str
+    commit: str | None
+    exit_code: int
+    tools: dict[str, int] = field(default_factory=dict)
+    counts: list[IssueCount] = field(default_factory=list)
+    failed: list[str] = field(default_factory=list)
+
+    def totals(self, group_by: str) -> Counter[str]:
+        """Add up the issues per tool, rule or severity.
+
+        Args:
+            group_by: One of ``GROUP_BY_FIELDS``.
+
+        Returns:
+            Counter[str]: Issues per key; tools that ran clean count 0.
+        """
+        totals: Counter[str] = Counter()
+        if group_by == "tool":
+            for tool, issues in self.tools.items():
+                totals[tool] += issues
+            return totals
+        for item in self.counts:
+            key = f"{item.tool}:{item.code}" if group_by == "rule" else item.severity
+            totals[key] += item.count
+        return totals
+
+
+@dataclass(frozen=True)
+class Regression:
+    """A tool, rule or severity whose issues grew since the previous run.
+
+    Attributes:
+        key: Tool, ``tool:code`` rule or severity.
+        previous: Issues in the previous run.
+        current: Issues in the latest run.
+    """
+
+    key: str
+    previous: int
+    current: int
+
+
+def count_issues(results: Iterable[ToolResult]) -> list[IssueCount]:
+    """Count the issues of a run per tool, rule and severity.
+
+    Args:
+        results: Results of the tools that ran.
+
+    Returns:
+        list[IssueCount]: Counts, sorted by tool, code and severity.
+    """
+    counter: Counter[tuple[str, str, str]] = Counter()
+    for result in results:
+        for issue in result.issues or []:
+            code = issue.to_display_row()["code"]
+            severity = str(issue.get_severity()).lower()
+            counter[(result.name, code, severity)] += 1
+    return [
+        IssueCount(tool=tool, code=code, severity=severity, count=count)
+        for (tool, code, severity), count in sorted(counter.items())
+    ]
+
+
+def _connect(path: str | Path) -> sqlite3.Connection:
+    """Open the history database, creating its tables on first use.
+
+    Args:
+        path: Database file.
+
+    Returns:
+        sqlite3.Connection: Open connection.
+
+    Raises:
+        ValueError: If the database was written by a newer lintro.
+    """
+    Path(path).parent.mkdir(parents=True, exist_ok=True)
+    connection = sqlite3.connect(str(path))
+    version = connection.execute("PRAGMA user_version").fetchone()[0]
+    if version > SCHEMA_VERSION:
+        connection.close()
+        raise ValueError(
+            f"{path} was written by a newer lintro (schema {version})",
+        )
+    connection.executescript(_SCHEMA)
+    connection.execute(f"PRAGMA user_version = {SCHEMA_VERSION}")
+    return connection
+
+
+def record_run(
+    path: str | Path,
+    results: Sequence[ToolResult],
+    exit_code: int,
+) -> int:
+    """Append a finished check run to the history.
+
+    Args:
+        path: Database file.
+        results: Results of the tools that ran; skipped tools are left out.
+        exit_code: Exit code of the run.
+
+    Returns:
+        int: Id of the recorded run.
+    """
+    ran = [r for r in results if not r.skipped]
+    connection = _connect(path)
+    try:
+        with connection:
+            cursor = connection.execute(
+                "INSERT INTO runs (timestamp, commit_id, exit_code) VALUES (?, ?, ?)",
+                (utc_timestamp(), detect_vcs().head_commit(), exit_code),
+            )
+            run_id = int(cursor.lastrowid or 0)
+            connection.executemany(
+                "INSERT INTO tool_runs (run_id, tool, success, issues) "
+                "VALUES (?, ?, ?, ?)",
+                [(run_id, r.name, int(r.success), r.issues_count) for r in ran],
+            )
+            connection.executemany(
+                "INSERT INTO issue_counts (run_id, tool, code, severity, count) "
+                "VALUES (?, ?, ?, ?, ?)",
+                [
+                    (run_id, c.tool, c.code, c.severity, c.count)
+                    for c in count_issues(ran)
+                ],
+            )
+    finally:
+        connection.close()
+    return run_id
+
+
+def load_runs(
+    path: str | Path,
+    since: datetime.datetime | None = None,
+    limit: int | None = None,
+) -> list[HistoryRun]:
+    """Read recorded runs, oldest first.
+
+    Args:
+        path: Database file.
+        since: Only runs that finished at or after this time; naive times
+            are taken as UTC.
+        limit: Only the most recent N runs.
+
+    Returns:
+        list[HistoryRun]: Runs with their counts.
+
+    Raises:
+        FileNotFoundError: If the database does not exist.
+    """
+    if not Path(path).is_file():
+        raise FileNotFoundError(f"No run history at {path}")
+    connection = _connect(path)
+    try:
+        query = "SELECT id, timestamp, commit_id, exit_code FROM runs"
+        params: list[object] = []
+        if since is not None:
+            # RFC 3339 UTC timestamps sort as text
+            query += " WHERE timestamp >= ?"
+            params.append(utc_timestamp(since))
+        query += " ORDER BY id DESC"
+        if limit is not None:
+            query += " LIMIT ?"
+            params.append(limit)
+        runs = {
+            row[0]: HistoryRun(
+                id=row[0],
+                timestamp=row[1],
+                commit=row[2],
+                exit_code=row[3],
+            )
+            for row in connection.execute(query, params)
+        }
+        if runs:
+            # Only placeholders are interpolated; the ids are bound
+            where = f"WHERE run_id IN ({','.join('?' * len(runs))})"
+            for run_id, tool, success, issues in connection.execute(
+                "SELECT run_id, tool, success, issues FROM tool_runs "
+                f"{where} ORDER BY tool",  # nosec B608
+                list(runs),
+            ):
+                runs[run_id].tools[tool] = issues
+                if not success and not issues:
+                    runs[run_id].failed.append(tool)
+            for run_id, tool, code, severity, count in connection.execute(
+                "SELECT run_id, tool, code, severity, count FROM issue_counts "
+                f"{where} ORDER BY tool, code, severity",  # nosec B608
+                list(runs),
+            ):
+                runs[run_id].counts.append(IssueCount(tool, code, severity, count))
+    finally:
+        connection.close()
+    return sorted(runs.values(), key=lambda run: run.id)
+
+
+def find_regressions(
+    runs: Sequence[HistoryRun],
+    group_by: str = "tool",
+) -> list[Regression]:
+    """Compare the latest run with the one before it.
+
+    Args:
+        runs: Runs, oldest first.
+        group_by: One of ``GROUP_BY_FIELDS``.
+
+    Returns:
+        list[Regression]: Keys with more issues than before, largest
+        increase first.
+    """
+    if len(runs) < 2:
+        return []
+    previous = runs[-2].totals(group_by)
+    current = runs[-1].totals(group_by)
+    regressions = [
+        Regression(key=key, previous=previous.get(key, 0), current=count)
+        for key, count in current.items()
+        if count > previous.get(key, 0)
+    ]
+    return sorted(regressions, key=lambda r: (r.previous - r.current, r.key))
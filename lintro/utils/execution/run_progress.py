@@ -0,0 +1,237 @@
+"""Live per-tool progress for long runs.
+
+Multi-tool runs print nothing while a tool is working, so a slow tool looks
+like a hang. On a terminal, the run shows a table of the selected tools:
+queued, running with the elapsed time, or done with their issue counts,
+plus how many of the files the tools process have been covered so far. The
+table is transient and never shown when stdout is not a TTY, so CI logs and
+piped output are unchanged.
+"""
+
+from __future__ import annotations
+
+import sys
+import time
+from collections.abc import Callable, Iterator, Mapping, Sequence
+from contextlib import contextmanager
+from dataclasses import dataclass
+
+from rich.console import Console
+from rich.live import Live
+from rich.table import Table
+
+from lintro.enums.tool_state import ToolState
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.exit_codes import is_infrastructure_failure
+
+REFRESH_PER_SECOND: int = 4
+
+_STATE_STYLES: dict[ToolState, str] = {
+    ToolState.QUEUED: "dim",
+    ToolState.RUNNING: "yellow",
+    ToolState.DONE: "green",
+    ToolState.FAILED: "red",
+    ToolState.SKIPPED: "dim",
+}
+_STATE_LABELS: dict[ToolState, str] = {
+    ToolState.QUEUED: "queued",
+    ToolState.RUNNING: "running",
+    ToolState.DONE: "✓ done",
+    ToolState.FAILED: "✗ failed",
+    ToolState.SKIPPED: "skipped",
+}
+
+
+@dataclass
+class ToolProgress:
+    """Progress of one tool.
+
+    Attributes:
+        name: Tool name.
+        state: Current state.
+        files: Files the tool processes, if known.
+        started: Monotonic time the tool started, while running.
+        elapsed: Seconds the tool took, once finished.
+        issues: Issues the tool reported, once finished.
+    """
+
+    name: str
+    state: ToolState = ToolState.QUEUED
+    files: int | None = None
+    started: float | None = None
+    elapsed: float | None = None
+    issues: int | None = None
+
+
+class RunProgress:
+    """States of the tools of one run, rendered as a Rich table.
+
+    The object is passed to ``rich.live.Live`` directly; Rich calls
+    ``__rich__`` on every refresh, which keeps running times current.
+    """
+
+    def __init__(
+        self,
+        tools: Sequence[str],
+        files_per_tool: Mapping[str, int] | None = None,
+        clock: Callable[[], float] = time.monotonic,
+    ) -> None:
+        """Queue the tools of a run.
+
+        Args:
+            tools: Tools in the order they are listed.
+            files_per_tool: Files each tool processes, for the file total.
+            clock: Monotonic clock in seconds.
+        """
+        counts = files_per_tool or {}
+        self.tools: dict[str, ToolProgress] = {
+            name: ToolProgress(name=name, files=counts.get(name)) for name in tools
+        }
+        self._clock = clock
+
+    def start(self, name: str) -> None:
+        """Mark a tool as running.
+
+        Args:
+            name: Tool name.
+        """
+        tool = self.tools.setdefault(name, ToolProgress(name=name))
+        tool.state = ToolState.RUNNING
+        tool.started = self._clock()
+
+    def finish(self, name: str, result: ToolResult) -> None:
+        """Mark a tool as finished.
+
+        Args:
+            name: Tool name.
+            result: Result of the tool.
+        """
+        tool = self.tools.setdefault(name, ToolProgress(name=name))
+        if result.skipped:
+            tool.state = ToolState.SKIPPED
+        else:
+            # Issues fail a check too; only a tool that did not run is an error
+            errored = is_infrastructure_failure(result)
+            tool.state = ToolState.FAILED if errored else ToolState.DONE
+            tool.issues = result.issues_count
+        if tool.started is not None:
+            tool.elapsed = self._clock() - tool.started
+
+    @property
+    def finished(self) -> int:
+        """Count the tools that are no longer queued or running.
+
+        Returns:
+            int: Finished tools.
+        """
+        return sum(
+            t.state not in (ToolState.QUEUED, ToolState.RUNNING)
+            for t in self.tools.values()
+        )
+
+    def file_progress(self) -> tuple[int, int] | None:
+        """Count the files covered by finished tools.
+
+        A file processed by several tools counts once per tool.
+
+        Returns:
+            tuple[int, int] | None: Files covered and files in total, or None
+            if the file counts are unknown.
+        """
+        known = [t for t in self.tools.values() if t.files is not None]
+        if not known:
+            return None
+        total = sum(t.files or 0 for t in known)
+        done = sum(
+            t.files or 0
+            for t in known
+            if t.state not in (ToolState.QUEUED, ToolState.RUNNING)
+        )
+        return done, total
+
+    def _elapsed(self, tool: ToolProgress) -> str:
+        """Format how long a tool has been running or took.
+
+        Args:
+            tool: Tool to describe.
+
+        Returns:
+            str: Seconds with one decimal, or empty while queued.
+        """
+        if tool.elapsed is not None:
+            return f"{tool.elapsed:.1f}s"
+        if tool.state == ToolState.RUNNING and tool.started is not None:
+            return f"{self._clock() - tool.started:.1f}s"
+        return ""
+
+    def render(self) -> Table:
+        """Render the tool states.
+
+        Returns:
+            Table: One row per tool, with the overall progress as caption.
+        """
+        table = Table(box=None, show_edge=False, pad_edge=False)
+        table.add_column("Tool")
+        table.add_column("State")
+        table.add_column("Time", justify="right")
+        table.add_column("Issues", justify="right")
+        table.add_column("Files", justify="right")
+        for tool in self.tools.values():
+            style, label = _STATE_STYLES[tool.state], _STATE_LABELS[tool.state]
+            if tool.state == ToolState.DONE and tool.issues:
+                style, label = "red", "✗ done"
+            table.add_row(
+                tool.name,
+                f"[{style}]{label}[/]",
+                self._elapsed(tool),
+                "" if tool.issues is None else str(tool.issues),
+                "" if tool.files is None else f"{tool.files:,}",
+            )
+        caption = f"{self.finished}/{len(self.tools)} tools done"
+        files = self.file_progress()
+        if files is not None:
+            caption += f", {files[0]:,}/{files[1]:,} files"
+        table.caption = caption
+        return table
+
+    def __rich__(self) -> Table:
+        """Render the tool states for Rich.
+
+        Returns:
+            Table: Current tool states.
+        """
+        return self.render()
+
+
+def progress_enabled(output_format: str | None = None) -> bool:
+    """Decide whether the live progress table is shown.
+
+    Args:
+        output_format: Output format of the run; JSON output stays clean.
+
+    Returns:
+        bool: True when stdout is a terminal and the output is not JSON.
+    """
+    return sys.stdout.isatty() and (output_format or "").lower() != "json"
+
+
+@contextmanager
+def live_progress(progress: RunProgress | None) -> Iterator[None]:
+    """Show a progress table while the block runs.
+
+    Args:
+        progress: Tool states to show, or None to show nothing.
+
+    Yields:
+        None: Control while the table is displayed.
+    """
+    if progress is None:
+        yield
+        return
+    with Live(
+        progress,
+        console=Console(),
+        refresh_per_second=REFRESH_PER_SECOND,
+        transient=True,
+    ):
+        yield
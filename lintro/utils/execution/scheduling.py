@@ -0,0 +1,137 @@
+"""Ordering the tools of a check run so quick feedback comes first.
+
+Each ``lintro check`` records how long every tool took in
+``execution.runtimes`` (``.lintro/runtimes.json`` by default), as a moving
+average over recent runs. The next check run orders its tools by configured
+priority and, among tools of equal priority, by that runtime, quickest
+first. Tools that took at least ``execution.slow_tool_seconds`` form a slow
+group that runs last:
+
+- Run one at a time, the slow tools start after every quick one, so
+  ``--fail-fast`` stops the run before they start when a quick tool already
+  failed it.
+- Run in parallel, the slow tools are started first and hold their own
+  workers while the quick tools share the others, so they do not lengthen
+  the run. With ``--fail-fast`` they wait for the quick tools instead.
+
+Tools never timed before count as quick. Fix runs and alphabetical or custom
+tool orders are not rescheduled.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Callable, Iterable, Mapping
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from loguru import logger
+
+from lintro.models.core.tool_result import ToolResult
+
+DEFAULT_RUNTIMES_FILE: str = ".lintro/runtimes.json"
+
+# Weight of the latest run in a tool's moving average runtime
+RUNTIME_WEIGHT: float = 0.5
+
+
+@dataclass(frozen=True)
+class Schedule:
+    """Order a check run starts its tools in.
+
+    Attributes:
+        quick: Tools expected to finish quickly, in the order they start.
+        slow: Tools whose runtime reaches the slow threshold, quickest first.
+    """
+
+    quick: list[str] = field(default_factory=list)
+    slow: list[str] = field(default_factory=list)
+
+    @property
+    def order(self) -> list[str]:
+        """Return every tool, quick ones first.
+
+        Returns:
+            list[str]: Tools in the order a sequential run starts them.
+        """
+        return [*self.quick, *self.slow]
+
+
+def load_runtimes(path: str | Path) -> dict[str, int]:
+    """Read the recorded runtime of each tool.
+
+    Args:
+        path: Runtimes file.
+
+    Returns:
+        dict[str, int]: Average runtime in milliseconds per tool; empty when
+        the file is missing or unreadable.
+    """
+    try:
+        data = json.loads(Path(path).read_text(encoding="utf-8"))
+    except (OSError, ValueError):
+        return {}
+    if not isinstance(data, dict):
+        return {}
+    return {
+        str(name): int(ms)
+        for name, ms in data.items()
+        if isinstance(ms, (int, float)) and not isinstance(ms, bool) and ms >= 0
+    }
+
+
+def record_runtimes(path: str | Path, results: Iterable[ToolResult]) -> None:
+    """Fold the runtimes of a finished run into the recorded averages.
+
+    Args:
+        path: Runtimes file.
+        results: Results of the run; skipped and untimed tools are left out.
+    """
+    runtimes = load_runtimes(path)
+    for result in results:
+        if result.skipped or result.duration_ms is None:
+            continue
+        previous = runtimes.get(result.name)
+        runtimes[result.name] = (
+            result.duration_ms
+            if previous is None
+            else round(
+                RUNTIME_WEIGHT * result.duration_ms + (1 - RUNTIME_WEIGHT) * previous,
+            )
+        )
+    try:
+        Path(path).parent.mkdir(parents=True, exist_ok=True)
+        Path(path).write_text(
+            json.dumps(dict(sorted(runtimes.items())), indent=2) + "\n",
+            encoding="utf-8",
+        )
+    except OSError as e:
+        logger.debug(f"Could not record tool runtimes in {path}: {e}")
+
+
+def schedule_tools(
+    tools: Iterable[str],
+    runtimes: Mapping[str, int],
+    priority: Callable[[str], int],
+    slow_ms: int,
+) -> Schedule:
+    """Order the tools of a check run.
+
+    Args:
+        tools: Tools to run.
+        runtimes: Average runtime in milliseconds per tool.
+        priority: Configured priority of a tool; lower runs first.
+        slow_ms: Runtime from which a tool counts as slow.
+
+    Returns:
+        Schedule: Quick tools by priority then runtime, and slow tools by
+        runtime.
+    """
+    quick: list[str] = []
+    slow: list[str] = []
+    for name in tools:
+        runtime = runtimes.get(name)
+        (slow if runtime is not None and runtime >= slow_ms else quick).append(name)
+    quick.sort(key=lambda name: (priority(name), runtimes.get(name, 0), name))
+    slow.sort(key=lambda name: (runtimes[name], name))
+    return Schedule(quick=quick, slow=slow)
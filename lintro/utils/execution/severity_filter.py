@@ -0,0 +1,177 @@
+"""Severity remapping and threshold filtering for tool results.
+
+Applies the configured per-rule severity overrides (``severity``, from the
+nearest nested config for files in a subtree that has one), then the
+overrides of labelled directories (``label_policies``), and then drops
+issues below the minimum severity (``output.min_severity``) so they are
+neither displayed nor counted towards the exit code.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Mapping, Sequence
+from dataclasses import replace
+from pathlib import Path
+
+from lintro.config.label_config import LabelPolicy
+from lintro.enums.severity_level import SeverityLevel, normalize_severity_level
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.exit_codes import success_after_filter
+from lintro.utils.execution.labels import DirectoryLabels
+
+# Higher rank means more severe
+_SEVERITY_RANK: dict[SeverityLevel, int] = {
+    SeverityLevel.INFO: 0,
+    SeverityLevel.WARNING: 1,
+    SeverityLevel.ERROR: 2,
+}
+
+
+def _overrides_for(
+    path: str,
+    overrides: Mapping[str, str] | None,
+    nested: Sequence[tuple[Path, Mapping[str, str]]],
+) -> Mapping[str, str] | None:
+    """Pick the severity overrides that apply to a file.
+
+    Args:
+        path: File of an issue.
+        overrides: Overrides of the run's config.
+        nested: Overrides of nested config subtrees, deepest first.
+
+    Returns:
+        Mapping[str, str] | None: Overrides of the nearest nested config
+        containing the file, or the run's overrides.
+    """
+    if not nested or not path:
+        return overrides
+    resolved = Path(path).resolve()
+    for directory, subtree_overrides in nested:
+        if directory in resolved.parents:
+            return subtree_overrides
+    return overrides
+
+
+def apply_severity_overrides(
+    result: ToolResult,
+    overrides: Mapping[str, str] | None,
+    nested: Sequence[tuple[Path, Mapping[str, str]]] = (),
+) -> ToolResult:
+    """Replace the native severity of issues whose rule has an override.
+
+    Overrides are keyed by ``tool:code`` or by the bare rule code; the
+    tool-qualified key wins.
+
+    Args:
+        result: Result returned by a tool.
+        overrides: Configured severity per rule, or None for no overrides.
+        nested: Effective overrides of nested config subtrees, deepest
+            first (see nested_severity); issues in a subtree use its
+            overrides instead of ``overrides``.
+
+    Returns:
+        ToolResult: Result with remapped issues, or the original result if
+        no override applied.
+    """
+    if (not overrides and not nested) or not result.issues:
+        return result
+
+    tool = result.name.lower()
+    changed = False
+    issues = []
+    for issue in result.issues:
+        code = issue_code(issue)
+        rules = _overrides_for(getattr(issue, "file", "") or "", overrides, nested)
+        level = rules.get(f"{tool}:{code}", rules.get(code)) if code and rules else None
+        if level is not None:
+            issue = replace(issue, severity_override=normalize_severity_level(level))
+            changed = True
+        issues.append(issue)
+    if not changed:
+        return result
+
+    return replace(result, issues=issues, formatted_output=None)
+
+
+def apply_label_severity(
+    result: ToolResult,
+    labels: DirectoryLabels,
+    policies: Mapping[str, LabelPolicy],
+) -> ToolResult:
+    """Apply the severity overrides of labelled directories.
+
+    Each issue gets the overrides of the labels on its file's directories,
+    keyed like ``severity``. When several labels override the same rule,
+    the most severe level wins.
+
+    Args:
+        result: Result after apply_severity_overrides().
+        labels: Labelled directories.
+        policies: Label policies keyed by label.
+
+    Returns:
+        ToolResult: Result with remapped issues, or the original result if
+        no override applied.
+    """
+    if not labels or not policies or not result.issues:
+        return result
+
+    tool = result.name.lower()
+    changed = False
+    issues = []
+    for issue in result.issues:
+        code = issue_code(issue)
+        levels: list[SeverityLevel] = []
+        for label in labels.labels_for(issue.file) if code else ():
+            overrides = policies[label].severity if label in policies else {}
+            level = overrides.get(f"{tool}:{code}", overrides.get(code))
+            if level is not None:
+                levels.append(normalize_severity_level(level))
+        if levels:
+            level = max(levels, key=_SEVERITY_RANK.__getitem__)
+            issue = replace(issue, severity_override=level)
+            changed = True
+        issues.append(issue)
+    if not changed:
+        return result
+
+    return replace(result, issues=issues, formatted_output=None)
+
+
+def apply_min_severity(
+    result: ToolResult,
+    min_severity: str | SeverityLevel | None,
+) -> ToolResult:
+    """Remove issues below a minimum severity from a check result.
+
+    Results without parsed issues (e.g. raw output only) are returned
+    unchanged. A result whose issues are all filtered out is marked
+    successful, since issues were the reason it failed.
+
+    Args:
+        result: Result returned by a tool.
+        min_severity: Lowest severity to keep, or None to keep everything.
+
+    Returns:
+        ToolResult: Result with filtered issues and updated count.
+    """
+    if min_severity is None or result.skipped or not result.issues:
+        return result
+
+    threshold = _SEVERITY_RANK[normalize_severity_level(min_severity)]
+    kept = [
+        issue
+        for issue in result.issues
+        if _SEVERITY_RANK[issue.get_severity()] >= threshold
+    ]
+    if len(kept) == len(result.issues):
+        return result
+
+    return replace(
+        result,
+        issues=kept,
+        issues_count=len(kept),
+        success=success_after_filter(result, len(kept)),
+        formatted_output=None,
+    )
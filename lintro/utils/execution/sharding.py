@@ -0,0 +1,187 @@
+"""Splitting a check run across CI jobs with ``--shard I/N``.
+
+Each of N jobs runs ``lintro check --shard I/N`` and checks a stable
+subset of the files, so the jobs of a CI matrix share the work::
+
+    lintro check --shard 2/4 --output json=lint-2.json
+    lintro merge-reports lint-*.json --output lint.json
+
+A file belongs to one shard chosen from a hash of its path relative to the
+working directory, so every job agrees on the split without talking to the
+others. Whole-project tools (``per_file=False``, such as mypy or clippy)
+cannot be split; each runs in full in one shard chosen from its name.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import os
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from lintro.plugins.file_discovery import discover_files, setup_exclude_patterns
+from lintro.tools import tool_manager
+from lintro.utils.execution.tool_configuration import SkippedTool
+from lintro.utils.path_filtering import DEFAULT_SYMLINK_POLICY
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from lintro.config.config_hierarchy import ToolScopePlan
+    from lintro.utils.execution.tool_configuration import ToolsToRunResult
+
+
+@dataclass(frozen=True)
+class Shard:
+    """One of N equal parts of a run.
+
+    Attributes:
+        index: Shard number, from 1 to count.
+        count: Number of shards.
+    """
+
+    index: int
+    count: int
+
+    def __str__(self) -> str:
+        """Format the shard as on the command line.
+
+        Returns:
+            str: ``I/N``.
+        """
+        return f"{self.index}/{self.count}"
+
+    def owns(self, key: str) -> bool:
+        """Tell whether a file or tool belongs to this shard.
+
+        Args:
+            key: Relative file path or ``tool:<name>``.
+
+        Returns:
+            bool: True if the key hashes to this shard.
+        """
+        digest = hashlib.sha256(key.encode()).digest()
+        return int.from_bytes(digest[:8], "big") % self.count == self.index - 1
+
+
+def parse_shard(value: str) -> Shard:
+    """Parse a ``--shard`` value.
+
+    Args:
+        value: ``I/N`` with 1 <= I <= N.
+
+    Returns:
+        Shard: Parsed shard.
+
+    Raises:
+        ValueError: If the value is not ``I/N`` or I is out of range.
+    """
+    index, sep, count = value.strip().partition("/")
+    try:
+        shard = Shard(index=int(index), count=int(count))
+    except ValueError:
+        shard = None
+    if not sep or shard is None:
+        raise ValueError(f"Shard must look like I/N (e.g. 2/4), got '{value}'")
+    if shard.count < 1 or not 1 <= shard.index <= shard.count:
+        raise ValueError(
+            f"Shard index must be between 1 and {shard.count}, got '{value}'",
+        )
+    return shard
+
+
+def shard_key(file_path: str) -> str:
+    """Return the key a file is sharded by.
+
+    Args:
+        file_path: Path of the file.
+
+    Returns:
+        str: Forward-slash path relative to the working directory, the same
+        on every CI job.
+    """
+    return Path(os.path.relpath(file_path)).as_posix()
+
+
+def apply_shard_scopes(
+    tools_result: ToolsToRunResult,
+    paths: list[str],
+    scopes: dict[str, ToolScopePlan],
+    shard: Shard,
+    exclude: str | None = None,
+    include_venv: bool = False,
+    prune_dirs: Sequence[str] = (),
+    gitignore: bool = True,
+    symlinks: str = DEFAULT_SYMLINK_POLICY,
+) -> None:
+    """Limit each tool to its files in a shard.
+
+    Per-file tools get the shard's files as their paths; tools with no file
+    in the shard, and whole-project tools run by another shard, are moved
+    to ``skipped``.
+
+    Args:
+        tools_result: Selection after the nested config and label scopes;
+            updated in place.
+        paths: Input paths passed to lintro.
+        scopes: Per-tool scope plans; updated with the shard's files.
+        shard: Shard this run checks.
+        exclude: Comma-separated exclude patterns, if any.
+        include_venv: Whether virtual environments are included.
+        prune_dirs: Directory names discovery does not descend into.
+        gitignore: Whether files git ignores are skipped.
+        symlinks: How symbolic links are treated (see SYMLINK_POLICIES).
+    """
+    from lintro.config.config_hierarchy import ToolScopePlan
+
+    if shard.count == 1:
+        return
+    cli_excludes = [p.strip() for p in (exclude or "").split(",") if p.strip()]
+    to_run: list[str] = []
+    for name in tools_result.to_run:
+        definition = tool_manager.get_tool(name).definition
+        scope = scopes.get(name)
+        if not definition.per_file:
+            if shard.owns(f"tool:{name}"):
+                to_run.append(name)
+            else:
+                tools_result.skipped.append(
+                    SkippedTool(name=name, reason="runs in another shard"),
+                )
+            continue
+        # Subtrees run with their own settings keep their own plans
+        sharded: list[ToolScopePlan] = []
+        for plan in [scope, *scope.subtrees] if scope else [None]:
+            excludes = setup_exclude_patterns(
+                [*cli_excludes, *(plan.exclude_patterns if plan else [])],
+            )
+            files = discover_files(
+                paths=plan.paths if plan else paths,
+                definition=definition,
+                exclude_patterns=excludes,
+                include_venv=include_venv,
+                show_progress=False,
+                prune_dirs=prune_dirs,
+                gitignore=gitignore,
+                symlinks=symlinks,
+            )
+            owned = [f for f in files if shard.owns(shard_key(f))]
+            if owned:
+                sharded.append(
+                    ToolScopePlan(
+                        paths=owned,
+                        exclude_patterns=list(plan.exclude_patterns) if plan else [],
+                        config=plan.config if plan else None,
+                    ),
+                )
+        if not sharded:
+            tools_result.skipped.append(
+                SkippedTool(name=name, reason=f"no files in shard {shard}"),
+            )
+            continue
+        first, *others = sharded
+        first.subtrees = others
+        scopes[name] = first
+        to_run.append(name)
+    tools_result.to_run = to_run
@@ -0,0 +1,242 @@
+"""Inserting suppression comments at the sites of current violations.
+
+Used by ``lintro suppress`` to adopt a new rule without fixing every
+existing violation first. Each flagged line gets one comment, either in
+lintro's own syntax (see ``suppressions``), placed on its own line above the
+flagged line, or in the tool's native syntax where the tool has one. Lines
+are edited bottom-up so inserted lines do not shift later edits.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Iterable
+from dataclasses import dataclass, field
+from datetime import date
+from pathlib import Path
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.suppressions import rule_matches
+
+
+@dataclass(frozen=True)
+class NativeSuppression:
+    """A tool's own suppression comment.
+
+    Attributes:
+        template: Comment text, with ``{code}`` for the rule code.
+        above: Whether the comment goes on its own line above the flagged
+            line rather than at its end.
+    """
+
+    template: str
+    above: bool = False
+
+
+NATIVE_SUPPRESSIONS: dict[str, NativeSuppression] = {
+    "bandit": NativeSuppression("# nosec {code}"),
+    "clippy": NativeSuppression("#[allow({code})]", above=True),
+    "eslint": NativeSuppression("// eslint-disable-next-line {code}", above=True),
+    "hadolint": NativeSuppression("# hadolint ignore={code}", above=True),
+    "markdownlint": NativeSuppression(
+        "<!-- markdownlint-disable-next-line {code} -->",
+        above=True,
+    ),
+    "mypy": NativeSuppression("# type: ignore[{code}]"),
+    "oxlint": NativeSuppression("// eslint-disable-next-line {code}", above=True),
+    "rubocop": NativeSuppression("# rubocop:disable {code}"),
+    "ruff": NativeSuppression("# noqa: {code}"),
+    "shellcheck": NativeSuppression("# shellcheck disable={code}", above=True),
+    "yamllint": NativeSuppression("# yamllint disable-line rule:{code}"),
+}
+
+# Comment delimiters for lintro's syntax, by file extension or name
+_HASH = ("# ", "")
+_SLASH = ("// ", "")
+_COMMENT_STYLES: dict[str, tuple[str, str]] = {
+    **dict.fromkeys(
+        [".py", ".pyi", ".sh", ".bash", ".zsh", ".rb", ".yaml", ".yml", ".toml"],
+        _HASH,
+    ),
+    **dict.fromkeys([".tf", ".hcl", ".r", ".pl", ".ps1"], _HASH),
+    **dict.fromkeys(
+        [".js", ".jsx", ".mjs", ".cjs", ".ts", ".tsx", ".mts", ".cts", ".rs"],
+        _SLASH,
+    ),
+    **dict.fromkeys(
+        [".go", ".c", ".h", ".cc", ".cpp", ".hpp", ".java", ".kt", ".kts"],
+        _SLASH,
+    ),
+    **dict.fromkeys([".swift", ".proto", ".scala", ".dart", ".jsonc"], _SLASH),
+    **dict.fromkeys([".lua", ".sql", ".hs"], ("-- ", "")),
+    **dict.fromkeys([".css", ".scss", ".less"], ("/* ", " */")),
+    **dict.fromkeys([".md", ".html", ".vue", ".svelte", ".xml"], ("<!-- ", " -->")),
+}
+
+
+@dataclass
+class SuppressionEdits:
+    """Outcome of inserting suppressions.
+
+    Attributes:
+        inserted: ``file:line`` of each flagged line that got a comment.
+        skipped: ``file:line`` of each flagged line left alone, with why.
+    """
+
+    inserted: list[str] = field(default_factory=list)
+    skipped: list[tuple[str, str]] = field(default_factory=list)
+
+
+def comment_style(path: str) -> tuple[str, str] | None:
+    """Return the comment delimiters of a file.
+
+    Args:
+        path: Path of the file.
+
+    Returns:
+        tuple[str, str] | None: Opening and closing delimiters, or None if
+        the file type is unknown.
+    """
+    name = os.path.basename(path).lower()
+    if name.startswith("dockerfile") or name.endswith(".dockerfile"):
+        return _HASH
+    return _COMMENT_STYLES.get(os.path.splitext(name)[1])
+
+
+def lintro_comment(
+    rule: str,
+    until: date | None = None,
+    reason: str = "",
+) -> str:
+    """Build the text of a ``lintro: ignore`` comment.
+
+    Args:
+        rule: Suppressed rule, bare or tool-qualified.
+        until: Last day the suppression applies, or None for no expiry.
+        reason: Why the rule is suppressed.
+
+    Returns:
+        str: Comment text without delimiters.
+    """
+    text = f"lintro: ignore[{rule}]"
+    if until is not None:
+        text += f" until={until.isoformat()}"
+    if reason:
+        text += f' reason="{reason}"'
+    return text
+
+
+def collect_sites(
+    results: Iterable[ToolResult],
+    rule: str,
+) -> dict[str, dict[int, tuple[str, str]]]:
+    """Find the flagged lines of a rule.
+
+    Args:
+        results: Results of a check run.
+        rule: Rule to suppress, bare or tool-qualified.
+
+    Returns:
+        dict[str, dict[int, tuple[str, str]]]: Tool and rule code reported
+        on each flagged line, by file.
+    """
+    sites: dict[str, dict[int, tuple[str, str]]] = {}
+    for result in results:
+        tool = result.name.lower()
+        for issue in result.issues or []:
+            code = issue_code(issue)
+            file_path = getattr(issue, "file", "") or ""
+            line = getattr(issue, "line", 0) or 0
+            if file_path and line and rule_matches(rule, tool, code):
+                sites.setdefault(file_path, {}).setdefault(line, (tool, code))
+    return sites
+
+
+def _suppress_line(
+    lines: list[str],
+    index: int,
+    comment: str,
+    above: bool,
+) -> None:
+    """Add a comment to a flagged line, in place.
+
+    Args:
+        lines: Lines of the file, with their line endings.
+        index: 0-based index of the flagged line.
+        comment: Complete comment, delimiters included.
+        above: Whether the comment goes on its own line above.
+    """
+    text = lines[index]
+    body = text.rstrip("\r\n")
+    ending = text[len(body) :] or "\n"
+    if above:
+        indent = body[: len(body) - len(body.lstrip())]
+        lines.insert(index, f"{indent}{comment}{ending}")
+    else:
+        lines[index] = f"{body}  {comment}{text[len(body) :]}"
+
+
+def insert_suppressions(
+    sites: dict[str, dict[int, tuple[str, str]]],
+    rule: str,
+    *,
+    native: bool = False,
+    until: date | None = None,
+    reason: str = "",
+    dry_run: bool = False,
+) -> SuppressionEdits:
+    """Insert a suppression comment at each flagged line.
+
+    Args:
+        sites: Flagged lines from collect_sites().
+        rule: Rule being suppressed, as given by the user.
+        native: Use each tool's native syntax instead of lintro's.
+        until: Expiry written into lintro comments.
+        reason: Reason written into lintro comments.
+        dry_run: Report the edits without writing files.
+
+    Returns:
+        SuppressionEdits: Inserted and skipped lines.
+    """
+    edits = SuppressionEdits()
+    for file_path in sorted(sites):
+        path = Path(file_path)
+        style = comment_style(file_path)
+        try:
+            with path.open(encoding="utf-8", newline="") as f:
+                lines = f.read().splitlines(keepends=True)
+        except (OSError, UnicodeDecodeError) as e:
+            for line in sorted(sites[file_path]):
+                edits.skipped.append((f"{file_path}:{line}", f"cannot read: {e}"))
+            continue
+
+        inserted: list[str] = []
+        for line in sorted(sites[file_path], reverse=True):
+            location = f"{file_path}:{line}"
+            tool, code = sites[file_path][line]
+            if line > len(lines):
+                edits.skipped.append((location, "line no longer exists"))
+                continue
+            if native:
+                syntax = NATIVE_SUPPRESSIONS.get(tool)
+                if syntax is None:
+                    edits.skipped.append((location, f"{tool} has no native syntax"))
+                    continue
+                comment = syntax.template.format(code=code)
+                above = syntax.above
+            else:
+                if style is None:
+                    edits.skipped.append((location, "unknown comment syntax"))
+                    continue
+                opening, closing = style
+                comment = f"{opening}{lintro_comment(rule, until, reason)}{closing}"
+                above = True
+            _suppress_line(lines, line - 1, comment, above)
+            inserted.append(location)
+
+        edits.inserted.extend(reversed(inserted))
+        if inserted and not dry_run:
+            with path.open("w", encoding="utf-8", newline="") as f:
+                f.write("".join(lines))
+    return edits
@@ -0,0 +1,251 @@
+"""Inline ``lintro: ignore`` suppressions with optional expiry dates.
+
+A comment on a line suppresses the issues any tool reports for that line::
+
+    import legacy  # lintro: ignore[F401] until=2025-12-31 reason="migration"
+
+Rules are bare codes (``F401``) or tool-qualified (``ruff:F401``); without
+brackets every rule is suppressed. A comment alone on its line applies to
+the next line instead. After its ``until`` date a suppression stops
+applying: the issues it matched are reported again as errors that name the
+expired suppression, so temporary ignores cannot quietly become permanent.
+``lintro suppressions`` lists the suppressions that expired or expire soon.
+"""
+
+from __future__ import annotations
+
+import re
+from collections.abc import Iterable
+from dataclasses import dataclass, replace
+from datetime import date
+
+from loguru import logger
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.exit_codes import success_after_filter
+from lintro.utils.fingerprint import source_lines
+
+_SUPPRESSION = re.compile(
+    r"lintro:\s*ignore(?:\[(?P<rules>[^\]]*)\])?"
+    r"(?P<options>(?:\s+[a-z]+=(?:\"[^\"]*\"|[^\s\"]+))*)",
+)
+_OPTION = re.compile(r"([a-z]+)=(?:\"([^\"]*)\"|([^\s\"]+))")
+# What may precede a comment that stands alone on its line
+_COMMENT_LEADERS = frozenset({"", "#", "//", "--", "/*", ";", "<!--", "%", "*"})
+
+
+def rule_matches(rule: str, tool: str, code: str) -> bool:
+    """Return whether a bare or tool-qualified rule names an issue's rule.
+
+    Args:
+        rule: Rule such as ``F401`` or ``ruff:F401``.
+        tool: Name of the tool reporting the issue.
+        code: Rule code of the issue.
+
+    Returns:
+        bool: True if the rule names the issue's rule.
+    """
+    if not code:
+        return False
+    return rule.lower() in (code.lower(), f"{tool}:{code}".lower())
+
+
+@dataclass(frozen=True)
+class Suppression:
+    """A ``lintro: ignore`` comment.
+
+    Attributes:
+        file: File holding the comment.
+        line: Line of the comment.
+        target_line: Line whose issues it suppresses.
+        rules: Suppressed rules, bare or ``tool:code``; empty for all rules.
+        until: Last day the suppression applies, or None for no expiry.
+        reason: Why the issues are suppressed.
+    """
+
+    file: str
+    line: int
+    target_line: int
+    rules: tuple[str, ...] = ()
+    until: date | None = None
+    reason: str = ""
+
+    def matches(self, tool: str, code: str) -> bool:
+        """Return whether the suppression covers a rule.
+
+        Args:
+            tool: Name of the tool reporting the issue.
+            code: Rule code of the issue.
+
+        Returns:
+            bool: True if the rule is suppressed.
+        """
+        if not self.rules:
+            return True
+        return any(rule_matches(rule, tool, code) for rule in self.rules)
+
+    def expired(self, today: date) -> bool:
+        """Return whether the suppression no longer applies.
+
+        Args:
+            today: Current date.
+
+        Returns:
+            bool: True if ``until`` has passed.
+        """
+        return self.until is not None and today > self.until
+
+
+def parse_suppressions(path: str) -> list[Suppression]:
+    """Find the ``lintro: ignore`` comments of a file.
+
+    Suppressions with an ``until`` that is not an ISO date are skipped with a
+    warning, so a typo cannot turn them into permanent ignores.
+
+    Args:
+        path: File path as reported by a tool.
+
+    Returns:
+        list[Suppression]: Suppressions in line order.
+    """
+    suppressions: list[Suppression] = []
+    for number, text in enumerate(source_lines(path), start=1):
+        if "lintro:" not in text:
+            continue
+        match = _SUPPRESSION.search(text)
+        if match is None:
+            continue
+        options = {
+            key: quoted if quoted else bare
+            for key, quoted, bare in _OPTION.findall(match.group("options"))
+        }
+        until: date | None = None
+        if "until" in options:
+            try:
+                until = date.fromisoformat(options["until"])
+            except ValueError:
+                logger.warning(
+                    f"{path}:{number}: ignoring lintro suppression with invalid "
+                    f"until={options['until']!r}; expected YYYY-MM-DD",
+                )
+                continue
+        rules = tuple(
+            rule.strip()
+            for rule in (match.group("rules") or "").split(",")
+            if rule.strip()
+        )
+        standalone = text[: match.start()].strip() in _COMMENT_LEADERS
+        suppressions.append(
+            Suppression(
+                file=path,
+                line=number,
+                target_line=number + 1 if standalone else number,
+                rules=rules,
+                until=until,
+                reason=options.get("reason", ""),
+            ),
+        )
+    return suppressions
+
+
+def apply_suppressions(result: ToolResult, today: date | None = None) -> ToolResult:
+    """Drop suppressed issues and escalate those whose suppression expired.
+
+    A result whose issues are all suppressed is marked successful, since
+    issues were the reason it failed.
+
+    Args:
+        result: Result returned by a tool.
+        today: Current date. Defaults to today.
+
+    Returns:
+        ToolResult: Result without suppressed issues, or the original result
+        if no suppression matched.
+    """
+    if result.skipped or not result.issues:
+        return result
+
+    today = today or date.today()
+    tool = result.name
+    by_file: dict[str, list[Suppression]] = {}
+    changed = False
+    kept = []
+    for issue in result.issues:
+        file_path = getattr(issue, "file", "") or ""
+        line = getattr(issue, "line", 0) or 0
+        if not (file_path and line):
+            kept.append(issue)
+            continue
+        if file_path not in by_file:
+            by_file[file_path] = parse_suppressions(file_path)
+        code = issue_code(issue)
+        matching = [
+            s
+            for s in by_file[file_path]
+            if s.target_line == line and s.matches(tool, code)
+        ]
+        if not matching:
+            kept.append(issue)
+            continue
+        changed = True
+        expired = [s for s in matching if s.expired(today)]
+        if len(expired) < len(matching):
+            continue
+        until = max(s.until for s in expired if s.until is not None)
+        kept.append(
+            replace(
+                issue,
+                severity_override=SeverityLevel.ERROR,
+                expired_suppression=until.isoformat(),
+            ),
+        )
+    if not changed:
+        return result
+
+    removed = len(result.issues) - len(kept)
+    issues_count = max(result.issues_count - removed, len(kept))
+    return replace(
+        result,
+        issues=kept,
+        issues_count=issues_count,
+        success=success_after_filter(result, issues_count),
+        formatted_output=None,
+    )
+
+
+def find_suppressions(files: Iterable[str]) -> list[Suppression]:
+    """Collect the suppressions of several files.
+
+    Args:
+        files: Files to search.
+
+    Returns:
+        list[Suppression]: Suppressions by file and line.
+    """
+    return [s for path in files for s in parse_suppressions(path)]
+
+
+def expiring_suppressions(
+    suppressions: Iterable[Suppression],
+    within_days: int,
+    today: date | None = None,
+) -> list[Suppression]:
+    """Select suppressions that expired or expire within a number of days.
+
+    Args:
+        suppressions: Suppressions to check.
+        within_days: Days ahead to look.
+        today: Current date. Defaults to today.
+
+    Returns:
+        list[Suppression]: Matching suppressions, soonest expiry first.
+    """
+    today = today or date.today()
+    expiring = [
+        s
+        for s in suppressions
+        if s.until is not None and (s.until - today).days <= within_days
+    ]
+    return sorted(expiring, key=lambda s: (s.until, s.file, s.line))
@@ -6,17 +6,30 @@ and determining which tools to run.
 
 from __future__ import annotations
 
+from collections.abc import Callable
 from dataclasses import dataclass, field
+from pathlib import Path
 from typing import TYPE_CHECKING
 
 from lintro.config.config_loader import get_config
 from lintro.enums.action import Action, normalize_action
 from lintro.enums.tool_name import ToolName
 from lintro.enums.tools_value import ToolsValue
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.container_backend import resolve_container_spec
+from lintro.plugins.daemon_backend import resolve_daemon_spec
+from lintro.plugins.extra_args import resolve_extra_args
+from lintro.plugins.remote_backend import resolve_remote_spec
+from lintro.plugins.resource_limits import resolve_resource_limits
 from lintro.tools import tool_manager
+from lintro.tools.core.command_builders import NodeJSBuilder
+from lintro.utils.execution.fix_safety import apply_fix_safety
+from lintro.utils.result_cache import resolve_result_cache
+from lintro.utils.toolchains import node_toolchain_bin
 from lintro.utils.unified_config import UnifiedConfigManager
 
 if TYPE_CHECKING:
+    from lintro.config.config_hierarchy import ToolScopePlan
     from lintro.config.lintro_config import LintroConfig
     from lintro.plugins.base import BaseToolPlugin
 
@@ -60,9 +73,33 @@ def _get_disabled_reason(config: LintroConfig, tool_name: str) -> str:
     if not tool_config.enabled:
         return "disabled in config"
 
+    # Opt-in tools that config does not mention
+    if tool_lower not in config.tools:
+        return "not enabled by default"
+
     return "disabled"
 
 
+def _node_toolchain_bin(tool_name: str) -> str | None:
+    """Get the Node.js install a tool runs with.
+
+    Args:
+        tool_name: Name of the tool.
+
+    Returns:
+        str | None: Bin directory of the nvm install of the Node.js release
+        the project pins, or None for other tools and projects without one.
+    """
+    try:
+        tool_enum = ToolName(tool_name.lower())
+    except ValueError:
+        return None
+    if not NodeJSBuilder().can_handle(tool_enum):
+        return None
+    bin_dir = node_toolchain_bin(Path.cwd())
+    return str(bin_dir) if bin_dir is not None else None
+
+
 def configure_tool_for_execution(
     tool: BaseToolPlugin,
     tool_name: str,
@@ -75,6 +112,8 @@ def configure_tool_for_execution(
     post_tools: set[str],
     auto_install: bool = False,
     lintro_config: LintroConfig | None = None,
+    scope: ToolScopePlan | None = None,
+    gitignore: bool = True,
 ) -> None:
     """Configure a tool for execution.
 
@@ -93,6 +132,11 @@ def configure_tool_for_execution(
         post_tools: Set of post-check tool names.
         auto_install: Whether to auto-install Node.js deps if missing (global default).
         lintro_config: Optional LintroConfig to reuse; fetched via get_config() if None.
+        scope: Optional nested-config scope plan whose exclude patterns
+            are applied on top of the CLI excludes. Its config, when set,
+            replaces lintro_config.
+        gitignore: False when ``--no-gitignore`` turns off
+            ``execution.gitignore`` for this run.
     """
     # Build CLI overrides from --tool-options
     cli_overrides: dict[str, object] = {}
@@ -100,6 +144,8 @@ def configure_tool_for_execution(
         overrides = tool_option_dict.get(option_key)
         if overrides:
             cli_overrides.update(overrides)
+    # Extra arguments go on the command line, not through set_options
+    cli_extra_args = cli_overrides.pop("extra_args", None)
 
     # Apply unified config with CLI overrides
     config_manager.apply_config_to_tool(
@@ -112,6 +158,9 @@ def configure_tool_for_execution(
         exclude_patterns = [p.strip() for p in exclude.split(",")]
         tool.set_options(exclude_patterns=exclude_patterns)
 
+    if scope is not None and scope.exclude_patterns:
+        tool.set_options(exclude_patterns=list(scope.exclude_patterns))
+
     tool.set_options(include_venv=include_venv)
 
     # Set incremental mode if enabled
@@ -119,6 +168,8 @@ def configure_tool_for_execution(
         tool.set_options(incremental=True)
 
     # Resolve per-tool auto_install: per-tool config > global effective > False
+    if scope is not None and scope.config is not None:
+        lintro_config = scope.config
     lintro_config = lintro_config or get_config()
     tool_cfg = lintro_config.get_tool_config(tool_name)
     if tool_cfg.auto_install is not None:
@@ -129,6 +180,69 @@ def configure_tool_for_execution(
     if effective_tool_auto_install:
         tool.set_options(auto_install=True)
 
+    # Unsafe fixes follow execution.fix_safety unless set on the command line
+    if action == Action.FIX:
+        apply_fix_safety(
+            tool,
+            tool_name,
+            lintro_config.execution.fix_safety,
+            cli_overrides,
+        )
+
+    tool.set_options(
+        prune_dirs=list(lintro_config.execution.prune_dirs),
+        gitignore=gitignore and lintro_config.execution.gitignore,
+        symlinks=lintro_config.execution.symlinks,
+    )
+
+    # Configured limits; --tool-options still wins for the timeout
+    if tool_cfg.timeout is not None and "timeout" not in cli_overrides:
+        tool.set_options(timeout=tool_cfg.timeout)
+    tool.limits = resolve_resource_limits(tool_name, lintro_config)
+    tool.extra_args = resolve_extra_args(tool_name, lintro_config, cli_extra_args)
+
+    # Native tools run inside Lintro; external tools may run on a remote
+    # host or in a container
+    tool.remote = (
+        None
+        if tool.definition.native
+        else resolve_remote_spec(
+            tool_name,
+            lintro_config,
+            writable=action == Action.FIX,
+        )
+    )
+    tool.container = (
+        None
+        if tool.definition.native or tool.remote is not None
+        else resolve_container_spec(
+            tool_name,
+            lintro_config,
+            writable=action == Action.FIX,
+        )
+    )
+
+    # Daemons run on this host, next to the tools they replace
+    tool.daemon = (
+        None
+        if tool.definition.native
+        or tool.remote is not None
+        or tool.container is not None
+        else resolve_daemon_spec(tool_name, lintro_config)
+    )
+
+    # Node.js tools on this host run with the release the project pins
+    tool.toolchain_bin = (
+        _node_toolchain_bin(tool_name)
+        if tool.remote is None and tool.container is None
+        else None
+    )
+
+    # Cached per-file results only stand in for a plain check
+    tool.result_cache = (
+        resolve_result_cache(tool, lintro_config) if action == Action.CHECK else None
+    )
+
     # Handle Black post-check coordination with Ruff
     # If Black is configured as a post-check, avoid double formatting by
     # disabling Ruff's formatting stages unless explicitly overridden.
@@ -228,7 +342,11 @@ def get_tools_to_run(
         for name in available_tools:
             if name.lower() == "pytest":
                 continue
-            if not lintro_config.is_tool_enabled(name):
+            definition = tool_manager.get_tool(name).definition
+            if not lintro_config.is_tool_enabled(
+                name,
+                by_default=definition.enabled_by_default,
+            ):
                 reason = _get_disabled_reason(lintro_config, name)
                 skipped.append(SkippedTool(name=name, reason=reason))
             else:
@@ -263,10 +381,255 @@ def get_tools_to_run(
         # Verify the tool supports the requested action
         if action == Action.FIX:
             tool_instance = tool_manager.get_tool(name)
-            if not tool_instance.definition.can_fix:
+            if not tool_instance.capabilities().supports_fix:
                 raise ValueError(
                     f"Tool '{name}' does not support formatting",
                 )
         to_run.append(name)
 
     return ToolsToRunResult(to_run=to_run, skipped=skipped)
+
+
+def apply_nested_config_scopes(
+    tools_result: ToolsToRunResult,
+    paths: list[str],
+    nested_dirs: list[Path] | None = None,
+) -> dict[str, ToolScopePlan]:
+    """Adjust tool selection for nested .lintro-config.yaml files.
+
+    Tools disabled at the top level but enabled by a nested config are moved
+    from ``skipped`` to ``to_run``; tools that end up with nothing to run on
+    are moved the other way. Tools skipped for other reasons are untouched.
+
+    Args:
+        tools_result: Selection from get_tools_to_run(); updated in place.
+        paths: Input paths passed to lintro.
+        nested_dirs: Result of find_nested_config_dirs(paths), or None to
+            search for nested configs here.
+
+    Returns:
+        dict[str, ToolScopePlan]: Per-tool scope plans. Empty when no nested
+            config files exist below the input paths.
+    """
+    from lintro.config.config_hierarchy import (
+        find_nested_config_dirs,
+        plan_tool_scopes,
+    )
+
+    if nested_dirs is None:
+        nested_dirs = find_nested_config_dirs(paths)
+    if not nested_dirs:
+        return {}
+
+    scopes: dict[str, ToolScopePlan] = {}
+    to_run: list[str] = []
+    skipped: list[SkippedTool] = []
+
+    for name in tools_result.to_run:
+        # A tool selected at the top runs in subtrees that do not mention it
+        plan = plan_tool_scopes(name, paths, nested_dirs, root_enabled=True)
+        if plan.is_empty:
+            skipped.append(
+                SkippedTool(name=name, reason="disabled in nested configs"),
+            )
+        else:
+            scopes[name] = plan
+            to_run.append(name)
+
+    for tool in tools_result.skipped:
+        if tool.reason not in (
+            "disabled in config",
+            "not in enabled_tools",
+            "not enabled by default",
+        ):
+            skipped.append(tool)
+            continue
+        plan = plan_tool_scopes(
+            tool.name,
+            paths,
+            nested_dirs,
+            root_enabled=False,
+            by_default=tool_manager.get_tool(tool.name).definition.enabled_by_default,
+        )
+        if plan.is_empty:
+            skipped.append(tool)
+        else:
+            scopes[tool.name] = plan
+            to_run.append(tool.name)
+
+    tools_result.to_run = to_run
+    tools_result.skipped = skipped
+    return scopes
+
+
+def apply_label_scopes(
+    tools_result: ToolsToRunResult,
+    paths: list[str],
+    scopes: dict[str, ToolScopePlan],
+) -> None:
+    """Run the tools of label policies on their labelled directories.
+
+    A tool disabled in config (or opt-in and not enabled) but listed in the
+    policy of a directory label is moved from ``skipped`` to ``to_run`` and
+    limited to the labelled directories within the input paths. Tools that
+    already run, and tools skipped for other reasons, are untouched.
+
+    Args:
+        tools_result: Selection after apply_nested_config_scopes(); updated
+            in place.
+        paths: Input paths passed to lintro.
+        scopes: Per-tool scope plans; updated with the labelled tools.
+    """
+    from lintro.config.config_hierarchy import ToolScopePlan
+    from lintro.utils.execution.labels import DirectoryLabels
+
+    lintro_config = get_config()
+    labels = DirectoryLabels.from_config(lintro_config)
+    if not labels:
+        return
+
+    skipped: list[SkippedTool] = []
+    for tool in tools_result.skipped:
+        policy_labels = [
+            label
+            for label, policy in lintro_config.label_policies.items()
+            if tool.name.lower() in (t.lower() for t in policy.tools)
+        ]
+        directories = labels.directories_for(policy_labels)
+        if not directories or tool.reason not in (
+            "disabled in config",
+            "not in enabled_tools",
+            "not enabled by default",
+        ):
+            skipped.append(tool)
+            continue
+        scoped: list[str] = []
+        for raw in paths:
+            resolved = Path(raw).resolve()
+            for directory in directories:
+                if resolved == directory or resolved in directory.parents:
+                    candidate = str(directory) if directory.exists() else None
+                elif directory in resolved.parents:
+                    candidate = raw
+                else:
+                    candidate = None
+                if candidate is not None and candidate not in scoped:
+                    scoped.append(candidate)
+        if not scoped:
+            skipped.append(tool)
+            continue
+        scopes[tool.name] = ToolScopePlan(paths=scoped)
+        tools_result.to_run.append(tool.name)
+    tools_result.skipped = skipped
+
+
+def apply_override_scopes(
+    tools_result: ToolsToRunResult,
+    paths: list[str],
+    scopes: dict[str, ToolScopePlan],
+    nested_dirs: list[Path] | None = None,
+) -> None:
+    """Keep tools away from the files that per-path overrides disable them for.
+
+    Each selected tool named in an override's ``disable`` list gets the
+    override's patterns as extra excludes on its scope and on the scopes of
+    its separately run subtrees.
+
+    Args:
+        tools_result: Selection after the nested config and label scopes.
+        paths: Input paths passed to lintro.
+        scopes: Per-tool scope plans; updated with the excludes.
+        nested_dirs: Directories with nested configs, whose own overrides
+            cover their subtrees.
+    """
+    from lintro.config.config_hierarchy import ToolScopePlan
+    from lintro.utils.execution.path_overrides import PathOverrides
+
+    overrides = PathOverrides.from_config(get_config(), nested_dirs or ())
+    if not overrides:
+        return
+    for name in tools_result.to_run:
+        patterns = overrides.excludes_for(name)
+        if patterns:
+            scope = scopes.setdefault(name, ToolScopePlan(paths=list(paths)))
+            for plan in (scope, *scope.subtrees):
+                plan.exclude_patterns.extend(patterns)
+
+
+def merge_scope_results(name: str, results: list[ToolResult]) -> ToolResult:
+    """Combine the results of a tool's separately run subtrees.
+
+    Args:
+        name: Tool name.
+        results: Result of the main run followed by those of the subtrees.
+
+    Returns:
+        ToolResult: One result with every run's issues and counts.
+    """
+    ran = [result for result in results if not result.skipped]
+    if len(ran) < 2:
+        return ran[0] if ran else results[0]
+
+    def total(attribute: str) -> int | None:
+        """Add up a count the runs may leave unset.
+
+        Args:
+            attribute: ToolResult field name.
+
+        Returns:
+            int | None: Sum of the set values, or None if no run set it.
+        """
+        values = [getattr(result, attribute) for result in ran]
+        if all(value is None for value in values):
+            return None
+        return sum(value or 0 for value in values)
+
+    output = "\n".join(result.output for result in ran if result.output)
+    return ToolResult(
+        name=name,
+        success=all(result.success for result in ran),
+        output=output or None,
+        issues_count=sum(result.issues_count for result in ran),
+        issues=[issue for result in ran for issue in result.issues or []],
+        initial_issues_count=total("initial_issues_count"),
+        fixed_issues_count=total("fixed_issues_count"),
+        remaining_issues_count=total("remaining_issues_count"),
+        error_code=next((r.error_code for r in ran if r.error_code), None),
+        duration_ms=total("duration_ms"),
+    )
+
+
+def run_subtree_scopes(
+    tool: BaseToolPlugin,
+    result: ToolResult,
+    scope: ToolScopePlan | None,
+    configure: Callable[..., None],
+    run: Callable[[list[str]], ToolResult],
+) -> ToolResult:
+    """Run a tool on the subtrees whose nested config changes its settings.
+
+    Args:
+        tool: Tool plugin, configured for and run on the main scope.
+        result: Result of the main run.
+        scope: Scope plan of the main run, or None.
+        configure: Configures the tool; called with ``scope=`` a subtree.
+        run: Checks or fixes the given paths with the configured tool.
+
+    Returns:
+        ToolResult: The main result merged with the subtrees' results.
+    """
+    if scope is None or not scope.subtrees or result.skipped:
+        return result
+    results = [result]
+    previous = scope
+    for subtree in scope.subtrees:
+        # Exclude patterns accumulate on the tool; drop the previous run's
+        tool.exclude_patterns = [
+            pattern
+            for pattern in tool.exclude_patterns
+            if pattern not in previous.exclude_patterns
+        ]
+        configure(scope=subtree)
+        results.append(run(subtree.paths))
+        previous = subtree
+    return merge_scope_results(tool.definition.name, results)
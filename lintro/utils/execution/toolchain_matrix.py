@@ -0,0 +1,136 @@
+"""Running version-sensitive tools once per configured toolchain.
+
+With ``lintro check --toolchain-matrix``, mypy runs once per Python version
+and clippy once per Rust toolchain listed in ``execution.toolchain_matrix``.
+The runs are merged into one result per tool: an issue reported by several
+toolchains appears once, and issues not reported by every toolchain are
+tagged with the toolchains that report them.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Mapping, Sequence
+from dataclasses import replace
+from typing import TYPE_CHECKING
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.utils.effort import issue_code
+
+if TYPE_CHECKING:
+    from lintro.plugins.base import BaseToolPlugin
+
+# Version-sensitive tools and the toolchain kind they run per
+MATRIX_TOOLS: dict[str, str] = {
+    "mypy": "python",
+    "clippy": "rust",
+}
+
+
+def matrix_versions(tool_name: str, matrix: Mapping[str, Sequence[str]]) -> list[str]:
+    """Return the toolchains a tool runs on.
+
+    Args:
+        tool_name: Tool name.
+        matrix: Configured versions per toolchain kind.
+
+    Returns:
+        list[str]: Versions to run, or an empty list to run the tool once.
+    """
+    kind = MATRIX_TOOLS.get(tool_name.lower())
+    return list(matrix.get(kind, [])) if kind else []
+
+
+def _issue_key(issue: BaseIssue) -> tuple[str, int, int, str, str]:
+    """Identify an issue across toolchain runs.
+
+    Args:
+        issue: Parsed issue.
+
+    Returns:
+        tuple[str, int, int, str, str]: File, line, column, code and message.
+    """
+    return (issue.file, issue.line, issue.column, issue_code(issue), issue.message)
+
+
+def merge_matrix_results(
+    tool_name: str,
+    runs: Sequence[tuple[str, ToolResult]],
+    tag_field: str = "toolchains",
+) -> ToolResult:
+    """Merge one tool's results from several toolchains.
+
+    Args:
+        tool_name: Tool name.
+        runs: (toolchain label, result) per run, in matrix order.
+        tag_field: Issue field listing the labels of the runs that report
+            an issue, such as clippy's target/feature ``configurations``.
+
+    Returns:
+        ToolResult: Combined result with deduplicated, tagged issues.
+    """
+    ran = [(label, result) for label, result in runs if not result.skipped]
+    if not ran:
+        return runs[0][1]
+
+    issues: dict[tuple[str, int, int, str, str], BaseIssue] = {}
+    labels: dict[tuple[str, int, int, str, str], list[str]] = {}
+    for label, result in ran:
+        for issue in result.issues or []:
+            key = _issue_key(issue)
+            issues.setdefault(key, issue)
+            labels.setdefault(key, []).append(label)
+
+    merged = [
+        (
+            replace(issues[key], **{tag_field: labels[key]})
+            if len(labels[key]) < len(ran)
+            else issues[key]
+        )
+        for key in issues
+    ]
+    output = "\n".join(
+        f"[{label}]\n{result.output}" for label, result in ran if result.output
+    )
+    return ToolResult(
+        name=tool_name,
+        success=all(result.success for _, result in ran),
+        output=output or None,
+        issues_count=(
+            len(merged)
+            if merged
+            else max(result.issues_count for _, result in ran)
+        ),
+        issues=merged,
+    )
+
+
+def run_toolchain_matrix(
+    tool: BaseToolPlugin,
+    paths: list[str],
+    versions: Sequence[str],
+) -> ToolResult:
+    """Check paths once per toolchain and merge the results.
+
+    Args:
+        tool: Configured tool plugin.
+        paths: Paths to check.
+        versions: Toolchain versions to run.
+
+    Returns:
+        ToolResult: Combined result.
+    """
+    name = tool.definition.name
+    kind = MATRIX_TOOLS.get(name, "toolchain")
+    previous = tool.options.get("toolchain")
+    runs: list[tuple[str, ToolResult]] = []
+    try:
+        for version in versions:
+            tool.set_options(toolchain=version)
+            runs.append((f"{kind} {version}", tool.check(paths, {})))
+    finally:
+        if previous is None:
+            tool.options.pop("toolchain", None)
+        else:
+            tool.options["toolchain"] = previous
+    return merge_matrix_results(name, runs)
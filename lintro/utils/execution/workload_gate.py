@@ -0,0 +1,162 @@
+"""File-count safeguards that run before any tool starts.
+
+A config mistake that pulls a vendored or generated tree into a run can turn
+a one-minute check into hours of tool runs. ``--max-files N`` counts the files
+the selected tools would process and aborts when there are more than N;
+``--confirm-over N`` asks before continuing instead. Both print where the
+files come from, by tool and by top-level directory, so the stray tree is
+easy to spot and exclude.
+"""
+
+from __future__ import annotations
+
+import os
+from collections import Counter
+from collections.abc import Mapping, Sequence
+from dataclasses import dataclass
+
+from lintro.plugins.file_discovery import setup_exclude_patterns
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.path_filtering import (
+    DEFAULT_PRUNE_DIRS,
+    DEFAULT_SYMLINK_POLICY,
+    matches_tool_files,
+    walk_files_with_excludes,
+)
+
+# Largest tools and directories listed in a breakdown
+BREAKDOWN_TOP: int = 5
+
+
+@dataclass(frozen=True)
+class WorkloadPreview:
+    """Files the selected tools would process.
+
+    Attributes:
+        total: Distinct files across all tools.
+        per_tool: Files per tool, largest first.
+        per_directory: Files per top-level directory of the searched paths,
+            largest first. Files directly in a searched directory count
+            under that directory.
+    """
+
+    total: int
+    per_tool: list[tuple[str, int]]
+    per_directory: list[tuple[str, int]]
+
+    def describe(self, limit: int) -> str:
+        """Describe a workload that exceeds a limit.
+
+        Args:
+            limit: File count the workload exceeds.
+
+        Returns:
+            str: Multi-line breakdown by tool and directory.
+        """
+
+        def top(counts: list[tuple[str, int]]) -> str:
+            """Format the largest counts.
+
+            Args:
+                counts: Names and counts, largest first.
+
+            Returns:
+                str: Comma-separated ``name count`` pairs.
+            """
+            shown = ", ".join(
+                f"{name} {count:,}" for name, count in counts[:BREAKDOWN_TOP]
+            )
+            hidden = len(counts) - BREAKDOWN_TOP
+            return f"{shown}, and {hidden} more" if hidden > 0 else shown
+
+        return "\n".join(
+            [
+                f"{self.total:,} files to process, more than the limit of {limit:,}.",
+                f"  By tool: {top(self.per_tool)}",
+                f"  By directory: {top(self.per_directory)}",
+                "Exclude the unexpected directories (--exclude or .lintro-ignore) "
+                "or raise the limit.",
+            ],
+        )
+
+
+def _directory_key(root: str, file_path: str) -> str:
+    """Name the top-level directory of a file below a searched path.
+
+    Args:
+        root: Searched path.
+        file_path: Absolute path of a file below it.
+
+    Returns:
+        str: The searched path joined with the file's first directory, or
+        the searched path itself for files directly in it.
+    """
+    relative = os.path.relpath(file_path, os.path.abspath(root))
+    first, _, rest = relative.partition(os.sep)
+    return os.path.normpath(os.path.join(root, first) if rest else root)
+
+
+def preview_workload(
+    definitions: Mapping[str, ToolDefinition],
+    paths: Sequence[str],
+    exclude: str | None = None,
+    include_venv: bool = False,
+    prune_dirs: Sequence[str] = DEFAULT_PRUNE_DIRS,
+    gitignore: bool = True,
+    symlinks: str = DEFAULT_SYMLINK_POLICY,
+) -> WorkloadPreview:
+    """Count the files each tool would process, walking the tree once.
+
+    Nested configs and per-tool excludes can narrow a tool's files further,
+    so the counts are an upper bound.
+
+    Args:
+        definitions: Definitions of the selected tools, by name.
+        paths: Paths passed to lintro.
+        exclude: Comma-separated exclude patterns, if any.
+        include_venv: Whether virtual environments are included.
+        prune_dirs: Directory names discovery does not descend into.
+        gitignore: Whether files git ignores are skipped.
+        symlinks: How symbolic links are treated (see SYMLINK_POLICIES).
+
+    Returns:
+        WorkloadPreview: Files in total, per tool and per directory.
+    """
+    cli_excludes = [p.strip() for p in (exclude or "").split(",") if p.strip()]
+    excludes = setup_exclude_patterns(cli_excludes)
+    patterns = sorted({p for d in definitions.values() for p in d.file_patterns})
+    interpreters = sorted(
+        {i for d in definitions.values() for i in d.shebang_interpreters},
+    )
+
+    per_tool: Counter[str] = Counter()
+    per_directory: Counter[str] = Counter()
+    seen: set[str] = set()
+    for root in paths:
+        for file_path in walk_files_with_excludes(
+            [root],
+            patterns,
+            excludes,
+            include_venv,
+            shebang_interpreters=interpreters,
+            prune_dirs=prune_dirs,
+            gitignore=gitignore,
+            symlinks=symlinks,
+        ):
+            if file_path in seen:
+                continue
+            seen.add(file_path)
+            per_directory[_directory_key(root, file_path)] += 1
+            for name, definition in definitions.items():
+                if matches_tool_files(
+                    file_path,
+                    definition.file_patterns,
+                    definition.shebang_interpreters,
+                ):
+                    per_tool[name] += 1
+
+    return WorkloadPreview(
+        total=len(seen),
+        per_tool=per_tool.most_common(),
+        per_directory=per_directory.most_common(),
+    )
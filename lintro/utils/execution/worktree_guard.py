@@ -0,0 +1,198 @@
+"""Guard against fixing files with uncommitted changes.
+
+Fixes written on top of a user's in-progress edits cannot be told apart from
+them afterwards, or reverted on their own. Before ``lintro format`` writes
+to the project, the paths it fixes are checked with ``git status``; the
+``execution.dirty_worktree`` policy (or ``--allow-dirty`` and
+``--autostash``) decides what happens when they have uncommitted changes:
+
+- ``refuse`` stops before any tool runs and lists the changed files.
+- ``allow`` fixes them anyway.
+- ``stash`` stashes the changes, fixes the clean files and re-applies the
+  stash. When a tool or the configuration fails the fixes are discarded
+  first, so the work tree ends up exactly as it was; issues left unfixed
+  keep the fixes that were made.
+
+Outside a git repository there is nothing to compare against and the run
+proceeds. Lintro's own ``.lintro`` directory does not count as a change.
+"""
+
+from __future__ import annotations
+
+import subprocess
+from collections.abc import Sequence
+from dataclasses import dataclass
+from pathlib import PurePosixPath
+
+from loguru import logger
+
+from lintro.enums.git_command import GitCommand
+from lintro.exceptions.errors import LintroError
+
+STASH_MESSAGE: str = "lintro format autostash"
+
+# Directories lintro writes to itself
+_OWN_DIRS: frozenset[str] = frozenset({".lintro"})
+
+
+class DirtyWorktreeError(LintroError):
+    """Raised when the paths to fix have uncommitted changes to protect."""
+
+
+def _git(
+    command: GitCommand,
+    *args: str,
+) -> subprocess.CompletedProcess[str] | None:
+    """Run a git command in the working directory.
+
+    Args:
+        command: Git command to run.
+        *args: Arguments for the command.
+
+    Returns:
+        subprocess.CompletedProcess[str] | None: The finished process, or
+        None if git is missing or hangs.
+    """
+    try:
+        return subprocess.run(
+            ["git", str(command), *args],
+            capture_output=True,
+            text=True,
+            timeout=30,
+        )
+    except (FileNotFoundError, OSError, subprocess.TimeoutExpired):
+        return None
+
+
+def dirty_files(paths: Sequence[str]) -> list[str] | None:
+    """List the files with uncommitted changes under some paths.
+
+    Staged, unstaged and untracked (but not ignored) files count.
+
+    Args:
+        paths: Files or directories to look at.
+
+    Returns:
+        list[str] | None: Changed paths relative to the repository root, or
+        None outside a git repository.
+    """
+    result = _git(
+        GitCommand.STATUS,
+        "--porcelain=v1",
+        "-z",
+        "--untracked-files=all",
+        "--",
+        *paths,
+    )
+    if result is None or result.returncode != 0:
+        return None
+    entries = iter(result.stdout.split("\0"))
+    files: list[str] = []
+    for entry in entries:
+        if len(entry) < 4:
+            continue
+        status, path = entry[:2], entry[3:]
+        if "R" in status or "C" in status:
+            # The source path of a rename or copy follows as its own entry
+            next(entries, None)
+        if _OWN_DIRS.isdisjoint(PurePosixPath(path).parts):
+            files.append(path)
+    return files
+
+
+@dataclass
+class Autostash:
+    """Uncommitted changes stashed for the duration of a fix run.
+
+    Attributes:
+        paths: Paths being fixed.
+        files: Stashed files, relative to the repository root.
+    """
+
+    paths: list[str]
+    files: list[str]
+
+    @classmethod
+    def push(cls, paths: Sequence[str], files: Sequence[str]) -> Autostash:
+        """Stash the uncommitted changes of some files.
+
+        Only the listed files are stashed, so files lintro writes during
+        the run (such as its audit log) cannot collide with the stash.
+
+        Args:
+            paths: Paths about to be fixed.
+            files: Changed files from :func:`dirty_files`.
+
+        Returns:
+            Autostash: Handle to restore the changes with.
+
+        Raises:
+            DirtyWorktreeError: If git cannot stash the changes.
+        """
+        result = _git(
+            GitCommand.STASH,
+            "push",
+            "--include-untracked",
+            "--message",
+            STASH_MESSAGE,
+            "--",
+            *(f":(top,literal){file}" for file in files),
+        )
+        if result is None or result.returncode != 0:
+            detail = result.stderr.strip() if result is not None else "git failed"
+            raise DirtyWorktreeError(f"Cannot stash uncommitted changes: {detail}")
+        logger.info("Stashed uncommitted changes before fixing")
+        return cls(paths=list(paths), files=list(files))
+
+    def restore(self, failed: bool) -> bool:
+        """Re-apply the stashed changes.
+
+        Args:
+            failed: Whether the fix run broke (a tool or configuration
+                error, not remaining issues); its fixes to tracked files are
+                then discarded so the stash applies cleanly.
+
+        Returns:
+            bool: True if the changes are back in the work tree; False if
+            they stay in the stash because they conflict with the fixes.
+        """
+        if failed:
+            _git(GitCommand.CHECKOUT, "--", *self.paths)
+        result = _git(GitCommand.STASH, "pop", "--index")
+        if result is not None and result.returncode == 0:
+            return True
+        logger.warning(
+            "Could not re-apply your uncommitted changes on top of the fixes; "
+            f"they are kept in the stash ({STASH_MESSAGE}). Review the fixes, "
+            "then run `git stash pop`.",
+        )
+        return False
+
+
+def guard_worktree(paths: Sequence[str], policy: str) -> Autostash | None:
+    """Apply the dirty work tree policy before fixing.
+
+    Args:
+        paths: Paths about to be fixed.
+        policy: ``refuse``, ``allow`` or ``stash``.
+
+    Returns:
+        Autostash | None: Stashed changes to restore after the run, if any.
+
+    Raises:
+        DirtyWorktreeError: If the policy is ``refuse`` and the paths have
+            uncommitted changes, or they cannot be stashed.
+    """
+    if policy == "allow":
+        return None
+    files = dirty_files(paths)
+    if not files:
+        return None
+    if policy == "stash":
+        return Autostash.push(paths, files)
+    more = f" and {len(files) - 5} more" if len(files) > 5 else ""
+    raise DirtyWorktreeError(
+        f"Uncommitted changes in {len(files)} file(s) to fix: "
+        f"{', '.join(files[:5])}{more}. Commit or stash them first, or pass "
+        "--allow-dirty or --autostash.",
+    )
@@ -0,0 +1,197 @@
+"""Content-based fingerprints that identify an issue across runs.
+
+Line numbers move whenever code above an issue changes, so they cannot tell
+a new issue from an old one. A fingerprint instead hashes the tool, the rule,
+the file's path relative to the working directory and the flagged line's
+text with whitespace collapsed. Adding or removing lines elsewhere, or
+re-indenting the flagged line, keeps the fingerprint; editing the flagged
+line changes it.
+
+Issues without a line, or whose file cannot be read, hash their message with
+digits masked instead of the line's text. Issues that would share a
+fingerprint, such as one rule flagging several columns of a line or several
+identical lines of a file, are told apart by their order of occurrence.
+
+JSON and SARIF reports record each issue's fingerprint, so anything that
+compares runs can match issues without relying on line numbers.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import os
+import re
+from collections.abc import Iterable
+from functools import lru_cache
+
+from lintro.utils.effort import issue_code
+
+# Length of a fingerprint in hex characters
+FINGERPRINT_LENGTH: int = 32
+
+_DIGITS = re.compile(r"\d+")
+_WHITESPACE = re.compile(r"\s+")
+
+
+def normalize_issue_path(path: str) -> str:
+    """Return the path an issue's fingerprint is computed from.
+
+    Args:
+        path: File path as reported by a tool.
+
+    Returns:
+        str: POSIX path relative to the working directory when the file is
+        inside it, otherwise the absolute POSIX path.
+    """
+    if not path:
+        return ""
+    absolute = os.path.abspath(path)
+    rel = os.path.relpath(absolute)
+    if not rel.startswith(".."):
+        absolute = rel
+    return absolute.replace(os.sep, "/")
+
+
+@lru_cache(maxsize=256)
+def _source_lines(path: str, mtime_ns: int, size: int) -> tuple[str, ...]:
+    """Read a file's lines, cached while the file is unchanged.
+
+    Args:
+        path: Absolute path of the file.
+        mtime_ns: Modification time, so edited files are read again.
+        size: Size in bytes, for edits within the clock's resolution.
+
+    Returns:
+        tuple[str, ...]: Lines of the file, empty if it cannot be read.
+    """
+    try:
+        with open(path, encoding="utf-8", errors="replace") as f:
+            return tuple(f.read().splitlines())
+    except OSError:
+        return ()
+
+
+def source_lines(path: str) -> tuple[str, ...]:
+    """Return the lines of a source file an issue points into.
+
+    Args:
+        path: File path as reported by a tool.
+
+    Returns:
+        tuple[str, ...]: Lines of the file, empty if it cannot be read.
+    """
+    if not path:
+        return ()
+    absolute = os.path.abspath(path)
+    try:
+        stat = os.stat(absolute)
+    except OSError:
+        return ()
+    return _source_lines(absolute, stat.st_mtime_ns, stat.st_size)
+
+
+def _line_text(path: str, line: int) -> str | None:
+    """Return the flagged line with whitespace collapsed.
+
+    Args:
+        path: File path as reported by a tool.
+        line: 1-based line number.
+
+    Returns:
+        str | None: Text of the line, or None if it cannot be read.
+    """
+    lines = source_lines(path) if line >= 1 else ()
+    if not 1 <= line <= len(lines):
+        return None
+    return _WHITESPACE.sub(" ", lines[line - 1]).strip()
+
+
+def context_hash(issue: object) -> str:
+    """Hash the code an issue flags.
+
+    Args:
+        issue: Parsed issue.
+
+    Returns:
+        str: Hex digest of the flagged line's text, or of the message with
+        digits masked when the line cannot be read.
+    """
+    line = getattr(issue, "line", None)
+    text = _line_text(
+        str(getattr(issue, "file", "") or ""),
+        line if isinstance(line, int) else 0,
+    )
+    if text is None:
+        message = str(getattr(issue, "message", "") or "")
+        text = "message:" + _DIGITS.sub("#", message)
+    return hashlib.sha256(text.encode("utf-8")).hexdigest()
+
+
+def issue_fingerprint(tool_name: str, issue: object, occurrence: int = 0) -> str:
+    """Compute the fingerprint of one issue.
+
+    Args:
+        tool_name: Name of the tool that reported the issue.
+        issue: Parsed issue.
+        occurrence: Position among the tool's issues that share the
+            fingerprint, 0 for the first.
+
+    Returns:
+        str: Hex fingerprint.
+    """
+    parts = [
+        tool_name.lower(),
+        issue_code(issue),
+        normalize_issue_path(str(getattr(issue, "file", "") or "")),
+        context_hash(issue),
+    ]
+    if occurrence:
+        parts.append(str(occurrence))
+    digest = hashlib.sha256("\0".join(parts).encode("utf-8")).hexdigest()
+    return digest[:FINGERPRINT_LENGTH]
+
+
+def _position(issue: object) -> tuple[int, int]:
+    """Return an issue's line and column for ordering repeats.
+
+    Args:
+        issue: Parsed issue.
+
+    Returns:
+        tuple[int, int]: Line and column, 0 when unknown.
+    """
+    line = getattr(issue, "line", None)
+    column = getattr(issue, "column", None)
+    return (
+        line if isinstance(line, int) else 0,
+        column if isinstance(column, int) else 0,
+    )
+
+
+def fingerprint_issues(tool_name: str, issues: Iterable[object]) -> list[str]:
+    """Compute distinct fingerprints for the issues of one tool.
+
+    Args:
+        tool_name: Name of the tool that reported the issues.
+        issues: Issues in the order the tool reported them.
+
+    Returns:
+        list[str]: Fingerprint of each issue, in the same order.
+    """
+    issue_list = list(issues)
+    bases = [issue_fingerprint(tool_name, issue) for issue in issue_list]
+    groups: dict[str, list[int]] = {}
+    for index, base in enumerate(bases):
+        groups.setdefault(base, []).append(index)
+
+    fingerprints = list(bases)
+    for indices in groups.values():
+        # Number repeats by position so tool output order does not matter
+        indices.sort(key=lambda i: (_position(issue_list[i]), i))
+        for occurrence, index in enumerate(indices[1:], start=1):
+            fingerprints[index] = issue_fingerprint(
+                tool_name,
+                issue_list[index],
+                occurrence,
+            )
+    return fingerprints
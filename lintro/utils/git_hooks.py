@@ -0,0 +1,217 @@
+"""Running lintro from git hooks and pre-commit.
+
+``lintro install-hooks`` writes a small shell hook that runs
+``lintro check --staged`` before each commit, and optionally a full
+``lintro check`` before each push. Hooks carry a marker line, so running
+the installer again updates them, while hooks written by other tools are
+only replaced with ``--force``.
+
+``lintro generate pre-commit-config`` instead renders a local-repository
+entry for the pre-commit framework, with one hook per enabled tool whose
+``files`` pattern matches the tool's file patterns.
+"""
+
+from __future__ import annotations
+
+import re
+import stat
+from collections.abc import Mapping
+from pathlib import Path
+from typing import Any
+
+import yaml
+
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.git_command import GitCommand
+from lintro.exceptions.errors import LintroError
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.git_info import git_output
+
+HOOK_MARKER: str = "# Installed by lintro install-hooks"
+HOOK_ARGS: dict[str, str] = {
+    "pre-commit": "check --staged",
+    "pre-push": "check",
+}
+# Tools that do not lint the files of a commit
+NON_FILE_TOOLS: frozenset[str] = frozenset({"pytest", "conventional_commits"})
+
+
+class HookInstallError(LintroError):
+    """Raised when a git hook cannot be installed."""
+
+
+def hooks_dir(cwd: Path | None = None) -> Path | None:
+    """Find the directory git runs hooks from.
+
+    Honours ``core.hooksPath`` and linked worktrees.
+
+    Args:
+        cwd: Directory inside the repository. Defaults to the working
+            directory.
+
+    Returns:
+        Path | None: Hooks directory, or None outside a git repository.
+    """
+    output = git_output(GitCommand.REV_PARSE, "--git-path", "hooks", cwd=cwd)
+    if output is None:
+        return None
+    path = Path(output)
+    return path if path.is_absolute() else (cwd or Path.cwd()) / path
+
+
+def render_hook(hook: str, command: str = "lintro") -> str:
+    """Write the script of a lintro git hook.
+
+    Args:
+        hook: Hook name, a key of ``HOOK_ARGS``.
+        command: Command that runs lintro, such as ``uv run lintro``.
+
+    Returns:
+        str: POSIX shell script.
+    """
+    return (
+        "#!/bin/sh\n"
+        f"{HOOK_MARKER}; run it again to update this hook.\n"
+        f"exec {command} {HOOK_ARGS[hook]}\n"
+    )
+
+
+def install_hook(
+    hook: str,
+    command: str = "lintro",
+    force: bool = False,
+    cwd: Path | None = None,
+) -> Path:
+    """Install or update one lintro git hook.
+
+    Args:
+        hook: Hook name, a key of ``HOOK_ARGS``.
+        command: Command that runs lintro.
+        force: Replace a hook that lintro did not install.
+        cwd: Directory inside the repository.
+
+    Returns:
+        Path: The hook file.
+
+    Raises:
+        HookInstallError: Outside a git repository, or if another hook is
+            in the way and ``force`` is not set.
+    """
+    directory = hooks_dir(cwd)
+    if directory is None:
+        raise HookInstallError("Not inside a git repository")
+    path = directory / hook
+    if path.exists() and not force:
+        current = path.read_text(encoding="utf-8", errors="replace")
+        if HOOK_MARKER not in current:
+            raise HookInstallError(
+                f"{path} exists and was not installed by lintro; "
+                "use --force to replace it",
+            )
+    directory.mkdir(parents=True, exist_ok=True)
+    path.write_text(render_hook(hook, command), encoding="utf-8")
+    path.chmod(path.stat().st_mode | stat.S_IXUSR | stat.S_IXGRP | stat.S_IXOTH)
+    return path
+
+
+def _glob_regex(pattern: str) -> str:
+    """Translate a tool file pattern into a regex for pre-commit.
+
+    Args:
+        pattern: Glob such as ``*.py`` or ``Dockerfile.*``.
+
+    Returns:
+        str: Regex matching the pattern's file names.
+    """
+    parts = []
+    for token in re.split(r"(\*\*|\*|\?)", pattern):
+        if token == "**":
+            parts.append(".*")
+        elif token == "*":
+            parts.append("[^/]*")
+        elif token == "?":
+            parts.append("[^/]")
+        else:
+            parts.append(re.escape(token))
+    return "".join(parts)
+
+
+def files_regex(patterns: list[str]) -> str | None:
+    """Build a pre-commit ``files`` regex from tool file patterns.
+
+    Args:
+        patterns: Glob patterns matched against file names.
+
+    Returns:
+        str | None: Regex, or None when the patterns match every file.
+    """
+    if not patterns or "*" in patterns:
+        return None
+    alternatives = "|".join(_glob_regex(p) for p in sorted(set(patterns)))
+    return f"(^|/)({alternatives})$"
+
+
+def pre_commit_hooks(
+    config: LintroConfig,
+    definitions: Mapping[str, ToolDefinition],
+    command: str = "lintro",
+) -> list[dict[str, Any]]:
+    """List a pre-commit hook for each tool the lintro config enables.
+
+    Args:
+        config: Effective lintro configuration.
+        definitions: Definitions of the registered tools, by name.
+        command: Command that runs lintro.
+
+    Returns:
+        list[dict[str, Any]]: Hook entries, sorted by tool name.
+    """
+    hooks = []
+    for name in sorted(definitions):
+        definition = definitions[name]
+        if name in NON_FILE_TOOLS or not definition.file_patterns:
+            continue
+        if not config.is_tool_enabled(
+            name,
+            by_default=definition.enabled_by_default,
+        ):
+            continue
+        hook: dict[str, Any] = {
+            "id": f"lintro-{name.replace('_', '-')}",
+            "name": f"lintro {name}",
+            "entry": f"{command} check --tools {name}",
+            "language": "system",
+            "require_serial": True,
+        }
+        regex = files_regex(definition.file_patterns)
+        if regex is not None:
+            hook["files"] = regex
+        hooks.append(hook)
+    return hooks
+
+
+def render_pre_commit_config(
+    config: LintroConfig,
+    definitions: Mapping[str, ToolDefinition],
+    command: str = "lintro",
+) -> str:
+    """Render a ``.pre-commit-config.yaml`` running the enabled tools.
+
+    Args:
+        config: Effective lintro configuration.
+        definitions: Definitions of the registered tools, by name.
+        command: Command that runs lintro.
+
+    Returns:
+        str: YAML document with one local repository entry.
+    """
+    data = {
+        "repos": [
+            {
+                "repo": "local",
+                "hooks": pre_commit_hooks(config, definitions, command),
+            },
+        ],
+    }
+    header = "# Generated by lintro generate pre-commit-config\n"
+    return header + yaml.safe_dump(data, sort_keys=False, width=1000)
@@ -0,0 +1,40 @@
+"""Reading facts about the surrounding git repository."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+
+from lintro.enums.git_command import GitCommand
+
+
+def git_output(
+    command: GitCommand,
+    *args: str,
+    cwd: Path | None = None,
+) -> str | None:
+    """Run a read-only git command.
+
+    Args:
+        command: Git command to run.
+        *args: Arguments for the command.
+        cwd: Directory to run in. Defaults to the working directory.
+
+    Returns:
+        str | None: Stripped output, or None if git is missing, fails or
+        prints nothing.
+    """
+    try:
+        result = subprocess.run(
+            ["git", str(command), *args],
+            capture_output=True,
+            text=True,
+            timeout=5,
+            cwd=cwd,
+        )
+    except (FileNotFoundError, OSError, subprocess.TimeoutExpired):
+        return None
+    if result.returncode != 0:
+        return None
+    return result.stdout.strip() or None
+
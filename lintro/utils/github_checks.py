@@ -0,0 +1,365 @@
+"""Publishing report issues as a GitHub check run.
+
+``lintro report --github-checks --input REPORT`` creates one check run on
+the commit with a summary of the report and an inline annotation per
+issue, as an alternative to the workflow-command annotations of
+``--output-format github``. The Checks API takes at most 50 annotations
+per request, so the rest are added by updating the check run in batches.
+
+The check run's conclusion follows the failure policy (``fail_on``,
+``max_issues`` and rule ``budgets``): ``failure`` when the issues fail it,
+``neutral`` when there are issues it tolerates, and ``success`` without
+issues. A tool that failed to run (an error or timeout recorded in the
+report) always concludes ``failure``.
+
+In GitHub Actions the repository is read from ``GITHUB_REPOSITORY``, the
+commit from the pull request head in the event payload or ``GITHUB_SHA``,
+and the API from ``GITHUB_API_URL``; the token needs ``checks: write``.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+from collections import Counter
+from collections.abc import Iterable, Mapping, Sequence
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.utils.execution.exit_codes import is_infrastructure_failure
+from lintro.utils.execution.failure_policy import FailurePolicy
+from lintro.utils.github_review import DEFAULT_API_URL, GitHubReviewError
+from lintro.utils.review_comments import ReviewApi, repo_relative_path
+
+DEFAULT_CHECK_NAME: str = "Lintro"
+# Annotations the Checks API accepts per create or update request
+ANNOTATION_BATCH_SIZE: int = 50
+
+_ANNOTATION_LEVELS: dict[SeverityLevel, str] = {
+    SeverityLevel.ERROR: "failure",
+    SeverityLevel.WARNING: "warning",
+    SeverityLevel.INFO: "notice",
+}
+
+
+@dataclass(frozen=True)
+class CheckRunTarget:
+    """Commit the check run is created on.
+
+    Attributes:
+        repo: Repository as ``owner/name``.
+        head_sha: Commit SHA.
+        api_url: Base URL of the GitHub REST API.
+    """
+
+    repo: str
+    head_sha: str
+    api_url: str = DEFAULT_API_URL
+
+
+@dataclass(frozen=True)
+class CheckRunResult:
+    """Outcome of publishing a check run.
+
+    Attributes:
+        check_run_id: Identifier of the check run.
+        conclusion: ``success``, ``neutral`` or ``failure``.
+        annotations: Annotations added to the check run.
+        skipped: Issues without a file, left out of the annotations.
+        url: Page of the check run on GitHub, if known.
+    """
+
+    check_run_id: int
+    conclusion: str
+    annotations: int
+    skipped: int
+    url: str | None = None
+
+
+def _event_head_sha(event_path: str | None) -> str | None:
+    """Read the pull request head commit from a GitHub Actions event payload.
+
+    Args:
+        event_path: Path of the event payload, if any.
+
+    Returns:
+        str | None: Head commit SHA, or None if the event has no pull request.
+    """
+    if not event_path:
+        return None
+    try:
+        event = json.loads(Path(event_path).read_text(encoding="utf-8"))
+    except (OSError, ValueError):
+        return None
+    pull = event.get("pull_request") if isinstance(event, dict) else None
+    head = pull.get("head") if isinstance(pull, dict) else None
+    sha = head.get("sha") if isinstance(head, dict) else None
+    return sha if isinstance(sha, str) and sha else None
+
+
+def detect_check_target(
+    repo: str | None = None,
+    head_sha: str | None = None,
+) -> CheckRunTarget:
+    """Work out which commit to create the check run on.
+
+    In pull request workflows ``GITHUB_SHA`` is a merge commit nobody sees,
+    so the pull request's head commit is preferred.
+
+    Args:
+        repo: Repository as ``owner/name``; read from ``GITHUB_REPOSITORY``
+            when not given.
+        head_sha: Commit SHA; read from the GitHub Actions event payload or
+            ``GITHUB_SHA`` when not given.
+
+    Returns:
+        CheckRunTarget: The commit.
+
+    Raises:
+        GitHubReviewError: If the repository or commit is unknown.
+    """
+    repo = repo or os.environ.get("GITHUB_REPOSITORY")
+    head_sha = (
+        head_sha
+        or _event_head_sha(os.environ.get("GITHUB_EVENT_PATH"))
+        or os.environ.get("GITHUB_SHA")
+    )
+    if not repo or not head_sha:
+        raise GitHubReviewError(
+            "Cannot tell which commit to check; run in a GitHub Actions "
+            "workflow or pass --repo and --sha",
+        )
+    api_url = os.environ.get("GITHUB_API_URL") or DEFAULT_API_URL
+    return CheckRunTarget(repo=repo, head_sha=head_sha, api_url=api_url.rstrip("/"))
+
+
+def build_annotations(
+    records: Iterable[Mapping[str, Any]],
+    root: Path | None = None,
+) -> tuple[list[dict[str, Any]], int]:
+    """Turn issue records into check run annotations.
+
+    Args:
+        records: Issue records from ``report_records``.
+        root: Repository root the report paths are resolved against.
+
+    Returns:
+        tuple[list[dict[str, Any]], int]: Annotations sorted by file and
+        line, and the number of issues without a file to annotate.
+    """
+    annotations: list[dict[str, Any]] = []
+    skipped = 0
+    for record in records:
+        if not record["path"]:
+            skipped += 1
+            continue
+        # Issues about a whole file are annotated on its first line
+        line = max(int(record["line"] or 0), 1)
+        annotations.append(
+            {
+                "path": repo_relative_path(str(record["path"]), root),
+                "start_line": line,
+                "end_line": line,
+                "annotation_level": _ANNOTATION_LEVELS[record["severity"]],
+                "title": f"{record['tool']} {record['code']}".strip(),
+                "message": record["message"] or record["code"] or record["tool"],
+            },
+        )
+    annotations.sort(key=lambda a: (a["path"], a["start_line"]))
+    return annotations, skipped
+
+
+@dataclass
+class _RecordIssue(BaseIssue):
+    """Issue rebuilt from a report record, keeping its rule for budgets.
+
+    Attributes:
+        code: Rule code of the issue.
+    """
+
+    code: str = field(default="")
+
+
+def _policy_results(records: Iterable[Mapping[str, Any]]) -> list[ToolResult]:
+    """Rebuild per-tool results from issue records for the failure policy.
+
+    Args:
+        records: Issue records from ``report_records``.
+
+    Returns:
+        list[ToolResult]: One result per tool with issues.
+    """
+    issues: dict[str, list[BaseIssue]] = {}
+    for record in records:
+        issues.setdefault(str(record["tool"]), []).append(
+            _RecordIssue(
+                file=str(record["path"]),
+                line=int(record["line"] or 0),
+                message=str(record["message"]),
+                severity_override=record["severity"],
+                code=str(record["code"] or ""),
+            ),
+        )
+    return [
+        ToolResult(name=tool, success=False, issues=found, issues_count=len(found))
+        for tool, found in issues.items()
+    ]
+
+
+def failed_tools(report: Mapping[str, Any]) -> list[str]:
+    """List the tools of a JSON report that failed to run.
+
+    Args:
+        report: Report written with ``--output-format json``.
+
+    Returns:
+        list[str]: Tools that errored or timed out, in report order.
+    """
+    failed: list[str] = []
+    for entry in report.get("results") or []:
+        result = ToolResult(
+            name=str(entry.get("tool") or ""),
+            success=bool(entry.get("success", True)),
+            issues_count=int(entry.get("issues_count") or 0),
+            remaining_issues_count=entry.get("remaining"),
+            skipped=bool(entry.get("skipped")),
+            error_code=entry.get("error_code"),
+        )
+        if is_infrastructure_failure(result) or (
+            result.error_code and not result.skipped
+        ):
+            failed.append(result.name)
+    return failed
+
+
+def check_conclusion(
+    records: list[dict[str, Any]],
+    policy: FailurePolicy,
+    failed: Sequence[str] = (),
+) -> str:
+    """Decide the conclusion of the check run.
+
+    Args:
+        records: Issue records of the report.
+        policy: Failure policy of the run.
+        failed: Tools that failed to run (see failed_tools).
+
+    Returns:
+        str: ``failure`` if a tool failed or the issues fail the policy,
+        ``neutral`` if it tolerates them, ``success`` without issues.
+    """
+    if failed:
+        return "failure"
+    if not records:
+        return "success"
+    return "failure" if policy.exceeded(_policy_results(records)) else "neutral"
+
+
+def _check_output(
+    records: list[dict[str, Any]],
+    conclusion: str,
+    skipped: int,
+    failed: Sequence[str] = (),
+) -> tuple[str, str]:
+    """Write the title and Markdown summary of the check run.
+
+    Args:
+        records: Issue records of the report.
+        conclusion: Conclusion of the check run.
+        skipped: Issues without a file to annotate.
+        failed: Tools that failed to run.
+
+    Returns:
+        tuple[str, str]: Title and summary.
+    """
+    errors = (
+        [f"{len(failed)} tool(s) failed to run: {', '.join(failed)}."]
+        if failed
+        else []
+    )
+    if not records:
+        if failed:
+            return f"{len(failed)} tool(s) failed", "\n".join(errors)
+        return "No issues", "Lintro found no issues."
+    files = len({record["path"] for record in records if record["path"]})
+    title = f"{len(records)} issue(s) in {files} file(s)"
+    lines = [*errors, f"Lintro found {title}."]
+    if conclusion == "neutral":
+        lines.append("They are within the failure policy, so the check passes.")
+    lines += ["", "| Tool | Issues |", "| ---- | ------ |"]
+    counts = Counter(str(record["tool"]) for record in records)
+    lines += [f"| {tool} | {count} |" for tool, count in sorted(counts.items())]
+    if skipped:
+        lines += ["", f"{skipped} issue(s) without a file are not annotated."]
+    return title, "\n".join(lines)
+
+
+def publish_check_run(
+    records: list[dict[str, Any]],
+    target: CheckRunTarget,
+    client: ReviewApi,
+    policy: FailurePolicy,
+    root: Path | None = None,
+    name: str = DEFAULT_CHECK_NAME,
+    failed: Sequence[str] = (),
+) -> CheckRunResult:
+    """Create a completed check run for a report.
+
+    The first batch of annotations is sent with the new check run; the
+    others are added by updating it.
+
+    Args:
+        records: Issue records of the report.
+        target: Commit to create the check run on.
+        client: GitHub API client.
+        policy: Failure policy deciding the conclusion.
+        root: Repository root the report paths are resolved against.
+        name: Name of the check run.
+        failed: Tools the report records as failed to run.
+
+    Returns:
+        CheckRunResult: The check run created.
+
+    Raises:
+        GitHubReviewError: If the API rejects a request.
+    """
+    annotations, skipped = build_annotations(records, root)
+    conclusion = check_conclusion(records, policy, failed)
+    title, summary = _check_output(records, conclusion, skipped, failed)
+    batches = [
+        annotations[start : start + ANNOTATION_BATCH_SIZE]
+        for start in range(0, len(annotations), ANNOTATION_BATCH_SIZE)
+    ] or [[]]
+
+    base = f"/repos/{target.repo}/check-runs"
+    created = client.request(
+        "POST",
+        base,
+        {
+            "name": name,
+            "head_sha": target.head_sha,
+            "status": "completed",
+            "conclusion": conclusion,
+            "output": {"title": title, "summary": summary, "annotations": batches[0]},
+        },
+    )
+    check_run_id = (created or {}).get("id")
+    if not isinstance(check_run_id, int):
+        raise GitHubReviewError("Creating the check run returned no id")
+    for batch in batches[1:]:
+        client.request(
+            "PATCH",
+            f"{base}/{check_run_id}",
+            {"output": {"title": title, "summary": summary, "annotations": batch}},
+        )
+    return CheckRunResult(
+        check_run_id=check_run_id,
+        conclusion=conclusion,
+        annotations=len(annotations),
+        skipped=skipped,
+        url=(created or {}).get("html_url"),
+    )
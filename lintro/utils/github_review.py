@@ -0,0 +1,226 @@
+"""Publishing report issues as a GitHub pull request review.
+
+``lintro report --github-pr --input REPORT`` posts the issues on lines of
+the pull request's diff as inline review comments, batched into a single
+review, and on later runs edits or deletes the comments it posted before
+(see :mod:`lintro.utils.review_comments`).
+
+In GitHub Actions the repository and pull request are read from
+``GITHUB_REPOSITORY`` and the event payload, and the API from
+``GITHUB_API_URL``; the token needs ``pull-requests: write``.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from lintro.utils.review_comments import (
+    ExistingComment,
+    ReviewApi,
+    ReviewApiClient,
+    ReviewApiError,
+    ReviewComment,
+    ReviewResult,
+    build_review_comments,
+    diff_line_map,
+    plan_review_sync,
+)
+
+TOKEN_ENV: str = "GITHUB_TOKEN"
+DEFAULT_API_URL: str = "https://api.github.com"
+
+_PULL_REF_RE = re.compile(r"^refs/pull/(\d+)/")
+
+
+class GitHubReviewError(ReviewApiError):
+    """Raised when a review cannot be published."""
+
+
+@dataclass(frozen=True)
+class PullRequestRef:
+    """Pull request the review is posted on.
+
+    Attributes:
+        repo: Repository as ``owner/name``.
+        number: Pull request number.
+        api_url: Base URL of the GitHub REST API.
+    """
+
+    repo: str
+    number: int
+    api_url: str = DEFAULT_API_URL
+
+
+def _event_pull_number(event_path: str | None) -> int | None:
+    """Read the pull request number from a GitHub Actions event payload.
+
+    Args:
+        event_path: Path of the event payload, if any.
+
+    Returns:
+        int | None: Pull request number, or None if the event has none.
+    """
+    if not event_path:
+        return None
+    try:
+        event = json.loads(Path(event_path).read_text(encoding="utf-8"))
+    except (OSError, ValueError):
+        return None
+    pull = event.get("pull_request") if isinstance(event, dict) else None
+    number = pull.get("number") if isinstance(pull, dict) else None
+    return number if isinstance(number, int) else None
+
+
+def detect_pull_request(
+    repo: str | None = None,
+    number: int | None = None,
+) -> PullRequestRef:
+    """Work out which pull request to review.
+
+    Args:
+        repo: Repository as ``owner/name``; read from ``GITHUB_REPOSITORY``
+            when not given.
+        number: Pull request number; read from the GitHub Actions event
+            payload or ``GITHUB_REF`` when not given.
+
+    Returns:
+        PullRequestRef: The pull request.
+
+    Raises:
+        GitHubReviewError: If the repository or pull request is unknown.
+    """
+    repo = repo or os.environ.get("GITHUB_REPOSITORY")
+    if number is None:
+        number = _event_pull_number(os.environ.get("GITHUB_EVENT_PATH"))
+    if number is None:
+        match = _PULL_REF_RE.match(os.environ.get("GITHUB_REF", ""))
+        number = int(match.group(1)) if match else None
+    if not repo or number is None:
+        raise GitHubReviewError(
+            "Cannot tell which pull request to review; run in a pull_request "
+            "workflow or pass --repo and --pr",
+        )
+    api_url = os.environ.get("GITHUB_API_URL") or DEFAULT_API_URL
+    return PullRequestRef(repo=repo, number=number, api_url=api_url.rstrip("/"))
+
+
+class GitHubClient(ReviewApiClient):
+    """Minimal GitHub REST client for pull request reviews."""
+
+    error = GitHubReviewError
+
+    def __init__(self, token: str, api_url: str, timeout: float = 30.0) -> None:
+        """Create the client.
+
+        Args:
+            token: Token with permission to write pull request reviews.
+            api_url: Base URL of the GitHub REST API.
+            timeout: Seconds to wait for each response.
+        """
+        super().__init__(
+            api_url,
+            {
+                "Accept": "application/vnd.github+json",
+                "Authorization": f"Bearer {token}",
+                "X-GitHub-Api-Version": "2022-11-28",
+            },
+            timeout,
+        )
+
+
+def _review_summary(comments: list[ReviewComment], outside_diff: int) -> str:
+    """Write the body of the review.
+
+    Args:
+        comments: Comments posted with the review.
+        outside_diff: Issues not on a line of the diff.
+
+    Returns:
+        str: Markdown review body.
+    """
+    summary = f"Lintro found issues on {len(comments)} changed line(s)."
+    if outside_diff:
+        summary += f" {outside_diff} issue(s) outside the diff are not shown inline."
+    return summary
+
+
+def publish_review(
+    records: list[dict[str, Any]],
+    pull: PullRequestRef,
+    client: ReviewApi,
+    root: Path | None = None,
+) -> ReviewResult:
+    """Bring the lintro review comments of a pull request up to date.
+
+    New comments are posted as one ``COMMENT`` review on the head commit;
+    earlier lintro comments are edited or deleted in place.
+
+    Args:
+        records: Issue records of the report.
+        pull: Pull request to review.
+        client: GitHub API client.
+        root: Repository root the report paths are resolved against.
+
+    Returns:
+        ReviewResult: What was posted, edited and deleted.
+
+    Raises:
+        GitHubReviewError: If the API rejects a request.
+    """
+    base = f"/repos/{pull.repo}/pulls"
+    details = client.request("GET", f"{base}/{pull.number}")
+    head_sha = ((details or {}).get("head") or {}).get("sha")
+    if not head_sha:
+        raise GitHubReviewError(f"Pull request #{pull.number} has no head commit")
+
+    diff_lines = {
+        str(f.get("filename")): diff_line_map(f.get("patch"))
+        for f in client.paginate(f"{base}/{pull.number}/files")
+        if f.get("status") != "removed"
+    }
+    comments, outside_diff = build_review_comments(records, diff_lines, root)
+    existing = [
+        ExistingComment(comment_id=str(c.get("id")), body=str(c.get("body") or ""))
+        for c in client.paginate(f"{base}/{pull.number}/comments")
+    ]
+    plan = plan_review_sync(comments, existing)
+
+    for current, wanted in plan.update:
+        client.request(
+            "PATCH",
+            f"{base}/comments/{current.comment_id}",
+            {"body": wanted.body},
+        )
+    for stale in plan.delete:
+        client.request("DELETE", f"{base}/comments/{stale.comment_id}")
+    if plan.create:
+        client.request(
+            "POST",
+            f"{base}/{pull.number}/reviews",
+            {
+                "commit_id": head_sha,
+                "event": "COMMENT",
+                "body": _review_summary(comments, outside_diff),
+                "comments": [
+                    {
+                        "path": c.path,
+                        "line": c.line,
+                        "side": "RIGHT",
+                        "body": c.body,
+                    }
+                    for c in plan.create
+                ],
+            },
+        )
+    return ReviewResult(
+        created=len(plan.create),
+        updated=len(plan.update),
+        deleted=len(plan.delete),
+        unchanged=plan.unchanged,
+        outside_diff=outside_diff,
+    )
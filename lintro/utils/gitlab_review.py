@@ -0,0 +1,224 @@
+"""Publishing report issues as GitLab merge request discussions.
+
+``lintro report --gitlab-mr --input REPORT`` starts a diff discussion on
+each line of the merge request's diff that has issues, and on later runs
+edits or deletes the discussions it started before (see
+:mod:`lintro.utils.review_comments`), so retried pipelines do not post the
+same issues twice.
+
+In GitLab CI the project, merge request and API are read from
+``CI_PROJECT_ID``, ``CI_MERGE_REQUEST_IID`` and ``CI_API_V4_URL``. The
+CI job token cannot write notes, so a project or personal access token
+with the ``api`` scope is needed.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+from urllib.parse import quote
+
+from lintro.utils.review_comments import (
+    ExistingComment,
+    ReviewApi,
+    ReviewApiClient,
+    ReviewApiError,
+    ReviewComment,
+    ReviewResult,
+    build_review_comments,
+    diff_line_map,
+    plan_review_sync,
+)
+
+TOKEN_ENV: str = "GITLAB_TOKEN"
+DEFAULT_API_URL: str = "https://gitlab.com/api/v4"
+
+
+class GitLabReviewError(ReviewApiError):
+    """Raised when merge request discussions cannot be published."""
+
+
+@dataclass(frozen=True)
+class MergeRequestRef:
+    """Merge request the discussions are started on.
+
+    Attributes:
+        project: Project ID or ``group/name`` path.
+        iid: Merge request number within the project.
+        api_url: Base URL of the GitLab REST API.
+    """
+
+    project: str
+    iid: int
+    api_url: str = DEFAULT_API_URL
+
+
+def detect_merge_request(
+    project: str | None = None,
+    iid: int | None = None,
+) -> MergeRequestRef:
+    """Work out which merge request to review.
+
+    Args:
+        project: Project ID or path; read from ``CI_PROJECT_ID`` when not
+            given.
+        iid: Merge request number; read from ``CI_MERGE_REQUEST_IID`` when
+            not given.
+
+    Returns:
+        MergeRequestRef: The merge request.
+
+    Raises:
+        GitLabReviewError: If the project or merge request is unknown.
+    """
+    project = project or os.environ.get("CI_PROJECT_ID")
+    if iid is None:
+        env_iid = os.environ.get("CI_MERGE_REQUEST_IID", "")
+        iid = int(env_iid) if env_iid.isdigit() else None
+    if not project or iid is None:
+        raise GitLabReviewError(
+            "Cannot tell which merge request to review; run in a merge "
+            "request pipeline or pass --project and --mr",
+        )
+    api_url = os.environ.get("CI_API_V4_URL") or DEFAULT_API_URL
+    return MergeRequestRef(project=project, iid=iid, api_url=api_url.rstrip("/"))
+
+
+class GitLabClient(ReviewApiClient):
+    """Minimal GitLab REST client for merge request discussions."""
+
+    error = GitLabReviewError
+
+    def __init__(self, token: str, api_url: str, timeout: float = 30.0) -> None:
+        """Create the client.
+
+        Args:
+            token: Access token with the ``api`` scope.
+            api_url: Base URL of the GitLab REST API.
+            timeout: Seconds to wait for each response.
+        """
+        super().__init__(api_url, {"PRIVATE-TOKEN": token}, timeout)
+
+
+def _position(
+    comment: ReviewComment,
+    old_path: str,
+    diff_refs: dict[str, Any],
+) -> dict[str, Any]:
+    """Place a comment on a line of the merge request's diff.
+
+    Args:
+        comment: Comment to place.
+        old_path: Path of the file before the merge request.
+        diff_refs: ``diff_refs`` of the merge request.
+
+    Returns:
+        dict[str, Any]: Text position of a new diff discussion.
+    """
+    position: dict[str, Any] = {
+        "position_type": "text",
+        "base_sha": diff_refs["base_sha"],
+        "start_sha": diff_refs["start_sha"],
+        "head_sha": diff_refs["head_sha"],
+        "old_path": old_path,
+        "new_path": comment.path,
+        "new_line": comment.line,
+    }
+    if comment.old_line is not None:
+        # Unchanged lines need both sides, added lines only the new one
+        position["old_line"] = comment.old_line
+    return position
+
+
+def _lintro_notes(discussions: list[Any]) -> list[ExistingComment]:
+    """Collect the first note of each discussion.
+
+    Args:
+        discussions: Discussions of the merge request.
+
+    Returns:
+        list[ExistingComment]: First notes, identified by their API path
+        below the discussions endpoint.
+    """
+    existing = []
+    for discussion in discussions:
+        notes = discussion.get("notes") or []
+        if not notes:
+            continue
+        first = notes[0]
+        existing.append(
+            ExistingComment(
+                comment_id=f"{discussion.get('id')}/notes/{first.get('id')}",
+                body=str(first.get("body") or ""),
+            ),
+        )
+    return existing
+
+
+def publish_discussions(
+    records: list[dict[str, Any]],
+    merge_request: MergeRequestRef,
+    client: ReviewApi,
+    root: Path | None = None,
+) -> ReviewResult:
+    """Bring the lintro discussions of a merge request up to date.
+
+    Args:
+        records: Issue records of the report.
+        merge_request: Merge request to review.
+        client: GitLab API client.
+        root: Repository root the report paths are resolved against.
+
+    Returns:
+        ReviewResult: What was posted, edited and deleted.
+
+    Raises:
+        GitLabReviewError: If the merge request has no diff yet.
+    """
+    project = quote(merge_request.project, safe="")
+    base = f"/projects/{project}/merge_requests/{merge_request.iid}"
+    details = client.request("GET", base)
+    diff_refs = (details or {}).get("diff_refs") or {}
+    if not all(diff_refs.get(k) for k in ("base_sha", "start_sha", "head_sha")):
+        raise GitLabReviewError(
+            f"Merge request !{merge_request.iid} has no diff yet",
+        )
+
+    old_paths: dict[str, str] = {}
+    diff_lines: dict[str, dict[int, int | None]] = {}
+    for diff in client.paginate(f"{base}/diffs"):
+        if diff.get("deleted_file"):
+            continue
+        new_path = str(diff.get("new_path"))
+        old_paths[new_path] = str(diff.get("old_path") or new_path)
+        diff_lines[new_path] = diff_line_map(diff.get("diff"))
+    comments, outside_diff = build_review_comments(records, diff_lines, root)
+    existing = _lintro_notes(client.paginate(f"{base}/discussions"))
+    plan = plan_review_sync(comments, existing)
+
+    for current, wanted in plan.update:
+        client.request(
+            "PUT",
+            f"{base}/discussions/{current.comment_id}",
+            {"body": wanted.body},
+        )
+    for stale in plan.delete:
+        client.request("DELETE", f"{base}/discussions/{stale.comment_id}")
+    for comment in plan.create:
+        client.request(
+            "POST",
+            f"{base}/discussions",
+            {
+                "body": comment.body,
+                "position": _position(comment, old_paths[comment.path], diff_refs),
+            },
+        )
+    return ReviewResult(
+        created=len(plan.create),
+        updated=len(plan.update),
+        deleted=len(plan.delete),
+        unchanged=plan.unchanged,
+        outside_diff=outside_diff,
+    )
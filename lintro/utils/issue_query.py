@@ -0,0 +1,564 @@
+"""Filter expressions over issues.
+
+``lintro query`` and ``lintro check --filter`` share this small language for
+slicing results without jq::
+
+    severity>=error and tool=clippy and path~"src/core/**"
+    not (code=E501 or code~"W*")
+
+A query is made of comparisons ``FIELD OP VALUE`` joined with ``and``,
+``or`` and ``not`` and grouped with parentheses; ``and`` binds tighter than
+``or``. Fields are ``tool``, ``path`` (alias ``file``), ``line``, ``code``
+(alias ``rule``), ``message``, ``severity`` and ``owner``. Operators are
+``=``, ``!=``, ``~`` and ``!~`` (glob match, where ``*`` also crosses
+directories) and, for ``line`` and ``severity``, ``<``, ``<=``, ``>`` and
+``>=``. Severities are ordered ``info < warning < error``. ``owner`` tests
+the owners CODEOWNERS assigns the issue's file, ignoring case:
+``owner=@org/backend`` holds when the team is one of them, and
+``not owner~"*"`` selects unowned files.
+Values containing spaces or operator characters must be quoted.
+"""
+
+from __future__ import annotations
+
+import fnmatch
+import os
+import re
+from collections.abc import Callable, Mapping, Sequence
+from dataclasses import dataclass, replace
+from typing import Any
+
+from lintro.enums.severity_level import SeverityLevel, normalize_severity_level
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.codeowners import file_owners
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.exit_codes import success_after_filter
+
+FIELD_ALIASES: dict[str, str] = {"file": "path", "rule": "code"}
+FIELDS: tuple[str, ...] = (
+    "tool",
+    "path",
+    "line",
+    "code",
+    "message",
+    "severity",
+    "owner",
+)
+ORDERED_FIELDS: frozenset[str] = frozenset({"line", "severity"})
+# Fields compared case-insensitively
+_FOLDED_FIELDS: frozenset[str] = frozenset({"tool", "owner"})
+
+_SEVERITY_RANK: dict[SeverityLevel, int] = {
+    SeverityLevel.INFO: 0,
+    SeverityLevel.WARNING: 1,
+    SeverityLevel.ERROR: 2,
+}
+
+_TOKEN_RE = re.compile(
+    r"""\s*(?:
+        (?P<string>"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*')
+        |(?P<op>==|!=|!~|<=|>=|=|~|<|>)
+        |(?P<paren>[()])
+        |(?P<word>[^\s()=!~<>"']+)
+    )""",
+    re.VERBOSE,
+)
+
+_COMPARE: dict[str, Callable[[Any, Any], bool]] = {
+    "=": lambda a, b: a == b,
+    "!=": lambda a, b: a != b,
+    "<": lambda a, b: a < b,
+    "<=": lambda a, b: a <= b,
+    ">": lambda a, b: a > b,
+    ">=": lambda a, b: a >= b,
+}
+
+
+@dataclass(frozen=True)
+class _Token:
+    """A lexical token of a query.
+
+    Attributes:
+        kind: ``string``, ``op``, ``paren`` or ``word``.
+        text: Token text, unquoted for strings.
+        pos: Offset of the token in the query.
+    """
+
+    kind: str
+    text: str
+    pos: int
+
+
+@dataclass(frozen=True)
+class _Comparison:
+    """A ``FIELD OP VALUE`` test.
+
+    Attributes:
+        field: Canonical field name.
+        op: Comparison operator.
+        value: Value to compare with, converted for the field.
+    """
+
+    field: str
+    op: str
+    value: Any
+
+    def matches(self, record: Mapping[str, Any]) -> bool:
+        """Test a record.
+
+        Args:
+            record: Issue record from :func:`issue_record`.
+
+        Returns:
+            bool: True if the comparison holds.
+        """
+        if self.field == "owner":
+            return self._matches_owners(record)
+        actual = record.get(self.field)
+        if self.field == "severity":
+            actual = _SEVERITY_RANK[actual or SeverityLevel.WARNING]
+        elif self.field == "line":
+            actual = actual or 0
+        elif self.field == "tool":
+            actual = str(actual or "").lower()
+        else:
+            actual = str(actual or "")
+        if self.op in ("~", "!~"):
+            matched = fnmatch.fnmatchcase(actual, self.value)
+            return matched if self.op == "~" else not matched
+        return _COMPARE[self.op](actual, self.value)
+
+    def _matches_owners(self, record: Mapping[str, Any]) -> bool:
+        """Test the owners of a record's file.
+
+        Args:
+            record: Issue record from :func:`issue_record`.
+
+        Returns:
+            bool: For ``=`` and ``~``, whether any owner matches; for ``!=``
+            and ``!~``, whether none does.
+        """
+        owners = [o.lower() for o in file_owners(str(record.get("path") or ""))]
+        if self.op in ("~", "!~"):
+            found = any(fnmatch.fnmatchcase(o, self.value) for o in owners)
+        else:
+            found = self.value in owners
+        return found if self.op in ("=", "~") else not found
+
+
+@dataclass(frozen=True)
+class _Not:
+    """Negation of a sub-query.
+
+    Attributes:
+        operand: Negated node.
+    """
+
+    operand: Any
+
+    def matches(self, record: Mapping[str, Any]) -> bool:
+        """Test a record.
+
+        Args:
+            record: Issue record from :func:`issue_record`.
+
+        Returns:
+            bool: True if the operand does not match.
+        """
+        return not self.operand.matches(record)
+
+
+@dataclass(frozen=True)
+class _BoolOp:
+    """Conjunction or disjunction of sub-queries.
+
+    Attributes:
+        op: ``and`` or ``or``.
+        operands: Combined nodes.
+    """
+
+    op: str
+    operands: tuple[Any, ...]
+
+    def matches(self, record: Mapping[str, Any]) -> bool:
+        """Test a record.
+
+        Args:
+            record: Issue record from :func:`issue_record`.
+
+        Returns:
+            bool: True if all (``and``) or any (``or``) operands match.
+        """
+        combine = all if self.op == "and" else any
+        return combine(node.matches(record) for node in self.operands)
+
+
+@dataclass(frozen=True)
+class IssueQuery:
+    """A compiled filter expression.
+
+    Attributes:
+        text: Expression as written.
+        root: Root node of the parsed expression.
+    """
+
+    text: str
+    root: Any
+
+    def matches(self, record: Mapping[str, Any]) -> bool:
+        """Test an issue record.
+
+        Args:
+            record: Issue record from :func:`issue_record` or
+                :func:`report_records`.
+
+        Returns:
+            bool: True if the issue is selected by the query.
+        """
+        return bool(self.root.matches(record))
+
+
+def _tokenize(text: str) -> list[_Token]:
+    """Split a query into tokens.
+
+    Args:
+        text: Query text.
+
+    Returns:
+        list[_Token]: Tokens in order.
+
+    Raises:
+        ValueError: If the text contains an unterminated string or a stray
+            character.
+    """
+    tokens: list[_Token] = []
+    pos = 0
+    text = text.rstrip()
+    while pos < len(text):
+        match = _TOKEN_RE.match(text, pos)
+        if match is None or match.end() == pos:
+            raise ValueError(f"Unexpected character {text[pos]!r} at {pos}")
+        kind = match.lastgroup or ""
+        value = match.group(kind)
+        if kind == "string":
+            value = re.sub(r"\\(.)", r"\1", value[1:-1])
+        tokens.append(_Token(kind, value, match.start(kind)))
+        pos = match.end()
+    return tokens
+
+
+class _Parser:
+    """Recursive-descent parser producing query nodes."""
+
+    def __init__(self, tokens: list[_Token]) -> None:
+        """Initialize the parser.
+
+        Args:
+            tokens: Tokens from :func:`_tokenize`.
+        """
+        self.tokens = tokens
+        self.index = 0
+
+    def _peek(self) -> _Token | None:
+        """Return the next token without consuming it.
+
+        Returns:
+            _Token | None: Next token, or None at the end.
+        """
+        return self.tokens[self.index] if self.index < len(self.tokens) else None
+
+    def _next(self, expected: str) -> _Token:
+        """Consume the next token.
+
+        Args:
+            expected: Description of the expected token for errors.
+
+        Returns:
+            _Token: Consumed token.
+
+        Raises:
+            ValueError: If the query ends early.
+        """
+        token = self._peek()
+        if token is None:
+            raise ValueError(f"Expected {expected} at end of query")
+        self.index += 1
+        return token
+
+    def _keyword(self, word: str) -> bool:
+        """Consume a keyword if it comes next.
+
+        Args:
+            word: Keyword such as ``and``.
+
+        Returns:
+            bool: True if the keyword was consumed.
+        """
+        token = self._peek()
+        if token and token.kind == "word" and token.text.lower() == word:
+            self.index += 1
+            return True
+        return False
+
+    def parse(self) -> Any:
+        """Parse the whole query.
+
+        Returns:
+            Any: Root node.
+
+        Raises:
+            ValueError: If tokens are left over.
+        """
+        node = self._or()
+        token = self._peek()
+        if token is not None:
+            raise ValueError(f"Unexpected {token.text!r} at {token.pos}")
+        return node
+
+    def _or(self) -> Any:
+        """Parse ``and`` groups joined with ``or``.
+
+        Returns:
+            Any: Parsed node.
+        """
+        operands = [self._and()]
+        while self._keyword("or"):
+            operands.append(self._and())
+        return operands[0] if len(operands) == 1 else _BoolOp("or", tuple(operands))
+
+    def _and(self) -> Any:
+        """Parse terms joined with ``and``.
+
+        Returns:
+            Any: Parsed node.
+        """
+        operands = [self._not()]
+        while self._keyword("and"):
+            operands.append(self._not())
+        return operands[0] if len(operands) == 1 else _BoolOp("and", tuple(operands))
+
+    def _not(self) -> Any:
+        """Parse an optionally negated term.
+
+        Returns:
+            Any: Parsed node.
+        """
+        if self._keyword("not"):
+            return _Not(self._not())
+        return self._term()
+
+    def _term(self) -> Any:
+        """Parse a parenthesized query or a comparison.
+
+        Returns:
+            Any: Parsed node.
+
+        Raises:
+            ValueError: If the term is malformed.
+        """
+        token = self._next("a comparison")
+        if token.kind == "paren" and token.text == "(":
+            node = self._or()
+            closing = self._next("')'")
+            if closing.text != ")":
+                raise ValueError(f"Expected ')' at {closing.pos}")
+            return node
+        if token.kind != "word":
+            raise ValueError(f"Expected a field name at {token.pos}")
+        field = FIELD_ALIASES.get(token.text.lower(), token.text.lower())
+        if field not in FIELDS:
+            raise ValueError(
+                f"Unknown field {token.text!r}; expected one of {', '.join(FIELDS)}",
+            )
+        op_token = self._next(f"an operator after {token.text!r}")
+        if op_token.kind != "op":
+            raise ValueError(f"Expected an operator at {op_token.pos}")
+        op = "=" if op_token.text == "==" else op_token.text
+        value_token = self._next(f"a value after {op!r}")
+        if value_token.kind not in ("word", "string"):
+            raise ValueError(f"Expected a value at {value_token.pos}")
+        return _Comparison(field, op, _convert(field, op, value_token.text))
+
+
+def _convert(field: str, op: str, value: str) -> Any:
+    """Convert a comparison value for its field.
+
+    Args:
+        field: Canonical field name.
+        op: Comparison operator.
+        value: Value as written.
+
+    Returns:
+        Any: Glob pattern, line number, severity rank or string.
+
+    Raises:
+        ValueError: If the operator or value does not suit the field.
+    """
+    if op in ("~", "!~"):
+        if field in ORDERED_FIELDS:
+            raise ValueError(f"'{op}' cannot be used with {field}")
+        return value.lower() if field in _FOLDED_FIELDS else value
+    if op not in ("=", "!=") and field not in ORDERED_FIELDS:
+        raise ValueError(f"'{op}' can only be used with line and severity")
+    if field == "severity":
+        return _SEVERITY_RANK[normalize_severity_level(value)]
+    if field == "line":
+        if not value.isdigit():
+            raise ValueError(f"line needs a number, got {value!r}")
+        return int(value)
+    return value.lower() if field in _FOLDED_FIELDS else value
+
+
+def compile_query(text: str) -> IssueQuery:
+    """Parse a filter expression.
+
+    Args:
+        text: Expression such as ``severity>=error and tool=ruff``.
+
+    Returns:
+        IssueQuery: Compiled query.
+
+    Raises:
+        ValueError: If the expression is empty or invalid.
+    """
+    tokens = _tokenize(text)
+    if not tokens:
+        raise ValueError("Empty query")
+    return IssueQuery(text=text, root=_Parser(tokens).parse())
+
+
+def restrict_to_owners(
+    query: IssueQuery | None,
+    owners: Sequence[str],
+) -> IssueQuery | None:
+    """Narrow a query to the files some CODEOWNERS owners own.
+
+    Args:
+        query: Query to narrow, or None to select every issue.
+        owners: Owners such as ``@org/backend``; any of them may own a file.
+
+    Returns:
+        IssueQuery | None: Combined query, or ``query`` without owners.
+    """
+    if not owners:
+        return query
+    quoted = (owner.replace("\\", "\\\\").replace('"', '\\"') for owner in owners)
+    clause = " or ".join(f'owner="{owner}"' for owner in quoted)
+    if query is None:
+        return compile_query(clause)
+    return compile_query(f"({query.text}) and ({clause})")
+
+
+def _relative_path(path: str) -> str:
+    """Express a path relative to the working directory when it lies below.
+
+    Args:
+        path: File path as reported by a tool.
+
+    Returns:
+        str: POSIX path, relative when possible.
+    """
+    if os.path.isabs(path):
+        rel = os.path.relpath(path)
+        if not rel.startswith(".."):
+            path = rel
+    return path.replace(os.sep, "/").removeprefix("./")
+
+
+def issue_record(tool: str, issue: object) -> dict[str, Any]:
+    """Describe a parsed issue in the fields a query can test.
+
+    Args:
+        tool: Name of the tool that reported the issue.
+        issue: Parsed issue.
+
+    Returns:
+        dict[str, Any]: Query fields of the issue.
+    """
+    get_severity = getattr(issue, "get_severity", None)
+    severity = get_severity() if callable(get_severity) else SeverityLevel.WARNING
+    return {
+        "tool": tool.lower(),
+        "path": _relative_path(getattr(issue, "file", "") or ""),
+        "line": getattr(issue, "line", None) or 0,
+        "code": issue_code(issue),
+        "message": getattr(issue, "message", "") or "",
+        "severity": severity,
+    }
+
+
+def report_records(report: Mapping[str, Any]) -> list[dict[str, Any]]:
+    """Describe the issues of a JSON report in the fields a query can test.
+
+    Issues of reports written before severities were recorded count as
+    warnings.
+
+    Args:
+        report: Report written with ``--output-format json``.
+
+    Returns:
+        list[dict[str, Any]]: Query fields of each issue, plus the issue as
+        written under ``issue``.
+
+    Raises:
+        ValueError: If the data is not a Lintro JSON report.
+    """
+    results = report.get("results")
+    if not isinstance(results, list):
+        raise ValueError("Not a Lintro JSON report: missing 'results'")
+    records: list[dict[str, Any]] = []
+    for result in results:
+        tool = str(result.get("tool") or "")
+        for issue in result.get("issues") or []:
+            try:
+                severity = normalize_severity_level(issue.get("severity") or "")
+            except ValueError:
+                severity = SeverityLevel.WARNING
+            records.append(
+                {
+                    "tool": tool.lower(),
+                    "path": _relative_path(str(issue.get("file") or "")),
+                    "line": issue.get("line") or 0,
+                    "code": str(issue.get("code") or ""),
+                    "message": str(issue.get("message") or ""),
+                    "severity": severity,
+                    "issue": issue,
+                },
+            )
+    return records
+
+
+def apply_issue_filter(
+    result: ToolResult,
+    query: IssueQuery | None,
+) -> ToolResult:
+    """Keep only the issues of a check result that a query selects.
+
+    Like the severity threshold, filtered issues are neither displayed nor
+    counted, and a result left without issues is marked successful.
+
+    Args:
+        result: Result after severity remapping.
+        query: Compiled ``--filter`` expression, or None to keep everything.
+
+    Returns:
+        ToolResult: Result with filtered issues and updated count.
+    """
+    if query is None or result.skipped or not result.issues:
+        return result
+
+    kept = [
+        issue
+        for issue in result.issues
+        if query.matches(issue_record(result.name, issue))
+    ]
+    if len(kept) == len(result.issues):
+        return result
+
+    return replace(
+        result,
+        issues=kept,
+        issues_count=len(kept),
+        success=success_after_filter(result, len(kept)),
+        formatted_output=None,
+    )
@@ -0,0 +1,196 @@
+"""Aggregate metrics over the issues of a check run.
+
+``lintro stats`` summarizes where issues come from: totals per tool, the
+rules and files with the most issues, issues per directory and how many of
+them the tools can fix automatically. The metrics are computed from issue
+records, so a live run and a saved JSON report give the same numbers.
+"""
+
+from __future__ import annotations
+
+import posixpath
+from collections import Counter
+from collections.abc import Iterable, Mapping, Sequence
+from dataclasses import asdict, dataclass, field
+from typing import Any
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_fixable
+from lintro.utils.issue_query import issue_record, report_records
+
+
+@dataclass(frozen=True)
+class CountRow:
+    """Issues of one tool, rule, file or directory.
+
+    Attributes:
+        key: Tool, ``tool:code`` rule, file or directory.
+        issues: Issues found.
+        fixable: Issues the tool can fix automatically.
+        errors: Issues with error severity.
+    """
+
+    key: str
+    issues: int
+    fixable: int
+    errors: int
+
+
+@dataclass
+class IssueStats:
+    """Metrics of a run.
+
+    Attributes:
+        issues: Issues found.
+        fixable: Issues the tools can fix automatically.
+        tools: Totals per tool, including tools without issues.
+        rules: Rules with the most issues.
+        files: Files with the most issues.
+        directories: Directories with the most issues.
+    """
+
+    issues: int = 0
+    fixable: int = 0
+    tools: list[CountRow] = field(default_factory=list)
+    rules: list[CountRow] = field(default_factory=list)
+    files: list[CountRow] = field(default_factory=list)
+    directories: list[CountRow] = field(default_factory=list)
+
+    @property
+    def fixable_ratio(self) -> float:
+        """Share of the issues that can be fixed automatically.
+
+        Returns:
+            float: Ratio between 0 and 1; 0.0 without issues.
+        """
+        return self.fixable / self.issues if self.issues else 0.0
+
+    def to_dict(self) -> dict[str, Any]:
+        """Describe the metrics as JSON-serializable data.
+
+        Returns:
+            dict[str, Any]: Totals, ratio and the per-key rows.
+        """
+        data = asdict(self)
+        data["fixable_ratio"] = round(self.fixable_ratio, 4)
+        return data
+
+
+def result_records(results: Sequence[ToolResult]) -> list[dict[str, Any]]:
+    """Describe the issues of check results for the metrics.
+
+    Args:
+        results: Results of the tools that ran.
+
+    Returns:
+        list[dict[str, Any]]: Query fields of each issue plus ``fixable``.
+    """
+    return [
+        {**issue_record(result.name, issue), "fixable": issue_fixable(issue)}
+        for result in results
+        for issue in result.issues or []
+    ]
+
+
+def saved_records(report: Mapping[str, Any]) -> list[dict[str, Any]]:
+    """Describe the issues of a JSON report for the metrics.
+
+    Args:
+        report: Report written with ``--output FILE --output-format json``.
+
+    Returns:
+        list[dict[str, Any]]: Query fields of each issue plus ``fixable``;
+        issues of reports without it count as not fixable.
+    """
+    return [
+        {**record, "fixable": bool(record["issue"].get("fixable"))}
+        for record in report_records(report)
+    ]
+
+
+def _directory(path: str, depth: int) -> str:
+    """Find the directory an issue is counted under.
+
+    Args:
+        path: POSIX file path.
+        depth: Directory levels to keep.
+
+    Returns:
+        str: The first ``depth`` directories of the path with a trailing
+        slash, or ``./`` for files at the top level.
+    """
+    parts = [p for p in posixpath.dirname(path).split("/") if p not in ("", ".")]
+    return "/".join(parts[:depth]) + "/" if parts else "./"
+
+
+def _rows(
+    records: Iterable[Mapping[str, Any]],
+    key: str,
+    top: int | None,
+) -> list[CountRow]:
+    """Count records per key, most issues first.
+
+    Args:
+        records: Issue records with a precomputed ``key`` field.
+        key: Field to group by.
+        top: Rows to keep, or None for every row.
+
+    Returns:
+        list[CountRow]: Rows sorted by issues, then key.
+    """
+    issues: Counter[str] = Counter()
+    fixable: Counter[str] = Counter()
+    errors: Counter[str] = Counter()
+    for record in records:
+        value = str(record[key])
+        issues[value] += 1
+        fixable[value] += bool(record["fixable"])
+        errors[value] += record["severity"] == SeverityLevel.ERROR
+    rows = [
+        CountRow(key=value, issues=count, fixable=fixable[value], errors=errors[value])
+        for value, count in issues.items()
+    ]
+    rows.sort(key=lambda row: (-row.issues, row.key))
+    return rows if top is None else rows[:top]
+
+
+def compute_stats(
+    records: Sequence[Mapping[str, Any]],
+    tools: Iterable[str] = (),
+    top: int = 10,
+    depth: int = 1,
+) -> IssueStats:
+    """Aggregate issue records into run metrics.
+
+    Args:
+        records: Issue records from result_records() or saved_records().
+        tools: Tools that ran, so clean tools are listed with 0 issues.
+        top: Rules, files and directories to list.
+        depth: Directory levels issues are grouped under.
+
+    Returns:
+        IssueStats: Metrics of the run.
+    """
+    keyed = [
+        {
+            **record,
+            "rule": f"{record['tool']}:{record['code'] or '-'}",
+            "directory": _directory(str(record["path"]), depth),
+        }
+        for record in records
+    ]
+    tool_rows = _rows(keyed, "tool", None)
+    listed = {row.key for row in tool_rows}
+    tool_rows += [
+        CountRow(key=tool, issues=0, fixable=0, errors=0)
+        for tool in sorted({t.lower() for t in tools} - listed)
+    ]
+    return IssueStats(
+        issues=len(keyed),
+        fixable=sum(bool(record["fixable"]) for record in keyed),
+        tools=tool_rows,
+        rules=_rows(keyed, "rule", top),
+        files=_rows([r for r in keyed if r["path"]], "path", top),
+        directories=_rows([r for r in keyed if r["path"]], "directory", top),
+    )
@@ -6,7 +6,69 @@ This module provides functionality for creating JSON output from tool results.
 from typing import Any
 
 from lintro.enums.action import Action, normalize_action
+from lintro.enums.severity_level import SeverityLevel
 from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_fixable
+from lintro.utils.fingerprint import fingerprint_issues
+from lintro.utils.report_metadata import build_report_metadata
+
+
+def json_issue(issue: object, fingerprint: str) -> dict[str, Any]:
+    """Serialize an issue for the JSON report.
+
+    Args:
+        issue: Parsed issue.
+        fingerprint: Fingerprint identifying the issue across runs.
+
+    Returns:
+        dict[str, Any]: File, line, code, message, severity and fingerprint,
+        plus the column, the notebook cell, whether it is fixable, the last
+        line, a diff preview, the crate, the clippy matrix entries, the
+        number of cargo targets reporting it and the fields computed by
+        ``output.script`` for issues that carry them.
+    """
+    data: dict[str, Any] = {
+        "file": getattr(issue, "file", "") or "",
+        "line": getattr(issue, "line", None) or 0,
+        "code": getattr(issue, "code", "") or "",
+        "message": getattr(issue, "message", "") or "",
+        "fingerprint": fingerprint,
+    }
+    column = getattr(issue, "column", None)
+    if isinstance(column, int) and column:
+        data["column"] = column
+    cell = getattr(issue, "cell", None)
+    if isinstance(cell, int) and cell:
+        data["cell"] = cell
+    get_severity = getattr(issue, "get_severity", None)
+    severity = get_severity() if callable(get_severity) else None
+    if isinstance(severity, SeverityLevel):
+        data["severity"] = severity.value.lower()
+    if issue_fixable(issue):
+        data["fixable"] = True
+        get_fix_safety = getattr(issue, "get_fix_safety", None)
+        fix_safety = get_fix_safety() if callable(get_fix_safety) else None
+        if fix_safety is not None:
+            data["fix_safety"] = str(fix_safety)
+    end_line = getattr(issue, "end_line", None)
+    if isinstance(end_line, int) and end_line:
+        data["end_line"] = end_line
+    diff = getattr(issue, "diff", None)
+    if isinstance(diff, str) and diff:
+        data["diff"] = diff
+    crate_name = getattr(issue, "crate_name", None)
+    if isinstance(crate_name, str) and crate_name:
+        data["crate"] = crate_name
+    configurations = getattr(issue, "configurations", None)
+    if isinstance(configurations, list) and configurations:
+        data["configurations"] = list(configurations)
+    target_count = getattr(issue, "target_count", None)
+    if isinstance(target_count, int) and target_count > 1:
+        data["target_count"] = target_count
+    script_fields = getattr(issue, "script_fields", None)
+    if isinstance(script_fields, dict) and script_fields:
+        data["fields"] = dict(script_fields)
+    return data
 
 
 def create_json_output(
@@ -16,6 +78,9 @@ def create_json_output(
     total_fixed: int,
     total_remaining: int,
     exit_code: int,
+    partial: bool = False,
+    timing: dict[str, Any] | None = None,
+    include_issues: bool = False,
 ) -> dict[str, Any]:
     """Create JSON output data structure from tool results.
 
@@ -26,6 +91,11 @@ def create_json_output(
         total_fixed: Total number of issues fixed (only for FIX action).
         total_remaining: Total number of issues remaining (only for FIX action).
         exit_code: Exit code for the run.
+        partial: Whether --fail-fast stopped the run before every tool ran.
+        timing: Timing profile of the run (see timing_profile), included
+            with --profile-timing.
+        include_issues: Whether to list each result's issues, as editors
+            checking a buffer with ``--stdin`` need.
 
     Returns:
         Dictionary containing JSON-serializable results and summary data.
@@ -39,8 +109,12 @@ def create_json_output(
             "total_issues": total_issues,
             "total_fixed": total_fixed if action_enum == Action.FIX else 0,
             "total_remaining": total_remaining if action_enum == Action.FIX else 0,
+            "partial": partial,
         },
+        "metadata": build_report_metadata(results),
     }
+    if timing is not None:
+        json_data["timing"] = timing
     for result in results:
         result_data: dict[str, Any] = {
             "tool": result.name,
@@ -48,6 +122,8 @@ def create_json_output(
             "issues_count": getattr(result, "issues_count", 0),
             "skipped": getattr(result, "skipped", False),
             "skip_reason": getattr(result, "skip_reason", None),
+            "error_code": getattr(result, "error_code", None),
+            "duration_ms": getattr(result, "duration_ms", None),
         }
         if action_enum == Action.FIX:
             result_data["fixed"] = getattr(result, "fixed_issues_count", 0)
@@ -56,6 +132,13 @@ def create_json_output(
                 "remaining_issues_count",
                 0,
             )
+        if include_issues:
+            issues = list(result.issues or [])
+            fingerprints = fingerprint_issues(result.name, issues)
+            result_data["issues"] = [
+                json_issue(issue, fingerprint)
+                for issue, fingerprint in zip(issues, fingerprints, strict=True)
+            ]
         json_data["results"].append(result_data)
 
     return json_data
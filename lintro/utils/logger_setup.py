@@ -1,13 +1,77 @@
 """Loguru logger configuration for Lintro.
 
 Provides centralized logging setup for both CLI and tool execution contexts.
+
+With ``--log-format json`` the log goes to stderr as one JSON object per
+line, separate from the results on stdout, so CI log aggregation can index
+runs. Records made with ``log_event`` carry an ``event`` name and fields
+such as a tool invocation's full argv, duration and exit code:
+
+    {"time": "...", "level": "INFO", "event": "subprocess",
+     "message": "ruff exited with 1 after 412 ms",
+     "argv": ["ruff", "check", "src"], "duration_ms": 412, "exit_code": 1}
 """
 
+import json
 import sys
 from pathlib import Path
+from typing import TYPE_CHECKING, Any
 
 from loguru import logger
 
+if TYPE_CHECKING:
+    from loguru import Message
+
+# Values accepted by --log-format
+LOG_FORMATS: tuple[str, ...] = ("text", "json")
+
+
+def log_event(event: str, message: str, **fields: Any) -> None:
+    """Log a structured event at INFO level.
+
+    Text logs show only the message; JSON logs add the event name and
+    fields.
+
+    Args:
+        event: Event name, such as ``subprocess`` or ``tool_result``.
+        message: Human-readable description.
+        **fields: JSON-serializable details of the event.
+    """
+    logger.bind(event=event, **fields).info(message)
+
+
+def _json_record(message: "Message") -> str:
+    """Serialize a log record as one line of JSON.
+
+    Args:
+        message: Formatted message carrying the loguru record.
+
+    Returns:
+        str: JSON object with time, level, logger, message and any fields
+        bound with ``log_event``.
+    """
+    record = message.record
+    data: dict[str, Any] = {
+        "time": record["time"].isoformat(),
+        "level": record["level"].name,
+        "logger": record["name"],
+        "message": record["message"],
+    }
+    data.update(record["extra"])
+    if record["exception"] is not None:
+        data["exception"] = str(record["exception"].value)
+    return json.dumps(data, default=str)
+
+
+def _json_sink(message: "Message") -> None:
+    """Write a log record to stderr as JSON.
+
+    Args:
+        message: Formatted message carrying the loguru record.
+    """
+    sys.stderr.write(_json_record(message) + "\n")
+    sys.stderr.flush()
+
 
 def setup_cli_logging() -> None:
     """Configure minimal logging for CLI commands (help, version, etc.).
@@ -24,23 +88,33 @@ def setup_cli_logging() -> None:
     )
 
 
-def setup_execution_logging(run_dir: Path, debug: bool = False) -> None:
+def setup_execution_logging(
+    run_dir: Path,
+    debug: bool = False,
+    log_format: str = "text",
+) -> None:
     """Configure full logging for tool execution.
 
     Args:
         run_dir: Directory for log files.
-        debug: If True, show DEBUG messages on console. Otherwise only WARNING+.
+        debug: If True, show DEBUG messages on console. Otherwise only WARNING+,
+            or INFO+ for JSON logs so that events are included.
+        log_format: "text" for plain messages or "json" for one JSON record
+            per line on stderr.
     """
     logger.remove()
 
-    # Console handler - DEBUG if flag set, else WARNING only
-    console_level = "DEBUG" if debug else "WARNING"
-    logger.add(
-        sys.stderr,
-        level=console_level,
-        format="{message}",
-        colorize=True,
-    )
+    if log_format == "json":
+        logger.add(_json_sink, level="DEBUG" if debug else "INFO")
+    else:
+        # Console handler - DEBUG if flag set, else WARNING only
+        console_level = "DEBUG" if debug else "WARNING"
+        logger.add(
+            sys.stderr,
+            level=console_level,
+            format="{message}",
+            colorize=True,
+        )
 
     # File handler with rotation (captures everything)
     run_dir.mkdir(parents=True, exist_ok=True)
@@ -0,0 +1,389 @@
+"""Posting run summaries to chat webhooks.
+
+``lintro report notify REPORT`` sends the outcome of a run to a Slack,
+Microsoft Teams or generic JSON webhook, typically at the end of a scheduled
+report job: the issue counts per tool, the issues that are new against a
+baseline report, and a link to the report artifact. Slack and Teams
+webhooks are recognised by their host; other URLs receive the summary as
+plain JSON.
+
+Webhook URLs embed their credentials, so they are read from
+``LINTRO_WEBHOOK_URL`` by default and never echoed back in errors.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Mapping
+from dataclasses import dataclass, field
+from time import sleep
+from typing import Any
+from urllib.parse import urlparse
+
+from lintro import __version__
+from lintro.exceptions.errors import LintroError
+from lintro.utils.badge import BadgeSummary, summarize_report, summarize_tools
+from lintro.utils.report_diff import DiffIssue, diff_issues, report_issues
+from lintro.utils.report_upload import (
+    BACKOFF_SECONDS,
+    MAX_ATTEMPTS,
+    MAX_BACKOFF_SECONDS,
+    RETRY_STATUS,
+)
+
+WEBHOOK_ENV: str = "LINTRO_WEBHOOK_URL"
+NOTIFY_FORMATS: tuple[str, ...] = ("auto", "slack", "teams", "json")
+DEFAULT_MAX_ISSUES: int = 10
+
+# Hosts of incoming webhooks, by payload format
+_SLACK_HOSTS: tuple[str, ...] = ("hooks.slack.com",)
+_TEAMS_HOSTS: tuple[str, ...] = ("webhook.office.com", "logic.azure.com")
+
+_PASS_COLOR = "2EB67D"
+_WARN_COLOR = "ECB22E"
+_FAIL_COLOR = "E01E5A"
+
+
+class NotificationError(LintroError):
+    """Raised when a notification cannot be posted."""
+
+
+@dataclass
+class RunNotification:
+    """Summary of a run as posted to a webhook.
+
+    Attributes:
+        title: Heading of the message.
+        total: Counts over the whole report.
+        tools: Counts per tool that ran, sorted by name.
+        new: Issues not in the baseline, or None without a baseline.
+        fixed: Baseline issues the run no longer reports, or None without a
+            baseline.
+        report_url: Link to the report artifact or CI run, if known.
+    """
+
+    title: str
+    total: BadgeSummary
+    tools: list[BadgeSummary] = field(default_factory=list)
+    new: list[DiffIssue] | None = None
+    fixed: int | None = None
+    report_url: str | None = None
+
+    @property
+    def status(self) -> str:
+        """Describe the outcome in one word.
+
+        Returns:
+            str: "failed", "issues" or "passed".
+        """
+        if self.total.failed or self.total.errors:
+            return "failed"
+        return "issues" if self.total.issues else "passed"
+
+    @property
+    def headline(self) -> str:
+        """Summarize the run in one line.
+
+        Returns:
+            str: Issue counts, and new and fixed issues against the baseline.
+        """
+        parts = [f"{self.total.issues} issue(s)", f"{self.total.errors} error(s)"]
+        if self.new is not None:
+            parts.append(f"{len(self.new)} new")
+        if self.fixed is not None:
+            parts.append(f"{self.fixed} fixed")
+        return f"{self.title}: {self.status} - {', '.join(parts)}"
+
+    def to_dict(self, max_issues: int = DEFAULT_MAX_ISSUES) -> dict[str, Any]:
+        """Return the summary as JSON-serializable data.
+
+        Args:
+            max_issues: New issues to list at most.
+
+        Returns:
+            dict[str, Any]: Counts, new issues and report link.
+        """
+        return {
+            "title": self.title,
+            "status": self.status,
+            "issues": self.total.issues,
+            "errors": self.total.errors,
+            "tools": {
+                t.tool: {"issues": t.issues, "errors": t.errors, "failed": t.failed}
+                for t in self.tools
+            },
+            "new": None if self.new is None else len(self.new),
+            "fixed": self.fixed,
+            "new_issues": [vars(i) for i in (self.new or [])[:max_issues]],
+            "report_url": self.report_url,
+            "lintro_version": __version__,
+        }
+
+
+def default_report_url() -> str | None:
+    """Link to the CI run that produced the report.
+
+    Returns:
+        str | None: The GitHub Actions run or GitLab job URL, or None
+        outside those.
+    """
+    server = os.environ.get("GITHUB_SERVER_URL")
+    repo = os.environ.get("GITHUB_REPOSITORY")
+    run_id = os.environ.get("GITHUB_RUN_ID")
+    if server and repo and run_id:
+        return f"{server}/{repo}/actions/runs/{run_id}"
+    return os.environ.get("CI_JOB_URL") or None
+
+
+def build_notification(
+    report: Mapping[str, Any],
+    *,
+    baseline: Mapping[str, Any] | None = None,
+    title: str = "lintro",
+    report_url: str | None = None,
+) -> RunNotification:
+    """Summarize a report for a webhook.
+
+    Args:
+        report: JSON report of the run.
+        baseline: JSON report to find new and fixed issues against.
+        title: Heading of the message.
+        report_url: Link to the report artifact or CI run.
+
+    Returns:
+        RunNotification: Counts, new issues and link.
+
+    Raises:
+        ValueError: If a report is not a Lintro JSON report.
+    """
+    notification = RunNotification(
+        title=title,
+        total=summarize_report(report),
+        tools=summarize_tools(report),
+        report_url=report_url,
+    )
+    if baseline is not None:
+        diff = diff_issues(report_issues(baseline), report_issues(report))
+        notification.new = diff.new
+        notification.fixed = len(diff.fixed)
+    return notification
+
+
+def detect_format(url: str) -> str:
+    """Choose the payload format for a webhook URL.
+
+    Args:
+        url: Webhook URL.
+
+    Returns:
+        str: "slack", "teams" or "json".
+    """
+    host = (urlparse(url).hostname or "").lower()
+    if any(host == h or host.endswith(f".{h}") for h in _SLACK_HOSTS):
+        return "slack"
+    if any(host == h or host.endswith(f".{h}") for h in _TEAMS_HOSTS):
+        return "teams"
+    return "json"
+
+
+def _issue_line(issue: DiffIssue) -> str:
+    """Describe a new issue in one line.
+
+    Args:
+        issue: Issue to describe.
+
+    Returns:
+        str: Tool, rule, location and message.
+    """
+    location = f"{issue.file}:{issue.line}" if issue.line else issue.file
+    code = f" {issue.code}" if issue.code else ""
+    return f"{issue.tool}{code} {location}: {issue.message}"
+
+
+def _issue_lines(notification: RunNotification, max_issues: int) -> list[str]:
+    """List the new issues to show, noting the ones left out.
+
+    Args:
+        notification: Summary to render.
+        max_issues: New issues to list at most.
+
+    Returns:
+        list[str]: One line per listed issue, plus a line for the rest.
+    """
+    new = notification.new or []
+    lines = [_issue_line(issue) for issue in new[:max_issues]]
+    if len(new) > max_issues:
+        lines.append(f"... and {len(new) - max_issues} more")
+    return lines
+
+
+def _color(notification: RunNotification) -> str:
+    """Pick the accent colour of a message.
+
+    Args:
+        notification: Summary to render.
+
+    Returns:
+        str: Hex colour without ``#``.
+    """
+    return {"failed": _FAIL_COLOR, "issues": _WARN_COLOR}.get(
+        notification.status,
+        _PASS_COLOR,
+    )
+
+
+def slack_payload(
+    notification: RunNotification,
+    max_issues: int = DEFAULT_MAX_ISSUES,
+) -> dict[str, Any]:
+    """Render a summary as a Slack incoming-webhook message.
+
+    Args:
+        notification: Summary to render.
+        max_issues: New issues to list at most.
+
+    Returns:
+        dict[str, Any]: Message with a plain-text fallback and blocks.
+    """
+    counts = "\n".join(
+        f"*{t.tool}*: {t.issues} issue(s)" + (" (failed)" if t.failed else "")
+        for t in notification.tools
+    )
+    blocks: list[dict[str, Any]] = [
+        {"type": "section", "text": {"type": "mrkdwn", "text": notification.headline}},
+    ]
+    if counts:
+        blocks.append({"type": "section", "text": {"type": "mrkdwn", "text": counts}})
+    lines = _issue_lines(notification, max_issues)
+    if lines:
+        text = "*New issues*\n" + "\n".join(f"• `{line}`" for line in lines)
+        blocks.append({"type": "section", "text": {"type": "mrkdwn", "text": text}})
+    if notification.report_url:
+        blocks.append(
+            {
+                "type": "context",
+                "elements": [
+                    {
+                        "type": "mrkdwn",
+                        "text": f"<{notification.report_url}|View report>",
+                    },
+                ],
+            },
+        )
+    return {
+        "text": notification.headline,
+        "attachments": [{"color": f"#{_color(notification)}", "blocks": blocks}],
+    }
+
+
+def teams_payload(
+    notification: RunNotification,
+    max_issues: int = DEFAULT_MAX_ISSUES,
+) -> dict[str, Any]:
+    """Render a summary as a Microsoft Teams message card.
+
+    Args:
+        notification: Summary to render.
+        max_issues: New issues to list at most.
+
+    Returns:
+        dict[str, Any]: Legacy ``MessageCard`` accepted by Teams webhooks.
+    """
+    sections: list[dict[str, Any]] = [
+        {
+            "facts": [
+                {"name": t.tool, "value": f"{t.issues} issue(s)"}
+                for t in notification.tools
+            ],
+        },
+    ]
+    lines = _issue_lines(notification, max_issues)
+    if lines:
+        sections.append(
+            {"title": "New issues", "text": "\n\n".join(f"`{x}`" for x in lines)},
+        )
+    card: dict[str, Any] = {
+        "@type": "MessageCard",
+        "@context": "https://schema.org/extensions",
+        "summary": notification.headline,
+        "themeColor": _color(notification),
+        "title": notification.headline,
+        "sections": sections,
+    }
+    if notification.report_url:
+        card["potentialAction"] = [
+            {
+                "@type": "OpenUri",
+                "name": "View report",
+                "targets": [{"os": "default", "uri": notification.report_url}],
+            },
+        ]
+    return card
+
+
+def render_payload(
+    notification: RunNotification,
+    fmt: str,
+    max_issues: int = DEFAULT_MAX_ISSUES,
+) -> dict[str, Any]:
+    """Render a summary in a webhook format.
+
+    Args:
+        notification: Summary to render.
+        fmt: "slack", "teams" or "json".
+        max_issues: New issues to list at most.
+
+    Returns:
+        dict[str, Any]: Request body.
+    """
+    if fmt == "slack":
+        return slack_payload(notification, max_issues)
+    if fmt == "teams":
+        return teams_payload(notification, max_issues)
+    return notification.to_dict(max_issues)
+
+
+def post_notification(
+    url: str,
+    payload: Mapping[str, Any],
+    timeout: float = 30.0,
+) -> None:
+    """Post a payload to a webhook, retrying transient failures.
+
+    Args:
+        url: Webhook URL.
+        payload: JSON body.
+        timeout: Seconds to wait for the response.
+
+    Raises:
+        NotificationError: If the webhook rejects the message or keeps
+            failing; the message names only the URL's host.
+    """
+    import httpx
+
+    host = urlparse(url).hostname or "webhook"
+    failure = ""
+    for attempt in range(1, MAX_ATTEMPTS + 1):
+        try:
+            response = httpx.post(
+                url,
+                json=dict(payload),
+                headers={"User-Agent": f"lintro/{__version__}"},
+                timeout=timeout,
+            )
+        except httpx.TransportError as e:
+            failure = str(e) or type(e).__name__
+        else:
+            if response.status_code < 400:
+                return
+            if response.status_code not in RETRY_STATUS:
+                raise NotificationError(
+                    f"{host} rejected the notification with HTTP "
+                    f"{response.status_code}: {response.text.strip()[:200]}",
+                )
+            failure = f"HTTP {response.status_code}"
+        if attempt < MAX_ATTEMPTS:
+            sleep(min(BACKOFF_SECONDS * 2 ** (attempt - 1), MAX_BACKOFF_SECONDS))
+    raise NotificationError(
+        f"posting to {host} failed after {MAX_ATTEMPTS} attempts: {failure}",
+    )
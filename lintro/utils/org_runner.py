@@ -0,0 +1,362 @@
+"""Running a quality gate across many repositories.
+
+``lintro org run --repos repos.yaml`` keeps a shallow checkout of every listed
+repository under the work directory, runs a gate from the current Lintro
+config in each one and merges the results into a single report. Each
+repository runs in its own ``lintro check`` process, so its native tool
+configs apply while the gate's tools and thresholds stay the same everywhere.
+Issue paths in the merged report are prefixed with the repository name.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import subprocess  # nosec B404 - commands are built from lists, no shell
+import sys
+from collections.abc import Callable
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from pydantic import ValidationError
+
+from lintro.config.gate_config import GateConfig
+from lintro.config.org_config import OrgConfig, OrgRepo
+from lintro.exceptions.errors import LintroError
+from lintro.utils.timestamps import utc_timestamp
+
+DEFAULT_JOBS: int = 4
+DEFAULT_REPO_TIMEOUT: int = 1800
+GIT_TIMEOUT: int = 600
+
+STATUS_PASSED = "passed"
+STATUS_FAILED = "failed"
+STATUS_ERROR = "error"
+
+
+class OrgRunError(LintroError):
+    """Raised when a repository cannot be checked out or checked."""
+
+
+@dataclass
+class RepoOutcome:
+    """Result of running the gate in one repository.
+
+    Attributes:
+        name: Repository name.
+        url: Clone URL.
+        gate: Gate that ran.
+        status: "passed", "failed" or "error".
+        commit: Checked commit, if the checkout succeeded.
+        exit_code: Exit code of the check, if it ran.
+        issues_count: Issues found.
+        results: Tool results from the repository's JSON report.
+        error: Why the repository could not be checked.
+    """
+
+    name: str
+    url: str
+    gate: str
+    status: str
+    commit: str | None = None
+    exit_code: int | None = None
+    issues_count: int = 0
+    results: list[dict[str, Any]] = field(default_factory=list)
+    error: str | None = None
+
+
+def load_org_config(path: str | Path) -> OrgConfig:
+    """Read a repository list.
+
+    Args:
+        path: YAML file such as ``repos.yaml``.
+
+    Returns:
+        OrgConfig: The validated list.
+
+    Raises:
+        ValueError: If the file cannot be read or is not a valid list.
+    """
+    import yaml
+
+    try:
+        data = yaml.safe_load(Path(path).read_text(encoding="utf-8"))
+    except (OSError, yaml.YAMLError) as e:
+        raise ValueError(f"Cannot read {path}: {e}") from e
+    if isinstance(data, list):
+        data = {"repos": data}
+    if not isinstance(data, dict):
+        raise ValueError(f"{path} must be a mapping with a 'repos' list")
+    try:
+        return OrgConfig(**data)
+    except ValidationError as e:
+        raise ValueError(f"Invalid repository list {path}: {e}") from e
+
+
+def _run(cmd: list[str], cwd: str | None, timeout: int) -> str:
+    """Run a git command without prompting for credentials.
+
+    Args:
+        cmd: Command to run.
+        cwd: Working directory.
+        timeout: Seconds before giving up.
+
+    Returns:
+        str: Stripped standard output.
+
+    Raises:
+        OrgRunError: If the command is missing, fails or times out.
+    """
+    env = {**os.environ, "GIT_TERMINAL_PROMPT": "0"}
+    try:
+        result = subprocess.run(  # nosec B603 - list command, no shell
+            cmd,
+            cwd=cwd,
+            capture_output=True,
+            text=True,
+            timeout=timeout,
+            env=env,
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        raise OrgRunError(f"{' '.join(cmd[:3])} failed: {e}") from e
+    if result.returncode != 0:
+        detail = (result.stderr or result.stdout).strip().splitlines()
+        reason = detail[-1] if detail else f"exit code {result.returncode}"
+        raise OrgRunError(f"{' '.join(cmd[:3])} failed: {reason}")
+    return result.stdout.strip()
+
+
+def sync_repo(repo: OrgRepo, directory: Path, timeout: int = GIT_TIMEOUT) -> str:
+    """Clone a repository shallowly, or update an existing checkout.
+
+    The checkout is owned by lintro: local changes in it are discarded.
+
+    Args:
+        repo: Repository to check out.
+        directory: Checkout directory.
+        timeout: Seconds allowed for each git command.
+
+    Returns:
+        str: Commit that was checked out.
+    """
+    if (directory / ".git").is_dir():
+        _run(
+            ["git", "fetch", "--depth", "1", "origin", repo.ref or "HEAD"],
+            str(directory),
+            timeout,
+        )
+        _run(["git", "reset", "--hard", "FETCH_HEAD"], str(directory), timeout)
+    else:
+        directory.parent.mkdir(parents=True, exist_ok=True)
+        cmd = ["git", "clone", "--depth", "1", "--single-branch"]
+        if repo.ref:
+            cmd.extend(["--branch", repo.ref])
+        _run([*cmd, "--", repo.url, str(directory)], None, timeout)
+    return _run(["git", "rev-parse", "HEAD"], str(directory), timeout)
+
+
+def gate_check_args(gate: GateConfig, report: Path) -> list[str]:
+    """Build the ``lintro check`` arguments that run a gate.
+
+    Args:
+        gate: Gate to run.
+        report: JSON report the check writes.
+
+    Returns:
+        list[str]: Arguments after ``lintro``.
+    """
+    args = ["check", *(gate.paths or ["."]), "--tools", ",".join(gate.tools)]
+    if gate.exclude:
+        args.extend(["--exclude", ",".join(gate.exclude)])
+    if gate.fail_on:
+        args.extend(["--fail-on", gate.fail_on])
+    if gate.max_issues is not None:
+        args.extend(["--max-issues", str(gate.max_issues)])
+    args.extend(["--output", str(report), "--output-format", "json", "--yes"])
+    return args
+
+
+def _run_lintro(args: list[str], cwd: Path, timeout: int) -> int:
+    """Run lintro in another directory.
+
+    Args:
+        args: Arguments after ``lintro``.
+        cwd: Repository to run in.
+        timeout: Seconds before giving up.
+
+    Returns:
+        int: Exit code of lintro.
+
+    Raises:
+        OrgRunError: If lintro cannot be started or times out.
+    """
+    try:
+        result = subprocess.run(  # nosec B603 - list command, no shell
+            [sys.executable, "-m", "lintro", *args],
+            cwd=str(cwd),
+            capture_output=True,
+            text=True,
+            timeout=timeout,
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        raise OrgRunError(f"lintro check failed: {e}") from e
+    return result.returncode
+
+
+def run_repo(
+    repo: OrgRepo,
+    gate_name: str,
+    gate: GateConfig,
+    workdir: Path,
+    timeout: int = DEFAULT_REPO_TIMEOUT,
+) -> RepoOutcome:
+    """Check out one repository and run the gate in it.
+
+    Args:
+        repo: Repository to audit.
+        gate_name: Name of the gate.
+        gate: Gate to run.
+        workdir: Directory holding checkouts and reports.
+        timeout: Seconds allowed for the check.
+
+    Returns:
+        RepoOutcome: What happened; failures are reported, not raised.
+    """
+    name = str(repo.name)
+    outcome = RepoOutcome(name=name, url=repo.url, gate=gate_name, status=STATUS_ERROR)
+    checkout = workdir / "repos" / name
+    report = (workdir / "reports" / f"{name}.json").resolve()
+    try:
+        outcome.commit = sync_repo(repo, checkout)
+        report.parent.mkdir(parents=True, exist_ok=True)
+        report.unlink(missing_ok=True)
+        args = gate_check_args(gate, report)
+        outcome.exit_code = _run_lintro(args, checkout, timeout)
+        data = json.loads(report.read_text(encoding="utf-8"))
+    except OrgRunError as e:
+        outcome.error = str(e)
+        return outcome
+    except (OSError, ValueError) as e:
+        outcome.error = f"lintro check exited {outcome.exit_code} without a report: {e}"
+        return outcome
+
+    outcome.results = list(data.get("results") or [])
+    outcome.issues_count = int((data.get("summary") or {}).get("total_issues", 0))
+    outcome.status = STATUS_PASSED if outcome.exit_code == 0 else STATUS_FAILED
+    return outcome
+
+
+def run_org(
+    config: OrgConfig,
+    gates: dict[str, GateConfig],
+    *,
+    gate: str | None = None,
+    jobs: int = DEFAULT_JOBS,
+    timeout: int = DEFAULT_REPO_TIMEOUT,
+    on_done: Callable[[RepoOutcome], None] | None = None,
+) -> list[RepoOutcome]:
+    """Run the gate in every listed repository.
+
+    Args:
+        config: Repository list.
+        gates: Gates of the current Lintro config.
+        gate: Gate overriding the list's default gate, if any.
+        jobs: Repositories processed at the same time.
+        timeout: Seconds allowed for each repository's check.
+        on_done: Called as each repository finishes.
+
+    Returns:
+        list[RepoOutcome]: One outcome per repository, in list order.
+
+    Raises:
+        ValueError: If a repository has no gate or names an unknown gate.
+    """
+    planned: list[tuple[OrgRepo, str]] = []
+    for repo in config.repos:
+        gate_name = repo.gate or gate or config.gate
+        if not gate_name:
+            raise ValueError(
+                f"No gate for repository '{repo.name}'; pass --gate or set 'gate'",
+            )
+        if gate_name not in gates:
+            available = ", ".join(sorted(gates)) or "none defined"
+            raise ValueError(f"Unknown gate '{gate_name}' (available: {available})")
+        planned.append((repo, gate_name))
+
+    workdir = Path(config.workdir)
+
+    def audit(item: tuple[OrgRepo, str]) -> RepoOutcome:
+        """Audit one repository and report it as done.
+
+        Args:
+            item: Repository and the name of its gate.
+
+        Returns:
+            RepoOutcome: Outcome of the repository.
+        """
+        repo, gate_name = item
+        outcome = run_repo(repo, gate_name, gates[gate_name], workdir, timeout)
+        if on_done:
+            on_done(outcome)
+        return outcome
+
+    with ThreadPoolExecutor(max_workers=max(1, jobs)) as pool:
+        return list(pool.map(audit, planned))
+
+
+def _namespaced(result: dict[str, Any], name: str) -> dict[str, Any]:
+    """Prefix the issue paths of a tool result with a repository name.
+
+    Args:
+        result: Tool result from a repository's JSON report.
+        name: Repository name.
+
+    Returns:
+        dict[str, Any]: Copy of the result with prefixed issue paths.
+    """
+    issues = [
+        {**issue, "file": f"{name}/{issue['file']}" if issue.get("file") else name}
+        for issue in result.get("issues") or []
+    ]
+    return {**result, "issues": issues} if "issues" in result else dict(result)
+
+
+def merge_reports(outcomes: list[RepoOutcome]) -> dict[str, Any]:
+    """Merge the repositories' reports into one namespaced report.
+
+    Args:
+        outcomes: Outcomes from ``run_org``.
+
+    Returns:
+        dict[str, Any]: Summary over all repositories and one entry per
+        repository with its summary and namespaced tool results.
+    """
+    statuses = [outcome.status for outcome in outcomes]
+    return {
+        "timestamp": utc_timestamp(),
+        "summary": {
+            "repos": len(outcomes),
+            STATUS_PASSED: statuses.count(STATUS_PASSED),
+            STATUS_FAILED: statuses.count(STATUS_FAILED),
+            STATUS_ERROR: statuses.count(STATUS_ERROR),
+            "total_issues": sum(outcome.issues_count for outcome in outcomes),
+        },
+        "repos": [
+            {
+                "name": outcome.name,
+                "url": outcome.url,
+                "gate": outcome.gate,
+                "commit": outcome.commit,
+                "status": outcome.status,
+                "exit_code": outcome.exit_code,
+                "issues_count": outcome.issues_count,
+                "error": outcome.error,
+                "results": [
+                    _namespaced(result, outcome.name) for result in outcome.results
+                ],
+            }
+            for outcome in outcomes
+        ],
+    }
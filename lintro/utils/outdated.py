@@ -0,0 +1,398 @@
+"""Dependency update advisor.
+
+Aggregates ``cargo outdated``, ``uv pip list --outdated`` and ``npm outdated``
+into a single list of update candidates. Each candidate is classified as a
+major, minor or patch update so reports can highlight breaking upgrades.
+
+The advisor only reads package manager output; it never modifies manifests
+or lockfiles.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import shutil
+import subprocess  # nosec B404 - used safely with shell disabled
+from collections.abc import Callable, Iterable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.env import get_subprocess_env
+
+DEFAULT_OUTDATED_TIMEOUT: int = 120
+
+# Update types ordered from least to most disruptive
+UPDATE_TYPES: tuple[str, ...] = ("unknown", "patch", "minor", "major")
+
+UPDATE_SEVERITY: dict[str, SeverityLevel] = {
+    "major": SeverityLevel.ERROR,
+    "minor": SeverityLevel.WARNING,
+    "patch": SeverityLevel.INFO,
+    "unknown": SeverityLevel.INFO,
+}
+
+_VERSION_RE: re.Pattern[str] = re.compile(r"(\d+)(?:\.(\d+))?(?:\.(\d+))?")
+
+
+@dataclass
+class OutdatedPackage:
+    """A dependency with a newer version available.
+
+    Attributes:
+        ecosystem: Package ecosystem (cargo, python or npm).
+        name: Package name.
+        current: Currently resolved version.
+        latest: Latest available version.
+        update_type: One of major, minor, patch or unknown.
+    """
+
+    ecosystem: str
+    name: str
+    current: str
+    latest: str
+    update_type: str = "unknown"
+
+    @property
+    def severity(self) -> SeverityLevel:
+        """Severity of the update, derived from its type.
+
+        Returns:
+            SeverityLevel: ERROR for major, WARNING for minor, INFO otherwise.
+        """
+        return UPDATE_SEVERITY.get(self.update_type, SeverityLevel.INFO)
+
+
+@dataclass
+class EcosystemReport:
+    """Outdated dependencies reported by one package manager.
+
+    Attributes:
+        ecosystem: Package ecosystem name.
+        packages: Update candidates found.
+        skipped: Reason the ecosystem was not checked, if any.
+        error: Error message if the package manager failed.
+    """
+
+    ecosystem: str
+    packages: list[OutdatedPackage] = field(default_factory=list)
+    skipped: str | None = None
+    error: str | None = None
+
+
+def _parse_version(version: str) -> tuple[int, int, int] | None:
+    """Extract the numeric major, minor and patch parts of a version.
+
+    Args:
+        version: Version string such as ``1.2.3`` or ``v2.0.0-rc.1``.
+
+    Returns:
+        tuple[int, int, int] | None: Version parts, or None if unparseable.
+    """
+    match = _VERSION_RE.search(version)
+    if not match:
+        return None
+    return (
+        int(match.group(1)),
+        int(match.group(2) or 0),
+        int(match.group(3) or 0),
+    )
+
+
+def classify_update(current: str, latest: str) -> str:
+    """Classify the difference between two versions.
+
+    Args:
+        current: Currently resolved version.
+        latest: Latest available version.
+
+    Returns:
+        str: ``major``, ``minor``, ``patch`` or ``unknown`` when either
+            version cannot be parsed.
+    """
+    current_parts = _parse_version(current)
+    latest_parts = _parse_version(latest)
+    if current_parts is None or latest_parts is None:
+        return "unknown"
+    if latest_parts[0] != current_parts[0]:
+        return "major"
+    if latest_parts[1] != current_parts[1]:
+        return "minor"
+    return "patch"
+
+
+def _package(ecosystem: str, name: str, current: str, latest: str) -> OutdatedPackage:
+    """Build an OutdatedPackage with its update type.
+
+    Args:
+        ecosystem: Package ecosystem name.
+        name: Package name.
+        current: Currently resolved version.
+        latest: Latest available version.
+
+    Returns:
+        OutdatedPackage: Classified update candidate.
+    """
+    return OutdatedPackage(
+        ecosystem=ecosystem,
+        name=name,
+        current=current,
+        latest=latest,
+        update_type=classify_update(current, latest),
+    )
+
+
+def parse_cargo_outdated(output: str) -> list[OutdatedPackage]:
+    """Parse ``cargo outdated --format json`` output.
+
+    Workspaces print one JSON document per member crate, one per line.
+
+    Args:
+        output: Raw stdout of cargo outdated.
+
+    Returns:
+        list[OutdatedPackage]: Update candidates.
+    """
+    packages: list[OutdatedPackage] = []
+    seen: set[tuple[str, str]] = set()
+    for line in output.splitlines():
+        line = line.strip()
+        if not line.startswith("{"):
+            continue
+        try:
+            document = json.loads(line)
+        except json.JSONDecodeError:
+            logger.debug("[outdated] Skipping unparseable cargo line: {}", line)
+            continue
+        for dep in document.get("dependencies", []):
+            name = str(dep.get("name", ""))
+            current = str(dep.get("project", ""))
+            latest = str(dep.get("latest", ""))
+            if latest in ("", "---", "Removed") or latest == current:
+                continue
+            if (name, current) in seen:
+                continue
+            seen.add((name, current))
+            packages.append(_package("cargo", name, current, latest))
+    return packages
+
+
+def parse_uv_outdated(output: str) -> list[OutdatedPackage]:
+    """Parse ``uv pip list --outdated --format json`` output.
+
+    Args:
+        output: Raw stdout of uv.
+
+    Returns:
+        list[OutdatedPackage]: Update candidates.
+    """
+    data = json.loads(output or "[]")
+    return [
+        _package(
+            "python",
+            str(entry.get("name", "")),
+            str(entry.get("version", "")),
+            str(entry.get("latest_version", "")),
+        )
+        for entry in data
+        if isinstance(entry, dict)
+    ]
+
+
+def parse_npm_outdated(output: str) -> list[OutdatedPackage]:
+    """Parse ``npm outdated --json`` output.
+
+    Workspaces report a list of entries per package name.
+
+    Args:
+        output: Raw stdout of npm.
+
+    Returns:
+        list[OutdatedPackage]: Update candidates.
+    """
+    data = json.loads(output or "{}")
+    packages: list[OutdatedPackage] = []
+    for name, info in data.items():
+        entries = info if isinstance(info, list) else [info]
+        for entry in entries:
+            if not isinstance(entry, dict):
+                continue
+            current = str(entry.get("current") or "missing")
+            packages.append(
+                _package("npm", name, current, str(entry.get("latest", ""))),
+            )
+    return packages
+
+
+@dataclass(frozen=True)
+class _Ecosystem:
+    """How to query one package manager for outdated dependencies.
+
+    Attributes:
+        manifests: Files whose presence enables the ecosystem.
+        command: Command to run.
+        parser: Function parsing the command's stdout.
+        ok_returncodes: Exit codes that indicate success.
+    """
+
+    manifests: tuple[str, ...]
+    command: tuple[str, ...]
+    parser: Callable[[str], list[OutdatedPackage]]
+    ok_returncodes: tuple[int, ...] = (0,)
+
+
+ECOSYSTEMS: dict[str, _Ecosystem] = {
+    "cargo": _Ecosystem(
+        manifests=("Cargo.toml",),
+        command=("cargo", "outdated", "--root-deps-only", "--format", "json"),
+        parser=parse_cargo_outdated,
+    ),
+    "python": _Ecosystem(
+        manifests=("pyproject.toml", "requirements.txt", "uv.lock"),
+        command=("uv", "pip", "list", "--outdated", "--format", "json"),
+        parser=parse_uv_outdated,
+    ),
+    "npm": _Ecosystem(
+        manifests=("package.json",),
+        command=("npm", "outdated", "--json"),
+        parser=parse_npm_outdated,
+        # npm exits 1 when outdated packages are found
+        ok_returncodes=(0, 1),
+    ),
+}
+
+
+def _collect_ecosystem(
+    name: str,
+    ecosystem: _Ecosystem,
+    root: Path,
+    timeout: int,
+) -> EcosystemReport:
+    """Query one package manager for outdated dependencies.
+
+    Args:
+        name: Ecosystem name.
+        ecosystem: How to query the package manager.
+        root: Project directory.
+        timeout: Timeout in seconds for the command.
+
+    Returns:
+        EcosystemReport: Update candidates or the reason none were collected.
+    """
+    if not any((root / manifest).exists() for manifest in ecosystem.manifests):
+        return EcosystemReport(ecosystem=name, skipped="no manifest found")
+    if not shutil.which(ecosystem.command[0]):
+        return EcosystemReport(
+            ecosystem=name,
+            skipped=f"{ecosystem.command[0]} not in PATH",
+        )
+
+    logger.debug("[outdated] Running {} in {}", " ".join(ecosystem.command), root)
+    try:
+        result = subprocess.run(  # nosec B603 - command is constructed safely
+            list(ecosystem.command),
+            cwd=root,
+            capture_output=True,
+            text=True,
+            timeout=timeout,
+            shell=False,
+            env=get_subprocess_env(),
+        )
+    except subprocess.TimeoutExpired:
+        return EcosystemReport(ecosystem=name, error=f"timed out after {timeout}s")
+    except OSError as e:
+        return EcosystemReport(ecosystem=name, error=str(e))
+
+    if result.returncode not in ecosystem.ok_returncodes:
+        message = (result.stderr or result.stdout).strip().splitlines()
+        return EcosystemReport(
+            ecosystem=name,
+            error=message[-1] if message else f"exit code {result.returncode}",
+        )
+    try:
+        packages = ecosystem.parser(result.stdout)
+    except (json.JSONDecodeError, AttributeError) as e:
+        return EcosystemReport(ecosystem=name, error=f"unparseable output: {e}")
+    return EcosystemReport(ecosystem=name, packages=packages)
+
+
+def collect_outdated(
+    root: Path,
+    ecosystems: Iterable[str] | None = None,
+    timeout: int = DEFAULT_OUTDATED_TIMEOUT,
+) -> list[EcosystemReport]:
+    """Collect update candidates from every applicable package manager.
+
+    Args:
+        root: Project directory.
+        ecosystems: Ecosystems to query, or None for all.
+        timeout: Timeout in seconds for each package manager.
+
+    Returns:
+        list[EcosystemReport]: One report per requested ecosystem.
+    """
+    selected = list(ecosystems) if ecosystems else list(ECOSYSTEMS)
+    return [
+        _collect_ecosystem(name, ECOSYSTEMS[name], root, timeout) for name in selected
+    ]
+
+
+def exceeds_threshold(packages: Iterable[OutdatedPackage], fail_on: str) -> bool:
+    """Check whether any update is at least as disruptive as a threshold.
+
+    Args:
+        packages: Update candidates.
+        fail_on: ``none`` or an update type (patch, minor, major).
+
+    Returns:
+        bool: True if any candidate meets the threshold.
+    """
+    if fail_on == "none":
+        return False
+    threshold = UPDATE_TYPES.index(fail_on)
+    return any(UPDATE_TYPES.index(p.update_type) >= threshold for p in packages)
+
+
+def report_to_dict(reports: list[EcosystemReport]) -> dict[str, Any]:
+    """Serialize reports for JSON output.
+
+    Args:
+        reports: Ecosystem reports.
+
+    Returns:
+        dict[str, Any]: Packages, per-ecosystem status and summary counts.
+    """
+    packages = [p for r in reports for p in r.packages]
+    return {
+        "packages": [
+            {
+                "ecosystem": p.ecosystem,
+                "name": p.name,
+                "current": p.current,
+                "latest": p.latest,
+                "update_type": p.update_type,
+                "severity": p.severity.value.lower(),
+            }
+            for p in packages
+        ],
+        "ecosystems": {
+            r.ecosystem: {
+                "checked": r.skipped is None and r.error is None,
+                "skipped": r.skipped,
+                "error": r.error,
+                "count": len(r.packages),
+            }
+            for r in reports
+        },
+        "summary": {
+            "total": len(packages),
+            **{
+                update_type: sum(1 for p in packages if p.update_type == update_type)
+                for update_type in reversed(UPDATE_TYPES)
+            },
+        },
+    }
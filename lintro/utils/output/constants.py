@@ -6,3 +6,8 @@ DEFAULT_KEEP_LAST: int = 10
 DEFAULT_TIMESTAMP_FORMAT: str = "%Y%m%d-%H%M%S"
 DEFAULT_RUN_PREFIX: str = "run-"
 DEFAULT_TEMP_PREFIX: str = ".lintro"
+
+# Values of the status column of summary.csv
+SUMMARY_STATUS_OK: str = "ok"
+SUMMARY_STATUS_SKIPPED: str = "skipped"
+SUMMARY_STATUS_FAILED: str = "failed"
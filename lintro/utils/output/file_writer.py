@@ -11,7 +11,6 @@ and formatting tool output for display.
 from __future__ import annotations
 
 import csv
-import datetime
 import html
 import json
 from collections.abc import Sequence
@@ -21,13 +20,28 @@ from typing import TYPE_CHECKING, Any
 # Import parser_registration to auto-register all parsers
 import lintro.utils.output.parser_registration  # noqa: F401
 from lintro.enums.action import Action
+from lintro.enums.display_column import parse_columns
 from lintro.enums.output_format import OutputFormat, normalize_output_format
 from lintro.enums.tool_name import ToolName
-from lintro.formatters.formatter import format_issues, format_issues_with_sections
+from lintro.exceptions.errors import OUTPUT_PARSE_FAILED
+from lintro.formatters.formatter import (
+    UnifiedTableDescriptor,
+    format_issues,
+    format_issues_with_sections,
+)
 from lintro.parsers.base_issue import BaseIssue
+from lintro.utils.fingerprint import fingerprint_issues
+from lintro.utils.json_output import json_issue
 from lintro.utils.output.helpers import sanitize_csv_value
 from lintro.utils.output.parser_registration import ParserError
 from lintro.utils.output.parser_registry import ParserRegistry
+from lintro.utils.output.rule_sampling import (
+    SampledRule,
+    format_sampling_note,
+    sample_issues,
+)
+from lintro.utils.report_metadata import build_report_metadata
+from lintro.utils.timestamps import utc_timestamp
 
 try:
     import tabulate as _tabulate_module  # noqa: F401
@@ -41,6 +55,25 @@ if TYPE_CHECKING:
     from lintro.models.core.tool_result import ToolResult
 
 
+def _sampled(
+    issues: Sequence[BaseIssue],
+    sample_per_rule: int | None,
+) -> tuple[Sequence[BaseIssue], list[SampledRule]]:
+    """Sample a result's issues per rule when sampling is enabled.
+
+    Args:
+        issues: Issues of one tool.
+        sample_per_rule: Issues kept per rule, or None to keep all.
+
+    Returns:
+        tuple[Sequence[BaseIssue], list[SampledRule]]: Issues to list and the
+        rules that were sampled.
+    """
+    if not sample_per_rule:
+        return issues, []
+    return sample_issues(issues, sample_per_rule)
+
+
 def write_output_file(
     *,
     output_path: str,
@@ -49,6 +82,9 @@ def write_output_file(
     action: Action,
     total_issues: int,
     total_fixed: int,
+    sample_per_rule: int | None = None,
+    shard: str | None = None,
+    timing: dict[str, Any] | None = None,
 ) -> None:
     """Write results to user-specified output file.
 
@@ -59,6 +95,12 @@ def write_output_file(
         action: Action: The action performed (check, fmt, test).
         total_issues: int: Total number of issues found.
         total_fixed: int: Total number of issues fixed.
+        sample_per_rule: int | None: Issues listed per rule in Markdown and
+            HTML reports. None lists every issue; JSON and CSV always do.
+        shard: str | None: ``I/N`` recorded in JSON reports of a sharded
+            run, for ``lintro merge-reports``.
+        timing: dict[str, Any] | None: Timing profile recorded in JSON
+            reports of a run with --profile-timing.
     """
     output_file = Path(output_path)
     output_file.parent.mkdir(parents=True, exist_ok=True)
@@ -66,15 +108,20 @@ def write_output_file(
     if output_format == OutputFormat.JSON:
         # Build JSON structure similar to stdout JSON mode
         json_data: dict[str, Any] = {
-            "timestamp": datetime.datetime.now(datetime.UTC).isoformat(),
+            "timestamp": utc_timestamp(),
             "action": action.value,
             "summary": {
                 "total_issues": total_issues,
                 "total_fixed": total_fixed,
                 "tools_run": len(all_results),
             },
+            "metadata": build_report_metadata(all_results),
             "results": [],
         }
+        if shard:
+            json_data["shard"] = shard
+        if timing is not None:
+            json_data["timing"] = timing
         for result in all_results:
             result_data = {
                 "tool": result.name,
@@ -82,15 +129,22 @@ def write_output_file(
                 "issues_count": getattr(result, "issues_count", 0),
                 "output": getattr(result, "output", ""),
             }
+            if getattr(result, "error_code", None):
+                result_data["error_code"] = result.error_code
+            if getattr(result, "skipped", False):
+                result_data["skipped"] = True
+                result_data["skip_reason"] = result.skip_reason
+            if getattr(result, "duration_ms", None) is not None:
+                result_data["duration_ms"] = result.duration_ms
             if hasattr(result, "issues") and result.issues:
+                fingerprints = fingerprint_issues(result.name, result.issues)
                 result_data["issues"] = [
-                    {
-                        "file": getattr(issue, "file", "") or "",
-                        "line": getattr(issue, "line", None) or 0,
-                        "code": getattr(issue, "code", "") or "",
-                        "message": getattr(issue, "message", "") or "",
-                    }
-                    for issue in result.issues
+                    json_issue(issue, fingerprint)
+                    for issue, fingerprint in zip(
+                        result.issues,
+                        fingerprints,
+                        strict=True,
+                    )
                 ]
             json_data["results"].append(result_data)
         output_file.write_text(
@@ -150,9 +204,10 @@ def write_output_file(
             issues_count = getattr(result, "issues_count", 0)
             lines.append(f"### {result.name} ({issues_count} issues)")
             if hasattr(result, "issues") and result.issues:
+                issues, sampled = _sampled(result.issues, sample_per_rule)
                 lines.append("| File | Line | Code | Message |")
                 lines.append("|------|------|------|---------|")
-                for issue in result.issues:
+                for issue in issues:
                     file_val = str(getattr(issue, "file", "") or "").replace("|", r"\|")
                     line_val = getattr(issue, "line", None) or 0
                     code_val = str(getattr(issue, "code", "") or "").replace("|", r"\|")
@@ -164,6 +219,9 @@ def write_output_file(
                         f"| {file_val} | {line_val} | {code_val} | {msg_val} |",
                     )
                 lines.append("")
+                note = format_sampling_note(sampled)
+                if note:
+                    lines.extend([*note.splitlines(), ""])
             else:
                 lines.append("No issues found.\n")
         output_file.write_text("\n".join(lines), encoding="utf-8")
@@ -189,11 +247,12 @@ def write_output_file(
                 f"<h3>{html.escape(result.name)} ({issues_count} issues)</h3>",
             )
             if hasattr(result, "issues") and result.issues:
+                issues, sampled = _sampled(result.issues, sample_per_rule)
                 html_lines.append(
                     "<table border='1'><tr><th>File</th><th>Line</th>"
                     "<th>Code</th><th>Message</th></tr>",
                 )
-                for issue in result.issues:
+                for issue in issues:
                     f_val = html.escape(str(getattr(issue, "file", "") or ""))
                     l_val = html.escape(str(getattr(issue, "line", None) or 0))
                     c_val = html.escape(str(getattr(issue, "code", "") or ""))
@@ -203,6 +262,17 @@ def write_output_file(
                         f"<td>{c_val}</td><td>{m_val}</td></tr>",
                     )
                 html_lines.append("</table>")
+                html_lines.extend(
+                    f"<details><summary>{html.escape(location)}</summary>"
+                    f"<pre>{html.escape(diff.rstrip())}</pre></details>"
+                    for location, diff in UnifiedTableDescriptor().get_previews(
+                        issues,
+                    )
+                )
+                html_lines.extend(
+                    f"<p>{html.escape(line)}</p>"
+                    for line in format_sampling_note(sampled).splitlines()
+                )
             else:
                 html_lines.append("<p>No issues found.</p>")
         html_lines.append("</body></html>")
@@ -229,6 +299,10 @@ def format_tool_output(
     output: str,
     output_format: str | OutputFormat = "grid",
     issues: Sequence[BaseIssue] | None = None,
+    sample_per_rule: int | None = None,
+    group_by: str | None = None,
+    columns: list[str] | None = None,
+    sort_by: str | None = None,
 ) -> str:
     """Format tool output using the specified format.
 
@@ -237,12 +311,35 @@ def format_tool_output(
         output: str: Raw output from the tool.
         output_format: str: Output format (plain, grid, markdown, html, json, csv).
         issues: Sequence[BaseIssue] | None: List of parsed issue objects (optional).
+        sample_per_rule: int | None: Issues shown per rule in formats other
+            than JSON, followed by a note of the rules sampled. None shows
+            every issue.
+        group_by: str | None: Grouping of the issue rows; rule, severity,
+            dir and tool add subtotal rows to table formats.
+        columns: list[str] | None: Columns of table formats, in order. None
+            shows every column.
+        sort_by: str | None: Order of the rows of table formats. None keeps
+            the order the tool reported.
 
     Returns:
         str: Formatted output string.
     """
     output_format = normalize_output_format(output_format)
 
+    if issues and sample_per_rule and output_format != OutputFormat.JSON:
+        issues, sampled = sample_issues(issues, sample_per_rule)
+        formatted = format_tool_output(
+            tool_name,
+            output,
+            output_format,
+            issues,
+            group_by=group_by,
+            columns=columns,
+            sort_by=sort_by,
+        )
+        note = format_sampling_note(sampled)
+        return f"{formatted}\n\n{note}" if note else formatted
+
     # Pytest output is already formatted by build_output_with_failures
     # in pytest_output_processor.py, so return it directly
     if tool_name == ToolName.PYTEST:
@@ -250,6 +347,7 @@ def format_tool_output(
 
     # If parsed issues are provided, use the unified formatter
     if issues:
+        selected = parse_columns(columns) if columns else None
         # Get fixability predicate from registry (O(1) lookup)
         is_fixable = ParserRegistry.get_fixability_predicate(tool_name)
 
@@ -259,11 +357,21 @@ def format_tool_output(
                 issues=issues,
                 output_format=output_format,
                 group_by_fixable=True,
+                columns=selected,
                 tool_name=tool_name,
+                group_by=group_by,
+                sort_by=sort_by,
             )
 
         # Use unified formatter for all issues
-        return format_issues(issues=issues, output_format=output_format)
+        return format_issues(
+            issues=issues,
+            output_format=output_format,
+            columns=selected,
+            tool_name=tool_name,
+            group_by=group_by,
+            sort_by=sort_by,
+        )
 
     if not output or not output.strip():
         return "No issues found."
@@ -275,10 +383,15 @@ def format_tool_output(
         parsed_issues = ParserRegistry.parse(tool_name, output)
     except ParserError as e:
         # Parsing failed - return error message with raw output for debugging
-        return f"Error: {e}\n\nRaw output:\n{output}"
+        return f"{OUTPUT_PARSE_FAILED.format(str(e))}\n\nRaw output:\n{output}"
 
     if parsed_issues:
-        return format_issues(issues=parsed_issues, output_format=output_format)
+        return format_issues(
+            issues=parsed_issues,
+            output_format=output_format,
+            columns=parse_columns(columns) if columns else None,
+            sort_by=sort_by,
+        )
 
     # Fallback: return the raw output
     return output
@@ -17,17 +17,59 @@ from typing import TYPE_CHECKING
 
 from loguru import logger
 
+from lintro.utils.effort import format_effort
 from lintro.utils.output.constants import (
     DEFAULT_BASE_DIR,
     DEFAULT_KEEP_LAST,
     DEFAULT_RUN_PREFIX,
     DEFAULT_TEMP_PREFIX,
     DEFAULT_TIMESTAMP_FORMAT,
+    SUMMARY_STATUS_FAILED,
+    SUMMARY_STATUS_OK,
+    SUMMARY_STATUS_SKIPPED,
 )
 from lintro.utils.output.helpers import html_escape, markdown_escape
+from lintro.utils.timestamps import render_timestamp
 
 if TYPE_CHECKING:
+    from collections.abc import Sequence
+
     from lintro.models.core.tool_result import ToolResult
+    from lintro.utils.effort import EffortEstimate
+
+
+def _ordered_issues(
+    issues: Sequence[object],
+    effort: EffortEstimate | None,
+    sort_by_effort: bool,
+) -> list[object]:
+    """Order a tool's issues for a report.
+
+    Args:
+        issues: Issues reported by one tool.
+        effort: Effort estimate for the run, if computed.
+        sort_by_effort: List files with the most estimated effort first.
+
+    Returns:
+        list[object]: Issues in report order.
+    """
+    if not sort_by_effort or effort is None:
+        return list(issues)
+
+    def sort_key(issue: object) -> tuple[int, str, int]:
+        """Sort by file effort (descending), then file and line.
+
+        Args:
+            issue: Issue to place.
+
+        Returns:
+            tuple[int, str, int]: Sort key.
+        """
+        file_path = str(getattr(issue, "file", "") or "")
+        line = getattr(issue, "line", None) or 0
+        return -effort.by_file.get(file_path, 0), file_path, int(line)
+
+    return sorted(issues, key=sort_key)
 
 
 class OutputManager:
@@ -150,25 +192,45 @@ class OutputManager:
     def write_reports_from_results(
         self,
         results: list[ToolResult],
+        effort: EffortEstimate | None = None,
+        sort_by_effort: bool = False,
+        local_time: bool = False,
     ) -> None:
         """Generate and write Markdown, HTML, and CSV reports from tool results.
 
         Args:
             results: list["ToolResult"]: List of ToolResult objects from a Lintro run.
+            effort: EffortEstimate | None: Remediation-effort estimate to include.
+            sort_by_effort: bool: List files with the most estimated effort first.
+            local_time: bool: Show the HTML report's time in the local timezone
+                instead of UTC.
         """
-        self._write_markdown_report(results=results)
-        self._write_html_report(results=results)
+        self._write_markdown_report(
+            results=results,
+            effort=effort,
+            sort_by_effort=sort_by_effort,
+        )
+        self._write_html_report(
+            results=results,
+            effort=effort,
+            sort_by_effort=sort_by_effort,
+            local_time=local_time,
+        )
         self._write_csv_summary(results=results)
 
     def _write_markdown_report(
         self,
         results: list[ToolResult],
+        effort: EffortEstimate | None = None,
+        sort_by_effort: bool = False,
     ) -> None:
         """Write a Markdown report summarizing all tool results and issues.
 
         Args:
             results: list["ToolResult"]: List of ToolResult objects from the linting
                 run.
+            effort: EffortEstimate | None: Remediation-effort estimate to include.
+            sort_by_effort: bool: List files with the most estimated effort first.
         """
         lines: list[str] = ["# Lintro Report", ""]
         lines.append("## Summary\n")
@@ -177,12 +239,25 @@ class OutputManager:
         for r in results:
             lines.append(f"| {r.name} | {r.issues_count} |")
         lines.append("")
+        if effort is not None and effort.total_minutes:
+            total = format_effort(effort.total_minutes)
+            lines.append(f"**Estimated effort:** {total} of lint debt\n")
+            lines.append("## Effort by File\n")
+            lines.append("| File | Issues | Effort |")
+            lines.append("|------|--------|--------|")
+            for file_path, minutes in effort.files_by_effort():
+                count = effort.issues_by_file.get(file_path, 0)
+                lines.append(
+                    f"| {markdown_escape(file_path)} | {count} "
+                    f"| {format_effort(minutes)} |",
+                )
+            lines.append("")
         for r in results:
             lines.append(f"### {r.name} ({r.issues_count} issues)")
             if hasattr(r, "issues") and r.issues:
                 lines.append("| File | Line | Code | Message |")
                 lines.append("|------|------|------|---------|")
-                for issue in r.issues:
+                for issue in _ordered_issues(r.issues, effort, sort_by_effort):
                     file: str = markdown_escape(getattr(issue, "file", "") or "")
                     line = getattr(issue, "line", None) or 0
                     code: str = markdown_escape(getattr(issue, "code", "") or "")
@@ -196,24 +271,57 @@ class OutputManager:
     def _write_html_report(
         self,
         results: list[ToolResult],
+        effort: EffortEstimate | None = None,
+        sort_by_effort: bool = False,
+        local_time: bool = False,
     ) -> None:
         """Write an HTML report summarizing all tool results and issues.
 
         Args:
             results: list["ToolResult"]: List of ToolResult objects from the linting
                 run.
+            effort: EffortEstimate | None: Remediation-effort estimate to include.
+            sort_by_effort: bool: List files with the most estimated effort first.
+            local_time: bool: Show the report's time in the local timezone.
         """
         html_content: list[str] = [
             "<html><head><title>Lintro Report</title></head><body>",
         ]
         html_content.append("<h1>Lintro Report</h1>")
+        generated = datetime.datetime.now(datetime.UTC)
+        html_content.append(
+            f"<p>Generated {render_timestamp(generated, local=local_time)}</p>",
+        )
         html_content.append("<h2>Summary</h2>")
-        html_content.append("<table border='1'><tr><th>Tool</th><th>Issues</th></tr>")
+        html_content.append(
+            "<table border='1'><tr><th>Tool</th><th>Issues</th>"
+            "<th>Duration</th></tr>",
+        )
         for r in results:
+            duration_ms = getattr(r, "duration_ms", None)
+            duration = f"{duration_ms / 1000:.1f}s" if duration_ms is not None else "-"
             html_content.append(
-                f"<tr><td>{html_escape(r.name)}</td><td>{r.issues_count}</td></tr>",
+                f"<tr><td>{html_escape(r.name)}</td><td>{r.issues_count}</td>"
+                f"<td>{duration}</td></tr>",
             )
         html_content.append("</table>")
+        if effort is not None and effort.total_minutes:
+            total = format_effort(effort.total_minutes)
+            html_content.append(
+                f"<p><strong>Estimated effort:</strong> {total} of lint debt</p>",
+            )
+            html_content.append("<h2>Effort by File</h2>")
+            html_content.append(
+                "<table border='1'><tr><th>File</th><th>Issues</th>"
+                "<th>Effort</th></tr>",
+            )
+            for file_path, minutes in effort.files_by_effort():
+                count = effort.issues_by_file.get(file_path, 0)
+                html_content.append(
+                    f"<tr><td>{html_escape(file_path)}</td><td>{count}</td>"
+                    f"<td>{format_effort(minutes)}</td></tr>",
+                )
+            html_content.append("</table>")
         for r in results:
             html_content.append(
                 f"<h3>{html_escape(r.name)} ({r.issues_count} issues)</h3>",
@@ -223,7 +331,7 @@ class OutputManager:
                     "<table border='1'><tr><th>File</th><th>Line</th><th>Code</th>"
                     "<th>Message</th></tr>",
                 )
-                for issue in r.issues:
+                for issue in _ordered_issues(r.issues, effort, sort_by_effort):
                     file: str = html_escape(getattr(issue, "file", "") or "")
                     line = getattr(issue, "line", None) or 0
                     code: str = html_escape(getattr(issue, "code", "") or "")
@@ -244,13 +352,33 @@ class OutputManager:
     ) -> None:
         """Write a CSV summary of all tool results and issues.
 
+        The ``status`` column says whether each tool ran (``ok``), was
+        skipped (``skipped``) or failed to run (``failed``), so readers of
+        past runs do not mistake the last two for clean runs.
+
         Args:
             results: list["ToolResult"]: List of ToolResult objects from the linting
                 run.
         """
+        from lintro.utils.execution.exit_codes import is_infrastructure_failure
+
         rows: list[list[str]] = []
-        header: list[str] = ["tool", "issues_count", "file", "line", "code", "message"]
+        header: list[str] = [
+            "tool",
+            "issues_count",
+            "file",
+            "line",
+            "code",
+            "message",
+            "status",
+        ]
         for r in results:
+            if getattr(r, "skipped", False):
+                status = SUMMARY_STATUS_SKIPPED
+            elif is_infrastructure_failure(r):
+                status = SUMMARY_STATUS_FAILED
+            else:
+                status = SUMMARY_STATUS_OK
             if hasattr(r, "issues") and r.issues:
                 for issue in r.issues:
                     rows.append(
@@ -261,10 +389,11 @@ class OutputManager:
                             str(getattr(issue, "line", None) or 0),
                             getattr(issue, "code", "") or "",
                             getattr(issue, "message", "") or "",
+                            status,
                         ],
                     )
             else:
-                rows.append([r.name, str(r.issues_count), "", "", "", ""])
+                rows.append([r.name, str(r.issues_count), "", "", "", "", status])
         self.write_csv(rows=rows, header=header)
 
     def cleanup_old_runs(self) -> None:
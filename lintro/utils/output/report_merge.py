@@ -0,0 +1,228 @@
+"""Combining the reports of a sharded run into one.
+
+Each job of ``lintro check --shard I/N`` writes its own report. JSON reports
+record their shard, so merging checks that every part is there once; SARIF
+reports are combined run by run. Tools that were skipped in some shards
+count once, with the results of the shards that ran them.
+"""
+
+from __future__ import annotations
+
+import copy
+import json
+from collections.abc import Sequence
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from lintro.utils.execution.sharding import parse_shard
+
+
+@dataclass
+class MergedReport:
+    """Report combined from several shard reports.
+
+    Attributes:
+        data: Combined report, in the format of its inputs.
+        missing_shards: Shards (``I/N``) without a report; empty when the
+            reports do not record their shard.
+    """
+
+    data: dict[str, Any]
+    missing_shards: list[str] = field(default_factory=list)
+
+
+def _is_sarif(report: dict[str, Any]) -> bool:
+    """Tell whether a report is a SARIF log.
+
+    Args:
+        report: Parsed report.
+
+    Returns:
+        bool: True for SARIF logs, False for lintro JSON reports.
+    """
+    return isinstance(report.get("runs"), list) and "version" in report
+
+
+def _load(path: str | Path) -> dict[str, Any]:
+    """Read a JSON or SARIF report.
+
+    Args:
+        path: Report file.
+
+    Returns:
+        dict[str, Any]: The report.
+
+    Raises:
+        ValueError: If the file is neither a lintro JSON report nor SARIF.
+        OSError: If the file cannot be read.
+    """
+    try:
+        report = json.loads(Path(path).read_text(encoding="utf-8"))
+    except json.JSONDecodeError as e:
+        raise ValueError(f"{path}: not valid JSON ({e})") from e
+    if not isinstance(report, dict) or not (
+        isinstance(report.get("results"), list) or _is_sarif(report)
+    ):
+        raise ValueError(f"{path}: not a Lintro JSON or SARIF report")
+    return report
+
+
+def _check_shards(reports: Sequence[tuple[str, dict[str, Any]]]) -> list[str]:
+    """Check that the reports come from distinct shards of one run.
+
+    Args:
+        reports: Path and contents of each JSON report.
+
+    Returns:
+        list[str]: Shards without a report.
+
+    Raises:
+        ValueError: If a shard is reported twice, shard counts differ, or
+            only some reports record their shard.
+    """
+    sharded = [(path, r["shard"]) for path, r in reports if r.get("shard")]
+    if not sharded:
+        return []
+    if len(sharded) < len(reports):
+        raise ValueError("Cannot merge reports of sharded and unsharded runs")
+    seen: dict[int, str] = {}
+    count: int | None = None
+    for path, value in sharded:
+        shard = parse_shard(str(value))
+        if count is not None and shard.count != count:
+            raise ValueError(
+                f"{path}: shard {shard} does not match the other reports "
+                f"({count} shards)",
+            )
+        if shard.index in seen:
+            raise ValueError(f"{path}: shard {shard} is also in {seen[shard.index]}")
+        count = shard.count
+        seen[shard.index] = path
+    return [f"{i}/{count}" for i in range(1, count + 1) if i not in seen]
+
+
+def _merge_results(results: Sequence[dict[str, Any]]) -> dict[str, Any]:
+    """Combine one tool's results from several shards.
+
+    Args:
+        results: The tool's result in each report, in report order.
+
+    Returns:
+        dict[str, Any]: Result of the shards that ran the tool, or the first
+        skipped result when no shard ran it.
+    """
+    ran = [r for r in results if not r.get("skipped")]
+    if not ran:
+        return dict(results[0])
+    merged: dict[str, Any] = {
+        "tool": ran[0]["tool"],
+        "success": all(r.get("success", True) for r in ran),
+        "issues_count": sum(int(r.get("issues_count", 0) or 0) for r in ran),
+        "output": "\n".join(r["output"] for r in ran if r.get("output")),
+    }
+    error_codes = [r["error_code"] for r in ran if r.get("error_code")]
+    if error_codes:
+        merged["error_code"] = error_codes[0]
+    durations = [r["duration_ms"] for r in ran if r.get("duration_ms") is not None]
+    if durations:
+        merged["duration_ms"] = sum(durations)
+    issues = [issue for r in ran for issue in r.get("issues", [])]
+    if issues:
+        merged["issues"] = issues
+    return merged
+
+
+def _merge_json(reports: Sequence[tuple[str, dict[str, Any]]]) -> MergedReport:
+    """Combine lintro JSON reports.
+
+    Args:
+        reports: Path and contents of each report.
+
+    Returns:
+        MergedReport: Combined report and the shards without a report.
+
+    Raises:
+        ValueError: If the reports are of different actions or shards
+            do not fit together.
+    """
+    actions = {report.get("action") for _, report in reports}
+    if len(actions) > 1:
+        names = ", ".join(sorted(map(str, actions)))
+        raise ValueError(f"Reports are of different actions: {names}")
+    missing = _check_shards(reports)
+    by_tool: dict[str, list[dict[str, Any]]] = {}
+    for _, report in reports:
+        for result in report["results"]:
+            by_tool.setdefault(str(result.get("tool", "")), []).append(result)
+    results = [_merge_results(tool_results) for tool_results in by_tool.values()]
+    timestamps = [str(r["timestamp"]) for _, r in reports if r.get("timestamp")]
+    data: dict[str, Any] = {
+        "timestamp": max(timestamps) if timestamps else None,
+        "action": actions.pop(),
+        "summary": {
+            "total_issues": sum(r["issues_count"] for r in results),
+            "total_fixed": sum(
+                int(report.get("summary", {}).get("total_fixed", 0) or 0)
+                for _, report in reports
+            ),
+            "tools_run": len(results),
+        },
+        "results": results,
+    }
+    return MergedReport(data=data, missing_shards=missing)
+
+
+def _merge_sarif(reports: Sequence[tuple[str, dict[str, Any]]]) -> MergedReport:
+    """Combine SARIF logs, one run per tool.
+
+    Args:
+        reports: Path and contents of each log.
+
+    Returns:
+        MergedReport: Combined log.
+    """
+    runs: dict[str, dict[str, Any]] = {}
+    for _, report in reports:
+        for run in report["runs"]:
+            driver = run.get("tool", {}).get("driver", {})
+            merged = runs.get(driver.get("name", ""))
+            if merged is None:
+                runs[driver.get("name", "")] = copy.deepcopy(run)
+                continue
+            merged_driver = merged["tool"]["driver"]
+            rule_ids = {rule["id"] for rule in merged_driver.get("rules", [])}
+            rule_ids.update(rule["id"] for rule in driver.get("rules", []))
+            merged_driver["rules"] = [{"id": rule_id} for rule_id in sorted(rule_ids)]
+            merged.setdefault("results", []).extend(run.get("results", []))
+    first = reports[0][1]
+    data = {
+        "$schema": first.get("$schema"),
+        "version": first["version"],
+        "runs": list(runs.values()),
+    }
+    return MergedReport(data=data)
+
+
+def merge_reports(paths: Sequence[str | Path]) -> MergedReport:
+    """Combine the reports of a sharded run.
+
+    Args:
+        paths: JSON reports, or SARIF logs, one per shard.
+
+    Returns:
+        MergedReport: Combined report, in the format of the inputs.
+
+    Raises:
+        ValueError: If no report is given, the reports mix formats, or they
+            are not the shards of one run.
+    """
+    if not paths:
+        raise ValueError("No reports to merge")
+    reports = [(str(path), _load(path)) for path in paths]
+    kinds = {_is_sarif(report) for _, report in reports}
+    if len(kinds) > 1:
+        raise ValueError("Cannot merge JSON reports with SARIF logs")
+    if kinds.pop():
+        return _merge_sarif(reports)
+    return _merge_json(reports)
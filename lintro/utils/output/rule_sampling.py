@@ -0,0 +1,116 @@
+"""Per-rule sampling of issues for readable reports.
+
+When lintro is first adopted on a legacy codebase a single rule can have
+thousands of hits, burying every other rule in console output and PR
+comments. ``lintro check --sample-per-rule N`` keeps N examples of each rule
+in those outputs, spread over as many files as possible, and states how many
+were left out. JSON and CSV reports keep every issue.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from dataclasses import dataclass
+from typing import TypeVar
+
+from lintro.utils.effort import issue_code
+
+IssueT = TypeVar("IssueT")
+
+
+@dataclass(frozen=True)
+class SampledRule:
+    """A rule whose issues were sampled.
+
+    Attributes:
+        code: Rule code.
+        shown: Issues kept as examples.
+        total: Issues reported for the rule.
+    """
+
+    code: str
+    shown: int
+    total: int
+
+
+def _representatives(issues: list[IssueT], limit: int) -> list[IssueT]:
+    """Pick examples of one rule, taking one file at a time.
+
+    Args:
+        issues: Issues of the rule, in report order.
+        limit: Number of examples to keep.
+
+    Returns:
+        list[IssueT]: The first issue of each file in turn, then the second,
+        and so on until limit issues are picked.
+    """
+    by_file: dict[str, list[IssueT]] = {}
+    for issue in issues:
+        by_file.setdefault(str(getattr(issue, "file", "") or ""), []).append(issue)
+    picked: list[IssueT] = []
+    depth = 0
+    while len(picked) < limit:
+        for file_issues in by_file.values():
+            if depth < len(file_issues) and len(picked) < limit:
+                picked.append(file_issues[depth])
+        depth += 1
+    return picked
+
+
+def sample_issues(
+    issues: Sequence[IssueT],
+    limit: int,
+) -> tuple[list[IssueT], list[SampledRule]]:
+    """Keep at most limit issues of each rule.
+
+    Issues without a rule code are always kept.
+
+    Args:
+        issues: Issues of one tool.
+        limit: Examples to keep per rule.
+
+    Returns:
+        tuple[list[IssueT], list[SampledRule]]: Kept issues in their original
+        order, and the rules that had more than limit issues.
+    """
+    by_rule: dict[str, list[IssueT]] = {}
+    for issue in issues:
+        code = issue_code(issue)
+        if code:
+            by_rule.setdefault(code, []).append(issue)
+
+    kept_ids: set[int] = set()
+    sampled: list[SampledRule] = []
+    for code, rule_issues in by_rule.items():
+        if len(rule_issues) <= limit:
+            continue
+        kept_ids.update(id(issue) for issue in _representatives(rule_issues, limit))
+        sampled.append(SampledRule(code=code, shown=limit, total=len(rule_issues)))
+    dropped = {
+        id(issue)
+        for rule in sampled
+        for issue in by_rule[rule.code]
+        if id(issue) not in kept_ids
+    }
+    kept = [issue for issue in issues if id(issue) not in dropped]
+    return kept, sampled
+
+
+def format_sampling_note(sampled: Sequence[SampledRule]) -> str:
+    """Describe which rules were sampled.
+
+    Args:
+        sampled: Rules returned by sample_issues().
+
+    Returns:
+        str: One line per rule, such as ``E501: showing 5 of 3,214
+        occurrences``, or an empty string when nothing was sampled.
+    """
+    if not sampled:
+        return ""
+    lines = [
+        f"{rule.code}: showing {rule.shown} of {rule.total:,} occurrences"
+        for rule in sampled
+    ]
+    lines.append("Every occurrence is kept in JSON and CSV reports.")
+    return "\n".join(lines)
@@ -0,0 +1,170 @@
+"""SARIF 2.1.0 reports for code scanning services.
+
+Each tool that ran becomes one SARIF run, so GitHub code scanning and
+similar services attribute every alert to the tool that reported it. Rule
+codes become ``ruleId``, lintro severities map to SARIF levels and issue
+fingerprints become ``partialFingerprints``.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Sequence
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.effort import issue_code
+from lintro.utils.fingerprint import fingerprint_issues
+from lintro.utils.report_metadata import build_report_metadata
+
+if TYPE_CHECKING:
+    from lintro.models.core.tool_result import ToolResult
+
+SARIF_VERSION = "2.1.0"
+SARIF_SCHEMA = "https://json.schemastore.org/sarif-2.1.0.json"
+# partialFingerprints key; code scanning matches alerts across runs on it
+FINGERPRINT_KEY = "lintroFingerprint/v1"
+
+_LEVELS: dict[SeverityLevel, str] = {
+    SeverityLevel.ERROR: "error",
+    SeverityLevel.WARNING: "warning",
+    SeverityLevel.INFO: "note",
+}
+
+
+def _artifact_uri(file: str) -> str:
+    """Return the SARIF URI of an issue's file.
+
+    Args:
+        file: Path as reported by the tool.
+
+    Returns:
+        str: Forward-slash path, relative to the working directory when the
+        file is inside it.
+    """
+    path = Path(file)
+    if path.is_absolute():
+        try:
+            path = path.relative_to(Path.cwd())
+        except ValueError:
+            return path.as_uri()
+    return path.as_posix()
+
+
+def _sarif_result(issue: object, fingerprint: str) -> dict[str, Any]:
+    """Convert one issue to a SARIF result.
+
+    Args:
+        issue: Parsed issue.
+        fingerprint: Fingerprint identifying the issue across runs.
+
+    Returns:
+        dict[str, Any]: SARIF result with rule, level, message, location and
+        fingerprint.
+    """
+    get_severity = getattr(issue, "get_severity", None)
+    severity = get_severity() if callable(get_severity) else None
+    result: dict[str, Any] = {
+        "level": _LEVELS.get(severity, "warning"),
+        "message": {"text": str(getattr(issue, "message", "") or "")},
+        "partialFingerprints": {FINGERPRINT_KEY: fingerprint},
+    }
+    code = issue_code(issue)
+    if code:
+        result["ruleId"] = code
+
+    file = str(getattr(issue, "file", "") or "")
+    if file:
+        region: dict[str, int] = {}
+        line = getattr(issue, "line", None)
+        if isinstance(line, int) and line > 0:
+            region["startLine"] = line
+            column = getattr(issue, "column", None)
+            if isinstance(column, int) and column > 0:
+                region["startColumn"] = column
+            end_line = getattr(issue, "end_line", None)
+            if isinstance(end_line, int) and end_line >= line:
+                region["endLine"] = end_line
+        location: dict[str, Any] = {
+            "artifactLocation": {"uri": _artifact_uri(file)},
+        }
+        if region:
+            location["region"] = region
+        result["locations"] = [{"physicalLocation": location}]
+    return result
+
+
+def build_sarif_report(
+    all_results: Sequence[ToolResult],
+    metadata: dict[str, Any] | None = None,
+) -> dict[str, Any]:
+    """Build a SARIF log from the results of a run.
+
+    Each run records its tool's version as the driver version, its duration
+    and extra arguments in the invocation, and the rest of the run metadata
+    (see report_metadata) under ``properties.lintro``.
+
+    Args:
+        all_results: Results of every tool; skipped tools are left out.
+        metadata: Run metadata from build_report_metadata(). None builds it.
+
+    Returns:
+        dict[str, Any]: SARIF log with one run per tool.
+    """
+    if metadata is None:
+        metadata = build_report_metadata(all_results)
+    tool_metadata = metadata.get("tools", {})
+    shared = {key: value for key, value in metadata.items() if key != "tools"}
+    runs: list[dict[str, Any]] = []
+    for tool_result in all_results:
+        if tool_result.skipped:
+            continue
+        issues = list(tool_result.issues or [])
+        rule_ids = sorted({code for code in map(issue_code, issues) if code})
+        driver: dict[str, Any] = {
+            "name": tool_result.name,
+            "informationUri": "https://github.com/lgtm-hq/py-lintro",
+            "rules": [{"id": rule_id} for rule_id in rule_ids],
+        }
+        tool_data = tool_metadata.get(tool_result.name, {})
+        version = tool_data.get("version")
+        if version:
+            driver["version"] = version
+        invocation: dict[str, Any] = {"executionSuccessful": tool_result.success}
+        if tool_result.duration_ms is not None:
+            invocation["properties"] = {"durationMs": tool_result.duration_ms}
+        extra_args = tool_data.get("extra_args")
+        if extra_args:
+            invocation.setdefault("properties", {})["extraArgs"] = extra_args
+        runs.append(
+            {
+                "tool": {"driver": driver},
+                "invocations": [invocation],
+                "properties": {"lintro": shared},
+                "results": [
+                    _sarif_result(issue, fingerprint)
+                    for issue, fingerprint in zip(
+                        issues,
+                        fingerprint_issues(tool_result.name, issues),
+                        strict=True,
+                    )
+                ],
+            },
+        )
+    return {"$schema": SARIF_SCHEMA, "version": SARIF_VERSION, "runs": runs}
+
+
+def write_sarif_file(output_path: str, all_results: Sequence[ToolResult]) -> None:
+    """Write the results of a run as a SARIF file.
+
+    Args:
+        output_path: Path of the SARIF file.
+        all_results: Results of every tool.
+    """
+    output_file = Path(output_path)
+    output_file.parent.mkdir(parents=True, exist_ok=True)
+    output_file.write_text(
+        json.dumps(build_sarif_report(all_results), indent=2, ensure_ascii=False),
+        encoding="utf-8",
+    )
@@ -0,0 +1,108 @@
+"""Output sinks of a run.
+
+``--output`` may be repeated so one run feeds both people and machines::
+
+    lintro check --output console --output sarif=report.sarif \\
+        --output json=report.json
+
+Every sink renders the same in-memory results. ``console`` is the terminal,
+optionally with its format (``console=json``); ``FORMAT=PATH`` writes a
+file in that format; a bare path writes a file in the ``--output-format``
+format, as a single ``--output`` always has.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from dataclasses import dataclass
+
+from lintro.enums.output_format import OutputFormat
+
+CONSOLE_SINK = "console"
+SARIF_FORMAT = "sarif"
+SONAR_FORMAT = "sonar"
+FILE_FORMATS: tuple[str, ...] = (
+    *(fmt.value for fmt in OutputFormat),
+    SARIF_FORMAT,
+    SONAR_FORMAT,
+)
+
+
+@dataclass(frozen=True)
+class OutputSink:
+    """One destination of the run's results.
+
+    Attributes:
+        format: Format of the sink, or None for the ``--output-format``
+            format.
+        path: File written, or None for the console.
+    """
+
+    format: str | None = None
+    path: str | None = None
+
+    @property
+    def is_console(self) -> bool:
+        """Whether the sink is the console.
+
+        Returns:
+            bool: True if the sink writes to the terminal.
+        """
+        return self.path is None
+
+
+def parse_output_sink(value: str) -> OutputSink:
+    """Parse one ``--output`` value.
+
+    A value is read as ``FORMAT=PATH`` only when the part before ``=`` is
+    a known format, so paths that contain ``=`` keep working.
+
+    Args:
+        value: ``console``, ``console=FORMAT``, ``FORMAT=PATH`` or a path.
+
+    Returns:
+        OutputSink: Parsed sink.
+
+    Raises:
+        ValueError: If the value is empty, the console format is not a
+            console format or a file sink names no path.
+    """
+    value = value.strip()
+    if not value:
+        raise ValueError("Output must not be empty")
+    prefix, sep, rest = value.partition("=")
+    name = prefix.strip().lower()
+    if name == CONSOLE_SINK:
+        fmt = rest.strip().lower() or None
+        if sep and fmt not in {f.value for f in OutputFormat}:
+            supported = ", ".join(f.value for f in OutputFormat)
+            raise ValueError(
+                f"Unsupported console format: {rest.strip()}. "
+                f"Supported: {supported}",
+            )
+        return OutputSink(format=fmt)
+    if sep and name in FILE_FORMATS:
+        if not rest.strip():
+            raise ValueError(f"Output '{value}' names no file; use {name}=PATH")
+        return OutputSink(format=name, path=rest.strip())
+    return OutputSink(path=value)
+
+
+def console_format(sinks: Iterable[OutputSink]) -> str | None:
+    """Return the console format chosen with ``console=FORMAT``.
+
+    Args:
+        sinks: Parsed sinks.
+
+    Returns:
+        str | None: Console format, or None if no console sink names one.
+
+    Raises:
+        ValueError: If console sinks name different formats.
+    """
+    formats = {sink.format for sink in sinks if sink.is_console and sink.format}
+    if len(formats) > 1:
+        raise ValueError(
+            f"Conflicting console formats: {', '.join(sorted(formats))}",
+        )
+    return formats.pop() if formats else None
@@ -0,0 +1,186 @@
+"""SonarQube generic issue reports.
+
+Writes the Generic Issue Import format of SonarQube 10.3 and later, read
+with the ``sonar.externalIssuesReportPaths`` analysis parameter, so issues
+of tools SonarQube has no analyzer for show up in its dashboards. Each
+``tool:code`` pair becomes one rule with the tool as its engine; the
+software quality, clean code attribute and issue type follow the kind of
+tool (security scanners report vulnerabilities, type checkers and test
+runners bugs, the rest code smells), and lintro severities set the impact.
+Estimated fix minutes (see effort) become ``effortMinutes``.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Mapping, Sequence
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.enums.tool_type import ToolType
+from lintro.plugins.registry import ToolRegistry
+from lintro.utils.effort import issue_code, issue_effort_minutes
+
+if TYPE_CHECKING:
+    from lintro.models.core.tool_result import ToolResult
+
+# Rule of issues reported without a code
+UNNAMED_RULE: str = "issue"
+
+# Most severe first
+_SEVERITY_ORDER: list[SeverityLevel] = [
+    SeverityLevel.ERROR,
+    SeverityLevel.WARNING,
+    SeverityLevel.INFO,
+]
+_IMPACT_SEVERITIES: dict[SeverityLevel, str] = {
+    SeverityLevel.ERROR: "HIGH",
+    SeverityLevel.WARNING: "MEDIUM",
+    SeverityLevel.INFO: "LOW",
+}
+_LEGACY_SEVERITIES: dict[SeverityLevel, str] = {
+    SeverityLevel.ERROR: "MAJOR",
+    SeverityLevel.WARNING: "MINOR",
+    SeverityLevel.INFO: "INFO",
+}
+
+
+def _file_path(file: str) -> str:
+    """Return the path SonarQube matches an issue's file by.
+
+    Args:
+        file: Path as reported by the tool.
+
+    Returns:
+        str: Forward-slash path, relative to the working directory (the
+        analysis base directory) when the file is inside it.
+    """
+    path = Path(file)
+    if path.is_absolute():
+        try:
+            path = path.relative_to(Path.cwd())
+        except ValueError:
+            return str(path)
+    return path.as_posix()
+
+
+def _rule_kind(tool_name: str) -> tuple[str, str, str]:
+    """Classify a tool's rules for SonarQube.
+
+    Args:
+        tool_name: Name of the tool.
+
+    Returns:
+        tuple[str, str, str]: Software quality, clean code attribute and
+        issue type. Tools without a definition, such as ingested SARIF
+        drivers, count as maintainability linters.
+    """
+    definition = ToolRegistry.get_definitions().get(tool_name.lower())
+    tool_type = definition.tool_type if definition else ToolType.LINTER
+    if tool_type & ToolType.SECURITY:
+        return "SECURITY", "TRUSTWORTHY", "VULNERABILITY"
+    if tool_type & (ToolType.TYPE_CHECKER | ToolType.TEST_RUNNER):
+        return "RELIABILITY", "LOGICAL", "BUG"
+    if tool_type == ToolType.FORMATTER:
+        return "MAINTAINABILITY", "FORMATTED", "CODE_SMELL"
+    return "MAINTAINABILITY", "CONVENTIONAL", "CODE_SMELL"
+
+
+def build_sonar_report(
+    all_results: Sequence[ToolResult],
+    effort_overrides: Mapping[str, int] | None = None,
+) -> dict[str, Any]:
+    """Build a SonarQube generic issue report from the results of a run.
+
+    Issues without a file are left out; SonarQube only imports issues on
+    files of the analyzed project.
+
+    Args:
+        all_results: Results of every tool; skipped tools are left out.
+        effort_overrides: Configured ``output.effort`` minutes.
+
+    Returns:
+        dict[str, Any]: Report with ``rules`` and ``issues``.
+    """
+    rules: dict[str, dict[str, Any]] = {}
+    issues: list[dict[str, Any]] = []
+    for tool_result in all_results:
+        if tool_result.skipped:
+            continue
+        quality, attribute, issue_type = _rule_kind(tool_result.name)
+        for issue in tool_result.issues or []:
+            if not issue.file:
+                continue
+            severity = issue.get_severity()
+            code = issue_code(issue) or UNNAMED_RULE
+            rule_id = f"{tool_result.name}:{code}"
+            rule = rules.setdefault(
+                rule_id,
+                {
+                    "id": rule_id,
+                    "name": f"{tool_result.name} {code}",
+                    "engineId": tool_result.name,
+                    "cleanCodeAttribute": attribute,
+                    "type": issue_type,
+                    "severity": severity,
+                    "impacts": [{"softwareQuality": quality}],
+                },
+            )
+            # A rule's impact is the worst severity it was reported with
+            rule["severity"] = min(
+                rule["severity"],
+                severity,
+                key=_SEVERITY_ORDER.index,
+            )
+
+            location: dict[str, Any] = {
+                "message": issue.message or code,
+                "filePath": _file_path(issue.file),
+            }
+            if issue.line > 0:
+                text_range: dict[str, int] = {"startLine": issue.line}
+                end_line = getattr(issue, "end_line", None)
+                if isinstance(end_line, int) and end_line >= issue.line:
+                    text_range["endLine"] = end_line
+                location["textRange"] = text_range
+            issues.append(
+                {
+                    "ruleId": rule_id,
+                    "effortMinutes": issue_effort_minutes(
+                        tool_result.name,
+                        issue,
+                        effort_overrides,
+                    ),
+                    "primaryLocation": location,
+                },
+            )
+    for rule in rules.values():
+        severity = rule["severity"]
+        rule["severity"] = _LEGACY_SEVERITIES[severity]
+        rule["impacts"][0]["severity"] = _IMPACT_SEVERITIES[severity]
+    return {"rules": list(rules.values()), "issues": issues}
+
+
+def write_sonar_file(
+    output_path: str,
+    all_results: Sequence[ToolResult],
+    effort_overrides: Mapping[str, int] | None = None,
+) -> None:
+    """Write the results of a run as a SonarQube generic issue report.
+
+    Args:
+        output_path: Path of the report.
+        all_results: Results of every tool.
+        effort_overrides: Configured ``output.effort`` minutes.
+    """
+    output_file = Path(output_path)
+    output_file.parent.mkdir(parents=True, exist_ok=True)
+    output_file.write_text(
+        json.dumps(
+            build_sonar_report(all_results, effort_overrides),
+            indent=2,
+            ensure_ascii=False,
+        ),
+        encoding="utf-8",
+    )
@@ -2,17 +2,75 @@
 
 Functions for filtering paths, walking directories, and excluding files based on
 patterns. Uses pathspec library for gitignore-style pattern matching.
+
+Directories are pruned before they are entered: dependency, build and cache
+trees in ``DEFAULT_PRUNE_DIRS`` (configurable as ``execution.prune_dirs``) and
+directories matched by an exclude pattern are never listed. The remaining tree
+is scanned by a thread pool, which keeps discovery fast on monorepos with
+millions of files.
+
+Inside a git work tree, files ignored by ``.gitignore`` files and
+``.git/info/exclude`` are skipped as git would (``execution.gitignore``).
+Symbolic links are skipped, followed, or followed only when they point inside
+the repository (``execution.symlinks``).
 """
 
 import fnmatch
 import os
+import re
+from collections.abc import Callable, Sequence
+from concurrent.futures import FIRST_COMPLETED, Future, ThreadPoolExecutor, wait
+from dataclasses import dataclass
 from functools import lru_cache
-from typing import TYPE_CHECKING
 
 import pathspec
 
-if TYPE_CHECKING:
-    pass
+# Directory names never descended into during discovery (fnmatch patterns)
+DEFAULT_PRUNE_DIRS: tuple[str, ...] = (
+    ".git",
+    ".hg",
+    ".svn",
+    ".jj",
+    "node_modules",
+    "bower_components",
+    ".venv",
+    "target",
+    "build",
+    "dist",
+    "__pycache__",
+    ".tox",
+    ".nox",
+    ".mypy_cache",
+    ".pytest_cache",
+    ".ruff_cache",
+    ".gradle",
+    ".terraform",
+    ".next",
+)
+
+# Threads listing directories; listing is I/O-bound and releases the GIL
+DISCOVERY_WORKERS: int = min(32, (os.cpu_count() or 1) + 4)
+
+# How discovery treats symbolic links (execution.symlinks)
+SYMLINK_POLICIES: tuple[str, ...] = ("skip", "follow", "follow-within-repo")
+DEFAULT_SYMLINK_POLICY: str = "follow-within-repo"
+
+# Bytes read from each end of an extensionless file to find a modeline
+_SNIFF_BYTES: int = 4096
+_EMACS_MODELINE = re.compile(r"-\*-\s*(?:.*?mode:\s*)?([\w+-]+)\s*(?:;.*?)?-\*-")
+_VIM_MODELINE = re.compile(r"\b(?:vim?|ex):.*?\b(?:ft|filetype)=([\w+-]+)")
+# Modeline language names and the interpreter they stand for
+_MODELINE_LANGUAGES: dict[str, str] = {
+    "python": "python",
+    "sh": "sh",
+    "shell-script": "sh",
+    "bash": "bash",
+    "zsh": "zsh",
+    "ksh": "ksh",
+    "ruby": "ruby",
+    "javascript": "node",
+    "js": "node",
+}
 
 
 @lru_cache(maxsize=32)
@@ -80,6 +138,147 @@ def should_exclude_path(
     return False
 
 
+def shebang_interpreter(path: str) -> str | None:
+    """Return the interpreter named by a file's shebang line.
+
+    Args:
+        path: File to inspect.
+
+    Returns:
+        str | None: Interpreter name, such as ``bash`` for ``#!/bin/bash`` or
+        ``#!/usr/bin/env -S bash -e``, or None without a shebang.
+    """
+    try:
+        with open(path, "rb") as f:
+            first_line = f.readline(256)
+    except OSError:
+        return None
+    if not first_line.startswith(b"#!"):
+        return None
+    words = first_line[2:].decode("utf-8", errors="replace").split()
+    if words and os.path.basename(words[0]) == "env":
+        # Skip env's options and VAR=value assignments
+        words = [w for w in words[1:] if not w.startswith("-") and "=" not in w]
+    return os.path.basename(words[0]) if words else None
+
+
+def modeline_interpreter(path: str) -> str | None:
+    """Return the interpreter named by an editor modeline.
+
+    Emacs modelines (``# -*- mode: python -*-``) are read from the first two
+    lines, Vim modelines (``# vim: set ft=sh:``) from the first and last five.
+
+    Args:
+        path: File to inspect.
+
+    Returns:
+        str | None: Interpreter of the language, such as ``python`` or
+        ``bash``, or None without a known modeline or for binary files.
+    """
+    try:
+        with open(path, "rb") as f:
+            head = f.read(_SNIFF_BYTES)
+            f.seek(0, os.SEEK_END)
+            size = f.tell()
+            f.seek(max(len(head), size - _SNIFF_BYTES))
+            tail = f.read()
+    except OSError:
+        return None
+    if b"\0" in head:
+        return None
+    head_lines = head.decode("utf-8", errors="replace").splitlines()
+    tail_lines = (head + tail).decode("utf-8", errors="replace").splitlines()
+    for line in head_lines[:2]:
+        match = _EMACS_MODELINE.search(line)
+        if match:
+            return _MODELINE_LANGUAGES.get(match.group(1).lower())
+    for line in [*head_lines[:5], *tail_lines[-5:]]:
+        match = _VIM_MODELINE.search(line)
+        if match:
+            return _MODELINE_LANGUAGES.get(match.group(1).lower())
+    return None
+
+
+def _interpreter_family(interpreter: str) -> str:
+    """Drop the version from an interpreter name.
+
+    Args:
+        interpreter: Interpreter name, such as ``python3.12``.
+
+    Returns:
+        str: Name without trailing version, such as ``python``.
+    """
+    return interpreter.rstrip("0123456789.") or interpreter
+
+
+@lru_cache(maxsize=4096)
+def _detect_interpreter(path: str, mtime_ns: int, size: int) -> str | None:
+    """Detect a script's interpreter (cached per file version).
+
+    Args:
+        path: File to inspect.
+        mtime_ns: Modification time of the file, part of the cache key.
+        size: Size of the file, part of the cache key.
+
+    Returns:
+        str | None: Interpreter from the shebang, else from a modeline.
+    """
+    return shebang_interpreter(path) or modeline_interpreter(path)
+
+
+def script_interpreter(path: str) -> str | None:
+    """Detect the interpreter of a script without an extension.
+
+    The shebang line wins; files without one are recognized by an editor
+    modeline. Each file is read once per version, however many tools ask.
+
+    Args:
+        path: File to inspect.
+
+    Returns:
+        str | None: Interpreter, such as ``bash`` or ``python3``, or None if
+        the file does not say.
+    """
+    try:
+        stat = os.stat(path)
+    except OSError:
+        return None
+    return _detect_interpreter(path, stat.st_mtime_ns, stat.st_size)
+
+
+def matches_tool_files(
+    path: str,
+    file_patterns: list[str],
+    shebang_interpreters: Sequence[str],
+) -> bool:
+    """Check whether a file is one a tool operates on.
+
+    Args:
+        path: File to check.
+        file_patterns: Glob patterns matched against the file name.
+        shebang_interpreters: Interpreters that select extensionless files.
+            Versions are ignored, so ``python`` selects ``python3.12``
+            scripts.
+
+    Returns:
+        bool: True if the name matches a pattern, or the file has no
+        extension and its shebang or modeline names one of the
+        interpreters.
+    """
+    filename = os.path.basename(path)
+    if any(fnmatch.fnmatch(filename, pattern) for pattern in file_patterns):
+        return True
+    if not shebang_interpreters or os.path.splitext(filename)[1]:
+        return False
+    interpreter = script_interpreter(path)
+    if interpreter is None:
+        return False
+    return (
+        interpreter in shebang_interpreters
+        or _interpreter_family(interpreter) in shebang_interpreters
+    )
+
+
 def walk_files_with_excludes(
     paths: list[str],
     file_patterns: list[str],
@@ -87,10 +286,16 @@ def walk_files_with_excludes(
     include_venv: bool = False,
     incremental: bool = False,
     tool_name: str | None = None,
+    shebang_interpreters: Sequence[str] = (),
+    prune_dirs: Sequence[str] = DEFAULT_PRUNE_DIRS,
+    gitignore: bool = True,
+    symlinks: str = DEFAULT_SYMLINK_POLICY,
 ) -> list[str]:
     """Return files under ``paths`` matching patterns and not excluded.
 
-    Uses pathspec for gitignore-style exclude pattern matching.
+    Uses pathspec for gitignore-style exclude pattern matching. Directories
+    named in ``prune_dirs`` or matched by an exclude pattern are not entered;
+    the paths given are always searched, even when git ignores them.
 
     Args:
         paths: Files or directories to search.
@@ -99,6 +304,15 @@ def walk_files_with_excludes(
         include_venv: Include virtual environment directories when True.
         incremental: If True, only return files changed since last run.
         tool_name: Tool name for incremental cache (required if incremental=True).
+        shebang_interpreters: Also include files without an extension whose
+            shebang names one of these interpreters, such as ``bash``.
+        prune_dirs: Directory names (fnmatch patterns) not to descend into.
+            Virtual environment names are still entered with include_venv.
+        gitignore: Skip files git ignores when a path is inside a git work
+            tree.
+        symlinks: ``skip`` linked files and directories, ``follow`` them, or
+            ``follow-within-repo`` only when they resolve inside the git work
+            tree (or the searched path outside one).
 
     Returns:
         Sorted file paths matching include filters and not excluded.
@@ -112,44 +326,62 @@ def walk_files_with_excludes(
     for path in paths:
         if os.path.isfile(path):
             # Single file - check if the filename matches any file pattern
-            filename = os.path.basename(path)
-            for pattern in file_patterns:
-                if fnmatch.fnmatch(filename, pattern):
-                    abs_path = os.path.abspath(path)
-                    if not _should_exclude_with_spec(abs_path, exclude_spec):
-                        all_files.append(abs_path)
-                    break
+            if matches_tool_files(path, file_patterns, shebang_interpreters):
+                abs_path = os.path.abspath(path)
+                if not _should_exclude_with_spec(abs_path, exclude_spec):
+                    all_files.append(abs_path)
         elif os.path.isdir(path):
-            # Directory - walk through it
-            for root, dirs, files in os.walk(path):
-                # Filter out virtual environment directories unless include_venv is True
-                if not include_venv:
-                    dirs[:] = [d for d in dirs if not _is_venv_directory(d)]
-
-                # Check each file against the patterns
-                for file in files:
-                    file_path: str = os.path.join(root, file)
-                    abs_file_path: str = os.path.abspath(file_path)
-
-                    # Check if file matches any file pattern
-                    matches_pattern: bool = False
-                    for pattern in file_patterns:
-                        if fnmatch.fnmatch(file, pattern):
-                            matches_pattern = True
-                            break
-
-                    if matches_pattern and not _should_exclude_with_spec(
-                        abs_file_path,
-                        exclude_spec,
-                    ):
-                        all_files.append(abs_file_path)
+            repo_root = find_repo_root(path)
+            found = _walk_parallel(
+                _Visit(
+                    path=path,
+                    rules=(
+                        GitignoreRules.for_directory(path)
+                        if gitignore and repo_root
+                        else None
+                    ),
+                    chain=frozenset(
+                        [os.path.realpath(path)] if symlinks != "skip" else [],
+                    ),
+                ),
+                descend=lambda dir_path, name: not _should_prune(
+                    dir_path,
+                    name,
+                    prune_dirs,
+                    include_venv,
+                    exclude_spec,
+                ),
+                keep=lambda file_path: not _should_exclude_with_spec(
+                    os.path.abspath(file_path),
+                    exclude_spec,
+                )
+                and matches_tool_files(
+                    file_path,
+                    file_patterns,
+                    shebang_interpreters,
+                ),
+                links=_LinkPolicy(
+                    mode=symlinks,
+                    boundary=os.path.realpath(repo_root or path),
+                ),
+            )
+            all_files.extend(os.path.abspath(file_path) for file_path in found)
 
     # Apply incremental filtering if enabled
     if incremental and tool_name:
         from lintro.utils.file_cache import ToolCache
+        from lintro.utils.logger_setup import log_event
 
         cache = ToolCache.load(tool_name)
         changed_files = cache.get_changed_files(all_files)
+        unchanged = len(all_files) - len(changed_files)
+        log_event(
+            "incremental_cache",
+            f"{tool_name}: {unchanged} of {len(all_files)} files unchanged",
+            tool=tool_name,
+            files=len(all_files),
+            cache_hits=unchanged,
+        )
 
         # Update cache with all discovered files for next run
         cache.update(all_files)
@@ -160,6 +392,308 @@ def walk_files_with_excludes(
     return sorted(all_files)
 
 
+def _should_prune(
+    dir_path: str,
+    name: str,
+    prune_dirs: Sequence[str],
+    include_venv: bool,
+    exclude_spec: pathspec.PathSpec | None,
+) -> bool:
+    """Check whether discovery should skip a directory entirely.
+
+    Args:
+        dir_path: Path of the directory.
+        name: Directory name.
+        prune_dirs: Directory names (fnmatch patterns) never entered.
+        include_venv: Enter virtual environment directories.
+        exclude_spec: Pre-compiled exclude patterns, or None.
+
+    Returns:
+        bool: True if nothing below the directory can be discovered.
+    """
+    if _is_venv_directory(name):
+        if not include_venv:
+            return True
+    elif any(fnmatch.fnmatch(name, pattern) for pattern in prune_dirs):
+        return True
+    return _should_exclude_with_spec(os.path.abspath(dir_path), exclude_spec)
+
+
+def find_repo_root(path: str) -> str | None:
+    """Return the root of the git work tree containing a path.
+
+    Args:
+        path: File or directory.
+
+    Returns:
+        str | None: Directory holding ``.git``, or None outside a work tree.
+    """
+    current = os.path.abspath(path)
+    if not os.path.isdir(current):
+        current = os.path.dirname(current)
+    while True:
+        if os.path.exists(os.path.join(current, ".git")):
+            return current
+        parent = os.path.dirname(current)
+        if parent == current:
+            return None
+        current = parent
+
+
+@lru_cache(maxsize=1024)
+def _load_ignore_file(path: str, mtime_ns: int) -> pathspec.PathSpec | None:
+    """Compile an ignore file (cached per file version).
+
+    Args:
+        path: ``.gitignore`` or ``.git/info/exclude`` file.
+        mtime_ns: Modification time of the file, part of the cache key.
+
+    Returns:
+        pathspec.PathSpec | None: Patterns of the file, or None if it is
+        unreadable or holds no pattern.
+    """
+    try:
+        with open(path, encoding="utf-8", errors="replace") as f:
+            lines = f.read().splitlines()
+    except OSError:
+        return None
+    spec = pathspec.GitIgnoreSpec.from_lines(lines)
+    return spec if spec.patterns else None
+
+
+def _ignore_file(path: str) -> pathspec.PathSpec | None:
+    """Return the patterns of an ignore file, if it exists.
+
+    Args:
+        path: Ignore file.
+
+    Returns:
+        pathspec.PathSpec | None: Patterns, or None without the file.
+    """
+    try:
+        mtime_ns = os.stat(path).st_mtime_ns
+    except OSError:
+        return None
+    return _load_ignore_file(path, mtime_ns)
+
+
+@dataclass(frozen=True)
+class GitignoreRules:
+    """Git ignore files that apply inside one directory.
+
+    Attributes:
+        specs: Directory and patterns of each ignore file, outermost first;
+            ``.git/info/exclude`` comes before every ``.gitignore``.
+    """
+
+    specs: tuple[tuple[str, pathspec.PathSpec], ...] = ()
+
+    @classmethod
+    def for_directory(cls, directory: str) -> "GitignoreRules":
+        """Collect the ignore files from the work tree root to a directory.
+
+        Args:
+            directory: Directory inside a git work tree.
+
+        Returns:
+            GitignoreRules: Rules of the directory, including its own
+            ``.gitignore``; empty outside a work tree.
+        """
+        root = find_repo_root(directory)
+        if root is None:
+            return cls()
+        specs: list[tuple[str, pathspec.PathSpec]] = []
+        exclude = _ignore_file(os.path.join(root, ".git", "info", "exclude"))
+        if exclude is not None:
+            specs.append((root, exclude))
+        current = root
+        rel = os.path.relpath(os.path.abspath(directory), root)
+        for part in ["", *([] if rel == "." else rel.split(os.sep))]:
+            current = os.path.join(current, part)
+            spec = _ignore_file(os.path.join(current, ".gitignore"))
+            if spec is not None:
+                specs.append((current, spec))
+        return cls(tuple(specs))
+
+    def entering(self, directory: str) -> "GitignoreRules":
+        """Return the rules inside a subdirectory.
+
+        Args:
+            directory: Subdirectory being entered.
+
+        Returns:
+            GitignoreRules: These rules plus the subdirectory's
+            ``.gitignore``.
+        """
+        spec = _ignore_file(os.path.join(directory, ".gitignore"))
+        if spec is None:
+            return self
+        return GitignoreRules((*self.specs, (directory, spec)))
+
+    def ignores(self, path: str, is_dir: bool) -> bool:
+        """Check whether git ignores a path.
+
+        The deepest ignore file with a matching pattern decides, and within
+        a file the last matching pattern, so ``!`` patterns re-include.
+
+        Args:
+            path: File or directory below the rules' directory.
+            is_dir: Whether the path is a directory.
+
+        Returns:
+            bool: True if the path is ignored.
+        """
+        for base, spec in reversed(self.specs):
+            rel = os.path.relpath(path, base).replace(os.sep, "/")
+            if rel.startswith("../"):
+                continue
+            include = spec.check_file(f"{rel}/" if is_dir else rel).include
+            if include is not None:
+                return bool(include)
+        return False
+
+
+@dataclass(frozen=True)
+class _LinkPolicy:
+    """How a walk treats symbolic links.
+
+    Attributes:
+        mode: One of SYMLINK_POLICIES.
+        boundary: Real path links must resolve inside with
+            ``follow-within-repo``.
+    """
+
+    mode: str
+    boundary: str
+
+    def allows(self, path: str) -> bool:
+        """Check whether a link is followed.
+
+        Args:
+            path: Symbolic link.
+
+        Returns:
+            bool: True if the link is followed; broken links never are.
+        """
+        if self.mode == "skip" or not os.path.exists(path):
+            return False
+        if self.mode == "follow":
+            return True
+        target = os.path.realpath(path)
+        return target == self.boundary or target.startswith(self.boundary + os.sep)
+
+
+@dataclass(frozen=True)
+class _Visit:
+    """A directory waiting to be listed.
+
+    Attributes:
+        path: Directory to list.
+        rules: Git ignore rules inside it, or None when they are not used.
+        chain: Real paths of the directories above it when links are
+            followed; a link back to one of them is not entered, so cycles
+            end.
+    """
+
+    path: str
+    rules: GitignoreRules | None
+    chain: frozenset[str]
+
+
+def _scan_directory(
+    visit: _Visit,
+    descend: Callable[[str, str], bool],
+    keep: Callable[[str], bool],
+    links: _LinkPolicy,
+) -> tuple[list[str], list[_Visit]]:
+    """List one directory.
+
+    Symbolic links are handled by the link policy; unreadable directories
+    are skipped.
+
+    Args:
+        visit: Directory to list.
+        descend: Whether to enter a subdirectory, given its path and name.
+        keep: Whether to return a file, given its path.
+        links: How symbolic links are treated.
+
+    Returns:
+        tuple[list[str], list[_Visit]]: Kept files and subdirectories to
+        enter.
+    """
+    files: list[str] = []
+    subdirs: list[_Visit] = []
+    rules = visit.rules
+    try:
+        with os.scandir(visit.path) as entries:
+            for entry in entries:
+                try:
+                    is_link = entry.is_symlink()
+                    is_dir = entry.is_dir()
+                except OSError:
+                    continue
+                if is_link and not links.allows(entry.path):
+                    continue
+                if rules is not None and rules.ignores(entry.path, is_dir):
+                    continue
+                if not is_dir:
+                    if keep(entry.path):
+                        files.append(entry.path)
+                    continue
+                if not descend(entry.path, entry.name):
+                    continue
+                chain = visit.chain
+                if links.mode != "skip":
+                    target = os.path.realpath(entry.path)
+                    if target in chain:
+                        continue
+                    chain = chain | {target}
+                subdirs.append(
+                    _Visit(
+                        path=entry.path,
+                        rules=rules.entering(entry.path) if rules else None,
+                        chain=chain,
+                    ),
+                )
+    except OSError:
+        pass
+    return files, subdirs
+
+
+def _walk_parallel(
+    root: _Visit,
+    descend: Callable[[str, str], bool],
+    keep: Callable[[str], bool],
+    links: _LinkPolicy,
+) -> list[str]:
+    """Walk a directory tree, listing directories concurrently.
+
+    Args:
+        root: Directory to walk.
+        descend: Whether to enter a subdirectory, given its path and name.
+        keep: Whether to return a file, given its path.
+        links: How symbolic links are treated.
+
+    Returns:
+        list[str]: Kept files below root, in no particular order.
+    """
+    found: list[str] = []
+    with ThreadPoolExecutor(max_workers=DISCOVERY_WORKERS) as pool:
+        pending: set[Future[tuple[list[str], list[_Visit]]]] = {
+            pool.submit(_scan_directory, root, descend, keep, links),
+        }
+        while pending:
+            done, pending = wait(pending, return_when=FIRST_COMPLETED)
+            for future in done:
+                files, subdirs = future.result()
+                found.extend(files)
+                pending.update(
+                    pool.submit(_scan_directory, subdir, descend, keep, links)
+                    for subdir in subdirs
+                )
+    return found
+
+
 def _should_exclude_with_spec(
     path: str,
     spec: pathspec.PathSpec | None,
@@ -1,12 +1,22 @@
 """Path utilities for Lintro.
 
 Small helpers to normalize paths for display consistency and path safety validation.
+Paths printed by tools, which on Windows mix ``\\`` and ``/`` and vary the case
+of drive letters, are mapped to project-relative POSIX paths.
 """
 
+import posixpath
+import re
 from pathlib import Path
 
 from loguru import logger
 
+# Drive letter at the start of a Windows path, as in "c:/src" or "C:"
+_WINDOWS_DRIVE: re.Pattern[str] = re.compile(r"^([A-Za-z]):(?=/|$)")
+
+# Prefix of Windows extended-length paths (\\?\C:\src), after slash conversion
+_EXTENDED_PREFIX: str = "//?/"
+
 
 def validate_safe_path(path: str | Path, base_dir: Path | None = None) -> bool:
     """Validate that a path doesn't escape the project boundaries.
@@ -172,3 +182,60 @@ def normalize_file_path_for_display(file_path: str) -> str:
     except (OSError, ValueError):
         # If path normalization fails, return the original path
         return file_path
+
+
+def to_posix_path(path: str) -> str:
+    """Write a path with forward slashes and an upper-case drive letter.
+
+    ``c:\\repo\\src/app.py``, ``C:/repo/src/app.py`` and the extended-length
+    form ``\\\\?\\C:\\repo\\src\\app.py`` all become ``C:/repo/src/app.py``.
+
+    Args:
+        path: Path as a tool printed it.
+
+    Returns:
+        str: The same path with forward slashes.
+    """
+    posix = path.replace("\\", "/")
+    if posix.startswith(_EXTENDED_PREFIX):
+        posix = posix[len(_EXTENDED_PREFIX) :]
+    return _WINDOWS_DRIVE.sub(lambda m: f"{m.group(1).upper()}:", posix)
+
+
+def relative_to_root(path: str, root: str | Path | None = None) -> str:
+    """Map a path printed by a tool to a path relative to the project root.
+
+    Absolute paths inside the root become relative POSIX paths such as
+    ``src/app.py``; relative paths are already relative to the root and only
+    get forward slashes. Absolute paths outside the root stay absolute. Below
+    a Windows root the comparison ignores case, as the file system does.
+
+    Args:
+        path: Path as a tool printed it. If empty, returns as is.
+        root: Absolute project root; defaults to the working directory.
+
+    Returns:
+        str: Normalized path.
+
+    Examples:
+        >>> relative_to_root("c:\\\\repo\\\\src\\\\app.py", "C:\\\\repo")
+        'src/app.py'
+        >>> relative_to_root("./src\\\\app.py", "/repo")
+        'src/app.py'
+    """
+    if not path or not path.strip():
+        return path
+
+    posix = posixpath.normpath(to_posix_path(path))
+    if not posix.startswith("/") and not _WINDOWS_DRIVE.match(posix):
+        return posix
+
+    base = to_posix_path(str(root if root is not None else Path.cwd())).rstrip("/")
+    windows = _WINDOWS_DRIVE.match(base) is not None
+    folded = posix.casefold() if windows else posix
+    folded_base = base.casefold() if windows else base
+    if folded == folded_base:
+        return "."
+    if folded.startswith(f"{folded_base}/"):
+        return posix[len(base) + 1 :]
+    return posix
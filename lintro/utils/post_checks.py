@@ -7,18 +7,25 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from lintro.config.config_loader import get_config
 from lintro.enums.action import Action
 from lintro.enums.group_by import normalize_group_by
 from lintro.enums.output_format import OutputFormat, normalize_output_format
+from lintro.plugins.container_backend import resolve_container_spec
 from lintro.plugins.registry import ToolRegistry
+from lintro.plugins.remote_backend import resolve_remote_spec
 from lintro.tools import tool_manager
 from lintro.utils.config import load_post_checks_config
+from lintro.utils.execution.read_only_guard import flag_writes, format_writes
+from lintro.utils.execution.rule_scoped_fix import fix_selected
 from lintro.utils.output import format_tool_output
 from lintro.utils.unified_config import UnifiedConfigManager
 
 if TYPE_CHECKING:
     from lintro.models.core.tool_result import ToolResult
     from lintro.utils.console import ThreadSafeConsoleLogger
+    from lintro.utils.execution.fix_pipeline import FixTracker
+    from lintro.utils.execution.read_only_guard import ReadOnlyGuard
 
 
 def execute_post_checks(
@@ -29,6 +36,8 @@ def execute_post_checks(
     include_venv: bool,
     group_by: str,
     output_format: str,
+    columns: list[str] | None = None,
+    sort_by: str | None = None,
     verbose: bool,
     raw_output: bool,
     logger: ThreadSafeConsoleLogger,
@@ -36,6 +45,9 @@ def execute_post_checks(
     total_issues: int,
     total_fixed: int,
     total_remaining: int,
+    fix_tracker: FixTracker | None = None,
+    fix_only: dict[str, list[str]] | None = None,
+    read_only_guard: ReadOnlyGuard | None = None,
 ) -> tuple[int, int, int]:
     """Execute post-check tools after primary linting.
 
@@ -46,6 +58,8 @@ def execute_post_checks(
         include_venv: Whether to include virtual environments.
         group_by: How to group results.
         output_format: Output format for results.
+        columns: Columns of the issue tables. None shows every column.
+        sort_by: Order of the issue table rows. None keeps the tool's order.
         verbose: Whether to enable verbose output.
         raw_output: Whether to show raw tool output.
         logger: Logger instance for output.
@@ -53,6 +67,11 @@ def execute_post_checks(
         total_issues: Current total issues count.
         total_fixed: Current total fixed count.
         total_remaining: Current total remaining count.
+        fix_tracker: Tracker that records the files post-check fixers change.
+        fix_only: Rules to fix per tool; tools without rules only report.
+            None fixes everything.
+        read_only_guard: Guard that fails post-checks writing to the
+            workspace during a check.
 
     Returns:
         tuple[int, int, int]: Updated (total_issues, total_fixed, total_remaining)
@@ -135,10 +154,33 @@ def execute_post_checks(
                         p.strip() for p in exclude.split(",")
                     ]
                     tool.set_options(exclude_patterns=exclude_patterns)
+                tool.remote = (
+                    None
+                    if tool.definition.native
+                    else resolve_remote_spec(
+                        tool_name_lower,
+                        get_config(),
+                        writable=action == Action.FIX,
+                    )
+                )
+                tool.container = (
+                    None
+                    if tool.definition.native or tool.remote is not None
+                    else resolve_container_spec(
+                        tool_name_lower,
+                        get_config(),
+                        writable=action == Action.FIX,
+                    )
+                )
 
                 # For check: Black should run in check mode; for fmt: run fix
-                if action == Action.FIX and tool.definition.can_fix:
-                    result = tool.fix(paths=paths, options={})
+                if action == Action.FIX and tool.capabilities().supports_fix:
+                    if fix_only is None:
+                        result = tool.fix(paths=paths, options={})
+                    else:
+                        result = fix_selected(tool, tool_name_lower, paths, fix_only)
+                    if fix_tracker is not None:
+                        fix_tracker.record(tool_name_lower)
                     issues_count = getattr(result, "issues_count", 0)
                     fixed_count = getattr(result, "fixed_issues_count", None)
                     total_fixed += fixed_count if fixed_count is not None else 0
@@ -148,6 +190,14 @@ def execute_post_checks(
                     )
                 else:
                     result = tool.check(paths=paths, options={})
+                    if read_only_guard is not None:
+                        writes = read_only_guard.record(tool_name_lower)
+                        if writes:
+                            logger.console_output(
+                                text=format_writes(writes),
+                                color="red",
+                            )
+                        result = flag_writes(result, writes)
                     issues_count = getattr(result, "issues_count", 0)
                     total_issues += issues_count
 
@@ -161,6 +211,9 @@ def execute_post_checks(
                         output=output or "",
                         output_format=output_fmt_enum.value,
                         issues=issues,
+                        group_by=group_by,
+                        columns=columns,
+                        sort_by=sort_by,
                     )
 
                 if not json_output_mode:
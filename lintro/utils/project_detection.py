@@ -0,0 +1,437 @@
+"""Project inspection for ``lintro init --detect``.
+
+Scans a repository for ecosystem markers (pyproject.toml, Cargo.toml,
+package.json, Dockerfiles, GitHub workflows, ...) to decide which tools
+apply, checks whether each tool is installed, and renders a starter
+configuration enabling the tools that can run.
+"""
+
+from __future__ import annotations
+
+import fnmatch
+import os
+import re
+import shutil
+import tomllib
+from collections.abc import Iterable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+# Directories never scanned for markers
+_SKIP_DIRS: frozenset[str] = frozenset(
+    {
+        ".git",
+        ".hg",
+        ".svn",
+        ".venv",
+        "venv",
+        "node_modules",
+        "target",
+        "dist",
+        "build",
+        "__pycache__",
+    },
+)
+
+# Maximum directory depth scanned below the project root
+MAX_SCAN_DEPTH: int = 4
+
+DEFAULT_LINE_LENGTH: int = 88
+
+
+@dataclass(frozen=True)
+class ProjectMarker:
+    """Files that make a set of tools applicable.
+
+    Attributes:
+        label: Human-readable ecosystem name.
+        patterns: Glob patterns matched against file names, or against the
+            relative path when the pattern contains a slash.
+        tools: Tools enabled when any pattern matches.
+    """
+
+    label: str
+    patterns: tuple[str, ...]
+    tools: tuple[str, ...]
+
+
+PROJECT_MARKERS: tuple[ProjectMarker, ...] = (
+    ProjectMarker(
+        label="Python",
+        patterns=("pyproject.toml", "setup.py", "setup.cfg", "requirements*.txt"),
+        tools=("ruff", "black", "mypy", "bandit"),
+    ),
+    ProjectMarker(label="Python", patterns=("pyproject.toml",), tools=("pyproject",)),
+    ProjectMarker(
+        label="Rust",
+        patterns=("Cargo.toml",),
+        tools=("clippy", "rustfmt", "cargo_audit"),
+    ),
+    ProjectMarker(
+        label="JavaScript",
+        patterns=("package.json",),
+        tools=("package_json", "prettier", "oxlint"),
+    ),
+    ProjectMarker(
+        label="Biome",
+        patterns=("biome.json", "biome.jsonc"),
+        tools=("biome",),
+    ),
+    ProjectMarker(
+        label="ESLint",
+        patterns=(
+            "eslint.config.js",
+            "eslint.config.mjs",
+            "eslint.config.cjs",
+            "eslint.config.ts",
+            "eslint.config.mts",
+            "eslint.config.cts",
+        ),
+        tools=("eslint",),
+    ),
+    ProjectMarker(
+        label="Stylelint",
+        patterns=(
+            ".stylelintrc",
+            ".stylelintrc.*",
+            "stylelint.config.js",
+            "stylelint.config.mjs",
+            "stylelint.config.cjs",
+        ),
+        tools=("stylelint",),
+    ),
+    ProjectMarker(
+        label="Spelling",
+        patterns=("_typos.toml", "typos.toml", ".typos.toml"),
+        tools=("typos",),
+    ),
+    ProjectMarker(
+        label="Prose",
+        patterns=(".vale.ini", "_vale.ini", "vale.ini"),
+        tools=("vale",),
+    ),
+    ProjectMarker(label="TypeScript", patterns=("tsconfig.json",), tools=("tsc",)),
+    ProjectMarker(
+        label="Terraform",
+        patterns=("*.tf",),
+        tools=("terraform_fmt", "tflint"),
+    ),
+    ProjectMarker(label="Go", patterns=("go.mod",), tools=("gofmt", "golangci_lint")),
+    ProjectMarker(
+        label="Protobuf",
+        patterns=("buf.yaml", "buf.work.yaml", "*.proto"),
+        tools=("buf",),
+    ),
+    ProjectMarker(
+        label="C/C++",
+        patterns=(".clang-format", "compile_commands.json", "*.c", "*.cpp", "*.cc"),
+        tools=("clang_format", "clang_tidy"),
+    ),
+    ProjectMarker(
+        label="Ansible",
+        patterns=(
+            ".ansible-lint",
+            ".ansible-lint.yml",
+            ".ansible-lint.yaml",
+            "ansible.cfg",
+            "galaxy.yml",
+        ),
+        tools=("ansible_lint",),
+    ),
+    ProjectMarker(label="Kotlin", patterns=("*.kt", "*.kts"), tools=("ktlint",)),
+    ProjectMarker(
+        label="Lua",
+        patterns=("*.lua", "stylua.toml", ".stylua.toml", ".luacheckrc"),
+        tools=("stylua", "luacheck"),
+    ),
+    ProjectMarker(
+        label="Ruby",
+        patterns=("Gemfile", "gems.rb", ".rubocop.yml", "*.gemspec"),
+        tools=("rubocop",),
+    ),
+    ProjectMarker(
+        label="Docker",
+        patterns=("Dockerfile", "Dockerfile.*", "*.Dockerfile"),
+        tools=("hadolint",),
+    ),
+    ProjectMarker(
+        label="GitHub Actions",
+        patterns=(".github/workflows/*.yml", ".github/workflows/*.yaml"),
+        tools=("actionlint",),
+    ),
+    ProjectMarker(label="Markdown", patterns=("*.md",), tools=("markdownlint",)),
+    ProjectMarker(label="YAML", patterns=("*.yml", "*.yaml"), tools=("yamllint",)),
+    ProjectMarker(label="Shell", patterns=("*.sh", "*.bash"), tools=("shellcheck",)),
+    ProjectMarker(label="TOML", patterns=("*.toml",), tools=("taplo",)),
+)
+
+# Executable probed to decide whether a tool is installed (None: built in)
+TOOL_EXECUTABLES: dict[str, str | None] = {
+    "actionlint": "actionlint",
+    "ansible_lint": "ansible-lint",
+    "bandit": "bandit",
+    "biome": "biome",
+    "black": "black",
+    "buf": "buf",
+    "cargo_audit": "cargo-audit",
+    "clang_format": "clang-format",
+    "clang_tidy": "clang-tidy",
+    "clippy": "cargo-clippy",
+    "eslint": "eslint",
+    "gofmt": "gofmt",
+    "golangci_lint": "golangci-lint",
+    "hadolint": "hadolint",
+    "ktlint": "ktlint",
+    "luacheck": "luacheck",
+    "markdownlint": "markdownlint-cli2",
+    "mypy": "mypy",
+    "oxlint": "oxlint",
+    "package_json": None,
+    "prettier": "prettier",
+    "pyproject": None,
+    "rubocop": "rubocop",
+    "ruff": "ruff",
+    "rustfmt": "rustfmt",
+    "shellcheck": "shellcheck",
+    "stylelint": "stylelint",
+    "stylua": "stylua",
+    "taplo": "taplo",
+    "terraform_fmt": "terraform",
+    "tflint": "tflint",
+    "tsc": "tsc",
+    "typos": "typos",
+    "vale": "vale",
+    "yamllint": "yamllint",
+}
+
+
+@dataclass
+class DetectedTool:
+    """A tool applicable to the project.
+
+    Attributes:
+        name: Tool name.
+        ecosystem: Ecosystem label of the marker that matched.
+        marker: Relative path of the first matching file.
+        installed: Whether the tool can run in this environment.
+    """
+
+    name: str
+    ecosystem: str
+    marker: str
+    installed: bool
+
+
+@dataclass
+class ProjectDetection:
+    """Result of inspecting a project.
+
+    Attributes:
+        tools: Applicable tools, in marker order.
+        line_length: Line length found in existing Python tool config.
+        target_python: Ruff/Black style target derived from requires-python.
+    """
+
+    tools: list[DetectedTool] = field(default_factory=list)
+    line_length: int | None = None
+    target_python: str | None = None
+
+    @property
+    def installed_tools(self) -> list[str]:
+        """Names of applicable tools that are installed.
+
+        Returns:
+            list[str]: Tool names.
+        """
+        return [t.name for t in self.tools if t.installed]
+
+
+def _iter_project_files(root: Path) -> Iterable[str]:
+    """Yield project files as POSIX paths relative to root.
+
+    Args:
+        root: Project directory.
+
+    Yields:
+        str: Relative file paths, skipping vendored and build directories.
+    """
+    for current, dirs, files in os.walk(root):
+        rel_dir = Path(current).relative_to(root)
+        depth = len(rel_dir.parts)
+        dirs[:] = sorted(
+            d
+            for d in dirs
+            if d not in _SKIP_DIRS
+            and (not d.startswith(".") or d == ".github")
+            and depth < MAX_SCAN_DEPTH
+        )
+        for name in sorted(files):
+            yield (rel_dir / name).as_posix()
+
+
+def _matches(rel_path: str, pattern: str) -> bool:
+    """Check whether a relative path matches a marker pattern.
+
+    Args:
+        rel_path: POSIX path relative to the project root.
+        pattern: Marker glob pattern.
+
+    Returns:
+        bool: True if the pattern matches.
+    """
+    if "/" in pattern:
+        return fnmatch.fnmatch(rel_path, pattern)
+    return fnmatch.fnmatch(rel_path.rsplit("/", 1)[-1], pattern)
+
+
+def is_tool_installed(tool_name: str) -> bool:
+    """Check whether a tool's executable is available.
+
+    Args:
+        tool_name: Tool name.
+
+    Returns:
+        bool: True for built-in tools or when the executable is in PATH.
+    """
+    executable = TOOL_EXECUTABLES.get(tool_name, tool_name)
+    return executable is None or shutil.which(executable) is not None
+
+
+def _python_settings(root: Path) -> tuple[int | None, str | None]:
+    """Read line length and Python target from pyproject.toml.
+
+    Args:
+        root: Project directory.
+
+    Returns:
+        tuple[int | None, str | None]: Line length from ``[tool.ruff]`` or
+            ``[tool.black]``, and a ``pyXY`` target from requires-python.
+    """
+    pyproject = root / "pyproject.toml"
+    if not pyproject.is_file():
+        return None, None
+    try:
+        data: dict[str, Any] = tomllib.loads(pyproject.read_text(encoding="utf-8"))
+    except (OSError, tomllib.TOMLDecodeError) as e:
+        logger.debug(f"[project_detection] Could not read {pyproject}: {e}")
+        return None, None
+
+    tool_section = data.get("tool", {})
+    line_length: int | None = None
+    for tool in ("ruff", "black"):
+        value = tool_section.get(tool, {}).get("line-length")
+        if isinstance(value, int):
+            line_length = value
+            break
+
+    target_python: str | None = None
+    requires = data.get("project", {}).get("requires-python")
+    if isinstance(requires, str):
+        match = re.search(r">=?\s*(\d+)\.(\d+)", requires)
+        if match:
+            target_python = f"py{match.group(1)}{match.group(2)}"
+    return line_length, target_python
+
+
+def detect_project(root: Path) -> ProjectDetection:
+    """Inspect a project and determine the applicable tools.
+
+    Args:
+        root: Project directory.
+
+    Returns:
+        ProjectDetection: Applicable tools and Python settings.
+    """
+    files = list(_iter_project_files(root))
+    detected: dict[str, DetectedTool] = {}
+    for marker in PROJECT_MARKERS:
+        if all(tool in detected for tool in marker.tools):
+            continue
+        match = next(
+            (f for f in files for p in marker.patterns if _matches(f, p)),
+            None,
+        )
+        if match is None:
+            continue
+        for tool in marker.tools:
+            if tool not in detected:
+                detected[tool] = DetectedTool(
+                    name=tool,
+                    ecosystem=marker.label,
+                    marker=match,
+                    installed=is_tool_installed(tool),
+                )
+
+    line_length, target_python = _python_settings(root)
+    return ProjectDetection(
+        tools=list(detected.values()),
+        line_length=line_length,
+        target_python=target_python,
+    )
+
+
+def render_detected_config(
+    detection: ProjectDetection,
+    enabled: Iterable[str] | None = None,
+) -> str:
+    """Render a starter .lintro-config.yaml for a detected project.
+
+    Args:
+        detection: Result of detect_project.
+        enabled: Tools to enable, or None for every installed tool.
+
+    Returns:
+        str: YAML configuration with comments.
+    """
+    enabled_set = set(detection.installed_tools if enabled is None else enabled)
+    ecosystems = sorted({t.ecosystem for t in detection.tools})
+
+    lines = [
+        "# Lintro Configuration",
+        "# https://github.com/lgtm-hq/py-lintro",
+        "#",
+        "# Generated by `lintro init --detect`.",
+    ]
+    if ecosystems:
+        lines.append(f"# Detected: {', '.join(ecosystems)}")
+    lines += [
+        "",
+        "enforce:",
+        f"  line_length: {detection.line_length or DEFAULT_LINE_LENGTH}",
+    ]
+    if detection.target_python:
+        lines.append(f'  target_python: "{detection.target_python}"')
+
+    enabled_names = [t.name for t in detection.tools if t.name in enabled_set]
+    lines += ["", "execution:"]
+    if enabled_names:
+        lines.append("  enabled_tools:")
+        lines += [f"    - {name}" for name in enabled_names]
+    lines += ['  tool_order: "priority"', "  fail_fast: false"]
+
+    # An empty enabled_tools runs every tool, so disable the undetected ones
+    undetected: list[str] = []
+    if not enabled_names:
+        from lintro.plugins.registry import ToolRegistry
+
+        detected_names = {t.name for t in detection.tools}
+        undetected = [
+            name for name in ToolRegistry.get_names() if name not in detected_names
+        ]
+
+    if detection.tools or undetected:
+        lines += ["", "tools:"]
+        for tool in detection.tools:
+            lines.append(f"  {tool.name}:")
+            if tool.name in enabled_set:
+                lines.append("    enabled: true")
+            elif not tool.installed:
+                lines.append("    enabled: false  # not installed")
+            else:
+                lines.append("    enabled: false")
+        for name in undetected:
+            lines += [f"  {name}:", "    enabled: false  # not detected"]
+    return "\n".join(lines) + "\n"
@@ -0,0 +1,165 @@
+"""Paragraph-style run summaries for CI logs and PR comments.
+
+``lintro check --summary prose`` replaces the summary tables with a short
+paragraph: how many issues were found, the most common rules, the files with
+the most issues and what to run locally to fix them. The text comes from a
+fixed template, so the same results always produce the same summary.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from collections.abc import Sequence
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.effort import EffortEstimate, format_effort, issue_code, issue_fixable
+
+# Number of rules and files named in the summary
+DEFAULT_TOP_COUNT: int = 3
+
+
+def _plural(count: int, word: str) -> str:
+    """Format a count with a naively pluralized noun.
+
+    Args:
+        count: Number of items.
+        word: Singular noun.
+
+    Returns:
+        str: Text such as ``1 issue`` or ``3 issues``.
+    """
+    return f"{count} {word}" if count == 1 else f"{count} {word}s"
+
+
+def _join(items: Sequence[str]) -> str:
+    """Join items as an English list.
+
+    Args:
+        items: Items to join.
+
+    Returns:
+        str: Text such as ``a``, ``a and b`` or ``a, b and c``.
+    """
+    if len(items) <= 1:
+        return "".join(items)
+    return f"{', '.join(items[:-1])} and {items[-1]}"
+
+
+def _ranked(counts: Counter[str], top: int) -> list[str]:
+    """Format the most frequent entries with their counts.
+
+    Args:
+        counts: Issue counts per entry.
+        top: Number of entries to include.
+
+    Returns:
+        list[str]: Entries such as ``ruff E501 (20)``; ties sort by name.
+    """
+    ranked = sorted(counts.items(), key=lambda item: (-item[1], item[0]))
+    return [f"{name} ({count})" for name, count in ranked[:top]]
+
+
+def build_prose_summary(
+    tool_results: Sequence[object],
+    effort: EffortEstimate | None = None,
+    top: int = DEFAULT_TOP_COUNT,
+) -> str:
+    """Build a paragraph summarizing a check run.
+
+    Args:
+        tool_results: Tool results from the run.
+        effort: Remediation-effort estimate, if one was computed.
+        top: Number of rules and files to name.
+
+    Returns:
+        str: Summary paragraph.
+    """
+    ran = [r for r in tool_results if not getattr(r, "skipped", False)]
+    missing = [
+        str(getattr(r, "name", ""))
+        for r in tool_results
+        if getattr(r, "missing", False)
+    ]
+    crashed = [
+        str(getattr(r, "name", ""))
+        for r in ran
+        if not getattr(r, "success", True) and not getattr(r, "issues_count", 0)
+    ]
+
+    rules: Counter[str] = Counter()
+    files: Counter[str] = Counter()
+    severities: Counter[SeverityLevel] = Counter()
+    issue_tools: list[str] = []
+    fixable_tools: list[str] = []
+    total = 0
+    fixable = 0
+    for result in ran:
+        name = str(getattr(result, "name", ""))
+        total += getattr(result, "issues_count", 0) or 0
+        issues = getattr(result, "issues", None) or []
+        if getattr(result, "issues_count", 0):
+            issue_tools.append(name)
+        for issue in issues:
+            code = issue_code(issue)
+            if code:
+                rules[f"{name} {code}"] += 1
+            file_path = str(getattr(issue, "file", "") or "")
+            if file_path:
+                files[file_path] += 1
+            get_severity = getattr(issue, "get_severity", None)
+            if get_severity:
+                severities[get_severity()] += 1
+            if issue_fixable(issue):
+                fixable += 1
+                if name not in fixable_tools:
+                    fixable_tools.append(name)
+
+    sentences: list[str] = []
+    if total == 0:
+        sentences.append(
+            f"Lintro ran {_plural(len(ran), 'tool')} and found no issues.",
+        )
+    else:
+        breakdown = [
+            _plural(severities[level], level.value.lower())
+            for level in SeverityLevel
+            if severities[level]
+        ]
+        found = f"Lintro found {_plural(total, 'issue')}"
+        if breakdown:
+            found += f" ({', '.join(breakdown)})"
+        if files:
+            found += f" in {_plural(len(files), 'file')}"
+        found += f" from {_join(issue_tools)}"
+        if effort is not None and effort.total_minutes:
+            found += f", an estimated {format_effort(effort.total_minutes)} of work"
+        sentences.append(f"{found}.")
+        if len(rules) > 1:
+            sentences.append(
+                f"The most common rules are {_join(_ranked(rules, top))}.",
+            )
+        elif rules:
+            sentences.append(f"The only rule hit is {_join(_ranked(rules, top))}.")
+        if len(files) > 1:
+            sentences.append(
+                f"The files with the most issues are {_join(_ranked(files, top))}.",
+            )
+    if crashed:
+        sentences.append(
+            f"{_join(crashed)} failed to run; see the tool output for details.",
+        )
+    if missing:
+        verb = "was" if len(missing) == 1 else "were"
+        sentences.append(f"{_join(missing)} {verb} skipped as not installed.")
+
+    if fixable:
+        sentences.append(
+            f"{_plural(fixable, 'issue')} can be fixed automatically with "
+            f"`lintro format --tools {','.join(fixable_tools)}`.",
+        )
+    failing = [*issue_tools, *(name for name in crashed if name not in issue_tools)]
+    if failing:
+        sentences.append(
+            f"To reproduce locally, run `lintro check --tools {','.join(failing)}`.",
+        )
+    return " ".join(sentences)
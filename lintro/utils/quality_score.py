@@ -0,0 +1,384 @@
+"""Quality score and letter grade of a check run.
+
+``lintro score`` condenses a run into one number that can be tracked over
+time. Every issue costs penalty points: its severity weight (error 10,
+warning 3, info 1) times the weight of its rule's category (security 3,
+formatting 0.5, anything else 1). The penalty per thousand non-blank lines of
+code gives the score::
+
+    score = 100 / (1 + penalty_per_kloc / half_density)
+
+so a clean tree scores 100 and ``half_density`` penalty points per thousand
+lines (50 by default) score 50. Scores map to grades A (90), B (80), C (70)
+and D (60), and F below. The same model scores each directory, so a large
+clean package does not hide a small neglected one. Every weight and
+threshold can be changed under ``score:`` in the Lintro config.
+"""
+
+from __future__ import annotations
+
+import fnmatch
+import os
+from collections.abc import Iterable, Mapping, Sequence
+from dataclasses import asdict, dataclass, field
+from typing import Any
+
+from lintro.config.score_config import ScoreConfig
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.issue_stats import _directory
+from lintro.utils.path_filtering import walk_files_with_excludes
+from lintro.utils.rule_selection import RulePattern, parse_rule_pattern
+
+DEFAULT_SEVERITY_WEIGHTS: dict[SeverityLevel, float] = {
+    SeverityLevel.ERROR: 10.0,
+    SeverityLevel.WARNING: 3.0,
+    SeverityLevel.INFO: 1.0,
+}
+
+# Rule categories by tool:rule pattern; the first matching category applies
+DEFAULT_CATEGORIES: dict[str, tuple[str, ...]] = {
+    "security": (
+        "bandit:*",
+        "cargo_audit:*",
+        "cargo_deny:*",
+        "cargo_geiger:*",
+        "checkov:*",
+        "gitleaks:*",
+        "semgrep:*",
+        "ruff:S*",
+    ),
+    "formatting": (
+        "black:*",
+        "clang_format:*",
+        "gofmt:*",
+        "oxfmt:*",
+        "prettier:*",
+        "rustfmt:*",
+        "shfmt:*",
+        "stylua:*",
+        "terraform_fmt:*",
+    ),
+}
+
+DEFAULT_CATEGORY_WEIGHTS: dict[str, float] = {"security": 3.0, "formatting": 0.5}
+
+# Penalty points per thousand lines that halve the score
+DEFAULT_HALF_DENSITY: float = 50.0
+
+DEFAULT_GRADES: dict[str, float] = {"A": 90.0, "B": 80.0, "C": 70.0, "D": 60.0}
+FAIL_GRADE: str = "F"
+
+# Lines a directory is assumed to have at least, so one issue in a tiny
+# directory does not sink its grade
+MIN_LINES: int = 100
+
+# Files whose lines are generated rather than written
+GENERATED_FILES: tuple[str, ...] = (
+    "*.lock",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "*.min.js",
+    "*.min.css",
+    "*.map",
+    "*.svg",
+)
+
+
+@dataclass(frozen=True)
+class ScoreModel:
+    """Weights and grade thresholds used to score a run.
+
+    Built with :meth:`from_config`.
+
+    Attributes:
+        severity_weights: Penalty points per issue by severity.
+        categories: Rule patterns per category, in matching order.
+        category_weights: Penalty multiplier per category.
+        half_density: Penalty points per thousand lines that score 50.
+        grades: Letter grades with their lowest score, best first.
+    """
+
+    severity_weights: Mapping[SeverityLevel, float]
+    categories: tuple[tuple[str, tuple[RulePattern, ...]], ...]
+    category_weights: Mapping[str, float]
+    half_density: float
+    grades: tuple[tuple[str, float], ...]
+
+    @classmethod
+    def from_config(cls, config: ScoreConfig | None = None) -> ScoreModel:
+        """Build the model from the built-in values and ``score:`` settings.
+
+        Args:
+            config: Configured score settings, or None for the built-ins.
+
+        Returns:
+            ScoreModel: The model.
+
+        Raises:
+            ValueError: If a category pattern names no rule.
+        """
+        config = config or ScoreConfig()
+        severity_weights = dict(DEFAULT_SEVERITY_WEIGHTS)
+        for level, weight in config.severity_weights.items():
+            severity_weights[SeverityLevel(level.upper())] = weight
+        raw_categories: dict[str, Sequence[str]] = {
+            **DEFAULT_CATEGORIES,
+            **config.categories,
+        }
+        categories = tuple(
+            (name, tuple(parse_rule_pattern(p) for p in patterns))
+            for name, patterns in raw_categories.items()
+        )
+        grades = config.grades or DEFAULT_GRADES
+        return cls(
+            severity_weights=severity_weights,
+            categories=categories,
+            category_weights={**DEFAULT_CATEGORY_WEIGHTS, **config.category_weights},
+            half_density=config.half_density or DEFAULT_HALF_DENSITY,
+            grades=tuple(sorted(grades.items(), key=lambda item: -item[1])),
+        )
+
+    def category(self, tool: str, code: str) -> str | None:
+        """Find the category of a rule.
+
+        Args:
+            tool: Tool that reported the issue.
+            code: Rule code of the issue.
+
+        Returns:
+            str | None: First category with a matching pattern, or None.
+        """
+        for name, patterns in self.categories:
+            if any(pattern.matches(tool, code) for pattern in patterns):
+                return name
+        return None
+
+    def penalty(self, record: Mapping[str, Any]) -> float:
+        """Weigh one issue.
+
+        Args:
+            record: Issue record with ``tool``, ``code`` and ``severity``.
+
+        Returns:
+            float: Severity weight times category weight.
+        """
+        severity = record.get("severity") or SeverityLevel.WARNING
+        category = self.category(str(record.get("tool", "")), record.get("code", ""))
+        weight = self.category_weights.get(category, 1.0) if category else 1.0
+        return self.severity_weights.get(severity, 1.0) * weight
+
+    def score(self, penalty: float, lines: int) -> float:
+        """Turn a penalty into a score.
+
+        Args:
+            penalty: Penalty points of the issues.
+            lines: Non-blank lines of code they were found in.
+
+        Returns:
+            float: Score from 0 to 100, rounded to one decimal.
+        """
+        density = penalty * 1000 / max(lines, MIN_LINES)
+        return round(100 / (1 + density / self.half_density), 1)
+
+    def grade(self, score: float) -> str:
+        """Find the letter grade of a score.
+
+        Args:
+            score: Score from 0 to 100.
+
+        Returns:
+            str: Best grade whose threshold the score reaches, else F.
+        """
+        for letter, threshold in self.grades:
+            if score >= threshold:
+                return letter
+        return FAIL_GRADE
+
+    def grade_rank(self, grade: str) -> int:
+        """Order a grade among the model's grades.
+
+        Args:
+            grade: Letter grade, in any case.
+
+        Returns:
+            int: 0 for the best grade; higher is worse.
+
+        Raises:
+            ValueError: If the model has no such grade.
+        """
+        letters = [letter.upper() for letter, _ in self.grades] + [FAIL_GRADE]
+        try:
+            return letters.index(grade.upper())
+        except ValueError:
+            raise ValueError(
+                f"Unknown grade '{grade}' (grades: {', '.join(letters)})",
+            ) from None
+
+
+@dataclass
+class QualityScore:
+    """Score of a run or of one directory.
+
+    Attributes:
+        path: Directory with a trailing slash, or ``.`` for the whole run.
+        lines: Non-blank lines of code.
+        issues: Issues found.
+        penalty: Weighted issues.
+        score: Score from 0 to 100.
+        grade: Letter grade of the score.
+    """
+
+    path: str
+    lines: int
+    issues: int
+    penalty: float
+    score: float
+    grade: str
+
+
+@dataclass
+class ScoreReport:
+    """Scores of a run.
+
+    Attributes:
+        total: Score of everything checked.
+        directories: Scores per directory, worst first.
+        categories: Penalty per rule category; ``other`` for rules in none.
+    """
+
+    total: QualityScore
+    directories: list[QualityScore] = field(default_factory=list)
+    categories: dict[str, float] = field(default_factory=dict)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Describe the scores as JSON-serializable data.
+
+        Returns:
+            dict[str, Any]: Total, per-directory and per-category figures.
+        """
+        return asdict(self)
+
+
+def _is_text(path: str) -> bool:
+    """Check whether a file looks like text.
+
+    Args:
+        path: File path.
+
+    Returns:
+        bool: False for binary or unreadable files.
+    """
+    try:
+        with open(path, "rb") as handle:
+            return b"\0" not in handle.read(8192)
+    except OSError:
+        return False
+
+
+def count_lines(
+    paths: Sequence[str],
+    exclude_patterns: Sequence[str] = (),
+) -> dict[str, int]:
+    """Count the non-blank lines of the text files under some paths.
+
+    Git-ignored, binary and generated files such as lock files are skipped.
+
+    Args:
+        paths: Files or directories to count.
+        exclude_patterns: Gitignore-style patterns of files to skip.
+
+    Returns:
+        dict[str, int]: Lines per POSIX path relative to the working
+        directory.
+    """
+    counts: dict[str, int] = {}
+    for path in walk_files_with_excludes(list(paths), ["*"], list(exclude_patterns)):
+        name = os.path.basename(path)
+        if any(fnmatch.fnmatch(name, p) for p in GENERATED_FILES):
+            continue
+        if not _is_text(path):
+            continue
+        try:
+            with open(path, encoding="utf-8", errors="replace") as handle:
+                lines = sum(1 for line in handle if line.strip())
+        except OSError:
+            continue
+        counts[os.path.relpath(path).replace(os.sep, "/")] = lines
+    return counts
+
+
+def _score(
+    model: ScoreModel,
+    path: str,
+    lines: int,
+    penalties: Sequence[float],
+) -> QualityScore:
+    """Score one part of a run.
+
+    Args:
+        model: Weights and grade thresholds.
+        path: Directory, or ``.`` for the whole run.
+        lines: Non-blank lines of code.
+        penalties: Penalty of each issue.
+
+    Returns:
+        QualityScore: The score and grade.
+    """
+    penalty = float(sum(penalties))
+    score = model.score(penalty, lines)
+    return QualityScore(
+        path=path,
+        lines=lines,
+        issues=len(penalties),
+        penalty=round(penalty, 2),
+        score=score,
+        grade=model.grade(score),
+    )
+
+
+def compute_score(
+    records: Iterable[Mapping[str, Any]],
+    line_counts: Mapping[str, int],
+    model: ScoreModel | None = None,
+    depth: int = 1,
+) -> ScoreReport:
+    """Score a run overall and per directory.
+
+    Args:
+        records: Issue records from :func:`result_records` or
+            :func:`saved_records`.
+        line_counts: Non-blank lines per file from :func:`count_lines`.
+        model: Weights and grade thresholds; None uses the built-ins.
+        depth: Directory levels the per-directory scores break down to.
+
+    Returns:
+        ScoreReport: Total, per-directory and per-category results.
+    """
+    model = model or ScoreModel.from_config()
+    penalties: dict[str, list[float]] = {}
+    categories: dict[str, float] = {}
+    all_penalties: list[float] = []
+    for record in records:
+        penalty = model.penalty(record)
+        all_penalties.append(penalty)
+        directory = _directory(str(record.get("path", "")), depth)
+        penalties.setdefault(directory, []).append(penalty)
+        category = model.category(str(record.get("tool", "")), record.get("code", ""))
+        key = category or "other"
+        categories[key] = round(categories.get(key, 0.0) + penalty, 2)
+
+    lines: dict[str, int] = {}
+    for path, count in line_counts.items():
+        directory = _directory(path, depth)
+        lines[directory] = lines.get(directory, 0) + count
+
+    directories = [
+        _score(model, directory, lines.get(directory, 0), penalties.get(directory, []))
+        for directory in sorted(set(lines) | set(penalties))
+    ]
+    directories.sort(key=lambda row: (row.score, row.path))
+    return ScoreReport(
+        total=_score(model, ".", sum(line_counts.values()), all_penalties),
+        directories=directories,
+        categories=dict(sorted(categories.items(), key=lambda item: -item[1])),
+    )
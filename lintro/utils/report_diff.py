@@ -0,0 +1,187 @@
+"""Comparing the issues of two runs by fingerprint.
+
+Used by ``lintro diff``: each issue of the new run is new, or unchanged when
+the old run reported an issue with the same fingerprint; old issues the new
+run no longer reports are fixed. Fingerprints ignore line numbers, so code
+moving around does not turn old issues into new ones, which is what lets a
+CI gate fail only on issues a change introduced.
+
+Reports written before fingerprints were recorded are compared on the tool,
+rule, path and message (digits masked) instead, for both sides.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import re
+from collections.abc import Iterable, Mapping, Sequence
+from dataclasses import dataclass, field, replace
+from typing import Any
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code
+from lintro.utils.fingerprint import fingerprint_issues, normalize_issue_path
+
+_DIGITS = re.compile(r"\d+")
+
+
+@dataclass(frozen=True)
+class DiffIssue:
+    """An issue as compared between runs.
+
+    Attributes:
+        tool: Tool that reported the issue.
+        file: File path relative to the working directory.
+        line: Line of the issue, 0 if unknown.
+        code: Rule code.
+        message: Issue message.
+        fingerprint: Fingerprint identifying the issue across runs, empty
+            for issues of reports that predate fingerprints.
+    """
+
+    tool: str
+    file: str
+    line: int
+    code: str
+    message: str
+    fingerprint: str = ""
+
+    @property
+    def fallback_key(self) -> str:
+        """Return the identity used when fingerprints are missing.
+
+        Returns:
+            str: Hash of the tool, rule, path and message.
+        """
+        message = _DIGITS.sub("#", self.message)
+        key = "\0".join([self.tool, self.code, self.file, message])
+        return hashlib.sha256(key.encode("utf-8")).hexdigest()[:32]
+
+
+@dataclass
+class ReportDiff:
+    """Issues of the new run classified against the old run.
+
+    Attributes:
+        new: Issues only the new run reports.
+        fixed: Issues only the old run reports.
+        unchanged: Issues both runs report, as reported by the new run.
+        fingerprinted: Whether both sides had fingerprints.
+    """
+
+    new: list[DiffIssue] = field(default_factory=list)
+    fixed: list[DiffIssue] = field(default_factory=list)
+    unchanged: list[DiffIssue] = field(default_factory=list)
+    fingerprinted: bool = True
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the diff as JSON-serializable data.
+
+        Returns:
+            dict[str, Any]: Counts and issues per classification.
+        """
+        return {
+            "summary": {
+                "new": len(self.new),
+                "fixed": len(self.fixed),
+                "unchanged": len(self.unchanged),
+            },
+            "fingerprinted": self.fingerprinted,
+            "new": [vars(issue) for issue in self.new],
+            "fixed": [vars(issue) for issue in self.fixed],
+        }
+
+
+def report_issues(report: Mapping[str, Any]) -> list[DiffIssue]:
+    """Read the issues of a JSON report.
+
+    Args:
+        report: Report written with ``--output-format json``.
+
+    Returns:
+        list[DiffIssue]: Issues of every tool in the report.
+
+    Raises:
+        ValueError: If the data is not a Lintro JSON report.
+    """
+    results = report.get("results")
+    if not isinstance(results, list):
+        raise ValueError("Not a Lintro JSON report: missing 'results'")
+    issues: list[DiffIssue] = []
+    for result in results:
+        tool = str(result.get("tool") or "").lower()
+        for issue in result.get("issues") or []:
+            issues.append(
+                DiffIssue(
+                    tool=tool,
+                    file=normalize_issue_path(str(issue.get("file") or "")),
+                    line=int(issue.get("line") or 0),
+                    code=str(issue.get("code") or ""),
+                    message=str(issue.get("message") or ""),
+                    fingerprint=str(issue.get("fingerprint") or ""),
+                ),
+            )
+    return issues
+
+
+def result_issues(results: Iterable[ToolResult]) -> list[DiffIssue]:
+    """Read the issues of tool results with their fingerprints.
+
+    Args:
+        results: Results of a check run.
+
+    Returns:
+        list[DiffIssue]: Issues of every tool that ran.
+    """
+    issues: list[DiffIssue] = []
+    for result in results:
+        if result.skipped or not result.issues:
+            continue
+        tool = result.name.lower()
+        fingerprints = fingerprint_issues(tool, result.issues)
+        for issue, fingerprint in zip(result.issues, fingerprints, strict=True):
+            issues.append(
+                DiffIssue(
+                    tool=tool,
+                    file=normalize_issue_path(getattr(issue, "file", "") or ""),
+                    line=getattr(issue, "line", 0) or 0,
+                    code=issue_code(issue),
+                    message=getattr(issue, "message", "") or "",
+                    fingerprint=fingerprint,
+                ),
+            )
+    return issues
+
+
+def diff_issues(old: Sequence[DiffIssue], new: Sequence[DiffIssue]) -> ReportDiff:
+    """Classify the issues of a new run against an old run.
+
+    Args:
+        old: Issues of the old run.
+        new: Issues of the new run.
+
+    Returns:
+        ReportDiff: New, fixed and unchanged issues.
+    """
+    fingerprinted = all(issue.fingerprint for issue in (*old, *new))
+    if not fingerprinted:
+        old = [replace(issue, fingerprint=issue.fallback_key) for issue in old]
+        new = [replace(issue, fingerprint=issue.fallback_key) for issue in new]
+
+    # Fingerprints repeat when fallback keys are used, so count them
+    remaining: dict[str, int] = {}
+    for issue in old:
+        remaining[issue.fingerprint] = remaining.get(issue.fingerprint, 0) + 1
+
+    diff = ReportDiff(fingerprinted=fingerprinted)
+    for issue in new:
+        if remaining.get(issue.fingerprint, 0) > 0:
+            remaining[issue.fingerprint] -= 1
+            diff.unchanged.append(issue)
+        else:
+            diff.new.append(issue)
+    for issue in old:
+        if remaining.get(issue.fingerprint, 0) > 0:
+            remaining[issue.fingerprint] -= 1
+            diff.fixed.append(issue)
+    return diff
@@ -0,0 +1,127 @@
+"""Environment and tool-version metadata recorded in machine-readable reports.
+
+JSON and SARIF reports carry the lintro and Python versions, the git commit,
+a hash of the effective configuration and, per tool, its version, duration
+and extra arguments, so a run can be reproduced and two runs that disagree,
+such as a local run and a CI run, can be told apart.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import platform
+import sys
+from collections.abc import Sequence
+from functools import lru_cache
+from typing import TYPE_CHECKING, Any
+
+from lintro import __version__
+from lintro.utils.environment.collectors import detect_ci_environment
+from lintro.utils.timestamps import utc_timestamp
+from lintro.utils.vcs import detect_vcs
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+    from lintro.models.core.tool_result import ToolResult
+
+
+def config_hash(lintro_config: LintroConfig) -> str:
+    """Hash the effective configuration of a run.
+
+    The hash covers the parsed settings, after profiles and defaults, not
+    the config file's text, so formatting changes do not alter it.
+
+    Args:
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        str: Hex SHA-256 digest.
+    """
+    data = lintro_config.model_dump(mode="json", exclude={"config_path"})
+    encoded = json.dumps(data, sort_keys=True, default=str).encode("utf-8")
+    return hashlib.sha256(encoded).hexdigest()
+
+
+@lru_cache(maxsize=None)
+def tool_version(name: str) -> str | None:
+    """Detect the installed version of a tool once per process.
+
+    Args:
+        name: Tool name.
+
+    Returns:
+        str | None: Installed version, or None for native tools, unknown
+        tools and versions that cannot be determined.
+    """
+    from lintro.plugins.execution_preparation import detect_tool_version
+    from lintro.plugins.registry import ToolRegistry
+
+    definition = ToolRegistry.get_definitions().get(name.lower())
+    if definition is None:
+        return None
+    return detect_tool_version(definition)
+
+
+def _runs_on_host(name: str, lintro_config: LintroConfig) -> bool:
+    """Return whether a tool runs on this machine.
+
+    Args:
+        name: Tool name.
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        bool: False for tools run in a container or on the remote host,
+        whose versions the host cannot tell.
+    """
+    execution = lintro_config.execution
+    remote_tools = {t.lower() for t in execution.remote.tools}
+    if execution.remote.host and name.lower() in remote_tools:
+        return False
+    return execution.backend == "host"
+
+
+def build_report_metadata(
+    results: Sequence[ToolResult],
+    lintro_config: LintroConfig | None = None,
+) -> dict[str, Any]:
+    """Describe the environment and tools of the run behind a report.
+
+    Args:
+        results: Tool results of the run.
+        lintro_config: Configuration of the run. None loads it.
+
+    Returns:
+        dict[str, Any]: Versions, commit, CI job, config hash and the
+        version, duration and extra arguments of each tool.
+    """
+    if lintro_config is None:
+        from lintro.config.config_loader import get_config
+
+        lintro_config = get_config()
+    ci = detect_ci_environment()
+    tools: dict[str, dict[str, Any]] = {}
+    for result in results:
+        ran = not result.skipped
+        tools[result.name] = {
+            "version": (
+                tool_version(result.name)
+                if ran and _runs_on_host(result.name, lintro_config)
+                else None
+            ),
+            "duration_ms": result.duration_ms,
+            "extra_args": list(result.extra_args),
+        }
+    return {
+        "lintro_version": __version__,
+        "python_version": platform.python_version(),
+        "platform": platform.platform(),
+        "generated_at": utc_timestamp(),
+        "command": sys.argv,
+        "commit": detect_vcs().head_commit(),
+        "ci": {"name": ci.name, **ci.details} if ci else None,
+        "config_hash": config_hash(lintro_config),
+        "profile": lintro_config.active_profile,
+        "backend": lintro_config.execution.backend,
+        "tools": tools,
+    }
@@ -0,0 +1,293 @@
+"""Signing report files with in-toto attestations.
+
+``lintro check --output report.json --sign-report key.pem`` writes an
+in-toto Statement next to the report. Its subject is the report's SHA-256
+digest and its predicate records how the run was made (lintro version,
+command line, commit, CI job and per-tool outcome). The statement is wrapped
+in a DSSE envelope signed with the given private key, so a later CI stage
+can check that neither the report nor the run metadata was changed.
+
+Signing needs the optional ``cryptography`` package (``pip install
+lintro[sign]``). Ed25519, ECDSA (P-256/P-384) and RSA PEM keys are
+supported; an encrypted key is unlocked with ``LINTRO_SIGNING_KEY_PASSWORD``.
+"""
+
+from __future__ import annotations
+
+import base64
+import hashlib
+import json
+import os
+import sys
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from lintro import __version__
+from lintro.utils.environment.collectors import detect_ci_environment
+from lintro.utils.timestamps import utc_timestamp
+from lintro.utils.vcs import detect_vcs
+
+if TYPE_CHECKING:
+    from lintro.models.core.tool_result import ToolResult
+
+STATEMENT_TYPE: str = "https://in-toto.io/Statement/v1"
+PREDICATE_TYPE: str = "https://github.com/lgtm-hq/py-lintro/attestation/run/v1"
+PAYLOAD_TYPE: str = "application/vnd.in-toto+json"
+ATTESTATION_SUFFIX: str = ".intoto.json"
+PASSWORD_ENV: str = "LINTRO_SIGNING_KEY_PASSWORD"
+
+
+def _crypto() -> Any:
+    """Import the pieces of ``cryptography`` used for signing.
+
+    Returns:
+        Any: The ``cryptography.hazmat.primitives`` package.
+
+    Raises:
+        RuntimeError: If cryptography is not installed.
+    """
+    try:
+        # Submodules are imported so they are reachable as attributes
+        import cryptography.hazmat.primitives.asymmetric.ec  # noqa: F401
+        import cryptography.hazmat.primitives.asymmetric.ed25519  # noqa: F401
+        import cryptography.hazmat.primitives.asymmetric.padding  # noqa: F401
+        import cryptography.hazmat.primitives.asymmetric.rsa  # noqa: F401
+        import cryptography.hazmat.primitives.hashes  # noqa: F401
+        import cryptography.hazmat.primitives.serialization  # noqa: F401
+        from cryptography.hazmat import primitives
+    except ImportError as e:
+        raise RuntimeError(
+            "Signing reports requires cryptography; "
+            "install it with: pip install 'lintro[sign]'",
+        ) from e
+    return primitives
+
+
+def pae(payload_type: str, payload: bytes) -> bytes:
+    """Build the DSSE pre-authentication encoding that gets signed.
+
+    Args:
+        payload_type: Envelope payload type.
+        payload: Serialized statement.
+
+    Returns:
+        bytes: ``DSSEv1 <len> <type> <len> <payload>``.
+    """
+    kind = payload_type.encode("utf-8")
+    return b"DSSEv1 %d %b %d %b" % (len(kind), kind, len(payload), payload)
+
+
+def build_run_metadata(
+    *,
+    action: str,
+    results: list[ToolResult],
+    exit_code: int,
+) -> dict[str, Any]:
+    """Describe the run that produced a report.
+
+    Args:
+        action: Action that was run.
+        results: Tool results of the run.
+        exit_code: Exit code of the run.
+
+    Returns:
+        dict[str, Any]: Predicate of the attestation.
+    """
+    ci = detect_ci_environment()
+    return {
+        "lintro_version": __version__,
+        "action": action,
+        "exit_code": exit_code,
+        "finished_at": utc_timestamp(),
+        "command": sys.argv,
+        "commit": detect_vcs().head_commit(),
+        "ci": {"name": ci.name, **ci.details} if ci else None,
+        "tools": [
+            {
+                "name": result.name,
+                "success": result.success,
+                "issues_count": result.issues_count,
+                "skipped": result.skipped,
+            }
+            for result in results
+        ],
+    }
+
+
+def _load_private_key(key_path: str) -> Any:
+    """Load a PEM private key.
+
+    Args:
+        key_path: Path to the PEM file.
+
+    Returns:
+        Any: Loaded private key.
+
+    Raises:
+        ValueError: If the key cannot be read or its type is unsupported.
+    """
+    primitives = _crypto()
+    password = os.environ.get(PASSWORD_ENV)
+    try:
+        key = primitives.serialization.load_pem_private_key(
+            Path(key_path).read_bytes(),
+            password=password.encode("utf-8") if password else None,
+        )
+    except TypeError as e:
+        # Raised for a missing or unexpected password
+        raise ValueError(f"Cannot load signing key {key_path}: {e}") from e
+    asymmetric = primitives.asymmetric
+    supported = (
+        asymmetric.ed25519.Ed25519PrivateKey,
+        asymmetric.ec.EllipticCurvePrivateKey,
+        asymmetric.rsa.RSAPrivateKey,
+    )
+    if not isinstance(key, supported):
+        raise ValueError(
+            f"Unsupported signing key type {type(key).__name__}; "
+            "use an Ed25519, ECDSA or RSA key",
+        )
+    return key
+
+
+def _sign(key: Any, data: bytes) -> bytes:
+    """Sign data with the algorithm matching the key type.
+
+    Args:
+        key: Ed25519, ECDSA or RSA private key.
+        data: Bytes to sign.
+
+    Returns:
+        bytes: Signature.
+    """
+    primitives = _crypto()
+    asymmetric = primitives.asymmetric
+    if isinstance(key, asymmetric.ed25519.Ed25519PrivateKey):
+        return bytes(key.sign(data))
+    if isinstance(key, asymmetric.ec.EllipticCurvePrivateKey):
+        return bytes(key.sign(data, asymmetric.ec.ECDSA(primitives.hashes.SHA256())))
+    return bytes(
+        key.sign(data, asymmetric.padding.PKCS1v15(), primitives.hashes.SHA256()),
+    )
+
+
+def _key_id(public_key: Any) -> str:
+    """Identify a key by the SHA-256 of its DER public key.
+
+    Args:
+        public_key: Public key.
+
+    Returns:
+        str: Hex digest.
+    """
+    serialization = _crypto().serialization
+    der = public_key.public_bytes(
+        serialization.Encoding.DER,
+        serialization.PublicFormat.SubjectPublicKeyInfo,
+    )
+    return hashlib.sha256(der).hexdigest()
+
+
+def sign_report(
+    report_path: str,
+    key_path: str,
+    predicate: dict[str, Any],
+) -> str:
+    """Write a signed attestation for a report file.
+
+    Args:
+        report_path: Report to attest.
+        key_path: PEM private key to sign with.
+        predicate: Run metadata from :func:`build_run_metadata`.
+
+    Returns:
+        str: Path of the written attestation.
+    """
+    key = _load_private_key(key_path)
+    report = Path(report_path)
+    statement = {
+        "_type": STATEMENT_TYPE,
+        "subject": [
+            {
+                "name": report.name,
+                "digest": {"sha256": hashlib.sha256(report.read_bytes()).hexdigest()},
+            },
+        ],
+        "predicateType": PREDICATE_TYPE,
+        "predicate": predicate,
+    }
+    payload = json.dumps(statement, sort_keys=True).encode("utf-8")
+    envelope = {
+        "payloadType": PAYLOAD_TYPE,
+        "payload": base64.b64encode(payload).decode("ascii"),
+        "signatures": [
+            {
+                "keyid": _key_id(key.public_key()),
+                "sig": base64.b64encode(
+                    _sign(key, pae(PAYLOAD_TYPE, payload)),
+                ).decode("ascii"),
+            },
+        ],
+    }
+    attestation = f"{report_path}{ATTESTATION_SUFFIX}"
+    Path(attestation).write_text(json.dumps(envelope, indent=2), encoding="utf-8")
+    return attestation
+
+
+def verify_attestation(
+    report_path: str,
+    attestation_path: str,
+    public_key_path: str,
+) -> dict[str, Any]:
+    """Check an attestation against a report and a public key.
+
+    Args:
+        report_path: Report that was attested.
+        attestation_path: Attestation written by :func:`sign_report`.
+        public_key_path: PEM public key matching the signing key.
+
+    Returns:
+        dict[str, Any]: Verified in-toto statement.
+
+    Raises:
+        ValueError: If the signature or the report digest does not match.
+    """
+    primitives = _crypto()
+    from cryptography.exceptions import InvalidSignature
+
+    asymmetric = primitives.asymmetric
+    public_key = primitives.serialization.load_pem_public_key(
+        Path(public_key_path).read_bytes(),
+    )
+    envelope = json.loads(Path(attestation_path).read_text(encoding="utf-8"))
+    payload = base64.b64decode(envelope["payload"])
+    signed = pae(envelope["payloadType"], payload)
+    key_id = _key_id(public_key)
+    signatures = [s for s in envelope["signatures"] if s.get("keyid") == key_id]
+    if not signatures:
+        raise ValueError("Attestation is not signed by the given key")
+    sig = base64.b64decode(signatures[0]["sig"])
+    try:
+        if isinstance(public_key, asymmetric.ed25519.Ed25519PublicKey):
+            public_key.verify(sig, signed)
+        elif isinstance(public_key, asymmetric.ec.EllipticCurvePublicKey):
+            public_key.verify(
+                sig,
+                signed,
+                asymmetric.ec.ECDSA(primitives.hashes.SHA256()),
+            )
+        else:
+            public_key.verify(
+                sig,
+                signed,
+                asymmetric.padding.PKCS1v15(),
+                primitives.hashes.SHA256(),
+            )
+    except InvalidSignature as e:
+        raise ValueError("Attestation signature is invalid") from e
+
+    statement: dict[str, Any] = json.loads(payload)
+    digest = hashlib.sha256(Path(report_path).read_bytes()).hexdigest()
+    if statement["subject"][0]["digest"]["sha256"] != digest:
+        raise ValueError(f"{report_path} does not match the attested digest")
+    return statement
@@ -0,0 +1,316 @@
+"""Uploading report files to a central lint-results service.
+
+``lintro report push REPORT --endpoint URL`` sends a report gzip-compressed
+and in chunks, so large reports survive flaky CI networks:
+
+1. ``POST {endpoint}/uploads`` with the JSON body ``{"filename", "size",
+   "sha256", "content_encoding": "gzip", "chunk_size", "metadata"}`` creates
+   an upload and answers ``{"upload_id": ...}``. ``size`` and ``sha256``
+   describe the compressed bytes.
+2. ``PUT {endpoint}/uploads/{upload_id}`` sends each chunk with
+   ``Content-Range: bytes START-END/SIZE`` and answers ``{"received": N}``,
+   the number of bytes the service holds.
+3. ``POST {endpoint}/uploads/{upload_id}/complete`` asks the service to check
+   the digest and answers ``{"report_id": ..., "url": ...}``.
+
+An interrupted upload resumes: its ID is kept in ``.lintro/uploads.json``
+and ``GET {endpoint}/uploads/{upload_id}`` tells how many bytes the service
+already has. Requests carry ``Authorization: Bearer <token>`` and are retried
+with exponential backoff on network errors, 408, 429 and 5xx responses,
+honouring ``Retry-After``.
+"""
+
+from __future__ import annotations
+
+import gzip
+import hashlib
+import json
+import os
+from dataclasses import dataclass
+from pathlib import Path
+from time import sleep
+from typing import Any
+
+from lintro import __version__
+from lintro.exceptions.errors import LintroError
+from lintro.utils.environment.collectors import detect_ci_environment
+from lintro.utils.vcs import detect_vcs
+
+TOKEN_ENV: str = "LINTRO_REPORT_TOKEN"
+DEFAULT_CHUNK_SIZE: int = 1024 * 1024
+DEFAULT_STATE_FILE: str = ".lintro/uploads.json"
+MAX_ATTEMPTS: int = 5
+BACKOFF_SECONDS: float = 1.0
+MAX_BACKOFF_SECONDS: float = 30.0
+RETRY_STATUS: frozenset[int] = frozenset({408, 429, 500, 502, 503, 504})
+
+
+class ReportUploadError(LintroError):
+    """Raised when a report cannot be uploaded."""
+
+
+@dataclass(frozen=True)
+class UploadResult:
+    """Outcome of a finished upload.
+
+    Attributes:
+        report_id: Identifier the service gave the report.
+        url: Where the service shows the report, if it says.
+        resumed_from: Compressed bytes already held from an earlier attempt.
+        chunks: Chunks sent by this attempt.
+    """
+
+    report_id: str
+    url: str | None
+    resumed_from: int
+    chunks: int
+
+
+def compress_report(path: str) -> bytes:
+    """Read and gzip a report.
+
+    The gzip header carries no timestamp, so the same report always gives
+    the same bytes and an interrupted upload can be resumed.
+
+    Args:
+        path: Report file.
+
+    Returns:
+        bytes: Compressed report.
+    """
+    return gzip.compress(Path(path).read_bytes(), mtime=0)
+
+
+def upload_metadata() -> dict[str, Any]:
+    """Describe where the report comes from.
+
+    Returns:
+        dict[str, Any]: Lintro version, git commit and CI job, if any.
+    """
+    ci = detect_ci_environment()
+    return {
+        "lintro_version": __version__,
+        "commit": detect_vcs().head_commit(),
+        "ci": {"name": ci.name, **ci.details} if ci else None,
+    }
+
+
+def _backoff(attempt: int, retry_after: str | None) -> float:
+    """Compute how long to wait before the next attempt.
+
+    Args:
+        attempt: Attempt that just failed, starting at 1.
+        retry_after: ``Retry-After`` header in seconds, if sent.
+
+    Returns:
+        float: Seconds to wait.
+    """
+    if retry_after:
+        try:
+            return min(float(retry_after), MAX_BACKOFF_SECONDS)
+        except ValueError:
+            # HTTP dates are not worth parsing for a retry hint
+            pass
+    return float(min(BACKOFF_SECONDS * 2 ** (attempt - 1), MAX_BACKOFF_SECONDS))
+
+
+def _call(
+    method: str,
+    url: str,
+    headers: dict[str, str],
+    timeout: float,
+    *,
+    missing_ok: bool = False,
+    **kwargs: Any,
+) -> dict[str, Any] | None:
+    """Send one protocol request, retrying transient failures.
+
+    Args:
+        method: HTTP method.
+        url: Request URL.
+        headers: Request headers.
+        timeout: Seconds to wait for each response.
+        missing_ok: Return None instead of failing on 404.
+        **kwargs: Body arguments passed to ``httpx.request``.
+
+    Returns:
+        dict[str, Any] | None: Decoded JSON reply; None for a tolerated 404.
+
+    Raises:
+        ReportUploadError: If the service rejects the request, keeps failing
+            after the last attempt, or does not answer with a JSON object.
+    """
+    import httpx
+
+    failure = ""
+    for attempt in range(1, MAX_ATTEMPTS + 1):
+        retry_after: str | None = None
+        try:
+            response = httpx.request(
+                method,
+                url,
+                headers=headers,
+                timeout=timeout,
+                **kwargs,
+            )
+        except httpx.TransportError as e:
+            failure = str(e) or type(e).__name__
+        else:
+            if response.status_code == 404 and missing_ok:
+                return None
+            if response.status_code not in RETRY_STATUS:
+                break
+            failure = f"HTTP {response.status_code}"
+            retry_after = response.headers.get("Retry-After")
+        if attempt < MAX_ATTEMPTS:
+            sleep(_backoff(attempt, retry_after))
+    else:
+        raise ReportUploadError(
+            f"{method} {url} failed after {MAX_ATTEMPTS} attempts: {failure}",
+        )
+
+    if response.status_code >= 400:
+        raise ReportUploadError(
+            f"{method} {url} was rejected with HTTP {response.status_code}: "
+            f"{response.text.strip()[:200]}",
+        )
+    try:
+        data = response.json()
+    except ValueError as e:
+        raise ReportUploadError(f"{method} {url} did not answer with JSON") from e
+    if not isinstance(data, dict):
+        raise ReportUploadError(f"{method} {url} did not answer with an object")
+    return data
+
+
+def _load_state(path: str) -> dict[str, str]:
+    """Read the IDs of unfinished uploads.
+
+    Args:
+        path: State file.
+
+    Returns:
+        dict[str, str]: Upload ID per endpoint and digest; empty if the file
+        is missing or unreadable.
+    """
+    try:
+        state = json.loads(Path(path).read_text(encoding="utf-8"))
+    except (OSError, ValueError):
+        return {}
+    return state if isinstance(state, dict) else {}
+
+
+def _save_state(path: str, state: dict[str, str]) -> None:
+    """Write the IDs of unfinished uploads.
+
+    Args:
+        path: State file; created with its directory if missing.
+        state: Upload ID per endpoint and digest.
+    """
+    Path(path).parent.mkdir(parents=True, exist_ok=True)
+    Path(path).write_text(json.dumps(state, indent=2) + "\n", encoding="utf-8")
+
+
+def push_report(
+    report: str,
+    endpoint: str,
+    *,
+    token: str | None = None,
+    chunk_size: int = DEFAULT_CHUNK_SIZE,
+    state_file: str = DEFAULT_STATE_FILE,
+    timeout: float = 30.0,
+) -> UploadResult:
+    """Upload a report, resuming an earlier attempt if there was one.
+
+    Args:
+        report: Report file.
+        endpoint: Base URL of the results service.
+        token: API token sent as a bearer token, if any.
+        chunk_size: Compressed bytes per chunk.
+        state_file: File keeping the IDs of unfinished uploads.
+        timeout: Seconds to wait for each response.
+
+    Returns:
+        UploadResult: The stored report and how the upload went.
+
+    Raises:
+        ReportUploadError: If the upload fails.
+    """
+    base = endpoint.rstrip("/")
+    payload = compress_report(report)
+    digest = hashlib.sha256(payload).hexdigest()
+    headers = {"User-Agent": f"lintro/{__version__}"}
+    if token:
+        headers["Authorization"] = f"Bearer {token}"
+
+    key = f"{base} {digest}"
+    state = _load_state(state_file)
+    upload_id = state.get(key)
+    received = 0
+    if upload_id:
+        status = _call(
+            "GET",
+            f"{base}/uploads/{upload_id}",
+            headers,
+            timeout,
+            missing_ok=True,
+        )
+        if status is None:
+            # The service dropped the unfinished upload; start over
+            upload_id = None
+        else:
+            received = int(status.get("received", 0))
+    if not upload_id:
+        created = _call(
+            "POST",
+            f"{base}/uploads",
+            headers,
+            timeout,
+            json={
+                "filename": os.path.basename(report),
+                "size": len(payload),
+                "sha256": digest,
+                "content_encoding": "gzip",
+                "chunk_size": chunk_size,
+                "metadata": upload_metadata(),
+            },
+        )
+        if not created or not created.get("upload_id"):
+            raise ReportUploadError(f"POST {base}/uploads returned no upload_id")
+        upload_id = str(created["upload_id"])
+        state[key] = upload_id
+        _save_state(state_file, state)
+
+    resumed_from = received
+    chunks = 0
+    while received < len(payload):
+        chunk = payload[received : received + chunk_size]
+        end = received + len(chunk) - 1
+        reply = _call(
+            "PUT",
+            f"{base}/uploads/{upload_id}",
+            {
+                **headers,
+                "Content-Type": "application/octet-stream",
+                "Content-Range": f"bytes {received}-{end}/{len(payload)}",
+            },
+            timeout,
+            content=chunk,
+        )
+        accepted = int((reply or {}).get("received", end + 1))
+        if accepted <= received:
+            raise ReportUploadError(
+                f"the service did not accept bytes {received}-{end}",
+            )
+        received = accepted
+        chunks += 1
+
+    done = _call("POST", f"{base}/uploads/{upload_id}/complete", headers, timeout)
+    state.pop(key, None)
+    _save_state(state_file, state)
+    return UploadResult(
+        report_id=str((done or {}).get("report_id", upload_id)),
+        url=(done or {}).get("url"),
+        resumed_from=resumed_from,
+        chunks=chunks,
+    )
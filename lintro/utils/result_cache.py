@@ -0,0 +1,508 @@
+"""Per-file result cache shared between runs and CI jobs.
+
+``execution.cache`` caches the issues a tool reports for each file, keyed by
+the file's path and content, so a run only sends changed files to the tool
+and takes the rest from the cache::
+
+    execution:
+      cache:
+        tools: [ruff, shellcheck, yamllint]
+        remote: s3://ci-cache/lintro
+
+Entries are namespaced by the tool, its version, the lintro version and a
+hash of the tool's configuration, so a tool upgrade or a config change
+never reuses stale results. Lookups read through the local cache to the
+remote store and keep what they find locally; new entries are written
+locally at once and written back to the remote store when the tool
+finishes. Only list tools whose findings in a file depend on that file
+alone; whole-program tools such as mypy or tsc must not be cached.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import hashlib
+import importlib
+import json
+import os
+from concurrent.futures import ThreadPoolExecutor
+from enum import Enum
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from loguru import logger
+
+from lintro import __version__
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.utils.cache_backends import (
+    CacheBackend,
+    LocalCacheBackend,
+    create_cache_backend,
+)
+from lintro.utils.file_cache import CACHE_DIR
+from lintro.utils.logger_setup import log_event
+
+if TYPE_CHECKING:
+    from lintro.config.lintro_config import LintroConfig
+    from lintro.plugins.base import BaseToolPlugin
+
+# Local directory of cached results
+RESULTS_DIR = CACHE_DIR / "results"
+
+# Issue fields set after the tool ran; they are never cached
+_POST_PROCESSING_FIELDS = frozenset(
+    {
+        "severity_override",
+        "also_reported_by",
+        "toolchains",
+        "expired_suppression",
+        "script_fields",
+    },
+)
+
+# Tool options that do not change what a tool reports
+_VOLATILE_OPTIONS = frozenset({"timeout", "auto_install", "incremental"})
+
+
+def _sha256(data: bytes) -> str:
+    """Hash bytes.
+
+    Args:
+        data: Bytes to hash.
+
+    Returns:
+        str: Hex SHA-256 digest.
+    """
+    return hashlib.sha256(data).hexdigest()
+
+
+def cache_namespace(
+    tool: BaseToolPlugin,
+    tool_version: str | None,
+    lintro_config: LintroConfig,
+) -> str:
+    """Compute the namespace of a tool's entries.
+
+    Args:
+        tool: Configured tool.
+        tool_version: Installed version of the tool, if known.
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        str: Digest of the tool, its version, the lintro version and the
+        tool's configuration, including its native config files.
+    """
+    native_configs: dict[str, str] = {}
+    for name in tool.definition.native_configs:
+        path = Path(name)
+        if path.is_file():
+            try:
+                native_configs[name] = _sha256(path.read_bytes())
+            except OSError:
+                native_configs[name] = "unreadable"
+    options = {
+        key: value
+        for key, value in tool.options.items()
+        if key not in _VOLATILE_OPTIONS
+    }
+    parts = {
+        "tool": tool.name,
+        "tool_version": tool_version,
+        "lintro_version": __version__,
+        "image": tool.container.image if tool.container else None,
+        "options": options,
+        "enforce": lintro_config.enforce.model_dump(),
+        "defaults": lintro_config.defaults.get(tool.name),
+        "native_configs": native_configs,
+    }
+    encoded = json.dumps(parts, sort_keys=True, default=str).encode()
+    return _sha256(encoded)[:32]
+
+
+def _issue_types() -> dict[str, type[BaseIssue]]:
+    """Map qualified names to the issue classes loaded so far.
+
+    Returns:
+        dict[str, type[BaseIssue]]: BaseIssue and all its subclasses.
+    """
+    found: dict[str, type[BaseIssue]] = {}
+    pending: list[type[BaseIssue]] = [BaseIssue]
+    while pending:
+        cls = pending.pop()
+        found[f"{cls.__module__}:{cls.__qualname__}"] = cls
+        pending.extend(cls.__subclasses__())
+    return found
+
+
+def _issue_type(name: str) -> type[BaseIssue] | None:
+    """Find an issue class by qualified name.
+
+    Only lintro modules are imported, so a tampered entry cannot load
+    arbitrary code.
+
+    Args:
+        name: ``module:QualName`` of the class.
+
+    Returns:
+        type[BaseIssue] | None: The class, or None if it is unknown.
+    """
+    types = _issue_types()
+    if name not in types:
+        module = name.partition(":")[0]
+        if not module.startswith("lintro."):
+            return None
+        try:
+            importlib.import_module(module)
+        except ImportError:
+            return None
+        types = _issue_types()
+    return types.get(name)
+
+
+def serialize_issue(issue: BaseIssue, file: str) -> dict[str, Any]:
+    """Convert an issue to a cache entry item.
+
+    Args:
+        issue: Issue reported by the tool.
+        file: Path stored for the issue, relative to the working directory.
+
+    Returns:
+        dict[str, Any]: Class name and constructor fields of the issue.
+    """
+    fields: dict[str, Any] = {}
+    for f in dataclasses.fields(issue):
+        if not f.init or f.name in _POST_PROCESSING_FIELDS:
+            continue
+        value = getattr(issue, f.name)
+        fields[f.name] = value.value if isinstance(value, Enum) else value
+    fields["file"] = file
+    cls = type(issue)
+    return {"type": f"{cls.__module__}:{cls.__qualname__}", "fields": fields}
+
+
+def deserialize_issue(data: dict[str, Any]) -> BaseIssue | None:
+    """Rebuild an issue from a cache entry item.
+
+    Args:
+        data: Item written by serialize_issue.
+
+    Returns:
+        BaseIssue | None: The issue, or None if the item is not usable.
+    """
+    cls = _issue_type(str(data.get("type", "")))
+    fields = data.get("fields")
+    if cls is None or not isinstance(fields, dict):
+        return None
+    known = {f.name for f in dataclasses.fields(cls) if f.init}
+    try:
+        return cls(**{k: v for k, v in fields.items() if k in known})
+    except (TypeError, ValueError):
+        return None
+
+
+class ResultCache:
+    """Cached issues of one tool, per file.
+
+    Attributes:
+        tool_name: Tool whose results are cached.
+        namespace: Digest separating tool versions and configurations.
+        local: Local store, read first.
+        remote: Shared store, or None for the local cache only.
+        read: Whether lookups use cached entries.
+        write: Whether new results are stored.
+    """
+
+    def __init__(
+        self,
+        tool_name: str,
+        namespace: str,
+        local: CacheBackend,
+        remote: CacheBackend | None = None,
+        *,
+        read: bool = True,
+        write: bool = True,
+    ) -> None:
+        """Create the cache.
+
+        Args:
+            tool_name: Tool whose results are cached.
+            namespace: Digest separating tool versions and configurations.
+            local: Local store, read first.
+            remote: Shared store, or None for the local cache only.
+            read: Whether lookups use cached entries.
+            write: Whether new results are stored.
+        """
+        self.tool_name = tool_name
+        self.namespace = namespace
+        self.local = local
+        self.remote = remote
+        self.read = read
+        self.write = write
+        self._pending: dict[str, bytes] = {}
+
+    def _key(self, rel_path: str, content: bytes) -> str:
+        """Compute the key of a file's entry.
+
+        Args:
+            rel_path: Path relative to the working directory.
+            content: File contents.
+
+        Returns:
+            str: Entry key under the tool and namespace.
+        """
+        digest = _sha256(f"{rel_path}\0{_sha256(content)}".encode())
+        return f"{self.tool_name}/{self.namespace}/{digest}.json"
+
+    def _file_key(self, file: str) -> tuple[str, str] | None:
+        """Compute the stored path and key of a file.
+
+        Args:
+            file: Absolute path of the file.
+
+        Returns:
+            tuple[str, str] | None: Relative path and key, or None if the
+            file cannot be read.
+        """
+        rel_path = Path(os.path.relpath(file)).as_posix()
+        try:
+            return rel_path, self._key(rel_path, Path(file).read_bytes())
+        except OSError:
+            return None
+
+    def _get(self, key: str) -> tuple[bytes | None, bool]:
+        """Read an entry through the local cache to the remote store.
+
+        Args:
+            key: Entry key.
+
+        Returns:
+            tuple[bytes | None, bool]: Entry, and whether it came from the
+            remote store.
+        """
+        data = self.local.get(key)
+        if data is not None or self.remote is None:
+            return data, False
+        data = self.remote.get(key)
+        if data is not None:
+            self.local.put(key, data)
+        return data, data is not None
+
+    def lookup(self, files: list[str]) -> tuple[list[BaseIssue], list[str]]:
+        """Split files into cached ones and ones the tool must check.
+
+        Args:
+            files: Absolute paths of the files the tool would check.
+
+        Returns:
+            tuple[list[BaseIssue], list[str]]: Cached issues of the hits, and
+            the files without a usable entry.
+        """
+        if not self.read:
+            return [], list(files)
+        issues: list[BaseIssue] = []
+        misses: list[str] = []
+        remote_hits = 0
+        for file in files:
+            file_key = self._file_key(file)
+            entry = self._get(file_key[1]) if file_key else (None, False)
+            cached = self._decode(entry[0])
+            if cached is None:
+                misses.append(file)
+                continue
+            issues.extend(cached)
+            remote_hits += entry[1]
+        log_event(
+            "result_cache",
+            f"{self.tool_name}: {len(files) - len(misses)} of {len(files)} "
+            "files from cache",
+            tool=self.tool_name,
+            files=len(files),
+            cache_hits=len(files) - len(misses),
+            remote_hits=remote_hits,
+        )
+        return issues, misses
+
+    @staticmethod
+    def _decode(data: bytes | None) -> list[BaseIssue] | None:
+        """Decode an entry.
+
+        Args:
+            data: Raw entry, or None for a miss.
+
+        Returns:
+            list[BaseIssue] | None: Issues of the file, or None if the entry
+            is missing or not usable.
+        """
+        if data is None:
+            return None
+        try:
+            items = json.loads(data)["issues"]
+        except (ValueError, KeyError, TypeError):
+            return None
+        issues = [deserialize_issue(item) for item in items]
+        if any(issue is None for issue in issues):
+            return None
+        return [issue for issue in issues if issue is not None]
+
+    def store(self, files: list[str], result: ToolResult) -> None:
+        """Queue the entries of the files a tool just checked.
+
+        Nothing is stored when the tool failed to run, or when an issue
+        cannot be attributed to one of the files.
+
+        Args:
+            files: Absolute paths of the files the tool checked.
+            result: The tool's result for those files.
+        """
+        if not self.write or result.skipped or result.error_code:
+            return
+        issues = list(result.issues or [])
+        if len(issues) != result.issues_count:
+            logger.debug(f"[{self.tool_name}] Not caching unparsed results")
+            return
+        by_file: dict[str, list[BaseIssue]] = {file: [] for file in files}
+        for issue in issues:
+            file = _match_file(str(issue.file or ""), by_file)
+            if file is None:
+                logger.debug(
+                    f"[{self.tool_name}] Not caching: {issue.file!r} is not a "
+                    "checked file",
+                )
+                return
+            by_file[file].append(issue)
+        for file, file_issues in by_file.items():
+            file_key = self._file_key(file)
+            if file_key is None:
+                continue
+            rel_path, key = file_key
+            try:
+                data = json.dumps(
+                    {
+                        "path": rel_path,
+                        "issues": [serialize_issue(i, rel_path) for i in file_issues],
+                    },
+                ).encode()
+            except (TypeError, ValueError) as e:
+                logger.debug(f"[{self.tool_name}] Not caching {rel_path}: {e}")
+                continue
+            self.local.put(key, data)
+            self._pending[key] = data
+
+    def flush(self) -> None:
+        """Write queued entries back to the remote store."""
+        pending, self._pending = self._pending, {}
+        if self.remote is None or not pending:
+            return
+        remote = self.remote
+        with ThreadPoolExecutor(max_workers=8) as pool:
+            list(pool.map(lambda item: remote.put(*item), pending.items()))
+        logger.debug(
+            f"[{self.tool_name}] Wrote {len(pending)} cache entries to {remote.name}",
+        )
+
+
+def _match_file(reported: str, files: dict[str, list[BaseIssue]]) -> str | None:
+    """Find the checked file an issue was reported for.
+
+    Tools report paths relative to the directory they ran in, so a path
+    that is not a checked file is matched by its trailing components.
+
+    Args:
+        reported: Path in the issue.
+        files: Checked files by absolute path.
+
+    Returns:
+        str | None: Absolute path of the file, or None if no single file
+        matches.
+    """
+    if not reported:
+        return None
+    absolute = os.path.abspath(reported)
+    if absolute in files:
+        return absolute
+    suffix = os.sep + os.path.normpath(reported).lstrip(os.sep)
+    matches = [file for file in files if file.endswith(suffix)]
+    return matches[0] if len(matches) == 1 else None
+
+
+def resolve_result_cache(
+    tool: BaseToolPlugin,
+    lintro_config: LintroConfig,
+) -> ResultCache | None:
+    """Create the result cache of a tool, if the config enables one.
+
+    Args:
+        tool: Configured tool.
+        lintro_config: Loaded Lintro configuration.
+
+    Returns:
+        ResultCache | None: Cache of the tool, or None if the tool is not
+        listed in ``execution.cache.tools`` or its version is unknown.
+    """
+    settings = lintro_config.execution.cache
+    if tool.name.lower() not in {name.lower() for name in settings.tools}:
+        return None
+    from lintro.plugins.execution_preparation import detect_tool_version
+
+    version = detect_tool_version(tool.definition)
+    if version is None and not tool.definition.native:
+        logger.debug(f"[{tool.name}] Not caching results: unknown tool version")
+        return None
+    return ResultCache(
+        tool_name=tool.name,
+        namespace=cache_namespace(tool, version, lintro_config),
+        local=LocalCacheBackend(RESULTS_DIR),
+        remote=(
+            create_cache_backend(settings.remote, settings.token_env)
+            if settings.remote
+            else None
+        ),
+        read=settings.read,
+        write=settings.write,
+    )
+
+
+def check_with_cache(
+    tool: BaseToolPlugin,
+    paths: list[str],
+    options: dict[str, object],
+) -> ToolResult:
+    """Run a tool's check, taking unchanged files from its result cache.
+
+    Args:
+        tool: Configured tool; its ``result_cache`` may be None.
+        paths: Paths to check.
+        options: Options passed to the tool's check.
+
+    Returns:
+        ToolResult: Result covering every file, cached or checked.
+    """
+    cache = tool.result_cache
+    if not isinstance(cache, ResultCache):
+        return tool.check(paths, options)
+    files = tool._discover_files(paths, show_progress=False)
+    if not files:
+        return tool.check(paths, options)
+
+    cached, misses = cache.lookup(files)
+    if not misses:
+        return ToolResult(
+            name=tool.name,
+            success=not cached,
+            issues_count=len(cached),
+            issues=cached,
+        )
+
+    result = tool.check(misses if len(misses) < len(files) else paths, options)
+    cache.store(misses, result)
+    cache.flush()
+    if not cached:
+        return result
+    issues = [*cached, *(result.issues or [])]
+    return dataclasses.replace(
+        result,
+        success=result.success and not cached,
+        issues_count=result.issues_count + len(cached),
+        issues=issues,
+    )
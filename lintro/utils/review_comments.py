@@ -0,0 +1,433 @@
+"""Publishing report issues as inline code review comments.
+
+Shared by ``lintro report --github-pr`` and ``--gitlab-mr``: issues of a
+JSON report are turned into one comment per flagged line, limited to the
+lines a review can comment on, the lines of the pull or merge request's
+diff.
+
+Each comment body starts with a hidden marker naming its file and line::
+
+    <!-- lintro-review: src/app.py:12 -->
+
+so a later run can tell its own comments apart from human ones: comments
+whose issues are gone are deleted, comments whose issues changed are
+edited, and unchanged comments are left alone. Re-running the same job,
+such as a retried CI pipeline, therefore posts nothing twice.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import re
+from collections.abc import Iterable, Mapping
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Protocol
+
+from lintro import __version__
+from lintro.exceptions.errors import LintroError
+from lintro.utils.issue_query import report_records
+
+MARKER_PREFIX: str = "<!-- lintro-review: "
+MARKER_SUFFIX: str = " -->"
+PAGE_SIZE: int = 100
+
+_HUNK_RE = re.compile(r"^@@ -(\d+)(?:,\d+)? \+(\d+)(?:,\d+)? @@")
+_MARKER_RE = re.compile(re.escape(MARKER_PREFIX) + r"(.+?)" + re.escape(MARKER_SUFFIX))
+
+
+@dataclass(frozen=True)
+class ReviewComment:
+    """Inline comment for one flagged line.
+
+    Attributes:
+        path: File path relative to the repository root.
+        line: Line of the new version of the file.
+        body: Markdown body, starting with the lintro marker.
+        old_line: Line of the old version for unchanged lines, None for
+            added lines.
+    """
+
+    path: str
+    line: int
+    body: str
+    old_line: int | None = None
+
+    @property
+    def key(self) -> str:
+        """Return the identity stored in the comment's marker.
+
+        Returns:
+            str: ``path:line``.
+        """
+        return f"{self.path}:{self.line}"
+
+
+@dataclass(frozen=True)
+class ExistingComment:
+    """Review comment already on the pull or merge request.
+
+    Attributes:
+        comment_id: Identifier of the comment on the forge, as used in the
+            API path that edits it.
+        body: Current body of the comment.
+    """
+
+    comment_id: str
+    body: str
+
+
+@dataclass(frozen=True)
+class ReviewResult:
+    """Outcome of publishing review comments.
+
+    Attributes:
+        created: Comments posted.
+        updated: Earlier comments edited.
+        deleted: Earlier comments whose issues are gone.
+        unchanged: Earlier comments left as they were.
+        outside_diff: Issues not on a line of the diff, so not commented.
+    """
+
+    created: int
+    updated: int
+    deleted: int
+    unchanged: int
+    outside_diff: int
+
+
+@dataclass
+class ReviewSyncPlan:
+    """Changes that bring the posted comments in line with a report.
+
+    Attributes:
+        create: Comments to post.
+        update: Existing comments to edit, with their new content.
+        delete: Existing lintro comments whose issues are gone.
+        unchanged: Number of existing comments left as they are.
+    """
+
+    create: list[ReviewComment] = field(default_factory=list)
+    update: list[tuple[ExistingComment, ReviewComment]] = field(
+        default_factory=list,
+    )
+    delete: list[ExistingComment] = field(default_factory=list)
+    unchanged: int = 0
+
+
+def load_report_records(path: str | Path) -> list[dict[str, Any]]:
+    """Read the issues of a JSON report.
+
+    Args:
+        path: Report written with ``--output FILE --output-format json``.
+
+    Returns:
+        list[dict[str, Any]]: Issue records as built by ``report_records``.
+
+    Raises:
+        ValueError: If the file is not a Lintro JSON report.
+        OSError: If the file cannot be read.
+    """
+    report = json.loads(Path(path).read_text(encoding="utf-8"))
+    if not isinstance(report, dict):
+        raise ValueError("Not a Lintro JSON report")
+    return report_records(report)
+
+
+def diff_line_map(patch: str | None) -> dict[int, int | None]:
+    """Find the new-file lines a unified diff shows.
+
+    Args:
+        patch: Unified diff of one file, hunks only or with headers.
+
+    Returns:
+        dict[int, int | None]: Old line for each shown new line; None for
+        added lines.
+    """
+    lines: dict[int, int | None] = {}
+    if not patch:
+        return lines
+    old = new = 0
+    in_hunk = False
+    for text in patch.splitlines():
+        match = _HUNK_RE.match(text)
+        if match:
+            old, new = int(match.group(1)), int(match.group(2))
+            in_hunk = True
+            continue
+        if not in_hunk or text.startswith("\\"):
+            continue
+        if text.startswith("+"):
+            lines[new] = None
+            new += 1
+        elif text.startswith("-"):
+            old += 1
+        else:
+            lines[new] = old
+            old += 1
+            new += 1
+    return lines
+
+
+def repo_relative_path(path: str, root: Path | None) -> str:
+    """Express a report path relative to the repository root.
+
+    Args:
+        path: Path as recorded in the report, relative to the working
+            directory or absolute.
+        root: Repository root, or None if unknown.
+
+    Returns:
+        str: POSIX path relative to the root when it lies below it.
+    """
+    if root is None:
+        return path
+    rel = os.path.relpath(os.path.abspath(path), root)
+    if rel.startswith(".."):
+        return path
+    return rel.replace(os.sep, "/")
+
+
+def _format_issue(record: Mapping[str, Any]) -> str:
+    """Render one issue as a list item.
+
+    Args:
+        record: Issue record from ``report_records``.
+
+    Returns:
+        str: Markdown line for the issue.
+    """
+    code = f" `{record['code']}`" if record["code"] else ""
+    severity = record["severity"].value.lower()
+    return f"- **{record['tool']}**{code} ({severity}): {record['message']}"
+
+
+def build_review_comments(
+    records: Iterable[Mapping[str, Any]],
+    diff_lines: Mapping[str, Mapping[int, int | None]],
+    root: Path | None = None,
+) -> tuple[list[ReviewComment], int]:
+    """Group the issues on diff lines into one comment per line.
+
+    Args:
+        records: Issue records from ``report_records``.
+        diff_lines: ``diff_line_map`` of each changed file, keyed by its
+            path relative to the repository root.
+        root: Repository root the report paths are resolved against.
+
+    Returns:
+        tuple[list[ReviewComment], int]: Comments sorted by file and line,
+        and the number of issues outside the diff.
+    """
+    grouped: dict[tuple[str, int], list[str]] = {}
+    outside = 0
+    for record in records:
+        path = repo_relative_path(str(record["path"]), root)
+        line = int(record["line"] or 0)
+        if line not in diff_lines.get(path, {}):
+            outside += 1
+            continue
+        item = _format_issue(record)
+        items = grouped.setdefault((path, line), [])
+        if item not in items:
+            items.append(item)
+
+    comments = []
+    for (path, line), items in sorted(grouped.items()):
+        marker = f"{MARKER_PREFIX}{path}:{line}{MARKER_SUFFIX}"
+        comments.append(
+            ReviewComment(
+                path=path,
+                line=line,
+                body="\n".join([marker, *items]),
+                old_line=diff_lines[path][line],
+            ),
+        )
+    return comments, outside
+
+
+def comment_key(body: str) -> str | None:
+    """Read the lintro marker of a comment body.
+
+    Args:
+        body: Comment body.
+
+    Returns:
+        str | None: The ``path:line`` identity, or None for comments lintro
+        did not write.
+    """
+    match = _MARKER_RE.search(body or "")
+    return match.group(1) if match else None
+
+
+def plan_review_sync(
+    comments: Iterable[ReviewComment],
+    existing: Iterable[ExistingComment],
+) -> ReviewSyncPlan:
+    """Compare the wanted comments with the ones already posted.
+
+    Comments without a lintro marker are never touched.
+
+    Args:
+        comments: Comments built from the current report.
+        existing: Comments on the pull or merge request.
+
+    Returns:
+        ReviewSyncPlan: Comments to create, edit and delete.
+    """
+    posted: dict[str, ExistingComment] = {}
+    plan = ReviewSyncPlan()
+    for comment in existing:
+        key = comment_key(comment.body)
+        if key is None:
+            continue
+        if key in posted:
+            # Left over from an interrupted run
+            plan.delete.append(comment)
+        else:
+            posted[key] = comment
+
+    for comment in comments:
+        current = posted.pop(comment.key, None)
+        if current is None:
+            plan.create.append(comment)
+        elif current.body.strip() != comment.body.strip():
+            plan.update.append((current, comment))
+        else:
+            plan.unchanged += 1
+    plan.delete.extend(posted.values())
+    return plan
+
+
+class ReviewApiError(LintroError):
+    """Raised when review comments cannot be published."""
+
+
+class ReviewApi(Protocol):
+    """The REST calls publishing review comments needs."""
+
+    def request(
+        self,
+        method: str,
+        path: str,
+        json_body: dict[str, Any] | None = None,
+    ) -> Any:
+        """Send one request and return the decoded reply."""
+        ...
+
+    def paginate(self, path: str) -> list[Any]:
+        """Fetch every page of a list endpoint."""
+        ...
+
+
+class ReviewApiClient:
+    """Minimal JSON REST client for publishing review comments.
+
+    Pages of list endpoints are followed through the ``Link`` header, which
+    both GitHub and GitLab send.
+    """
+
+    error: type[ReviewApiError] = ReviewApiError
+
+    def __init__(
+        self,
+        api_url: str,
+        headers: Mapping[str, str],
+        timeout: float = 30.0,
+    ) -> None:
+        """Create the client.
+
+        Args:
+            api_url: Base URL of the REST API.
+            headers: Headers sent with every request, such as the token.
+            timeout: Seconds to wait for each response.
+        """
+        self.api_url = api_url.rstrip("/")
+        self.timeout = timeout
+        self.headers = {"User-Agent": f"lintro/{__version__}", **headers}
+
+    def request(
+        self,
+        method: str,
+        path: str,
+        json_body: dict[str, Any] | None = None,
+    ) -> Any:
+        """Send one API request.
+
+        Args:
+            method: HTTP method.
+            path: API path, or a full URL.
+            json_body: JSON request body, if any.
+
+        Returns:
+            Any: Decoded JSON reply; None for empty replies.
+        """
+        return self._send(method, path, json_body)[0]
+
+    def paginate(self, path: str) -> list[Any]:
+        """Fetch every page of a list endpoint.
+
+        Args:
+            path: API path of the list.
+
+        Returns:
+            list[Any]: Items of all pages.
+
+        Raises:
+            ReviewApiError: If a page is not a list.
+        """
+        items: list[Any] = []
+        url: str | None = f"{path}?per_page={PAGE_SIZE}"
+        while url:
+            data, url = self._send("GET", url, None)
+            if not isinstance(data, list):
+                raise self.error(f"GET {path} did not return a list")
+            items.extend(data)
+        return items
+
+    def _send(
+        self,
+        method: str,
+        path: str,
+        json_body: dict[str, Any] | None,
+    ) -> tuple[Any, str | None]:
+        """Send one API request and find the next page.
+
+        Args:
+            method: HTTP method.
+            path: API path, or a full URL.
+            json_body: JSON request body, if any.
+
+        Returns:
+            tuple[Any, str | None]: Decoded JSON reply, and the URL of the
+            next page if the reply is paginated.
+
+        Raises:
+            ReviewApiError: If the request fails or is rejected.
+        """
+        import httpx
+
+        url = path if path.startswith("http") else f"{self.api_url}{path}"
+        try:
+            response = httpx.request(
+                method,
+                url,
+                headers=self.headers,
+                json=json_body,
+                timeout=self.timeout,
+            )
+        except httpx.HTTPError as e:
+            raise self.error(f"{method} {url} failed: {e}") from e
+        if response.status_code >= 400:
+            raise self.error(
+                f"{method} {url} was rejected with HTTP {response.status_code}: "
+                f"{response.text.strip()[:200]}",
+            )
+        next_url = response.links.get("next", {}).get("url")
+        if response.status_code == 204 or not response.content:
+            return None, next_url
+        try:
+            return response.json(), next_url
+        except ValueError as e:
+            raise self.error(f"{method} {url} did not answer with JSON") from e
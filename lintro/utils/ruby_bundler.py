@@ -0,0 +1,93 @@
+"""Bundler detection utilities for Ruby tools.
+
+Ruby projects usually pin their linters in a ``Gemfile`` resolved to exact
+versions in ``Gemfile.lock``. Running such a tool through ``bundle exec``
+from the directory of the Gemfile uses the pinned version and the
+project's plugins instead of whatever is installed globally.
+"""
+
+from __future__ import annotations
+
+import re
+import shutil
+from pathlib import Path
+
+from loguru import logger
+
+# Gemfile names with the lockfile Bundler writes for each
+GEMFILE_LOCKFILES: tuple[tuple[str, str], ...] = (
+    ("Gemfile", "Gemfile.lock"),
+    ("gems.rb", "gems.locked"),
+)
+
+
+def find_gemfile(start: str | Path) -> Path | None:
+    """Find the Gemfile governing a directory.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        Path to the nearest ``Gemfile`` or ``gems.rb``, or None if there is
+        none.
+
+    Examples:
+        >>> find_gemfile("/project/lib")  # doctest: +SKIP
+        PosixPath('/project/Gemfile')
+    """
+    directory = Path(start).resolve()
+    for candidate in (directory, *directory.parents):
+        for gemfile_name, _ in GEMFILE_LOCKFILES:
+            gemfile = candidate / gemfile_name
+            if gemfile.is_file():
+                return gemfile
+    return None
+
+
+def get_locked_version(gemfile: Path, gem: str) -> str | None:
+    """Get the version of a gem resolved in a Gemfile's lockfile.
+
+    Args:
+        gemfile: Path to the ``Gemfile`` or ``gems.rb``.
+        gem: Name of the gem to look up.
+
+    Returns:
+        Locked version of the gem, or None if the lockfile is missing or
+        does not include it.
+    """
+    lockfile_name = dict(GEMFILE_LOCKFILES).get(gemfile.name, "Gemfile.lock")
+    lockfile = gemfile.with_name(lockfile_name)
+    try:
+        content = lockfile.read_text(encoding="utf-8")
+    except OSError:
+        return None
+    # Resolved gems are listed under "specs:" as "    name (version)"
+    pattern = rf"^    {re.escape(gem)} \(([^)\s]+)\)$"
+    match = re.search(pattern, content, re.MULTILINE)
+    return match.group(1) if match else None
+
+
+def get_bundled_gem_dir(start: str | Path, gem: str) -> Path | None:
+    """Get the directory to run a gem from through ``bundle exec``.
+
+    Args:
+        start: Directory to search for a Gemfile from.
+        gem: Name of the gem to run.
+
+    Returns:
+        Directory of the Gemfile locking the gem, or None if the gem is not
+        bundled there or Bundler is not installed.
+    """
+    gemfile = find_gemfile(start)
+    if gemfile is None:
+        return None
+    version = get_locked_version(gemfile, gem)
+    if version is None:
+        return None
+    if shutil.which("bundle") is None:
+        logger.debug(
+            f"[ruby_bundler] {gem} {version} is locked in {gemfile.parent} but "
+            "bundle is not installed",
+        )
+        return None
+    return gemfile.parent
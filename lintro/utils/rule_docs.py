@@ -0,0 +1,258 @@
+"""Documentation lookup for the rules tools report.
+
+``lintro explain E501`` or ``lintro explain clippy::needless_return`` asks
+the tool itself when it can describe its rules: ``ruff rule`` and
+``cargo clippy --explain``. When the tool is not installed, or with
+``--offline``, a small set of bundled summaries and documentation URL
+templates in ``tools/rule_docs.json`` is used instead.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import subprocess  # nosec B404 - fixed tool commands, no shell
+from collections.abc import Callable
+from dataclasses import dataclass
+from functools import lru_cache
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro.enums.tool_name import ToolName
+
+RULE_DOCS_PATH: Path = Path(__file__).parent.parent / "tools" / "rule_docs.json"
+EXPLAIN_TIMEOUT: int = 15
+
+_RUFF_URL = "https://docs.astral.sh/ruff/rules/{name}/"
+
+
+@dataclass(frozen=True)
+class RuleDoc:
+    """Description of one tool rule.
+
+    Attributes:
+        tool: Tool that reports the rule.
+        code: Rule code or lint name, as the tool reports it.
+        summary: One-line description.
+        url: Documentation page, if known.
+        explanation: Longer description from the tool; empty when bundled.
+        source: ``tool`` when the tool described the rule, else ``bundled``.
+    """
+
+    tool: str
+    code: str
+    summary: str
+    url: str | None = None
+    explanation: str = ""
+    source: str = "bundled"
+
+
+@lru_cache(maxsize=1)
+def _bundled() -> dict[str, Any]:
+    """Read the bundled rule metadata.
+
+    Returns:
+        dict[str, Any]: URL templates and rule summaries per tool.
+    """
+    data: dict[str, Any] = json.loads(RULE_DOCS_PATH.read_text(encoding="utf-8"))
+    return data
+
+
+def split_rule(rule: str) -> tuple[str | None, str]:
+    """Split a rule into its tool and code.
+
+    Accepts the tool's own prefix (``clippy::needless_return``) and lintro's
+    ``tool:code`` form (``ruff:E501``).
+
+    Args:
+        rule: Rule as given on the command line.
+
+    Returns:
+        tuple[str | None, str]: Tool, or None without a known tool prefix,
+        and the code.
+    """
+    rule = rule.strip()
+    prefix, sep, code = rule.partition(":")
+    tool = prefix.lower().replace("-", "_")
+    if sep and tool in {name.value for name in ToolName}:
+        return tool, code.lstrip(":")
+    return None, rule
+
+
+def _normalize(tool: str, code: str) -> str:
+    """Spell a code the way the tool's documentation does.
+
+    Args:
+        tool: Tool name.
+        code: Code as given.
+
+    Returns:
+        str: Lint names in snake case for clippy, upper case codes otherwise.
+    """
+    if tool == ToolName.CLIPPY:
+        return code.lower().replace("-", "_")
+    if re.fullmatch(r"[A-Za-z]+\d+", code):
+        return code.upper()
+    return code
+
+
+def bundled_doc(tool: str, code: str) -> RuleDoc | None:
+    """Describe a rule from the bundled metadata.
+
+    Args:
+        tool: Tool name.
+        code: Rule code.
+
+    Returns:
+        RuleDoc | None: Bundled summary and URL, a URL alone when the tool
+        has a URL template, or None if nothing is known.
+    """
+    code = _normalize(tool, code)
+    data = _bundled()
+    entry = data["rules"].get(tool, {}).get(code)
+    template = data["url_templates"].get(tool)
+    url = template.format(code=code, code_lower=code.lower()) if template else None
+    if entry is None:
+        return RuleDoc(tool=tool, code=code, summary="", url=url) if url else None
+    return RuleDoc(
+        tool=tool,
+        code=code,
+        summary=entry["summary"],
+        url=entry.get("url", url),
+    )
+
+
+def _run(command: list[str]) -> str | None:
+    """Run a tool's documentation command.
+
+    Args:
+        command: Command and arguments.
+
+    Returns:
+        str | None: Standard output, or None if the command is missing or
+        fails.
+    """
+    try:
+        result = subprocess.run(  # nosec B603 - list command, no shell
+            command,
+            capture_output=True,
+            text=True,
+            timeout=EXPLAIN_TIMEOUT,
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        logger.debug(f"{command[0]} could not explain a rule: {e}")
+        return None
+    if result.returncode != 0:
+        return None
+    return result.stdout
+
+
+def _ruff_doc(code: str) -> RuleDoc | None:
+    """Ask ruff to describe a rule.
+
+    Args:
+        code: Rule code such as ``E501``.
+
+    Returns:
+        RuleDoc | None: Description, or None if ruff does not know it.
+    """
+    output = _run(["ruff", "rule", code, "--output-format", "json"])
+    try:
+        data = json.loads(output or "")
+    except json.JSONDecodeError:
+        return None
+    if not isinstance(data, dict) or not data.get("code"):
+        return None
+    name = str(data.get("name") or "")
+    return RuleDoc(
+        tool=ToolName.RUFF.value,
+        code=str(data["code"]),
+        summary=str(data.get("summary") or name),
+        url=_RUFF_URL.format(name=name) if name else None,
+        explanation=str(data.get("explanation") or "").strip(),
+        source="tool",
+    )
+
+
+def _clippy_doc(code: str) -> RuleDoc | None:
+    """Ask clippy to describe a lint.
+
+    Args:
+        code: Lint name such as ``needless_return``.
+
+    Returns:
+        RuleDoc | None: Description, or None if clippy does not know it.
+    """
+    output = _run(["cargo", "clippy", "--explain", code])
+    if not output or not output.strip():
+        return None
+    # The first paragraph under "What it does" is the summary
+    lines = output.strip().splitlines()
+    body = [line for line in lines if not line.startswith("#")]
+    summary = next((line.strip() for line in body if line.strip()), code)
+    bundled = bundled_doc(ToolName.CLIPPY, code)
+    return RuleDoc(
+        tool=ToolName.CLIPPY.value,
+        code=code,
+        summary=summary,
+        url=bundled.url if bundled else None,
+        explanation=output.strip(),
+        source="tool",
+    )
+
+
+EXPLAIN_BACKENDS: dict[str, Callable[[str], RuleDoc | None]] = {
+    ToolName.RUFF: _ruff_doc,
+    ToolName.CLIPPY: _clippy_doc,
+}
+
+
+def _candidate_tools(code: str) -> list[str]:
+    """Guess which tools may report a code given without a tool.
+
+    Args:
+        code: Rule code.
+
+    Returns:
+        list[str]: Tools with bundled metadata for the code first, then the
+        tools that can describe their own rules.
+    """
+    rules = _bundled()["rules"]
+    known = [tool for tool in sorted(rules) if _normalize(tool, code) in rules[tool]]
+    return known + [tool for tool in EXPLAIN_BACKENDS if tool not in known]
+
+
+def explain_rule(
+    rule: str,
+    tool: str | None = None,
+    offline: bool = False,
+) -> RuleDoc | None:
+    """Describe a rule, asking the tool first and the bundled data second.
+
+    Args:
+        rule: Rule such as ``E501``, ``ruff:E501`` or
+            ``clippy::needless_return``.
+        tool: Tool that reports the rule, when the rule has no prefix.
+        offline: Only use the bundled metadata.
+
+    Returns:
+        RuleDoc | None: Description, or None if no tool knows the rule.
+    """
+    prefix, code = split_rule(rule)
+    if not code:
+        return None
+    wanted = prefix or (tool.lower().replace("-", "_") if tool else None)
+    candidates = [wanted] if wanted else _candidate_tools(code)
+    for candidate in candidates:
+        backend = EXPLAIN_BACKENDS.get(candidate)
+        if backend is not None and not offline:
+            doc = backend(_normalize(candidate, code))
+            if doc is not None:
+                return doc
+        doc = bundled_doc(candidate, code)
+        # A bare URL template says nothing about codes given without a tool
+        if doc is not None and (wanted or doc.summary):
+            return doc
+    return None
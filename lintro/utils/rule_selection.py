@@ -0,0 +1,191 @@
+"""Cross-tool rule selection for ``--select`` and ``--ignore``.
+
+Patterns name rules the way the tools do, optionally qualified by the tool,
+and may use globs::
+
+    ruff:E5*            ruff rules starting with E5
+    clippy::*           every clippy lint
+    E501                E501 from any tool
+    *:W*                rules starting with W from any tool
+
+Issues are selected after parsing, so a focused run does not require
+editing each tool's configuration. Like ``--filter``, issues that are not
+selected are neither displayed nor counted.
+"""
+
+from __future__ import annotations
+
+import fnmatch
+from collections.abc import Iterable
+from dataclasses import dataclass, replace
+
+from lintro.enums.tool_name import ToolName
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.effort import issue_code
+from lintro.utils.execution.exit_codes import success_after_filter
+
+_GLOB_CHARS = frozenset("*?[")
+
+
+@dataclass(frozen=True)
+class RulePattern:
+    """One ``--select`` or ``--ignore`` pattern.
+
+    Attributes:
+        text: Pattern as given.
+        tool: Glob over lower-case tool names; ``*`` for any tool.
+        code: Glob over lower-case rule codes.
+    """
+
+    text: str
+    tool: str
+    code: str
+
+    def matches(self, tool: str, code: str) -> bool:
+        """Check whether a tool's rule matches the pattern.
+
+        Codes that carry the tool's own prefix, like clippy's
+        ``clippy::needless_return``, also match without it.
+
+        Args:
+            tool: Tool that reported the issue.
+            code: Rule code of the issue.
+
+        Returns:
+            bool: True if both the tool and the code match.
+        """
+        tool = tool.lower()
+        if not fnmatch.fnmatchcase(tool, self.tool):
+            return False
+        code = code.lower()
+        bare = code.removeprefix(f"{tool}::")
+        return fnmatch.fnmatchcase(code, self.code) or fnmatch.fnmatchcase(
+            bare,
+            self.code,
+        )
+
+
+def parse_rule_pattern(text: str) -> RulePattern:
+    """Parse a ``tool:rule`` pattern.
+
+    A prefix is read as the tool when it names a known tool or is a glob;
+    otherwise the whole pattern is a rule code.
+
+    Args:
+        text: Pattern such as ``ruff:E5*``, ``clippy::*`` or ``E501``.
+
+    Returns:
+        RulePattern: Parsed pattern.
+
+    Raises:
+        ValueError: If the pattern names no rule.
+    """
+    text = text.strip()
+    prefix, sep, rest = text.partition(":")
+    tool = prefix.lower().replace("-", "_")
+    known = tool in {name.value for name in ToolName}
+    if sep and (known or _GLOB_CHARS & set(tool)):
+        code = rest.lstrip(":").strip().lower()
+    else:
+        tool, code = "*", text.lower()
+    if not code:
+        raise ValueError(f"Rule pattern '{text}' names no rule; use '{prefix}:*'")
+    return RulePattern(text=text, tool=tool or "*", code=code)
+
+
+@dataclass(frozen=True)
+class RuleSelection:
+    """Rules selected with ``--select`` minus those in ``--ignore``.
+
+    Attributes:
+        select: Patterns of the rules to keep; empty keeps every rule.
+        ignore: Patterns of the rules to drop, applied after ``select``.
+    """
+
+    select: tuple[RulePattern, ...] = ()
+    ignore: tuple[RulePattern, ...] = ()
+
+    @classmethod
+    def from_patterns(
+        cls,
+        select: Iterable[str] = (),
+        ignore: Iterable[str] = (),
+    ) -> RuleSelection | None:
+        """Parse comma-separated patterns from the command line.
+
+        Args:
+            select: Values of ``--select``; each may hold several patterns.
+            ignore: Values of ``--ignore``; each may hold several patterns.
+
+        Returns:
+            RuleSelection | None: Selection, or None without any pattern.
+        """
+
+        def parse(values: Iterable[str]) -> tuple[RulePattern, ...]:
+            """Parse every pattern of the given values.
+
+            Args:
+                values: Comma-separated patterns.
+
+            Returns:
+                tuple[RulePattern, ...]: Parsed patterns.
+            """
+            return tuple(
+                parse_rule_pattern(part)
+                for value in values
+                for part in value.split(",")
+                if part.strip()
+            )
+
+        selection = cls(select=parse(select), ignore=parse(ignore))
+        return selection if selection.select or selection.ignore else None
+
+    def matches(self, tool: str, code: str) -> bool:
+        """Check whether an issue's rule is selected.
+
+        Args:
+            tool: Tool that reported the issue.
+            code: Rule code of the issue; issues without a code only match
+                ``*`` patterns.
+
+        Returns:
+            bool: True if the rule is selected and not ignored.
+        """
+        if self.select and not any(p.matches(tool, code) for p in self.select):
+            return False
+        return not any(p.matches(tool, code) for p in self.ignore)
+
+
+def apply_rule_selection(
+    result: ToolResult,
+    selection: RuleSelection | None,
+) -> ToolResult:
+    """Keep only the issues of a check result whose rules are selected.
+
+    Args:
+        result: Result after severity remapping.
+        selection: Parsed ``--select`` and ``--ignore`` patterns, or None to
+            keep everything.
+
+    Returns:
+        ToolResult: Result with unselected issues removed and the count
+        updated; a result left without issues is marked successful.
+    """
+    if selection is None or result.skipped or not result.issues:
+        return result
+
+    kept = [
+        issue
+        for issue in result.issues
+        if selection.matches(result.name, issue_code(issue))
+    ]
+    if len(kept) == len(result.issues):
+        return result
+
+    return replace(
+        result,
+        issues=kept,
+        issues_count=len(kept),
+        success=success_after_filter(result, len(kept)),
+        formatted_output=None,
+    )
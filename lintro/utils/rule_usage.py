@@ -0,0 +1,344 @@
+"""Rule usage analytics from past runs.
+
+Every ``lintro check`` and ``lintro format`` run leaves a ``summary.csv`` in
+its ``.lintro/run-*`` directory listing the tools that ran, whether they
+were skipped or failed, and the issues they reported. Only the last few run
+directories are kept; when ``execution.history`` names a run history
+database, the rule counts recorded there reach further back. ``lintro stats
+--rule-usage`` reads the most recent runs and points at configuration that
+is doing nothing:
+
+- tools that ran but reported no issue in any of the runs,
+- rules selected explicitly in the config (ruff ``select`` and
+  ``extend-select``, oxlint ``deny`` and ``warn``) that never fired,
+- suppression comments that no longer match anything, as reported by the
+  tools themselves (ruff ``RUF100``, mypy ``unused-ignore``).
+"""
+
+from __future__ import annotations
+
+import csv
+import os
+from collections.abc import Mapping, Sequence
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from lintro.utils.config import load_lintro_tool_config, load_ruff_config
+from lintro.utils.output.constants import (
+    DEFAULT_BASE_DIR,
+    DEFAULT_KEEP_LAST,
+    DEFAULT_RUN_PREFIX,
+    SUMMARY_STATUS_FAILED,
+    SUMMARY_STATUS_SKIPPED,
+)
+
+SUMMARY_FILE: str = "summary.csv"
+DEFAULT_RUNS: int = DEFAULT_KEEP_LAST
+
+# Codes a tool reports for a suppression comment that matched nothing
+UNUSED_SUPPRESSION_CODES: dict[str, frozenset[str]] = {
+    "ruff": frozenset({"RUF100"}),
+    "mypy": frozenset({"unused-ignore"}),
+}
+
+# Oxlint accepts whole categories in deny/warn; they are not single rules
+OXLINT_CATEGORIES: frozenset[str] = frozenset(
+    {
+        "all",
+        "correctness",
+        "nursery",
+        "pedantic",
+        "perf",
+        "restriction",
+        "style",
+        "suspicious",
+    },
+)
+
+
+@dataclass(frozen=True)
+class RecordedIssue:
+    """An issue as stored in a run's summary.
+
+    Attributes:
+        tool: Tool that reported it.
+        file: File it was reported in.
+        line: Line number, 0 if unknown.
+        code: Rule code.
+        message: Issue message.
+    """
+
+    tool: str
+    file: str
+    line: int
+    code: str
+    message: str
+
+
+@dataclass
+class RunRecord:
+    """Tools and issues of one past run.
+
+    Attributes:
+        name: Run directory name, such as ``run-20250101-120000``.
+        tools: Tools that ran, in report order; skipped tools and tools that
+            failed to run are left out.
+        issues: Issues the tools reported.
+    """
+
+    name: str
+    tools: list[str] = field(default_factory=list)
+    issues: list[RecordedIssue] = field(default_factory=list)
+
+
+@dataclass
+class RuleUsageReport:
+    """Configuration that produced nothing over a span of runs.
+
+    Attributes:
+        runs: Number of runs examined.
+        silent_tools: Tools without a single issue, with the number of runs
+            they took part in.
+        unused_rules: Explicitly selected rules that never fired, as
+            (tool, rule) pairs.
+        unused_suppressions: Suppression comments the tools reported as
+            unused in their latest run.
+    """
+
+    runs: int
+    silent_tools: list[tuple[str, int]] = field(default_factory=list)
+    unused_rules: list[tuple[str, str]] = field(default_factory=list)
+    unused_suppressions: list[RecordedIssue] = field(default_factory=list)
+
+    @property
+    def is_clean(self) -> bool:
+        """Return whether nothing unused was found.
+
+        Returns:
+            bool: True when all three lists are empty.
+        """
+        return not (
+            self.silent_tools or self.unused_rules or self.unused_suppressions
+        )
+
+
+def _read_summary(run_dir: Path) -> RunRecord:
+    """Read the summary of one run.
+
+    Args:
+        run_dir: Run directory holding ``summary.csv``.
+
+    Returns:
+        RunRecord: Tools and issues of the run.
+    """
+    record = RunRecord(name=run_dir.name)
+    with open(run_dir / SUMMARY_FILE, encoding="utf-8", newline="") as f:
+        for row in csv.DictReader(f):
+            tool = row.get("tool") or ""
+            if not tool:
+                continue
+            # Skipped tools and tools that failed to run say nothing about rules
+            if row.get("status") in (SUMMARY_STATUS_SKIPPED, SUMMARY_STATUS_FAILED):
+                continue
+            if tool not in record.tools:
+                record.tools.append(tool)
+            # Tools without issues are written as a row with no file
+            if not row.get("file"):
+                continue
+            line = row.get("line") or "0"
+            record.issues.append(
+                RecordedIssue(
+                    tool=tool,
+                    file=row["file"],
+                    line=int(line) if line.isdigit() else 0,
+                    code=row.get("code") or "",
+                    message=row.get("message") or "",
+                ),
+            )
+    return record
+
+
+def load_run_history(
+    base_dir: str | None = None,
+    limit: int = DEFAULT_RUNS,
+) -> list[RunRecord]:
+    """Load the most recent runs that wrote a summary.
+
+    Args:
+        base_dir: Directory holding the run directories. Defaults to
+            ``LINTRO_LOG_DIR`` or ``.lintro``, as for the runs themselves.
+        limit: Number of runs to load.
+
+    Returns:
+        list[RunRecord]: Runs, newest first; empty if there is no history.
+    """
+    base = Path(base_dir or os.environ.get("LINTRO_LOG_DIR") or DEFAULT_BASE_DIR)
+    if not base.is_dir():
+        return []
+    run_dirs = sorted(
+        (
+            d
+            for d in base.iterdir()
+            if d.name.startswith(DEFAULT_RUN_PREFIX) and (d / SUMMARY_FILE).is_file()
+        ),
+        key=lambda d: d.name,
+        reverse=True,
+    )
+    return [_read_summary(d) for d in run_dirs[:limit]]
+
+
+def load_stored_history(path: str, limit: int = DEFAULT_RUNS) -> list[RunRecord]:
+    """Load the most recent runs from the run history database.
+
+    The database keeps every check run but only issue counts per rule, so
+    the issues read from it have no file, line or message.
+
+    Args:
+        path: Database file set with ``execution.history``.
+        limit: Number of runs to load.
+
+    Returns:
+        list[RunRecord]: Runs, newest first; empty if the database does not
+        exist yet.
+    """
+    from lintro.utils.execution.run_history import load_runs
+
+    try:
+        runs = load_runs(path, limit=limit)
+    except FileNotFoundError:
+        return []
+    return [
+        RunRecord(
+            name=f"history-{run.id}",
+            tools=[tool for tool in run.tools if tool not in run.failed],
+            issues=[
+                RecordedIssue(tool=c.tool, file="", line=0, code=c.code, message="")
+                for c in run.counts
+                if c.tool not in run.failed
+            ],
+        )
+        for run in reversed(runs)
+    ]
+
+
+def _as_list(value: object) -> list[str]:
+    """Normalize a config value holding one rule or a list of rules.
+
+    Args:
+        value: Config value.
+
+    Returns:
+        list[str]: Rules as strings.
+    """
+    if isinstance(value, str):
+        return [part.strip() for part in value.split(",") if part.strip()]
+    if isinstance(value, list):
+        return [str(item) for item in value]
+    return []
+
+
+def load_configured_rules() -> dict[str, list[str]]:
+    """Collect the rules selected explicitly in the project config.
+
+    Returns:
+        dict[str, list[str]]: Selected rules by tool, without duplicates.
+    """
+    ruff_native = load_ruff_config()
+    ruff_lintro = load_lintro_tool_config("ruff")
+    oxlint = load_lintro_tool_config("oxlint")
+    sources = {
+        "ruff": [
+            ruff_native.get("select"),
+            ruff_native.get("extend_select"),
+            ruff_lintro.get("select"),
+            ruff_lintro.get("extend_select"),
+        ],
+        "oxlint": [oxlint.get("deny"), oxlint.get("warn")],
+    }
+    configured: dict[str, list[str]] = {}
+    for tool, values in sources.items():
+        rules = list(dict.fromkeys(r for v in values for r in _as_list(v)))
+        if tool == "oxlint":
+            rules = [r for r in rules if r not in OXLINT_CATEGORIES]
+        if rules:
+            configured[tool] = rules
+    return configured
+
+
+def rule_matches(tool: str, rule: str, code: str) -> bool:
+    """Check whether a reported code falls under a configured rule.
+
+    Ruff selectors are code prefixes (``E`` covers ``E501``) and ``ALL``
+    covers every code. Oxlint reports ``plugin(rule)`` codes and is
+    configured with ``rule`` or ``plugin/rule``.
+
+    Args:
+        tool: Tool the rule is configured for.
+        rule: Configured rule or selector.
+        code: Code of a reported issue.
+
+    Returns:
+        bool: True if the issue was raised by the rule.
+    """
+    if tool == "ruff":
+        return rule == "ALL" or code.startswith(rule)
+    if tool == "oxlint" and code.endswith(")") and "(" in code:
+        name = code[code.index("(") + 1 : -1]
+        return rule == code or rule.rsplit("/", 1)[-1] == name
+    return rule == code
+
+
+def analyze_rule_usage(
+    history: Sequence[RunRecord],
+    configured: Mapping[str, Sequence[str]],
+    latest: Sequence[RunRecord] | None = None,
+) -> RuleUsageReport:
+    """Find tools, rules and suppressions that did nothing.
+
+    Only tools that appear in the history are judged; rules of a tool that
+    never ran, was skipped or failed to run are not reported as unused.
+
+    Args:
+        history: Past runs, newest first.
+        configured: Explicitly selected rules by tool.
+        latest: Runs with full issue details to find unused suppressions
+            in, newest first; defaults to ``history``.
+
+    Returns:
+        RuleUsageReport: Unused configuration.
+    """
+    report = RuleUsageReport(runs=len(history))
+    runs_per_tool: dict[str, int] = {}
+    codes_per_tool: dict[str, set[str]] = {}
+    for run in history:
+        for tool in run.tools:
+            runs_per_tool[tool] = runs_per_tool.get(tool, 0) + 1
+            codes_per_tool.setdefault(tool, set())
+        for issue in run.issues:
+            codes_per_tool.setdefault(issue.tool, set()).add(issue.code)
+
+    for tool in sorted(runs_per_tool):
+        if not codes_per_tool[tool]:
+            report.silent_tools.append((tool, runs_per_tool[tool]))
+
+    for tool, rules in configured.items():
+        if tool not in runs_per_tool:
+            continue
+        codes = codes_per_tool[tool]
+        report.unused_rules.extend(
+            (tool, rule)
+            for rule in rules
+            if not any(rule_matches(tool, rule, code) for code in codes)
+        )
+
+    # Older runs may list comments that have since been removed
+    judged: set[str] = set()
+    for run in history if latest is None else latest:
+        for issue in run.issues:
+            if issue.tool in judged:
+                continue
+            if issue.code in UNUSED_SUPPRESSION_CODES.get(issue.tool, frozenset()):
+                report.unused_suppressions.append(issue)
+        judged.update(run.tools)
+
+    return report
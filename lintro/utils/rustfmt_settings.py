@@ -0,0 +1,151 @@
+"""Settings rustfmt formats a crate with.
+
+``cargo fmt`` passes each crate's ``edition`` from its Cargo.toml to
+rustfmt, which reads the nearest ``.rustfmt.toml`` or ``rustfmt.toml`` above
+each file. Two settings get lost on the way without a word: an ``edition``
+in the rustfmt config loses to the crate's, and stable rustfmt ignores
+nightly-only options with a warning that lintro's diff parsing would hide.
+These helpers read the crate edition and the config so the rustfmt plugin
+can report both.
+"""
+
+from __future__ import annotations
+
+import re
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+# Config file names in the order rustfmt looks for them in a directory
+RUSTFMT_CONFIG_NAMES: tuple[str, ...] = (".rustfmt.toml", "rustfmt.toml")
+
+# Edition of crates whose manifest sets none
+DEFAULT_EDITION: str = "2015"
+
+
+@dataclass(frozen=True)
+class RustfmtSettings:
+    """Edition and config rustfmt formats one crate with.
+
+    Attributes:
+        crate_dir: Directory containing the crate's Cargo.toml.
+        edition: Edition from the crate's manifest, which cargo fmt passes.
+        config_path: Nearest rustfmt config above the crate, if any.
+        config_edition: Edition the rustfmt config sets, if any.
+    """
+
+    crate_dir: Path
+    edition: str
+    config_path: Path | None = None
+    config_edition: str | None = None
+
+    @property
+    def edition_mismatch(self) -> bool:
+        """Whether the rustfmt config sets an edition cargo fmt overrides.
+
+        Returns:
+            bool: True if the config's edition differs from the crate's.
+        """
+        return self.config_edition not in (None, self.edition)
+
+
+def _read_toml(path: Path) -> dict[str, Any]:
+    """Read a TOML file.
+
+    Args:
+        path: File to read.
+
+    Returns:
+        dict[str, Any]: Parsed document, empty if missing or invalid.
+    """
+    try:
+        return tomllib.loads(path.read_text(encoding="utf-8"))
+    except (OSError, tomllib.TOMLDecodeError):
+        return {}
+
+
+def crate_edition(crate_dir: Path) -> str:
+    """Read a crate's edition from its Cargo.toml.
+
+    ``edition.workspace = true`` is resolved from the
+    ``[workspace.package]`` table of the nearest workspace manifest.
+
+    Args:
+        crate_dir: Directory containing the crate's Cargo.toml.
+
+    Returns:
+        str: Edition, ``2015`` when the manifest sets none.
+    """
+    package = _read_toml(crate_dir / "Cargo.toml").get("package")
+    edition = package.get("edition") if isinstance(package, dict) else None
+    if isinstance(edition, dict) and edition.get("workspace") is True:
+        edition = None
+        for directory in [crate_dir, *crate_dir.parents]:
+            workspace = _read_toml(directory / "Cargo.toml").get("workspace")
+            if isinstance(workspace, dict):
+                shared = workspace.get("package")
+                if isinstance(shared, dict):
+                    edition = shared.get("edition")
+                break
+    return str(edition) if isinstance(edition, (str, int)) else DEFAULT_EDITION
+
+
+def find_rustfmt_config(start: Path) -> Path | None:
+    """Find the rustfmt config that applies to a directory.
+
+    Args:
+        start: Directory to search from, upwards.
+
+    Returns:
+        Path | None: Nearest ``.rustfmt.toml`` or ``rustfmt.toml``, if any.
+    """
+    for directory in [start, *start.parents]:
+        for name in RUSTFMT_CONFIG_NAMES:
+            candidate = directory / name
+            if candidate.is_file():
+                return candidate
+    return None
+
+
+def config_option_line(config_path: Path, option: str) -> int:
+    """Find the line a rustfmt config sets an option on.
+
+    Args:
+        config_path: rustfmt config file.
+        option: Option name, such as ``imports_granularity``.
+
+    Returns:
+        int: 1-based line, or 0 if the config does not set the option.
+    """
+    pattern = re.compile(rf"^\s*{re.escape(option)}\s*=")
+    try:
+        lines = config_path.read_text(encoding="utf-8").splitlines()
+    except OSError:
+        return 0
+    return next(
+        (number for number, line in enumerate(lines, 1) if pattern.match(line)),
+        0,
+    )
+
+
+def rustfmt_settings(crate_dir: Path) -> RustfmtSettings:
+    """Work out the edition and config rustfmt formats a crate with.
+
+    Args:
+        crate_dir: Directory containing the crate's Cargo.toml.
+
+    Returns:
+        RustfmtSettings: The crate's settings.
+    """
+    config_path = find_rustfmt_config(crate_dir)
+    config_edition = None
+    if config_path is not None:
+        value = _read_toml(config_path).get("edition")
+        config_edition = str(value) if isinstance(value, (str, int)) else None
+    return RustfmtSettings(
+        crate_dir=crate_dir,
+        edition=crate_edition(crate_dir),
+        config_path=config_path,
+        config_edition=config_edition,
+    )
@@ -0,0 +1,74 @@
+"""Importing SARIF logs written by tools lintro does not run.
+
+``lintro ingest`` turns each tool of the logs into a ToolResult, so the
+results of scanners such as CodeQL go through the same severity overrides,
+suppressions, failure thresholds, formatters and report files as the
+results of the tools lintro runs itself.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from collections.abc import Sequence
+from pathlib import Path
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.sarif import parse_sarif_log
+
+
+def result_name(driver_name: str) -> str:
+    """Derive the tool name of imported results from a SARIF driver name.
+
+    Args:
+        driver_name: ``tool.driver.name`` of a SARIF run, e.g. ``CodeQL``.
+
+    Returns:
+        str: Lower-case name with runs of other characters turned into
+        dashes, like the names of lintro's tools.
+    """
+    return re.sub(r"[^a-z0-9_]+", "-", driver_name.lower()).strip("-") or "sarif"
+
+
+def load_sarif_results(
+    paths: Sequence[str | Path],
+    tool_name: str | None = None,
+) -> list[ToolResult]:
+    """Read SARIF logs into one result per tool.
+
+    Args:
+        paths: SARIF files.
+        tool_name: Name given to every imported result instead of the
+            driver names of the logs' runs.
+
+    Returns:
+        list[ToolResult]: Results in the order their tools first appear.
+
+    Raises:
+        ValueError: If a file is not valid JSON or not a SARIF log.
+        OSError: If a file cannot be read.
+    """
+    issues: dict[str, list[BaseIssue]] = {}
+    for path in paths:
+        try:
+            data = json.loads(Path(path).read_text(encoding="utf-8"))
+        except json.JSONDecodeError as e:
+            raise ValueError(f"{path}: invalid JSON: {e}") from e
+        try:
+            runs = parse_sarif_log(data)
+        except ValueError as e:
+            raise ValueError(f"{path}: {e}") from e
+        for driver_name, run_issues in runs.items():
+            name = tool_name or result_name(driver_name)
+            issues.setdefault(name, []).extend(run_issues)
+    return [
+        ToolResult(
+            name=name,
+            success=not tool_issues,
+            output="",
+            issues_count=len(tool_issues),
+            issues=tool_issues,
+        )
+        for name, tool_issues in issues.items()
+    ]
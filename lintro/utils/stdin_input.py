@@ -0,0 +1,117 @@
+"""Checking content given on stdin as if it were a named file.
+
+Editors lint unsaved buffers with ``lintro check --stdin --stdin-filename
+src/main.rs``. The buffer is written to a temporary copy under the same name
+in a hidden sibling directory (``src/.lintro-stdin-<token>/main.rs``), so
+tools are chosen by the file's name and find the same project configuration
+as for the file itself. Issues reported in the copy are attributed to the
+named file, and the copy is removed after the run. Tools that check a whole
+project rather than files would lint the saved tree instead of the buffer, so
+they are skipped.
+"""
+
+from __future__ import annotations
+
+import os
+import secrets
+import shutil
+from collections.abc import Iterator
+from contextlib import contextmanager
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from lintro.utils.path_utils import relative_to_root
+
+if TYPE_CHECKING:
+    from lintro.utils.execution.tool_configuration import ToolsToRunResult
+
+# Prefix of the hidden directory holding the copy of a buffer
+STDIN_DIR_PREFIX = ".lintro-stdin-"
+
+# Skip reason of tools that cannot check a buffer on its own
+WHOLE_PROJECT_REASON = "checks the whole project, not applicable to --stdin"
+
+
+@dataclass(frozen=True)
+class StdinBuffer:
+    """A buffer given on stdin and the copy of it that tools check.
+
+    Attributes:
+        filename: Path of the file the buffer stands for, relative to the
+            project root.
+        copy: Temporary file holding the buffer.
+    """
+
+    filename: str
+    copy: Path
+
+    @property
+    def path_aliases(self) -> dict[str, str]:
+        """Map the copy to the file it stands for.
+
+        Tools that run in the copy's directory report it by name alone.
+
+        Returns:
+            dict[str, str]: Project-relative path and name of the copy mapped
+            to the file name, as used when normalizing issue paths.
+        """
+        return {
+            relative_to_root(str(self.copy), os.getcwd()): self.filename,
+            self.copy.name: self.filename,
+        }
+
+
+@contextmanager
+def stdin_buffer(content: bytes, filename: str) -> Iterator[StdinBuffer]:
+    """Write a buffer next to the file it stands for while it is checked.
+
+    Args:
+        content: Contents of the buffer, written unchanged.
+        filename: Path of the file, absolute or relative to the working
+            directory; the file itself need not exist.
+
+    Yields:
+        StdinBuffer: The buffer and its temporary copy.
+
+    Raises:
+        ValueError: If the file's directory does not exist.
+    """
+    target = Path(filename)
+    directory = target.parent
+    if not directory.is_dir():
+        raise ValueError(f"directory of {filename} does not exist")
+    holder = directory / f"{STDIN_DIR_PREFIX}{secrets.token_hex(4)}"
+    holder.mkdir()
+    try:
+        copy = holder / target.name
+        copy.write_bytes(content)
+        yield StdinBuffer(
+            filename=relative_to_root(str(target.resolve()), os.getcwd()),
+            copy=copy.resolve(),
+        )
+    finally:
+        shutil.rmtree(holder, ignore_errors=True)
+
+
+def skip_whole_project_tools(tools_result: ToolsToRunResult) -> None:
+    """Move tools that check a whole project out of a stdin run.
+
+    Tools such as clippy, mypy or tsc (``per_file`` off) read the project's
+    files from disk rather than the paths they are given.
+
+    Args:
+        tools_result: Selected tools; updated in place.
+    """
+    from lintro.tools import tool_manager
+    from lintro.utils.execution.tool_configuration import SkippedTool
+
+    to_run: list[str] = []
+    for name in tools_result.to_run:
+        if tool_manager.get_tool(name).definition.per_file:
+            to_run.append(name)
+        else:
+            tools_result.skipped.append(
+                SkippedTool(name=name, reason=WHOLE_PROJECT_REASON),
+            )
+    tools_result.to_run = to_run
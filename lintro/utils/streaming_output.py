@@ -179,6 +179,10 @@ class StreamingResultHandler:
 
         if result.output:
             data["output"] = result.output
+        if result.error_code:
+            data["error_code"] = result.error_code
+        if result.duration_ms is not None:
+            data["duration_ms"] = result.duration_ms
 
         if result.initial_issues_count is not None:
             data["initial_issues_count"] = result.initial_issues_count
@@ -16,6 +16,7 @@ from lintro.utils.console import (
     get_summary_value,
     get_tool_emoji,
 )
+from lintro.utils.effort import format_effort
 
 # Constants
 DEFAULT_REMAINING_COUNT: str = "?"
@@ -466,6 +467,7 @@ def print_totals_table(
     severity_errors: int = 0,
     severity_warnings: int = 0,
     severity_info: int = 0,
+    effort_minutes: int | None = None,
 ) -> None:
     """Print a totals summary table for the run.
 
@@ -479,6 +481,7 @@ def print_totals_table(
         severity_errors: Number of issues at ERROR severity.
         severity_warnings: Number of issues at WARNING severity.
         severity_info: Number of issues at INFO severity.
+        effort_minutes: Estimated minutes to fix all issues (CHECK mode).
     """
     try:
         import click
@@ -502,6 +505,8 @@ def print_totals_table(
                 rows.append(["  Warnings", severity_warnings])
                 rows.append(["  Info", severity_info])
             rows.append(["Affected Files", affected_files])
+            if effort_minutes and total_issues > 0:
+                rows.append(["Estimated Effort", format_effort(effort_minutes)])
 
         headers: list[str] = ["Metric", "Count"]
         table: str = tabulate(
@@ -0,0 +1,151 @@
+"""Decoding of tool output that is not guaranteed to be valid text.
+
+Tools print file names and messages in whatever encoding their platform
+uses: UTF-8 with or without a byte order mark, UTF-16 on some Windows tools,
+the locale's code page, or raw bytes of a latin-1 file name. Output is
+decoded as UTF-8, then in the locale's encoding; bytes neither can decode
+are replaced with U+FFFD and reported as ``LINTRO014``, so one odd file
+name costs a replaced character instead of the whole run.
+"""
+
+from __future__ import annotations
+
+import codecs
+import locale
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+from lintro.exceptions.errors import OUTPUT_NOT_DECODABLE
+
+# Byte order marks and the encodings they announce, longest first
+_BOMS: tuple[tuple[bytes, str], ...] = (
+    (codecs.BOM_UTF8, "utf-8"),
+    (codecs.BOM_UTF16_LE, "utf-16-le"),
+    (codecs.BOM_UTF16_BE, "utf-16-be"),
+)
+
+REPLACEMENT_CHARACTER: str = "\ufffd"
+
+
+@dataclass(frozen=True)
+class DecodedOutput:
+    """Tool output decoded to text.
+
+    Attributes:
+        text: Decoded text with ``\\n`` line breaks and no byte order mark.
+        encoding: Encoding the output was decoded with.
+        replaced: Undecodable byte sequences replaced with U+FFFD.
+    """
+
+    text: str
+    encoding: str
+    replaced: int = 0
+
+
+def _locale_encoding() -> str | None:
+    """Return the locale's encoding when it differs from UTF-8.
+
+    Returns:
+        str | None: Codec name, or None for UTF-8 or an unknown codec.
+    """
+    try:
+        name = codecs.lookup(locale.getpreferredencoding(False)).name
+    except LookupError:
+        return None
+    return None if name == "utf-8" else name
+
+
+def _newlines(text: str) -> str:
+    """Translate line breaks to ``\\n``, as text-mode pipes do.
+
+    Args:
+        text: Decoded text.
+
+    Returns:
+        str: Text with ``\\r\\n`` and ``\\r`` line breaks replaced.
+    """
+    return text.replace("\r\n", "\n").replace("\r", "\n")
+
+
+def _decode_replacing(data: bytes, encoding: str) -> DecodedOutput:
+    """Decode bytes, replacing the sequences that are invalid.
+
+    Args:
+        data: Raw output.
+        encoding: Encoding to decode with.
+
+    Returns:
+        DecodedOutput: Text and the number of replaced sequences.
+    """
+    text = data.decode(encoding, errors="replace")
+    # Each replaced sequence adds one U+FFFD the bytes did not contain
+    kept = data.decode(encoding, errors="ignore")
+    replaced = text.count(REPLACEMENT_CHARACTER) - kept.count(REPLACEMENT_CHARACTER)
+    return DecodedOutput(_newlines(text), encoding, replaced)
+
+
+def decode_output(data: bytes) -> DecodedOutput:
+    """Decode the output of a tool without failing on invalid bytes.
+
+    Args:
+        data: Raw output.
+
+    Returns:
+        DecodedOutput: Text and how it was decoded.
+    """
+    for bom, encoding in _BOMS:
+        if data.startswith(bom):
+            data = data[len(bom) :]
+            if encoding != "utf-8":
+                return _decode_replacing(data, encoding)
+            break
+
+    candidates = ["utf-8"]
+    fallback = _locale_encoding()
+    if fallback is not None:
+        candidates.append(fallback)
+    for encoding in candidates:
+        try:
+            return DecodedOutput(_newlines(data.decode(encoding)), encoding)
+        except UnicodeDecodeError:
+            continue
+
+    return _decode_replacing(data, "utf-8")
+
+
+def report_undecodable(source: str, replaced: int, **fields: Any) -> None:
+    """Warn that a tool printed bytes that are not valid text.
+
+    Args:
+        source: Tool or command that produced the output.
+        replaced: Byte sequences replaced with U+FFFD.
+        **fields: Extra details for JSON logs.
+    """
+    logger.bind(
+        event="decode",
+        error_code=OUTPUT_NOT_DECODABLE.code,
+        replaced=replaced,
+        **fields,
+    ).warning(
+        f"[{OUTPUT_NOT_DECODABLE.code}] {source} printed {replaced} "
+        "undecodable byte sequence(s); they were replaced with U+FFFD",
+    )
+
+
+def read_output_file(path: str | Path, source: str) -> str:
+    """Read a report file written by a tool.
+
+    Args:
+        path: Report file.
+        source: Tool that wrote the report.
+
+    Returns:
+        str: Decoded contents.
+    """
+    decoded = decode_output(Path(path).read_bytes())
+    if decoded.replaced:
+        report_undecodable(source, decoded.replaced, path=str(path))
+    return decoded.text
@@ -0,0 +1,81 @@
+"""Timestamps and durations for reports.
+
+Reports used to mix local naive times, ``+00:00`` offsets with microseconds
+and ``strftime`` renderings, so the same run diffed differently depending on
+the machine that produced it. Every stored timestamp is now RFC 3339 UTC with
+second precision (``2026-10-15T09:30:12Z``). Console and HTML output render
+UTC too unless ``output.local_time`` is set. Durations are measured with the
+monotonic clock and reported separately as whole milliseconds, so a clock
+change during a run cannot make them negative.
+"""
+
+from __future__ import annotations
+
+import datetime
+import time
+
+
+def utc_timestamp(moment: datetime.datetime | None = None) -> str:
+    """Format a time as an RFC 3339 UTC timestamp.
+
+    Args:
+        moment: Time to format; naive times are taken as UTC. Defaults to now.
+
+    Returns:
+        str: Timestamp such as ``2026-10-15T09:30:12Z``.
+    """
+    if moment is None:
+        moment = datetime.datetime.now(datetime.UTC)
+    elif moment.tzinfo is None:
+        moment = moment.replace(tzinfo=datetime.UTC)
+    return moment.astimezone(datetime.UTC).strftime("%Y-%m-%dT%H:%M:%SZ")
+
+
+def parse_timestamp(value: str) -> datetime.datetime:
+    """Parse a stored timestamp.
+
+    Accepts the RFC 3339 form written by utc_timestamp() as well as the
+    ISO 8601 forms written by earlier releases.
+
+    Args:
+        value: Timestamp to parse.
+
+    Returns:
+        datetime.datetime: Timezone-aware time; naive values are taken as UTC.
+    """
+    moment = datetime.datetime.fromisoformat(value)
+    if moment.tzinfo is None:
+        moment = moment.replace(tzinfo=datetime.UTC)
+    return moment
+
+
+def render_timestamp(moment: datetime.datetime, local: bool = False) -> str:
+    """Render a time for people to read.
+
+    The rendering does not depend on the locale.
+
+    Args:
+        moment: Timezone-aware time.
+        local: Render in the local timezone instead of UTC.
+
+    Returns:
+        str: Time such as ``2026-10-15 09:30:12 UTC`` or, in local time,
+        ``2026-10-15 11:30:12 +02:00``.
+    """
+    if not local:
+        return moment.astimezone(datetime.UTC).strftime("%Y-%m-%d %H:%M:%S UTC")
+    local_moment = moment.astimezone()
+    offset = local_moment.strftime("%z")
+    return f"{local_moment:%Y-%m-%d %H:%M:%S} {offset[:3]}:{offset[3:]}"
+
+
+def elapsed_ms(started: float) -> int:
+    """Return the milliseconds since a monotonic clock reading.
+
+    Args:
+        started: Value of time.monotonic() when the measured work began.
+
+    Returns:
+        int: Elapsed whole milliseconds.
+    """
+    return round((time.monotonic() - started) * 1000)
@@ -0,0 +1,414 @@
+"""Where the wall-clock time of a run went, for ``--profile-timing``.
+
+Each tool's duration is split into phases:
+
+- startup: from the tool's start to its first subprocess, covering file
+  discovery, version checks and option handling;
+- execution: the tool's subprocesses, one chunk per invocation;
+- parsing: the rest of the tool's time, mostly reading its output into
+  issues;
+- formatting: lintro's post-processing and rendering of the tool's results.
+
+Tools that run no subprocess, such as native checks, count their whole
+duration as execution. A chunk's time is shared evenly among the files
+named on its command line, so the slowest-files list is exact for tools run
+once per file and an estimate for batched runs.
+
+Subprocesses report themselves through ``record_invocation``, which is a
+no-op outside ``measure_tool``, so runs without the flag pay nothing.
+"""
+
+from __future__ import annotations
+
+import os
+import threading
+import time
+from collections.abc import Callable, Iterator, Sequence
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from typing import Any
+
+SLOWEST_FILES: int = 10
+
+# Profile, tool timing and start of the tool running in each thread
+_current = threading.local()
+
+
+@dataclass(frozen=True)
+class ChunkTiming:
+    """One subprocess invocation of a tool.
+
+    Attributes:
+        command: Program that ran.
+        files: Files named on the command line.
+        started_ms: Start, in milliseconds after the tool started.
+        duration_ms: Time the invocation took.
+    """
+
+    command: str
+    files: tuple[str, ...]
+    started_ms: int
+    duration_ms: int
+
+
+@dataclass
+class ToolTiming:
+    """Phases of one tool's run.
+
+    Attributes:
+        name: Tool name.
+        total_ms: Time the tool took, excluding formatting.
+        formatting_ms: Time spent post-processing and rendering its results.
+        chunks: Subprocess invocations, in start order.
+    """
+
+    name: str
+    total_ms: int = 0
+    formatting_ms: int = 0
+    chunks: list[ChunkTiming] = field(default_factory=list)
+
+    @property
+    def startup_ms(self) -> int:
+        """Time before the first subprocess started.
+
+        Returns:
+            int: Milliseconds; 0 for tools that ran no subprocess.
+        """
+        if not self.chunks:
+            return 0
+        return min(self.total_ms, min(c.started_ms for c in self.chunks))
+
+    @property
+    def execution_ms(self) -> int:
+        """Time spent in subprocesses.
+
+        Returns:
+            int: Milliseconds; the whole duration for tools that ran no
+            subprocess.
+        """
+        if not self.chunks:
+            return self.total_ms
+        return sum(c.duration_ms for c in self.chunks)
+
+    @property
+    def parsing_ms(self) -> int:
+        """Time left after startup and execution.
+
+        Returns:
+            int: Milliseconds, never negative.
+        """
+        return max(self.total_ms - self.startup_ms - self.execution_ms, 0)
+
+
+@dataclass(frozen=True)
+class FileTiming:
+    """Time a tool spent on one file.
+
+    Attributes:
+        tool: Tool name.
+        path: File path as passed to the tool.
+        duration_ms: Time attributed to the file.
+        estimated: Whether the time is a share of a batched invocation.
+    """
+
+    tool: str
+    path: str
+    duration_ms: int
+    estimated: bool
+
+
+class TimingProfile:
+    """Collects the timing of every tool of a run.
+
+    Tools may run in worker threads, so the tool a subprocess belongs to is
+    tracked per thread and updates are locked.
+    """
+
+    def __init__(self, clock: Callable[[], float] = time.monotonic) -> None:
+        """Start an empty profile.
+
+        Args:
+            clock: Monotonic clock, in seconds.
+        """
+        self.clock = clock
+        self.tools: dict[str, ToolTiming] = {}
+        self._lock = threading.Lock()
+
+    def _tool(self, name: str) -> ToolTiming:
+        """Return the timing of a tool, creating it on first use.
+
+        Args:
+            name: Tool name.
+
+        Returns:
+            ToolTiming: The tool's timing.
+        """
+        with self._lock:
+            return self.tools.setdefault(name, ToolTiming(name))
+
+    @contextmanager
+    def measure(self, name: str) -> Iterator[None]:
+        """Time a tool's run and attribute its subprocesses to it.
+
+        Args:
+            name: Tool name.
+
+        Yields:
+            None: While the tool runs in the calling thread.
+        """
+        timing = self._tool(name)
+        started = self.clock()
+        previous = getattr(_current, "tool", None)
+        _current.tool = (self, timing, started)
+        try:
+            yield
+        finally:
+            _current.tool = previous
+            timing.total_ms += round((self.clock() - started) * 1000)
+
+    def add_formatting(self, name: str, duration_ms: int) -> None:
+        """Add time spent post-processing and rendering a tool's results.
+
+        Args:
+            name: Tool name.
+            duration_ms: Time spent.
+        """
+        timing = self._tool(name)
+        with self._lock:
+            timing.formatting_ms += duration_ms
+
+    def record_chunk(
+        self,
+        cmd: Sequence[str],
+        cwd: str | None,
+        started: float,
+        duration_ms: int,
+    ) -> None:
+        """Record a subprocess of the tool running in this thread.
+
+        Subprocesses started outside ``measure`` belong to no tool and are
+        ignored.
+
+        Args:
+            cmd: Command and arguments that ran.
+            cwd: Working directory of the command.
+            started: Monotonic clock reading when the command started.
+            duration_ms: Time the command took.
+        """
+        current = getattr(_current, "tool", None)
+        if current is None or current[0] is not self or not cmd:
+            return
+        _, timing, tool_started = current
+        base = cwd or ""
+        files = tuple(
+            arg
+            for arg in cmd[1:]
+            if not arg.startswith("-") and os.path.isfile(os.path.join(base, arg))
+        )
+        chunk = ChunkTiming(
+            command=os.path.basename(cmd[0]),
+            files=files,
+            started_ms=max(round((started - tool_started) * 1000), 0),
+            duration_ms=duration_ms,
+        )
+        with self._lock:
+            timing.chunks.append(chunk)
+
+    def slowest_files(self, limit: int = SLOWEST_FILES) -> list[FileTiming]:
+        """Rank files by the time tools spent on them.
+
+        Args:
+            limit: Files to return.
+
+        Returns:
+            list[FileTiming]: Slowest files first, one entry per tool and
+            file.
+        """
+        totals: dict[tuple[str, str], tuple[float, bool]] = {}
+        for timing in self.tools.values():
+            for chunk in timing.chunks:
+                if not chunk.files:
+                    continue
+                share = chunk.duration_ms / len(chunk.files)
+                shared = len(chunk.files) > 1
+                for path in chunk.files:
+                    spent, estimated = totals.get((timing.name, path), (0.0, False))
+                    totals[(timing.name, path)] = (spent + share, estimated or shared)
+        ranked = sorted(totals.items(), key=lambda item: -item[1][0])[:limit]
+        return [
+            FileTiming(tool, path, round(spent), estimated)
+            for (tool, path), (spent, estimated) in ranked
+        ]
+
+    def to_dict(self) -> dict[str, Any]:
+        """Serialize the profile for JSON output.
+
+        Returns:
+            dict[str, Any]: Phases and chunks per tool, and the slowest
+            files.
+        """
+        return {
+            "tools": [
+                {
+                    "tool": t.name,
+                    "total_ms": t.total_ms + t.formatting_ms,
+                    "startup_ms": t.startup_ms,
+                    "execution_ms": t.execution_ms,
+                    "parsing_ms": t.parsing_ms,
+                    "formatting_ms": t.formatting_ms,
+                    "chunks": [
+                        {
+                            "command": c.command,
+                            "files": len(c.files),
+                            "started_ms": c.started_ms,
+                            "duration_ms": c.duration_ms,
+                        }
+                        for c in t.chunks
+                    ],
+                }
+                for t in self.ranked_tools()
+            ],
+            "slowest_files": [
+                {
+                    "tool": f.tool,
+                    "path": f.path,
+                    "duration_ms": f.duration_ms,
+                    "estimated": f.estimated,
+                }
+                for f in self.slowest_files()
+            ],
+        }
+
+    def ranked_tools(self) -> list[ToolTiming]:
+        """Order the tools by the time they took.
+
+        Returns:
+            list[ToolTiming]: Slowest tool first.
+        """
+        return sorted(
+            self.tools.values(),
+            key=lambda t: -(t.total_ms + t.formatting_ms),
+        )
+
+
+@contextmanager
+def measure_tool(profile: TimingProfile | None, name: str) -> Iterator[None]:
+    """Time a tool's run when profiling.
+
+    Args:
+        profile: Profile of the run, or None when not profiling.
+        name: Tool name.
+
+    Yields:
+        None: While the tool runs.
+    """
+    if profile is None:
+        yield
+        return
+    with profile.measure(name):
+        yield
+
+
+def record_invocation(
+    cmd: Sequence[str],
+    cwd: str | None,
+    started: float,
+    duration_ms: int,
+) -> None:
+    """Record a finished subprocess in the profile of its thread's tool, if any.
+
+    Args:
+        cmd: Command and arguments that ran.
+        cwd: Working directory of the command.
+        started: Monotonic clock reading when the command started.
+        duration_ms: Time the command took.
+    """
+    current = getattr(_current, "tool", None)
+    if current is not None:
+        current[0].record_chunk(cmd, cwd, started, duration_ms)
+
+
+def print_timing_profile(
+    console_output_func: Callable[..., None],
+    profile: TimingProfile,
+) -> None:
+    """Print the phases per tool and the slowest files as tables.
+
+    Args:
+        console_output_func: Function to output text to console.
+        profile: Profile of the finished run.
+    """
+    try:
+        import click
+        from tabulate import tabulate
+
+        header: str = click.style("\u23f1  TIMING PROFILE", fg="cyan", bold=True)
+        console_output_func(text=header)
+        rows = [
+            [
+                t.name,
+                _seconds(t.total_ms + t.formatting_ms),
+                _seconds(t.startup_ms),
+                _seconds(t.execution_ms),
+                str(len(t.chunks)),
+                _seconds(t.parsing_ms),
+                _seconds(t.formatting_ms),
+            ]
+            for t in profile.ranked_tools()
+        ]
+        console_output_func(
+            text=tabulate(
+                tabular_data=rows,
+                headers=[
+                    "Tool",
+                    "Total",
+                    "Startup",
+                    "Execution",
+                    "Chunks",
+                    "Parsing",
+                    "Formatting",
+                ],
+                tablefmt="grid",
+                stralign="left",
+                disable_numparse=True,
+            ),
+        )
+        console_output_func(text="")
+        files = profile.slowest_files()
+        if files:
+            console_output_func(
+                text=tabulate(
+                    tabular_data=[
+                        [
+                            f.path,
+                            f.tool,
+                            ("~" if f.estimated else "") + _seconds(f.duration_ms),
+                        ]
+                        for f in files
+                    ],
+                    headers=["Slowest File", "Tool", "Time"],
+                    tablefmt="grid",
+                    stralign="left",
+                    disable_numparse=True,
+                ),
+            )
+            console_output_func(
+                text="~ marks a share of a run over several files",
+            )
+            console_output_func(text="")
+
+    except ImportError:
+        # Fallback if tabulate not available
+        console_output_func(text="Timing profile requires tabulate package")
+
+
+def _seconds(milliseconds: int) -> str:
+    """Format a duration for the timing tables.
+
+    Args:
+        milliseconds: Duration in milliseconds.
+
+    Returns:
+        str: Duration in seconds with two decimals.
+    """
+    return f"{milliseconds / 1000:.2f}s"
@@ -11,31 +11,116 @@ Supports parallel execution when enabled via configuration.
 
 from __future__ import annotations
 
+import sqlite3
+import subprocess  # nosec B404 - only used to catch tool timeouts
+import time
+from dataclasses import replace
+from functools import partial
 from typing import TYPE_CHECKING
 
+from lintro.config.config_hierarchy import find_nested_config_dirs, nested_severity
+from lintro.config.config_loader import set_active_profile
 from lintro.enums.action import Action, normalize_action
+from lintro.enums.output_format import normalize_output_format
+from lintro.enums.run_outcome import RunOutcome
+from lintro.exceptions.errors import (
+    CONFIG_INVALID,
+    CONTAINER_RUNTIME_MISSING,
+    REPORT_WRITE_FAILED,
+    TOOL_EXECUTION_FAILED,
+    UNKNOWN_TOOL,
+    WORKLOAD_TOO_LARGE,
+    ErrorCode,
+    error_code_for,
+)
 from lintro.models.core.tool_result import ToolResult
 from lintro.tools import tool_manager
 from lintro.utils.config import load_post_checks_config
+from lintro.utils.effort import estimate_effort
+from lintro.utils.execution.code_blocks import check_code_blocks
+from lintro.utils.execution.deduplication import IssueDeduplicator
 from lintro.utils.execution.exit_codes import (
     DEFAULT_EXIT_CODE_FAILURE,
     DEFAULT_EXIT_CODE_SUCCESS,
     DEFAULT_REMAINING_COUNT,
     aggregate_tool_results,
-    determine_exit_code,
+    determine_outcome,
+    exit_code_for,
+    is_infrastructure_failure,
+)
+from lintro.utils.execution.fail_fast import (
+    CANCELLED_REASON,
+    FailFast,
+    cancelled_result,
+)
+from lintro.utils.execution.failure_policy import build_failure_policy
+from lintro.utils.execution.fix_pipeline import (
+    FixTracker,
+    files_for_fixers,
+    files_under,
+    order_fixers,
+    stabilize_fixes,
 )
+from lintro.utils.execution.issue_script import load_configured_script
+from lintro.utils.execution.labels import DirectoryLabels
 from lintro.utils.execution.parallel_executor import run_tools_parallel
+from lintro.utils.execution.path_overrides import PathOverrides
+from lintro.utils.execution.postprocess import STAGE_JUDGE, postprocess_result
+from lintro.utils.execution.read_only_guard import (
+    ReadOnlyGuard,
+    flag_writes,
+    format_writes,
+)
+from lintro.utils.execution.rule_scoped_fix import fix_selected
+from lintro.utils.execution.run_progress import (
+    RunProgress,
+    live_progress,
+    progress_enabled,
+)
+from lintro.utils.execution.scheduling import (
+    load_runtimes,
+    record_runtimes,
+    schedule_tools,
+)
+from lintro.utils.execution.sharding import Shard, apply_shard_scopes
 from lintro.utils.execution.tool_configuration import (
+    ToolsToRunResult,
+    apply_label_scopes,
+    apply_nested_config_scopes,
+    apply_override_scopes,
     configure_tool_for_execution,
     get_tool_display_name,
     get_tools_to_run,
+    run_subtree_scopes,
 )
+from lintro.utils.execution.toolchain_matrix import (
+    matrix_versions,
+    run_toolchain_matrix,
+)
+from lintro.utils.issue_query import IssueQuery
+from lintro.utils.logger_setup import log_event
 from lintro.utils.output import OutputManager
+from lintro.utils.path_filtering import matches_tool_files, should_exclude_path
 from lintro.utils.post_checks import execute_post_checks
-from lintro.utils.unified_config import UnifiedConfigManager
+from lintro.utils.result_cache import check_with_cache
+from lintro.utils.rule_selection import RuleSelection
+from lintro.utils.timestamps import elapsed_ms
+from lintro.utils.timing_profile import (
+    TimingProfile,
+    measure_tool,
+    print_timing_profile,
+)
+from lintro.utils.unified_config import UnifiedConfigManager, get_tool_priority
 
 if TYPE_CHECKING:
-    pass
+    from collections.abc import Callable, Sequence
+
+    from lintro.config.config_hierarchy import ToolScopePlan
+    from lintro.plugins.base import BaseToolPlugin
+    from lintro.plugins.protocol import ToolDefinition
+    from lintro.utils.console.logger import ThreadSafeConsoleLogger
+    from lintro.utils.output.sinks import OutputSink
+    from lintro.utils.stdin_input import StdinBuffer
 
 # Re-export constants for backwards compatibility
 __all__ = [
@@ -46,6 +131,40 @@ __all__ = [
 ]
 
 
+def _report_error(
+    logger: ThreadSafeConsoleLogger,
+    error: ErrorCode,
+    message: str,
+    output_format: str | None,
+) -> None:
+    """Report an error that stops the run before any tool starts.
+
+    Args:
+        logger: Console logger.
+        error: Catalogued error.
+        message: Details of this occurrence.
+        output_format: Requested output format; JSON output gets a JSON
+            object so scripts can read the code.
+    """
+    if output_format and output_format.lower() == "json":
+        import json
+
+        print(
+            json.dumps(
+                {
+                    "error": {
+                        "code": error.code,
+                        "title": error.title,
+                        "message": message,
+                    },
+                },
+                indent=2,
+            ),
+        )
+        return
+    logger.console_output(error.format(message))
+
+
 def run_lint_tools_simple(
     *,
     action: str | Action,
@@ -54,17 +173,48 @@ def run_lint_tools_simple(
     tool_options: str | None,
     exclude: str | None,
     include_venv: bool,
-    group_by: str,
-    output_format: str,
+    group_by: str | None,
+    output_format: str | None,
     verbose: bool,
     raw_output: bool = False,
     output_file: str | None = None,
+    outputs: Sequence[OutputSink] | None = None,
     incremental: bool = False,
     debug: bool = False,
+    log_format: str = "text",
     stream: bool = False,
     no_log: bool = False,
     auto_install: bool = False,
     yes: bool = False,
+    profile: str | None = None,
+    install_missing: bool = False,
+    fail_on: str | None = None,
+    max_issues: int | None = None,
+    summary: str = "table",
+    summary_only: bool = False,
+    quiet: bool = False,
+    sample_per_rule: int | None = None,
+    dedupe: bool = True,
+    toolchain_matrix: bool = False,
+    sign_key: str | None = None,
+    audit: bool = True,
+    fix_only: dict[str, list[str]] | None = None,
+    assert_read_only: bool = False,
+    max_files: int | None = None,
+    confirm_over: int | None = None,
+    issue_filter: IssueQuery | None = None,
+    rule_selection: RuleSelection | None = None,
+    extra_tool_options: dict[str, dict[str, object]] | None = None,
+    columns: list[str] | None = None,
+    sort_by: str | None = None,
+    shard: Shard | None = None,
+    gitignore: bool = True,
+    code_blocks: bool = False,
+    fail_fast: bool = False,
+    profile_timing: bool = False,
+    ingested: Sequence[ToolResult] | None = None,
+    stdin: StdinBuffer | None = None,
+    on_outcome: Callable[[RunOutcome], None] | None = None,
 ) -> int:
     """Simplified runner using Loguru-based logging with rich formatting.
 
@@ -81,17 +231,86 @@ def run_lint_tools_simple(
         tool_options: Additional tool options.
         exclude: Patterns to exclude.
         include_venv: Whether to include virtual environments.
-        group_by: How to group results.
-        output_format: Output format for results.
+        group_by: How to group results. None uses the configured default.
+        output_format: Output format for results. None uses the configured
+            default.
         verbose: Whether to enable verbose output.
         raw_output: Whether to show raw tool output instead of formatted output.
         output_file: Optional file path to write results to.
+        outputs: Further files written from the same results, each in its
+            own format (see sinks); sinks without a format use
+            output_format.
         incremental: Whether to only check files changed since last run.
         debug: Whether to show DEBUG messages on console.
+        log_format: "text", or "json" to write the log to stderr as one JSON
+            record per line, including tool invocation and result events.
         stream: Whether to stream output in real-time (not yet implemented).
         no_log: Whether to disable file logging (not yet implemented).
         auto_install: Whether to auto-install Node.js deps if node_modules missing.
         yes: Skip confirmation prompt and proceed immediately.
+        profile: Named configuration profile to apply for this run.
+        install_missing: Whether to install selected tools that are missing
+            from PATH before running them.
+        fail_on: Lowest issue severity that fails a check run. None uses
+            execution.fail_on.
+        max_issues: Failing issues tolerated in a check run. None uses
+            execution.max_issues.
+        summary: Execution summary style for check runs: "table" or
+            "prose" (a paragraph for CI logs and PR comments).
+        summary_only: Whether to print only the per-tool counts table
+            instead of the issues and the execution summary.
+        quiet: Whether to print nothing when the run succeeds and only the
+            per-tool counts table when it fails.
+        sample_per_rule: Issues shown per rule on the console and in
+            Markdown and HTML output files. None shows every issue.
+        dedupe: Whether to collapse issues that several tools report at the
+            same location in a check run.
+        toolchain_matrix: Whether to run version-sensitive tools once per
+            version in execution.toolchain_matrix in a check run.
+        sign_key: PEM private key used to write a signed attestation next to
+            output_file and every file of outputs.
+        audit: Whether a fix run is recorded in execution.audit_log; off
+            when fixes are computed on a scratch copy.
+        fix_only: Rules to fix per tool in a fix run; every other issue is
+            only reported. None fixes everything.
+        assert_read_only: Whether a check run fails when a tool writes to the
+            workspace (see read_only_guard).
+        max_files: Abort before any tool starts when the selected tools
+            would process more files than this.
+        confirm_over: Ask before running when the selected tools would
+            process more files than this (see workload_gate).
+        issue_filter: Query selecting the issues a check run displays and
+            counts (see issue_query). None keeps every issue.
+        rule_selection: Rules a check run displays and counts, from
+            --select and --ignore (see rule_selection). None keeps every
+            rule.
+        extra_tool_options: Options per tool set by dedicated CLI flags,
+            applied over tool_options. Values are used as given, so they
+            may contain the commas that --tool-options splits on.
+        columns: Columns of the issue tables, in order. None uses
+            output.columns, or every column.
+        sort_by: Order of the issue table rows. None uses
+            output.table_sort_by, or the order the tools reported.
+        shard: Part of the files this run checks, for splitting a run
+            across CI jobs (see sharding). None checks everything.
+        gitignore: False to also discover files git ignores, overriding
+            execution.gitignore (``--no-gitignore``).
+        code_blocks: Whether a check run also checks the fenced code blocks
+            of Markdown files with the tools of their languages (see
+            code_blocks). execution.code_blocks turns it on as well.
+        fail_fast: Whether a check run stops starting tools once the issues
+            found so far exceed the failure policy; the tools not run are
+            reported as cancelled (see fail_fast). execution.fail_fast turns
+            it on as well.
+        profile_timing: Whether to report where the run's time went, per
+            tool phase and for the slowest files (see timing_profile).
+        ingested: Results imported from SARIF logs (see sarif_ingest),
+            reported like the results of tools instead of running any.
+        stdin: Buffer checked in place of its file with ``--stdin`` (see
+            stdin_input); issues in its copy are reported under the file's
+            name, and JSON console output lists every issue.
+        on_outcome: Called with the run's outcome once the tools have run,
+            since several outcomes may share an exit code.
 
     Returns:
         Exit code (0 for success, 1 for failures).
@@ -102,6 +321,7 @@ def run_lint_tools_simple(
     """
     # Normalize action to enum
     action = normalize_action(action)
+    path_aliases = stdin.path_aliases if stdin is not None else None
 
     # Initialize output manager for this run
     output_manager = OutputManager()
@@ -109,26 +329,135 @@ def run_lint_tools_simple(
     # Initialize Loguru logging (must happen before any logger.debug() calls)
     from lintro.utils.logger_setup import setup_execution_logging
 
-    setup_execution_logging(output_manager.run_dir, debug=debug)
+    setup_execution_logging(
+        output_manager.run_dir,
+        debug=debug,
+        log_format=log_format,
+    )
 
     # Create simplified logger with rich formatting
     from lintro.utils.console import create_logger
 
     logger = create_logger(run_dir=output_manager.run_dir)
 
+    # Apply the selected profile before anything reads the config
+    if profile is not None:
+        set_active_profile(profile)
+
+    # Load config once; an unknown profile or invalid section fails here
+    from lintro.config.config_loader import get_config
+
+    try:
+        lintro_config = get_config()
+    except ValueError as e:
+        _report_error(logger, CONFIG_INVALID, str(e), output_format)
+        # The configured exit codes are unavailable when the config is invalid
+        return exit_code_for(RunOutcome.CONFIG_ERROR)
+    exit_codes = lintro_config.execution.exit_codes
+
+    # The container backend needs a runtime before any tool starts
+    use_containers = (
+        lintro_config.execution.backend == "container" and ingested is None
+    )
+    if use_containers:
+        from lintro.plugins.container_backend import detect_container_runtime
+
+        runtime = lintro_config.execution.container_runtime
+        if detect_container_runtime(runtime) is None:
+            _report_error(
+                logger,
+                CONTAINER_RUNTIME_MISSING,
+                f"execution.backend is 'container' but "
+                f"{runtime or 'docker or podman'} is not installed",
+                output_format,
+            )
+            return exit_code_for(RunOutcome.TOOL_ERROR, exit_codes)
+
+    # CLI flags win over the configured output defaults
+    output_format = output_format or lintro_config.output.format or "grid"
+    if group_by is None:
+        default_group_by = "auto" if action == Action.FIX else "file"
+        group_by = lintro_config.output.group_by or default_group_by
+    columns = columns or lintro_config.output.columns
+    sort_by = sort_by or lintro_config.output.table_sort_by
+    # Console output is tracked for console.log but only shown at the end;
+    # editors checking a buffer on stdin read nothing but the results
+    logger.muted = stdin is not None or (
+        (quiet or summary_only) and output_format.lower() != "json"
+    )
+    min_severity = lintro_config.output.min_severity if action != Action.FIX else None
+    if action == Action.FIX:
+        issue_filter = None
+        rule_selection = None
+    try:
+        issue_script = load_configured_script(lintro_config)
+    except RuntimeError as e:
+        _report_error(logger, CONFIG_INVALID, str(e), output_format)
+        return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+    # Nested .lintro-config.yaml files apply their settings to their subtree
+    nested_dirs = find_nested_config_dirs(paths)
+    postprocess = partial(
+        postprocess_result,
+        aliases=path_aliases,
+        path_overrides=PathOverrides.from_config(lintro_config, nested_dirs),
+        severity_overrides=lintro_config.severity,
+        nested_severity=nested_severity(nested_dirs),
+        directory_labels=DirectoryLabels.from_config(lintro_config),
+        label_policies=lintro_config.label_policies,
+        issue_script=issue_script,
+        min_severity=min_severity,
+        issue_filter=issue_filter,
+        rule_selection=rule_selection,
+    )
+    use_gitignore = gitignore and lintro_config.execution.gitignore
+    use_code_blocks = (
+        action == Action.CHECK
+        and stdin is None
+        and (code_blocks or lintro_config.execution.code_blocks)
+    )
+    deduplicator = IssueDeduplicator() if dedupe and action != Action.FIX else None
+
     # Get tools to run (now returns ToolsToRunResult with skip info)
     try:
-        tools_result = get_tools_to_run(tools, action)
+        tools_result = (
+            get_tools_to_run(tools, action) if ingested is None else ToolsToRunResult()
+        )
     except ValueError as e:
-        logger.console_output(f"Error: {e}")
-        return 1
+        _report_error(logger, UNKNOWN_TOOL, str(e), output_format)
+        return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+
+    # Nested .lintro-config.yaml files can enable/disable tools per subtree
+    tool_scopes = apply_nested_config_scopes(tools_result, paths, nested_dirs)
+    # Label policies add their tools to the labelled directories
+    apply_label_scopes(tools_result, paths, tool_scopes)
+    # Per-path overrides keep disabled tools away from their files
+    apply_override_scopes(tools_result, paths, tool_scopes, nested_dirs)
+    # --shard keeps this job's part of the files
+    if shard is not None:
+        apply_shard_scopes(
+            tools_result,
+            paths,
+            tool_scopes,
+            shard,
+            exclude=exclude,
+            include_venv=include_venv,
+            prune_dirs=lintro_config.execution.prune_dirs,
+            gitignore=use_gitignore,
+            symlinks=lintro_config.execution.symlinks,
+        )
+
+    # A buffer on stdin can only be checked by tools that take files
+    if stdin is not None:
+        from lintro.utils.stdin_input import skip_whole_project_tools
+
+        skip_whole_project_tools(tools_result)
 
     tools_to_run = tools_result.to_run
     skipped_tools = tools_result.skipped
 
-    if not tools_to_run and not skipped_tools:
+    if not tools_to_run and not skipped_tools and ingested is None:
         logger.console_output("No tools to run.")
-        return 0
+        return exit_code_for(RunOutcome.CLEAN, exit_codes)
 
     if not tools_to_run and skipped_tools:
         skipped_names = ", ".join(st.name for st in skipped_tools)
@@ -153,6 +482,57 @@ def run_lint_tools_simple(
     # that's okay - post-checks will still run. Just log the situation.
     # Track this state so we can return failure if post-checks don't run.
     main_phase_empty_due_to_filter = bool(not tools_to_run and post_tools_early)
+
+    # Several fixers share files: run them in dependency order, one at a time,
+    # and coordinate their passes (see fix_pipeline). Only the default
+    # priority order is adjusted; alphabetical and custom orders are kept.
+    coordinate_fixes = (
+        action == Action.FIX
+        and not fix_only
+        and len(tools_to_run) > 1
+        and lintro_config.execution.fix_passes > 1
+    )
+    fixer_definitions = (
+        {name: tool_manager.get_tool(name).definition for name in tools_to_run}
+        if coordinate_fixes
+        else {}
+    )
+    if coordinate_fixes and lintro_config.execution.tool_order == "priority":
+        tools_to_run = order_fixers(tools_to_run, fixer_definitions)
+
+    # Check runs start quick tools first and slow ones last (see scheduling)
+    slow_tools: list[str] = []
+    runtimes_file = lintro_config.execution.runtimes if ingested is None else None
+    if action == Action.CHECK and lintro_config.execution.tool_order == "priority":
+        schedule = schedule_tools(
+            tools_to_run,
+            load_runtimes(runtimes_file) if runtimes_file else {},
+            priority=get_tool_priority,
+            slow_ms=round(lintro_config.execution.slow_tool_seconds * 1000),
+        )
+        tools_to_run = schedule.order
+        slow_tools = schedule.slow
+
+    # Fix runs are recorded per fixer and file, so they also need a tracker
+    audit_log = (
+        lintro_config.execution.audit_log if audit and action == Action.FIX else None
+    )
+
+    # Versions each tool runs on; tools missing here run once
+    matrix = (
+        {
+            name: versions
+            for name in tools_to_run
+            if (
+                versions := matrix_versions(
+                    name,
+                    lintro_config.execution.toolchain_matrix,
+                )
+            )
+        }
+        if toolchain_matrix and action == Action.CHECK
+        else {}
+    )
     if main_phase_empty_due_to_filter:
         logger.console_output(
             text=(
@@ -171,6 +551,20 @@ def run_lint_tools_simple(
             color="cyan",
         )
 
+    # Install absent tools (including post-checks) before anything runs.
+    # Container images already ship their tools.
+    if install_missing and tools_result.to_run and not use_containers:
+        from lintro.utils.tool_installer import install_missing_tools
+
+        for outcome in install_missing_tools(tools_result.to_run):
+            logger.console_output(
+                text=outcome.message,
+                color={"installed": "green", "failed": "red"}.get(
+                    outcome.status,
+                    "yellow",
+                ),
+            )
+
     # Execute tools and collect results
     all_results: list[ToolResult] = []
     total_issues = 0
@@ -181,15 +575,22 @@ def run_lint_tools_simple(
     from lintro.utils.tool_options import parse_tool_options
 
     tool_option_dict = parse_tool_options(tool_options)
+    for extra_tool, extra_options in (extra_tool_options or {}).items():
+        tool_option_dict.setdefault(extra_tool, {}).update(extra_options)
 
     # Create UnifiedConfigManager once before the loop
     config_manager = UnifiedConfigManager()
 
     # Check if parallel execution is enabled
-    from lintro.config.config_loader import get_config
-
-    lintro_config = get_config()
-    use_parallel = lintro_config.execution.parallel and len(tools_to_run) > 1
+    use_parallel = (
+        lintro_config.execution.parallel
+        and len(tools_to_run) > 1
+        and not coordinate_fixes
+        and not matrix
+        and not audit_log
+        and not fix_only
+        and not assert_read_only
+    )
 
     # Determine auto_install: CLI flag > config > container default
     from lintro.utils.environment.container_detection import is_container_environment
@@ -203,7 +604,11 @@ def run_lint_tools_simple(
         effective_auto_install = is_container
 
     # Pre-execution config summary (suppress in JSON mode)
-    if output_format.lower() != "json" and (tools_to_run or skipped_tools):
+    if (
+        output_format.lower() != "json"
+        and not logger.muted
+        and (tools_to_run or skipped_tools)
+    ):
         from lintro.utils.console.pre_execution_summary import (
             print_pre_execution_summary,
         )
@@ -240,29 +645,143 @@ def run_lint_tools_simple(
                 logger.console_output(text="Aborted.", color="yellow")
                 return int(DEFAULT_EXIT_CODE_SUCCESS)
 
+    # Count the workload before committing to a long run
+    if (max_files is not None or confirm_over is not None) and tools_result.to_run:
+        import sys
+
+        from lintro.utils.environment import detect_ci_environment
+        from lintro.utils.execution.workload_gate import preview_workload
+
+        workload = preview_workload(
+            {
+                name: tool_manager.get_tool(name).definition
+                for name in tools_result.to_run
+            },
+            paths,
+            exclude=exclude,
+            include_venv=include_venv,
+            prune_dirs=lintro_config.execution.prune_dirs,
+            gitignore=use_gitignore,
+            symlinks=lintro_config.execution.symlinks,
+        )
+        if max_files is not None and workload.total > max_files:
+            _report_error(
+                logger,
+                WORKLOAD_TOO_LARGE,
+                workload.describe(max_files),
+                output_format,
+            )
+            return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+        if confirm_over is not None and workload.total > confirm_over and not yes:
+            ci_env = detect_ci_environment()
+            can_prompt = (
+                output_format.lower() != "json"
+                and not (ci_env is not None and ci_env.is_ci)
+                and sys.stdin.isatty()
+            )
+            if not can_prompt:
+                _report_error(
+                    logger,
+                    WORKLOAD_TOO_LARGE,
+                    workload.describe(confirm_over),
+                    output_format,
+                )
+                return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+            logger.console_output(
+                text=workload.describe(confirm_over),
+                color="yellow",
+            )
+            try:
+                answer = input("Run anyway? [y/N] ").strip().lower()
+            except (EOFError, KeyboardInterrupt):
+                answer = "n"
+            if answer not in ("y", "yes"):
+                logger.console_output(text="Aborted.", color="yellow")
+                return int(DEFAULT_EXIT_CODE_SUCCESS)
+
     # Define success_func once before the loop
     def success_func(message: str) -> None:
         logger.console_output(text=message, color="green")
 
-    # Use parallel execution if enabled
-    if use_parallel:
-        logger.console_output(
-            text=f"Running {len(tools_to_run)} tools in parallel "
-            f"(max {lintro_config.execution.max_workers} workers)",
-        )
-        all_results = run_tools_parallel(
-            tools_to_run=tools_to_run,
-            paths=paths,
-            action=action,
-            config_manager=config_manager,
-            tool_option_dict=tool_option_dict,
+    fix_tracker: FixTracker | None = None
+    read_only_guard = (
+        ReadOnlyGuard(paths, exclude, include_venv)
+        if assert_read_only and action == Action.CHECK
+        else None
+    )
+    # Show each tool's state while the run is in progress on a terminal
+    progress: RunProgress | None = None
+    if tools_to_run and not logger.muted and progress_enabled(output_format):
+        from lintro.utils.execution.workload_gate import preview_workload
+
+        workload = preview_workload(
+            {name: tool_manager.get_tool(name).definition for name in tools_to_run},
+            paths,
             exclude=exclude,
             include_venv=include_venv,
-            post_tools=post_tools_early,
-            max_workers=lintro_config.execution.max_workers,
-            incremental=incremental,
-            auto_install=effective_auto_install,
+            prune_dirs=lintro_config.execution.prune_dirs,
+            gitignore=use_gitignore,
+            symlinks=lintro_config.execution.symlinks,
+        )
+        progress = RunProgress(tools_to_run, dict(workload.per_tool))
+    fail_fast_tracker: FailFast | None = None
+    if (fail_fast or lintro_config.execution.fail_fast) and action == Action.CHECK:
+
+        fail_fast_tracker = FailFast(
+            build_failure_policy(lintro_config, fail_on, max_issues),
+            judge=partial(postprocess, stage=STAGE_JUDGE) if use_parallel else None,
         )
+    cancelled_tools: list[str] = []
+    timing_profile = TimingProfile() if profile_timing else None
+
+    # Use parallel execution if enabled; imported results are post-processed
+    # and displayed the same way
+    if use_parallel or ingested is not None:
+        if ingested is not None:
+            all_results = list(ingested)
+        else:
+            logger.console_output(
+                text=f"Running {len(tools_to_run)} tools in parallel "
+                f"(max {lintro_config.execution.max_workers} workers)",
+            )
+            all_results = run_tools_parallel(
+                tools_to_run=tools_to_run,
+                paths=paths,
+                action=action,
+                config_manager=config_manager,
+                tool_option_dict=tool_option_dict,
+                exclude=exclude,
+                include_venv=include_venv,
+                post_tools=post_tools_early,
+                max_workers=lintro_config.execution.max_workers,
+                incremental=incremental,
+                auto_install=effective_auto_install,
+                tool_scopes=tool_scopes,
+                progress=progress,
+                gitignore=gitignore,
+                fail_fast=fail_fast_tracker,
+                timing_profile=timing_profile,
+                slow_tools=set(slow_tools),
+            )
+            if use_code_blocks:
+                all_results = [
+                    check_code_blocks(
+                        tool_manager.get_tool(r.name),
+                        tool_scopes[r.name].paths if r.name in tool_scopes else paths,
+                        r,
+                    )
+                    for r in all_results
+                ]
+        cancelled_tools = [
+            r.name for r in all_results if r.skip_reason == CANCELLED_REASON
+        ]
+        try:
+            all_results = [postprocess(r) for r in all_results]
+        except RuntimeError as e:
+            _report_error(logger, CONFIG_INVALID, str(e), output_format)
+            return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+        if deduplicator is not None:
+            all_results = deduplicator.add_all(all_results)
 
         # Calculate totals from parallel results using helper
         total_issues, total_fixed, total_remaining = aggregate_tool_results(
@@ -271,6 +790,7 @@ def run_lint_tools_simple(
         )
         # Display results for parallel execution
         for result in all_results:
+            formatting_started = time.monotonic()
             # Print tool header like sequential mode does
             display_name = get_tool_display_name(result.name)
             logger.print_tool_header(tool_name=display_name, action=action)
@@ -286,6 +806,10 @@ def run_lint_tools_simple(
                     output=result.output or "",
                     output_format=output_format,
                     issues=list(result.issues) if result.issues else None,
+                    sample_per_rule=sample_per_rule,
+                    group_by=group_by,
+                    columns=columns,
+                    sort_by=sort_by,
                 )
             if result.output and raw_output:
                 display_output = result.output
@@ -308,10 +832,34 @@ def run_lint_tools_simple(
                     text="✓ No issues found.",
                     color="green",
                 )
+            if timing_profile is not None and not result.skipped:
+                timing_profile.add_formatting(
+                    result.name,
+                    elapsed_ms(formatting_started),
+                )
 
     else:
         # Sequential execution (original behavior)
-        for tool_name in tools_to_run:
+        fix_tracker = (
+            FixTracker(
+                files_for_fixers(
+                    _tracked_fixer_definitions(
+                        fixer_definitions,
+                        [*tools_to_run, *sorted(post_tools_early)],
+                    ),
+                    paths,
+                    exclude,
+                    include_venv,
+                ),
+            )
+            if coordinate_fixes or audit_log
+            else None
+        )
+        for index, tool_name in enumerate(tools_to_run):
+            if fail_fast_tracker is not None and fail_fast_tracker.stopped():
+                cancelled_tools = tools_to_run[index:]
+                all_results.extend(cancelled_result(name) for name in cancelled_tools)
+                break
             try:
                 tool = tool_manager.get_tool(tool_name)
                 display_name = get_tool_display_name(tool_name)
@@ -320,7 +868,9 @@ def run_lint_tools_simple(
                 logger.print_tool_header(tool_name=display_name, action=action)
 
                 # Configure tool using shared helper
-                configure_tool_for_execution(
+                scope = tool_scopes.get(tool_name)
+                configure = partial(
+                    configure_tool_for_execution,
                     tool=tool,
                     tool_name=tool_name,
                     config_manager=config_manager,
@@ -332,16 +882,57 @@ def run_lint_tools_simple(
                     post_tools=post_tools_early,
                     auto_install=effective_auto_install,
                     lintro_config=lintro_config,
+                    gitignore=gitignore,
                 )
+                configure(scope=scope)
 
                 # Execute the tool
-                result = (
-                    tool.fix(paths, {})
-                    if action == Action.FIX
-                    else tool.check(paths, {})
+                tool_paths = scope.paths if scope else paths
+                run = partial(
+                    _run_tool,
+                    tool,
+                    action=action,
+                    fix_only=fix_only,
+                    versions=matrix.get(tool_name),
                 )
+                tool_started = time.monotonic()
+                if progress is not None:
+                    progress.start(tool_name)
+                with live_progress(progress), measure_tool(timing_profile, tool_name):
+                    result = run_subtree_scopes(
+                        tool,
+                        run(tool_paths),
+                        scope,
+                        configure=configure,
+                        run=run,
+                    )
+                    if use_code_blocks:
+                        result = check_code_blocks(tool, tool_paths, result)
+                result.duration_ms = elapsed_ms(tool_started)
+                result.extra_args = list(tool.extra_args)
+                if progress is not None:
+                    progress.finish(tool_name, result)
+                formatting_started = time.monotonic()
+                try:
+                    result = postprocess(result)
+                except RuntimeError as e:
+                    # A failing output.script is a config error, as in
+                    # parallel runs, rather than a failure of this tool
+                    _report_error(logger, CONFIG_INVALID, str(e), output_format)
+                    return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+                if deduplicator is not None:
+                    result = deduplicator.add(result)
+                if fix_tracker is not None:
+                    fix_tracker.record(tool_name)
+                if read_only_guard is not None:
+                    writes = read_only_guard.record(tool_name)
+                    if writes:
+                        logger.console_output(text=format_writes(writes), color="red")
+                    result = flag_writes(result, writes)
 
                 all_results.append(result)
+                if fail_fast_tracker is not None:
+                    fail_fast_tracker.record(result)
 
                 # Update totals
                 total_issues += getattr(result, "issues_count", 0)
@@ -368,6 +959,10 @@ def run_lint_tools_simple(
                         output=result.output or "",
                         output_format=output_format,
                         issues=list(result.issues) if result.issues else None,
+                        sample_per_rule=sample_per_rule,
+                        group_by=group_by,
+                        columns=columns,
+                        sort_by=sort_by,
                     )
                 if result.output and raw_output:
                     # Use raw output when raw_output flag is True (overrides formatted)
@@ -392,6 +987,11 @@ def run_lint_tools_simple(
                     logger.console_output(text="Processing files")
                     logger.console_output(text="✓ No issues found.", color="green")
                     logger.console_output(text="")
+                if timing_profile is not None:
+                    timing_profile.add_formatting(
+                        tool_name,
+                        elapsed_ms(formatting_started),
+                    )
 
             except (TypeError, AttributeError):
                 # Programming errors should be re-raised for debugging
@@ -399,22 +999,76 @@ def run_lint_tools_simple(
 
                 loguru_logger.exception(f"Programming error running {tool_name}")
                 raise
-            except (OSError, ValueError, RuntimeError) as e:
+            except (
+                OSError,
+                ValueError,
+                RuntimeError,
+                subprocess.TimeoutExpired,
+            ) as e:
                 from loguru import logger as loguru_logger
 
                 # Log full exception with traceback to debug.log via loguru
-                loguru_logger.exception(f"Error running {tool_name}")
+                error = error_code_for(e, TOOL_EXECUTION_FAILED)
+                loguru_logger.exception(f"[{error.code}] Error running {tool_name}")
                 # Show user-friendly error message on console
-                logger.console_output(f"Error running {tool_name}: {e}")
+                logger.console_output(error.format(f"running {tool_name}: {e}"))
 
-                # Create a failed result for this tool
+                # Create a failed result for this tool; a timeout is an
+                # infrastructure failure, not a crash during setup
                 failed_result = ToolResult(
                     name=tool_name,
                     success=False,
-                    output=f"Failed to initialize tool: {e}",
+                    output=(
+                        str(e)
+                        if isinstance(e, subprocess.TimeoutExpired)
+                        else f"Failed to initialize tool: {e}"
+                    ),
                     issues_count=0,
+                    error_code=error.code,
                 )
                 all_results.append(failed_result)
+                if progress is not None:
+                    progress.finish(tool_name, failed_result)
+
+        if coordinate_fixes and fix_tracker is not None:
+
+            def _configure(name: str) -> None:
+                """Configure a fixer again for a later fix pass.
+
+                Args:
+                    name: Tool name.
+                """
+                configure_tool_for_execution(
+                    tool=tool_manager.get_tool(name),
+                    tool_name=name,
+                    config_manager=config_manager,
+                    tool_option_dict=tool_option_dict,
+                    exclude=exclude,
+                    include_venv=include_venv,
+                    incremental=False,
+                    action=action,
+                    post_tools=post_tools_early,
+                    auto_install=effective_auto_install,
+                    lintro_config=lintro_config,
+                    scope=tool_scopes.get(name),
+                    gitignore=gitignore,
+                )
+
+            all_results = _stabilize_fix_results(
+                fix_tracker=fix_tracker,
+                all_results=all_results,
+                definitions=fixer_definitions,
+                tool_scopes=tool_scopes,
+                paths=paths,
+                max_passes=lintro_config.execution.fix_passes,
+                configure=_configure,
+                postprocess=postprocess,
+                logger=logger,
+            )
+            _, total_fixed, total_remaining = aggregate_tool_results(
+                all_results,
+                action,
+            )
 
     # Add skipped tool results for display in summary table
     for st in skipped_tools:
@@ -427,36 +1081,104 @@ def run_lint_tools_simple(
             ),
         )
 
-    # Execute post-checks if configured
-    total_issues, total_fixed, total_remaining = execute_post_checks(
+    # Execute post-checks if configured; an import runs no tools
+    if ingested is None:
+        total_issues, total_fixed, total_remaining = execute_post_checks(
+            action=action,
+            paths=paths,
+            exclude=exclude,
+            include_venv=include_venv,
+            group_by=group_by,
+            columns=columns,
+            sort_by=sort_by,
+            output_format=output_format,
+            verbose=verbose,
+            raw_output=raw_output,
+            logger=logger,
+            all_results=all_results,
+            total_issues=total_issues,
+            total_fixed=total_fixed,
+            total_remaining=total_remaining,
+            fix_tracker=fix_tracker,
+            fix_only=fix_only if action == Action.FIX else None,
+            read_only_guard=read_only_guard,
+        )
+
+    # Determine final exit code once — used for both JSON output and return
+    failure_policy = (
+        build_failure_policy(lintro_config, fail_on, max_issues)
+        if action != Action.FIX
+        else None
+    )
+    outcome = determine_outcome(
         action=action,
-        paths=paths,
-        exclude=exclude,
-        include_venv=include_venv,
-        group_by=group_by,
-        output_format=output_format,
-        verbose=verbose,
-        raw_output=raw_output,
-        logger=logger,
         all_results=all_results,
         total_issues=total_issues,
-        total_fixed=total_fixed,
         total_remaining=total_remaining,
+        main_phase_empty_due_to_filter=main_phase_empty_due_to_filter,
+        policy=failure_policy,
     )
 
-    # Determine final exit code once — used for both JSON output and return
-    final_exit_code = int(
-        determine_exit_code(
-            action=action,
-            all_results=all_results,
-            total_issues=total_issues,
-            total_remaining=total_remaining,
-            main_phase_empty_due_to_filter=main_phase_empty_due_to_filter,
-        ),
-    )
+    if failure_policy is not None:
+        # Budgeted rules in use warn, and fail the run once over budget
+        for usage in failure_policy.budget_usage(all_results):
+            if usage.issues or usage.budget:
+                logger.console_output(
+                    text=usage.describe(),
+                    color="red" if usage.exceeded else "yellow",
+                )
+
+    if read_only_guard is not None and read_only_guard.writes:
+        logger.console_output(text=read_only_guard.describe(), color="red")
+        # A check that changes the workspace fails whatever the issue policy
+        outcome = RunOutcome.TOOL_ERROR
+    final_exit_code = exit_code_for(outcome, exit_codes)
+    if on_outcome is not None:
+        on_outcome(outcome)
+
+    if fail_fast_tracker is not None and cancelled_tools:
+        logger.console_output(
+            text=fail_fast_tracker.describe(len(cancelled_tools)),
+            color="yellow",
+        )
+
+    _log_run_events(action, all_results, total_issues, final_exit_code)
+
+    if audit_log and fix_tracker is not None:
+        from lintro.utils.execution.audit_log import append_entry, build_entry
+
+        try:
+            append_entry(
+                audit_log,
+                build_entry(
+                    tools=[r.name for r in all_results if not r.skipped],
+                    fixes=fix_tracker.fixes,
+                    exit_code=final_exit_code,
+                ),
+            )
+        except OSError as e:
+            logger.console_output(f"Warning: Failed to write audit log: {e}")
+
+    history = lintro_config.execution.history
+    if history and action == Action.CHECK:
+        from lintro.utils.execution.run_history import record_run
+
+        try:
+            record_run(history, all_results, exit_code=final_exit_code)
+        except (OSError, ValueError, sqlite3.Error) as e:
+            logger.console_output(f"Warning: Failed to record run history: {e}")
+
+    if runtimes_file and action == Action.CHECK:
+        record_runtimes(runtimes_file, all_results)
 
     # Display results
     if all_results:
+        # Fix runs report what was fixed, so effort is estimated for checks only
+        effort = (
+            estimate_effort(all_results, lintro_config.output.effort)
+            if action != Action.FIX
+            else None
+        )
         if output_format.lower() == "json":
             # Output JSON to stdout
             import json
@@ -470,16 +1192,320 @@ def run_lint_tools_simple(
                 total_fixed=total_fixed,
                 total_remaining=total_remaining,
                 exit_code=final_exit_code,
+                partial=bool(cancelled_tools),
+                timing=timing_profile.to_dict() if timing_profile else None,
+                include_issues=stdin is not None,
             )
             print(json.dumps(json_data, indent=2))
+        elif logger.muted:
+            logger.muted = False
+            if summary_only or final_exit_code != DEFAULT_EXIT_CODE_SUCCESS:
+                logger.print_summary_only(action, all_results)
+        elif summary == "prose" and action != Action.FIX:
+            from lintro.utils.prose_summary import build_prose_summary
+
+            logger.console_output(text="")
+            logger.console_output(text=build_prose_summary(all_results, effort))
         else:
-            logger.print_execution_summary(action, all_results)
+            logger.print_execution_summary(action, all_results, effort=effort)
+        if timing_profile is not None and output_format.lower() != "json":
+            print_timing_profile(logger.console_output, timing_profile)
 
         # Write report files (markdown, html, csv)
         try:
-            output_manager.write_reports_from_results(all_results)
+            output_manager.write_reports_from_results(
+                all_results,
+                effort=effort,
+                sort_by_effort=lintro_config.output.sort_by == "effort",
+                local_time=lintro_config.output.local_time,
+            )
         except (OSError, ValueError, TypeError) as e:
-            logger.console_output(f"Warning: Failed to write reports: {e}")
+            logger.console_output(
+                f"Warning: [{REPORT_WRITE_FAILED.code}] Failed to write reports: {e}",
+            )
             # Continue execution - report writing failures should not stop the tool
 
+        file_sinks = [sink for sink in outputs or () if not sink.is_console]
+        if output_file:
+            from lintro.utils.output.sinks import OutputSink
+
+            file_sinks.insert(0, OutputSink(path=output_file))
+        for sink in file_sinks:
+            report_path = str(sink.path)
+            report_format = sink.format or output_format
+            if report_format == "sarif":
+                from lintro.utils.output.sarif import write_sarif_file
+
+                write_sarif_file(report_path, all_results)
+            elif report_format == "sonar":
+                from lintro.utils.output.sonar import write_sonar_file
+
+                write_sonar_file(
+                    report_path,
+                    all_results,
+                    lintro_config.output.effort,
+                )
+            else:
+                from lintro.utils.output.file_writer import write_output_file
+
+                write_output_file(
+                    output_path=report_path,
+                    output_format=normalize_output_format(report_format),
+                    all_results=all_results,
+                    action=action,
+                    total_issues=total_issues,
+                    total_fixed=total_fixed,
+                    sample_per_rule=sample_per_rule,
+                    shard=str(shard) if shard else None,
+                    timing=timing_profile.to_dict() if timing_profile else None,
+                )
+            if sign_key:
+                from lintro.utils.report_signing import (
+                    build_run_metadata,
+                    sign_report,
+                )
+
+                try:
+                    attestation = sign_report(
+                        report_path,
+                        sign_key,
+                        build_run_metadata(
+                            action=str(action),
+                            results=all_results,
+                            exit_code=final_exit_code,
+                        ),
+                    )
+                except (OSError, ValueError, RuntimeError) as e:
+                    logger.console_output(
+                        REPORT_WRITE_FAILED.format(f"Failed to sign report: {e}"),
+                    )
+                    return exit_code_for(RunOutcome.CONFIG_ERROR, exit_codes)
+                if output_format.lower() != "json":
+                    logger.console_output(f"Signed report: {attestation}")
+
     return final_exit_code
+
+
+def _run_tool(
+    tool: BaseToolPlugin,
+    paths: list[str],
+    action: Action,
+    fix_only: dict[str, list[str]] | None,
+    versions: list[str] | None,
+) -> ToolResult:
+    """Check or fix paths with a configured tool.
+
+    Args:
+        tool: Configured tool plugin.
+        paths: Paths to check or fix.
+        action: Check or fix.
+        fix_only: Rules to fix per tool, from --fix-only, or None.
+        versions: Toolchains to check on (see toolchain_matrix), or None.
+
+    Returns:
+        ToolResult: Result of the run.
+    """
+    name = tool.definition.name
+    if action == Action.FIX and fix_only:
+        return fix_selected(tool, name, paths, fix_only)
+    if action == Action.FIX:
+        return tool.fix(paths, {})
+    if versions:
+        return run_toolchain_matrix(tool, paths, versions)
+    return check_with_cache(tool, paths, {})
+
+
+def _log_run_events(
+    action: Action,
+    all_results: list[ToolResult],
+    total_issues: int,
+    exit_code: int,
+) -> None:
+    """Log one event per tool result and one for the run.
+
+    Args:
+        action: Action performed.
+        all_results: Results of every tool, including skipped ones.
+        total_issues: Issues counted for the run.
+        exit_code: Exit code of the run.
+    """
+    for result in all_results:
+        if result.skipped:
+            state = f"skipped ({result.skip_reason})"
+        else:
+            state = f"{result.issues_count} issues"
+        log_event(
+            "tool_result",
+            f"{result.name}: {state}",
+            tool=result.name,
+            success=result.success,
+            skipped=result.skipped,
+            issues_count=result.issues_count,
+            duration_ms=result.duration_ms,
+            error_code=result.error_code,
+        )
+    log_event(
+        "run_finished",
+        f"{action.value} finished with exit code {exit_code}",
+        action=action.value,
+        tools=[r.name for r in all_results if not r.skipped],
+        total_issues=total_issues,
+        exit_code=exit_code,
+    )
+
+
+def _tracked_fixer_definitions(
+    known: dict[str, ToolDefinition],
+    tool_names: list[str],
+) -> list[ToolDefinition]:
+    """Collect the definitions of every fixer whose changes are tracked.
+
+    Args:
+        known: Definitions already looked up, keyed by tool name.
+        tool_names: Fixers of the run, including post-check tools.
+
+    Returns:
+        list[ToolDefinition]: Definitions of the tools that could be resolved.
+    """
+    definitions = dict(known)
+    for name in tool_names:
+        if name in definitions:
+            continue
+        try:
+            definitions[name] = tool_manager.get_tool(name).definition
+        except (KeyError, ValueError, RuntimeError):
+            # Unavailable tools cannot change files
+            continue
+    return list(definitions.values())
+
+
+def _stabilize_fix_results(
+    *,
+    fix_tracker: FixTracker,
+    all_results: list[ToolResult],
+    definitions: dict[str, ToolDefinition],
+    tool_scopes: dict[str, ToolScopePlan],
+    paths: list[str],
+    max_passes: int,
+    configure: Callable[[str], None],
+    postprocess: Callable[[ToolResult], ToolResult],
+    logger: ThreadSafeConsoleLogger,
+) -> list[ToolResult]:
+    """Run further fix passes until the fixers agree, and report the outcome.
+
+    Fixers whose first pass left issues take part too. Once files changed,
+    each fixer is checked again on all its paths, and its remaining issues
+    are taken from that final check.
+
+    Args:
+        fix_tracker: Tracker holding the first pass's file changes.
+        all_results: Fix results of the first pass.
+        definitions: Fixer definitions keyed by tool name.
+        tool_scopes: Per-tool scope plans from nested configs.
+        paths: Paths the run fixes.
+        max_passes: Maximum fix passes, including the first.
+        configure: Applies the run's options and excludes to a fixer.
+        postprocess: Applies the run's issue handling to a check result.
+        logger: Console logger.
+
+    Returns:
+        list[ToolResult]: First-pass results with the fixes of later passes
+        added to their fixed counts and the remaining issues of the final
+        check.
+    """
+    fixers = [
+        r.name
+        for r in all_results
+        if not r.skipped and not is_infrastructure_failure(r)
+    ]
+    for name in fixers:
+        configure(name)
+
+    def _scoped(name: str, files: list[str]) -> list[str]:
+        """Limit files to the ones the tool handles in this run.
+
+        Args:
+            name: Tool name.
+            files: Modified files.
+
+        Returns:
+            list[str]: Files in the tool's scope that match its file
+            patterns and no exclude pattern.
+        """
+        tool = tool_manager.get_tool(name)
+        scope = tool_scopes.get(name)
+        return [
+            path
+            for path in (files_under(files, scope.paths) if scope else files)
+            if matches_tool_files(
+                path,
+                tool.definition.file_patterns,
+                tool.definition.shebang_interpreters,
+            )
+            and not should_exclude_path(path, tool.exclude_patterns)
+        ]
+
+    def _run(name: str, files: list[str], fix: bool) -> ToolResult:
+        """Check or fix a fixer's files, or nothing when none are in scope.
+
+        Args:
+            name: Tool name.
+            files: Modified files.
+            fix: Whether to fix rather than check.
+
+        Returns:
+            ToolResult: Result of the tool on its files.
+        """
+        scoped = _scoped(name, files)
+        if not scoped:
+            return ToolResult(name=name, success=True)
+        tool = tool_manager.get_tool(name)
+        return tool.fix(scoped, {}) if fix else postprocess(tool.check(scoped, {}))
+
+    report, extra_results = stabilize_fixes(
+        fix_tracker,
+        fixers,
+        check=lambda name, files: _run(name, files, fix=False),
+        fix=lambda name, files: _run(name, files, fix=True),
+        definitions=definitions,
+        max_passes=max_passes,
+    )
+    if report.modified_files:
+        settled = report.stable and not report.conflicts
+        logger.console_output(
+            text=report.describe(),
+            color="green" if settled else "yellow",
+        )
+
+    extra_fixed: dict[str, int] = {}
+    for extra in extra_results:
+        extra_fixed[extra.name] = extra_fixed.get(extra.name, 0) + (
+            extra.fixed_issues_count or 0
+        )
+    final: dict[str, ToolResult] = {}
+    if report.modified_files:
+        for name in fixers:
+            scope = tool_scopes.get(name)
+            tool = tool_manager.get_tool(name)
+            final[name] = postprocess(tool.check(scope.paths if scope else paths, {}))
+
+    stabilized: list[ToolResult] = []
+    for result in all_results:
+        if extra_fixed.get(result.name):
+            result = replace(
+                result,
+                fixed_issues_count=(result.fixed_issues_count or 0)
+                + extra_fixed[result.name],
+            )
+        check = final.get(result.name)
+        if check is not None:
+            remaining = check.issues_count
+            result = replace(
+                result,
+                success=check.success,
+                issues_count=remaining,
+                issues=check.issues,
+                remaining_issues_count=remaining,
+            )
+        stabilized.append(result)
+    return stabilized
@@ -0,0 +1,337 @@
+"""Install missing external tools before a run.
+
+Backs ``lintro check --install-missing`` and ``lintro format
+--install-missing``. Each selected tool that is not on PATH is installed
+with its ecosystem's package manager (uv/pipx, npm/bun, cargo, rustup, gem,
+luarocks or go) at the version pinned under ``tools.<name>.version``, falling back to
+the version Lintro was tested against in ``lintro/tools/manifest.json``.
+"""
+
+from __future__ import annotations
+
+import shutil
+import subprocess  # nosec B404 - used safely with shell disabled
+import sys
+from collections.abc import Callable, Sequence
+from dataclasses import dataclass
+
+from loguru import logger
+
+from lintro._tool_versions import get_all_expected_versions, get_install_methods
+from lintro.utils.env import get_subprocess_env
+from lintro.utils.version_pins import (
+    get_version_pins,
+    normalize_version_spec,
+    version_satisfies,
+)
+
+# Maximum time in seconds for a single install command
+INSTALL_TIMEOUT: int = 600
+
+# Install sources for tools the manifest lists as prebuilt binaries
+BINARY_SOURCES: dict[str, tuple[str, str]] = {
+    "actionlint": ("go", "github.com/rhysd/actionlint/cmd/actionlint"),
+    "buf": ("go", "github.com/bufbuild/buf/cmd/buf"),
+    "gitleaks": ("go", "github.com/zricethezav/gitleaks/v8"),
+    "golangci_lint": ("go", "github.com/golangci/golangci-lint/v2/cmd/golangci-lint"),
+    "shfmt": ("go", "mvdan.cc/sh/v3/cmd/shfmt"),
+    "stylua": ("cargo", "stylua"),
+    "taplo": ("cargo", "taplo-cli"),
+    "typos": ("cargo", "typos-cli"),
+    "vale": ("go", "github.com/errata-ai/vale/v3/cmd/vale"),
+    "terraform_fmt": ("go", "github.com/hashicorp/terraform"),
+    "tflint": ("go", "github.com/terraform-linters/tflint"),
+}
+
+# Executables provided by rustup components
+RUSTUP_COMPONENT_EXECUTABLES: dict[str, str] = {
+    "clippy": "cargo-clippy",
+    "rustfmt": "rustfmt",
+}
+
+InstallRunner = Callable[[list[str]], tuple[bool, str]]
+
+
+@dataclass(frozen=True)
+class InstallPlan:
+    """How a missing tool would be installed.
+
+    Attributes:
+        tool: Lintro tool name.
+        executable: Executable the tool provides on PATH.
+        command: Install command, or None if Lintro cannot install the tool.
+        version: Version being installed, or None for the latest release.
+        note: Manual instructions or caveats shown to the user.
+    """
+
+    tool: str
+    executable: str
+    command: list[str] | None
+    version: str | None = None
+    note: str | None = None
+
+
+@dataclass(frozen=True)
+class InstallOutcome:
+    """Result of installing one tool.
+
+    Attributes:
+        tool: Lintro tool name.
+        status: One of ``installed``, ``failed`` or ``unsupported``.
+        message: Human-readable summary of what happened.
+    """
+
+    tool: str
+    status: str
+    message: str
+
+
+def resolve_install_version(expected: str | None, pin: str | None) -> str | None:
+    """Choose the version to install for a tool.
+
+    Args:
+        expected: Version Lintro was tested against, if known.
+        pin: Configured ``tools.<name>.version`` pin, if any.
+
+    Returns:
+        str | None: Exact version to install, or None if the pin is a range
+        that the expected version does not satisfy.
+    """
+    if not pin:
+        return expected
+    spec = normalize_version_spec(pin)
+    if spec.startswith("==") and not any(c in spec for c in "*,"):
+        return spec[2:]
+    if expected and version_satisfies(expected, pin):
+        return expected
+    return None
+
+
+def _executable_for(tool: str, method: dict[str, str]) -> str:
+    """Return the executable a tool provides once installed.
+
+    Args:
+        tool: Lintro tool name.
+        method: Manifest install entry for the tool.
+
+    Returns:
+        str: Executable name looked up on PATH.
+    """
+    install_type = method.get("type")
+    if install_type == "rustup":
+        component = method.get("component", "")
+        return RUSTUP_COMPONENT_EXECUTABLES.get(component, component or tool)
+    if install_type in ("npm", "pip", "cargo", "gem", "luarocks"):
+        return method.get("bin") or method.get("package") or tool
+    return method.get("bin") or tool
+
+
+def build_install_plan(
+    tool: str,
+    method: dict[str, str],
+    expected: str | None = None,
+    pin: str | None = None,
+) -> InstallPlan:
+    """Build the install command for a tool.
+
+    Args:
+        tool: Lintro tool name.
+        method: Manifest install entry for the tool.
+        expected: Version Lintro was tested against, if known.
+        pin: Configured ``tools.<name>.version`` pin, if any.
+
+    Returns:
+        InstallPlan: Command and version to use, or a manual note when no
+        suitable package manager is available.
+    """
+    executable = _executable_for(tool, method)
+    version = resolve_install_version(expected, pin)
+    install_type = method.get("type", "")
+    package = method.get("package", tool)
+    if install_type == "binary" and tool in BINARY_SOURCES:
+        install_type, package = BINARY_SOURCES[tool]
+
+    command: list[str] | None = None
+    manager = install_type
+    if install_type == "pip":
+        requirement = package
+        if version:
+            requirement = f"{package}=={version}"
+        elif pin:
+            requirement = f"{package}{normalize_version_spec(pin)}"
+        if shutil.which("uv"):
+            command = ["uv", "tool", "install", requirement]
+        elif shutil.which("pipx"):
+            command = ["pipx", "install", requirement]
+        else:
+            command = [sys.executable, "-m", "pip", "install", requirement]
+    elif install_type == "npm":
+        target = f"{package}@{version}" if version else package
+        if shutil.which("npm"):
+            command = ["npm", "install", "-g", "--ignore-scripts", target]
+        elif shutil.which("bun"):
+            command = ["bun", "add", "-g", "--ignore-scripts", target]
+        manager = "npm or bun"
+    elif install_type == "cargo":
+        if shutil.which("cargo"):
+            command = ["cargo", "install", "--locked", package]
+            if version:
+                command.extend(["--version", version])
+    elif install_type == "gem":
+        if shutil.which("gem"):
+            command = ["gem", "install", package]
+            if version:
+                command.extend(["--version", version])
+    elif install_type == "luarocks":
+        if shutil.which("luarocks"):
+            command = ["luarocks", "install", package]
+            if version:
+                command.append(version)
+    elif install_type == "rustup" and method.get("component"):
+        # Components follow the active toolchain, so no version is passed
+        version = None
+        if shutil.which("rustup"):
+            command = ["rustup", "component", "add", method["component"]]
+    elif install_type == "go":
+        if shutil.which("go"):
+            ref = f"v{version}" if version else "latest"
+            command = ["go", "install", f"{package}@{ref}"]
+    else:
+        return InstallPlan(
+            tool=tool,
+            executable=executable,
+            command=None,
+            note=f"{tool} cannot be installed automatically; see 'lintro doctor'",
+        )
+
+    if command is None:
+        return InstallPlan(
+            tool=tool,
+            executable=executable,
+            command=None,
+            version=version,
+            note=f"{manager} not found; install it to set up {tool}",
+        )
+    note = None
+    if pin and version is None and install_type not in ("pip", "rustup"):
+        note = f"pin {pin!r} is a range; installing the latest release"
+    return InstallPlan(
+        tool=tool,
+        executable=executable,
+        command=command,
+        version=version,
+        note=note,
+    )
+
+
+def find_missing_tools(tool_names: Sequence[str]) -> list[InstallPlan]:
+    """Plan installs for selected tools that are not on PATH.
+
+    Native tools and tools absent from the manifest are never reported.
+
+    Args:
+        tool_names: Tools selected for the run.
+
+    Returns:
+        list[InstallPlan]: One plan per missing tool, in selection order.
+    """
+    methods = get_install_methods()
+    expected_versions = {
+        str(name).lower(): version
+        for name, version in get_all_expected_versions().items()
+    }
+    pins = get_version_pins()
+
+    plans: list[InstallPlan] = []
+    for name in tool_names:
+        tool = name.lower()
+        method = methods.get(tool)
+        if method is None:
+            continue
+        plan = build_install_plan(
+            tool,
+            method,
+            expected=expected_versions.get(tool),
+            pin=pins.get(tool),
+        )
+        if shutil.which(plan.executable) is None:
+            plans.append(plan)
+    return plans
+
+
+def _run_install(command: list[str]) -> tuple[bool, str]:
+    """Run an install command.
+
+    Args:
+        command: Install command to execute.
+
+    Returns:
+        tuple[bool, str]: Whether it succeeded and its combined output.
+    """
+    try:
+        result = subprocess.run(  # nosec B603 - command is constructed safely
+            command,
+            capture_output=True,
+            text=True,
+            timeout=INSTALL_TIMEOUT,
+            shell=False,
+            env=get_subprocess_env(),
+        )
+    except subprocess.TimeoutExpired:
+        return False, f"timed out after {INSTALL_TIMEOUT} seconds"
+    except OSError as e:
+        return False, str(e)
+    return result.returncode == 0, (result.stdout + result.stderr).strip()
+
+
+def install_missing_tools(
+    tool_names: Sequence[str],
+    runner: InstallRunner | None = None,
+) -> list[InstallOutcome]:
+    """Install every selected tool that is missing from PATH.
+
+    Args:
+        tool_names: Tools selected for the run.
+        runner: Function that runs an install command. Defaults to running
+            it as a subprocess.
+
+    Returns:
+        list[InstallOutcome]: One outcome per missing tool.
+    """
+    run = runner or _run_install
+    outcomes: list[InstallOutcome] = []
+    for plan in find_missing_tools(tool_names):
+        if plan.command is None:
+            outcomes.append(
+                InstallOutcome(plan.tool, "unsupported", plan.note or plan.tool),
+            )
+            continue
+
+        label = f"{plan.tool} {plan.version}" if plan.version else plan.tool
+        logger.debug("[tool_installer] Running {}", " ".join(plan.command))
+        success, output = run(plan.command)
+        if not success:
+            detail = output.splitlines()[-1] if output else "no output"
+            outcomes.append(
+                InstallOutcome(
+                    plan.tool,
+                    "failed",
+                    f"Failed to install {label}: {detail}",
+                ),
+            )
+            continue
+        if shutil.which(plan.executable) is None:
+            outcomes.append(
+                InstallOutcome(
+                    plan.tool,
+                    "failed",
+                    f"Installed {label}, but {plan.executable} is not on PATH; "
+                    "run 'lintro doctor' for details",
+                ),
+            )
+            continue
+        message = f"Installed {label} ({plan.command[0]})"
+        if plan.note:
+            message = f"{message}; {plan.note}"
+        outcomes.append(InstallOutcome(plan.tool, "installed", message))
+    return outcomes
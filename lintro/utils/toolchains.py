@@ -0,0 +1,369 @@
+"""Project toolchain detection for Rust, Node.js and Python tools.
+
+Projects pin the toolchain their tools should run with: rustup reads
+``rust-toolchain.toml``, nvm and volta read ``.nvmrc``, ``.node-version`` or
+the ``volta`` section of ``package.json``, and Python projects pin
+``.python-version`` and keep their tools in a virtual environment
+(``VIRTUAL_ENV`` or uv's ``.venv``). Running
+clippy from another Rust release or mypy from another interpreter silently
+changes the results, so lintro runs tools with the pinned toolchain when it
+is installed and ``lintro doctor`` reports the pins the active toolchains do
+not match.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import re
+import shutil
+import sys
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+from loguru import logger
+
+# Files pinning the rustup toolchain; the legacy name may hold a bare channel
+RUST_TOOLCHAIN_FILES: tuple[str, ...] = ("rust-toolchain.toml", "rust-toolchain")
+
+# Files pinning the Node.js version, read by nvm, fnm and similar managers
+NODE_VERSION_FILES: tuple[str, ...] = (".nvmrc", ".node-version")
+
+# File pinning the Python version, read by uv and pyenv
+PYTHON_VERSION_FILE = ".python-version"
+
+# Name of the project virtual environment created by uv
+PROJECT_VENV_DIR = ".venv"
+
+_NUMERIC_VERSION = re.compile(r"^\d+(\.\d+){0,2}$")
+
+
+@dataclass(frozen=True)
+class ToolchainPin:
+    """Toolchain version a project pins.
+
+    Attributes:
+        version: Pinned version or channel (e.g. ``1.80.0``, ``stable``, ``20``).
+        source: File the pin was read from.
+    """
+
+    version: str
+    source: Path
+
+
+def _ancestors(start: str | Path) -> tuple[Path, ...]:
+    """Return a directory and its parents, nearest first.
+
+    Args:
+        start: File or directory to search from.
+
+    Returns:
+        tuple[Path, ...]: Directories to search for pin files.
+    """
+    directory = Path(start).resolve()
+    if not directory.is_dir():
+        directory = directory.parent
+    return (directory, *directory.parents)
+
+
+def _read_text(path: Path) -> str | None:
+    """Read a pin file.
+
+    Args:
+        path: File to read.
+
+    Returns:
+        str | None: File contents, or None if it cannot be read.
+    """
+    try:
+        return path.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError):
+        return None
+
+
+def _rust_channel(content: str) -> str | None:
+    """Read the channel from the contents of a rust-toolchain file.
+
+    Args:
+        content: TOML ``[toolchain]`` table, or a bare channel name in the
+            legacy single-line format.
+
+    Returns:
+        str | None: Channel, or None if the file names no channel (for
+        example a custom toolchain ``path``).
+    """
+    try:
+        data = tomllib.loads(content)
+    except tomllib.TOMLDecodeError:
+        lines = content.strip().splitlines()
+        return lines[0].strip() or None if lines else None
+    toolchain = data.get("toolchain")
+    if not isinstance(toolchain, dict):
+        return None
+    channel = toolchain.get("channel")
+    return channel if isinstance(channel, str) and channel else None
+
+
+def find_rust_toolchain(start: str | Path) -> ToolchainPin | None:
+    """Find the rustup toolchain a directory is pinned to.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        ToolchainPin | None: Pinned channel of the nearest rust-toolchain
+        file, or None if there is none.
+
+    Examples:
+        >>> find_rust_toolchain("/project/crates/core").version  # doctest: +SKIP
+        '1.80.0'
+    """
+    for directory in _ancestors(start):
+        for name in RUST_TOOLCHAIN_FILES:
+            path = directory / name
+            if not path.is_file():
+                continue
+            content = _read_text(path)
+            channel = _rust_channel(content) if content is not None else None
+            return ToolchainPin(channel, path) if channel else None
+    return None
+
+
+def rustup_toolchain(start: str | Path) -> str | None:
+    """Get the toolchain to pass to cargo as ``+<toolchain>``.
+
+    Naming the pinned toolchain explicitly makes cargo fail loudly instead
+    of silently using another release when the ``cargo`` on PATH is not the
+    rustup proxy.
+
+    Args:
+        start: Directory the cargo command runs for.
+
+    Returns:
+        str | None: Pinned channel, or None without a pin or without rustup.
+    """
+    pin = find_rust_toolchain(start)
+    if pin is None:
+        return None
+    if shutil.which("rustup") is None:
+        logger.debug(
+            f"[toolchains] {pin.source} pins Rust {pin.version} but rustup is "
+            "not installed",
+        )
+        return None
+    return pin.version
+
+
+def _normalize_node_version(value: str) -> str | None:
+    """Normalize a pinned Node.js version.
+
+    Args:
+        value: Version as written in a pin file (e.g. ``v20.11.1``).
+
+    Returns:
+        str | None: Version without the ``v`` prefix, or None if empty.
+    """
+    lines = value.strip().splitlines()
+    version = lines[0].strip() if lines else ""
+    return version.removeprefix("v") or None
+
+
+def find_node_version(start: str | Path) -> ToolchainPin | None:
+    """Find the Node.js version a directory is pinned to.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        ToolchainPin | None: Version from the nearest ``.nvmrc``,
+        ``.node-version`` or ``volta.node`` of ``package.json``, or None if
+        there is none.
+    """
+    for directory in _ancestors(start):
+        for name in NODE_VERSION_FILES:
+            path = directory / name
+            content = _read_text(path) if path.is_file() else None
+            version = _normalize_node_version(content) if content else None
+            if version:
+                return ToolchainPin(version, path)
+        package_json = directory / "package.json"
+        content = _read_text(package_json) if package_json.is_file() else None
+        if content is None:
+            continue
+        try:
+            volta = json.loads(content).get("volta")
+        except (ValueError, AttributeError):
+            continue
+        if isinstance(volta, dict) and isinstance(volta.get("node"), str):
+            version = _normalize_node_version(volta["node"])
+            if version:
+                return ToolchainPin(version, package_json)
+    return None
+
+
+def version_matches_pin(installed: str, pinned: str) -> bool | None:
+    """Check an installed toolchain version against a pin.
+
+    Args:
+        installed: Version reported by the toolchain (e.g. ``v20.11.1``).
+        pinned: Pinned version; ``20`` and ``20.11`` match any release of
+            that line.
+
+    Returns:
+        bool | None: Whether the versions match, or None for pins that are
+        not version numbers (e.g. ``lts/iron``).
+    """
+    installed = installed.strip().removeprefix("v")
+    if not _NUMERIC_VERSION.match(pinned):
+        return None
+    installed_parts = installed.split(".")
+    pinned_parts = pinned.split(".")
+    return installed_parts[: len(pinned_parts)] == pinned_parts
+
+
+def find_nvm_node_bin(version: str) -> Path | None:
+    """Find the bin directory of an nvm-installed Node.js version.
+
+    Args:
+        version: Pinned version; the newest installed release of a partial
+            version (``20``) is used.
+
+    Returns:
+        Path | None: Directory holding ``node`` and ``npx``, or None if nvm
+        has no matching install.
+    """
+    nvm_dir = Path(os.environ.get("NVM_DIR") or Path.home() / ".nvm")
+    versions_dir = nvm_dir / "versions" / "node"
+    if not versions_dir.is_dir():
+        return None
+    candidates: list[tuple[tuple[int, ...], Path]] = []
+    for install in versions_dir.iterdir():
+        installed = install.name.removeprefix("v")
+        if not version_matches_pin(installed, version):
+            continue
+        try:
+            key = tuple(int(part) for part in installed.split("."))
+        except ValueError:
+            continue
+        if (install / "bin" / "node").is_file():
+            candidates.append((key, install / "bin"))
+    return max(candidates)[1] if candidates else None
+
+
+def node_toolchain_bin(start: str | Path) -> Path | None:
+    """Get the directory to put first on PATH for Node.js tools.
+
+    Volta's shims already pick the version pinned in ``package.json``, so
+    only pins installed through nvm need PATH changes.
+
+    Args:
+        start: Directory the tool runs for.
+
+    Returns:
+        Path | None: Bin directory of the pinned Node.js install, or None
+        without a pin or a matching nvm install.
+    """
+    pin = find_node_version(start)
+    if pin is None:
+        return None
+    if pin.source.name == "package.json" and shutil.which("volta"):
+        return None
+    bin_dir = find_nvm_node_bin(pin.version)
+    if bin_dir is None:
+        logger.debug(
+            f"[toolchains] {pin.source} pins Node.js {pin.version}, which nvm "
+            "has not installed",
+        )
+    return bin_dir
+
+
+def find_python_version(start: str | Path) -> ToolchainPin | None:
+    """Find the Python version a directory is pinned to.
+
+    Args:
+        start: Directory to search from, walking up to the filesystem root.
+
+    Returns:
+        ToolchainPin | None: Version from the nearest ``.python-version``, or
+        None if there is none.
+    """
+    for directory in _ancestors(start):
+        path = directory / PYTHON_VERSION_FILE
+        content = _read_text(path) if path.is_file() else None
+        lines = content.strip().splitlines() if content else []
+        if lines and lines[0].strip():
+            return ToolchainPin(lines[0].strip(), path)
+    return None
+
+
+def venv_python_version(venv: Path) -> str | None:
+    """Get the Python version of a virtual environment.
+
+    Args:
+        venv: Virtual environment directory.
+
+    Returns:
+        str | None: Version recorded in its ``pyvenv.cfg``, or None if it
+        records none.
+    """
+    content = _read_text(venv / "pyvenv.cfg") or ""
+    for line in content.splitlines():
+        key, sep, value = line.partition("=")
+        if sep and key.strip() in ("version", "version_info"):
+            return value.strip()
+    return None
+
+
+def _venv_python(venv: Path) -> Path:
+    """Return the interpreter of a virtual environment.
+
+    Args:
+        venv: Virtual environment directory.
+
+    Returns:
+        Path: Path of its Python executable.
+    """
+    if os.name == "nt":
+        return venv / "Scripts" / "python.exe"
+    return venv / "bin" / "python"
+
+
+def find_python_environment(start: str | Path) -> Path | None:
+    """Find the virtual environment Python tools of a project run in.
+
+    Args:
+        start: Directory to search for uv's ``.venv`` from.
+
+    Returns:
+        Path | None: The active environment (``VIRTUAL_ENV``), else the
+        nearest ``.venv``, or None if there is neither.
+    """
+    active = os.environ.get("VIRTUAL_ENV")
+    if active and _venv_python(Path(active)).is_file():
+        return Path(active)
+    for directory in _ancestors(start):
+        venv = directory / PROJECT_VENV_DIR
+        if (venv / "pyvenv.cfg").is_file() and _venv_python(venv).is_file():
+            return venv
+    return None
+
+
+def project_python(start: str | Path, tool: str) -> str | None:
+    """Get the interpreter of the project environment a Python tool is in.
+
+    Args:
+        start: Directory the tool runs for.
+        tool: Console script of the tool (e.g. ``mypy``).
+
+    Returns:
+        str | None: Interpreter to run ``python -m <tool>`` with, or None if
+        the project environment is lintro's own or lacks the tool.
+    """
+    venv = find_python_environment(start)
+    if venv is None or venv.resolve() == Path(sys.prefix).resolve():
+        return None
+    scripts = _venv_python(venv).parent
+    if not any((scripts / name).is_file() for name in (tool, f"{tool}.exe")):
+        return None
+    return str(_venv_python(venv))
@@ -0,0 +1,393 @@
+"""Directory treemap of lint debt, rendered as SVG.
+
+``lintro stats --treemap out.svg`` draws every directory as a rectangle whose
+area is proportional to the number of files it holds. Each directory is
+filled by issue density: severity-weighted issues per file, from green (clean)
+through yellow to red (the densest part of the repository). Directories
+deeper than the requested depth are drawn as a single tile; shallower files
+get their own tiles.
+
+The layout is the squarified treemap of Bruls, Huizing and van Wijk, which
+keeps tiles close to square so small directories stay readable.
+"""
+
+from __future__ import annotations
+
+import html
+import os
+from collections.abc import Iterable, Sequence
+from dataclasses import dataclass, field
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.cleanup_plan import resolve_repo_paths
+
+SEVERITY_WEIGHTS: dict[SeverityLevel, float] = {
+    SeverityLevel.ERROR: 3.0,
+    SeverityLevel.WARNING: 1.0,
+    SeverityLevel.INFO: 0.5,
+}
+DEFAULT_DEPTH: int = 3
+
+_TITLE_HEIGHT = 28.0
+_HEADER_HEIGHT = 16.0
+_PADDING = 2.0
+_CHAR_WIDTH = 6.5
+# Density colour scale: clean, middle and densest
+_COLOR_STOPS: tuple[tuple[int, int, int], ...] = (
+    (165, 214, 167),
+    (255, 241, 118),
+    (229, 57, 53),
+)
+
+Rect = tuple[float, float, float, float]
+
+
+@dataclass
+class TreemapNode:
+    """A directory or file with its lint statistics.
+
+    Attributes:
+        name: Last path component; "." for the root.
+        path: Path relative to the current directory.
+        files: Files in the subtree.
+        issues: Issues in the subtree.
+        errors: Error-severity issues in the subtree.
+        weight: Severity-weighted issues in the subtree.
+        children: Subdirectories and files by name; empty for a file.
+    """
+
+    name: str
+    path: str
+    files: int = 0
+    issues: int = 0
+    errors: int = 0
+    weight: float = 0.0
+    children: dict[str, TreemapNode] = field(default_factory=dict)
+
+    @property
+    def density(self) -> float:
+        """Severity-weighted issues per file.
+
+        Returns:
+            float: Density; 0.0 for an empty directory.
+        """
+        return self.weight / self.files if self.files else 0.0
+
+    def child(self, name: str) -> TreemapNode:
+        """Return a child, creating it if needed.
+
+        Args:
+            name: Name of the child.
+
+        Returns:
+            TreemapNode: The child.
+        """
+        if name not in self.children:
+            path = name if self.path == "." else os.path.join(self.path, name)
+            self.children[name] = TreemapNode(name=name, path=path)
+        return self.children[name]
+
+
+def build_tree(
+    files: Iterable[str],
+    tool_results: Sequence[ToolResult],
+    roots: Sequence[str],
+) -> TreemapNode:
+    """Count files and issues per directory.
+
+    Args:
+        files: Files under the checked paths, relative to the current directory.
+        tool_results: Results of a check run.
+        roots: Checked paths, used to resolve the file paths tools reported.
+
+    Returns:
+        TreemapNode: Root of the directory tree. Files that only appear in
+        issues are counted too.
+    """
+    issues = [
+        issue for result in tool_results for issue in (result.issues or [])
+    ]
+    resolved = resolve_repo_paths(
+        [str(getattr(issue, "file", "") or "") for issue in issues],
+        roots,
+    )
+    per_file: dict[str, list[SeverityLevel]] = {
+        os.path.normpath(path): [] for path in files
+    }
+    for issue in issues:
+        reported = str(getattr(issue, "file", "") or "")
+        if not reported:
+            continue
+        path = os.path.normpath(resolved.get(reported, reported))
+        get_severity = getattr(issue, "get_severity", None)
+        per_file.setdefault(path, []).append(
+            get_severity() if get_severity else SeverityLevel.WARNING,
+        )
+
+    root = TreemapNode(name=".", path=".")
+    for path, severities in per_file.items():
+        weight = sum(SEVERITY_WEIGHTS[severity] for severity in severities)
+        errors = severities.count(SeverityLevel.ERROR)
+        node = root
+        parts = [part for part in path.split(os.sep) if part not in ("", ".")]
+        for part in [None, *parts]:
+            if part is not None:
+                node = node.child(part)
+            node.files += 1
+            node.issues += len(severities)
+            node.errors += errors
+            node.weight += weight
+    return root
+
+
+def squarify(sizes: Sequence[float], rect: Rect) -> list[Rect]:
+    """Split a rectangle into tiles with areas proportional to sizes.
+
+    Args:
+        sizes: Positive sizes, largest first.
+        rect: ``(x, y, width, height)`` to fill.
+
+    Returns:
+        list[Rect]: One tile per size, in the same order.
+    """
+    x, y, width, height = rect
+    total = sum(sizes)
+    if not sizes or total <= 0 or width <= 0 or height <= 0:
+        return [(x, y, 0.0, 0.0) for _ in sizes]
+    areas = [size * width * height / total for size in sizes]
+
+    def worst(row: list[float], side: float) -> float:
+        """Return the worst aspect ratio of a row laid along a side.
+
+        Args:
+            row: Areas in the row.
+            side: Length of the side the row is laid along.
+
+        Returns:
+            float: Largest aspect ratio in the row.
+        """
+        row_sum = sum(row)
+        return max(
+            max(row) * side * side / (row_sum * row_sum),
+            row_sum * row_sum / (side * side * min(row)),
+        )
+
+    tiles: list[Rect] = []
+    index = 0
+    while index < len(areas):
+        side = min(width, height)
+        row = [areas[index]]
+        index += 1
+        while index < len(areas) and worst([*row, areas[index]], side) <= worst(
+            row,
+            side,
+        ):
+            row.append(areas[index])
+            index += 1
+        row_sum = sum(row)
+        if width >= height:
+            # Lay the row out as a column along the left edge
+            column = row_sum / height
+            offset = y
+            for area in row:
+                tiles.append((x, offset, column, area / column))
+                offset += area / column
+            x += column
+            width -= column
+        else:
+            # Lay the row out along the top edge
+            band = row_sum / width
+            offset = x
+            for area in row:
+                tiles.append((offset, y, area / band, band))
+                offset += area / band
+            y += band
+            height -= band
+    return tiles
+
+
+def density_color(density: float, max_density: float) -> str:
+    """Pick the fill colour for a density.
+
+    Args:
+        density: Severity-weighted issues per file.
+        max_density: Highest density in the treemap.
+
+    Returns:
+        str: Hex colour.
+    """
+    ratio = min(density / max_density, 1.0) if max_density > 0 else 0.0
+    position = ratio * (len(_COLOR_STOPS) - 1)
+    low = min(int(position), len(_COLOR_STOPS) - 2)
+    fraction = position - low
+    channels = (
+        round(a + (b - a) * fraction)
+        for a, b in zip(_COLOR_STOPS[low], _COLOR_STOPS[low + 1], strict=True)
+    )
+    return "#" + "".join(f"{channel:02x}" for channel in channels)
+
+
+def _leaves(node: TreemapNode, depth: int, max_depth: int) -> Iterable[TreemapNode]:
+    """Yield the nodes drawn as tiles.
+
+    Args:
+        node: Node to start from.
+        depth: Directory depth of the node; 0 for the root.
+        max_depth: Deepest directory level that is subdivided.
+
+    Yields:
+        TreemapNode: Files and directories at the maximum depth.
+    """
+    if not node.children or depth >= max_depth:
+        yield node
+        return
+    for child in node.children.values():
+        yield from _leaves(child, depth + 1, max_depth)
+
+
+def iter_directories(
+    node: TreemapNode,
+    max_depth: int = DEFAULT_DEPTH,
+    depth: int = 0,
+) -> Iterable[TreemapNode]:
+    """Yield the directories of a tree down to a depth, root excluded.
+
+    Args:
+        node: Node to start from.
+        max_depth: Deepest directory level to yield.
+        depth: Directory depth of the node; 0 for the root.
+
+    Yields:
+        TreemapNode: Directories, parents before their children.
+    """
+    for child in node.children.values():
+        if child.children and depth < max_depth:
+            yield child
+            yield from iter_directories(child, max_depth, depth + 1)
+
+
+def _label(text: str, width: float) -> str | None:
+    """Fit a label into a width.
+
+    Args:
+        text: Label text.
+        width: Available width in pixels.
+
+    Returns:
+        str | None: The label, shortened if needed; None if nothing fits.
+    """
+    chars = int((width - 2 * _PADDING) / _CHAR_WIDTH)
+    if chars < 3:
+        return None
+    return text if len(text) <= chars else text[: chars - 1] + "…"
+
+
+def _tooltip(node: TreemapNode) -> str:
+    """Describe a node for the tile tooltip.
+
+    Args:
+        node: Node of the tile.
+
+    Returns:
+        str: Path, file count, issues and density.
+    """
+    return (
+        f"{node.path}: {node.files} file(s), {node.issues} issue(s), "
+        f"{node.errors} error(s), density {node.density:.2f}"
+    )
+
+
+def render_treemap_svg(
+    root: TreemapNode,
+    *,
+    max_depth: int = DEFAULT_DEPTH,
+    width: int = 1200,
+    height: int = 800,
+) -> str:
+    """Render a directory tree as an SVG treemap.
+
+    Args:
+        root: Root of the tree from ``build_tree``.
+        max_depth: Deepest directory level that is subdivided.
+        width: Image width in pixels.
+        height: Image height in pixels.
+
+    Returns:
+        str: SVG document.
+    """
+    max_density = max(
+        (leaf.density for leaf in _leaves(root, 0, max_depth)),
+        default=0.0,
+    )
+    elements: list[str] = []
+
+    def draw(node: TreemapNode, rect: Rect, depth: int) -> None:
+        """Draw a node and, unless it is a tile, its children.
+
+        Args:
+            node: Node to draw.
+            rect: Rectangle of the node.
+            depth: Directory depth of the node.
+        """
+        x, y, w, h = rect
+        if w < 1 or h < 1:
+            return
+        tile = not node.children or depth >= max_depth
+        inner = (
+            x + _PADDING,
+            y + _HEADER_HEIGHT,
+            w - 2 * _PADDING,
+            h - _HEADER_HEIGHT - _PADDING,
+        )
+        if not tile and (inner[2] < 4 or inner[3] < 4):
+            # Too small to show the contents
+            tile = True
+        fill = density_color(node.density, max_density) if tile else "#f5f5f5"
+        elements.append(
+            f'<rect x="{x:.1f}" y="{y:.1f}" width="{w:.1f}" height="{h:.1f}" '
+            f'fill="{fill}" stroke="#ffffff" stroke-width="1">'
+            f"<title>{html.escape(_tooltip(node))}</title></rect>",
+        )
+        label = _label(node.name, w) if h >= 14 else None
+        if label and node.path != ".":
+            elements.append(
+                f'<text x="{x + _PADDING + 1:.1f}" y="{y + 12:.1f}">'
+                f"{html.escape(label)}</text>",
+            )
+        if tile:
+            return
+        children = sorted(
+            node.children.values(),
+            key=lambda child: (-child.files, child.name),
+        )
+        tiles = squarify([child.files for child in children], inner)
+        for child, child_rect in zip(children, tiles, strict=True):
+            draw(child, child_rect, depth + 1)
+
+    area = (0.0, _TITLE_HEIGHT, float(width), height - _TITLE_HEIGHT)
+    if root.children:
+        children = sorted(
+            root.children.values(),
+            key=lambda child: (-child.files, child.name),
+        )
+        tiles = squarify([child.files for child in children], area)
+        for child, child_rect in zip(children, tiles, strict=True):
+            draw(child, child_rect, 1)
+
+    title = (
+        f"Lint debt by directory: {root.files} file(s), {root.issues} issue(s); "
+        "area = files, colour = severity-weighted issues per file"
+    )
+    return "\n".join(
+        [
+            '<svg xmlns="http://www.w3.org/2000/svg" '
+            f'width="{width}" height="{height}" '
+            f'viewBox="0 0 {width} {height}" '
+            'font-family="sans-serif" font-size="11">',
+            f'<text x="4" y="18" font-size="14">{html.escape(title)}</text>',
+            *elements,
+            "</svg>",
+            "",
+        ],
+    )
@@ -0,0 +1,626 @@
+"""Version control providers.
+
+Lintro reads a few facts from version control: the commit a report or
+attestation was produced from, the author recorded in the fix audit log, and
+the files ``lintro check --staged`` checks, the files changed since a
+revision, and who last changed a line. Systems without a staging area
+stand in their closest equivalent: the working-copy commit's changes in jj
+and the uncommitted changes in hg.
+Jujutsu (jj), Mercurial (hg) and git repositories are recognised by their
+marker directory; the nearest one wins, and jj wins over the git repository
+it is colocated with. Source-only trees, such as Docker builds that copy the
+project without its repository, and images without the executable fall back
+to empty values; the reason is logged once and ``lintro doctor`` shows it,
+instead of the run failing.
+
+Another system is added by implementing VcsProvider and adding a
+ProviderSpec to PROVIDERS.
+"""
+
+from __future__ import annotations
+
+import functools
+import os
+import re
+import shutil
+import subprocess
+from collections.abc import Callable
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Protocol
+
+from loguru import logger
+
+from lintro.enums.git_command import GitCommand
+from lintro.utils.environment.container_detection import is_container_environment
+from lintro.utils.git_info import git_output
+
+
+class VcsProvider(Protocol):
+    """Read-only access to the repository around the working directory."""
+
+    @property
+    def name(self) -> str:
+        """Return the version control system name (e.g. ``git``)."""
+        ...
+
+    @property
+    def root(self) -> Path | None:
+        """Return the repository root, or None when there is no repository."""
+        ...
+
+    def head_commit(self) -> str | None:
+        """Return the identifier of the checked-out commit, if known."""
+        ...
+
+    def user_email(self) -> str | None:
+        """Return the configured author email, if known."""
+        ...
+
+    def staged_files(self) -> list[str] | None:
+        """Return the files about to be committed, or None if unknown."""
+        ...
+
+    def changed_files(self, base: str | None = None) -> list[str] | None:
+        """Return the files changed since a revision, or None if unknown."""
+        ...
+
+    def blame(self, path: str, line: int) -> str | None:
+        """Return the email of who last changed a line, if known."""
+        ...
+
+
+_EMAIL_RE = re.compile(r"<([^>]+)>")
+
+
+def _repo_path(root: Path, path: str) -> str:
+    """Express a path from the working directory relative to the repository.
+
+    Args:
+        root: Repository root.
+        path: File path, absolute or relative to the working directory.
+
+    Returns:
+        str: Forward-slash path relative to ``root``.
+    """
+    return Path(os.path.relpath(os.path.abspath(path), root)).as_posix()
+
+
+def _nth(lines: list[str] | None, line: int) -> str | None:
+    """Pick one line of a per-line listing.
+
+    Args:
+        lines: Output lines, one per file line, or None.
+        line: 1-based line number.
+
+    Returns:
+        str | None: The stripped entry, or None if it is missing or empty.
+    """
+    if lines is None or not 0 < line <= len(lines):
+        return None
+    return lines[line - 1].strip() or None
+
+
+def _existing_paths(root: Path, names: list[str]) -> list[str]:
+    """Turn repository paths into paths relative to the working directory.
+
+    Deleted files are left out, since there is nothing left to lint.
+
+    Args:
+        root: Repository root the names are relative to.
+        names: Repository-relative paths, possibly empty strings.
+
+    Returns:
+        list[str]: Existing files relative to the working directory.
+    """
+    start = Path.cwd()
+    return [
+        os.path.relpath(root / name, start)
+        for name in names
+        if name and (root / name).is_file()
+    ]
+
+
+@dataclass(frozen=True)
+class GitProvider:
+    """Git repository provider.
+
+    Attributes:
+        root: Directory containing ``.git``.
+    """
+
+    root: Path
+
+    @property
+    def name(self) -> str:
+        """Return the version control system name.
+
+        Returns:
+            str: Always ``git``.
+        """
+        return "git"
+
+    def head_commit(self) -> str | None:
+        """Return the checked-out commit.
+
+        Returns:
+            str | None: Full commit hash, or None if git cannot resolve HEAD.
+        """
+        return git_output(GitCommand.REV_PARSE, "HEAD", cwd=self.root)
+
+    def user_email(self) -> str | None:
+        """Return the configured author email.
+
+        Returns:
+            str | None: ``user.email``, or None if it is not set.
+        """
+        return git_output(GitCommand.CONFIG, "user.email", cwd=self.root)
+
+    def staged_files(self) -> list[str] | None:
+        """Return the files staged in the index.
+
+        Returns:
+            list[str] | None: Added, copied, modified or renamed files,
+            relative to the working directory.
+        """
+        output = git_output(
+            GitCommand.DIFF,
+            "--cached",
+            "--name-only",
+            "--diff-filter=ACMR",
+            "-z",
+            cwd=self.root,
+        )
+        return _existing_paths(self.root, (output or "").split("\0"))
+
+    def changed_files(self, base: str | None = None) -> list[str] | None:
+        """Return the files whose working-tree content differs from a commit.
+
+        Args:
+            base: Revision to compare with. Defaults to HEAD.
+
+        Returns:
+            list[str] | None: Changed files relative to the working
+            directory, or None if git cannot resolve the revision.
+        """
+        revision = base or "HEAD"
+        resolved = git_output(GitCommand.REV_PARSE, "--verify", revision, cwd=self.root)
+        if resolved is None:
+            return None
+        output = git_output(
+            GitCommand.DIFF,
+            "--name-only",
+            "--diff-filter=ACMR",
+            "-z",
+            revision,
+            cwd=self.root,
+        )
+        return _existing_paths(self.root, (output or "").split("\0"))
+
+    def blame(self, path: str, line: int) -> str | None:
+        """Return the author email of the commit that last changed a line.
+
+        Args:
+            path: File path relative to the working directory.
+            line: 1-based line number.
+
+        Returns:
+            str | None: Author email, or None if git cannot blame the line
+            or it is not committed yet.
+        """
+        output = git_output(
+            GitCommand.BLAME,
+            "--porcelain",
+            "-L",
+            f"{line},{line}",
+            "--",
+            _repo_path(self.root, path),
+            cwd=self.root,
+        )
+        for entry in (output or "").splitlines():
+            if entry.startswith("author-mail "):
+                match = _EMAIL_RE.search(entry)
+                email = match.group(1) if match else None
+                return None if email == "not.committed.yet" else email
+        return None
+
+
+def _output(command: list[str], cwd: Path, timeout: float = 5) -> str | None:
+    """Run a read-only version control command and capture its output.
+
+    Args:
+        command: Command and arguments.
+        cwd: Repository root to run in.
+        timeout: Seconds before the command is abandoned.
+
+    Returns:
+        str | None: Output as printed, or None if the command is missing or
+        fails.
+    """
+    try:
+        result = subprocess.run(
+            command,
+            capture_output=True,
+            text=True,
+            timeout=timeout,
+            cwd=cwd,
+        )
+    except (FileNotFoundError, OSError, subprocess.TimeoutExpired):
+        return None
+    if result.returncode != 0:
+        return None
+    return result.stdout
+
+
+def _run(command: list[str], cwd: Path) -> str | None:
+    """Run a read-only version control command.
+
+    Args:
+        command: Command and arguments.
+        cwd: Repository root to run in.
+
+    Returns:
+        str | None: Stripped output, or None if the command is missing,
+        fails or prints nothing.
+    """
+    output = _output(command, cwd)
+    if output is None:
+        return None
+    return output.strip() or None
+
+
+def _run_lines(command: list[str], cwd: Path) -> list[str] | None:
+    """Run a version control command that lists paths, one per line.
+
+    Listing a large repository can take longer than reading one fact.
+
+    Args:
+        command: Command and arguments.
+        cwd: Repository root to run in.
+
+    Returns:
+        list[str] | None: Output lines, or None if the command is missing
+        or fails.
+    """
+    output = _output(command, cwd, timeout=30)
+    return output.splitlines() if output is not None else None
+
+
+@dataclass(frozen=True)
+class JujutsuProvider:
+    """Jujutsu (jj) repository provider.
+
+    Commands pass ``--ignore-working-copy`` so reading the repository never
+    snapshots the working copy, except when listing changed files, which
+    only the snapshot knows about.
+
+    Attributes:
+        root: Directory containing ``.jj``.
+    """
+
+    root: Path
+
+    @property
+    def name(self) -> str:
+        """Return the version control system name.
+
+        Returns:
+            str: Always ``jj``.
+        """
+        return "jj"
+
+    def head_commit(self) -> str | None:
+        """Return the parent of the working-copy commit.
+
+        Like git's HEAD, it excludes changes still in the working copy.
+
+        Returns:
+            str | None: Full commit id, or None if jj cannot resolve it.
+        """
+        return _run(
+            ["jj", "--ignore-working-copy", "log", "--no-graph"]
+            + ["-r", "@-", "-T", "commit_id"],
+            self.root,
+        )
+
+    def user_email(self) -> str | None:
+        """Return the configured author email.
+
+        Returns:
+            str | None: ``user.email``, or None if it is not set.
+        """
+        return _run(
+            ["jj", "--ignore-working-copy", "config", "get", "user.email"],
+            self.root,
+        )
+
+    def staged_files(self) -> list[str] | None:
+        """Return the files changed in the working-copy commit.
+
+        jj has no index; the working-copy commit is what the next
+        ``jj commit`` records.
+
+        Returns:
+            list[str] | None: Changed files relative to the working
+            directory, or None if jj fails.
+        """
+        return self.changed_files()
+
+    def changed_files(self, base: str | None = None) -> list[str] | None:
+        """Return the files the working copy changes relative to a revision.
+
+        Args:
+            base: Revision to compare with. Defaults to ``@-``.
+
+        Returns:
+            list[str] | None: Changed files relative to the working
+            directory, or None if jj fails.
+        """
+        names = _run_lines(
+            ["jj", "diff", "--name-only", "--from", base or "@-", "--to", "@"],
+            self.root,
+        )
+        return _existing_paths(self.root, names) if names is not None else None
+
+    def blame(self, path: str, line: int) -> str | None:
+        """Return the author email of the change that last touched a line.
+
+        Args:
+            path: File path relative to the working directory.
+            line: 1-based line number.
+
+        Returns:
+            str | None: Author email, or None if jj cannot annotate the file.
+        """
+        return _nth(
+            _run_lines(
+                ["jj", "file", "annotate", "-T", 'commit.author().email() ++ "\\n"']
+                + [_repo_path(self.root, path)],
+                self.root,
+            ),
+            line,
+        )
+
+
+@dataclass(frozen=True)
+class MercurialProvider:
+    """Mercurial (hg) repository provider.
+
+    Attributes:
+        root: Directory containing ``.hg``.
+    """
+
+    root: Path
+
+    @property
+    def name(self) -> str:
+        """Return the version control system name.
+
+        Returns:
+            str: Always ``hg``.
+        """
+        return "hg"
+
+    def head_commit(self) -> str | None:
+        """Return the working directory's parent changeset.
+
+        Returns:
+            str | None: Full changeset hash, or None outside a repository.
+        """
+        return _run(["hg", "log", "-r", ".", "-T", "{node}"], self.root)
+
+    def user_email(self) -> str | None:
+        """Return the email of the configured user.
+
+        Returns:
+            str | None: Email from ``ui.username`` (``Name <email>``), the
+            whole setting if it holds no angle brackets, or None if unset.
+        """
+        username = _run(["hg", "config", "ui.username"], self.root)
+        if username is None:
+            return None
+        match = _EMAIL_RE.search(username)
+        return match.group(1) if match else username
+
+    def staged_files(self) -> list[str] | None:
+        """Return the files added or modified since the parent changeset.
+
+        Mercurial has no index; ``hg commit`` records every uncommitted
+        change.
+
+        Returns:
+            list[str] | None: Changed files relative to the working
+            directory, or None if hg fails.
+        """
+        return self.changed_files()
+
+    def changed_files(self, base: str | None = None) -> list[str] | None:
+        """Return the files added or modified relative to a revision.
+
+        Args:
+            base: Revision to compare with. Defaults to the working
+                directory's parent.
+
+        Returns:
+            list[str] | None: Changed files relative to the working
+            directory, or None if hg fails.
+        """
+        names = _run_lines(
+            ["hg", "status", "--added", "--modified", "--no-status"]
+            + ["--rev", base or "."],
+            self.root,
+        )
+        return _existing_paths(self.root, names) if names is not None else None
+
+    def blame(self, path: str, line: int) -> str | None:
+        """Return the email of who committed the last change to a line.
+
+        Args:
+            path: File path relative to the working directory.
+            line: 1-based line number.
+
+        Returns:
+            str | None: Email from the changeset's user (the whole user if it
+            holds no angle brackets), or None if hg cannot annotate the file.
+        """
+        user = _nth(
+            _run_lines(
+                ["hg", "annotate", "-T", "{lines % '{user}\\n'}"]
+                + [_repo_path(self.root, path)],
+                self.root,
+            ),
+            line,
+        )
+        if user is None:
+            return None
+        match = _EMAIL_RE.search(user)
+        return match.group(1) if match else user
+
+
+@functools.cache
+def _report_fallback(reason: str) -> None:
+    """Log once per reason that version control facts are unavailable.
+
+    Args:
+        reason: Why no provider was found.
+    """
+    logger.warning(
+        f"Version control unavailable ({reason}); commit and author "
+        "details are left empty",
+    )
+
+
+@dataclass(frozen=True)
+class UnavailableVcs:
+    """Fallback used when no version control system could be detected.
+
+    Attributes:
+        reason: Why no provider was found, shown by ``lintro doctor``.
+    """
+
+    reason: str
+
+    @property
+    def name(self) -> str:
+        """Return the version control system name.
+
+        Returns:
+            str: Always ``none``.
+        """
+        return "none"
+
+    @property
+    def root(self) -> Path | None:
+        """Return the repository root.
+
+        Returns:
+            Path | None: Always None.
+        """
+        return None
+
+    def head_commit(self) -> str | None:
+        """Report the fallback and return no commit.
+
+        Returns:
+            str | None: Always None.
+        """
+        _report_fallback(self.reason)
+        return None
+
+    def user_email(self) -> str | None:
+        """Report the fallback and return no author.
+
+        Returns:
+            str | None: Always None.
+        """
+        _report_fallback(self.reason)
+        return None
+
+    def staged_files(self) -> list[str] | None:
+        """Return no staged files.
+
+        Returns:
+            list[str] | None: Always None.
+        """
+        return None
+
+    def changed_files(self, base: str | None = None) -> list[str] | None:
+        """Return no changed files.
+
+        Args:
+            base: Ignored.
+
+        Returns:
+            list[str] | None: Always None.
+        """
+        return None
+
+    def blame(self, path: str, line: int) -> str | None:
+        """Return no author.
+
+        Args:
+            path: Ignored.
+            line: Ignored.
+
+        Returns:
+            str | None: Always None.
+        """
+        return None
+
+
+@dataclass(frozen=True)
+class ProviderSpec:
+    """How to detect one version control system.
+
+    Attributes:
+        marker: Entry marking a repository root (file or directory).
+        executable: Command the provider runs.
+        create: Builds the provider for a repository root.
+    """
+
+    marker: str
+    executable: str
+    create: Callable[[Path], VcsProvider]
+
+
+# Checked in order in each directory; jj first as it colocates with git
+PROVIDERS: tuple[ProviderSpec, ...] = (
+    ProviderSpec(marker=".jj", executable="jj", create=JujutsuProvider),
+    ProviderSpec(marker=".hg", executable="hg", create=MercurialProvider),
+    ProviderSpec(marker=".git", executable="git", create=GitProvider),
+)
+
+
+def detect_vcs(start: Path | None = None) -> VcsProvider:
+    """Detect the version control system around a directory.
+
+    Args:
+        start: Directory to search from. Defaults to the working directory.
+
+    Returns:
+        VcsProvider: Provider of the nearest usable repository, or
+        UnavailableVcs explaining why none could be used.
+    """
+    directory = (start or Path.cwd()).resolve()
+    unusable: list[str] = []
+    for candidate in [directory, *directory.parents]:
+        for spec in PROVIDERS:
+            # Worktrees and submodules use a .git file instead of a directory
+            if not (candidate / spec.marker).exists():
+                continue
+            if shutil.which(spec.executable) is None:
+                unusable.append(
+                    f"{spec.marker} found in {candidate} but {spec.executable} "
+                    "is not on PATH",
+                )
+                continue
+            return spec.create(candidate)
+
+    markers = [spec.marker for spec in PROVIDERS]
+    reason = "; ".join(unusable) or (
+        f"no {', '.join(markers[:-1])} or {markers[-1]} in {directory} "
+        "or its parents"
+    )
+    if is_container_environment():
+        reason += " (running in a container; copy .git into the image to keep it)"
+    return UnavailableVcs(reason=reason)
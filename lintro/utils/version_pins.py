@@ -0,0 +1,83 @@
+"""Per-tool version pins.
+
+Projects pin tool versions under ``tools.<name>.version`` in the Lintro
+config so every machine and CI job runs the same tool releases. A pin is a
+PEP 440 specifier such as ``>=0.6.0`` or ``~=1.92.0``; a bare version such
+as ``0.14.2`` means exactly that version.
+"""
+
+from __future__ import annotations
+
+from packaging.specifiers import InvalidSpecifier, SpecifierSet
+
+
+def normalize_version_spec(spec: str) -> str:
+    """Turn a configured pin into a PEP 440 specifier string.
+
+    Args:
+        spec: Configured pin, e.g. ``0.14.2``, ``>=0.6`` or ``0.14.*``.
+
+    Returns:
+        str: Specifier string; bare versions become ``==<version>``.
+
+    Raises:
+        ValueError: If the pin is empty or not a valid specifier.
+    """
+    cleaned = spec.strip()
+    if not cleaned:
+        raise ValueError("version pin must not be empty")
+    if cleaned[0].isdigit():
+        cleaned = f"=={cleaned}"
+    elif cleaned[0] in "vV" and cleaned[1:2].isdigit():
+        cleaned = f"=={cleaned[1:]}"
+    try:
+        SpecifierSet(cleaned)
+    except InvalidSpecifier as e:
+        raise ValueError(f"invalid version pin {spec!r}") from e
+    return cleaned
+
+
+def version_satisfies(version: str, spec: str) -> bool:
+    """Check whether an installed version satisfies a pin.
+
+    Args:
+        version: Installed version as reported by the tool.
+        spec: Configured pin.
+
+    Returns:
+        bool: True if the version matches the pin.
+
+    Raises:
+        ValueError: If the version or the pin cannot be parsed.
+    """
+    from lintro.tools.core.version_parsing import parse_version
+
+    specifier = SpecifierSet(normalize_version_spec(spec))
+    return specifier.contains(parse_version(version), prereleases=True)
+
+
+def get_version_pin(tool_name: str) -> str | None:
+    """Return the configured version pin for a tool.
+
+    Args:
+        tool_name: Tool name as used in the config (e.g. ``ruff``).
+
+    Returns:
+        str | None: Configured pin, or None if the tool is not pinned.
+    """
+    return get_version_pins().get(tool_name.lower())
+
+
+def get_version_pins() -> dict[str, str]:
+    """Return all configured version pins.
+
+    Returns:
+        dict[str, str]: Pins keyed by lower-case tool name.
+    """
+    from lintro.config.config_loader import get_config
+
+    return {
+        name: tool_config.version
+        for name, tool_config in get_config().tools.items()
+        if tool_config.version
+    }
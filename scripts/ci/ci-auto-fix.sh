@@ -51,7 +51,7 @@ docker run --rm \
 	-v "$PWD:/code" \
 	-w /code \
 	py-lintro:latest \
-	lintro format . --output-format grid || true
+	lintro format . --fix-safety all --output-format grid || true
 
 CHANGED=$(git status --porcelain)
 if [ -n "$CHANGED" ]; then
@@ -43,7 +43,8 @@ mkdir -p lintro-report
 # 126 (container command cannot be invoked), and 127 (command not found).
 LINTRO_OUTPUT=$(mktemp)
 LINTRO_RC=0
-"${DOCKER_RUN[@]}" lintro check . --output-format markdown \
+# The report profile adds opt-in tools such as cargo-outdated
+"${DOCKER_RUN[@]}" lintro check . --profile report --output-format markdown \
 	--exclude "$EXCLUDE_DIRS" \
 	--tool-options pydoclint:timeout=120 >"$LINTRO_OUTPUT" 2>&1 || LINTRO_RC=$?
 
@@ -79,6 +80,12 @@ rm -f "$LINTRO_OUTPUT"
 	echo ""
 	# Drop the first two lines (title and blank line) to keep summary heading hierarchy clean
 	tail -n +3 lintro-report/report.md
+	echo ""
+	echo "### 📈 Issue Trend"
+	echo '```'
+	# The report profile records each run in .lintro/history.db
+	"${DOCKER_RUN[@]}" lintro history --db .lintro/history.db --limit 10 || true
+	echo '```'
 } >>"$GITHUB_STEP_SUMMARY"
 
 log_success "Lintro report generated successfully"
@@ -64,18 +64,40 @@ def _tool_command(tool_name: str, install: dict[str, Any]) -> list[str]:
         return ["cargo", "audit", "--version"]
     if tool_name == "cargo_deny":
         return ["cargo", "deny", "--version"]
+    if tool_name == "cargo_geiger":
+        return ["cargo", "geiger", "--version"]
+    if tool_name == "cargo_machete":
+        return ["cargo", "machete", "--version"]
+    if tool_name == "cargo_outdated":
+        return ["cargo", "outdated", "--version"]
     if tool_name == "clippy":
         return ["cargo", "clippy", "--version"]
+    if tool_name == "clang_format":
+        return [bin_name or "clang-format", "--version"]
+    if tool_name == "clang_tidy":
+        return [bin_name or "clang-tidy", "--version"]
     if tool_name == "markdownlint":
         return [bin_name or "markdownlint-cli2", "--version"]
     if tool_name == "gitleaks":
         return [bin_name or "gitleaks", "version"]
+    if tool_name == "gofmt":
+        return ["go", "version"]
+    if tool_name == "golangci_lint":
+        return [bin_name or "golangci-lint", "--version"]
     if tool_name == "rustfmt":
         return [bin_name or "rustfmt", "--version"]
     if tool_name == "shellcheck":
         return [bin_name or "shellcheck", "--version"]
     if tool_name == "taplo":
         return [bin_name or "taplo", "--version"]
+    if tool_name == "typos":
+        return [bin_name or "typos", "--version"]
+    if tool_name == "vale":
+        return [bin_name or "vale", "--version"]
+    if tool_name == "terraform_fmt":
+        return [bin_name or "terraform", "version"]
+    if tool_name == "tflint":
+        return [bin_name or "tflint", "--version"]
     if tool_name == "actionlint":
         return [bin_name or "actionlint", "--version"]
 
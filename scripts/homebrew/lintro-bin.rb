@@ -36,9 +36,10 @@ class LintroBin < Formula
 
       However, the external tools must be installed separately.
       Install them via Homebrew:
-        brew install ruff black mypy bandit rust \\
-          hadolint actionlint gitleaks markdownlint-cli2 prettier \\
-          yamllint semgrep shellcheck shfmt sqlfluff taplo
+        brew install ruff black mypy bandit checkov rust go golangci-lint \\
+          hadolint actionlint gitleaks markdownlint-cli2 prettier clang-format \\
+          yamllint semgrep shellcheck shfmt sqlfluff taplo tflint typos-cli \\
+          vale bufbuild/buf/buf ansible-lint rubocop ktlint stylua luacheck
 
       For JavaScript/TypeScript linting and formatting, install via bun/npm:
         bun add -d oxlint oxfmt
@@ -49,6 +50,12 @@ class LintroBin < Formula
       Optional Rust tools (install via cargo):
         cargo install cargo-audit
 
+      For Terraform formatting, install terraform from HashiCorp's tap:
+        brew install hashicorp/tap/terraform
+
+      For C/C++ linting, install clang-tidy from LLVM:
+        brew install llvm
+
       Or for Python tools via pipx:
         pipx install ruff black mypy bandit pydoclint yamllint sqlfluff semgrep
 
@@ -20,10 +20,6 @@ if [ -f .env ]; then
 	export $(grep -v '^#' .env | xargs)
 fi
 
-# Enable unsafe fixes for local development
-# This allows C4 and SIM rules to be auto-fixed during development
-export RUFF_UNSAFE_FIXES=true
-echo -e "${YELLOW}Enabled unsafe fixes for local development (RUFF_UNSAFE_FIXES=true)${NC}"
 
 # Function to check if tools are installed
 check_and_install_tools() {
@@ -122,6 +118,15 @@ run_lintro() {
 		set -- "--help"
 	fi
 
+	# Enable unsafe fixes for local development
+	# This allows C4 and SIM rules to be auto-fixed during development
+	case "$1" in
+	format | fmt | fix)
+		set -- "$@" --fix-safety all
+		echo -e "${YELLOW}Enabled unsafe fixes for local development (--fix-safety all)${NC}"
+		;;
+	esac
+
 	# Run lintro with the provided arguments
 	# In Docker, use uv run to execute within the container's virtual environment
 	if [ -n "${RUNNING_IN_DOCKER:-}" ] || [ -f "/.dockerenv" ]; then
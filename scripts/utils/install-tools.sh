@@ -72,13 +72,30 @@ This script installs:
   - Rustfmt (Rust formatter; requires Rust toolchain)
   - Cargo-audit (Rust dependency vulnerability scanner; requires Rust toolchain)
   - Cargo-deny (Rust dependency license/advisory checker; requires Rust toolchain)
+  - Cargo-geiger (Rust unsafe usage report; requires Rust toolchain)
+  - Cargo-machete (Rust unused dependency finder; requires Rust toolchain)
+  - Cargo-outdated (Rust stale dependency report; requires Rust toolchain)
   - Oxlint (JavaScript/TypeScript linter)
   - Oxfmt (JavaScript/TypeScript formatter)
   - Semgrep (Security scanner)
+  - Checkov (IaC misconfiguration scanner)
+  - ansible-lint (Ansible playbook and role linter)
+  - RuboCop (Ruby linter and formatter, requires RubyGems)
+  - ktlint (Kotlin linter and formatter, requires Java)
+  - StyLua (Lua formatter)
+  - luacheck (Lua linter, requires LuaRocks)
   - ShellCheck (Shell script linter)
   - shfmt (Shell script formatter)
   - SQLFluff (SQL linter and formatter)
+  - clang-format (C/C++ formatter)
+  - clang-tidy (C/C++ linter)
   - Taplo (TOML linter and formatter)
+  - Terraform (terraform fmt formatter)
+  - TFLint (Terraform linter)
+  - golangci-lint (Go linter runner; gofmt needs the Go toolchain)
+  - buf (Protobuf linter, formatter and breaking change detector)
+  - typos (Source code spell checker)
+  - Vale (Prose linter)
   - TypeScript (TypeScript compiler and type checker)
   - Astro Check (Astro component type checker)
   - Gitleaks (Secret detection scanner)
@@ -747,6 +764,154 @@ main() {
 		fi
 	fi
 
+	# Install cargo-geiger (Rust unsafe usage report)
+	# Prefer pre-built binary from cargo-quickinstall to avoid long compile times
+	echo -e "${BLUE}Installing cargo-geiger...${NC}"
+	CARGO_GEIGER_VERSION=$(get_tool_version "cargo_geiger") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install cargo-geiger==${CARGO_GEIGER_VERSION}"
+	elif command -v cargo-geiger &>/dev/null; then
+		echo -e "${GREEN}✓ cargo-geiger already installed${NC}"
+	else
+		cargo_geiger_installed=false
+		# Try pre-built binary from cargo-quickinstall first (much faster than cargo install)
+		tmpdir=$(mktemp -d)
+		os=$(uname -s | tr '[:upper:]' '[:lower:]')
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) target="x86_64-unknown-linux-gnu" ;;
+		aarch64 | arm64) target="aarch64-unknown-linux-gnu" ;;
+		*) target="" ;;
+		esac
+		# cargo-quickinstall only provides linux binaries
+		if [[ "$os" == "linux" ]] && [[ -n "$target" ]]; then
+			tgz_url="https://github.com/cargo-bins/cargo-quickinstall/releases/download/cargo-geiger-${CARGO_GEIGER_VERSION}/cargo-geiger-${CARGO_GEIGER_VERSION}-${target}.tar.gz"
+			echo -e "${YELLOW}Trying pre-built binary from cargo-quickinstall...${NC}"
+			if download_with_retries "$tgz_url" "$tmpdir/cargo-geiger.tar.gz" 3; then
+				tar -xzf "$tmpdir/cargo-geiger.tar.gz" -C "$tmpdir"
+				if [ -f "$tmpdir/cargo-geiger" ]; then
+					cp "$tmpdir/cargo-geiger" "$BIN_DIR/cargo-geiger"
+					chmod +x "$BIN_DIR/cargo-geiger"
+					echo -e "${GREEN}✓ cargo-geiger installed from pre-built binary${NC}"
+					cargo_geiger_installed=true
+				fi
+			fi
+		fi
+		rm -rf "$tmpdir"
+
+		# Fallback to cargo install if pre-built binary not available
+		if [ "$cargo_geiger_installed" = false ] && command -v cargo &>/dev/null; then
+			echo -e "${YELLOW}Pre-built binary not available, falling back to cargo install...${NC}"
+			ensure_cargo_audit_deps
+			if cargo install cargo-geiger --locked --version "$CARGO_GEIGER_VERSION"; then
+				echo -e "${GREEN}✓ cargo-geiger installed via cargo${NC}"
+				cargo_geiger_installed=true
+			fi
+		fi
+
+		if [ "$cargo_geiger_installed" = false ]; then
+			echo -e "${YELLOW}⚠ Failed to install cargo-geiger (optional tool)${NC}"
+		fi
+	fi
+
+	# Install cargo-machete (Rust unused dependency finder)
+	# Prefer pre-built binary from cargo-quickinstall to avoid long compile times
+	echo -e "${BLUE}Installing cargo-machete...${NC}"
+	CARGO_MACHETE_VERSION=$(get_tool_version "cargo_machete") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install cargo-machete==${CARGO_MACHETE_VERSION}"
+	elif command -v cargo-machete &>/dev/null; then
+		echo -e "${GREEN}✓ cargo-machete already installed${NC}"
+	else
+		cargo_machete_installed=false
+		# Try pre-built binary from cargo-quickinstall first (much faster than cargo install)
+		tmpdir=$(mktemp -d)
+		os=$(uname -s | tr '[:upper:]' '[:lower:]')
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) target="x86_64-unknown-linux-gnu" ;;
+		aarch64 | arm64) target="aarch64-unknown-linux-gnu" ;;
+		*) target="" ;;
+		esac
+		# cargo-quickinstall only provides linux binaries
+		if [[ "$os" == "linux" ]] && [[ -n "$target" ]]; then
+			tgz_url="https://github.com/cargo-bins/cargo-quickinstall/releases/download/cargo-machete-${CARGO_MACHETE_VERSION}/cargo-machete-${CARGO_MACHETE_VERSION}-${target}.tar.gz"
+			echo -e "${YELLOW}Trying pre-built binary from cargo-quickinstall...${NC}"
+			if download_with_retries "$tgz_url" "$tmpdir/cargo-machete.tar.gz" 3; then
+				tar -xzf "$tmpdir/cargo-machete.tar.gz" -C "$tmpdir"
+				if [ -f "$tmpdir/cargo-machete" ]; then
+					cp "$tmpdir/cargo-machete" "$BIN_DIR/cargo-machete"
+					chmod +x "$BIN_DIR/cargo-machete"
+					echo -e "${GREEN}✓ cargo-machete installed from pre-built binary${NC}"
+					cargo_machete_installed=true
+				fi
+			fi
+		fi
+		rm -rf "$tmpdir"
+
+		# Fallback to cargo install if pre-built binary not available
+		if [ "$cargo_machete_installed" = false ] && command -v cargo &>/dev/null; then
+			echo -e "${YELLOW}Pre-built binary not available, falling back to cargo install...${NC}"
+			if cargo install cargo-machete --locked --version "$CARGO_MACHETE_VERSION"; then
+				echo -e "${GREEN}✓ cargo-machete installed via cargo${NC}"
+				cargo_machete_installed=true
+			fi
+		fi
+
+		if [ "$cargo_machete_installed" = false ]; then
+			echo -e "${YELLOW}⚠ Failed to install cargo-machete (optional tool)${NC}"
+		fi
+	fi
+
+	# Install cargo-outdated (Rust stale dependency report)
+	# Prefer pre-built binary from cargo-quickinstall to avoid long compile times
+	echo -e "${BLUE}Installing cargo-outdated...${NC}"
+	CARGO_OUTDATED_VERSION=$(get_tool_version "cargo_outdated") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install cargo-outdated==${CARGO_OUTDATED_VERSION}"
+	elif command -v cargo-outdated &>/dev/null; then
+		echo -e "${GREEN}✓ cargo-outdated already installed${NC}"
+	else
+		cargo_outdated_installed=false
+		# Try pre-built binary from cargo-quickinstall first (much faster than cargo install)
+		tmpdir=$(mktemp -d)
+		os=$(uname -s | tr '[:upper:]' '[:lower:]')
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) target="x86_64-unknown-linux-gnu" ;;
+		aarch64 | arm64) target="aarch64-unknown-linux-gnu" ;;
+		*) target="" ;;
+		esac
+		# cargo-quickinstall only provides linux binaries
+		if [[ "$os" == "linux" ]] && [[ -n "$target" ]]; then
+			tgz_url="https://github.com/cargo-bins/cargo-quickinstall/releases/download/cargo-outdated-${CARGO_OUTDATED_VERSION}/cargo-outdated-${CARGO_OUTDATED_VERSION}-${target}.tar.gz"
+			echo -e "${YELLOW}Trying pre-built binary from cargo-quickinstall...${NC}"
+			if download_with_retries "$tgz_url" "$tmpdir/cargo-outdated.tar.gz" 3; then
+				tar -xzf "$tmpdir/cargo-outdated.tar.gz" -C "$tmpdir"
+				if [ -f "$tmpdir/cargo-outdated" ]; then
+					cp "$tmpdir/cargo-outdated" "$BIN_DIR/cargo-outdated"
+					chmod +x "$BIN_DIR/cargo-outdated"
+					echo -e "${GREEN}✓ cargo-outdated installed from pre-built binary${NC}"
+					cargo_outdated_installed=true
+				fi
+			fi
+		fi
+		rm -rf "$tmpdir"
+
+		# Fallback to cargo install if pre-built binary not available
+		if [ "$cargo_outdated_installed" = false ] && command -v cargo &>/dev/null; then
+			echo -e "${YELLOW}Pre-built binary not available, falling back to cargo install...${NC}"
+			if cargo install cargo-outdated --locked --version "$CARGO_OUTDATED_VERSION"; then
+				echo -e "${GREEN}✓ cargo-outdated installed via cargo${NC}"
+				cargo_outdated_installed=true
+			fi
+		fi
+
+		if [ "$cargo_outdated_installed" = false ]; then
+			echo -e "${YELLOW}⚠ Failed to install cargo-outdated (optional tool)${NC}"
+		fi
+	fi
+
 	# Install ruff (Python linting and formatting)
 	echo -e "${BLUE}Installing ruff...${NC}"
 	RUFF_VERSION=$(get_tool_version "ruff") || exit 1
@@ -861,6 +1026,77 @@ main() {
 		exit 1
 	fi
 
+	# Install checkov (IaC misconfiguration scanner)
+	echo -e "${BLUE}Installing checkov...${NC}"
+	CHECKOV_VERSION=$(get_tool_version "checkov") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install checkov==${CHECKOV_VERSION}"
+	elif install_python_package "checkov" "$CHECKOV_VERSION"; then
+		echo -e "${GREEN}✓ checkov installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install checkov${NC}"
+		exit 1
+	fi
+
+	# Install ansible-lint (Ansible playbook and role linter)
+	echo -e "${BLUE}Installing ansible-lint...${NC}"
+	ANSIBLE_LINT_VERSION=$(get_tool_version "ansible_lint") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install ansible-lint==${ANSIBLE_LINT_VERSION}"
+	elif install_python_package "ansible-lint" "$ANSIBLE_LINT_VERSION"; then
+		echo -e "${GREEN}✓ ansible-lint installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install ansible-lint${NC}"
+		exit 1
+	fi
+
+	# Install rubocop (Ruby linter and formatter)
+	# Bundled projects run their own pinned rubocop, so this is optional
+	echo -e "${BLUE}Installing rubocop...${NC}"
+	RUBOCOP_VERSION=$(get_tool_version "rubocop") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install rubocop==${RUBOCOP_VERSION}"
+	elif command -v rubocop &>/dev/null; then
+		echo -e "${GREEN}✓ rubocop already installed${NC}"
+	elif ! command -v gem &>/dev/null; then
+		echo -e "${YELLOW}⚠ RubyGems not found, skipping rubocop (optional tool)${NC}"
+	else
+		gem_args=(install rubocop --version "$RUBOCOP_VERSION" --no-document --bindir "$BIN_DIR")
+		if [ "$INSTALL_MODE" != "--docker" ] && [ "$INSTALL_MODE" != "docker" ]; then
+			gem_args+=(--user-install)
+		fi
+		if gem "${gem_args[@]}"; then
+			echo -e "${GREEN}✓ rubocop installed successfully${NC}"
+		else
+			echo -e "${YELLOW}⚠ Failed to install rubocop (optional tool)${NC}"
+		fi
+	fi
+
+	# Install luacheck (Lua linter)
+	# luacheck is a Lua program, so it is installed with LuaRocks
+	echo -e "${BLUE}Installing luacheck...${NC}"
+	LUACHECK_VERSION=$(get_tool_version "luacheck") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install luacheck==${LUACHECK_VERSION}"
+	elif command -v luacheck &>/dev/null; then
+		echo -e "${GREEN}✓ luacheck already installed${NC}"
+	elif ! command -v luarocks &>/dev/null; then
+		echo -e "${YELLOW}⚠ LuaRocks not found, skipping luacheck (optional tool)${NC}"
+	else
+		luarocks_args=(install luacheck "$LUACHECK_VERSION")
+		if [ "$INSTALL_MODE" != "--docker" ] && [ "$INSTALL_MODE" != "docker" ]; then
+			luarocks_args=(--local "${luarocks_args[@]}")
+		fi
+		if luarocks "${luarocks_args[@]}"; then
+			if [ -x "$HOME/.luarocks/bin/luacheck" ] && ! command -v luacheck &>/dev/null; then
+				ln -sf "$HOME/.luarocks/bin/luacheck" "$BIN_DIR/luacheck"
+			fi
+			echo -e "${GREEN}✓ luacheck installed successfully${NC}"
+		else
+			echo -e "${YELLOW}⚠ Failed to install luacheck (optional tool)${NC}"
+		fi
+	fi
+
 	# Install shellcheck (shell script linter)
 	echo -e "${BLUE}Installing shellcheck...${NC}"
 	SHELLCHECK_VERSION=$(get_tool_version "shellcheck") || exit 1
@@ -957,6 +1193,45 @@ main() {
 		exit 1
 	fi
 
+	# Install biome via bun (JavaScript/TypeScript/JSON linting and formatting)
+	echo -e "${BLUE}Installing biome...${NC}"
+
+	BIOME_VERSION=$(get_tool_version "biome") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install @biomejs/biome@${BIOME_VERSION} globally via bun"
+	elif bun add -g "@biomejs/biome@${BIOME_VERSION}"; then
+		echo -e "${GREEN}✓ biome@${BIOME_VERSION} installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install biome${NC}"
+		exit 1
+	fi
+
+	# Install eslint via bun (JavaScript/TypeScript linting)
+	echo -e "${BLUE}Installing eslint...${NC}"
+
+	ESLINT_VERSION=$(get_tool_version "eslint") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install eslint@${ESLINT_VERSION} globally via bun"
+	elif bun add -g "eslint@${ESLINT_VERSION}"; then
+		echo -e "${GREEN}✓ eslint@${ESLINT_VERSION} installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install eslint${NC}"
+		exit 1
+	fi
+
+	# Install stylelint via bun (CSS/SCSS/Less linting)
+	echo -e "${BLUE}Installing stylelint...${NC}"
+
+	STYLELINT_VERSION=$(get_tool_version "stylelint") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install stylelint@${STYLELINT_VERSION} globally via bun"
+	elif bun add -g "stylelint@${STYLELINT_VERSION}"; then
+		echo -e "${GREEN}✓ stylelint@${STYLELINT_VERSION} installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install stylelint${NC}"
+		exit 1
+	fi
+
 	# Install yamllint (Python package)
 	echo -e "${BLUE}Installing yamllint...${NC}"
 	YAMLLINT_VERSION=$(get_tool_version "yamllint") || exit 1
@@ -994,6 +1269,30 @@ main() {
 		exit 1
 	fi
 
+	# Install clang-format (C/C++ formatter)
+	echo -e "${BLUE}Installing clang-format...${NC}"
+	CLANG_FORMAT_VERSION=$(get_tool_version "clang_format") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install clang-format==${CLANG_FORMAT_VERSION}"
+	elif install_python_package "clang-format" "$CLANG_FORMAT_VERSION"; then
+		echo -e "${GREEN}✓ clang-format installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install clang-format${NC}"
+		exit 1
+	fi
+
+	# Install clang-tidy (C/C++ linter)
+	echo -e "${BLUE}Installing clang-tidy...${NC}"
+	CLANG_TIDY_VERSION=$(get_tool_version "clang_tidy") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install clang-tidy==${CLANG_TIDY_VERSION}"
+	elif install_python_package "clang-tidy" "$CLANG_TIDY_VERSION"; then
+		echo -e "${GREEN}✓ clang-tidy installed successfully${NC}"
+	else
+		echo -e "${RED}✗ Failed to install clang-tidy${NC}"
+		exit 1
+	fi
+
 	# Install taplo (TOML linter and formatter)
 	echo -e "${BLUE}Installing taplo...${NC}"
 	TAPLO_VERSION=$(get_tool_version "taplo") || exit 1
@@ -1078,6 +1377,273 @@ main() {
 		fi
 	fi
 
+	# Install typos (source code spell checker)
+	# Prebuilt binaries: https://github.com/crate-ci/typos/releases
+	echo -e "${BLUE}Installing typos...${NC}"
+	TYPOS_VERSION=$(get_tool_version "typos") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install typos v${TYPOS_VERSION}"
+	elif command -v typos &>/dev/null; then
+		echo -e "${GREEN}✓ typos already installed${NC}"
+	else
+		typos_installed=false
+		tmpdir=$(mktemp -d)
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) arch="x86_64" ;;
+		aarch64 | arm64) arch="aarch64" ;;
+		esac
+		case "$(uname -s)" in
+		Linux) target="${arch}-unknown-linux-musl" ;;
+		Darwin) target="${arch}-apple-darwin" ;;
+		*) target="" ;;
+		esac
+		if [ -n "$target" ]; then
+			tar_url="https://github.com/crate-ci/typos/releases/download/v${TYPOS_VERSION}/typos-v${TYPOS_VERSION}-${target}.tar.gz"
+			if download_with_retries "$tar_url" "$tmpdir/typos.tar.gz" 3; then
+				tar -xzf "$tmpdir/typos.tar.gz" -C "$tmpdir"
+				if [ -f "$tmpdir/typos" ]; then
+					cp "$tmpdir/typos" "$BIN_DIR/typos"
+					chmod +x "$BIN_DIR/typos"
+					echo -e "${GREEN}✓ typos installed successfully${NC}"
+					typos_installed=true
+				fi
+			fi
+		fi
+		rm -rf "$tmpdir"
+
+		# Fallback to cargo install if the release binary is unavailable
+		if [ "$typos_installed" = false ] && command -v cargo &>/dev/null; then
+			echo -e "${BLUE}Installing typos via cargo...${NC}"
+			if cargo install typos-cli --version "${TYPOS_VERSION}" --locked; then
+				echo -e "${GREEN}✓ typos installed via cargo${NC}"
+				typos_installed=true
+			fi
+		fi
+
+		if [ "$typos_installed" = false ]; then
+			echo -e "${RED}✗ Failed to install typos${NC}"
+			exit 1
+		fi
+	fi
+
+	# Install vale (prose linter)
+	# Release archives: https://github.com/errata-ai/vale/releases
+	echo -e "${BLUE}Installing vale...${NC}"
+	VALE_VERSION=$(get_tool_version "vale") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install vale v${VALE_VERSION}"
+	elif command -v vale &>/dev/null; then
+		echo -e "${GREEN}✓ vale already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		case "$(uname -s)" in
+		Darwin) os="macOS" ;;
+		*) os="Linux" ;;
+		esac
+		case "$(uname -m)" in
+		aarch64 | arm64) arch="arm64" ;;
+		*) arch="64-bit" ;;
+		esac
+		tar_url="https://github.com/errata-ai/vale/releases/download/v${VALE_VERSION}/vale_${VALE_VERSION}_${os}_${arch}.tar.gz"
+		if download_with_retries "$tar_url" "$tmpdir/vale.tar.gz" 3 &&
+			tar -xzf "$tmpdir/vale.tar.gz" -C "$tmpdir" &&
+			[ -f "$tmpdir/vale" ]; then
+			cp "$tmpdir/vale" "$BIN_DIR/vale"
+			chmod +x "$BIN_DIR/vale"
+			echo -e "${GREEN}✓ vale installed successfully${NC}"
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install vale${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
+	# Install terraform (provides terraform fmt)
+	# Release archives: https://releases.hashicorp.com/terraform/
+	echo -e "${BLUE}Installing terraform...${NC}"
+	TERRAFORM_VERSION=$(get_tool_version "terraform_fmt") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install terraform v${TERRAFORM_VERSION}"
+	elif command -v terraform &>/dev/null; then
+		echo -e "${GREEN}✓ terraform already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		os=$(uname -s | tr '[:upper:]' '[:lower:]')
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) arch="amd64" ;;
+		aarch64 | arm64) arch="arm64" ;;
+		esac
+		zip_url="https://releases.hashicorp.com/terraform/${TERRAFORM_VERSION}/terraform_${TERRAFORM_VERSION}_${os}_${arch}.zip"
+		if download_with_retries "$zip_url" "$tmpdir/terraform.zip" 3 &&
+			python3 -m zipfile -e "$tmpdir/terraform.zip" "$tmpdir" &&
+			[ -f "$tmpdir/terraform" ]; then
+			cp "$tmpdir/terraform" "$BIN_DIR/terraform"
+			chmod +x "$BIN_DIR/terraform"
+			echo -e "${GREEN}✓ terraform installed successfully${NC}"
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install terraform${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
+	# Install tflint (Terraform linter)
+	# Release archives: https://github.com/terraform-linters/tflint/releases
+	echo -e "${BLUE}Installing tflint...${NC}"
+	TFLINT_VERSION=$(get_tool_version "tflint") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install tflint v${TFLINT_VERSION}"
+	elif command -v tflint &>/dev/null; then
+		echo -e "${GREEN}✓ tflint already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		os=$(uname -s | tr '[:upper:]' '[:lower:]')
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) arch="amd64" ;;
+		aarch64 | arm64) arch="arm64" ;;
+		esac
+		zip_url="https://github.com/terraform-linters/tflint/releases/download/v${TFLINT_VERSION}/tflint_${os}_${arch}.zip"
+		if download_with_retries "$zip_url" "$tmpdir/tflint.zip" 3 &&
+			python3 -m zipfile -e "$tmpdir/tflint.zip" "$tmpdir" &&
+			[ -f "$tmpdir/tflint" ]; then
+			cp "$tmpdir/tflint" "$BIN_DIR/tflint"
+			chmod +x "$BIN_DIR/tflint"
+			echo -e "${GREEN}✓ tflint installed successfully${NC}"
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install tflint${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
+	# Install golangci-lint (Go linter runner)
+	# Release archives: https://github.com/golangci/golangci-lint/releases
+	echo -e "${BLUE}Installing golangci-lint...${NC}"
+	GOLANGCI_LINT_VERSION=$(get_tool_version "golangci_lint") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install golangci-lint v${GOLANGCI_LINT_VERSION}"
+	elif command -v golangci-lint &>/dev/null; then
+		echo -e "${GREEN}✓ golangci-lint already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		os=$(uname -s | tr '[:upper:]' '[:lower:]')
+		arch=$(uname -m)
+		case "$arch" in
+		x86_64 | amd64) arch="amd64" ;;
+		aarch64 | arm64) arch="arm64" ;;
+		esac
+		name="golangci-lint-${GOLANGCI_LINT_VERSION}-${os}-${arch}"
+		tar_url="https://github.com/golangci/golangci-lint/releases/download/v${GOLANGCI_LINT_VERSION}/${name}.tar.gz"
+		if download_with_retries "$tar_url" "$tmpdir/golangci-lint.tar.gz" 3 &&
+			tar -xzf "$tmpdir/golangci-lint.tar.gz" -C "$tmpdir" &&
+			[ -f "$tmpdir/$name/golangci-lint" ]; then
+			cp "$tmpdir/$name/golangci-lint" "$BIN_DIR/golangci-lint"
+			chmod +x "$BIN_DIR/golangci-lint"
+			echo -e "${GREEN}✓ golangci-lint installed successfully${NC}"
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install golangci-lint${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
+	# gofmt ships with the Go toolchain, which golangci-lint also needs
+	if command -v gofmt &>/dev/null; then
+		echo -e "${GREEN}✓ gofmt available (Go toolchain)${NC}"
+	else
+		echo -e "${YELLOW}⚠ gofmt not found; install Go from https://go.dev/dl/${NC}"
+	fi
+
+	# Install buf (Protobuf linter and formatter)
+	# Release binaries: https://github.com/bufbuild/buf/releases
+	echo -e "${BLUE}Installing buf...${NC}"
+	BUF_VERSION=$(get_tool_version "buf") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install buf v${BUF_VERSION}"
+	elif command -v buf &>/dev/null; then
+		echo -e "${GREEN}✓ buf already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		bin_url="https://github.com/bufbuild/buf/releases/download/v${BUF_VERSION}/buf-$(uname -s)-$(uname -m)"
+		if download_with_retries "$bin_url" "$tmpdir/buf" 3; then
+			cp "$tmpdir/buf" "$BIN_DIR/buf"
+			chmod +x "$BIN_DIR/buf"
+			echo -e "${GREEN}✓ buf installed successfully${NC}"
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install buf${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
+	# Install ktlint (Kotlin linter and formatter)
+	# Release binaries: https://github.com/pinterest/ktlint/releases
+	# The binary is a self-executing jar, so it needs Java on PATH to run
+	echo -e "${BLUE}Installing ktlint...${NC}"
+	KTLINT_VERSION=$(get_tool_version "ktlint") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install ktlint ${KTLINT_VERSION}"
+	elif command -v ktlint &>/dev/null; then
+		echo -e "${GREEN}✓ ktlint already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		bin_url="https://github.com/pinterest/ktlint/releases/download/${KTLINT_VERSION}/ktlint"
+		if download_with_retries "$bin_url" "$tmpdir/ktlint" 3; then
+			cp "$tmpdir/ktlint" "$BIN_DIR/ktlint"
+			chmod +x "$BIN_DIR/ktlint"
+			echo -e "${GREEN}✓ ktlint installed successfully${NC}"
+			if ! command -v java &>/dev/null; then
+				echo -e "${YELLOW}⚠ Java not found; ktlint needs a Java runtime to run${NC}"
+			fi
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install ktlint${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
+	# Install stylua (Lua formatter)
+	# Release archives: https://github.com/JohnnyMorganz/StyLua/releases
+	echo -e "${BLUE}Installing stylua...${NC}"
+	STYLUA_VERSION=$(get_tool_version "stylua") || exit 1
+	if [ $DRY_RUN -eq 1 ]; then
+		log_info "[DRY-RUN] Would install stylua v${STYLUA_VERSION}"
+	elif command -v stylua &>/dev/null; then
+		echo -e "${GREEN}✓ stylua already installed${NC}"
+	else
+		tmpdir=$(mktemp -d)
+		case "$(uname -s)" in
+		Darwin) os="macos" ;;
+		*) os="linux" ;;
+		esac
+		case "$(uname -m)" in
+		aarch64 | arm64) arch="aarch64" ;;
+		*) arch="x86_64" ;;
+		esac
+		zip_url="https://github.com/JohnnyMorganz/StyLua/releases/download/v${STYLUA_VERSION}/stylua-${os}-${arch}.zip"
+		if download_with_retries "$zip_url" "$tmpdir/stylua.zip" 3 &&
+			unzip -q "$tmpdir/stylua.zip" -d "$tmpdir" &&
+			[ -f "$tmpdir/stylua" ]; then
+			cp "$tmpdir/stylua" "$BIN_DIR/stylua"
+			chmod +x "$BIN_DIR/stylua"
+			echo -e "${GREEN}✓ stylua installed successfully${NC}"
+		else
+			rm -rf "$tmpdir"
+			echo -e "${RED}✗ Failed to install stylua${NC}"
+			exit 1
+		fi
+		rm -rf "$tmpdir"
+	fi
+
 	# Install typescript via bun (TypeScript compiler)
 	echo -e "${BLUE}Installing typescript...${NC}"
 
@@ -1143,11 +1709,17 @@ main() {
 	echo "  - astro (Astro type checking)"
 	echo "  - svelte-check (Svelte type checking)"
 	echo "  - bandit (Python security checks)"
+	echo "  - biome (JavaScript/TypeScript/JSON linting and formatting)"
 	echo "  - black (Python formatting)"
 	echo "  - cargo-audit (Rust dependency vulnerability scanning)"
 	echo "  - cargo-deny (Rust dependency license/advisory checking)"
+	echo "  - cargo-geiger (Rust unsafe usage reports)"
+	echo "  - cargo-machete (Rust unused dependency detection)"
+	echo "  - cargo-outdated (Rust stale dependency reports)"
 	echo "  - clippy (Rust linting)"
 	echo "  - rustfmt (Rust formatting)"
+	echo "  - eslint (JavaScript/TypeScript linting)"
+	echo "  - stylelint (CSS/SCSS/Less linting)"
 	echo "  - pydoclint (Python docstring validation)"
 	echo "  - gitleaks (Secret detection)"
 	echo "  - hadolint (Docker linting)"
@@ -1158,10 +1730,24 @@ main() {
 	echo "  - prettier (JavaScript/JSON formatting)"
 	echo "  - ruff (Python linting and formatting)"
 	echo "  - semgrep (Security scanning)"
+	echo "  - checkov (IaC misconfiguration scanning)"
+	echo "  - ansible-lint (Ansible linting)"
+	echo "  - rubocop (Ruby linting and formatting)"
 	echo "  - shellcheck (Shell script linting)"
 	echo "  - shfmt (Shell script formatting)"
 	echo "  - sqlfluff (SQL linting and formatting)"
+	echo "  - clang-format (C/C++ formatting)"
+	echo "  - clang-tidy (C/C++ linting)"
 	echo "  - taplo (TOML linting and formatting)"
+	echo "  - terraform (Terraform formatting)"
+	echo "  - tflint (Terraform linting)"
+	echo "  - golangci-lint (Go linting)"
+	echo "  - buf (Protobuf linting and formatting)"
+	echo "  - ktlint (Kotlin linting and formatting)"
+	echo "  - stylua (Lua formatting)"
+	echo "  - luacheck (Lua linting)"
+	echo "  - typos (Spell checking)"
+	echo "  - vale (Prose linting)"
 	echo "  - tsc (TypeScript type checking)"
 	echo "  - vue-tsc (Vue TypeScript type checking)"
 	echo "  - yamllint (YAML linting)"
@@ -1170,7 +1756,7 @@ main() {
 	# Verify installations
 	echo -e "${YELLOW}Verifying installations...${NC}"
 
-	tools_to_verify=("actionlint" "astro" "bandit" "black" "cargo-audit" "cargo-deny" "clippy" "rustfmt" "gitleaks" "hadolint" "markdownlint-cli2" "mypy" "oxfmt" "oxlint" "prettier" "pydoclint" "ruff" "semgrep" "shellcheck" "shfmt" "sqlfluff" "svelte-check" "taplo" "tsc" "vue-tsc" "yamllint")
+	tools_to_verify=("actionlint" "ansible-lint" "astro" "bandit" "biome" "black" "buf" "cargo-audit" "cargo-deny" "cargo-geiger" "cargo-machete" "cargo-outdated" "checkov" "clang-format" "clang-tidy" "clippy" "rustfmt" "eslint" "gitleaks" "golangci-lint" "hadolint" "ktlint" "luacheck" "markdownlint-cli2" "mypy" "oxfmt" "oxlint" "prettier" "pydoclint" "rubocop" "ruff" "semgrep" "shellcheck" "shfmt" "sqlfluff" "stylelint" "stylua" "svelte-check" "taplo" "terraform" "tflint" "tsc" "typos" "vale" "vue-tsc" "yamllint")
 	for tool in "${tools_to_verify[@]}"; do
 		if [ "$tool" = "clippy" ]; then
 			# Clippy is invoked through cargo
@@ -2,6 +2,7 @@
 
 import subprocess
 import sys
+from pathlib import Path
 from unittest.mock import patch
 
 import pytest
@@ -9,6 +10,7 @@ from assertpy import assert_that
 from click.testing import CliRunner
 
 from lintro.cli import cli
+from lintro.config.config_loader import clear_config_cache
 
 
 def test_cli_help() -> None:
@@ -134,3 +136,26 @@ def test_pytest_excluded_from_command_help(command: str) -> None:
     assert_that(result.exit_code).is_equal_to(0)
     # The help should not mention pytest as an available tool
     assert_that(result.output).does_not_contain("pytest")
+
+
+def test_unknown_profile_is_a_usage_error(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """An undefined LINTRO_PROFILE fails any command with a click error.
+
+    Args:
+        tmp_path: Temporary directory holding the config file.
+        monkeypatch: Pytest monkeypatch to chdir and set the env var.
+    """
+    (tmp_path / ".lintro-config.yaml").write_text("profiles:\n  ci: {}\n")
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setenv("LINTRO_PROFILE", "nope")
+    clear_config_cache()
+
+    result = CliRunner(mix_stderr=False).invoke(cli, ["generate", "pre-commit-config"])
+
+    clear_config_cache()
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.stderr).contains("Unknown profile 'nope' (available: ci)")
+    assert_that(result.exception).is_instance_of(SystemExit)
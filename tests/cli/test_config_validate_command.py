@@ -0,0 +1,109 @@
+"""Tests for the lintro config validate and schema subcommands."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli import cli
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Run from an empty project directory.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The project directory.
+    """
+    monkeypatch.chdir(tmp_path)
+    return tmp_path
+
+
+def test_validate_reports_unknown_key(project: Path) -> None:
+    """A typo in the config file fails validation.
+
+    Args:
+        project: Project directory fixture.
+    """
+    (project / ".lintro-config.yaml").write_text("enforce:\n  line_lenght: 88\n")
+
+    result = CliRunner().invoke(cli, ["config", "validate"])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("did you mean 'line_length'?")
+
+
+def test_validate_warnings_only_fail_when_strict(project: Path) -> None:
+    """Conflicts are warnings unless --strict is given.
+
+    Args:
+        project: Project directory fixture.
+    """
+    (project / ".lintro-config.yaml").write_text(
+        "execution:\n  enabled_tools: [ruff]\ntools:\n  ruff: false\n",
+    )
+
+    relaxed = CliRunner().invoke(cli, ["config", "validate"])
+    strict = CliRunner().invoke(cli, ["config", "validate", "--strict"])
+
+    assert_that(relaxed.exit_code).is_equal_to(0)
+    assert_that(relaxed.output).contains("valid with 1 warning(s)")
+    assert_that(strict.exit_code).is_equal_to(1)
+
+
+def test_validate_json_shows_effective_config(project: Path) -> None:
+    """--show-effective includes the resolved config with the profile applied.
+
+    Args:
+        project: Project directory fixture.
+    """
+    (project / ".lintro-config.yaml").write_text(
+        "enforce:\n  line_length: 88\n"
+        "profiles:\n  wide:\n    enforce:\n      line_length: 120\n",
+    )
+
+    result = CliRunner().invoke(
+        cli,
+        ["config", "validate", "--json", "--show-effective", "--profile", "wide"],
+    )
+
+    data = json.loads(result.output)
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(data["valid"]).is_true()
+    assert_that(data["effective"]["enforce"]["line_length"]).is_equal_to(120)
+
+
+def test_validate_unknown_profile_is_error(project: Path) -> None:
+    """Selecting an undefined profile is reported as an error.
+
+    Args:
+        project: Project directory fixture.
+    """
+    (project / ".lintro-config.yaml").write_text("enforce:\n  line_length: 88\n")
+
+    result = CliRunner().invoke(
+        cli,
+        ["config", "validate", "--json", "--profile", "nope"],
+    )
+
+    data = json.loads(result.output)
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(data["errors"][0]["message"]).contains("Unknown profile 'nope'")
+
+
+def test_schema_prints_json_schema() -> None:
+    """The schema subcommand prints the published JSON Schema."""
+    result = CliRunner().invoke(cli, ["config", "schema"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(json.loads(result.output)["title"]).is_equal_to(
+        "Lintro configuration",
+    )
@@ -108,3 +108,32 @@ def test_init_via_cli() -> None:
         result = runner.invoke(cli, ["init"])
         assert_that(result.exit_code).is_equal_to(0)
         assert_that(Path(".lintro-config.yaml").exists()).is_true()
+
+
+def test_init_detect_writes_detected_tools() -> None:
+    """Test that init --detect enables tools found in the repository."""
+    runner = CliRunner()
+    with runner.isolated_filesystem():
+        Path("package.json").write_text("{}")
+
+        result = runner.invoke(init_command, ["--detect"])
+        assert_that(result.exit_code).is_equal_to(0)
+        assert_that(result.output).contains("Detected Tools")
+
+        content = Path(".lintro-config.yaml").read_text()
+        assert_that(content).contains("Generated by `lintro init --detect`")
+        assert_that(content).contains("    - package_json")
+
+
+def test_init_interactive_prompts_for_each_tool() -> None:
+    """Test that init --interactive only enables confirmed tools."""
+    runner = CliRunner()
+    with runner.isolated_filesystem():
+        Path("package.json").write_text("{}")
+
+        result = runner.invoke(init_command, ["--interactive"], input="n\nn\nn\n")
+        assert_that(result.exit_code).is_equal_to(0)
+        assert_that(result.output).contains("Enable package_json?")
+
+        content = Path(".lintro-config.yaml").read_text()
+        assert_that(content).contains("enabled_tools: []")
@@ -2,10 +2,12 @@
 
 from pathlib import Path
 
+import pytest
 from assertpy import assert_that
 
 from lintro.config.config_loader import (
     _convert_pyproject_to_config,
+    _parse_budgets_config,
     _parse_defaults,
     _parse_enforce_config,
     _parse_execution_config,
@@ -33,6 +35,16 @@ def test_execution_config_empty_data() -> None:
     assert_that(config.tool_order).is_equal_to("priority")
     assert_that(config.fail_fast).is_false()
     assert_that(config.parallel).is_true()
+    assert_that(config.runtimes).is_equal_to(".lintro/runtimes.json")
+    assert_that(config.slow_tool_seconds).is_equal_to(10)
+
+
+def test_runtime_scheduling_settings() -> None:
+    """Should parse the runtimes file and slow threshold; false disables it."""
+    config = _parse_execution_config({"runtimes": False, "slow_tool_seconds": 2.5})
+
+    assert_that(config.runtimes).is_none()
+    assert_that(config.slow_tool_seconds).is_equal_to(2.5)
 
 
 def test_string_enabled_tools() -> None:
@@ -147,6 +159,19 @@ def test_execution_settings() -> None:
     assert_that(result["execution"]["fail_fast"]).is_true()
 
 
+def test_budget_settings() -> None:
+    """Should extract rule budgets and reject negative ones."""
+    data = {"budgets": {"clippy::todo": 10, "ruff:T201": 0}}
+
+    result = _convert_pyproject_to_config(data)
+
+    assert_that(_parse_budgets_config(result["budgets"])).is_equal_to(
+        {"clippy::todo": 10, "ruff:T201": 0},
+    )
+    with pytest.raises(ValueError, match="ruff:T201"):
+        _parse_budgets_config({"ruff:T201": -1})
+
+
 def test_load_yaml_config_with_defaults(tmp_path: Path) -> None:
     """Should load .lintro-config.yaml file with defaults section.
 
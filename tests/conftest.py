@@ -100,3 +100,20 @@ def clear_logging_handlers() -> Iterator[None]:
 
     logging.getLogger().handlers.clear()
     yield
+
+
+@pytest.fixture(autouse=True)
+def ignore_uncommitted_changes(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Let format runs ignore uncommitted changes in the lintro checkout.
+
+    Many tests run ``lintro format`` in the repository itself, whose work
+    tree is usually dirty during development. Tests of the guard import
+    ``dirty_files`` directly or patch it back.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        "lintro.utils.execution.worktree_guard.dirty_files",
+        lambda paths: None,
+    )
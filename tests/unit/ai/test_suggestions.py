@@ -0,0 +1,194 @@
+"""Tests for LLM-assisted fix suggestions."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.ai.client import AIClientError, request_completion
+from lintro.ai.suggestions import (
+    extract_patch,
+    read_code_context,
+    suggest_fixes,
+)
+from lintro.config.ai_config import AIConfig
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+_PATCH = "--- a/app.py\n+++ b/app.py\n@@ -2 +2 @@\n-x = eval(s)\n+x = int(s)\n"
+
+
+@dataclass
+class _Result:
+    """Minimal tool result carrying issues.
+
+    Attributes:
+        name: Tool name.
+        issues: Issues reported by the tool.
+    """
+
+    name: str
+    issues: list[BaseIssue] = field(default_factory=list)
+
+
+@pytest.fixture
+def enabled() -> AIConfig:
+    """Return an AI config pointing at a dummy endpoint.
+
+    Returns:
+        AIConfig: Enabled configuration.
+    """
+    return AIConfig(enabled=True, endpoint="http://llm.test/v1", context_lines=1)
+
+
+@pytest.mark.parametrize(
+    "reply",
+    [f"Here you go:\n```diff\n{_PATCH}```\nDone.", _PATCH, f"```\n{_PATCH}```"],
+)
+def test_extract_patch_accepts_fenced_and_bare_diffs(reply: str) -> None:
+    """Diffs are found with or without a code fence.
+
+    Args:
+        reply: Endpoint reply.
+    """
+    assert_that(extract_patch(reply)).is_equal_to(_PATCH)
+
+
+def test_extract_patch_rejects_non_diffs() -> None:
+    """Replies without a unified diff yield no patch."""
+    assert_that(extract_patch("NO_PATCH")).is_none()
+    assert_that(extract_patch("--- just a heading")).is_none()
+
+
+def test_read_code_context_numbers_lines(tmp_path: Path) -> None:
+    """Context is cut around the issue line and numbered.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    path = tmp_path / "app.py"
+    path.write_text("a\nb\nc\nd\n")
+
+    context = read_code_context(path, 2, 1)
+
+    assert_that(context.splitlines()).is_equal_to(
+        ["    1 | a", "    2 | b", "    3 | c"],
+    )
+    assert_that(read_code_context(tmp_path / "missing.py", 1, 1)).is_empty()
+
+
+def test_suggest_fixes_sends_only_unfixable_issues(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    enabled: AIConfig,
+) -> None:
+    """Auto-fixable issues are left to the tools; failures are recorded.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+        enabled: Enabled AI configuration.
+    """
+    (tmp_path / "app.py").write_text("import os\nx = eval(s)\n")
+    monkeypatch.chdir(tmp_path)
+    prompts: list[list[dict[str, str]]] = []
+
+    def fake_request(config: AIConfig, messages: list[dict[str, str]]) -> str:
+        """Answer the first request with a patch and fail the second.
+
+        Args:
+            config: AI configuration.
+            messages: Chat messages.
+
+        Returns:
+            str: Reply text.
+
+        Raises:
+            AIClientError: On the second request.
+        """
+        prompts.append(messages)
+        if len(prompts) > 1:
+            raise AIClientError("timed out")
+        return f"```diff\n{_PATCH}```"
+
+    results = [
+        _Result(
+            "ruff",
+            [
+                RuffIssue(file="app.py", line=1, code="F401", fixable=True),
+                RuffIssue(file="app.py", line=2, code="S307", message="eval"),
+                RuffIssue(file="app.py", line=2, code="PGH001"),
+            ],
+        ),
+    ]
+
+    suggestions = suggest_fixes(results, enabled, request=fake_request)
+
+    assert_that([s.code for s in suggestions]).is_equal_to(["S307", "PGH001"])
+    assert_that(suggestions[0].patch).is_equal_to(_PATCH)
+    assert_that(suggestions[1].error).is_equal_to("timed out")
+    assert_that(prompts[0][1]["content"]).contains("Rule: ruff S307")
+    assert_that(prompts[0][1]["content"]).contains("    2 | x = eval(s)")
+
+
+def test_suggestions_are_disabled_by_default() -> None:
+    """Nothing is sent unless suggestions are enabled with an endpoint."""
+    with pytest.raises(AIClientError, match="disabled"):
+        suggest_fixes([], AIConfig())
+    with pytest.raises(AIClientError, match="disabled"):
+        request_completion(AIConfig(enabled=True), [])
+
+
+def test_request_completion_posts_chat_request(
+    monkeypatch: pytest.MonkeyPatch,
+    enabled: AIConfig,
+) -> None:
+    """Requests go to /chat/completions with the configured API key.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        enabled: Enabled AI configuration.
+    """
+    import httpx
+
+    sent: dict[str, Any] = {}
+
+    class _Response:
+        """Successful chat completion response."""
+
+        def raise_for_status(self) -> None:
+            """Accept the response."""
+
+        def json(self) -> dict[str, Any]:
+            """Return the reply payload.
+
+            Returns:
+                dict[str, Any]: Chat completion body.
+            """
+            return {"choices": [{"message": {"content": "NO_PATCH"}}]}
+
+    def fake_post(url: str, **kwargs: Any) -> _Response:
+        """Record the request.
+
+        Args:
+            url: Request URL.
+            **kwargs: Request options.
+
+        Returns:
+            _Response: Canned response.
+        """
+        sent.update(kwargs, url=url)
+        return _Response()
+
+    monkeypatch.setattr(httpx, "post", fake_post)
+    monkeypatch.setenv("LINTRO_AI_API_KEY", "secret")
+
+    reply = request_completion(enabled, [{"role": "user", "content": "hi"}])
+
+    assert_that(reply).is_equal_to("NO_PATCH")
+    assert_that(sent["url"]).is_equal_to("http://llm.test/v1/chat/completions")
+    assert_that(sent["headers"]["Authorization"]).is_equal_to("Bearer secret")
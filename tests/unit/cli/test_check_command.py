@@ -10,6 +10,7 @@ from assertpy import assert_that
 from click.testing import CliRunner
 
 from lintro.cli_utils.commands.check import check, check_command
+from lintro.utils.output.sinks import OutputSink
 
 # =============================================================================
 # Check Command Basic Tests
@@ -318,7 +319,421 @@ def test_check_command_output_file(
 
     mock_run_lint_tools_check.assert_called_once()
     call_kwargs = mock_run_lint_tools_check.call_args.kwargs
-    assert_that(call_kwargs["output_file"]).is_equal_to(output_file)
+    assert_that(call_kwargs["outputs"]).is_equal_to([OutputSink(path=output_file)])
+
+
+def test_check_command_multiple_outputs(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify repeated --output values feed the console and several files.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    cli_runner.invoke(
+        check_command,
+        [
+            "--output",
+            "console=plain",
+            "--output",
+            "sarif=report.sarif",
+            "--output",
+            "json=report.json",
+        ],
+    )
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["output_format"]).is_equal_to("plain")
+    assert_that(call_kwargs["outputs"]).is_equal_to(
+        [
+            OutputSink(format="sarif", path="report.sarif"),
+            OutputSink(format="json", path="report.json"),
+        ],
+    )
+
+
+@pytest.mark.parametrize(
+    ("args", "message"),
+    [
+        (["--output", "console=sarif"], "Unsupported console format"),
+        (["--output", "sarif="], "names no file"),
+        (
+            ["--output", "console=json", "--output-format", "grid"],
+            "conflicts with --output-format grid",
+        ),
+    ],
+)
+def test_check_command_rejects_invalid_outputs(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+    args: list[str],
+    message: str,
+) -> None:
+    """Verify invalid or conflicting sinks are rejected before running.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+        args: Command-line arguments.
+        message: Expected part of the error message.
+    """
+    result = cli_runner.invoke(check_command, args)
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains(message)
+    mock_run_lint_tools_check.assert_not_called()
+
+
+def test_check_command_sign_report_requires_output(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+    tmp_path: Path,
+) -> None:
+    """Verify --sign-report is passed on and needs a report file to sign.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+        tmp_path: Temporary directory path for testing.
+    """
+    key = tmp_path / "key.pem"
+    key.write_text("key")
+    output_file = str(tmp_path / "results.json")
+
+    result = cli_runner.invoke(check_command, ["--sign-report", str(key)])
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("--sign-report requires --output")
+    mock_run_lint_tools_check.assert_not_called()
+
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(
+            check_command,
+            ["--output", output_file, "--sign-report", str(key)],
+        )
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["sign_key"]).is_equal_to(str(key))
+
+
+def test_check_command_assert_read_only(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --assert-read-only is passed to the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--assert-read-only"])
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["assert_read_only"]).is_true()
+
+
+def test_check_command_workload_limits(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --max-files and --confirm-over are passed to the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(
+            check_command,
+            ["--max-files", "50000", "--confirm-over", "5000"],
+        )
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["max_files"]).is_equal_to(50000)
+    assert_that(call_kwargs["confirm_over"]).is_equal_to(5000)
+
+
+def test_check_command_filter(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --filter is compiled and passed to the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--filter", "severity>=error"])
+
+    query = mock_run_lint_tools_check.call_args.kwargs["issue_filter"]
+    assert_that(query.text).is_equal_to("severity>=error")
+
+
+def test_check_command_rejects_invalid_filter(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify an invalid --filter is a usage error.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        result = cli_runner.invoke(check_command, ["--filter", "colour=red"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("Unknown field 'colour'")
+    mock_run_lint_tools_check.assert_not_called()
+
+
+def test_check_command_passes_rule_selection(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --select and --ignore patterns reach the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(
+            check_command,
+            ["--select", "ruff:E5*,clippy::*", "--ignore", "E501"],
+        )
+
+    selection = mock_run_lint_tools_check.call_args.kwargs["rule_selection"]
+    assert_that([p.text for p in selection.select]).is_equal_to(
+        ["ruff:E5*", "clippy::*"],
+    )
+    assert_that(selection.matches("ruff", "E501")).is_false()
+
+
+def test_check_command_rejects_empty_rule_pattern(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify a tool without a rule is a usage error.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        result = cli_runner.invoke(check_command, ["--select", "ruff:"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("names no rule")
+    mock_run_lint_tools_check.assert_not_called()
+
+
+def test_check_command_passes_quiet(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify -q reaches the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["-q"])
+
+    kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(kwargs["quiet"]).is_true()
+    assert_that(kwargs["summary_only"]).is_false()
+
+
+def test_check_command_rejects_quiet_with_summary_only(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --quiet and --summary-only are mutually exclusive.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        result = cli_runner.invoke(check_command, ["--quiet", "--summary-only"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("cannot be combined")
+    mock_run_lint_tools_check.assert_not_called()
+
+
+def test_check_command_passes_log_format(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --log-format reaches the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--log-format", "json"])
+
+    kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(kwargs["log_format"]).is_equal_to("json")
+
+
+def test_check_command_rejects_unknown_log_format(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --log-format only accepts text and json.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        result = cli_runner.invoke(check_command, ["--log-format", "xml"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    mock_run_lint_tools_check.assert_not_called()
+
+
+def test_check_command_commit_range_selects_conventional_commits(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --commit-range runs only conventional_commits by default.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--commit-range", "origin/main..HEAD"])
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["tools"]).is_equal_to("conventional_commits")
+    assert_that(call_kwargs["extra_tool_options"]).is_equal_to(
+        {"conventional_commits": {"commit_range": "origin/main..HEAD"}},
+    )
+
+
+def test_check_command_pr_title_keeps_selected_tools(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --pr-title is added to --tools and passed without splitting.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    title = "feat(cli): add --pr-title, --commit-range"
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--tools", "ruff", "--pr-title", title])
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["tools"]).is_equal_to("ruff,conventional_commits")
+    assert_that(call_kwargs["extra_tool_options"]).is_equal_to(
+        {"conventional_commits": {"pr_title": title}},
+    )
+
+
+def test_check_command_without_commit_flags(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify no commit options are passed without the commit flags.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, [])
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["tools"]).is_none()
+    assert_that(call_kwargs["extra_tool_options"]).is_none()
+
+
+def _vcs_with_staged(staged: list[str] | None) -> MagicMock:
+    """Build a version control provider listing the given staged files.
+
+    Args:
+        staged: Staged files, or None if they cannot be listed.
+
+    Returns:
+        MagicMock: Provider stand-in.
+    """
+    vcs = MagicMock()
+    vcs.name = "none"
+    vcs.staged_files.return_value = staged
+    return vcs
+
+
+def test_check_command_staged_checks_staged_files(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --staged checks the files staged for the next commit.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with patch(
+        "lintro.cli_utils.commands.check.detect_vcs",
+        return_value=_vcs_with_staged(["app.py", "docs/index.md"]),
+    ):
+        cli_runner.invoke(check_command, ["--staged"])
+
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["paths"]).is_equal_to(["app.py", "docs/index.md"])
+
+
+def test_check_command_staged_without_staged_files(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --staged succeeds without running tools when nothing is staged.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with patch(
+        "lintro.cli_utils.commands.check.detect_vcs",
+        return_value=_vcs_with_staged([]),
+    ):
+        result = cli_runner.invoke(check_command, ["--staged"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("No staged files")
+    mock_run_lint_tools_check.assert_not_called()
+
+
+def test_check_command_staged_without_version_control(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --staged warns and checks everything without version control.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with patch(
+        "lintro.cli_utils.commands.check.detect_vcs",
+        return_value=_vcs_with_staged(None),
+    ):
+        result = cli_runner.invoke(check_command, ["--staged"])
+
+    assert_that(result.stderr).contains("checking all files instead")
+    call_kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(call_kwargs["paths"]).is_equal_to(["."])
 
 
 # =============================================================================
@@ -381,3 +796,74 @@ def test_check_function_exits_on_failure() -> None:
             )
 
         assert_that(exc_info.value.code).is_equal_to(1)
+
+
+def test_check_command_passes_shard(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --shard reaches the runner parsed.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--shard", "2/4"])
+
+    kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(str(kwargs["shard"])).is_equal_to("2/4")
+
+
+def test_check_command_no_gitignore(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --no-gitignore turns off .gitignore filtering in the runner.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--no-gitignore"])
+
+    kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(kwargs["gitignore"]).is_false()
+
+
+def test_check_command_code_blocks(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+) -> None:
+    """Verify --code-blocks asks the runner to check Markdown code blocks.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+    """
+    with cli_runner.isolated_filesystem():
+        cli_runner.invoke(check_command, ["--code-blocks"])
+
+    kwargs = mock_run_lint_tools_check.call_args.kwargs
+    assert_that(kwargs["code_blocks"]).is_true()
+
+
+@pytest.mark.parametrize("value", ["2", "0/4", "5/4", "a/b"])
+def test_check_command_rejects_invalid_shard(
+    cli_runner: CliRunner,
+    mock_run_lint_tools_check: MagicMock,
+    value: str,
+) -> None:
+    """Verify --shard only accepts I/N with I between 1 and N.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+        mock_run_lint_tools_check: Mock for the run_lint_tools_check function.
+        value: Invalid shard.
+    """
+    with cli_runner.isolated_filesystem():
+        result = cli_runner.invoke(check_command, ["--shard", value])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    mock_run_lint_tools_check.assert_not_called()
@@ -2,6 +2,8 @@
 
 from __future__ import annotations
 
+import subprocess
+import sys
 from unittest.mock import patch
 
 import pytest
@@ -117,6 +119,33 @@ def test_lintro_group_format_commands_empty() -> None:
 # =============================================================================
 
 
+def test_cli_import_defers_command_modules() -> None:
+    """Verify importing the CLI does not import the command modules."""
+    code = (
+        "import sys, lintro.cli; "
+        "print(sorted(m for m in sys.modules if m.startswith('lintro.cli_utils."
+        "commands.') or m.startswith('lintro.tools.definitions.')))"
+    )
+    result = subprocess.run(
+        [sys.executable, "-c", code],
+        capture_output=True,
+        text=True,
+        timeout=30,
+    )
+
+    assert_that(result.returncode).is_equal_to(0)
+    assert_that(result.stdout.strip()).is_equal_to("[]")
+
+
+def test_lintro_group_lists_lazy_commands_and_aliases() -> None:
+    """Verify commands and aliases are listed before they are imported."""
+    import click
+
+    names = cli.list_commands(click.Context(cli))
+
+    assert_that(names).contains("check", "chk", "fix-file", "install-hooks")
+
+
 def test_cli_has_check_command(cli_runner: CliRunner) -> None:
     """Verify check command is registered.
 
@@ -129,6 +158,18 @@ def test_cli_has_check_command(cli_runner: CliRunner) -> None:
     assert_that(result.output).contains("Check files")
 
 
+def test_cli_has_fix_file_command(cli_runner: CliRunner) -> None:
+    """Verify fix-file command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["fix-file", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("--timeout")
+
+
 def test_cli_has_format_command(cli_runner: CliRunner) -> None:
     """Verify format command is registered.
 
@@ -163,6 +204,39 @@ def test_cli_has_config_command(cli_runner: CliRunner) -> None:
     assert_that(result.exit_code).is_equal_to(0)
 
 
+def test_cli_has_audit_command(cli_runner: CliRunner) -> None:
+    """Verify audit command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["audit", "log", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_cli_has_report_command(cli_runner: CliRunner) -> None:
+    """Verify report command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["report", "push", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_cli_has_explain_command(cli_runner: CliRunner) -> None:
+    """Verify explain command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["explain", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
 def test_cli_has_versions_command(cli_runner: CliRunner) -> None:
     """Verify versions command is registered.
 
@@ -174,6 +248,17 @@ def test_cli_has_versions_command(cli_runner: CliRunner) -> None:
     assert_that(result.exit_code).is_equal_to(0)
 
 
+def test_cli_has_outdated_command(cli_runner: CliRunner) -> None:
+    """Verify outdated command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["outdated", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
 def test_cli_has_list_tools_command(cli_runner: CliRunner) -> None:
     """Verify list-tools command is registered.
 
@@ -185,6 +270,17 @@ def test_cli_has_list_tools_command(cli_runner: CliRunner) -> None:
     assert_that(result.exit_code).is_equal_to(0)
 
 
+def test_cli_has_gate_command(cli_runner: CliRunner) -> None:
+    """Verify gate command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["gate", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
 def test_cli_has_init_command(cli_runner: CliRunner) -> None:
     """Verify init command is registered.
 
@@ -196,6 +292,61 @@ def test_cli_has_init_command(cli_runner: CliRunner) -> None:
     assert_that(result.exit_code).is_equal_to(0)
 
 
+def test_cli_has_org_command(cli_runner: CliRunner) -> None:
+    """Verify org command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["org", "run", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_cli_has_plan_command(cli_runner: CliRunner) -> None:
+    """Verify plan command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["plan", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_cli_has_query_command(cli_runner: CliRunner) -> None:
+    """Verify query command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["query", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_cli_has_stats_command(cli_runner: CliRunner) -> None:
+    """Verify stats command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["stats", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_cli_has_suggest_command(cli_runner: CliRunner) -> None:
+    """Verify suggest command is registered.
+
+    Args:
+        cli_runner: The Click CLI test runner.
+    """
+    result = cli_runner.invoke(cli, ["suggest", "--help"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+
+
 # =============================================================================
 # Command Alias Tests
 # =============================================================================
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import json
 from typing import Any
 
 import pytest
@@ -33,6 +34,46 @@ def test_check_invokes_executor(monkeypatch: pytest.MonkeyPatch) -> None:
     assert_that(calls.get("action")).is_equal_to("check")
 
 
+def test_check_passes_extra_args(monkeypatch: pytest.MonkeyPatch) -> None:
+    """--extra-args reach the executor as per-tool extra_args options.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture to stub executor call.
+    """
+    calls: dict[str, Any] = {}
+    import lintro.cli_utils.commands.check as check_mod
+
+    def fake_run(**kwargs: Any) -> int:
+        """Record the executor arguments.
+
+        Args:
+            **kwargs: Arguments passed to run_lint_tools_simple.
+
+        Returns:
+            int: Successful exit code.
+        """
+        calls.update(kwargs)
+        return 0
+
+    monkeypatch.setattr(check_mod, "run_lint_tools_simple", fake_run)
+    runner = CliRunner()
+    result = runner.invoke(
+        check_command,
+        ["--extra-args", "clippy:--locked --offline", "--extra-args", "ruff:--preview"],
+    )
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(calls["extra_tool_options"]).is_equal_to(
+        {
+            "clippy": {"extra_args": ["--locked", "--offline"]},
+            "ruff": {"extra_args": ["--preview"]},
+        },
+    )
+
+    result = runner.invoke(check_command, ["--extra-args", "--locked"])
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("TOOL:ARGS")
+
+
 def test_format_invokes_executor(monkeypatch: pytest.MonkeyPatch) -> None:
     """Invoke format subcommand and verify executor receives parameters.
 
@@ -65,3 +106,43 @@ def test_list_tools_outputs(monkeypatch: pytest.MonkeyPatch) -> None:
     assert_that(result.output).contains("Available Tools")
     # Rich table format uses "Total tools" without trailing colon
     assert_that(result.output).contains("Total tools")
+
+
+def test_list_tools_json_reports_features() -> None:
+    """JSON output includes each tool's capability flags."""
+    runner = CliRunner()
+    result = runner.invoke(list_tools_command, ["--json"])
+    assert_that(result.exit_code).is_equal_to(0)
+    tools = json.loads(result.output)
+    assert_that(tools["ruff"]["features"]).is_equal_to(
+        {
+            "supports_fix": True,
+            "version_detected": None,
+        },
+    )
+    assert_that(tools["mypy"]["features"]).contains_entry({"supports_fix": False})
+
+
+def test_list_tools_json_describes_tool_matrix(monkeypatch: pytest.MonkeyPatch) -> None:
+    """JSON output lists file types, options and the binary each tool needs.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture to stub the PATH lookup.
+    """
+    import shutil
+
+    monkeypatch.setattr(shutil, "which", lambda name: f"/bin/{name}")
+    runner = CliRunner()
+    result = runner.invoke(list_tools_command, ["--json"])
+    assert_that(result.exit_code).is_equal_to(0)
+    tools = json.loads(result.output)
+    black = tools["black"]
+    assert_that(black["file_patterns"]).contains("*.py")
+    assert_that(black["options"]).contains("line_length", "preview")
+    assert_that(black["options"]).does_not_contain("self", "kwargs")
+    assert_that(black["native_configs"]).contains("pyproject.toml")
+    assert_that(black["binary"]).contains_entry(
+        {"name": "black"},
+        {"path": "/bin/black"},
+    )
+    assert_that(tools["linkcheck"]["binary"]).is_none()
@@ -1,6 +1,6 @@
 """Tests for LintroGroup and CLI module functionality."""
 
-from unittest.mock import patch
+from unittest.mock import ANY, patch
 
 from assertpy import assert_that
 from click.testing import CliRunner
@@ -94,35 +94,75 @@ def test_invoke_with_comma_separated_commands() -> None:
             tool_options=None,
             exclude=None,
             include_venv=False,
-            group_by="file",
-            output_format="grid",
+            gitignore=True,
+            code_blocks=False,
+            group_by=None,
+            output_format=None,
             verbose=False,
             raw_output=False,
-            output_file=None,
+            outputs=[],
             incremental=False,
             debug=False,
+            log_format="text",
             stream=False,
             no_log=False,
             auto_install=False,
+            install_missing=False,
             yes=False,
+            profile=None,
+            fail_on=None,
+            max_issues=None,
+            fail_fast=False,
+            summary="table",
+            summary_only=False,
+            quiet=False,
+            profile_timing=False,
+            sample_per_rule=None,
+            dedupe=True,
+            toolchain_matrix=False,
+            sign_key=None,
+            assert_read_only=False,
+            max_files=None,
+            confirm_over=None,
+            issue_filter=None,
+            rule_selection=None,
+            extra_tool_options=None,
+            columns=None,
+            sort_by=None,
+            shard=None,
+            stdin=None,
         )
         mock_fmt.assert_any_call(
             action="fmt",
             paths=["."],
             tools=None,
             tool_options=None,
+            extra_tool_options=None,
             exclude=None,
             include_venv=False,
-            group_by="auto",
-            output_format="grid",
+            gitignore=True,
+            group_by=None,
+            columns=None,
+            sort_by=None,
+            output_format=None,
             verbose=False,
             raw_output=False,
+            summary_only=False,
+            quiet=False,
             output_file=None,
             debug=False,
+            log_format="text",
             stream=False,
             no_log=False,
             auto_install=False,
+            install_missing=False,
             yes=False,
+            profile=None,
+            audit=True,
+            fix_only=None,
+            max_files=None,
+            confirm_over=None,
+            on_outcome=ANY,
         )
 
 
@@ -0,0 +1,79 @@
+"""Tests for lintro.cli_utils.commands.audit module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.audit import audit_command
+from lintro.utils.execution.audit_log import append_entry, build_entry
+from lintro.utils.execution.fix_pipeline import FileFix
+
+
+@pytest.fixture
+def audit_log(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> str:
+    """Write an audit log with one ruff run and one run without changes.
+
+    Args:
+        tmp_path: Temporary project directory.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        str: Path of the audit log.
+    """
+    monkeypatch.chdir(tmp_path)
+    log = str(tmp_path / "audit.jsonl")
+    fix = FileFix(str(tmp_path / "a.py"), "ruff", "1" * 64, "2" * 64)
+    append_entry(log, build_entry(tools=["ruff"], fixes=[fix], exit_code=0))
+    append_entry(log, build_entry(tools=["black"], fixes=[], exit_code=0))
+    return log
+
+
+def test_audit_log_lists_runs(audit_log: str) -> None:
+    """Runs are shown with their changed files and can be filtered.
+
+    Args:
+        audit_log: Path of the audit log.
+    """
+    runner = CliRunner()
+
+    result = runner.invoke(audit_command, ["log", "--log", audit_log])
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Fix Runs", "a.py (ruff, 11111111")
+
+    result = runner.invoke(
+        audit_command,
+        ["log", "--log", audit_log, "--tool", "black", "--json"],
+    )
+    assert_that(json.loads(result.output)).is_empty()
+
+    result = runner.invoke(
+        audit_command,
+        ["log", "--log", audit_log, "--limit", "1", "--json"],
+    )
+    assert_that([e["tools"] for e in json.loads(result.output)]).is_equal_to(
+        [["black"]],
+    )
+
+
+def test_audit_log_verify(audit_log: str) -> None:
+    """--verify fails once an entry is removed.
+
+    Args:
+        audit_log: Path of the audit log.
+    """
+    runner = CliRunner()
+
+    result = runner.invoke(audit_command, ["log", "--log", audit_log, "--verify"])
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("is intact")
+
+    lines = Path(audit_log).read_text().splitlines()
+    Path(audit_log).write_text(lines[1] + "\n")
+    result = runner.invoke(audit_command, ["log", "--log", audit_log, "--verify"])
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("broken at line(s) 1")
@@ -0,0 +1,86 @@
+"""Tests for lintro.cli_utils.commands.badge module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.badge import badge_command
+
+
+@pytest.fixture
+def report(tmp_path: Path) -> Path:
+    """Write a JSON report where ruff found one issue.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: The report file.
+    """
+    path = tmp_path / "lint.json"
+    data = {
+        "results": [
+            {"tool": "ruff", "success": False, "issues_count": 1},
+            {"tool": "yamllint", "success": True, "issues_count": 0},
+        ],
+    }
+    path.write_text(json.dumps(data))
+    return path
+
+
+def test_badge_writes_issue_count(report: Path, tmp_path: Path) -> None:
+    """The default badge shows the report's issue count.
+
+    Args:
+        report: JSON report file.
+        tmp_path: Temporary directory path for test files.
+    """
+    output = tmp_path / "badge.svg"
+
+    result = CliRunner().invoke(
+        badge_command,
+        ["--input", str(report), "--output", str(output)],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(output.read_text()).contains("lintro: 1 issue")
+
+
+def test_badge_per_tool_status(report: Path, tmp_path: Path) -> None:
+    """--per-tool --status writes a passing or failing badge per tool.
+
+    Args:
+        report: JSON report file.
+        tmp_path: Temporary directory path for test files.
+    """
+    output = tmp_path / "badges"
+
+    result = CliRunner().invoke(
+        badge_command,
+        ["--input", str(report), "--per-tool", "--status", "-o", str(output)],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that((output / "ruff.svg").read_text()).contains("ruff: failing")
+    assert_that((output / "yamllint.svg").read_text()).contains("yamllint: passing")
+
+
+def test_badge_unknown_tool(report: Path, tmp_path: Path) -> None:
+    """A tool missing from the report fails the command.
+
+    Args:
+        report: JSON report file.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(
+        badge_command,
+        ["--input", str(report), "--tool", "mypy", "-o", str(tmp_path / "b.svg")],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("'mypy' did not run")
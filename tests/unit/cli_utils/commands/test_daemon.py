@@ -0,0 +1,68 @@
+"""Tests for lintro.cli_utils.commands.daemon module."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.daemon as daemon_cmd
+from lintro.cli_utils.commands.daemon import daemon_command
+from lintro.plugins.daemon_backend import DaemonStatus
+
+
+def test_daemon_status_lists_daemons(monkeypatch: pytest.MonkeyPatch) -> None:
+    """The default action shows each tool's client and its state.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        daemon_cmd,
+        "daemon_statuses",
+        lambda tools: [DaemonStatus("eslint", "eslint_d", True, running=True)],
+    )
+
+    result = CliRunner().invoke(daemon_command, [])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("eslint_d").contains("running")
+
+
+def test_daemon_stop_reports_stopped_clients(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """``stop`` names each client it stopped.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    requested: list[list[str] | None] = []
+
+    def fake_stop(tools: list[str] | None) -> list[str]:
+        """Record the requested tools and stop eslint.
+
+        Args:
+            tools: Tools passed to stop_daemons.
+
+        Returns:
+            list[str]: The stopped tools.
+        """
+        requested.append(tools)
+        return ["eslint"]
+
+    monkeypatch.setattr(daemon_cmd, "stop_daemons", fake_stop)
+
+    result = CliRunner().invoke(daemon_command, ["stop", "--tools", "eslint"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Stopped the eslint_d daemon")
+    assert_that(requested).is_equal_to([["eslint"]])
+
+
+def test_daemon_rejects_tools_without_daemon() -> None:
+    """Tools without a daemon client are a usage error."""
+    result = CliRunner().invoke(daemon_command, ["stop", "--tools", "ruff"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("no daemon for ruff")
@@ -0,0 +1,161 @@
+"""Tests for lintro.cli_utils.commands.diff module."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.diff as diff_cmd
+import lintro.utils.execution.ref_workspace as ref_workspace
+from lintro.cli_utils.commands.diff import diff_command
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.vcs import MercurialProvider
+
+
+def _write_report(path: Path, *fingerprints: str) -> Path:
+    """Write a JSON report with one ruff issue per fingerprint.
+
+    Args:
+        path: Path of the report.
+        *fingerprints: Fingerprints of the issues.
+
+    Returns:
+        Path: The report.
+    """
+    issues = [
+        {"file": "a.py", "line": 1, "code": "F401", "message": "m", "fingerprint": f}
+        for f in fingerprints
+    ]
+    path.write_text(json.dumps({"results": [{"tool": "ruff", "issues": issues}]}))
+    return path
+
+
+def test_diff_reports_fails_on_new_issues(tmp_path: Path) -> None:
+    """New issues are listed and fail the command with --fail-on-new.
+
+    Args:
+        tmp_path: Temporary directory for the reports.
+    """
+    old = _write_report(tmp_path / "old.json", "a", "b")
+    new = _write_report(tmp_path / "new.json", "b", "c")
+
+    result = CliRunner().invoke(
+        diff_command,
+        [str(old), str(new), "--fail-on-new", "--json"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(json.loads(result.output)["summary"]).is_equal_to(
+        {"new": 1, "fixed": 1, "unchanged": 1},
+    )
+
+
+def test_diff_without_new_issues_passes(tmp_path: Path) -> None:
+    """Fixed issues alone do not fail the command.
+
+    Args:
+        tmp_path: Temporary directory for the reports.
+    """
+    old = _write_report(tmp_path / "old.json", "a", "b")
+    new = _write_report(tmp_path / "new.json", "b")
+
+    result = CliRunner().invoke(diff_command, [str(old), str(new), "--fail-on-new"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Fixed issues:")
+
+
+def test_diff_needs_two_reports_or_a_ref(tmp_path: Path) -> None:
+    """A single report without --against is a usage error.
+
+    Args:
+        tmp_path: Temporary directory for the reports.
+    """
+    old = _write_report(tmp_path / "old.json", "a")
+
+    result = CliRunner().invoke(diff_command, [str(old)])
+
+    assert_that(result.exit_code).is_equal_to(2)
+
+
+def test_diff_against_ref_runs_checks_at_the_ref(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--against runs the checks in a checkout of the ref for the old side.
+
+    Args:
+        tmp_path: Temporary directory for the repository.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+
+    def git(*args: str) -> None:
+        """Run git in the test repository.
+
+        Args:
+            *args: Git arguments.
+        """
+        subprocess.run(["git", *args], cwd=tmp_path, check=True, capture_output=True)
+
+    git("init", "-q")
+    (tmp_path / "a.py").write_text("import os\n")
+    git("add", "a.py")
+    git("-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", "base")
+    (tmp_path / "a.py").write_text("import sys\nimport os\n")
+    monkeypatch.chdir(tmp_path)
+
+    def fake_checks(*args: Any) -> list[ToolResult]:
+        """Flag every import of a.py as it is in the current directory.
+
+        Args:
+            *args: Arguments of run_checks.
+
+        Returns:
+            list[ToolResult]: A ruff result.
+        """
+        lines = Path("a.py").read_text().splitlines()
+        issues = [
+            RuffIssue(file="a.py", line=n, code="F401", message="unused")
+            for n in range(1, len(lines) + 1)
+        ]
+        return [
+            ToolResult(name="ruff", success=False, issues_count=1, issues=issues),
+        ]
+
+    monkeypatch.setattr(diff_cmd, "run_checks", fake_checks)
+
+    result = CliRunner().invoke(diff_command, ["--against", "HEAD", "--json"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    data = json.loads(result.output)
+    assert_that(data["summary"]).is_equal_to({"new": 1, "fixed": 0, "unchanged": 1})
+    assert_that(data["new"][0]["line"]).is_equal_to(1)
+
+
+def test_diff_against_outside_git_fails_with_a_hint(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--against in a Mercurial repository names the VCS and the alternative.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        ref_workspace,
+        "detect_vcs",
+        lambda start: MercurialProvider(root=tmp_path),
+    )
+
+    result = CliRunner().invoke(diff_command, ["--against", "main"])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("this hg repository", "lintro diff OLD NEW")
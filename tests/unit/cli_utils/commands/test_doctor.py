@@ -0,0 +1,183 @@
+"""Tests for lintro.cli_utils.commands.doctor module."""
+
+from __future__ import annotations
+
+import json
+import os
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.doctor as doctor_cmd
+from lintro.cli_utils.commands.doctor import (
+    VersionCheckResult,
+    _find_path_problems,
+    doctor_command,
+)
+from lintro.utils.vcs import UnavailableVcs
+
+
+@pytest.fixture
+def pinned_ruff(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Pin ruff and report an installed version that does not match.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(doctor_cmd, "get_version_pins", lambda: {"ruff": "0.6.0"})
+    monkeypatch.setattr(
+        doctor_cmd,
+        "_get_installed_version",
+        lambda tool: VersionCheckResult(version="0.7.1", path="/usr/bin/ruff"),
+    )
+    monkeypatch.setattr(doctor_cmd, "_find_path_problems", lambda executable: [])
+
+
+def test_doctor_reports_pin_mismatch(pinned_ruff: None) -> None:
+    """A pinned bundled tool is checked and a mismatch fails the command.
+
+    Args:
+        pinned_ruff: Stubbed pin and version lookup.
+    """
+    result = CliRunner().invoke(doctor_command, ["--json", "--tools", "ruff"])
+
+    data = json.loads(result.output[result.output.index("{") :])
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(data["tools"]["ruff"]["status"]).is_equal_to("mismatch")
+    assert_that(data["tools"]["ruff"]["remediation"]).contains("tools.ruff.version")
+    assert_that(data["summary"]["mismatch"]).is_equal_to(1)
+    assert_that(data["issues"][0]["message"]).is_equal_to(
+        "version 0.7.1 does not match pinned 0.6.0",
+    )
+
+
+def test_doctor_table_shows_pin_and_fix(pinned_ruff: None) -> None:
+    """The table marks pinned versions and lists how to fix problems.
+
+    Args:
+        pinned_ruff: Stubbed pin and version lookup.
+    """
+    result = CliRunner().invoke(doctor_command, ["--tools", "ruff"])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("0.6.0 (pinned)", "How to fix:")
+
+
+def _make_executable(directory: Path, name: str) -> Path:
+    """Create an executable stub.
+
+    Args:
+        directory: Directory to create the stub in.
+        name: Executable name.
+
+    Returns:
+        Path: The created file.
+    """
+    directory.mkdir(parents=True, exist_ok=True)
+    path = directory / name
+    path.write_text("#!/bin/sh\n")
+    path.chmod(0o755)
+    return path
+
+
+def test_find_path_problems_reports_shadowed_copies(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Several copies on PATH are reported with the one that wins.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    first = _make_executable(tmp_path / "a", "taplo")
+    _make_executable(tmp_path / "b", "taplo")
+    monkeypatch.setenv(
+        "PATH",
+        os.pathsep.join([str(tmp_path / "a"), str(tmp_path / "b")]),
+    )
+
+    problems = _find_path_problems("taplo")
+
+    assert_that(problems).is_length(1)
+    assert_that(problems[0]).starts_with(f"2 copies on PATH, {first} is used")
+
+
+def test_find_path_problems_reports_install_off_path(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """An install in a common directory missing from PATH is reported.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    off_path = _make_executable(tmp_path / "cargo-bin", "taplo")
+    monkeypatch.setattr(doctor_cmd, "COMMON_BIN_DIRS", (str(tmp_path / "cargo-bin"),))
+    monkeypatch.setenv("PATH", str(tmp_path / "empty"))
+
+    assert_that(_find_path_problems("taplo")).is_equal_to(
+        [f"installed at {off_path}, which is not on PATH"],
+    )
+
+
+def test_doctor_reports_missing_version_control(
+    pinned_ruff: None,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without a repository the table run explains the fallback.
+
+    Args:
+        pinned_ruff: Stubbed pin and version lookup.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        doctor_cmd,
+        "detect_vcs",
+        lambda: UnavailableVcs(reason="no .git in /src or its parents"),
+    )
+
+    result = CliRunner().invoke(doctor_command, ["--tools", "ruff"])
+
+    assert_that(result.output).contains(
+        "Version control unavailable: no .git in /src",
+        "omit the commit",
+    )
+
+
+def test_doctor_warns_about_unmatched_node_pin(
+    pinned_ruff: None,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A pinned toolchain the active one does not match is a warning.
+
+    Args:
+        pinned_ruff: Stubbed pin and version lookup.
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / ".nvmrc").write_text("20\n")
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr(doctor_cmd, "node_toolchain_bin", lambda cwd: None)
+    monkeypatch.setattr(
+        doctor_cmd,
+        "_toolchain_version",
+        lambda command, cwd: "18.20.0",
+    )
+
+    result = CliRunner().invoke(doctor_command, ["--json", "--tools", "ruff"])
+
+    data = json.loads(result.output[result.output.index("{") :])
+    assert_that(data["toolchains"]).is_length(1)
+    mismatch = data["toolchains"][0]
+    assert_that(mismatch).contains_entry(
+        {"toolchain": "node"},
+        {"pinned": "20"},
+        {"active": "18.20.0"},
+    )
+    assert_that(mismatch["message"]).contains("install 20 with nvm or volta")
+    assert_that(data["issues"][-1]["severity"]).is_equal_to("warning")
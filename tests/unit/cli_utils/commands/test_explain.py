@@ -0,0 +1,68 @@
+"""Tests for lintro.cli_utils.commands.explain module."""
+
+from __future__ import annotations
+
+import json
+
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.explain import explain_command
+from lintro.exceptions.errors import ERROR_CODES
+
+
+def test_explain_describes_a_code() -> None:
+    """The code's title and explanation are printed."""
+    result = CliRunner().invoke(explain_command, ["lintro012"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("LINTRO012")
+    assert_that(result.output).contains("Report could not be written")
+
+
+def test_explain_json_outputs_one_object() -> None:
+    """--json prints the entry as a JSON object."""
+    result = CliRunner().invoke(explain_command, ["LINTRO007", "--json"])
+
+    data = json.loads(result.output)
+    assert_that(data["code"]).is_equal_to("LINTRO007")
+    assert_that(data["title"]).is_equal_to("Tool timed out")
+
+
+def test_explain_without_code_lists_catalog() -> None:
+    """Without a code every entry is listed."""
+    result = CliRunner().invoke(explain_command, ["--json"])
+
+    codes = [entry["code"] for entry in json.loads(result.output)]
+    assert_that(codes).is_equal_to(list(ERROR_CODES))
+
+
+def test_explain_rejects_unknown_code() -> None:
+    """An unknown code is a usage error."""
+    result = CliRunner().invoke(explain_command, ["LINTRO999"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("unknown error code")
+
+
+def test_explain_describes_a_tool_rule() -> None:
+    """Rules that are not error codes are looked up in the rule metadata."""
+    result = CliRunner().invoke(explain_command, ["ruff:F401", "--offline"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("ruff:F401", "Module imported but unused")
+    assert_that(result.output).contains(
+        "https://docs.astral.sh/ruff/rules/unused-import/",
+    )
+
+
+def test_explain_tool_rule_json() -> None:
+    """--json prints the rule description."""
+    result = CliRunner().invoke(
+        explain_command,
+        ["MD013", "--tool", "markdownlint", "--offline", "--json"],
+    )
+
+    data = json.loads(result.output)
+    assert_that(data["tool"]).is_equal_to("markdownlint")
+    assert_that(data["url"]).ends_with("/doc/md013.md")
@@ -16,6 +16,9 @@ from lintro.cli_utils.commands.format import (
     format_code,
     format_command,
 )
+from lintro.config.execution_config import ExecutionConfig
+from lintro.config.lintro_config import LintroConfig
+from lintro.utils.execution.audit_log import read_entries
 
 # =============================================================================
 # Module constants tests
@@ -96,6 +99,30 @@ def test_format_command_with_tools_option(mock_run: MagicMock) -> None:
     assert_that(call_kwargs["tools"]).is_equal_to("ruff,black")
 
 
+@patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
+def test_format_command_fix_safety_all(mock_run: MagicMock) -> None:
+    """--fix-safety all turns on every tool's unsafe fixes.
+
+    Args:
+        mock_run: Mock for run_lint_tools_simple.
+    """
+    mock_run.return_value = 0
+    runner = CliRunner()
+
+    result = runner.invoke(
+        format_command,
+        ["--fix-safety", "all", "--extra-args", "ruff:--show-fixes"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    extra = mock_run.call_args.kwargs["extra_tool_options"]
+    assert_that(extra["ruff"]).is_equal_to(
+        {"extra_args": ["--show-fixes"], "unsafe_fixes": True},
+    )
+    assert_that(extra["clippy"]).is_equal_to({"unsafe_fixes": True})
+    assert_that(extra["biome"]).is_equal_to({"unsafe": True})
+
+
 @patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
 def test_format_command_with_exclude(mock_run: MagicMock) -> None:
     """format_command passes exclude option.
@@ -179,6 +206,207 @@ def test_format_command_returns_tool_exit_code(mock_run: MagicMock) -> None:
     assert_that(result.exit_code).is_equal_to(1)
 
 
+def test_format_command_patch_requires_dry_run() -> None:
+    """--patch without --dry-run is a usage error."""
+    result = CliRunner().invoke(format_command, ["--patch", "fixes.patch"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("--patch requires --dry-run")
+
+
+@patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
+def test_format_command_dry_run_leaves_files_untouched(
+    mock_run: MagicMock,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A dry run prints and saves the diff but keeps the project unchanged.
+
+    Args:
+        mock_run: Mock for run_lint_tools_simple.
+        tmp_path: Temporary path fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "app.py").write_text("x=1\n")
+    monkeypatch.chdir(tmp_path)
+
+    def _fix(**kwargs: object) -> int:
+        """Rewrite the file like a formatter would.
+
+        Args:
+            **kwargs: Runner arguments.
+
+        Returns:
+            int: Exit code.
+        """
+        Path("app.py").write_text("x = 1\n")
+        return 0
+
+    mock_run.side_effect = _fix
+
+    result = CliRunner().invoke(
+        format_command,
+        ["--dry-run", "--patch", "fixes.patch"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that((tmp_path / "app.py").read_text()).is_equal_to("x=1\n")
+    assert_that(result.output).contains("-x=1", "+x = 1", "1 file(s) would change")
+    assert_that((tmp_path / "fixes.patch").read_text()).contains("+++ b/app.py")
+
+
+@patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
+def test_format_command_emit_patch_writes_fixes_only_to_file(
+    mock_run: MagicMock,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--emit-patch saves the fixes as a patch without printing or applying it.
+
+    Args:
+        mock_run: Mock for run_lint_tools_simple.
+        tmp_path: Temporary path fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "app.py").write_text("x=1\n")
+    monkeypatch.chdir(tmp_path)
+
+    def _fix(**kwargs: object) -> int:
+        """Rewrite the file like a formatter would.
+
+        Args:
+            **kwargs: Runner arguments.
+
+        Returns:
+            int: Exit code.
+        """
+        Path("app.py").write_text("x = 1\n")
+        return 0
+
+    mock_run.side_effect = _fix
+
+    result = CliRunner().invoke(format_command, ["--emit-patch", "fixes.patch"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that((tmp_path / "app.py").read_text()).is_equal_to("x=1\n")
+    assert_that(result.output).contains("1 file(s)", "git apply fixes.patch")
+    assert_that(result.output).does_not_contain("+x = 1")
+    assert_that((tmp_path / "fixes.patch").read_text()).contains(
+        "+++ b/app.py",
+        "+x = 1",
+    )
+
+
+def test_format_command_emit_patch_rejects_interactive() -> None:
+    """--emit-patch cannot be combined with --interactive."""
+    result = CliRunner().invoke(
+        format_command,
+        ["--emit-patch", "fixes.patch", "--interactive"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("--emit-patch cannot be combined")
+
+
+@patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
+def test_format_command_interactive_applies_accepted_hunks(
+    mock_run: MagicMock,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Interactive mode writes only the fixes the user accepts.
+
+    Args:
+        mock_run: Mock for run_lint_tools_simple.
+        tmp_path: Temporary path fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "a.py").write_text("x=1\n")
+    (tmp_path / "b.py").write_text("y=2\n")
+    monkeypatch.chdir(tmp_path)
+    config = LintroConfig(
+        execution=ExecutionConfig(audit_log=".lintro/audit.jsonl"),
+    )
+    monkeypatch.setattr("lintro.cli_utils.commands.format.get_config", lambda: config)
+
+    def _fix(**kwargs: object) -> int:
+        """Rewrite both files like a formatter would.
+
+        Args:
+            **kwargs: Runner arguments.
+
+        Returns:
+            int: Exit code.
+        """
+        Path("a.py").write_text("x = 1\n")
+        Path("b.py").write_text("y = 2\n")
+        return 0
+
+    mock_run.side_effect = _fix
+
+    result = CliRunner().invoke(format_command, ["--interactive"], input="y\nn\n")
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that((tmp_path / "a.py").read_text()).is_equal_to("x = 1\n")
+    assert_that((tmp_path / "b.py").read_text()).is_equal_to("y=2\n")
+    assert_that(result.output).contains("Applied fixes to 1 of 2 file(s).")
+    assert_that(mock_run.call_args.kwargs["audit"]).is_false()
+    entries = read_entries(str(tmp_path / ".lintro" / "audit.jsonl"))
+    assert_that([(f.file, f.tool) for f in entries[0].files]).is_equal_to(
+        [("a.py", "interactive")],
+    )
+
+
+def test_format_command_interactive_excludes_dry_run() -> None:
+    """--interactive and --dry-run are mutually exclusive."""
+    result = CliRunner().invoke(format_command, ["--interactive", "--dry-run"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+
+
+@patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
+def test_format_command_fix_only(mock_run: MagicMock) -> None:
+    """--fix-only passes the parsed rules and rejects unsupported tools.
+
+    Args:
+        mock_run: Mock for run_lint_tools_simple.
+    """
+    mock_run.return_value = 0
+    runner = CliRunner()
+
+    result = runner.invoke(
+        format_command,
+        ["--fix-only", "clippy::needless_return,ruff:I001"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(mock_run.call_args.kwargs["fix_only"]).is_equal_to(
+        {"clippy": ["clippy::needless_return"], "ruff": ["I001"]},
+    )
+
+    result = runner.invoke(format_command, ["--fix-only", "black:E501"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("black cannot fix selected rules")
+
+
+@patch("lintro.cli_utils.commands.format.run_lint_tools_simple")
+def test_format_command_confirm_over(mock_run: MagicMock) -> None:
+    """--confirm-over is passed to the runner.
+
+    Args:
+        mock_run: Mock for run_lint_tools_simple.
+    """
+    mock_run.return_value = 0
+    runner = CliRunner()
+
+    result = runner.invoke(format_command, ["--confirm-over", "2000"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(mock_run.call_args.kwargs["confirm_over"]).is_equal_to(2000)
+    assert_that(mock_run.call_args.kwargs["max_files"]).is_none()
+
+
 # =============================================================================
 # format_code tests
 # =============================================================================
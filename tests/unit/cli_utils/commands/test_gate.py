@@ -0,0 +1,136 @@
+"""Tests for lintro.cli_utils.commands.gate module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.gate as gate_cmd
+from lintro.cli_utils.commands.gate import gate_command
+from lintro.config.config_loader import build_config_from_data
+
+
+@pytest.fixture
+def captured_run(monkeypatch: pytest.MonkeyPatch) -> dict[str, Any]:
+    """Configure two gates and capture the check run they start.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Keyword arguments of the last stubbed run.
+    """
+    config = build_config_from_data(
+        {
+            "gates": {
+                "security": {
+                    "description": "Secrets and advisories",
+                    "tools": ["gitleaks", "bandit"],
+                    "fail_on": "error",
+                    "output_format": "github",
+                    "output": "security.txt",
+                },
+                "style": {
+                    "tools": "ruff,black",
+                    "paths": ["src"],
+                    "max_issues": 10,
+                    "summary": "prose",
+                },
+            },
+        },
+    )
+    captured: dict[str, Any] = {}
+
+    def fake_run(**kwargs: Any) -> int:
+        """Record the run and report a custom exit code.
+
+        Args:
+            **kwargs: Arguments passed to run_lint_tools_simple.
+
+        Returns:
+            int: Exit code of the stubbed run.
+        """
+        captured.update(kwargs)
+        return 3
+
+    monkeypatch.setattr(gate_cmd, "get_config", lambda: config)
+    monkeypatch.setattr(gate_cmd, "run_lint_tools_simple", fake_run)
+    return captured
+
+
+def test_gate_runs_its_tools_and_thresholds(captured_run: dict[str, Any]) -> None:
+    """A gate runs its own tools, thresholds and output.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+    """
+    result = CliRunner().invoke(gate_command, ["security"])
+
+    assert_that(result.exit_code).is_equal_to(3)
+    assert_that(captured_run).contains_entry(
+        {"tools": "gitleaks,bandit"},
+        {"paths": ["."]},
+        {"fail_on": "error"},
+        {"max_issues": None},
+        {"output_format": "github"},
+        {"output_file": "security.txt"},
+        {"summary": "table"},
+    )
+
+
+def test_command_line_paths_replace_gate_paths(
+    captured_run: dict[str, Any],
+    tmp_path: Path,
+) -> None:
+    """Gate paths apply unless paths are given on the command line.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+        tmp_path: Temporary directory path for test files.
+    """
+    CliRunner().invoke(gate_command, ["style"])
+    assert_that(captured_run).contains_entry(
+        {"paths": ["src"]},
+        {"tools": "ruff,black"},
+        {"max_issues": 10},
+        {"summary": "prose"},
+    )
+
+    CliRunner().invoke(gate_command, ["style", str(tmp_path)])
+    assert_that(captured_run["paths"]).is_equal_to([str(tmp_path)])
+
+
+def test_unknown_gate_lists_available_gates(captured_run: dict[str, Any]) -> None:
+    """Unknown gate names fail without running anything.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+    """
+    result = CliRunner().invoke(gate_command, ["lint"])
+
+    assert_that(result.exit_code).is_not_equal_to(0)
+    assert_that(result.output).contains("available: security, style")
+    assert_that(captured_run).is_empty()
+
+
+def test_list_shows_gates(captured_run: dict[str, Any]) -> None:
+    """Listing shows each gate's tools and description.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+    """
+    result = CliRunner().invoke(gate_command, ["--list"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("security", "gitleaks, bandit", "Secrets")
+    assert_that(captured_run).is_empty()
+
+
+def test_gates_require_tools() -> None:
+    """A gate without tools is a configuration error."""
+    with pytest.raises(ValueError):
+        build_config_from_data({"gates": {"empty": {"fail_on": "error"}}})
@@ -0,0 +1,53 @@
+"""Tests for lintro.cli_utils.commands.generate module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import yaml
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands import generate as generate_module
+from lintro.cli_utils.commands.generate import generate_command
+from lintro.config.execution_config import ExecutionConfig
+from lintro.config.lintro_config import LintroConfig
+
+
+@pytest.fixture(autouse=True)
+def ruff_only(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Use a config that enables only ruff.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    config = LintroConfig(execution=ExecutionConfig(enabled_tools=["ruff"]))
+    monkeypatch.setattr(generate_module, "get_config", lambda: config)
+
+
+def test_pre_commit_config_prints_yaml() -> None:
+    """Without --output the config is printed."""
+    result = CliRunner().invoke(generate_command, ["pre-commit-config"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    hooks = yaml.safe_load(result.output)["repos"][0]["hooks"]
+    assert_that([h["id"] for h in hooks]).is_equal_to(["lintro-ruff"])
+
+
+def test_pre_commit_config_keeps_existing_file(tmp_path: Path) -> None:
+    """An existing output file is only replaced with --force.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    output = tmp_path / ".pre-commit-config.yaml"
+    output.write_text("repos: []\n")
+    args = ["pre-commit-config", "--output", str(output)]
+
+    refused = CliRunner().invoke(generate_command, args)
+    forced = CliRunner().invoke(generate_command, [*args, "--force"])
+
+    assert_that(refused.exit_code).is_equal_to(1)
+    assert_that(forced.exit_code).is_equal_to(0)
+    assert_that(output.read_text()).contains("lintro check --tools ruff")
@@ -0,0 +1,99 @@
+"""Tests for lintro.cli_utils.commands.history module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.history import history_command
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution import run_history
+from lintro.utils.execution.run_history import record_run
+
+
+class _FakeVcs:
+    """VCS provider with a fixed checked-out commit."""
+
+    def head_commit(self) -> str:
+        """Return the checked-out commit.
+
+        Returns:
+            str: Commit id.
+        """
+        return "abc1234def"
+
+
+@pytest.fixture
+def history_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> str:
+    """Record two runs in which ruff went from one issue to three.
+
+    Args:
+        tmp_path: Temporary project directory.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        str: Path of the history database.
+    """
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr(run_history, "detect_vcs", lambda: _FakeVcs())
+    db = str(tmp_path / "history.db")
+    record_run(db, [ToolResult(name="ruff", issues_count=1)], exit_code=1)
+    record_run(db, [ToolResult(name="ruff", issues_count=3)], exit_code=1)
+    return db
+
+
+def test_history_shows_trend(history_db: str) -> None:
+    """Runs are listed with their counts and the change between them.
+
+    Args:
+        history_db: Path of the history database.
+    """
+    result = CliRunner().invoke(history_command, ["--db", history_db])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Issues per tool", "abc1234def", "+2")
+    assert_that(result.output).contains("ruff: 1 → 3")
+
+
+def test_history_fail_on_regression(history_db: str) -> None:
+    """--fail-on-regression exits 1 when the latest run has more issues.
+
+    Args:
+        history_db: Path of the history database.
+    """
+    runner = CliRunner()
+
+    result = runner.invoke(
+        history_command,
+        ["--db", history_db, "--json", "--fail-on-regression"],
+    )
+    assert_that(result.exit_code).is_equal_to(1)
+    data = json.loads(result.output)
+    assert_that(data["regressions"]).is_equal_to(
+        [{"key": "ruff", "previous": 1, "current": 3}],
+    )
+
+    result = runner.invoke(
+        history_command,
+        ["--db", history_db, "--limit", "1", "--fail-on-regression"],
+    )
+    assert_that(result.exit_code).is_equal_to(0)
+
+
+def test_history_without_database(tmp_path: Path) -> None:
+    """A missing database is reported as an error.
+
+    Args:
+        tmp_path: Temporary directory without a database.
+    """
+    result = CliRunner().invoke(
+        history_command,
+        ["--db", str(tmp_path / "missing.db")],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("No run history")
@@ -0,0 +1,142 @@
+"""Tests for lintro.cli_utils.commands.ingest module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.ingest as ingest_cmd
+from lintro.cli_utils.commands.ingest import ingest_command
+
+
+@pytest.fixture
+def captured_run(monkeypatch: pytest.MonkeyPatch) -> dict[str, Any]:
+    """Capture the run the ingested results are reported through.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Keyword arguments of the last stubbed run.
+    """
+    captured: dict[str, Any] = {}
+
+    def fake_run(**kwargs: Any) -> int:
+        """Record the run and report a custom exit code.
+
+        Args:
+            **kwargs: Arguments passed to run_lint_tools_simple.
+
+        Returns:
+            int: Exit code of the stubbed run.
+        """
+        captured.update(kwargs)
+        return 1
+
+    monkeypatch.setattr(ingest_cmd, "run_lint_tools_simple", fake_run)
+    return captured
+
+
+@pytest.fixture
+def sarif_file(tmp_path: Path) -> Path:
+    """Write a SARIF log with one CodeQL result.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: The SARIF file.
+    """
+    log = {
+        "version": "2.1.0",
+        "runs": [
+            {
+                "tool": {"driver": {"name": "CodeQL"}},
+                "results": [
+                    {
+                        "ruleId": "py/sql-injection",
+                        "level": "error",
+                        "message": {"text": "Query built from user input"},
+                        "locations": [
+                            {
+                                "physicalLocation": {
+                                    "artifactLocation": {"uri": "src/app.py"},
+                                    "region": {"startLine": 12},
+                                },
+                            },
+                        ],
+                    },
+                ],
+            },
+        ],
+    }
+    path = tmp_path / "codeql.sarif"
+    path.write_text(json.dumps(log))
+    return path
+
+
+def test_ingest_reports_results_per_driver(
+    captured_run: dict[str, Any],
+    sarif_file: Path,
+) -> None:
+    """Each SARIF tool becomes a result named after its driver.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+        sarif_file: SARIF log to ingest.
+    """
+    result = CliRunner().invoke(
+        ingest_command,
+        [str(sarif_file), "--fail-on", "error", "--output-format", "json"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+    ingested = captured_run["ingested"]
+    assert_that([r.name for r in ingested]).is_equal_to(["codeql"])
+    assert_that(ingested[0].issues_count).is_equal_to(1)
+    assert_that(ingested[0].issues[0].file).is_equal_to("src/app.py")
+    assert_that(captured_run).contains_entry(
+        {"fail_on": "error"},
+        {"output_format": "json"},
+        {"tools": None},
+    )
+
+
+def test_ingest_renames_results(
+    captured_run: dict[str, Any],
+    sarif_file: Path,
+) -> None:
+    """--tool names every imported result.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+        sarif_file: SARIF log to ingest.
+    """
+    CliRunner().invoke(ingest_command, [str(sarif_file), "--tool", "vendor"])
+
+    assert_that([r.name for r in captured_run["ingested"]]).is_equal_to(["vendor"])
+
+
+def test_ingest_rejects_files_that_are_not_sarif(
+    captured_run: dict[str, Any],
+    tmp_path: Path,
+) -> None:
+    """Invalid files fail without reporting anything.
+
+    Args:
+        captured_run: Arguments of the stubbed run.
+        tmp_path: Temporary directory path for test files.
+    """
+    path = tmp_path / "report.json"
+    path.write_text('{"results": []}')
+
+    result = CliRunner().invoke(ingest_command, [str(path)])
+
+    assert_that(result.exit_code).is_not_equal_to(0)
+    assert_that(result.output).contains("report.json", "not a SARIF log")
+    assert_that(captured_run).is_empty()
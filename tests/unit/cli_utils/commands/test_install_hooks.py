@@ -0,0 +1,53 @@
+"""Tests for lintro.cli_utils.commands.install_hooks module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.install_hooks import install_hooks_command
+from lintro.utils import git_hooks
+
+
+def test_install_hooks_writes_pre_commit_and_pre_push(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--pre-push installs both hooks.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    hooks = tmp_path / "hooks"
+    monkeypatch.setattr(git_hooks, "git_output", lambda *args, **kwargs: str(hooks))
+
+    result = CliRunner().invoke(install_hooks_command, ["--pre-push"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(sorted(p.name for p in hooks.iterdir())).is_equal_to(
+        ["pre-commit", "pre-push"],
+    )
+    assert_that(result.output).contains("Installed pre-push hook")
+
+
+def test_install_hooks_refuses_foreign_hook(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """An existing hook from another tool fails the command.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "pre-commit").write_text("#!/bin/sh\nmake lint\n")
+    monkeypatch.setattr(git_hooks, "git_output", lambda *args, **kwargs: str(tmp_path))
+
+    result = CliRunner().invoke(install_hooks_command, [])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("--force")
@@ -0,0 +1,84 @@
+"""Tests for lintro.cli_utils.commands.merge_reports module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.merge_reports import merge_reports_command
+
+
+def _shard_report(path: Path, shard: str, issues: int) -> str:
+    """Write the JSON report of one shard.
+
+    Args:
+        path: Report file.
+        shard: Shard of the report, as I/N.
+        issues: Issues ruff found in the shard.
+
+    Returns:
+        str: The report file.
+    """
+    data = {
+        "action": "check",
+        "shard": shard,
+        "results": [{"tool": "ruff", "success": not issues, "issues_count": issues}],
+    }
+    path.write_text(json.dumps(data))
+    return str(path)
+
+
+def test_merge_reports_writes_output(tmp_path: Path) -> None:
+    """The merged report is written to --output.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    reports = [
+        _shard_report(tmp_path / "1.json", "1/2", 1),
+        _shard_report(tmp_path / "2.json", "2/2", 2),
+    ]
+    output = tmp_path / "out" / "lint.json"
+
+    result = CliRunner().invoke(
+        merge_reports_command,
+        [*reports, "--output", str(output)],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    merged = json.loads(output.read_text())
+    assert_that(merged["summary"]["total_issues"]).is_equal_to(3)
+
+
+def test_merge_reports_prints_to_stdout(tmp_path: Path) -> None:
+    """Without --output the merged report is printed.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    report = _shard_report(tmp_path / "1.json", "1/1", 0)
+
+    result = CliRunner().invoke(merge_reports_command, [report])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(json.loads(result.output)["results"]).is_length(1)
+
+
+def test_merge_reports_fails_on_duplicate_shards(tmp_path: Path) -> None:
+    """Two reports of the same shard fail the command.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    reports = [
+        _shard_report(tmp_path / "1.json", "1/2", 0),
+        _shard_report(tmp_path / "2.json", "1/2", 0),
+    ]
+
+    result = CliRunner().invoke(merge_reports_command, reports)
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("also in")
@@ -0,0 +1,93 @@
+"""Tests for lintro.cli_utils.commands.outdated module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.outdated as outdated_cmd
+from lintro.cli_utils.commands.outdated import outdated_command
+from lintro.utils.outdated import EcosystemReport, OutdatedPackage
+
+
+@pytest.fixture
+def fake_reports(monkeypatch: pytest.MonkeyPatch) -> list[EcosystemReport]:
+    """Replace report collection with a fixed major update.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        The reports returned by the stubbed collector.
+    """
+    reports = [
+        EcosystemReport(
+            ecosystem="npm",
+            packages=[OutdatedPackage("npm", "vite", "4.5.0", "5.2.0", "major")],
+        ),
+        EcosystemReport(ecosystem="cargo", skipped="no manifest found"),
+    ]
+    monkeypatch.setattr(
+        outdated_cmd,
+        "collect_outdated",
+        lambda *args, **kwargs: reports,
+    )
+    return reports
+
+
+def test_outdated_is_informational_by_default(
+    fake_reports: list[EcosystemReport],
+    tmp_path: Path,
+) -> None:
+    """Updates are listed without failing the command.
+
+    Args:
+        fake_reports: Stubbed ecosystem reports.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(outdated_command, [str(tmp_path)])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("vite")
+    assert_that(result.output).contains("1 major")
+
+
+def test_outdated_fail_on_threshold(
+    fake_reports: list[EcosystemReport],
+    tmp_path: Path,
+) -> None:
+    """--fail-on exits 1 when an update meets the threshold.
+
+    Args:
+        fake_reports: Stubbed ecosystem reports.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(
+        outdated_command,
+        [str(tmp_path), "--fail-on", "major"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+
+
+def test_outdated_json_output(
+    fake_reports: list[EcosystemReport],
+    tmp_path: Path,
+) -> None:
+    """--json prints packages, ecosystem status and summary.
+
+    Args:
+        fake_reports: Stubbed ecosystem reports.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(outdated_command, [str(tmp_path), "--json"])
+
+    data = json.loads(result.output)
+    assert_that(data["summary"]["total"]).is_equal_to(1)
+    assert_that(data["ecosystems"]["cargo"]["skipped"]).is_equal_to(
+        "no manifest found",
+    )
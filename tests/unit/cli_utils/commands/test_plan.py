@@ -0,0 +1,92 @@
+"""Tests for lintro.cli_utils.commands.plan module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.plan as plan_cmd
+from lintro.cli_utils.commands.plan import plan_command
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+
+@pytest.fixture
+def fake_results(monkeypatch: pytest.MonkeyPatch) -> list[ToolResult]:
+    """Replace the check run with fixed ruff issues.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        The results returned by the stubbed check run.
+    """
+    results = [
+        ToolResult(
+            name="ruff",
+            success=False,
+            issues_count=2,
+            issues=[
+                RuffIssue(file="a.py", line=1, code="E501"),
+                RuffIssue(file="b.py", line=1, code="F401", fixable=True),
+            ],
+        ),
+    ]
+    monkeypatch.setattr(plan_cmd, "run_checks", lambda *args, **kwargs: results)
+    return results
+
+
+def test_plan_prints_prioritized_tasks(
+    fake_results: list[ToolResult],
+    tmp_path: Path,
+) -> None:
+    """The plan lists auto-fixable work first and summarizes the budget.
+
+    Args:
+        fake_results: Stubbed check results.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(plan_command, [str(tmp_path), "--budget", "4h"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Cleanup Plan")
+    assert_that(result.output.index("b.py")).is_less_than(result.output.index("a.py"))
+    assert_that(result.output).contains("(budget ~4 hours)")
+
+
+def test_plan_json_output_and_deferral(
+    fake_results: list[ToolResult],
+    tmp_path: Path,
+) -> None:
+    """JSON output reports planned tasks and how many were deferred.
+
+    Args:
+        fake_results: Stubbed check results.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(
+        plan_command,
+        [str(tmp_path), "--budget", "2m", "--json"],
+    )
+
+    data = json.loads(result.output)
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(data["budget_minutes"]).is_equal_to(2)
+    assert_that([t["code"] for t in data["tasks"]]).is_equal_to(["F401"])
+    assert_that(data["deferred"]).is_equal_to(1)
+
+
+def test_plan_rejects_invalid_budget(fake_results: list[ToolResult]) -> None:
+    """An unparseable budget is a usage error.
+
+    Args:
+        fake_results: Stubbed check results.
+    """
+    result = CliRunner().invoke(plan_command, ["--budget", "soon"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("invalid budget")
@@ -0,0 +1,127 @@
+"""Tests for lintro.cli_utils.commands.query module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.query import query_command
+
+
+@pytest.fixture
+def report(tmp_path: Path) -> Path:
+    """Write a JSON report with issues from two tools.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: The report file.
+    """
+    path = tmp_path / "report.json"
+    data = {
+        "timestamp": "2025-01-01T00:00:00Z",
+        "action": "check",
+        "summary": {"total_issues": 3, "total_fixed": 0, "tools_run": 2},
+        "results": [
+            {
+                "tool": "clippy",
+                "success": False,
+                "issues_count": 2,
+                "output": "",
+                "issues": [
+                    {
+                        "file": "src/core/engine.rs",
+                        "line": 4,
+                        "code": "clippy::unwrap_used",
+                        "message": "used unwrap",
+                        "severity": "error",
+                    },
+                    {
+                        "file": "src/cli.rs",
+                        "line": 9,
+                        "code": "clippy::needless_return",
+                        "message": "unneeded return",
+                        "severity": "warning",
+                    },
+                ],
+            },
+            {
+                "tool": "ruff",
+                "success": False,
+                "issues_count": 1,
+                "output": "",
+                "issues": [
+                    {"file": "a.py", "line": 1, "code": "F401", "message": "unused"},
+                ],
+            },
+        ],
+    }
+    path.write_text(json.dumps(data))
+    return path
+
+
+def test_query_prints_matching_issues(report: Path) -> None:
+    """Matching issues are listed with the number of matches.
+
+    Args:
+        report: JSON report fixture.
+    """
+    result = CliRunner().invoke(
+        query_command,
+        ['severity>=error and path~"src/core/**"', "--input", str(report)],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("src/core/engine.rs:4")
+    assert_that(result.output).does_not_contain("src/cli.rs")
+    assert_that(result.output).contains("1 of 3 issue(s) match.")
+
+
+def test_query_json_output(report: Path) -> None:
+    """--json prints the matching issues as written, with their tool.
+
+    Args:
+        report: JSON report fixture.
+    """
+    result = CliRunner().invoke(
+        query_command,
+        ["severity<error", "--input", str(report), "--json"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    issues = json.loads(result.output)
+    assert_that([(i["tool"], i["code"]) for i in issues]).is_equal_to(
+        [("clippy", "clippy::needless_return"), ("ruff", "F401")],
+    )
+
+
+def test_query_rejects_invalid_expression(report: Path) -> None:
+    """An invalid expression is a usage error.
+
+    Args:
+        report: JSON report fixture.
+    """
+    result = CliRunner().invoke(query_command, ["line~4", "--input", str(report)])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("'~' cannot be used with line")
+
+
+def test_query_rejects_non_report(tmp_path: Path) -> None:
+    """A file that is not a Lintro JSON report is refused.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    path = tmp_path / "other.json"
+    path.write_text("[1, 2]")
+
+    result = CliRunner().invoke(query_command, ["tool=ruff", "--input", str(path)])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("Not a Lintro JSON report")
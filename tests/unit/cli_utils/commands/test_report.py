@@ -0,0 +1,290 @@
+"""Tests for lintro.cli_utils.commands.report module."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Sequence
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands import report as report_module
+from lintro.cli_utils.commands.report import report_command
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.execution.failure_policy import FailurePolicy
+from lintro.utils.github_checks import CheckRunResult, CheckRunTarget
+from lintro.utils.github_review import PullRequestRef
+from lintro.utils.gitlab_review import MergeRequestRef
+from lintro.utils.review_comments import ReviewResult
+
+
+@pytest.fixture
+def report(tmp_path: Path) -> Path:
+    """Write a JSON report with one issue.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: The report file.
+    """
+    path = tmp_path / "lint.json"
+    data = {
+        "results": [
+            {
+                "tool": "ruff",
+                "issues": [
+                    {"file": "app.py", "line": 2, "code": "F401", "message": "x"},
+                ],
+            },
+        ],
+    }
+    path.write_text(json.dumps(data))
+    return path
+
+
+def test_github_pr_publishes_review(
+    report: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--github-pr publishes the report's issues on the detected PR.
+
+    Args:
+        report: JSON report file.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[tuple[list[dict[str, Any]], PullRequestRef]] = []
+
+    def fake_publish(
+        records: list[dict[str, Any]],
+        pull: PullRequestRef,
+        client: object,
+        root: Path | None = None,
+    ) -> ReviewResult:
+        """Record the publish call.
+
+        Args:
+            records: Issue records of the report.
+            pull: Pull request to review.
+            client: GitHub API client.
+            root: Repository root.
+
+        Returns:
+            ReviewResult: A fixed outcome.
+        """
+        calls.append((records, pull))
+        return ReviewResult(
+            created=1,
+            updated=0,
+            deleted=2,
+            unchanged=0,
+            outside_diff=3,
+        )
+
+    monkeypatch.setattr(report_module, "publish_review", fake_publish)
+    monkeypatch.setenv("GITHUB_TOKEN", "t0ken")
+
+    result = CliRunner().invoke(
+        report_command,
+        ["--github-pr", "--input", str(report), "--repo", "acme/app", "--pr", "7"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(calls).is_length(1)
+    records, pull = calls[0]
+    assert_that(records[0]["code"]).is_equal_to("F401")
+    assert_that((pull.repo, pull.number)).is_equal_to(("acme/app", 7))
+    assert_that(result.output).contains("acme/app#7: 1 new, 0 updated, 2 deleted")
+    assert_that(result.output).contains("3 issue(s) outside the diff")
+
+
+def test_github_pr_needs_a_token(
+    report: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Publishing fails without the GitHub token.
+
+    Args:
+        report: JSON report file.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.delenv("GITHUB_TOKEN", raising=False)
+
+    result = CliRunner().invoke(
+        report_command,
+        ["--github-pr", "--input", str(report), "--repo", "acme/app", "--pr", "7"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("GITHUB_TOKEN is not set")
+
+
+def test_github_pr_needs_input() -> None:
+    """--github-pr without --input is a usage error."""
+    result = CliRunner().invoke(report_command, ["--github-pr"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("--input")
+
+
+def test_gitlab_mr_publishes_discussions(
+    report: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--gitlab-mr publishes the report's issues on the detected MR.
+
+    Args:
+        report: JSON report file.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[MergeRequestRef] = []
+
+    def fake_publish(
+        records: list[dict[str, Any]],
+        merge_request: MergeRequestRef,
+        client: object,
+        root: Path | None = None,
+    ) -> ReviewResult:
+        """Record the publish call.
+
+        Args:
+            records: Issue records of the report.
+            merge_request: Merge request to review.
+            client: GitLab API client.
+            root: Repository root.
+
+        Returns:
+            ReviewResult: A fixed outcome.
+        """
+        calls.append(merge_request)
+        return ReviewResult(
+            created=0,
+            updated=1,
+            deleted=0,
+            unchanged=4,
+            outside_diff=0,
+        )
+
+    monkeypatch.setattr(report_module, "publish_discussions", fake_publish)
+    monkeypatch.setenv("GITLAB_TOKEN", "glpat")
+    monkeypatch.setenv("CI_PROJECT_ID", "123")
+    monkeypatch.setenv("CI_MERGE_REQUEST_IID", "8")
+
+    result = CliRunner().invoke(
+        report_command,
+        ["--gitlab-mr", "--input", str(report)],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that([(m.project, m.iid) for m in calls]).is_equal_to([("123", 8)])
+    assert_that(result.output).contains("123!8: 0 new, 1 updated, 0 deleted")
+
+
+def test_gitlab_mr_needs_a_token(
+    report: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Publishing fails without the GitLab token.
+
+    Args:
+        report: JSON report file.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.delenv("GITLAB_TOKEN", raising=False)
+
+    result = CliRunner().invoke(
+        report_command,
+        ["--gitlab-mr", "--input", str(report), "--project", "1", "--mr", "8"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("GITLAB_TOKEN is not set")
+
+
+def test_github_checks_creates_check_run(
+    report: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--github-checks creates a check run under the --fail-on policy.
+
+    Args:
+        report: JSON report file.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[tuple[CheckRunTarget, FailurePolicy, str]] = []
+
+    def fake_publish(
+        records: list[dict[str, Any]],
+        target: CheckRunTarget,
+        client: object,
+        policy: FailurePolicy,
+        root: Path | None = None,
+        name: str = "Lintro",
+        failed: Sequence[str] = (),
+    ) -> CheckRunResult:
+        """Record the publish call.
+
+        Args:
+            records: Issue records of the report.
+            target: Commit of the check run.
+            client: GitHub API client.
+            policy: Failure policy.
+            root: Repository root.
+            name: Name of the check run.
+            failed: Tools that failed to run.
+
+        Returns:
+            CheckRunResult: A fixed outcome.
+        """
+        calls.append((target, policy, name))
+        return CheckRunResult(
+            check_run_id=42,
+            conclusion="neutral",
+            annotations=1,
+            skipped=0,
+        )
+
+    monkeypatch.setattr(report_module, "publish_check_run", fake_publish)
+    monkeypatch.setenv("GITHUB_TOKEN", "t0ken")
+
+    result = CliRunner().invoke(
+        report_command,
+        [
+            "--github-checks",
+            "--input",
+            str(report),
+            "--repo",
+            "acme/app",
+            "--sha",
+            "abc1234def",
+            "--fail-on",
+            "error",
+            "--check-name",
+            "Lint",
+        ],
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    target, policy, name = calls[0]
+    assert_that((target.repo, target.head_sha)).is_equal_to(("acme/app", "abc1234def"))
+    assert_that(policy.fail_on).is_equal_to(SeverityLevel.ERROR)
+    assert_that(name).is_equal_to("Lint")
+    assert_that(result.output).contains("acme/app@abc1234: neutral, 1 annotation(s)")
+
+
+def test_github_pr_and_gitlab_mr_are_exclusive(report: Path) -> None:
+    """Only one forge can be published to at a time.
+
+    Args:
+        report: JSON report file.
+    """
+    result = CliRunner().invoke(
+        report_command,
+        ["--github-pr", "--gitlab-mr", "--input", str(report)],
+    )
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("cannot be combined")
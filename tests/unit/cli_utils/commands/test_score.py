@@ -0,0 +1,92 @@
+"""Tests for lintro.cli_utils.commands.score module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.score as score_cmd
+from lintro.cli_utils.commands.score import score_command
+from lintro.config.lintro_config import LintroConfig
+from lintro.config.score_config import ScoreConfig
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Create a 500-line project and replace the check run with ruff issues.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The project directory, which is also the working directory.
+    """
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "a.py").write_text("x = 1\n" * 400)
+    (tmp_path / "b.py").write_text("y = 2\n" * 100)
+    monkeypatch.chdir(tmp_path)
+    results = [
+        ToolResult(
+            name="ruff",
+            success=False,
+            issues_count=2,
+            issues=[
+                RuffIssue(file="src/a.py", line=1, code="E501"),
+                RuffIssue(file="src/a.py", line=2, code="E501"),
+            ],
+        ),
+    ]
+    monkeypatch.setattr(score_cmd, "run_checks", lambda *args, **kwargs: results)
+    monkeypatch.setattr(
+        score_cmd,
+        "get_config",
+        lambda: LintroConfig(score=ScoreConfig(min_grade="C")),
+    )
+    return tmp_path
+
+
+def test_score_prints_total_and_directories(project: Path) -> None:
+    """The total score and the directories with issues are shown.
+
+    Args:
+        project: Project directory with stubbed check results.
+    """
+    result = CliRunner().invoke(score_command, [])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Lowest-Scoring Directories", "src/")
+    assert_that(result.output).contains("Quality score 80.6 (B)")
+    assert_that(result.output).contains("2 issue(s), penalty 6 over 500 line(s)")
+
+
+def test_score_fail_under_gates(project: Path) -> None:
+    """A score below --fail-under exits with status 1.
+
+    Args:
+        project: Project directory with stubbed check results.
+    """
+    result = CliRunner().invoke(score_command, ["--fail-under", "90", "--json"])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    data = json.loads(result.output)
+    assert_that(data["total"]).contains_entry({"score": 80.6}, {"grade": "B"})
+    assert_that(data["passed"]).is_false()
+
+
+def test_score_min_grade_must_exist(project: Path) -> None:
+    """An unknown --min-grade is a usage error.
+
+    Args:
+        project: Project directory with stubbed check results.
+    """
+    result = CliRunner().invoke(score_command, ["--min-grade", "Z"])
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("Unknown grade 'Z'")
@@ -0,0 +1,187 @@
+"""Tests for lintro.cli_utils.commands.stats module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.stats as stats_cmd
+from lintro.cli_utils.commands.stats import stats_command
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Create a small project and replace the check run with ruff issues.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The project directory, which is also the working directory.
+    """
+    for name in ("src/a.py", "src/b.py", "tests/test_a.py"):
+        (tmp_path / name).parent.mkdir(parents=True, exist_ok=True)
+        (tmp_path / name).write_text("x = 1\n")
+    monkeypatch.chdir(tmp_path)
+    results = [
+        ToolResult(
+            name="ruff",
+            success=False,
+            issues_count=2,
+            issues=[
+                RuffIssue(file="src/a.py", line=1, code="E501"),
+                RuffIssue(file="src/b.py", line=1, code="E501"),
+            ],
+        ),
+    ]
+    monkeypatch.setattr(stats_cmd, "run_checks", lambda *args, **kwargs: results)
+    return tmp_path
+
+
+def test_stats_lists_directories_and_writes_treemap(project: Path) -> None:
+    """Directories with issues are listed and the treemap is written.
+
+    Args:
+        project: Project directory with stubbed check results.
+    """
+    result = CliRunner().invoke(stats_command, ["--treemap", "out/debt.svg"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Lint Debt by Directory", "src/")
+    assert_that(result.output).does_not_contain("tests/")
+    assert_that(result.output).contains("2 issue(s) in 3 file(s)")
+    svg = (project / "out" / "debt.svg").read_text()
+    assert_that(svg).starts_with("<svg").contains("src: 2 file(s), 2 issue(s)")
+
+
+def test_stats_lists_tools_rules_and_files(project: Path) -> None:
+    """Issues are also summarized per tool, rule and file.
+
+    Args:
+        project: Project directory with stubbed check results.
+    """
+    result = CliRunner().invoke(stats_command, [])
+
+    assert_that(result.output).contains("Issues by Tool", "Top Rules", "Top Files")
+    assert_that(result.output).contains("ruff:E501", "src/a.py")
+    assert_that(result.output).contains("0 of 2 issue(s) fixable automatically (0%)")
+
+
+def test_stats_json_output(project: Path) -> None:
+    """--json prints only the metrics.
+
+    Args:
+        project: Project directory with stubbed check results.
+    """
+    result = CliRunner().invoke(stats_command, ["--json"])
+
+    data = json.loads(result.output)
+    assert_that(data["issues"]).is_equal_to(2)
+    assert_that(data["rules"]).is_equal_to(
+        [{"key": "ruff:E501", "issues": 2, "fixable": 0, "errors": 0}],
+    )
+
+
+def test_stats_reads_saved_report(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--input summarizes a JSON report without running any checks.
+
+    Args:
+        tmp_path: Temporary directory path.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr(
+        stats_cmd,
+        "run_checks",
+        lambda *args, **kwargs: pytest.fail("checks should not run"),
+    )
+    report = tmp_path / "lint.json"
+    issue = {"file": "src/a.py", "line": 1, "code": "F401", "fixable": True}
+    report.write_text(
+        json.dumps(
+            {
+                "results": [
+                    {"tool": "ruff", "issues": [issue]},
+                    {"tool": "mypy", "issues": []},
+                ],
+            },
+        ),
+    )
+    runner = CliRunner()
+
+    result = runner.invoke(stats_command, ["--input", str(report)])
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Issues by Directory", "src/", "mypy")
+    assert_that(result.output).contains("1 of 1 issue(s) fixable automatically")
+
+    result = runner.invoke(stats_command, ["--input", str(report), "--tools", "ruff"])
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("cannot be combined")
+
+
+def test_stats_rule_usage_reads_past_runs(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Rule usage comes from past runs without running any checks.
+
+    Args:
+        tmp_path: Temporary directory path.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.delenv("LINTRO_LOG_DIR", raising=False)
+    run_dir = tmp_path / ".lintro" / "run-20250101-100000"
+    run_dir.mkdir(parents=True)
+    (run_dir / "summary.csv").write_text(
+        "tool,issues_count,file,line,code,message\n"
+        "ruff,1,a.py,3,E501,Line too long\n"
+        "yamllint,0,,,,\n",
+    )
+    monkeypatch.setattr(
+        stats_cmd,
+        "run_checks",
+        lambda *args, **kwargs: pytest.fail("checks should not run"),
+    )
+    monkeypatch.setattr(
+        stats_cmd,
+        "load_configured_rules",
+        lambda: {"ruff": ["E", "B"]},
+    )
+
+    result = CliRunner().invoke(stats_command, ["--rule-usage"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Tools Without", "│ yamllint │    1 │")
+    assert_that(result.output).contains("│ ruff │ B    │")
+    assert_that(result.output).does_not_contain("│ ruff │ E    │")
+    assert_that(result.output).contains("Based on the last 1 run(s).")
+
+
+def test_stats_rule_usage_requires_history(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without past runs, --rule-usage explains how to create them.
+
+    Args:
+        tmp_path: Temporary directory path.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.delenv("LINTRO_LOG_DIR", raising=False)
+
+    result = CliRunner().invoke(stats_command, ["--rule-usage"])
+
+    assert_that(result.exit_code).is_not_equal_to(0)
+    assert_that(result.output).contains("No past runs found")
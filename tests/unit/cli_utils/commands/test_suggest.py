@@ -0,0 +1,71 @@
+"""Tests for lintro.cli_utils.commands.suggest module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.suggest as suggest_cmd
+from lintro.ai.suggestions import FixSuggestion
+from lintro.cli_utils.commands.suggest import suggest_command
+from lintro.config.ai_config import AIConfig
+from lintro.config.lintro_config import LintroConfig
+
+_PATCH = "--- a/app.py\n+++ b/app.py\n@@ -2 +2 @@\n-x = eval(s)\n+x = int(s)\n"
+
+
+@pytest.fixture
+def enabled_config(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Enable suggestions and replace the check run and endpoint.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    config = LintroConfig(ai=AIConfig(enabled=True, endpoint="http://llm.test"))
+    monkeypatch.setattr(suggest_cmd, "get_config", lambda: config)
+    monkeypatch.setattr(suggest_cmd, "run_checks", lambda *args, **kwargs: [])
+    monkeypatch.setattr(
+        suggest_cmd,
+        "suggest_fixes",
+        lambda *args, **kwargs: [
+            FixSuggestion("app.py", 2, "ruff", "S307", "eval", patch=_PATCH),
+            FixSuggestion("lib.py", 1, "mypy", "", "bad", error="no patch returned"),
+        ],
+    )
+
+
+def test_suggest_requires_opt_in(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Without ai.enabled nothing is checked or sent.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(suggest_cmd, "get_config", lambda: LintroConfig())
+
+    result = CliRunner().invoke(suggest_command, [])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(result.output).contains("AI suggestions are disabled")
+
+
+def test_suggest_saves_patches_without_applying(
+    enabled_config: None,
+    tmp_path: Path,
+) -> None:
+    """Patches are shown as unverified and saved, never applied.
+
+    Args:
+        enabled_config: Enables suggestions with stubbed results.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = CliRunner().invoke(suggest_command, ["--save-dir", str(tmp_path)])
+
+    saved = sorted(tmp_path.glob("*.patch"))
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("suggested (unverified)")
+    assert_that(result.output).contains("no patch returned")
+    assert_that([p.name for p in saved]).is_equal_to(["001-app.py-S307.patch"])
+    assert_that(saved[0].read_text()).is_equal_to(_PATCH)
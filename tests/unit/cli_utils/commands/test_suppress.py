@@ -0,0 +1,78 @@
+"""Tests for lintro.cli_utils.commands.suppress module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.suppress as suppress_cmd
+from lintro.cli_utils.commands.suppress import suppress_command
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Provide a project whose checks flag one ruff E501 violation.
+
+    Args:
+        tmp_path: Temporary directory for the project.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The flagged file.
+    """
+    monkeypatch.chdir(tmp_path)
+    source = tmp_path / "app.py"
+    source.write_text("x = 1\n")
+    result = ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=1,
+        issues=[RuffIssue(file="app.py", line=1, code="E501")],
+    )
+    monkeypatch.setattr(suppress_cmd, "run_checks", lambda *args: [result])
+    return source
+
+
+def test_suppress_inserts_comments(project: Path) -> None:
+    """Each violation of the rule gets a comment.
+
+    Args:
+        project: The flagged file.
+    """
+    result = CliRunner().invoke(suppress_command, ["--rule", "E501", "--native"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("Suppressed E501 at app.py:1")
+    assert_that(project.read_text()).is_equal_to("x = 1  # noqa: E501\n")
+
+
+def test_suppress_reports_rules_without_violations(project: Path) -> None:
+    """A rule nothing violates leaves the files alone.
+
+    Args:
+        project: The flagged file.
+    """
+    result = CliRunner().invoke(suppress_command, ["--rule", "F401"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("No violations of F401 found.")
+    assert_that(project.read_text()).is_equal_to("x = 1\n")
+
+
+def test_suppress_rejects_lintro_options_with_native(project: Path) -> None:
+    """--until and --reason cannot be written into native comments.
+
+    Args:
+        project: The flagged file.
+    """
+    result = CliRunner().invoke(
+        suppress_command,
+        ["--rule", "E501", "--native", "--reason", "legacy"],
+    )
+
+    assert_that(result.exit_code).is_equal_to(2)
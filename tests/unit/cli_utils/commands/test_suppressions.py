@@ -0,0 +1,68 @@
+"""Tests for lintro.cli_utils.commands.suppressions module."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.suppressions import suppressions_command
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Provide a project with an expired and a distant suppression.
+
+    Args:
+        tmp_path: Temporary directory for the project.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The project directory.
+    """
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "app.py").write_text(
+        "import os  # lintro: ignore[F401] until=2000-01-01 reason=\"old\"\n"
+        "import sys  # lintro: ignore[F401] until=2999-01-01\n",
+    )
+    return tmp_path
+
+
+def test_suppressions_lists_expired_and_fails(project: Path) -> None:
+    """Expired suppressions are listed and make the command fail.
+
+    Args:
+        project: The project directory.
+    """
+    result = CliRunner().invoke(suppressions_command, ["--json"])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that(json.loads(result.output)).is_equal_to(
+        [
+            {
+                "file": "app.py",
+                "line": 1,
+                "rules": ["F401"],
+                "until": "2000-01-01",
+                "reason": "old",
+                "expired": True,
+            },
+        ],
+    )
+
+
+def test_suppressions_without_expirations(project: Path) -> None:
+    """Nothing expiring soon is reported as such.
+
+    Args:
+        project: The project directory.
+    """
+    (project / "app.py").write_text("import os\n")
+
+    result = CliRunner().invoke(suppressions_command, ["--within", "7"])
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(result.output).contains("No suppressions expire within 7 days.")
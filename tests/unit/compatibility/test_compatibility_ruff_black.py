@@ -28,7 +28,9 @@ class FakeToolDefinition:
     can_fix: bool = False
     description: str = ""
     file_patterns: list[str] = field(default_factory=list)
+    shebang_interpreters: list[str] = field(default_factory=list)
     native_configs: list[str] = field(default_factory=list)
+    native: bool = False
 
 
 class FakeTool:
@@ -160,12 +162,14 @@ def _setup_tools(monkeypatch: pytest.MonkeyPatch) -> tuple[FakeTool, FakeTool]:
     def noop_write_reports_from_results(
         self: object,
         results: list[ToolResult],
+        **kwargs: object,
     ) -> None:
         """No-op writer used to avoid filesystem interaction.
 
         Args:
             self: Output manager instance under test.
             results: Aggregated tool results to write.
+            **kwargs: Effort options, ignored.
 
         Returns:
             None.
@@ -0,0 +1,218 @@
+"""Tests for lintro.config.config_hierarchy module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.config.config_hierarchy import (
+    find_nested_config_dirs,
+    load_config_for_path,
+    merge_config_data,
+    nested_severity,
+    plan_tool_scopes,
+)
+
+
+def _write_config(directory: Path, content: str) -> None:
+    """Write a .lintro-config.yaml into a directory.
+
+    Args:
+        directory: Directory to write the config into.
+        content: YAML content.
+    """
+    directory.mkdir(parents=True, exist_ok=True)
+    (directory / ".lintro-config.yaml").write_text(content)
+
+
+def test_merge_config_data_merges_mappings_and_replaces_lists() -> None:
+    """Nested mappings merge while lists are replaced by the nearer value."""
+    base = {
+        "tools": {"ruff": {"enabled": True}},
+        "execution": {"enabled_tools": ["a"]},
+    }
+    override = {"tools": {"mypy": False}, "execution": {"enabled_tools": ["b"]}}
+
+    merged = merge_config_data(base, override)
+
+    assert_that(merged["tools"]).is_equal_to({"ruff": {"enabled": True}, "mypy": False})
+    assert_that(merged["execution"]["enabled_tools"]).is_equal_to(["b"])
+
+
+def test_load_config_for_path_nearest_config_wins(tmp_path: Path) -> None:
+    """Nearest config overrides ancestors while inheriting other settings.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path, "enforce:\n  line_length: 100\ntools:\n  ruff: true\n")
+    _write_config(tmp_path / "sub", "tools:\n  ruff: false\n")
+
+    config = load_config_for_path(tmp_path / "sub")
+
+    assert_that(config.enforce.line_length).is_equal_to(100)
+    assert_that(config.is_tool_enabled("ruff")).is_false()
+    assert_that(config.config_path).ends_with(str(Path("sub") / ".lintro-config.yaml"))
+
+
+def test_find_nested_config_dirs_skips_root_and_vendor_dirs(tmp_path: Path) -> None:
+    """Only nested configs outside skipped directories are found.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path, "tools: {}\n")
+    _write_config(tmp_path / "crates" / "engine", "tools: {}\n")
+    _write_config(tmp_path / "node_modules" / "pkg", "tools: {}\n")
+
+    found = find_nested_config_dirs([str(tmp_path)])
+
+    assert_that(found).is_equal_to([(tmp_path / "crates" / "engine").resolve()])
+
+
+def test_plan_tool_scopes_excludes_disabled_subtree(tmp_path: Path) -> None:
+    """A subtree disabling an enabled tool becomes an exclude pattern.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "crates", "tools:\n  ruff: false\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes("ruff", [str(tmp_path)], nested, root_enabled=True)
+
+    assert_that(plan.paths).is_equal_to([str(tmp_path)])
+    assert_that(plan.exclude_patterns).is_length(1)
+    assert_that(plan.exclude_patterns[0]).ends_with("crates/")
+    assert_that(plan.exclude_patterns[0]).does_not_start_with("!")
+
+
+def test_plan_tool_scopes_adds_enabled_subtree(tmp_path: Path) -> None:
+    """A subtree enabling a tool disabled at the top becomes its own path.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "crates", "tools:\n  clippy: true\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes("clippy", [str(tmp_path)], nested, root_enabled=False)
+
+    assert_that(plan.paths).is_equal_to([str((tmp_path / "crates").resolve())])
+    assert_that(plan.exclude_patterns).is_empty()
+
+
+def test_plan_tool_scopes_reincludes_nested_enabled_subtree(tmp_path: Path) -> None:
+    """Re-enabling below a disabled subtree yields a negated pattern.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "a", "tools:\n  ruff: false\n")
+    _write_config(tmp_path / "a" / "b", "tools:\n  ruff: true\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes("ruff", [str(tmp_path)], nested, root_enabled=True)
+
+    assert_that(plan.paths).is_equal_to([str(tmp_path)])
+    assert_that(plan.exclude_patterns).is_length(2)
+    assert_that(plan.exclude_patterns[1]).starts_with("!")
+    assert_that(plan.exclude_patterns[1]).ends_with("a/b/")
+
+
+def test_plan_tool_scopes_empty_when_disabled_everywhere(tmp_path: Path) -> None:
+    """A tool disabled at the top and never re-enabled has no paths.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "sub", "tools:\n  ruff: false\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes("ruff", [str(tmp_path)], nested, root_enabled=False)
+
+    assert_that(plan.is_empty).is_true()
+
+
+def test_plan_tool_scopes_opt_in_tool_needs_a_mention(tmp_path: Path) -> None:
+    """An opt-in tool only runs in subtrees whose config lists it.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "web", "tools: {}\n")
+    _write_config(tmp_path / "crates", "tools:\n  cargo_geiger: true\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes(
+        "cargo_geiger",
+        [str(tmp_path)],
+        nested,
+        root_enabled=False,
+        by_default=False,
+    )
+
+    assert_that(plan.paths).is_equal_to([str((tmp_path / "crates").resolve())])
+
+
+def test_plan_tool_scopes_runs_subtree_settings_separately(tmp_path: Path) -> None:
+    """A subtree changing a tool's settings is excluded and planned on its own.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "legacy", "tools:\n  mypy:\n    timeout: 600\n")
+    _write_config(tmp_path / "legacy" / "vendor", "tools:\n  mypy: false\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes("mypy", [str(tmp_path)], nested, root_enabled=True)
+
+    assert_that(plan.paths).is_equal_to([str(tmp_path)])
+    assert_that(plan.exclude_patterns).is_length(1)
+    assert_that(plan.exclude_patterns[0]).ends_with("legacy/")
+    assert_that(plan.subtrees).is_length(1)
+    subtree = plan.subtrees[0]
+    assert_that(subtree.paths).is_equal_to([str((tmp_path / "legacy").resolve())])
+    assert_that(subtree.config.get_tool_config("mypy").timeout).is_equal_to(600)
+    assert_that(subtree.exclude_patterns).is_length(1)
+    assert_that(subtree.exclude_patterns[0]).ends_with("legacy/vendor/")
+
+
+def test_plan_tool_scopes_promotes_the_only_subtree(tmp_path: Path) -> None:
+    """A tool running only in subtrees with their own settings plans the first.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path / "a", "tools:\n  clippy:\n    timeout: 60\n")
+    _write_config(tmp_path / "b", "tools:\n  clippy:\n    timeout: 90\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    plan = plan_tool_scopes("clippy", [str(tmp_path)], nested, root_enabled=False)
+
+    assert_that(plan.paths).is_equal_to([str((tmp_path / "a").resolve())])
+    assert_that(plan.config.get_tool_config("clippy").timeout).is_equal_to(60)
+    assert_that([s.paths for s in plan.subtrees]).is_equal_to(
+        [[str((tmp_path / "b").resolve())]],
+    )
+
+
+def test_nested_severity_lists_deepest_subtrees_first(tmp_path: Path) -> None:
+    """Nested severity overrides merge over their ancestors, deepest first.
+
+    Args:
+        tmp_path: Temporary directory for the fake monorepo.
+    """
+    _write_config(tmp_path, "severity:\n  E501: info\n")
+    _write_config(tmp_path / "a", "severity:\n  F401: error\n")
+    _write_config(tmp_path / "a" / "b", "severity:\n  E501: error\n")
+    nested = find_nested_config_dirs([str(tmp_path)])
+
+    overrides = nested_severity(nested)
+
+    assert_that([directory for directory, _ in overrides]).is_equal_to(
+        [(tmp_path / "a" / "b").resolve(), (tmp_path / "a").resolve()],
+    )
+    assert_that(overrides[0][1]).is_equal_to({"E501": "error", "F401": "error"})
+    assert_that(overrides[1][1]).is_equal_to({"E501": "info", "F401": "error"})
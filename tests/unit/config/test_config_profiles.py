@@ -0,0 +1,170 @@
+"""Tests for named configuration profiles."""
+
+from __future__ import annotations
+
+from collections.abc import Iterator
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import (
+    LINTRO_PROFILE_ENV,
+    UnknownProfileError,
+    _convert_pyproject_to_config,
+    apply_profile,
+    clear_config_cache,
+    get_active_profile,
+    get_config,
+    load_config,
+    set_active_profile,
+)
+
+_CONFIG = """\
+execution:
+  enabled_tools: [ruff, mypy]
+output:
+  format: grid
+tools:
+  mypy: true
+profiles:
+  ci:
+    execution:
+      enabled_tools: [ruff]
+    output:
+      format: github
+      min_severity: error
+  local:
+    tools:
+      mypy: false
+"""
+
+
+@pytest.fixture(autouse=True)
+def reset_profile() -> Iterator[None]:
+    """Reset the active profile and config cache around each test.
+
+    Yields:
+        None: Control to the test.
+    """
+    set_active_profile(None)
+    yield
+    set_active_profile(None)
+    clear_config_cache()
+
+
+def test_load_config_without_profile_keeps_base(tmp_path: Path) -> None:
+    """Profiles are recorded but not applied unless selected.
+
+    Args:
+        tmp_path: Temporary directory for the config file.
+    """
+    config_file = tmp_path / ".lintro-config.yaml"
+    config_file.write_text(_CONFIG)
+
+    config = load_config(config_path=config_file)
+
+    assert_that(config.active_profile).is_none()
+    assert_that(sorted(config.profiles)).is_equal_to(["ci", "local"])
+    assert_that(config.output.format).is_equal_to("grid")
+    assert_that(config.execution.enabled_tools).is_equal_to(["ruff", "mypy"])
+
+
+def test_load_config_with_profile_overrides_sections(tmp_path: Path) -> None:
+    """A selected profile overrides tool lists and output settings.
+
+    Args:
+        tmp_path: Temporary directory for the config file.
+    """
+    config_file = tmp_path / ".lintro-config.yaml"
+    config_file.write_text(_CONFIG)
+
+    config = load_config(config_path=config_file, profile="ci")
+
+    assert_that(config.active_profile).is_equal_to("ci")
+    assert_that(config.execution.enabled_tools).is_equal_to(["ruff"])
+    assert_that(config.output.format).is_equal_to("github")
+    assert_that(config.output.min_severity).is_equal_to("error")
+    assert_that(config.is_tool_enabled("mypy")).is_false()
+
+
+def test_apply_profile_unknown_name_lists_available() -> None:
+    """Selecting an undefined profile raises with the available names."""
+    with pytest.raises(UnknownProfileError, match="available: ci, local"):
+        apply_profile({"profiles": {"ci": {}, "local": {}}}, "strict")
+
+
+def test_get_config_uses_profile_env_var(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """LINTRO_PROFILE selects a profile when --profile is not given.
+
+    Args:
+        tmp_path: Temporary directory for the config file.
+        monkeypatch: Pytest monkeypatch to chdir and set the env var.
+    """
+    (tmp_path / ".lintro-config.yaml").write_text(_CONFIG)
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setenv(LINTRO_PROFILE_ENV, "local")
+
+    config = get_config(reload=True)
+
+    assert_that(get_active_profile()).is_equal_to("local")
+    assert_that(config.is_tool_enabled("mypy")).is_false()
+
+
+def test_set_active_profile_takes_precedence_over_env(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """An explicit profile wins over the environment variable.
+
+    Args:
+        monkeypatch: Pytest monkeypatch to set the env var.
+    """
+    monkeypatch.setenv(LINTRO_PROFILE_ENV, "local")
+
+    set_active_profile("ci")
+
+    assert_that(get_active_profile()).is_equal_to("ci")
+
+
+def test_pyproject_profiles_are_converted() -> None:
+    """[tool.lintro.profiles.<name>] uses the flat pyproject layout."""
+    converted = _convert_pyproject_to_config(
+        {
+            "line_length": 88,
+            "profiles": {"strict": {"line_length": 79, "mypy": {"enabled": True}}},
+        },
+    )
+
+    strict = converted["profiles"]["strict"]
+    assert_that(strict["enforce"]).is_equal_to({"line_length": 79})
+    assert_that(strict["tools"]).is_equal_to({"mypy": {"enabled": True}})
+
+
+def test_singular_profile_section_is_an_alias(tmp_path: Path) -> None:
+    """A ``profile:`` section defines profiles like ``profiles:``.
+
+    Args:
+        tmp_path: Temporary directory for the config file.
+    """
+    config_file = tmp_path / ".lintro-config.yaml"
+    config_file.write_text(_CONFIG.replace("profiles:", "profile:"))
+
+    config = load_config(config_path=config_file, profile="ci")
+
+    assert_that(sorted(config.profiles)).is_equal_to(["ci", "local"])
+    assert_that(config.output.format).is_equal_to("github")
+
+
+def test_pyproject_profile_tables_are_converted() -> None:
+    """[tool.lintro.profile.<name>] tables are read as profiles."""
+    converted = _convert_pyproject_to_config(
+        {"profile": {"ci": {"line_length": 100}}},
+    )
+
+    assert_that(converted["profiles"]["ci"]["enforce"]).is_equal_to(
+        {"line_length": 100},
+    )
+
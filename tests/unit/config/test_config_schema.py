@@ -0,0 +1,178 @@
+"""Tests for configuration schema validation."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.config.config_schema import (
+    find_config_conflicts,
+    load_config_schema,
+    validate_against_schema,
+    validate_config_data,
+)
+
+_KNOWN_TOOLS = ["black", "mypy", "prettier", "ruff"]
+
+
+def _messages(data: dict[str, Any], **kwargs: Any) -> list[str]:
+    """Validate data and format each problem as ``path: message``.
+
+    Args:
+        data: Configuration in .lintro-config.yaml layout.
+        **kwargs: Extra arguments for validate_config_data.
+
+    Returns:
+        list[str]: Formatted problems.
+    """
+    return [f"{p.path}: {p.message}" for p in validate_config_data(data, **kwargs)]
+
+
+def test_schema_is_published_draft_2020_12() -> None:
+    """The bundled schema declares its dialect and root sections."""
+    schema = load_config_schema()
+
+    assert_that(schema["$schema"]).contains("2020-12")
+    assert_that(schema["properties"]).contains_key(
+        "enforce",
+        "execution",
+        "tools",
+        "profiles",
+    )
+
+
+def test_valid_config_has_no_problems() -> None:
+    """A well-formed config passes validation."""
+    data = {
+        "enforce": {"line_length": 100, "target_python": "py312"},
+        "execution": {"enabled_tools": "ruff", "tool_order": ["black", "ruff"]},
+        "tools": {"mypy": False, "ruff": {"enabled": True}},
+        "output": {"format": "json"},
+        "profiles": {"ci": {"output": {"format": "github"}}},
+    }
+
+    assert_that(_messages(data)).is_empty()
+
+
+def test_unknown_keys_suggest_corrections() -> None:
+    """Typos in section and option names are reported with suggestions."""
+    data = {"enforce": {"line_lenght": 88}, "exectuion": {}}
+
+    assert_that(_messages(data)).is_equal_to(
+        [
+            "enforce.line_lenght: unknown key 'line_lenght' "
+            "(did you mean 'line_length'?)",
+            "exectuion: unknown key 'exectuion' (did you mean 'execution'?)",
+        ],
+    )
+
+
+def test_type_and_value_errors() -> None:
+    """Wrong types, enum values and ranges are reported."""
+    data = {
+        "enforce": {"line_length": 0},
+        "execution": {"fail_fast": "yes", "tool_order": "random"},
+        "output": {"min_severity": "fatal"},
+    }
+
+    assert_that(_messages(data)).is_equal_to(
+        [
+            "enforce.line_length: 0 is less than 1",
+            "execution.fail_fast: expected boolean, got string",
+            "execution.tool_order: 'random' is not one of 'priority', 'alphabetical'",
+            "output.min_severity: 'fatal' is not one of 'error', 'warning', 'info'",
+        ],
+    )
+
+
+def test_tool_entries_and_profiles_are_validated() -> None:
+    """Tool entries and profile overlays use the same rules."""
+    data = {
+        "tools": {"ruff": {"enabeld": False}, "black": 1},
+        "profiles": {"ci": {"enforce": {"line_length": "88"}}},
+    }
+
+    assert_that(_messages(data)).is_equal_to(
+        [
+            "tools.ruff.enabeld: unknown key 'enabeld' (did you mean 'enabled'?)",
+            "tools.black: expected boolean or object, got integer",
+            "profiles.ci.enforce.line_length: expected integer, got string",
+        ],
+    )
+
+
+def test_pyproject_tool_options_are_allowed() -> None:
+    """Tool options in pyproject.toml sections are not unknown keys."""
+    data = {"tools": {"black": {"line_length": 100, "enabled": "no"}}}
+
+    assert_that(_messages(data, allow_tool_options=True)).is_equal_to(
+        ["tools.black.enabled: expected boolean, got string"],
+    )
+
+
+def test_validate_against_user_schema() -> None:
+    """User schemas can use required, const and length keywords."""
+    schema = {
+        "type": "object",
+        "required": ["title", "layout"],
+        "properties": {
+            "title": {"type": "string", "minLength": 3},
+            "kind": {"const": "post"},
+            "tags": {"type": "array", "maxItems": 1},
+            "weight": {"minimum": 0},
+        },
+    }
+    data = {"title": "Hi", "kind": "page", "tags": ["a", "b"], "weight": "x"}
+
+    problems = validate_against_schema(data, schema)
+
+    assert_that([f"{p.path}: {p.message}" for p in problems]).is_equal_to(
+        [
+            "<root>: missing required key 'layout'",
+            "title: 'Hi' is shorter than 3 characters",
+            "kind: 'page' is not 'post'",
+            "tags: expected at most 1 item(s), got 2",
+        ],
+    )
+
+
+def test_conflicts_are_reported_as_warnings(tmp_path: Path) -> None:
+    """Semantic conflicts the schema cannot express become warnings.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    data = {
+        "enforce": {"line_length": 88},
+        "execution": {"enabled_tools": ["ruff", "ruf"]},
+        "defaults": {"black": {"line-length": 100}},
+        "tools": {
+            "ruff": {"enabled": False},
+            "prettier": {"config_source": ".prettierrc"},
+        },
+    }
+
+    problems = find_config_conflicts(data, _KNOWN_TOOLS, base_dir=tmp_path)
+
+    assert_that({p.level for p in problems}).is_equal_to({"warning"})
+    assert_that([f"{p.path}: {p.message}" for p in problems]).is_equal_to(
+        [
+            "execution.enabled_tools[1]: unknown tool 'ruf' (did you mean 'ruff'?)",
+            "tools.ruff: 'ruff' is listed in execution.enabled_tools but "
+            "disabled here, so it will not run",
+            "defaults.black: line length 100 is overridden by "
+            "enforce.line_length=88",
+            "tools.prettier.config_source: file not found: .prettierrc",
+        ],
+    )
+
+
+def test_table_layout_is_validated() -> None:
+    """Persisted table columns and sort order are checked against the choices."""
+    valid = {"output": {"columns": ["file", "line", "code"], "table_sort_by": "line"}}
+    invalid = {"output": {"columns": ["file", "width"], "table_sort_by": "size"}}
+
+    assert_that(_messages(valid)).is_empty()
+    assert_that(_messages(invalid)).is_length(2)
@@ -152,6 +152,44 @@ def test_tool_config_loaders_return_correct_config(
     assert_that(result).is_instance_of(dict)
 
 
+def test_load_bandit_config_from_dot_bandit(tmp_path: Path) -> None:
+    """Verify bandit config falls back to the .bandit INI file.
+
+    Args:
+        tmp_path: Pytest temporary directory fixture.
+    """
+    (tmp_path / ".bandit").write_text(
+        "[bandit]\nexclude = tests/, venv\nskips = B101\nseverity = MEDIUM\n",
+    )
+
+    with patch(
+        "lintro.utils.config.load_tool_config_from_pyproject",
+        return_value={},
+    ):
+        config = load_bandit_config(base_dir=tmp_path)
+
+    assert_that(config).is_equal_to(
+        {"exclude_dirs": ["tests", "venv"], "skips": "B101", "severity": "MEDIUM"},
+    )
+
+
+def test_load_bandit_config_prefers_pyproject(tmp_path: Path) -> None:
+    """Verify [tool.bandit] in pyproject.toml wins over .bandit.
+
+    Args:
+        tmp_path: Pytest temporary directory fixture.
+    """
+    (tmp_path / ".bandit").write_text("[bandit]\nseverity = LOW\n")
+
+    with patch(
+        "lintro.utils.config.load_tool_config_from_pyproject",
+        return_value={"severity": "HIGH"},
+    ):
+        config = load_bandit_config(base_dir=tmp_path)
+
+    assert_that(config).is_equal_to({"severity": "HIGH"})
+
+
 # =============================================================================
 # Tests for load_mypy_config
 # =============================================================================
@@ -7,7 +7,9 @@ from unittest.mock import patch
 
 import pytest
 from assertpy import assert_that
+from pydantic import ValidationError
 
+from lintro.config.config_loader import _parse_execution_config
 from lintro.config.execution_config import ExecutionConfig, _get_default_max_workers
 
 
@@ -42,6 +44,48 @@ def test_execution_config_max_workers_uses_cpu_count() -> None:
     assert_that(config.max_workers).is_equal_to(expected)
 
 
+def test_execution_config_default_prune_dirs() -> None:
+    """Default prune_dirs skips dependency and build directories."""
+    config = ExecutionConfig()
+    assert_that(config.prune_dirs).contains("node_modules", "target", ".venv")
+
+
+def test_execution_config_set_prune_dirs() -> None:
+    """prune_dirs can replace the defaults."""
+    config = ExecutionConfig(prune_dirs=["bazel-*"])
+    assert_that(config.prune_dirs).is_equal_to(["bazel-*"])
+
+
+def test_execution_config_default_discovery_policy() -> None:
+    """Discovery honors .gitignore and follows links inside the repository."""
+    config = ExecutionConfig()
+    assert_that(config.gitignore).is_true()
+    assert_that(config.symlinks).is_equal_to("follow-within-repo")
+
+
+def test_execution_config_rejects_unknown_symlink_policy() -> None:
+    """symlinks only accepts the supported policies."""
+    with pytest.raises(ValidationError):
+        ExecutionConfig(symlinks="always")
+
+
+def test_parse_execution_config_reads_discovery_settings() -> None:
+    """prune_dirs, gitignore and symlinks are read from the config file."""
+    config = _parse_execution_config(
+        {"prune_dirs": ["out"], "gitignore": False, "symlinks": "follow"},
+    )
+    assert_that(config.prune_dirs).is_equal_to(["out"])
+    assert_that(config.gitignore).is_false()
+    assert_that(config.symlinks).is_equal_to("follow")
+
+
+def test_parse_execution_config_reads_code_blocks() -> None:
+    """Checking Markdown code blocks is off unless the config turns it on."""
+    assert_that(_parse_execution_config({}).code_blocks).is_false()
+    config = _parse_execution_config({"code_blocks": True})
+    assert_that(config.code_blocks).is_true()
+
+
 def test_execution_config_set_enabled_tools() -> None:
     """ExecutionConfig accepts enabled_tools list."""
     config = ExecutionConfig(enabled_tools=["ruff", "black"])
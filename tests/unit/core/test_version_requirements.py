@@ -442,10 +442,25 @@ def test_get_all_tool_versions(mock_run: MagicMock) -> None:
         "clippy",
         "rustfmt",
         "semgrep",
+        "checkov",
+        "ansible_lint",
+        "rubocop",
+        "ktlint",
+        "stylua",
+        "luacheck",
         "gitleaks",
         "shellcheck",
         "shfmt",
         "taplo",
+        "typos",
+        "vale",
+        "terraform_fmt",
+        "tflint",
+        "clang_format",
+        "clang_tidy",
+        "buf",
+        "gofmt",
+        "golangci_lint",
         "cargo_audit",
     }
 
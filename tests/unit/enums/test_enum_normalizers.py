@@ -26,6 +26,7 @@ from lintro.enums.semgrep_enums import (
     SemgrepSeverity,
     normalize_semgrep_severity,
 )
+from lintro.enums.table_sort import TableSort, normalize_table_sort
 from lintro.enums.tool_type import ToolType, normalize_tool_type
 from lintro.enums.tools_value import ToolsValue, normalize_tools_value
 
@@ -104,6 +105,15 @@ NORMALIZER_TEST_CASES = [
         "Invalid Semgrep severity",
         id="semgrep_severity",
     ),
+    pytest.param(
+        normalize_table_sort,
+        TableSort,
+        TableSort.SEVERITY,
+        "line",
+        "FILE",
+        "Unsupported sort order",
+        id="table_sort",
+    ),
 ]
 
 
@@ -43,6 +43,9 @@ from lintro.enums.severity_level import (
         pytest.param("FAILED", SeverityLevel.ERROR, id="failed"),
         pytest.param("SKIPPED", SeverityLevel.INFO, id="skipped"),
         pytest.param("PASSED", SeverityLevel.INFO, id="passed"),
+        # Biome
+        pytest.param("fatal", SeverityLevel.ERROR, id="fatal"),
+        pytest.param("information", SeverityLevel.INFO, id="information"),
     ],
 )
 def test_normalize_maps_alias_to_expected_level(
@@ -59,6 +59,15 @@ def test_normalize_tool_name_hyphenated() -> None:
     """normalize_tool_name converts hyphenated names to underscored ToolName."""
     assert_that(normalize_tool_name("astro-check")).is_equal_to(ToolName.ASTRO_CHECK)
     assert_that(normalize_tool_name("cargo-audit")).is_equal_to(ToolName.CARGO_AUDIT)
+    assert_that(normalize_tool_name("cargo-geiger")).is_equal_to(
+        ToolName.CARGO_GEIGER,
+    )
+    assert_that(normalize_tool_name("cargo-machete")).is_equal_to(
+        ToolName.CARGO_MACHETE,
+    )
+    assert_that(normalize_tool_name("cargo-outdated")).is_equal_to(
+        ToolName.CARGO_OUTDATED,
+    )
 
 
 def test_normalize_tool_name_passthrough() -> None:
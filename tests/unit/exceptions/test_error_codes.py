@@ -0,0 +1,78 @@
+"""Unit tests for the error code catalog."""
+
+from __future__ import annotations
+
+import subprocess
+
+import pytest
+from assertpy import assert_that
+
+from lintro.exceptions.errors import (
+    CONFIG_INVALID,
+    ERROR_CODES,
+    OUTPUT_PARSE_FAILED,
+    REPORT_WRITE_FAILED,
+    TOOL_EXECUTION_FAILED,
+    TOOL_TIMEOUT,
+    LintroError,
+    ParserError,
+    ToolTimeoutError,
+    error_code_for,
+    lookup_error,
+)
+
+
+def test_codes_are_sequential_and_keyed_by_code() -> None:
+    """Codes run from LINTRO001 without gaps and key their own entry."""
+    expected = [f"LINTRO{n:03d}" for n in range(1, len(ERROR_CODES) + 1)]
+
+    assert_that(list(ERROR_CODES)).is_equal_to(expected)
+    for code, error in ERROR_CODES.items():
+        assert_that(error.code).is_equal_to(code)
+
+
+@pytest.mark.parametrize("code", ["LINTRO012", "lintro012", "12", " LINTRO12 "])
+def test_lookup_error_accepts_code_variants(code: str) -> None:
+    """Case, the LINTRO prefix and leading zeros are optional.
+
+    Args:
+        code: Spelling of the code to look up.
+    """
+    assert_that(lookup_error(code)).is_equal_to(REPORT_WRITE_FAILED)
+
+
+@pytest.mark.parametrize("code", ["LINTRO999", "E501", ""])
+def test_lookup_error_returns_none_for_unknown_codes(code: str) -> None:
+    """Codes outside the catalog are not found.
+
+    Args:
+        code: Code to look up.
+    """
+    assert_that(lookup_error(code)).is_none()
+
+
+def test_format_prefixes_the_code() -> None:
+    """Console messages start with the code."""
+    assert_that(CONFIG_INVALID.format("bad key")).is_equal_to(
+        "Error: [LINTRO001] bad key",
+    )
+
+
+def test_error_code_for_uses_exception_code_or_default() -> None:
+    """Lintro exceptions report their own code; others get the default."""
+    assert_that(error_code_for(ParserError("x"), TOOL_EXECUTION_FAILED)).is_equal_to(
+        OUTPUT_PARSE_FAILED,
+    )
+    assert_that(
+        error_code_for(ToolTimeoutError("x"), TOOL_EXECUTION_FAILED),
+    ).is_equal_to(TOOL_TIMEOUT)
+    assert_that(error_code_for(LintroError("x"), TOOL_EXECUTION_FAILED)).is_equal_to(
+        TOOL_EXECUTION_FAILED,
+    )
+    assert_that(error_code_for(OSError("x"), TOOL_EXECUTION_FAILED)).is_equal_to(
+        TOOL_EXECUTION_FAILED,
+    )
+    timeout = subprocess.TimeoutExpired(cmd=["eslint"], timeout=30)
+    assert_that(error_code_for(timeout, TOOL_EXECUTION_FAILED)).is_equal_to(
+        TOOL_TIMEOUT,
+    )
@@ -62,3 +62,19 @@ def test_grid_style_empty_columns_handles_gracefully(grid_style: GridStyle) -> N
     result = grid_style.format([], [["data"]])
 
     assert_that(result).is_not_none()
+
+
+def test_grid_style_lists_diff_previews_below_table(grid_style: GridStyle) -> None:
+    """GridStyle prints each diff preview under its location after the table.
+
+    Args:
+        grid_style: The GridStyle formatter instance.
+    """
+    result = grid_style.format(
+        ["File"],
+        [["src/main.rs"]],
+        previews=[("src/main.rs:3-5", "-fn main(){}\n+fn main() {}\n")],
+    )
+
+    assert_that(result).contains("Diff previews:\n\nsrc/main.rs:3-5\n-fn main(){}")
+    assert_that(result).ends_with("+fn main() {}")
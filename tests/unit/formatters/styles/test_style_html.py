@@ -74,3 +74,19 @@ def test_html_style_row_shorter_than_columns(html_style: HtmlStyle) -> None:
     result = html_style.format(STANDARD_COLUMNS, [["src/main.py"]])
 
     assert_that(result).contains("<td>src/main.py</td>")
+
+
+def test_html_style_escapes_diff_previews(html_style: HtmlStyle) -> None:
+    """HtmlStyle lists diff previews in escaped collapsible blocks.
+
+    Args:
+        html_style: The HtmlStyle formatter instance.
+    """
+    result = html_style.format(
+        ["File"],
+        [["src/main.rs"]],
+        previews=[("src/main.rs:3", "-let v: Vec<u8>=x;\n+let v: Vec<u8> = x;")],
+    )
+
+    assert_that(result).contains("<details><summary>src/main.rs:3</summary>")
+    assert_that(result).contains("<pre>-let v: Vec&lt;u8&gt;=x;")
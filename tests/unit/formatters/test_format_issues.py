@@ -9,16 +9,23 @@ from __future__ import annotations
 import pytest
 from assertpy import assert_that
 
-from lintro.enums.display_column import STANDARD_COLUMNS, DisplayColumn
+from lintro.enums.display_column import (
+    STANDARD_COLUMNS,
+    DisplayColumn,
+    parse_columns,
+)
+from lintro.enums.severity_level import SeverityLevel
 from lintro.formatters.formatter import (
     format_issues,
     format_issues_with_sections,
+    sort_issues,
 )
 from lintro.parsers.bandit.bandit_issue import BanditIssue
 from lintro.parsers.base_issue import BaseIssue
 from lintro.parsers.black.black_issue import BlackIssue
 from lintro.parsers.ruff.ruff_format_issue import RuffFormatIssue
 from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.parsers.rustfmt.rustfmt_issue import RustfmtIssue
 
 # =============================================================================
 # Tests for STANDARD_COLUMNS constant
@@ -431,3 +438,206 @@ def test_format_issues_with_sections_empty_list_returns_no_issues_message() -> N
     result = format_issues_with_sections([], group_by_fixable=True)
 
     assert_that(result).is_equal_to("No issues found.")
+
+
+# =============================================================================
+# Tests for group_by
+# =============================================================================
+
+
+@pytest.fixture
+def grouped_issues() -> list[RuffIssue]:
+    """Provide issues spread over two rules and two directories.
+
+    Returns:
+        list[RuffIssue]: Two F401 issues under src/ and one E501 at the top.
+    """
+    return [
+        RuffIssue(file="b.py", line=2, code="E501", message="Line too long"),
+        RuffIssue(file="src/a.py", line=1, code="F401", message="Unused import"),
+        RuffIssue(file="src/c.py", line=3, code="F401", message="Unused import"),
+    ]
+
+
+def test_format_issues_group_by_rule_orders_largest_group_first(
+    grouped_issues: list[RuffIssue],
+) -> None:
+    """Rule groups are ordered by size and each ends with a subtotal.
+
+    Args:
+        grouped_issues: Issues to format.
+    """
+    result = format_issues(grouped_issues, output_format="markdown", group_by="rule")
+
+    f401 = result.index("Subtotal F401: 2 issues")
+    e501 = result.index("Subtotal E501: 1 issue")
+    assert_that(result.index("src/a.py")).is_less_than(f401)
+    assert_that(f401).is_less_than(result.index("b.py |"))
+    assert_that(result.index("b.py |")).is_less_than(e501)
+
+
+def test_format_issues_group_by_dir(grouped_issues: list[RuffIssue]) -> None:
+    """Directory groups use the parent directory, ./ for top-level files.
+
+    Args:
+        grouped_issues: Issues to format.
+    """
+    result = format_issues(grouped_issues, output_format="plain", group_by="dir")
+
+    assert_that(result).contains("Subtotal src/: 2 issues")
+    assert_that(result).contains("Subtotal ./: 1 issue")
+
+
+def test_format_issues_group_by_severity_and_tool(
+    grouped_issues: list[RuffIssue],
+) -> None:
+    """Severity groups use the lower-case severity, tool groups the tool.
+
+    Args:
+        grouped_issues: Issues to format.
+    """
+    by_severity = format_issues(
+        grouped_issues,
+        output_format="markdown",
+        group_by="severity",
+    )
+    by_tool = format_issues(
+        grouped_issues,
+        output_format="markdown",
+        group_by="tool",
+        tool_name="ruff",
+    )
+
+    assert_that(by_severity).contains("Subtotal warning: 3 issues")
+    assert_that(by_tool).contains("Subtotal ruff: 3 issues")
+
+
+def test_format_issues_group_by_leaves_json_flat(
+    grouped_issues: list[RuffIssue],
+) -> None:
+    """Machine-readable formats are never given subtotal rows.
+
+    Args:
+        grouped_issues: Issues to format.
+    """
+    result = format_issues(grouped_issues, output_format="json", group_by="rule")
+
+    assert_that(result).does_not_contain("Subtotal")
+
+
+# =============================================================================
+# Tests for columns and sort_by
+# =============================================================================
+
+
+def test_parse_columns_keeps_order_and_rejects_unknown_names() -> None:
+    """Column names are case-insensitive and kept in the order given."""
+    assert_that(parse_columns("line, FILE")).is_equal_to(
+        [DisplayColumn.LINE, DisplayColumn.FILE],
+    )
+    with pytest.raises(ValueError, match="Unknown column: width"):
+        parse_columns("file,width")
+    with pytest.raises(ValueError, match="At least one column"):
+        parse_columns(" , ")
+
+
+def test_format_issues_with_selected_columns(
+    grouped_issues: list[RuffIssue],
+) -> None:
+    """Only the selected columns are rendered.
+
+    Args:
+        grouped_issues: Issues to format.
+    """
+    result = format_issues(
+        grouped_issues,
+        output_format="markdown",
+        columns=parse_columns("file,line,code"),
+    )
+
+    assert_that(result.splitlines()[0]).is_equal_to("| File | Line | Code |")
+    assert_that(result).does_not_contain("Unused import")
+
+
+@pytest.mark.parametrize(
+    ("sort_by", "expected"),
+    [
+        ("file", ["b.py", "src/a.py", "src/c.py"]),
+        ("line", ["src/a.py", "b.py", "src/c.py"]),
+        ("code", ["b.py", "src/a.py", "src/c.py"]),
+    ],
+)
+def test_sort_issues(
+    grouped_issues: list[RuffIssue],
+    sort_by: str,
+    expected: list[str],
+) -> None:
+    """Issues are ordered by the requested field.
+
+    Args:
+        grouped_issues: Issues to sort.
+        sort_by: Sort order.
+        expected: Files in the expected order.
+    """
+    result = sort_issues(grouped_issues, sort_by)
+
+    assert_that([issue.file for issue in result]).is_equal_to(expected)
+
+
+def test_sort_issues_by_severity_lists_errors_first() -> None:
+    """Errors come before warnings when sorting by severity."""
+    issues = [
+        RuffIssue(file="a.py", line=1, code="E501", message="Line too long"),
+        RuffIssue(
+            file="b.py",
+            line=1,
+            code="E999",
+            message="Syntax error",
+            severity_override=SeverityLevel.ERROR,
+        ),
+    ]
+
+    result = sort_issues(issues, "severity")
+
+    assert_that([issue.code for issue in result]).is_equal_to(["E999", "E501"])
+
+
+def test_format_issues_sort_by_leaves_json_in_reported_order(
+    grouped_issues: list[RuffIssue],
+) -> None:
+    """Machine-readable formats keep the order the tool reported.
+
+    Args:
+        grouped_issues: Issues to format.
+    """
+    result = format_issues(grouped_issues, output_format="json", sort_by="line")
+
+    assert_that(result.index("b.py")).is_less_than(result.index("src/a.py"))
+
+
+@pytest.mark.parametrize(
+    ("output_format", "shown"),
+    [("grid", True), ("html", True), ("markdown", False), ("json", False)],
+)
+def test_format_issues_lists_diff_previews_in_grid_and_html(
+    output_format: str,
+    shown: bool,
+) -> None:
+    """Grid and HTML tables are followed by the diff each issue carries.
+
+    Args:
+        output_format: Output format to render.
+        shown: Whether the format lists the previews.
+    """
+    issue = RustfmtIssue(
+        file="src/main.rs",
+        line=3,
+        end_line=5,
+        message="Would reformat lines 3-5",
+        diff="-fn main(){}\n+fn main() {}",
+    )
+
+    result = format_issues([issue], output_format=output_format)
+
+    assert_that("src/main.rs:3-5" in result).is_equal_to(shown)
+    assert_that("+fn main() {}" in result).is_equal_to(shown)
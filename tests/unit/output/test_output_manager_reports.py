@@ -7,6 +7,7 @@ from pathlib import Path
 import pytest
 from assertpy import assert_that
 
+from lintro.utils.effort import estimate_effort
 from lintro.utils.output import OutputManager
 
 
@@ -67,3 +68,64 @@ def test_output_manager_writes_reports(
     assert_that((om.run_dir / "report.md").exists()).is_true()
     assert_that((om.run_dir / "report.html").exists()).is_true()
     assert_that((om.run_dir / "summary.csv").exists()).is_true()
+
+
+def test_output_manager_reports_effort_by_file(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Reports include lint debt and can list costly files first.
+
+    Args:
+        tmp_path: Temporary directory for placing report outputs.
+        monkeypatch: Pytest monkeypatch to set output directory.
+    """
+    monkeypatch.setenv("LINTRO_LOG_DIR", str(tmp_path))
+    om = OutputManager()
+    issues = [
+        DummyIssue(file="a.py", line=1, code="E501", message="long line"),
+        DummyIssue(file="b.py", line=1, code="F401", message="unused import"),
+        DummyIssue(file="b.py", line=2, code="F401", message="unused import"),
+    ]
+    results = [DummyResult(name="ruff", issues_count=3, issues=issues)]
+    effort = estimate_effort(results, {"F401": 60})
+
+    om.write_reports_from_results(
+        results=results,  # type: ignore[arg-type]
+        effort=effort,
+        sort_by_effort=True,
+    )
+
+    markdown = (om.run_dir / "report.md").read_text()
+    html = (om.run_dir / "report.html").read_text()
+    assert_that(markdown).contains("~2 hours of lint debt")
+    assert_that(markdown).contains("| b.py | 2 | ~2 hours |")
+    assert_that(markdown.index("| b.py | 1 | F401")).is_less_than(
+        markdown.index("| a.py | 1 | E501"),
+    )
+    assert_that(html).contains("<h2>Effort by File</h2>")
+
+
+def test_html_report_shows_generated_time_and_durations(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """The HTML report states when it was generated and how long tools ran.
+
+    Args:
+        tmp_path: Temporary directory for placing report outputs.
+        monkeypatch: Pytest monkeypatch to set output directory.
+    """
+    monkeypatch.setenv("LINTRO_LOG_DIR", str(tmp_path))
+    om = OutputManager()
+    timed = DummyResult(name="ruff", issues_count=0)
+    timed.duration_ms = 1250  # type: ignore[attr-defined]
+    results = [timed, DummyResult(name="mypy", issues_count=0)]
+
+    om.write_reports_from_results(results=results)  # type: ignore[arg-type]
+
+    html = (om.run_dir / "report.html").read_text()
+    assert_that(html).matches(r"<p>Generated \d{4}-\d{2}-\d{2} [\d:]{8} UTC</p>")
+    assert_that(html).contains("<td>ruff</td><td>0</td><td>1.2s</td>")
+    assert_that(html).contains("<td>mypy</td><td>0</td><td>-</td>")
+
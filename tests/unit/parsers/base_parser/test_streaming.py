@@ -0,0 +1,70 @@
+"""Tests for parse_json_line and IssueCollector."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+import pytest
+from assertpy import assert_that
+
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.base_parser import IssueCollector, parse_json_line
+
+
+@dataclass
+class StreamedIssue(BaseIssue):
+    """Simple test issue for IssueCollector tests."""
+
+    pass
+
+
+@pytest.mark.parametrize(
+    ("line", "expected"),
+    [
+        ('{"file": "a.rs"}', {"file": "a.rs"}),
+        ('  \x1b[1m{"file": "a.rs"}\x1b[0m  ', {"file": "a.rs"}),
+        ("   Compiling demo v0.1.0", None),
+        ('{"file": ', None),
+        ("[1, 2]", None),
+        ("", None),
+    ],
+)
+def test_parse_json_line(line: str, expected: dict[str, str] | None) -> None:
+    """Only lines holding a JSON object are decoded.
+
+    Args:
+        line: Line of output.
+        expected: Decoded object, or None.
+    """
+    assert_that(parse_json_line(line)).is_equal_to(expected)
+
+
+def test_issue_collector_parses_lines_as_they_arrive() -> None:
+    """Issues are collected line by line, skipping lines that fail to parse."""
+
+    def parse_line(line: str) -> StreamedIssue | None:
+        """Parse a line naming a file.
+
+        Args:
+            line: Line of output.
+
+        Returns:
+            StreamedIssue | None: Issue of the file, or None for blank lines.
+
+        Raises:
+            ValueError: If the line is malformed.
+        """
+        if not line:
+            return None
+        if line == "bad":
+            raise ValueError("malformed line")
+        return StreamedIssue(file=line)
+
+    collector = IssueCollector(parse_line, tool_name="test_tool")
+
+    for line in ["a.py", "", "bad", "b.py"]:
+        collector(line)
+
+    assert_that([issue.file for issue in collector.issues]).is_equal_to(
+        ["a.py", "b.py"],
+    )
@@ -41,3 +41,19 @@ def test_gitleaks_issue_message_without_secret() -> None:
 
     assert_that(issue.message).is_equal_to("[test-rule] Test Description")
     assert_that(issue.message).does_not_contain("REDACTED")
+
+
+def test_gitleaks_issue_redacts_secret_in_match() -> None:
+    """The secret is masked in the matched text and never shown."""
+    issue = GitleaksIssue(  # nosec B106 - test data for secret detection
+        file="settings.py",
+        line=3,
+        rule_id="generic-api-key",
+        description="Generic API Key",
+        secret="s3cr3tvalue123",
+        match='api_key = "s3cr3tvalue123"',
+    )
+
+    assert_that(issue.secret).is_equal_to("REDACTED")
+    assert_that(issue.match).is_equal_to('api_key = "REDACTED"')
+    assert_that(str(issue.to_display_row())).does_not_contain("s3cr3tvalue123")
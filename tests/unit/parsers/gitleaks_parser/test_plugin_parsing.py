@@ -59,8 +59,8 @@ def test_gitleaks_check_parses_output(
     def fake_run(
         cmd: list[str],
         capture_output: bool,
-        text: bool,
         timeout: int,
+        text: bool = False,
         **kwargs: Any,
     ) -> SimpleNamespace:
         # Handle version check calls (check for --version flag)
@@ -104,8 +104,8 @@ def test_gitleaks_check_handles_no_secrets(
     def fake_run(
         cmd: list[str],
         capture_output: bool,
-        text: bool,
         timeout: int,
+        text: bool = False,
         **kwargs: Any,
     ) -> SimpleNamespace:
         # Handle version check calls (check for --version flag)
@@ -143,8 +143,8 @@ def test_gitleaks_check_handles_unparseable_output(
     def fake_run(
         cmd: list[str],
         capture_output: bool,
-        text: bool,
         timeout: int,
+        text: bool = False,
         **kwargs: Any,
     ) -> SimpleNamespace:
         # Handle version check calls (check for --version flag)
@@ -1,7 +1,7 @@
 """Unit tests for the Actionlint output parser.
 
-These tests validate that the parser handles empty output and typical
-``file:line:col: level: message [CODE]`` lines, producing structured issues.
+These tests validate that the parser handles empty output, the JSON report and
+typical ``file:line:col: level: message [CODE]`` lines, producing structured issues.
 """
 
 from assertpy import assert_that
@@ -39,3 +39,38 @@ def test_parse_actionlint_ansi_codes_stripped() -> None:
     assert_that(len(issues)).is_equal_to(1)
     assert_that(issues[0].file).is_equal_to("workflow.yml")
     assert_that(issues[0].code).is_equal_to("AL100")
+
+
+def test_parse_actionlint_json() -> None:
+    """Parse the JSON report, using the error kind as the issue code."""
+    output = (
+        '[{"message":"property \\"foo\\" is not defined","filepath":'
+        '".github/workflows/ci.yml","line":7,"column":14,"kind":"expression",'
+        '"snippet":"${{ foo }}","end_column":20},'
+        '{"message":"shellcheck reported issue in this script: SC2086:info:1:6",'
+        '"filepath":".github/workflows/ci.yml","line":9,"column":9,'
+        '"kind":"shellcheck"}]\n'
+    )
+
+    issues = parse_actionlint_output(output)
+
+    assert_that([i.code for i in issues]).is_equal_to(["expression", "shellcheck"])
+    assert_that(issues[0].file).is_equal_to(".github/workflows/ci.yml")
+    assert_that(issues[0].line).is_equal_to(7)
+    assert_that(issues[0].column).is_equal_to(14)
+    assert_that(issues[0].level).is_equal_to("error")
+    assert_that(issues[0].message).contains('"foo" is not defined')
+
+
+def test_parse_actionlint_empty_json() -> None:
+    """An empty JSON report yields no issues."""
+    assert_that(parse_actionlint_output("[]\n")).is_empty()
+
+
+def test_parse_actionlint_windows_paths() -> None:
+    """Keep the drive letter of Windows paths in the file."""
+    out = "C:\\repo\\.github\\workflows\\ci.yml:10:5: error: unexpected key [AL100]"
+    issues = parse_actionlint_output(out)
+    assert_that(len(issues)).is_equal_to(1)
+    assert_that(issues[0].file).is_equal_to("C:\\repo\\.github\\workflows\\ci.yml")
+    assert_that(issues[0].line).is_equal_to(10)
@@ -0,0 +1,70 @@
+"""Unit tests for the ansible-lint output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.ansible_lint.ansible_lint_parser import parse_ansible_lint_output
+
+REPORT = [
+    {
+        "type": "issue",
+        "check_name": "name[missing]",
+        "categories": ["idiom"],
+        "url": "https://ansible.readthedocs.io/projects/lint/rules/name/",
+        "severity": "major",
+        "description": "All tasks should be named.",
+        "fingerprint": "abc",
+        "location": {"path": "site.yml", "lines": {"begin": 4}},
+    },
+    {
+        "type": "issue",
+        "check_name": "yaml[truthy]",
+        "categories": ["formatting", "yaml"],
+        "severity": "minor",
+        "description": "Truthy value should be one of [false, true]",
+        "location": {
+            "path": "roles/web/tasks/main.yml",
+            "positions": {"begin": {"line": 7, "column": 12}},
+        },
+    },
+]
+
+
+def test_parse_ansible_lint_issues() -> None:
+    """Code Climate issues keep their rule, location and severity."""
+    output = (
+        "WARNING  Listing 2 violation(s) that are fatal\n"
+        f"{json.dumps(REPORT)}\n"
+        "Failed: 2 failure(s), 0 warning(s) on 3 files.\n"
+    )
+
+    issues = parse_ansible_lint_output(output)
+
+    assert issues is not None
+    assert_that(issues).is_length(2)
+    first, second = issues
+    assert_that(first.file).is_equal_to("site.yml")
+    assert_that((first.line, first.column)).is_equal_to((4, 0))
+    assert_that(first.code).is_equal_to("name[missing]")
+    assert_that(first.message).is_equal_to("All tasks should be named.")
+    assert_that(first.categories).is_equal_to(["idiom"])
+    assert_that(first.url).contains("rules/name")
+    assert_that(first.get_severity()).is_equal_to(SeverityLevel.ERROR)
+    assert_that((second.line, second.column)).is_equal_to((7, 12))
+    assert_that(second.get_severity()).is_equal_to(SeverityLevel.WARNING)
+
+
+def test_parse_ansible_lint_empty_report() -> None:
+    """An empty list means no violations."""
+    assert_that(parse_ansible_lint_output("[]\n")).is_equal_to([])
+
+
+def test_parse_ansible_lint_without_report() -> None:
+    """Output without a JSON list returns None."""
+    assert_that(parse_ansible_lint_output(None)).is_none()
+    assert_that(parse_ansible_lint_output("")).is_none()
+    assert_that(
+        parse_ansible_lint_output("CRITICAL Invalid profile name 'strict'\n"),
+    ).is_none()
@@ -0,0 +1,146 @@
+"""Unit tests for Biome parser functionality."""
+
+from __future__ import annotations
+
+import json
+
+from assertpy import assert_that
+
+from lintro.parsers.biome.biome_parser import parse_biome_output
+
+
+def _report(*diagnostics: dict[str, object]) -> str:
+    """Build a Biome JSON report.
+
+    Args:
+        *diagnostics: Diagnostics to include.
+
+    Returns:
+        str: Report as printed by ``biome check --reporter=json``.
+    """
+    return json.dumps(
+        {
+            "summary": {"errors": len(diagnostics), "warnings": 0},
+            "diagnostics": list(diagnostics),
+            "command": "check",
+        },
+    )
+
+
+def test_parse_biome_output_empty() -> None:
+    """Empty output yields no issues."""
+    assert_that(parse_biome_output("")).is_empty()
+
+
+def test_parse_biome_output_malformed_json() -> None:
+    """Malformed JSON yields no issues."""
+    assert_that(parse_biome_output("{invalid json")).is_empty()
+
+
+def test_parse_biome_output_lint_diagnostic() -> None:
+    """A lint diagnostic's byte span is converted to a line and column."""
+    source = "const a = 1;\nif (a == 1) {\n  debugger;\n}\n"
+    output = _report(
+        {
+            "category": "lint/suspicious/noDebugger",
+            "severity": "error",
+            "description": "This is an unexpected use of the debugger statement.",
+            "location": {
+                "path": {"file": "src/app.ts"},
+                "span": [source.index("debugger"), source.index("debugger") + 9],
+                "sourceCode": source,
+            },
+            "tags": ["fixable"],
+        },
+    )
+
+    issues = parse_biome_output(output)
+
+    assert_that(issues).is_length(1)
+    issue = issues[0]
+    assert_that(issue.file).is_equal_to("src/app.ts")
+    assert_that(issue.line).is_equal_to(3)
+    assert_that(issue.column).is_equal_to(3)
+    assert_that(issue.code).is_equal_to("lint/suspicious/noDebugger")
+    assert_that(issue.severity).is_equal_to("error")
+    assert_that(issue.fixable).is_true()
+    assert_that(issue.message).contains("debugger statement")
+
+
+def test_parse_biome_output_span_counts_utf8_bytes() -> None:
+    """Columns count characters although spans count UTF-8 bytes."""
+    source = 'const s = "é"; var x;\n'
+    offset = len(source[: source.index("var")].encode("utf-8"))
+    output = _report(
+        {
+            "category": "lint/style/noVar",
+            "severity": "warning",
+            "description": "Use let or const instead of var.",
+            "location": {
+                "path": {"file": "a.js"},
+                "span": [offset, offset + 3],
+                "sourceCode": source,
+            },
+            "tags": [],
+        },
+    )
+
+    issue = parse_biome_output(output)[0]
+
+    assert_that(issue.line).is_equal_to(1)
+    assert_that(issue.column).is_equal_to(source.index("var") + 1)
+    assert_that(issue.fixable).is_false()
+
+
+def test_parse_biome_output_format_diagnostic_without_span() -> None:
+    """Format diagnostics cover the whole file and default to line 1."""
+    output = _report(
+        {
+            "category": "format",
+            "severity": "error",
+            "description": "File content differs from formatting output",
+            "location": {"path": {"file": "package.json"}, "span": None},
+            "tags": ["fixable"],
+        },
+    )
+
+    issue = parse_biome_output(output)[0]
+
+    assert_that(issue.code).is_equal_to("format")
+    assert_that(issue.line).is_equal_to(1)
+    assert_that(issue.column).is_equal_to(1)
+
+
+def test_parse_biome_output_skips_diagnostics_without_file() -> None:
+    """Diagnostics not tied to a file, such as config errors, are skipped."""
+    output = _report(
+        {
+            "category": "configuration",
+            "severity": "error",
+            "description": "The configuration file is invalid.",
+            "location": {"path": None},
+        },
+    )
+
+    assert_that(parse_biome_output(output)).is_empty()
+
+
+def test_parse_biome_output_ignores_text_around_json() -> None:
+    """Text printed around the JSON report is ignored."""
+    output = (
+        "The --reporter=json option is experimental.\n"
+        + _report(
+            {
+                "category": "lint/style/useConst",
+                "severity": "warning",
+                "description": "This let declares a variable assigned once.",
+                "location": {"path": {"file": "b.js"}},
+            },
+        )
+        + "\n"
+    )
+
+    issues = parse_biome_output(output)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].severity).is_equal_to("warning")
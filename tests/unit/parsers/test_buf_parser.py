@@ -0,0 +1,83 @@
+"""Unit tests for the buf output parser."""
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.buf.buf_parser import parse_buf_format_output, parse_buf_output
+
+LINT_OUTPUT = (
+    '{"path":"acme/v1/pet.proto","start_line":12,"start_column":3,'
+    '"end_line":12,"end_column":20,"type":"FIELD_LOWER_SNAKE_CASE",'
+    '"message":"Field name \\"petID\\" should be lower_snake_case."}\n'
+    '{"path":"acme/v1/store.proto","start_line":4,"start_column":8,'
+    '"end_line":4,"end_column":8,"type":"COMPILE",'
+    '"message":"syntax error: unexpected \'}\'"}\n'
+)
+
+FORMAT_DIFF = """diff -u acme/v1/pet.proto.orig acme/v1/pet.proto
+--- acme/v1/pet.proto.orig\t2026-10-15 12:00:00
++++ acme/v1/pet.proto\t2026-10-15 12:00:00
+@@ -1,4 +1,4 @@
+ syntax = "proto3";
+-package   acme.v1;
++package acme.v1;
+ 
+ message Pet {}
+"""
+
+
+def test_parse_buf_lint_findings() -> None:
+    """Lint findings keep their rule, location and range."""
+    report = parse_buf_output(LINT_OUTPUT)
+
+    assert_that(report.errors).is_empty()
+    assert_that(report.issues).is_length(2)
+    issue = report.issues[0]
+    assert_that(issue.file).is_equal_to("acme/v1/pet.proto")
+    assert_that((issue.line, issue.column)).is_equal_to((12, 3))
+    assert_that((issue.end_line, issue.end_column)).is_equal_to((12, 20))
+    assert_that(issue.code).is_equal_to("FIELD_LOWER_SNAKE_CASE")
+    assert_that(issue.fixable).is_false()
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.WARNING)
+    assert_that(report.issues[1].get_severity()).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_buf_breaking_findings_are_errors() -> None:
+    """Breaking changes are reported as errors."""
+    output = (
+        '{"path":"acme/v1/pet.proto","start_line":8,"start_column":1,'
+        '"type":"FIELD_NO_DELETE",'
+        '"message":"Previously present field \\"2\\" was deleted."}'
+    )
+
+    report = parse_buf_output(output, breaking=True)
+
+    assert_that(report.issues[0].code).is_equal_to("FIELD_NO_DELETE")
+    assert_that(report.issues[0].end_line).is_none()
+    assert_that(report.issues[0].get_severity()).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_buf_collects_errors() -> None:
+    """Lines that are not findings are kept as errors."""
+    report = parse_buf_output("Failure: decode buf.yaml: unknown field \"lnt\"\n")
+
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_equal_to(
+        ['Failure: decode buf.yaml: unknown field "lnt"'],
+    )
+    assert_that(parse_buf_output(None).issues).is_empty()
+
+
+def test_parse_buf_format_diff() -> None:
+    """Each format hunk becomes a fixable FORMAT issue."""
+    issues = parse_buf_format_output(FORMAT_DIFF)
+
+    assert_that(issues).is_length(1)
+    issue = issues[0]
+    assert_that(issue.file).is_equal_to("acme/v1/pet.proto")
+    assert_that(issue.line).is_equal_to(2)
+    assert_that(issue.code).is_equal_to("FORMAT")
+    assert_that(issue.fixable).is_true()
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.INFO)
+    assert_that(issue.diff).contains("+package acme.v1;")
+    assert_that(parse_buf_format_output("")).is_empty()
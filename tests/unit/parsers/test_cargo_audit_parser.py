@@ -5,7 +5,10 @@ from __future__ import annotations
 import pytest
 from assertpy import assert_that
 
-from lintro.parsers.cargo_audit.cargo_audit_parser import parse_cargo_audit_output
+from lintro.parsers.cargo_audit.cargo_audit_parser import (
+    cvss_base_score,
+    parse_cargo_audit_output,
+)
 
 
 @pytest.mark.parametrize(
@@ -222,3 +225,59 @@ Done."""
 
     assert_that(result).is_length(1)
     assert_that(result[0].advisory_id).is_equal_to("RUSTSEC-2021-0001")
+
+
+def test_parse_cargo_audit_output_patched_versions_and_cvss() -> None:
+    """Parser reads patched versions and rates CVSS-only advisories."""
+    output = """{
+        "vulnerabilities": {
+            "count": 2,
+            "list": [
+                {
+                    "advisory": {
+                        "id": "RUSTSEC-2023-0001",
+                        "title": "Remote code execution",
+                        "cvss": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+                    },
+                    "versions": {"patched": [">=1.2.4, <1.3.0", ">=1.3.1"]},
+                    "package": {"name": "crate-a", "version": "1.2.3"}
+                },
+                {
+                    "advisory": {"id": "RUSTSEC-2023-0002", "title": "Abandoned"},
+                    "versions": {"patched": []},
+                    "package": {"name": "crate-b", "version": "0.1.0"}
+                }
+            ]
+        }
+    }"""
+    result = parse_cargo_audit_output(output)
+
+    assert_that(result[0].severity).is_equal_to("CRITICAL")
+    assert_that(result[0].patched_versions).is_equal_to([">=1.2.4, <1.3.0", ">=1.3.1"])
+    assert_that(result[0].message).ends_with(
+        "(upgrade to >=1.2.4, <1.3.0 or >=1.3.1)",
+    )
+    assert_that(result[1].severity).is_equal_to("UNKNOWN")
+    assert_that(result[1].message).ends_with("(no patched version)")
+
+
+@pytest.mark.parametrize(
+    ("vector", "score"),
+    [
+        ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 9.8),
+        ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H", 10.0),
+        ("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:H", 7.5),
+        ("CVSS:3.1/AV:L/AC:H/PR:L/UI:N/S:U/C:L/I:N/A:N", 2.5),
+        ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N", 0.0),
+        ("CVSS:3.1/AV:N/AC:L", None),
+        ("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N", None),
+    ],
+)
+def test_cvss_base_score(vector: str, score: float | None) -> None:
+    """Base scores match the CVSS v3 calculator.
+
+    Args:
+        vector: CVSS vector.
+        score: Expected base score, or None for unusable vectors.
+    """
+    assert_that(cvss_base_score(vector)).is_equal_to(score)
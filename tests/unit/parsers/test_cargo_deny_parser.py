@@ -2,10 +2,15 @@
 
 from __future__ import annotations
 
+from pathlib import Path
+
 import pytest
 from assertpy import assert_that
 
-from lintro.parsers.cargo_deny.cargo_deny_parser import parse_cargo_deny_output
+from lintro.parsers.cargo_deny.cargo_deny_parser import (
+    check_for_code,
+    parse_cargo_deny_output,
+)
 
 
 def test_parse_cargo_deny_output_single_diagnostic() -> None:
@@ -107,3 +112,132 @@ def test_parse_cargo_deny_output_crate_with_version() -> None:
     assert_that(issues).is_length(1)
     assert_that(issues[0].crate_name).is_equal_to("serde")
     assert_that(issues[0].crate_version).is_equal_to("1.0.150")
+
+
+def test_parse_cargo_deny_output_graph_crate_and_check() -> None:
+    """Take the crate from the dependency graph and name the check."""
+    output = (
+        '{"type":"diagnostic","fields":{"severity":"error","code":"rejected",'
+        '"message":"failed to satisfy license requirements","labels":[],'
+        '"graphs":[{"Krate":{"name":"gpl-crate","version":"0.2.0"}}]}}\n'
+        '{"type":"diagnostic","fields":{"severity":"error","code":"vulnerability",'
+        '"message":"Use-after-free","advisory":{"id":"RUSTSEC-2024-0001"},'
+        '"graphs":[{"Krate":{"name":"time","version":"0.1.45"}}]}}'
+    )
+    issues = parse_cargo_deny_output(output)
+    assert_that([issue.check for issue in issues]).is_equal_to(
+        ["licenses", "advisories"],
+    )
+    assert_that(issues[0].crate_name).is_equal_to("gpl-crate")
+    assert_that(issues[0].crate_version).is_equal_to("0.2.0")
+    assert_that(issues[1].advisory_id).is_equal_to("RUSTSEC-2024-0001")
+
+
+@pytest.mark.parametrize(
+    ("code", "check"),
+    [
+        ("L001", "licenses"),
+        ("D001", "bans"),
+        ("duplicate", "bans"),
+        ("source-not-allowed", "sources"),
+        ("yanked", "advisories"),
+        ("something-new", None),
+    ],
+)
+def test_check_for_code(code: str, check: str | None) -> None:
+    """Map diagnostic codes to the check that emits them.
+
+    Args:
+        code: Diagnostic code.
+        check: Expected check.
+    """
+    assert_that(check_for_code(code)).is_equal_to(check)
+
+
+@pytest.fixture
+def cargo_project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Create a Cargo project with a manifest, lock file and deny.toml.
+
+    Args:
+        tmp_path: Temporary directory for the project.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: Project root, which is also the working directory.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        "[package]\n"
+        'name = "app"\n'
+        'version = "0.1.0"\n'
+        "\n"
+        "[dependencies]\n"
+        'serde = "1"\n'
+        'json = { package = "serde_json", version = "1" }\n'
+        "\n"
+        "[dev-dependencies.insta]\n"
+        'version = "1"\n',
+    )
+    (tmp_path / "Cargo.lock").write_text(
+        "version = 3\n"
+        "\n"
+        "[[package]]\n"
+        'name = "itoa"\n'
+        'version = "0.4.8"\n'
+        "\n"
+        "[[package]]\n"
+        'name = "itoa"\n'
+        'version = "1.0.9"\n',
+    )
+    (tmp_path / "deny.toml").write_text("[bans]\nskip = []\n")
+    monkeypatch.chdir(tmp_path)
+    return tmp_path
+
+
+@pytest.mark.parametrize(
+    ("crate", "location"),
+    [
+        pytest.param("serde", ("Cargo.toml", 6), id="direct_dependency"),
+        pytest.param("serde_json", ("Cargo.toml", 7), id="renamed_dependency"),
+        pytest.param("insta", ("Cargo.toml", 9), id="dependency_table"),
+        pytest.param("itoa@1.0.9", ("Cargo.lock", 8), id="locked_version"),
+        pytest.param("itoa@2.0.0", ("Cargo.lock", 4), id="other_version"),
+        pytest.param("missing", ("Cargo.toml", 0), id="unknown_crate"),
+    ],
+)
+def test_parse_cargo_deny_output_locates_crates(
+    cargo_project: Path,
+    crate: str,
+    location: tuple[str, int],
+) -> None:
+    """Place crate issues on their manifest entry or lock file stanza.
+
+    Args:
+        cargo_project: Project root.
+        crate: Crate named by the diagnostic label.
+        location: Expected file and line.
+    """
+    label = f'[{{"message":"crate {crate}"}}]'
+    output = (
+        '{"type":"diagnostic","fields":{"severity":"warning","code":"duplicate",'
+        f'"message":"found 2 duplicate entries","labels":{label}}}}}'
+    )
+    issues = parse_cargo_deny_output(output, cargo_root=cargo_project)
+    assert_that((issues[0].file, issues[0].line)).is_equal_to(location)
+
+
+def test_parse_cargo_deny_output_config_issue_on_deny_toml(
+    cargo_project: Path,
+) -> None:
+    """Place issues without a crate on the line of deny.toml they point at.
+
+    Args:
+        cargo_project: Project root.
+    """
+    output = (
+        '{"type":"diagnostic","fields":{"severity":"warning",'
+        '"code":"unmatched-skip","message":"skipped crate was not encountered",'
+        '"labels":[{"line":2,"column":8,"span":"foo","message":"unmatched"}]}}'
+    )
+    issues = parse_cargo_deny_output(output, cargo_root=cargo_project)
+    assert_that((issues[0].file, issues[0].line)).is_equal_to(("deny.toml", 2))
+    assert_that(issues[0].check).is_equal_to("bans")
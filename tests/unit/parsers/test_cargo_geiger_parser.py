@@ -0,0 +1,130 @@
+"""Unit tests for cargo-geiger parser."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.cargo_geiger.cargo_geiger_parser import (
+    budget_issue,
+    parse_cargo_geiger_output,
+)
+
+
+def _package(name: str, source: dict | None, exprs: int, functions: int = 0) -> dict:
+    """Build one package entry of cargo-geiger JSON output.
+
+    Args:
+        name: Crate name.
+        source: Package source, e.g. ``{"Path": "file:///src/app"}``.
+        exprs: Unsafe expressions used.
+        functions: Unsafe functions used.
+
+    Returns:
+        dict: Package entry.
+    """
+    zero = {"safe": 0, "unsafe_": 0}
+    return {
+        "package": {"id": {"name": name, "version": "1.0.0", "source": source}},
+        "unsafety": {
+            "used": {
+                "functions": {"safe": 3, "unsafe_": functions},
+                "exprs": {"safe": 40, "unsafe_": exprs},
+                "item_impls": zero,
+                "item_traits": zero,
+                "methods": zero,
+            },
+            "unused": {"exprs": {"safe": 0, "unsafe_": 99}},
+            "forbids_unsafe": False,
+        },
+    }
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    """Create a crate depending on libc.
+
+    Args:
+        tmp_path: Temporary directory path.
+
+    Returns:
+        Path: Crate directory.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "app"\n\n[dependencies]\nlibc = "0.2"\n',
+    )
+    return tmp_path
+
+
+def _output(project: Path) -> str:
+    """Build cargo-geiger output for the project, with build noise around it.
+
+    Args:
+        project: Crate directory.
+
+    Returns:
+        str: Raw output.
+    """
+    registry = {"Registry": {"name": "crates.io", "url": "https://crates.io"}}
+    report = {
+        "packages": [
+            _package("app", {"Path": project.as_uri()}, exprs=3, functions=1),
+            _package("libc", registry, exprs=120),
+            _package("memchr", registry, exprs=8),
+            _package("safe", registry, exprs=0),
+        ],
+        "packages_without_metrics": [],
+        "used_but_not_scanned_files": [],
+    }
+    return f"   Compiling app v1.0.0\n{json.dumps(report)}\n"
+
+
+def test_parse_reports_crates_using_unsafe(project: Path) -> None:
+    """Crates using unsafe are info issues located in the project's manifests.
+
+    Args:
+        project: Crate directory.
+    """
+    issues = parse_cargo_geiger_output(_output(project), cargo_root=project)
+
+    assert_that([i.crate_name for i in issues]).is_equal_to(["app", "libc", "memchr"])
+    app, libc, memchr = issues
+    assert_that(app.local).is_true()
+    assert_that(app.unsafe_count).is_equal_to(4)
+    assert_that(app.message).is_equal_to(
+        "app 1.0.0 uses unsafe 4 times (1 functions, 3 expressions)",
+    )
+    assert_that(libc.line).is_equal_to(5)
+    assert_that(memchr.line).is_equal_to(0)
+    assert_that(memchr.file).ends_with("Cargo.toml")
+    assert_that(libc.get_severity()).is_equal_to(SeverityLevel.INFO)
+
+
+def test_parse_ignores_output_without_report() -> None:
+    """Output without a JSON report yields no issues."""
+    assert_that(parse_cargo_geiger_output("error: could not compile")).is_empty()
+    assert_that(parse_cargo_geiger_output("")).is_empty()
+
+
+def test_budget_counts_project_crates_by_default(project: Path) -> None:
+    """Only the project's own unsafe code counts unless dependencies are included.
+
+    Args:
+        project: Crate directory.
+    """
+    issues = parse_cargo_geiger_output(_output(project), cargo_root=project)
+
+    assert_that(budget_issue(issues, 4)).is_none()
+    over = budget_issue(issues, 3, cargo_root=project)
+    assert over is not None
+    assert_that(over.code).is_equal_to("unsafe-budget")
+    assert_that(over.get_severity()).is_equal_to(SeverityLevel.ERROR)
+    assert_that(over.message).contains("4 unsafe items used in project crates")
+
+    everything = budget_issue(issues, 100, count_dependencies=True)
+    assert everything is not None
+    assert_that(everything.message).contains("132 unsafe items")
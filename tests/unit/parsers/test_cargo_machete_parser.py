@@ -0,0 +1,100 @@
+"""Unit tests for cargo-machete parser."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.parsers.cargo_machete.cargo_machete_parser import (
+    parse_cargo_machete_output,
+)
+
+OUTPUT = (
+    "Analyzing dependencies of crates in this directory...\n"
+    "cargo-machete found the following unused dependencies in this directory:\n"
+    "app -- ./Cargo.toml:\n"
+    "\tserde\n"
+    "\tjson\n"
+    "helper -- ./crates/helper/Cargo.toml:\n"
+    "\tanyhow\n"
+    "\n"
+    "If you believe cargo-machete has detected an unused dependency incorrectly,\n"
+    "you can add the dependency to the list of dependencies to ignore in the\n"
+    "`[package.metadata.cargo-machete]` section of the appropriate Cargo.toml.\n"
+    "\n"
+    "Done!\n"
+)
+
+
+def test_parse_cargo_machete_output_without_root() -> None:
+    """Parse every unused dependency with its crate and manifest."""
+    issues = parse_cargo_machete_output(OUTPUT)
+    assert_that(
+        [(i.file, i.crate_name, i.dependency, i.line) for i in issues],
+    ).is_equal_to(
+        [
+            ("Cargo.toml", "app", "serde", 0),
+            ("Cargo.toml", "app", "json", 0),
+            ("crates/helper/Cargo.toml", "helper", "anyhow", 0),
+        ],
+    )
+    assert_that(issues[0].code).is_equal_to("unused-dependency")
+    assert_that(issues[0].fixable).is_true()
+    assert_that(issues[0].message).is_equal_to(
+        "unused dependency 'serde' in crate app",
+    )
+
+
+def test_parse_cargo_machete_output_locates_dependencies(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Place each issue on the manifest line declaring the dependency.
+
+    Args:
+        tmp_path: Temporary directory for the workspace.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "app"\n\n[dependencies]\n'
+        'serde = "1"\njson = { package = "serde_json", version = "1" }\n',
+    )
+    helper = tmp_path / "crates" / "helper"
+    helper.mkdir(parents=True)
+    (helper / "Cargo.toml").write_text(
+        '[package]\nname = "helper"\n\n[dependencies.anyhow]\nversion = "1"\n',
+    )
+    monkeypatch.chdir(tmp_path)
+
+    issues = parse_cargo_machete_output(OUTPUT, cargo_root=tmp_path)
+
+    assert_that([(i.file, i.line) for i in issues]).is_equal_to(
+        [
+            ("Cargo.toml", 5),
+            ("Cargo.toml", 6),
+            (str(Path("crates/helper/Cargo.toml")), 4),
+        ],
+    )
+
+
+@pytest.mark.parametrize(
+    "output",
+    [
+        pytest.param("", id="empty"),
+        pytest.param(
+            "Analyzing dependencies of crates in this directory...\n"
+            "cargo-machete didn't find any unused dependencies in this directory. "
+            "Good job!\nDone!\n",
+            id="clean",
+        ),
+    ],
+)
+def test_parse_cargo_machete_output_no_issues(output: str) -> None:
+    """Return no issues when nothing is unused.
+
+    Args:
+        output: cargo-machete output.
+    """
+    assert_that(parse_cargo_machete_output(output)).is_empty()
@@ -0,0 +1,154 @@
+"""Unit tests for cargo-outdated parser."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.cargo_outdated.cargo_outdated_parser import (
+    majors_behind,
+    parse_cargo_outdated_output,
+)
+
+
+def _dependency(name: str, project: str, compat: str, latest: str) -> dict:
+    """Build one dependency entry of cargo-outdated JSON output.
+
+    Args:
+        name: Dependency name.
+        project: Version in use.
+        compat: Newest compatible release, or ``---``.
+        latest: Newest release, or ``---``.
+
+    Returns:
+        dict: Dependency entry.
+    """
+    return {
+        "name": name,
+        "project": project,
+        "compat": compat,
+        "latest": latest,
+        "kind": "Normal",
+        "platform": None,
+    }
+
+
+OUTPUT = "\n".join(
+    [
+        json.dumps(
+            {
+                "crate_name": "app",
+                "dependencies": [
+                    _dependency("clap", "2.34.0", "---", "4.5.4"),
+                    _dependency("serde", "1.0.100", "1.0.200", "1.0.200"),
+                    _dependency("rand", "0.7.3", "---", "0.8.5"),
+                    _dependency("gone", "1.0.0", "---", "Removed"),
+                ],
+            },
+        ),
+        json.dumps(
+            {
+                "crate_name": "helper",
+                "dependencies": [_dependency("anyhow", "0.9.0", "---", "1.0.86")],
+            },
+        ),
+    ],
+)
+
+
+@pytest.mark.parametrize(
+    ("current", "latest", "expected"),
+    [
+        ("1.0.100", "1.0.200", 0),
+        ("2.34.0", "4.5.4", 2),
+        ("0.7.3", "0.8.5", 1),
+        ("0.0.1", "0.0.3", 2),
+        ("0.9.0", "1.0.86", 1),
+        ("1.0.0", "---", 0),
+    ],
+)
+def test_majors_behind(current: str, latest: str, expected: int) -> None:
+    """Breaking releases follow Cargo's rules, including for 0.x crates.
+
+    Args:
+        current: Version in use.
+        latest: Newest release.
+        expected: Breaking releases behind.
+    """
+    assert_that(majors_behind(current, latest)).is_equal_to(expected)
+
+
+def test_parse_cargo_outdated_output_classifies_by_majors_behind() -> None:
+    """Severity follows the majors-behind thresholds; removed crates are skipped."""
+    issues = parse_cargo_outdated_output(OUTPUT, warning_majors=1, error_majors=2)
+
+    assert_that(
+        [(i.crate_name, i.dependency, i.code, i.get_severity()) for i in issues],
+    ).is_equal_to(
+        [
+            ("app", "clap", "outdated-major", SeverityLevel.ERROR),
+            ("app", "serde", "outdated-minor", SeverityLevel.INFO),
+            ("app", "rand", "outdated-major", SeverityLevel.WARNING),
+            ("helper", "anyhow", "outdated-major", SeverityLevel.WARNING),
+        ],
+    )
+    assert_that(issues[0].message).is_equal_to(
+        "clap 2.34.0 is 2 major versions behind 4.5.4",
+    )
+    assert_that(issues[1].message).is_equal_to(
+        "serde 1.0.100 is behind 1.0.200 (compatible: 1.0.200)",
+    )
+
+
+def test_parse_cargo_outdated_output_locates_dependencies(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Place each issue on its line in the crate's own manifest.
+
+    Args:
+        tmp_path: Temporary directory for the workspace.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "app"\n\n[dependencies]\n'
+        'clap = "2"\nserde = "1"\nrand = "0.7"\n',
+    )
+    helper = tmp_path / "crates" / "helper"
+    helper.mkdir(parents=True)
+    (helper / "Cargo.toml").write_text(
+        '[package]\nname = "helper"\n\n[dependencies]\nanyhow = "0.9"\n',
+    )
+    monkeypatch.chdir(tmp_path)
+
+    issues = parse_cargo_outdated_output(OUTPUT, cargo_root=tmp_path)
+
+    assert_that([(i.file, i.line) for i in issues]).is_equal_to(
+        [
+            ("Cargo.toml", 5),
+            ("Cargo.toml", 6),
+            ("Cargo.toml", 7),
+            (str(Path("crates/helper/Cargo.toml")), 5),
+        ],
+    )
+
+
+@pytest.mark.parametrize(
+    "output",
+    [
+        pytest.param("", id="empty"),
+        pytest.param('{"crate_name": "app", "dependencies": []}\n', id="clean"),
+        pytest.param("error: failed to fetch index\n", id="not-json"),
+    ],
+)
+def test_parse_cargo_outdated_output_no_issues(output: str) -> None:
+    """Return no issues when nothing is outdated or nothing was reported.
+
+    Args:
+        output: cargo-outdated output.
+    """
+    assert_that(parse_cargo_outdated_output(output)).is_empty()
@@ -0,0 +1,126 @@
+"""Unit tests for the Checkov output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.checkov.checkov_parser import parse_checkov_output
+
+GUIDELINE = "https://docs.prismacloud.io/en/policy-reference/aws-policies/s3-16"
+
+
+def _framework_report(
+    framework: str,
+    failed: list[dict[str, object]],
+    parsing_errors: list[str] | None = None,
+) -> dict[str, object]:
+    """Build the report Checkov prints for one framework.
+
+    Args:
+        framework: Checkov framework name.
+        failed: Entries of ``failed_checks``.
+        parsing_errors: Files Checkov could not parse.
+
+    Returns:
+        dict[str, object]: The framework report.
+    """
+    return {
+        "check_type": framework,
+        "results": {
+            "failed_checks": failed,
+            "parsing_errors": parsing_errors or [],
+        },
+        "summary": {"failed": len(failed)},
+    }
+
+
+S3_CHECK: dict[str, object] = {
+    "check_id": "CKV_AWS_21",
+    "check_name": "Ensure all data stored in the S3 bucket have versioning enabled",
+    "file_path": "/infra/main.tf",
+    "file_abs_path": "/repo/infra/main.tf",
+    "file_line_range": [3, 9],
+    "resource": "aws_s3_bucket.logs",
+    "guideline": GUIDELINE,
+    "severity": None,
+}
+
+
+def test_parse_checkov_single_framework() -> None:
+    """A single framework report yields one issue per failed check."""
+    output = json.dumps(_framework_report("terraform", [S3_CHECK]))
+
+    report = parse_checkov_output(output)
+
+    assert report is not None
+    assert_that(report.errors).is_empty()
+    assert_that(report.issues).is_length(1)
+    issue = report.issues[0]
+    assert_that(issue.file).is_equal_to("/repo/infra/main.tf")
+    assert_that((issue.line, issue.end_line)).is_equal_to((3, 9))
+    assert_that(issue.code).is_equal_to("CKV_AWS_21")
+    assert_that(issue.message).starts_with("Ensure all data stored")
+    assert_that(issue.resource).is_equal_to("aws_s3_bucket.logs")
+    assert_that(issue.framework).is_equal_to("terraform")
+    assert_that(issue.guideline).is_equal_to(GUIDELINE)
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.WARNING)
+
+
+def test_parse_checkov_framework_list() -> None:
+    """Reports of several frameworks are combined, keeping parsing errors."""
+    dockerfile_check = {
+        "check_id": "CKV_DOCKER_3",
+        "check_name": "Ensure that a user for the container has been created",
+        "file_path": "/Dockerfile",
+        "file_line_range": [1, 4],
+        "resource": "/Dockerfile.",
+        "severity": "HIGH",
+    }
+    output = "\n".join(
+        [
+            "2026-10-15 WARNING Failed to download guidelines",
+            json.dumps(
+                [
+                    _framework_report("terraform", [S3_CHECK]),
+                    _framework_report(
+                        "dockerfile",
+                        [dockerfile_check],
+                        ["/repo/Dockerfile.broken"],
+                    ),
+                ],
+                indent=2,
+            ),
+        ],
+    )
+
+    report = parse_checkov_output(output)
+
+    assert report is not None
+    assert_that([i.code for i in report.issues]).is_equal_to(
+        ["CKV_AWS_21", "CKV_DOCKER_3"],
+    )
+    assert_that(report.issues[1].file).is_equal_to("/Dockerfile")
+    assert_that(report.issues[1].get_severity()).is_equal_to(SeverityLevel.ERROR)
+    assert_that(report.errors).is_equal_to(
+        ["Failed to parse /repo/Dockerfile.broken"],
+    )
+
+
+def test_parse_checkov_summary_only() -> None:
+    """A summary without results means no resources were found."""
+    output = json.dumps({"passed": 0, "failed": 0, "resource_count": 0})
+
+    report = parse_checkov_output(output)
+
+    assert report is not None
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_empty()
+
+
+def test_parse_checkov_without_report() -> None:
+    """Output without a JSON report returns None."""
+    assert_that(parse_checkov_output("")).is_none()
+    assert_that(parse_checkov_output(None)).is_none()
+    assert_that(parse_checkov_output("checkov: error: unrecognized")).is_none()
+    assert_that(parse_checkov_output("{broken")).is_none()
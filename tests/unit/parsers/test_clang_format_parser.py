@@ -0,0 +1,79 @@
+"""Unit tests for the clang-format output parser."""
+
+from assertpy import assert_that
+
+from lintro.parsers.clang_format.clang_format_parser import (
+    DIFF_PREVIEW_LINES,
+    parse_clang_format_output,
+)
+
+ORIGINAL = "int main(){\n  return 0;\n}\n"
+
+
+def test_parse_clang_format_unchanged() -> None:
+    """Identical or missing output yields no issues."""
+    assert_that(parse_clang_format_output("a.c", ORIGINAL, ORIGINAL)).is_empty()
+    assert_that(parse_clang_format_output("a.c", ORIGINAL, None)).is_empty()
+
+
+def test_parse_clang_format_hunk() -> None:
+    """A rewritten line becomes one fixable issue with a diff preview."""
+    formatted = "int main() {\n  return 0;\n}\n"
+
+    issues = parse_clang_format_output("src/a.c", ORIGINAL, formatted)
+
+    assert_that(issues).is_length(1)
+    issue = issues[0]
+    assert_that(issue.file).is_equal_to("src/a.c")
+    assert_that(issue.line).is_equal_to(1)
+    assert_that(issue.end_line).is_equal_to(1)
+    assert_that(issue.code).is_equal_to("FORMAT")
+    assert_that(issue.fixable).is_true()
+    assert_that(issue.message).is_equal_to("Formatting differs on line 1")
+    assert_that(issue.diff).contains("-int main(){", "+int main() {", "   return 0;")
+
+
+def test_parse_clang_format_separate_hunks() -> None:
+    """Changes far apart become separate issues spanning their lines."""
+    body = [f"int v{i};" for i in range(20)]
+    original = "\n".join(["int  a;", "int  b;", *body, "int  z;"]) + "\n"
+    formatted = "\n".join(["int a;", "int b;", *body, "int z;"]) + "\n"
+
+    issues = parse_clang_format_output("a.c", original, formatted)
+
+    assert_that([(i.line, i.end_line) for i in issues]).is_equal_to(
+        [(1, 2), (23, 23)],
+    )
+    assert_that(issues[0].message).is_equal_to("Formatting differs on lines 1-2")
+
+
+def test_parse_clang_format_insertion() -> None:
+    """A line clang-format inserts is reported before the next original line."""
+    original = "void f() {}\nvoid g() {}\n"
+    formatted = "void f() {}\n\nvoid g() {}\n"
+
+    issues = parse_clang_format_output("a.c", original, formatted)
+
+    assert_that(issues[0].line).is_equal_to(2)
+    assert_that(issues[0].diff).contains("+")
+
+
+def test_parse_clang_format_end_of_file() -> None:
+    """A missing final newline is reported at the last line."""
+    issues = parse_clang_format_output("a.c", ORIGINAL.rstrip("\n"), ORIGINAL)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].line).is_equal_to(3)
+    assert_that(issues[0].message).is_equal_to("Formatting differs at end of file")
+
+
+def test_parse_clang_format_truncates_preview() -> None:
+    """Long hunks keep a bounded preview."""
+    original = "".join(f"int  v{i};\n" for i in range(40))
+    formatted = "".join(f"int v{i};\n" for i in range(40))
+
+    issues = parse_clang_format_output("a.c", original, formatted)
+
+    preview = issues[0].diff.splitlines()
+    assert_that(preview).is_length(DIFF_PREVIEW_LINES + 1)
+    assert_that(preview[-1]).is_equal_to("... (60 more lines)")
@@ -0,0 +1,162 @@
+"""Unit tests for the clang-tidy output parser."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.clang_tidy.clang_tidy_parser import (
+    offset_to_position,
+    parse_clang_tidy_export,
+    parse_clang_tidy_output,
+)
+
+SOURCE = "#include <vector>\nint main() {\n  int *p = 0;\n}\n"
+
+
+def _source(tmp_path: Path) -> Path:
+    """Write a C++ source the diagnostics point into.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: The source file.
+    """
+    source = tmp_path / "main.cpp"
+    source.write_text(SOURCE)
+    return source
+
+
+def test_offset_to_position(tmp_path: Path) -> None:
+    """Byte offsets map to 1-based lines and columns.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    source = _source(tmp_path)
+
+    assert_that(offset_to_position(str(source), 0)).is_equal_to((1, 1))
+    assert_that(offset_to_position(str(source), SOURCE.index("0;"))).is_equal_to(
+        (3, 12),
+    )
+    assert_that(offset_to_position(str(tmp_path / "missing.cpp"), 5)).is_equal_to(
+        (0, 0),
+    )
+
+
+def test_parse_clang_tidy_export_yaml(tmp_path: Path) -> None:
+    """Exported diagnostics become issues located in the source.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    source = _source(tmp_path)
+    offset = SOURCE.index("0;")
+    export = f"""---
+MainSourceFile:  '{source}'
+Diagnostics:
+  - DiagnosticName:  modernize-use-nullptr
+    DiagnosticMessage:
+      Message:         use nullptr
+      FilePath:        '{source}'
+      FileOffset:      {offset}
+      Replacements:
+        - FilePath:        '{source}'
+          Offset:          {offset}
+          Length:          1
+          ReplacementText: nullptr
+    Level:           Warning
+    BuildDirectory:  '{tmp_path}'
+  - DiagnosticName:  modernize-use-nullptr
+    DiagnosticMessage:
+      Message:         use nullptr
+      FilePath:        '{source}'
+      FileOffset:      {offset}
+      Replacements:    []
+    Level:           Warning
+    BuildDirectory:  '{tmp_path}'
+...
+"""
+
+    issues = parse_clang_tidy_export(export)
+
+    assert_that(issues).is_length(1)
+    assert issues is not None
+    issue = issues[0]
+    assert_that(issue.file).is_equal_to(str(source))
+    assert_that((issue.line, issue.column)).is_equal_to((3, 12))
+    assert_that(issue.code).is_equal_to("modernize-use-nullptr")
+    assert_that(issue.message).is_equal_to("use nullptr")
+    assert_that(issue.fixable).is_true()
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.WARNING)
+
+
+def test_parse_clang_tidy_export_json(tmp_path: Path) -> None:
+    """JSON exports load too, with relative paths under the build directory.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    _source(tmp_path)
+    export = json.dumps(
+        {
+            "Diagnostics": [
+                {
+                    "DiagnosticName": "clang-diagnostic-error",
+                    "DiagnosticMessage": {
+                        "Message": "unknown type name 'foo'",
+                        "FilePath": "main.cpp",
+                        "FileOffset": 18,
+                    },
+                    "Level": "Error",
+                    "BuildDirectory": str(tmp_path),
+                },
+                {
+                    "DiagnosticName": "clang-diagnostic-error",
+                    "DiagnosticMessage": {"Message": "bad flag", "FilePath": ""},
+                    "Level": "Error",
+                },
+            ],
+        },
+    )
+
+    issues = parse_clang_tidy_export(export)
+
+    assert issues is not None
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].file).is_equal_to(str(tmp_path / "main.cpp"))
+    assert_that(issues[0].line).is_equal_to(2)
+    assert_that(issues[0].get_severity()).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_clang_tidy_export_empty() -> None:
+    """Missing or invalid exports return None so console output is used."""
+    assert_that(parse_clang_tidy_export("")).is_none()
+    assert_that(parse_clang_tidy_export(None)).is_none()
+    assert_that(parse_clang_tidy_export("- not a report")).is_none()
+    assert_that(parse_clang_tidy_export("Diagnostics: [")).is_none()
+
+
+def test_parse_clang_tidy_output() -> None:
+    """Console diagnostics are parsed, skipping notes and source excerpts."""
+    output = (
+        "src/main.cpp:3:12: warning: use nullptr [modernize-use-nullptr]\n"
+        "  int *p = 0;\n"
+        "           ^\n"
+        "           nullptr\n"
+        "src/main.cpp:1:10: error: 'vector' file not found "
+        "[clang-diagnostic-error]\n"
+        "src/util.h:4:1: note: previous definition is here\n"
+    )
+
+    issues = parse_clang_tidy_output(output)
+
+    assert_that([(i.line, i.code) for i in issues]).is_equal_to(
+        [(3, "modernize-use-nullptr"), (1, "clang-diagnostic-error")],
+    )
+    assert_that(issues[1].severity).is_equal_to("error")
+    assert_that(parse_clang_tidy_output(None)).is_empty()
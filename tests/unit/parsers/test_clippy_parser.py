@@ -4,7 +4,12 @@ from __future__ import annotations
 
 from assertpy import assert_that
 
-from lintro.parsers.clippy.clippy_parser import parse_clippy_output
+from lintro.enums.fix_safety import FixSafety
+from lintro.parsers.clippy.clippy_issue import ClippyReplacement, ClippySuggestion
+from lintro.parsers.clippy.clippy_parser import (
+    parse_clippy_line,
+    parse_clippy_output,
+)
 
 
 def test_parse_clippy_output_single_issue() -> None:
@@ -129,3 +134,107 @@ def test_parse_clippy_output_ansi_codes_stripped() -> None:
     assert_that(issues).is_length(1)
     assert_that(issues[0].file).is_equal_to("src/lib.rs")
     assert_that(issues[0].code).is_equal_to("clippy::needless_return")
+
+
+def test_parse_clippy_output_uses_primary_span() -> None:
+    """Locate multi-span diagnostics at the span marked primary."""
+    output = (
+        '{"reason":"compiler-message","message":{"code":{"code":"clippy::similar_names"},'
+        '"level":"warning","message":"binding is too similar",'
+        '"spans":[{"file_name":"src/lib.rs","is_primary":false,'
+        '"line_start":3,"line_end":3,"column_start":9,"column_end":12},'
+        '{"file_name":"src/lib.rs","is_primary":true,'
+        '"line_start":7,"line_end":7,"column_start":9,"column_end":13}]}}'
+    )
+    issues = parse_clippy_output(output)
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].line).is_equal_to(7)
+    assert_that(issues[0].column).is_equal_to(9)
+
+
+def test_parse_clippy_output_suggestions() -> None:
+    """Keep suggested fixes; maybe-incorrect ones are fixable but unsafe."""
+    output = (
+        '{"reason":"compiler-message","message":{"code":{"code":"clippy::needless_return"},'
+        '"level":"warning","message":"unneeded `return` statement",'
+        '"spans":[{"file_name":"src/lib.rs","is_primary":true,'
+        '"line_start":2,"line_end":2,"column_start":5,"column_end":14}],'
+        '"children":[{"level":"help","message":"remove `return`","spans":['
+        '{"file_name":"src/lib.rs","byte_start":20,"byte_end":29,'
+        '"suggested_replacement":"x","suggestion_applicability":"MachineApplicable"}'
+        "]}]}}\n"
+        '{"reason":"compiler-message","message":{"code":{"code":"clippy::ptr_arg"},'
+        '"level":"warning","message":"writing `&Vec` instead of `&[_]`",'
+        '"spans":[{"file_name":"src/lib.rs","is_primary":true,'
+        '"line_start":5,"line_end":5,"column_start":8,"column_end":17}],'
+        '"children":[{"level":"help","message":"change this to","spans":['
+        '{"file_name":"src/lib.rs","byte_start":50,"byte_end":59,'
+        '"suggested_replacement":"&[i32]","suggestion_applicability":"MaybeIncorrect"}'
+        "]}]}}"
+    )
+    issues = parse_clippy_output(output)
+    assert_that(issues).is_length(2)
+    assert_that(issues[0].fixable).is_true()
+    assert_that(issues[0].suggestions).is_equal_to(
+        [
+            ClippySuggestion(
+                message="remove `return`",
+                applicability="MachineApplicable",
+                replacements=(ClippyReplacement("src/lib.rs", 20, 29, "x"),),
+            ),
+        ],
+    )
+    assert_that(issues[0].get_fix_safety()).is_equal_to(FixSafety.SAFE)
+    assert_that(issues[1].fixable).is_true()
+    assert_that(issues[1].suggestions[0].applicability).is_equal_to("MaybeIncorrect")
+    assert_that(issues[1].get_fix_safety()).is_equal_to(FixSafety.UNSAFE)
+    assert_that(issues[1].to_display_row()["fixable"]).is_equal_to("Unsafe")
+
+
+def test_parse_clippy_line_streams_one_diagnostic() -> None:
+    """Parse the lines of a streaming run one at a time."""
+    line = (
+        '{"reason":"compiler-message","message":{"code":{"code":"clippy::ptr_arg"},'
+        '"level":"warning","message":"writing `&Vec` instead of `&[_]`",'
+        '"spans":[{"file_name":"src/lib.rs","line_start":5,"line_end":5,'
+        '"column_start":8,"column_end":17}]}}'
+    )
+
+    issue = parse_clippy_line(line)
+
+    assert_that(issue).is_not_none()
+    assert_that(issue.code).is_equal_to("clippy::ptr_arg")  # type: ignore[union-attr]
+    assert_that(parse_clippy_line("    Checking demo v0.1.0")).is_none()
+    assert_that(parse_clippy_line('{"reason":"build-finished"}')).is_none()
+
+
+
+def test_parse_clippy_output_merges_targets() -> None:
+    """Diagnostics repeated by each target a file is compiled into merge."""
+    diagnostic = (
+        '{{"reason":"compiler-message","target":{{"kind":["{kind}"],"name":"demo"}},'
+        '"message":{{"code":{{"code":"clippy::{lint}"}},'
+        '"level":"warning","message":"{lint} triggered",'
+        '"spans":[{{"file_name":"src/util.rs",'
+        '"line_start":3,"line_end":3,"column_start":{column},"column_end":20}}]}}}}'
+    )
+    output = "\n".join(
+        [
+            diagnostic.format(kind="lib", lint="needless_return", column=5),
+            diagnostic.format(kind="bin", lint="needless_return", column=5),
+            diagnostic.format(kind="test", lint="needless_return", column=5),
+            diagnostic.format(kind="lib", lint="needless_return", column=9),
+            diagnostic.format(kind="lib", lint="unwrap_used", column=5),
+        ],
+    )
+
+    issues = parse_clippy_output(output)
+
+    assert_that([(i.code, i.column) for i in issues]).is_equal_to(
+        [
+            ("clippy::needless_return", 5),
+            ("clippy::needless_return", 9),
+            ("clippy::unwrap_used", 5),
+        ],
+    )
+    assert_that([i.target_count for i in issues]).is_equal_to([3, 1, 1])
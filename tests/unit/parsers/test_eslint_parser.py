@@ -0,0 +1,112 @@
+"""Unit tests for ESLint parser functionality."""
+
+from __future__ import annotations
+
+import json
+
+from assertpy import assert_that
+
+from lintro.parsers.eslint.eslint_parser import parse_eslint_output
+
+
+def _report(*messages: dict[str, object], file_path: str = "/repo/app.ts") -> str:
+    """Build an ESLint JSON report for one file.
+
+    Args:
+        *messages: Messages to include.
+        file_path: File the messages belong to.
+
+    Returns:
+        str: Report as printed by ``eslint --format json``.
+    """
+    return json.dumps(
+        [
+            {
+                "filePath": file_path,
+                "messages": list(messages),
+                "errorCount": 0,
+                "warningCount": 0,
+            },
+        ],
+    )
+
+
+def test_parse_eslint_output_empty() -> None:
+    """Empty output and clean files yield no issues."""
+    assert_that(parse_eslint_output("")).is_empty()
+    assert_that(parse_eslint_output(_report())).is_empty()
+
+
+def test_parse_eslint_output_malformed_json() -> None:
+    """Malformed JSON yields no issues."""
+    assert_that(parse_eslint_output("[invalid json")).is_empty()
+
+
+def test_parse_eslint_output_maps_rule_severities() -> None:
+    """Numeric severities become warning and error; fixes mark issues fixable."""
+    output = _report(
+        {
+            "ruleId": "@typescript-eslint/no-explicit-any",
+            "severity": 1,
+            "message": "Unexpected any. Specify a different type.",
+            "line": 3,
+            "column": 14,
+        },
+        {
+            "ruleId": "eqeqeq",
+            "severity": 2,
+            "message": "Expected '===' and instead saw '=='.",
+            "line": 5,
+            "column": 7,
+            "fix": {"range": [40, 42], "text": "==="},
+        },
+    )
+
+    issues = parse_eslint_output(output)
+
+    assert_that([i.code for i in issues]).is_equal_to(
+        ["@typescript-eslint/no-explicit-any", "eqeqeq"],
+    )
+    assert_that([i.severity for i in issues]).is_equal_to(["warning", "error"])
+    assert_that([i.fixable for i in issues]).is_equal_to([False, True])
+    assert_that(issues[1].file).is_equal_to("/repo/app.ts")
+    assert_that((issues[1].line, issues[1].column)).is_equal_to((5, 7))
+
+
+def test_parse_eslint_output_fatal_parsing_error() -> None:
+    """Parsing errors have no rule and are always errors."""
+    output = _report(
+        {
+            "ruleId": None,
+            "fatal": True,
+            "severity": 2,
+            "message": "Parsing error: Unexpected token )",
+            "line": 2,
+            "column": 10,
+        },
+    )
+
+    issues = parse_eslint_output(output)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].code).is_equal_to("")
+    assert_that(issues[0].severity).is_equal_to("error")
+    assert_that(issues[0].get_severity().value).is_equal_to("ERROR")
+
+
+def test_parse_eslint_output_ignores_text_around_json() -> None:
+    """Deprecation notices printed before the report are ignored."""
+    output = (
+        "(node:123) ESLintRCWarning: deprecated option\n"
+        + _report(
+            {
+                "ruleId": "no-undef",
+                "severity": 2,
+                "message": "'x' is not defined.",
+                "line": 1,
+                "column": 1,
+            },
+        )
+    )
+
+    assert_that(parse_eslint_output(output)).is_length(1)
@@ -0,0 +1,79 @@
+"""Unit tests for the gofmt output parser."""
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.gofmt.gofmt_parser import DIFF_PREVIEW_LINES, parse_gofmt_output
+
+DIFF = """diff cmd/main.go.orig cmd/main.go
+--- cmd/main.go.orig
++++ cmd/main.go
+@@ -1,7 +1,7 @@
+ package main
+ 
+-import "fmt"
++import "fmt"
+ 
+ func main() {
+-\tfmt.Println( "hi" )
++\tfmt.Println("hi")
+ }
+diff util.go.orig util.go
+--- util.go.orig
++++ util.go
+@@ -3,4 +3,5 @@
+ func a() {}
++
+ func b() {}
+"""
+
+
+def test_parse_gofmt_empty() -> None:
+    """Empty or missing output yields no issues."""
+    assert_that(parse_gofmt_output("")).is_empty()
+    assert_that(parse_gofmt_output(None)).is_empty()
+
+
+def test_parse_gofmt_diff_hunks() -> None:
+    """Each hunk becomes a fixable issue spanning the lines that change."""
+    issues = parse_gofmt_output(DIFF)
+
+    assert_that([(i.file, i.line, i.end_line) for i in issues]).is_equal_to(
+        [("cmd/main.go", 3, 6), ("util.go", 4, 4)],
+    )
+    issue = issues[0]
+    assert_that(issue.code).is_equal_to("FORMAT")
+    assert_that(issue.fixable).is_true()
+    assert_that(issue.message).is_equal_to("Formatting differs on lines 3-6")
+    assert_that(issue.diff).contains('+\tfmt.Println("hi")')
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.INFO)
+    assert_that(issues[1].message).is_equal_to("Formatting differs on line 4")
+
+
+def test_parse_gofmt_syntax_errors() -> None:
+    """Parse errors become unfixable SYNTAX issues."""
+    output = "broken.go:4:2: expected declaration, found foo\n" + DIFF
+
+    issues = parse_gofmt_output(output)
+
+    assert_that(issues).is_length(3)
+    issue = issues[0]
+    assert_that((issue.file, issue.line, issue.column)).is_equal_to(
+        ("broken.go", 4, 2),
+    )
+    assert_that(issue.code).is_equal_to("SYNTAX")
+    assert_that(issue.fixable).is_false()
+    assert_that(issue.message).is_equal_to("expected declaration, found foo")
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_gofmt_truncates_preview() -> None:
+    """Long hunks keep a bounded preview."""
+    body = "".join(f"-var  v{i} int\n+var v{i} int\n" for i in range(20))
+    output = f"--- a.go.orig\n+++ a.go\n@@ -1,20 +1,20 @@\n{body}"
+
+    issues = parse_gofmt_output(output)
+
+    preview = issues[0].diff.splitlines()
+    assert_that(preview).is_length(DIFF_PREVIEW_LINES + 1)
+    assert_that(preview[-1]).is_equal_to("... (20 more lines)")
@@ -0,0 +1,88 @@
+"""Unit tests for the golangci-lint output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.golangci_lint.golangci_lint_parser import (
+    parse_golangci_lint_output,
+)
+
+
+def _issue(linter: str, text: str, **extra: object) -> dict[str, object]:
+    """Build a golangci-lint issue entry.
+
+    Args:
+        linter: Linter reporting the issue.
+        text: Issue message.
+        **extra: Additional issue fields.
+
+    Returns:
+        dict[str, object]: The issue as golangci-lint serializes it.
+    """
+    return {
+        "FromLinter": linter,
+        "Text": text,
+        "Pos": {"Filename": "/src/app/main.go", "Line": 12, "Column": 2},
+        **extra,
+    }
+
+
+def test_parse_golangci_lint_issues() -> None:
+    """Issues carry the linter, position, severity and fixability."""
+    output = json.dumps(
+        {
+            "Issues": [
+                _issue(
+                    "errcheck",
+                    "Error return value is not checked",
+                    Severity="error",
+                ),
+                _issue(
+                    "gofumpt",
+                    "File is not properly formatted",
+                    Replacement={"NewLines": ["x"]},
+                ),
+            ],
+            "Report": {"Linters": [{"Name": "errcheck", "Enabled": True}]},
+        },
+    )
+
+    report = parse_golangci_lint_output(output)
+
+    assert report is not None
+    assert_that(report.errors).is_empty()
+    assert_that(report.issues).is_length(2)
+    issue = report.issues[0]
+    assert_that(issue.file).is_equal_to("/src/app/main.go")
+    assert_that((issue.line, issue.column)).is_equal_to((12, 2))
+    assert_that(issue.code).is_equal_to("errcheck")
+    assert_that(issue.fixable).is_false()
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.ERROR)
+    assert_that(report.issues[1].fixable).is_true()
+
+
+def test_parse_golangci_lint_skips_log_lines() -> None:
+    """Log lines around the JSON report are ignored and run errors kept."""
+    output = (
+        "level=warning msg=\"[runner] Can't run linter goanalysis_metalinter\"\n"
+        + json.dumps({"Issues": None, "Report": {"Error": "context loading failed"}})
+        + "\n"
+    )
+
+    report = parse_golangci_lint_output(output)
+
+    assert report is not None
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_equal_to(["context loading failed"])
+
+
+def test_parse_golangci_lint_without_report() -> None:
+    """Output without a JSON report returns None."""
+    assert_that(parse_golangci_lint_output("")).is_none()
+    assert_that(parse_golangci_lint_output(None)).is_none()
+    assert_that(
+        parse_golangci_lint_output("Error: can't load config: unknown linter"),
+    ).is_none()
+    assert_that(parse_golangci_lint_output("{not json")).is_none()
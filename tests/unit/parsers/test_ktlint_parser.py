@@ -0,0 +1,71 @@
+"""Unit tests for the ktlint output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.parsers.ktlint.ktlint_parser import parse_ktlint_output
+
+REPORT = [
+    {
+        "file": "src/main/kotlin/App.kt",
+        "errors": [
+            {
+                "line": 3,
+                "column": 1,
+                "message": "Wildcard import",
+                "rule": "standard:no-wildcard-imports",
+            },
+            {
+                "line": 12,
+                "column": 5,
+                "message": "Unexpected indentation (4) (should be 8)",
+                "rule": "standard:indent",
+            },
+        ],
+    },
+    {
+        "file": "build.gradle.kts",
+        "errors": [
+            {
+                "line": 1,
+                "column": 7,
+                "message": "Not a valid Kotlin file (expecting an element)",
+                "rule": "",
+            },
+        ],
+    },
+]
+
+
+def test_parse_ktlint_violations() -> None:
+    """Violations keep their file, location and rule."""
+    output = (
+        "12:00:00.000 [main] WARN com.pinterest.ktlint.cli - Unknown property\n"
+        f"{json.dumps(REPORT)}\n"
+    )
+
+    issues = parse_ktlint_output(output)
+
+    assert_that(issues).is_not_none()
+    assert issues is not None
+    assert_that(issues).is_length(3)
+    assert_that(issues[0].file).is_equal_to("src/main/kotlin/App.kt")
+    assert_that((issues[0].line, issues[0].column)).is_equal_to((3, 1))
+    assert_that(issues[0].code).is_equal_to("standard:no-wildcard-imports")
+    assert_that(issues[1].message).is_equal_to(
+        "Unexpected indentation (4) (should be 8)",
+    )
+    assert_that(issues[2].file).is_equal_to("build.gradle.kts")
+    assert_that(issues[2].code).is_empty()
+
+
+def test_parse_ktlint_clean_report() -> None:
+    """An empty report yields no issues."""
+    assert_that(parse_ktlint_output("[\n]\n")).is_equal_to([])
+
+
+def test_parse_ktlint_without_report() -> None:
+    """Output without a JSON report yields None."""
+    assert_that(parse_ktlint_output("")).is_none()
+    assert_that(parse_ktlint_output("Error: Unable to access jarfile")).is_none()
@@ -0,0 +1,53 @@
+"""Unit tests for the luacheck output parser."""
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.luacheck.luacheck_parser import parse_luacheck_output
+
+
+def test_parse_luacheck_findings() -> None:
+    """Warnings and errors keep their location, range and code."""
+    output = (
+        "lua/init.lua:3:7-9: (W211) unused variable 'foo'\n"
+        "lua/init.lua:12:1-6: (W113) accessing undefined variable 'vim2'\n"
+        "lua/broken.lua:1:5-5: (E011) expected '=' near 'x'\n"
+    )
+
+    report = parse_luacheck_output(output)
+
+    assert_that(report.errors).is_empty()
+    assert_that(report.issues).is_length(3)
+    first = report.issues[0]
+    assert_that(first.file).is_equal_to("lua/init.lua")
+    assert_that((first.line, first.column, first.end_column)).is_equal_to(
+        (3, 7, 9),
+    )
+    assert_that(first.code).is_equal_to("W211")
+    assert_that(first.message).is_equal_to("unused variable 'foo'")
+    assert_that(first.get_severity()).is_equal_to(SeverityLevel.WARNING)
+    assert_that(report.issues[2].code).is_equal_to("E011")
+    assert_that(report.issues[2].get_severity()).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_luacheck_without_ranges() -> None:
+    """Findings without a column range are still parsed."""
+    output = "init.lua:4:3: (W612) line contains trailing whitespace"
+
+    report = parse_luacheck_output(output)
+
+    assert_that(report.issues).is_length(1)
+    assert_that(report.issues[0].column).is_equal_to(3)
+    assert_that(report.issues[0].end_column).is_none()
+
+
+def test_parse_luacheck_unchecked_files() -> None:
+    """Files luacheck could not check are collected as errors."""
+    output = "lua/gone.lua: I/O error (couldn't read: No such file or directory)\n"
+
+    report = parse_luacheck_output(output)
+
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_equal_to(
+        ["lua/gone.lua: I/O error (couldn't read: No such file or directory)"],
+    )
@@ -63,6 +63,16 @@ def test_parse_markdownlint_without_column() -> None:
     assert_that(i0.message).contains("First line should be a heading")
 
 
+def test_parse_markdownlint_windows_paths() -> None:
+    """Keep the drive letter of Windows paths in the file."""
+    out = "C:\\docs\\file.md:5:1 MD041/first-line-heading First line is no heading"
+    issues = parse_markdownlint_output(out)
+    assert_that(len(issues)).is_equal_to(1)
+    assert_that(issues[0].file).is_equal_to("C:\\docs\\file.md")
+    assert_that(issues[0].line).is_equal_to(5)
+    assert_that(issues[0].code).is_equal_to("MD041")
+
+
 def test_parse_markdownlint_ignores_malformed_lines() -> None:
     """Ignore lines that don't match the expected format."""
     out = (
@@ -149,3 +149,25 @@ def test_parse_mypy_skips_entries_without_file() -> None:
 
     assert_that(issues).is_length(1)
     assert_that(issues[0].file).ends_with("valid.py")
+
+
+def test_parse_mypy_text_output() -> None:
+    """Standard text output from mypy without --output json is parsed."""
+    output = (
+        "src/app.py:12:5: error: Incompatible return value type "
+        '(got "int", expected "str")  [return-value]\n'
+        "src/app.py:14:1:14:9: warning: Unused \"type: ignore\" comment  "
+        "[unused-ignore]\n"
+        "src/app.py:20: note: Revealed type is \"builtins.int\"\n"
+        "Found 1 error in 1 file (checked 3 source files)\n"
+    )
+    issues = parse_mypy_output(output)
+
+    assert_that(issues).is_length(3)
+    assert_that(issues[0].code).is_equal_to("return-value")
+    assert_that(issues[0].column).is_equal_to(5)
+    assert_that(issues[0].message).ends_with('expected "str")')
+    assert_that(issues[1].end_column).is_equal_to(9)
+    assert_that(issues[1].severity).is_equal_to("warning")
+    assert_that(issues[2].code).is_empty()
+    assert_that(issues[2].column).is_equal_to(0)
@@ -0,0 +1,82 @@
+"""Unit tests for the RuboCop output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.rubocop.rubocop_parser import parse_rubocop_output
+
+REPORT = {
+    "metadata": {"rubocop_version": "1.81.1"},
+    "files": [
+        {"path": "lib/clean.rb", "offenses": []},
+        {
+            "path": "lib/app.rb",
+            "offenses": [
+                {
+                    "severity": "convention",
+                    "message": "Style/StringLiterals: Prefer single-quoted strings.",
+                    "cop_name": "Style/StringLiterals",
+                    "corrected": True,
+                    "correctable": True,
+                    "location": {
+                        "start_line": 3,
+                        "start_column": 8,
+                        "last_line": 3,
+                        "last_column": 14,
+                    },
+                },
+                {
+                    "severity": "warning",
+                    "message": "Useless assignment to variable - `x`.",
+                    "cop_name": "Lint/UselessAssignment",
+                    "corrected": False,
+                    "correctable": False,
+                    "location": {"line": 5, "column": 3},
+                },
+            ],
+        },
+    ],
+    "summary": {"offense_count": 2, "target_file_count": 2},
+}
+
+
+def test_parse_rubocop_offenses() -> None:
+    """Offenses keep their cop, location, severity and correction state."""
+    issues = parse_rubocop_output(json.dumps(REPORT))
+
+    assert_that(issues).is_not_none()
+    assert issues is not None
+    assert_that(issues).is_length(2)
+    first, second = issues
+    assert_that(first.file).is_equal_to("lib/app.rb")
+    assert_that(first.code).is_equal_to("Style/StringLiterals")
+    assert_that(first.message).is_equal_to("Prefer single-quoted strings.")
+    assert_that((first.line, first.column)).is_equal_to((3, 8))
+    assert_that((first.end_line, first.end_column)).is_equal_to((3, 14))
+    assert_that(first.fixable).is_true()
+    assert_that(first.corrected).is_true()
+    assert_that(first.get_severity()).is_equal_to(SeverityLevel.INFO)
+    assert_that((second.line, second.column)).is_equal_to((5, 3))
+    assert_that(second.end_line).is_none()
+    assert_that(second.fixable).is_false()
+    assert_that(second.get_severity()).is_equal_to(SeverityLevel.WARNING)
+
+
+def test_parse_rubocop_skips_preceding_warnings() -> None:
+    """Config warnings printed before the report are ignored."""
+    output = (
+        "Warning: Layout/LineLength does not support Foo parameter.\n"
+        + json.dumps({"files": []})
+    )
+
+    assert_that(parse_rubocop_output(output)).is_equal_to([])
+
+
+def test_parse_rubocop_without_report() -> None:
+    """Output without a JSON report yields None."""
+    assert_that(parse_rubocop_output("")).is_none()
+    assert_that(
+        parse_rubocop_output("Error: configuration for Foo/Bar cop not found"),
+    ).is_none()
@@ -61,3 +61,20 @@ def test_parse_ruff_format_check_output_variants_more() -> None:
     out = "Would reformat: a.py\nWould reformat b.py\n"
     files = parse_ruff_format_check_output(out)
     assert_that(sorted(files)).is_equal_to(["a.py", "b.py"])
+
+
+def test_parse_ruff_output_notebook_cells() -> None:
+    """Keep the cell of notebook issues, whose rows count from the cell."""
+    output = (
+        '[{"filename": "nb.ipynb", "cell": 3, "location": {"row": 2, '
+        '"column": 1}, "code": "F401", "message": "unused"}, '
+        '{"filename": "a.py", "cell": null, "location": {"row": 4, '
+        '"column": 1}, "code": "F401", "message": "unused"}]'
+    )
+
+    notebook, script = parse_ruff_output(output)
+
+    assert_that((notebook.cell, notebook.line)).is_equal_to((3, 2))
+    assert_that(notebook.to_display_row()["line"]).is_equal_to("cell 3:2")
+    assert_that(script.cell).is_equal_to(0)
+    assert_that(script.to_display_row()["line"]).is_equal_to("4")
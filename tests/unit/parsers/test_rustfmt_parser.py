@@ -5,7 +5,11 @@ from __future__ import annotations
 import pytest
 from assertpy import assert_that
 
-from lintro.parsers.rustfmt.rustfmt_parser import parse_rustfmt_output
+from lintro.parsers.rustfmt.rustfmt_parser import (
+    DIFF_PREVIEW_LINES,
+    parse_rustfmt_output,
+    parse_unstable_options,
+)
 
 
 @pytest.mark.parametrize(
@@ -42,8 +46,9 @@ def test_parse_rustfmt_output_diff_format() -> None:
 
     assert_that(result).is_length(1)
     assert_that(result[0].file).is_equal_to("src/main.rs")
-    assert_that(result[0].line).is_equal_to(5)
-    assert_that(result[0].message).is_equal_to("File needs formatting")
+    assert_that(result[0].line).is_equal_to(6)
+    assert_that(result[0].end_line).is_equal_to(6)
+    assert_that(result[0].message).is_equal_to("Formatting differs on line 6")
     assert_that(result[0].fixable).is_true()
 
 
@@ -62,12 +67,11 @@ Diff in src/lib.rs:10:
 """
     result = parse_rustfmt_output(output)
 
-    # Parser deduplicates by file, so each file only appears once
     assert_that(result).is_length(2)
     assert_that(result[0].file).is_equal_to("src/main.rs")
-    assert_that(result[0].line).is_equal_to(5)
+    assert_that(result[0].line).is_equal_to(6)
     assert_that(result[1].file).is_equal_to("src/lib.rs")
-    assert_that(result[1].line).is_equal_to(10)
+    assert_that(result[1].line).is_equal_to(11)
 
 
 def test_parse_rustfmt_output_standalone_file_paths() -> None:
@@ -81,20 +85,53 @@ src/lib.rs"""
     assert_that(result[1].file).is_equal_to("src/lib.rs")
 
 
-def test_parse_rustfmt_output_deduplicates_files() -> None:
-    """Parser deduplicates issues by file path."""
+def test_parse_rustfmt_output_reports_each_hunk() -> None:
+    """Parser reports every hunk of a file with its own lines and preview."""
     output = """Diff in src/main.rs:5:
 - old
 + new
 Diff in src/main.rs:10:
 - another old
 + another new
+Diff in src/main.rs:10:
+- another old
++ another new
+"""
+    result = parse_rustfmt_output(output)
+
+    # The repeated hunk is reported once
+    assert_that(result).is_length(2)
+    assert_that([issue.line for issue in result]).is_equal_to([5, 10])
+    assert_that(result[1].diff).is_equal_to("- another old\n+ another new")
+
+
+def test_parse_rustfmt_output_line_range() -> None:
+    """The range covers the original lines that change, not the context."""
+    output = """Diff in src/lib.rs at line 3:
+ fn foo() {
+-    let x=1;
+-    let y=2;
++    let x = 1;
++    let y = 2;
+ }
 """
     result = parse_rustfmt_output(output)
 
-    # Only one issue per file
     assert_that(result).is_length(1)
-    assert_that(result[0].file).is_equal_to("src/main.rs")
+    assert_that(result[0].line).is_equal_to(4)
+    assert_that(result[0].end_line).is_equal_to(5)
+    assert_that(result[0].message).is_equal_to("Formatting differs on lines 4-5")
+    assert_that(result[0].diff).starts_with(" fn foo() {\n-    let x=1;")
+
+
+def test_parse_rustfmt_output_truncates_preview() -> None:
+    """Long hunks keep a bounded preview."""
+    body = "\n".join(f"+line {i}" for i in range(DIFF_PREVIEW_LINES + 5))
+    result = parse_rustfmt_output(f"Diff in src/main.rs:1:\n{body}")
+
+    preview = result[0].diff.splitlines()
+    assert_that(preview).is_length(DIFF_PREVIEW_LINES + 1)
+    assert_that(preview[-1]).is_equal_to("... (5 more lines)")
 
 
 def test_parse_rustfmt_output_mixed_content() -> None:
@@ -119,3 +156,24 @@ def test_parse_rustfmt_output_no_issues() -> None:
     result = parse_rustfmt_output(output)
 
     assert_that(result).is_length(0)
+
+
+def test_parse_unstable_options() -> None:
+    """Warnings about ignored nightly-only options are read once each."""
+    warning = (
+        "Warning: can't set `{}`, unstable features are only available in "
+        "nightly channel."
+    )
+    output = "\n".join(
+        [
+            warning.format("imports_granularity = Crate"),
+            warning.format("group_imports = StdExternalCrate"),
+            warning.format("imports_granularity = Crate"),
+            "Diff in src/main.rs:5:",
+        ],
+    )
+
+    assert_that(parse_unstable_options(output)).is_equal_to(
+        {"imports_granularity": "Crate", "group_imports": "StdExternalCrate"},
+    )
+    assert_that(parse_rustfmt_output(output)).is_length(1)
@@ -0,0 +1,173 @@
+"""Unit tests for the SARIF log parser."""
+
+from __future__ import annotations
+
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.parsers.sarif.sarif_parser import parse_sarif_log
+
+
+def _log(results: list[dict[str, Any]], **run: Any) -> dict[str, Any]:
+    """Build a one-run SARIF log.
+
+    Args:
+        results: Results of the run.
+        **run: Further run fields, such as ``originalUriBaseIds``.
+
+    Returns:
+        dict[str, Any]: The SARIF log.
+    """
+    driver = {
+        "name": "CodeQL",
+        "rules": [
+            {
+                "id": "py/sql-injection",
+                "shortDescription": {"text": "SQL injection"},
+                "defaultConfiguration": {"level": "error"},
+                "helpUri": "https://codeql.github.com/sql",
+            },
+        ],
+    }
+    return {
+        "version": "2.1.0",
+        "runs": [{"tool": {"driver": driver}, "results": results, **run}],
+    }
+
+
+def _result(**fields: Any) -> dict[str, Any]:
+    """Build a SARIF result located in ``src/app.py``.
+
+    Args:
+        **fields: Fields overriding the defaults.
+
+    Returns:
+        dict[str, Any]: The SARIF result.
+    """
+    result: dict[str, Any] = {
+        "ruleId": "py/sql-injection",
+        "message": {"text": "Query built from user input"},
+        "locations": [
+            {
+                "physicalLocation": {
+                    "artifactLocation": {"uri": "src/app.py"},
+                    "region": {"startLine": 12, "startColumn": 5, "endLine": 13},
+                },
+            },
+        ],
+    }
+    result.update(fields)
+    return result
+
+
+def test_parse_sarif_log_reads_result_fields() -> None:
+    """Location, rule, message and rule metadata are read."""
+    issues = parse_sarif_log(_log([_result()]))
+
+    issue = issues["CodeQL"][0]
+    assert_that(issue.file).is_equal_to("src/app.py")
+    assert_that(issue.line).is_equal_to(12)
+    assert_that(issue.column).is_equal_to(5)
+    assert_that(issue.end_line).is_equal_to(13)
+    assert_that(issue.rule_id).is_equal_to("py/sql-injection")
+    assert_that(issue.message).is_equal_to("Query built from user input")
+    assert_that(issue.help_uri).is_equal_to("https://codeql.github.com/sql")
+
+
+@pytest.mark.parametrize(
+    "fields,expected",
+    [
+        ({}, "error"),
+        ({"level": "note"}, "note"),
+        ({"level": "none"}, "note"),
+        ({"ruleId": "py/other"}, "warning"),
+    ],
+    ids=["rule_default", "result_level", "none_is_note", "unknown_rule"],
+)
+def test_parse_sarif_log_levels(fields: dict[str, Any], expected: str) -> None:
+    """The level comes from the result, then the rule, then defaults to warning.
+
+    Args:
+        fields: Fields set on the result.
+        expected: Expected issue level.
+    """
+    issues = parse_sarif_log(_log([_result(**fields)]))
+
+    assert_that(issues["CodeQL"][0].level).is_equal_to(expected)
+
+
+def test_parse_sarif_log_resolves_rule_index_and_description() -> None:
+    """Results naming their rule by index fall back to its description."""
+    result = _result(message={}, ruleIndex=0)
+    del result["ruleId"]
+
+    issue = parse_sarif_log(_log([result]))["CodeQL"][0]
+
+    assert_that(issue.rule_id).is_equal_to("py/sql-injection")
+    assert_that(issue.message).is_equal_to("SQL injection")
+
+
+@pytest.mark.parametrize(
+    "fields",
+    [
+        {"kind": "pass"},
+        {"baselineState": "absent"},
+        {"suppressions": [{"kind": "inSource"}]},
+        {"locations": []},
+    ],
+    ids=["pass", "absent", "suppressed", "no_location"],
+)
+def test_parse_sarif_log_skips_closed_results(fields: dict[str, Any]) -> None:
+    """Results that are not open problems with a file are left out.
+
+    Args:
+        fields: Fields set on the result.
+    """
+    issues = parse_sarif_log(_log([_result(**fields)]))
+
+    assert_that(issues["CodeQL"]).is_empty()
+
+
+def test_parse_sarif_log_keeps_rejected_suppressions() -> None:
+    """A suppression under review or rejected does not hide the result."""
+    result = _result(suppressions=[{"kind": "external", "status": "rejected"}])
+
+    assert_that(parse_sarif_log(_log([result]))["CodeQL"]).is_length(1)
+
+
+def test_parse_sarif_log_resolves_uri_base_ids() -> None:
+    """Relative URIs are joined to the file URI of their base id."""
+    result = _result()
+    result["locations"][0]["physicalLocation"]["artifactLocation"] = {
+        "uri": "app%20main.py",
+        "uriBaseId": "SRCROOT",
+    }
+    log = _log(
+        [result],
+        originalUriBaseIds={"SRCROOT": {"uri": "file:///work/src/"}},
+    )
+
+    issue = parse_sarif_log(log)["CodeQL"][0]
+
+    assert_that(issue.file).is_equal_to("/work/src/app main.py")
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        [],
+        {"version": "2.1.0"},
+        {"runs": [{"tool": {"driver": {}}}]},
+    ],
+    ids=["not_object", "no_runs", "no_driver_name"],
+)
+def test_parse_sarif_log_rejects_invalid_logs(data: object) -> None:
+    """Data that is not a SARIF log raises ValueError.
+
+    Args:
+        data: Parsed JSON to parse.
+    """
+    with pytest.raises(ValueError):
+        parse_sarif_log(data)
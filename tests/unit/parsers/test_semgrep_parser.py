@@ -238,8 +238,8 @@ def test_semgrep_check_parses_mixed_output_json(
     def fake_run(
         cmd: list[str],
         capture_output: bool,
-        text: bool,
         timeout: int,
+        text: bool = False,
         **kwargs: Any,
     ) -> SimpleNamespace:
         # Handle version check calls
@@ -296,8 +296,8 @@ def test_semgrep_check_handles_nonzero_rc_with_errors_array(
     def fake_run(
         cmd: list[str],
         capture_output: bool,
-        text: bool,
         timeout: int,
+        text: bool = False,
         **kwargs: Any,
     ) -> SimpleNamespace:
         # Handle version check calls
@@ -330,8 +330,8 @@ def test_semgrep_check_handles_unparseable_output(
     def fake_run(
         cmd: list[str],
         capture_output: bool,
-        text: bool,
         timeout: int,
+        text: bool = False,
         **kwargs: Any,
     ) -> SimpleNamespace:
         # Handle version check calls
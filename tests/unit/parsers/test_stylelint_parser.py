@@ -0,0 +1,116 @@
+"""Unit tests for Stylelint parser functionality."""
+
+from __future__ import annotations
+
+import json
+
+from assertpy import assert_that
+
+from lintro.parsers.stylelint.stylelint_parser import parse_stylelint_output
+
+
+def _report(*warnings: dict[str, object], source: str = "/repo/app.css") -> str:
+    """Build a Stylelint JSON report for one style sheet.
+
+    Args:
+        *warnings: Warnings to include.
+        source: Style sheet the warnings belong to.
+
+    Returns:
+        str: Report as printed by ``stylelint --formatter json``.
+    """
+    return json.dumps(
+        [
+            {
+                "source": source,
+                "errored": bool(warnings),
+                "warnings": list(warnings),
+                "deprecations": [],
+                "invalidOptionWarnings": [],
+            },
+        ],
+    )
+
+
+def test_parse_stylelint_output_empty() -> None:
+    """Empty output and clean style sheets yield no issues."""
+    assert_that(parse_stylelint_output("")).is_empty()
+    assert_that(parse_stylelint_output(_report())).is_empty()
+
+
+def test_parse_stylelint_output_malformed_json() -> None:
+    """Malformed JSON yields no issues."""
+    assert_that(parse_stylelint_output("[invalid json")).is_empty()
+
+
+def test_parse_stylelint_output_strips_rule_from_message() -> None:
+    """Messages lose the rule suffix Stylelint appends; severities are kept."""
+    output = _report(
+        {
+            "line": 3,
+            "column": 5,
+            "rule": "color-no-invalid-hex",
+            "severity": "error",
+            "text": 'Unexpected invalid hex color "#ffg" (color-no-invalid-hex)',
+        },
+        {
+            "line": 7,
+            "column": 1,
+            "rule": "scss/at-rule-no-unknown",
+            "severity": "warning",
+            "text": 'Unexpected unknown at-rule "@foo" (scss/at-rule-no-unknown)',
+        },
+    )
+
+    issues = parse_stylelint_output(output)
+
+    assert_that(issues).is_length(2)
+    assert_that(issues[0].file).is_equal_to("/repo/app.css")
+    assert_that(issues[0].line).is_equal_to(3)
+    assert_that(issues[0].column).is_equal_to(5)
+    assert_that(issues[0].code).is_equal_to("color-no-invalid-hex")
+    assert_that(issues[0].message).is_equal_to('Unexpected invalid hex color "#ffg"')
+    assert_that(issues[0].severity).is_equal_to("error")
+    assert_that(issues[1].severity).is_equal_to("warning")
+
+
+def test_parse_stylelint_output_syntax_error() -> None:
+    """Style sheets that cannot be parsed are reported as errors."""
+    output = _report(
+        {
+            "line": 2,
+            "column": 1,
+            "rule": "CssSyntaxError",
+            "severity": "error",
+            "text": "Unclosed block (CssSyntaxError)",
+        },
+        source="/repo/broken.scss",
+    )
+
+    issues = parse_stylelint_output(output)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].code).is_equal_to("CssSyntaxError")
+    assert_that(issues[0].message).is_equal_to("Unclosed block")
+
+
+def test_parse_stylelint_output_ignores_surrounding_text() -> None:
+    """Deprecation notices around the report are skipped."""
+    output = (
+        "Deprecation warnings:\n"
+        + _report(
+            {
+                "line": 1,
+                "column": 1,
+                "rule": "block-no-empty",
+                "severity": "error",
+                "text": "Unexpected empty block (block-no-empty)",
+            },
+        )
+        + "\n"
+    )
+
+    issues = parse_stylelint_output(output)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].code).is_equal_to("block-no-empty")
@@ -0,0 +1,68 @@
+"""Unit tests for the StyLua output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.stylua.stylua_parser import parse_stylua_output
+
+MISMATCHES = {
+    "file": "lua/init.lua",
+    "mismatches": [
+        {
+            "original_start_line": 2,
+            "original_end_line": 5,
+            "expected_start_line": 2,
+            "expected_end_line": 3,
+            "original": "local t = {1,\n2,\n3}\n",
+            "expected": "local t = { 1, 2, 3 }\n",
+        },
+        {
+            "original_start_line": 9,
+            "original_end_line": 10,
+            "expected_start_line": 7,
+            "expected_end_line": 8,
+            "original": "print( 'x' )\n",
+            "expected": 'print("x")\n',
+        },
+    ],
+}
+
+
+def test_parse_stylua_mismatches() -> None:
+    """Mismatches become 1-based, fixable formatting issues."""
+    report = parse_stylua_output(json.dumps(MISMATCHES) + "\n")
+
+    assert_that(report.errors).is_empty()
+    assert_that(report.issues).is_length(2)
+    first, second = report.issues
+    assert_that(first.file).is_equal_to("lua/init.lua")
+    assert_that((first.line, first.end_line)).is_equal_to((3, 5))
+    assert_that(first.message).is_equal_to("Formatting differs on lines 3-5")
+    assert_that(first.expected).is_equal_to("local t = { 1, 2, 3 }\n")
+    assert_that(first.fixable).is_true()
+    assert_that(first.get_severity()).is_equal_to(SeverityLevel.INFO)
+    assert_that(second.message).is_equal_to("Formatting differs on line 10")
+
+
+def test_parse_stylua_errors() -> None:
+    """Files StyLua cannot parse are collected as errors."""
+    output = (
+        "error: could not format file lua/broken.lua: failed to parse: "
+        "unexpected token `end`\n"
+    )
+
+    report = parse_stylua_output(output)
+
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_length(1)
+    assert_that(report.errors[0]).contains("lua/broken.lua")
+
+
+def test_parse_stylua_empty_output() -> None:
+    """Formatted files produce no output and no issues."""
+    report = parse_stylua_output("")
+
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_empty()
@@ -332,3 +332,48 @@ def test_parse_taplo_output_ansi_codes_stripped() -> None:
     assert_that(result).is_length(1)
     assert_that(result[0].file).is_equal_to("pyproject.toml")
     assert_that(result[0].code).is_equal_to("invalid_value")
+
+
+def test_parse_taplo_output_schema_error() -> None:
+    """Parse a schema validation error reported by taplo lint."""
+    output = """error: "2030" is not one of ["2015", "2018", "2021", "2024"]
+  ┌─ Cargo.toml:4:11
+  │
+4 │ edition = "2030"
+  │           ^^^^^^ "2030" is not one of ["2015", "2018", "2021", "2024"]
+
+ERROR operation failed error=some files were not valid"""
+    result = parse_taplo_output(output)
+
+    assert_that(result).is_length(1)
+    assert_that(result[0].file).is_equal_to("Cargo.toml")
+    assert_that(result[0].line).is_equal_to(4)
+    assert_that(result[0].column).is_equal_to(11)
+    assert_that(result[0].code).is_equal_to("schema")
+    assert_that(result[0].message).starts_with('"2030" is not one of')
+
+
+@pytest.mark.parametrize(
+    ("message", "code"),
+    [("invalid TOML", "invalid_toml"), ("conflicting keys", "conflicting_keys")],
+)
+def test_parse_taplo_output_uncoded_lint_errors(message: str, code: str) -> None:
+    """Syntax and key errors from taplo lint get codes of their own.
+
+    Args:
+        message: Diagnostic message printed by taplo.
+        code: Expected issue code.
+    """
+    output = f"error: {message}\n  ┌─ pyproject.toml:2:1"
+    result = parse_taplo_output(output)
+
+    assert_that(result).is_length(1)
+    assert_that(result[0].code).is_equal_to(code)
+
+
+def test_parse_taplo_output_lint_log_line_is_not_a_format_issue() -> None:
+    """Log lines of failed lint runs are not mistaken for format issues."""
+    output = 'ERROR taplo:lint_files: invalid file error=invalid TOML path="a.toml"'
+
+    assert_that(parse_taplo_output(output)).is_empty()
+
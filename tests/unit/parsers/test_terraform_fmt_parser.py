@@ -0,0 +1,54 @@
+"""Unit tests for the terraform fmt output parser."""
+
+from assertpy import assert_that
+
+from lintro.parsers.terraform_fmt.terraform_fmt_parser import (
+    parse_terraform_fmt_output,
+)
+
+
+def test_parse_terraform_fmt_empty() -> None:
+    """Return an empty list for empty parser input."""
+    assert_that(parse_terraform_fmt_output("")).is_empty()
+    assert_that(parse_terraform_fmt_output(None)).is_empty()
+
+
+def test_parse_terraform_fmt_listed_files() -> None:
+    """Each listed file becomes one fixable FORMAT issue."""
+    output = "main.tf\nmodules/vpc/variables.tf\nenv/prod.tfvars\n"
+
+    issues = parse_terraform_fmt_output(output)
+
+    assert_that([i.file for i in issues]).is_equal_to(
+        ["main.tf", "modules/vpc/variables.tf", "env/prod.tfvars"],
+    )
+    assert_that(issues[0].code).is_equal_to("FORMAT")
+    assert_that(issues[0].fixable).is_true()
+    assert_that(issues[0].message).is_equal_to("File is not formatted")
+
+
+def test_parse_terraform_fmt_skips_diagnostics() -> None:
+    """Diagnostics for unparsable files are not reported as files."""
+    output = (
+        "Error: Invalid character\n"
+        "\n"
+        "  on broken.tf line 3, in resource \"x\" \"y\":\n"
+        "   3:   name = @\n"
+        "\n"
+        "tests/main.tftest.hcl\n"
+    )
+
+    issues = parse_terraform_fmt_output(output)
+
+    assert_that([i.file for i in issues]).is_equal_to(["tests/main.tftest.hcl"])
+
+
+def test_parse_terraform_fmt_windows_paths() -> None:
+    """Windows paths with a drive letter are listed files."""
+    output = "C:\\infra\\main.tf\nmodules\\vpc\\variables.tf\n"
+
+    issues = parse_terraform_fmt_output(output)
+
+    assert_that([i.file for i in issues]).is_equal_to(
+        ["C:\\infra\\main.tf", "modules\\vpc\\variables.tf"],
+    )
@@ -0,0 +1,93 @@
+"""Unit tests for the TFLint JSON output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.parsers.tflint.tflint_parser import parse_tflint_output
+
+
+def _range(filename: str, line: int, column: int) -> dict[str, object]:
+    """Build a TFLint source range.
+
+    Args:
+        filename: File the range is in.
+        line: First line of the range.
+        column: First column of the range.
+
+    Returns:
+        dict[str, object]: The range as TFLint serializes it.
+    """
+    return {
+        "filename": filename,
+        "start": {"line": line, "column": column},
+        "end": {"line": line, "column": column + 10},
+    }
+
+
+def test_parse_tflint_empty() -> None:
+    """Output without a JSON report yields no report."""
+    assert_that(parse_tflint_output("")).is_none()
+    assert_that(parse_tflint_output("Failed to initialize plugins")).is_none()
+
+
+def test_parse_tflint_issues() -> None:
+    """Rule violations use the rule name as code and keep its severity."""
+    output = json.dumps(
+        {
+            "issues": [
+                {
+                    "rule": {
+                        "name": "terraform_unused_declarations",
+                        "severity": "warning",
+                        "link": "https://example.com/rule.md",
+                    },
+                    "message": 'variable "region" is declared but not used',
+                    "range": _range("variables.tf", 4, 1),
+                    "callers": [],
+                },
+            ],
+            "errors": [],
+        },
+    )
+
+    report = parse_tflint_output(output)
+
+    assert_that(report).is_not_none()
+    assert report is not None
+    issue = report.issues[0]
+    assert_that(issue.code).is_equal_to("terraform_unused_declarations")
+    assert_that(issue.file).is_equal_to("variables.tf")
+    assert_that(issue.line).is_equal_to(4)
+    assert_that(issue.column).is_equal_to(1)
+    assert_that(str(issue.get_severity())).is_equal_to("WARNING")
+    assert_that(issue.message).contains('"region" is declared')
+    assert_that(report.errors).is_empty()
+
+
+def test_parse_tflint_errors() -> None:
+    """Located errors become issues; others are returned as messages."""
+    output = json.dumps(
+        {
+            "issues": [],
+            "errors": [
+                {
+                    "summary": "Invalid expression",
+                    "message": "main.tf:3,10-11: Invalid expression",
+                    "severity": "error",
+                    "range": _range("main.tf", 3, 10),
+                },
+                {
+                    "message": "Failed to load configurations",
+                    "severity": "error",
+                },
+            ],
+        },
+    )
+
+    report = parse_tflint_output(output)
+
+    assert report is not None
+    assert_that([i.code for i in report.issues]).is_equal_to(["tflint_error"])
+    assert_that(str(report.issues[0].get_severity())).is_equal_to("ERROR")
+    assert_that(report.errors).is_equal_to(["Failed to load configurations"])
@@ -0,0 +1,92 @@
+"""Unit tests for the typos output parser."""
+
+from __future__ import annotations
+
+import json
+
+from assertpy import assert_that
+
+from lintro.parsers.typos.typos_parser import parse_typos_output
+
+
+def _line(**fields: object) -> str:
+    """Serialize one typos JSON message.
+
+    Args:
+        **fields: Message fields.
+
+    Returns:
+        str: The message as a JSON line.
+    """
+    return json.dumps(fields)
+
+
+def test_parse_typos_empty() -> None:
+    """Return an empty list for empty output."""
+    assert_that(parse_typos_output("")).is_empty()
+
+
+def test_parse_typos_content_typo() -> None:
+    """A typo in file contents gets a 1-based column and one correction."""
+    output = _line(
+        type="typo",
+        path="docs/guide.md",
+        line_num=3,
+        byte_offset=8,
+        typo="teh",
+        corrections=["the"],
+    )
+
+    issues = parse_typos_output(output)
+
+    assert_that(issues).is_length(1)
+    issue = issues[0]
+    assert_that(issue.file).is_equal_to("docs/guide.md")
+    assert_that(issue.line).is_equal_to(3)
+    assert_that(issue.column).is_equal_to(9)
+    assert_that(issue.code).is_equal_to("typo")
+    assert_that(issue.message).is_equal_to("`teh` should be `the`")
+    assert_that(issue.fixable).is_true()
+
+
+def test_parse_typos_ambiguous_path_and_disallowed() -> None:
+    """Several corrections, path typos and disallowed words are not fixable."""
+    output = "\n".join(
+        [
+            _line(
+                type="typo",
+                path="src/app.py",
+                line_num=1,
+                byte_offset=0,
+                typo="thier",
+                corrections=["their", "there"],
+            ),
+            _line(
+                type="typo",
+                path="src/recieve.py",
+                byte_offset=4,
+                typo="recieve",
+                corrections=["receive"],
+            ),
+            _line(
+                type="typo",
+                path="src/app.py",
+                line_num=2,
+                byte_offset=4,
+                typo="foo",
+                corrections="Invalid",
+            ),
+            _line(type="binary_file", path="logo.png"),
+            "warning: not json",
+        ],
+    )
+
+    issues = parse_typos_output(output)
+
+    assert_that([i.typo for i in issues]).is_equal_to(["thier", "recieve", "foo"])
+    assert_that(issues[0].message).is_equal_to("`thier` should be `their`, `there`")
+    assert_that(issues[0].fixable).is_false()
+    assert_that(issues[1].line).is_equal_to(0)
+    assert_that(issues[1].column).is_equal_to(0)
+    assert_that(issues[2].message).is_equal_to("`foo` is disallowed")
+    assert_that(issues[2].corrections).is_empty()
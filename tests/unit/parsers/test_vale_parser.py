@@ -0,0 +1,92 @@
+"""Unit tests for the Vale output parser."""
+
+import json
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.vale.vale_parser import parse_vale_output
+
+PASSIVE_ALERT: dict[str, object] = {
+    "Action": {"Name": "", "Params": None},
+    "Span": [5, 18],
+    "Check": "Microsoft.Passive",
+    "Description": "",
+    "Link": "https://docs.microsoft.com/en-us/style-guide/grammar/verbs",
+    "Message": "'is checked' looks like passive voice.",
+    "Severity": "suggestion",
+    "Match": "is checked",
+    "Line": 3,
+}
+
+
+def test_parse_vale_alerts() -> None:
+    """Every alert of every file becomes an issue."""
+    terms_alert = {
+        **PASSIVE_ALERT,
+        "Span": [1, 6],
+        "Check": "Vale.Terms",
+        "Message": "Use 'GitHub' instead of 'Github'.",
+        "Severity": "error",
+        "Match": "Github",
+        "Line": 7,
+    }
+    output = json.dumps(
+        {"README.md": [PASSIVE_ALERT], "docs/usage.rst": [terms_alert]},
+    )
+
+    report = parse_vale_output(output)
+
+    assert report is not None
+    assert_that(report.errors).is_empty()
+    assert_that(report.issues).is_length(2)
+    passive = report.issues[0]
+    assert_that(passive.file).is_equal_to("README.md")
+    assert_that((passive.line, passive.column)).is_equal_to((3, 5))
+    assert_that(passive.end_column).is_equal_to(18)
+    assert_that(passive.code).is_equal_to("Microsoft.Passive")
+    assert_that(passive.match).is_equal_to("is checked")
+    assert_that(passive.link).contains("style-guide")
+    assert_that(passive.get_severity()).is_equal_to(SeverityLevel.INFO)
+    terms = report.issues[1]
+    assert_that(terms.file).is_equal_to("docs/usage.rst")
+    assert_that(terms.get_severity()).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_vale_clean_run() -> None:
+    """An empty object is a report without issues."""
+    report = parse_vale_output("{}\n")
+
+    assert report is not None
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_empty()
+
+
+def test_parse_vale_runtime_error() -> None:
+    """A runtime error object becomes a report error."""
+    output = json.dumps(
+        {
+            "Code": "E100",
+            "Text": "The path 'styles/Microsoft' does not exist.",
+            "Path": "",
+            "Line": 0,
+            "Span": 0,
+        },
+    )
+
+    report = parse_vale_output(output)
+
+    assert report is not None
+    assert_that(report.issues).is_empty()
+    assert_that(report.errors).is_equal_to(
+        ["E100: The path 'styles/Microsoft' does not exist."],
+    )
+
+
+def test_parse_vale_without_json() -> None:
+    """Output without a JSON report returns None."""
+    assert_that(parse_vale_output("")).is_none()
+    assert_that(parse_vale_output(None)).is_none()
+    assert_that(parse_vale_output("vale: command failed")).is_none()
+    assert_that(parse_vale_output("{not json")).is_none()
+    assert_that(parse_vale_output('["README.md"]')).is_none()
@@ -114,3 +114,12 @@ def test_parse_yamllint_output_ansi_codes_stripped() -> None:
     assert_that(result).is_length(1)
     assert_that(result[0].file).is_equal_to("config.yml")
     assert_that(result[0].rule).is_equal_to("trailing-spaces")
+
+
+def test_parse_yamllint_output_windows_paths() -> None:
+    """Keep the drive letter of Windows paths in the file."""
+    output = "D:\\a\\repo\\config.yml:5:10: [error] trailing spaces (trailing-spaces)"
+    result = parse_yamllint_output(output)
+    assert_that(result).is_length(1)
+    assert_that(result[0].file).is_equal_to("D:\\a\\repo\\config.yml")
+    assert_that(result[0].line).is_equal_to(5)
@@ -79,6 +79,42 @@ def test_fix_raises_not_implemented_when_cannot_fix() -> None:
         plugin.fix([], {})
 
 
+# =============================================================================
+# BaseToolPlugin.capabilities Tests
+# =============================================================================
+
+
+def test_capabilities_follow_definition(fake_tool_plugin: FakeToolPlugin) -> None:
+    """Capabilities mirror the definition without running the tool.
+
+    Args:
+        fake_tool_plugin: The fake tool plugin instance to test.
+    """
+    with patch("lintro.plugins.base.detect_tool_version") as mock_detect:
+        capabilities = fake_tool_plugin.capabilities()
+
+    mock_detect.assert_not_called()
+    assert_that(capabilities.to_dict()).is_equal_to(
+        {
+            "supports_fix": True,
+            "version_detected": None,
+        },
+    )
+    assert_that(NoFixPlugin().capabilities().supports_fix).is_false()
+
+
+def test_capabilities_detect_version(fake_tool_plugin: FakeToolPlugin) -> None:
+    """Version detection is opt-in.
+
+    Args:
+        fake_tool_plugin: The fake tool plugin instance to test.
+    """
+    with patch("lintro.plugins.base.detect_tool_version", return_value="1.2.3"):
+        capabilities = fake_tool_plugin.capabilities(detect_version=True)
+
+    assert_that(capabilities.version_detected).is_equal_to("1.2.3")
+
+
 # =============================================================================
 # BaseToolPlugin._validate_paths Tests
 # =============================================================================
@@ -500,6 +536,7 @@ def test_get_executable_command_unknown_tool(fake_tool_plugin: FakeToolPlugin) -
     "pattern",
     [
         pytest.param(".git", id="git_directory"),
+        pytest.param(".lintro", id="lintro_reports"),
         pytest.param("__pycache__", id="pycache_directory"),
         pytest.param("*.pyc", id="pyc_files"),
     ],
@@ -89,6 +89,34 @@ def test_run_subprocess_file_not_found_raises(
             fake_tool_plugin._run_subprocess(["nonexistent"])
 
 
+def test_run_subprocess_replaces_undecodable_output(
+    fake_tool_plugin: FakeToolPlugin,
+) -> None:
+    """Verify invalid bytes in the output are replaced and reported.
+
+    Args:
+        fake_tool_plugin: The fake tool plugin instance to test.
+    """
+    with (
+        patch("subprocess.run") as mock_run,
+        patch("lintro.utils.text_decoding._locale_encoding", return_value=None),
+        patch(
+            "lintro.plugins.subprocess_executor.report_undecodable",
+        ) as mock_report,
+    ):
+        mock_run.return_value = MagicMock(
+            returncode=1,
+            stdout=b"caf\xe9.py:1: E501\r\n",
+            stderr=b"",
+        )
+        success, output = fake_tool_plugin._run_subprocess(["ruff", "check"])
+
+    assert_that(success).is_false()
+    assert_that(output).is_equal_to("caf\ufffd.py:1: E501\n")
+    mock_report.assert_called_once()
+    assert_that(mock_report.call_args.args[1]).is_equal_to(1)
+
+
 # =============================================================================
 # BaseToolPlugin._get_effective_timeout Tests
 # =============================================================================
@@ -249,6 +277,7 @@ def test_verify_tool_version_fails_returns_skip_result(
     with patch("lintro.tools.core.version_requirements.check_tool_version") as mock:
         mock.return_value = MagicMock(
             version_check_passed=False,
+            pin_mismatch=False,
             error_message="Version too old",
             min_version="1.0.0",
             install_hint="pip install tool",
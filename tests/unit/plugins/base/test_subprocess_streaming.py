@@ -8,7 +8,10 @@ from unittest.mock import MagicMock, patch
 import pytest
 from assertpy import assert_that
 
-from lintro.plugins.subprocess_executor import run_subprocess_streaming
+from lintro.plugins.subprocess_executor import (
+    OUTPUT_TRUNCATED_MARKER,
+    run_subprocess_streaming,
+)
 
 # =============================================================================
 # run_subprocess_streaming - Success Cases
@@ -163,3 +166,29 @@ def test_streaming_with_cwd_and_env() -> None:
         # Custom env is merged with os.environ to preserve PATH
         assert_that(call_kwargs["env"]["MY_VAR"]).is_equal_to("value")
         assert_that(call_kwargs["env"]).contains_key("PATH")
+
+
+def test_streaming_caps_kept_output() -> None:
+    """Verify output past the cap is dropped but still reaches the handler."""
+    lines_received: list[str] = []
+
+    with patch("lintro.plugins.subprocess_executor.subprocess.Popen") as mock_popen:
+        mock_process = MagicMock()
+        mock_process.stdout = iter(["aaaa\n", "bbbb\n", "cccc\n", "d\n"])
+        mock_process.wait.return_value = 0
+        mock_popen.return_value = mock_process
+
+        success, output = run_subprocess_streaming(
+            ["cargo", "clippy"],
+            timeout=30,
+            line_handler=lines_received.append,
+            max_output_chars=12,
+        )
+
+    assert_that(success).is_true()
+    assert_that(lines_received).is_equal_to(["aaaa", "bbbb", "cccc", "d"])
+    assert_that(output).is_equal_to(
+        "aaaa\nbbbb\n"
+        + OUTPUT_TRUNCATED_MARKER.format(omitted=7),
+    )
+
@@ -0,0 +1,263 @@
+"""Tests for the container execution backend."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+import lintro.plugins.container_backend as container_backend
+from lintro.config.config_loader import _parse_execution_config, _parse_tool_config
+from lintro.config.execution_config import ExecutionConfig
+from lintro.config.lintro_config import LintroConfig
+from lintro.config.tool_config import LintroToolConfig
+from lintro.plugins.container_backend import (
+    DEFAULT_CONTAINER_IMAGE,
+    ContainerSpec,
+    build_container_command,
+    resolve_container_spec,
+)
+
+
+def _container_config(**tools: LintroToolConfig) -> LintroConfig:
+    """Build a config that selects the container backend.
+
+    Args:
+        **tools: Per-tool configuration keyed by tool name.
+
+    Returns:
+        LintroConfig: Configuration using docker with a shared image.
+    """
+    return LintroConfig(
+        execution=ExecutionConfig(
+            backend="container",
+            container_runtime="docker",
+            container_image="example/tools:1.0",
+        ),
+        tools=dict(tools),
+    )
+
+
+def test_check_mounts_workspace_read_only(tmp_path: Path) -> None:
+    """Checks run from the image with the workspace mounted read-only.
+
+    Args:
+        tmp_path: Temporary directory used as the workspace.
+    """
+    workspace = str(tmp_path)
+    spec = ContainerSpec(image="img:1", runtime="docker", workspace=workspace)
+
+    cmd = build_container_command(
+        ["/usr/local/bin/hadolint", f"{workspace}/Dockerfile"],
+        spec,
+    )
+
+    assert_that(cmd[:5]).is_equal_to(
+        ["docker", "run", "--rm", "--entrypoint", "hadolint"],
+    )
+    assert_that(cmd).contains(f"{workspace}:{workspace}:ro")
+    assert_that(cmd).does_not_contain("--user")
+    assert_that(cmd[-2:]).is_equal_to(["img:1", f"{workspace}/Dockerfile"])
+
+
+def test_check_builds_outside_read_only_workspace(tmp_path: Path) -> None:
+    """Build output such as Cargo's target/ goes to a writable directory.
+
+    Args:
+        tmp_path: Temporary directory used as the workspace.
+    """
+    workspace = str(tmp_path)
+    spec = ContainerSpec(image="img:1", runtime="docker", workspace=workspace)
+
+    cmd = build_container_command(["cargo", "clippy", "--message-format=json"], spec)
+
+    assert_that(cmd).contains("CARGO_TARGET_DIR=/tmp/cargo-target")
+    assert_that(cmd[-3:]).is_equal_to(["img:1", "clippy", "--message-format=json"])
+
+
+def test_fix_mounts_workspace_read_write(tmp_path: Path) -> None:
+    """Fixes mount the workspace read-write and run as the host user.
+
+    Args:
+        tmp_path: Temporary directory used as the workspace.
+    """
+    workspace = str(tmp_path)
+    spec = ContainerSpec(
+        image="img:1",
+        runtime="podman",
+        workspace=workspace,
+        writable=True,
+    )
+
+    cmd = build_container_command(["shfmt", "-w", "run.sh"], spec, cwd=workspace)
+
+    assert_that(cmd[0]).is_equal_to("podman")
+    assert_that(cmd).contains(f"{workspace}:{workspace}:rw")
+    assert_that(cmd[cmd.index("-w") + 1]).is_equal_to(workspace)
+    assert_that(cmd).contains("--user")
+    assert_that(cmd[-3:]).is_equal_to(["img:1", "-w", "run.sh"])
+
+
+@pytest.mark.parametrize(
+    "host_cmd,entrypoint,args",
+    [
+        (["/usr/bin/python3", "-m", "mypy", "src"], "mypy", ["src"]),
+        (["bunx", "prettier", "--check", "a.md"], "prettier", ["--check", "a.md"]),
+        (["cargo", "clippy"], "cargo", ["clippy"]),
+    ],
+)
+def test_host_launchers_are_stripped(
+    tmp_path: Path,
+    host_cmd: list[str],
+    entrypoint: str,
+    args: list[str],
+) -> None:
+    """Host launchers are dropped so the tool runs from the image's PATH.
+
+    Args:
+        tmp_path: Temporary directory used as the workspace.
+        host_cmd: Command as built for the host.
+        entrypoint: Expected container entrypoint.
+        args: Expected arguments after the image.
+    """
+    spec = ContainerSpec(image="img:1", runtime="docker", workspace=str(tmp_path))
+
+    cmd = build_container_command(host_cmd, spec)
+
+    assert_that(cmd[cmd.index("--entrypoint") + 1]).is_equal_to(entrypoint)
+    assert_that(cmd[cmd.index("img:1") + 1 :]).is_equal_to(args)
+
+
+def test_paths_outside_workspace_are_mounted(tmp_path: Path) -> None:
+    """Config files outside the workspace are mounted read-only.
+
+    Args:
+        tmp_path: Temporary directory holding the workspace and a config file.
+    """
+    workspace = tmp_path / "project"
+    workspace.mkdir()
+    config = tmp_path / "generated.yaml"
+    config.write_text("rules: {}\n")
+    spec = ContainerSpec(image="img:1", runtime="docker", workspace=str(workspace))
+
+    cmd = build_container_command(
+        ["yamllint", f"--config-file={config}", str(workspace / "a.yaml")],
+        spec,
+    )
+
+    assert_that(cmd).contains(f"{config}:{config}:ro")
+    assert_that(cmd).does_not_contain(
+        f"{workspace / 'a.yaml'}:{workspace / 'a.yaml'}:ro",
+    )
+
+
+def test_only_environment_overrides_are_forwarded(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Host environment stays outside; explicit overrides are passed with -e.
+
+    Args:
+        tmp_path: Temporary directory used as the workspace.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setenv("HOME", "/home/dev")
+    spec = ContainerSpec(image="img:1", runtime="docker", workspace=str(tmp_path))
+
+    cmd = build_container_command(
+        ["ruff", "check"],
+        spec,
+        env={"HOME": "/home/dev", "NO_COLOR": "1"},
+    )
+
+    assert_that(cmd).contains("NO_COLOR=1")
+    assert_that(cmd).does_not_contain("HOME=/home/dev")
+
+
+def test_host_backend_resolves_to_none() -> None:
+    """The default host backend runs tools without a container."""
+    spec = resolve_container_spec("ruff", LintroConfig(), writable=False)
+
+    assert_that(spec).is_none()
+
+
+def test_tool_image_overrides_shared_image(monkeypatch: pytest.MonkeyPatch) -> None:
+    """A per-tool image wins over execution.container_image.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        container_backend.shutil,
+        "which",
+        lambda name: f"/usr/bin/{name}",
+    )
+    config = _container_config(
+        hadolint=LintroToolConfig(image="hadolint/hadolint:v2.12.0"),
+    )
+
+    hadolint = resolve_container_spec(
+        "hadolint",
+        config,
+        writable=False,
+        workspace="/work",
+    )
+    ruff = resolve_container_spec("ruff", config, writable=True, workspace="/work")
+
+    assert hadolint is not None and ruff is not None
+    assert_that(hadolint.image).is_equal_to("hadolint/hadolint:v2.12.0")
+    assert_that(ruff.image).is_equal_to("example/tools:1.0")
+    assert_that(ruff.writable).is_true()
+
+
+def test_default_image_matches_release(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Without configured images the Lintro image for this release is used.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        container_backend.shutil,
+        "which",
+        lambda name: "/usr/bin/podman" if name == "podman" else None,
+    )
+    config = LintroConfig(execution=ExecutionConfig(backend="container"))
+
+    spec = resolve_container_spec("ruff", config, writable=False, workspace="/w")
+
+    assert spec is not None
+    assert_that(spec.image).is_equal_to(DEFAULT_CONTAINER_IMAGE)
+    assert_that(spec.runtime).is_equal_to("podman")
+
+
+def test_missing_runtime_raises(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Selecting the container backend without a runtime is an error.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(container_backend.shutil, "which", lambda name: None)
+
+    with pytest.raises(RuntimeError, match="docker is not installed"):
+        resolve_container_spec("ruff", _container_config(), writable=False)
+
+
+def test_container_settings_are_parsed() -> None:
+    """Backend, runtime and images are read from the config file."""
+    execution = _parse_execution_config(
+        {
+            "backend": "container",
+            "container_runtime": "podman",
+            "container_image": "example/tools:1.0",
+        },
+    )
+    tool = _parse_tool_config({"image": "hadolint/hadolint:v2.12.0"})
+
+    assert_that(execution.backend).is_equal_to("container")
+    assert_that(execution.container_runtime).is_equal_to("podman")
+    assert_that(execution.container_image).is_equal_to("example/tools:1.0")
+    assert_that(tool.image).is_equal_to("hadolint/hadolint:v2.12.0")
+
+    with pytest.raises(ValueError, match="image must be a string"):
+        _parse_tool_config({"image": 2})
@@ -0,0 +1,210 @@
+"""Tests for running tools through their daemon clients."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_tool_config
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.action import Action
+from lintro.plugins import daemon_backend
+from lintro.plugins.daemon_backend import (
+    DAEMON_CLIENTS,
+    DaemonSpec,
+    build_daemon_command,
+    daemon_statuses,
+    ensure_daemon_started,
+    resolve_daemon_spec,
+    stop_daemons,
+)
+from lintro.utils.execution.tool_configuration import configure_tool_for_execution
+
+if TYPE_CHECKING:
+    from tests.unit.plugins.conftest import FakeToolPlugin
+
+ESLINT_D = "/usr/local/bin/eslint_d"
+
+
+@pytest.fixture
+def eslint_daemon(monkeypatch: pytest.MonkeyPatch) -> DaemonSpec:
+    """Provide eslint's daemon settings with no server started yet.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        DaemonSpec: Daemon settings for eslint.
+    """
+    monkeypatch.setattr(daemon_backend, "_started", set())
+    return DaemonSpec(tool="eslint", client=DAEMON_CLIENTS["eslint"], path=ESLINT_D)
+
+
+def test_resolve_daemon_spec_uses_installed_client() -> None:
+    """A tool with ``daemon: true`` runs through its installed client."""
+    config = LintroConfig(tools={"eslint": _parse_tool_config({"daemon": True})})
+
+    with patch.object(daemon_backend, "find_daemon_client", return_value=ESLINT_D):
+        spec = resolve_daemon_spec("eslint", config)
+
+    assert_that(spec).is_equal_to(
+        DaemonSpec(tool="eslint", client=DAEMON_CLIENTS["eslint"], path=ESLINT_D),
+    )
+    assert_that(resolve_daemon_spec("prettier", config)).is_none()
+
+
+@pytest.mark.parametrize(
+    ("tool", "client_path", "message"),
+    [
+        ("ruff", ESLINT_D, "has no daemon client"),
+        ("eslint", None, "eslint_d is not installed"),
+    ],
+)
+def test_resolve_daemon_spec_falls_back_with_warning(
+    tool: str,
+    client_path: str | None,
+    message: str,
+) -> None:
+    """Without a usable client the tool runs directly after a warning.
+
+    Args:
+        tool: Tool with ``daemon: true``.
+        client_path: Path the client is found at, or None if missing.
+        message: Expected part of the warning.
+    """
+    config = LintroConfig(tools={tool: _parse_tool_config({"daemon": True})})
+
+    with (
+        patch.object(daemon_backend, "find_daemon_client", return_value=client_path),
+        patch.object(daemon_backend, "logger") as mock_logger,
+    ):
+        spec = resolve_daemon_spec(tool, config)
+
+    assert_that(spec).is_none()
+    assert_that(mock_logger.warning.call_args.args[0]).contains(message)
+
+
+@pytest.mark.parametrize(
+    "cmd",
+    [
+        ["bunx", "eslint", "--format", "json", "src/a.js"],
+        ["npx", "eslint", "--format", "json", "src/a.js"],
+        ["/repo/node_modules/.bin/eslint", "--format", "json", "src/a.js"],
+    ],
+)
+def test_build_daemon_command_replaces_the_tool(
+    eslint_daemon: DaemonSpec,
+    cmd: list[str],
+) -> None:
+    """The client takes the tool's arguments, whatever launched it.
+
+    Args:
+        eslint_daemon: Daemon settings for eslint.
+        cmd: Command as built for the host.
+    """
+    assert_that(build_daemon_command(cmd, eslint_daemon)).is_equal_to(
+        [ESLINT_D, "--format", "json", "src/a.js"],
+    )
+
+
+def test_build_daemon_command_keeps_other_commands(
+    eslint_daemon: DaemonSpec,
+) -> None:
+    """Commands that do not run the tool itself are left alone.
+
+    Args:
+        eslint_daemon: Daemon settings for eslint.
+    """
+    cmd = ["node", "--version"]
+
+    assert_that(build_daemon_command(cmd, eslint_daemon)).is_same_as(cmd)
+
+
+def test_ensure_daemon_started_starts_once_per_run(
+    eslint_daemon: DaemonSpec,
+) -> None:
+    """Later invocations reuse the server started by the first one.
+
+    Args:
+        eslint_daemon: Daemon settings for eslint.
+    """
+    with patch.object(daemon_backend, "_control", return_value=(True, "")) as control:
+        ensure_daemon_started(eslint_daemon)
+        ensure_daemon_started(eslint_daemon)
+
+    control.assert_called_once_with(ESLINT_D, ("start",))
+
+
+def test_run_subprocess_routes_through_daemon(
+    fake_tool_plugin: FakeToolPlugin,
+    eslint_daemon: DaemonSpec,
+) -> None:
+    """A plugin with a daemon runs its tool commands through the client.
+
+    Args:
+        fake_tool_plugin: The fake tool plugin instance to test.
+        eslint_daemon: Daemon settings for eslint.
+    """
+    fake_tool_plugin.daemon = eslint_daemon
+
+    with (
+        patch.object(daemon_backend, "_control", return_value=(True, "")),
+        patch(
+            "lintro.plugins.base.run_subprocess",
+            return_value=(True, ""),
+        ) as mock_run,
+    ):
+        fake_tool_plugin._run_subprocess(["npx", "eslint", "src/a.js"])
+
+    assert_that(mock_run.call_args.args[0]).is_equal_to([ESLINT_D, "src/a.js"])
+
+
+def test_configured_daemon_reaches_the_tool() -> None:
+    """configure_tool_for_execution resolves the daemon of host tools."""
+    tool = MagicMock()
+    tool.definition.native = False
+    config = LintroConfig(tools={"eslint": _parse_tool_config({"daemon": True})})
+
+    with patch.object(daemon_backend, "find_daemon_client", return_value=ESLINT_D):
+        configure_tool_for_execution(
+            tool=tool,
+            tool_name="eslint",
+            config_manager=MagicMock(),
+            tool_option_dict={},
+            exclude=None,
+            include_venv=False,
+            incremental=False,
+            action=Action.CHECK,
+            post_tools=set(),
+            lintro_config=config,
+        )
+
+    assert_that(tool.daemon.path).is_equal_to(ESLINT_D)
+
+
+def test_daemon_statuses_and_stop(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Status reports whether the server runs; stop tells the client to stop.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(daemon_backend, "_started", {ESLINT_D})
+
+    with (
+        patch.object(daemon_backend, "find_daemon_client", return_value=ESLINT_D),
+        patch.object(
+            daemon_backend,
+            "_control",
+            return_value=(True, "eslint_d: Running"),
+        ) as control,
+    ):
+        statuses = daemon_statuses()
+        stopped = stop_daemons(["eslint"])
+
+    assert_that(statuses[0].running).is_true()
+    assert_that(stopped).is_equal_to(["eslint"])
+    control.assert_called_with(ESLINT_D, ("stop",))
+    assert_that(daemon_backend._started).is_empty()
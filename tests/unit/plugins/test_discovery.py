@@ -12,6 +12,7 @@ from lintro.plugins.discovery import (
     BUILTIN_DEFINITIONS_PATH,
     ENTRY_POINT_GROUP,
     discover_all_tools,
+    discover_builtin_tool,
     discover_builtin_tools,
     discover_external_plugins,
     is_discovered,
@@ -68,6 +69,21 @@ def test_discover_builtin_tools_handles_missing_path(tmp_path: Path) -> None:
         assert_that(result).is_equal_to(0)
 
 
+@pytest.mark.parametrize(
+    ("name", "expected"),
+    [("rustfmt", True), ("Astro-Check", True), ("__init__", False), ("nope", False)],
+    ids=["builtin", "hyphenated", "private", "unknown"],
+)
+def test_discover_builtin_tool_loads_one_definition(name: str, expected: bool) -> None:
+    """Load a single builtin tool by name.
+
+    Args:
+        name: Tool name to load.
+        expected: Whether the tool is registered afterwards.
+    """
+    assert_that(discover_builtin_tool(name)).is_equal_to(expected)
+
+
 # =============================================================================
 # Tests for discover_external_plugins
 # =============================================================================
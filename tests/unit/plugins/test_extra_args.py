@@ -0,0 +1,179 @@
+"""Tests for extra arguments passed through to tools."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_tool_config
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.action import Action
+from lintro.plugins.extra_args import (
+    insert_extra_args,
+    parse_extra_args_option,
+    resolve_extra_args,
+)
+from lintro.utils.execution.tool_configuration import configure_tool_for_execution
+
+if TYPE_CHECKING:
+    from tests.unit.plugins.conftest import FakeToolPlugin
+
+
+@pytest.mark.parametrize(
+    ("cmd", "executable", "binaries", "expected"),
+    [
+        (
+            ["python", "-m", "ruff", "check", "--fix", "a.py"],
+            ["python", "-m", "ruff"],
+            {"python", "ruff"},
+            ["python", "-m", "ruff", "check", "--x", "--fix", "a.py"],
+        ),
+        (
+            ["cargo", "+nightly", "clippy", "--workspace"],
+            ["cargo", "clippy"],
+            {"cargo", "rustc"},
+            ["cargo", "+nightly", "clippy", "--x", "--workspace"],
+        ),
+        (
+            ["/usr/bin/hadolint", "--no-color", "Dockerfile"],
+            ["hadolint"],
+            {"hadolint"},
+            ["/usr/bin/hadolint", "--x", "--no-color", "Dockerfile"],
+        ),
+        (
+            ["git", "ls-files"],
+            ["hadolint"],
+            {"hadolint"},
+            ["git", "ls-files"],
+        ),
+    ],
+    ids=["python_module", "cargo_toolchain", "binary_path", "other_program"],
+)
+def test_insert_extra_args_after_subcommands(
+    cmd: list[str],
+    executable: list[str],
+    binaries: set[str],
+    expected: list[str],
+) -> None:
+    """Arguments follow the executable and subcommands of the tool's commands.
+
+    Args:
+        cmd: Command built by the plugin.
+        executable: Command prefix that launches the tool.
+        binaries: Executable names that run the tool.
+        expected: Command with the extra arguments.
+    """
+    result = insert_extra_args(cmd, ["--x"], executable, binaries)
+
+    assert_that(result).is_equal_to(expected)
+
+
+def test_insert_extra_args_ignores_existing_paths(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A word naming an existing path is not taken for a subcommand.
+
+    Args:
+        tmp_path: Temporary directory holding the checked directory.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    monkeypatch.chdir(tmp_path)
+
+    result = insert_extra_args(["shellcheck", "src"], ["-x"], [], {"shellcheck"})
+
+    assert_that(result).is_equal_to(["shellcheck", "-x", "src"])
+
+
+def test_parse_extra_args_option_combines_items() -> None:
+    """Items are split like a shell and combined per tool."""
+    parsed = parse_extra_args_option(
+        ["Clippy:--locked --features 'a b'", "clippy:--offline", "ruff:--preview"],
+    )
+
+    assert_that(parsed).is_equal_to(
+        {
+            "clippy": ["--locked", "--features", "a b", "--offline"],
+            "ruff": ["--preview"],
+        },
+    )
+
+
+@pytest.mark.parametrize("value", ["--locked", "clippy:", ":--locked"])
+def test_parse_extra_args_option_rejects_malformed_items(value: str) -> None:
+    """Items need a tool name and arguments.
+
+    Args:
+        value: Malformed --extra-args value.
+    """
+    with pytest.raises(ValueError, match="TOOL:ARGS"):
+        parse_extra_args_option([value])
+
+
+def test_resolve_extra_args_appends_command_line_args() -> None:
+    """Configured arguments come first, then command-line ones."""
+    config = LintroConfig(
+        tools={"clippy": _parse_tool_config({"extra_args": "--locked --no-deps"})},
+    )
+
+    assert_that(resolve_extra_args("clippy", config, ["--offline"])).is_equal_to(
+        ["--locked", "--no-deps", "--offline"],
+    )
+    assert_that(resolve_extra_args("ruff", config)).is_empty()
+    with pytest.raises(ValueError):
+        resolve_extra_args("clippy", config, 3)
+
+
+def test_tool_config_rejects_invalid_extra_args() -> None:
+    """extra_args must be a string or a list of strings."""
+    with pytest.raises(ValueError, match="extra_args"):
+        _parse_tool_config({"extra_args": 5})
+    with pytest.raises(ValueError, match="extra_args"):
+        _parse_tool_config({"extra_args": ["--ok", 5]})
+
+
+def test_tool_options_extra_args_reach_the_tool() -> None:
+    """--tool-options extra_args are set on the tool, not passed as options."""
+    tool = MagicMock()
+    tool.definition.native = False
+    config = LintroConfig(
+        tools={"clippy": _parse_tool_config({"extra_args": ["--locked"]})},
+    )
+
+    configure_tool_for_execution(
+        tool=tool,
+        tool_name="clippy",
+        config_manager=MagicMock(),
+        tool_option_dict={"clippy": {"extra_args": ["--offline"]}},
+        exclude=None,
+        include_venv=False,
+        incremental=False,
+        action=Action.CHECK,
+        post_tools=set(),
+        lintro_config=config,
+    )
+
+    assert_that(tool.extra_args).is_equal_to(["--locked", "--offline"])
+    for call in tool.set_options.call_args_list:
+        assert_that(call.kwargs).does_not_contain_key("extra_args")
+
+
+def test_run_subprocess_adds_extra_args(fake_tool_plugin: FakeToolPlugin) -> None:
+    """Commands run through the plugin get its extra arguments.
+
+    Args:
+        fake_tool_plugin: The fake tool plugin instance to test.
+    """
+    fake_tool_plugin.extra_args = ["--strict"]
+    with patch("subprocess.run") as mock_run:
+        mock_run.return_value = MagicMock(returncode=0, stdout="", stderr="")
+        fake_tool_plugin._run_subprocess(["fake-tool", "check", "a.py"])
+
+    assert_that(mock_run.call_args.args[0]).is_equal_to(
+        ["fake-tool", "check", "--strict", "a.py"],
+    )
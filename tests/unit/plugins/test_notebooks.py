@@ -0,0 +1,149 @@
+"""Tests for checking Jupyter notebooks with Python tools."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.bandit.bandit_issue import BanditIssue
+from lintro.plugins.notebooks import _MAGIC_RE, check_notebooks, read_notebook
+from lintro.tools.definitions.bandit import BanditPlugin
+
+
+def _write_notebook(
+    path: Path,
+    cells: list[tuple[str, str]],
+    **metadata: object,
+) -> None:
+    """Write a notebook file.
+
+    Args:
+        path: Notebook file to write.
+        cells: (cell type, source) of each cell.
+        **metadata: Notebook metadata.
+    """
+    path.write_text(
+        json.dumps(
+            {
+                "cells": [
+                    {"cell_type": kind, "source": source.splitlines(keepends=True)}
+                    for kind, source in cells
+                ],
+                "metadata": metadata,
+                "nbformat": 4,
+                "nbformat_minor": 5,
+            },
+        ),
+    )
+
+
+def test_read_notebook_maps_script_lines_to_cells(tmp_path: Path) -> None:
+    """Code cells become one script; magics keep their lines as pass.
+
+    Args:
+        tmp_path: Temporary directory path for the notebook.
+    """
+    notebook = tmp_path / "nb.ipynb"
+    _write_notebook(
+        notebook,
+        [
+            ("markdown", "# Title"),
+            ("code", "import os\n%time x = 1\nfor f in []:\n    !ls"),
+            ("code", "%%bash\necho hi"),
+            ("code", "os?"),
+        ],
+    )
+
+    script = read_notebook(str(notebook), "nb.ipynb")
+
+    assert script is not None
+    assert_that(script.source).is_equal_to(
+        "import os\npass\nfor f in []:\n    pass\n\n\npass\n",
+    )
+    assert_that(script.locate(4)).is_equal_to((2, 4))
+    assert_that(script.locate(7)).is_equal_to((4, 1))
+    assert_that(script.locate(8)).is_none()
+
+
+@pytest.mark.parametrize(
+    ("line", "is_magic"),
+    [
+        ("os?", True),
+        ("os.path.join??", True),
+        ("?os", True),
+        ('print("done?")', False),
+        ('x = "why?"', False),
+        ("# is this right?", False),
+    ],
+)
+def test_only_help_syntax_counts_as_magic(line: str, is_magic: bool) -> None:
+    """A trailing ? is help syntax only after a bare dotted name.
+
+    Args:
+        line: Code cell line.
+        is_magic: Whether IPython runs the line as a magic.
+    """
+    assert_that(bool(_MAGIC_RE.match(line))).is_equal_to(is_magic)
+
+
+def test_read_notebook_skips_other_kernels(tmp_path: Path) -> None:
+    """Notebooks of other languages are not checked.
+
+    Args:
+        tmp_path: Temporary directory path for the notebook.
+    """
+    notebook = tmp_path / "nb.ipynb"
+    _write_notebook(notebook, [("code", "x <- 1")], language_info={"name": "R"})
+
+    assert_that(read_notebook(str(notebook), "nb.ipynb")).is_none()
+    (tmp_path / "bad.ipynb").write_text("{")
+    with pytest.raises(ValueError, match="Cannot read notebook"):
+        read_notebook(str(tmp_path / "bad.ipynb"), "bad.ipynb")
+
+
+def test_check_notebooks_reports_issues_by_cell(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Issues in the generated scripts point at notebook cells.
+
+    Args:
+        tmp_path: Temporary directory path for the project.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    _write_notebook(
+        tmp_path / "analysis.ipynb",
+        [("markdown", "Intro"), ("code", "import subprocess\nsubprocess.call(x)")],
+    )
+    tool = BanditPlugin()
+
+    def _check_files(paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Report an issue on the second line of every script.
+
+        Args:
+            paths: Directory of generated scripts.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: One issue per script.
+        """
+        issues = [
+            BanditIssue(file=str(script), line=2, message="subprocess call")
+            for script in Path(paths[0]).glob("*.py")
+        ]
+        return ToolResult(name="bandit", issues=issues, issues_count=len(issues))
+
+    base = ToolResult(name="bandit", success=True, issues=[], issues_count=0)
+    result = check_notebooks(tool, ["."], {}, base, _check_files)
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    issue = result.issues[0]  # type: ignore[index]
+    assert_that((issue.file, issue.cell, issue.line)).is_equal_to(
+        ("analysis.ipynb", 2, 2),
+    )
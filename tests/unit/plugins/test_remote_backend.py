@@ -0,0 +1,240 @@
+"""Tests for the remote (SSH) execution backend."""
+
+from __future__ import annotations
+
+import shlex
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+import lintro.plugins.remote_backend as remote_backend
+from lintro.config.config_loader import _parse_execution_config
+from lintro.config.execution_config import ExecutionConfig, RemoteConfig
+from lintro.config.lintro_config import LintroConfig
+from lintro.plugins.remote_backend import (
+    RemoteSpec,
+    build_remote_command,
+    localize_output,
+    resolve_remote_spec,
+    run_remote,
+    sync_dir_name,
+    sync_to_remote,
+)
+
+
+def _remote_config(**settings: object) -> LintroConfig:
+    """Build a config that runs hadolint on a remote host.
+
+    Args:
+        **settings: Overrides for the remote section.
+
+    Returns:
+        LintroConfig: Configuration with a remote host.
+    """
+    remote = {"host": "ci@mac-mini", "tools": ["hadolint"], **settings}
+    return LintroConfig(
+        execution=ExecutionConfig(remote=RemoteConfig.model_validate(remote)),
+    )
+
+
+def _installed(*names: str) -> object:
+    """Build a ``shutil.which`` replacement.
+
+    Args:
+        *names: Executables that are installed.
+
+    Returns:
+        object: Function returning a path for installed executables only.
+    """
+    return lambda name: f"/usr/bin/{name}" if name in names else None
+
+
+def test_only_listed_tools_run_remotely(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Tools not in execution.remote.tools stay local.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(remote_backend.shutil, "which", _installed("ssh", "rsync"))
+    config = _remote_config(port=2222)
+
+    spec = resolve_remote_spec("hadolint", config, writable=False, workspace="/w/app")
+
+    assert_that(resolve_remote_spec("ruff", config, writable=False)).is_none()
+    assert_that(resolve_remote_spec("hadolint", LintroConfig(), False)).is_none()
+    assert spec is not None
+    assert_that(spec.workdir).is_equal_to(f"/tmp/lintro/{sync_dir_name('/w/app')}")
+    assert_that(spec.sync).is_equal_to("rsync")
+    assert_that(spec.ssh_command()).is_equal_to(
+        ["ssh", "-o", "BatchMode=yes", "-p", "2222", "ci@mac-mini"],
+    )
+
+
+def test_sync_falls_back_to_stream(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Without rsync, auto sync streams a tar archive; ssh is required.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(remote_backend.shutil, "which", _installed("ssh"))
+    spec = resolve_remote_spec("hadolint", _remote_config(), writable=False)
+    assert spec is not None
+    assert_that(spec.sync).is_equal_to("stream")
+
+    with pytest.raises(RuntimeError, match="rsync is not installed"):
+        resolve_remote_spec("hadolint", _remote_config(sync="rsync"), False)
+
+    monkeypatch.setattr(remote_backend.shutil, "which", _installed())
+    with pytest.raises(RuntimeError, match="ssh is not installed"):
+        resolve_remote_spec("hadolint", _remote_config(), writable=False)
+
+
+def test_command_paths_are_translated(tmp_path: Path) -> None:
+    """Workspace and external paths map to the remote directory.
+
+    Args:
+        tmp_path: Temporary directory used as the workspace.
+    """
+    workspace = str(tmp_path)
+    spec = RemoteSpec(host="mac", workdir="/srv/app", workspace=workspace)
+
+    cmd = build_remote_command(
+        ["/usr/local/bin/hadolint", f"--config={workspace}/.hadolint.yaml", "a b"],
+        spec,
+        cwd=f"{workspace}/docker",
+        env={"LINTRO_REMOTE_TEST": "1"},
+        externals={"/tmp/gen.json": "/srv/app/.lintro-external/x-gen.json"},
+    )
+
+    assert_that(cmd[:-1]).is_equal_to(["ssh", "-o", "BatchMode=yes", "mac"])
+    assert_that(shlex.split(cmd[-1])).is_equal_to(
+        [
+            "cd",
+            "/srv/app/docker",
+            "&&",
+            "env",
+            "LINTRO_REMOTE_TEST=1",
+            "hadolint",
+            "--config=/srv/app/.hadolint.yaml",
+            "a b",
+        ],
+    )
+
+
+def test_output_paths_are_localized() -> None:
+    """Remote paths in output point back at local files."""
+    spec = RemoteSpec(host="mac", workdir="/srv/app", workspace="/home/me/app")
+
+    output = localize_output(
+        "/srv/app/Dockerfile:3 DL3008\n/srv/app-old/x\n/srv/app/.lintro-external/a",
+        spec,
+        externals={"/tmp/a": "/srv/app/.lintro-external/a"},
+    )
+
+    assert_that(output).is_equal_to(
+        "/home/me/app/Dockerfile:3 DL3008\n/srv/app-old/x\n/tmp/a",
+    )
+
+
+def test_fixes_are_copied_back(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Writable runs sync before and after; read-only runs sync once.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[str] = []
+    monkeypatch.setattr(remote_backend, "_synced", set())
+    monkeypatch.setattr(
+        remote_backend,
+        "sync_to_remote",
+        lambda spec, timeout: calls.append("up"),
+    )
+    monkeypatch.setattr(
+        remote_backend,
+        "sync_from_remote",
+        lambda spec, timeout: calls.append("down"),
+    )
+    monkeypatch.setattr(
+        remote_backend,
+        "run_subprocess",
+        lambda cmd, timeout: (False, "/srv/app/Dockerfile:1 DL3006"),
+    )
+    check = RemoteSpec(host="mac", workdir="/srv/app", workspace="/w")
+
+    run_remote(["hadolint", "Dockerfile"], check, 30)
+    success, output = run_remote(["hadolint", "Dockerfile"], check, 30)
+    assert_that(calls).is_equal_to(["up"])
+    assert_that(success).is_false()
+    assert_that(output).is_equal_to("/w/Dockerfile:1 DL3006")
+
+    fix = RemoteSpec(host="mac", workdir="/srv/app", workspace="/w", writable=True)
+    run_remote(["hadolint", "Dockerfile"], fix, 30)
+    assert_that(calls).is_equal_to(["up", "up", "down"])
+
+
+def test_remote_settings_are_parsed() -> None:
+    """The remote section accepts comma-separated tools and checks the host."""
+    execution = _parse_execution_config(
+        {"remote": {"host": "mac", "tools": "hadolint, swiftlint", "port": 22}},
+    )
+
+    assert_that(execution.remote.tools).is_equal_to(["hadolint", "swiftlint"])
+    assert_that(execution.remote.port).is_equal_to(22)
+
+    with pytest.raises(ValueError, match="requires execution.remote.host"):
+        _parse_execution_config({"remote": {"tools": ["hadolint"]}})
+    with pytest.raises(ValueError, match="absolute path"):
+        _parse_execution_config({"remote": {"host": "mac", "workdir": "lintro"}})
+
+
+@pytest.mark.parametrize(
+    "workdir",
+    ["/", "//", "/home/ci", "/Users/ci/", "/root", "/srv", "/tmp/.."],
+)
+def test_unsafe_workdirs_are_rejected(workdir: str) -> None:
+    """The root, home directories and other shallow directories are refused.
+
+    Args:
+        workdir: Configured remote directory.
+    """
+    with pytest.raises(ValueError, match="execution.remote.workdir"):
+        _parse_execution_config({"remote": {"host": "mac", "workdir": workdir}})
+
+
+def test_workspaces_sync_into_own_directories(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Same-named projects get distinct lintro-owned sync directories.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(remote_backend.shutil, "which", _installed("ssh"))
+    config = _remote_config(workdir="/srv/lint/")
+
+    first = resolve_remote_spec("hadolint", config, False, workspace="/a/app")
+    second = resolve_remote_spec("hadolint", config, False, workspace="/b/app")
+
+    assert first is not None and second is not None
+    assert_that(first.workdir).starts_with("/srv/lint/lintro-app-")
+    assert_that(first.workdir).is_not_equal_to(second.workdir)
+
+
+def test_sync_refuses_foreign_directory(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Syncing never deletes a directory Lintro did not name.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[list[str]] = []
+    monkeypatch.setattr(
+        remote_backend,
+        "_transfer",
+        lambda cmd, timeout, data=None: calls.append(cmd) or b"",
+    )
+    spec = RemoteSpec(host="mac", workdir="/home/ci", workspace="/w", sync="stream")
+
+    with pytest.raises(RuntimeError, match="not a lintro sync directory"):
+        sync_to_remote(spec, 30)
+    assert_that(calls).is_empty()
@@ -0,0 +1,128 @@
+"""Tests for per-tool resource limits."""
+
+from __future__ import annotations
+
+import os
+import resource
+import subprocess
+import sys
+from unittest.mock import MagicMock
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_tool_config
+from lintro.config.lintro_config import LintroConfig
+from lintro.config.tool_config import LintroToolConfig
+from lintro.enums.action import Action
+from lintro.plugins.resource_limits import ResourceLimits, resolve_resource_limits
+from lintro.plugins.subprocess_executor import (
+    run_subprocess,
+    run_subprocess_streaming,
+)
+from lintro.utils.execution.tool_configuration import configure_tool_for_execution
+
+
+def test_resolve_resource_limits_reads_tool_config() -> None:
+    """Tools with limits get them; other tools run unlimited."""
+    config = LintroConfig(
+        tools={
+            "clippy": _parse_tool_config({"memory_limit_mb": 2048, "niceness": 10}),
+            "ruff": LintroToolConfig(timeout=60),
+        },
+    )
+
+    assert_that(resolve_resource_limits("clippy", config)).is_equal_to(
+        ResourceLimits(memory_limit_mb=2048, niceness=10),
+    )
+    assert_that(resolve_resource_limits("ruff", config)).is_none()
+    assert_that(resolve_resource_limits("eslint", config)).is_none()
+
+
+def test_tool_limits_are_validated() -> None:
+    """Niceness must be 0-19 and limits positive."""
+    with pytest.raises(ValueError, match="niceness"):
+        LintroToolConfig(niceness=20)
+    with pytest.raises(ValueError, match="timeout"):
+        LintroToolConfig(timeout=0)
+
+
+@pytest.mark.parametrize(
+    ("cli_options", "timeout"),
+    [({}, 600), ({"timeout": 30}, None)],
+)
+def test_configured_timeout_and_limits_reach_the_tool(
+    cli_options: dict[str, object],
+    timeout: int | None,
+) -> None:
+    """tools.<name>.timeout is applied unless --tool-options sets it.
+
+    Args:
+        cli_options: Options given with --tool-options.
+        timeout: Timeout expected from the config, or None if not applied.
+    """
+    tool = MagicMock()
+    tool.definition.native = True
+    config = LintroConfig(
+        tools={"clippy": LintroToolConfig(timeout=600, niceness=5)},
+    )
+
+    configure_tool_for_execution(
+        tool=tool,
+        tool_name="clippy",
+        config_manager=MagicMock(),
+        tool_option_dict={"clippy": cli_options},
+        exclude=None,
+        include_venv=False,
+        incremental=False,
+        action=Action.CHECK,
+        post_tools=set(),
+        lintro_config=config,
+    )
+
+    applied = [
+        call.kwargs["timeout"]
+        for call in tool.set_options.call_args_list
+        if "timeout" in call.kwargs
+    ]
+    assert_that(applied).is_equal_to([timeout] if timeout else [])
+    assert_that(tool.limits).is_equal_to(ResourceLimits(niceness=5))
+
+
+@pytest.mark.skipif(os.name != "posix", reason="POSIX process limits")
+@pytest.mark.parametrize("streaming", [False, True], ids=["run", "streaming"])
+def test_run_subprocess_applies_limits(streaming: bool) -> None:
+    """The tool's process runs with the configured niceness and heap cap.
+
+    Args:
+        streaming: Whether the streaming runner is used.
+    """
+    script = (
+        "import os, resource, time; time.sleep(0.2); "
+        "print(os.nice(0), resource.getrlimit(resource.RLIMIT_DATA)[0])"
+    )
+    base_nice = os.nice(0)
+    cmd = [sys.executable, "-c", script]
+    limits = ResourceLimits(memory_limit_mb=4096, niceness=5)
+
+    if streaming:
+        success, output = run_subprocess_streaming(cmd, timeout=30, limits=limits)
+    else:
+        success, output = run_subprocess(cmd, timeout=30, limits=limits)
+
+    assert_that(success).is_true()
+    nice, memory = output.split()
+    assert_that(int(nice)).is_equal_to(min(base_nice + 5, 19))
+    if hasattr(resource, "prlimit"):
+        assert_that(int(memory)).is_equal_to(4096 * 1024 * 1024)
+
+
+@pytest.mark.skipif(os.name != "posix", reason="POSIX process limits")
+def test_limited_run_times_out() -> None:
+    """A limited process that runs too long is killed and reported."""
+    with pytest.raises(subprocess.TimeoutExpired):
+        run_subprocess(
+            [sys.executable, "-c", "import time; time.sleep(30)"],
+            timeout=0.5,
+            limits=ResourceLimits(niceness=1),
+        )
@@ -0,0 +1 @@
+"""ansible-lint plugin tests."""
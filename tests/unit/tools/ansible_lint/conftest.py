@@ -0,0 +1,17 @@
+"""Shared fixtures for ansible-lint plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.ansible_lint import AnsibleLintPlugin
+
+
+@pytest.fixture
+def ansible_lint_plugin() -> AnsibleLintPlugin:
+    """Provide an AnsibleLintPlugin instance for testing.
+
+    Returns:
+        AnsibleLintPlugin: A new AnsibleLintPlugin instance.
+    """
+    return AnsibleLintPlugin()
@@ -0,0 +1,230 @@
+"""Tests for AnsibleLintPlugin."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.ansible_lint import find_ansible_root
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.ansible_lint import AnsibleLintPlugin
+
+REPORT = json.dumps(
+    [
+        {
+            "check_name": "name[missing]",
+            "severity": "major",
+            "description": "All tasks should be named.",
+            "location": {"path": "playbooks/site.yml", "lines": {"begin": 4}},
+        },
+    ],
+)
+
+
+def _context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for a playbook below the project root.
+
+    Args:
+        tmp_path: Root of the Ansible project.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 300
+    ctx.cwd = str(tmp_path / "playbooks")
+    ctx.rel_files = ["site.yml"]
+    ctx.files = [str(tmp_path / "playbooks" / "site.yml")]
+    return ctx
+
+
+def _project(tmp_path: Path) -> None:
+    """Create an Ansible project with one playbook.
+
+    Args:
+        tmp_path: Directory to create the project in.
+    """
+    (tmp_path / "playbooks").mkdir()
+    (tmp_path / "playbooks" / "site.yml").write_text("- hosts: all\n")
+    (tmp_path / ".ansible-lint").write_text("profile: basic\n")
+
+
+def test_find_ansible_root(tmp_path: Path) -> None:
+    """The nearest ansible-lint config or Ansible marker is the root.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "roles").mkdir()
+
+    assert_that(find_ansible_root(tmp_path / "roles")).is_none()
+
+    (tmp_path / "ansible.cfg").write_text("[defaults]\n")
+
+    assert_that(find_ansible_root(tmp_path / "roles")).is_equal_to(
+        tmp_path.resolve(),
+    )
+
+
+def test_set_options_normalizes_profile(
+    ansible_lint_plugin: AnsibleLintPlugin,
+) -> None:
+    """Profiles are lowercased and unknown profiles are rejected.
+
+    Args:
+        ansible_lint_plugin: The AnsibleLintPlugin instance to test.
+    """
+    ansible_lint_plugin.set_options(profile="Production")
+
+    assert_that(ansible_lint_plugin.options["profile"]).is_equal_to("production")
+    with pytest.raises(ValueError, match="profile"):
+        ansible_lint_plugin.set_options(profile="strict")
+
+
+def test_check_runs_from_project_root(
+    ansible_lint_plugin: AnsibleLintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Check runs from the Ansible root with the selected profile.
+
+    Args:
+        ansible_lint_plugin: The AnsibleLintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _project(tmp_path)
+    ansible_lint_plugin.set_options(profile="safety")
+
+    with (
+        patch.object(
+            ansible_lint_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            ansible_lint_plugin,
+            "_run_subprocess",
+            return_value=(False, REPORT),
+        ) as mock_run,
+    ):
+        result = ansible_lint_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        [
+            "ansible-lint",
+            "--format=codeclimate",
+            "--nocolor",
+            "--offline",
+            "--profile=safety",
+            "playbooks/site.yml",
+        ],
+    )
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(tmp_path.resolve()))
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("name[missing]")
+
+
+def test_check_skips_without_ansible_project(
+    ansible_lint_plugin: AnsibleLintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Projects without Ansible markers are skipped.
+
+    Args:
+        ansible_lint_plugin: The AnsibleLintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "playbooks").mkdir()
+
+    with (
+        patch.object(
+            ansible_lint_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(ansible_lint_plugin, "_run_subprocess") as mock_run,
+    ):
+        result = ansible_lint_plugin.check(["."], {})
+
+    mock_run.assert_not_called()
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to("no Ansible project")
+
+
+def test_check_reports_failed_runs(
+    ansible_lint_plugin: AnsibleLintPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without a report fails the result with its output.
+
+    Args:
+        ansible_lint_plugin: The AnsibleLintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _project(tmp_path)
+
+    with (
+        patch.object(
+            ansible_lint_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            ansible_lint_plugin,
+            "_run_subprocess",
+            return_value=(False, "CRITICAL Failed to load .ansible-lint"),
+        ),
+    ):
+        result = ansible_lint_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("CRITICAL Failed to load .ansible-lint")
+
+
+def test_check_reports_timeout(
+    ansible_lint_plugin: AnsibleLintPlugin,
+    tmp_path: Path,
+) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        ansible_lint_plugin: The AnsibleLintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _project(tmp_path)
+
+    with (
+        patch.object(
+            ansible_lint_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            ansible_lint_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["ansible-lint"], timeout=300),
+        ),
+    ):
+        result = ansible_lint_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("ansible_lint:timeout=N")
+
+
+def test_fix_is_not_supported(ansible_lint_plugin: AnsibleLintPlugin) -> None:
+    """Fixing raises NotImplementedError.
+
+    Args:
+        ansible_lint_plugin: The AnsibleLintPlugin instance to test.
+    """
+    with pytest.raises(NotImplementedError):
+        ansible_lint_plugin.fix(["."], {})
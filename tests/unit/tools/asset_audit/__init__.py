@@ -0,0 +1 @@
+"""Asset audit plugin tests."""
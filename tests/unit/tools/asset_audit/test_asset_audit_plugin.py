@@ -0,0 +1,124 @@
+"""Unit tests for the asset_audit plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions import asset_audit
+from lintro.tools.definitions.asset_audit import AssetAuditPlugin
+
+_EDITOR_SVG = '<svg xmlns:sketch="http://www.bohemiancoding.com/sketch/ns"/>\n'
+
+
+@pytest.fixture
+def asset_audit_plugin() -> AssetAuditPlugin:
+    """Provide an AssetAuditPlugin instance for testing.
+
+    Returns:
+        An AssetAuditPlugin instance.
+    """
+    return AssetAuditPlugin()
+
+
+def test_definition_is_native_and_fixable(
+    asset_audit_plugin: AssetAuditPlugin,
+) -> None:
+    """Verify the plugin needs no external binary and can fix.
+
+    Args:
+        asset_audit_plugin: The plugin instance.
+    """
+    definition = asset_audit_plugin.definition
+    assert_that(definition.name).is_equal_to("asset_audit")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).contains("*.png", "*.svg")
+
+
+def test_set_options_merges_thresholds(asset_audit_plugin: AssetAuditPlugin) -> None:
+    """Verify threshold overrides merge with defaults and are validated.
+
+    Args:
+        asset_audit_plugin: The plugin instance.
+    """
+    asset_audit_plugin.set_options(max_size_kb={"png": 200})
+
+    limits = asset_audit_plugin.options["max_size_kb"]
+    assert_that(limits["png"]).is_equal_to(200)  # type: ignore[index]
+    assert_that(limits["svg"]).is_equal_to(100)  # type: ignore[index]
+    with pytest.raises(ValueError, match="max_size_kb.svg"):
+        asset_audit_plugin.set_options(max_size_kb={"svg": -1})
+
+
+def test_check_reports_editor_metadata(
+    asset_audit_plugin: AssetAuditPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify check reports SVG editor metadata.
+
+    Args:
+        asset_audit_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "icon.svg").write_text(_EDITOR_SVG)
+
+    result = asset_audit_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("IA003")  # type: ignore[index]
+
+
+def test_fix_without_svgo_reports_remaining(
+    asset_audit_plugin: AssetAuditPlugin,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Verify fix leaves SVGs untouched and explains when svgo is missing.
+
+    Args:
+        asset_audit_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(asset_audit.shutil, "which", lambda name: None)
+    (tmp_path / "icon.svg").write_text(_EDITOR_SVG)
+
+    result = asset_audit_plugin.fix([str(tmp_path)], {})
+
+    assert_that(result.output).contains("svgo not found")
+    assert_that(result.fixed_issues_count).is_equal_to(0)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that((tmp_path / "icon.svg").read_text()).is_equal_to(_EDITOR_SVG)
+
+
+@pytest.mark.parametrize(("check_webp", "warned"), [(True, 1), (False, 0)])
+def test_check_warns_when_pillow_is_missing(
+    asset_audit_plugin: AssetAuditPlugin,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    check_webp: bool,
+    warned: int,
+) -> None:
+    """Verify a skipped WebP check is reported instead of passing silently.
+
+    Args:
+        asset_audit_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+        check_webp: Whether the WebP check is enabled.
+        warned: Expected number of warnings.
+    """
+    warnings: list[str] = []
+    monkeypatch.setattr(asset_audit, "webp_encoder_available", lambda: False)
+    monkeypatch.setattr(asset_audit.logger, "warning", warnings.append)
+    (tmp_path / "shot.png").write_bytes(b"\0" * 100)
+
+    asset_audit_plugin.check([str(tmp_path)], {"check_webp": check_webp})
+
+    assert_that(warnings).is_length(warned)
+    if warned:
+        assert_that(warnings[0]).contains("IA002", "1 PNG file", "lintro[images]")
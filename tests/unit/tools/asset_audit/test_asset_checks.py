@@ -0,0 +1,95 @@
+"""Tests for the image and asset optimization checks."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.implementations.asset_audit import (
+    asset_checks,
+    check_asset_bytes,
+    find_svg_editor_metadata,
+    image_format,
+)
+
+_INKSCAPE_SVG = (
+    '<svg xmlns="http://www.w3.org/2000/svg" '
+    'xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape">'
+    '<metadata id="m"/><path inkscape:label="x" d="M0 0"/></svg>'
+)
+
+
+def test_image_format_normalizes_suffixes() -> None:
+    """JPEG aliases map to one format and case is ignored."""
+    assert_that(image_format("docs/Photo.JPEG")).is_equal_to("jpg")
+    assert_that(image_format("logo.svg")).is_equal_to("svg")
+
+
+def test_oversized_file_uses_format_threshold() -> None:
+    """IA001 fires above the per-format threshold only."""
+    data = b"\0" * (120 * 1024)
+
+    svg_issues = check_asset_bytes(data, "big.svg")
+    jpg_issues = check_asset_bytes(data, "ok.jpg")
+
+    assert_that([i.code for i in svg_issues]).is_equal_to(["IA001"])
+    assert_that(svg_issues[0].message).contains("over the 100 KiB limit")
+    assert_that(jpg_issues).is_empty()
+
+
+def test_threshold_overrides_merge_with_defaults() -> None:
+    """Overrides replace single formats and 0 disables the check."""
+    data = b"\0" * (120 * 1024)
+
+    assert_that(check_asset_bytes(data, "a.svg", max_size_kb={"svg": 0})).is_empty()
+    assert_that(
+        [i.code for i in check_asset_bytes(data, "a.gif", max_size_kb={"gif": 100})],
+    ).is_equal_to(["IA001"])
+
+
+def test_png_smaller_as_webp(monkeypatch: pytest.MonkeyPatch) -> None:
+    """IA002 reports PNGs whose WebP encoding saves enough.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(asset_checks, "estimate_webp_size", lambda data: 600)
+    data = b"\0" * 1000
+
+    issues = check_asset_bytes(data, "shot.png")
+
+    assert_that([(i.code, i.level) for i in issues]).is_equal_to(
+        [("IA002", "info")],
+    )
+    assert_that(issues[0].message).contains("40% smaller")
+    assert_that(check_asset_bytes(data, "shot.png", webp_min_savings=50)).is_empty()
+    assert_that(check_asset_bytes(data, "shot.png", check_webp=False)).is_empty()
+
+
+def test_webp_check_skipped_without_encoder(monkeypatch: pytest.MonkeyPatch) -> None:
+    """IA002 is silently skipped when no WebP size is available.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(asset_checks, "estimate_webp_size", lambda data: None)
+
+    assert_that(check_asset_bytes(b"\0" * 1000, "shot.png")).is_empty()
+
+
+def test_svg_editor_metadata() -> None:
+    """IA003 names the editors whose metadata an SVG carries."""
+    issues = check_asset_bytes(_INKSCAPE_SVG.encode(), "icon.svg")
+
+    assert_that(find_svg_editor_metadata(_INKSCAPE_SVG)).is_equal_to(
+        ["Inkscape", "<metadata>"],
+    )
+    assert_that([i.code for i in issues]).is_equal_to(["IA003"])
+    assert_that(issues[0].fixable).is_true()
+
+
+def test_clean_svg_passes() -> None:
+    """An optimized SVG has no issues."""
+    svg = b'<svg xmlns="http://www.w3.org/2000/svg"><path d="M0 0h1"/></svg>'
+
+    assert_that(check_asset_bytes(svg, "icon.svg")).is_empty()
@@ -0,0 +1 @@
+"""Biome plugin tests."""
@@ -0,0 +1,162 @@
+"""Tests for BiomePlugin.check method."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.biome import BiomePlugin
+
+
+def _mock_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one JavaScript file.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["app.js"]
+    ctx.files = [str(tmp_path / "app.js")]
+    return ctx
+
+
+def test_check_reports_diagnostics(biome_plugin: BiomePlugin, tmp_path: Path) -> None:
+    """Check runs the JSON reporter and parses its diagnostics.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    output = json.dumps(
+        {
+            "diagnostics": [
+                {
+                    "category": "lint/style/noVar",
+                    "severity": "warning",
+                    "description": "Use let or const instead of var.",
+                    "location": {"path": {"file": "app.js"}},
+                    "tags": ["fixable"],
+                },
+            ],
+        },
+    )
+    biome_plugin.set_options(config_path="tools/biome.json")
+
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(
+            biome_plugin,
+            "_run_subprocess",
+            return_value=(False, output),
+        ) as mock_run,
+    ):
+        result = biome_plugin.check([str(tmp_path)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd[:4]).is_equal_to(
+        ["biome", "check", "--reporter=json", "--max-diagnostics=none"],
+    )
+    assert_that(cmd).contains("--no-errors-on-unmatched", "tools/biome.json")
+    assert_that(cmd[-1]).is_equal_to("app.js")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+
+
+def test_check_success_suppresses_output(
+    biome_plugin: BiomePlugin,
+    tmp_path: Path,
+) -> None:
+    """A clean run succeeds without output.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(
+            biome_plugin,
+            "_run_subprocess",
+            return_value=(True, '{"diagnostics": []}'),
+        ),
+    ):
+        result = biome_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.output).is_none()
+
+
+def test_check_reports_failure_without_report(
+    biome_plugin: BiomePlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without a JSON report fails with Biome's message.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    message = "biome.json:3:5 deserialize  Found an unknown key `lintr`."
+
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(biome_plugin, "_run_subprocess", return_value=(False, message)),
+    ):
+        result = biome_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).is_equal_to(message)
+
+def test_check_timeout(biome_plugin: BiomePlugin, tmp_path: Path) -> None:
+    """A timeout is reported as a failed result.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(
+            biome_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["biome"], timeout=60),
+        ),
+    ):
+        result = biome_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
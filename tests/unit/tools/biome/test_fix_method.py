@@ -0,0 +1,168 @@
+"""Tests for BiomePlugin.fix method."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.biome import BiomePlugin
+
+
+def _report(*categories: str) -> str:
+    """Build a Biome JSON report with one diagnostic per category.
+
+    Args:
+        *categories: Diagnostic categories to report.
+
+    Returns:
+        str: JSON report.
+    """
+    return json.dumps(
+        {
+            "diagnostics": [
+                {
+                    "category": category,
+                    "severity": "error",
+                    "description": f"{category} diagnostic",
+                    "location": {"path": {"file": "app.ts"}},
+                }
+                for category in categories
+            ],
+        },
+    )
+
+
+def _mock_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one TypeScript file.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["app.ts"]
+    ctx.files = [str(tmp_path / "app.ts")]
+    return ctx
+
+
+@pytest.mark.parametrize(
+    ("unsafe", "expect_unsafe_flag"),
+    [(False, False), (True, True)],
+    ids=["safe_fixes", "unsafe_fixes"],
+)
+def test_fix_counts_fixed_and_remaining(
+    biome_plugin: BiomePlugin,
+    tmp_path: Path,
+    unsafe: bool,
+    expect_unsafe_flag: bool,
+) -> None:
+    """Fix writes changes and counts what is left.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+        unsafe: Value of the unsafe option.
+        expect_unsafe_flag: Whether --unsafe should be passed.
+    """
+    biome_plugin.set_options(unsafe=unsafe)
+    outputs = [
+        (False, _report("format", "lint/style/useConst", "lint/correctness/noUndef")),
+        (True, ""),
+        (False, _report("lint/correctness/noUndef")),
+    ]
+
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(
+            biome_plugin,
+            "_run_subprocess",
+            side_effect=outputs,
+        ) as mock_run,
+    ):
+        result = biome_plugin.fix([str(tmp_path)], {})
+
+    fix_cmd = mock_run.call_args_list[1].kwargs["cmd"]
+    assert_that(fix_cmd[:3]).is_equal_to(["biome", "check", "--write"])
+    assert_that("--unsafe" in fix_cmd).is_equal_to(expect_unsafe_flag)
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("Fixed 2 issue(s)")
+    assert_that(result.output).contains("cannot be auto-fixed")
+
+
+def test_fix_all_fixed(biome_plugin: BiomePlugin, tmp_path: Path) -> None:
+    """Fix succeeds when nothing is left.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    outputs = [
+        (False, _report("format")),
+        (True, ""),
+        (True, _report()),
+    ]
+
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(biome_plugin, "_run_subprocess", side_effect=outputs),
+    ):
+        result = biome_plugin.fix([str(tmp_path)], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("All issues were successfully auto-fixed")
+
+
+def test_fix_reports_failure_without_fixing(
+    biome_plugin: BiomePlugin,
+    tmp_path: Path,
+) -> None:
+    """A check run that fails without a report stops before fixing.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            biome_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(biome_plugin, "_get_executable_command", return_value=["biome"]),
+        patch.object(
+            biome_plugin,
+            "_run_subprocess",
+            return_value=(False, ""),
+        ) as mock_run,
+    ):
+        result = biome_plugin.fix([str(tmp_path)], {})
+
+    assert_that(mock_run.call_count).is_equal_to(1)
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("Biome execution failed.")
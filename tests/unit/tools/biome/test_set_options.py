@@ -0,0 +1,80 @@
+"""Tests for BiomePlugin definition and option validation."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.tool_type import ToolType
+from lintro.tools.definitions.biome import BIOME_DEFAULT_TIMEOUT
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.biome import BiomePlugin
+
+
+def test_definition_covers_linting_and_formatting(biome_plugin: BiomePlugin) -> None:
+    """Biome is a fixing linter and formatter for JS, TS and JSON.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+    """
+    definition = biome_plugin.definition
+
+    assert_that(definition.can_fix).is_true()
+    assert_that(ToolType.LINTER in definition.tool_type).is_true()
+    assert_that(ToolType.FORMATTER in definition.tool_type).is_true()
+    assert_that(definition.file_patterns).contains("*.ts", "*.tsx", "*.json")
+    assert_that(definition.native_configs).contains("biome.json", "biome.jsonc")
+    assert_that(definition.default_options).is_equal_to(
+        {"timeout": BIOME_DEFAULT_TIMEOUT, "unsafe": False},
+    )
+
+
+def test_set_options_valid(biome_plugin: BiomePlugin) -> None:
+    """Valid options are stored.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+    """
+    biome_plugin.set_options(
+        timeout=120,
+        config_path="config/biome.json",
+        unsafe=True,
+        verbose_fix_output=True,
+    )
+
+    assert_that(biome_plugin.options).contains_entry(
+        {"timeout": 120},
+        {"config_path": "config/biome.json"},
+        {"unsafe": True},
+        {"verbose_fix_output": True},
+    )
+
+
+@pytest.mark.parametrize(
+    ("option_name", "value", "message"),
+    [
+        ("timeout", -1, "timeout must be positive"),
+        ("config_path", 1, "config_path must be a string"),
+        ("unsafe", "yes", "unsafe must be a boolean"),
+    ],
+    ids=["negative_timeout", "non_string_config_path", "non_bool_unsafe"],
+)
+def test_set_options_invalid(
+    biome_plugin: BiomePlugin,
+    option_name: str,
+    value: object,
+    message: str,
+) -> None:
+    """Invalid option values raise ValueError.
+
+    Args:
+        biome_plugin: The BiomePlugin instance to test.
+        option_name: Option to set.
+        value: Invalid value for the option.
+        message: Expected error message.
+    """
+    with pytest.raises(ValueError, match=message):
+        biome_plugin.set_options(**{option_name: value})  # type: ignore[arg-type]
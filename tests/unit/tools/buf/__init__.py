@@ -0,0 +1 @@
+"""buf plugin tests."""
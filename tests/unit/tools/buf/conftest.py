@@ -0,0 +1,17 @@
+"""Shared fixtures for buf plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.buf import BufPlugin
+
+
+@pytest.fixture
+def buf_plugin() -> BufPlugin:
+    """Provide a BufPlugin instance for testing.
+
+    Returns:
+        BufPlugin: A new BufPlugin instance.
+    """
+    return BufPlugin()
@@ -0,0 +1,236 @@
+"""Tests for BufPlugin."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.buf import find_buf_root
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.buf import BufPlugin
+
+LINT_OUTPUT = (
+    '{"path":"acme/v1/pet.proto","start_line":5,"start_column":3,'
+    '"type":"FIELD_LOWER_SNAKE_CASE","message":"Field name should be snake_case."}'
+)
+
+FORMAT_DIFF = """--- acme/v1/pet.proto.orig
++++ acme/v1/pet.proto
+@@ -1,2 +1,2 @@
+-package   acme.v1;
++package acme.v1;
+"""
+
+
+def _context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for a single Protobuf file.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 120
+    ctx.cwd = str(tmp_path / "acme" / "v1")
+    ctx.rel_files = ["pet.proto"]
+    ctx.files = [str(tmp_path / "acme" / "v1" / "pet.proto")]
+    return ctx
+
+
+def _module(tmp_path: Path) -> None:
+    """Create a buf module with one Protobuf file.
+
+    Args:
+        tmp_path: Directory to create the module in.
+    """
+    (tmp_path / "acme" / "v1").mkdir(parents=True)
+    (tmp_path / "acme" / "v1" / "pet.proto").write_text('syntax = "proto3";\n')
+    (tmp_path / "buf.yaml").write_text("version: v2\n")
+
+
+def test_find_buf_root_prefers_workspace(tmp_path: Path) -> None:
+    """The outermost buf.work.yaml wins over a module's buf.yaml.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    module = tmp_path / "proto"
+    (module / "acme").mkdir(parents=True)
+    (module / "buf.yaml").write_text("version: v1\n")
+
+    assert_that(find_buf_root(module / "acme")).is_equal_to(module.resolve())
+
+    (tmp_path / "buf.work.yaml").write_text("version: v1\n")
+
+    assert_that(find_buf_root(module / "acme")).is_equal_to(tmp_path.resolve())
+
+
+def test_set_options_rejects_invalid_against(buf_plugin: BufPlugin) -> None:
+    """A non-string against option is rejected.
+
+    Args:
+        buf_plugin: The BufPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="against"):
+        buf_plugin.set_options(against=1)  # type: ignore[arg-type]
+
+
+def test_check_runs_lint_and_format_from_module_root(
+    buf_plugin: BufPlugin,
+    tmp_path: Path,
+) -> None:
+    """Check runs lint and format from the buf.yaml directory.
+
+    Args:
+        buf_plugin: The BufPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _module(tmp_path)
+    outputs = iter([(False, LINT_OUTPUT), (True, FORMAT_DIFF)])
+
+    with (
+        patch.object(buf_plugin, "_prepare_execution", return_value=_context(tmp_path)),
+        patch.object(
+            buf_plugin,
+            "_run_subprocess",
+            side_effect=lambda **_: next(outputs),
+        ) as mock_run,
+    ):
+        result = buf_plugin.check(["."], {})
+
+    calls = [c.kwargs for c in mock_run.call_args_list]
+    assert_that(calls[0]["cmd"]).is_equal_to(
+        ["buf", "lint", "--error-format=json", "--path", "acme/v1/pet.proto"],
+    )
+    assert_that(calls[0]["cwd"]).is_equal_to(str(tmp_path.resolve()))
+    assert_that(calls[1]["cmd"][:3]).is_equal_to(["buf", "format", "--diff"])
+    assert_that(result.success).is_false()
+    assert_that([i.code for i in result.issues]).is_equal_to(
+        ["FIELD_LOWER_SNAKE_CASE", "FORMAT"],
+    )
+
+
+def test_check_runs_breaking_against_baseline(
+    buf_plugin: BufPlugin,
+    tmp_path: Path,
+) -> None:
+    """Breaking changes are only checked with an against input.
+
+    Args:
+        buf_plugin: The BufPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _module(tmp_path)
+    buf_plugin.set_options(lint=False, format=False, against=".git#branch=main")
+
+    with (
+        patch.object(buf_plugin, "_prepare_execution", return_value=_context(tmp_path)),
+        patch.object(
+            buf_plugin,
+            "_run_subprocess",
+            return_value=(True, ""),
+        ) as mock_run,
+    ):
+        result = buf_plugin.check(["."], {})
+
+    assert_that(mock_run.call_count).is_equal_to(1)
+    assert_that(mock_run.call_args.kwargs["cmd"]).contains(
+        "breaking",
+        "--against=.git#branch=main",
+    )
+    assert_that(result.success).is_true()
+
+
+def test_check_reports_failed_runs(buf_plugin: BufPlugin, tmp_path: Path) -> None:
+    """A failed run without findings fails the result with its output.
+
+    Args:
+        buf_plugin: The BufPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _module(tmp_path)
+    buf_plugin.set_options(format=False)
+
+    with (
+        patch.object(buf_plugin, "_prepare_execution", return_value=_context(tmp_path)),
+        patch.object(
+            buf_plugin,
+            "_run_subprocess",
+            return_value=(False, "Failure: decode buf.yaml: invalid version"),
+        ),
+    ):
+        result = buf_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).is_equal_to("Failure: decode buf.yaml: invalid version")
+
+
+def test_fix_formats_and_rechecks(buf_plugin: BufPlugin, tmp_path: Path) -> None:
+    """Fix runs ``buf format -w`` and keeps lint findings as remaining.
+
+    Args:
+        buf_plugin: The BufPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _module(tmp_path)
+    outputs = iter(
+        [
+            (False, LINT_OUTPUT),
+            (True, FORMAT_DIFF),
+            (True, ""),
+            (False, LINT_OUTPUT),
+            (True, ""),
+        ],
+    )
+
+    with (
+        patch.object(buf_plugin, "_prepare_execution", return_value=_context(tmp_path)),
+        patch.object(
+            buf_plugin,
+            "_run_subprocess",
+            side_effect=lambda **_: next(outputs),
+        ) as mock_run,
+    ):
+        result = buf_plugin.fix(["."], {})
+
+    commands = [c.kwargs["cmd"] for c in mock_run.call_args_list]
+    assert_that(commands[2][:3]).is_equal_to(["buf", "format", "-w"])
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(2)
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("Fixed 1 issue(s)", "cannot be auto-fixed")
+
+
+def test_fix_reports_timeout(buf_plugin: BufPlugin, tmp_path: Path) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        buf_plugin: The BufPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    _module(tmp_path)
+
+    with (
+        patch.object(buf_plugin, "_prepare_execution", return_value=_context(tmp_path)),
+        patch.object(
+            buf_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["buf"], timeout=120),
+        ),
+    ):
+        result = buf_plugin.fix(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("buf:timeout=N")
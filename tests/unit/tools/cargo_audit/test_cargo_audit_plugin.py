@@ -137,6 +137,59 @@ def test_set_options_non_numeric_timeout(
         cargo_audit_plugin.set_options(timeout="invalid")
 
 
+def test_set_options_ignore_adds_ignore_flags(
+    cargo_audit_plugin: CargoAuditPlugin,
+) -> None:
+    """Verify ignored advisories are passed to cargo-audit.
+
+    Args:
+        cargo_audit_plugin: The plugin instance.
+    """
+    cargo_audit_plugin.set_options(
+        ignore=["RUSTSEC-2020-0071", "RUSTSEC-2021-0145"],
+    )
+
+    assert_that(cargo_audit_plugin._build_command()).is_equal_to(
+        [
+            "cargo",
+            "audit",
+            "--json",
+            "--ignore",
+            "RUSTSEC-2020-0071",
+            "--ignore",
+            "RUSTSEC-2021-0145",
+        ],
+    )
+
+
+def test_set_options_single_ignore(cargo_audit_plugin: CargoAuditPlugin) -> None:
+    """Verify a single advisory ID from --tool-options is accepted.
+
+    Args:
+        cargo_audit_plugin: The plugin instance.
+    """
+    cargo_audit_plugin.set_options(ignore="RUSTSEC-2020-0071")
+
+    assert_that(cargo_audit_plugin.options.get("ignore")).is_equal_to(
+        ["RUSTSEC-2020-0071"],
+    )
+
+
+@pytest.mark.parametrize("ignore", [[""], [42], 42])
+def test_set_options_invalid_ignore(
+    cargo_audit_plugin: CargoAuditPlugin,
+    ignore: object,
+) -> None:
+    """Verify ignore must be advisory IDs.
+
+    Args:
+        cargo_audit_plugin: The plugin instance.
+        ignore: Invalid ignore value.
+    """
+    with pytest.raises(ValueError, match="ignore must be a string"):
+        cargo_audit_plugin.set_options(ignore=ignore)
+
+
 def test_fix_raises_not_implemented(cargo_audit_plugin: CargoAuditPlugin) -> None:
     """Verify fix raises NotImplementedError.
 
@@ -6,7 +6,10 @@ import pytest
 from assertpy import assert_that
 
 from lintro.enums.tool_type import ToolType
-from lintro.tools.definitions.cargo_deny import CargoDenyPlugin
+from lintro.tools.definitions.cargo_deny import (
+    CargoDenyPlugin,
+    _build_cargo_deny_command,
+)
 
 
 @pytest.fixture
@@ -114,3 +117,46 @@ def test_set_options_invalid_timeout(cargo_deny_plugin: CargoDenyPlugin) -> None
     """
     with pytest.raises(ValueError):
         cargo_deny_plugin.set_options(timeout=-1)
+
+
+@pytest.mark.parametrize(
+    ("checks", "expected"),
+    [
+        pytest.param("licenses", ["licenses"], id="single"),
+        pytest.param(["Bans", " sources"], ["bans", "sources"], id="list"),
+    ],
+)
+def test_set_options_checks(
+    cargo_deny_plugin: CargoDenyPlugin,
+    checks: str | list[str],
+    expected: list[str],
+) -> None:
+    """Verify checks are normalized to a list of check names.
+
+    Args:
+        cargo_deny_plugin: The plugin instance.
+        checks: Checks as given on the command line or in config.
+        expected: Normalized checks.
+    """
+    cargo_deny_plugin.set_options(checks=checks)
+    assert_that(cargo_deny_plugin.options.get("checks")).is_equal_to(expected)
+
+
+def test_set_options_unknown_check(cargo_deny_plugin: CargoDenyPlugin) -> None:
+    """Verify unknown checks raise ValueError.
+
+    Args:
+        cargo_deny_plugin: The plugin instance.
+    """
+    with pytest.raises(ValueError, match="Unknown cargo-deny check"):
+        cargo_deny_plugin.set_options(checks=["licenses", "typos"])
+
+
+def test_build_command_with_checks() -> None:
+    """Verify selected checks are passed after the format."""
+    assert_that(_build_cargo_deny_command(["licenses", "bans"])).is_equal_to(
+        ["cargo", "deny", "check", "--format", "json", "licenses", "bans"],
+    )
+    assert_that(_build_cargo_deny_command()).is_equal_to(
+        ["cargo", "deny", "check", "--format", "json"],
+    )
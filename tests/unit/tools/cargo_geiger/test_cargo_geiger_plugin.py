@@ -0,0 +1,204 @@
+"""Unit tests for cargo-geiger plugin."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from subprocess import TimeoutExpired
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.tools.definitions.cargo_geiger import CargoGeigerPlugin
+from lintro.utils.execution.failure_policy import build_failure_policy
+
+
+@pytest.fixture
+def cargo_geiger_plugin() -> CargoGeigerPlugin:
+    """Provide a CargoGeigerPlugin instance for testing.
+
+    Returns:
+        A CargoGeigerPlugin instance.
+    """
+    return CargoGeigerPlugin()
+
+
+@pytest.fixture
+def manifest(tmp_path: Path) -> Path:
+    """Create a Cargo.toml for a crate without dependencies.
+
+    Args:
+        tmp_path: Temporary directory path.
+
+    Returns:
+        Path: The manifest.
+    """
+    path = tmp_path / "Cargo.toml"
+    path.write_text('[package]\nname = "app"\n')
+    return path
+
+
+def _geiger_output(crate_dir: Path, exprs: int) -> str:
+    """Build cargo-geiger output for one local crate.
+
+    Args:
+        crate_dir: Crate directory.
+        exprs: Unsafe expressions the crate uses.
+
+    Returns:
+        str: Raw output.
+    """
+    package = {
+        "package": {
+            "id": {
+                "name": "app",
+                "version": "0.1.0",
+                "source": {"Path": crate_dir.as_uri()},
+            },
+        },
+        "unsafety": {"used": {"exprs": {"safe": 10, "unsafe_": exprs}}},
+    }
+    return json.dumps({"packages": [package]})
+
+
+def _check(plugin: CargoGeigerPlugin, manifest: Path, output: str) -> ToolResult:
+    """Run check with a stubbed cargo-geiger.
+
+    Args:
+        plugin: The plugin instance.
+        manifest: Project manifest.
+        output: Output cargo-geiger prints.
+
+    Returns:
+        ToolResult: Result of the check.
+    """
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(plugin, "_run_subprocess", return_value=(True, output)):
+            return plugin.check([str(manifest)], {})
+
+
+def test_definition(cargo_geiger_plugin: CargoGeigerPlugin) -> None:
+    """Verify the tool is opt-in, cannot fix and only fails on errors.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+    """
+    definition = cargo_geiger_plugin.definition
+    assert_that(definition.name).is_equal_to("cargo_geiger")
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.enabled_by_default).is_false()
+    assert_that(definition.default_fail_on).is_equal_to("error")
+
+
+def test_build_command(cargo_geiger_plugin: CargoGeigerPlugin) -> None:
+    """Verify options map to cargo geiger flags.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+    """
+    assert_that(cargo_geiger_plugin._build_command()).is_equal_to(
+        ["cargo", "geiger", "--output-format", "Json"],
+    )
+    cargo_geiger_plugin.set_options(
+        all_features=True,
+        features=["tls", "cli"],
+        include_tests=True,
+    )
+    assert_that(cargo_geiger_plugin._build_command()).is_equal_to(
+        ["cargo", "geiger", "--output-format", "Json", "--all-features"]
+        + ["--features", "tls,cli", "--include-tests"],
+    )
+
+
+def test_set_options_rejects_negative_budget(
+    cargo_geiger_plugin: CargoGeigerPlugin,
+) -> None:
+    """Verify the budget may be zero but not negative.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+    """
+    cargo_geiger_plugin.set_options(max_unsafe=0)
+    with pytest.raises(ValueError, match="max_unsafe"):
+        cargo_geiger_plugin.set_options(max_unsafe=-1)
+
+
+def test_check_within_budget_reports_usage(
+    cargo_geiger_plugin: CargoGeigerPlugin,
+    manifest: Path,
+) -> None:
+    """Usage within the budget is reported without failing the run.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    cargo_geiger_plugin.set_options(max_unsafe=5)
+    result = _check(cargo_geiger_plugin, manifest, _geiger_output(manifest.parent, 5))
+
+    assert_that(result.success).is_true()
+    assert_that(result.issues_count).is_equal_to(1)
+    policy = build_failure_policy(LintroConfig())
+    assert_that(policy.exceeded([result])).is_false()
+
+
+def test_check_over_budget_fails(
+    cargo_geiger_plugin: CargoGeigerPlugin,
+    manifest: Path,
+) -> None:
+    """Exceeding the budget adds an error that fails the run.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    cargo_geiger_plugin.set_options(max_unsafe=5)
+    result = _check(cargo_geiger_plugin, manifest, _geiger_output(manifest.parent, 6))
+
+    assert_that(result.success).is_false()
+    budget = result.issues[-1]  # type: ignore[index]
+    assert_that(budget.get_severity()).is_equal_to(SeverityLevel.ERROR)
+    policy = build_failure_policy(LintroConfig())
+    assert_that(policy.exceeded([result])).is_true()
+
+
+def test_check_timeout(
+    cargo_geiger_plugin: CargoGeigerPlugin,
+    manifest: Path,
+) -> None:
+    """Check handles timeout correctly.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(
+            cargo_geiger_plugin,
+            "_run_subprocess",
+            side_effect=TimeoutExpired(cmd=["cargo", "geiger"], timeout=600),
+        ):
+            result = cargo_geiger_plugin.check([str(manifest)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
+
+
+def test_fix_raises(cargo_geiger_plugin: CargoGeigerPlugin) -> None:
+    """Verify fix is not supported.
+
+    Args:
+        cargo_geiger_plugin: The plugin instance.
+    """
+    with pytest.raises(NotImplementedError):
+        cargo_geiger_plugin.fix(["."], {})
@@ -0,0 +1,161 @@
+"""Unit tests for cargo-machete plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from subprocess import TimeoutExpired
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.cargo_machete import CargoMachetePlugin
+
+UNUSED_OUTPUT = (
+    "cargo-machete found the following unused dependencies in this directory:\n"
+    "app -- ./Cargo.toml:\n"
+    "\tserde\n"
+)
+CLEAN_OUTPUT = "cargo-machete didn't find any unused dependencies in this directory."
+
+
+@pytest.fixture
+def cargo_machete_plugin() -> CargoMachetePlugin:
+    """Provide a CargoMachetePlugin instance for testing.
+
+    Returns:
+        A CargoMachetePlugin instance.
+    """
+    return CargoMachetePlugin()
+
+
+@pytest.fixture
+def manifest(tmp_path: Path) -> Path:
+    """Create a Cargo.toml with one dependency.
+
+    Args:
+        tmp_path: Temporary directory path.
+
+    Returns:
+        Path: The manifest.
+    """
+    path = tmp_path / "Cargo.toml"
+    path.write_text('[package]\nname = "app"\n\n[dependencies]\nserde = "1"\n')
+    return path
+
+
+def test_definition(cargo_machete_plugin: CargoMachetePlugin) -> None:
+    """Verify the tool can fix and runs on Cargo.toml.
+
+    Args:
+        cargo_machete_plugin: The plugin instance.
+    """
+    definition = cargo_machete_plugin.definition
+    assert_that(definition.name).is_equal_to("cargo_machete")
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).is_equal_to(["Cargo.toml"])
+
+
+def test_set_options(cargo_machete_plugin: CargoMachetePlugin) -> None:
+    """Verify with_metadata adds its flag and must be a boolean.
+
+    Args:
+        cargo_machete_plugin: The plugin instance.
+    """
+    cargo_machete_plugin.set_options(with_metadata=True)
+    assert_that(cargo_machete_plugin._build_command(fix=True)).is_equal_to(
+        ["cargo", "machete", "--with-metadata", "--fix"],
+    )
+    with pytest.raises(ValueError):
+        cargo_machete_plugin.set_options(with_metadata="yes")
+
+
+def test_check_reports_unused_dependencies(
+    cargo_machete_plugin: CargoMachetePlugin,
+    manifest: Path,
+) -> None:
+    """Check reports each unused dependency on its manifest line.
+
+    Args:
+        cargo_machete_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(
+            cargo_machete_plugin,
+            "_run_subprocess",
+            return_value=(False, UNUSED_OUTPUT),
+        ) as run:
+            result = cargo_machete_plugin.check([str(manifest)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].line).is_equal_to(5)  # type: ignore[index]
+    assert_that(run.call_args.kwargs["cwd"]).is_equal_to(str(manifest.parent))
+
+
+def test_fix_removes_unused_dependencies(
+    cargo_machete_plugin: CargoMachetePlugin,
+    manifest: Path,
+) -> None:
+    """Fix runs cargo machete --fix and reports what was removed.
+
+    Args:
+        cargo_machete_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    outputs = iter([(False, UNUSED_OUTPUT), (True, ""), (True, CLEAN_OUTPUT)])
+    commands: list[list[str]] = []
+
+    def run(cmd: list[str], **kwargs: object) -> tuple[bool, str]:
+        """Return the next canned output.
+
+        Args:
+            cmd: Command being run.
+            **kwargs: Subprocess options.
+
+        Returns:
+            tuple[bool, str]: Canned success and output.
+        """
+        commands.append(cmd)
+        return next(outputs)
+
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(cargo_machete_plugin, "_run_subprocess", side_effect=run):
+            result = cargo_machete_plugin.fix([str(manifest)], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+    assert_that(commands[1]).is_equal_to(["cargo", "machete", "--fix"])
+
+
+def test_check_timeout(
+    cargo_machete_plugin: CargoMachetePlugin,
+    manifest: Path,
+) -> None:
+    """Check handles timeout correctly.
+
+    Args:
+        cargo_machete_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(
+            cargo_machete_plugin,
+            "_run_subprocess",
+            side_effect=TimeoutExpired(cmd=["cargo", "machete"], timeout=60),
+        ):
+            result = cargo_machete_plugin.check([str(manifest)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
@@ -0,0 +1,166 @@
+"""Unit tests for cargo-outdated plugin."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from subprocess import TimeoutExpired
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.tools.definitions.cargo_outdated import CargoOutdatedPlugin
+
+OUTDATED_OUTPUT = json.dumps(
+    {
+        "crate_name": "app",
+        "dependencies": [
+            {
+                "name": "clap",
+                "project": "2.34.0",
+                "compat": "---",
+                "latest": "4.5.4",
+                "kind": "Normal",
+                "platform": None,
+            },
+        ],
+    },
+)
+
+
+@pytest.fixture
+def cargo_outdated_plugin() -> CargoOutdatedPlugin:
+    """Provide a CargoOutdatedPlugin instance for testing.
+
+    Returns:
+        A CargoOutdatedPlugin instance.
+    """
+    return CargoOutdatedPlugin()
+
+
+@pytest.fixture
+def manifest(tmp_path: Path) -> Path:
+    """Create a Cargo.toml with one dependency.
+
+    Args:
+        tmp_path: Temporary directory path.
+
+    Returns:
+        Path: The manifest.
+    """
+    path = tmp_path / "Cargo.toml"
+    path.write_text('[package]\nname = "app"\n\n[dependencies]\nclap = "2"\n')
+    return path
+
+
+def test_definition(cargo_outdated_plugin: CargoOutdatedPlugin) -> None:
+    """Verify the tool is opt-in and cannot fix.
+
+    Args:
+        cargo_outdated_plugin: The plugin instance.
+    """
+    definition = cargo_outdated_plugin.definition
+    assert_that(definition.name).is_equal_to("cargo_outdated")
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.enabled_by_default).is_false()
+
+
+def test_build_command(cargo_outdated_plugin: CargoOutdatedPlugin) -> None:
+    """Verify options map to cargo outdated flags.
+
+    Args:
+        cargo_outdated_plugin: The plugin instance.
+    """
+    assert_that(cargo_outdated_plugin._build_command()).is_equal_to(
+        ["cargo", "outdated", "--format", "json", "--root-deps-only"],
+    )
+    cargo_outdated_plugin.set_options(
+        root_deps_only=False,
+        workspace=True,
+        ignore=["serde", "log"],
+    )
+    assert_that(cargo_outdated_plugin._build_command()).is_equal_to(
+        ["cargo", "outdated", "--format", "json", "--workspace"]
+        + ["--ignore", "serde,log"],
+    )
+
+
+def test_set_options_rejects_inverted_thresholds(
+    cargo_outdated_plugin: CargoOutdatedPlugin,
+) -> None:
+    """Verify error_majors may not be below warning_majors.
+
+    Args:
+        cargo_outdated_plugin: The plugin instance.
+    """
+    with pytest.raises(ValueError, match="error_majors"):
+        cargo_outdated_plugin.set_options(warning_majors=3, error_majors=2)
+    with pytest.raises(ValueError, match="positive"):
+        cargo_outdated_plugin.set_options(warning_majors=0)
+
+
+def test_check_applies_thresholds(
+    cargo_outdated_plugin: CargoOutdatedPlugin,
+    manifest: Path,
+) -> None:
+    """Check reports outdated dependencies with the configured severity.
+
+    Args:
+        cargo_outdated_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    cargo_outdated_plugin.set_options(warning_majors=1, error_majors=3)
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(
+            cargo_outdated_plugin,
+            "_run_subprocess",
+            return_value=(True, OUTDATED_OUTPUT),
+        ) as run:
+            result = cargo_outdated_plugin.check([str(manifest)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    issue = result.issues[0]  # type: ignore[index]
+    assert_that(issue.line).is_equal_to(5)
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.WARNING)
+    assert_that(run.call_args.kwargs["cwd"]).is_equal_to(str(manifest.parent))
+
+
+def test_check_timeout(
+    cargo_outdated_plugin: CargoOutdatedPlugin,
+    manifest: Path,
+) -> None:
+    """Check handles timeout correctly.
+
+    Args:
+        cargo_outdated_plugin: The plugin instance.
+        manifest: Project manifest.
+    """
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(
+            cargo_outdated_plugin,
+            "_run_subprocess",
+            side_effect=TimeoutExpired(cmd=["cargo", "outdated"], timeout=180),
+        ):
+            result = cargo_outdated_plugin.check([str(manifest)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
+
+
+def test_fix_raises(cargo_outdated_plugin: CargoOutdatedPlugin) -> None:
+    """Verify fix is not supported.
+
+    Args:
+        cargo_outdated_plugin: The plugin instance.
+    """
+    with pytest.raises(NotImplementedError):
+        cargo_outdated_plugin.fix(["."], {})
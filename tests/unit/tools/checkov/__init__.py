@@ -0,0 +1 @@
+"""checkov plugin tests."""
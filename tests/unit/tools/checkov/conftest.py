@@ -0,0 +1,17 @@
+"""Shared fixtures for Checkov plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.checkov import CheckovPlugin
+
+
+@pytest.fixture
+def checkov_plugin() -> CheckovPlugin:
+    """Provide a CheckovPlugin instance for testing.
+
+    Returns:
+        CheckovPlugin: A new CheckovPlugin instance.
+    """
+    return CheckovPlugin()
@@ -0,0 +1,192 @@
+"""Tests for CheckovPlugin."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.checkov import CheckovPlugin
+
+REPORT = json.dumps(
+    {
+        "check_type": "dockerfile",
+        "results": {
+            "failed_checks": [
+                {
+                    "check_id": "CKV_DOCKER_2",
+                    "check_name": "Ensure that HEALTHCHECK instructions exist",
+                    "file_abs_path": "/repo/Dockerfile",
+                    "file_line_range": [1, 3],
+                    "resource": "/Dockerfile.",
+                },
+            ],
+            "parsing_errors": [],
+        },
+    },
+)
+
+
+def _context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for two infrastructure files.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 300
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["Dockerfile", "infra/main.tf"]
+    ctx.files = [str(tmp_path / f) for f in ctx.rel_files]
+    return ctx
+
+
+def test_definition_is_opt_in(checkov_plugin: CheckovPlugin) -> None:
+    """Checkov only runs when enabled in config or requested.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+    """
+    definition = checkov_plugin.definition
+
+    assert_that(definition.enabled_by_default).is_false()
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.file_patterns).contains("*.tf", "*.yaml", "Dockerfile")
+
+
+def test_build_command(checkov_plugin: CheckovPlugin) -> None:
+    """Frameworks, checks and skipped checks are passed to Checkov.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+    """
+    checkov_plugin.set_options(
+        frameworks="terraform",
+        checks=["CKV_AWS_20", "CKV_AWS_21"],
+        skip_checks="CKV_AWS_18",
+    )
+
+    cmd = checkov_plugin._build_command(["main.tf", "vars.tf"])
+
+    assert_that(cmd).contains("-o", "json", "--quiet", "--compact")
+    joined = " ".join(cmd)
+    assert_that(joined).contains("--framework terraform -")
+    assert_that(joined).contains("--check CKV_AWS_20,CKV_AWS_21")
+    assert_that(joined).contains("--skip-check CKV_AWS_18")
+    assert_that(joined).ends_with("-f main.tf -f vars.tf")
+
+
+def test_set_options_rejects_invalid_checks(checkov_plugin: CheckovPlugin) -> None:
+    """A non-list checks option is rejected.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="checks"):
+        checkov_plugin.set_options(checks=20)  # type: ignore[arg-type]
+
+
+def test_check_reports_failed_checks(
+    checkov_plugin: CheckovPlugin,
+    tmp_path: Path,
+) -> None:
+    """Failed checks become issues even though Checkov exits non-zero.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            checkov_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            checkov_plugin,
+            "_run_subprocess",
+            return_value=(False, REPORT),
+        ) as mock_run,
+    ):
+        result = checkov_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(tmp_path))
+    assert_that(mock_run.call_args.kwargs["cmd"]).contains("dockerfile")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("CKV_DOCKER_2")
+    assert_that(result.output).is_none()
+
+
+def test_check_reports_errors_without_report(
+    checkov_plugin: CheckovPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without a report fails the result with its output.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            checkov_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            checkov_plugin,
+            "_run_subprocess",
+            return_value=(False, "checkov: error: argument --framework"),
+        ),
+    ):
+        result = checkov_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("checkov: error: argument --framework")
+
+
+def test_check_reports_timeout(checkov_plugin: CheckovPlugin, tmp_path: Path) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            checkov_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            checkov_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["checkov"], timeout=300),
+        ),
+    ):
+        result = checkov_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("checkov:timeout=N")
+
+
+def test_fix_is_not_supported(checkov_plugin: CheckovPlugin) -> None:
+    """Fixing raises NotImplementedError.
+
+    Args:
+        checkov_plugin: The CheckovPlugin instance to test.
+    """
+    with pytest.raises(NotImplementedError):
+        checkov_plugin.fix(["."], {})
@@ -0,0 +1 @@
+"""clang-format plugin tests."""
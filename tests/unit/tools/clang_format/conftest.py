@@ -0,0 +1,17 @@
+"""Shared fixtures for clang-format plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.clang_format import ClangFormatPlugin
+
+
+@pytest.fixture
+def clang_format_plugin() -> ClangFormatPlugin:
+    """Provide a ClangFormatPlugin instance for testing.
+
+    Returns:
+        ClangFormatPlugin: A new ClangFormatPlugin instance.
+    """
+    return ClangFormatPlugin()
@@ -0,0 +1,187 @@
+"""Tests for ClangFormatPlugin."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.clang_format import ClangFormatPlugin
+
+UNFORMATTED = "int main(){\n  return 0;\n}\n"
+FORMATTED = "int main() {\n  return 0;\n}\n"
+
+
+def test_definition(clang_format_plugin: ClangFormatPlugin) -> None:
+    """The plugin formats C/C++ sources and headers and can fix them.
+
+    Args:
+        clang_format_plugin: The ClangFormatPlugin instance to test.
+    """
+    definition = clang_format_plugin.definition
+
+    assert_that(definition.name).is_equal_to("clang_format")
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).contains("*.c", "*.cpp", "*.h", "*.hpp")
+    assert_that(definition.native_configs).contains(".clang-format")
+
+
+def test_set_options_rejects_invalid_style(
+    clang_format_plugin: ClangFormatPlugin,
+) -> None:
+    """A non-string style is rejected.
+
+    Args:
+        clang_format_plugin: The ClangFormatPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="style"):
+        clang_format_plugin.set_options(style=1)  # type: ignore[arg-type]
+
+
+def test_check_reports_changed_regions(
+    clang_format_plugin: ClangFormatPlugin,
+    tmp_path: Path,
+) -> None:
+    """Check diffs the file against clang-format's output.
+
+    Args:
+        clang_format_plugin: The ClangFormatPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    source = tmp_path / "main.c"
+    source.write_text(UNFORMATTED)
+    clang_format_plugin.set_options(style="LLVM")
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(
+            clang_format_plugin,
+            "_run_subprocess",
+            return_value=(True, FORMATTED),
+        ) as mock_run,
+    ):
+        result = clang_format_plugin.check([str(source)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd).contains("--style=LLVM")
+    assert_that(cmd).does_not_contain("-i")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].message).is_equal_to("Formatting differs on line 1")
+    assert_that(result.output).is_none()
+
+
+def test_check_reports_clang_format_errors(
+    clang_format_plugin: ClangFormatPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failing clang-format run is reported as an execution error.
+
+    Args:
+        clang_format_plugin: The ClangFormatPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    source = tmp_path / "main.c"
+    source.write_text(UNFORMATTED)
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(
+            clang_format_plugin,
+            "_run_subprocess",
+            return_value=(False, "Invalid value for ColumnLimit"),
+        ),
+    ):
+        result = clang_format_plugin.check([str(source)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).contains("Invalid value for ColumnLimit")
+
+
+def test_fix_formats_in_place(
+    clang_format_plugin: ClangFormatPlugin,
+    tmp_path: Path,
+) -> None:
+    """Fix runs ``clang-format -i`` and re-checks the file.
+
+    Args:
+        clang_format_plugin: The ClangFormatPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    source = tmp_path / "main.c"
+    source.write_text(UNFORMATTED)
+    commands: list[list[str]] = []
+
+    def run(cmd: list[str], timeout: int) -> tuple[bool, str]:
+        """Format the file on ``-i`` and print it otherwise.
+
+        Args:
+            cmd: Command list.
+            timeout: Timeout in seconds.
+
+        Returns:
+            Tuple of (success, output).
+        """
+        commands.append(cmd)
+        if "-i" in cmd:
+            source.write_text(FORMATTED)
+            return True, ""
+        return True, FORMATTED
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(clang_format_plugin, "_run_subprocess", side_effect=run),
+    ):
+        result = clang_format_plugin.fix([str(source)], {})
+
+    assert_that(commands).is_length(3)
+    assert_that(result.success).is_true()
+    assert_that(result.initial_issues_count).is_equal_to(1)
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+    assert_that(result.output).contains("Fixed 1 issue(s)")
+
+
+def test_fix_skips_on_timeout(
+    clang_format_plugin: ClangFormatPlugin,
+    tmp_path: Path,
+) -> None:
+    """A timed out file is reported as skipped.
+
+    Args:
+        clang_format_plugin: The ClangFormatPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    source = tmp_path / "main.c"
+    source.write_text(UNFORMATTED)
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(
+            clang_format_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["clang-format"], timeout=30),
+        ),
+    ):
+        result = clang_format_plugin.fix([str(source)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("Skipped/failed 1 file(s)")
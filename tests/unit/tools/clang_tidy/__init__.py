@@ -0,0 +1 @@
+"""clang-tidy plugin tests."""
@@ -0,0 +1,17 @@
+"""Shared fixtures for clang-tidy plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.clang_tidy import ClangTidyPlugin
+
+
+@pytest.fixture
+def clang_tidy_plugin() -> ClangTidyPlugin:
+    """Provide a ClangTidyPlugin instance for testing.
+
+    Returns:
+        ClangTidyPlugin: A new ClangTidyPlugin instance.
+    """
+    return ClangTidyPlugin()
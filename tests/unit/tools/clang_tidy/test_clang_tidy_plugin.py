@@ -0,0 +1,233 @@
+"""Tests for ClangTidyPlugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.clang_tidy import (
+    find_compile_commands_dir,
+    group_by_compile_commands,
+)
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.clang_tidy import ClangTidyPlugin
+
+
+def _context(files: list[Path]) -> MagicMock:
+    """Build an execution context for the given files.
+
+    Args:
+        files: Source files to check.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 300
+    ctx.cwd = None
+    ctx.files = [str(f) for f in files]
+    ctx.rel_files = ctx.files
+    return ctx
+
+
+def _export_from(cmd: list[str]) -> Path:
+    """Return the export file named on a clang-tidy command line.
+
+    Args:
+        cmd: clang-tidy command.
+
+    Returns:
+        Path: The ``--export-fixes`` file.
+    """
+    arg = next(a for a in cmd if a.startswith("--export-fixes="))
+    return Path(arg.split("=", 1)[1])
+
+
+def test_find_compile_commands_dir_searches_build_dirs(tmp_path: Path) -> None:
+    """The database is found in a parent's build directory.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "src").mkdir()
+    (tmp_path / "build").mkdir()
+    (tmp_path / "build" / "compile_commands.json").write_text("[]")
+
+    found = find_compile_commands_dir(tmp_path / "src" / "main.cpp")
+
+    assert_that(found).is_equal_to((tmp_path / "build").resolve())
+
+
+def test_group_by_compile_commands(tmp_path: Path) -> None:
+    """Files without a database are grouped under None.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "app").mkdir()
+    (tmp_path / "app" / "compile_commands.json").write_text("[]")
+    (tmp_path / "tools").mkdir()
+    app = str(tmp_path / "app" / "main.cpp")
+    tool = str(tmp_path / "tools" / "gen.c")
+
+    groups = group_by_compile_commands([app, tool])
+
+    assert_that(groups).is_equal_to(
+        {str((tmp_path / "app").resolve()): [app], None: [tool]},
+    )
+    assert_that(group_by_compile_commands([app, tool], "out")).is_equal_to(
+        {"out": [app, tool]},
+    )
+
+
+def test_set_options_rejects_invalid_checks(
+    clang_tidy_plugin: ClangTidyPlugin,
+) -> None:
+    """A non-string checks option is rejected.
+
+    Args:
+        clang_tidy_plugin: The ClangTidyPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="checks"):
+        clang_tidy_plugin.set_options(checks=["bugprone-*"])  # type: ignore[arg-type]
+
+
+def test_check_reads_export_with_database(
+    clang_tidy_plugin: ClangTidyPlugin,
+    tmp_path: Path,
+) -> None:
+    """Sources with a database run with ``-p`` and report exported diagnostics.
+
+    Args:
+        clang_tidy_plugin: The ClangTidyPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "compile_commands.json").write_text("[]")
+    source = tmp_path / "main.cpp"
+    source.write_text("int main() {\n  int *p = 0;\n}\n")
+    clang_tidy_plugin.set_options(checks="-*,modernize-*")
+    exports: list[Path] = []
+
+    def run(cmd: list[str], timeout: int) -> tuple[bool, str]:
+        """Write an export with one diagnostic.
+
+        Args:
+            cmd: Command list.
+            timeout: Timeout in seconds.
+
+        Returns:
+            Tuple of (success, output).
+        """
+        export = _export_from(cmd)
+        exports.append(export)
+        export.write_text(
+            "Diagnostics:\n"
+            "  - DiagnosticName: modernize-use-nullptr\n"
+            "    DiagnosticMessage:\n"
+            "      Message: use nullptr\n"
+            f"      FilePath: '{source}'\n"
+            "      FileOffset: 24\n"
+            "    Level: Warning\n",
+        )
+        return True, ""
+
+    with (
+        patch.object(
+            clang_tidy_plugin,
+            "_prepare_execution",
+            return_value=_context([source]),
+        ),
+        patch.object(clang_tidy_plugin, "_run_subprocess", side_effect=run) as run_,
+    ):
+        result = clang_tidy_plugin.check(["."], {})
+
+    cmd = run_.call_args.kwargs["cmd"]
+    assert_that(cmd).contains("--quiet", "--checks=-*,modernize-*", "-p")
+    assert_that(cmd).does_not_contain("--")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that((result.issues[0].line, result.issues[0].column)).is_equal_to((2, 12))
+    assert_that(exports[0].exists()).is_false()
+
+
+def test_check_without_database_uses_default_flags(
+    clang_tidy_plugin: ClangTidyPlugin,
+    tmp_path: Path,
+) -> None:
+    """Sources without a database end with ``--`` and fall back to console output.
+
+    Args:
+        clang_tidy_plugin: The ClangTidyPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    source = tmp_path / "main.c"
+    source.write_text("int main(void) { return 0; }\n")
+    output = f"{source}:1:1: warning: bad style [readability-x]\n"
+
+    with (
+        patch.object(
+            clang_tidy_plugin,
+            "_prepare_execution",
+            return_value=_context([source]),
+        ),
+        patch.object(
+            clang_tidy_plugin,
+            "_run_subprocess",
+            return_value=(True, output),
+        ) as mock_run,
+    ):
+        result = clang_tidy_plugin.check(["."], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd[-1]).is_equal_to("--")
+    assert_that(cmd).does_not_contain("-p")
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("readability-x")
+
+
+def test_check_reports_failed_runs(
+    clang_tidy_plugin: ClangTidyPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without diagnostics fails the result with its output.
+
+    Args:
+        clang_tidy_plugin: The ClangTidyPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    source = tmp_path / "main.c"
+    source.write_text("int main(void) { return 0; }\n")
+
+    with (
+        patch.object(
+            clang_tidy_plugin,
+            "_prepare_execution",
+            return_value=_context([source]),
+        ),
+        patch.object(
+            clang_tidy_plugin,
+            "_run_subprocess",
+            return_value=(False, "Error: no checks enabled."),
+        ),
+    ):
+        result = clang_tidy_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("Error: no checks enabled.")
+
+
+def test_fix_is_not_supported(clang_tidy_plugin: ClangTidyPlugin) -> None:
+    """Fixing raises NotImplementedError.
+
+    Args:
+        clang_tidy_plugin: The ClangTidyPlugin instance to test.
+    """
+    with pytest.raises(NotImplementedError):
+        clang_tidy_plugin.fix(["."], {})
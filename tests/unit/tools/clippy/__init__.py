@@ -0,0 +1 @@
+"""Clippy plugin tests."""
@@ -0,0 +1,132 @@
+"""Unit tests for applying clippy suggestions without cargo clippy --fix."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+from typing import Any
+from unittest.mock import patch
+
+from assertpy import assert_that
+
+from lintro.parsers.clippy.clippy_parser import parse_clippy_output
+from lintro.tools.definitions.clippy import ClippyPlugin, _apply_suggestions
+
+SOURCE = "fn f() -> i32 { return 1; }\n"
+
+
+def _diagnostic(file_name: str, start: int, end: int, replacement: str) -> str:
+    """Build a needless_return diagnostic with a machine-applicable fix.
+
+    Args:
+        file_name: File the suggestion edits.
+        start: First byte replaced.
+        end: Byte after the replaced range.
+        replacement: Replacement text.
+
+    Returns:
+        str: JSON line as cargo prints it.
+    """
+    span: dict[str, Any] = {
+        "file_name": file_name,
+        "byte_start": start,
+        "byte_end": end,
+        "suggested_replacement": replacement,
+        "suggestion_applicability": "MachineApplicable",
+    }
+    message = {
+        "code": {"code": "clippy::needless_return"},
+        "level": "warning",
+        "message": "unneeded `return` statement",
+        "spans": [{"file_name": file_name, "line_start": 1, "column_start": 17}],
+        "children": [{"level": "help", "message": "remove", "spans": [span]}],
+    }
+    return json.dumps({"reason": "compiler-message", "message": message})
+
+
+def test_fix_applies_suggestions_without_cargo_fix(
+    clippy_plugin: ClippyPlugin,
+    tmp_path: Path,
+) -> None:
+    """Fixing edits the files itself and re-checks until nothing applies.
+
+    Args:
+        clippy_plugin: The ClippyPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "demo"\nversion = "0.1.0"\n',
+    )
+    source = tmp_path / "src" / "lib.rs"
+    source.parent.mkdir()
+    source.write_text(SOURCE)
+    commands: list[list[str]] = []
+
+    def stream(
+        cmd: list[str],
+        timeout: int | None = None,
+        cwd: str | None = None,
+        line_handler: Callable[[str], None] | None = None,
+        max_output_chars: int | None = None,
+    ) -> tuple[bool, str]:
+        """Report the needless return while the file still has it.
+
+        Args:
+            cmd: Command to run.
+            timeout: Timeout in seconds.
+            cwd: Working directory.
+            line_handler: Callback for each line of output.
+            max_output_chars: Characters of output to return.
+
+        Returns:
+            tuple[bool, str]: Success flag and output.
+        """
+        assert line_handler is not None
+        commands.append(cmd)
+        if "return" in source.read_text():
+            line_handler(_diagnostic("src/lib.rs", 16, 25, "1"))
+        return True, ""
+
+    with (
+        patch.object(clippy_plugin, "_verify_tool_version", return_value=None),
+        patch.object(
+            clippy_plugin,
+            "_run_subprocess_streaming",
+            side_effect=stream,
+        ),
+    ):
+        result = clippy_plugin.fix([str(source)], {})
+
+    assert_that(source.read_text()).is_equal_to("fn f() -> i32 { 1 }\n")
+    assert_that(commands).is_length(2)
+    assert_that([arg for cmd in commands for arg in cmd]).does_not_contain("--fix")
+    assert_that(result.initial_issues_count).is_equal_to(1)
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+    assert_that(result.success).is_true()
+
+
+def test_apply_suggestions_leaves_files_outside_the_project(tmp_path: Path) -> None:
+    """Suggestions pointing into dependency sources are not applied.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    project = tmp_path / "app"
+    project.mkdir()
+    dependency = tmp_path / "registry" / "lib.rs"
+    dependency.parent.mkdir()
+    dependency.write_text(SOURCE)
+    issues = parse_clippy_output(
+        "\n".join(
+            [
+                _diagnostic(str(dependency), 16, 25, "1"),
+                _diagnostic("../registry/lib.rs", 16, 25, "1"),
+            ],
+        ),
+    )
+
+    _apply_suggestions(project, issues, {"clippy::needless_return"})
+
+    assert_that(dependency.read_text()).is_equal_to(SOURCE)
@@ -0,0 +1,186 @@
+"""Unit tests for running clippy across a target/feature matrix."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.clippy import (
+    ClippyPlugin,
+    _build_clippy_command,
+    parse_matrix_entry,
+)
+
+
+def _diagnostic(code: str, line: int) -> str:
+    """Build one line of cargo clippy JSON output.
+
+    Args:
+        code: Lint code of the diagnostic.
+        line: Line the diagnostic points at.
+
+    Returns:
+        str: JSON line of the diagnostic.
+    """
+    return json.dumps(
+        {
+            "reason": "compiler-message",
+            "message": {
+                "code": {"code": code},
+                "level": "warning",
+                "message": f"{code} triggered",
+                "spans": [
+                    {
+                        "file_name": "src/lib.rs",
+                        "line_start": line,
+                        "line_end": line,
+                        "column_start": 5,
+                        "column_end": 15,
+                    },
+                ],
+            },
+        },
+    )
+
+
+@pytest.mark.parametrize(
+    ("entry", "flags"),
+    [
+        ("default", []),
+        ("--all-targets --all-features", ["--all-targets", "--all-features"]),
+        (
+            "--lib --no-default-features --features 'serde std'",
+            ["--lib", "--no-default-features", "--features", "serde std"],
+        ),
+        ("--tests -F=serde", ["--tests", "-F=serde"]),
+    ],
+)
+def test_parse_matrix_entry(entry: str, flags: list[str]) -> None:
+    """Entries are split into target and feature flags.
+
+    Args:
+        entry: Matrix entry as configured.
+        flags: Expected cargo flags.
+    """
+    assert_that(parse_matrix_entry(entry)).is_equal_to(flags)
+
+
+@pytest.mark.parametrize(
+    ("entry", "message"),
+    [
+        ("--all-targets --release", "not '--release'"),
+        ("--features", "misses a value for --features"),
+    ],
+)
+def test_parse_matrix_entry_rejects_other_flags(entry: str, message: str) -> None:
+    """Only target and feature selection flags are accepted.
+
+    Args:
+        entry: Matrix entry as configured.
+        message: Expected part of the error.
+    """
+    with pytest.raises(ValueError, match=message):
+        parse_matrix_entry(entry)
+
+
+def test_build_clippy_command_uses_selection() -> None:
+    """A selection replaces the default all-targets, all-features build."""
+    assert_that(_build_clippy_command()).contains("--all-targets", "--all-features")
+    cmd = _build_clippy_command(selection=["--lib", "--no-default-features"])
+
+    assert_that(cmd).is_equal_to(
+        [
+            "cargo",
+            "clippy",
+            "--workspace",
+            "--lib",
+            "--no-default-features",
+            "--message-format=json",
+        ],
+    )
+
+
+def test_set_options_validates_matrix(clippy_plugin: ClippyPlugin) -> None:
+    """Invalid matrix entries are rejected when the option is set.
+
+    Args:
+        clippy_plugin: The ClippyPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="--locked"):
+        clippy_plugin.set_options(matrix=["--lib --locked"])
+
+
+def test_check_merges_matrix_runs(
+    clippy_plugin: ClippyPlugin,
+    tmp_path: Path,
+) -> None:
+    """Each entry runs once; issues of only some entries are tagged.
+
+    Args:
+        clippy_plugin: The ClippyPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "demo"\nversion = "0.1.0"\n',
+    )
+    source = tmp_path / "src" / "lib.rs"
+    source.parent.mkdir()
+    source.write_text("pub fn f() -> i32 { return 1; }\n")
+    commands: list[list[str]] = []
+
+    def stream(
+        cmd: list[str],
+        timeout: int | None = None,
+        cwd: str | None = None,
+        line_handler: Callable[[str], None] | None = None,
+        max_output_chars: int | None = None,
+    ) -> tuple[bool, str]:
+        """Report a test-only lint only when test targets are built.
+
+        Args:
+            cmd: Command to run.
+            timeout: Timeout in seconds.
+            cwd: Working directory.
+            line_handler: Callback for each line of output.
+            max_output_chars: Characters of output to return.
+
+        Returns:
+            tuple[bool, str]: Success flag and output.
+        """
+        assert line_handler is not None
+        commands.append(cmd)
+        line_handler(_diagnostic("clippy::needless_return", 1))
+        if "--all-targets" in cmd:
+            line_handler(_diagnostic("clippy::unwrap_used", 9))
+        return True, ""
+
+    clippy_plugin.set_options(
+        matrix=["--all-targets --all-features", "--lib --no-default-features"],
+    )
+    with (
+        patch.object(clippy_plugin, "_verify_tool_version", return_value=None),
+        patch.object(
+            clippy_plugin,
+            "_run_subprocess_streaming",
+            side_effect=stream,
+        ),
+    ):
+        result = clippy_plugin.check([str(source)], {})
+
+    assert_that(commands).is_length(2)
+    assert_that(commands[1]).contains("--lib", "--no-default-features")
+    assert_that(commands[1]).does_not_contain("--all-targets")
+    assert_that(result.issues_count).is_equal_to(2)
+    shared, test_only = result.issues  # type: ignore[misc]
+    assert_that(shared.configurations).is_empty()
+    assert_that(test_only.configurations).is_equal_to(
+        ["--all-targets --all-features"],
+    )
+    assert_that(test_only.to_display_row()["message"]).ends_with(
+        "[only with --all-targets --all-features]",
+    )
@@ -0,0 +1,99 @@
+"""Unit tests for parsing clippy output as it streams."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+from unittest.mock import patch
+
+from assertpy import assert_that
+
+from lintro.tools.definitions.clippy import ClippyPlugin
+
+
+def _diagnostic(line: int) -> str:
+    """Build one line of cargo clippy JSON output.
+
+    Args:
+        line: Line the diagnostic points at.
+
+    Returns:
+        str: JSON line of the diagnostic.
+    """
+    return json.dumps(
+        {
+            "reason": "compiler-message",
+            "message": {
+                "code": {"code": "clippy::needless_return"},
+                "level": "warning",
+                "message": "unneeded `return` statement",
+                "spans": [
+                    {
+                        "file_name": "src/lib.rs",
+                        "line_start": line,
+                        "line_end": line,
+                        "column_start": 5,
+                        "column_end": 15,
+                    },
+                ],
+            },
+        },
+    )
+
+
+def test_check_parses_lines_as_they_stream(
+    clippy_plugin: ClippyPlugin,
+    tmp_path: Path,
+) -> None:
+    """Issues come from the streamed lines, not the truncated output.
+
+    Args:
+        clippy_plugin: The ClippyPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "demo"\nversion = "0.1.0"\n',
+    )
+    source = tmp_path / "src" / "lib.rs"
+    source.parent.mkdir()
+    source.write_text("pub fn f() -> i32 { return 1; }\n")
+
+    def stream(
+        cmd: list[str],
+        timeout: int | None = None,
+        cwd: str | None = None,
+        line_handler: Callable[[str], None] | None = None,
+        max_output_chars: int | None = None,
+    ) -> tuple[bool, str]:
+        """Stream diagnostics to the handler and return truncated output.
+
+        Args:
+            cmd: Command to run.
+            timeout: Timeout in seconds.
+            cwd: Working directory.
+            line_handler: Callback for each line of output.
+            max_output_chars: Characters of output to return.
+
+        Returns:
+            tuple[bool, str]: Success flag and the kept output.
+        """
+        assert line_handler is not None
+        for line in range(1, 1001):
+            line_handler(_diagnostic(line))
+        return False, "... [output truncated]"
+
+    with (
+        patch.object(clippy_plugin, "_verify_tool_version", return_value=None),
+        patch.object(
+            clippy_plugin,
+            "_run_subprocess_streaming",
+            side_effect=stream,
+        ) as mock_stream,
+    ):
+        result = clippy_plugin.check([str(source)], {})
+
+    assert_that(result.issues_count).is_equal_to(1000)
+    assert_that(result.issues[999].line).is_equal_to(1000)
+    assert_that(result.output).is_none()
+    assert_that(mock_stream.call_args.kwargs["max_output_chars"]).is_not_none()
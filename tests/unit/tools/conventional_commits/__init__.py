@@ -0,0 +1 @@
+"""Conventional Commits plugin tests."""
@@ -0,0 +1,142 @@
+"""Unit tests for the Conventional Commits message checks."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.implementations.conventional_commits import (
+    check_commit_message,
+    read_commit_messages,
+)
+
+
+def _git(repo: Path, *args: str) -> str:
+    """Run git in a repository.
+
+    Args:
+        repo: Repository directory.
+        *args: Git arguments.
+
+    Returns:
+        str: The command's stdout.
+    """
+    result = subprocess.run(
+        ["git", "-C", str(repo), *args],
+        capture_output=True,
+        text=True,
+        check=True,
+    )
+    return result.stdout.strip()
+
+
+def _commit(repo: Path, message: str) -> None:
+    """Create an empty commit.
+
+    Args:
+        repo: Repository directory.
+        message: Commit message.
+    """
+    _git(
+        repo,
+        "-c",
+        "user.name=Test",
+        "-c",
+        "user.email=test@example.com",
+        "commit",
+        "--allow-empty",
+        "-q",
+        "-m",
+        message,
+    )
+
+
+@pytest.mark.parametrize(
+    "message",
+    [
+        "feat: add the thing",
+        "fix(parser): handle empty config",
+        "refactor(core)!: rewrite execution model",
+        "docs: explain\n\nLonger body text.",
+        "fixup! feat: add the thing",
+    ],
+)
+def test_valid_messages(message: str) -> None:
+    """Conventional headers and autosquash commits pass.
+
+    Args:
+        message: Commit message to check.
+    """
+    assert_that(check_commit_message(message, "PR title")).is_empty()
+
+
+def test_malformed_header() -> None:
+    """A header without a type is reported as CC001."""
+    issues = check_commit_message("Add the thing", "PR title")
+
+    assert_that([i.code for i in issues]).is_equal_to(["CC001"])
+    assert_that(issues[0].file).is_equal_to("PR title")
+    assert_that(issues[0].line).is_equal_to(1)
+
+
+def test_unknown_type_and_missing_scope() -> None:
+    """Unknown types and missing required scopes are reported."""
+    issues = check_commit_message(
+        "feature: add the thing",
+        "PR title",
+        types=["feat", "fix"],
+        require_scope=True,
+    )
+
+    assert_that([i.code for i in issues]).is_equal_to(["CC002", "CC003"])
+    assert_that(issues[0].message).contains("'feature'", "feat, fix")
+
+
+def test_long_header_and_body_separation() -> None:
+    """Long headers and bodies glued to the header are reported."""
+    message = "feat: " + "x" * 60 + "\nbody right after the header"
+
+    issues = check_commit_message(message, "commit abc", max_header_length=50)
+
+    assert_that([(i.code, i.line) for i in issues]).is_equal_to(
+        [("CC004", 1), ("CC005", 2)],
+    )
+    unlimited = check_commit_message("feat: " + "x" * 200, "t", max_header_length=0)
+    assert_that(unlimited).is_empty()
+
+
+def test_read_commit_messages(tmp_path: Path) -> None:
+    """Commits in a range are read newest first with their full messages.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    _git(tmp_path, "init", "-q")
+    _commit(tmp_path, "chore: initial commit")
+    base = _git(tmp_path, "rev-parse", "HEAD")
+    _commit(tmp_path, "feat: first\n\nWith a body.")
+    _commit(tmp_path, "Second change")
+
+    commits = read_commit_messages(f"{base}..HEAD", cwd=str(tmp_path))
+
+    assert_that([c.message.splitlines()[0] for c in commits]).is_equal_to(
+        ["Second change", "feat: first"],
+    )
+    assert_that(commits[1].message).contains("With a body.")
+    assert_that(commits[0].sha).is_length(40)
+
+
+def test_read_commit_messages_rejects_unknown_range(tmp_path: Path) -> None:
+    """An unresolvable range raises ValueError with git's message.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    _git(tmp_path, "init", "-q")
+    _commit(tmp_path, "chore: initial commit")
+
+    with pytest.raises(ValueError, match="no-such-branch"):
+        read_commit_messages("no-such-branch..HEAD", cwd=str(tmp_path))
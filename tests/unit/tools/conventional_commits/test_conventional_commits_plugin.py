@@ -0,0 +1,148 @@
+"""Unit tests for the Conventional Commits plugin."""
+
+from __future__ import annotations
+
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.conventional_commits import ConventionalCommitsPlugin
+from lintro.tools.implementations.conventional_commits import CommitMessage
+
+SHA = "0123456789abcdef0123456789abcdef01234567"
+
+
+@pytest.fixture
+def conventional_commits_plugin() -> ConventionalCommitsPlugin:
+    """Provide a ConventionalCommitsPlugin instance for testing.
+
+    Returns:
+        A ConventionalCommitsPlugin instance.
+    """
+    return ConventionalCommitsPlugin()
+
+
+def test_definition_is_opt_in_native_linter(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify the plugin needs no binary, cannot fix and is opt-in.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    definition = conventional_commits_plugin.definition
+    assert_that(definition.name).is_equal_to("conventional_commits")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.enabled_by_default).is_false()
+
+
+def test_check_is_skipped_without_input(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify the tool is skipped without a commit range or PR title.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    result = conventional_commits_plugin.check(["."], {})
+
+    assert_that(result.skipped).is_true()
+    assert_that(result.output).contains("--commit-range", "--pr-title")
+
+
+def test_check_pr_title(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify a PR title is checked with the configured rules.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    conventional_commits_plugin.set_options(
+        pr_title="feat: add, then polish",
+        require_scope=True,
+    )
+
+    result = conventional_commits_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].file).is_equal_to("PR title")
+    assert_that(result.issues[0].code).is_equal_to("CC003")
+
+
+def test_check_commit_range(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify each commit in the range is checked and labelled by hash.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    conventional_commits_plugin.set_options(
+        commit_range="origin/main..HEAD",
+        types="fix",
+    )
+    commits = [
+        CommitMessage(sha=SHA, message="feat: add the thing\n"),
+        CommitMessage(sha="f" * 40, message="fix: handle it\n"),
+    ]
+
+    with patch(
+        "lintro.tools.definitions.conventional_commits.read_commit_messages",
+        return_value=commits,
+    ) as mock_read:
+        result = conventional_commits_plugin.check(["."], {})
+
+    assert_that(mock_read.call_args.args[0]).is_equal_to("origin/main..HEAD")
+    assert_that(result.issues_count).is_equal_to(1)
+    issue = result.issues[0]
+    assert_that(issue.file).is_equal_to("commit 01234567")
+    assert_that(issue.commit).is_equal_to(SHA)
+    assert_that(issue.code).is_equal_to("CC002")
+
+
+def test_check_reports_git_errors(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify an unreadable range fails the run with git's message.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    conventional_commits_plugin.set_options(commit_range="nope..HEAD")
+
+    with patch(
+        "lintro.tools.definitions.conventional_commits.read_commit_messages",
+        side_effect=ValueError("Cannot read commits in 'nope..HEAD': bad revision"),
+    ):
+        result = conventional_commits_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("bad revision")
+
+
+def test_set_options_rejects_negative_header_length(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify max_header_length must not be negative.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    with pytest.raises(ValueError, match="max_header_length"):
+        conventional_commits_plugin.set_options(max_header_length=-1)
+
+
+def test_fix_is_not_supported(
+    conventional_commits_plugin: ConventionalCommitsPlugin,
+) -> None:
+    """Verify fixing raises NotImplementedError.
+
+    Args:
+        conventional_commits_plugin: The plugin instance.
+    """
+    with pytest.raises(NotImplementedError):
+        conventional_commits_plugin.fix(["."], {})
@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 from collections.abc import Generator
+from pathlib import Path
 from unittest.mock import patch
 
 import pytest
@@ -16,6 +17,7 @@ from lintro.tools.core.command_builders import (
     NodeJSBuilder,
     PytestBuilder,
     PythonBundledBuilder,
+    RubyBuilder,
     StandaloneBuilder,
 )
 
@@ -257,6 +259,24 @@ def test_nodejs_builder_handles_astro_check() -> None:
     assert_that(builder.can_handle(ToolName.ASTRO_CHECK)).is_true()
 
 
+def test_nodejs_builder_handles_eslint() -> None:
+    """NodeJSBuilder runs eslint through bunx."""
+    builder = NodeJSBuilder()
+    assert_that(builder.can_handle(ToolName.ESLINT)).is_true()
+    with patch("shutil.which", return_value="/usr/local/bin/bunx"):
+        cmd = builder.get_command("eslint", ToolName.ESLINT)
+        assert_that(cmd).is_equal_to(["bunx", "eslint"])
+
+
+def test_nodejs_builder_handles_stylelint() -> None:
+    """NodeJSBuilder runs stylelint through bunx."""
+    builder = NodeJSBuilder()
+    assert_that(builder.can_handle(ToolName.STYLELINT)).is_true()
+    with patch("shutil.which", return_value="/usr/local/bin/bunx"):
+        cmd = builder.get_command("stylelint", ToolName.STYLELINT)
+        assert_that(cmd).is_equal_to(["bunx", "stylelint"])
+
+
 def test_nodejs_builder_does_not_handle_ruff() -> None:
     """NodeJSBuilder does not handle Python tools."""
     builder = NodeJSBuilder()
@@ -338,6 +358,54 @@ def test_cargo_builder_returns_cargo_audit() -> None:
     assert_that(cmd).is_equal_to(["cargo", "audit"])
 
 
+# =============================================================================
+# RubyBuilder tests
+# =============================================================================
+
+
+def test_ruby_builder_handles_rubocop() -> None:
+    """RubyBuilder can handle rubocop."""
+    builder = RubyBuilder()
+    assert_that(builder.can_handle(ToolName.RUBOCOP)).is_true()
+    assert_that(builder.can_handle(ToolName.RUFF)).is_false()
+
+
+def test_ruby_builder_uses_bundle_exec_for_locked_gem(tmp_path: Path) -> None:
+    """RubyBuilder runs a gem locked in the project's Gemfile via bundle exec.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    builder = RubyBuilder()
+    with (
+        patch(
+            "lintro.utils.ruby_bundler.get_bundled_gem_dir",
+            return_value=tmp_path,
+        ),
+        patch(
+            "lintro.tools.core.command_builders.os.getcwd",
+            return_value=str(tmp_path),
+        ),
+    ):
+        cmd = builder.get_command("rubocop", ToolName.RUBOCOP)
+    assert_that(cmd).is_equal_to(["bundle", "exec", "rubocop"])
+
+
+def test_ruby_builder_falls_back_to_tool_name(tmp_path: Path) -> None:
+    """RubyBuilder runs the gem directly outside a bundled project.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    builder = RubyBuilder()
+    with patch(
+        "lintro.tools.core.command_builders.os.getcwd",
+        return_value=str(tmp_path),
+    ):
+        cmd = builder.get_command("rubocop", ToolName.RUBOCOP)
+    assert_that(cmd).is_equal_to(["rubocop"])
+
+
 # =============================================================================
 # StandaloneBuilder tests
 # =============================================================================
@@ -11,6 +11,7 @@ from lintro.tools.core.option_validators import (
     filter_none_options,
     normalize_str_or_list,
     validate_bool,
+    validate_dict,
     validate_int,
     validate_list,
     validate_positive_int,
@@ -262,6 +263,46 @@ def test_validate_list_rejects_invalid_values(value: Any, description: str) -> N
         validate_list(value, "test")
 
 
+# =============================================================================
+# validate_dict tests
+# =============================================================================
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        pytest.param({"MD013": False}, id="non_empty_dict"),
+        pytest.param({}, id="empty_dict"),
+        pytest.param(None, id="none"),
+    ],
+)
+def test_validate_dict_accepts_valid_values(value: dict[str, Any] | None) -> None:
+    """Accept dict and None values.
+
+    Args:
+        value: The dict value to validate.
+    """
+    # Should not raise - test passes if no exception
+    validate_dict(value, "test")
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        pytest.param("MD013=false", id="string"),
+        pytest.param([("MD013", False)], id="list"),
+    ],
+)
+def test_validate_dict_rejects_invalid_values(value: Any) -> None:
+    """Reject non-dict values.
+
+    Args:
+        value: The invalid value to test.
+    """
+    with pytest.raises(ValueError, match="must be a dictionary"):
+        validate_dict(value, "test")
+
+
 # =============================================================================
 # normalize_str_or_list tests
 # =============================================================================
@@ -346,3 +387,4 @@ def test_filter_none_options_preserves_falsy_values() -> None:
     """Preserve False, 0, empty string (not None)."""
     result = filter_none_options(a=False, b=0, c="", d=None)
     assert_that(result).is_equal_to({"a": False, "b": 0, "c": ""})
+
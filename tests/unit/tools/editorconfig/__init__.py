@@ -0,0 +1 @@
+"""EditorConfig plugin tests."""
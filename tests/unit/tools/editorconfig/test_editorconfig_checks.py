@@ -0,0 +1,89 @@
+"""Unit tests for the EditorConfig compliance checks and fixes."""
+
+from __future__ import annotations
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.editorconfig import check_text, fix_text
+
+SPACES = {"indent_style": "space", "indent_size": "4", "tab_width": "4"}
+TABS = {"indent_style": "tab", "indent_size": "4", "tab_width": "4"}
+
+
+def _codes(text: str, properties: dict[str, str]) -> list[tuple[str, int]]:
+    """Return the (code, line) pairs reported for a text.
+
+    Args:
+        text: File contents.
+        properties: Effective EditorConfig properties.
+
+    Returns:
+        list[tuple[str, int]]: Codes and lines of the issues.
+    """
+    return [(i.code, i.line) for i in check_text(text, "f.txt", properties)]
+
+
+def test_indent_style_space() -> None:
+    """Tabs in the indentation of space-indented files are reported and expanded."""
+    text = "def f():\n\treturn 1\n"
+
+    assert_that(_codes(text, SPACES)).is_equal_to([("EC001", 2)])
+    assert_that(fix_text(text, SPACES)).is_equal_to("def f():\n    return 1\n")
+
+
+def test_indent_style_tab() -> None:
+    """Space indentation in tab-indented files is converted, alignment kept."""
+    text = "all:\n        build\n/*\n * comment\n */\n"
+
+    assert_that(_codes(text, TABS)).is_equal_to([("EC001", 2)])
+    assert_that(fix_text(text, TABS)).is_equal_to(
+        "all:\n\t\tbuild\n/*\n * comment\n */\n",
+    )
+
+
+def test_trailing_whitespace() -> None:
+    """Trailing spaces and tabs are reported at their column and trimmed."""
+    properties = {"trim_trailing_whitespace": "true"}
+    text = "a  \nb\t\nc\n"
+
+    issues = check_text(text, "f.txt", properties)
+
+    assert_that([(i.line, i.column) for i in issues]).is_equal_to([(1, 2), (2, 2)])
+    assert_that(issues[0].fixable).is_true()
+    assert_that(fix_text(text, properties)).is_equal_to("a\nb\nc\n")
+
+
+def test_final_newline() -> None:
+    """A missing or unwanted final newline is reported and fixed."""
+    wanted = {"insert_final_newline": "true"}
+    unwanted = {"insert_final_newline": "false"}
+
+    assert_that(_codes("a\r\nb", wanted)).is_equal_to([("EC003", 2)])
+    assert_that(fix_text("a\r\nb", wanted)).is_equal_to("a\r\nb\r\n")
+    assert_that(_codes("a\n", unwanted)).is_equal_to([("EC003", 1)])
+    assert_that(fix_text("a\n\n", unwanted)).is_equal_to("a")
+    assert_that(_codes("", wanted)).is_empty()
+
+
+def test_end_of_line() -> None:
+    """The first wrong line ending is reported and all are converted."""
+    properties = {"end_of_line": "lf"}
+    text = "a\r\nb\r\nc\n"
+
+    assert_that(_codes(text, properties)).is_equal_to([("EC004", 1)])
+    assert_that(fix_text(text, properties)).is_equal_to("a\nb\nc\n")
+
+
+def test_max_line_length_is_not_fixed() -> None:
+    """Long lines are reported but left for a human to wrap."""
+    properties = {"max_line_length": "10"}
+    text = "short\n" + "x" * 12 + "\n"
+
+    issues = check_text(text, "f.txt", properties)
+
+    assert_that([(i.code, i.line, i.column) for i in issues]).is_equal_to(
+        [("EC005", 2, 11)],
+    )
+    assert_that(issues[0].fixable).is_false()
+    assert_that(fix_text(text, properties)).is_equal_to(text)
+    assert_that(_codes(text, {"max_line_length": "off"})).is_empty()
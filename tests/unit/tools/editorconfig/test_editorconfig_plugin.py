@@ -0,0 +1,127 @@
+"""Unit tests for the EditorConfig plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.editorconfig import EditorconfigPlugin
+
+LONG_LINE = b"value = 'a string well over forty characters'"
+EDITORCONFIG = (
+    "root = true\n\n[*]\nindent_style = space\nindent_size = 2\n"
+    "trim_trailing_whitespace = true\ninsert_final_newline = true\n"
+    "max_line_length = 40\n"
+)
+
+
+@pytest.fixture
+def editorconfig_plugin() -> EditorconfigPlugin:
+    """Provide an EditorconfigPlugin instance for testing.
+
+    Returns:
+        An EditorconfigPlugin instance.
+    """
+    return EditorconfigPlugin()
+
+
+def test_definition_is_native_fixer(editorconfig_plugin: EditorconfigPlugin) -> None:
+    """Verify the plugin needs no external binary and can fix.
+
+    Args:
+        editorconfig_plugin: The plugin instance.
+    """
+    definition = editorconfig_plugin.definition
+    assert_that(definition.name).is_equal_to("editorconfig")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.native_configs).contains(".editorconfig")
+
+
+def test_check_reports_violations(
+    editorconfig_plugin: EditorconfigPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify violations are reported and binary files are skipped.
+
+    Args:
+        editorconfig_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".editorconfig").write_text(EDITORCONFIG)
+    (tmp_path / "app.py").write_text("def f():\n\treturn 1  ")
+    (tmp_path / "logo.png").write_bytes(b"\x89PNG\r\n\x00\x00  \t")
+
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        result = editorconfig_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(sorted(i.code for i in result.issues)).is_equal_to(
+        ["EC001", "EC002", "EC003"],
+    )
+    assert_that({Path(i.file).name for i in result.issues}).is_equal_to({"app.py"})
+
+
+def test_check_is_skipped_without_editorconfig(
+    editorconfig_plugin: EditorconfigPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify projects without an .editorconfig are skipped.
+
+    Args:
+        editorconfig_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "app.py").write_text("x = 1\n")
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch(
+            "lintro.tools.implementations.editorconfig.editorconfig_rules."
+            "EditorconfigResolver.properties_for",
+            return_value={},
+        ),
+    ):
+        result = editorconfig_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to("no .editorconfig")
+
+
+def test_fix_rewrites_mechanical_violations(
+    editorconfig_plugin: EditorconfigPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify fixing rewrites whitespace and leaves long lines reported.
+
+    Args:
+        editorconfig_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".editorconfig").write_text(EDITORCONFIG)
+    source = tmp_path / "app.py"
+    source.write_bytes(b"def f():\r\n\treturn 1  \r\n" + LONG_LINE)
+
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        result = editorconfig_plugin.fix([str(tmp_path)], {})
+
+    assert_that(source.read_bytes()).is_equal_to(
+        b"def f():\r\n  return 1\r\n" + LONG_LINE + b"\r\n",
+    )
+    assert_that(result.initial_issues_count).is_equal_to(4)
+    assert_that(result.fixed_issues_count).is_equal_to(3)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("EC005")
+    assert_that(result.output).contains("Fixed 3 issue(s) in 1 file(s)")
@@ -0,0 +1,119 @@
+"""Unit tests for .editorconfig parsing and property resolution."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.implementations.editorconfig import (
+    EditorconfigResolver,
+    compile_glob,
+    parse_editorconfig,
+)
+
+
+@pytest.mark.parametrize(
+    ("glob", "path", "expected"),
+    [
+        ("*", "src/app.py", True),
+        ("*.py", "src/app.py", True),
+        ("*.py", "src/app.pyc", False),
+        ("*.{js,ts}", "web/index.ts", True),
+        ("*.{js,ts}", "web/index.tsx", False),
+        ("*.[ch]", "lib/util.h", True),
+        ("Makefile", "sub/dir/Makefile", True),
+        ("/docs/*.md", "docs/index.md", True),
+        ("/docs/*.md", "docs/api/index.md", False),
+        ("docs/**.md", "docs/api/index.md", True),
+        ("file{1..3}.txt", "file2.txt", True),
+        ("file{1..3}.txt", "file4.txt", False),
+        ("{single}", "{single}", True),
+    ],
+)
+def test_compile_glob(glob: str, path: str, expected: bool) -> None:
+    """Section globs follow the EditorConfig glob rules.
+
+    Args:
+        glob: Section glob.
+        path: Path relative to the .editorconfig directory.
+        expected: Whether the glob matches the path.
+    """
+    assert_that(bool(compile_glob(glob).fullmatch(path))).is_equal_to(expected)
+
+
+def test_parse_editorconfig(tmp_path: Path) -> None:
+    """The preamble, sections and lower-cased keyword values are parsed.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    config = parse_editorconfig(
+        "# comment\nroot = TRUE\n\n[*]\nIndent_Style = Space\n"
+        "; another comment\n[*.md]\ntrim_trailing_whitespace = false\n",
+        tmp_path,
+    )
+
+    assert_that(config.root).is_true()
+    assert_that([s.glob for s in config.sections]).is_equal_to(["*", "*.md"])
+    assert_that(config.sections[0].properties).is_equal_to({"indent_style": "space"})
+
+
+def test_resolver_cascades_until_root(tmp_path: Path) -> None:
+    """Nearer files and later sections win; lookup stops at root = true.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    project = tmp_path / "project"
+    (project / "web").mkdir(parents=True)
+    (tmp_path / ".editorconfig").write_text("[*]\ncharset = latin1\n")
+    (project / ".editorconfig").write_text(
+        "root = true\n[*]\nindent_style = space\nindent_size = 4\n"
+        "[*.js]\nindent_size = 2\n",
+    )
+    (project / "web" / ".editorconfig").write_text(
+        "[*.js]\nmax_line_length = 100\n[vendor.js]\nmax_line_length = unset\n",
+    )
+
+    resolver = EditorconfigResolver()
+    app = resolver.properties_for(project / "web" / "app.js")
+    vendor = resolver.properties_for(project / "web" / "vendor.js")
+
+    assert_that(app).is_equal_to(
+        {
+            "indent_style": "space",
+            "indent_size": "2",
+            "tab_width": "2",
+            "max_line_length": "100",
+        },
+    )
+    assert_that(vendor).does_not_contain_key("max_line_length")
+    assert_that(resolver.found_config).is_true()
+
+
+def test_resolver_tab_defaults(tmp_path: Path) -> None:
+    """Tab indentation defaults indent_size to tab_width.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".editorconfig").write_text(
+        "root = true\n[Makefile]\nindent_style = tab\ntab_width = 8\n",
+    )
+
+    properties = EditorconfigResolver().properties_for(tmp_path / "Makefile")
+
+    assert_that(properties["indent_size"]).is_equal_to("8")
+
+
+def test_resolver_without_config(tmp_path: Path) -> None:
+    """Files outside any configured tree have no properties.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    resolver = EditorconfigResolver()
+
+    assert_that(resolver.properties_for(tmp_path / "a.py")).is_empty()
@@ -0,0 +1 @@
+"""ESLint plugin tests."""
@@ -0,0 +1,17 @@
+"""Shared fixtures for eslint plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.eslint import EslintPlugin
+
+
+@pytest.fixture
+def eslint_plugin() -> EslintPlugin:
+    """Provide an EslintPlugin instance for testing.
+
+    Returns:
+        EslintPlugin: A new EslintPlugin instance.
+    """
+    return EslintPlugin()
@@ -0,0 +1,216 @@
+"""Tests for EslintPlugin.check method."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.eslint import EslintPlugin
+
+
+def _mock_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one JavaScript file.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["app.js"]
+    ctx.files = [str(tmp_path / "app.js")]
+    return ctx
+
+
+def test_check_reports_messages(eslint_plugin: EslintPlugin, tmp_path: Path) -> None:
+    """Check runs the JSON formatter and keeps each rule's severity.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "eslint.config.js").write_text("export default [];\n")
+    output = json.dumps(
+        [
+            {
+                "filePath": str(tmp_path / "app.js"),
+                "messages": [
+                    {
+                        "ruleId": "no-debugger",
+                        "severity": 2,
+                        "message": "Unexpected 'debugger' statement.",
+                        "line": 1,
+                        "column": 1,
+                    },
+                    {
+                        "ruleId": "prefer-const",
+                        "severity": 1,
+                        "message": "'x' is never reassigned.",
+                        "line": 2,
+                        "column": 5,
+                        "fix": {"range": [10, 13], "text": "const"},
+                    },
+                ],
+            },
+        ],
+    )
+    eslint_plugin.set_options(quiet=True)
+
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(
+            eslint_plugin,
+            "_run_subprocess",
+            return_value=(False, output),
+        ) as mock_run,
+    ):
+        result = eslint_plugin.check([str(tmp_path)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd[:3]).is_equal_to(["eslint", "--format", "json"])
+    assert_that(cmd).contains("--no-warn-ignored", "--quiet")
+    assert_that(cmd).does_not_contain("--fix")
+    assert_that(cmd[-1]).is_equal_to("app.js")
+    assert_that(result.success).is_false()
+    assert_that([issue.severity for issue in result.issues]).is_equal_to(
+        ["error", "warning"],
+    )
+
+
+def test_check_skips_without_flat_config(
+    eslint_plugin: EslintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Without a flat config, ESLint is skipped instead of failing.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.eslint.find_flat_config",
+            return_value=None,
+        ),
+        patch.object(eslint_plugin, "_run_subprocess") as mock_run,
+    ):
+        result = eslint_plugin.check([str(tmp_path)], {})
+
+    mock_run.assert_not_called()
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to("no flat config")
+
+
+def test_check_uses_configured_config(
+    eslint_plugin: EslintPlugin,
+    tmp_path: Path,
+) -> None:
+    """An explicit config is passed on and needs no flat config lookup.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    eslint_plugin.set_options(config="config/eslint.config.js")
+
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.eslint.find_flat_config",
+            return_value=None,
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(
+            eslint_plugin,
+            "_run_subprocess",
+            return_value=(True, "[]"),
+        ) as mock_run,
+    ):
+        result = eslint_plugin.check([str(tmp_path)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd).contains("--config", "config/eslint.config.js")
+    assert_that(result.success).is_true()
+    assert_that(result.output).is_none()
+
+
+def test_check_timeout(eslint_plugin: EslintPlugin, tmp_path: Path) -> None:
+    """A timeout is reported as a failed result.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "eslint.config.js").write_text("export default [];\n")
+
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(
+            eslint_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["eslint"], timeout=60),
+        ),
+    ):
+        result = eslint_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
+
+
+def test_check_reports_crash_without_report(
+    eslint_plugin: EslintPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without a JSON report fails with ESLint's message.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "eslint.config.js").write_text("export default [];\n")
+    message = "Oops! Something went wrong! :(\nCannot find package 'typescript-eslint'"
+
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(eslint_plugin, "_run_subprocess", return_value=(False, message)),
+    ):
+        result = eslint_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).contains("Cannot find package")
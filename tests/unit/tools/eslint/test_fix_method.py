@@ -0,0 +1,161 @@
+"""Tests for EslintPlugin.fix method."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.eslint import EslintPlugin
+
+
+def _report(*rule_ids: str) -> str:
+    """Build an ESLint JSON report with one error per rule.
+
+    Args:
+        *rule_ids: Rules to report.
+
+    Returns:
+        str: JSON report.
+    """
+    return json.dumps(
+        [
+            {
+                "filePath": "/project/app.ts",
+                "messages": [
+                    {
+                        "ruleId": rule_id,
+                        "severity": 2,
+                        "message": f"{rule_id} violation",
+                        "line": 1,
+                        "column": 1,
+                    }
+                    for rule_id in rule_ids
+                ],
+            },
+        ],
+    )
+
+
+def _mock_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one TypeScript file.
+
+    Args:
+        tmp_path: Working directory of the run, holding a flat config.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    (tmp_path / "eslint.config.js").write_text("export default [];\n")
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["app.ts"]
+    ctx.files = [str(tmp_path / "app.ts")]
+    return ctx
+
+
+def test_fix_counts_fixed_and_remaining(
+    eslint_plugin: EslintPlugin,
+    tmp_path: Path,
+) -> None:
+    """The fix run's report gives the issues left after fixing.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    outputs = [
+        (False, _report("prefer-const", "eqeqeq", "no-undef")),
+        (False, _report("no-undef")),
+    ]
+
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(
+            eslint_plugin,
+            "_run_subprocess",
+            side_effect=outputs,
+        ) as mock_run,
+    ):
+        result = eslint_plugin.fix([str(tmp_path)], {})
+
+    assert_that(mock_run.call_count).is_equal_to(2)
+    fix_cmd = mock_run.call_args_list[1].kwargs["cmd"]
+    assert_that(fix_cmd).contains("--fix", "--format")
+    assert_that(fix_cmd[-1]).is_equal_to("app.ts")
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("Fixed 2 issue(s)")
+    assert_that(result.output).contains("cannot be auto-fixed")
+
+
+def test_fix_all_fixed(eslint_plugin: EslintPlugin, tmp_path: Path) -> None:
+    """Fix succeeds when nothing is left.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    outputs = [
+        (False, _report("prefer-const")),
+        (True, _report()),
+    ]
+
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(eslint_plugin, "_run_subprocess", side_effect=outputs),
+    ):
+        result = eslint_plugin.fix([str(tmp_path)], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("All issues were successfully auto-fixed")
+
+
+def test_fix_reports_crash_without_fixing(
+    eslint_plugin: EslintPlugin,
+    tmp_path: Path,
+) -> None:
+    """A check run that crashes fails before ESLint is asked to fix.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            eslint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(eslint_plugin, "_get_executable_command", return_value=["eslint"]),
+        patch.object(
+            eslint_plugin,
+            "_run_subprocess",
+            return_value=(False, ""),
+        ) as mock_run,
+    ):
+        result = eslint_plugin.fix([str(tmp_path)], {})
+
+    assert_that(mock_run.call_count).is_equal_to(1)
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("ESLint execution failed.")
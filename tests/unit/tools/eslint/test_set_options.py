@@ -0,0 +1,109 @@
+"""Tests for EslintPlugin definition, options and flat config lookup."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.eslint import ESLINT_DEFAULT_TIMEOUT, find_flat_config
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.eslint import EslintPlugin
+
+
+def test_definition_uses_flat_configs(eslint_plugin: EslintPlugin) -> None:
+    """ESLint is a fixing linter that detects flat configs.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+    """
+    definition = eslint_plugin.definition
+
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).contains("*.js", "*.tsx")
+    assert_that(definition.native_configs).contains(
+        "eslint.config.js",
+        "eslint.config.mjs",
+        "eslint.config.ts",
+    )
+    assert_that(definition.native_configs).does_not_contain(".eslintrc.json")
+    assert_that(definition.default_options).is_equal_to(
+        {"timeout": ESLINT_DEFAULT_TIMEOUT, "quiet": False},
+    )
+
+
+def test_set_options_valid(eslint_plugin: EslintPlugin) -> None:
+    """Valid options are stored.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+    """
+    eslint_plugin.set_options(
+        timeout=120,
+        config="config/eslint.config.js",
+        quiet=True,
+        verbose_fix_output=True,
+    )
+
+    assert_that(eslint_plugin.options).contains_entry(
+        {"timeout": 120},
+        {"config": "config/eslint.config.js"},
+        {"quiet": True},
+        {"verbose_fix_output": True},
+    )
+
+
+@pytest.mark.parametrize(
+    ("option_name", "value", "message"),
+    [
+        ("timeout", -1, "timeout must be positive"),
+        ("config", 1, "config must be a string"),
+        ("quiet", "yes", "quiet must be a boolean"),
+    ],
+    ids=["negative_timeout", "non_string_config", "non_bool_quiet"],
+)
+def test_set_options_invalid(
+    eslint_plugin: EslintPlugin,
+    option_name: str,
+    value: object,
+    message: str,
+) -> None:
+    """Invalid option values raise ValueError.
+
+    Args:
+        eslint_plugin: The EslintPlugin instance to test.
+        option_name: Option to set.
+        value: Invalid value for the option.
+        message: Expected error message.
+    """
+    with pytest.raises(ValueError, match=message):
+        eslint_plugin.set_options(**{option_name: value})  # type: ignore[arg-type]
+
+
+def test_find_flat_config_searches_parents(tmp_path: Path) -> None:
+    """The nearest flat config in the directory or a parent is found.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "eslint.config.mjs").write_text("export default [];\n")
+    nested = tmp_path / "packages" / "web"
+    nested.mkdir(parents=True)
+
+    assert_that(find_flat_config(nested)).is_equal_to(
+        tmp_path / "eslint.config.mjs",
+    )
+
+
+def test_find_flat_config_ignores_legacy_configs(tmp_path: Path) -> None:
+    """Legacy .eslintrc files are not flat configs.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".eslintrc.json").write_text("{}")
+
+    assert_that(find_flat_config(tmp_path)).is_none()
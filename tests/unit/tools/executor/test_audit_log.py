@@ -0,0 +1,189 @@
+"""Tests for the audit log of fix runs."""
+
+from __future__ import annotations
+
+import datetime
+import hashlib
+import os
+from concurrent.futures import ThreadPoolExecutor
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_execution_config
+from lintro.utils.execution import fix_pipeline
+from lintro.utils.execution.audit_log import (
+    append_entry,
+    build_entry,
+    filter_entries,
+    read_entries,
+    verify_chain,
+)
+from lintro.utils.execution.fix_pipeline import FileFix, FixTracker
+
+
+def _digest(text: str) -> str:
+    """Hash text the way the tracker hashes files.
+
+    Args:
+        text: File contents.
+
+    Returns:
+        str: SHA-256 hex digest.
+    """
+    return hashlib.sha256(text.encode()).hexdigest()
+
+
+def test_tracker_records_each_fixer_change(tmp_path: Path) -> None:
+    """Every fixer's change is kept with the file hashes around it.
+
+    Args:
+        tmp_path: Temporary directory with the fixed file.
+    """
+    target = tmp_path / "a.py"
+    target.write_text("x=1\n")
+    tracker = FixTracker([str(target)])
+
+    target.write_text("x = 1\n")
+    tracker.record("ruff")
+    tracker.record("black")
+    target.write_text("x = 1  # ok\n")
+    tracker.record("black")
+
+    assert_that(tracker.fixes).is_equal_to(
+        [
+            FileFix(str(target), "ruff", _digest("x=1\n"), _digest("x = 1\n")),
+            FileFix(
+                str(target),
+                "black",
+                _digest("x = 1\n"),
+                _digest("x = 1  # ok\n"),
+            ),
+        ],
+    )
+
+
+def test_tracker_rehashes_only_touched_files(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Files whose stat is unchanged since long ago are not read again.
+
+    Args:
+        tmp_path: Temporary directory with the tracked files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    old, fixed = tmp_path / "old.py", tmp_path / "fixed.py"
+    for path in (old, fixed):
+        path.write_text("x=1\n")
+        os.utime(path, ns=(0, 0))
+    tracker = FixTracker([str(old), str(fixed)])
+    hashed: list[str] = []
+    original = fix_pipeline.hash_files
+
+    def _spy(files: list[str]) -> dict[str, str]:
+        """Record which files are hashed.
+
+        Args:
+            files: Files to hash.
+
+        Returns:
+            dict[str, str]: Digests from the real implementation.
+        """
+        hashed.extend(files)
+        return original(files)
+
+    monkeypatch.setattr(fix_pipeline, "hash_files", _spy)
+    fixed.write_text("x = 1\n")
+
+    assert_that(tracker.record("black")).is_equal_to([str(fixed)])
+    assert_that(hashed).is_equal_to([str(fixed)])
+
+
+def test_entries_are_chained(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Appended entries read back, and editing one breaks the chain.
+
+    Args:
+        tmp_path: Temporary project directory.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    log = str(tmp_path / ".lintro" / "audit.jsonl")
+    fix = FileFix(str(tmp_path / "src" / "a.py"), "ruff", "1" * 64, "2" * 64)
+
+    append_entry(log, build_entry(tools=["ruff"], fixes=[fix], exit_code=0))
+    append_entry(log, build_entry(tools=["black"], fixes=[], exit_code=1))
+
+    entries = read_entries(log)
+    assert_that([e.tools for e in entries]).is_equal_to([["ruff"], ["black"]])
+    assert_that(entries[0].files[0].file).is_equal_to("src/a.py")
+    assert_that(entries[0].previous).is_none()
+    assert_that(entries[1].previous).is_not_none()
+    assert_that(verify_chain(log)).is_empty()
+
+    lines = Path(log).read_text().splitlines()
+    Path(log).write_text(lines[0].replace('"ruff"', '"black"') + "\n" + lines[1])
+    assert_that(verify_chain(log)).is_equal_to([2])
+    Path(log).write_text(lines[1] + "\n")
+    assert_that(verify_chain(log)).is_equal_to([1])
+
+
+def test_concurrent_appends_keep_the_chain(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Runs appending at the same time each chain to a distinct line.
+
+    Args:
+        tmp_path: Temporary project directory.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    log = str(tmp_path / "audit.jsonl")
+    entries = [build_entry(tools=["ruff"], fixes=[], exit_code=0) for _ in range(8)]
+
+    with ThreadPoolExecutor(max_workers=8) as pool:
+        list(pool.map(lambda entry: append_entry(log, entry), entries))
+
+    assert_that(read_entries(log)).is_length(8)
+    assert_that(verify_chain(log)).is_empty()
+
+
+def test_filter_entries(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Entries are selected by time, fixer, file and user.
+
+    Args:
+        tmp_path: Temporary project directory.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    fix = FileFix(str(tmp_path / "a.py"), "ruff", "1" * 64, "2" * 64)
+    old = build_entry(tools=["ruff"], fixes=[fix], exit_code=0)
+    old.timestamp = "2026-01-01T00:00:00+00:00"
+    new = build_entry(tools=["black"], fixes=[], exit_code=0)
+    new.user, new.git_user = "ci", "bot@example.com"
+
+    entries = [old, new]
+
+    since = datetime.datetime(2026, 6, 1)
+    assert_that(filter_entries(entries, since=since)).is_equal_to([new])
+    assert_that(filter_entries(entries, tool="ruff")).is_equal_to([old])
+    assert_that(filter_entries(entries, file="a.py")).is_equal_to([old])
+    assert_that(filter_entries(entries, tool="black")).is_empty()
+    assert_that(filter_entries(entries, user="bot@example.com")).is_equal_to([new])
+
+
+def test_audit_log_setting_is_opt_in() -> None:
+    """The log is off unless a file is named."""
+    assert_that(_parse_execution_config({}).audit_log).is_none()
+    assert_that(_parse_execution_config({"audit_log": False}).audit_log).is_none()
+    assert_that(
+        _parse_execution_config({"audit_log": "audit/fixes.jsonl"}).audit_log,
+    ).is_equal_to("audit/fixes.jsonl")
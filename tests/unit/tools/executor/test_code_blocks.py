@@ -0,0 +1,122 @@
+"""Tests for checking the fenced code blocks of Markdown files."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.bandit.bandit_issue import BanditIssue
+from lintro.tools.definitions.bandit import BanditPlugin
+from lintro.utils.execution.code_blocks import (
+    check_code_blocks,
+    checks_block,
+    extract_code_blocks,
+)
+
+README = """\
+# Example
+
+```python
+import subprocess
+subprocess.call(cmd)
+```
+
+  ~~~rust,ignore
+  fn main() {}
+  ~~~
+
+````Bash
+  echo "indented"
+````
+
+```text
+not code
+```
+"""
+
+
+def test_extract_code_blocks_maps_languages_and_lines() -> None:
+    """Blocks record their fence line; ignored and unknown blocks are skipped."""
+    blocks = extract_code_blocks(README, "README.md")
+
+    assert_that([(b.language, b.suffix, b.line) for b in blocks]).is_equal_to(
+        [("python", ".py", 3), ("bash", ".sh", 12)],
+    )
+    assert_that(blocks[0].source).is_equal_to(
+        "import subprocess\nsubprocess.call(cmd)\n",
+    )
+    assert_that(blocks[1].source).is_equal_to('  echo "indented"\n')
+    assert_that(blocks[0].locate(2)).is_equal_to(5)
+    assert_that(blocks[0].locate(0)).is_equal_to(3)
+
+
+def test_extract_code_blocks_runs_unclosed_block_to_end() -> None:
+    """A block without a closing fence ends with the document."""
+    blocks = extract_code_blocks("text\n~~~py\nx = 1\n```\n", "doc.md")
+
+    assert_that(blocks).is_length(1)
+    assert_that(blocks[0].source).is_equal_to("x = 1\n```\n")
+
+
+def test_checks_block_ignores_catch_all_patterns() -> None:
+    """Only tools with patterns for the block's language check it."""
+    python_block = extract_code_blocks(README, "README.md")[0]
+
+    assert_that(checks_block(BanditPlugin(), python_block)).is_true()
+
+
+def test_check_code_blocks_reports_issues_on_markdown_lines(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Issues in the block files point at lines of the Markdown file.
+
+    Args:
+        tmp_path: Temporary directory path for the project.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "README.md").write_text(README)
+    tool = BanditPlugin()
+
+    def _check(paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Report an issue on the second line of every Python file.
+
+        Args:
+            paths: Directory of block files.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: One issue per file.
+        """
+        issues = [
+            BanditIssue(file=str(script), line=2, message="subprocess call")
+            for script in Path(paths[0]).glob("*.py")
+        ]
+        return ToolResult(name="bandit", issues=issues, issues_count=len(issues))
+
+    monkeypatch.setattr(tool, "check", _check)
+    base = ToolResult(name="bandit", success=True, issues=[], issues_count=0)
+    result = check_code_blocks(tool, ["."], base)
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    issue = result.issues[0]  # type: ignore[index]
+    assert_that((issue.file, issue.line)).is_equal_to(("README.md", 5))
+
+
+def test_check_code_blocks_leaves_skipped_results() -> None:
+    """Skipped tools do not check code blocks."""
+    skipped = ToolResult(
+        name="bandit",
+        success=True,
+        skipped=True,
+        skip_reason="not installed",
+    )
+
+    assert_that(check_code_blocks(BanditPlugin(), ["."], skipped)).is_same_as(
+        skipped,
+    )
@@ -0,0 +1,113 @@
+"""Tests for cross-tool issue deduplication."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.bandit.bandit_issue import BanditIssue
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.parsers.svelte_check.svelte_check_issue import SvelteCheckIssue
+from lintro.parsers.tsc.tsc_issue import TscIssue
+from lintro.utils.execution.deduplication import IssueDeduplicator, canonical_rule
+
+
+def _result(name: str, *issues: BaseIssue) -> ToolResult:
+    """Build a failed check result.
+
+    Args:
+        name: Tool name.
+        *issues: Issues reported by the tool.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    return ToolResult(
+        name=name,
+        success=False,
+        issues_count=len(issues),
+        issues=list(issues),
+    )
+
+
+@pytest.mark.parametrize(
+    "tool,code,canonical",
+    [
+        ("ruff", "S101", "B101"),
+        ("bandit", "B101", "B101"),
+        ("svelte_check", "ts-2322", "TS2322"),
+        ("tsc", "TS2322", "TS2322"),
+        ("ruff", "E501", "E501"),
+        ("oxlint", "eslint(no-unused-vars)", "ESLINTNOUNUSEDVARS"),
+    ],
+)
+def test_canonical_rule(tool: str, code: str, canonical: str) -> None:
+    """Equivalent rules from different tools share one canonical code.
+
+    Args:
+        tool: Reporting tool.
+        code: Code as reported.
+        canonical: Expected canonical code.
+    """
+    assert_that(canonical_rule(tool, code)).is_equal_to(canonical)
+
+
+def test_duplicates_collapse_into_first_report() -> None:
+    """A later tool's duplicate is dropped and listed on the first issue."""
+    ruff = _result(
+        "ruff",
+        RuffIssue(file="app.py", line=3, column=1, code="S101"),
+        RuffIssue(file="app.py", line=9, code="E501"),
+    )
+    bandit = _result(
+        "bandit",
+        BanditIssue(file="./app.py", line=3, col_offset=4, test_id="B101"),
+    )
+
+    ruff_out, bandit_out = IssueDeduplicator().add_all([ruff, bandit])
+
+    assert_that(ruff_out.issues_count).is_equal_to(2)
+    assert_that(bandit_out.issues).is_empty()
+    assert_that(bandit_out.issues_count).is_equal_to(0)
+    assert_that(bandit_out.success).is_true()
+    first = ruff_out.issues[0]
+    assert_that(first.also_reported_by).is_equal_to(["bandit"])
+    assert_that(first.to_display_row()["message"]).ends_with(
+        "(also reported by bandit)",
+    )
+
+
+def test_distinct_findings_are_kept() -> None:
+    """Different lines, rules or repeats within one tool are not duplicates."""
+    tsc = _result(
+        "tsc",
+        TscIssue(file="a.ts", line=1, code="TS2322"),
+        TscIssue(file="a.ts", line=1, code="TS2322"),
+    )
+    svelte = _result(
+        "svelte_check",
+        SvelteCheckIssue(file="a.ts", line=2, code="ts-2322"),
+        SvelteCheckIssue(file="a.ts", line=1, code="ts-2307"),
+        SvelteCheckIssue(file="a.ts", line=1, code="ts-2322"),
+    )
+
+    tsc_out, svelte_out = IssueDeduplicator().add_all([tsc, svelte])
+
+    assert_that(tsc_out).is_same_as(tsc)
+    assert_that([i.line for i in svelte_out.issues]).is_equal_to([2, 1])
+    assert_that(svelte_out.issues_count).is_equal_to(2)
+    assert_that(svelte_out.success).is_false()
+
+
+def test_issues_without_location_or_code_are_kept() -> None:
+    """Issues that cannot be matched reliably are never collapsed."""
+    results = [
+        _result("mypy", BaseIssue(file="a.py", line=1, message="x")),
+        _result("pydoclint", BaseIssue(file="a.py", line=1, message="x")),
+    ]
+
+    deduped = IssueDeduplicator().add_all(results)
+
+    assert_that(deduped[1]).is_same_as(results[1])
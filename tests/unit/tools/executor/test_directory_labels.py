@@ -0,0 +1,242 @@
+"""Tests for directory labels and their policies."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import (
+    _convert_pyproject_to_config,
+    build_config_from_data,
+)
+from lintro.config.lintro_config import LintroConfig
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+from lintro.utils.execution import tool_configuration
+from lintro.utils.execution.failure_policy import build_failure_policy
+from lintro.utils.execution.labels import DirectoryLabels
+from lintro.utils.execution.severity_filter import apply_label_severity
+from lintro.utils.execution.tool_configuration import (
+    SkippedTool,
+    ToolsToRunResult,
+    apply_label_scopes,
+)
+
+
+@pytest.fixture
+def labelled_config(tmp_path: Path) -> LintroConfig:
+    """Build a config labelling services/payments as critical.
+
+    Args:
+        tmp_path: Temporary repository root holding the config file.
+
+    Returns:
+        LintroConfig: Config with one labelled directory and its policy.
+    """
+    (tmp_path / "services" / "payments").mkdir(parents=True)
+    (tmp_path / "web").mkdir()
+    return build_config_from_data(
+        {
+            "labels": {"services/payments/": "critical, pci"},
+            "label_policies": {
+                "critical": {
+                    "tools": "bandit",
+                    "severity": {"PP003": "Error"},
+                    "fail_on": "warning",
+                    "max_issues": 1,
+                },
+                "pci": {"severity": {"pyproject:PP003": "warning"}},
+            },
+        },
+        config_path=str(tmp_path / ".lintro-config.yaml"),
+    )
+
+
+def _result(*files: str, level: str = "info") -> ToolResult:
+    """Build a failed check result with one issue per file.
+
+    Args:
+        *files: File of each issue.
+        level: Severity level of every issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues: list[BaseIssue] = [
+        PyprojectIssue(file=file, level=level, code="PP003") for file in files
+    ]
+    return ToolResult(
+        name="pyproject",
+        success=False,
+        issues_count=len(issues),
+        issues=issues,
+    )
+
+
+def test_labels_are_parsed(labelled_config: LintroConfig) -> None:
+    """Comma-separated labels and tools become lists; levels are lower-cased.
+
+    Args:
+        labelled_config: Config with a labelled directory.
+    """
+    assert_that(labelled_config.labels).is_equal_to(
+        {"services/payments": ["critical", "pci"]},
+    )
+    critical = labelled_config.label_policies["critical"]
+    assert_that(critical.tools).is_equal_to(["bandit"])
+    assert_that(critical.severity).is_equal_to({"PP003": "error"})
+
+
+def test_unknown_policy_keys_are_rejected() -> None:
+    """Label policies reject settings they do not know."""
+    with pytest.raises(ValueError, match="treshold"):
+        build_config_from_data({"label_policies": {"critical": {"treshold": 1}}})
+
+
+def test_pyproject_labels_are_converted() -> None:
+    """[tool.lintro.labels] and [tool.lintro.label_policies] are kept."""
+    converted = _convert_pyproject_to_config(
+        {
+            "labels": {"services/payments": ["critical"]},
+            "label_policies": {"critical": {"tools": ["bandit"]}},
+        },
+    )
+
+    assert_that(converted["labels"]).is_equal_to({"services/payments": ["critical"]})
+    assert_that(converted["label_policies"]).contains_key("critical")
+
+
+def test_labels_for_matches_files_below_directories(
+    labelled_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """A file gets the labels of every labelled directory above it.
+
+    Args:
+        labelled_config: Config with a labelled directory.
+        tmp_path: Temporary repository root.
+    """
+    labels = DirectoryLabels.from_config(labelled_config)
+
+    inside = str(tmp_path / "services" / "payments" / "api" / "app.py")
+    sibling = str(tmp_path / "services" / "payments-old" / "app.py")
+    assert_that(labels.labels_for(inside)).is_equal_to(("critical", "pci"))
+    assert_that(labels.labels_for(sibling)).is_empty()
+    assert_that(labels.labels_for("")).is_empty()
+
+
+def test_label_severity_escalates_labelled_issues(
+    labelled_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """Issues in labelled directories get the most severe label override.
+
+    Args:
+        labelled_config: Config with a labelled directory.
+        tmp_path: Temporary repository root.
+    """
+    inside = str(tmp_path / "services" / "payments" / "pyproject.toml")
+    outside = str(tmp_path / "web" / "pyproject.toml")
+
+    result = apply_label_severity(
+        _result(inside, outside),
+        DirectoryLabels.from_config(labelled_config),
+        labelled_config.label_policies,
+    )
+
+    assert result.issues is not None
+    assert_that([i.get_severity() for i in result.issues]).is_equal_to(
+        [SeverityLevel.ERROR, SeverityLevel.INFO],
+    )
+
+
+def test_label_severity_without_labels_returns_result_unchanged() -> None:
+    """Without labelled directories results pass through untouched."""
+    result = _result("pyproject.toml")
+
+    assert_that(apply_label_severity(result, DirectoryLabels(), {})).is_same_as(
+        result,
+    )
+
+
+def test_label_thresholds_tighten_the_policy(
+    labelled_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """Labelled issues count from the label's severity and have their own limit.
+
+    Args:
+        labelled_config: Config with a labelled directory.
+        tmp_path: Temporary repository root.
+    """
+    inside = str(tmp_path / "services" / "payments" / "pyproject.toml")
+    outside = str(tmp_path / "web" / "pyproject.toml")
+    policy = build_failure_policy(labelled_config, fail_on="error", max_issues=5)
+
+    warnings_outside = _result(outside, outside, level="warning")
+    one_inside = _result(inside, level="warning")
+    two_inside = _result(inside, inside, level="warning")
+
+    assert_that(policy.counted_issues(warnings_outside)).is_equal_to(0)
+    assert_that(policy.counted_issues(two_inside)).is_equal_to(2)
+    assert_that(policy.exceeded([warnings_outside, one_inside])).is_false()
+    assert_that(policy.exceeded([two_inside])).is_true()
+
+
+def test_label_scopes_enable_policy_tools(
+    labelled_config: LintroConfig,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A disabled tool named by a label policy runs on the labelled directory.
+
+    Args:
+        labelled_config: Config with a labelled directory.
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_configuration, "get_config", lambda: labelled_config)
+    tools_result = ToolsToRunResult(
+        to_run=["ruff"],
+        skipped=[
+            SkippedTool(name="bandit", reason="disabled in config"),
+            SkippedTool(name="mypy", reason="disabled in config"),
+        ],
+    )
+    scopes: dict = {}
+
+    apply_label_scopes(tools_result, [str(tmp_path)], scopes)
+
+    assert_that(tools_result.to_run).is_equal_to(["ruff", "bandit"])
+    assert_that([t.name for t in tools_result.skipped]).is_equal_to(["mypy"])
+    assert_that(scopes["bandit"].paths).is_equal_to(
+        [str((tmp_path / "services" / "payments").resolve())],
+    )
+
+
+def test_label_scopes_skip_paths_outside_labelled_directories(
+    labelled_config: LintroConfig,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Checking only unlabelled paths leaves policy tools skipped.
+
+    Args:
+        labelled_config: Config with a labelled directory.
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_configuration, "get_config", lambda: labelled_config)
+    tools_result = ToolsToRunResult(
+        skipped=[SkippedTool(name="bandit", reason="disabled in config")],
+    )
+    scopes: dict = {}
+
+    apply_label_scopes(tools_result, [str(tmp_path / "web")], scopes)
+
+    assert_that(tools_result.to_run).is_empty()
+    assert_that(scopes).is_empty()
@@ -0,0 +1,180 @@
+"""Tests for dry-run fixing in a scratch copy of the project."""
+
+from __future__ import annotations
+
+import os
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.utils.execution.dry_run import (
+    DryRunWorkspace,
+    FileChange,
+    format_patch,
+    line_ending,
+)
+
+
+def test_file_change_renders_git_style_diff() -> None:
+    """Changed lines and a missing final newline follow git's format."""
+    change = FileChange(path="src/app.py", before=b"a\nb\n", after=b"a\nc")
+
+    assert_that(change.diff()).is_equal_to(
+        "--- a/src/app.py\n"
+        "+++ b/src/app.py\n"
+        "@@ -1,2 +1,2 @@\n"
+        " a\n"
+        "-b\n"
+        "+c\n"
+        "\\ No newline at end of file\n",
+    )
+
+
+def test_file_change_for_created_and_binary_files() -> None:
+    """Created files diff against /dev/null; binary files get a note."""
+    created = FileChange(path="new.py", before=None, after=b"x\n")
+    binary = FileChange(path="logo.png", before=b"\xff", after=b"\xfe")
+
+    assert_that(created.diff()).starts_with("--- /dev/null\n+++ b/new.py\n")
+    assert_that(binary.diff()).is_equal_to(
+        "Binary files a/logo.png and b/logo.png differ\n",
+    )
+    assert_that(format_patch([])).is_empty()
+
+
+@pytest.mark.parametrize(
+    ("text", "expected"),
+    [
+        ("a\r\nb\r\n", "\r\n"),
+        ("a\nb\r\nc\n", "\n"),
+        ("a\rb\r", "\r"),
+        ("a", None),
+    ],
+)
+def test_line_ending(text: str, expected: str | None) -> None:
+    """The most common line ending wins.
+
+    Args:
+        text: File contents.
+        expected: Line ending found.
+    """
+    assert_that(line_ending(text)).is_equal_to(expected)
+
+
+def test_file_change_converting_line_endings_shows_only_real_changes() -> None:
+    """A CRLF file rewritten with LF endings diffs only the changed lines."""
+    change = FileChange(
+        path="app.py",
+        before=b"a\r\nb\r\nc\r\n",
+        after=b"a\nB\nc\n",
+    )
+
+    assert_that(change.diff()).is_equal_to(
+        "Line endings of app.py change from CRLF to LF\n"
+        "--- a/app.py\n"
+        "+++ b/app.py\n"
+        "@@ -1,3 +1,3 @@\n"
+        " a\r\n"
+        "-b\r\n"
+        "+B\r\n"
+        " c\r\n",
+    )
+
+
+def test_file_change_with_only_line_endings_changed() -> None:
+    """A file whose only change is its line endings gets just the note."""
+    change = FileChange(path="app.py", before=b"a\r\nb\r\n", after=b"a\nb\n")
+
+    assert_that(change.diff()).is_equal_to(
+        "Line endings of app.py change from CRLF to LF\n",
+    )
+
+
+def test_workspace_collects_changes_without_touching_project(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Edits inside the workspace show up as changes, not in the project.
+
+    Args:
+        tmp_path: Temporary path fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "app.py").write_text("x=1\n")
+    (tmp_path / "old.py").write_text("pass\n")
+    (tmp_path / "node_modules").mkdir()
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.delenv("LINTRO_LOG_DIR", raising=False)
+
+    with DryRunWorkspace() as workspace:
+        assert_that(os.getcwd()).is_equal_to(workspace.copy)
+        assert_that(os.path.islink("node_modules")).is_true()
+        Path("src/app.py").write_text("x = 1\n")
+        Path("old.py").unlink()
+        Path(".ruff_cache").mkdir()
+        Path(".ruff_cache/state").write_text("cache")
+        changes = workspace.changes()
+        copy = workspace.copy
+
+    assert_that([c.path for c in changes]).is_equal_to(["old.py", "src/app.py"])
+    assert_that(changes[0].after).is_none()
+    assert_that((tmp_path / "src" / "app.py").read_text()).is_equal_to("x=1\n")
+    assert_that(os.getcwd()).is_equal_to(str(tmp_path))
+    assert_that(os.path.exists(copy)).is_false()
+    assert_that(os.environ).does_not_contain_key("LINTRO_LOG_DIR")
+
+
+def test_workspace_rebases_absolute_paths(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Project paths map into the copy; paths outside the project fail.
+
+    Args:
+        tmp_path: Temporary path fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    project = tmp_path / "project"
+    (project / "src").mkdir(parents=True)
+    monkeypatch.chdir(project)
+
+    with DryRunWorkspace() as workspace:
+        assert_that(workspace.rebase("src")).is_equal_to("src")
+        assert_that(workspace.rebase(str(project / "src"))).is_equal_to(
+            os.path.join(workspace.copy, "src"),
+        )
+        with pytest.raises(ValueError, match="outside"):
+            workspace.rebase(str(tmp_path))
+
+
+def test_workspace_copies_the_repository_from_a_subdirectory(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """From a subdirectory the whole repository is copied, config included.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / ".git").mkdir()
+    (tmp_path / ".lintro-config.yaml").write_text("tools: {}\n")
+    (tmp_path / "lib").mkdir()
+    (tmp_path / "lib" / "util.py").write_text("y=2\n")
+    (tmp_path / "src").mkdir()
+    monkeypatch.chdir(tmp_path / "src")
+    monkeypatch.delenv("LINTRO_LOG_DIR", raising=False)
+
+    with DryRunWorkspace() as workspace:
+        assert_that(workspace.root).is_equal_to(str(tmp_path))
+        assert_that(os.getcwd()).is_equal_to(os.path.join(workspace.copy, "src"))
+        assert_that(Path("../.lintro-config.yaml").exists()).is_true()
+        assert_that(workspace.rebase("../lib")).is_equal_to("../lib")
+        with pytest.raises(ValueError, match="outside"):
+            workspace.rebase("../..")
+        Path("../lib/util.py").write_text("y = 2\n")
+        changes = workspace.changes()
+
+    assert_that([c.path for c in changes]).is_equal_to(["lib/util.py"])
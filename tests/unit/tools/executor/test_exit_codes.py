@@ -0,0 +1,231 @@
+"""Tests for run outcomes and the configurable exit-code scheme."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import build_config_from_data
+from lintro.config.execution_config import ExitCodesConfig
+from lintro.enums.action import Action
+from lintro.enums.run_outcome import RunOutcome
+from lintro.exceptions.errors import TOOL_TIMEOUT
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.exit_codes import (
+    determine_exit_code,
+    determine_outcome,
+    exit_code_for,
+    success_after_filter,
+)
+
+CUSTOM_CODES = ExitCodesConfig(
+    clean=0,
+    issues=1,
+    tool_error=3,
+    tool_missing=4,
+    config_error=2,
+)
+
+
+def _missing(name: str) -> ToolResult:
+    """Build the skipped result for a tool that is not installed.
+
+    Args:
+        name: Tool name.
+
+    Returns:
+        ToolResult: Skipped result marked missing.
+    """
+    return ToolResult(
+        name=name,
+        skipped=True,
+        skip_reason="not installed",
+        missing=True,
+    )
+
+
+def _outcome(results: list[ToolResult], action: Action = Action.CHECK) -> RunOutcome:
+    """Classify results the way the executor does.
+
+    Args:
+        results: Tool results.
+        action: Action performed.
+
+    Returns:
+        RunOutcome: Outcome of the run.
+    """
+    return determine_outcome(
+        action=action,
+        all_results=results,
+        total_issues=sum(r.issues_count for r in results),
+        total_remaining=sum(r.remaining_issues_count or 0 for r in results),
+        main_phase_empty_due_to_filter=False,
+    )
+
+
+@pytest.mark.parametrize(
+    "results,outcome",
+    [
+        ([ToolResult(name="ruff", success=True)], RunOutcome.CLEAN),
+        (
+            [ToolResult(name="ruff", success=False, issues_count=2)],
+            RunOutcome.ISSUES,
+        ),
+        (
+            [ToolResult(name="ruff", success=False, output="Traceback")],
+            RunOutcome.TOOL_ERROR,
+        ),
+        (
+            [
+                ToolResult(name="ruff", success=True),
+                _missing("mypy"),
+            ],
+            RunOutcome.TOOL_MISSING,
+        ),
+        (
+            [
+                ToolResult(name="ruff", success=False, issues_count=2),
+                ToolResult(name="mypy", success=False, output="Traceback"),
+            ],
+            RunOutcome.TOOL_ERROR,
+        ),
+        (
+            [
+                ToolResult(name="ruff", success=False, issues_count=2),
+                _missing("mypy"),
+            ],
+            RunOutcome.TOOL_MISSING,
+        ),
+        (
+            [ToolResult(name="ruff", skipped=True, skip_reason="disabled")],
+            RunOutcome.CLEAN,
+        ),
+        (
+            [
+                ToolResult(
+                    name="eslint",
+                    success=False,
+                    issues_count=1,
+                    error_code=TOOL_TIMEOUT.code,
+                ),
+            ],
+            RunOutcome.TOOL_ERROR,
+        ),
+    ],
+)
+def test_outcome_is_most_severe_that_applies(
+    results: list[ToolResult],
+    outcome: RunOutcome,
+) -> None:
+    """Infrastructure failures outrank issues, which outrank a clean run.
+
+    Args:
+        results: Tool results.
+        outcome: Expected outcome.
+    """
+    assert_that(_outcome(results)).is_equal_to(outcome)
+
+
+def test_fix_outcome_uses_remaining_issues() -> None:
+    """Fixed issues do not count; remaining ones do."""
+    fixed = ToolResult(name="ruff", success=True, remaining_issues_count=0)
+    remaining = ToolResult(name="ruff", success=True, remaining_issues_count=1)
+
+    assert_that(_outcome([fixed], Action.FIX)).is_equal_to(RunOutcome.CLEAN)
+    assert_that(_outcome([remaining], Action.FIX)).is_equal_to(RunOutcome.ISSUES)
+
+
+def test_empty_filtered_run_is_a_config_error() -> None:
+    """A run the configuration filtered down to nothing is a config error."""
+    outcome = determine_outcome(
+        action=Action.CHECK,
+        all_results=[],
+        total_issues=0,
+        total_remaining=0,
+        main_phase_empty_due_to_filter=True,
+    )
+
+    assert_that(outcome).is_equal_to(RunOutcome.CONFIG_ERROR)
+
+
+def test_default_codes_preserve_previous_behavior() -> None:
+    """Missing tools pass and every other failure exits 1 by default."""
+    assert_that(exit_code_for(RunOutcome.CLEAN)).is_equal_to(0)
+    assert_that(exit_code_for(RunOutcome.TOOL_MISSING)).is_equal_to(0)
+    for outcome in (
+        RunOutcome.ISSUES,
+        RunOutcome.TOOL_ERROR,
+        RunOutcome.CONFIG_ERROR,
+    ):
+        assert_that(exit_code_for(outcome)).is_equal_to(1)
+
+
+def test_custom_codes_distinguish_failures() -> None:
+    """Configured codes separate lint violations from infrastructure failures."""
+    crashed = ToolResult(name="mypy", success=False, output="Traceback")
+    issues = ToolResult(name="ruff", success=False, issues_count=1)
+
+    codes = [
+        determine_exit_code(
+            action=Action.CHECK,
+            all_results=[result],
+            total_issues=result.issues_count,
+            total_remaining=0,
+            main_phase_empty_due_to_filter=False,
+            exit_codes=CUSTOM_CODES,
+        )
+        for result in (crashed, issues)
+    ]
+
+    assert_that(codes).is_equal_to([3, 1])
+    assert_that(exit_code_for(RunOutcome.TOOL_MISSING, CUSTOM_CODES)).is_equal_to(4)
+
+
+def test_exit_codes_are_parsed_from_config() -> None:
+    """Exit codes are read from the execution section."""
+    config = build_config_from_data(
+        {"execution": {"exit_codes": {"tool_error": 3, "tool_missing": 4}}},
+    )
+
+    codes = config.execution.exit_codes
+    assert_that(codes.tool_error).is_equal_to(3)
+    assert_that(codes.tool_missing).is_equal_to(4)
+    assert_that(codes.issues).is_equal_to(1)
+
+    with pytest.raises(ValueError):
+        build_config_from_data({"execution": {"exit_codes": {"issues": 256}}})
+
+
+@pytest.mark.parametrize(
+    ("result", "remaining", "expected"),
+    [
+        (ToolResult(name="ruff", success=False, issues_count=2), 0, True),
+        (ToolResult(name="ruff", success=False, issues_count=2), 1, False),
+        (ToolResult(name="ruff", success=False, output="crash"), 0, False),
+        (
+            ToolResult(
+                name="ruff",
+                success=False,
+                issues_count=1,
+                error_code=TOOL_TIMEOUT.code,
+            ),
+            0,
+            False,
+        ),
+        (ToolResult(name="ruff", success=True), 0, True),
+    ],
+    ids=["issues_dropped", "issues_left", "crash", "error_code", "success"],
+)
+def test_success_after_filter_keeps_tool_failures(
+    result: ToolResult,
+    remaining: int,
+    expected: bool,
+) -> None:
+    """Only a failure caused by the dropped issues alone becomes a success.
+
+    Args:
+        result: Result before filtering.
+        remaining: Issues left after filtering.
+        expected: Expected success flag.
+    """
+    assert_that(success_after_filter(result, remaining)).is_equal_to(expected)
@@ -0,0 +1,106 @@
+"""Tests for stopping a check run early with --fail-fast."""
+
+from __future__ import annotations
+
+from dataclasses import replace
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+from lintro.utils.execution.fail_fast import (
+    CANCELLED_REASON,
+    FailFast,
+    cancelled_result,
+)
+from lintro.utils.execution.failure_policy import FailurePolicy
+
+
+def _result(name: str, *levels: str) -> ToolResult:
+    """Build a check result with one issue per severity level.
+
+    Args:
+        name: Tool name.
+        *levels: Severity level of each issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues: list[BaseIssue] = [
+        PyprojectIssue(file="pyproject.toml", level=level, code="PP003")
+        for level in levels
+    ]
+    return ToolResult(
+        name=name,
+        success=not issues,
+        issues_count=len(issues),
+        issues=issues,
+    )
+
+
+def test_fail_fast_stops_once_policy_is_exceeded() -> None:
+    """The run stops on the result that takes it past the policy."""
+    fail_fast = FailFast(FailurePolicy(fail_on=SeverityLevel.ERROR, max_issues=1))
+
+    assert_that(fail_fast.record(_result("ruff", "warning", "error"))).is_false()
+    assert_that(fail_fast.record(_result("mypy", "error"))).is_true()
+    assert_that(fail_fast.stopped()).is_true()
+    assert_that(fail_fast.trigger).is_equal_to("mypy")
+
+
+def test_fail_fast_ignores_results_after_stopping() -> None:
+    """Results recorded after the stop do not change the trigger."""
+    fail_fast = FailFast(FailurePolicy())
+    fail_fast.record(_result("ruff", "error"))
+
+    assert_that(fail_fast.record(_result("mypy", "error"))).is_true()
+    assert_that(fail_fast.trigger).is_equal_to("ruff")
+    assert_that(fail_fast.results).is_length(1)
+
+
+def test_fail_fast_ignores_skipped_results() -> None:
+    """Skipped tools never stop the run."""
+    fail_fast = FailFast(FailurePolicy())
+
+    assert_that(fail_fast.record(cancelled_result("ruff"))).is_false()
+    assert_that(fail_fast.results).is_empty()
+
+
+def test_fail_fast_judges_results_before_counting() -> None:
+    """Issues the judge drops do not count towards the policy."""
+
+    def judge(result: ToolResult) -> ToolResult:
+        """Drop every issue, as a severity filter could.
+
+        Args:
+            result: Result returned by a tool.
+
+        Returns:
+            ToolResult: Result without issues.
+        """
+        return replace(result, issues=[], issues_count=0, success=True)
+
+    fail_fast = FailFast(FailurePolicy(), judge=judge)
+
+    assert_that(fail_fast.record(_result("ruff", "error"))).is_false()
+
+
+def test_cancelled_result_is_marked_skipped() -> None:
+    """Cancelled tools are reported as skipped with the fail-fast reason."""
+    result = cancelled_result("mypy")
+
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to(CANCELLED_REASON)
+
+
+def test_describe_names_trigger_and_cancelled_tools() -> None:
+    """The console note names the failing tool and marks results partial."""
+    fail_fast = FailFast(FailurePolicy())
+    fail_fast.record(_result("ruff", "error"))
+
+    assert_that(fail_fast.describe(2)).is_equal_to(
+        "Stopped early (--fail-fast): ruff exceeded the failure threshold; "
+        "2 tools not run, results are partial",
+    )
@@ -0,0 +1,223 @@
+"""Tests for the --fail-on / --max-issues failure policy."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import build_config_from_data
+from lintro.enums.action import Action
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.clippy.clippy_issue import ClippyIssue
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.exit_codes import determine_exit_code
+from lintro.utils.execution.failure_policy import (
+    BudgetUsage,
+    FailurePolicy,
+    build_failure_policy,
+)
+
+
+def _result(name: str, *levels: str) -> ToolResult:
+    """Build a failed check result with one issue per severity level.
+
+    Args:
+        name: Tool name.
+        *levels: Severity level of each issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues: list[BaseIssue] = [
+        PyprojectIssue(file="pyproject.toml", level=level, code="PP003")
+        for level in levels
+    ]
+    return ToolResult(
+        name=name,
+        success=not issues,
+        issues_count=len(issues),
+        issues=issues,
+    )
+
+
+def _exit_code(results: list[ToolResult], policy: FailurePolicy) -> int:
+    """Return the check exit code for results under a policy.
+
+    Args:
+        results: Tool results.
+        policy: Failure policy.
+
+    Returns:
+        int: Exit code.
+    """
+    return determine_exit_code(
+        action=Action.CHECK,
+        all_results=results,
+        total_issues=sum(r.issues_count for r in results),
+        total_remaining=0,
+        main_phase_empty_due_to_filter=False,
+        policy=policy,
+    )
+
+
+@pytest.mark.parametrize(
+    "fail_on,max_issues,expected",
+    [
+        (None, None, 1),
+        ("error", None, 0),
+        ("warning", None, 1),
+        ("warning", 2, 0),
+        (None, 2, 1),
+        ("never", None, 0),
+    ],
+)
+def test_run_wide_thresholds(
+    fail_on: str | None,
+    max_issues: int | None,
+    expected: int,
+) -> None:
+    """Severity and count thresholds decide whether issues fail the run.
+
+    Args:
+        fail_on: Value of --fail-on.
+        max_issues: Value of --max-issues.
+        expected: Expected exit code for two warnings and one info issue.
+    """
+    policy = build_failure_policy(build_config_from_data({}), fail_on, max_issues)
+
+    exit_code = _exit_code([_result("pyproject", "warning", "warning", "info")], policy)
+
+    assert_that(exit_code).is_equal_to(expected)
+
+
+def test_per_tool_settings_override_run_wide_ones() -> None:
+    """Tool limits tolerate their own issues and tool thresholds apply first."""
+    config = build_config_from_data(
+        {
+            "execution": {"fail_on": "warning"},
+            "tools": {
+                "mypy": {"max_issues": 3},
+                "bandit": {"fail_on": "error"},
+            },
+        },
+    )
+    policy = build_failure_policy(config)
+    tolerated = [_result("mypy", "error", "error", "error"), _result("bandit", "info")]
+
+    assert_that(_exit_code(tolerated, policy)).is_equal_to(0)
+    assert_that(
+        _exit_code([_result("mypy", *["error"] * 4)], policy),
+    ).is_equal_to(1)
+    assert_that(_exit_code([_result("ruff", "warning")], policy)).is_equal_to(1)
+
+
+def test_tool_definition_threshold_applies_unless_configured() -> None:
+    """A definition's default_fail_on applies until the config sets fail_on."""
+    default = build_failure_policy(build_config_from_data({}))
+    configured = build_failure_policy(
+        build_config_from_data({"tools": {"cargo_geiger": {"fail_on": "info"}}}),
+    )
+    usage = [_result("cargo_geiger", "info")]
+
+    assert_that(_exit_code(usage, default)).is_equal_to(0)
+    assert_that(_exit_code(usage, configured)).is_equal_to(1)
+
+
+@pytest.mark.parametrize(
+    ("data", "fail_on"),
+    [
+        ({}, "warning"),
+        ({"execution": {"fail_on": "warning"}}, None),
+    ],
+    ids=["flag", "execution"],
+)
+def test_run_threshold_replaces_tool_definition_default(
+    data: dict[str, object],
+    fail_on: str | None,
+) -> None:
+    """A run threshold from --fail-on or the config beats a definition default.
+
+    Args:
+        data: Configuration data.
+        fail_on: Value of ``--fail-on``, if given.
+    """
+    policy = build_failure_policy(build_config_from_data(data), fail_on=fail_on)
+
+    assert_that(policy.tool_fail_on).does_not_contain_key("cargo_geiger")
+    assert_that(
+        _exit_code([_result("cargo_geiger", "warning")], policy),
+    ).is_equal_to(1)
+
+
+def test_cli_flags_win_over_config() -> None:
+    """--fail-on and --max-issues replace the execution settings."""
+    config = build_config_from_data(
+        {"execution": {"fail_on": "never", "max_issues": 10}},
+    )
+
+    policy = build_failure_policy(config, fail_on="info", max_issues=0)
+
+    assert_that(policy.max_issues).is_equal_to(0)
+    assert_that(_exit_code([_result("ruff", "info")], policy)).is_equal_to(1)
+
+
+def test_tool_failures_still_fail_under_a_lenient_policy() -> None:
+    """Crashed tools fail the run even when issues are tolerated."""
+    crashed = ToolResult(name="ruff", success=False, issues_count=0)
+    policy = FailurePolicy(fail_on=None)
+
+    assert_that(_exit_code([crashed], policy)).is_equal_to(1)
+    assert_that(_exit_code([_result("ruff", "error")], policy)).is_equal_to(0)
+
+
+def test_rule_budgets_fail_only_when_exceeded() -> None:
+    """Budgeted issues count against their budget, not the run threshold."""
+    config = build_config_from_data(
+        {"budgets": {"clippy::todo": 2, "ruff:T201": 0}},
+    )
+    policy = build_failure_policy(config)
+    todos = ToolResult(
+        name="clippy",
+        success=False,
+        issues_count=2,
+        issues=[
+            ClippyIssue(file="src/lib.rs", line=line, code="clippy::todo")
+            for line in (3, 8)
+        ],
+    )
+    prints = ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=1,
+        issues=[RuffIssue(file="app.py", line=1, code="T201", message="print")],
+    )
+
+    assert_that(_exit_code([todos], policy)).is_equal_to(0)
+    assert_that(policy.budget_usage([todos])).is_equal_to(
+        [
+            BudgetUsage(pattern="clippy::todo", issues=2, budget=2),
+            BudgetUsage(pattern="ruff:T201", issues=0, budget=0),
+        ],
+    )
+    assert_that(_exit_code([todos, prints], policy)).is_equal_to(1)
+    assert_that(
+        _exit_code([todos, _result("ruff", "warning")], policy),
+    ).is_equal_to(1)
+
+
+def test_budget_usage_describes_ratchet() -> None:
+    """Usage under budget suggests lowering it; usage over budget says so."""
+    under = BudgetUsage(pattern="clippy::todo", issues=7, budget=10)
+    over = BudgetUsage(pattern="ruff:T201", issues=1, budget=0)
+
+    assert_that(under.exceeded).is_false()
+    assert_that(under.describe()).is_equal_to(
+        "Within budget for clippy::todo: 7 of 10 issue(s); "
+        "the budget can be lowered to 7",
+    )
+    assert_that(over.exceeded).is_true()
+    assert_that(over.describe()).is_equal_to(
+        "Budget exceeded for ruff:T201: 1 issue(s), budget 0",
+    )
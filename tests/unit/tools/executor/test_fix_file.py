@@ -0,0 +1,233 @@
+"""Tests for fixing a single file from an editor hook."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from types import SimpleNamespace
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.protocol import ToolCapabilities
+from lintro.utils.execution import fix_file as fix_file_module
+from lintro.utils.execution.fix_file import fix_file, parse_duration, resolve_fix_tools
+
+
+class _FakeTool:
+    """Fixer that rewrites the file it is given."""
+
+    def __init__(
+        self,
+        patterns: list[str],
+        rewrite: str | None = None,
+        error: Exception | None = None,
+    ) -> None:
+        """Create the fake.
+
+        Args:
+            patterns: File patterns the tool handles.
+            rewrite: New file contents, or None to leave the file alone.
+            error: Exception raised instead of fixing, if any.
+        """
+        self.definition = SimpleNamespace(
+            file_patterns=patterns,
+            shebang_interpreters=[],
+            can_fix=True,
+        )
+        self.exclude_patterns: list[str] = []
+        self.rewrite = rewrite
+        self.error = error
+        self.result = ToolResult(name="fake", success=True, remaining_issues_count=0)
+        self.calls: list[tuple[list[str], dict[str, object]]] = []
+
+    def capabilities(self) -> ToolCapabilities:
+        """Report the fake as a fixer.
+
+        Returns:
+            ToolCapabilities: Capabilities with fixing supported.
+        """
+        return ToolCapabilities(supports_fix=True)
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Pretend to fix the files.
+
+        Args:
+            paths: Files to fix.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: The configured result, by default a success
+            without remaining issues.
+
+        Raises:
+            Exception: The configured error, if any.
+        """
+        self.calls.append((paths, options))
+        if self.error is not None:
+            raise self.error
+        if self.rewrite is not None:
+            Path(paths[0]).write_text(self.rewrite)
+        return self.result
+
+
+@pytest.fixture
+def fake_tools(monkeypatch: pytest.MonkeyPatch) -> dict[str, Any]:
+    """Register fake fixers and record how they are configured.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        dict[str, Any]: Fake tools by name, plus "options" with the tool
+        options each tool was configured with.
+    """
+    tools: dict[str, Any] = {
+        "ruff": _FakeTool(["*.py"], rewrite="x = 1\n"),
+        "rustfmt": _FakeTool(["*.rs"], rewrite="fn main() {}\n"),
+        "black": _FakeTool(["*.py"]),
+        "options": {},
+    }
+    manager = SimpleNamespace(
+        get_tool=lambda name: tools[name],
+        load_tool=lambda name: tools.get(name) if name != "options" else None,
+    )
+
+    def configure(**kwargs: Any) -> None:
+        """Record the options a tool is configured with.
+
+        Args:
+            **kwargs: Arguments of configure_tool_for_execution.
+        """
+        tools["options"][kwargs["tool_name"]] = kwargs["tool_option_dict"]
+
+    monkeypatch.setattr(fix_file_module, "tool_manager", manager)
+    monkeypatch.setattr(fix_file_module, "configure_tool_for_execution", configure)
+    monkeypatch.setattr(fix_file_module, "UnifiedConfigManager", lambda: None)
+    return tools
+
+
+@pytest.mark.parametrize(
+    ("value", "seconds"),
+    [("2s", 2.0), ("500ms", 0.5), ("1.5", 1.5), (" 3 s ", 3.0)],
+)
+def test_parse_duration(value: str, seconds: float) -> None:
+    """Durations accept seconds and milliseconds.
+
+    Args:
+        value: Duration as typed.
+        seconds: Expected seconds.
+    """
+    assert_that(parse_duration(value)).is_equal_to(seconds)
+
+
+@pytest.mark.parametrize("value", ["", "2m", "0s", "-1"])
+def test_parse_duration_rejects_bad_values(value: str) -> None:
+    """Other units and non-positive durations are rejected.
+
+    Args:
+        value: Duration as typed.
+    """
+    with pytest.raises(ValueError, match="uration"):
+        parse_duration(value)
+
+
+def test_resolve_fix_tools_variants(fake_tools: dict[str, Any]) -> None:
+    """Variant names select one half of a tool.
+
+    Args:
+        fake_tools: Fake fixers.
+    """
+    assert_that(resolve_fix_tools("ruff-format, rustfmt")).is_equal_to(
+        [
+            ("ruff-format", "ruff", {"format": True, "lint_fix": False}),
+            ("rustfmt", "rustfmt", {}),
+        ],
+    )
+    with pytest.raises(ValueError, match="Unknown tool 'nope'"):
+        resolve_fix_tools("nope")
+
+
+def test_fix_file_routes_by_file_type(
+    fake_tools: dict[str, Any],
+    tmp_path: Path,
+) -> None:
+    """Only tools handling the file run; the result says what changed.
+
+    Args:
+        fake_tools: Fake fixers.
+        tmp_path: Temporary directory for the file.
+    """
+    target = tmp_path / "app.py"
+    target.write_text("x=1\n")
+
+    result = fix_file(target, "ruff-format,rustfmt,black", timeout=2)
+    data = result.to_dict()
+
+    assert_that(target.read_text()).is_equal_to("x = 1\n")
+    assert_that(data["changed"]).is_true()
+    assert_that(data["ok"]).is_true()
+    assert_that([(t["tool"], t["status"]) for t in data["tools"]]).is_equal_to(
+        [("ruff-format", "changed"), ("rustfmt", "skipped"), ("black", "unchanged")],
+    )
+    assert_that(fake_tools["rustfmt"].calls).is_empty()
+    assert_that(fake_tools["options"]).does_not_contain_key("rustfmt")
+    assert_that(fake_tools["options"]["ruff"]).is_equal_to(
+        {"ruff": {"format": True, "lint_fix": False}},
+    )
+    paths, options = fake_tools["ruff"].calls[0]
+    assert_that(paths).is_equal_to([str(target)])
+    assert_that(options).contains_only("timeout")
+    assert_that(options["timeout"]).is_greater_than(0).is_less_than_or_equal_to(2)
+
+
+def test_fix_file_reports_timeouts(
+    fake_tools: dict[str, Any],
+    tmp_path: Path,
+) -> None:
+    """A tool that times out makes the result not ok.
+
+    Args:
+        fake_tools: Fake fixers.
+        tmp_path: Temporary directory for the file.
+    """
+    target = tmp_path / "app.py"
+    target.write_text("x=1\n")
+    fake_tools["black"].error = subprocess.TimeoutExpired(["black"], 1)
+
+    result = fix_file(target, "black", timeout=0.5)
+
+    assert_that(result.ok).is_false()
+    assert_that(result.changed).is_false()
+    assert_that(result.tools[0].status).is_equal_to("timeout")
+    # The remaining half second is passed on, not rounded up to a second
+    assert_that(fake_tools["black"].calls[0][1]["timeout"]).is_less_than_or_equal_to(
+        0.5,
+    )
+
+
+def test_fix_file_reports_failed_fixers_as_errors(
+    fake_tools: dict[str, Any],
+    tmp_path: Path,
+) -> None:
+    """A fixer returning a failed result without issues is an error.
+
+    Args:
+        fake_tools: Fake fixers.
+        tmp_path: Temporary directory for the file.
+    """
+    target = tmp_path / "app.py"
+    target.write_text("x=1\n")
+    fake_tools["black"].result = ToolResult(
+        name="black",
+        success=False,
+        output="\nerror: cannot parse app.py\n",
+    )
+
+    result = fix_file(target, "black", timeout=2)
+
+    assert_that(result.ok).is_false()
+    assert_that(result.tools[0].status).is_equal_to("error")
+    assert_that(result.tools[0].message).is_equal_to("error: cannot parse app.py")
@@ -0,0 +1,299 @@
+"""Tests for coordinating several fixers on the same files."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.tool_type import ToolType
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.execution.fix_pipeline import (
+    FixConflict,
+    FixTracker,
+    order_fixers,
+    stabilize_fixes,
+)
+from lintro.utils.tool_executor import _stabilize_fix_results, tool_manager
+
+DEFINITIONS = {
+    name: ToolDefinition(name=name, description=name, can_fix=True, tool_type=kind)
+    for name, kind in [
+        ("black", ToolType.FORMATTER),
+        ("ruff", ToolType.LINTER | ToolType.FORMATTER),
+        ("prettier", ToolType.FORMATTER),
+        ("oxlint", ToolType.LINTER),
+    ]
+}
+
+
+def _clean(name: str, files: list[str]) -> ToolResult:
+    """Report no issues.
+
+    Args:
+        name: Tool name.
+        files: Files checked.
+
+    Returns:
+        ToolResult: Successful result.
+    """
+    return ToolResult(name=name, success=True)
+
+
+def test_formatters_run_after_lint_fixers() -> None:
+    """Pure formatters move behind lint fixers; group order is kept."""
+    order = order_fixers(["black", "ruff", "prettier", "oxlint"], DEFINITIONS)
+
+    assert_that(order).is_equal_to(["ruff", "oxlint", "black", "prettier"])
+
+
+def test_stable_after_first_pass(tmp_path: Path) -> None:
+    """Nothing left to fix on re-check is a stable point.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    target = tmp_path / "a.py"
+    target.write_text("x = 1\n")
+    tracker = FixTracker([str(target)])
+    target.write_text("x = 2\n")
+
+    assert_that(tracker.record("ruff")).is_equal_to([str(target)])
+    assert_that(tracker.record("black")).is_empty()
+
+    report, extra = stabilize_fixes(
+        tracker,
+        ["ruff", "black"],
+        check=_clean,
+        fix=_clean,
+        definitions=DEFINITIONS,
+    )
+
+    assert_that(report.stable).is_true()
+    assert_that(report.passes).is_equal_to(1)
+    assert_that(extra).is_empty()
+    assert_that(report.describe()).contains("stable point after 1 pass")
+
+
+def test_remaining_fixable_issues_trigger_another_pass(tmp_path: Path) -> None:
+    """Fixers run again on modified files while checks find fixable issues.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    target = tmp_path / "a.py"
+    target.write_text("import os, sys\n")
+    tracker = FixTracker([str(target)])
+    target.write_text("import os\nimport sys\n")
+    tracker.record("black")
+    checked: list[list[str]] = []
+
+    def check(name: str, files: list[str]) -> ToolResult:
+        """Report one fixable ruff issue until ruff fixes again.
+
+        Args:
+            name: Tool name.
+            files: Files checked.
+
+        Returns:
+            ToolResult: Check result.
+        """
+        checked.append(files)
+        if name == "ruff" and "sys" in target.read_text():
+            issue = RuffIssue(file=files[0], line=2, code="F401", fixable=True)
+            return ToolResult(name=name, success=False, issues_count=1, issues=[issue])
+        return ToolResult(name=name, success=True)
+
+    def fix(name: str, files: list[str]) -> ToolResult:
+        """Remove the unused import.
+
+        Args:
+            name: Tool name.
+            files: Files to fix.
+
+        Returns:
+            ToolResult: Fix result.
+        """
+        target.write_text(target.read_text().replace("import sys\n", ""))
+        return ToolResult(name=name, success=True, fixed_issues_count=1)
+
+    report, extra = stabilize_fixes(
+        tracker,
+        ["ruff", "black"],
+        check=check,
+        fix=fix,
+        definitions=DEFINITIONS,
+    )
+
+    assert_that(checked[0]).is_equal_to([str(target)])
+    assert_that(report.stable).is_true()
+    assert_that(report.passes).is_equal_to(2)
+    assert_that([r.name for r in extra]).is_equal_to(["ruff"])
+
+
+def test_fixers_undoing_each_other_oscillate(tmp_path: Path) -> None:
+    """A fixer restoring another's input is reported as an oscillation.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    target = tmp_path / "a.js"
+    target.write_text("x\n")
+    tracker = FixTracker([str(target)])
+    styles = {"oxlint": "a\n", "prettier": "b\n"}
+    target.write_text(styles["oxlint"])
+    tracker.record("oxlint")
+    target.write_text(styles["prettier"])
+    tracker.record("prettier")
+
+    def check(name: str, files: list[str]) -> ToolResult:
+        """Report a formatting issue unless the file has the tool's style.
+
+        Args:
+            name: Tool name.
+            files: Files checked.
+
+        Returns:
+            ToolResult: Check result.
+        """
+        issues = 0 if target.read_text() == styles[name] else 1
+        return ToolResult(name=name, success=not issues, issues_count=issues)
+
+    def fix(name: str, files: list[str]) -> ToolResult:
+        """Rewrite the file in the tool's style.
+
+        Args:
+            name: Tool name.
+            files: Files to fix.
+
+        Returns:
+            ToolResult: Fix result.
+        """
+        target.write_text(styles[name])
+        return ToolResult(name=name, success=True)
+
+    definitions = {
+        name: ToolDefinition(
+            name=name,
+            description=name,
+            can_fix=True,
+            tool_type=ToolType.FORMATTER,
+        )
+        for name in styles
+    }
+    report, _ = stabilize_fixes(
+        tracker,
+        ["oxlint", "prettier"],
+        check=check,
+        fix=fix,
+        definitions=definitions,
+    )
+
+    assert_that(report.stable).is_false()
+    assert_that(report.conflicts).contains(
+        FixConflict(file=str(target), tool="oxlint", undone_tool="prettier"),
+    )
+    assert_that(report.describe()).starts_with("Fixers oscillate: oxlint undoes")
+
+
+class _FakeFixer:
+    """Fixer whose check reports one issue per listed file."""
+
+    def __init__(self, name: str, unfixable: Path) -> None:
+        """Set up the fixer.
+
+        Args:
+            name: Tool name.
+            unfixable: File that keeps an issue nothing fixes.
+        """
+        self.definition = ToolDefinition(
+            name=name,
+            description=name,
+            can_fix=True,
+            file_patterns=["*.py"],
+        )
+        self.exclude_patterns = ["vendor"]
+        self.unfixable = unfixable
+        self.checked: list[list[str]] = []
+
+    def check(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Report the issue left in the unfixable file.
+
+        Args:
+            paths: Files or directories checked.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: One issue.
+        """
+        self.checked.append(paths)
+        issue = RuffIssue(file=str(self.unfixable), line=1, code="E501")
+        return ToolResult(
+            name=self.definition.name,
+            success=False,
+            issues_count=1,
+            issues=[issue],
+        )
+
+    def fix(self, paths: list[str], options: dict[str, object]) -> ToolResult:
+        """Fix nothing.
+
+        Args:
+            paths: Files to fix.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: Empty fix result.
+        """
+        return ToolResult(name=self.definition.name, success=True)
+
+
+def test_stabilized_results_take_remaining_issues_from_final_check(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A fixer that failed its first pass is re-checked with the run's setup.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    target, vendored = tmp_path / "a.py", tmp_path / "vendor" / "b.py"
+    vendored.parent.mkdir()
+    for path in (target, vendored):
+        path.write_text("x=1\n")
+    tracker = FixTracker([str(target), str(vendored)])
+    target.write_text("x = 1\n")
+    vendored.write_text("x = 1\n")
+    tracker.record("black")
+    fixer = _FakeFixer("ruff", target)
+    monkeypatch.setattr(tool_manager, "get_tool", lambda name: fixer)
+    configured: list[str] = []
+    first_pass = ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=3,
+        remaining_issues_count=3,
+    )
+
+    results = _stabilize_fix_results(
+        fix_tracker=tracker,
+        all_results=[first_pass],
+        definitions=DEFINITIONS,
+        tool_scopes={},
+        paths=[str(tmp_path)],
+        max_passes=3,
+        configure=configured.append,
+        postprocess=lambda result: result,
+        logger=MagicMock(),
+    )
+
+    assert_that(configured).is_equal_to(["ruff"])
+    assert_that(fixer.checked[0]).is_equal_to([str(target)])
+    assert_that(fixer.checked[-1]).is_equal_to([str(tmp_path)])
+    assert_that(results[0].remaining_issues_count).is_equal_to(1)
+    assert_that(results[0].issues_count).is_equal_to(1)
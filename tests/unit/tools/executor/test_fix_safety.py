@@ -0,0 +1,98 @@
+"""Tests for classifying fixes as safe or unsafe and the fix safety policy."""
+
+from __future__ import annotations
+
+from typing import Any
+from unittest.mock import MagicMock
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import build_config_from_data
+from lintro.enums.fix_safety import FixSafety, normalize_fix_safety
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.fix_safety import apply_fix_safety, fix_safety_options
+from lintro.utils.json_output import json_issue
+
+
+@pytest.mark.parametrize(
+    ("raw", "expected"),
+    [
+        ("safe", FixSafety.SAFE),
+        ("MachineApplicable", FixSafety.SAFE),
+        ("unsafe", FixSafety.UNSAFE),
+        ("display-only", FixSafety.UNSAFE),
+        ("MaybeIncorrect", FixSafety.UNSAFE),
+        ("HasPlaceholders", FixSafety.UNSAFE),
+    ],
+)
+def test_normalize_fix_safety(raw: str, expected: FixSafety) -> None:
+    """Only applicabilities known to be safe are safe.
+
+    Args:
+        raw: Native applicability.
+        expected: Expected classification.
+    """
+    assert_that(normalize_fix_safety(raw)).is_equal_to(expected)
+
+
+def test_issue_fix_safety() -> None:
+    """Ruff's applicability sets the safety; other fixable issues are safe."""
+    unsafe = RuffIssue(code="F841", fixable=True, fix_applicability="unsafe")
+    safe = RuffIssue(code="F401", fixable=True, fix_applicability="safe")
+
+    assert_that(unsafe.get_fix_safety()).is_equal_to(FixSafety.UNSAFE)
+    assert_that(unsafe.to_display_row()["fixable"]).is_equal_to("Unsafe")
+    assert_that(json_issue(unsafe, "f")).contains_entry({"fix_safety": "unsafe"})
+    assert_that(safe.to_display_row()["fixable"]).is_equal_to("Yes")
+    assert_that(RuffIssue(code="E501").get_fix_safety()).is_none()
+    assert_that(BaseIssue().get_fix_safety()).is_none()
+
+
+def test_fix_safety_options() -> None:
+    """Each policy sets the unsafe option of every tool that has one."""
+    assert_that(fix_safety_options("all")).contains_entry(
+        {"rubocop": {"unsafe": True}},
+    )
+    assert_that(fix_safety_options("safe")).contains_entry(
+        {"ruff": {"unsafe_fixes": False}},
+    )
+
+
+@pytest.mark.parametrize(
+    ("tool_name", "overrides", "expected"),
+    [
+        ("ruff", {}, {"unsafe_fixes": False}),
+        ("ruff", {"unsafe_fixes": True}, None),
+        ("black", {}, None),
+    ],
+    ids=["policy_applies", "command_line_wins", "tool_without_option"],
+)
+def test_apply_fix_safety(
+    tool_name: str,
+    overrides: dict[str, Any],
+    expected: dict[str, Any] | None,
+) -> None:
+    """The configured policy yields to options given on the command line.
+
+    Args:
+        tool_name: Tool being configured.
+        overrides: Command-line options of the tool.
+        expected: Options the policy sets, or None if it sets none.
+    """
+    tool = MagicMock()
+
+    apply_fix_safety(tool, tool_name, "safe", overrides)
+
+    if expected is None:
+        tool.set_options.assert_not_called()
+    else:
+        tool.set_options.assert_called_once_with(**expected)
+
+
+def test_fix_safety_is_loaded() -> None:
+    """execution.fix_safety defaults to safe and accepts all."""
+    assert_that(build_config_from_data({}).execution.fix_safety).is_equal_to("safe")
+    config = build_config_from_data({"execution": {"fix_safety": "all"}})
+    assert_that(config.execution.fix_safety).is_equal_to("all")
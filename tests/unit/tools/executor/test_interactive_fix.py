@@ -0,0 +1,149 @@
+"""Tests for reviewing fixes hunk by hunk."""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.utils.execution.dry_run import FileChange
+from lintro.utils.execution.interactive_fix import (
+    apply_hunks,
+    review_changes,
+    split_hunks,
+    write_changes,
+)
+
+BEFORE = "".join(f"line{i}\n" for i in range(1, 21))
+AFTER = BEFORE.replace("line2\n", "LINE2\n").replace("line18\n", "LINE18\n")
+
+
+def _answers(*answers: str) -> Callable[[str], str]:
+    """Build a prompt that replays fixed answers.
+
+    Args:
+        *answers: Answers in order.
+
+    Returns:
+        Callable[[str], str]: Prompt function.
+    """
+    remaining = list(answers)
+    return lambda text: remaining.pop(0)
+
+
+def _review(
+    changes: list[FileChange],
+    *answers: str,
+    edited: str | None = None,
+) -> list[FileChange]:
+    """Review changes with scripted answers and a fixed editor result.
+
+    Args:
+        changes: Changes to review.
+        *answers: Prompt answers in order.
+        edited: Text the editor returns.
+
+    Returns:
+        list[FileChange]: Accepted changes.
+    """
+    shown: list[str] = []
+    return review_changes(
+        changes,
+        prompt=_answers(*answers),
+        edit=lambda text: edited,
+        echo=shown.append,
+    )
+
+
+def test_distant_changes_split_into_hunks() -> None:
+    """Changes far apart become separate hunks with line numbers."""
+    hunks = split_hunks(BEFORE, AFTER)
+
+    assert_that(hunks).is_length(2)
+    assert_that(hunks[0].diff()).starts_with("@@ -1,5 +1,5 @@\n line1\n-line2\n+LINE2")
+    assert_that(hunks[1].start).is_equal_to(14)
+    assert_that(apply_hunks(BEFORE, hunks, [None, hunks[1].after])).is_equal_to(
+        BEFORE.replace("line18\n", "LINE18\n"),
+    )
+
+
+def test_review_applies_only_accepted_hunks() -> None:
+    """Rejected hunks keep the original lines."""
+    change = FileChange(path="a.py", before=BEFORE.encode(), after=AFTER.encode())
+
+    accepted = _review([change], "n", "y")
+
+    assert_that(accepted).is_length(1)
+    assert_that(accepted[0].after).is_equal_to(
+        BEFORE.replace("line18\n", "LINE18\n").encode(),
+    )
+    assert_that(_review([change], "n", "n")).is_empty()
+
+
+def test_review_edit_and_help() -> None:
+    """Unknown answers show help; edited lines replace the hunk."""
+    change = FileChange(path="a.py", before=BEFORE.encode(), after=AFTER.encode())
+    edited = "line1\nLine2\nline3\nline4\nline5\n"
+
+    accepted = _review([change], "?", "e", "d", edited=edited)
+
+    assert_that(accepted[0].after).is_equal_to(
+        BEFORE.replace("line2\n", "Line2\n").encode(),
+    )
+
+
+def test_quit_keeps_earlier_answers() -> None:
+    """Quitting applies what was accepted and skips the rest."""
+    first = FileChange(path="a.py", before=BEFORE.encode(), after=AFTER.encode())
+    created = FileChange(path="b.py", before=None, after=b"new\n")
+
+    accepted = _review([first, created], "y", "q")
+
+    assert_that([c.path for c in accepted]).is_equal_to(["a.py"])
+    assert_that(accepted[0].after).is_equal_to(
+        BEFORE.replace("line2\n", "LINE2\n").encode(),
+    )
+    assert_that(_review([created], "y")).is_equal_to([created])
+
+
+def test_review_keeps_crlf_line_endings() -> None:
+    """Converted line endings do not turn the whole file into one hunk."""
+    before = BEFORE.replace("\n", "\r\n")
+    change = FileChange(path="a.py", before=before.encode(), after=AFTER.encode())
+
+    accepted = _review([change], "y", "n")
+
+    assert_that(accepted[0].after).is_equal_to(
+        before.replace("line2\r\n", "LINE2\r\n").encode(),
+    )
+
+
+def test_review_skips_files_with_only_line_endings_changed() -> None:
+    """A file whose only change is its line endings has nothing to review."""
+    change = FileChange(
+        path="a.py",
+        before=BEFORE.replace("\n", "\r\n").encode(),
+        after=BEFORE.encode(),
+    )
+
+    assert_that(_review([change])).is_empty()
+
+
+def test_write_changes(tmp_path: Path) -> None:
+    """Accepted contents are written and accepted deletions remove files.
+
+    Args:
+        tmp_path: Temporary directory used as the project.
+    """
+    (tmp_path / "old.py").write_text("x\n")
+    write_changes(
+        [
+            FileChange(path="src/a.py", before=None, after=b"a\n"),
+            FileChange(path="old.py", before=b"x\n", after=None),
+        ],
+        str(tmp_path),
+    )
+
+    assert_that((tmp_path / "src" / "a.py").read_text()).is_equal_to("a\n")
+    assert_that((tmp_path / "old.py").exists()).is_false()
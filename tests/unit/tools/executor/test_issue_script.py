@@ -0,0 +1,198 @@
+"""Tests for the output.script issue post-processing hook."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import build_config_from_data
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.issue_script import (
+    IssueScript,
+    apply_issue_script,
+    load_configured_script,
+    load_issue_script,
+)
+
+HOOK = """
+function process(issue)
+  if issue.file:find("^gen/") then
+    return nil
+  end
+  if issue.code == "E501" then
+    issue.severity = "info"
+  end
+  issue.owner = "team-" .. issue.tool
+  return issue
+end
+"""
+
+
+def _result() -> ToolResult:
+    """Build a failed ruff result with two issues.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues = [
+        RuffIssue(file="gen/api.py", line=1, code="F401", message="unused"),
+        RuffIssue(file="src/app.py", line=3, code="E501", message="too long"),
+    ]
+    return ToolResult(name="ruff", success=False, issues_count=2, issues=issues)
+
+
+def _script(process: Any) -> IssueScript:
+    """Wrap a Python function as a loaded script.
+
+    Args:
+        process: Function standing in for the Lua ``process``.
+
+    Returns:
+        IssueScript: Script calling the function.
+    """
+    return IssueScript(path=Path("hook.lua"), process=process)
+
+
+def test_script_drops_remaps_and_annotates_issues() -> None:
+    """Returned tables update severity, message and computed fields."""
+
+    def process(issue: dict[str, Any]) -> dict[str, Any] | None:
+        """Drop one issue and rewrite the other.
+
+        Args:
+            issue: Issue fields.
+
+        Returns:
+            dict[str, Any] | None: Updated fields, or None to drop.
+        """
+        if issue["file"].startswith("gen/"):
+            return None
+        return {**issue, "severity": "error", "message": "wrap it", "owner": "web"}
+
+    filtered = apply_issue_script(_result(), _script(process))
+
+    assert_that(filtered.issues_count).is_equal_to(1)
+    issue = filtered.issues[0]
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.ERROR)
+    assert_that(issue.message).is_equal_to("wrap it")
+    assert_that(issue.script_fields).is_equal_to({"owner": "web"})
+
+
+def test_script_passes_normalized_fields() -> None:
+    """The script sees the tool, rule code and lower-case severity."""
+    seen: list[dict[str, Any]] = []
+
+    def process(issue: dict[str, Any]) -> dict[str, Any]:
+        """Record the fields and keep the issue.
+
+        Args:
+            issue: Issue fields.
+
+        Returns:
+            dict[str, Any]: The fields unchanged.
+        """
+        seen.append(issue)
+        return issue
+
+    result = _result()
+    kept = apply_issue_script(result, _script(process))
+
+    assert_that(seen[1]).contains_entry(
+        {"tool": "ruff"},
+        {"code": "E501"},
+        {"severity": "warning"},
+        {"fixable": False},
+    )
+    assert_that(kept.issues).is_equal_to(result.issues)
+
+
+def test_script_dropping_all_issues_succeeds() -> None:
+    """A result left without issues is successful."""
+    filtered = apply_issue_script(_result(), _script(lambda issue: None))
+
+    assert_that(filtered.issues_count).is_equal_to(0)
+    assert_that(filtered.success).is_true()
+
+
+def test_unknown_severity_raises() -> None:
+    """A severity the script invents is an error."""
+    script = _script(lambda issue: {**issue, "severity": "urgent"})
+
+    with pytest.raises(RuntimeError, match="Unknown severity level"):
+        apply_issue_script(_result(), script)
+
+
+def test_without_script_result_is_unchanged() -> None:
+    """No configured script leaves results alone."""
+    result = _result()
+
+    assert_that(apply_issue_script(result, None)).is_same_as(result)
+    assert_that(load_configured_script(build_config_from_data({}))).is_none()
+
+
+def test_missing_lupa_is_reported(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without lupa the error names the extra to install.
+
+    Args:
+        tmp_path: Temporary directory for the script.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    script = tmp_path / "hook.lua"
+    script.write_text(HOOK)
+    monkeypatch.setitem(sys.modules, "lupa", None)
+
+    with pytest.raises(RuntimeError, match=r"lintro\[scripting\]"):
+        load_issue_script(script)
+
+
+def test_lua_script_processes_issues(tmp_path: Path) -> None:
+    """A Lua hook runs against real issues.
+
+    Args:
+        tmp_path: Temporary directory for the config and script.
+    """
+    (tmp_path / "hook.lua").write_text(HOOK)
+    config = build_config_from_data({"output": {"script": "hook.lua"}})
+    config.config_path = str(tmp_path / ".lintro-config.yaml")
+
+    script = load_configured_script(config)
+    filtered = apply_issue_script(_result(), script)
+
+    assert_that(filtered.issues_count).is_equal_to(1)
+    assert_that(filtered.issues[0].get_severity()).is_equal_to(SeverityLevel.INFO)
+    assert_that(filtered.issues[0].script_fields).is_equal_to({"owner": "team-ruff"})
+
+
+@pytest.mark.parametrize(
+    ("source", "message"),
+    [
+        ("function process(", "Error in output.script"),
+        ("x = 1", "does not define a function process"),
+    ],
+)
+def test_invalid_lua_scripts_are_rejected(
+    tmp_path: Path,
+    source: str,
+    message: str,
+) -> None:
+    """Scripts that do not compile or lack process() are refused.
+
+    Args:
+        tmp_path: Temporary directory for the script.
+        source: Script source.
+        message: Expected part of the error message.
+    """
+    script = tmp_path / "hook.lua"
+    script.write_text(source)
+
+    with pytest.raises(RuntimeError, match=message):
+        load_issue_script(script)
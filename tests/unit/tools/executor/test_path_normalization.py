@@ -0,0 +1,55 @@
+"""Tests for normalizing the file paths of tool results."""
+
+from __future__ import annotations
+
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.path_normalization import normalize_issue_paths
+
+
+def _result(*files: str) -> ToolResult:
+    """Build a failed check result with one issue per file.
+
+    Args:
+        *files: File of each issue, as the tool printed it.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues: list[BaseIssue] = [
+        RuffIssue(file=file, line=1, column=1, code="F401", message="unused")
+        for file in files
+    ]
+    return ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=len(issues),
+        issues=issues,
+        formatted_output="ruff output",
+    )
+
+
+def test_windows_paths_become_repo_relative() -> None:
+    """Mixed separators and drive-letter casing map to one relative path."""
+    result = _result(
+        "c:\\repo\\src\\app.py",
+        "C:/repo/src\\app.py",
+        "D:\\other\\lib.py",
+    )
+
+    normalized = normalize_issue_paths(result, "C:\\repo")
+
+    assert_that([i.file for i in normalized.issues or []]).is_equal_to(
+        ["src/app.py", "src/app.py", "D:/other/lib.py"],
+    )
+    assert_that(normalized.formatted_output).is_none()
+
+
+def test_normalized_paths_return_result_unchanged() -> None:
+    """Results whose paths are normalized already are returned as they are."""
+    result = _result("src/app.py", "")
+
+    assert_that(normalize_issue_paths(result, "/repo")).is_same_as(result)
@@ -0,0 +1,259 @@
+"""Tests for per-path tool and rule overrides."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import (
+    _convert_pyproject_to_config,
+    build_config_from_data,
+)
+from lintro.config.lintro_config import LintroConfig
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+from lintro.utils.execution import tool_configuration
+from lintro.utils.execution.path_normalization import normalize_issue_paths
+from lintro.utils.execution.path_overrides import PathOverrides, apply_path_overrides
+from lintro.utils.execution.tool_configuration import (
+    ToolsToRunResult,
+    apply_override_scopes,
+)
+
+
+@pytest.fixture
+def overridden_config(tmp_path: Path) -> LintroConfig:
+    """Build a config relaxing checks for tests and generated code.
+
+    Args:
+        tmp_path: Temporary repository root holding the config file.
+
+    Returns:
+        LintroConfig: Config with two overrides.
+    """
+    return build_config_from_data(
+        {
+            "overrides": [
+                {"paths": ["tests/**"], "disable": "bandit", "ignore": ["PP001"]},
+                {"paths": "*_pb2.py", "ignore": "pyproject:PP003"},
+            ],
+        },
+        config_path=str(tmp_path / ".lintro-config.yaml"),
+    )
+
+
+def _result(name: str, *issues: tuple[str, str]) -> ToolResult:
+    """Build a failed check result.
+
+    Args:
+        name: Tool name.
+        *issues: File and rule code of each issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    found: list[BaseIssue] = [
+        PyprojectIssue(file=file, level="error", code=code) for file, code in issues
+    ]
+    return ToolResult(
+        name=name,
+        success=False,
+        issues_count=len(found),
+        issues=found,
+    )
+
+
+def test_overrides_are_parsed(overridden_config: LintroConfig) -> None:
+    """Comma-separated strings are accepted like lists.
+
+    Args:
+        overridden_config: Config with overrides.
+    """
+    first, second = overridden_config.overrides
+
+    assert_that(first.disable).is_equal_to(["bandit"])
+    assert_that(second.paths).is_equal_to(["*_pb2.py"])
+    assert_that(second.ignore).is_equal_to(["pyproject:PP003"])
+
+
+def test_overrides_require_paths() -> None:
+    """An override without paths is rejected."""
+    with pytest.raises(ValueError, match="paths"):
+        build_config_from_data({"overrides": [{"disable": ["bandit"]}]})
+
+
+def test_overrides_must_be_a_list() -> None:
+    """A mapping in place of the override list is rejected."""
+    with pytest.raises(ValueError, match="list"):
+        build_config_from_data({"overrides": {"paths": ["tests/**"]}})
+
+
+def test_pyproject_override_tables_are_converted() -> None:
+    """[[tool.lintro.override]] tables become the overrides list."""
+    converted = _convert_pyproject_to_config(
+        {"override": [{"paths": ["tests/**"], "disable": ["bandit"]}]},
+    )
+
+    assert_that(converted["overrides"]).is_equal_to(
+        [{"paths": ["tests/**"], "disable": ["bandit"]}],
+    )
+
+
+def test_disabled_tool_issues_are_dropped_in_matching_files(
+    overridden_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """Issues of a disabled tool go only where its override applies.
+
+    Args:
+        overridden_config: Config with overrides.
+        tmp_path: Temporary repository root.
+    """
+    overrides = PathOverrides.from_config(overridden_config)
+    result = _result(
+        "bandit",
+        (str(tmp_path / "tests" / "unit" / "test_a.py"), "B101"),
+        (str(tmp_path / "src" / "a.py"), "B101"),
+    )
+
+    filtered = apply_path_overrides(result, overrides)
+
+    assert_that([i.file for i in filtered.issues]).is_equal_to(
+        [str(tmp_path / "src" / "a.py")],
+    )
+    assert_that(filtered.issues_count).is_equal_to(1)
+
+
+def test_ignored_rules_are_dropped_in_matching_files(
+    overridden_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """Ignored rules are dropped, bare or tool-qualified, at any depth.
+
+    Args:
+        overridden_config: Config with overrides.
+        tmp_path: Temporary repository root.
+    """
+    overrides = PathOverrides.from_config(overridden_config)
+    result = _result(
+        "pyproject",
+        (str(tmp_path / "tests" / "test_a.py"), "PP001"),
+        (str(tmp_path / "tests" / "test_a.py"), "PP002"),
+        (str(tmp_path / "api" / "v1" / "user_pb2.py"), "PP003"),
+        (str(tmp_path / "src" / "a.py"), "PP001"),
+    )
+
+    filtered = apply_path_overrides(result, overrides)
+
+    assert_that([(i.file, i.code) for i in filtered.issues]).is_equal_to(
+        [
+            (str(tmp_path / "tests" / "test_a.py"), "PP002"),
+            (str(tmp_path / "src" / "a.py"), "PP001"),
+        ],
+    )
+
+
+def test_result_with_every_issue_dropped_succeeds(
+    overridden_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """A result left without issues is marked successful.
+
+    Args:
+        overridden_config: Config with overrides.
+        tmp_path: Temporary repository root.
+    """
+    overrides = PathOverrides.from_config(overridden_config)
+    result = _result("bandit", (str(tmp_path / "tests" / "test_a.py"), "B101"))
+
+    filtered = apply_path_overrides(result, overrides)
+
+    assert_that(filtered.success).is_true()
+    assert_that(filtered.issues_count).is_equal_to(0)
+
+
+def test_patterns_are_relative_to_the_config_file(
+    overridden_config: LintroConfig,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Relative issue paths match the patterns from any working directory.
+
+    Args:
+        overridden_config: Config with overrides.
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "tests").mkdir()
+    monkeypatch.chdir(tmp_path / "tests")
+    overrides = PathOverrides.from_config(overridden_config)
+    result = normalize_issue_paths(_result("bandit", ("test_a.py", "B101")))
+
+    assert_that(apply_path_overrides(result, overrides).issues).is_empty()
+
+
+def test_nested_config_overrides_cover_their_subtree(
+    overridden_config: LintroConfig,
+    tmp_path: Path,
+) -> None:
+    """A nested config's overrides are relative to it and stay in its subtree.
+
+    Args:
+        overridden_config: Config with overrides.
+        tmp_path: Temporary repository root.
+    """
+    nested = tmp_path / "services" / "api"
+    nested.mkdir(parents=True)
+    (nested / ".lintro-config.yaml").write_text(
+        "overrides:\n  - paths: [gen/**]\n    disable: [ruff]\n",
+    )
+
+    overrides = PathOverrides.from_config(overridden_config, [nested])
+
+    assert_that(overrides.excludes_for("ruff")).is_length(1)
+    assert_that(overrides.drops("ruff", "E501", str(nested / "gen" / "a.py"))).is_true()
+    assert_that(
+        overrides.drops("ruff", "E501", str(tmp_path / "gen" / "a.py")),
+    ).is_false()
+    assert_that(
+        overrides.drops("bandit", "B101", str(tmp_path / "tests" / "a.py")),
+    ).is_true()
+
+
+def test_result_without_overrides_is_unchanged(tmp_path: Path) -> None:
+    """Without overrides the result is returned as is.
+
+    Args:
+        tmp_path: Temporary repository root.
+    """
+    result = _result("bandit", (str(tmp_path / "tests" / "test_a.py"), "B101"))
+
+    assert_that(apply_path_overrides(result, PathOverrides())).is_same_as(result)
+
+
+def test_override_scopes_exclude_files_of_disabled_tools(
+    overridden_config: LintroConfig,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Disabled tools get the override's patterns as excludes.
+
+    Args:
+        overridden_config: Config with overrides.
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_configuration, "get_config", lambda: overridden_config)
+    tools_result = ToolsToRunResult(to_run=["bandit", "ruff"])
+    scopes: dict = {}
+
+    apply_override_scopes(tools_result, [str(tmp_path)], scopes)
+
+    assert_that(scopes).contains_only("bandit")
+    assert_that(scopes["bandit"].paths).is_equal_to([str(tmp_path)])
+    assert_that(scopes["bandit"].exclude_patterns).is_equal_to(
+        [f"{tmp_path.resolve().as_posix().strip('/')}/tests/**"],
+    )
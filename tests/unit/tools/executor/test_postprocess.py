@@ -0,0 +1,113 @@
+"""Tests for the issue handling shared by every run path."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.issue_script import IssueScript
+from lintro.utils.execution.labels import DirectoryLabels
+from lintro.utils.execution.path_overrides import PathOverrides
+from lintro.utils.execution.postprocess import (
+    STAGE_JUDGE,
+    STAGE_REPORT,
+    postprocess_result,
+)
+
+
+def _result() -> ToolResult:
+    """Build a failed ruff result with two issues.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues = [
+        RuffIssue(file="gen/api.py", line=1, code="F401", message="unused"),
+        RuffIssue(file="src/app.py", line=3, code="E501", message="too long"),
+    ]
+    return ToolResult(name="ruff", success=False, issues_count=2, issues=issues)
+
+
+def _postprocess(
+    result: ToolResult,
+    script: IssueScript | None,
+    stage: str,
+) -> ToolResult:
+    """Run the chain with no settings but the script.
+
+    Args:
+        result: Result to post-process.
+        script: Issue script, or None.
+        stage: Post-processing stage.
+
+    Returns:
+        ToolResult: Post-processed result.
+    """
+    return postprocess_result(
+        result,
+        aliases=None,
+        path_overrides=PathOverrides(),
+        severity_overrides=None,
+        directory_labels=DirectoryLabels(),
+        label_policies={},
+        issue_script=script,
+        min_severity=None,
+        issue_filter=None,
+        rule_selection=None,
+        stage=stage,
+    )
+
+
+@pytest.mark.parametrize("stage", [STAGE_REPORT, STAGE_JUDGE])
+def test_every_stage_runs_the_issue_script(stage: str) -> None:
+    """Issues the script drops are not counted, even by the judge.
+
+    Args:
+        stage: Post-processing stage.
+    """
+
+    def process(issue: dict[str, Any]) -> dict[str, Any] | None:
+        """Drop generated files.
+
+        Args:
+            issue: Issue fields.
+
+        Returns:
+            dict[str, Any] | None: The fields, or None to drop.
+        """
+        return None if issue["file"].startswith("gen/") else issue
+
+    script = IssueScript(path=Path("hook.lua"), process=process)
+
+    result = _postprocess(_result(), script, stage)
+
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].file).is_equal_to("src/app.py")
+
+
+def test_script_errors_only_raise_when_reporting() -> None:
+    """The judge keeps the issues a failing script left; reporting raises."""
+
+    def process(issue: dict[str, Any]) -> dict[str, Any] | None:
+        """Fail on every issue.
+
+        Args:
+            issue: Issue fields.
+
+        Raises:
+            RuntimeError: Always.
+        """
+        raise RuntimeError("hook.lua: boom")
+
+    script = IssueScript(path=Path("hook.lua"), process=process)
+
+    judged = _postprocess(_result(), script, STAGE_JUDGE)
+
+    assert_that(judged.issues_count).is_equal_to(2)
+    with pytest.raises(RuntimeError, match="boom"):
+        _postprocess(_result(), script, STAGE_REPORT)
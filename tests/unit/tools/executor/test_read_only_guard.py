@@ -0,0 +1,83 @@
+"""Tests for the read-only guard of check runs."""
+
+from __future__ import annotations
+
+import os
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.read_only_guard import (
+    ReadOnlyGuard,
+    WorkspaceWrite,
+    flag_writes,
+)
+
+
+def test_guard_attributes_writes_to_tools(tmp_path: Path) -> None:
+    """Created, modified and deleted files are attributed to the last tool.
+
+    Args:
+        tmp_path: Temporary workspace.
+    """
+    kept = tmp_path / "kept.py"
+    kept.write_text("x = 1\n")
+    touched = tmp_path / "touched.py"
+    touched.write_text("y = 2\n")
+    guard = ReadOnlyGuard([str(tmp_path)])
+
+    assert_that(guard.record("ruff")).is_empty()
+
+    # Same contents, but still a write
+    os.utime(touched, ns=(0, 0))
+    (tmp_path / "new.py").write_text("")
+    assert_that(guard.record("black")).is_equal_to(
+        [
+            WorkspaceWrite("black", str(tmp_path / "new.py"), "created"),
+            WorkspaceWrite("black", str(touched), "modified"),
+        ],
+    )
+
+    kept.unlink()
+    assert_that(guard.record("mypy")).is_equal_to(
+        [WorkspaceWrite("mypy", str(kept), "deleted")],
+    )
+    assert_that(guard.describe()).is_equal_to(
+        "Read-only check failed: black, mypy wrote 3 file(s) in the workspace.",
+    )
+
+
+def test_guard_ignores_caches_and_lintro_output(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Tool caches, excluded paths and lintro's own output are not writes.
+
+    Args:
+        tmp_path: Temporary workspace.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    guard = ReadOnlyGuard(["."], exclude="generated")
+
+    for directory in (".ruff_cache", ".lintro", "target", "generated"):
+        (tmp_path / directory).mkdir()
+        (tmp_path / directory / "data").write_text("x")
+
+    assert_that(guard.record("ruff")).is_empty()
+
+
+def test_flag_writes_fails_result() -> None:
+    """A result of a tool that wrote files fails and lists them."""
+    result = ToolResult(name="black", success=True, output=None, issues_count=0)
+    write = WorkspaceWrite("black", os.path.abspath("src/app.py"), "modified")
+
+    assert_that(flag_writes(result, [])).is_same_as(result)
+    flagged = flag_writes(result, [write])
+    assert_that(flagged.success).is_false()
+    assert_that(flagged.output).contains(
+        "during a read-only check",
+        f"modified: {os.path.join('src', 'app.py')}",
+    )
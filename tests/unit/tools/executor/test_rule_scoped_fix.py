@@ -0,0 +1,162 @@
+"""Tests for fixing only selected rules."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+from unittest.mock import MagicMock
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.clippy.clippy_parser import parse_clippy_output
+from lintro.tools.definitions.clippy import _apply_suggestions, _suggested_edits
+from lintro.utils.execution.rule_scoped_fix import (
+    fix_selected,
+    parse_fix_only,
+    run_rule_scoped_fix,
+)
+
+
+def _diagnostic(code: str, *spans: dict[str, Any]) -> str:
+    """Build one line of cargo clippy JSON output with a suggestion.
+
+    Args:
+        code: Lint code of the diagnostic.
+        *spans: Suggestion spans of its help message.
+
+    Returns:
+        str: JSON line as cargo prints it.
+    """
+    message = {
+        "code": {"code": code},
+        "level": "warning",
+        "message": "lint fired",
+        "spans": [{"file_name": "src/main.rs", "line_start": 1, "column_start": 1}],
+        "children": [{"level": "help", "message": "fix it", "spans": list(spans)}],
+    }
+    return json.dumps({"reason": "compiler-message", "message": message})
+
+
+def _span(start: int, end: int, replacement: str) -> dict[str, Any]:
+    """Build a machine-applicable suggestion span in src/main.rs.
+
+    Args:
+        start: First byte replaced.
+        end: Byte after the replaced range.
+        replacement: Replacement text.
+
+    Returns:
+        dict[str, Any]: Span as cargo reports it.
+    """
+    return {
+        "file_name": "src/main.rs",
+        "byte_start": start,
+        "byte_end": end,
+        "suggested_replacement": replacement,
+        "suggestion_applicability": "MachineApplicable",
+    }
+
+
+def test_parse_fix_only() -> None:
+    """Rust lint paths name their tool; other rules use tool:RULE."""
+    assert_that(
+        parse_fix_only("clippy::needless_return, ruff:I001,ruff:F401,ruff:I001"),
+    ).is_equal_to(
+        {"clippy": ["clippy::needless_return"], "ruff": ["I001", "F401"]},
+    )
+
+
+@pytest.mark.parametrize(
+    ("spec", "message"),
+    [
+        ("I001", "is not tool:RULE"),
+        ("ruff:", "is not tool:RULE"),
+        ("clippy::", "is not tool:RULE"),
+        ("black:E501", "black cannot fix selected rules"),
+        (" , ", "no rules given"),
+    ],
+)
+def test_parse_fix_only_rejects_bad_items(spec: str, message: str) -> None:
+    """Malformed items and tools without rule-scoped fixing are rejected.
+
+    Args:
+        spec: --fix-only value.
+        message: Expected part of the error.
+    """
+    with pytest.raises(ValueError, match=message):
+        parse_fix_only(spec)
+
+
+def test_fix_selected_reports_tools_without_rules() -> None:
+    """Tools without listed rules are checked, with nothing fixed."""
+    tool = MagicMock()
+    tool.check.return_value = ToolResult(name="black", success=False, issues_count=3)
+
+    result = fix_selected(tool, "black", ["src"], {"ruff": ["I001"]})
+
+    tool.fix.assert_not_called()
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(0)
+    assert_that(result.remaining_issues_count).is_equal_to(3)
+
+
+def test_run_rule_scoped_fix_restores_fixable_option() -> None:
+    """The rules apply to this fix only."""
+    tool = MagicMock()
+    tool.options = {"fixable": ["F401"]}
+    tool.set_options.side_effect = lambda **kwargs: tool.options.update(kwargs)
+    seen: list[list[str]] = []
+
+    def fix(paths: list[str], options: dict[str, Any]) -> ToolResult:
+        """Record the rules the fixer sees.
+
+        Args:
+            paths: Paths to fix.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: Successful fix result.
+        """
+        seen.append(tool.options["fixable"])
+        return ToolResult(name="ruff", success=True)
+
+    tool.fix.side_effect = fix
+
+    run_rule_scoped_fix(tool, ["src"], ["I001"])
+
+    assert_that(seen).is_equal_to([["I001"]])
+    assert_that(tool.options).is_equal_to({"fixable": ["F401"]})
+
+
+def test_clippy_applies_only_selected_lints(tmp_path: Path) -> None:
+    """Suggestions of other lints and overlapping suggestions are skipped.
+
+    Args:
+        tmp_path: Temporary cargo project directory.
+    """
+    source = tmp_path / "src" / "main.rs"
+    source.parent.mkdir()
+    source.write_text("fn f() -> i32 { return 1; }\n")
+    issues = parse_clippy_output(
+        "\n".join(
+            [
+                _diagnostic("clippy::needless_return", _span(16, 25, "1")),
+                # Reported again for another target
+                _diagnostic("clippy::needless_return", _span(16, 25, "1")),
+                _diagnostic("clippy::needless_return", _span(23, 24, "2")),
+                _diagnostic("clippy::other_lint", _span(0, 2, "pub fn")),
+                "Compiling app v0.1.0",
+            ],
+        ),
+    )
+
+    assert_that(_suggested_edits(issues, {"clippy::needless_return"})).is_equal_to(
+        {"src/main.rs": [(16, 25, "1")]},
+    )
+
+    _apply_suggestions(tmp_path, issues, {"clippy::needless_return"})
+
+    assert_that(source.read_text()).is_equal_to("fn f() -> i32 { 1 }\n")
@@ -0,0 +1,166 @@
+"""Tests for the run history of check runs."""
+
+from __future__ import annotations
+
+import datetime
+import sqlite3
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_execution_config
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution import run_history
+from lintro.utils.execution.run_history import (
+    IssueCount,
+    Regression,
+    count_issues,
+    find_regressions,
+    load_runs,
+    record_run,
+)
+
+
+class _FakeVcs:
+    """VCS provider with a fixed checked-out commit."""
+
+    def head_commit(self) -> str:
+        """Return the checked-out commit.
+
+        Returns:
+            str: Commit id.
+        """
+        return "abc1234def"
+
+
+@pytest.fixture(autouse=True)
+def fixed_commit(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Record every run on the same commit.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(run_history, "detect_vcs", lambda: _FakeVcs())
+
+
+def _result(name: str, codes: list[str], skipped: bool = False) -> ToolResult:
+    """Build the result of a tool that found one issue per code.
+
+    Args:
+        name: Tool name.
+        codes: Codes of the issues; ``E`` codes are errors.
+        skipped: Whether the tool was skipped.
+
+    Returns:
+        ToolResult: The result.
+    """
+    issues = [
+        RuffIssue(
+            file="app.py",
+            line=1,
+            message="issue",
+            code=code,
+            severity_override=(
+                SeverityLevel.ERROR if code.startswith("E") else SeverityLevel.WARNING
+            ),
+        )
+        for code in codes
+    ]
+    return ToolResult(
+        name=name,
+        success=not issues,
+        issues_count=len(issues),
+        issues=issues,
+        skipped=skipped,
+        skip_reason="not installed" if skipped else None,
+    )
+
+
+def test_count_issues_per_rule_and_severity() -> None:
+    """Issues are counted per tool, rule and severity."""
+    counts = count_issues([_result("ruff", ["F401", "F401", "E501"])])
+
+    assert_that(counts).is_equal_to(
+        [
+            IssueCount("ruff", "E501", "error", 1),
+            IssueCount("ruff", "F401", "warning", 2),
+        ],
+    )
+
+
+def test_record_and_load_runs(tmp_path: Path) -> None:
+    """Recorded runs read back oldest first, with clean tools counted as 0.
+
+    Args:
+        tmp_path: Temporary directory for the database.
+    """
+    db = tmp_path / "history" / "runs.db"
+
+    record_run(db, [_result("ruff", ["F401"]), _result("mypy", [])], exit_code=1)
+    record_run(db, [_result("ruff", []), _result("taplo", [], skipped=True)], 0)
+
+    runs = load_runs(db)
+    assert_that([r.exit_code for r in runs]).is_equal_to([1, 0])
+    assert_that(runs[0].commit).is_equal_to("abc1234def")
+    assert_that(runs[0].tools).is_equal_to({"mypy": 0, "ruff": 1})
+    assert_that(runs[1].tools).is_equal_to({"ruff": 0})
+    assert_that(runs[0].totals("rule")).is_equal_to({"ruff:F401": 1})
+    assert_that(load_runs(db, limit=1)[0].id).is_equal_to(runs[1].id)
+
+
+def test_load_runs_since(tmp_path: Path) -> None:
+    """Runs that finished before --since are left out.
+
+    Args:
+        tmp_path: Temporary directory for the database.
+    """
+    db = tmp_path / "runs.db"
+    record_run(db, [_result("ruff", [])], exit_code=0)
+    with sqlite3.connect(db) as connection:
+        connection.execute("UPDATE runs SET timestamp = '2026-01-01T00:00:00Z'")
+    record_run(db, [_result("ruff", ["F401"])], exit_code=1)
+
+    runs = load_runs(db, since=datetime.datetime(2026, 6, 1))
+
+    assert_that([r.exit_code for r in runs]).is_equal_to([1])
+
+
+def test_load_runs_without_database(tmp_path: Path) -> None:
+    """Reading a history that was never recorded fails.
+
+    Args:
+        tmp_path: Temporary directory without a database.
+    """
+    with pytest.raises(FileNotFoundError, match="No run history"):
+        load_runs(tmp_path / "runs.db")
+
+
+def test_find_regressions(tmp_path: Path) -> None:
+    """Only what grew since the previous run is a regression.
+
+    Args:
+        tmp_path: Temporary directory for the database.
+    """
+    db = tmp_path / "runs.db"
+    record_run(db, [_result("ruff", ["F401"]), _result("mypy", ["E1"])], 1)
+    record_run(db, [_result("ruff", ["F401", "F841", "E501"]), _result("mypy", [])], 1)
+
+    runs = load_runs(db)
+
+    assert_that(find_regressions(runs)).is_equal_to([Regression("ruff", 1, 3)])
+    assert_that(find_regressions(runs, group_by="severity")).is_equal_to(
+        [Regression("warning", 1, 2)],
+    )
+    assert_that(find_regressions(runs[:1])).is_empty()
+
+
+def test_history_setting_is_opt_in() -> None:
+    """Run history is off unless execution.history names a file."""
+    assert_that(_parse_execution_config({}).history).is_none()
+    assert_that(_parse_execution_config({"history": False}).history).is_none()
+    assert_that(
+        _parse_execution_config({"history": ".lintro/history.db"}).history,
+    ).is_equal_to(".lintro/history.db")
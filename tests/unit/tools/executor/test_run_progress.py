@@ -0,0 +1,128 @@
+"""Tests for the live progress display of tool runs."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+from assertpy import assert_that
+from rich.console import Console
+
+from lintro.enums.tool_state import ToolState
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution import run_progress
+from lintro.utils.execution.run_progress import (
+    RunProgress,
+    live_progress,
+    progress_enabled,
+)
+
+
+class FakeClock:
+    """Monotonic clock the tests advance by hand.
+
+    Attributes:
+        now: Current time in seconds.
+    """
+
+    def __init__(self) -> None:
+        """Start the clock at zero."""
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        """Read the clock.
+
+        Returns:
+            float: Current time in seconds.
+        """
+        return self.now
+
+
+def _render(progress: RunProgress) -> str:
+    """Render the progress table as plain text.
+
+    Args:
+        progress: Progress to render.
+
+    Returns:
+        str: Rendered table.
+    """
+    console = Console(file=io.StringIO(), width=80, color_system=None)
+    console.print(progress)
+    return console.file.getvalue()  # type: ignore[attr-defined]
+
+
+def test_tools_move_from_queued_to_done() -> None:
+    """Tools report elapsed time while running and counts when done."""
+    clock = FakeClock()
+    progress = RunProgress(["ruff", "mypy"], {"ruff": 120, "mypy": 80}, clock=clock)
+
+    progress.start("ruff")
+    clock.now = 2.5
+    running = _render(progress)
+    progress.finish("ruff", ToolResult(name="ruff", success=False, issues_count=4))
+
+    assert_that(running).contains("running", "2.5s", "queued")
+    assert_that(progress.tools["ruff"].state).is_equal_to(ToolState.DONE)
+    assert_that(progress.tools["ruff"].issues).is_equal_to(4)
+    assert_that(progress.file_progress()).is_equal_to((120, 200))
+    assert_that(_render(progress)).contains("1/2 tools done, 120/200 files")
+
+
+def test_failure_without_issues_is_an_error() -> None:
+    """A tool that failed without reporting issues is marked failed."""
+    progress = RunProgress(["ruff", "black"])
+
+    progress.finish("ruff", ToolResult(name="ruff", success=False))
+    progress.finish(
+        "black",
+        ToolResult(name="black", skipped=True, skip_reason="not installed"),
+    )
+
+    assert_that(progress.tools["ruff"].state).is_equal_to(ToolState.FAILED)
+    assert_that(progress.tools["black"].state).is_equal_to(ToolState.SKIPPED)
+    assert_that(progress.file_progress()).is_none()
+    assert_that(_render(progress)).contains("2/2 tools done").does_not_contain(
+        "files",
+    )
+
+
+@pytest.mark.parametrize(
+    ("tty", "output_format", "expected"),
+    [
+        (True, "grid", True),
+        (True, "json", False),
+        (False, "grid", False),
+    ],
+)
+def test_progress_enabled_only_on_terminals(
+    monkeypatch: pytest.MonkeyPatch,
+    tty: bool,
+    output_format: str,
+    expected: bool,
+) -> None:
+    """The display is off for pipes, CI logs and JSON output.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        tty: Whether stdout is a terminal.
+        output_format: Output format of the run.
+        expected: Whether progress is shown.
+    """
+    monkeypatch.setattr(run_progress.sys.stdout, "isatty", lambda: tty)
+
+    assert_that(progress_enabled(output_format)).is_equal_to(expected)
+
+
+def test_live_progress_without_progress_shows_nothing(
+    capsys: pytest.CaptureFixture[str],
+) -> None:
+    """Without progress the block simply runs.
+
+    Args:
+        capsys: Pytest capture fixture.
+    """
+    with live_progress(None):
+        pass
+
+    assert_that(capsys.readouterr().out).is_empty()
@@ -0,0 +1,86 @@
+"""Tests for ordering check runs by priority and recorded runtime."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.execution.fail_fast import cancelled_result
+from lintro.utils.execution.scheduling import (
+    load_runtimes,
+    record_runtimes,
+    schedule_tools,
+)
+
+PRIORITIES = {"ruff": 20, "typos": 60}
+
+
+def _priority(name: str) -> int:
+    """Look up a tool's priority, 50 unless listed.
+
+    Args:
+        name: Tool name.
+
+    Returns:
+        int: Priority of the tool.
+    """
+    return PRIORITIES.get(name, 50)
+
+
+def _timed(name: str, duration_ms: int) -> ToolResult:
+    """Build a result of a tool that took some time.
+
+    Args:
+        name: Tool name.
+        duration_ms: Time the tool took.
+
+    Returns:
+        ToolResult: Clean result with the duration.
+    """
+    return ToolResult(name=name, success=True, duration_ms=duration_ms)
+
+
+def test_schedule_orders_by_priority_then_runtime() -> None:
+    """Quick tools run by priority, the quicker first; slow tools run last."""
+    schedule = schedule_tools(
+        ["clippy", "typos", "shellcheck", "hadolint", "cargo_deny", "ruff"],
+        {"clippy": 95_000, "cargo_deny": 30_000, "shellcheck": 900, "hadolint": 200},
+        priority=_priority,
+        slow_ms=10_000,
+    )
+
+    assert_that(schedule.quick).is_equal_to(["ruff", "hadolint", "shellcheck", "typos"])
+    assert_that(schedule.slow).is_equal_to(["cargo_deny", "clippy"])
+    assert_that(schedule.order).is_equal_to([*schedule.quick, *schedule.slow])
+
+
+def test_record_runtimes_keeps_a_moving_average(tmp_path: Path) -> None:
+    """Each run moves a tool's runtime halfway towards its latest one.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    path = tmp_path / ".lintro" / "runtimes.json"
+
+    record_runtimes(path, [_timed("clippy", 60_000), _timed("ruff", 300)])
+    record_runtimes(path, [_timed("clippy", 20_000), cancelled_result("ruff")])
+
+    assert_that(load_runtimes(path)).is_equal_to({"clippy": 40_000, "ruff": 300})
+
+
+def test_load_runtimes_ignores_unreadable_files(tmp_path: Path) -> None:
+    """A missing or corrupt file means no tool has been timed yet.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    path = tmp_path / "runtimes.json"
+    assert_that(load_runtimes(path)).is_empty()
+
+    path.write_text("{not json")
+    assert_that(load_runtimes(path)).is_empty()
+
+    path.write_text('{"ruff": 120, "mypy": "slow", "bandit": -1}')
+    assert_that(load_runtimes(path)).is_equal_to({"ruff": 120})
@@ -0,0 +1,172 @@
+"""Tests for severity overrides and the output.min_severity result filter."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import (
+    _convert_pyproject_to_config,
+    build_config_from_data,
+)
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.clippy.clippy_issue import ClippyIssue
+from lintro.parsers.pyproject.pyproject_issue import PyprojectIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.severity_filter import (
+    apply_min_severity,
+    apply_severity_overrides,
+)
+
+
+def _result(*levels: str) -> ToolResult:
+    """Build a failed check result with one issue per severity level.
+
+    Args:
+        *levels: Severity level of each issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues: list[BaseIssue] = [
+        PyprojectIssue(file="pyproject.toml", level=level, code="PP003")
+        for level in levels
+    ]
+    return ToolResult(
+        name="pyproject",
+        success=False,
+        issues_count=len(issues),
+        issues=issues,
+    )
+
+
+def test_no_threshold_returns_result_unchanged() -> None:
+    """Without a threshold every issue is kept."""
+    result = _result("warning", "info")
+
+    assert_that(apply_min_severity(result, None)).is_same_as(result)
+
+
+def test_threshold_drops_lower_severities() -> None:
+    """Issues below the threshold are removed and the count updated."""
+    filtered = apply_min_severity(_result("error", "warning", "info"), "warning")
+
+    assert_that(filtered.issues_count).is_equal_to(2)
+    assert_that(filtered.success).is_false()
+
+
+def test_result_without_remaining_issues_succeeds() -> None:
+    """A result whose issues are all filtered out is successful."""
+    filtered = apply_min_severity(_result("warning", "info"), "error")
+
+    assert_that(filtered.issues_count).is_equal_to(0)
+    assert_that(filtered.success).is_true()
+
+
+def test_overrides_replace_native_severity() -> None:
+    """Rule overrides win over the severity reported by the tool."""
+    result = ToolResult(
+        name="clippy",
+        success=False,
+        issues_count=2,
+        issues=[
+            ClippyIssue(file="a.rs", code="clippy::too_many_arguments"),
+            ClippyIssue(file="a.rs", code="clippy::needless_return"),
+        ],
+    )
+
+    remapped = apply_severity_overrides(
+        result,
+        {"clippy::too_many_arguments": "info"},
+    )
+
+    severities = [issue.get_severity() for issue in remapped.issues]
+    assert_that(severities[0]).is_equal_to(SeverityLevel.INFO)
+    assert_that(severities[1]).is_equal_to(result.issues[1].get_severity())
+    assert_that(result.issues[0].get_severity()).is_not_equal_to(SeverityLevel.INFO)
+
+
+@pytest.mark.parametrize(
+    "overrides,expected",
+    [
+        ({"E501": "error"}, SeverityLevel.ERROR),
+        ({"E501": "error", "ruff:E501": "info"}, SeverityLevel.INFO),
+        ({"mypy:E501": "error"}, SeverityLevel.WARNING),
+    ],
+)
+def test_tool_qualified_override_wins(
+    overrides: dict[str, str],
+    expected: SeverityLevel,
+) -> None:
+    """``tool:code`` keys beat bare codes and only match their own tool.
+
+    Args:
+        overrides: Configured severity overrides.
+        expected: Expected severity of a ruff E501 issue.
+    """
+    result = ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=1,
+        issues=[RuffIssue(file="a.py", code="E501")],
+    )
+
+    remapped = apply_severity_overrides(result, overrides)
+
+    assert_that(remapped.issues[0].get_severity()).is_equal_to(expected)
+
+
+def test_nested_overrides_apply_in_their_subtree(tmp_path: Path) -> None:
+    """Issues in a subtree with a nested config use its overrides.
+
+    Args:
+        tmp_path: Temporary repository root.
+    """
+    result = ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=2,
+        issues=[
+            RuffIssue(file=str(tmp_path / "legacy" / "a.py"), code="E501"),
+            RuffIssue(file=str(tmp_path / "src" / "a.py"), code="E501"),
+        ],
+    )
+
+    remapped = apply_severity_overrides(
+        result,
+        {"E501": "error"},
+        [(tmp_path / "legacy", {"E501": "info"})],
+    )
+
+    assert_that([issue.get_severity() for issue in remapped.issues]).is_equal_to(
+        [SeverityLevel.INFO, SeverityLevel.ERROR],
+    )
+
+
+def test_downgraded_rules_fall_below_threshold() -> None:
+    """Remapped severities decide which issues the threshold drops."""
+    result = apply_severity_overrides(_result("error", "warning"), {"PP003": "info"})
+
+    filtered = apply_min_severity(result, "warning")
+
+    assert_that(filtered.issues_count).is_equal_to(0)
+    assert_that(filtered.success).is_true()
+
+
+def test_severity_section_is_loaded() -> None:
+    """Overrides load from YAML and pyproject layouts, case-insensitively."""
+    config = build_config_from_data({"severity": {"E501": "ERROR"}})
+    converted = _convert_pyproject_to_config(
+        {"severity": {"clippy::too_many_arguments": "info"}},
+    )
+
+    assert_that(config.severity).is_equal_to({"E501": "error"})
+    assert_that(converted["severity"]).is_equal_to(
+        {"clippy::too_many_arguments": "info"},
+    )
+    with pytest.raises(ValueError):
+        build_config_from_data({"severity": {"E501": "fatal"}})
@@ -0,0 +1,165 @@
+"""Tests for splitting a check run across CI jobs with --shard."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_hierarchy import ToolScopePlan
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.execution import sharding
+from lintro.utils.execution.sharding import (
+    Shard,
+    apply_shard_scopes,
+    parse_shard,
+    shard_key,
+)
+from lintro.utils.execution.tool_configuration import ToolsToRunResult
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Create a project with twenty Python files and enter it.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: Project directory.
+    """
+    for i in range(20):
+        (tmp_path / f"mod_{i}.py").write_text("x = 1\n")
+    monkeypatch.chdir(tmp_path)
+    return tmp_path
+
+
+@pytest.fixture
+def tools(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Register a per-file tool (ruff) and a whole-project one (mypy).
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    definitions = {
+        "ruff": ToolDefinition(name="ruff", description="", file_patterns=["*.py"]),
+        "mypy": ToolDefinition(
+            name="mypy",
+            description="",
+            file_patterns=["*.py"],
+            per_file=False,
+        ),
+    }
+    manager = SimpleNamespace(
+        get_tool=lambda name: SimpleNamespace(definition=definitions[name]),
+    )
+    monkeypatch.setattr(sharding, "tool_manager", manager)
+
+
+def _run_shard(shard: Shard) -> tuple[ToolsToRunResult, dict[str, ToolScopePlan]]:
+    """Apply a shard to a run of ruff and mypy on the working directory.
+
+    Args:
+        shard: Shard to apply.
+
+    Returns:
+        tuple[ToolsToRunResult, dict[str, ToolScopePlan]]: Selection and
+        scopes after sharding.
+    """
+    result = ToolsToRunResult(to_run=["ruff", "mypy"])
+    scopes: dict[str, ToolScopePlan] = {}
+    apply_shard_scopes(result, ["."], scopes, shard)
+    return result, scopes
+
+
+def test_parse_shard() -> None:
+    """I/N values are parsed and printed back the same way."""
+    shard = parse_shard(" 2/4 ")
+
+    assert_that(shard).is_equal_to(Shard(index=2, count=4))
+    assert_that(str(shard)).is_equal_to("2/4")
+
+
+@pytest.mark.parametrize(
+    ("value", "message"),
+    [
+        ("2", "must look like I/N"),
+        ("two/four", "must look like I/N"),
+        ("0/4", "between 1 and 4"),
+        ("5/4", "between 1 and 4"),
+    ],
+)
+def test_parse_shard_rejects_invalid_values(value: str, message: str) -> None:
+    """Malformed and out-of-range shards are rejected.
+
+    Args:
+        value: Invalid shard.
+        message: Expected part of the error.
+    """
+    with pytest.raises(ValueError, match=message):
+        parse_shard(value)
+
+
+def test_every_key_belongs_to_exactly_one_shard() -> None:
+    """The shards of a run split the keys without overlap or gaps."""
+    shards = [Shard(index=i, count=3) for i in range(1, 4)]
+
+    for i in range(50):
+        owners = [s for s in shards if s.owns(f"src/mod_{i}.py")]
+        assert_that(owners).is_length(1)
+
+
+def test_shard_key_is_relative(project: Path) -> None:
+    """Files are keyed by their path relative to the working directory.
+
+    Args:
+        project: Project directory.
+    """
+    assert_that(shard_key(str(project / "mod_1.py"))).is_equal_to("mod_1.py")
+
+
+@pytest.mark.usefixtures("tools")
+def test_shards_split_files_of_per_file_tools(project: Path) -> None:
+    """Each per-file tool checks its share of the files in each shard.
+
+    Args:
+        project: Project directory.
+    """
+    checked: list[str] = []
+    for index in (1, 2):
+        result, scopes = _run_shard(Shard(index=index, count=2))
+        if "ruff" in result.to_run:
+            checked.extend(scopes["ruff"].paths)
+
+    expected = sorted(str(project / f"mod_{i}.py") for i in range(20))
+    assert_that(sorted(checked)).is_equal_to(expected)
+
+
+@pytest.mark.usefixtures("tools", "project")
+def test_whole_project_tools_run_in_one_shard() -> None:
+    """A whole-project tool runs unsplit in one shard and is skipped in others."""
+    results = [_run_shard(Shard(index=i, count=3))[0] for i in (1, 2, 3)]
+
+    running = [r for r in results if "mypy" in r.to_run]
+    assert_that(running).is_length(1)
+    skipped = [s for r in results for s in r.skipped if s.name == "mypy"]
+    assert_that(skipped).is_length(2)
+    assert_that(skipped[0].reason).is_equal_to("runs in another shard")
+
+
+@pytest.mark.usefixtures("tools")
+def test_tools_without_files_in_shard_are_skipped(tmp_path: Path) -> None:
+    """A per-file tool with nothing to check in a shard is skipped there.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    result = ToolsToRunResult(to_run=["ruff"])
+
+    apply_shard_scopes(result, [str(tmp_path)], {}, Shard(index=1, count=2))
+
+    assert_that(result.to_run).is_empty()
+    assert_that(result.skipped[0].reason).is_equal_to("no files in shard 1/2")
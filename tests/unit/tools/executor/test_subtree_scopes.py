@@ -0,0 +1,100 @@
+"""Tests for running the subtrees that nested configs give their own settings."""
+
+from __future__ import annotations
+
+from types import SimpleNamespace
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.config.config_hierarchy import ToolScopePlan
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.tool_configuration import run_subtree_scopes
+
+
+def _result(*files: str) -> ToolResult:
+    """Build a failed ruff result with one issue per file.
+
+    Args:
+        *files: File of each issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    issues = [RuffIssue(file=file, line=1, code="E501") for file in files]
+    return ToolResult(
+        name="ruff",
+        success=not issues,
+        issues_count=len(issues),
+        issues=issues,
+    )
+
+
+def test_subtrees_run_with_their_own_scope_and_merge() -> None:
+    """Each subtree is configured and run, and the results are combined."""
+    tool: Any = SimpleNamespace(
+        definition=SimpleNamespace(name="ruff"),
+        exclude_patterns=["node_modules/", "repo/legacy/"],
+    )
+    subtree = ToolScopePlan(paths=["repo/legacy"], exclude_patterns=["repo/legacy/x/"])
+    main = ToolScopePlan(
+        paths=["repo"],
+        exclude_patterns=["repo/legacy/"],
+        subtrees=[subtree],
+    )
+    configured: list[ToolScopePlan] = []
+    ran: list[list[str]] = []
+
+    def configure(scope: ToolScopePlan) -> None:
+        """Record the scope and apply its excludes.
+
+        Args:
+            scope: Scope the tool is configured for.
+        """
+        configured.append(scope)
+        tool.exclude_patterns.extend(scope.exclude_patterns)
+
+    def run(paths: list[str]) -> ToolResult:
+        """Record the paths and report one issue in the subtree.
+
+        Args:
+            paths: Paths to check.
+
+        Returns:
+            ToolResult: Result of the subtree.
+        """
+        ran.append(list(paths))
+        return _result("repo/legacy/a.py")
+
+    merged = run_subtree_scopes(
+        tool,
+        _result("repo/a.py"),
+        main,
+        configure=configure,
+        run=run,
+    )
+
+    assert_that(configured).is_equal_to([subtree])
+    assert_that(ran).is_equal_to([["repo/legacy"]])
+    assert_that(tool.exclude_patterns).is_equal_to(["node_modules/", "repo/legacy/x/"])
+    assert_that(merged.issues_count).is_equal_to(2)
+    assert_that([i.file for i in merged.issues]).is_equal_to(
+        ["repo/a.py", "repo/legacy/a.py"],
+    )
+    assert_that(merged.success).is_false()
+
+
+def test_scope_without_subtrees_keeps_the_result() -> None:
+    """A plain scope returns the main result untouched."""
+    result = _result()
+
+    merged = run_subtree_scopes(
+        SimpleNamespace(exclude_patterns=[]),  # type: ignore[arg-type]
+        result,
+        ToolScopePlan(paths=["repo"]),
+        configure=lambda **_: None,
+        run=lambda paths: _result("x.py"),
+    )
+
+    assert_that(merged).is_same_as(result)
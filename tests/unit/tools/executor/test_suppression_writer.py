@@ -0,0 +1,172 @@
+"""Tests for inserting suppression comments at violation sites."""
+
+from __future__ import annotations
+
+from datetime import date
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.clippy.clippy_issue import ClippyIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.suppression_writer import (
+    collect_sites,
+    comment_style,
+    insert_suppressions,
+)
+from lintro.utils.execution.suppressions import parse_suppressions
+
+RUST = "fn main() {}\n\n    fn f(a: u8, b: u8) {}\n"
+
+
+def _results(rust: str, python: str) -> list[ToolResult]:
+    """Build clippy and ruff results flagging the test files.
+
+    Args:
+        rust: Path of the Rust file.
+        python: Path of the Python file.
+
+    Returns:
+        list[ToolResult]: The results.
+    """
+    clippy: list[BaseIssue] = [
+        ClippyIssue(file=rust, line=3, code="clippy::too_many_arguments"),
+        ClippyIssue(file=rust, line=1, code="clippy::needless_return"),
+    ]
+    ruff: list[BaseIssue] = [RuffIssue(file=python, line=1, code="E501")]
+    return [
+        ToolResult(name="clippy", success=False, issues_count=2, issues=clippy),
+        ToolResult(name="ruff", success=False, issues_count=1, issues=ruff),
+    ]
+
+
+@pytest.fixture
+def files(tmp_path: Path) -> tuple[Path, Path]:
+    """Provide a Rust file and a Python file with CRLF line endings.
+
+    Args:
+        tmp_path: Temporary directory for the files.
+
+    Returns:
+        tuple[Path, Path]: The Rust and Python files.
+    """
+    rust = tmp_path / "lib.rs"
+    rust.write_text(RUST)
+    python = tmp_path / "app.py"
+    python.write_bytes(b"x = 1\r\ny = 2\r\n")
+    return rust, python
+
+
+def test_collect_sites_matches_bare_and_qualified_rules(
+    files: tuple[Path, Path],
+) -> None:
+    """Only lines flagged by the rule are collected.
+
+    Args:
+        files: The Rust and Python files.
+    """
+    rust, python = (str(f) for f in files)
+    results = _results(rust, python)
+
+    assert_that(collect_sites(results, "clippy::too_many_arguments")).is_equal_to(
+        {rust: {3: ("clippy", "clippy::too_many_arguments")}},
+    )
+    assert_that(collect_sites(results, "ruff:E501")).is_equal_to(
+        {python: {1: ("ruff", "E501")}},
+    )
+
+
+def test_insert_lintro_suppressions_above_flagged_lines(
+    files: tuple[Path, Path],
+) -> None:
+    """lintro comments go above the line, indented, and suppress it.
+
+    Args:
+        files: The Rust and Python files.
+    """
+    rust, python = files
+    rule = "clippy::too_many_arguments"
+    sites = collect_sites(_results(str(rust), str(python)), rule)
+
+    edits = insert_suppressions(
+        sites,
+        rule,
+        until=date(2026, 6, 30),
+        reason="legacy",
+    )
+
+    assert_that(edits.inserted).is_equal_to([f"{rust}:3"])
+    assert_that(rust.read_text()).is_equal_to(
+        "fn main() {}\n\n"
+        "    // lintro: ignore[clippy::too_many_arguments] until=2026-06-30 "
+        'reason="legacy"\n'
+        "    fn f(a: u8, b: u8) {}\n",
+    )
+    assert_that(parse_suppressions(str(rust))[0].target_line).is_equal_to(4)
+
+
+def test_insert_native_suppressions_keeps_line_endings(
+    files: tuple[Path, Path],
+) -> None:
+    """Native comments use the tool's syntax and keep CRLF endings.
+
+    Args:
+        files: The Rust and Python files.
+    """
+    rust, python = files
+    sites = collect_sites(_results(str(rust), str(python)), "E501")
+
+    insert_suppressions(sites, "E501", native=True)
+
+    assert_that(python.read_bytes()).is_equal_to(b"x = 1  # noqa: E501\r\ny = 2\r\n")
+
+
+def test_insert_suppressions_dry_run_and_skips(tmp_path: Path) -> None:
+    """Dry runs change nothing; unknown syntaxes are skipped.
+
+    Args:
+        tmp_path: Temporary directory for the files.
+    """
+    data = tmp_path / "data.unknown"
+    data.write_text("value\n")
+    rust = tmp_path / "lib.rs"
+    rust.write_text(RUST)
+    sites = {
+        str(data): {1: ("ruff", "E501")},
+        str(rust): {1: ("rustfmt", "format")},
+    }
+
+    lintro_edits = insert_suppressions(sites, "E501", dry_run=True)
+    native_edits = insert_suppressions(sites, "E501", native=True)
+
+    assert_that(lintro_edits.inserted).is_equal_to([f"{rust}:1"])
+    assert_that(lintro_edits.skipped).is_equal_to(
+        [(f"{data}:1", "unknown comment syntax")],
+    )
+    assert_that(native_edits.skipped).contains(
+        (f"{rust}:1", "rustfmt has no native syntax"),
+    )
+    assert_that(rust.read_text()).is_equal_to(RUST)
+
+
+@pytest.mark.parametrize(
+    ("path", "expected"),
+    [
+        ("src/app.py", ("# ", "")),
+        ("web/App.TSX", ("// ", "")),
+        ("docker/Dockerfile.dev", ("# ", "")),
+        ("styles/site.css", ("/* ", " */")),
+        ("README", None),
+    ],
+)
+def test_comment_style(path: str, expected: tuple[str, str] | None) -> None:
+    """Comment delimiters follow the file type.
+
+    Args:
+        path: Path of the file.
+        expected: Expected delimiters.
+    """
+    assert_that(comment_style(path)).is_equal_to(expected)
@@ -0,0 +1,154 @@
+"""Tests for inline ``lintro: ignore`` suppressions with expiry dates."""
+
+from __future__ import annotations
+
+from datetime import date
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.suppressions import (
+    Suppression,
+    apply_suppressions,
+    expiring_suppressions,
+    parse_suppressions,
+)
+
+TODAY = date(2025, 6, 1)
+
+SOURCE = """\
+import os  # lintro: ignore[F401] until=2025-12-31 reason="migration"
+import sys  # lintro: ignore[ruff:F401] until=2025-01-31
+# lintro: ignore
+x = 1
+y = 2  # lintro: ignore[E501] until=tomorrow
+"""
+
+
+@pytest.fixture
+def source(tmp_path: Path) -> str:
+    """Provide a file with suppression comments.
+
+    Args:
+        tmp_path: Temporary directory for the file.
+
+    Returns:
+        str: Path of the file.
+    """
+    path = tmp_path / "app.py"
+    path.write_text(SOURCE)
+    return str(path)
+
+
+def _result(source: str, *issues: tuple[int, str]) -> ToolResult:
+    """Build a failed ruff result with issues in the source file.
+
+    Args:
+        source: Path of the file.
+        *issues: Line and rule code of each issue.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    parsed: list[BaseIssue] = [
+        RuffIssue(file=source, line=line, code=code, message="problem")
+        for line, code in issues
+    ]
+    return ToolResult(
+        name="ruff",
+        success=False,
+        issues_count=len(parsed),
+        issues=parsed,
+    )
+
+
+def test_parse_suppressions_reads_rules_and_options(source: str) -> None:
+    """Rules, expiry and reason are read; invalid dates are skipped.
+
+    Args:
+        source: Path of the file.
+    """
+    assert_that(parse_suppressions(source)).is_equal_to(
+        [
+            Suppression(
+                file=source,
+                line=1,
+                target_line=1,
+                rules=("F401",),
+                until=date(2025, 12, 31),
+                reason="migration",
+            ),
+            Suppression(
+                file=source,
+                line=2,
+                target_line=2,
+                rules=("ruff:F401",),
+                until=date(2025, 1, 31),
+            ),
+            Suppression(file=source, line=3, target_line=4),
+        ],
+    )
+
+
+def test_apply_suppressions_drops_active_suppressions(source: str) -> None:
+    """Issues under an active suppression are removed from the result.
+
+    Args:
+        source: Path of the file.
+    """
+    result = apply_suppressions(
+        _result(source, (1, "F401"), (1, "E501"), (4, "E225"), (5, "E501")),
+        today=TODAY,
+    )
+
+    assert_that([(i.line, i.code) for i in result.issues]).is_equal_to(
+        [(1, "E501"), (5, "E501")],
+    )
+    assert_that(result.issues_count).is_equal_to(2)
+
+
+def test_expired_suppression_reports_an_error(source: str) -> None:
+    """Issues of an expired suppression return as errors naming its date.
+
+    Args:
+        source: Path of the file.
+    """
+    result = apply_suppressions(_result(source, (2, "F401")), today=TODAY)
+
+    issue = result.issues[0]
+    assert_that(issue.get_severity()).is_equal_to(SeverityLevel.ERROR)
+    assert_that(issue.to_display_row()["message"]).is_equal_to(
+        "problem (suppression expired 2025-01-31)",
+    )
+
+
+def test_apply_suppressions_without_match_returns_result(source: str) -> None:
+    """Results no suppression applies to are returned unchanged.
+
+    Args:
+        source: Path of the file.
+    """
+    result = _result(source, (5, "E501"))
+
+    assert_that(apply_suppressions(result, today=TODAY)).is_same_as(result)
+
+
+def test_expiring_suppressions_lists_soonest_first(source: str) -> None:
+    """Expired and soon-expiring suppressions are listed by expiry date.
+
+    Args:
+        source: Path of the file.
+    """
+    suppressions = parse_suppressions(source)
+
+    assert_that(
+        [s.line for s in expiring_suppressions(suppressions, 30, TODAY)],
+    ).is_equal_to([2])
+    assert_that(
+        [s.line for s in expiring_suppressions(suppressions, 365, TODAY)],
+    ).is_equal_to([2, 1])
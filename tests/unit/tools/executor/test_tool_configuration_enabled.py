@@ -11,7 +11,8 @@ from assertpy import assert_that
 from lintro.config.config_loader import clear_config_cache
 from lintro.config.execution_config import ExecutionConfig
 from lintro.config.lintro_config import LintroConfig, LintroToolConfig
-from lintro.utils.execution.tool_configuration import get_tools_to_run
+from lintro.plugins.protocol import ToolCapabilities
+from lintro.utils.execution.tool_configuration import SkippedTool, get_tools_to_run
 
 # =============================================================================
 # Fixtures
@@ -39,6 +40,9 @@ class _FakeTool:
     def definition(self) -> _FakeToolDefinition:
         return self._definition
 
+    def capabilities(self) -> ToolCapabilities:
+        return ToolCapabilities(supports_fix=self._definition.can_fix)
+
     def set_options(self, **kwargs: Any) -> None:
         pass
 
@@ -402,3 +406,41 @@ def test_disabled_tool_case_insensitive(
     assert_that(result.to_run).is_equal_to(["ruff"])
     assert_that(result.to_run).does_not_contain("MyPy")
     assert_that(result.to_run).does_not_contain("mypy")
+
+
+# =============================================================================
+# Opt-in tools
+# =============================================================================
+
+
+def test_opt_in_tool_skipped_unless_configured(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Opt-in tools only run when config lists them."""
+    from lintro.tools import tool_manager
+
+    monkeypatch.setattr(
+        tool_manager,
+        "get_check_tools",
+        lambda: ["ruff", "cargo_outdated"],
+    )
+
+    with patch(
+        "lintro.utils.execution.tool_configuration.get_config",
+        return_value=LintroConfig(),
+    ):
+        result = get_tools_to_run(tools=None, action="check")
+
+    assert_that(result.to_run).is_equal_to(["ruff"])
+    assert_that(result.skipped).is_equal_to(
+        [SkippedTool(name="cargo_outdated", reason="not enabled by default")],
+    )
+
+    config = LintroConfig(tools={"cargo_outdated": LintroToolConfig()})
+    with patch(
+        "lintro.utils.execution.tool_configuration.get_config",
+        return_value=config,
+    ):
+        result = get_tools_to_run(tools=None, action="check")
+
+    assert_that(result.to_run).is_equal_to(["ruff", "cargo_outdated"])
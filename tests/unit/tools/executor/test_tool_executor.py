@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 import json
+import time
 from dataclasses import dataclass, field
 from pathlib import Path
 from typing import Any, Never
@@ -26,7 +27,9 @@ class FakeToolDefinition:
     can_fix: bool = False
     description: str = ""
     file_patterns: list[str] = field(default_factory=list)
+    shebang_interpreters: list[str] = field(default_factory=list)
     native_configs: list[str] = field(default_factory=list)
+    native: bool = False
 
 
 class FakeTool:
@@ -132,6 +135,7 @@ def _setup_tool_manager(
     def noop_write_reports_from_results(
         self: Any,
         results: list[ToolResult],
+        **kwargs: object,
     ) -> None:
         return None
 
@@ -184,6 +188,93 @@ def test_executor_check_success(
         raw_output=False,
     )
     assert_that(code).is_equal_to(0)
+    assert_that(result.duration_ms).is_not_none()
+
+
+def test_executor_max_files_aborts_before_running_tools(
+    monkeypatch: pytest.MonkeyPatch,
+    fake_logger: Any,
+    capsys: pytest.CaptureFixture[str],
+    tmp_path: Path,
+) -> None:
+    """--max-files stops the run when discovery finds too many files.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        fake_logger: FakeLogger fixture.
+        capsys: Pytest capture fixture for stdout.
+        tmp_path: Temporary project.
+    """
+    _stub_logger(monkeypatch, fake_logger)
+    monkeypatch.chdir(tmp_path)
+    for index in range(3):
+        (tmp_path / f"module{index}.py").write_text("")
+    result = ToolResult(name="ruff", success=True, output="", issues_count=0)
+    tool = FakeTool("ruff", can_fix=True, result=result)
+    tool.definition.file_patterns = ["*.py"]
+    _setup_tool_manager(monkeypatch, {"ruff": tool})
+
+    code = run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools="all",
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by="auto",
+        output_format="json",
+        verbose=False,
+        raw_output=False,
+        max_files=2,
+    )
+
+    assert_that(code).is_equal_to(1)
+    assert_that(result.duration_ms).is_none()
+    error = json.loads(capsys.readouterr().out)["error"]
+    assert_that(error["code"]).is_equal_to("LINTRO013")
+    assert_that(error["message"]).contains("By tool: ruff 3")
+
+
+def test_executor_confirm_over_without_terminal_aborts_unless_yes(
+    monkeypatch: pytest.MonkeyPatch,
+    fake_logger: Any,
+    tmp_path: Path,
+) -> None:
+    """--confirm-over aborts when nobody can answer, and --yes proceeds.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        fake_logger: FakeLogger fixture.
+        tmp_path: Temporary project.
+    """
+    _stub_logger(monkeypatch, fake_logger)
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr("sys.stdin.isatty", lambda: False)
+    (tmp_path / "a.py").write_text("")
+    (tmp_path / "b.py").write_text("")
+    result = ToolResult(name="ruff", success=True, output="", issues_count=0)
+    tool = FakeTool("ruff", can_fix=True, result=result)
+    tool.definition.file_patterns = ["*.py"]
+    _setup_tool_manager(monkeypatch, {"ruff": tool})
+    options: dict[str, Any] = {
+        "action": "check",
+        "paths": ["."],
+        "tools": "all",
+        "tool_options": None,
+        "exclude": None,
+        "include_venv": False,
+        "group_by": "auto",
+        "output_format": "grid",
+        "verbose": False,
+        "raw_output": False,
+        "confirm_over": 1,
+    }
+
+    assert_that(run_lint_tools_simple(**options)).is_equal_to(1)
+    assert_that(result.duration_ms).is_none()
+
+    assert_that(run_lint_tools_simple(**options, yes=True)).is_equal_to(0)
+    assert_that(result.duration_ms).is_not_none()
 
 
 def test_executor_check_failure(
@@ -400,3 +491,211 @@ def test_executor_unknown_tool(
         raw_output=False,
     )
     assert_that(code).is_equal_to(1)
+
+
+def test_executor_unknown_tool_json_reports_error_code(
+    monkeypatch: pytest.MonkeyPatch,
+    fake_logger: Any,
+    capsys: pytest.CaptureFixture[str],
+) -> None:
+    """JSON output reports an unknown tool as an error object with its code.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        fake_logger: Fake logger fixture.
+        capsys: Pytest capture fixture for stdout.
+    """
+    _stub_logger(monkeypatch, fake_logger)
+
+    def raise_value_error(tools: str | None, action: str) -> Never:
+        raise ValueError("unknown tool")
+
+    monkeypatch.setattr(te, "get_tools_to_run", raise_value_error, raising=True)
+    run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools="unknown",
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by="auto",
+        output_format="json",
+        verbose=False,
+        raw_output=False,
+    )
+    error = json.loads(capsys.readouterr().out)["error"]
+    assert_that(error["code"]).is_equal_to("LINTRO002")
+    assert_that(error["message"]).is_equal_to("unknown tool")
+
+
+def test_executor_fail_fast_cancels_remaining_tools(
+    monkeypatch: pytest.MonkeyPatch,
+    fake_logger: Any,
+    capsys: pytest.CaptureFixture[str],
+) -> None:
+    """Skip the tools after the first failing one and mark the run partial.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        fake_logger: Fake logger fixture.
+        capsys: Pytest capture fixture for stdout.
+    """
+    import lintro.config.config_loader as config_loader
+    from lintro.config.execution_config import ExecutionConfig
+    from lintro.config.lintro_config import LintroConfig
+
+    config = LintroConfig(execution=ExecutionConfig(parallel=False))
+    monkeypatch.setattr(config_loader, "get_config", lambda: config)
+    _stub_logger(monkeypatch, fake_logger)
+    failing = ToolResult(name="ruff", success=False, output="", issues_count=2)
+    clean = ToolResult(name="prettier", success=True, output="", issues_count=0)
+    prettier = FakeTool("prettier", can_fix=True, result=clean)
+    checked: list[str] = []
+    monkeypatch.setattr(
+        prettier,
+        "check",
+        lambda paths, options=None: checked.append("prettier") or clean,
+    )
+    _setup_tool_manager(
+        monkeypatch,
+        {"ruff": FakeTool("ruff", can_fix=True, result=failing), "prettier": prettier},
+    )
+    code = run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools="all",
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by="auto",
+        output_format="json",
+        verbose=False,
+        raw_output=False,
+        fail_fast=True,
+    )
+    assert_that(code).is_equal_to(1)
+    assert_that(checked).is_empty()
+    data = json.loads(capsys.readouterr().out)
+    assert_that(data["summary"]["partial"]).is_true()
+    skipped = {r["tool"]: r["skip_reason"] for r in data["results"] if r["skipped"]}
+    assert_that(skipped).is_equal_to({"prettier": "cancelled by --fail-fast"})
+
+
+def test_executor_profile_timing_adds_timing_to_json(
+    monkeypatch: pytest.MonkeyPatch,
+    fake_logger: Any,
+    capsys: pytest.CaptureFixture[str],
+) -> None:
+    """Record each tool's subprocesses in the JSON timing profile.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        fake_logger: Fake logger fixture.
+        capsys: Pytest capture fixture for stdout.
+    """
+    import lintro.config.config_loader as config_loader
+    from lintro.config.execution_config import ExecutionConfig
+    from lintro.config.lintro_config import LintroConfig
+    from lintro.utils.timing_profile import record_invocation
+
+    config = LintroConfig(execution=ExecutionConfig(parallel=False))
+    monkeypatch.setattr(config_loader, "get_config", lambda: config)
+    _stub_logger(monkeypatch, fake_logger)
+    clean = ToolResult(name="ruff", success=True, output="", issues_count=0)
+    ruff = FakeTool("ruff", can_fix=True, result=clean)
+
+    def check(paths: list[str], options: dict[str, object] | None = None) -> Any:
+        """Run one fake subprocess and return a clean result.
+
+        Args:
+            paths: Paths to check.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: The clean result.
+        """
+        record_invocation(["ruff", "check", "."], None, time.monotonic(), 25)
+        return clean
+
+    monkeypatch.setattr(ruff, "check", check)
+    _setup_tool_manager(monkeypatch, {"ruff": ruff})
+    code = run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools="ruff",
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by="auto",
+        output_format="json",
+        verbose=False,
+        raw_output=False,
+        profile_timing=True,
+    )
+    assert_that(code).is_equal_to(0)
+    timing = json.loads(capsys.readouterr().out)["timing"]
+    assert_that([t["tool"] for t in timing["tools"]]).is_equal_to(["ruff"])
+    assert_that(timing["tools"][0]["execution_ms"]).is_equal_to(25)
+    assert_that(timing["tools"][0]["chunks"][0]["command"]).is_equal_to("ruff")
+
+
+def test_executor_reports_ingested_results_without_running_tools(
+    monkeypatch: pytest.MonkeyPatch,
+    fake_logger: Any,
+    capsys: pytest.CaptureFixture[str],
+) -> None:
+    """Report imported results through the same thresholds and JSON output.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        fake_logger: Fake logger fixture.
+        capsys: Pytest capture fixture for stdout.
+    """
+    from lintro.parsers.sarif import SarifIssue
+
+    _stub_logger(monkeypatch, fake_logger)
+
+    def fail_selection(*args: Any, **kwargs: Any) -> Never:
+        """Fail if tools are selected.
+
+        Args:
+            *args: Positional arguments.
+            **kwargs: Keyword arguments.
+
+        Raises:
+            AssertionError: Always.
+        """
+        raise AssertionError("tools must not be selected for ingested results")
+
+    monkeypatch.setattr(te, "get_tools_to_run", fail_selection)
+    issue = SarifIssue(
+        file="src/app.py",
+        line=12,
+        message="Query built from user input",
+        rule_id="py/sql-injection",
+        level="note",
+    )
+    codeql = ToolResult(
+        name="codeql",
+        success=False,
+        output="",
+        issues_count=1,
+        issues=[issue],
+    )
+    code = run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools=None,
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by="auto",
+        output_format="json",
+        verbose=False,
+        fail_on="error",
+        ingested=[codeql],
+    )
+    assert_that(code).is_equal_to(0)
+    data = json.loads(capsys.readouterr().out)
+    assert_that([r["tool"] for r in data["results"]]).is_equal_to(["codeql"])
+    assert_that(data["results"][0]["issues_count"]).is_equal_to(1)
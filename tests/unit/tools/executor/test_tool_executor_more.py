@@ -23,6 +23,7 @@ if TYPE_CHECKING:
 
 import lintro.utils.tool_executor as te
 from lintro.models.core.tool_result import ToolResult
+from lintro.plugins.protocol import ToolCapabilities
 from lintro.tools import tool_manager
 from lintro.utils.execution.tool_configuration import ToolsToRunResult
 from lintro.utils.output import OutputManager
@@ -38,6 +39,7 @@ class FakeToolDefinition:
     description: str = ""
     file_patterns: list[str] = field(default_factory=list)
     native_configs: list[str] = field(default_factory=list)
+    native: bool = False
 
 
 def _stub_logger(monkeypatch: pytest.MonkeyPatch) -> None:
@@ -111,6 +113,9 @@ def test_get_tools_to_run_fmt_with_cannot_fix_raises(
         def can_fix(self) -> bool:
             return self._definition.can_fix
 
+        def capabilities(self) -> ToolCapabilities:
+            return ToolCapabilities(supports_fix=self._definition.can_fix)
+
         def set_options(self, **kwargs: Any) -> None:  # noqa: D401
             return None
 
@@ -169,7 +174,7 @@ def test_main_loop_get_tool_raises_appends_failure(
     monkeypatch.setattr(
         OutputManager,
         "write_reports_from_results",
-        lambda self, results: None,
+        lambda self, results, **kwargs: None,
         raising=True,
     )
 
@@ -223,7 +228,11 @@ def test_write_reports_errors_are_swallowed(monkeypatch: pytest.MonkeyPatch) ->
     monkeypatch.setattr(te, "get_tools_to_run", fake_get_tools, raising=True)
     monkeypatch.setattr(tool_manager, "get_tool", lambda name: ruff_tool)
 
-    def boom(self: object, results: list[ToolResult]) -> Never:
+    def boom(
+        self: object,
+        results: list[ToolResult],
+        **kwargs: object,
+    ) -> Never:
         raise OSError("disk full")
 
     monkeypatch.setattr(
@@ -386,7 +395,7 @@ def test_post_checks_early_filter_removes_black_from_main(
     monkeypatch.setattr(
         OutputManager,
         "write_reports_from_results",
-        lambda self, results: None,
+        lambda self, results, **kwargs: None,
         raising=True,
     )
 
@@ -472,3 +481,57 @@ def test_all_filtered_results_in_no_tools_warning(
         raw_output=False,
     )
     assert_that(code).is_equal_to(1)
+
+
+def test_sequential_script_error_is_a_config_error(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A failing output.script stops a sequential run as a config error.
+
+    Args:
+        monkeypatch: Pytest fixture to modify objects during the test.
+    """
+    from lintro.utils.execution.exit_codes import RunOutcome, exit_code_for
+
+    _stub_logger(monkeypatch)
+
+    ok = ToolResult(name="ruff", success=True, output="", issues_count=0)
+    ruff_tool = type(
+        "_T",
+        (),
+        {
+            "name": "ruff",
+            "definition": FakeToolDefinition(name="ruff", can_fix=True),
+            "can_fix": True,
+            "set_options": lambda self, **k: None,
+            "check": lambda self, paths, options=None: ok,
+            "fix": lambda self, paths, options=None: ok,
+            "options": {},
+        },
+    )()
+
+    def failing_script(result: ToolResult, **kwargs: object) -> Never:
+        raise RuntimeError("Error in output.script hook.lua: boom")
+
+    monkeypatch.setattr(
+        te,
+        "get_tools_to_run",
+        lambda tools, action: ToolsToRunResult(to_run=["ruff"]),
+    )
+    monkeypatch.setattr(tool_manager, "get_tool", lambda name: ruff_tool)
+    monkeypatch.setattr(te, "postprocess_result", failing_script)
+
+    code = run_lint_tools_simple(
+        action="check",
+        paths=["."],
+        tools="all",
+        tool_options=None,
+        exclude=None,
+        include_venv=False,
+        group_by="auto",
+        output_format="grid",
+        verbose=False,
+        raw_output=False,
+    )
+
+    assert_that(code).is_equal_to(exit_code_for(RunOutcome.CONFIG_ERROR))
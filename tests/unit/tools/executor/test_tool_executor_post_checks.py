@@ -29,6 +29,7 @@ class FakeToolDefinition:
     description: str = ""
     file_patterns: list[str] = field(default_factory=list)
     native_configs: list[str] = field(default_factory=list)
+    native: bool = False
 
 
 class FakeTool:
@@ -142,6 +143,7 @@ def _setup_main_tool(monkeypatch: pytest.MonkeyPatch) -> FakeTool:
     def noop_write_reports_from_results(
         self: object,
         results: list[ToolResult],
+        **kwargs: object,
     ) -> None:
         return None
 
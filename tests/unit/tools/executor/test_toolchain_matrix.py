@@ -0,0 +1,144 @@
+"""Tests for running version-sensitive tools across a toolchain matrix."""
+
+from __future__ import annotations
+
+from typing import Any, cast
+from unittest.mock import MagicMock
+
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.mypy.mypy_issue import MypyIssue
+from lintro.tools.definitions.clippy import _build_clippy_command
+from lintro.utils.execution.toolchain_matrix import (
+    matrix_versions,
+    merge_matrix_results,
+    run_toolchain_matrix,
+)
+
+
+def _issue(line: int, code: str = "attr-defined") -> MypyIssue:
+    """Build a mypy issue in a fixed file.
+
+    Args:
+        line: Line number.
+        code: Mypy error code.
+
+    Returns:
+        MypyIssue: The issue.
+    """
+    return MypyIssue(file="app.py", line=line, column=1, code=code, message="bad")
+
+
+def _result(*issues: MypyIssue) -> ToolResult:
+    """Build a mypy check result.
+
+    Args:
+        *issues: Issues reported by the run.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    return ToolResult(
+        name="mypy",
+        success=not issues,
+        output="\n".join(str(issue.line) for issue in issues) or None,
+        issues_count=len(issues),
+        issues=list(issues),
+    )
+
+
+def test_matrix_versions_by_tool_kind() -> None:
+    """Mypy uses Python versions, clippy Rust toolchains, others none."""
+    matrix = {"python": ["3.10", "3.12"], "rust": ["stable"]}
+
+    assert_that(matrix_versions("mypy", matrix)).is_equal_to(["3.10", "3.12"])
+    assert_that(matrix_versions("clippy", matrix)).is_equal_to(["stable"])
+    assert_that(matrix_versions("ruff", matrix)).is_empty()
+    assert_that(matrix_versions("clippy", {"python": ["3.12"]})).is_empty()
+
+
+def test_merge_dedupes_and_tags_partial_issues() -> None:
+    """Shared issues appear once; others name the toolchains reporting them."""
+    merged = merge_matrix_results(
+        "mypy",
+        [
+            ("python 3.10", _result(_issue(1), _issue(5))),
+            ("python 3.12", _result(_issue(1))),
+        ],
+    )
+
+    assert_that(merged.issues_count).is_equal_to(2)
+    assert_that(merged.success).is_false()
+    issues = cast(list[MypyIssue], merged.issues)
+    assert_that(issues[0].toolchains).is_empty()
+    assert_that(issues[1].toolchains).is_equal_to(["python 3.10"])
+    assert_that(issues[1].to_display_row()["message"]).ends_with(
+        "[only on python 3.10]",
+    )
+    assert_that(merged.output).contains("[python 3.10]", "[python 3.12]")
+
+
+def test_merge_succeeds_only_when_every_toolchain_passes() -> None:
+    """A clean merge needs every run to succeed."""
+    clean = merge_matrix_results(
+        "mypy",
+        [("python 3.10", _result()), ("python 3.12", _result())],
+    )
+    failing = merge_matrix_results(
+        "mypy",
+        [("python 3.10", _result()), ("python 3.12", _result(_issue(2)))],
+    )
+
+    assert_that(clean.success).is_true()
+    assert_that(clean.issues_count).is_equal_to(0)
+    assert_that(failing.success).is_false()
+    assert_that(cast(list[MypyIssue], failing.issues)[0].toolchains).is_equal_to(
+        ["python 3.12"],
+    )
+
+
+def test_run_toolchain_matrix_restores_options() -> None:
+    """Each version runs once and the toolchain option is reset afterwards."""
+    tool = MagicMock()
+    tool.definition.name = "mypy"
+    tool.options = {}
+    seen: list[Any] = []
+
+    def _set_options(**kwargs: Any) -> None:
+        """Record the toolchain option like a real plugin.
+
+        Args:
+            **kwargs: Options to set.
+        """
+        tool.options.update(kwargs)
+
+    def _check(paths: list[str], options: dict[str, Any]) -> ToolResult:
+        """Return a result for the current toolchain.
+
+        Args:
+            paths: Paths to check.
+            options: Runtime options.
+
+        Returns:
+            ToolResult: Clean result.
+        """
+        seen.append(tool.options["toolchain"])
+        return _result()
+
+    tool.set_options.side_effect = _set_options
+    tool.check.side_effect = _check
+
+    result = run_toolchain_matrix(tool, ["."], ["3.10", "3.12"])
+
+    assert_that(seen).is_equal_to(["3.10", "3.12"])
+    assert_that(tool.options).does_not_contain_key("toolchain")
+    assert_that(result.success).is_true()
+
+
+def test_clippy_command_selects_toolchain() -> None:
+    """A toolchain is passed to cargo with rustup's + syntax."""
+    assert_that(_build_clippy_command(toolchain="1.80")[:3]).is_equal_to(
+        ["cargo", "+1.80", "clippy"],
+    )
+    assert_that(_build_clippy_command()[:2]).is_equal_to(["cargo", "clippy"])
@@ -0,0 +1,113 @@
+"""Tests for the file-count workload gate."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.tool_type import ToolType
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils.execution.workload_gate import (
+    WorkloadPreview,
+    preview_workload,
+)
+
+
+def _definition(name: str, patterns: list[str]) -> ToolDefinition:
+    """Build a minimal tool definition.
+
+    Args:
+        name: Tool name.
+        patterns: File patterns the tool handles.
+
+    Returns:
+        ToolDefinition: Definition handling the patterns.
+    """
+    return ToolDefinition(
+        name=name,
+        description="",
+        tool_type=ToolType.LINTER,
+        file_patterns=patterns,
+    )
+
+
+def test_preview_counts_files_per_tool_and_directory(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Each file counts once in total and once per tool that handles it.
+
+    Args:
+        tmp_path: Temporary project.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "setup.py").write_text("")
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "app.py").write_text("")
+    (tmp_path / "vendor" / "lib").mkdir(parents=True)
+    for index in range(3):
+        (tmp_path / "vendor" / "lib" / f"dep{index}.py").write_text("")
+    (tmp_path / "vendor" / "config.yaml").write_text("")
+
+    preview = preview_workload(
+        {
+            "ruff": _definition("ruff", ["*.py"]),
+            "mypy": _definition("mypy", ["*.py", "*.pyi"]),
+            "yamllint": _definition("yamllint", ["*.yaml"]),
+        },
+        ["."],
+    )
+
+    assert_that(preview.total).is_equal_to(6)
+    assert_that(dict(preview.per_tool)).is_equal_to(
+        {"ruff": 5, "mypy": 5, "yamllint": 1},
+    )
+    assert_that(preview.per_directory).is_equal_to(
+        [("vendor", 4), (".", 1), ("src", 1)],
+    )
+
+
+def test_preview_honours_excludes_and_pruned_directories(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Excluded and pruned directories are not counted.
+
+    Args:
+        tmp_path: Temporary project.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    for directory in ("src", "generated", "node_modules"):
+        (tmp_path / directory).mkdir()
+        (tmp_path / directory / "module.py").write_text("")
+
+    preview = preview_workload(
+        {"ruff": _definition("ruff", ["*.py"])},
+        ["."],
+        exclude="generated",
+    )
+
+    assert_that(preview.total).is_equal_to(1)
+    assert_that(preview.per_directory).is_equal_to([("src", 1)])
+
+
+def test_describe_lists_largest_entries() -> None:
+    """The breakdown names the largest tools and directories."""
+    preview = WorkloadPreview(
+        total=12_500,
+        per_tool=[("prettier", 12_000), ("ruff", 500)],
+        per_directory=[(f"dir{index}", 10) for index in range(7)],
+    )
+
+    text = preview.describe(5_000)
+
+    assert_that(text).starts_with(
+        "12,500 files to process, more than the limit of 5,000.",
+    )
+    assert_that(text).contains("By tool: prettier 12,000, ruff 500")
+    assert_that(text).contains("dir4 10, and 2 more")
+    assert_that(text).does_not_contain("dir5")
@@ -0,0 +1,212 @@
+"""Tests for the guard against fixing files with uncommitted changes."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.utils.execution.worktree_guard as worktree_guard
+from lintro.cli_utils.commands.format import format_command
+from lintro.enums.run_outcome import RunOutcome
+from lintro.utils.execution.worktree_guard import (
+    DirtyWorktreeError,
+    dirty_files,
+    guard_worktree,
+)
+
+
+def _git(repo: Path, *args: str) -> None:
+    """Run git in a repository.
+
+    Args:
+        repo: Repository directory.
+        *args: Git arguments.
+    """
+    subprocess.run(
+        ["git", "-C", str(repo), *args],
+        capture_output=True,
+        check=True,
+    )
+
+
+@pytest.fixture
+def repo(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Create a committed repository with a.py and b.py and enter it.
+
+    Args:
+        tmp_path: Temporary directory path for the repository.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The repository, which is also the working directory.
+    """
+    _git(tmp_path, "init", "-q")
+    _git(tmp_path, "config", "user.name", "Test")
+    _git(tmp_path, "config", "user.email", "test@example.com")
+    (tmp_path / "a.py").write_text("a = 1\n")
+    (tmp_path / "b.py").write_text("b = 1\n")
+    _git(tmp_path, "add", ".")
+    _git(tmp_path, "commit", "-q", "-m", "init")
+    monkeypatch.chdir(tmp_path)
+    # The global test fixture turns the check off
+    monkeypatch.setattr(worktree_guard, "dirty_files", dirty_files)
+    return tmp_path
+
+
+def test_dirty_files_lists_changes_but_not_lintro_files(repo: Path) -> None:
+    """Modified and untracked files count; .lintro does not.
+
+    Args:
+        repo: Committed repository.
+    """
+    (repo / "a.py").write_text("a = 2\n")
+    (repo / "c.py").write_text("c = 1\n")
+    (repo / ".lintro").mkdir()
+    (repo / ".lintro" / "audit.jsonl").write_text("{}\n")
+
+    assert_that(dirty_files(["."])).contains_only("a.py", "c.py")
+    assert_that(dirty_files(["b.py"])).is_empty()
+
+
+def test_dirty_files_outside_repository(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Outside a repository there is nothing to check.
+
+    Args:
+        tmp_path: Temporary directory path outside any repository.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setenv("GIT_CEILING_DIRECTORIES", str(tmp_path.parent))
+
+    assert_that(dirty_files(["."])).is_none()
+
+
+def test_guard_refuses_dirty_paths(repo: Path) -> None:
+    """Refuse lists the changed files; allow and clean paths pass.
+
+    Args:
+        repo: Committed repository.
+    """
+    (repo / "a.py").write_text("a = 2\n")
+
+    with pytest.raises(DirtyWorktreeError, match="1 file\\(s\\) to fix: a.py"):
+        guard_worktree(["."], "refuse")
+    assert_that(guard_worktree(["."], "allow")).is_none()
+    assert_that(guard_worktree(["b.py"], "refuse")).is_none()
+
+
+@pytest.mark.parametrize(
+    ("failed", "fixed"),
+    [(False, "b = 2\n"), (True, "b = 1\n")],
+    ids=["success_keeps_fixes", "failure_discards_fixes"],
+)
+def test_autostash_restores_uncommitted_changes(
+    repo: Path,
+    failed: bool,
+    fixed: str,
+) -> None:
+    """Edits are stashed during the run and re-applied after it.
+
+    Args:
+        repo: Committed repository.
+        failed: Whether the fix run fails.
+        fixed: Expected contents of the file the fixer changed.
+    """
+    (repo / "a.py").write_text("a = 2\n")
+
+    stash = guard_worktree(["."], "stash")
+
+    assert_that(stash).is_not_none()
+    assert_that((repo / "a.py").read_text()).is_equal_to("a = 1\n")
+    (repo / "b.py").write_text("b = 2\n")
+    assert_that(stash.restore(failed=failed)).is_true()  # type: ignore[union-attr]
+    assert_that((repo / "a.py").read_text()).is_equal_to("a = 2\n")
+    assert_that((repo / "b.py").read_text()).is_equal_to(fixed)
+
+
+def test_autostash_keeps_conflicting_changes_stashed(repo: Path) -> None:
+    """Edits to a file the fixer changed stay in the stash.
+
+    Args:
+        repo: Committed repository.
+    """
+    (repo / "a.py").write_text("a = 2\n")
+    stash = guard_worktree(["."], "stash")
+    (repo / "a.py").write_text("a = 3\n")
+
+    assert_that(stash.restore(failed=False)).is_false()  # type: ignore[union-attr]
+    assert_that((repo / "a.py").read_text()).is_equal_to("a = 3\n")
+
+
+def test_format_refuses_dirty_worktree(
+    repo: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Format stops before running tools unless told what to do.
+
+    Args:
+        repo: Committed repository.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (repo / "a.py").write_text("a = 2\n")
+    run = MagicMock(return_value=0)
+    monkeypatch.setattr("lintro.cli_utils.commands.format.run_lint_tools_simple", run)
+
+    refused = CliRunner().invoke(format_command, [])
+    allowed = CliRunner().invoke(format_command, ["--allow-dirty"])
+
+    assert_that(refused.exit_code).is_equal_to(1)
+    assert_that(refused.output).contains("Uncommitted changes", "--autostash")
+    assert_that(allowed.exit_code).is_equal_to(0)
+    run.assert_called_once()
+
+
+@pytest.mark.parametrize(
+    ("outcome", "fixed"),
+    [(RunOutcome.ISSUES, "b = 2\n"), (RunOutcome.TOOL_ERROR, "b = 1\n")],
+    ids=["remaining_issues_keep_fixes", "tool_error_discards_fixes"],
+)
+def test_format_autostash_discards_fixes_only_on_errors(
+    repo: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    outcome: RunOutcome,
+    fixed: str,
+) -> None:
+    """Issues left unfixed fail the run but keep the fixes that were made.
+
+    Args:
+        repo: Committed repository.
+        monkeypatch: Pytest monkeypatch fixture.
+        outcome: Outcome of the fix run.
+        fixed: Expected contents of the file the fixer changed.
+    """
+    (repo / "a.py").write_text("a = 2\n")
+
+    def fix(**kwargs: object) -> int:
+        """Fix b.py and report the outcome.
+
+        Args:
+            **kwargs: Arguments of run_lint_tools_simple.
+
+        Returns:
+            int: Exit code of a failed run.
+        """
+        (repo / "b.py").write_text("b = 2\n")
+        kwargs["on_outcome"](outcome)  # type: ignore[operator]
+        return 1
+
+    monkeypatch.setattr("lintro.cli_utils.commands.format.run_lint_tools_simple", fix)
+
+    result = CliRunner().invoke(format_command, ["--autostash"])
+
+    assert_that(result.exit_code).is_equal_to(1)
+    assert_that((repo / "a.py").read_text()).is_equal_to("a = 2\n")
+    assert_that((repo / "b.py").read_text()).is_equal_to(fixed)
@@ -0,0 +1 @@
+"""Frontmatter plugin tests."""
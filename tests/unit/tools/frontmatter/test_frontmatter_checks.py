@@ -0,0 +1,111 @@
+"""Unit tests for frontmatter extraction and schema checks."""
+
+from __future__ import annotations
+
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.frontmatter import (
+    check_frontmatter,
+    extract_frontmatter,
+)
+
+_SCHEMA: dict[str, Any] = {
+    "type": "object",
+    "required": ["title", "date"],
+    "additionalProperties": False,
+    "properties": {
+        "title": {"type": "string"},
+        "date": {"type": "string", "pattern": r"^\d{4}-\d{2}-\d{2}$"},
+        "draft": {"type": "boolean"},
+        "tags": {"type": "array", "items": {"type": "string"}},
+        "author": {
+            "type": "object",
+            "properties": {"name": {"type": "string"}},
+        },
+    },
+}
+
+
+def test_extract_frontmatter() -> None:
+    """The YAML block between the delimiters is returned."""
+    block = extract_frontmatter("---\ntitle: Hello\n---\n# Body\n")
+
+    assert block is not None
+    assert_that(block.text).is_equal_to("title: Hello")
+    assert_that(block.first_line).is_equal_to(2)
+    assert_that(extract_frontmatter("# No frontmatter\n---\n")).is_none()
+
+
+def test_valid_frontmatter_has_no_issues() -> None:
+    """Frontmatter matching the schema passes, with dates read as strings."""
+    text = "---\ntitle: Hello\ndate: 2024-05-01\ntags: [a, b]\n---\nBody\n"
+
+    assert_that(check_frontmatter(text, "post.md", _SCHEMA)).is_empty()
+
+
+def test_violations_point_at_keys() -> None:
+    """Each violation is reported on the line of the offending key."""
+    text = (
+        "---\n"
+        "title: 42\n"
+        "date: 2024-05-01\n"
+        "tags:\n"
+        "  - ok\n"
+        "  - 7\n"
+        "author:\n"
+        "  name: [x]\n"
+        "categroy: news\n"
+        "---\n"
+    )
+
+    issues = check_frontmatter(text, "post.md", _SCHEMA)
+
+    assert_that([(i.line, i.code, i.message) for i in issues]).is_equal_to(
+        [
+            (2, "FM001", "title: expected string, got integer"),
+            (6, "FM001", "tags[1]: expected string, got integer"),
+            (8, "FM001", "author.name: expected string, got array"),
+            (9, "FM001", "categroy: unknown key 'categroy'"),
+        ],
+    )
+    assert_that(issues[1].column).is_equal_to(5)
+
+
+def test_missing_required_key_points_at_opening_delimiter() -> None:
+    """Missing top-level keys are reported on the first line."""
+    issues = check_frontmatter("---\ntitle: Hello\n---\n", "post.md", _SCHEMA)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].line).is_equal_to(1)
+    assert_that(issues[0].message).is_equal_to("missing required key 'date'")
+
+
+def test_invalid_yaml_and_unclosed_frontmatter() -> None:
+    """Broken YAML and missing closing delimiters are FM002."""
+    invalid = check_frontmatter(
+        "---\ntitle: Hello\ndate: [2024\n---\n",
+        "post.md",
+        _SCHEMA,
+    )
+    unclosed = check_frontmatter("---\ntitle: Hello\n", "post.md", _SCHEMA)
+
+    assert_that(invalid[0].code).is_equal_to("FM002")
+    assert_that(invalid[0].line).is_greater_than(1)
+    assert_that(unclosed[0].code).is_equal_to("FM002")
+    assert_that(unclosed[0].message).contains("closing")
+
+
+def test_missing_frontmatter_only_reported_when_required() -> None:
+    """Pages without frontmatter are FM003 only when frontmatter is required."""
+    assert_that(check_frontmatter("# Page\n", "page.md", _SCHEMA)).is_empty()
+
+    issues = check_frontmatter(
+        "# Page\n",
+        "page.md",
+        _SCHEMA,
+        require_frontmatter=True,
+    )
+
+    assert_that(issues[0].code).is_equal_to("FM003")
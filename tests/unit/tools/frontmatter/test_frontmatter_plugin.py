@@ -0,0 +1,100 @@
+"""Unit tests for the frontmatter plugin."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.frontmatter import FrontmatterPlugin
+
+
+@pytest.fixture
+def frontmatter_plugin() -> FrontmatterPlugin:
+    """Provide a FrontmatterPlugin instance for testing.
+
+    Returns:
+        A FrontmatterPlugin instance.
+    """
+    return FrontmatterPlugin()
+
+
+def test_definition_is_native_linter(frontmatter_plugin: FrontmatterPlugin) -> None:
+    """Verify the plugin needs no external binary and cannot fix.
+
+    Args:
+        frontmatter_plugin: The plugin instance.
+    """
+    definition = frontmatter_plugin.definition
+    assert_that(definition.name).is_equal_to("frontmatter")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.file_patterns).contains("*.md", "*.mdx")
+
+
+def test_check_is_skipped_without_schema(
+    frontmatter_plugin: FrontmatterPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify the tool is skipped until a schema is configured.
+
+    Args:
+        frontmatter_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "page.md").write_text("---\ntitle: 1\n---\n")
+
+    result = frontmatter_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.skipped).is_true()
+    assert_that(result.output).contains("tools.frontmatter.schema")
+
+
+def test_check_validates_against_schema(
+    frontmatter_plugin: FrontmatterPlugin,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Verify pages are validated against the configured schema.
+
+    Args:
+        frontmatter_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    schema = {"type": "object", "properties": {"title": {"type": "string"}}}
+    (tmp_path / "page.schema.json").write_text(json.dumps(schema))
+    (tmp_path / "good.md").write_text("---\ntitle: Hello\n---\n")
+    (tmp_path / "bad.md").write_text("---\ntitle: 1\n---\n")
+    frontmatter_plugin.set_options(schema="page.schema.json")
+
+    result = frontmatter_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].line).is_equal_to(2)  # type: ignore[index]
+
+
+def test_unreadable_schema_fails(
+    frontmatter_plugin: FrontmatterPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify a broken schema file fails the run with a clear message.
+
+    Args:
+        frontmatter_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    schema_path = tmp_path / "broken.json"
+    schema_path.write_text("{not json")
+
+    result = frontmatter_plugin.check(
+        [str(tmp_path)],
+        {"schema": str(schema_path)},
+    )
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("not valid JSON")
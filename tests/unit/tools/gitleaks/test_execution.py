@@ -110,3 +110,30 @@ def test_check_with_mocked_subprocess_secrets_found(
     assert_that(result.issues_count).is_equal_to(1)
     assert_that(result.issues).is_not_none()
     assert_that(result.issues).is_length(1)
+
+
+def test_check_staged_scans_repository(
+    gitleaks_plugin: GitleaksPlugin,
+    tmp_path: Path,
+) -> None:
+    """Staged scans point gitleaks at the repository, not the given file.
+
+    Args:
+        gitleaks_plugin: The GitleaksPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    test_file = tmp_path / "app.py"
+    test_file.write_text("x = 1\n")
+    gitleaks_plugin.set_options(staged=True)
+
+    with patch.object(
+        gitleaks_plugin,
+        "_run_subprocess",
+        side_effect=_mock_subprocess_factory("[]"),
+    ) as mock_run:
+        result = gitleaks_plugin.check([str(test_file)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd).contains("protect", "--staged")
+    assert_that(cmd[cmd.index("--source") + 1]).is_equal_to(str(tmp_path))
+    assert_that(result.issues_count).is_equal_to(0)
@@ -19,6 +19,7 @@ from lintro.tools.definitions.gitleaks import (
     [
         ("timeout", GITLEAKS_DEFAULT_TIMEOUT),
         ("no_git", True),
+        ("staged", False),
         ("config", None),
         ("baseline_path", None),
         ("redact", True),
@@ -27,6 +28,7 @@ from lintro.tools.definitions.gitleaks import (
     ids=[
         "timeout_equals_default",
         "no_git_is_true",
+        "staged_is_false",
         "config_is_none",
         "baseline_path_is_none",
         "redact_is_true",
@@ -177,6 +179,32 @@ def test_build_check_command_with_no_git_false(gitleaks_plugin: GitleaksPlugin)
     assert_that(cmd).does_not_contain("--no-git")
 
 
+def test_build_check_command_staged(gitleaks_plugin: GitleaksPlugin) -> None:
+    """Build a protect --staged command for staged changes.
+
+    Args:
+        gitleaks_plugin: The GitleaksPlugin instance to test.
+    """
+    gitleaks_plugin.set_options(staged=True)
+    cmd = gitleaks_plugin._build_check_command(
+        source_path="/repo",
+        report_path="/tmp/report.json",
+    )
+
+    assert_that(cmd[:3]).is_equal_to(["gitleaks", "protect", "--staged"])
+    assert_that(cmd).does_not_contain("detect", "--no-git")
+    assert_that(cmd).contains("--redact")
+
+
+def test_gitleaks_is_opt_in(gitleaks_plugin: GitleaksPlugin) -> None:
+    """Gitleaks only runs when enabled in config or requested.
+
+    Args:
+        gitleaks_plugin: The GitleaksPlugin instance to test.
+    """
+    assert_that(gitleaks_plugin.definition.enabled_by_default).is_false()
+
+
 def test_build_check_command_with_config(gitleaks_plugin: GitleaksPlugin) -> None:
     """Build command with config file path.
 
@@ -0,0 +1 @@
+"""gofmt plugin tests."""
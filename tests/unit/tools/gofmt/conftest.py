@@ -0,0 +1,17 @@
+"""Shared fixtures for gofmt plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.gofmt import GofmtPlugin
+
+
+@pytest.fixture
+def gofmt_plugin() -> GofmtPlugin:
+    """Provide a GofmtPlugin instance for testing.
+
+    Returns:
+        GofmtPlugin: A new GofmtPlugin instance.
+    """
+    return GofmtPlugin()
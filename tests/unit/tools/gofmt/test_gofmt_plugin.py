@@ -0,0 +1,221 @@
+"""Tests for GofmtPlugin."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.gofmt import GofmtPlugin
+
+DIFF = """diff main.go.orig main.go
+--- main.go.orig
++++ main.go
+@@ -1,3 +1,3 @@
+ package main
+-func main() {  }
++func main() {}
+"""
+
+
+def _context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for a single Go file.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["main.go"]
+    ctx.files = [str(tmp_path / "main.go")]
+    return ctx
+
+
+def test_set_options_rejects_invalid_gofumpt(gofmt_plugin: GofmtPlugin) -> None:
+    """A non-boolean gofumpt option is rejected.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="gofumpt"):
+        gofmt_plugin.set_options(gofumpt="yes")  # type: ignore[arg-type]
+
+
+def test_build_command(gofmt_plugin: GofmtPlugin) -> None:
+    """gofmt simplifies on request and gofumpt replaces the binary.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+    """
+    gofmt_plugin.set_options(simplify=True)
+    assert_that(gofmt_plugin._build_command(["a.go"], check_only=True)).is_equal_to(
+        ["gofmt", "-s", "-d", "a.go"],
+    )
+
+    gofmt_plugin.set_options(gofumpt=True)
+    assert_that(gofmt_plugin._build_command(["a.go"], check_only=False)).is_equal_to(
+        ["gofumpt", "-w", "a.go"],
+    )
+
+
+def test_check_runs_from_project_root(
+    gofmt_plugin: GofmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """Check runs ``gofmt -d`` in the working directory and parses hunks.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            gofmt_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            gofmt_plugin,
+            "_run_subprocess",
+            return_value=(True, DIFF),
+        ) as mock_run,
+    ):
+        result = gofmt_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(tmp_path))
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        ["gofmt", "-d", "main.go"],
+    )
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].line).is_equal_to(2)
+
+
+def test_check_reports_failed_runs(
+    gofmt_plugin: GofmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without diagnostics fails the result with its output.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            gofmt_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            gofmt_plugin,
+            "_run_subprocess",
+            return_value=(False, "open main.go: permission denied"),
+        ),
+    ):
+        result = gofmt_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("open main.go: permission denied")
+
+
+def test_fix_rewrites_and_rechecks(
+    gofmt_plugin: GofmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """Fix runs ``gofmt -w`` between two checks and reports the counts.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    outputs = iter([(True, DIFF), (True, ""), (True, "")])
+
+    with (
+        patch.object(
+            gofmt_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            gofmt_plugin,
+            "_run_subprocess",
+            side_effect=lambda **_: next(outputs),
+        ) as mock_run,
+    ):
+        result = gofmt_plugin.fix(["."], {})
+
+    commands = [c.kwargs["cmd"] for c in mock_run.call_args_list]
+    assert_that(commands[1]).contains("-w")
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+    assert_that(result.output).contains("All issues were successfully auto-fixed")
+
+
+def test_fix_keeps_syntax_errors(
+    gofmt_plugin: GofmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """Files gofmt cannot parse are not rewritten and stay reported.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    error = "main.go:2:1: expected declaration, found foo"
+
+    with (
+        patch.object(
+            gofmt_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            gofmt_plugin,
+            "_run_subprocess",
+            return_value=(False, error),
+        ) as mock_run,
+    ):
+        result = gofmt_plugin.fix(["."], {})
+
+    assert_that(mock_run.call_count).is_equal_to(2)
+    assert_that(result.success).is_false()
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("cannot be auto-fixed")
+
+
+def test_fix_reports_timeout(gofmt_plugin: GofmtPlugin, tmp_path: Path) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        gofmt_plugin: The GofmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            gofmt_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            gofmt_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["gofmt"], timeout=60),
+        ),
+    ):
+        result = gofmt_plugin.fix(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("gofmt:timeout=N")
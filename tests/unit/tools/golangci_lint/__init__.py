@@ -0,0 +1 @@
+"""golangci-lint plugin tests."""
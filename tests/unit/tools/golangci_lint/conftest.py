@@ -0,0 +1,17 @@
+"""Shared fixtures for golangci-lint plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.golangci_lint import GolangciLintPlugin
+
+
+@pytest.fixture
+def golangci_lint_plugin() -> GolangciLintPlugin:
+    """Provide a GolangciLintPlugin instance for testing.
+
+    Returns:
+        GolangciLintPlugin: A new GolangciLintPlugin instance.
+    """
+    return GolangciLintPlugin()
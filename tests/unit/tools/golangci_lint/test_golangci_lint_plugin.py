@@ -0,0 +1,230 @@
+"""Tests for GolangciLintPlugin."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.golangci_lint import (
+    find_go_module_root,
+    group_by_module,
+    package_patterns,
+)
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.golangci_lint import GolangciLintPlugin
+
+
+def _report(*filenames: Path) -> str:
+    """Serialize a golangci-lint report with one issue per file.
+
+    Args:
+        *filenames: Files the issues are in.
+
+    Returns:
+        str: The report as golangci-lint prints it.
+    """
+    return json.dumps(
+        {
+            "Issues": [
+                {
+                    "FromLinter": "errcheck",
+                    "Text": "Error return value is not checked",
+                    "Pos": {"Filename": str(f), "Line": 3, "Column": 2},
+                }
+                for f in filenames
+            ],
+            "Report": {},
+        },
+    )
+
+
+def _context(files: list[Path]) -> MagicMock:
+    """Build an execution context for the given files.
+
+    Args:
+        files: Go files to check.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 300
+    ctx.cwd = None
+    ctx.files = [str(f) for f in files]
+    ctx.rel_files = ctx.files
+    return ctx
+
+
+@pytest.fixture
+def go_project(tmp_path: Path) -> Path:
+    """Create two Go modules and a file outside any module.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: Root of the project.
+    """
+    for module in ("api", "worker"):
+        (tmp_path / module / "internal" / "db").mkdir(parents=True)
+        (tmp_path / module / "go.mod").write_text(f"module example.com/{module}\n")
+        (tmp_path / module / "main.go").write_text("package main\n")
+        (tmp_path / module / "internal" / "db" / "db.go").write_text("package db\n")
+    (tmp_path / "scripts").mkdir()
+    (tmp_path / "scripts" / "gen.go").write_text("package main\n")
+    return tmp_path
+
+
+def test_find_go_module_root(go_project: Path) -> None:
+    """Files resolve to the nearest go.mod, or None outside a module.
+
+    Args:
+        go_project: Project with Go modules.
+    """
+    db = go_project / "api" / "internal" / "db" / "db.go"
+
+    assert_that(find_go_module_root(db)).is_equal_to((go_project / "api").resolve())
+    assert_that(find_go_module_root(go_project / "scripts" / "gen.go")).is_none()
+
+
+def test_group_by_module_and_packages(go_project: Path) -> None:
+    """Files are grouped per module and passed as package directories.
+
+    Args:
+        go_project: Project with Go modules.
+    """
+    files = [
+        str(go_project / "api" / "main.go"),
+        str(go_project / "api" / "internal" / "db" / "db.go"),
+        str(go_project / "scripts" / "gen.go"),
+    ]
+
+    groups = group_by_module(files)
+
+    api = (go_project / "api").resolve()
+    scripts = (go_project / "scripts").resolve()
+    assert_that(sorted(groups)).is_equal_to(sorted([api, scripts]))
+    assert_that(package_patterns(api, groups[api])).is_equal_to(
+        [".", "./internal/db"],
+    )
+    assert_that(package_patterns(scripts, groups[scripts])).is_equal_to(["."])
+
+
+def test_set_options_normalizes_build_tags(
+    golangci_lint_plugin: GolangciLintPlugin,
+) -> None:
+    """A single build tag is accepted and passed as ``--build-tags``.
+
+    Args:
+        golangci_lint_plugin: The GolangciLintPlugin instance to test.
+    """
+    golangci_lint_plugin.set_options(build_tags="integration")
+
+    cmd = golangci_lint_plugin._build_command(["./..."])
+
+    assert_that(cmd).contains("run", "--output.json.path=stdout")
+    assert_that(cmd).contains("--build-tags=integration")
+    with pytest.raises(ValueError, match="build_tags"):
+        golangci_lint_plugin.set_options(build_tags=1)  # type: ignore[arg-type]
+
+
+def test_check_runs_per_module(
+    golangci_lint_plugin: GolangciLintPlugin,
+    go_project: Path,
+) -> None:
+    """Each module is linted from its root, keeping issues in checked files.
+
+    Args:
+        golangci_lint_plugin: The GolangciLintPlugin instance to test.
+        go_project: Project with Go modules.
+    """
+    api_main = (go_project / "api" / "main.go").resolve()
+    api_db = (go_project / "api" / "internal" / "db" / "db.go").resolve()
+    worker_main = (go_project / "worker" / "main.go").resolve()
+
+    def run(cmd: list[str], timeout: int, cwd: str) -> tuple[bool, str]:
+        """Report issues in every file of the module, checked or not.
+
+        Args:
+            cmd: Command list.
+            timeout: Timeout in seconds.
+            cwd: Module root the command runs in.
+
+        Returns:
+            Tuple of (success, output).
+        """
+        if cwd.endswith("api"):
+            return False, _report(api_main, api_db)
+        return False, _report(worker_main)
+
+    with (
+        patch.object(
+            golangci_lint_plugin,
+            "_prepare_execution",
+            return_value=_context([api_main, worker_main]),
+        ),
+        patch.object(
+            golangci_lint_plugin,
+            "_run_subprocess",
+            side_effect=run,
+        ) as mock_run,
+    ):
+        result = golangci_lint_plugin.check(["."], {})
+
+    cwds = sorted(c.kwargs["cwd"] for c in mock_run.call_args_list)
+    assert_that(cwds).is_equal_to(
+        sorted([str(api_main.parent), str(worker_main.parent)]),
+    )
+    assert_that(mock_run.call_args_list[0].kwargs["cmd"][-1]).is_equal_to(".")
+    assert_that(result.success).is_false()
+    assert_that(sorted(i.file for i in result.issues)).is_equal_to(
+        sorted([str(api_main), str(worker_main)]),
+    )
+
+
+def test_check_reports_load_errors(
+    golangci_lint_plugin: GolangciLintPlugin,
+    go_project: Path,
+) -> None:
+    """Runs without a JSON report fail with the module and its output.
+
+    Args:
+        golangci_lint_plugin: The GolangciLintPlugin instance to test.
+        go_project: Project with Go modules.
+    """
+    main = go_project / "api" / "main.go"
+
+    with (
+        patch.object(
+            golangci_lint_plugin,
+            "_prepare_execution",
+            return_value=_context([main]),
+        ),
+        patch.object(
+            golangci_lint_plugin,
+            "_run_subprocess",
+            return_value=(False, "Error: can't load config"),
+        ),
+    ):
+        result = golangci_lint_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).ends_with(": Error: can't load config")
+
+
+def test_fix_is_not_supported(golangci_lint_plugin: GolangciLintPlugin) -> None:
+    """Fixing raises NotImplementedError.
+
+    Args:
+        golangci_lint_plugin: The GolangciLintPlugin instance to test.
+    """
+    with pytest.raises(NotImplementedError):
+        golangci_lint_plugin.fix(["."], {})
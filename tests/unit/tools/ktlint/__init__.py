@@ -0,0 +1 @@
+"""ktlint plugin tests."""
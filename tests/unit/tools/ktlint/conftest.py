@@ -0,0 +1,17 @@
+"""Shared fixtures for ktlint plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.ktlint import KtlintPlugin
+
+
+@pytest.fixture
+def ktlint_plugin() -> KtlintPlugin:
+    """Provide a KtlintPlugin instance for testing.
+
+    Returns:
+        KtlintPlugin: A new KtlintPlugin instance.
+    """
+    return KtlintPlugin()
@@ -0,0 +1,186 @@
+"""Tests for KtlintPlugin."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.ktlint import KtlintPlugin
+
+
+def _report(count: int) -> str:
+    """Build a ktlint JSON report with the given number of violations.
+
+    Args:
+        count: Number of violations to report.
+
+    Returns:
+        str: The JSON report.
+    """
+    errors = [
+        {
+            "line": index + 1,
+            "column": 1,
+            "message": "Wildcard import",
+            "rule": "standard:no-wildcard-imports",
+        }
+        for index in range(count)
+    ]
+    if not errors:
+        return "[]"
+    return json.dumps([{"file": "App.kt", "errors": errors}])
+
+
+def _context() -> MagicMock:
+    """Build an execution context for one Kotlin file.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 120
+    ctx.cwd = "/project/src"
+    ctx.rel_files = ["App.kt"]
+    ctx.files = ["/project/src/App.kt"]
+    return ctx
+
+
+def test_check_runs_json_reporter(ktlint_plugin: KtlintPlugin) -> None:
+    """Check runs ktlint with the JSON reporter and the configured files.
+
+    Args:
+        ktlint_plugin: The KtlintPlugin instance to test.
+    """
+    ktlint_plugin.set_options(baseline="ktlint-baseline.xml")
+
+    with (
+        patch.object(ktlint_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            ktlint_plugin,
+            "_run_subprocess",
+            return_value=(False, _report(2)),
+        ) as mock_run,
+    ):
+        result = ktlint_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        [
+            "ktlint",
+            "--reporter=json",
+            "--relative",
+            "--baseline=ktlint-baseline.xml",
+            "App.kt",
+        ],
+    )
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to("/project/src")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(2)
+    assert_that(result.issues[0].code).is_equal_to("standard:no-wildcard-imports")
+
+
+def test_check_passes_clean_run_without_report(ktlint_plugin: KtlintPlugin) -> None:
+    """A successful run that prints no report has no issues.
+
+    Args:
+        ktlint_plugin: The KtlintPlugin instance to test.
+    """
+    with (
+        patch.object(ktlint_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(ktlint_plugin, "_run_subprocess", return_value=(True, "")),
+    ):
+        result = ktlint_plugin.check(["."], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.issues_count).is_equal_to(0)
+
+
+def test_check_reports_failed_runs(ktlint_plugin: KtlintPlugin) -> None:
+    """A failed run without a report fails the result with its output.
+
+    Args:
+        ktlint_plugin: The KtlintPlugin instance to test.
+    """
+    with (
+        patch.object(ktlint_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            ktlint_plugin,
+            "_run_subprocess",
+            return_value=(False, "java: command not found"),
+        ),
+    ):
+        result = ktlint_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("java: command not found")
+
+
+def test_check_reports_timeout(ktlint_plugin: KtlintPlugin) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        ktlint_plugin: The KtlintPlugin instance to test.
+    """
+    with (
+        patch.object(ktlint_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            ktlint_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["ktlint"], timeout=120),
+        ),
+    ):
+        result = ktlint_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("ktlint:timeout=N")
+
+
+def test_fix_formats_and_counts_remaining(ktlint_plugin: KtlintPlugin) -> None:
+    """Fix runs ktlint --format and reports the violations it left.
+
+    Args:
+        ktlint_plugin: The KtlintPlugin instance to test.
+    """
+    with (
+        patch.object(ktlint_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            ktlint_plugin,
+            "_run_subprocess",
+            side_effect=[(False, _report(3)), (False, _report(1))],
+        ) as mock_run,
+    ):
+        result = ktlint_plugin.fix(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).contains("--format")
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("Fixed 2 issue(s)")
+
+
+def test_fix_skips_format_when_clean(ktlint_plugin: KtlintPlugin) -> None:
+    """Clean files are not rewritten.
+
+    Args:
+        ktlint_plugin: The KtlintPlugin instance to test.
+    """
+    with (
+        patch.object(ktlint_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            ktlint_plugin,
+            "_run_subprocess",
+            return_value=(True, _report(0)),
+        ) as mock_run,
+    ):
+        result = ktlint_plugin.fix(["."], {})
+
+    assert_that(mock_run.call_count).is_equal_to(1)
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(0)
@@ -0,0 +1 @@
+"""License header plugin tests."""
@@ -0,0 +1,145 @@
+"""Unit tests for the license header checks and fixes."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.implementations.license_header import (
+    CommentStyle,
+    LicenseHeader,
+    check_text,
+    fix_text,
+    load_license_header,
+    parse_comment_styles,
+)
+
+TEMPLATE = "Copyright (c) {year} {owner}\nSPDX-License-Identifier: MIT\n"
+HASH = CommentStyle("#")
+C_BLOCK = CommentStyle(" *", start="/*", end=" */")
+
+
+@pytest.fixture
+def header() -> LicenseHeader:
+    """Provide a license header owned by Acme for 2024.
+
+    Returns:
+        LicenseHeader: The header.
+    """
+    header = load_license_header(TEMPLATE, None, "Acme", "2024", None)
+    assert header is not None
+    return header
+
+
+def _codes(text: str, header: LicenseHeader, style: CommentStyle) -> list[str]:
+    """Return the codes reported for a text.
+
+    Args:
+        text: File contents.
+        header: License header to look for.
+        style: Comment style of the file.
+
+    Returns:
+        list[str]: Codes of the issues.
+    """
+    return [i.code for i in check_text(text, "f", header, style)]
+
+
+@pytest.mark.parametrize(
+    "year",
+    ["2019", "2019-2024", "2019 - 2024", "2019, 2021"],
+)
+def test_header_with_any_year_passes(header: LicenseHeader, year: str) -> None:
+    """Existing headers match whatever year they were written in.
+
+    Args:
+        header: License header owned by Acme.
+        year: Year or year range in the file.
+    """
+    text = f"# Copyright (c) {year} Acme\n# SPDX-License-Identifier: MIT\nx = 1\n"
+
+    assert_that(_codes(text, header, HASH)).is_empty()
+
+
+def test_missing_header_is_inserted_after_shebang(header: LicenseHeader) -> None:
+    """LH001 is fixed by inserting the header below the shebang.
+
+    Args:
+        header: License header owned by Acme.
+    """
+    text = "#!/usr/bin/env python3\nx = 1\n"
+
+    assert_that(_codes(text, header, HASH)).is_equal_to(["LH001"])
+    fixed = fix_text(text, header, HASH)
+    assert_that(fixed).is_equal_to(
+        "#!/usr/bin/env python3\n# Copyright (c) 2024 Acme\n"
+        "# SPDX-License-Identifier: MIT\n\nx = 1\n",
+    )
+    assert_that(_codes(fixed, header, HASH)).is_empty()
+
+
+def test_block_comment_header_keeps_line_endings(header: LicenseHeader) -> None:
+    """Block comment styles wrap the header and CRLF files stay CRLF.
+
+    Args:
+        header: License header owned by Acme.
+    """
+    fixed = fix_text("body {}\r\n", header, C_BLOCK)
+
+    assert_that(fixed).is_equal_to(
+        "/*\r\n * Copyright (c) 2024 Acme\r\n * SPDX-License-Identifier: MIT\r\n"
+        " */\r\n\r\nbody {}\r\n",
+    )
+
+
+def test_other_header_is_reported_not_fixed(header: LicenseHeader) -> None:
+    """A different copyright header is LH002 and left alone.
+
+    Args:
+        header: License header owned by Acme.
+    """
+    text = "# Copyright 2020 Someone Else\nx = 1\n"
+
+    assert_that(_codes(text, header, HASH)).is_equal_to(["LH002"])
+    assert_that(fix_text(text, header, HASH)).is_equal_to(text)
+
+
+def test_empty_file_needs_no_header(header: LicenseHeader) -> None:
+    """Empty files are not reported.
+
+    Args:
+        header: License header owned by Acme.
+    """
+    assert_that(_codes("\n", header, HASH)).is_empty()
+
+
+def test_load_license_header_validates_options() -> None:
+    """Missing templates disable the check; conflicting options are errors."""
+    assert_that(load_license_header(None, None, None, None, None)).is_none()
+    with pytest.raises(ValueError, match="set owner"):
+        load_license_header(TEMPLATE, None, None, None, None)
+    with pytest.raises(ValueError, match="not both"):
+        load_license_header(TEMPLATE, "LICENSE_HEADER", "Acme", None, None)
+
+
+def test_comment_styles_extend_defaults(header: LicenseHeader) -> None:
+    """Configured comment styles add file types and replace built-in ones.
+
+    Args:
+        header: License header owned by Acme.
+    """
+    custom = load_license_header(
+        TEMPLATE,
+        None,
+        "Acme",
+        None,
+        {".nix": "#", ".js": ["/*", " *", " */"]},
+    )
+
+    assert custom is not None
+    assert_that(custom.style_for("default.nix")).is_equal_to(HASH)
+    assert_that(custom.style_for("app.js")).is_equal_to(C_BLOCK)
+    assert_that(header.style_for("Dockerfile")).is_equal_to(HASH)
+    assert_that(header.style_for("image.png")).is_none()
+    with pytest.raises(ValueError, match=r"comment_styles\['x'\]"):
+        parse_comment_styles({"x": ["/*"]})
@@ -0,0 +1,100 @@
+"""Unit tests for the license header plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.license_header import LicenseHeaderPlugin
+
+OPTIONS: dict[str, object] = {
+    "template": "Copyright (c) {year} {owner}",
+    "owner": "Acme",
+    "year": 2024,
+}
+
+
+@pytest.fixture
+def license_header_plugin() -> LicenseHeaderPlugin:
+    """Provide a LicenseHeaderPlugin instance for testing.
+
+    Returns:
+        A LicenseHeaderPlugin instance.
+    """
+    return LicenseHeaderPlugin()
+
+
+def test_definition_is_native_fixer(
+    license_header_plugin: LicenseHeaderPlugin,
+) -> None:
+    """Verify the plugin needs no external binary and can fix.
+
+    Args:
+        license_header_plugin: The plugin instance.
+    """
+    definition = license_header_plugin.definition
+    assert_that(definition.name).is_equal_to("license_header")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_true()
+
+
+def test_check_is_skipped_without_template(
+    license_header_plugin: LicenseHeaderPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify the tool does nothing until a template is configured.
+
+    Args:
+        license_header_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    result = license_header_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to("no template configured")
+
+
+def test_set_options_rejects_invalid_comment_styles(
+    license_header_plugin: LicenseHeaderPlugin,
+) -> None:
+    """Verify comment styles are validated when set.
+
+    Args:
+        license_header_plugin: The plugin instance.
+    """
+    with pytest.raises(ValueError, match=r"comment_styles\['.py'\]"):
+        license_header_plugin.set_options(comment_styles={".py": 1})
+
+
+def test_fix_inserts_missing_headers(
+    license_header_plugin: LicenseHeaderPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify fixing inserts headers and skips unknown file types.
+
+    Args:
+        license_header_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    license_header_plugin.set_options(**OPTIONS)  # type: ignore[arg-type]
+    (tmp_path / "app.py").write_text("x = 1\n")
+    (tmp_path / "main.go").write_text("// Copyright (c) 2020-2024 Acme\n")
+    (tmp_path / "notes.txt").write_text("no header\n")
+
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        checked = license_header_plugin.check([str(tmp_path)], {})
+        result = license_header_plugin.fix([str(tmp_path)], {})
+
+    assert_that([Path(i.file).name for i in checked.issues]).is_equal_to(["app.py"])
+    assert_that((tmp_path / "app.py").read_text()).is_equal_to(
+        "# Copyright (c) 2024 Acme\n\nx = 1\n",
+    )
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+    assert_that(result.output).contains("Added license headers to 1 file(s)")
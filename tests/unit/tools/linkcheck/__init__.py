@@ -0,0 +1 @@
+"""Linkcheck plugin tests."""
@@ -0,0 +1,185 @@
+"""Tests for relative and external link checks."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.linkcheck import (
+    ExternalLink,
+    UrlCache,
+    check_document_links,
+    check_external_links,
+    is_allowed_domain,
+)
+
+
+def _check(doc: Path, root: Path) -> list[str]:
+    """Check a document and format issues as ``code line:column``.
+
+    Args:
+        doc: Document to check.
+        root: Repository root.
+
+    Returns:
+        list[str]: Formatted issues.
+    """
+    issues, _ = check_document_links(doc.read_text(), doc, doc.name, root=root)
+    return [f"{i.code} {i.line}:{i.column}" for i in issues]
+
+
+def test_relative_links_and_anchors(tmp_path: Path) -> None:
+    """Missing files are LC001 and missing anchors are LC002.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "docs").mkdir()
+    (tmp_path / "docs" / "guide.md").write_text("# Getting Started\n")
+    doc = tmp_path / "README.md"
+    doc.write_text(
+        "# Title\n"
+        "[ok](docs/guide.md#getting-started) [root](/docs/guide.md)\n"
+        "[gone](docs/missing.md) [bad](docs/guide.md#nope)\n"
+        "[self](#title) [self-bad](#nothing) [mail](mailto:a@b.c)\n",
+    )
+
+    assert_that(_check(doc, tmp_path)).is_equal_to(
+        ["LC001 3:8", "LC002 3:31", "LC002 4:27"],
+    )
+
+
+def test_anchor_checks_can_be_disabled(tmp_path: Path) -> None:
+    """check_anchors=False only verifies that targets exist.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    doc = tmp_path / "README.md"
+    doc.write_text("[a](#missing) [b](other.md#x)\n")
+
+    issues, _ = check_document_links(
+        doc.read_text(),
+        doc,
+        "README.md",
+        root=tmp_path,
+        check_anchors=False,
+    )
+
+    assert_that([i.code for i in issues]).is_equal_to(["LC001"])
+
+
+def test_external_links_are_collected(tmp_path: Path) -> None:
+    """http(s) links are returned for the network pass, not checked inline.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    doc = tmp_path / "README.md"
+    doc.write_text("[a](https://example.com/a) [b](//cdn.example.com/x.js)\n")
+
+    issues, external = check_document_links(
+        doc.read_text(),
+        doc,
+        "README.md",
+        root=tmp_path,
+    )
+
+    assert_that(issues).is_empty()
+    assert_that(external).is_equal_to(
+        [ExternalLink("https://example.com/a", "README.md", 1, 5)],
+    )
+
+
+def test_is_allowed_domain_matches_subdomains() -> None:
+    """A domain allowlists itself and its subdomains only."""
+    assert_that(
+        is_allowed_domain("https://docs.example.com/x", ["example.com"]),
+    ).is_true()
+    assert_that(
+        is_allowed_domain("https://notexample.com/", ["example.com"]),
+    ).is_false()
+
+
+def test_external_status_codes(tmp_path: Path) -> None:
+    """HTTP errors are LC003, unreachable URLs LC004, allowlisted are skipped.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    statuses = {
+        "https://ok.test/": (200, None),
+        "https://gone.test/": (404, None),
+        "https://limited.test/": (429, None),
+        "https://down.test/": (None, "connection refused"),
+    }
+    requested: list[str] = []
+
+    def fetcher(url: str, timeout: int) -> tuple[int | None, str | None]:
+        """Return a canned status.
+
+        Args:
+            url: Requested URL.
+            timeout: Request timeout in seconds.
+
+        Returns:
+            tuple[int | None, str | None]: Status code and error.
+        """
+        requested.append(url)
+        return statuses[url]
+
+    links = [ExternalLink(url, "a.md", n, 1) for n, url in enumerate(statuses, 1)]
+    links.append(ExternalLink("https://gone.test/", "b.md", 7, 3))
+    links.append(ExternalLink("https://private.internal/", "b.md", 8, 1))
+    cache = UrlCache(path=tmp_path / "cache.json")
+
+    issues = check_external_links(
+        links,
+        allow_domains=["internal"],
+        cache=cache,
+        fetcher=fetcher,
+    )
+
+    assert_that(sorted(requested)).is_equal_to(sorted(statuses))
+    assert_that([f"{i.code} {i.file}:{i.line}" for i in issues]).is_equal_to(
+        ["LC004 a.md:4", "LC003 a.md:2", "LC003 b.md:7"],
+    )
+    assert_that(sorted(cache.entries)).is_equal_to(
+        ["https://limited.test/", "https://ok.test/"],
+    )
+
+
+def test_cache_skips_recently_reachable_urls(tmp_path: Path) -> None:
+    """URLs cached as reachable are not requested again.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    cache_file = tmp_path / "cache.json"
+    cache = UrlCache(path=cache_file)
+    cache.record("https://ok.test/")
+    cache.save()
+
+    def fetcher(url: str, timeout: int) -> tuple[int | None, str | None]:
+        """Fail if called.
+
+        Args:
+            url: Requested URL.
+            timeout: Request timeout in seconds.
+
+        Raises:
+            AssertionError: Always, since the URL should come from the cache.
+        """
+        raise AssertionError(f"unexpected request to {url}")
+
+    issues = check_external_links(
+        [ExternalLink("https://ok.test/", "a.md", 1, 1)],
+        cache=UrlCache.load(cache_file),
+        fetcher=fetcher,
+    )
+
+    assert_that(issues).is_empty()
+    assert_that(
+        UrlCache.load(cache_file, ttl=0).is_fresh("https://ok.test/"),
+    ).is_false()
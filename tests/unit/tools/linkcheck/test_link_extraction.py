@@ -0,0 +1,90 @@
+"""Tests for Markdown and HTML link extraction."""
+
+from __future__ import annotations
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.linkcheck import (
+    DocumentLink,
+    extract_anchors,
+    extract_html_links,
+    extract_markdown_links,
+    github_slug,
+)
+
+
+def test_markdown_links_of_every_kind() -> None:
+    """Inline links, images, references, autolinks and HTML are found."""
+    text = (
+        "See [docs](docs/a.md#setup \"Title\") and ![logo](img/logo.png).\n"
+        "Visit <https://example.com/x> or <a href='guide.html'>guide</a>.\n"
+        "[ref]: https://example.org/ref\n"
+    )
+
+    urls = [link.url for link in extract_markdown_links(text)]
+
+    assert_that(urls).is_equal_to(
+        [
+            "docs/a.md#setup",
+            "img/logo.png",
+            "https://example.com/x",
+            "guide.html",
+            "https://example.org/ref",
+        ],
+    )
+
+
+def test_markdown_links_report_position() -> None:
+    """Links carry the line and column of their target."""
+    links = extract_markdown_links("intro\n  [a](b.md)\n")
+
+    assert_that(links).is_equal_to([DocumentLink(url="b.md", line=2, column=7)])
+
+
+def test_markdown_links_skip_code() -> None:
+    """Links in fenced code blocks and inline code spans are ignored."""
+    text = (
+        "```md\n[fenced](missing.md)\n```\n"
+        "Use `[span](missing.md)` here, [real](ok.md).\n"
+    )
+
+    urls = [link.url for link in extract_markdown_links(text)]
+
+    assert_that(urls).is_equal_to(["ok.md"])
+
+
+def test_html_links() -> None:
+    """href and src attributes are extracted from HTML documents."""
+    text = '<link href="style.css">\n<img SRC="a.png"><a href="#top">top</a>\n'
+
+    urls = [link.url for link in extract_html_links(text)]
+
+    assert_that(urls).is_equal_to(["style.css", "a.png", "#top"])
+
+
+def test_github_slug() -> None:
+    """Headings are slugified like GitHub does."""
+    assert_that(github_slug("Hello, World!")).is_equal_to("hello-world")
+    assert_that(github_slug("Use `lintro check` now")).is_equal_to(
+        "use-lintro-check-now",
+    )
+    assert_that(github_slug("[Link](x.md) heading")).is_equal_to("link-heading")
+
+
+def test_markdown_anchors() -> None:
+    """ATX and setext headings, duplicates and explicit ids become anchors."""
+    text = (
+        "# Intro\n\nSetup\n-----\n\n## Intro\n"
+        '<a name="custom"></a>\n```\n# not a heading\n```\n'
+    )
+
+    anchors = extract_anchors(text, is_markdown=True)
+
+    assert_that(anchors).is_equal_to({"intro", "setup", "intro-1", "custom"})
+
+
+def test_html_anchors_ignore_headings() -> None:
+    """HTML documents only define anchors through id and name attributes."""
+    anchors = extract_anchors('<h1 id="main"># Title</h1>', is_markdown=False)
+
+    assert_that(anchors).is_equal_to({"main"})
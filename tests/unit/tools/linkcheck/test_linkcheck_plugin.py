@@ -0,0 +1,72 @@
+"""Unit tests for the linkcheck plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.linkcheck import LinkcheckPlugin
+
+
+@pytest.fixture
+def linkcheck_plugin() -> LinkcheckPlugin:
+    """Provide a LinkcheckPlugin instance for testing.
+
+    Returns:
+        A LinkcheckPlugin instance.
+    """
+    return LinkcheckPlugin()
+
+
+def test_definition_is_native_linter(linkcheck_plugin: LinkcheckPlugin) -> None:
+    """Verify the plugin needs no external binary and cannot fix.
+
+    Args:
+        linkcheck_plugin: The plugin instance.
+    """
+    definition = linkcheck_plugin.definition
+    assert_that(definition.name).is_equal_to("linkcheck")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.file_patterns).contains("*.md", "*.html")
+
+
+def test_set_options_validates_values(linkcheck_plugin: LinkcheckPlugin) -> None:
+    """Verify invalid option values are rejected.
+
+    Args:
+        linkcheck_plugin: The plugin instance.
+    """
+    linkcheck_plugin.set_options(allow_domains="example.com", offline=True)
+
+    assert_that(linkcheck_plugin.options["allow_domains"]).is_equal_to(
+        ["example.com"],
+    )
+    with pytest.raises(ValueError, match="max_concurrency"):
+        linkcheck_plugin.set_options(max_concurrency=0)
+
+
+def test_offline_check_reports_relative_links_only(
+    linkcheck_plugin: LinkcheckPlugin,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Verify offline mode checks relative links without network access.
+
+    Args:
+        linkcheck_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "README.md").write_text(
+        "[a](missing.md) [b](https://example.invalid/)\n",
+    )
+
+    result = linkcheck_plugin.check([str(tmp_path)], {"offline": True})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("LC001")  # type: ignore[index]
@@ -0,0 +1 @@
+"""luacheck plugin tests."""
@@ -0,0 +1,17 @@
+"""Shared fixtures for luacheck plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.luacheck import LuacheckPlugin
+
+
+@pytest.fixture
+def luacheck_plugin() -> LuacheckPlugin:
+    """Provide a LuacheckPlugin instance for testing.
+
+    Returns:
+        LuacheckPlugin: A new LuacheckPlugin instance.
+    """
+    return LuacheckPlugin()
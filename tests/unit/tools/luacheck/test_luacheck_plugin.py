@@ -0,0 +1,134 @@
+"""Tests for LuacheckPlugin."""
+
+from __future__ import annotations
+
+import subprocess
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.luacheck import LuacheckPlugin
+
+
+def _context() -> MagicMock:
+    """Build an execution context for one Lua file.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = "/project/scripts"
+    ctx.rel_files = ["game.lua"]
+    ctx.files = ["/project/scripts/game.lua"]
+    return ctx
+
+
+def test_check_runs_plain_formatter(luacheck_plugin: LuacheckPlugin) -> None:
+    """Check runs luacheck with codes and ranges and parses its findings.
+
+    Args:
+        luacheck_plugin: The LuacheckPlugin instance to test.
+    """
+    luacheck_plugin.set_options(std="luajit")
+    output = (
+        "game.lua:3:7-9: (W211) unused variable 'foo'\n"
+        "game.lua:8:1-1: (E011) expected expression near 'end'\n"
+    )
+
+    with (
+        patch.object(luacheck_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            luacheck_plugin,
+            "_run_subprocess",
+            return_value=(False, output),
+        ) as mock_run,
+    ):
+        result = luacheck_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        [
+            "luacheck",
+            "--formatter=plain",
+            "--codes",
+            "--ranges",
+            "--no-color",
+            "--std",
+            "luajit",
+            "game.lua",
+        ],
+    )
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to("/project/scripts")
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_none()
+    assert_that(result.issues_count).is_equal_to(2)
+    assert_that(result.issues[1].code).is_equal_to("E011")
+
+
+def test_check_passes_clean_run(luacheck_plugin: LuacheckPlugin) -> None:
+    """A run without findings passes.
+
+    Args:
+        luacheck_plugin: The LuacheckPlugin instance to test.
+    """
+    with (
+        patch.object(luacheck_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(luacheck_plugin, "_run_subprocess", return_value=(True, "")),
+    ):
+        result = luacheck_plugin.check(["."], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.issues_count).is_equal_to(0)
+
+
+def test_check_reports_critical_errors(luacheck_plugin: LuacheckPlugin) -> None:
+    """A failed run without findings fails the result with its output.
+
+    Args:
+        luacheck_plugin: The LuacheckPlugin instance to test.
+    """
+    output = "Critical error: Couldn't find configuration file ci/luacheckrc"
+
+    with (
+        patch.object(luacheck_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(luacheck_plugin, "_run_subprocess", return_value=(False, output)),
+    ):
+        result = luacheck_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to(output)
+
+
+def test_check_reports_timeout(luacheck_plugin: LuacheckPlugin) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        luacheck_plugin: The LuacheckPlugin instance to test.
+    """
+    with (
+        patch.object(luacheck_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            luacheck_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["luacheck"], timeout=60),
+        ),
+    ):
+        result = luacheck_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("luacheck:timeout=N")
+
+
+def test_fix_is_not_supported(luacheck_plugin: LuacheckPlugin) -> None:
+    """luacheck cannot fix issues.
+
+    Args:
+        luacheck_plugin: The LuacheckPlugin instance to test.
+    """
+    with pytest.raises(NotImplementedError):
+        luacheck_plugin.fix(["."], {})
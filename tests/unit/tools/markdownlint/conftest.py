@@ -0,0 +1,23 @@
+"""Pytest configuration for markdownlint tests."""
+
+from __future__ import annotations
+
+from unittest.mock import patch
+
+import pytest
+
+from lintro.tools.definitions.markdownlint import MarkdownlintPlugin
+
+
+@pytest.fixture
+def markdownlint_plugin() -> MarkdownlintPlugin:
+    """Provide a MarkdownlintPlugin instance for testing.
+
+    Returns:
+        A MarkdownlintPlugin instance.
+    """
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        return MarkdownlintPlugin()
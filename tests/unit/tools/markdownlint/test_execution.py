@@ -0,0 +1,118 @@
+"""Unit tests for markdownlint plugin execution."""
+
+from __future__ import annotations
+
+import os
+from pathlib import Path
+from unittest.mock import patch
+
+from assertpy import assert_that
+
+from lintro.tools.definitions.markdownlint import MarkdownlintPlugin
+
+
+def test_check_passes_rules_config_and_removes_it(
+    markdownlint_plugin: MarkdownlintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Check runs with a temp config carrying the rules and deletes it.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    test_file = tmp_path / "README.md"
+    test_file.write_text("# Title\n")
+    markdownlint_plugin.set_options(rules={"MD041": False})
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(
+            markdownlint_plugin,
+            "_run_subprocess",
+            return_value=(True, ""),
+        ) as mock_run,
+    ):
+        result = markdownlint_plugin.check([str(test_file)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    config_path = cmd[cmd.index("--config") + 1]
+    assert_that(result.success).is_true()
+    assert_that(cmd).does_not_contain("--fix")
+    assert_that(os.path.exists(config_path)).is_false()
+
+
+def test_fix_reports_fixed_and_remaining_issues(
+    markdownlint_plugin: MarkdownlintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Fix counts issues before and after running with --fix.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    test_file = tmp_path / "README.md"
+    test_file.write_text("#Title\n\ntext   \n")
+    before = (
+        "README.md:1:1 MD018/no-missing-space-atx No space after hash\n"
+        "README.md:3:5 MD009/no-trailing-spaces Trailing spaces\n"
+        "README.md:3 MD013/line-length Line length"
+    )
+    after = "README.md:3 MD013/line-length Line length"
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(
+            markdownlint_plugin,
+            "_run_subprocess",
+            side_effect=[(False, before), (False, after)],
+        ) as mock_run,
+    ):
+        result = markdownlint_plugin.fix([str(test_file)], {})
+
+    assert_that(mock_run.call_args_list[1].kwargs["cmd"]).contains("--fix")
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.output).contains("Fixed 2 issue(s)")
+
+
+def test_fix_succeeds_when_all_issues_fixed(
+    markdownlint_plugin: MarkdownlintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Fix succeeds when nothing is left after --fix.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    test_file = tmp_path / "README.md"
+    test_file.write_text("#Title\n")
+    before = "README.md:1:1 MD018/no-missing-space-atx No space after hash"
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch.object(
+            markdownlint_plugin,
+            "_run_subprocess",
+            side_effect=[(False, before), (True, "")],
+        ),
+    ):
+        result = markdownlint_plugin.fix([str(test_file)], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("All issues were successfully auto-fixed")
@@ -0,0 +1,163 @@
+"""Unit tests for markdownlint plugin options and config generation."""
+
+from __future__ import annotations
+
+import json
+import os
+from pathlib import Path
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.markdownlint import MarkdownlintPlugin
+
+
+def _read_config(path: str) -> dict[str, object]:
+    """Read and delete a generated markdownlint-cli2 config.
+
+    Args:
+        path: Path of the generated config.
+
+    Returns:
+        The rule configuration under the ``config`` key.
+    """
+    try:
+        data = json.loads(Path(path).read_text(encoding="utf-8"))
+    finally:
+        os.unlink(path)
+    config: dict[str, object] = data["config"]
+    return config
+
+
+def test_default_options(markdownlint_plugin: MarkdownlintPlugin) -> None:
+    """Default options include an unset rules option.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    assert_that(markdownlint_plugin.definition.can_fix).is_true()
+    assert_that(markdownlint_plugin.definition.default_options).contains_entry(
+        {"rules": None},
+    )
+
+
+def test_set_options_rules(markdownlint_plugin: MarkdownlintPlugin) -> None:
+    """Set rules option correctly.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    markdownlint_plugin.set_options(rules={"MD041": False})
+
+    assert_that(markdownlint_plugin.options.get("rules")).is_equal_to(
+        {"MD041": False},
+    )
+
+
+def test_set_options_invalid_rules(markdownlint_plugin: MarkdownlintPlugin) -> None:
+    """Raise ValueError for rules that are not a dictionary.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="rules must be a dictionary"):
+        markdownlint_plugin.set_options(rules=["MD041"])  # type: ignore[arg-type]
+
+
+def test_temp_config_layers_defaults_line_length_and_rules(
+    markdownlint_plugin: MarkdownlintPlugin,
+) -> None:
+    """Rules override the line length, which overrides the defaults tier.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    path = markdownlint_plugin._create_temp_markdownlint_config(
+        line_length=100,
+        rules={"MD013": False, "MD041": False},
+        defaults={"MD013": {"line_length": 80}, "MD033": False},
+    )
+
+    assert_that(path).ends_with(".markdownlint-cli2.jsonc")
+    assert_that(_read_config(str(path))).is_equal_to(
+        {"MD013": False, "MD033": False, "MD041": False},
+    )
+
+
+def test_config_args_without_rules_use_config_injection(
+    markdownlint_plugin: MarkdownlintPlugin,
+) -> None:
+    """Lintro config injection is used unchanged when no rules are set.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    with patch.object(
+        markdownlint_plugin,
+        "_build_config_args",
+        return_value=["--config", "/tmp/defaults.markdownlint-cli2.jsonc"],
+    ):
+        args, temp_path = markdownlint_plugin._build_markdownlint_config_args()
+
+    assert_that(args).is_equal_to(
+        ["--config", "/tmp/defaults.markdownlint-cli2.jsonc"],
+    )
+    assert_that(temp_path).is_none()
+
+
+def test_config_args_with_rules_merge_defaults_tier(
+    markdownlint_plugin: MarkdownlintPlugin,
+) -> None:
+    """Rules are merged over the defaults tier when there is no native config.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    markdownlint_plugin.set_options(line_length=100, rules={"MD041": False})
+    lintro_config = MagicMock()
+    lintro_config.get_tool_defaults.return_value = {
+        "config": {"MD013": {"line_length": 88}},
+    }
+
+    with (
+        patch(
+            "lintro.tools.definitions.markdownlint.has_native_config",
+            return_value=False,
+        ),
+        patch.object(
+            markdownlint_plugin,
+            "_get_lintro_config",
+            return_value=lintro_config,
+        ),
+    ):
+        args, temp_path = markdownlint_plugin._build_markdownlint_config_args()
+
+    assert_that(args).is_equal_to(["--config", temp_path])
+    assert_that(_read_config(str(temp_path))).is_equal_to(
+        {"MD013": {"line_length": 88}, "MD041": False},
+    )
+
+
+def test_config_args_with_rules_skip_defaults_when_native_config_exists(
+    markdownlint_plugin: MarkdownlintPlugin,
+) -> None:
+    """The defaults tier is ignored when the project has a native config.
+
+    Args:
+        markdownlint_plugin: The MarkdownlintPlugin instance to test.
+    """
+    markdownlint_plugin.set_options(line_length=100, rules={"MD041": False})
+
+    with patch(
+        "lintro.tools.definitions.markdownlint.has_native_config",
+        return_value=True,
+    ):
+        _, temp_path = markdownlint_plugin._build_markdownlint_config_args()
+
+    assert_that(_read_config(str(temp_path))).is_equal_to(
+        {
+            "MD013": {"line_length": 100, "code_blocks": False, "tables": False},
+            "MD041": False,
+        },
+    )
@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 from typing import TYPE_CHECKING
+from unittest.mock import patch
 
 import pytest
 from assertpy import assert_that
@@ -195,3 +196,45 @@ def test_build_effective_excludes_converts_regex(mypy_plugin: MypyPlugin) -> Non
     result = mypy_plugin._build_effective_excludes(["^tests/.*$"])
 
     assert_that("tests/*" in result).is_true()
+
+
+@pytest.mark.parametrize(
+    ("options", "config_data", "expected"),
+    [
+        ({}, {}, ["--strict", "--ignore-missing-imports"]),
+        ({}, {"strict": False, "ignore_missing_imports": "True"}, []),
+        (
+            {"strict": True},
+            {"strict": False},
+            ["--strict", "--ignore-missing-imports"],
+        ),
+        ({"ignore_missing_imports": False}, {}, ["--strict"]),
+    ],
+    ids=["defaults", "project_config", "explicit_strict", "explicit_no_ignore"],
+)
+def test_build_command_honors_project_config(
+    mypy_plugin: MypyPlugin,
+    options: dict[str, bool],
+    config_data: dict[str, object],
+    expected: list[str],
+) -> None:
+    """Lintro's strict defaults only apply where the mypy config is silent.
+
+    Args:
+        mypy_plugin: The MypyPlugin instance to test.
+        options: Options set on the plugin.
+        config_data: Settings from the project's mypy config.
+        expected: Strictness flags expected on the command line.
+    """
+    mypy_plugin.set_options(**options)
+    mypy_plugin._config_data = config_data
+
+    with (
+        patch.object(mypy_plugin, "_get_executable_command", return_value=["mypy"]),
+        patch.object(mypy_plugin, "_build_config_args", return_value=[]),
+        patch.object(mypy_plugin, "_get_enforced_settings", return_value={}),
+    ):
+        cmd = mypy_plugin._build_command(["app.py"])
+
+    flags = ["--strict", "--ignore-missing-imports"]
+    assert_that([arg for arg in cmd if arg in flags]).is_equal_to(expected)
@@ -0,0 +1 @@
+"""Package.json plugin tests."""
@@ -0,0 +1,134 @@
+"""Unit tests for package.json manifest checks."""
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.package_json import (
+    check_package_json_text,
+    sort_package_json_text,
+)
+
+_VALID: dict[str, Any] = {
+    "name": "@acme/widget",
+    "version": "1.2.3",
+    "private": False,
+    "scripts": {"build": "tsc", "test:unit": "vitest"},
+    "dependencies": {"left-pad": "^1.3.0"},
+    "engines": {"node": ">=20"},
+}
+
+
+def _codes(data: Any, **kwargs: Any) -> list[str]:
+    """Run the checks on serialized data and return the reported codes.
+
+    Args:
+        data: JSON-serializable manifest.
+        **kwargs: Extra arguments for check_package_json_text.
+
+    Returns:
+        list[str]: Codes of the reported issues.
+    """
+    text = json.dumps(data, indent=2)
+    return [i.code for i in check_package_json_text(text, "package.json", **kwargs)]
+
+
+def test_valid_manifest_has_no_issues() -> None:
+    """A complete, sorted manifest produces no issues."""
+    assert_that(_codes(_VALID)).is_empty()
+
+
+def test_invalid_json_reports_pj000_with_location() -> None:
+    """Unparseable content is reported as PJ000 at the error position."""
+    issues = check_package_json_text('{\n  "name": \n}', "package.json")
+
+    assert_that([i.code for i in issues]).is_equal_to(["PJ000"])
+    assert_that(issues[0].line).is_equal_to(3)
+
+
+def test_non_object_reports_pj001() -> None:
+    """A top-level array is reported as PJ001."""
+    assert_that(_codes([1, 2])).is_equal_to(["PJ001"])
+
+
+def test_name_and_version_required_unless_private() -> None:
+    """Missing name/version only matter for publishable packages."""
+    public = _codes({"engines": {"node": ">=20"}})
+    private = _codes({"private": True, "engines": {"node": ">=20"}})
+
+    assert_that(public).is_equal_to(["PJ002", "PJ003"])
+    assert_that(private).is_empty()
+
+
+def test_invalid_name_and_version() -> None:
+    """Uppercase names and non-semver versions are rejected."""
+    data = {**_VALID, "name": "Widget", "version": "1.0"}
+
+    assert_that(_codes(data)).is_equal_to(["PJ002", "PJ003"])
+
+
+def test_field_types_are_validated() -> None:
+    """Known fields must have the expected JSON types."""
+    data = {**_VALID, "type": "esm", "files": "dist"}
+
+    assert_that(_codes(data, check_sorting=False)).is_equal_to(["PJ004", "PJ004"])
+
+
+def test_duplicate_dependency_is_reported() -> None:
+    """A package in both dependency maps is reported as PJ005."""
+    data = {**_VALID, "devDependencies": {"left-pad": "^1.3.0"}}
+
+    assert_that(_codes(data, check_sorting=False)).is_equal_to(["PJ005"])
+
+
+def test_engines_must_be_pinned() -> None:
+    """Missing or wildcard engine ranges are reported as PJ006."""
+    missing = {k: v for k, v in _VALID.items() if k != "engines"}
+    wildcard = {**_VALID, "engines": {"node": "*"}}
+
+    assert_that(_codes(missing)).is_equal_to(["PJ006"])
+    assert_that(_codes(wildcard)).is_equal_to(["PJ006"])
+    assert_that(_codes(missing, required_engines=[])).is_empty()
+
+
+def test_script_names_follow_convention() -> None:
+    """Script names outside the pattern are reported unless disabled."""
+    data = {**_VALID, "scripts": {"buildDocs": "typedoc"}}
+
+    assert_that(_codes(data)).is_equal_to(["PJ008"])
+    assert_that(_codes(data, script_name_pattern=None)).is_empty()
+
+
+def test_unsorted_keys_are_fixable() -> None:
+    """Out-of-order keys are reported as a fixable PJ007."""
+    data = {"version": "1.0.0", "name": "widget", "engines": {"node": ">=20"}}
+    issues = check_package_json_text(json.dumps(data), "package.json")
+
+    assert_that([i.code for i in issues]).is_equal_to(["PJ007"])
+    assert_that(issues[0].fixable).is_true()
+
+
+def test_sort_package_json_text_orders_keys_and_keeps_style() -> None:
+    """Sorting uses canonical order, sorts dependencies and keeps indentation."""
+    text = (
+        "{\n"
+        '    "scripts": {"test": "vitest", "build": "tsc"},\n'
+        '    "dependencies": {"zod": "^3.0.0", "axios": "^1.0.0"},\n'
+        '    "custom": true,\n'
+        '    "name": "widget"\n'
+        "}\n"
+    )
+
+    sorted_text = sort_package_json_text(text)
+
+    assert_that(sorted_text).is_not_none()
+    assert sorted_text is not None
+    data = json.loads(sorted_text)
+    assert_that(list(data)).is_equal_to(["name", "scripts", "dependencies", "custom"])
+    assert_that(list(data["dependencies"])).is_equal_to(["axios", "zod"])
+    assert_that(list(data["scripts"])).is_equal_to(["test", "build"])
+    assert_that(sorted_text).starts_with('{\n    "name"')
+    assert_that(sorted_text).ends_with("}\n")
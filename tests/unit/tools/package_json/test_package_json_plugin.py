@@ -0,0 +1,87 @@
+"""Unit tests for the package_json plugin."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.package_json import PackageJsonPlugin
+from lintro.tools.definitions.prettier import PRETTIER_DEFAULT_PRIORITY
+
+
+@pytest.fixture
+def package_json_plugin() -> PackageJsonPlugin:
+    """Provide a PackageJsonPlugin instance for testing.
+
+    Returns:
+        A PackageJsonPlugin instance.
+    """
+    return PackageJsonPlugin()
+
+
+def test_definition_is_native_and_fixable(
+    package_json_plugin: PackageJsonPlugin,
+) -> None:
+    """Verify the plugin needs no external binary and can fix.
+
+    Args:
+        package_json_plugin: The plugin instance.
+    """
+    definition = package_json_plugin.definition
+    assert_that(definition.name).is_equal_to("package_json")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).is_equal_to(["package.json"])
+    assert_that(definition.priority).is_less_than(PRETTIER_DEFAULT_PRIORITY)
+
+
+def test_check_reports_issues(
+    package_json_plugin: PackageJsonPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify check reports manifest issues.
+
+    Args:
+        package_json_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "package.json").write_text('{"private": true}\n')
+
+    result = package_json_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("PJ006")  # type: ignore[index]
+
+
+def test_fix_sorts_keys_and_reports_remaining(
+    package_json_plugin: PackageJsonPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify fix rewrites unsorted keys and keeps unfixable issues.
+
+    Args:
+        package_json_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    manifest = tmp_path / "package.json"
+    manifest.write_text(
+        json.dumps({"scripts": {"buildDocs": "typedoc"}, "private": True}, indent=2)
+        + "\n",
+    )
+
+    result = package_json_plugin.fix(
+        [str(tmp_path)],
+        {"required_engines": []},
+    )
+
+    assert_that(list(json.loads(manifest.read_text()))).is_equal_to(
+        ["private", "scripts"],
+    )
+    assert_that(result.initial_issues_count).is_equal_to(2)
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.success).is_false()
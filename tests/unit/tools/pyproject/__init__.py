@@ -0,0 +1 @@
+"""Pyproject plugin tests."""
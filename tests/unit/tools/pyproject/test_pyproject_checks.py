@@ -0,0 +1,136 @@
+"""Unit tests for pyproject.toml metadata checks."""
+
+from __future__ import annotations
+
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.pyproject import check_pyproject_text
+
+_COMPLETE = """\
+[project]
+name = "demo"
+version = "1.0.0"
+description = "Demo"
+readme = "README.md"
+requires-python = ">=3.11"
+license = "MIT"
+authors = [{ name = "Someone" }]
+"""
+
+
+def _codes(text: str, **kwargs: Any) -> list[str]:
+    """Run the checks and return the reported codes.
+
+    Args:
+        text: pyproject.toml content.
+        **kwargs: Extra arguments for check_pyproject_text.
+
+    Returns:
+        list[str]: Codes of the reported issues.
+    """
+    issues = check_pyproject_text(text, "pyproject.toml", **kwargs)
+    return [issue.code for issue in issues]
+
+
+def test_complete_project_has_no_issues() -> None:
+    """A complete [project] table produces no issues."""
+    assert_that(_codes(_COMPLETE)).is_empty()
+
+
+def test_invalid_toml_reports_pp000() -> None:
+    """Unparseable content is reported as PP000."""
+    assert_that(_codes("[project\nname = 1\n")).is_equal_to(["PP000"])
+
+
+def test_file_without_project_table_is_skipped() -> None:
+    """Tool-only pyproject files are not checked for metadata."""
+    assert_that(_codes("[tool.ruff]\nline-length = 88\n")).is_empty()
+
+
+def test_missing_name_and_version() -> None:
+    """Missing name and static-or-dynamic version are errors."""
+    codes = _codes('[project]\ndescription = "x"\n', recommended_fields=[])
+
+    assert_that(codes).is_equal_to(["PP001", "PP002"])
+
+
+def test_dynamic_version_is_accepted() -> None:
+    """A version listed in dynamic satisfies PP002."""
+    text = '[project]\nname = "demo"\ndynamic = ["version"]\n'
+
+    assert_that(_codes(text, recommended_fields=[])).is_empty()
+
+
+def test_missing_recommended_field_is_warning() -> None:
+    """Missing recommended fields are reported as warnings."""
+    text = '[project]\nname = "demo"\nversion = "1"\n'
+    issues = check_pyproject_text(text, "pyproject.toml", ["description"])
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].code).is_equal_to("PP003")
+    assert_that(issues[0].level).is_equal_to("warning")
+
+
+def test_static_and_dynamic_conflict() -> None:
+    """A field set statically and listed in dynamic is PP010."""
+    text = _COMPLETE + 'dynamic = ["description"]\n'
+
+    assert_that(_codes(text)).is_equal_to(["PP010"])
+
+
+def test_invalid_classifier() -> None:
+    """Classifiers outside the trove list are PP004."""
+    text = _COMPLETE + 'classifiers = ["Made Up :: Category"]\n'
+
+    assert_that(_codes(text)).is_equal_to(["PP004"])
+
+
+def test_invalid_dependency_and_requires_python() -> None:
+    """Bad PEP 508 dependencies and version specifiers are reported."""
+    text = _COMPLETE.replace(">=3.11", "3.11+") + 'dependencies = ["requests >>= 2"]\n'
+
+    assert_that(_codes(text)).contains("PP005", "PP006")
+
+
+def test_undefined_self_extra_and_unnormalized_group() -> None:
+    """Self-references must use defined, normalized extras."""
+    text = (
+        _COMPLETE
+        + "\n[project.optional-dependencies]\n"
+        + 'Dev_Tools = ["demo[missing]"]\n'
+    )
+
+    codes = _codes(text, check_tool_dependencies=False)
+
+    assert_that(codes).contains("PP007", "PP008")
+
+
+def test_tool_section_without_dependency() -> None:
+    """A [tool.*] section whose tool is never declared is PP009."""
+    text = (
+        _COMPLETE
+        + "\n[project.optional-dependencies]\n"
+        + 'dev = ["pytest>=8"]\n'
+        + "\n[tool.pytest.ini_options]\naddopts = '-q'\n"
+        + "\n[tool.mypy]\nstrict = true\n"
+    )
+
+    issues = check_pyproject_text(text, "pyproject.toml")
+
+    assert_that([i.code for i in issues]).is_equal_to(["PP009"])
+    assert_that(issues[0].message).contains("[tool.mypy]")
+    assert_that(issues[0].line).is_greater_than(0)
+
+
+def test_tool_dependency_check_can_be_disabled() -> None:
+    """PP009 is skipped when check_tool_dependencies is False."""
+    text = (
+        _COMPLETE
+        + "\n[project.optional-dependencies]\n"
+        + 'dev = ["pytest>=8"]\n'
+        + "\n[tool.mypy]\nstrict = true\n"
+    )
+
+    assert_that(_codes(text, check_tool_dependencies=False)).is_empty()
@@ -0,0 +1,81 @@
+"""Unit tests for the pyproject plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.pyproject import PyprojectPlugin
+
+
+@pytest.fixture
+def pyproject_plugin() -> PyprojectPlugin:
+    """Provide a PyprojectPlugin instance for testing.
+
+    Returns:
+        A PyprojectPlugin instance.
+    """
+    return PyprojectPlugin()
+
+
+def test_definition_is_native(pyproject_plugin: PyprojectPlugin) -> None:
+    """Verify the plugin needs no external binary.
+
+    Args:
+        pyproject_plugin: The plugin instance.
+    """
+    definition = pyproject_plugin.definition
+    assert_that(definition.name).is_equal_to("pyproject")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.file_patterns).is_equal_to(["pyproject.toml"])
+
+
+def test_set_options_accepts_single_field(
+    pyproject_plugin: PyprojectPlugin,
+) -> None:
+    """Verify recommended_fields accepts a single string.
+
+    Args:
+        pyproject_plugin: The plugin instance.
+    """
+    pyproject_plugin.set_options(recommended_fields="license")
+
+    assert_that(pyproject_plugin.options.get("recommended_fields")).is_equal_to(
+        ["license"],
+    )
+
+
+def test_check_reports_issues(
+    pyproject_plugin: PyprojectPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify check reports metadata issues from pyproject.toml.
+
+    Args:
+        pyproject_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "pyproject.toml").write_text('[project]\nversion = "1.0"\n')
+
+    result = pyproject_plugin.check([str(tmp_path)], {"recommended_fields": []})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("PP001")  # type: ignore[index]
+
+
+def test_fix_raises_not_implemented(
+    pyproject_plugin: PyprojectPlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify fix raises NotImplementedError.
+
+    Args:
+        pyproject_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    with pytest.raises(NotImplementedError):
+        pyproject_plugin.fix([str(tmp_path)], {})
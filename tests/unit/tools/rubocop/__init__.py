@@ -0,0 +1 @@
+"""RuboCop plugin tests."""
@@ -0,0 +1,17 @@
+"""Shared fixtures for RuboCop plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.rubocop import RubocopPlugin
+
+
+@pytest.fixture
+def rubocop_plugin() -> RubocopPlugin:
+    """Provide a RubocopPlugin instance for testing.
+
+    Returns:
+        RubocopPlugin: A new RubocopPlugin instance.
+    """
+    return RubocopPlugin()
@@ -0,0 +1,260 @@
+"""Tests for RubocopPlugin."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.rubocop import RubocopPlugin
+
+
+def _report(*corrected: bool) -> str:
+    """Build a RuboCop JSON report with one offense per flag.
+
+    Args:
+        *corrected: Whether each offense was corrected.
+
+    Returns:
+        str: The JSON report.
+    """
+    offenses = [
+        {
+            "severity": "convention",
+            "message": "Style/StringLiterals: Prefer single-quoted strings.",
+            "cop_name": "Style/StringLiterals",
+            "corrected": flag,
+            "correctable": True,
+            "location": {"start_line": index + 1, "start_column": 8},
+        }
+        for index, flag in enumerate(corrected)
+    ]
+    return json.dumps({"files": [{"path": "lib/app.rb", "offenses": offenses}]})
+
+
+def _context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for a Ruby file below the project root.
+
+    Args:
+        tmp_path: Root of the Ruby project.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 120
+    ctx.cwd = str(tmp_path / "lib")
+    ctx.rel_files = ["app.rb"]
+    ctx.files = [str(tmp_path / "lib" / "app.rb")]
+    return ctx
+
+
+def test_check_runs_rubocop_directly(
+    rubocop_plugin: RubocopPlugin,
+    tmp_path: Path,
+) -> None:
+    """Without a bundled rubocop, check runs rubocop in the files' directory.
+
+    Args:
+        rubocop_plugin: The RubocopPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    rubocop_plugin.set_options(config=".rubocop-ci.yml")
+
+    with (
+        patch.object(
+            rubocop_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.rubocop.get_bundled_gem_dir",
+            return_value=None,
+        ),
+        patch.object(
+            rubocop_plugin,
+            "_run_subprocess",
+            return_value=(False, _report(False)),
+        ) as mock_run,
+    ):
+        result = rubocop_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        [
+            "rubocop",
+            "--format",
+            "json",
+            "--force-exclusion",
+            "--config",
+            ".rubocop-ci.yml",
+            "app.rb",
+        ],
+    )
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(tmp_path / "lib"))
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("Style/StringLiterals")
+
+
+def test_check_uses_bundler_from_gemfile_directory(
+    rubocop_plugin: RubocopPlugin,
+    tmp_path: Path,
+) -> None:
+    """A bundled rubocop runs via bundle exec from the Gemfile directory.
+
+    Args:
+        rubocop_plugin: The RubocopPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            rubocop_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.rubocop.get_bundled_gem_dir",
+            return_value=tmp_path,
+        ),
+        patch.object(
+            rubocop_plugin,
+            "_run_subprocess",
+            return_value=(True, _report()),
+        ) as mock_run,
+    ):
+        result = rubocop_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        [
+            "bundle",
+            "exec",
+            "rubocop",
+            "--format",
+            "json",
+            "--force-exclusion",
+            "lib/app.rb",
+        ],
+    )
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(tmp_path))
+    assert_that(result.success).is_true()
+    assert_that(result.issues_count).is_equal_to(0)
+
+
+def test_check_reports_failed_runs(
+    rubocop_plugin: RubocopPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without a report fails the result with its output.
+
+    Args:
+        rubocop_plugin: The RubocopPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            rubocop_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.rubocop.get_bundled_gem_dir",
+            return_value=None,
+        ),
+        patch.object(
+            rubocop_plugin,
+            "_run_subprocess",
+            return_value=(False, "Error: unrecognized cop or department Foo/Bar"),
+        ),
+    ):
+        result = rubocop_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to(
+        "Error: unrecognized cop or department Foo/Bar",
+    )
+
+
+def test_check_reports_timeout(
+    rubocop_plugin: RubocopPlugin,
+    tmp_path: Path,
+) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        rubocop_plugin: The RubocopPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            rubocop_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.rubocop.get_bundled_gem_dir",
+            return_value=None,
+        ),
+        patch.object(
+            rubocop_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["rubocop"], timeout=120),
+        ),
+    ):
+        result = rubocop_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("rubocop:timeout=N")
+
+
+@pytest.mark.parametrize(
+    "unsafe,flag",
+    [(False, "--autocorrect"), (True, "--autocorrect-all")],
+)
+def test_fix_counts_corrected_offenses(
+    rubocop_plugin: RubocopPlugin,
+    tmp_path: Path,
+    unsafe: bool,
+    flag: str,
+) -> None:
+    """Fix autocorrects and reports the offenses RuboCop could not correct.
+
+    Args:
+        rubocop_plugin: The RubocopPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+        unsafe: Whether unsafe corrections are enabled.
+        flag: Expected autocorrect flag.
+    """
+    rubocop_plugin.set_options(unsafe=unsafe)
+
+    with (
+        patch.object(
+            rubocop_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.rubocop.get_bundled_gem_dir",
+            return_value=None,
+        ),
+        patch.object(
+            rubocop_plugin,
+            "_run_subprocess",
+            return_value=(False, _report(True, True, False)),
+        ) as mock_run,
+    ):
+        result = rubocop_plugin.fix(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).contains(flag)
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("Fixed 2 issue(s)")
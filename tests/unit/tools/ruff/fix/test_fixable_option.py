@@ -0,0 +1,60 @@
+"""Tests for execute_ruff_fix - Rule-scoped fixing scenarios."""
+
+from __future__ import annotations
+
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+from lintro.tools.implementations.ruff.commands import build_ruff_check_command
+from lintro.tools.implementations.ruff.fix import execute_ruff_fix
+
+
+def test_build_ruff_check_command_limits_fixable_rules(
+    mock_ruff_tool: MagicMock,
+) -> None:
+    """Fixable rules are passed to ruff check --fix only.
+
+    Args:
+        mock_ruff_tool: Mock RuffTool instance for testing.
+    """
+    mock_ruff_tool.options["fixable"] = ["I001", "F401"]
+
+    fix_cmd = build_ruff_check_command(mock_ruff_tool, ["a.py"], fix=True)
+    check_cmd = build_ruff_check_command(mock_ruff_tool, ["a.py"])
+
+    index = fix_cmd.index("--fixable")
+    assert_that(fix_cmd[index + 1]).is_equal_to("I001,F401")
+    assert_that(check_cmd).does_not_contain("--fixable")
+
+
+def test_execute_ruff_fix_skips_format_with_fixable_rules(
+    mock_ruff_tool: MagicMock,
+    sample_ruff_json_empty_output: str,
+) -> None:
+    """Formatting is left alone when only selected rules are fixed.
+
+    Args:
+        mock_ruff_tool: Mock RuffTool instance for testing.
+        sample_ruff_json_empty_output: Sample empty JSON output from ruff.
+    """
+    mock_ruff_tool.options["format"] = True
+    mock_ruff_tool.options["fixable"] = ["I001"]
+
+    with patch(
+        "lintro.tools.implementations.ruff.fix.walk_files_with_excludes",
+    ) as mock_walk:
+        mock_walk.return_value = ["test.py"]
+
+        mock_ruff_tool._run_subprocess.side_effect = [
+            (True, sample_ruff_json_empty_output),  # Initial lint check
+            (True, sample_ruff_json_empty_output),  # Lint fix
+        ]
+
+        result = execute_ruff_fix(mock_ruff_tool, ["test.py"])
+
+    assert_that(result.success).is_true()
+    commands = [
+        call.kwargs["cmd"] for call in mock_ruff_tool._run_subprocess.call_args_list
+    ]
+    assert_that([cmd[1] for cmd in commands]).is_equal_to(["check", "check"])
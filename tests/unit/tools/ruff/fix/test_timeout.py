@@ -139,39 +139,3 @@ def test_execute_ruff_fix_timeout_on_format_command(
     assert_that(result.success).is_false()
     assert_that(result.output).contains("timed out")
 
-
-def test_execute_ruff_fix_timeout_on_unsafe_check_continues(
-    mock_ruff_tool: MagicMock,
-) -> None:
-    """Continue execution when unsafe fix check times out.
-
-    Args:
-        mock_ruff_tool: Mock RuffTool instance for testing.
-    """
-    remaining_output = """[
-        {
-            "code": "F841",
-            "message": "Local variable 'x' is assigned but never used",
-            "filename": "test.py",
-            "location": {"row": 1, "column": 1},
-            "end_location": {"row": 1, "column": 5},
-            "fix": {"applicability": "unsafe"}
-        }
-    ]"""
-
-    with patch(
-        "lintro.tools.implementations.ruff.fix.walk_files_with_excludes",
-    ) as mock_walk:
-        mock_walk.return_value = ["test.py"]
-
-        mock_ruff_tool._run_subprocess.side_effect = [
-            (False, remaining_output),  # Initial check
-            (False, remaining_output),  # Fix attempt
-            subprocess.TimeoutExpired(cmd=["ruff"], timeout=30),  # Unsafe check timeout
-        ]
-
-        result = execute_ruff_fix(mock_ruff_tool, ["test.py"])
-
-    # Should still complete with remaining issues
-    assert_that(result.success).is_false()
-    assert_that(result.remaining_issues_count).is_equal_to(1)
@@ -39,7 +39,7 @@ def test_execute_ruff_fix_unsafe_fixes_enabled(
 def test_execute_ruff_fix_warns_about_unsafe_fixes(
     mock_ruff_tool: MagicMock,
 ) -> None:
-    """Warn when remaining issues could be fixed with unsafe fixes.
+    """Warn about remaining unsafe fixes without applying them.
 
     Args:
         mock_ruff_tool: Mock RuffTool instance for testing.
@@ -66,11 +66,17 @@ def test_execute_ruff_fix_warns_about_unsafe_fixes(
         mock_ruff_tool._run_subprocess.side_effect = [
             (False, remaining_output),  # Initial check
             (False, remaining_output),  # Fix attempt
-            (True, "[]"),  # Unsafe fix would fix it
         ]
 
-        execute_ruff_fix(mock_ruff_tool, ["test.py"])
+        result = execute_ruff_fix(mock_ruff_tool, ["test.py"])
 
     mock_logger.warning.assert_called()
     warning_msg = str(mock_logger.warning.call_args)
-    assert_that(warning_msg).contains("unsafe")
+    assert_that(warning_msg).contains("1 remaining issue(s) have only unsafe fixes")
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    # The applicability comes from the fix run; no unsafe run writes files
+    commands = [
+        call.kwargs["cmd"] for call in mock_ruff_tool._run_subprocess.call_args_list
+    ]
+    assert_that(commands).is_length(2)
+    assert_that(commands[1]).contains("--fix", "--no-unsafe-fixes")
@@ -3,11 +3,13 @@
 from __future__ import annotations
 
 from pathlib import Path
+from typing import Any
 from unittest.mock import patch
 
 from assertpy import assert_that
 
 from lintro.tools.definitions.rustfmt import RustfmtPlugin
+from lintro.utils.cargo_workspace import CargoPackage, CargoWorkspace
 
 # =============================================================================
 # Tests for RustfmtPlugin.check method
@@ -252,3 +254,64 @@ def test_fix_with_nothing_to_fix(
     assert_that(result.success).is_true()
     assert_that(result.issues_count).is_equal_to(0)
     assert_that(result.fixed_issues_count).is_equal_to(0)
+
+
+def test_check_selects_workspace_members(
+    rustfmt_plugin: RustfmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """Files in one member are checked from the root with ``-p``.
+
+    Args:
+        rustfmt_plugin: The RustfmtPlugin instance to test.
+        tmp_path: Temporary workspace root.
+    """
+    (tmp_path / "Cargo.toml").write_text('[workspace]\nmembers = ["crates/*"]\n')
+    for name in ("core", "cli"):
+        member = tmp_path / "crates" / name
+        (member / "src").mkdir(parents=True)
+        (member / "Cargo.toml").write_text(f'[package]\nname = "{name}"\n')
+        (member / "src" / "lib.rs").write_text("fn f(){}\n")
+    workspace = CargoWorkspace(
+        root=tmp_path.resolve(),
+        members=tuple(
+            CargoPackage(name=name, root=(tmp_path / "crates" / name).resolve())
+            for name in ("core", "cli")
+        ),
+    )
+    calls: list[tuple[list[str], str | None]] = []
+
+    def fake_run(cmd: list[str], **kwargs: Any) -> tuple[bool, str]:
+        """Record a command and report one formatting difference.
+
+        Args:
+            cmd: Command that would run.
+            **kwargs: Subprocess options, including ``cwd``.
+
+        Returns:
+            tuple[bool, str]: Failure with a rustfmt diff.
+        """
+        calls.append((cmd, kwargs.get("cwd")))
+        return False, f"Diff in {tmp_path}/crates/core/src/lib.rs:1:\n-fn f(){{}}\n"
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch(
+            "lintro.utils.cargo_workspace.load_workspace",
+            return_value=workspace,
+        ),
+        patch.object(rustfmt_plugin, "_run_subprocess", side_effect=fake_run),
+    ):
+        result = rustfmt_plugin.check(
+            [str(tmp_path / "crates" / "core" / "src" / "lib.rs")],
+            {},
+        )
+
+    assert_that(calls[0][0]).is_equal_to(
+        ["cargo", "fmt", "-p", "core", "--", "--check"],
+    )
+    assert_that(calls[0][1]).is_equal_to(str(tmp_path.resolve()))
+    assert_that(result.issues[0].crate_name).is_equal_to("core")
@@ -0,0 +1,118 @@
+"""Unit tests for reporting rustfmt settings cargo fmt drops."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.parsers.rustfmt.rustfmt_issue import RustfmtIssue
+from lintro.tools.definitions.rustfmt import RustfmtPlugin
+from lintro.utils.cargo_workspace import CargoTarget
+from lintro.utils.rustfmt_settings import crate_edition, rustfmt_settings
+
+UNSTABLE_WARNING = (
+    "Warning: can't set `imports_granularity = Crate`, unstable features are "
+    "only available in nightly channel."
+)
+
+
+@pytest.mark.parametrize(
+    ("package", "edition"),
+    [
+        ('edition = "2021"', "2021"),
+        ("edition = 2018", "2018"),
+        ("edition.workspace = true", "2024"),
+        ("", "2015"),
+    ],
+)
+def test_crate_edition(tmp_path: Path, package: str, edition: str) -> None:
+    """The edition comes from the manifest or the workspace it inherits from.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        package: Edition line of the member's manifest.
+        edition: Expected edition.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[workspace]\nmembers = ["core"]\n\n[workspace.package]\nedition = "2024"\n',
+    )
+    member = tmp_path / "core"
+    member.mkdir()
+    (member / "Cargo.toml").write_text(f'[package]\nname = "core"\n{package}\n')
+
+    assert_that(crate_edition(member)).is_equal_to(edition)
+
+
+def test_rustfmt_settings_prefers_hidden_config(tmp_path: Path) -> None:
+    """The nearest config wins, and .rustfmt.toml before rustfmt.toml.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "rustfmt.toml").write_text('edition = "2018"\n')
+    crate = tmp_path / "crate"
+    crate.mkdir()
+    (crate / "Cargo.toml").write_text('[package]\nname = "c"\nedition = "2021"\n')
+
+    settings = rustfmt_settings(crate)
+    assert_that(settings.config_path).is_equal_to(tmp_path / "rustfmt.toml")
+    assert_that(settings.edition_mismatch).is_true()
+
+    (tmp_path / ".rustfmt.toml").write_text("max_width = 100\n")
+    settings = rustfmt_settings(crate)
+    assert_that(settings.config_path).is_equal_to(tmp_path / ".rustfmt.toml")
+    assert_that(settings.edition_mismatch).is_false()
+
+
+def test_check_reports_dropped_settings(
+    rustfmt_plugin: RustfmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """Overridden editions and ignored nightly options point at the config.
+
+    Args:
+        rustfmt_plugin: The RustfmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "demo"\nversion = "0.1.0"\nedition = "2021"\n',
+    )
+    config = tmp_path / "rustfmt.toml"
+    config.write_text('edition = "2018"\nimports_granularity = "Crate"\n')
+    source = tmp_path / "src" / "main.rs"
+    source.parent.mkdir()
+    source.write_text("fn main() {}\n")
+
+    with (
+        patch(
+            "lintro.plugins.execution_preparation.verify_tool_version",
+            return_value=None,
+        ),
+        patch(
+            "lintro.tools.definitions.rustfmt.resolve_target",
+            side_effect=lambda files, root: CargoTarget(root=root),
+        ),
+        patch.object(
+            rustfmt_plugin,
+            "_run_subprocess",
+            return_value=(True, UNSTABLE_WARNING),
+        ),
+    ):
+        result = rustfmt_plugin.check([str(source)], {})
+
+    issues = [issue for issue in result.issues or [] if isinstance(issue, RustfmtIssue)]
+    assert_that([issue.code for issue in issues]).is_equal_to(
+        ["edition_mismatch", "unstable_option"],
+    )
+    edition, unstable = issues
+    assert_that(edition.file).is_equal_to(str(config))
+    assert_that(edition.line).is_equal_to(1)
+    assert_that(edition.message).contains("edition 2018", "edition 2021")
+    assert_that(unstable.line).is_equal_to(2)
+    assert_that(unstable.message).contains("imports_granularity = Crate")
+    assert_that(unstable.get_severity()).is_equal_to(SeverityLevel.WARNING)
+    assert_that(unstable.fixable).is_false()
@@ -76,6 +76,33 @@ def test_check_with_mocked_subprocess_issues(
     assert_that(result.issues_count).is_greater_than(0)
 
 
+def test_check_includes_extensionless_scripts_with_shell_shebang(
+    shellcheck_plugin: ShellcheckPlugin,
+    tmp_path: Path,
+) -> None:
+    """Extensionless scripts with a shell shebang are checked.
+
+    Args:
+        shellcheck_plugin: The ShellcheckPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    scripts = tmp_path / "scripts"
+    scripts.mkdir()
+    (scripts / "deploy").write_text("#!/usr/bin/env bash\necho deploy\n")
+    (scripts / "migrate").write_text("#!/usr/bin/env python3\nprint()\n")
+
+    with patch.object(
+        shellcheck_plugin,
+        "_run_subprocess",
+        return_value=(True, "[]"),
+    ) as mock_run:
+        shellcheck_plugin.check([str(tmp_path)], {})
+
+    checked = [arg for call in mock_run.call_args_list for arg in call.kwargs["cmd"]]
+    assert_that([arg for arg in checked if arg.endswith("deploy")]).is_length(1)
+    assert_that(" ".join(checked)).does_not_contain("migrate")
+
+
 def test_check_with_no_shell_files(
     shellcheck_plugin: ShellcheckPlugin,
     tmp_path: Path,
@@ -86,6 +86,37 @@ def test_check_with_mocked_subprocess_issues(
     assert_that(result.issues_count).is_greater_than(0)
 
 
+def test_check_includes_extensionless_scripts_with_shell_shebang(
+    shfmt_plugin: ShfmtPlugin,
+    tmp_path: Path,
+) -> None:
+    """Extensionless scripts with a shell shebang are checked.
+
+    Args:
+        shfmt_plugin: The ShfmtPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    bin_dir = tmp_path / "bin"
+    bin_dir.mkdir()
+    (bin_dir / "release").write_text("#!/bin/sh\necho release\n")
+    (bin_dir / "report").write_text("#!/usr/bin/env ruby\nputs 1\n")
+
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        with patch.object(
+            shfmt_plugin,
+            "_run_subprocess",
+            return_value=(True, ""),
+        ) as mock_run:
+            shfmt_plugin.check([str(tmp_path)], {})
+
+    checked = [call.kwargs["cmd"][-1] for call in mock_run.call_args_list]
+    assert_that(checked).is_length(1)
+    assert_that(checked[0]).ends_with("release")
+
+
 def test_check_with_no_shell_files(
     shfmt_plugin: ShfmtPlugin,
     tmp_path: Path,
@@ -29,6 +29,7 @@ if TYPE_CHECKING:
         ("binary_next_line", False),
         ("switch_case_indent", False),
         ("space_redirects", False),
+        ("func_next_line", False),
         ("language_dialect", None),
         ("simplify", False),
     ],
@@ -38,6 +39,7 @@ if TYPE_CHECKING:
         "binary_next_line_is_false",
         "switch_case_indent_is_false",
         "space_redirects_is_false",
+        "func_next_line_is_false",
         "language_dialect_is_none",
         "simplify_is_false",
     ],
@@ -73,6 +75,7 @@ def test_default_options_values(
         ("binary_next_line", True),
         ("switch_case_indent", True),
         ("space_redirects", True),
+        ("func_next_line", True),
         ("language_dialect", "bash"),
         ("language_dialect", "posix"),
         ("language_dialect", "mksh"),
@@ -86,6 +89,7 @@ def test_default_options_values(
         "binary_next_line_true",
         "switch_case_indent_true",
         "space_redirects_true",
+        "func_next_line_true",
         "language_dialect_bash",
         "language_dialect_posix",
         "language_dialect_mksh",
@@ -135,6 +139,7 @@ def test_set_options_language_dialect_case_insensitive(
         ("binary_next_line", 1, "binary_next_line must be a boolean"),
         ("switch_case_indent", "true", "switch_case_indent must be a boolean"),
         ("space_redirects", "no", "space_redirects must be a boolean"),
+        ("func_next_line", "yes", "func_next_line must be a boolean"),
         ("language_dialect", 123, "language_dialect must be a string"),
         ("language_dialect", "invalid", "Invalid language_dialect"),
         ("language_dialect", "sh", "Invalid language_dialect"),
@@ -147,6 +152,7 @@ def test_set_options_language_dialect_case_insensitive(
         "invalid_binary_next_line_int",
         "invalid_switch_case_indent_string",
         "invalid_space_redirects_string",
+        "invalid_func_next_line_string",
         "invalid_language_dialect_int",
         "invalid_language_dialect_value",
         "invalid_language_dialect_sh",
@@ -250,6 +256,18 @@ def test_build_common_args_with_space_redirects(shfmt_plugin: ShfmtPlugin) -> No
     assert_that(args).contains("-sr")
 
 
+def test_build_common_args_with_func_next_line(shfmt_plugin: ShfmtPlugin) -> None:
+    """Build common args with func_next_line option.
+
+    Args:
+        shfmt_plugin: The ShfmtPlugin instance to test.
+    """
+    shfmt_plugin.set_options(func_next_line=True)
+    args = shfmt_plugin._build_common_args()
+
+    assert_that(args).contains("-fn")
+
+
 def test_build_common_args_with_language_dialect(shfmt_plugin: ShfmtPlugin) -> None:
     """Build common args with language_dialect option.
 
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import json
 from pathlib import Path
 from unittest.mock import patch
 
@@ -169,3 +170,128 @@ def test_fix_with_no_sql_files(
 
     assert_that(result.success).is_true()
     assert_that(result.output).contains("No")
+
+
+def _violations(count: int) -> str:
+    """Serialize a SQLFluff report with the given number of violations.
+
+    Args:
+        count: Number of violations in the report.
+
+    Returns:
+        str: The report as ``sqlfluff lint --format json`` prints it.
+    """
+    violation = {
+        "start_line_no": 1,
+        "start_line_pos": 1,
+        "code": "CP01",
+        "description": "Keywords must be consistently upper case.",
+        "name": "capitalisation.keywords",
+    }
+    return json.dumps([{"filepath": "query.sql", "violations": [violation] * count}])
+
+
+def test_fix_reports_fixed_and_remaining_counts(
+    sqlfluff_plugin: SqlfluffPlugin,
+    tmp_path: Path,
+) -> None:
+    """Fix lints before and after fixing and reports what is left.
+
+    Args:
+        sqlfluff_plugin: The SqlfluffPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    test_file = tmp_path / "query.sql"
+    test_file.write_text("select a from t;\n")
+    outputs = [(False, _violations(3)), (True, ""), (False, _violations(1))]
+
+    with patch.object(
+        sqlfluff_plugin,
+        "_run_subprocess",
+        side_effect=outputs,
+    ) as mock_run:
+        result = sqlfluff_plugin.fix([str(test_file)], {})
+
+    commands = [call.kwargs["cmd"] for call in mock_run.call_args_list]
+    assert_that([cmd[1] for cmd in commands]).is_equal_to(["lint", "fix", "lint"])
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("Fixed 2 issue(s)")
+
+
+def test_check_without_dialect_explains_fix(
+    sqlfluff_plugin: SqlfluffPlugin,
+    tmp_path: Path,
+) -> None:
+    """A missing dialect fails with a hint on how to set one.
+
+    Args:
+        sqlfluff_plugin: The SqlfluffPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    test_file = tmp_path / "query.sql"
+    test_file.write_text("SELECT 1;\n")
+    output = "User Error: No dialect was specified. You must configure a dialect."
+
+    with patch.object(
+        sqlfluff_plugin,
+        "_run_subprocess",
+        return_value=(False, output),
+    ):
+        result = sqlfluff_plugin.check([str(test_file)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("sqlfluff:dialect=")
+
+
+def test_dbt_models_run_from_project_root(
+    sqlfluff_plugin: SqlfluffPlugin,
+    tmp_path: Path,
+) -> None:
+    """Models linted with the dbt templater run from the dbt project.
+
+    Args:
+        sqlfluff_plugin: The SqlfluffPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    project = tmp_path / "analytics"
+    (project / "models").mkdir(parents=True)
+    (project / "dbt_project.yml").write_text("name: analytics\n")
+    (project / ".sqlfluff").write_text("[sqlfluff]\ntemplater = dbt\n")
+    model = project / "models" / "orders.sql"
+    model.write_text("select * from {{ ref('stg_orders') }}\n")
+
+    with patch.object(
+        sqlfluff_plugin,
+        "_run_subprocess",
+        return_value=(True, "[]"),
+    ) as mock_run:
+        sqlfluff_plugin.check([str(model)], {})
+
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(project.resolve()))
+
+
+def test_plain_sql_runs_from_current_directory(
+    sqlfluff_plugin: SqlfluffPlugin,
+    tmp_path: Path,
+) -> None:
+    """Files outside the dbt templater keep the current directory.
+
+    Args:
+        sqlfluff_plugin: The SqlfluffPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "dbt_project.yml").write_text("name: analytics\n")
+    test_file = tmp_path / "query.sql"
+    test_file.write_text("SELECT 1;\n")
+
+    with patch.object(
+        sqlfluff_plugin,
+        "_run_subprocess",
+        return_value=(True, "[]"),
+    ) as mock_run:
+        sqlfluff_plugin.check([str(test_file)], {})
+
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_none()
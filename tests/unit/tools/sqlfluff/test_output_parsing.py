@@ -132,3 +132,33 @@ def test_parse_sqlfluff_output_none() -> None:
     issues = parse_sqlfluff_output(None)
 
     assert_that(issues).is_empty()
+
+
+def test_parse_sqlfluff_output_fixable() -> None:
+    """Violations with fixes are fixable; the others are not."""
+    output = """[
+        {
+            "filepath": "test.sql",
+            "violations": [
+                {
+                    "start_line_no": 1,
+                    "start_line_pos": 1,
+                    "code": "CP01",
+                    "description": "Keywords must be consistently upper case.",
+                    "name": "capitalisation.keywords",
+                    "fixes": [{"type": "replace", "edit": "SELECT"}]
+                },
+                {
+                    "start_line_no": 2,
+                    "start_line_pos": 1,
+                    "code": "AM04",
+                    "description": "Query produces an unknown number of columns.",
+                    "name": "ambiguous.column_count",
+                    "fixes": []
+                }
+            ]
+        }
+    ]"""
+    issues = parse_sqlfluff_output(output)
+
+    assert_that([issue.fixable for issue in issues]).is_equal_to([True, False])
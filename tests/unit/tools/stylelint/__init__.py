@@ -0,0 +1 @@
+"""Stylelint plugin tests."""
@@ -0,0 +1,17 @@
+"""Shared fixtures for stylelint plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.stylelint import StylelintPlugin
+
+
+@pytest.fixture
+def stylelint_plugin() -> StylelintPlugin:
+    """Provide a StylelintPlugin instance for testing.
+
+    Returns:
+        StylelintPlugin: A new StylelintPlugin instance.
+    """
+    return StylelintPlugin()
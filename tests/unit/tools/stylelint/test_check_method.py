@@ -0,0 +1,238 @@
+"""Tests for StylelintPlugin.check method."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.stylelint import StylelintPlugin
+
+
+def _mock_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one style sheet.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["app.scss"]
+    ctx.files = [str(tmp_path / "app.scss")]
+    return ctx
+
+
+def test_check_reports_warnings(
+    stylelint_plugin: StylelintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Check runs the JSON formatter and keeps each rule's severity.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".stylelintrc.json").write_text("{}")
+    output = json.dumps(
+        [
+            {
+                "source": str(tmp_path / "app.scss"),
+                "warnings": [
+                    {
+                        "line": 1,
+                        "column": 5,
+                        "rule": "block-no-empty",
+                        "severity": "error",
+                        "text": "Unexpected empty block (block-no-empty)",
+                    },
+                    {
+                        "line": 4,
+                        "column": 10,
+                        "rule": "color-named",
+                        "severity": "warning",
+                        "text": 'Unexpected named color "red" (color-named)',
+                    },
+                ],
+            },
+        ],
+    )
+    stylelint_plugin.set_options(quiet=True)
+
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_get_executable_command",
+            return_value=["stylelint"],
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_run_subprocess",
+            return_value=(False, output),
+        ) as mock_run,
+    ):
+        result = stylelint_plugin.check([str(tmp_path)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd[:3]).is_equal_to(["stylelint", "--formatter", "json"])
+    assert_that(cmd).contains("--allow-empty-input", "--quiet")
+    assert_that(cmd).does_not_contain("--fix")
+    assert_that(cmd[-1]).is_equal_to("app.scss")
+    assert_that(result.success).is_false()
+    assert_that([issue.severity for issue in result.issues]).is_equal_to(
+        ["error", "warning"],
+    )
+
+
+def test_check_skips_without_config(
+    stylelint_plugin: StylelintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Without a config, Stylelint is skipped instead of failing.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.stylelint.find_stylelint_config",
+            return_value=None,
+        ),
+        patch.object(stylelint_plugin, "_run_subprocess") as mock_run,
+    ):
+        result = stylelint_plugin.check([str(tmp_path)], {})
+
+    mock_run.assert_not_called()
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to("no config")
+
+
+def test_check_uses_configured_config(
+    stylelint_plugin: StylelintPlugin,
+    tmp_path: Path,
+) -> None:
+    """An explicit config is passed on and needs no config lookup.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    stylelint_plugin.set_options(config="config/stylelint.json")
+
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.stylelint.find_stylelint_config",
+            return_value=None,
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_get_executable_command",
+            return_value=["stylelint"],
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_run_subprocess",
+            return_value=(True, "[]"),
+        ) as mock_run,
+    ):
+        result = stylelint_plugin.check([str(tmp_path)], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd).contains("--config", "config/stylelint.json")
+    assert_that(result.success).is_true()
+    assert_that(result.output).is_none()
+
+
+def test_check_reports_config_errors(
+    stylelint_plugin: StylelintPlugin,
+    tmp_path: Path,
+) -> None:
+    """A failed run without a report fails with Stylelint's message.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".stylelintrc.json").write_text("{}")
+    message = 'Error: Could not find "stylelint-config-standard".'
+
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_get_executable_command",
+            return_value=["stylelint"],
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_run_subprocess",
+            return_value=(False, message),
+        ),
+    ):
+        result = stylelint_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).is_equal_to(message)
+
+
+def test_check_timeout(stylelint_plugin: StylelintPlugin, tmp_path: Path) -> None:
+    """A timeout is reported as a failed result.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".stylelintrc.json").write_text("{}")
+
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_get_executable_command",
+            return_value=["stylelint"],
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["stylelint"], timeout=60),
+        ),
+    ):
+        result = stylelint_plugin.check([str(tmp_path)], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
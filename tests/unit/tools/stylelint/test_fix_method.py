@@ -0,0 +1,139 @@
+"""Tests for StylelintPlugin.fix method."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.stylelint import StylelintPlugin
+
+
+def _report(*rules: str) -> str:
+    """Build a Stylelint JSON report with one error per rule.
+
+    Args:
+        *rules: Rules to report.
+
+    Returns:
+        str: JSON report.
+    """
+    return json.dumps(
+        [
+            {
+                "source": "/project/app.css",
+                "warnings": [
+                    {
+                        "line": 1,
+                        "column": 1,
+                        "rule": rule,
+                        "severity": "error",
+                        "text": f"{rule} violation ({rule})",
+                    }
+                    for rule in rules
+                ],
+            },
+        ],
+    )
+
+
+def _mock_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one style sheet.
+
+    Args:
+        tmp_path: Working directory of the run, holding a config.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    (tmp_path / ".stylelintrc.json").write_text("{}")
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["app.css"]
+    ctx.files = [str(tmp_path / "app.css")]
+    return ctx
+
+
+def test_fix_counts_fixed_and_remaining(
+    stylelint_plugin: StylelintPlugin,
+    tmp_path: Path,
+) -> None:
+    """The fix run's report gives the issues left after fixing.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    outputs = [
+        (False, _report("color-hex-length", "length-zero-no-unit", "block-no-empty")),
+        (False, _report("block-no-empty")),
+    ]
+
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_get_executable_command",
+            return_value=["stylelint"],
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_run_subprocess",
+            side_effect=outputs,
+        ) as mock_run,
+    ):
+        result = stylelint_plugin.fix([str(tmp_path)], {})
+
+    assert_that(mock_run.call_count).is_equal_to(2)
+    fix_cmd = mock_run.call_args_list[1].kwargs["cmd"]
+    assert_that(fix_cmd).contains("--fix", "--formatter")
+    assert_that(fix_cmd[-1]).is_equal_to("app.css")
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("Fixed 2 issue(s)")
+    assert_that(result.output).contains("cannot be auto-fixed")
+
+
+def test_fix_all_fixed(stylelint_plugin: StylelintPlugin, tmp_path: Path) -> None:
+    """Fix succeeds when nothing is left.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    outputs = [
+        (False, _report("color-hex-length")),
+        (True, _report()),
+    ]
+
+    with (
+        patch.object(
+            stylelint_plugin,
+            "_prepare_execution",
+            return_value=_mock_context(tmp_path),
+        ),
+        patch.object(
+            stylelint_plugin,
+            "_get_executable_command",
+            return_value=["stylelint"],
+        ),
+        patch.object(stylelint_plugin, "_run_subprocess", side_effect=outputs),
+    ):
+        result = stylelint_plugin.fix([str(tmp_path)], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("All issues were successfully auto-fixed")
@@ -0,0 +1,116 @@
+"""Tests for StylelintPlugin definition, options and config lookup."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.stylelint import (
+    STYLELINT_DEFAULT_TIMEOUT,
+    find_stylelint_config,
+)
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.stylelint import StylelintPlugin
+
+
+def test_definition_covers_style_sheets(stylelint_plugin: StylelintPlugin) -> None:
+    """Stylelint is a fixing linter for CSS, SCSS and Less.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+    """
+    definition = stylelint_plugin.definition
+
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).is_equal_to(["*.css", "*.scss", "*.less"])
+    assert_that(definition.native_configs).contains(
+        ".stylelintrc.json",
+        "stylelint.config.mjs",
+    )
+    assert_that(definition.default_options).is_equal_to(
+        {"timeout": STYLELINT_DEFAULT_TIMEOUT, "quiet": False},
+    )
+
+
+def test_set_options_valid(stylelint_plugin: StylelintPlugin) -> None:
+    """Valid options are stored.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+    """
+    stylelint_plugin.set_options(
+        timeout=120,
+        config="config/stylelint.json",
+        quiet=True,
+        verbose_fix_output=True,
+    )
+
+    assert_that(stylelint_plugin.options).contains_entry(
+        {"timeout": 120},
+        {"config": "config/stylelint.json"},
+        {"quiet": True},
+        {"verbose_fix_output": True},
+    )
+
+
+@pytest.mark.parametrize(
+    ("option_name", "value", "message"),
+    [
+        ("timeout", -1, "timeout must be positive"),
+        ("config", 1, "config must be a string"),
+        ("quiet", "yes", "quiet must be a boolean"),
+    ],
+    ids=["negative_timeout", "non_string_config", "non_bool_quiet"],
+)
+def test_set_options_invalid(
+    stylelint_plugin: StylelintPlugin,
+    option_name: str,
+    value: object,
+    message: str,
+) -> None:
+    """Invalid option values raise ValueError.
+
+    Args:
+        stylelint_plugin: The StylelintPlugin instance to test.
+        option_name: Option to set.
+        value: Invalid value for the option.
+        message: Expected error message.
+    """
+    with pytest.raises(ValueError, match=message):
+        stylelint_plugin.set_options(**{option_name: value})  # type: ignore[arg-type]
+
+
+def test_find_config_searches_parents(tmp_path: Path) -> None:
+    """The nearest config in the directory or a parent is found.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".stylelintrc.json").write_text("{}")
+    nested = tmp_path / "packages" / "web"
+    nested.mkdir(parents=True)
+
+    assert_that(find_stylelint_config(nested)).is_equal_to(
+        tmp_path / ".stylelintrc.json",
+    )
+
+
+def test_find_config_reads_package_json(tmp_path: Path) -> None:
+    """A package.json counts only when it has a stylelint key.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    package_json = tmp_path / "package.json"
+    package_json.write_text(json.dumps({"name": "web"}))
+
+    assert_that(find_stylelint_config(tmp_path)).is_none()
+
+    package_json.write_text(json.dumps({"name": "web", "stylelint": {}}))
+
+    assert_that(find_stylelint_config(tmp_path)).is_equal_to(package_json)
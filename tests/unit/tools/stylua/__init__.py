@@ -0,0 +1 @@
+"""StyLua plugin tests."""
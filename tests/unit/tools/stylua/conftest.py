@@ -0,0 +1,17 @@
+"""Shared fixtures for StyLua plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.stylua import StyluaPlugin
+
+
+@pytest.fixture
+def stylua_plugin() -> StyluaPlugin:
+    """Provide a StyluaPlugin instance for testing.
+
+    Returns:
+        StyluaPlugin: A new StyluaPlugin instance.
+    """
+    return StyluaPlugin()
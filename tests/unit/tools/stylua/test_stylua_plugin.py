@@ -0,0 +1,173 @@
+"""Tests for StyluaPlugin."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.stylua import StyluaPlugin
+
+
+def _report(count: int) -> str:
+    """Build StyLua check output with the given number of mismatches.
+
+    Args:
+        count: Number of mismatched ranges to report.
+
+    Returns:
+        str: The JSON output, empty when there are no mismatches.
+    """
+    if not count:
+        return ""
+    mismatches = [
+        {
+            "original_start_line": index,
+            "original_end_line": index + 1,
+            "expected_start_line": index,
+            "expected_end_line": index + 1,
+            "original": "local x=1\n",
+            "expected": "local x = 1\n",
+        }
+        for index in range(count)
+    ]
+    return json.dumps({"file": "init.lua", "mismatches": mismatches})
+
+
+def _context() -> MagicMock:
+    """Build an execution context for one Lua file.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = "/project/nvim"
+    ctx.rel_files = ["init.lua"]
+    ctx.files = ["/project/nvim/init.lua"]
+    return ctx
+
+
+def test_check_runs_json_check(stylua_plugin: StyluaPlugin) -> None:
+    """Check runs StyLua in check mode with JSON output.
+
+    Args:
+        stylua_plugin: The StyluaPlugin instance to test.
+    """
+    stylua_plugin.set_options(config="ci/stylua.toml")
+
+    with (
+        patch.object(stylua_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            stylua_plugin,
+            "_run_subprocess",
+            return_value=(False, _report(2)),
+        ) as mock_run,
+    ):
+        result = stylua_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).is_equal_to(
+        [
+            "stylua",
+            "--respect-ignores",
+            "--config-path",
+            "ci/stylua.toml",
+            "--check",
+            "--output-format=json",
+            "init.lua",
+        ],
+    )
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to("/project/nvim")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(2)
+
+
+def test_check_reports_parse_errors(stylua_plugin: StyluaPlugin) -> None:
+    """Files StyLua cannot parse fail the result.
+
+    Args:
+        stylua_plugin: The StyluaPlugin instance to test.
+    """
+    error = "error: could not format file init.lua: failed to parse"
+
+    with (
+        patch.object(stylua_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(stylua_plugin, "_run_subprocess", return_value=(False, error)),
+    ):
+        result = stylua_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).is_equal_to(error)
+
+
+def test_check_reports_timeout(stylua_plugin: StyluaPlugin) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        stylua_plugin: The StyluaPlugin instance to test.
+    """
+    with (
+        patch.object(stylua_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            stylua_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["stylua"], timeout=60),
+        ),
+    ):
+        result = stylua_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("stylua:timeout=N")
+
+
+def test_fix_formats_and_rechecks(stylua_plugin: StyluaPlugin) -> None:
+    """Fix rewrites the files and re-checks them for remaining mismatches.
+
+    Args:
+        stylua_plugin: The StyluaPlugin instance to test.
+    """
+    with (
+        patch.object(stylua_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            stylua_plugin,
+            "_run_subprocess",
+            side_effect=[(False, _report(3)), (True, ""), (True, _report(0))],
+        ) as mock_run,
+    ):
+        result = stylua_plugin.fix(["."], {})
+
+    format_cmd = mock_run.call_args_list[1].kwargs["cmd"]
+    assert_that(format_cmd).is_equal_to(["stylua", "--respect-ignores", "init.lua"])
+    assert_that(result.initial_issues_count).is_equal_to(3)
+    assert_that(result.fixed_issues_count).is_equal_to(3)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+    assert_that(result.success).is_true()
+    assert_that(result.output).contains("All issues were successfully auto-fixed")
+
+
+def test_fix_skips_format_when_clean(stylua_plugin: StyluaPlugin) -> None:
+    """Formatted files are not rewritten.
+
+    Args:
+        stylua_plugin: The StyluaPlugin instance to test.
+    """
+    with (
+        patch.object(stylua_plugin, "_prepare_execution", return_value=_context()),
+        patch.object(
+            stylua_plugin,
+            "_run_subprocess",
+            return_value=(True, _report(0)),
+        ) as mock_run,
+    ):
+        result = stylua_plugin.fix(["."], {})
+
+    assert_that(mock_run.call_count).is_equal_to(1)
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(0)
@@ -18,6 +18,7 @@ from lintro.tools.definitions.taplo import (
     [
         ("timeout", TAPLO_DEFAULT_TIMEOUT),
         ("schema", None),
+        ("default_schema_catalogs", None),
         ("aligned_arrays", None),
         ("aligned_entries", None),
         ("array_trailing_comma", None),
@@ -27,6 +28,7 @@ from lintro.tools.definitions.taplo import (
     ids=[
         "timeout_equals_default",
         "schema_is_none",
+        "default_schema_catalogs_is_none",
         "aligned_arrays_is_none",
         "aligned_entries_is_none",
         "array_trailing_comma_is_none",
@@ -101,6 +103,11 @@ def test_set_options_valid(
     [
         ("schema", 123, "schema must be a string"),
         ("schema", ["path"], "schema must be a string"),
+        (
+            "default_schema_catalogs",
+            "yes",
+            "default_schema_catalogs must be a boolean",
+        ),
         ("aligned_arrays", "yes", "aligned_arrays must be a boolean"),
         ("aligned_arrays", 1, "aligned_arrays must be a boolean"),
         ("aligned_entries", "true", "aligned_entries must be a boolean"),
@@ -112,6 +119,7 @@ def test_set_options_valid(
     ids=[
         "invalid_schema_int",
         "invalid_schema_list",
+        "invalid_default_schema_catalogs_str",
         "invalid_aligned_arrays_str",
         "invalid_aligned_arrays_int",
         "invalid_aligned_entries_str",
@@ -277,3 +285,36 @@ def test_build_lint_args_with_url_schema(taplo_plugin: TaploPlugin) -> None:
     assert_that(args).contains("--schema")
     schema_idx = args.index("--schema")
     assert_that(args[schema_idx + 1]).is_equal_to(schema_url)
+
+
+def test_build_lint_args_with_default_schema_catalogs(
+    taplo_plugin: TaploPlugin,
+) -> None:
+    """Build lint args enables the default schema catalogs.
+
+    Args:
+        taplo_plugin: The TaploPlugin instance to test.
+    """
+    taplo_plugin.set_options(default_schema_catalogs=True)
+
+    assert_that(taplo_plugin._build_lint_args()).is_equal_to(
+        ["--default-schema-catalogs"],
+    )
+
+
+def test_build_lint_args_explicit_schema_overrides_catalogs(
+    taplo_plugin: TaploPlugin,
+) -> None:
+    """An explicit schema is used instead of the schema catalogs.
+
+    Args:
+        taplo_plugin: The TaploPlugin instance to test.
+    """
+    taplo_plugin.set_options(
+        schema="/path/to/schema.json",
+        default_schema_catalogs=True,
+    )
+
+    assert_that(taplo_plugin._build_lint_args()).does_not_contain(
+        "--default-schema-catalogs",
+    )
@@ -0,0 +1 @@
+"""terraform fmt plugin tests."""
@@ -0,0 +1,40 @@
+"""Shared fixtures for terraform fmt plugin tests."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+
+from lintro.tools.definitions.terraform_fmt import TerraformFmtPlugin
+
+
+@pytest.fixture
+def terraform_fmt_plugin() -> TerraformFmtPlugin:
+    """Provide a TerraformFmtPlugin instance for testing.
+
+    Returns:
+        TerraformFmtPlugin: A new TerraformFmtPlugin instance.
+    """
+    return TerraformFmtPlugin()
+
+
+@pytest.fixture
+def terraform_fmt_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for two Terraform files.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["main.tf", "prod.tfvars"]
+    ctx.files = [str(tmp_path / f) for f in ctx.rel_files]
+    return ctx
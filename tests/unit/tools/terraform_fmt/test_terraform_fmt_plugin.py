@@ -0,0 +1,197 @@
+"""Tests for TerraformFmtPlugin."""
+
+from __future__ import annotations
+
+import subprocess
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.terraform_fmt import TerraformFmtPlugin
+
+
+def test_definition(terraform_fmt_plugin: TerraformFmtPlugin) -> None:
+    """The plugin formats Terraform files and can fix them.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+    """
+    definition = terraform_fmt_plugin.definition
+
+    assert_that(definition.name).is_equal_to("terraform_fmt")
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).contains("*.tf", "*.tfvars")
+
+
+def test_set_options_rejects_invalid_timeout(
+    terraform_fmt_plugin: TerraformFmtPlugin,
+) -> None:
+    """A non-positive timeout is rejected.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="timeout"):
+        terraform_fmt_plugin.set_options(timeout=0)
+
+
+def test_check_reports_unformatted_files(
+    terraform_fmt_plugin: TerraformFmtPlugin,
+    terraform_fmt_context: MagicMock,
+) -> None:
+    """Check runs ``terraform fmt -check`` and reports listed files.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+        terraform_fmt_context: Execution context for two files.
+    """
+    with (
+        patch.object(
+            terraform_fmt_plugin,
+            "_prepare_execution",
+            return_value=terraform_fmt_context,
+        ),
+        patch.object(
+            terraform_fmt_plugin,
+            "_run_subprocess",
+            return_value=(False, "main.tf\n"),
+        ) as mock_run,
+    ):
+        result = terraform_fmt_plugin.check(["."], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd[:2]).is_equal_to(["terraform", "fmt"])
+    assert_that(cmd).contains("-check", "main.tf", "prod.tfvars")
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].file).is_equal_to("main.tf")
+
+
+def test_check_reports_parse_errors_as_failure(
+    terraform_fmt_plugin: TerraformFmtPlugin,
+    terraform_fmt_context: MagicMock,
+) -> None:
+    """Files terraform cannot parse fail the run with its diagnostics.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+        terraform_fmt_context: Execution context for two files.
+    """
+    diagnostics = "Error: Invalid character\n\n  on main.tf line 3:\n"
+
+    with (
+        patch.object(
+            terraform_fmt_plugin,
+            "_prepare_execution",
+            return_value=terraform_fmt_context,
+        ),
+        patch.object(
+            terraform_fmt_plugin,
+            "_run_subprocess",
+            return_value=(False, diagnostics),
+        ),
+    ):
+        result = terraform_fmt_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).contains("Invalid character")
+
+
+def test_check_timeout(
+    terraform_fmt_plugin: TerraformFmtPlugin,
+    terraform_fmt_context: MagicMock,
+) -> None:
+    """A timed out run fails with a hint to raise the timeout.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+        terraform_fmt_context: Execution context for two files.
+    """
+    with (
+        patch.object(
+            terraform_fmt_plugin,
+            "_prepare_execution",
+            return_value=terraform_fmt_context,
+        ),
+        patch.object(
+            terraform_fmt_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["terraform"], timeout=60),
+        ),
+    ):
+        result = terraform_fmt_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("terraform_fmt:timeout")
+
+
+def test_fix_formats_files(
+    terraform_fmt_plugin: TerraformFmtPlugin,
+    terraform_fmt_context: MagicMock,
+) -> None:
+    """Fix rewrites listed files and re-checks them.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+        terraform_fmt_context: Execution context for two files.
+    """
+    outputs = [
+        (False, "main.tf\nprod.tfvars\n"),
+        (True, "main.tf\nprod.tfvars\n"),
+        (True, ""),
+    ]
+
+    with (
+        patch.object(
+            terraform_fmt_plugin,
+            "_prepare_execution",
+            return_value=terraform_fmt_context,
+        ),
+        patch.object(
+            terraform_fmt_plugin,
+            "_run_subprocess",
+            side_effect=outputs,
+        ) as mock_run,
+    ):
+        result = terraform_fmt_plugin.fix(["."], {})
+
+    commands = [call.kwargs["cmd"] for call in mock_run.call_args_list]
+    assert_that(commands[1]).does_not_contain("-check")
+    assert_that(commands[2]).contains("-check")
+    assert_that(result.success).is_true()
+    assert_that(result.initial_issues_count).is_equal_to(2)
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that(result.remaining_issues_count).is_equal_to(0)
+
+
+def test_fix_skips_formatted_files(
+    terraform_fmt_plugin: TerraformFmtPlugin,
+    terraform_fmt_context: MagicMock,
+) -> None:
+    """Formatted files are not rewritten.
+
+    Args:
+        terraform_fmt_plugin: The TerraformFmtPlugin instance to test.
+        terraform_fmt_context: Execution context for two files.
+    """
+    with (
+        patch.object(
+            terraform_fmt_plugin,
+            "_prepare_execution",
+            return_value=terraform_fmt_context,
+        ),
+        patch.object(
+            terraform_fmt_plugin,
+            "_run_subprocess",
+            return_value=(True, ""),
+        ) as mock_run,
+    ):
+        result = terraform_fmt_plugin.fix(["."], {})
+
+    assert_that(mock_run.call_count).is_equal_to(2)
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(0)
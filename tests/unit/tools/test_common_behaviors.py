@@ -96,11 +96,6 @@ TOOLS_THAT_CANNOT_FIX = [
         "cannot automatically fix",
         id="yamllint",
     ),
-    pytest.param(
-        "lintro.tools.definitions.markdownlint.MarkdownlintPlugin",
-        "cannot fix issues",
-        id="markdownlint",
-    ),
     pytest.param(
         "lintro.tools.definitions.mypy.MypyPlugin",
         "cannot automatically fix",
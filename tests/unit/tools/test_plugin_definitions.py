@@ -50,7 +50,7 @@ PLUGIN_DEFINITIONS: list[tuple[ToolName, str, bool, ToolType, list[str], list[st
     (
         ToolName.MARKDOWNLINT,
         "lintro.tools.definitions.markdownlint.MarkdownlintPlugin",
-        False,
+        True,
         ToolType.LINTER,
         ["Markdown", "linter"],
         [".markdownlint.json", ".markdownlint.yaml", ".markdownlint.yml"],
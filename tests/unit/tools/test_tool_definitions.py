@@ -52,7 +52,7 @@ TOOL_SPECS = [
         "MarkdownlintPlugin",
         30,
         30,
-        False,
+        True,
     ),
     (
         ToolName.ACTIONLINT,
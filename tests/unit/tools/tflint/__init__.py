@@ -0,0 +1 @@
+"""tflint plugin tests."""
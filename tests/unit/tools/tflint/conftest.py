@@ -0,0 +1,17 @@
+"""Shared fixtures for TFLint plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.tflint import TflintPlugin
+
+
+@pytest.fixture
+def tflint_plugin() -> TflintPlugin:
+    """Provide a TflintPlugin instance for testing.
+
+    Returns:
+        TflintPlugin: A new TflintPlugin instance.
+    """
+    return TflintPlugin()
@@ -0,0 +1,168 @@
+"""Tests for TflintPlugin."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+from lintro.tools.definitions.tflint import find_module_dirs, find_tflint_config
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.tflint import TflintPlugin
+
+
+def _report(filename: str, rule: str = "terraform_unused_declarations") -> str:
+    """Serialize a TFLint report with one rule violation.
+
+    Args:
+        filename: File the violation is in, relative to the module.
+        rule: Name of the violated rule.
+
+    Returns:
+        str: The report as TFLint prints it.
+    """
+    return json.dumps(
+        {
+            "issues": [
+                {
+                    "rule": {"name": rule, "severity": "warning", "link": ""},
+                    "message": "violation",
+                    "range": {
+                        "filename": filename,
+                        "start": {"line": 1, "column": 1},
+                        "end": {"line": 1, "column": 5},
+                    },
+                },
+            ],
+            "errors": [],
+        },
+    )
+
+
+def _context(tmp_path: Path, rel_files: list[str]) -> MagicMock:
+    """Build an execution context for the given files.
+
+    Args:
+        tmp_path: Working directory of the run.
+        rel_files: Files relative to the working directory.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 120
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = rel_files
+    ctx.files = [str(tmp_path / f) for f in rel_files]
+    return ctx
+
+
+def test_find_module_dirs_groups_files_by_directory() -> None:
+    """Each directory holding Terraform files is linted once."""
+    files = ["main.tf", "variables.tf", "modules/vpc/main.tf", "modules/vpc/out.tf"]
+
+    assert_that(find_module_dirs(files)).is_equal_to([".", "modules/vpc"])
+
+
+def test_find_tflint_config_searches_parents(tmp_path: Path) -> None:
+    """A repository-root config applies to nested modules.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    module = tmp_path / "modules" / "vpc"
+    module.mkdir(parents=True)
+    (tmp_path / ".tflint.hcl").write_text('plugin "terraform" {}\n')
+
+    assert_that(find_tflint_config(module)).is_equal_to(tmp_path / ".tflint.hcl")
+
+
+def test_check_runs_each_module(tflint_plugin: TflintPlugin, tmp_path: Path) -> None:
+    """TFLint runs in every module and paths are made relative to the run.
+
+    Args:
+        tflint_plugin: The TflintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "modules" / "vpc").mkdir(parents=True)
+    ctx = _context(tmp_path, ["main.tf", "modules/vpc/main.tf"])
+
+    with (
+        patch.object(tflint_plugin, "_prepare_execution", return_value=ctx),
+        patch.object(
+            tflint_plugin,
+            "_run_subprocess",
+            side_effect=[(True, _report("main.tf")), (False, _report("main.tf"))],
+        ) as mock_run,
+    ):
+        result = tflint_plugin.check(["."], {})
+
+    cwds = [call.kwargs["cwd"] for call in mock_run.call_args_list]
+    assert_that(cwds).is_equal_to(
+        [str(tmp_path.resolve()), str((tmp_path / "modules" / "vpc").resolve())],
+    )
+    assert_that(mock_run.call_args.kwargs["cmd"]).contains("--format=json")
+    assert_that(result.success).is_false()
+    assert_that([i.file for i in result.issues]).is_equal_to(
+        ["main.tf", "modules/vpc/main.tf"],
+    )
+
+
+def test_check_ignores_unchecked_files(
+    tflint_plugin: TflintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Violations in module files outside the run are not reported.
+
+    Args:
+        tflint_plugin: The TflintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    ctx = _context(tmp_path, ["main.tf"])
+
+    with (
+        patch.object(tflint_plugin, "_prepare_execution", return_value=ctx),
+        patch.object(
+            tflint_plugin,
+            "_run_subprocess",
+            return_value=(False, _report("generated.tf")),
+        ),
+    ):
+        result = tflint_plugin.check(["."], {})
+
+    assert_that(result.success).is_true()
+    assert_that(result.issues_count).is_equal_to(0)
+
+
+def test_check_reports_module_errors(
+    tflint_plugin: TflintPlugin,
+    tmp_path: Path,
+) -> None:
+    """Errors that stop TFLint fail the run and name the module.
+
+    Args:
+        tflint_plugin: The TflintPlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    ctx = _context(tmp_path, ["main.tf"])
+    output = json.dumps(
+        {
+            "issues": [],
+            "errors": [{"message": "Plugin `aws` not found", "severity": "error"}],
+        },
+    )
+
+    with (
+        patch.object(tflint_plugin, "_prepare_execution", return_value=ctx),
+        patch.object(tflint_plugin, "_run_subprocess", return_value=(False, output)),
+    ):
+        result = tflint_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to(".: Plugin `aws` not found")
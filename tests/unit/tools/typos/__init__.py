@@ -0,0 +1 @@
+"""typos plugin tests."""
@@ -0,0 +1,40 @@
+"""Shared fixtures for typos plugin tests."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+
+from lintro.tools.definitions.typos import TyposPlugin
+
+
+@pytest.fixture
+def typos_plugin() -> TyposPlugin:
+    """Provide a TyposPlugin instance for testing.
+
+    Returns:
+        TyposPlugin: A new TyposPlugin instance.
+    """
+    return TyposPlugin()
+
+
+@pytest.fixture
+def typos_context(tmp_path: Path) -> MagicMock:
+    """Build an execution context for one Markdown file.
+
+    Args:
+        tmp_path: Working directory of the run.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 60
+    ctx.cwd = str(tmp_path)
+    ctx.rel_files = ["README.md"]
+    ctx.files = [str(tmp_path / "README.md")]
+    return ctx
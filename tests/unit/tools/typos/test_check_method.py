@@ -0,0 +1,145 @@
+"""Tests for TyposPlugin.check method."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.typos import TyposPlugin
+
+TYPO = json.dumps(
+    {
+        "type": "typo",
+        "path": "README.md",
+        "line_num": 2,
+        "byte_offset": 4,
+        "typo": "teh",
+        "corrections": ["the"],
+    },
+)
+
+
+def test_check_reports_typos(
+    typos_plugin: TyposPlugin,
+    typos_context: MagicMock,
+) -> None:
+    """Check runs typos with JSON output on the discovered files.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        typos_context: Execution context for one file.
+    """
+    with (
+        patch.object(typos_plugin, "_prepare_execution", return_value=typos_context),
+        patch.object(
+            typos_plugin,
+            "_run_subprocess",
+            return_value=(False, TYPO),
+        ) as mock_run,
+    ):
+        result = typos_plugin.check(["."], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd).is_equal_to(
+        ["typos", "--format", "json", "--force-exclude", "README.md"],
+    )
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].message).is_equal_to("`teh` should be `the`")
+
+
+def test_check_passes_accepted_words(
+    typos_plugin: TyposPlugin,
+    typos_context: MagicMock,
+) -> None:
+    """Accepted words reach typos through a temporary config.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        typos_context: Execution context for one file.
+    """
+    typos_plugin.set_options(accepted_words=["tyes", "lintro"])
+    seen: dict[str, str] = {}
+
+    def run(cmd: list[str], timeout: int, cwd: str | None) -> tuple[bool, str]:
+        """Capture the temporary config while it exists.
+
+        Args:
+            cmd: Command being run.
+            timeout: Timeout in seconds.
+            cwd: Working directory.
+
+        Returns:
+            tuple[bool, str]: A clean run.
+        """
+        config = cmd[cmd.index("--config") + 1]
+        seen["path"] = config
+        seen["text"] = Path(config).read_text(encoding="utf-8")
+        return True, ""
+
+    with (
+        patch.object(typos_plugin, "_prepare_execution", return_value=typos_context),
+        patch.object(typos_plugin, "_run_subprocess", side_effect=run),
+    ):
+        result = typos_plugin.check(["."], {})
+
+    assert_that(result.success).is_true()
+    assert_that(seen["text"]).is_equal_to(
+        '[default.extend-words]\n"tyes" = "tyes"\n"lintro" = "lintro"\n',
+    )
+    assert_that(Path(seen["path"]).exists()).is_false()
+
+
+def test_check_reports_failure_without_typos(
+    typos_plugin: TyposPlugin,
+    typos_context: MagicMock,
+) -> None:
+    """A failed run without findings carries typos' message.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        typos_context: Execution context for one file.
+    """
+    with (
+        patch.object(typos_plugin, "_prepare_execution", return_value=typos_context),
+        patch.object(
+            typos_plugin,
+            "_run_subprocess",
+            return_value=(False, "error: invalid config: unknown field `word`"),
+        ),
+    ):
+        result = typos_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(0)
+    assert_that(result.output).contains("invalid config")
+
+
+def test_check_timeout(
+    typos_plugin: TyposPlugin,
+    typos_context: MagicMock,
+) -> None:
+    """A timeout produces a failed result naming the option to raise.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        typos_context: Execution context for one file.
+    """
+    with (
+        patch.object(typos_plugin, "_prepare_execution", return_value=typos_context),
+        patch.object(
+            typos_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["typos"], timeout=60),
+        ),
+    ):
+        result = typos_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("typos:timeout")
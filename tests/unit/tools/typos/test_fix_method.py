@@ -0,0 +1,97 @@
+"""Tests for TyposPlugin.fix method."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.typos import TyposPlugin
+
+
+def _typo(word: str, corrections: list[str]) -> str:
+    """Serialize a typos finding in README.md.
+
+    Args:
+        word: The misspelled word.
+        corrections: Suggested corrections.
+
+    Returns:
+        str: The finding as a JSON line.
+    """
+    return json.dumps(
+        {
+            "type": "typo",
+            "path": "README.md",
+            "line_num": 1,
+            "byte_offset": 0,
+            "typo": word,
+            "corrections": corrections,
+        },
+    )
+
+
+def test_fix_writes_changes_and_reports_ambiguous_words(
+    typos_plugin: TyposPlugin,
+    typos_context: MagicMock,
+) -> None:
+    """Single corrections are applied; ambiguous words remain.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        typos_context: Execution context for one file.
+    """
+    ambiguous = _typo("thier", ["their", "there"])
+    outputs = [
+        (False, f"{_typo('teh', ['the'])}\n{ambiguous}"),
+        (True, ""),
+        (False, ambiguous),
+    ]
+
+    with (
+        patch.object(typos_plugin, "_prepare_execution", return_value=typos_context),
+        patch.object(
+            typos_plugin,
+            "_run_subprocess",
+            side_effect=outputs,
+        ) as mock_run,
+    ):
+        result = typos_plugin.fix(["."], {})
+
+    commands = [call.kwargs["cmd"] for call in mock_run.call_args_list]
+    assert_that(commands[1]).contains("--write-changes")
+    assert_that(commands[2]).does_not_contain("--write-changes")
+    assert_that(result.success).is_false()
+    assert_that(result.initial_issues_count).is_equal_to(2)
+    assert_that(result.fixed_issues_count).is_equal_to(1)
+    assert_that(result.remaining_issues_count).is_equal_to(1)
+    assert_that(result.output).contains("cannot be auto-fixed")
+
+
+def test_fix_without_typos_skips_writing(
+    typos_plugin: TyposPlugin,
+    typos_context: MagicMock,
+) -> None:
+    """Clean files are not rewritten.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        typos_context: Execution context for one file.
+    """
+    with (
+        patch.object(typos_plugin, "_prepare_execution", return_value=typos_context),
+        patch.object(
+            typos_plugin,
+            "_run_subprocess",
+            return_value=(True, ""),
+        ) as mock_run,
+    ):
+        result = typos_plugin.fix(["."], {})
+
+    commands = [call.kwargs["cmd"] for call in mock_run.call_args_list]
+    assert_that(any("--write-changes" in cmd for cmd in commands)).is_false()
+    assert_that(result.success).is_true()
+    assert_that(result.fixed_issues_count).is_equal_to(0)
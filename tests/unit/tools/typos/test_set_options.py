@@ -0,0 +1,66 @@
+"""Tests for TyposPlugin definition and options."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+from assertpy import assert_that
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.typos import TyposPlugin
+
+
+def test_definition_checks_all_files(typos_plugin: TyposPlugin) -> None:
+    """typos is a fixing linter that reads every discovered file.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+    """
+    definition = typos_plugin.definition
+
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.file_patterns).is_equal_to(["*"])
+    assert_that(definition.native_configs).contains("_typos.toml", ".typos.toml")
+
+
+def test_set_options_accepted_words(typos_plugin: TyposPlugin) -> None:
+    """Accepted words and the timeout are stored.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+    """
+    typos_plugin.set_options(accepted_words=["lintro", "tyes"], timeout=120)
+
+    assert_that(typos_plugin.options).contains_entry(
+        {"accepted_words": ["lintro", "tyes"]},
+        {"timeout": 120},
+    )
+
+
+@pytest.mark.parametrize(
+    ("option_name", "invalid_value", "error_match"),
+    [
+        ("accepted_words", "lintro", "accepted_words must be a list"),
+        ("accepted_words", ["lintro", 3], "accepted_words must be a list of strings"),
+        ("timeout", 0, "timeout must be positive"),
+    ],
+    ids=["words_not_list", "words_not_strings", "timeout_zero"],
+)
+def test_set_options_invalid(
+    typos_plugin: TyposPlugin,
+    option_name: str,
+    invalid_value: object,
+    error_match: str,
+) -> None:
+    """Raise ValueError for invalid option values.
+
+    Args:
+        typos_plugin: The TyposPlugin instance to test.
+        option_name: The name of the option to test.
+        invalid_value: An invalid value for the option.
+        error_match: The expected error message pattern.
+    """
+    options: dict[str, object] = {option_name: invalid_value}
+    with pytest.raises(ValueError, match=error_match):
+        typos_plugin.set_options(**options)  # type: ignore[arg-type]
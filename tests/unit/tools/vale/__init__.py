@@ -0,0 +1 @@
+"""vale plugin tests."""
@@ -0,0 +1,17 @@
+"""Shared fixtures for Vale plugin tests."""
+
+from __future__ import annotations
+
+import pytest
+
+from lintro.tools.definitions.vale import ValePlugin
+
+
+@pytest.fixture
+def vale_plugin() -> ValePlugin:
+    """Provide a ValePlugin instance for testing.
+
+    Returns:
+        ValePlugin: A new ValePlugin instance.
+    """
+    return ValePlugin()
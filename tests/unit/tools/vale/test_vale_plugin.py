@@ -0,0 +1,222 @@
+"""Tests for ValePlugin."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import TYPE_CHECKING
+from unittest.mock import MagicMock, patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.vale import find_vale_config
+
+if TYPE_CHECKING:
+    from lintro.tools.definitions.vale import ValePlugin
+
+ALERTS = {
+    "README.md": [
+        {
+            "Span": [1, 6],
+            "Check": "Vale.Terms",
+            "Message": "Use 'GitHub' instead of 'Github'.",
+            "Severity": "error",
+            "Match": "Github",
+            "Line": 2,
+        },
+    ],
+}
+
+
+def _context(cwd: Path) -> MagicMock:
+    """Build an execution context for a README in a directory.
+
+    Args:
+        cwd: Directory Vale runs in.
+
+    Returns:
+        MagicMock: Context returned by _prepare_execution.
+    """
+    ctx = MagicMock()
+    ctx.should_skip = False
+    ctx.early_result = None
+    ctx.timeout = 120
+    ctx.cwd = str(cwd)
+    ctx.files = [str(cwd / "README.md")]
+    ctx.rel_files = ["README.md"]
+    return ctx
+
+
+def test_definition(vale_plugin: ValePlugin) -> None:
+    """The plugin lints Markdown and reStructuredText and cannot fix.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+    """
+    definition = vale_plugin.definition
+
+    assert_that(definition.name).is_equal_to("vale")
+    assert_that(definition.can_fix).is_false()
+    assert_that(definition.file_patterns).contains("*.md", "*.mdx", "*.rst")
+    assert_that(definition.native_configs).contains(".vale.ini")
+
+
+def test_find_vale_config_searches_parents(tmp_path: Path) -> None:
+    """The nearest config in the directory or a parent is found.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "docs").mkdir()
+    (tmp_path / "_vale.ini").write_text("MinAlertLevel = warning\n")
+
+    found = find_vale_config(tmp_path / "docs")
+
+    assert_that(found).is_equal_to((tmp_path / "_vale.ini").resolve())
+
+
+def test_set_options_rejects_invalid_alert_level(vale_plugin: ValePlugin) -> None:
+    """An unknown min_alert_level is rejected.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+    """
+    with pytest.raises(ValueError, match="min_alert_level"):
+        vale_plugin.set_options(min_alert_level="fatal")
+
+
+def test_check_reports_alerts(vale_plugin: ValePlugin, tmp_path: Path) -> None:
+    """Alerts are reported from Vale's JSON output.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".vale.ini").write_text("StylesPath = styles\n")
+    vale_plugin.set_options(min_alert_level="Warning")
+
+    with (
+        patch.object(
+            vale_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            vale_plugin,
+            "_run_subprocess",
+            return_value=(False, json.dumps(ALERTS)),
+        ) as mock_run,
+    ):
+        result = vale_plugin.check(["."], {})
+
+    cmd = mock_run.call_args.kwargs["cmd"]
+    assert_that(cmd).contains("--output=JSON", "--minAlertLevel=warning")
+    assert_that(cmd[-1]).is_equal_to("README.md")
+    assert_that(mock_run.call_args.kwargs["cwd"]).is_equal_to(str(tmp_path))
+    assert_that(result.success).is_false()
+    assert_that(result.issues_count).is_equal_to(1)
+    assert_that(result.issues[0].code).is_equal_to("Vale.Terms")
+    assert_that(result.output).is_none()
+
+
+def test_check_skips_without_config(
+    vale_plugin: ValePlugin,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Projects without a Vale config are skipped without running Vale.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.delenv("VALE_CONFIG_PATH", raising=False)
+
+    with (
+        patch.object(
+            vale_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch(
+            "lintro.tools.definitions.vale.find_vale_config",
+            return_value=None,
+        ),
+        patch.object(vale_plugin, "_run_subprocess") as mock_run,
+    ):
+        result = vale_plugin.check(["."], {})
+
+    mock_run.assert_not_called()
+    assert_that(result.skipped).is_true()
+    assert_that(result.skip_reason).is_equal_to("no .vale.ini")
+
+
+def test_check_passes_config_option(vale_plugin: ValePlugin, tmp_path: Path) -> None:
+    """An explicit config is passed to Vale and runtime errors fail the run.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    vale_plugin.set_options(config="docs/.vale.ini")
+    error = {"Code": "E100", "Text": "styles/Google does not exist."}
+
+    with (
+        patch.object(
+            vale_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            vale_plugin,
+            "_run_subprocess",
+            return_value=(False, json.dumps(error)),
+        ) as mock_run,
+    ):
+        result = vale_plugin.check(["."], {})
+
+    assert_that(mock_run.call_args.kwargs["cmd"]).contains(
+        "--config=docs/.vale.ini",
+    )
+    assert_that(result.success).is_false()
+    assert_that(result.output).is_equal_to("E100: styles/Google does not exist.")
+
+
+def test_check_times_out(vale_plugin: ValePlugin, tmp_path: Path) -> None:
+    """A timed out run fails with a timeout message.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".vale.ini").write_text("")
+
+    with (
+        patch.object(
+            vale_plugin,
+            "_prepare_execution",
+            return_value=_context(tmp_path),
+        ),
+        patch.object(
+            vale_plugin,
+            "_run_subprocess",
+            side_effect=subprocess.TimeoutExpired(cmd=["vale"], timeout=120),
+        ),
+    ):
+        result = vale_plugin.check(["."], {})
+
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("timed out")
+
+
+def test_fix_is_not_supported(vale_plugin: ValePlugin) -> None:
+    """Fixing raises NotImplementedError.
+
+    Args:
+        vale_plugin: The ValePlugin instance to test.
+    """
+    with pytest.raises(NotImplementedError):
+        vale_plugin.fix(["."], {})
@@ -0,0 +1 @@
+"""Whitespace plugin tests."""
@@ -0,0 +1,71 @@
+"""Unit tests for the whitespace hygiene checks and fixes."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.implementations.whitespace import check_text, fix_text
+
+
+def _codes(text: str, markdown: bool = False) -> list[tuple[str, int]]:
+    """Return the (code, line) pairs reported for a text.
+
+    Args:
+        text: File contents.
+        markdown: Whether to keep Markdown hard line breaks.
+
+    Returns:
+        list[tuple[str, int]]: Codes and lines of the issues.
+    """
+    return [(i.code, i.line) for i in check_text(text, "f.txt", markdown=markdown)]
+
+
+@pytest.mark.parametrize(
+    ("text", "codes", "fixed"),
+    [
+        ("x = 1  \ny\t\n", [("WS001", 1), ("WS001", 2)], "x = 1\ny\n"),
+        ("x = 1", [("WS002", 1)], "x = 1\n"),
+        ("x = 1\n\n  \n", [("WS003", 2)], "x = 1\n"),
+        ("\ufeffx = 1\n", [("WS005", 1)], "x = 1\n"),
+        ("\n\n", [("WS003", 1)], ""),
+        ("a\r\nb", [("WS002", 2)], "a\r\nb\r\n"),
+    ],
+    ids=["trailing", "final_newline", "blank_tail", "bom", "blank_file", "crlf"],
+)
+def test_fixable_problems(
+    text: str,
+    codes: list[tuple[str, int]],
+    fixed: str,
+) -> None:
+    """Fixable problems are reported and rewritten.
+
+    Args:
+        text: File contents.
+        codes: Expected codes and lines.
+        fixed: Expected fixed contents.
+    """
+    assert_that(_codes(text)).is_equal_to(codes)
+    assert_that(fix_text(text)).is_equal_to(fixed)
+    assert_that(_codes(fixed)).is_empty()
+
+
+def test_space_before_tab_is_reported_not_fixed() -> None:
+    """Mixed indentation is left for a person to decide."""
+    text = "def f():\n \treturn 1\n\t  alignment\n"
+
+    assert_that(_codes(text)).is_equal_to([("WS004", 2)])
+    assert_that(fix_text(text)).is_equal_to(text)
+
+
+def test_markdown_hard_line_breaks_are_kept() -> None:
+    """Two trailing spaces are a Markdown line break unless the line is blank."""
+    text = "first  \nsecond \n  \nthird\n"
+
+    assert_that(_codes(text, markdown=True)).is_equal_to(
+        [("WS001", 2), ("WS001", 3)],
+    )
+    assert_that(fix_text(text, markdown=True)).is_equal_to(
+        "first  \nsecond\n\nthird\n",
+    )
+    assert_that(_codes(text)).contains(("WS001", 1))
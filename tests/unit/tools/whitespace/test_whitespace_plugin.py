@@ -0,0 +1,64 @@
+"""Unit tests for the whitespace plugin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.whitespace import WhitespacePlugin
+
+
+@pytest.fixture
+def whitespace_plugin() -> WhitespacePlugin:
+    """Provide a WhitespacePlugin instance for testing.
+
+    Returns:
+        A WhitespacePlugin instance.
+    """
+    return WhitespacePlugin()
+
+
+def test_definition_is_opt_in_native_fixer(
+    whitespace_plugin: WhitespacePlugin,
+) -> None:
+    """Verify the plugin needs no external binary, can fix and is opt-in.
+
+    Args:
+        whitespace_plugin: The plugin instance.
+    """
+    definition = whitespace_plugin.definition
+    assert_that(definition.name).is_equal_to("whitespace")
+    assert_that(definition.native).is_true()
+    assert_that(definition.can_fix).is_true()
+    assert_that(definition.enabled_by_default).is_false()
+
+
+def test_fix_rewrites_text_files_only(
+    whitespace_plugin: WhitespacePlugin,
+    tmp_path: Path,
+) -> None:
+    """Verify fixing skips binaries and patches and reports mixed indentation.
+
+    Args:
+        whitespace_plugin: The plugin instance.
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "app.py").write_text("x = 1   \n\n")
+    (tmp_path / "tabs.py").write_text("if x:\n \ty = 1\n")
+    (tmp_path / "fixes.patch").write_text(" context \n")
+    (tmp_path / "logo.png").write_bytes(b"\x89PNG\x00  \n")
+
+    with patch(
+        "lintro.plugins.execution_preparation.verify_tool_version",
+        return_value=None,
+    ):
+        result = whitespace_plugin.fix([str(tmp_path)], {})
+
+    assert_that((tmp_path / "app.py").read_text()).is_equal_to("x = 1\n")
+    assert_that((tmp_path / "fixes.patch").read_text()).is_equal_to(" context \n")
+    assert_that(result.fixed_issues_count).is_equal_to(2)
+    assert_that([i.code for i in result.issues]).is_equal_to(["WS004"])
+    assert_that(result.output).contains("Fixed 2 issue(s) in 1 file(s)")
@@ -49,3 +49,4 @@ def test_exception_in_tool_creates_failed_result(
     assert_that(name).is_equal_to("raise_tool")
     assert_that(result.success).is_false()
     assert_that(result.output).contains("Parallel execution failed")
+    assert_that(result.error_code).is_equal_to("LINTRO008")
@@ -11,6 +11,7 @@ from lintro.enums.action import Action
 from lintro.models.core.tool_result import ToolResult
 from lintro.plugins.base import BaseToolPlugin
 from lintro.utils.async_tool_executor import AsyncToolExecutor
+from lintro.utils.execution.fail_fast import CANCELLED_REASON
 
 from .conftest import MockToolDefinition, MockToolPlugin
 
@@ -188,3 +189,28 @@ def test_run_tools_parallel_with_options_per_tool(
     assert_that(tool1_opts).contains_key("option_a")
     assert_that(tool2_opts).contains_key("option_b")
     assert_that(tool1_opts).does_not_contain_key("option_b")
+
+
+def test_run_tools_parallel_skips_cancelled_tools(
+    executor: AsyncToolExecutor,
+) -> None:
+    """Tools picked up after cancellation are reported as cancelled, not run.
+
+    Args:
+        executor: AsyncToolExecutor fixture.
+    """
+    tool = MockToolPlugin(definition=MockToolDefinition(name="tool1"))
+    tools = cast(list[tuple[str, BaseToolPlugin]], [("tool1", tool)])
+
+    async def run_test() -> Any:
+        return await executor.run_tools_parallel(
+            tools,
+            paths=["."],
+            action=Action.CHECK,
+            cancelled=lambda: True,
+        )
+
+    results = asyncio.run(run_test())
+
+    assert_that(tool.check_called).is_false()
+    assert_that(results[0][1].skip_reason).is_equal_to(CANCELLED_REASON)
@@ -46,6 +46,29 @@ def test_print_summary_table_delegates_to_module_function(
         mock_print.assert_called_once()
 
 
+def test_print_summary_only_prints_only_the_counts_table(
+    fake_tool_result_factory: Callable[..., FakeToolResult],
+) -> None:
+    """Verify print_summary_only skips the totals table and ASCII art.
+
+    Args:
+        fake_tool_result_factory: Factory for creating FakeToolResult instances.
+    """
+    logger = ThreadSafeConsoleLogger()
+    results = [fake_tool_result_factory()]
+
+    with (
+        patch("lintro.utils.summary_tables.print_summary_table") as mock_print,
+        patch.object(logger, "_print_totals_table") as mock_totals,
+        patch.object(logger, "_print_ascii_art") as mock_art,
+    ):
+        logger.print_summary_only(Action.CHECK, results)
+
+    mock_print.assert_called_once()
+    mock_totals.assert_not_called()
+    mock_art.assert_not_called()
+
+
 def test_print_summary_table_converts_string_action(
     fake_tool_result_factory: Callable[..., FakeToolResult],
 ) -> None:
@@ -131,6 +154,7 @@ def test_print_totals_table_delegates_to_module_function() -> None:
             severity_errors=0,
             severity_warnings=0,
             severity_info=0,
+            effort_minutes=None,
         )
 
 
@@ -159,6 +183,7 @@ def test_print_totals_table_delegates_fix_mode() -> None:
             severity_errors=0,
             severity_warnings=0,
             severity_info=0,
+            effort_minutes=None,
         )
 
 
@@ -49,6 +49,20 @@ def test_console_output_with_color(logger: ThreadSafeConsoleLogger) -> None:
             mock_echo.assert_called_once_with("styled text")
 
 
+def test_console_output_muted_only_tracks(logger: ThreadSafeConsoleLogger) -> None:
+    """Verify a muted logger prints nothing but keeps the text for console.log.
+
+    Args:
+        logger: ThreadSafeConsoleLogger instance fixture.
+    """
+    logger.muted = True
+    with patch("click.echo") as mock_echo:
+        logger.console_output("test message", color="red")
+
+    mock_echo.assert_not_called()
+    assert_that(logger._messages).is_equal_to(["test message"])
+
+
 @pytest.mark.parametrize(
     ("color", "expected_fg"),
     [
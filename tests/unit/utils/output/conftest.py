@@ -34,6 +34,9 @@ class MockToolResult:
     issues_count: int = 0
     output: str = ""
     issues: list[MockIssue] = field(default_factory=list)
+    skipped: bool = False
+    duration_ms: int | None = None
+    extra_args: list[str] = field(default_factory=list)
 
 
 @pytest.fixture
@@ -214,7 +214,7 @@ def test_format_tool_output_handles_invalid_bandit_json() -> None:
     result = format_tool_output("bandit", bandit_output, output_format="plain")
 
     # Should return error message with raw output for debugging
-    assert_that(result).contains("Error:")
+    assert_that(result).contains("Error: [LINTRO009]")
     assert_that(result).contains("Failed to parse Bandit output")
     assert_that(result).contains("Raw output:")
     assert_that(result).contains(bandit_output)
@@ -254,3 +254,40 @@ def test_tabulate_available_flag_is_true_in_test_environment() -> None:
     """
     assert_that(TABULATE_AVAILABLE).is_instance_of(bool)
     assert_that(TABULATE_AVAILABLE).is_true()
+
+
+def test_format_tool_output_samples_issues_per_rule() -> None:
+    """Sampling lists a few examples of each rule and notes the total."""
+    from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+    issues = [
+        RuffIssue(file=f"src/m{n}.py", line=1, code="E501", message="Too long")
+        for n in range(10)
+    ]
+
+    result = format_tool_output("ruff", "", issues=issues, sample_per_rule=2)
+
+    assert_that(result).contains("src/m0.py", "src/m1.py")
+    assert_that(result).does_not_contain("src/m2.py")
+    assert_that(result).contains("E501: showing 2 of 10 occurrences")
+
+
+def test_format_tool_output_json_ignores_sampling() -> None:
+    """JSON output keeps every issue even when sampling is requested."""
+    from lintro.parsers.ruff.ruff_issue import RuffIssue
+
+    issues = [
+        RuffIssue(file=f"src/m{n}.py", line=1, code="E501", message="Too long")
+        for n in range(4)
+    ]
+
+    result = format_tool_output(
+        "ruff",
+        "",
+        output_format="json",
+        issues=issues,
+        sample_per_rule=1,
+    )
+
+    assert_that(result).contains("src/m3.py")
+    assert_that(result).does_not_contain("showing")
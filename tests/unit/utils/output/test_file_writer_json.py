@@ -13,6 +13,8 @@ from assertpy import assert_that
 
 from lintro.enums.action import Action
 from lintro.enums.output_format import OutputFormat
+from lintro.parsers.eslint.eslint_issue import EslintIssue
+from lintro.parsers.rustfmt.rustfmt_issue import RustfmtIssue
 from lintro.utils.output.file_writer import write_output_file
 
 if TYPE_CHECKING:
@@ -57,6 +59,8 @@ def test_write_json_file_creates_valid_file(
     assert_that(content["results"]).is_length(1)
     assert_that(content["results"][0]["tool"]).is_equal_to("ruff")
     assert_that(content["timestamp"]).is_not_none()
+    assert_that(content["metadata"]["tools"]).contains_key("ruff")
+    assert_that(content["metadata"]).contains_key("lintro_version", "config_hash")
 
 
 def test_write_json_file_includes_parsed_issues(
@@ -90,3 +94,130 @@ def test_write_json_file_includes_parsed_issues(
     assert_that(issues[0]["line"]).is_equal_to(10)
     assert_that(issues[0]["code"]).is_equal_to("E001")
     assert_that(issues[0]["message"]).is_equal_to("Test error")
+    assert_that(issues[0]["fingerprint"]).matches(r"^[0-9a-f]{32}$")
+
+
+def test_write_json_file_includes_line_range_and_diff(
+    tmp_path: Path,
+    mock_tool_result_factory: Callable[..., MockToolResult],
+) -> None:
+    """Issues spanning several lines keep their range and diff preview.
+
+    Args:
+        tmp_path: Temporary directory path for test output.
+        mock_tool_result_factory: Factory for creating mock tool results.
+    """
+    output_path = tmp_path / "report.json"
+    issue = RustfmtIssue(
+        file="src/lib.rs",
+        line=4,
+        message="Formatting differs on lines 4-5",
+        end_line=5,
+        diff="-let x=1;\n+let x = 1;",
+    )
+    results = [
+        mock_tool_result_factory(name="rustfmt", issues_count=1, issues=[issue]),
+    ]
+
+    write_output_file(
+        output_path=str(output_path),
+        output_format=OutputFormat.JSON,
+        all_results=results,  # type: ignore[arg-type]
+        action=Action.CHECK,
+        total_issues=1,
+        total_fixed=0,
+    )
+
+    written = json.loads(output_path.read_text())["results"][0]["issues"][0]
+    assert_that(written).contains_entry(
+        {"line": 4},
+        {"end_line": 5},
+        {"diff": "-let x=1;\n+let x = 1;"},
+    )
+
+
+def test_write_json_file_includes_crate(
+    tmp_path: Path,
+    mock_tool_result_factory: Callable[..., MockToolResult],
+) -> None:
+    """Issues attributed to a workspace member name their crate.
+
+    Args:
+        tmp_path: Temporary directory path for test output.
+        mock_tool_result_factory: Factory for creating mock tool results.
+    """
+    output_path = tmp_path / "report.json"
+    issue = RustfmtIssue(file="crates/core/src/lib.rs", line=4, crate_name="core")
+    results = [
+        mock_tool_result_factory(name="rustfmt", issues_count=1, issues=[issue]),
+    ]
+
+    write_output_file(
+        output_path=str(output_path),
+        output_format=OutputFormat.JSON,
+        all_results=results,  # type: ignore[arg-type]
+        action=Action.CHECK,
+        total_issues=1,
+        total_fixed=0,
+    )
+
+    written = json.loads(output_path.read_text())["results"][0]["issues"][0]
+    assert_that(written).contains_entry({"crate": "core"})
+
+
+def test_write_json_file_includes_severity(
+    tmp_path: Path,
+    mock_tool_result_factory: Callable[..., MockToolResult],
+) -> None:
+    """Issues record their normalized severity for lintro query.
+
+    Args:
+        tmp_path: Temporary directory path for test output.
+        mock_tool_result_factory: Factory for creating mock tool results.
+    """
+    output_path = tmp_path / "report.json"
+    issue = EslintIssue(file="app.ts", line=2, code="no-undef", severity="error")
+    results = [
+        mock_tool_result_factory(name="eslint", issues_count=1, issues=[issue]),
+    ]
+
+    write_output_file(
+        output_path=str(output_path),
+        output_format=OutputFormat.JSON,
+        all_results=results,  # type: ignore[arg-type]
+        action=Action.CHECK,
+        total_issues=1,
+        total_fixed=0,
+    )
+
+    written = json.loads(output_path.read_text())["results"][0]["issues"][0]
+    assert_that(written).contains_entry({"severity": "error"})
+
+
+def test_write_json_file_includes_script_fields(
+    tmp_path: Path,
+    mock_tool_result_factory: Callable[..., MockToolResult],
+) -> None:
+    """Fields computed by output.script are written under "fields".
+
+    Args:
+        tmp_path: Temporary directory path for test output.
+        mock_tool_result_factory: Factory for creating mock tool results.
+    """
+    output_path = tmp_path / "report.json"
+    issue = EslintIssue(file="app.ts", line=2, script_fields={"owner": "web"})
+    results = [
+        mock_tool_result_factory(name="eslint", issues_count=1, issues=[issue]),
+    ]
+
+    write_output_file(
+        output_path=str(output_path),
+        output_format=OutputFormat.JSON,
+        all_results=results,  # type: ignore[arg-type]
+        action=Action.CHECK,
+        total_issues=1,
+        total_fixed=0,
+    )
+
+    written = json.loads(output_path.read_text())["results"][0]["issues"][0]
+    assert_that(written).contains_entry({"fields": {"owner": "web"}})
@@ -113,3 +113,35 @@ def test_write_markdown_file_escapes_pipe_characters(
     content = output_path.read_text()
     assert_that(content).contains(r"A \| B")
     assert_that(content).does_not_contain("| A | B |")
+
+
+def test_write_markdown_file_samples_issues_per_rule(
+    tmp_path: Path,
+    mock_tool_result_factory: Callable[..., MockToolResult],
+    mock_issue_factory: Callable[..., MockIssue],
+) -> None:
+    """Sampling lists a few examples per rule and notes the total.
+
+    Args:
+        tmp_path: Temporary directory path for test output.
+        mock_tool_result_factory: Factory for creating mock tool results.
+        mock_issue_factory: Factory for creating mock issues.
+    """
+    output_path = tmp_path / "report.md"
+    issues = [mock_issue_factory(file=f"src/m{n}.py") for n in range(5)]
+    results = [mock_tool_result_factory(name="ruff", issues_count=5, issues=issues)]
+
+    write_output_file(
+        output_path=str(output_path),
+        output_format=OutputFormat.MARKDOWN,
+        all_results=results,  # type: ignore[arg-type]
+        action=Action.CHECK,
+        total_issues=5,
+        total_fixed=0,
+        sample_per_rule=2,
+    )
+
+    content = output_path.read_text()
+    assert_that(content).contains("### ruff (5 issues)", "src/m1.py")
+    assert_that(content).does_not_contain("src/m2.py")
+    assert_that(content).contains("E001: showing 2 of 5 occurrences")
@@ -0,0 +1,222 @@
+"""Tests for combining the reports of a sharded run."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.utils.output.report_merge import merge_reports
+
+
+def _write(path: Path, data: dict[str, Any]) -> Path:
+    """Write a report.
+
+    Args:
+        path: Report file.
+        data: Report contents.
+
+    Returns:
+        Path: The report file.
+    """
+    path.write_text(json.dumps(data))
+    return path
+
+
+def _report(shard: str, *results: dict[str, Any]) -> dict[str, Any]:
+    """Build the JSON report of one shard.
+
+    Args:
+        shard: Shard of the report, as I/N.
+        *results: Tool results.
+
+    Returns:
+        dict[str, Any]: JSON report.
+    """
+    return {
+        "timestamp": f"2026-01-0{shard[0]}T00:00:00Z",
+        "action": "check",
+        "shard": shard,
+        "summary": {"total_issues": 0, "total_fixed": 0, "tools_run": 0},
+        "results": list(results),
+    }
+
+
+def _issue(file: str) -> dict[str, Any]:
+    """Build a reported issue.
+
+    Args:
+        file: File of the issue.
+
+    Returns:
+        dict[str, Any]: Issue as written in JSON reports.
+    """
+    return {"file": file, "line": 1, "code": "F401", "message": "unused"}
+
+
+def test_merge_json_groups_results_by_tool(tmp_path: Path) -> None:
+    """Each tool appears once, with the issues of every shard.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    first = _write(
+        tmp_path / "1.json",
+        _report(
+            "1/2",
+            {
+                "tool": "ruff",
+                "success": False,
+                "issues_count": 1,
+                "issues": [_issue("a.py")],
+            },
+            {"tool": "mypy", "success": True, "issues_count": 0},
+        ),
+    )
+    second = _write(
+        tmp_path / "2.json",
+        _report(
+            "2/2",
+            {
+                "tool": "ruff",
+                "success": False,
+                "issues_count": 1,
+                "issues": [_issue("b.py")],
+            },
+            {
+                "tool": "mypy",
+                "success": True,
+                "issues_count": 0,
+                "skipped": True,
+                "skip_reason": "runs in another shard",
+            },
+        ),
+    )
+
+    merged = merge_reports([first, second])
+
+    results = {r["tool"]: r for r in merged.data["results"]}
+    assert_that(results["ruff"]["issues_count"]).is_equal_to(2)
+    assert_that([i["file"] for i in results["ruff"]["issues"]]).is_equal_to(
+        ["a.py", "b.py"],
+    )
+    assert_that(results["mypy"]).does_not_contain_key("skipped")
+    assert_that(merged.data["summary"]["total_issues"]).is_equal_to(2)
+    assert_that(merged.data["timestamp"]).starts_with("2026-01-02")
+    assert_that(merged.missing_shards).is_empty()
+
+
+def test_merge_json_reports_missing_shards(tmp_path: Path) -> None:
+    """Shards without a report are listed.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    report = _write(tmp_path / "1.json", _report("1/3"))
+
+    merged = merge_reports([report])
+
+    assert_that(merged.missing_shards).is_equal_to(["2/3", "3/3"])
+
+
+@pytest.mark.parametrize(
+    ("shards", "message"),
+    [
+        (["1/2", "1/2"], "also in"),
+        (["1/2", "2/3"], "does not match"),
+    ],
+)
+def test_merge_json_rejects_mismatched_shards(
+    tmp_path: Path,
+    shards: list[str],
+    message: str,
+) -> None:
+    """Duplicate shards and differing shard counts are rejected.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+        shards: Shard of each report.
+        message: Expected part of the error.
+    """
+    paths = [
+        _write(tmp_path / f"{i}.json", _report(shard))
+        for i, shard in enumerate(shards)
+    ]
+
+    with pytest.raises(ValueError, match=message):
+        merge_reports(paths)
+
+
+def test_merge_rejects_different_actions(tmp_path: Path) -> None:
+    """Reports of check and fmt runs are not merged.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    fmt_report = {**_report("2/2"), "action": "fmt"}
+    paths = [
+        _write(tmp_path / "1.json", _report("1/2")),
+        _write(tmp_path / "2.json", fmt_report),
+    ]
+
+    with pytest.raises(ValueError, match="different actions"):
+        merge_reports(paths)
+
+
+def test_merge_sarif_combines_runs_per_tool(tmp_path: Path) -> None:
+    """SARIF runs of the same tool are joined with the union of their rules.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+
+    def sarif(rule: str) -> dict[str, Any]:
+        """Build a SARIF log with one ruff result.
+
+        Args:
+            rule: Rule of the result.
+
+        Returns:
+            dict[str, Any]: SARIF log.
+        """
+        return {
+            "version": "2.1.0",
+            "runs": [
+                {
+                    "tool": {"driver": {"name": "ruff", "rules": [{"id": rule}]}},
+                    "results": [{"ruleId": rule}],
+                },
+            ],
+        }
+
+    paths = [
+        _write(tmp_path / "1.sarif", sarif("F401")),
+        _write(tmp_path / "2.sarif", sarif("E501")),
+    ]
+
+    merged = merge_reports(paths)
+
+    runs = merged.data["runs"]
+    assert_that(runs).is_length(1)
+    assert_that(runs[0]["tool"]["driver"]["rules"]).is_equal_to(
+        [{"id": "E501"}, {"id": "F401"}],
+    )
+    assert_that(runs[0]["results"]).is_length(2)
+
+
+def test_merge_rejects_mixed_formats(tmp_path: Path) -> None:
+    """JSON reports and SARIF logs are not merged together.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    paths = [
+        _write(tmp_path / "1.json", _report("1/2")),
+        _write(tmp_path / "2.sarif", {"version": "2.1.0", "runs": []}),
+    ]
+
+    with pytest.raises(ValueError, match="SARIF"):
+        merge_reports(paths)
@@ -0,0 +1,84 @@
+"""Unit tests for per-rule issue sampling."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from assertpy import assert_that
+
+from lintro.utils.output.rule_sampling import (
+    SampledRule,
+    format_sampling_note,
+    sample_issues,
+)
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .conftest import MockIssue
+
+
+def test_sample_issues_keeps_small_rules_whole(
+    mock_issue_factory: Callable[..., MockIssue],
+) -> None:
+    """Rules with no more hits than the limit are left alone.
+
+    Args:
+        mock_issue_factory: Factory for creating mock issues.
+    """
+    issues = [mock_issue_factory(code="E1", line=n) for n in range(3)]
+
+    kept, sampled = sample_issues(issues, 3)
+
+    assert_that(kept).is_equal_to(issues)
+    assert_that(sampled).is_empty()
+
+
+def test_sample_issues_spreads_examples_over_files(
+    mock_issue_factory: Callable[..., MockIssue],
+) -> None:
+    """Examples come from as many files as possible, in report order.
+
+    Args:
+        mock_issue_factory: Factory for creating mock issues.
+    """
+    issues = [
+        mock_issue_factory(file="a.py", line=1, code="E501"),
+        mock_issue_factory(file="a.py", line=2, code="E501"),
+        mock_issue_factory(file="a.py", line=3, code="E501"),
+        mock_issue_factory(file="b.py", line=1, code="F401"),
+        mock_issue_factory(file="b.py", line=4, code="E501"),
+        mock_issue_factory(file="c.py", line=9, code="E501"),
+    ]
+
+    kept, sampled = sample_issues(issues, 3)
+
+    assert_that([(i.file, i.line) for i in kept]).is_equal_to(
+        [("a.py", 1), ("b.py", 1), ("b.py", 4), ("c.py", 9)],
+    )
+    assert_that(sampled).is_equal_to([SampledRule(code="E501", shown=3, total=5)])
+
+
+def test_sample_issues_keeps_issues_without_a_code(
+    mock_issue_factory: Callable[..., MockIssue],
+) -> None:
+    """Issues without a rule code cannot be grouped and are all kept.
+
+    Args:
+        mock_issue_factory: Factory for creating mock issues.
+    """
+    issues = [mock_issue_factory(code="", line=n) for n in range(5)]
+
+    kept, sampled = sample_issues(issues, 1)
+
+    assert_that(kept).is_length(5)
+    assert_that(sampled).is_empty()
+
+
+def test_format_sampling_note_states_totals() -> None:
+    """The note names each sampled rule with its total count."""
+    note = format_sampling_note([SampledRule(code="E501", shown=5, total=3214)])
+
+    assert_that(note).contains("E501: showing 5 of 3,214 occurrences")
+    assert_that(note).contains("JSON and CSV")
+    assert_that(format_sampling_note([])).is_empty()
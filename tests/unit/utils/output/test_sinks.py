@@ -0,0 +1,134 @@
+"""Unit tests for output sinks and SARIF reports."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.output.sarif import build_sarif_report, write_sarif_file
+from lintro.utils.output.sinks import OutputSink, console_format, parse_output_sink
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [
+        ("console", OutputSink()),
+        ("Console=JSON", OutputSink(format="json")),
+        ("sarif=out/r.sarif", OutputSink(format="sarif", path="out/r.sarif")),
+        ("json=report.json", OutputSink(format="json", path="report.json")),
+        ("report.txt", OutputSink(path="report.txt")),
+        ("a=b.txt", OutputSink(path="a=b.txt")),
+    ],
+)
+def test_parse_output_sink(value: str, expected: OutputSink) -> None:
+    """Sinks name the console, a file with its format, or a bare path.
+
+    Args:
+        value: Value of --output.
+        expected: Parsed sink.
+    """
+    assert_that(parse_output_sink(value)).is_equal_to(expected)
+
+
+@pytest.mark.parametrize(
+    ("value", "message"),
+    [
+        ("", "must not be empty"),
+        ("console=sarif", "Unsupported console format: sarif"),
+        ("csv=", "names no file; use csv=PATH"),
+    ],
+)
+def test_parse_output_sink_rejects_invalid_values(value: str, message: str) -> None:
+    """Invalid sinks raise ValueError with the reason.
+
+    Args:
+        value: Value of --output.
+        message: Expected part of the error message.
+    """
+    with pytest.raises(ValueError, match=message):
+        parse_output_sink(value)
+
+
+def test_console_format_rejects_conflicts() -> None:
+    """Only one console format may be chosen."""
+    sinks = [OutputSink(), OutputSink(format="plain"), OutputSink(path="r.txt")]
+
+    assert_that(console_format(sinks)).is_equal_to("plain")
+    assert_that(console_format([OutputSink()])).is_none()
+    with pytest.raises(ValueError, match="Conflicting console formats"):
+        console_format([OutputSink(format="plain"), OutputSink(format="json")])
+
+
+def test_build_sarif_report_has_one_run_per_tool() -> None:
+    """Issues become SARIF results with rule, level and location."""
+    results = [
+        ToolResult(
+            name="ruff",
+            success=False,
+            issues=[
+                RuffIssue(
+                    file="src/a.py",
+                    line=3,
+                    column=5,
+                    code="F401",
+                    message="unused import",
+                    severity_override=SeverityLevel.ERROR,
+                ),
+                RuffIssue(file="src/b.py", line=1, code="E501", message="long"),
+            ],
+            issues_count=2,
+        ),
+        ToolResult(name="mypy", success=True, issues_count=0),
+        ToolResult(
+            name="hadolint",
+            success=True,
+            skipped=True,
+            skip_reason="not installed",
+        ),
+    ]
+
+    report = build_sarif_report(results)
+
+    assert_that(report["version"]).is_equal_to("2.1.0")
+    assert_that([run["tool"]["driver"]["name"] for run in report["runs"]]).is_equal_to(
+        ["ruff", "mypy"],
+    )
+    ruff_run = report["runs"][0]
+    assert_that(ruff_run["tool"]["driver"]["rules"]).is_equal_to(
+        [{"id": "E501"}, {"id": "F401"}],
+    )
+    first = ruff_run["results"][0]
+    assert_that(first["ruleId"]).is_equal_to("F401")
+    assert_that(first["level"]).is_equal_to("error")
+    assert_that(first["message"]).is_equal_to({"text": "unused import"})
+    assert_that(first["locations"][0]["physicalLocation"]).is_equal_to(
+        {
+            "artifactLocation": {"uri": "src/a.py"},
+            "region": {"startLine": 3, "startColumn": 5},
+        },
+    )
+    assert_that(first["partialFingerprints"]).is_equal_to(
+        {"lintroFingerprint/v1": results[0].issues[0].fingerprint("ruff")},
+    )
+    assert_that(ruff_run["results"][1]["level"]).is_equal_to("warning")
+    assert_that(report["runs"][1]["results"]).is_empty()
+
+
+def test_write_sarif_file_creates_parent_directories(tmp_path: Path) -> None:
+    """The SARIF file is written as JSON, creating its directory.
+
+    Args:
+        tmp_path: Temporary directory path for testing.
+    """
+    path = tmp_path / "out" / "report.sarif"
+
+    write_sarif_file(str(path), [ToolResult(name="ruff", success=True)])
+
+    data = json.loads(path.read_text(encoding="utf-8"))
+    assert_that(data["runs"]).is_length(1)
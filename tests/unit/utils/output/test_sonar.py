@@ -0,0 +1,106 @@
+"""Unit tests for SonarQube generic issue reports."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.bandit.bandit_issue import BanditIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.output.sinks import OutputSink, parse_output_sink
+from lintro.utils.output.sonar import build_sonar_report, write_sonar_file
+
+
+def test_build_sonar_report_groups_issues_into_rules() -> None:
+    """Each tool:code is one rule, at the worst severity it was seen with."""
+    results = [
+        ToolResult(
+            name="ruff",
+            success=False,
+            issues=[
+                RuffIssue(file="src/a.py", line=3, code="F401", message="unused"),
+                RuffIssue(
+                    file="src/b.py",
+                    line=7,
+                    code="F401",
+                    message="unused",
+                    severity_override=SeverityLevel.ERROR,
+                ),
+                RuffIssue(file="", line=1, code="E902", message="no file"),
+            ],
+            issues_count=3,
+        ),
+        ToolResult(
+            name="bandit",
+            success=False,
+            issues=[
+                BanditIssue(
+                    file="src/a.py",
+                    line=9,
+                    test_id="B602",
+                    issue_severity="HIGH",
+                    issue_text="shell=True",
+                ),
+            ],
+            issues_count=1,
+        ),
+        ToolResult(
+            name="hadolint",
+            success=True,
+            skipped=True,
+            skip_reason="not installed",
+        ),
+    ]
+
+    report = build_sonar_report(results, {"ruff:F401": 2})
+
+    ruff_rule, bandit_rule = report["rules"]
+    assert_that(ruff_rule).is_equal_to(
+        {
+            "id": "ruff:F401",
+            "name": "ruff F401",
+            "engineId": "ruff",
+            "cleanCodeAttribute": "CONVENTIONAL",
+            "type": "CODE_SMELL",
+            "severity": "MAJOR",
+            "impacts": [{"softwareQuality": "MAINTAINABILITY", "severity": "HIGH"}],
+        },
+    )
+    assert_that(bandit_rule["type"]).is_equal_to("VULNERABILITY")
+    assert_that(bandit_rule["impacts"][0]["softwareQuality"]).is_equal_to(
+        "SECURITY",
+    )
+    assert_that(report["issues"]).is_length(3)
+    assert_that(report["issues"][0]).is_equal_to(
+        {
+            "ruleId": "ruff:F401",
+            "effortMinutes": 2,
+            "primaryLocation": {
+                "message": "unused",
+                "filePath": "src/a.py",
+                "textRange": {"startLine": 3},
+            },
+        },
+    )
+    assert_that(report["issues"][2]["ruleId"]).is_equal_to("bandit:B602")
+
+
+def test_write_sonar_file_is_an_output_sink(tmp_path: Path) -> None:
+    """sonar=PATH writes the report as JSON, creating its directory.
+
+    Args:
+        tmp_path: Temporary directory path for testing.
+    """
+    path = tmp_path / "out" / "sonar.json"
+    assert_that(parse_output_sink(f"sonar={path}")).is_equal_to(
+        OutputSink(format="sonar", path=str(path)),
+    )
+
+    write_sonar_file(str(path), [ToolResult(name="ruff", success=True)])
+
+    data = json.loads(path.read_text(encoding="utf-8"))
+    assert_that(data).is_equal_to({"rules": [], "issues": []})
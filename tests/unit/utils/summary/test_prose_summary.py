@@ -0,0 +1,94 @@
+"""Tests for paragraph-style run summaries."""
+
+from __future__ import annotations
+
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.effort import estimate_effort
+from lintro.utils.prose_summary import build_prose_summary
+
+
+def _ruff_result(*issues: RuffIssue) -> ToolResult:
+    """Build a ruff check result.
+
+    Args:
+        *issues: Issues reported by ruff.
+
+    Returns:
+        ToolResult: Result containing the issues.
+    """
+    return ToolResult(
+        name="ruff",
+        success=not issues,
+        issues_count=len(issues),
+        issues=list(issues),
+    )
+
+
+def test_clean_run() -> None:
+    """A clean run says so in one sentence."""
+    summary = build_prose_summary(
+        [ToolResult(name="ruff", success=True), ToolResult(name="mypy", success=True)],
+    )
+
+    assert_that(summary).is_equal_to("Lintro ran 2 tools and found no issues.")
+
+
+def test_summary_names_top_rules_files_and_fixes() -> None:
+    """Rules and files are ranked by count and fix commands are suggested."""
+    results = [
+        _ruff_result(
+            RuffIssue(file="a.py", code="E501"),
+            RuffIssue(file="a.py", code="E501"),
+            RuffIssue(file="b.py", code="F401", fixable=True),
+        ),
+        ToolResult(
+            name="mypy",
+            success=False,
+            issues_count=1,
+            issues=[BaseIssue(file="b.py", message="bad")],
+        ),
+    ]
+
+    summary = build_prose_summary(results, estimate_effort(results))
+
+    assert_that(summary).is_equal_to(
+        "Lintro found 4 issues (4 warnings) in 2 files from ruff and mypy, "
+        "an estimated ~16 minutes of work. The most common rules are "
+        "ruff E501 (2) and ruff F401 (1). The files with the most issues are "
+        "a.py (2) and b.py (2). 1 issue can be fixed automatically with "
+        "`lintro format --tools ruff`. To reproduce locally, run "
+        "`lintro check --tools ruff,mypy`.",
+    )
+
+
+def test_summary_is_deterministic() -> None:
+    """Ties are broken by name, so input order does not change the text."""
+    first = RuffIssue(file="b.py", code="W291")
+    second = RuffIssue(file="a.py", code="E501")
+
+    assert_that(build_prose_summary([_ruff_result(first, second)])).is_equal_to(
+        build_prose_summary([_ruff_result(second, first)]),
+    )
+
+
+def test_summary_reports_crashed_and_missing_tools() -> None:
+    """Infrastructure problems are reported separately from issues."""
+    results = [
+        ToolResult(name="mypy", success=False, output="Traceback"),
+        ToolResult(
+            name="hadolint",
+            skipped=True,
+            skip_reason="not installed",
+            missing=True,
+        ),
+    ]
+
+    summary = build_prose_summary(results)
+
+    assert_that(summary).contains("mypy failed to run")
+    assert_that(summary).contains("hadolint was skipped as not installed.")
+    assert_that(summary).ends_with("run `lintro check --tools mypy`.")
@@ -223,6 +223,27 @@ def test_totals_table_check_mode_does_not_contain_fix_rows(
     assert_that(combined).does_not_contain("Remaining Issues")
 
 
+def test_totals_table_check_mode_contains_estimated_effort(
+    console_capture: tuple[Callable[..., None], list[str]],
+) -> None:
+    """Verify CHECK mode table shows the estimated remediation effort.
+
+    Args:
+        console_capture: Fixture for capturing console output.
+    """
+    capture_func, output = console_capture
+    print_totals_table(
+        console_output_func=capture_func,
+        action=Action.CHECK,
+        total_issues=90,
+        affected_files=12,
+        effort_minutes=840,
+    )
+    combined = "\n".join(output)
+    assert_that(combined).contains("Estimated Effort")
+    assert_that(combined).contains("~14 hours")
+
+
 # =============================================================================
 # print_totals_table Tests - TEST Mode
 # =============================================================================
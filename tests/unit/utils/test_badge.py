@@ -0,0 +1,103 @@
+"""Tests for lintro.utils.badge."""
+
+from __future__ import annotations
+
+import xml.etree.ElementTree as ET
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.utils.badge import (
+    FAIL_COLOR,
+    PASS_COLOR,
+    WARN_COLOR,
+    BadgeSummary,
+    badge_message,
+    render_badge_svg,
+    summarize_report,
+    summarize_tools,
+)
+
+
+def _report() -> dict[str, Any]:
+    """Build a report where ruff found a warning and an error.
+
+    Returns:
+        dict[str, Any]: The report.
+    """
+    return {
+        "results": [
+            {
+                "tool": "ruff",
+                "success": False,
+                "issues_count": 2,
+                "issues": [
+                    {"file": "a.py", "line": 1, "severity": "error"},
+                    {"file": "a.py", "line": 2, "severity": "warning"},
+                ],
+            },
+            {"tool": "yamllint", "success": True, "issues_count": 0},
+        ],
+    }
+
+
+def test_summarize_report_counts_every_tool() -> None:
+    """The whole-report summary adds up the tools."""
+    summary = summarize_report(_report())
+
+    assert_that(summary).is_equal_to(
+        BadgeSummary(tool=None, issues=2, errors=1, failed=True),
+    )
+
+
+def test_summarize_tools_gives_one_summary_per_tool() -> None:
+    """Each tool that ran gets its own summary."""
+    summaries = summarize_tools(_report())
+
+    assert_that([(s.tool, s.issues, s.failed) for s in summaries]).is_equal_to(
+        [("ruff", 2, True), ("yamllint", 0, False)],
+    )
+
+
+def test_summarize_report_for_missing_tool() -> None:
+    """Asking for a tool that did not run is an error."""
+    with pytest.raises(ValueError, match="did not run"):
+        summarize_report(_report(), tool="mypy")
+
+
+@pytest.mark.parametrize(
+    ("summary", "status", "expected"),
+    [
+        (BadgeSummary(None, 0, 0, False), False, ("0 issues", PASS_COLOR)),
+        (BadgeSummary(None, 1, 0, True), False, ("1 issue", WARN_COLOR)),
+        (BadgeSummary(None, 3, 1, True), False, ("3 issues", FAIL_COLOR)),
+        (BadgeSummary(None, 0, 0, True), False, ("failed", FAIL_COLOR)),
+        (BadgeSummary(None, 0, 0, False), True, ("passing", PASS_COLOR)),
+        (BadgeSummary(None, 2, 0, True), True, ("failing", FAIL_COLOR)),
+    ],
+)
+def test_badge_message(
+    summary: BadgeSummary,
+    status: bool,
+    expected: tuple[str, str],
+) -> None:
+    """Counts are coloured by worst severity, statuses by outcome.
+
+    Args:
+        summary: Summary the badge shows.
+        status: Whether the badge shows passing or failing.
+        expected: Message and colour.
+    """
+    assert_that(badge_message(summary, status=status)).is_equal_to(expected)
+
+
+def test_render_badge_svg_is_valid_and_escaped() -> None:
+    """The badge is well-formed SVG with the texts escaped."""
+    svg = render_badge_svg("a&b", "<1 issue>", WARN_COLOR)
+
+    root = ET.fromstring(svg)
+    texts = [t.text for t in root.iter("{http://www.w3.org/2000/svg}text")]
+    assert_that(texts).is_equal_to(["a&b", "a&b", "<1 issue>", "<1 issue>"])
+    assert_that(root.get("aria-label")).is_equal_to("a&b: <1 issue>")
+    assert_that(svg).contains(f'fill="{WARN_COLOR}"')
@@ -0,0 +1,187 @@
+"""Tests for the storage backends of the result cache."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from typing import Any
+
+import httpx
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_execution_config
+from lintro.utils import cache_backends
+from lintro.utils.cache_backends import (
+    CliCacheBackend,
+    HttpCacheBackend,
+    LocalCacheBackend,
+    create_cache_backend,
+)
+
+
+class _Response:
+    """Minimal HTTP response."""
+
+    def __init__(self, status_code: int, content: bytes = b"") -> None:
+        """Build the response.
+
+        Args:
+            status_code: HTTP status.
+            content: Response body.
+        """
+        self.status_code = status_code
+        self.content = content
+
+
+def test_local_backend_round_trip(tmp_path: Path) -> None:
+    """Entries written locally are read back; unknown keys miss.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+    """
+    backend = LocalCacheBackend(tmp_path)
+    backend.put("ruff/ns/key.json", b"{}")
+
+    assert_that(backend.get("ruff/ns/key.json")).is_equal_to(b"{}")
+    assert_that(backend.get("ruff/ns/other.json")).is_none()
+
+
+def test_create_cache_backend_by_scheme(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Each URL scheme gets its backend; HTTP stores take the token.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setenv("LINTRO_CACHE_TOKEN", "secret")
+
+    s3 = create_cache_backend("s3://bucket/lintro")
+    gcs = create_cache_backend("gs://bucket/lintro")
+    http = create_cache_backend("https://cache.example.com/lintro/")
+
+    assert isinstance(s3, CliCacheBackend)
+    assert isinstance(gcs, CliCacheBackend)
+    assert isinstance(http, HttpCacheBackend)
+    assert_that(s3.command[:3]).is_equal_to(["aws", "s3", "cp"])
+    assert_that(gcs.command[:2]).is_equal_to(["gcloud", "storage"])
+    assert_that(http.name).is_equal_to("https://cache.example.com/lintro")
+    assert_that(http.token).is_equal_to("secret")
+
+
+def test_create_cache_backend_rejects_unknown_scheme() -> None:
+    """Unsupported URLs are rejected."""
+    with pytest.raises(ValueError, match="Unsupported cache URL"):
+        create_cache_backend("ftp://cache.example.com")
+
+
+def test_http_backend_get_and_put(monkeypatch: pytest.MonkeyPatch) -> None:
+    """HTTP stores are read with GET and written with PUT, with the token.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[tuple[str, str, dict[str, str]]] = []
+
+    def fake_request(method: str, url: str, **kwargs: Any) -> _Response:
+        """Record the request and answer like a cache service.
+
+        Args:
+            method: HTTP method.
+            url: Request URL.
+            **kwargs: Request arguments.
+
+        Returns:
+            _Response: 200 for known keys and writes, else 404.
+        """
+        calls.append((method, url, kwargs["headers"]))
+        if method == "GET" and url.endswith("/hit.json"):
+            return _Response(200, b"{}")
+        return _Response(404 if method == "GET" else 201)
+
+    monkeypatch.setattr(httpx, "request", fake_request, raising=False)
+    backend = HttpCacheBackend("https://cache.example.com", token="t")
+
+    assert_that(backend.get("hit.json")).is_equal_to(b"{}")
+    assert_that(backend.get("miss.json")).is_none()
+    backend.put("new.json", b"{}")
+
+    assert_that(calls[2][:2]).is_equal_to(("PUT", "https://cache.example.com/new.json"))
+    assert_that(calls[0][2]).contains_entry({"Authorization": "Bearer t"})
+
+
+def test_http_backend_treats_network_errors_as_misses(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """An unreachable store is a miss, not a failure.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+
+    def fake_request(method: str, url: str, **kwargs: Any) -> _Response:
+        """Fail like an unreachable host.
+
+        Args:
+            method: HTTP method.
+            url: Request URL.
+            **kwargs: Request arguments.
+
+        Raises:
+            ConnectError: Always.
+        """
+        raise httpx.ConnectError("unreachable")
+
+    monkeypatch.setattr(httpx, "request", fake_request, raising=False)
+    backend = HttpCacheBackend("https://cache.example.com")
+
+    assert_that(backend.get("key.json")).is_none()
+    backend.put("key.json", b"{}")
+
+
+def test_cli_backend_copies_through_stdio(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Object stores are read from stdout and written from stdin.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    calls: list[tuple[list[str], bytes | None]] = []
+
+    def fake_run(cmd: list[str], **kwargs: Any) -> subprocess.CompletedProcess[bytes]:
+        """Record the copy and answer like ``aws s3 cp``.
+
+        Args:
+            cmd: Command line.
+            **kwargs: Run arguments.
+
+        Returns:
+            subprocess.CompletedProcess[bytes]: Successful copy.
+        """
+        calls.append((cmd, kwargs.get("input")))
+        return subprocess.CompletedProcess(cmd, 0, stdout=b"{}", stderr=b"")
+
+    monkeypatch.setattr(cache_backends.shutil, "which", lambda name: name)
+    monkeypatch.setattr(cache_backends.subprocess, "run", fake_run)
+    backend = CliCacheBackend(["aws", "s3", "cp"], "s3://bucket/lintro")
+
+    assert_that(backend.get("key.json")).is_equal_to(b"{}")
+    backend.put("key.json", b"data")
+
+    assert_that(calls[0][0][-2:]).is_equal_to(["s3://bucket/lintro/key.json", "-"])
+    assert_that(calls[1][0][-2:]).is_equal_to(["-", "s3://bucket/lintro/key.json"])
+    assert_that(calls[1][1]).is_equal_to(b"data")
+
+
+def test_cache_config_rejects_unsupported_remote() -> None:
+    """execution.cache.remote must use a supported scheme."""
+    with pytest.raises(ValueError, match="execution.cache.remote"):
+        _parse_execution_config({"cache": {"remote": "/mnt/cache"}})
+
+
+def test_cache_config_accepts_comma_separated_tools() -> None:
+    """execution.cache.tools accepts a comma-separated string."""
+    config = _parse_execution_config(
+        {"cache": {"tools": "ruff, yamllint", "remote": "gs://bucket/lintro"}},
+    )
+
+    assert_that(config.cache.tools).is_equal_to(["ruff", "yamllint"])
+    assert_that(config.cache.remote).is_equal_to("gs://bucket/lintro")
@@ -0,0 +1,164 @@
+"""Tests for Cargo workspace discovery and member selection."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+import lintro.utils.cargo_workspace as cargo_workspace_module
+from lintro.utils.cargo_workspace import (
+    CargoPackage,
+    CargoWorkspace,
+    parse_metadata,
+    resolve_target,
+)
+
+
+@pytest.fixture
+def workspace(tmp_path: Path) -> CargoWorkspace:
+    """Provide a workspace with a root package and two members.
+
+    Args:
+        tmp_path: Temporary workspace root.
+
+    Returns:
+        CargoWorkspace: Workspace whose ``app`` package sits at the root.
+    """
+    root = tmp_path.resolve()
+    return CargoWorkspace(
+        root=root,
+        members=(
+            CargoPackage(name="app", root=root),
+            CargoPackage(name="core", root=root / "crates" / "core"),
+            CargoPackage(name="cli", root=root / "crates" / "cli"),
+        ),
+    )
+
+
+def test_parse_metadata_keeps_workspace_members(tmp_path: Path) -> None:
+    """Only packages listed as workspace members are kept.
+
+    Args:
+        tmp_path: Temporary workspace root.
+    """
+    output = json.dumps(
+        {
+            "workspace_root": str(tmp_path),
+            "workspace_members": ["core 0.1.0 (path+file:///ws/crates/core)"],
+            "packages": [
+                {
+                    "id": "core 0.1.0 (path+file:///ws/crates/core)",
+                    "name": "core",
+                    "manifest_path": str(tmp_path / "crates/core/Cargo.toml"),
+                },
+                {
+                    "id": "vendored 0.1.0 (path+file:///ws/vendor)",
+                    "name": "vendored",
+                    "manifest_path": str(tmp_path / "vendor/Cargo.toml"),
+                },
+            ],
+        },
+    )
+
+    workspace = parse_metadata(output)
+
+    assert workspace is not None
+    assert_that(workspace.root).is_equal_to(tmp_path.resolve())
+    assert_that([member.name for member in workspace.members]).is_equal_to(
+        ["core"],
+    )
+    assert_that(workspace.members[0].root).is_equal_to(
+        (tmp_path / "crates" / "core").resolve(),
+    )
+
+
+def test_parse_metadata_rejects_other_output() -> None:
+    """Output that is not cargo metadata gives no workspace."""
+    assert_that(parse_metadata("error: could not find Cargo.toml")).is_none()
+    assert_that(parse_metadata('{"packages": []}')).is_none()
+
+
+def test_deepest_member_owns_a_file(workspace: CargoWorkspace) -> None:
+    """A member nested below the root package owns its own files.
+
+    Args:
+        workspace: Workspace with a root package.
+    """
+    core = workspace.package_for("crates/core/src/lib.rs")
+    app = workspace.package_for(workspace.root / "src" / "main.rs")
+
+    assert_that(core.name if core else None).is_equal_to("core")
+    assert_that(app.name if app else None).is_equal_to("app")
+
+
+def test_resolve_target_selects_members_of_the_files(
+    workspace: CargoWorkspace,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Files in a member run from the workspace root with that member only.
+
+    Args:
+        workspace: Workspace with a root package.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        cargo_workspace_module,
+        "load_workspace",
+        lambda directory: workspace,
+    )
+    member = workspace.root / "crates" / "cli"
+
+    target = resolve_target([str(member / "src" / "main.rs")], member)
+
+    assert_that(target.root).is_equal_to(workspace.root)
+    assert_that(target.packages).is_equal_to(("cli",))
+    assert_that(target.crate_for("crates/cli/src/main.rs")).is_equal_to("cli")
+
+
+def test_resolve_target_selects_everything_for_all_members(
+    workspace: CargoWorkspace,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Selecting every member runs on the whole workspace.
+
+    Args:
+        workspace: Workspace with a root package.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        cargo_workspace_module,
+        "load_workspace",
+        lambda directory: workspace,
+    )
+    files = [str(member.root / "src" / "lib.rs") for member in workspace.members]
+
+    target = resolve_target(files, workspace.root)
+
+    assert_that(target.root).is_equal_to(workspace.root)
+    assert_that(target.packages).is_none()
+
+
+def test_resolve_target_falls_back_without_metadata(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without cargo metadata the tool runs where the manifest is.
+
+    Args:
+        tmp_path: Temporary crate root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(
+        cargo_workspace_module,
+        "load_workspace",
+        lambda directory: None,
+    )
+
+    target = resolve_target([str(tmp_path / "src" / "lib.rs")], tmp_path)
+
+    assert_that(target.root).is_equal_to(tmp_path)
+    assert_that(target.packages).is_none()
+    assert_that(target.crate_for("src/lib.rs")).is_none()
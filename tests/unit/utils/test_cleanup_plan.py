@@ -0,0 +1,181 @@
+"""Tests for prioritized cleanup plans."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.frontmatter.frontmatter_issue import FrontmatterIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.cleanup_plan import (
+    build_cleanup_plan,
+    load_codeowners,
+    owners_for,
+    parse_budget,
+    resolve_repo_paths,
+    write_owner_task_lists,
+)
+
+
+@dataclass
+class _Result:
+    """Minimal tool result carrying issues.
+
+    Attributes:
+        name: Tool name.
+        issues: Issues reported by the tool.
+    """
+
+    name: str
+    issues: list[BaseIssue] = field(default_factory=list)
+
+
+@pytest.mark.parametrize(
+    "text,minutes",
+    [("4h", 240), ("90m", 90), ("1h30m", 90), ("2d", 960), ("45", 45), ("1.5h", 90)],
+)
+def test_parse_budget(text: str, minutes: int) -> None:
+    """Budgets accept days, hours and minutes.
+
+    Args:
+        text: Budget as typed.
+        minutes: Expected minutes.
+    """
+    assert_that(parse_budget(text)).is_equal_to(minutes)
+
+
+@pytest.mark.parametrize("text", ["", "soon", "4x", "0h", "4h later"])
+def test_parse_budget_rejects_invalid_values(text: str) -> None:
+    """Unparseable and empty budgets are rejected.
+
+    Args:
+        text: Invalid budget.
+    """
+    with pytest.raises(ValueError):
+        parse_budget(text)
+
+
+def test_codeowners_last_matching_rule_wins(tmp_path: Path) -> None:
+    """CODEOWNERS rules are read in order and later rules take precedence.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".github").mkdir()
+    (tmp_path / ".github" / "CODEOWNERS").write_text(
+        "# owners\n* @org/core\n*.md @org/docs\n/src/api/ @org/api # api\n",
+    )
+
+    rules = load_codeowners(tmp_path)
+
+    assert_that(owners_for("src/app.py", rules)).is_equal_to(["@org/core"])
+    assert_that(owners_for("docs/guide.md", rules)).is_equal_to(["@org/docs"])
+    assert_that(owners_for("src/api/views.py", rules)).is_equal_to(["@org/api"])
+    assert_that(owners_for("lib/api/views.py", [("/src/api/", ["x"])])).is_empty()
+    assert_that(load_codeowners(tmp_path / "missing")).is_empty()
+
+
+def test_plan_orders_fixable_and_severe_work_first() -> None:
+    """Auto-fixable tasks come first, then errors before warnings."""
+    results = [
+        _Result(
+            "ruff",
+            [
+                RuffIssue(file="a.py", code="E501"),
+                RuffIssue(file="a.py", code="E501"),
+                RuffIssue(file="b.py", code="F401", fixable=True),
+            ],
+        ),
+        _Result(
+            "frontmatter",
+            [FrontmatterIssue(file="c.md", code="FM001", level="error")],
+        ),
+    ]
+
+    plan = build_cleanup_plan(results)
+
+    assert_that([(i.file, i.code) for i in plan.items]).is_equal_to(
+        [("b.py", "F401"), ("c.md", "FM001"), ("a.py", "E501")],
+    )
+    assert_that(plan.items[2].issues).is_equal_to(2)
+    assert_that(plan.items[2].minutes).is_equal_to(10)
+    assert_that(plan.total_minutes).is_equal_to(26)
+
+
+def test_plan_defers_tasks_over_budget() -> None:
+    """Tasks that do not fit are deferred while smaller ones still fit."""
+    results = [
+        _Result(
+            "frontmatter",
+            [FrontmatterIssue(file="a.md", code="FM001", level="error")] * 2,
+        ),
+        _Result("ruff", [RuffIssue(file="b.py", code="E501")]),
+    ]
+
+    plan = build_cleanup_plan(results, budget_minutes=20)
+
+    assert_that([i.file for i in plan.items]).is_equal_to(["b.py"])
+    assert_that([i.file for i in plan.deferred]).is_equal_to(["a.md"])
+    assert_that(plan.planned_minutes).is_equal_to(5)
+    assert_that(plan.total_minutes).is_equal_to(35)
+
+
+def test_owner_task_lists_are_written(tmp_path: Path) -> None:
+    """Each owner gets a Markdown checklist of their planned tasks.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    results = [
+        _Result(
+            "ruff",
+            [
+                RuffIssue(file="src/a.py", code="F401", fixable=True),
+                RuffIssue(file="scripts/b.py", code="E501"),
+            ],
+        ),
+    ]
+    plan = build_cleanup_plan(results, codeowners=[("src/", ["@org/core"])])
+
+    written = write_owner_task_lists(plan, tmp_path / "plans")
+
+    assert_that([p.name for p in written]).is_equal_to(["org-core.md", "unowned.md"])
+    core = written[0].read_text()
+    assert_that(core).starts_with("# Cleanup plan: @org/core")
+    assert_that(core).contains(
+        "- [ ] `src/a.py`: ruff F401 (1 issue, ~1 minute, "
+        "auto-fixable with `lintro format`)",
+    )
+    assert_that(written[1].read_text()).contains("`scripts/b.py`: ruff E501")
+
+
+def test_resolve_repo_paths_uses_checked_roots(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Paths relative to a tool's working directory are mapped back.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "docs").mkdir()
+    (tmp_path / "docs" / "guide.md").write_text("# Guide\n")
+    (tmp_path / "a").mkdir()
+    (tmp_path / "b").mkdir()
+    (tmp_path / "a" / "x.py").write_text("")
+    (tmp_path / "b" / "x.py").write_text("")
+    monkeypatch.chdir(tmp_path)
+
+    paths = resolve_repo_paths(
+        ["guide.md", "x.py", str(tmp_path / "a" / "x.py")],
+        ["."],
+    )
+
+    assert_that(paths["guide.md"]).is_equal_to("docs/guide.md")
+    assert_that(paths["x.py"]).is_equal_to("x.py")
+    assert_that(paths[str(tmp_path / "a" / "x.py")]).is_equal_to("a/x.py")
@@ -0,0 +1,150 @@
+"""Tests for CODEOWNERS parsing and owner grouping and filtering."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.formatters.formatter import format_issues
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.codeowners import (
+    UNOWNED,
+    find_codeowners,
+    load_codeowners,
+    owner_label,
+    parse_codeowners,
+)
+from lintro.utils.issue_query import compile_query, restrict_to_owners
+
+CODEOWNERS = """\
+# Default owners
+*       @org/core
+*.py    @org/python
+/docs/  @org/docs @alice
+apps/   @org/apps
+config/*  @org/config
+vendor/  # no owners: leaves vendored code unowned
+
+[Frontend]
+web/**/*.ts  @org/frontend
+"""
+
+
+@pytest.fixture
+def repo(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Provide a working directory with a .github/CODEOWNERS file.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The repository root, also the working directory.
+    """
+    (tmp_path / ".github").mkdir()
+    (tmp_path / ".github" / "CODEOWNERS").write_text(CODEOWNERS)
+    monkeypatch.chdir(tmp_path)
+    load_codeowners.cache_clear()
+    return tmp_path
+
+
+@pytest.mark.parametrize(
+    ("path", "owners"),
+    [
+        ("README.md", ("@org/core",)),
+        ("src/app.py", ("@org/python",)),
+        ("docs/guide/intro.md", ("@org/docs", "@alice")),
+        ("src/docs/notes.md", ("@org/core",)),
+        ("apps/api/main.go", ("@org/apps",)),
+        ("lib/apps/x.go", ("@org/apps",)),
+        ("config/app.toml", ("@org/config",)),
+        ("config/deep/app.toml", ("@org/core",)),
+        ("vendor/lib.py", ()),
+        ("web/src/view.ts", ("@org/frontend",)),
+        ("web/view.ts", ("@org/frontend",)),
+    ],
+)
+def test_owners_follow_github_rules(path: str, owners: tuple[str, ...]) -> None:
+    """The last matching line wins; anchoring follows slashes in the pattern.
+
+    Args:
+        path: Root-relative file path.
+        owners: Expected owners.
+    """
+    codeowners = parse_codeowners(CODEOWNERS, "/repo")
+
+    assert_that(codeowners.owners_of(f"/repo/{path}")).is_equal_to(owners)
+
+
+def test_files_outside_root_are_unowned() -> None:
+    """Patterns do not apply above the directory holding the file."""
+    codeowners = parse_codeowners("* @org/core\n", "/repo")
+
+    assert_that(codeowners.owners_of("/elsewhere/a.py")).is_empty()
+
+
+def test_find_codeowners_walks_up(repo: Path) -> None:
+    """The file is found from a subdirectory of the repository.
+
+    Args:
+        repo: Repository root.
+    """
+    nested = repo / "src" / "pkg"
+    nested.mkdir(parents=True)
+
+    assert_that(find_codeowners(nested)).is_equal_to(
+        (repo / ".github" / "CODEOWNERS").resolve(),
+    )
+    assert_that(owner_label("vendor/lib.py")).is_equal_to(UNOWNED)
+
+
+def test_owner_query_field(repo: Path) -> None:
+    """owner= matches any owner of the file, ignoring case.
+
+    Args:
+        repo: Repository root.
+    """
+    record = {"path": "docs/index.md"}
+
+    assert_that(compile_query("owner=@ALICE").matches(record)).is_true()
+    assert_that(compile_query("owner~@org/*").matches(record)).is_true()
+    assert_that(compile_query("owner!=@org/docs").matches(record)).is_false()
+    assert_that(compile_query("owner=@org/core").matches(record)).is_false()
+
+
+def test_restrict_to_owners_combines_with_filter(repo: Path) -> None:
+    """Owners are or'ed together and and'ed with an existing query.
+
+    Args:
+        repo: Repository root.
+    """
+    query = restrict_to_owners(
+        compile_query("tool=ruff"),
+        ["@org/python", "@org/apps"],
+    )
+
+    assert query is not None
+    assert_that(query.matches({"tool": "ruff", "path": "apps/x.go"})).is_true()
+    assert_that(query.matches({"tool": "ruff", "path": "README.md"})).is_false()
+    assert_that(query.matches({"tool": "mypy", "path": "a.py"})).is_false()
+    assert_that(restrict_to_owners(None, [])).is_none()
+
+
+def test_format_issues_group_by_owner(repo: Path) -> None:
+    """Owner groups subtotal issues per owning team.
+
+    Args:
+        repo: Repository root.
+    """
+    issues = [
+        RuffIssue(file="src/a.py", line=1, code="F401", message="Unused import"),
+        RuffIssue(file="src/b.py", line=2, code="F401", message="Unused import"),
+        RuffIssue(file="vendor/c.py", line=3, code="E501", message="Too long"),
+    ]
+
+    result = format_issues(issues, output_format="plain", group_by="owner")
+
+    assert_that(result).contains("Subtotal @org/python: 2 issues")
+    assert_that(result).contains(f"Subtotal {UNOWNED}: 1 issue")
@@ -0,0 +1,124 @@
+"""Tests for remediation-effort estimation."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import _parse_output_config
+from lintro.parsers.base_issue import BaseIssue
+from lintro.parsers.frontmatter.frontmatter_issue import FrontmatterIssue
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.effort import (
+    estimate_effort,
+    format_effort,
+    issue_effort_minutes,
+)
+
+
+@dataclass
+class _Result:
+    """Minimal tool result carrying issues.
+
+    Attributes:
+        name: Tool name.
+        issues: Issues reported by the tool.
+    """
+
+    name: str
+    issues: list[BaseIssue] = field(default_factory=list)
+
+
+@pytest.mark.parametrize(
+    "overrides,minutes",
+    [
+        ({"ruff:E501": 3, "E501": 7, "ruff": 9}, 3),
+        ({"E501": 7, "ruff": 9}, 7),
+        ({"ruff": 9, "warning": 4}, 9),
+        ({"warning": 4}, 4),
+        ({}, 5),
+    ],
+)
+def test_rule_overrides_win_in_order(
+    overrides: dict[str, int],
+    minutes: int,
+) -> None:
+    """Tool-qualified rules beat bare rules, tools and severities.
+
+    Args:
+        overrides: Configured effort minutes.
+        minutes: Expected estimate for a ruff E501 warning.
+    """
+    issue = RuffIssue(file="a.py", line=1, code="E501")
+
+    assert_that(issue_effort_minutes("ruff", issue, overrides)).is_equal_to(minutes)
+
+
+def test_builtin_defaults() -> None:
+    """Fixable issues, formatters and security findings have built-in costs."""
+    fixable = RuffIssue(file="a.py", code="F401", fixable=True)
+    error = FrontmatterIssue(file="a.md", code="FM001", level="error")
+
+    assert_that(issue_effort_minutes("ruff", fixable)).is_equal_to(1)
+    assert_that(issue_effort_minutes("ruff", fixable, {"F401": 10})).is_equal_to(10)
+    assert_that(issue_effort_minutes("prettier", BaseIssue())).is_equal_to(1)
+    assert_that(issue_effort_minutes("bandit", BaseIssue())).is_equal_to(30)
+    assert_that(issue_effort_minutes("frontmatter", error)).is_equal_to(15)
+
+
+def test_estimate_effort_totals_by_file_and_tool() -> None:
+    """Estimates are summed per file and tool and ranked by effort."""
+    results = [
+        _Result(
+            "ruff",
+            [
+                RuffIssue(file="a.py", code="E501"),
+                RuffIssue(file="b.py", code="F841"),
+                RuffIssue(file="b.py", code="F841"),
+            ],
+        ),
+        _Result("mypy", [BaseIssue(file="a.py")]),
+        _Result("black", []),
+    ]
+
+    estimate = estimate_effort(results, {"F841": 10, "mypy": 20})
+
+    assert_that(estimate.total_minutes).is_equal_to(45)
+    assert_that(estimate.by_tool).is_equal_to({"ruff": 25, "mypy": 20})
+    assert_that(estimate.files_by_effort()).is_equal_to([("a.py", 25), ("b.py", 20)])
+    assert_that(estimate.issues_by_file).is_equal_to({"a.py": 2, "b.py": 2})
+
+
+@pytest.mark.parametrize(
+    "minutes,text",
+    [
+        (1, "~1 minute"),
+        (45, "~45 minutes"),
+        (60, "~1 hour"),
+        (95, "~1.5 hours"),
+        (840, "~14 hours"),
+    ],
+)
+def test_format_effort(minutes: int, text: str) -> None:
+    """Durations are rounded for reading, not accounting.
+
+    Args:
+        minutes: Estimated minutes.
+        text: Expected display text.
+    """
+    assert_that(format_effort(minutes)).is_equal_to(text)
+
+
+def test_effort_settings_are_parsed() -> None:
+    """Effort minutes and report ordering come from the output section."""
+    config = _parse_output_config(
+        {"effort": {"ruff:E501": 2, "mypy": 20}, "sort_by": "effort"},
+    )
+
+    assert_that(config.effort).is_equal_to({"ruff:E501": 2, "mypy": 20})
+    assert_that(config.sort_by).is_equal_to("effort")
+
+    with pytest.raises(ValueError):
+        _parse_output_config({"effort": {"E501": -1}})
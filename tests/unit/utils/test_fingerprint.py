@@ -0,0 +1,134 @@
+"""Tests for lintro.utils.fingerprint."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.fingerprint import fingerprint_issues, normalize_issue_path
+
+SOURCE = "import os\n\n\ndef main():\n    return os.sep\n"
+
+
+@pytest.fixture
+def project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Provide a project directory with one Python file as the cwd.
+
+    Args:
+        tmp_path: Temporary directory for the project.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The Python file.
+    """
+    monkeypatch.chdir(tmp_path)
+    source = tmp_path / "app.py"
+    source.write_text(SOURCE)
+    return source
+
+
+def _issue(line: int, column: int = 1, code: str = "F401") -> RuffIssue:
+    """Build a ruff issue in app.py.
+
+    Args:
+        line: Line of the issue.
+        column: Column of the issue.
+        code: Rule code.
+
+    Returns:
+        RuffIssue: The issue.
+    """
+    return RuffIssue(
+        file="app.py",
+        line=line,
+        column=column,
+        code=code,
+        message=f"issue at line {line}",
+    )
+
+
+def test_fingerprint_survives_line_shifts(project: Path) -> None:
+    """Lines added above an issue or re-indenting it keep its fingerprint.
+
+    Args:
+        project: The Python file.
+    """
+    before = _issue(line=1).fingerprint("ruff")
+
+    project.write_text("# header\n\n  import os\n" + SOURCE.split("\n", 1)[1])
+
+    assert_that(_issue(line=3).fingerprint("ruff")).is_equal_to(before)
+
+
+@pytest.mark.parametrize(
+    ("tool", "code", "new_source"),
+    [
+        ("ruff", "F401", "import sys\n"),
+        ("ruff", "E501", SOURCE),
+        ("pylint", "F401", SOURCE),
+    ],
+)
+def test_fingerprint_changes_with_code_rule_or_tool(
+    project: Path,
+    tool: str,
+    code: str,
+    new_source: str,
+) -> None:
+    """Editing the flagged line or reporting another rule is a new issue.
+
+    Args:
+        project: The Python file.
+        tool: Tool reporting the second issue.
+        code: Rule of the second issue.
+        new_source: Contents of the file for the second issue.
+    """
+    before = _issue(line=1).fingerprint("ruff")
+
+    project.write_text(new_source)
+
+    assert_that(_issue(line=1, code=code).fingerprint(tool)).is_not_equal_to(
+        before,
+    )
+
+
+def test_fingerprint_issues_distinguishes_repeats(project: Path) -> None:
+    """Repeats on one line get distinct fingerprints, whatever their order.
+
+    Args:
+        project: The Python file.
+    """
+    first, second = _issue(line=5, column=5), _issue(line=5, column=12)
+
+    in_order = fingerprint_issues("ruff", [first, second])
+    reversed_order = fingerprint_issues("ruff", [second, first])
+
+    assert_that(in_order[0]).is_equal_to(first.fingerprint("ruff"))
+    assert_that(in_order[0]).is_not_equal_to(in_order[1])
+    assert_that(reversed_order).is_equal_to(in_order[::-1])
+
+
+def test_fingerprint_without_source_uses_message(project: Path) -> None:
+    """Issues whose line cannot be read hash the message, digits masked.
+
+    Args:
+        project: The Python file.
+    """
+    project.unlink()
+
+    assert_that(_issue(line=3).fingerprint("ruff")).is_equal_to(
+        _issue(line=7).fingerprint("ruff"),
+    )
+
+
+def test_normalize_issue_path_relativizes_paths(project: Path) -> None:
+    """Absolute and relative paths of one file normalize alike.
+
+    Args:
+        project: The Python file.
+    """
+    assert_that(normalize_issue_path(str(project))).is_equal_to("app.py")
+    assert_that(normalize_issue_path("./app.py")).is_equal_to("app.py")
+    assert_that(normalize_issue_path("")).is_equal_to("")
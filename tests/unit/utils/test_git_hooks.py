@@ -0,0 +1,193 @@
+"""Tests for lintro.utils.git_hooks."""
+
+from __future__ import annotations
+
+import os
+import re
+from pathlib import Path
+
+import pytest
+import yaml
+from assertpy import assert_that
+
+from lintro.config.execution_config import ExecutionConfig
+from lintro.config.lintro_config import LintroConfig
+from lintro.config.tool_config import LintroToolConfig
+from lintro.plugins.protocol import ToolDefinition
+from lintro.utils import git_hooks
+from lintro.utils.git_hooks import (
+    HOOK_MARKER,
+    HookInstallError,
+    files_regex,
+    install_hook,
+    pre_commit_hooks,
+    render_pre_commit_config,
+)
+
+
+@pytest.fixture
+def repo(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Pretend tmp_path is a git repository with the default hooks path.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: The repository root.
+    """
+    monkeypatch.setattr(git_hooks, "git_output", lambda *args, **kwargs: ".git/hooks")
+    return tmp_path
+
+
+def test_install_hook_writes_executable_script(repo: Path) -> None:
+    """The pre-commit hook runs lintro check --staged.
+
+    Args:
+        repo: Repository root.
+    """
+    path = install_hook("pre-commit", command="uv run lintro", cwd=repo)
+
+    assert_that(path).is_equal_to(repo / ".git" / "hooks" / "pre-commit")
+    assert_that(path.read_text()).contains("exec uv run lintro check --staged\n")
+    assert_that(os.access(path, os.X_OK)).is_true()
+
+
+def test_install_hook_updates_its_own_hook(repo: Path) -> None:
+    """Hooks installed by lintro are rewritten without --force.
+
+    Args:
+        repo: Repository root.
+    """
+    install_hook("pre-push", cwd=repo)
+
+    path = install_hook("pre-push", command="pdm run lintro", cwd=repo)
+
+    assert_that(path.read_text()).contains("exec pdm run lintro check\n")
+
+
+def test_install_hook_keeps_foreign_hook(repo: Path) -> None:
+    """Hooks written by other tools are only replaced with force.
+
+    Args:
+        repo: Repository root.
+    """
+    hook = repo / ".git" / "hooks" / "pre-commit"
+    hook.parent.mkdir(parents=True)
+    hook.write_text("#!/bin/sh\nmake lint\n")
+
+    with pytest.raises(HookInstallError, match="--force"):
+        install_hook("pre-commit", cwd=repo)
+    install_hook("pre-commit", force=True, cwd=repo)
+
+    assert_that(hook.read_text()).contains(HOOK_MARKER)
+
+
+def test_install_hook_outside_a_repository(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Installing fails when git finds no repository.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(git_hooks, "git_output", lambda *args, **kwargs: None)
+
+    with pytest.raises(HookInstallError, match="Not inside a git repository"):
+        install_hook("pre-commit", cwd=tmp_path)
+
+
+@pytest.mark.parametrize(
+    ("path", "matches"),
+    [
+        ("app.py", True),
+        ("src/pkg/types.pyi", True),
+        ("docker/Dockerfile.dev", True),
+        ("Dockerfile", True),
+        ("app.pyc", False),
+        ("notes.txt", False),
+    ],
+)
+def test_files_regex_matches_file_names(path: str, matches: bool) -> None:
+    """Tool globs are matched against the file name of a path.
+
+    Args:
+        path: Path pre-commit would pass.
+        matches: Whether the regex should match it.
+    """
+    regex = files_regex(["*.py", "*.pyi", "Dockerfile", "Dockerfile.*"])
+
+    assert_that(regex).is_not_none()
+    assert_that(bool(re.search(str(regex), path))).is_equal_to(matches)
+
+
+def test_files_regex_for_every_file() -> None:
+    """A catch-all pattern leaves pre-commit's default of every file."""
+    assert_that(files_regex(["*"])).is_none()
+
+
+def _definition(
+    name: str,
+    patterns: list[str],
+    by_default: bool = True,
+) -> ToolDefinition:
+    """Build a tool definition.
+
+    Args:
+        name: Tool name.
+        patterns: File patterns of the tool.
+        by_default: Whether the tool runs without being enabled.
+
+    Returns:
+        ToolDefinition: The definition.
+    """
+    return ToolDefinition(
+        name=name,
+        description=name,
+        file_patterns=patterns,
+        enabled_by_default=by_default,
+    )
+
+
+def test_pre_commit_hooks_mirror_enabled_tools() -> None:
+    """Only tools the lintro config enables get a hook."""
+    config = LintroConfig(tools={"black": LintroToolConfig(enabled=False)})
+    definitions = {
+        "ruff": _definition("ruff", ["*.py", "*.pyi"]),
+        "black": _definition("black", ["*.py"]),
+        "semgrep": _definition("semgrep", ["*.py"], by_default=False),
+        "conventional_commits": _definition("conventional_commits", ["*"]),
+        "gitleaks": _definition("gitleaks", ["*"]),
+    }
+
+    hooks = pre_commit_hooks(config, definitions)
+
+    ids = [h["id"] for h in hooks]
+    assert_that(ids).is_equal_to(["lintro-gitleaks", "lintro-ruff"])
+    ruff = hooks[1]
+    assert_that(ruff["entry"]).is_equal_to("lintro check --tools ruff")
+    assert_that(ruff["language"]).is_equal_to("system")
+    assert_that(ruff["files"]).is_equal_to(r"(^|/)([^/]*\.py|[^/]*\.pyi)$")
+    assert_that(hooks[0]).does_not_contain_key("files")
+
+
+def test_render_pre_commit_config_is_a_local_repo() -> None:
+    """The rendered YAML is a pre-commit config with one local repo."""
+    config = LintroConfig(execution=ExecutionConfig(enabled_tools=["ruff"]))
+    definitions = {
+        "ruff": _definition("ruff", ["*.py"]),
+        "yamllint": _definition("yamllint", ["*.yml"]),
+    }
+
+    data = yaml.safe_load(
+        render_pre_commit_config(config, definitions, command="uv run lintro"),
+    )
+
+    assert_that(data["repos"]).is_length(1)
+    assert_that(data["repos"][0]["repo"]).is_equal_to("local")
+    hooks = data["repos"][0]["hooks"]
+    assert_that([h["entry"] for h in hooks]).is_equal_to(
+        ["uv run lintro check --tools ruff"],
+    )
@@ -0,0 +1,228 @@
+"""Tests for publishing reports as GitHub check runs."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.execution.failure_policy import FailurePolicy
+from lintro.utils.github_checks import (
+    ANNOTATION_BATCH_SIZE,
+    CheckRunTarget,
+    build_annotations,
+    check_conclusion,
+    detect_check_target,
+    failed_tools,
+    publish_check_run,
+)
+from lintro.utils.github_review import GitHubReviewError
+from lintro.utils.rule_selection import parse_rule_pattern
+
+TARGET = CheckRunTarget(repo="acme/app", head_sha="abc123")
+
+
+class _FakeClient:
+    """In-memory GitHub API recording the requests it gets."""
+
+    def __init__(self) -> None:
+        """Start without requests."""
+        self.requests: list[tuple[str, str, Any]] = []
+
+    def request(
+        self,
+        method: str,
+        path: str,
+        json_body: dict[str, Any] | None = None,
+    ) -> Any:
+        """Answer one request.
+
+        Args:
+            method: HTTP method.
+            path: API path.
+            json_body: JSON request body.
+
+        Returns:
+            Any: Reply of the API.
+        """
+        self.requests.append((method, path, json_body))
+        if method == "POST":
+            return {"id": 42, "html_url": "https://github.com/acme/app/runs/42"}
+        return None
+
+    def paginate(self, path: str) -> list[Any]:
+        """Answer a list request.
+
+        Args:
+            path: API path.
+
+        Returns:
+            list[Any]: No items.
+        """
+        return []
+
+
+def _record(
+    line: int,
+    severity: SeverityLevel = SeverityLevel.ERROR,
+    path: str = "app.py",
+) -> dict[str, Any]:
+    """Build an issue record.
+
+    Args:
+        line: Line of the issue.
+        severity: Severity of the issue.
+        path: File of the issue.
+
+    Returns:
+        dict[str, Any]: The record.
+    """
+    return {
+        "tool": "ruff",
+        "path": path,
+        "line": line,
+        "code": "F401",
+        "message": "unused import",
+        "severity": severity,
+    }
+
+
+def test_build_annotations_maps_severities_and_lines() -> None:
+    """Annotations are sorted, leveled by severity and never on line 0."""
+    annotations, skipped = build_annotations(
+        [
+            _record(9, SeverityLevel.INFO),
+            _record(0, SeverityLevel.WARNING),
+            _record(3, path=""),
+        ],
+    )
+
+    placed = [
+        (a["path"], a["start_line"], a["annotation_level"]) for a in annotations
+    ]
+    assert_that(placed).is_equal_to(
+        [("app.py", 1, "warning"), ("app.py", 9, "notice")],
+    )
+    assert_that(annotations[0]["title"]).is_equal_to("ruff F401")
+    assert_that(skipped).is_equal_to(1)
+
+
+def test_check_conclusion_follows_failure_policy() -> None:
+    """Tolerated issues are neutral, failing ones failure, none success."""
+    warning = [_record(1, SeverityLevel.WARNING)]
+
+    assert_that(check_conclusion([], FailurePolicy())).is_equal_to("success")
+    assert_that(check_conclusion(warning, FailurePolicy())).is_equal_to("failure")
+    lenient = FailurePolicy(fail_on=SeverityLevel.ERROR)
+    assert_that(check_conclusion(warning, lenient)).is_equal_to("neutral")
+    assert_that(
+        check_conclusion(warning * 2, FailurePolicy(max_issues=2)),
+    ).is_equal_to("neutral")
+    budgeted = FailurePolicy(budgets=((parse_rule_pattern("ruff:F401"), 1),))
+    assert_that(check_conclusion(warning, budgeted)).is_equal_to("neutral")
+    assert_that(check_conclusion(warning * 2, budgeted)).is_equal_to("failure")
+
+
+def test_failed_tools_fail_the_check_run() -> None:
+    """A tool that errored concludes failure even without issues."""
+    report = {
+        "results": [
+            {"tool": "ruff", "success": False, "issues_count": 3},
+            {"tool": "mypy", "success": False, "issues_count": 0},
+            {
+                "tool": "clippy",
+                "success": False,
+                "issues_count": 1,
+                "error_code": "LINTRO007",
+            },
+            {"tool": "bandit", "success": True, "skipped": True},
+        ],
+    }
+    client = _FakeClient()
+
+    failed = failed_tools(report)
+    result = publish_check_run([], TARGET, client, FailurePolicy(), failed=failed)
+
+    assert_that(failed).is_equal_to(["mypy", "clippy"])
+    assert_that(check_conclusion([], FailurePolicy(), failed)).is_equal_to("failure")
+    assert_that(result.conclusion).is_equal_to("failure")
+    assert_that(client.requests[0][2]["output"]["summary"]).contains(
+        "2 tool(s) failed to run: mypy, clippy.",
+    )
+
+
+def test_publish_check_run_batches_annotations() -> None:
+    """The first 50 annotations create the run; the rest update it."""
+    client = _FakeClient()
+    records = [_record(line) for line in range(1, ANNOTATION_BATCH_SIZE + 8)]
+
+    result = publish_check_run(records, TARGET, client, FailurePolicy())
+
+    (post_method, post_path, post), patch = client.requests
+    assert_that((post_method, post_path)).is_equal_to(
+        ("POST", "/repos/acme/app/check-runs"),
+    )
+    assert_that(post["head_sha"]).is_equal_to("abc123")
+    assert_that(post["conclusion"]).is_equal_to("failure")
+    assert_that(post["output"]["annotations"]).is_length(ANNOTATION_BATCH_SIZE)
+    assert_that(post["output"]["summary"]).contains("| ruff | 57 |")
+    assert_that(patch[:2]).is_equal_to(("PATCH", "/repos/acme/app/check-runs/42"))
+    assert_that(patch[2]["output"]["annotations"]).is_length(7)
+    assert_that(result.annotations).is_equal_to(57)
+    assert_that(result.url).ends_with("/runs/42")
+
+
+def test_publish_check_run_without_issues_succeeds() -> None:
+    """A clean report creates one successful check run."""
+    client = _FakeClient()
+
+    result = publish_check_run([], TARGET, client, FailurePolicy())
+
+    assert_that(client.requests).is_length(1)
+    body = client.requests[0][2]
+    assert_that(body["conclusion"]).is_equal_to("success")
+    assert_that(body["output"]["annotations"]).is_empty()
+    assert_that(result.conclusion).is_equal_to("success")
+
+
+def test_detect_check_target_prefers_pull_request_head(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Pull request runs are checked on the head commit, not the merge.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    event = tmp_path / "event.json"
+    event.write_text(json.dumps({"pull_request": {"head": {"sha": "head1"}}}))
+    monkeypatch.setenv("GITHUB_REPOSITORY", "acme/app")
+    monkeypatch.setenv("GITHUB_EVENT_PATH", str(event))
+    monkeypatch.setenv("GITHUB_SHA", "merge1")
+    monkeypatch.delenv("GITHUB_API_URL", raising=False)
+
+    target = detect_check_target()
+
+    assert_that((target.repo, target.head_sha)).is_equal_to(("acme/app", "head1"))
+    monkeypatch.delenv("GITHUB_EVENT_PATH")
+    assert_that(detect_check_target().head_sha).is_equal_to("merge1")
+
+
+def test_detect_check_target_outside_actions(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without the repository or commit, the error says what to pass.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    for name in ("GITHUB_REPOSITORY", "GITHUB_EVENT_PATH", "GITHUB_SHA"):
+        monkeypatch.delenv(name, raising=False)
+
+    with pytest.raises(GitHubReviewError, match="--sha"):
+        detect_check_target(repo="acme/app")
@@ -0,0 +1,190 @@
+"""Tests for publishing reports as GitHub pull request reviews."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.github_review import (
+    GitHubReviewError,
+    PullRequestRef,
+    detect_pull_request,
+    publish_review,
+)
+
+PATCH = "@@ -1,2 +1,3 @@\n import os\n+import sys\n print(os)\n"
+
+
+class _FakeClient:
+    """In-memory GitHub API recording the requests it gets."""
+
+    def __init__(self, comments: list[dict[str, Any]]) -> None:
+        """Start with the given review comments on the pull request.
+
+        Args:
+            comments: Review comments already posted.
+        """
+        self.comments = comments
+        self.requests: list[tuple[str, str, Any]] = []
+
+    def request(
+        self,
+        method: str,
+        path: str,
+        json_body: dict[str, Any] | None = None,
+    ) -> Any:
+        """Answer one request.
+
+        Args:
+            method: HTTP method.
+            path: API path.
+            json_body: JSON request body.
+
+        Returns:
+            Any: Reply of the API.
+        """
+        self.requests.append((method, path, json_body))
+        if method == "GET":
+            return {"head": {"sha": "abc123"}}
+        return None
+
+    def paginate(self, path: str) -> list[Any]:
+        """Answer a list request.
+
+        Args:
+            path: API path.
+
+        Returns:
+            list[Any]: Changed files or review comments.
+        """
+        if path.endswith("/files"):
+            return [
+                {"filename": "app.py", "status": "modified", "patch": PATCH},
+                {"filename": "gone.py", "status": "removed"},
+            ]
+        return self.comments
+
+
+def _record(line: int, message: str = "unused import") -> dict[str, Any]:
+    """Build an issue record for app.py.
+
+    Args:
+        line: Line of the issue.
+        message: Issue message.
+
+    Returns:
+        dict[str, Any]: The record.
+    """
+    return {
+        "tool": "ruff",
+        "path": "app.py",
+        "line": line,
+        "code": "F401",
+        "message": message,
+        "severity": SeverityLevel.WARNING,
+    }
+
+
+def test_publish_review_batches_new_comments() -> None:
+    """New comments are posted in one review on the head commit."""
+    client = _FakeClient(comments=[])
+    pull = PullRequestRef(repo="acme/app", number=7)
+
+    result = publish_review([_record(2), _record(3), _record(40)], pull, client)
+
+    posts = [r for r in client.requests if r[0] == "POST"]
+    assert_that(posts).is_length(1)
+    _, path, body = posts[0]
+    assert_that(path).is_equal_to("/repos/acme/app/pulls/7/reviews")
+    assert_that(body["commit_id"]).is_equal_to("abc123")
+    assert_that(body["event"]).is_equal_to("COMMENT")
+    placed = [(c["path"], c["line"], c["side"]) for c in body["comments"]]
+    assert_that(placed).is_equal_to([("app.py", 2, "RIGHT"), ("app.py", 3, "RIGHT")])
+    assert_that(body["body"]).contains("1 issue(s) outside the diff")
+    assert_that(result.created).is_equal_to(2)
+    assert_that(result.outside_diff).is_equal_to(1)
+
+
+def test_publish_review_updates_and_deletes_earlier_comments() -> None:
+    """Earlier lintro comments are edited or deleted, human ones kept."""
+    client = _FakeClient(
+        comments=[
+            {"id": 11, "body": "<!-- lintro-review: app.py:2 -->\n- stale text"},
+            {"id": 12, "body": "<!-- lintro-review: app.py:1 -->\n- fixed"},
+            {"id": 13, "body": "nit: rename"},
+        ],
+    )
+    pull = PullRequestRef(repo="acme/app", number=7)
+
+    result = publish_review([_record(2, "still unused")], pull, client)
+
+    changes = [(m, p) for m, p, _ in client.requests if m != "GET"]
+    assert_that(changes).is_equal_to(
+        [
+            ("PATCH", "/repos/acme/app/pulls/comments/11"),
+            ("DELETE", "/repos/acme/app/pulls/comments/12"),
+        ],
+    )
+    assert_that(result.updated).is_equal_to(1)
+    assert_that(result.deleted).is_equal_to(1)
+    assert_that(result.created).is_equal_to(0)
+
+
+def test_detect_pull_request_from_actions_event(
+    monkeypatch: pytest.MonkeyPatch,
+    tmp_path: Path,
+) -> None:
+    """The pull request is read from the GitHub Actions event payload.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        tmp_path: Temporary directory for the event payload.
+    """
+    event = tmp_path / "event.json"
+    event.write_text(json.dumps({"pull_request": {"number": 42}}))
+    monkeypatch.setenv("GITHUB_REPOSITORY", "acme/app")
+    monkeypatch.setenv("GITHUB_EVENT_PATH", str(event))
+    monkeypatch.setenv("GITHUB_API_URL", "https://ghe.example.com/api/v3/")
+
+    pull = detect_pull_request()
+
+    assert_that(pull).is_equal_to(
+        PullRequestRef(
+            repo="acme/app",
+            number=42,
+            api_url="https://ghe.example.com/api/v3",
+        ),
+    )
+
+
+def test_detect_pull_request_from_ref(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Without an event payload the number comes from GITHUB_REF.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setenv("GITHUB_REPOSITORY", "acme/app")
+    monkeypatch.delenv("GITHUB_EVENT_PATH", raising=False)
+    monkeypatch.setenv("GITHUB_REF", "refs/pull/9/merge")
+
+    assert_that(detect_pull_request().number).is_equal_to(9)
+
+
+def test_detect_pull_request_outside_a_pull_request(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Runs that are not for a pull request need --repo and --pr.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    for name in ("GITHUB_REPOSITORY", "GITHUB_EVENT_PATH", "GITHUB_REF"):
+        monkeypatch.delenv(name, raising=False)
+
+    with pytest.raises(GitHubReviewError, match="--repo and --pr"):
+        detect_pull_request()
@@ -0,0 +1,204 @@
+"""Tests for publishing reports as GitLab merge request discussions."""
+
+from __future__ import annotations
+
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.gitlab_review import (
+    GitLabReviewError,
+    MergeRequestRef,
+    detect_merge_request,
+    publish_discussions,
+)
+
+DIFF = "@@ -1,2 +1,3 @@\n import os\n+import sys\n print(os)\n"
+BASE = "/projects/group%2Fapp/merge_requests/7"
+
+
+class _FakeClient:
+    """In-memory GitLab API recording the requests it gets."""
+
+    def __init__(self, discussions: list[dict[str, Any]]) -> None:
+        """Start with the given discussions on the merge request.
+
+        Args:
+            discussions: Discussions already started.
+        """
+        self.discussions = discussions
+        self.diff_refs: dict[str, str] = {
+            "base_sha": "base1",
+            "start_sha": "start1",
+            "head_sha": "head1",
+        }
+        self.requests: list[tuple[str, str, Any]] = []
+
+    def request(
+        self,
+        method: str,
+        path: str,
+        json_body: dict[str, Any] | None = None,
+    ) -> Any:
+        """Answer one request.
+
+        Args:
+            method: HTTP method.
+            path: API path.
+            json_body: JSON request body.
+
+        Returns:
+            Any: Reply of the API.
+        """
+        self.requests.append((method, path, json_body))
+        if method == "GET":
+            return {"diff_refs": self.diff_refs}
+        return None
+
+    def paginate(self, path: str) -> list[Any]:
+        """Answer a list request.
+
+        Args:
+            path: API path.
+
+        Returns:
+            list[Any]: Diffs or discussions.
+        """
+        if path.endswith("/diffs"):
+            return [
+                {"old_path": "old.py", "new_path": "app.py", "diff": DIFF},
+                {"old_path": "gone.py", "new_path": "gone.py", "deleted_file": True},
+            ]
+        return self.discussions
+
+
+def _record(line: int, message: str = "unused import") -> dict[str, Any]:
+    """Build an issue record for app.py.
+
+    Args:
+        line: Line of the issue.
+        message: Issue message.
+
+    Returns:
+        dict[str, Any]: The record.
+    """
+    return {
+        "tool": "ruff",
+        "path": "app.py",
+        "line": line,
+        "code": "F401",
+        "message": message,
+        "severity": SeverityLevel.WARNING,
+    }
+
+
+def _discussion(discussion_id: str, note_id: int, body: str) -> dict[str, Any]:
+    """Build a discussion with one note.
+
+    Args:
+        discussion_id: ID of the discussion.
+        note_id: ID of its first note.
+        body: Body of the note.
+
+    Returns:
+        dict[str, Any]: The discussion.
+    """
+    return {"id": discussion_id, "notes": [{"id": note_id, "body": body}]}
+
+
+def test_publish_discussions_positions_new_discussions() -> None:
+    """Each flagged diff line gets a discussion placed on the head diff."""
+    client = _FakeClient(discussions=[])
+    merge_request = MergeRequestRef(project="group/app", iid=7)
+
+    result = publish_discussions(
+        [_record(2), _record(3), _record(40)],
+        merge_request,
+        client,
+    )
+
+    posts = [(p, b) for m, p, b in client.requests if m == "POST"]
+    assert_that([p for p, _ in posts]).is_equal_to([f"{BASE}/discussions"] * 2)
+    added, context = (b["position"] for _, b in posts)
+    assert_that(added).is_equal_to(
+        {
+            "position_type": "text",
+            "base_sha": "base1",
+            "start_sha": "start1",
+            "head_sha": "head1",
+            "old_path": "old.py",
+            "new_path": "app.py",
+            "new_line": 2,
+        },
+    )
+    assert_that((context["new_line"], context["old_line"])).is_equal_to((3, 2))
+    assert_that(result.created).is_equal_to(2)
+    assert_that(result.outside_diff).is_equal_to(1)
+
+
+def test_publish_discussions_is_idempotent_across_retries() -> None:
+    """A retried pipeline updates and deletes its notes instead of reposting."""
+    client = _FakeClient(
+        discussions=[
+            _discussion("d1", 11, "<!-- lintro-review: app.py:2 -->\n- stale"),
+            _discussion("d2", 12, "<!-- lintro-review: app.py:1 -->\n- fixed"),
+            _discussion("d3", 13, "nit: rename"),
+        ],
+    )
+    merge_request = MergeRequestRef(project="group/app", iid=7)
+
+    result = publish_discussions([_record(2, "still unused")], merge_request, client)
+
+    changes = [(m, p) for m, p, _ in client.requests if m != "GET"]
+    assert_that(changes).is_equal_to(
+        [
+            ("PUT", f"{BASE}/discussions/d1/notes/11"),
+            ("DELETE", f"{BASE}/discussions/d2/notes/12"),
+        ],
+    )
+    assert_that(result.created).is_equal_to(0)
+
+
+def test_publish_discussions_needs_diff_refs() -> None:
+    """Merge requests without a diff cannot be commented on."""
+    client = _FakeClient(discussions=[])
+    client.diff_refs = {}
+
+    with pytest.raises(GitLabReviewError, match="no diff"):
+        publish_discussions([], MergeRequestRef(project="1", iid=7), client)
+
+
+def test_detect_merge_request_from_ci(monkeypatch: pytest.MonkeyPatch) -> None:
+    """The merge request is read from the GitLab CI variables.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setenv("CI_PROJECT_ID", "123")
+    monkeypatch.setenv("CI_MERGE_REQUEST_IID", "42")
+    monkeypatch.setenv("CI_API_V4_URL", "https://gitlab.example.com/api/v4/")
+
+    assert_that(detect_merge_request()).is_equal_to(
+        MergeRequestRef(
+            project="123",
+            iid=42,
+            api_url="https://gitlab.example.com/api/v4",
+        ),
+    )
+
+
+def test_detect_merge_request_outside_a_merge_request(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Pipelines that are not for a merge request need --project and --mr.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setenv("CI_PROJECT_ID", "123")
+    monkeypatch.delenv("CI_MERGE_REQUEST_IID", raising=False)
+
+    with pytest.raises(GitLabReviewError, match="--project and --mr"):
+        detect_merge_request()
@@ -0,0 +1,180 @@
+"""Tests for lintro.utils.issue_query."""
+
+from __future__ import annotations
+
+import re
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.eslint.eslint_issue import EslintIssue
+from lintro.utils.issue_query import (
+    apply_issue_filter,
+    compile_query,
+    issue_record,
+    report_records,
+)
+
+
+def _record(**fields: Any) -> dict[str, Any]:
+    """Build an issue record with defaults for unspecified fields.
+
+    Args:
+        **fields: Fields overriding the defaults.
+
+    Returns:
+        dict[str, Any]: Issue record.
+    """
+    record: dict[str, Any] = {
+        "tool": "clippy",
+        "path": "src/core/engine/mod.rs",
+        "line": 12,
+        "code": "clippy::unwrap_used",
+        "message": "used `unwrap()` on a `Result` value",
+        "severity": SeverityLevel.ERROR,
+    }
+    record.update(fields)
+    return record
+
+
+@pytest.mark.parametrize(
+    ("query", "expected"),
+    [
+        ('severity>=error and tool=clippy and path~"src/core/**"', True),
+        ("severity<error", False),
+        ("severity>=warning", True),
+        ("tool=CLIPPY", True),
+        ("tool!=clippy or line>10", True),
+        ("line>=13", False),
+        ('code~"clippy::*" and not message~"*expect*"', True),
+        ("file!~'src/**' or rule=E501", False),
+        ("(tool=ruff or tool=clippy) and line=12", True),
+        ("not (tool=ruff or line<12)", True),
+    ],
+)
+def test_query_matches(query: str, expected: bool) -> None:
+    """Queries combine comparisons with and, or, not and parentheses.
+
+    Args:
+        query: Filter expression.
+        expected: Whether the sample record should match.
+    """
+    assert_that(compile_query(query).matches(_record())).is_equal_to(expected)
+
+
+def test_and_binds_tighter_than_or() -> None:
+    """``a or b and c`` reads as ``a or (b and c)``."""
+    query = compile_query("tool=clippy or tool=ruff and line=1")
+
+    assert_that(query.matches(_record(line=99))).is_true()
+
+
+@pytest.mark.parametrize(
+    ("query", "message"),
+    [
+        ("", "Empty query"),
+        ("colour=red", "Unknown field 'colour'"),
+        ("tool~", "Expected a value"),
+        ("tool clippy", "Expected an operator"),
+        ("tool>clippy", "can only be used with line and severity"),
+        ("line=abc", "line needs a number"),
+        ("severity>=fatalish", "Unknown severity level"),
+        ("(tool=ruff", "Expected ')'"),
+        ("tool=ruff)", "Unexpected ')'"),
+        ('message="unterminated', "Unexpected character"),
+    ],
+)
+def test_invalid_queries_raise(query: str, message: str) -> None:
+    """Syntax errors and unsuitable values are reported.
+
+    Args:
+        query: Invalid filter expression.
+        message: Expected part of the error message.
+    """
+    with pytest.raises(ValueError, match=re.escape(message)):
+        compile_query(query)
+
+
+def test_issue_record_uses_normalized_severity() -> None:
+    """Records of parsed issues carry the issue's severity level."""
+    issue = EslintIssue(
+        file="web/app.ts",
+        line=3,
+        code="no-console",
+        message="Unexpected console statement.",
+        severity="warning",
+    )
+
+    record = issue_record("ESLint", issue)
+
+    assert_that(record).contains_entry(
+        {"tool": "eslint"},
+        {"path": "web/app.ts"},
+        {"code": "no-console"},
+        {"severity": SeverityLevel.WARNING},
+    )
+
+
+def test_report_records_reads_json_reports() -> None:
+    """Report issues become records; missing severities count as warnings."""
+    report = {
+        "results": [
+            {
+                "tool": "ruff",
+                "issues": [
+                    {"file": "a.py", "line": 1, "code": "F401", "message": "x"},
+                    {
+                        "file": "b.py",
+                        "line": 2,
+                        "code": "E501",
+                        "message": "y",
+                        "severity": "error",
+                    },
+                ],
+            },
+            {"tool": "mypy", "issues_count": 0},
+        ],
+    }
+
+    records = report_records(report)
+
+    assert_that([r["severity"] for r in records]).is_equal_to(
+        [SeverityLevel.WARNING, SeverityLevel.ERROR],
+    )
+    assert_that(records[1]["issue"]["code"]).is_equal_to("E501")
+
+
+def test_report_records_rejects_other_json() -> None:
+    """Data without results is not a report."""
+    with pytest.raises(ValueError, match="missing 'results'"):
+        report_records({"issues": []})
+
+
+def test_apply_issue_filter_drops_unmatched_issues() -> None:
+    """Unmatched issues are neither kept nor counted."""
+    issues = [
+        EslintIssue(file="a.ts", line=1, code="no-console", severity="warning"),
+        EslintIssue(file="b.ts", line=2, code="no-undef", severity="error"),
+    ]
+    result = ToolResult(name="eslint", success=False, issues_count=2, issues=issues)
+
+    filtered = apply_issue_filter(result, compile_query("severity>=error"))
+
+    assert_that(filtered.issues).is_equal_to([issues[1]])
+    assert_that(filtered.issues_count).is_equal_to(1)
+    assert_that(filtered.success).is_false()
+
+
+def test_apply_issue_filter_without_matches_succeeds() -> None:
+    """A result whose issues are all filtered out is successful."""
+    issue = EslintIssue(file="a.ts", line=1, code="no-console", severity="warning")
+    result = ToolResult(name="eslint", success=False, issues_count=1, issues=[issue])
+
+    filtered = apply_issue_filter(result, compile_query("tool=ruff"))
+
+    assert_that(filtered.issues_count).is_equal_to(0)
+    assert_that(filtered.success).is_true()
+    assert_that(apply_issue_filter(result, None)).is_same_as(result)
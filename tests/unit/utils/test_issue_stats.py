@@ -0,0 +1,85 @@
+"""Tests for lintro.utils.issue_stats."""
+
+from __future__ import annotations
+
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.issue_stats import (
+    CountRow,
+    compute_stats,
+    result_records,
+    saved_records,
+)
+
+
+def test_compute_stats_from_results() -> None:
+    """Issues are totalled per tool, rule, file and directory."""
+    results = [
+        ToolResult(
+            name="ruff",
+            issues=[
+                RuffIssue(file="src/app/a.py", line=1, code="F401", fixable=True),
+                RuffIssue(file="src/app/a.py", line=2, code="F401", fixable=True),
+                RuffIssue(file="src/b.py", line=1, code="E501"),
+                RuffIssue(file="setup.py", line=1, code="E501"),
+            ],
+        ),
+    ]
+
+    stats = compute_stats(result_records(results), tools=["ruff", "mypy"], top=2)
+
+    assert_that(stats.issues).is_equal_to(4)
+    assert_that(stats.fixable_ratio).is_equal_to(0.5)
+    assert_that(stats.tools).is_equal_to(
+        [CountRow("ruff", 4, 2, 0), CountRow("mypy", 0, 0, 0)],
+    )
+    assert_that([r.key for r in stats.rules]).is_equal_to(["ruff:E501", "ruff:F401"])
+    assert_that(stats.files[0]).is_equal_to(CountRow("src/app/a.py", 2, 2, 0))
+    assert_that(stats.directories).is_equal_to(
+        [CountRow("src/", 3, 2, 0), CountRow("./", 1, 0, 0)],
+    )
+
+
+def test_compute_stats_directory_depth() -> None:
+    """Deeper grouping splits directories further."""
+    results = [
+        ToolResult(
+            name="ruff",
+            issues=[RuffIssue(file="src/app/a.py", line=1, code="F401")],
+        ),
+    ]
+
+    stats = compute_stats(result_records(results), depth=2)
+
+    assert_that([r.key for r in stats.directories]).is_equal_to(["src/app/"])
+
+
+def test_saved_records_read_fixable_and_severity() -> None:
+    """Saved reports give the same records, counting errors by severity."""
+    report = {
+        "results": [
+            {
+                "tool": "Clippy",
+                "issues": [
+                    {
+                        "file": "src/lib.rs",
+                        "line": 3,
+                        "code": "needless_return",
+                        "severity": "error",
+                        "fixable": True,
+                    },
+                    {"file": "src/lib.rs", "line": 9, "code": "", "message": "x"},
+                ],
+            },
+        ],
+    }
+
+    stats = compute_stats(saved_records(report))
+
+    assert_that(stats.tools).is_equal_to([CountRow("clippy", 2, 1, 1)])
+    assert_that([r.key for r in stats.rules]).is_equal_to(
+        ["clippy:-", "clippy:needless_return"],
+    )
+    assert_that(stats.to_dict()["fixable_ratio"]).is_equal_to(0.5)
@@ -2,12 +2,21 @@
 
 from __future__ import annotations
 
+import json
+from datetime import datetime, timezone
 from pathlib import Path
+from types import SimpleNamespace
 from unittest.mock import MagicMock, patch
 
 from assertpy import assert_that
 
-from lintro.utils.logger_setup import setup_cli_logging, setup_execution_logging
+from lintro.utils.logger_setup import (
+    _json_record,
+    _json_sink,
+    log_event,
+    setup_cli_logging,
+    setup_execution_logging,
+)
 
 # =============================================================================
 # setup_cli_logging tests
@@ -150,3 +159,64 @@ def test_setup_execution_logging_creates_run_dir(
     setup_execution_logging(run_dir=run_dir)
 
     assert_that(run_dir.exists()).is_true()
+
+
+# =============================================================================
+# JSON log format tests
+# =============================================================================
+
+
+@patch("lintro.utils.logger_setup.logger")
+def test_setup_execution_logging_json_uses_json_sink(
+    mock_logger: MagicMock,
+    tmp_path: Path,
+) -> None:
+    """JSON logs go through the JSON sink at INFO so events are kept.
+
+    Args:
+        mock_logger: Mock logger object.
+        tmp_path: Temporary directory fixture.
+    """
+    setup_execution_logging(run_dir=tmp_path, log_format="json")
+
+    first_call = mock_logger.add.call_args_list[0]
+    assert_that(first_call.args[0]).is_equal_to(_json_sink)
+    assert_that(first_call.kwargs["level"]).is_equal_to("INFO")
+
+
+@patch("lintro.utils.logger_setup.logger")
+def test_log_event_binds_event_and_fields(mock_logger: MagicMock) -> None:
+    """log_event binds the event name and fields to an INFO record.
+
+    Args:
+        mock_logger: Mock logger object.
+    """
+    log_event("subprocess", "ruff exited with 1", exit_code=1)
+
+    mock_logger.bind.assert_called_once_with(event="subprocess", exit_code=1)
+    mock_logger.bind.return_value.info.assert_called_once_with(
+        "ruff exited with 1",
+    )
+
+
+def test_json_record_includes_bound_fields() -> None:
+    """_json_record serializes the record and its bound fields as JSON."""
+    record = {
+        "time": datetime(2026, 1, 2, 3, 4, 5, tzinfo=timezone.utc),
+        "level": SimpleNamespace(name="INFO"),
+        "name": "lintro.plugins.subprocess_executor",
+        "message": "ruff exited with 1",
+        "extra": {"event": "subprocess", "argv": ["ruff", "check"]},
+        "exception": None,
+    }
+
+    data = json.loads(_json_record(SimpleNamespace(record=record)))
+
+    assert_that(data).contains_entry(
+        {"level": "INFO"},
+        {"message": "ruff exited with 1"},
+        {"event": "subprocess"},
+        {"argv": ["ruff", "check"]},
+    )
+    assert_that(data["time"]).starts_with("2026-01-02T03:04:05")
+    assert_that(data).does_not_contain_key("exception")
@@ -0,0 +1,225 @@
+"""Tests for posting run summaries to chat webhooks."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import httpx
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+from lintro.cli_utils.commands.report import report_command
+from lintro.utils import notifications
+from lintro.utils.notifications import (
+    NotificationError,
+    build_notification,
+    detect_format,
+    post_notification,
+    render_payload,
+)
+
+SLACK_URL = "https://hooks.slack.com/services/T0/B0/secret"
+
+
+def _report(*issues: tuple[str, str, int]) -> dict[str, Any]:
+    """Build a JSON report with ruff issues.
+
+    Args:
+        *issues: Code, file and line of each issue.
+
+    Returns:
+        dict[str, Any]: The report.
+    """
+    return {
+        "results": [
+            {
+                "tool": "ruff",
+                "success": True,
+                "issues_count": len(issues),
+                "issues": [
+                    {
+                        "file": file,
+                        "line": line,
+                        "code": code,
+                        "message": f"{code} message",
+                        "fingerprint": f"{code}-{file}",
+                    }
+                    for code, file, line in issues
+                ],
+            },
+        ],
+    }
+
+
+class _Response:
+    """Minimal HTTP response."""
+
+    def __init__(self, status_code: int, text: str = "ok") -> None:
+        """Build the response.
+
+        Args:
+            status_code: HTTP status.
+            text: Response body.
+        """
+        self.status_code = status_code
+        self.text = text
+
+
+@pytest.mark.parametrize(
+    ("url", "expected"),
+    [
+        (SLACK_URL, "slack"),
+        ("https://contoso.webhook.office.com/webhookb2/x", "teams"),
+        ("https://prod-1.westus.logic.azure.com/workflows/x", "teams"),
+        ("https://ci.example.com/hooks/lint", "json"),
+    ],
+    ids=["slack", "teams", "teams_workflow", "generic"],
+)
+def test_detect_format_by_host(url: str, expected: str) -> None:
+    """Slack and Teams webhooks are recognised by their host.
+
+    Args:
+        url: Webhook URL.
+        expected: Expected payload format.
+    """
+    assert_that(detect_format(url)).is_equal_to(expected)
+
+
+def test_notification_lists_new_issues_against_baseline() -> None:
+    """Issues missing from the baseline are new; gone ones are fixed."""
+    baseline = _report(("F401", "a.py", 1), ("E501", "b.py", 3))
+    report = _report(("F401", "a.py", 2), ("B006", "c.py", 7))
+
+    notification = build_notification(
+        report,
+        baseline=baseline,
+        report_url="https://ci.example.com/runs/1",
+    )
+    data = render_payload(notification, "json")
+
+    assert_that(data).contains_entry(
+        {"issues": 2},
+        {"new": 1},
+        {"fixed": 1},
+        {"status": "issues"},
+        {"report_url": "https://ci.example.com/runs/1"},
+    )
+    assert_that(data["new_issues"][0]).contains_entry({"code": "B006"})
+    assert_that(data["tools"]["ruff"]["issues"]).is_equal_to(2)
+
+
+def test_slack_payload_caps_listed_issues() -> None:
+    """Slack messages list a few new issues and link the report."""
+    report = _report(*[(f"E{n}", "a.py", n) for n in range(1, 5)])
+    notification = build_notification(
+        report,
+        baseline=_report(),
+        report_url="https://ci.example.com/runs/1",
+    )
+
+    payload = render_payload(notification, "slack", max_issues=2)
+
+    assert_that(payload["text"]).is_equal_to(
+        "lintro: issues - 4 issue(s), 0 error(s), 4 new, 0 fixed",
+    )
+    blocks = json.dumps(payload["attachments"][0]["blocks"])
+    assert_that(blocks).contains("E1 a.py:1", "E2 a.py:2", "... and 2 more")
+    assert_that(blocks).does_not_contain("E3 a.py:3")
+    assert_that(blocks).contains("<https://ci.example.com/runs/1|View report>")
+
+
+def test_teams_payload_is_message_card() -> None:
+    """Teams messages are cards with one fact per tool."""
+    notification = build_notification(_report(), title="nightly")
+
+    card = render_payload(notification, "teams")
+
+    assert_that(card["@type"]).is_equal_to("MessageCard")
+    assert_that(card["title"]).is_equal_to(
+        "nightly: passed - 0 issue(s), 0 error(s)",
+    )
+    assert_that(card["sections"][0]["facts"]).is_equal_to(
+        [{"name": "ruff", "value": "0 issue(s)"}],
+    )
+    assert_that(card).does_not_contain_key("potentialAction")
+
+
+def test_post_notification_retries_then_hides_url(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Transient failures are retried; errors do not reveal the URL.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    replies = [_Response(503), _Response(200)]
+    posted: list[dict[str, Any]] = []
+
+    def fake_post(url: str, **kwargs: Any) -> _Response:
+        """Reply with the next queued response.
+
+        Args:
+            url: Webhook URL.
+            **kwargs: JSON body, headers and timeout.
+
+        Returns:
+            _Response: The next reply.
+        """
+        posted.append(kwargs["json"])
+        return replies.pop(0)
+
+    monkeypatch.setattr(httpx, "post", fake_post)
+    monkeypatch.setattr(notifications, "sleep", lambda seconds: None)
+
+    post_notification(SLACK_URL, {"text": "hi"})
+    assert_that(posted).is_length(2)
+
+    replies.append(_Response(403, "invalid_token"))
+    with pytest.raises(NotificationError, match="hooks.slack.com") as excinfo:
+        post_notification(SLACK_URL, {"text": "hi"})
+    assert_that(str(excinfo.value)).does_not_contain("secret")
+
+
+def test_notify_command_dry_run(tmp_path: Path) -> None:
+    """--dry-run prints the payload without a webhook.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    report = tmp_path / "lint.json"
+    report.write_text(json.dumps(_report(("F401", "a.py", 1))))
+
+    result = CliRunner().invoke(
+        report_command,
+        ["notify", str(report), "--format", "json", "--dry-run"],
+        env={"LINTRO_WEBHOOK_URL": "", "GITHUB_RUN_ID": "", "CI_JOB_URL": ""},
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(json.loads(result.output)).contains_entry(
+        {"issues": 1},
+        {"new": None},
+        {"report_url": None},
+    )
+
+
+def test_notify_command_needs_webhook(tmp_path: Path) -> None:
+    """Posting without a webhook is a usage error.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    report = tmp_path / "lint.json"
+    report.write_text(json.dumps(_report()))
+
+    result = CliRunner().invoke(
+        report_command,
+        ["notify", str(report)],
+        env={"LINTRO_WEBHOOK_URL": ""},
+    )
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("LINTRO_WEBHOOK_URL")
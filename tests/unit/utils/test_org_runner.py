@@ -0,0 +1,277 @@
+"""Tests for running a gate across many repositories."""
+
+from __future__ import annotations
+
+import json
+import shutil
+import subprocess
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.gate_config import GateConfig
+from lintro.config.org_config import OrgConfig, OrgRepo
+from lintro.utils import org_runner
+from lintro.utils.org_runner import (
+    gate_check_args,
+    load_org_config,
+    merge_reports,
+    run_org,
+    sync_repo,
+)
+
+GATES = {
+    "security": GateConfig(tools=["bandit", "gitleaks"], fail_on="error"),
+    "style": GateConfig(tools=["ruff"], paths=["src"], max_issues=0),
+}
+
+
+def _git(*args: str, cwd: Path) -> str:
+    """Run git in a directory.
+
+    Args:
+        *args: Git arguments.
+        cwd: Working directory.
+
+    Returns:
+        str: Stripped output.
+    """
+    return subprocess.run(
+        ["git", *args],
+        cwd=cwd,
+        check=True,
+        capture_output=True,
+        text=True,
+    ).stdout.strip()
+
+
+def test_load_org_config(tmp_path: Path) -> None:
+    """Plain URLs are shorthand, and names default to the URL's last part.
+
+    Args:
+        tmp_path: Temporary directory for the list.
+    """
+    path = tmp_path / "repos.yaml"
+    path.write_text(
+        "gate: security\n"
+        "repos:\n"
+        "  - https://github.com/acme/api.git\n"
+        "  - url: git@github.com:acme/web.git\n"
+        "    ref: release\n"
+        "    gate: style\n",
+    )
+
+    config = load_org_config(path)
+
+    assert_that([repo.name for repo in config.repos]).is_equal_to(["api", "web"])
+    assert_that(config.repos[1].ref).is_equal_to("release")
+    assert_that(config.workdir).is_equal_to(".lintro/org")
+
+
+@pytest.mark.parametrize(
+    ("content", "message"),
+    [
+        ("repos:\n  - https://a/x.git\n  - https://b/x.git\n", "used twice"),
+        ("repos: []\n", "repos"),
+        ("repos:\n  - url: https://a/x.git\n    name: ../x\n", "may only contain"),
+        ("repos: [", "Cannot read"),
+    ],
+)
+def test_load_org_config_rejects_bad_lists(
+    tmp_path: Path,
+    content: str,
+    message: str,
+) -> None:
+    """Invalid lists are reported with the file name.
+
+    Args:
+        tmp_path: Temporary directory for the list.
+        content: List contents.
+        message: Expected part of the error.
+    """
+    path = tmp_path / "repos.yaml"
+    path.write_text(content)
+
+    with pytest.raises(ValueError, match=message):
+        load_org_config(path)
+
+
+def test_gate_check_args(tmp_path: Path) -> None:
+    """The check runs the gate's tools and thresholds with a JSON report.
+
+    Args:
+        tmp_path: Temporary directory for the report.
+    """
+    report = tmp_path / "api.json"
+
+    assert_that(gate_check_args(GATES["style"], report)).is_equal_to(
+        [
+            "check",
+            "src",
+            "--tools",
+            "ruff",
+            "--max-issues",
+            "0",
+            "--output",
+            str(report),
+            "--output-format",
+            "json",
+            "--yes",
+        ],
+    )
+
+
+@pytest.mark.skipif(shutil.which("git") is None, reason="git is not installed")
+def test_sync_repo_clones_and_updates(tmp_path: Path) -> None:
+    """A shallow clone is made once and moved to the latest commit later.
+
+    Args:
+        tmp_path: Temporary directory for the repositories.
+    """
+    origin = tmp_path / "origin"
+    origin.mkdir()
+    _git("init", "-q", cwd=origin)
+    _git("config", "user.email", "ci@example.com", cwd=origin)
+    _git("config", "user.name", "CI", cwd=origin)
+    (origin / "a.py").write_text("x = 1\n")
+    _git("add", ".", cwd=origin)
+    _git("commit", "-qm", "first", cwd=origin)
+    repo = OrgRepo(url=origin.as_uri())
+    checkout = tmp_path / "org" / "origin"
+
+    first = sync_repo(repo, checkout)
+    (origin / "a.py").write_text("x = 2\n")
+    _git("commit", "-qam", "second", cwd=origin)
+    second = sync_repo(repo, checkout)
+
+    assert_that(first).is_equal_to(_git("rev-parse", "HEAD~1", cwd=origin))
+    assert_that(second).is_equal_to(_git("rev-parse", "HEAD", cwd=origin))
+    assert_that((checkout / "a.py").read_text()).is_equal_to("x = 2\n")
+
+
+@pytest.fixture
+def fake_repos(monkeypatch: pytest.MonkeyPatch) -> list[list[str]]:
+    """Replace checkouts and lintro runs with canned results.
+
+    "api" passes with one issue, "web" fails and "broken" cannot be cloned.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        list[list[str]]: Arguments of each lintro run.
+    """
+    runs: list[list[str]] = []
+
+    def sync(repo: OrgRepo, directory: Path, timeout: int = 0) -> str:
+        """Pretend to check out a repository.
+
+        Args:
+            repo: Repository to check out.
+            directory: Checkout directory.
+            timeout: Unused.
+
+        Returns:
+            str: Fake commit.
+
+        Raises:
+            OrgRunError: For the "broken" repository.
+        """
+        if repo.name == "broken":
+            raise org_runner.OrgRunError("git clone -- failed: not found")
+        return f"{repo.name}-0123456789"
+
+    def run_lintro(args: list[str], cwd: Path, timeout: int) -> int:
+        """Write a canned report for the repository.
+
+        Args:
+            args: Lintro arguments.
+            cwd: Checkout directory.
+            timeout: Unused.
+
+        Returns:
+            int: Exit code of the pretend check.
+        """
+        runs.append(args)
+        report = Path(args[args.index("--output") + 1])
+        issues = [{"file": "src/app.py", "line": 3, "code": "B101", "message": "m"}]
+        report.write_text(
+            json.dumps(
+                {
+                    "summary": {"total_issues": 1},
+                    "results": [{"tool": "bandit", "issues": issues}],
+                },
+            ),
+        )
+        return 1 if cwd.name == "web" else 0
+
+    monkeypatch.setattr(org_runner, "sync_repo", sync)
+    monkeypatch.setattr(org_runner, "_run_lintro", run_lintro)
+    return runs
+
+
+def test_run_org_merges_namespaced_results(
+    fake_repos: list[list[str]],
+    tmp_path: Path,
+) -> None:
+    """Every repository is reported under its name, failures included.
+
+    Args:
+        fake_repos: Arguments of each lintro run.
+        tmp_path: Temporary work directory.
+    """
+    config = OrgConfig(
+        gate="security",
+        workdir=str(tmp_path),
+        repos=[
+            OrgRepo(url="https://x/api.git"),
+            OrgRepo(url="https://x/web.git", gate="style"),
+            OrgRepo(url="https://x/broken.git"),
+        ],
+    )
+    finished: list[str] = []
+
+    outcomes = run_org(config, GATES, jobs=2, on_done=lambda o: finished.append(o.name))
+    report = merge_reports(outcomes)
+
+    assert_that(sorted(finished)).is_equal_to(["api", "broken", "web"])
+    assert_that([o.status for o in outcomes]).is_equal_to(["passed", "failed", "error"])
+    assert_that(report["summary"]).contains_entry(
+        {"repos": 3},
+        {"passed": 1},
+        {"failed": 1},
+        {"error": 1},
+        {"total_issues": 2},
+    )
+    api = report["repos"][0]
+    assert_that(api["commit"]).is_equal_to("api-0123456789")
+    assert_that(api["results"][0]["issues"][0]["file"]).is_equal_to("api/src/app.py")
+    assert_that(report["repos"][2]["error"]).contains("not found")
+    assert_that([run[run.index("--tools") + 1] for run in fake_repos]).contains(
+        "bandit,gitleaks",
+        "ruff",
+    )
+
+
+@pytest.mark.parametrize(
+    ("gate", "message"),
+    [(None, "No gate for repository 'api'"), ("nightly", "Unknown gate 'nightly'")],
+)
+def test_run_org_requires_known_gate(
+    fake_repos: list[list[str]],
+    gate: str | None,
+    message: str,
+) -> None:
+    """Gates are checked before any repository is touched.
+
+    Args:
+        fake_repos: Arguments of each lintro run.
+        gate: Gate requested on the command line.
+        message: Expected part of the error.
+    """
+    config = OrgConfig(repos=[OrgRepo(url="https://x/api.git")])
+
+    with pytest.raises(ValueError, match=message):
+        run_org(config, GATES, gate=gate)
+    assert_that(fake_repos).is_empty()
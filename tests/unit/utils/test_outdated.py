@@ -0,0 +1,182 @@
+"""Unit tests for the dependency update advisor."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+import lintro.utils.outdated as outdated_mod
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.outdated import (
+    OutdatedPackage,
+    classify_update,
+    collect_outdated,
+    exceeds_threshold,
+    parse_cargo_outdated,
+    parse_npm_outdated,
+    parse_uv_outdated,
+    report_to_dict,
+)
+
+
+@pytest.mark.parametrize(
+    ("current", "latest", "expected"),
+    [
+        ("1.2.3", "2.0.0", "major"),
+        ("1.2.3", "1.3.0", "minor"),
+        ("1.2.3", "1.2.4", "patch"),
+        ("v1.2", "1.2.9-rc.1", "patch"),
+        ("missing", "1.0.0", "unknown"),
+    ],
+)
+def test_classify_update(current: str, latest: str, expected: str) -> None:
+    """Version differences are classified by the first changed component.
+
+    Args:
+        current: Currently resolved version.
+        latest: Latest available version.
+        expected: Expected update type.
+    """
+    assert_that(classify_update(current, latest)).is_equal_to(expected)
+
+
+def test_parse_cargo_outdated_skips_removed_and_duplicates() -> None:
+    """Cargo workspace output is merged and unavailable updates dropped."""
+    member = {
+        "crate_name": "app",
+        "dependencies": [
+            {"name": "serde", "project": "1.0.100", "latest": "1.0.200"},
+            {"name": "old", "project": "0.1.0", "latest": "Removed"},
+            {"name": "tokio", "project": "1.0.0", "latest": "---"},
+        ],
+    }
+    output = f"{json.dumps(member)}\n{json.dumps(member)}\n"
+
+    packages = parse_cargo_outdated(output)
+
+    assert_that([p.name for p in packages]).is_equal_to(["serde"])
+    assert_that(packages[0].update_type).is_equal_to("patch")
+
+
+def test_parse_uv_outdated() -> None:
+    """Uv JSON output maps version fields to current and latest."""
+    output = json.dumps(
+        [{"name": "click", "version": "7.1.2", "latest_version": "8.1.7"}],
+    )
+
+    packages = parse_uv_outdated(output)
+
+    assert_that(packages).is_length(1)
+    assert_that(packages[0].ecosystem).is_equal_to("python")
+    assert_that(packages[0].severity).is_equal_to(SeverityLevel.ERROR)
+
+
+def test_parse_npm_outdated_handles_workspaces_and_missing() -> None:
+    """Npm list entries are flattened and uninstalled packages kept."""
+    output = json.dumps(
+        {
+            "react": [
+                {"current": "18.2.0", "latest": "18.3.1"},
+                {"current": "17.0.2", "latest": "18.3.1"},
+            ],
+            "zod": {"wanted": "3.23.0", "latest": "3.23.8"},
+        },
+    )
+
+    packages = parse_npm_outdated(output)
+
+    assert_that([p.update_type for p in packages]).is_equal_to(
+        ["minor", "major", "unknown"],
+    )
+    assert_that(packages[2].current).is_equal_to("missing")
+
+
+def test_exceeds_threshold() -> None:
+    """Only updates at or above the threshold trigger failure."""
+    packages = [OutdatedPackage("npm", "zod", "3.0.0", "3.1.0", "minor")]
+
+    assert_that(exceeds_threshold(packages, "none")).is_false()
+    assert_that(exceeds_threshold(packages, "major")).is_false()
+    assert_that(exceeds_threshold(packages, "minor")).is_true()
+    assert_that(exceeds_threshold(packages, "patch")).is_true()
+
+
+def test_collect_skips_ecosystems_without_manifest(tmp_path: Path) -> None:
+    """Ecosystems are skipped when no manifest is present.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    reports = collect_outdated(tmp_path)
+
+    assert_that([r.skipped for r in reports]).is_equal_to(
+        ["no manifest found"] * 3,
+    )
+
+
+def test_collect_runs_package_manager(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Npm's exit code 1 is accepted and its output parsed.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "package.json").write_text("{}")
+    calls: list[list[str]] = []
+
+    def fake_run(command: list[str], **kwargs: Any) -> Any:
+        calls.append(command)
+        return subprocess.CompletedProcess(
+            command,
+            1,
+            stdout=json.dumps({"vite": {"current": "4.5.0", "latest": "5.2.0"}}),
+            stderr="",
+        )
+
+    monkeypatch.setattr(outdated_mod.shutil, "which", lambda cmd: f"/bin/{cmd}")
+    monkeypatch.setattr(outdated_mod.subprocess, "run", fake_run)
+
+    reports = collect_outdated(tmp_path, ["npm"])
+    data = report_to_dict(reports)
+
+    assert_that(calls).is_equal_to([["npm", "outdated", "--json"]])
+    assert_that(data["summary"]["major"]).is_equal_to(1)
+    assert_that(data["ecosystems"]["npm"]["checked"]).is_true()
+    assert_that(data["packages"][0]["severity"]).is_equal_to("error")
+
+
+def test_collect_reports_command_failure(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A failing package manager is reported as an error, not raised.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "Cargo.toml").write_text("[package]\n")
+
+    def fake_run(command: list[str], **kwargs: Any) -> Any:
+        return subprocess.CompletedProcess(
+            command,
+            101,
+            stdout="",
+            stderr="error: no such command: `outdated`\n",
+        )
+
+    monkeypatch.setattr(outdated_mod.shutil, "which", lambda cmd: f"/bin/{cmd}")
+    monkeypatch.setattr(outdated_mod.subprocess, "run", fake_run)
+
+    reports = collect_outdated(tmp_path, ["cargo"])
+
+    assert_that(reports[0].error).is_equal_to("error: no such command: `outdated`")
+    assert_that(reports[0].packages).is_empty()
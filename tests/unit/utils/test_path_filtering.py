@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import os
 from pathlib import Path
 from unittest.mock import patch
 
@@ -10,6 +11,8 @@ from assertpy import assert_that
 
 from lintro.utils.path_filtering import (
     _is_venv_directory,
+    modeline_interpreter,
+    shebang_interpreter,
     should_exclude_path,
     walk_files_with_excludes,
 )
@@ -227,6 +230,119 @@ def test_walk_files_includes_venv_when_requested(project_with_venv: Path) -> Non
     assert_that(result).is_length(2)
 
 
+def test_walk_files_prunes_dependency_and_build_dirs(tmp_path: Path) -> None:
+    """Skip node_modules and target trees without listing them.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "main.py").write_text("")
+    (tmp_path / "node_modules" / "pkg").mkdir(parents=True)
+    (tmp_path / "node_modules" / "pkg" / "index.py").write_text("")
+    (tmp_path / "target" / "debug").mkdir(parents=True)
+    (tmp_path / "target" / "debug" / "gen.py").write_text("")
+
+    with patch(
+        "lintro.utils.path_filtering.os.scandir",
+        wraps=os.scandir,
+    ) as scandir:
+        result = walk_files_with_excludes(
+            paths=[str(tmp_path)],
+            file_patterns=["*.py"],
+            exclude_patterns=[],
+        )
+
+    assert_that(result).is_equal_to([str(tmp_path / "main.py")])
+    assert_that(scandir.call_count).is_equal_to(1)
+
+
+def test_walk_files_honours_custom_prune_dirs(tmp_path: Path) -> None:
+    """Replace the default prune list, including wildcard names.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "bazel-out").mkdir()
+    (tmp_path / "bazel-out" / "gen.py").write_text("")
+    (tmp_path / "target").mkdir()
+    (tmp_path / "target" / "lib.py").write_text("")
+
+    result = walk_files_with_excludes(
+        paths=[str(tmp_path)],
+        file_patterns=["*.py"],
+        exclude_patterns=[],
+        prune_dirs=["bazel-*"],
+    )
+
+    assert_that(result).is_equal_to([str(tmp_path / "target" / "lib.py")])
+
+
+def test_walk_files_searches_pruned_dir_given_explicitly(tmp_path: Path) -> None:
+    """Search a pruned directory when it is the path passed in.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    build_dir = tmp_path / "target"
+    build_dir.mkdir()
+    (build_dir / "gen.py").write_text("")
+
+    result = walk_files_with_excludes(
+        paths=[str(build_dir)],
+        file_patterns=["*.py"],
+        exclude_patterns=[],
+    )
+
+    assert_that(result).is_equal_to([str(build_dir / "gen.py")])
+
+
+def test_walk_files_does_not_enter_excluded_dirs(tmp_path: Path) -> None:
+    """Prune directories matched by an exclude pattern.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "main.py").write_text("")
+    (tmp_path / "vendor" / "lib").mkdir(parents=True)
+    (tmp_path / "vendor" / "lib" / "dep.py").write_text("")
+
+    with patch(
+        "lintro.utils.path_filtering.os.scandir",
+        wraps=os.scandir,
+    ) as scandir:
+        result = walk_files_with_excludes(
+            paths=[str(tmp_path)],
+            file_patterns=["*.py"],
+            exclude_patterns=["vendor"],
+        )
+
+    assert_that(result).is_equal_to([str(tmp_path / "main.py")])
+    assert_that(scandir.call_count).is_equal_to(1)
+
+
+def test_walk_files_walks_nested_directories(tmp_path: Path) -> None:
+    """Find files at every depth of the tree.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    expected = []
+    current = tmp_path
+    for depth in range(5):
+        current = current / f"level{depth}"
+        current.mkdir()
+        (current / f"file{depth}.py").write_text("")
+        expected.append(str(current / f"file{depth}.py"))
+
+    result = walk_files_with_excludes(
+        paths=[str(tmp_path)],
+        file_patterns=["*.py"],
+        exclude_patterns=[],
+    )
+
+    assert_that(result).is_equal_to(sorted(expected))
+
+
 def test_walk_files_returns_sorted_results(tmp_path: Path) -> None:
     """Return sorted file paths.
 
@@ -264,6 +380,323 @@ def test_walk_files_single_file_excluded(tmp_path: Path) -> None:
     assert_that(result).is_empty()
 
 
+@pytest.mark.parametrize(
+    ("first_line", "expected"),
+    [
+        ("#!/bin/bash", "bash"),
+        ("#! /bin/sh -e", "sh"),
+        ("#!/usr/bin/env bash", "bash"),
+        ("#!/usr/bin/env -S LC_ALL=C dash -e", "dash"),
+        ("#!/usr/bin/env python3", "python3"),
+        ("echo hello", None),
+        ("#!", None),
+    ],
+)
+def test_shebang_interpreter(tmp_path: Path, first_line: str, expected: str) -> None:
+    """Read the interpreter from the shebang line, looking through env.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        first_line: First line of the script.
+        expected: Expected interpreter name.
+    """
+    script = tmp_path / "script"
+    script.write_text(f"{first_line}\necho done\n")
+
+    assert_that(shebang_interpreter(str(script))).is_equal_to(expected)
+
+
+def test_walk_files_detects_extensionless_scripts_by_shebang(tmp_path: Path) -> None:
+    """Include extensionless files whose shebang names a listed interpreter.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    scripts = tmp_path / "scripts"
+    scripts.mkdir()
+    (scripts / "deploy").write_text("#!/usr/bin/env bash\necho deploy\n")
+    (scripts / "migrate").write_text("#!/usr/bin/env python3\nprint()\n")
+    (scripts / "README").write_text("Deployment scripts\n")
+    (scripts / "notes.txt").write_text("#!/bin/bash\n")
+    (scripts / "build.sh").write_text("echo build\n")
+
+    result = walk_files_with_excludes(
+        paths=[str(tmp_path)],
+        file_patterns=["*.sh"],
+        exclude_patterns=[],
+        shebang_interpreters=["sh", "bash"],
+    )
+
+    assert_that([Path(f).name for f in result]).is_equal_to(["build.sh", "deploy"])
+
+
+def test_walk_files_ignores_shebangs_without_interpreters(tmp_path: Path) -> None:
+    """Extensionless scripts are only included when interpreters are given.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "deploy").write_text("#!/bin/bash\n")
+
+    result = walk_files_with_excludes(
+        paths=[str(tmp_path / "deploy")],
+        file_patterns=["*.sh"],
+        exclude_patterns=[],
+    )
+
+    assert_that(result).is_empty()
+
+
+@pytest.mark.parametrize(
+    ("content", "expected"),
+    [
+        ("# -*- mode: python -*-\nprint()\n", "python"),
+        ("# -*- coding: utf-8; mode: sh -*-\necho\n", "sh"),
+        ("echo one\necho two\n# vim: set ft=bash:\n", "bash"),
+        ("# vi: filetype=ruby\nputs 1\n", "ruby"),
+        ("# -*- mode: lisp -*-\n", None),
+        ("plain text\n", None),
+    ],
+)
+def test_modeline_interpreter(
+    tmp_path: Path,
+    content: str,
+    expected: str | None,
+) -> None:
+    """Read the language of files without a shebang from editor modelines.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        content: File contents.
+        expected: Expected interpreter name.
+    """
+    script = tmp_path / "script"
+    script.write_text(content)
+
+    assert_that(modeline_interpreter(str(script))).is_equal_to(expected)
+
+
+def test_modeline_interpreter_ignores_binary_files(tmp_path: Path) -> None:
+    """Binary files are never classified as scripts.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    blob = tmp_path / "blob"
+    blob.write_bytes(b"\0\1# vim: ft=python\n")
+
+    assert_that(modeline_interpreter(str(blob))).is_none()
+
+
+def test_walk_files_routes_scripts_by_interpreter_family(tmp_path: Path) -> None:
+    """Versioned interpreters and modelines select extensionless scripts.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    scripts = tmp_path / "scripts"
+    scripts.mkdir()
+    (scripts / "migrate").write_text("#!/usr/bin/env python3.12\nprint()\n")
+    (scripts / "seed").write_text("# -*- mode: python -*-\nprint()\n")
+    (scripts / "deploy").write_text("#!/bin/bash\necho deploy\n")
+
+    result = walk_files_with_excludes(
+        paths=[str(tmp_path)],
+        file_patterns=["*.py"],
+        exclude_patterns=[],
+        shebang_interpreters=["python", "pypy"],
+    )
+
+    assert_that([Path(f).name for f in result]).is_equal_to(["migrate", "seed"])
+
+
+def test_walk_files_sees_rewritten_scripts(tmp_path: Path) -> None:
+    """A script whose shebang changes is classified again.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    script = tmp_path / "tool"
+    script.write_text("#!/bin/bash\n")
+    ruby = ["ruby"]
+    before = walk_files_with_excludes([str(script)], [], [], shebang_interpreters=ruby)
+    script.write_text("#!/usr/bin/env ruby\nputs 'tool'\n")
+
+    after = walk_files_with_excludes([str(script)], [], [], shebang_interpreters=ruby)
+
+    assert_that(before).is_empty()
+    assert_that(after).is_length(1)
+
+
+def _repo(tmp_path: Path) -> Path:
+    """Mark a directory as the root of a git repository.
+
+    Args:
+        tmp_path: Directory to mark.
+
+    Returns:
+        Path: The repository root.
+    """
+    (tmp_path / ".git" / "info").mkdir(parents=True)
+    return tmp_path
+
+
+def _names(files: list[str], root: Path) -> list[str]:
+    """Express walked files relative to a root.
+
+    Args:
+        files: Walked files.
+        root: Directory the names are relative to.
+
+    Returns:
+        list[str]: Relative POSIX paths.
+    """
+    return [Path(f).relative_to(root).as_posix() for f in files]
+
+
+def test_walk_files_honors_gitignore(tmp_path: Path) -> None:
+    """Skip files ignored by .gitignore files and .git/info/exclude.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    repo = _repo(tmp_path)
+    (repo / ".gitignore").write_text("generated/\n*_pb2.py\n")
+    (repo / ".git" / "info" / "exclude").write_text("scratch.py\n")
+    (repo / "pkg").mkdir()
+    (repo / "pkg" / ".gitignore").write_text("!keep_pb2.py\nlocal.py\n")
+    (repo / "generated").mkdir()
+    for name in [
+        "main.py",
+        "scratch.py",
+        "api_pb2.py",
+        "generated/out.py",
+        "pkg/keep_pb2.py",
+        "pkg/local.py",
+        "pkg/mod.py",
+    ]:
+        (repo / name).write_text("")
+
+    result = walk_files_with_excludes([str(repo)], ["*.py"], [])
+
+    assert_that(_names(result, repo)).is_equal_to(
+        ["main.py", "pkg/keep_pb2.py", "pkg/mod.py"],
+    )
+
+
+def test_walk_files_without_gitignore(tmp_path: Path) -> None:
+    """Find git-ignored files when gitignore is off.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    repo = _repo(tmp_path)
+    (repo / ".gitignore").write_text("*.py\n")
+    (repo / "main.py").write_text("")
+
+    result = walk_files_with_excludes([str(repo)], ["*.py"], [], gitignore=False)
+
+    assert_that(_names(result, repo)).is_equal_to(["main.py"])
+
+
+def test_walk_files_searches_ignored_file_given_explicitly(tmp_path: Path) -> None:
+    """Search a git-ignored file when it is the path passed in.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    repo = _repo(tmp_path)
+    (repo / ".gitignore").write_text("main.py\n")
+    (repo / "main.py").write_text("")
+
+    result = walk_files_with_excludes([str(repo / "main.py")], ["*.py"], [])
+
+    assert_that(_names(result, repo)).is_equal_to(["main.py"])
+
+
+def test_walk_files_gitignore_outside_repository(tmp_path: Path) -> None:
+    """Ignore .gitignore files outside a git repository.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / ".gitignore").write_text("*.py\n")
+    (tmp_path / "main.py").write_text("")
+
+    result = walk_files_with_excludes([str(tmp_path)], ["*.py"], [])
+
+    assert_that(_names(result, tmp_path)).is_equal_to(["main.py"])
+
+
+@pytest.fixture
+def linked_repo(tmp_path: Path) -> Path:
+    """Create a repository with links inside, outside and back up the tree.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+
+    Returns:
+        Path: The repository root.
+    """
+    repo = _repo(tmp_path / "repo")
+    (repo / "src").mkdir()
+    (repo / "src" / "main.py").write_text("")
+    outside = tmp_path / "shared"
+    outside.mkdir()
+    (outside / "common.py").write_text("")
+    (repo / "lib").symlink_to(repo / "src")
+    (repo / "shared").symlink_to(outside)
+    (repo / "src" / "loop").symlink_to(repo)
+    return repo
+
+
+@pytest.mark.parametrize(
+    ("policy", "expected"),
+    [
+        ("skip", ["src/main.py"]),
+        ("follow", ["lib/main.py", "shared/common.py", "src/main.py"]),
+        ("follow-within-repo", ["lib/main.py", "src/main.py"]),
+    ],
+)
+def test_walk_files_symlink_policy(
+    linked_repo: Path,
+    policy: str,
+    expected: list[str],
+) -> None:
+    """Follow symlinks as the policy allows, without looping on cycles.
+
+    Args:
+        linked_repo: Repository with symlinked directories.
+        policy: Symlink policy under test.
+        expected: Files found under the policy.
+    """
+    result = walk_files_with_excludes(
+        [str(linked_repo)],
+        ["*.py"],
+        [],
+        symlinks=policy,
+    )
+
+    assert_that(_names(result, linked_repo)).is_equal_to(expected)
+
+
+def test_walk_files_follows_links_inside_the_repo_by_default(
+    linked_repo: Path,
+) -> None:
+    """Linked files inside the repository are found without a policy.
+
+    Args:
+        linked_repo: Repository with symlinked directories.
+    """
+    (linked_repo / "app.py").symlink_to(linked_repo / "src" / "main.py")
+
+    result = walk_files_with_excludes([str(linked_repo)], ["*.py"], [])
+
+    assert_that(_names(result, linked_repo)).is_equal_to(
+        ["app.py", "lib/main.py", "src/main.py"],
+    )
+
+
 # =============================================================================
 # Tests for _is_venv_directory
 # =============================================================================
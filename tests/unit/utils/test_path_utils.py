@@ -13,6 +13,8 @@ from lintro.utils.path_utils import (
     find_lintro_ignore,
     load_lintro_ignore,
     normalize_file_path_for_display,
+    relative_to_root,
+    to_posix_path,
 )
 
 # =============================================================================
@@ -241,3 +243,70 @@ def test_normalize_file_path_adds_dot_slash_prefix(
     result = normalize_file_path_for_display("src/file.py")
 
     assert_that(result).is_equal_to("./src/file.py")
+
+
+# =============================================================================
+# Tests for to_posix_path and relative_to_root
+# =============================================================================
+
+
+@pytest.mark.parametrize(
+    ("path", "expected"),
+    [
+        ("c:\\repo\\src/app.py", "C:/repo/src/app.py"),
+        ("\\\\?\\C:\\repo\\app.py", "C:/repo/app.py"),
+        ("src\\app.py", "src/app.py"),
+        ("/repo/src/app.py", "/repo/src/app.py"),
+    ],
+)
+def test_to_posix_path(path: str, expected: str) -> None:
+    """Use forward slashes and an upper-case drive letter.
+
+    Args:
+        path: Path as a tool printed it.
+        expected: Normalized path.
+    """
+    assert_that(to_posix_path(path)).is_equal_to(expected)
+
+
+@pytest.mark.parametrize(
+    ("path", "root", "expected"),
+    [
+        ("c:\\Repo\\src\\app.py", "C:\\repo", "src/app.py"),
+        ("C:/repo/src\\app.py", "c:\\repo\\", "src/app.py"),
+        ("D:\\other\\app.py", "C:\\repo", "D:/other/app.py"),
+        ("/repo/src/app.py", "/repo", "src/app.py"),
+        ("/Repo/src/app.py", "/repo", "/Repo/src/app.py"),
+        ("/repository/app.py", "/repo", "/repository/app.py"),
+        ("./src\\app.py", "/repo", "src/app.py"),
+        ("../lib/app.py", "/repo", "../lib/app.py"),
+        ("/repo", "/repo", "."),
+        ("", "/repo", ""),
+    ],
+)
+def test_relative_to_root(path: str, root: str, expected: str) -> None:
+    """Map tool paths inside the root to relative POSIX paths.
+
+    Args:
+        path: Path as a tool printed it.
+        root: Project root.
+        expected: Normalized path.
+    """
+    assert_that(relative_to_root(path, root)).is_equal_to(expected)
+
+
+def test_relative_to_root_defaults_to_working_directory(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Paths are relative to the working directory by default.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+
+    result = relative_to_root(os.path.join(os.getcwd(), "src", "app.py"))
+
+    assert_that(result).is_equal_to("src/app.py")
@@ -0,0 +1,332 @@
+"""Unit tests for project detection used by ``lintro init --detect``."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import yaml
+from assertpy import assert_that
+
+import lintro.utils.project_detection as detection_mod
+from lintro.utils.project_detection import detect_project, render_detected_config
+
+
+@pytest.fixture
+def all_installed(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Pretend every tool executable is in PATH.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(detection_mod.shutil, "which", lambda cmd: f"/bin/{cmd}")
+
+
+def test_detects_tools_from_markers(tmp_path: Path, all_installed: None) -> None:
+    """Manifests, Dockerfiles and workflows enable their tools.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "Cargo.toml").write_text("[package]\n")
+    (tmp_path / "docker").mkdir()
+    (tmp_path / "docker" / "api.Dockerfile").write_text("FROM alpine\n")
+    workflows = tmp_path / ".github" / "workflows"
+    workflows.mkdir(parents=True)
+    (workflows / "ci.yml").write_text("on: push\n")
+
+    detection = detect_project(tmp_path)
+    markers = {t.name: t.marker for t in detection.tools}
+
+    assert_that(markers).contains_key("clippy", "rustfmt", "cargo_audit", "taplo")
+    assert_that(markers["hadolint"]).is_equal_to("docker/api.Dockerfile")
+    assert_that(markers["actionlint"]).is_equal_to(".github/workflows/ci.yml")
+    assert_that(markers).does_not_contain_key("ruff", "prettier")
+
+
+def test_biome_config_enables_biome(tmp_path: Path, all_installed: None) -> None:
+    """A biome.json enables Biome next to the JavaScript tools.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "package.json").write_text("{}")
+    (tmp_path / "biome.json").write_text("{}")
+
+    markers = {t.name: t.marker for t in detect_project(tmp_path).tools}
+
+    assert_that(markers["biome"]).is_equal_to("biome.json")
+    assert_that(markers).contains_key("package_json", "prettier", "oxlint")
+
+
+def test_flat_config_enables_eslint(tmp_path: Path, all_installed: None) -> None:
+    """An eslint.config.mjs enables ESLint.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "eslint.config.mjs").write_text("export default [];\n")
+
+    markers = {t.name: t.marker for t in detect_project(tmp_path).tools}
+
+    assert_that(markers["eslint"]).is_equal_to("eslint.config.mjs")
+
+
+def test_stylelintrc_enables_stylelint(tmp_path: Path, all_installed: None) -> None:
+    """A .stylelintrc.json enables Stylelint.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / ".stylelintrc.json").write_text("{}\n")
+
+    markers = {t.name: t.marker for t in detect_project(tmp_path).tools}
+
+    assert_that(markers["stylelint"]).is_equal_to(".stylelintrc.json")
+
+
+def test_typos_config_enables_typos(tmp_path: Path, all_installed: None) -> None:
+    """A _typos.toml enables the typos spell checker.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "_typos.toml").write_text("[default.extend-words]\n")
+
+    detection = detect_project(tmp_path)
+    tools = {t.name: t for t in detection.tools}
+
+    assert_that(tools["typos"].ecosystem).is_equal_to("Spelling")
+    assert_that(tools["typos"].marker).is_equal_to("_typos.toml")
+
+
+def test_vale_config_enables_vale(tmp_path: Path, all_installed: None) -> None:
+    """A .vale.ini enables the Vale prose linter.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / ".vale.ini").write_text("StylesPath = styles\n")
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["vale"].ecosystem).is_equal_to("Prose")
+    assert_that(tools["vale"].marker).is_equal_to(".vale.ini")
+
+
+def test_terraform_files_enable_terraform_tools(
+    tmp_path: Path,
+    all_installed: None,
+) -> None:
+    """A .tf file in a module directory enables terraform fmt and TFLint.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "infra").mkdir()
+    (tmp_path / "infra" / "main.tf").write_text("terraform {}\n")
+
+    detection = detect_project(tmp_path)
+    tools = {t.name: t for t in detection.tools}
+
+    assert_that(tools["terraform_fmt"].ecosystem).is_equal_to("Terraform")
+    assert_that(tools["tflint"].marker).is_equal_to("infra/main.tf")
+
+
+def test_c_sources_enable_clang_tools(tmp_path: Path, all_installed: None) -> None:
+    """A C++ source enables clang-format and clang-tidy.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "main.cpp").write_text("int main() {}\n")
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["clang_format"].ecosystem).is_equal_to("C/C++")
+    assert_that(tools["clang_tidy"].marker).is_equal_to("src/main.cpp")
+
+
+def test_go_module_enables_go_tools(tmp_path: Path, all_installed: None) -> None:
+    """A go.mod enables gofmt and golangci-lint.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "go.mod").write_text("module example.com/app\n")
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["gofmt"].ecosystem).is_equal_to("Go")
+    assert_that(tools["golangci_lint"].marker).is_equal_to("go.mod")
+
+
+def test_buf_config_enables_buf(tmp_path: Path, all_installed: None) -> None:
+    """A buf.yaml enables buf.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "buf.yaml").write_text("version: v2\n")
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["buf"].ecosystem).is_equal_to("Protobuf")
+    assert_that(tools["buf"].marker).is_equal_to("buf.yaml")
+
+
+def test_ansible_config_enables_ansible_lint(
+    tmp_path: Path,
+    all_installed: None,
+) -> None:
+    """An ansible.cfg enables ansible-lint.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "ansible.cfg").write_text("[defaults]\n")
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["ansible_lint"].ecosystem).is_equal_to("Ansible")
+    assert_that(tools["ansible_lint"].marker).is_equal_to("ansible.cfg")
+
+
+def test_gemfile_enables_rubocop(tmp_path: Path, all_installed: None) -> None:
+    """A Gemfile enables RuboCop.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "Gemfile").write_text('source "https://rubygems.org"\n')
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["rubocop"].ecosystem).is_equal_to("Ruby")
+    assert_that(tools["rubocop"].marker).is_equal_to("Gemfile")
+
+
+def test_kotlin_sources_enable_ktlint(tmp_path: Path, all_installed: None) -> None:
+    """Kotlin sources and Gradle Kotlin scripts enable ktlint.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "build.gradle.kts").write_text('plugins { kotlin("jvm") }\n')
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["ktlint"].ecosystem).is_equal_to("Kotlin")
+    assert_that(tools["ktlint"].marker).is_equal_to("build.gradle.kts")
+
+
+def test_lua_sources_enable_stylua_and_luacheck(
+    tmp_path: Path,
+    all_installed: None,
+) -> None:
+    """Lua sources enable both StyLua and luacheck.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "init.lua").write_text("vim.opt.number = true\n")
+
+    tools = {t.name: t for t in detect_project(tmp_path).tools}
+
+    assert_that(tools["stylua"].ecosystem).is_equal_to("Lua")
+    assert_that(tools["luacheck"].ecosystem).is_equal_to("Lua")
+    assert_that(tools["luacheck"].marker).is_equal_to("init.lua")
+
+
+def test_skips_vendored_directories(tmp_path: Path, all_installed: None) -> None:
+    """Files under node_modules do not count as project markers.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    vendored = tmp_path / "node_modules" / "pkg"
+    vendored.mkdir(parents=True)
+    (vendored / "package.json").write_text("{}")
+
+    assert_that(detect_project(tmp_path).tools).is_empty()
+
+
+def test_reads_python_settings(tmp_path: Path, all_installed: None) -> None:
+    """Line length and target come from pyproject.toml.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        all_installed: Fixture marking every tool as installed.
+    """
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nrequires-python = ">=3.11"\n\n[tool.ruff]\nline-length = 100\n',
+    )
+
+    detection = detect_project(tmp_path)
+
+    assert_that(detection.line_length).is_equal_to(100)
+    assert_that(detection.target_python).is_equal_to("py311")
+    assert_that(detection.installed_tools).contains("ruff", "pyproject")
+
+
+def test_missing_tools_are_disabled(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Tools without an executable are written as disabled.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "package.json").write_text("{}")
+    monkeypatch.setattr(detection_mod.shutil, "which", lambda cmd: None)
+
+    content = render_detected_config(detect_project(tmp_path))
+    data = yaml.safe_load(content)
+
+    assert_that(data["execution"]["enabled_tools"]).is_equal_to(["package_json"])
+    assert_that(data["tools"]["prettier"]["enabled"]).is_false()
+    assert_that(content).contains("enabled: false  # not installed")
+    assert_that(data["enforce"]["line_length"]).is_equal_to(88)
+
+
+def test_nothing_detected_disables_every_tool(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without an enabled tool, every tool is written as disabled.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "Cargo.toml").write_text("[package]\n")
+    monkeypatch.setattr(detection_mod.shutil, "which", lambda cmd: None)
+
+    content = render_detected_config(detect_project(tmp_path))
+    data = yaml.safe_load(content)
+
+    assert_that(data["execution"]).does_not_contain_key("enabled_tools")
+    assert_that(data["tools"]["clippy"]["enabled"]).is_false()
+    assert_that(data["tools"]["ruff"]["enabled"]).is_false()
+    assert_that(content).contains("enabled: false  # not detected")
+    assert_that(
+        [name for name, section in data["tools"].items() if section["enabled"]],
+    ).is_empty()
@@ -0,0 +1,149 @@
+"""Tests for the quality score model."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import build_config_from_data
+from lintro.config.score_config import ScoreConfig
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.quality_score import (
+    ScoreModel,
+    compute_score,
+    count_lines,
+)
+
+
+def _record(
+    tool: str,
+    code: str,
+    path: str,
+    severity: SeverityLevel = SeverityLevel.WARNING,
+) -> dict[str, Any]:
+    """Build an issue record.
+
+    Args:
+        tool: Tool that reported the issue.
+        code: Rule code.
+        path: File path.
+        severity: Issue severity.
+
+    Returns:
+        dict[str, Any]: The record.
+    """
+    return {"tool": tool, "code": code, "path": path, "severity": severity}
+
+
+@pytest.mark.parametrize(
+    ("record", "penalty"),
+    [
+        (_record("ruff", "F401", "a.py", SeverityLevel.ERROR), 10.0),
+        (_record("mypy", "misc", "a.py"), 3.0),
+        (_record("ruff", "S101", "a.py"), 9.0),
+        (_record("bandit", "B101", "a.py", SeverityLevel.INFO), 3.0),
+        (_record("prettier", "", "a.ts"), 1.5),
+    ],
+    ids=["error", "warning", "ruff_security", "bandit_info", "formatting"],
+)
+def test_penalty_weighs_severity_and_category(
+    record: dict[str, Any],
+    penalty: float,
+) -> None:
+    """Severity weights are multiplied by the rule category's weight.
+
+    Args:
+        record: Issue record.
+        penalty: Expected penalty points.
+    """
+    model = ScoreModel.from_config()
+
+    assert_that(model.penalty(record)).is_equal_to(penalty)
+
+
+def test_score_and_grade_follow_density() -> None:
+    """A clean tree scores 100; half_density points per KLOC score 50."""
+    model = ScoreModel.from_config()
+
+    assert_that(model.score(0, 5000)).is_equal_to(100.0)
+    assert_that(model.score(250, 5000)).is_equal_to(50.0)
+    assert_that(model.grade(90.0)).is_equal_to("A")
+    assert_that(model.grade(79.9)).is_equal_to("C")
+    assert_that(model.grade(12.0)).is_equal_to("F")
+    # Tiny directories are scored as if they had MIN_LINES lines
+    assert_that(model.score(3, 10)).is_equal_to(model.score(3, 100))
+
+
+def test_config_replaces_weights_and_grades() -> None:
+    """Configured values replace the built-in ones."""
+    model = ScoreModel.from_config(
+        ScoreConfig(
+            severity_weights={"warning": 1},
+            categories={"docs": ["pydoclint:*"]},
+            category_weights={"docs": 0.1},
+            grades={"pass": 50},
+        ),
+    )
+
+    assert_that(model.penalty(_record("pydoclint", "DOC101", "a.py"))).is_close_to(
+        0.1,
+        1e-9,
+    )
+    assert_that(model.grade(60)).is_equal_to("pass")
+    assert_that(model.grade(40)).is_equal_to("F")
+    assert_that(model.grade_rank("PASS")).is_less_than(model.grade_rank("f"))
+    with pytest.raises(ValueError, match="Unknown grade 'B'"):
+        model.grade_rank("B")
+
+
+def test_compute_score_per_directory() -> None:
+    """Directories are scored on their own lines, worst first."""
+    records = [
+        _record("ruff", "F401", "src/a.py", SeverityLevel.ERROR),
+        _record("ruff", "E501", "src/b.py"),
+        _record("prettier", "", "web/app.ts"),
+    ]
+    lines = {"src/a.py": 400, "src/b.py": 600, "web/app.ts": 1000, "README.md": 20}
+
+    report = compute_score(records, lines, ScoreModel.from_config())
+
+    assert_that(report.total.lines).is_equal_to(2020)
+    assert_that(report.total.penalty).is_equal_to(14.5)
+    assert_that([row.path for row in report.directories]).is_equal_to(
+        ["src/", "web/", "./"],
+    )
+    assert_that(report.directories[0].score).is_equal_to(79.4)
+    assert_that(report.directories[0].grade).is_equal_to("C")
+    assert_that(report.categories).is_equal_to({"other": 13.0, "formatting": 1.5})
+
+
+def test_count_lines_skips_blank_binary_and_lock_files(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Only non-blank lines of written text files count.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "a.py").write_text("x = 1\n\n\ny = 2\n")
+    (tmp_path / "logo.png").write_bytes(b"\x89PNG\0\0data")
+    (tmp_path / "Cargo.lock").write_text("[[package]]\n" * 50)
+    monkeypatch.chdir(tmp_path)
+
+    assert_that(count_lines(["."])).is_equal_to({"src/a.py": 2})
+
+
+def test_score_section_is_loaded() -> None:
+    """The score section of the config is parsed into ScoreConfig."""
+    config = build_config_from_data(
+        {"score": {"fail-under": 75, "grades": {"A": 95, "B": 85}}},
+    )
+
+    assert_that(config.score.fail_under).is_equal_to(75)
+    assert_that(config.score.grades).is_equal_to({"A": 95, "B": 85})
@@ -0,0 +1,104 @@
+"""Tests for lintro.utils.report_diff."""
+
+from __future__ import annotations
+
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.report_diff import (
+    DiffIssue,
+    diff_issues,
+    report_issues,
+    result_issues,
+)
+
+
+def _issue(fingerprint: str, message: str = "unused import") -> DiffIssue:
+    """Build a ruff issue for diffing.
+
+    Args:
+        fingerprint: Fingerprint of the issue.
+        message: Issue message.
+
+    Returns:
+        DiffIssue: The issue.
+    """
+    return DiffIssue(
+        tool="ruff",
+        file="src/a.py",
+        line=3,
+        code="F401",
+        message=message,
+        fingerprint=fingerprint,
+    )
+
+
+def test_diff_issues_classifies_by_fingerprint() -> None:
+    """Shared fingerprints are unchanged; the rest are new or fixed."""
+    diff = diff_issues([_issue("a"), _issue("b")], [_issue("b"), _issue("c")])
+
+    assert_that([i.fingerprint for i in diff.new]).is_equal_to(["c"])
+    assert_that([i.fingerprint for i in diff.fixed]).is_equal_to(["a"])
+    assert_that([i.fingerprint for i in diff.unchanged]).is_equal_to(["b"])
+    assert_that(diff.fingerprinted).is_true()
+
+
+def test_diff_issues_without_fingerprints_matches_messages() -> None:
+    """Reports without fingerprints are matched on message, counting repeats."""
+    old = [_issue("", "line 3 too long"), _issue("", "line 9 too long")]
+    new = [_issue("x", "line 4 too long")]
+
+    diff = diff_issues(old, new)
+
+    assert_that(diff.fingerprinted).is_false()
+    assert_that(diff.new).is_empty()
+    assert_that(diff.unchanged).is_length(1)
+    assert_that(diff.fixed).is_length(1)
+    assert_that(diff.to_dict()["summary"]).is_equal_to(
+        {"new": 0, "fixed": 1, "unchanged": 1},
+    )
+
+
+def test_report_issues_reads_json_reports() -> None:
+    """Issues of every tool in a JSON report are read with fingerprints."""
+    report: dict[str, Any] = {
+        "results": [
+            {
+                "tool": "Ruff",
+                "issues": [
+                    {
+                        "file": "src/a.py",
+                        "line": 3,
+                        "code": "F401",
+                        "message": "unused import",
+                        "fingerprint": "a",
+                    },
+                ],
+            },
+            {"tool": "mypy", "issues_count": 0},
+        ],
+    }
+
+    assert_that(report_issues(report)).is_equal_to([_issue("a")])
+
+
+def test_result_issues_fingerprints_results() -> None:
+    """Issues of tool results carry the fingerprints reports would record."""
+    issue = RuffIssue(file="src/a.py", line=3, code="F401", message="unused")
+    results = [
+        ToolResult(name="ruff", success=False, issues_count=1, issues=[issue]),
+        ToolResult(
+            name="mypy",
+            success=True,
+            skipped=True,
+            skip_reason="not installed",
+        ),
+    ]
+
+    issues = result_issues(results)
+
+    assert_that(issues).is_length(1)
+    assert_that(issues[0].fingerprint).is_equal_to(issue.fingerprint("ruff"))
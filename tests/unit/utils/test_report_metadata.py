@@ -0,0 +1,124 @@
+"""Tests for the environment and tool metadata embedded in reports."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro import __version__
+from lintro.config.execution_config import ExecutionConfig
+from lintro.config.lintro_config import LintroConfig
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils import report_metadata
+from lintro.utils.output.sarif import build_sarif_report
+from lintro.utils.report_metadata import build_report_metadata, config_hash
+
+
+class _FakeVcs:
+    """VCS provider with a fixed checked-out commit."""
+
+    def head_commit(self) -> str:
+        """Return the checked-out commit.
+
+        Returns:
+            str: Commit id.
+        """
+        return "abc1234def"
+
+
+@pytest.fixture(autouse=True)
+def fixed_environment(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Pin the commit, CI job and tool versions seen by the metadata.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(report_metadata, "detect_vcs", lambda: _FakeVcs())
+    monkeypatch.setattr(report_metadata, "detect_ci_environment", lambda: None)
+    monkeypatch.setattr(report_metadata, "tool_version", lambda name: f"{name}-1.0")
+
+
+def _results() -> list[ToolResult]:
+    """Build the results of a run with one skipped tool.
+
+    Returns:
+        list[ToolResult]: Results of ruff, which ran, and hadolint, skipped.
+    """
+    return [
+        ToolResult(
+            name="ruff",
+            success=True,
+            issues_count=0,
+            duration_ms=120,
+            extra_args=["--preview"],
+        ),
+        ToolResult(
+            name="hadolint",
+            success=True,
+            skipped=True,
+            skip_reason="not installed",
+        ),
+    ]
+
+
+def test_build_report_metadata_records_environment_and_tools() -> None:
+    """Versions, commit, config hash and per-tool data are recorded."""
+    config = LintroConfig()
+
+    metadata = build_report_metadata(_results(), config)
+
+    assert_that(metadata["lintro_version"]).is_equal_to(__version__)
+    assert_that(metadata["commit"]).is_equal_to("abc1234def")
+    assert_that(metadata["ci"]).is_none()
+    assert_that(metadata["config_hash"]).is_equal_to(config_hash(config))
+    assert_that(metadata["backend"]).is_equal_to("host")
+    assert_that(metadata["tools"]).is_equal_to(
+        {
+            "ruff": {
+                "version": "ruff-1.0",
+                "duration_ms": 120,
+                "extra_args": ["--preview"],
+            },
+            "hadolint": {"version": None, "duration_ms": None, "extra_args": []},
+        },
+    )
+
+
+def test_build_report_metadata_omits_versions_off_host() -> None:
+    """Tools run in a container have no host version to report."""
+    config = LintroConfig(execution=ExecutionConfig(backend="container"))
+
+    metadata = build_report_metadata(_results(), config)
+
+    assert_that(metadata["tools"]["ruff"]["version"]).is_none()
+
+
+def test_config_hash_tracks_effective_settings() -> None:
+    """The hash changes with the settings but not with the config location."""
+    base = LintroConfig()
+    moved = LintroConfig(config_path="/elsewhere/.lintro-config.yaml")
+    changed = LintroConfig(execution=ExecutionConfig(parallel=False))
+
+    assert_that(config_hash(moved)).is_equal_to(config_hash(base))
+    assert_that(config_hash(changed)).is_not_equal_to(config_hash(base))
+
+
+def test_build_sarif_report_records_versions_and_durations() -> None:
+    """SARIF runs carry the driver version, duration, arguments and metadata."""
+    results = _results()
+    metadata = build_report_metadata(results, LintroConfig())
+
+    report = build_sarif_report(results, metadata)
+
+    run = report["runs"][0]
+    assert_that(run["tool"]["driver"]["version"]).is_equal_to("ruff-1.0")
+    assert_that(run["invocations"]).is_equal_to(
+        [
+            {
+                "executionSuccessful": True,
+                "properties": {"durationMs": 120, "extraArgs": ["--preview"]},
+            },
+        ],
+    )
+    assert_that(run["properties"]["lintro"]["commit"]).is_equal_to("abc1234def")
+    assert_that(run["properties"]["lintro"]).does_not_contain_key("tools")
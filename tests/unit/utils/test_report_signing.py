@@ -0,0 +1,141 @@
+"""Tests for signing reports with in-toto attestations."""
+
+from __future__ import annotations
+
+import base64
+import json
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric import ec, ed25519
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.utils.report_signing import (
+    PREDICATE_TYPE,
+    build_run_metadata,
+    pae,
+    sign_report,
+    verify_attestation,
+)
+
+
+def _write_keys(directory: Path, private_key: object) -> tuple[str, str]:
+    """Write a key pair as PEM files.
+
+    Args:
+        directory: Directory for the key files.
+        private_key: Private key to write.
+
+    Returns:
+        tuple[str, str]: Paths of the private and public key.
+    """
+    private = directory / "key.pem"
+    public = directory / "key.pub"
+    private.write_bytes(
+        private_key.private_bytes(  # type: ignore[attr-defined]
+            serialization.Encoding.PEM,
+            serialization.PrivateFormat.PKCS8,
+            serialization.NoEncryption(),
+        ),
+    )
+    public.write_bytes(
+        private_key.public_key().public_bytes(  # type: ignore[attr-defined]
+            serialization.Encoding.PEM,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        ),
+    )
+    return str(private), str(public)
+
+
+def _report(directory: Path) -> str:
+    """Write a JSON report and return its path.
+
+    Args:
+        directory: Directory for the report.
+
+    Returns:
+        str: Report path.
+    """
+    report = directory / "report.json"
+    report.write_text('{"summary": {"total_issues": 2}}')
+    return str(report)
+
+
+def test_pae_matches_dsse_spec() -> None:
+    """The signed bytes follow the DSSE pre-authentication encoding."""
+    assert_that(pae("http://example.com/HelloWorld", b"hello world")).is_equal_to(
+        b"DSSEv1 29 http://example.com/HelloWorld 11 hello world",
+    )
+
+
+def test_run_metadata_describes_tools() -> None:
+    """The predicate records the lintro version, exit code and tool outcomes."""
+    metadata = build_run_metadata(
+        action="check",
+        results=[ToolResult(name="ruff", success=False, issues_count=2)],
+        exit_code=1,
+    )
+
+    assert_that(metadata).contains_key("lintro_version", "commit", "command")
+    assert_that(metadata["exit_code"]).is_equal_to(1)
+    assert_that(metadata["tools"]).is_equal_to(
+        [{"name": "ruff", "success": False, "issues_count": 2, "skipped": False}],
+    )
+
+
+@pytest.mark.parametrize(
+    "make_key",
+    [
+        ed25519.Ed25519PrivateKey.generate,
+        lambda: ec.generate_private_key(ec.SECP256R1()),
+    ],
+    ids=["ed25519", "ecdsa"],
+)
+def test_signed_report_verifies(tmp_path: Path, make_key: object) -> None:
+    """A signed report verifies with the matching public key.
+
+    Args:
+        tmp_path: Temporary directory for the report and keys.
+        make_key: Generates a private key.
+    """
+    private, public = _write_keys(tmp_path, make_key())  # type: ignore[operator]
+    report = _report(tmp_path)
+
+    attestation = sign_report(report, private, {"exit_code": 1})
+
+    assert_that(attestation).is_equal_to(f"{report}.intoto.json")
+    statement = verify_attestation(report, attestation, public)
+    assert_that(statement["predicateType"]).is_equal_to(PREDICATE_TYPE)
+    assert_that(statement["subject"][0]["name"]).is_equal_to("report.json")
+    assert_that(statement["predicate"]).is_equal_to({"exit_code": 1})
+
+
+def test_tampering_is_detected(tmp_path: Path) -> None:
+    """Changing the report, the metadata or the key fails verification.
+
+    Args:
+        tmp_path: Temporary directory for the report and keys.
+    """
+    private, public = _write_keys(tmp_path, ed25519.Ed25519PrivateKey.generate())
+    report = _report(tmp_path)
+    attestation = sign_report(report, private, {"exit_code": 1})
+    envelope = json.loads(Path(attestation).read_text())
+
+    Path(report).write_text('{"summary": {"total_issues": 0}}')
+    with pytest.raises(ValueError, match="does not match the attested digest"):
+        verify_attestation(report, attestation, public)
+
+    statement = json.loads(base64.b64decode(envelope["payload"]))
+    statement["predicate"]["exit_code"] = 0
+    envelope["payload"] = base64.b64encode(json.dumps(statement).encode()).decode()
+    Path(attestation).write_text(json.dumps(envelope))
+    with pytest.raises(ValueError, match="signature is invalid"):
+        verify_attestation(report, attestation, public)
+
+    other = tmp_path / "other"
+    other.mkdir()
+    _, other_public = _write_keys(other, ed25519.Ed25519PrivateKey.generate())
+    with pytest.raises(ValueError, match="not signed by the given key"):
+        verify_attestation(report, attestation, other_public)
@@ -0,0 +1,241 @@
+"""Tests for uploading reports to a results service."""
+
+from __future__ import annotations
+
+import gzip
+import json
+from pathlib import Path
+from typing import Any
+
+import httpx
+import pytest
+from assertpy import assert_that
+
+from lintro.utils import report_upload
+from lintro.utils.report_upload import ReportUploadError, push_report
+
+ENDPOINT = "https://lint.example.com/api"
+
+
+class _Response:
+    """Minimal HTTP response."""
+
+    def __init__(
+        self,
+        status_code: int,
+        body: Any = None,
+        headers: dict[str, str] | None = None,
+    ) -> None:
+        """Build the response.
+
+        Args:
+            status_code: HTTP status.
+            body: JSON body.
+            headers: Response headers.
+        """
+        self.status_code = status_code
+        self.headers = headers or {}
+        self.text = json.dumps(body)
+        self._body = body
+
+    def json(self) -> Any:
+        """Return the JSON body.
+
+        Returns:
+            Any: Decoded body.
+        """
+        return self._body
+
+
+class _Service:
+    """In-memory results service speaking the upload protocol."""
+
+    def __init__(self) -> None:
+        """Start without uploads."""
+        self.uploads: dict[str, bytearray] = {}
+        self.requests: list[tuple[str, str, dict[str, str]]] = []
+        self.fail_next: list[_Response | Exception] = []
+        self.created: dict[str, Any] = {}
+        self.drop_puts = False
+
+    def request(self, method: str, url: str, **kwargs: Any) -> _Response:
+        """Handle one request.
+
+        Args:
+            method: HTTP method.
+            url: Request URL.
+            **kwargs: Headers, body and timeout.
+
+        Returns:
+            _Response: Reply of the service.
+
+        Raises:
+            Exception: A queued failure.
+            httpx.TransportError: For chunks after the first while
+                ``drop_puts`` is set.
+        """
+        self.requests.append((method, url, kwargs["headers"]))
+        if self.fail_next:
+            failure = self.fail_next.pop(0)
+            if isinstance(failure, Exception):
+                raise failure
+            return failure
+        path = url.removeprefix(ENDPOINT)
+        if method == "POST" and path == "/uploads":
+            self.created = kwargs["json"]
+            self.uploads["u1"] = bytearray()
+            return _Response(201, {"upload_id": "u1"})
+        upload_id = path.split("/")[2]
+        if upload_id not in self.uploads:
+            return _Response(404, {"error": "unknown upload"})
+        data = self.uploads[upload_id]
+        if method == "GET":
+            return _Response(200, {"received": len(data)})
+        if method == "PUT":
+            if self.drop_puts and data:
+                raise httpx.TransportError("connection reset")
+            data.extend(kwargs["content"])
+            return _Response(200, {"received": len(data)})
+        return _Response(200, {"report_id": "r-42", "url": f"{ENDPOINT}/r-42"})
+
+
+@pytest.fixture
+def service(monkeypatch: pytest.MonkeyPatch) -> _Service:
+    """Route httpx requests to an in-memory service without real sleeps.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        _Service: The service.
+    """
+    fake = _Service()
+    monkeypatch.setattr(httpx, "request", fake.request)
+    monkeypatch.setattr(report_upload, "sleep", lambda seconds: None)
+    return fake
+
+
+@pytest.fixture
+def report(tmp_path: Path) -> str:
+    """Write a report big enough for several chunks.
+
+    Args:
+        tmp_path: Temporary directory.
+
+    Returns:
+        str: Path of the report.
+    """
+    path = tmp_path / "lint.json"
+    issues = [{"file": f"src/f{i}.py", "line": i} for i in range(400)]
+    path.write_text(json.dumps({"results": issues}))
+    return str(path)
+
+
+def test_push_report_uploads_gzip_chunks(
+    service: _Service,
+    report: str,
+    tmp_path: Path,
+) -> None:
+    """The compressed report arrives whole, chunk by chunk, with the token.
+
+    Args:
+        service: In-memory results service.
+        report: Report file.
+        tmp_path: Temporary directory for the upload state.
+    """
+    state = str(tmp_path / "uploads.json")
+
+    result = push_report(
+        report,
+        ENDPOINT,
+        token="secret",
+        chunk_size=1024,
+        state_file=state,
+    )
+
+    sent = bytes(service.uploads["u1"])
+    assert_that(gzip.decompress(sent)).is_equal_to(Path(report).read_bytes())
+    assert_that(service.created).contains_entry(
+        {"filename": "lint.json"},
+        {"size": len(sent)},
+        {"content_encoding": "gzip"},
+    )
+    assert_that(result.report_id).is_equal_to("r-42")
+    assert_that(result.chunks).is_greater_than(1)
+    assert_that(result.resumed_from).is_equal_to(0)
+    puts = [headers for method, _, headers in service.requests if method == "PUT"]
+    assert_that(puts[0]["Content-Range"]).is_equal_to(f"bytes 0-1023/{len(sent)}")
+    assert_that({h["Authorization"] for h in puts}).is_equal_to({"Bearer secret"})
+    assert_that(json.loads(Path(state).read_text())).is_empty()
+
+
+def test_push_report_resumes_interrupted_upload(
+    service: _Service,
+    report: str,
+    tmp_path: Path,
+) -> None:
+    """A second run continues from the bytes the service already holds.
+
+    Args:
+        service: In-memory results service.
+        report: Report file.
+        tmp_path: Temporary directory for the upload state.
+    """
+    state = str(tmp_path / "uploads.json")
+    service.drop_puts = True
+    with pytest.raises(ReportUploadError, match="after 5 attempts"):
+        push_report(report, ENDPOINT, chunk_size=1024, state_file=state)
+    service.drop_puts = False
+
+    result = push_report(report, ENDPOINT, chunk_size=1024, state_file=state)
+
+    assert_that(result.resumed_from).is_equal_to(1024)
+    assert_that(gzip.decompress(bytes(service.uploads["u1"]))).is_equal_to(
+        Path(report).read_bytes(),
+    )
+
+
+def test_push_report_retries_with_backoff(
+    service: _Service,
+    report: str,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Transient failures are retried, honouring Retry-After.
+
+    Args:
+        service: In-memory results service.
+        report: Report file.
+        tmp_path: Temporary directory for the upload state.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    delays: list[float] = []
+    monkeypatch.setattr(report_upload, "sleep", delays.append)
+    service.fail_next = [
+        httpx.TransportError("timed out"),
+        _Response(503, {}),
+        _Response(429, {}, {"Retry-After": "7"}),
+    ]
+
+    push_report(report, ENDPOINT, state_file=str(tmp_path / "uploads.json"))
+
+    assert_that(delays).is_equal_to([1.0, 2.0, 7.0])
+
+
+def test_push_report_rejected(
+    service: _Service,
+    report: str,
+    tmp_path: Path,
+) -> None:
+    """A client error is reported without retrying.
+
+    Args:
+        service: In-memory results service.
+        report: Report file.
+        tmp_path: Temporary directory for the upload state.
+    """
+    service.fail_next = [_Response(401, {"error": "bad token"})]
+
+    with pytest.raises(ReportUploadError, match="HTTP 401: .*bad token"):
+        push_report(report, ENDPOINT, state_file=str(tmp_path / "uploads.json"))
+    assert_that(service.requests).is_length(1)
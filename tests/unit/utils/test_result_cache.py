@@ -0,0 +1,265 @@
+"""Tests for the per-file result cache."""
+
+from __future__ import annotations
+
+import json
+import os
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.cache_backends import LocalCacheBackend
+from lintro.utils.result_cache import (
+    ResultCache,
+    check_with_cache,
+    deserialize_issue,
+    serialize_issue,
+)
+
+
+@pytest.fixture
+def workspace(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """Create a project with two Python files and enter it.
+
+    Args:
+        tmp_path: Temporary directory fixture.
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        Path: Project directory.
+    """
+    project = tmp_path / "project"
+    project.mkdir()
+    (project / "a.py").write_text("import os\n")
+    (project / "b.py").write_text("x = 1\n")
+    monkeypatch.chdir(project)
+    return project
+
+
+def _cache(root: Path, remote: LocalCacheBackend | None = None) -> ResultCache:
+    """Create a ruff cache in a directory.
+
+    Args:
+        root: Directory of the local store.
+        remote: Store standing in for the remote one, if any.
+
+    Returns:
+        ResultCache: Cache of ruff results.
+    """
+    return ResultCache(
+        tool_name="ruff",
+        namespace="ns",
+        local=LocalCacheBackend(root / "local"),
+        remote=remote,
+    )
+
+
+def _result(*issues: RuffIssue) -> ToolResult:
+    """Build a check result.
+
+    Args:
+        *issues: Issues reported.
+
+    Returns:
+        ToolResult: Ruff result with the issues.
+    """
+    return ToolResult(
+        name="ruff",
+        success=not issues,
+        issues_count=len(issues),
+        issues=list(issues),
+    )
+
+
+def test_issue_round_trip() -> None:
+    """An issue survives serialization with its own class and fields."""
+    issue = RuffIssue(file="a.py", line=1, column=8, message="unused", code="F401")
+
+    restored = deserialize_issue(serialize_issue(issue, "src/a.py"))
+
+    assert_that(restored).is_instance_of(RuffIssue)
+    assert_that(restored.code).is_equal_to("F401")  # type: ignore[union-attr]
+    assert_that(restored.file).is_equal_to("src/a.py")  # type: ignore[union-attr]
+
+
+def test_deserialize_rejects_foreign_classes() -> None:
+    """Entries naming classes outside lintro are not loaded."""
+    data = {"type": "os:PathLike", "fields": {}}
+
+    assert_that(deserialize_issue(data)).is_none()
+
+
+def test_lookup_hits_after_store(workspace: Path, tmp_path: Path) -> None:
+    """Stored results are found again, for clean files too.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    files = [str(workspace / "a.py"), str(workspace / "b.py")]
+    cache = _cache(tmp_path)
+    cache.store(files, _result(RuffIssue(file="a.py", line=1, code="F401")))
+
+    issues, misses = cache.lookup(files)
+
+    assert_that(misses).is_empty()
+    assert_that([i.to_display_row()["code"] for i in issues]).is_equal_to(["F401"])
+
+
+def test_lookup_misses_changed_files(workspace: Path, tmp_path: Path) -> None:
+    """Editing a file invalidates its entry only.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    files = [str(workspace / "a.py"), str(workspace / "b.py")]
+    cache = _cache(tmp_path)
+    cache.store(files, _result())
+    (workspace / "b.py").write_text("x = 2\n")
+
+    _, misses = cache.lookup(files)
+
+    assert_that(misses).is_equal_to([str(workspace / "b.py")])
+
+
+def test_lookup_reads_through_to_remote(workspace: Path, tmp_path: Path) -> None:
+    """Remote hits are used and kept in the local store.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    files = [str(workspace / "a.py")]
+    remote = LocalCacheBackend(tmp_path / "remote")
+    writer = _cache(tmp_path / "ci", remote=remote)
+    writer.store(files, _result())
+    writer.flush()
+
+    reader = _cache(tmp_path, remote=remote)
+    _, misses = reader.lookup(files)
+
+    assert_that(misses).is_empty()
+    assert_that(list((tmp_path / "local").rglob("*.json"))).is_length(1)
+
+
+def test_store_skips_unattributed_issues(workspace: Path, tmp_path: Path) -> None:
+    """Results with an issue outside the checked files are not cached.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    files = [str(workspace / "a.py")]
+    cache = _cache(tmp_path)
+    cache.store(files, _result(RuffIssue(file="other.py", line=1)))
+
+    _, misses = cache.lookup(files)
+
+    assert_that(misses).is_equal_to(files)
+
+
+def test_store_skips_failed_runs(workspace: Path, tmp_path: Path) -> None:
+    """Results of a tool that could not run are not cached.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    files = [str(workspace / "a.py")]
+    cache = _cache(tmp_path)
+    failed = _result()
+    failed.error_code = "LINTRO007"
+    cache.store(files, failed)
+
+    _, misses = cache.lookup(files)
+
+    assert_that(misses).is_equal_to(files)
+
+
+def test_check_with_cache_only_checks_misses(
+    workspace: Path,
+    tmp_path: Path,
+) -> None:
+    """Only files without an entry reach the tool; cached issues are merged.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    a_file, b_file = str(workspace / "a.py"), str(workspace / "b.py")
+    cache = _cache(tmp_path)
+    cache.store([a_file], _result(RuffIssue(file="a.py", line=1, code="F401")))
+    tool = MagicMock()
+    tool.name = "ruff"
+    tool.result_cache = cache
+    tool._discover_files.return_value = [a_file, b_file]
+    tool.check.return_value = _result(RuffIssue(file="b.py", line=1, code="E501"))
+
+    result = check_with_cache(tool, ["."], {})
+
+    tool.check.assert_called_once_with([b_file], {})
+    assert_that(result.issues_count).is_equal_to(2)
+    assert_that(result.success).is_false()
+    _, misses = cache.lookup([a_file, b_file])
+    assert_that(misses).is_empty()
+
+
+def test_check_with_cache_skips_tool_on_full_hit(
+    workspace: Path,
+    tmp_path: Path,
+) -> None:
+    """A tool whose files are all cached does not run.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    files = [str(workspace / "a.py"), str(workspace / "b.py")]
+    cache = _cache(tmp_path)
+    cache.store(files, _result())
+    tool = MagicMock()
+    tool.name = "ruff"
+    tool.result_cache = cache
+    tool._discover_files.return_value = files
+
+    result = check_with_cache(tool, ["."], {})
+
+    tool.check.assert_not_called()
+    assert_that(result.success).is_true()
+    assert_that(result.issues_count).is_equal_to(0)
+
+
+def test_check_with_cache_without_cache_runs_tool() -> None:
+    """Tools without a cache are checked as usual."""
+    tool = MagicMock()
+    tool.result_cache = None
+    tool.check.return_value = _result()
+
+    check_with_cache(tool, ["."], {})
+
+    tool.check.assert_called_once_with(["."], {})
+    tool._discover_files.assert_not_called()
+
+
+def test_entries_store_relative_paths(workspace: Path, tmp_path: Path) -> None:
+    """Entries hold workspace-relative paths so checkouts can share them.
+
+    Args:
+        workspace: Project directory.
+        tmp_path: Temporary directory fixture.
+    """
+    file = str(workspace / "a.py")
+    cache = _cache(tmp_path)
+    cache.store([file], _result(RuffIssue(file=file, line=1)))
+
+    entry = next((tmp_path / "local").rglob("*.json"))
+    data = json.loads(entry.read_text())
+
+    assert_that(data["path"]).is_equal_to("a.py")
+    assert_that(data["issues"][0]["fields"]["file"]).is_equal_to("a.py")
+    assert_that(os.path.isabs(data["path"])).is_false()
@@ -0,0 +1,146 @@
+"""Tests for turning report issues into review comments."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.utils.review_comments import (
+    ExistingComment,
+    build_review_comments,
+    comment_key,
+    diff_line_map,
+    plan_review_sync,
+)
+
+PATCH = """@@ -1,4 +1,5 @@
+ import os
+-import sys
++import json
++import re
+ 
+ def main():
+@@ -20,2 +21,3 @@ def main():
+     run()
++    exit()
+\\ No newline at end of file
+"""
+
+
+def _record(
+    path: str,
+    line: int,
+    code: str = "F401",
+    message: str = "unused import",
+) -> dict[str, Any]:
+    """Build an issue record as read from a JSON report.
+
+    Args:
+        path: File of the issue.
+        line: Line of the issue.
+        code: Rule code.
+        message: Issue message.
+
+    Returns:
+        dict[str, Any]: The record.
+    """
+    return {
+        "tool": "ruff",
+        "path": path,
+        "line": line,
+        "code": code,
+        "message": message,
+        "severity": SeverityLevel.ERROR,
+    }
+
+
+def test_diff_line_map_tracks_new_and_old_lines() -> None:
+    """Added lines map to None and context lines to their old line."""
+    lines = diff_line_map(PATCH)
+
+    assert_that(lines).is_equal_to(
+        {1: 1, 2: None, 3: None, 4: 3, 5: 4, 21: 20, 22: None},
+    )
+
+
+def test_diff_line_map_without_patch() -> None:
+    """Binary or oversized files have no patch and no commentable lines."""
+    assert_that(diff_line_map(None)).is_empty()
+
+
+def test_build_review_comments_groups_issues_per_line() -> None:
+    """Issues on one diff line share a comment; others are counted outside."""
+    records = [
+        _record("app.py", 2),
+        _record("app.py", 2, code="E501", message="line too long"),
+        _record("app.py", 10),
+        _record("other.py", 1),
+    ]
+
+    comments, outside = build_review_comments(
+        records,
+        {"app.py": diff_line_map(PATCH)},
+    )
+
+    assert_that(outside).is_equal_to(2)
+    assert_that(comments).is_length(1)
+    comment = comments[0]
+    assert_that(comment.key).is_equal_to("app.py:2")
+    assert_that(comment.old_line).is_none()
+    assert_that(comment.body).is_equal_to(
+        "<!-- lintro-review: app.py:2 -->\n"
+        "- **ruff** `F401` (error): unused import\n"
+        "- **ruff** `E501` (error): line too long",
+    )
+
+
+def test_build_review_comments_resolves_paths_against_root(tmp_path: Path) -> None:
+    """Absolute report paths are matched relative to the repository root.
+
+    Args:
+        tmp_path: Temporary directory used as the repository root.
+    """
+    records = [_record(str(tmp_path / "src" / "app.py"), 4)]
+
+    comments, _ = build_review_comments(
+        records,
+        {"src/app.py": diff_line_map(PATCH)},
+        root=tmp_path,
+    )
+
+    assert_that([c.key for c in comments]).is_equal_to(["src/app.py:4"])
+    assert_that(comments[0].old_line).is_equal_to(3)
+
+
+def test_comment_key_ignores_foreign_comments() -> None:
+    """Only bodies with the lintro marker have a key."""
+    assert_that(comment_key("<!-- lintro-review: a.py:3 -->\n- x")).is_equal_to(
+        "a.py:3",
+    )
+    assert_that(comment_key("Looks good to me")).is_none()
+
+
+def test_plan_review_sync() -> None:
+    """New, changed and stale comments are told apart from human ones."""
+    comments, _ = build_review_comments(
+        [_record("app.py", 2), _record("app.py", 3), _record("app.py", 4)],
+        {"app.py": diff_line_map(PATCH)},
+    )
+    existing = [
+        ExistingComment("1", comments[0].body),
+        ExistingComment("2", "<!-- lintro-review: app.py:3 -->\n- old issue"),
+        ExistingComment("3", "<!-- lintro-review: app.py:21 -->\n- fixed"),
+        ExistingComment("4", "Please rename this"),
+    ]
+
+    plan = plan_review_sync(comments, existing)
+
+    assert_that(plan.unchanged).is_equal_to(1)
+    assert_that([c.key for c in plan.create]).is_equal_to(["app.py:4"])
+    assert_that([(e.comment_id, c.key) for e, c in plan.update]).is_equal_to(
+        [("2", "app.py:3")],
+    )
+    assert_that([e.comment_id for e in plan.delete]).is_equal_to(["3"])
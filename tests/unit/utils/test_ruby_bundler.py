@@ -0,0 +1,114 @@
+"""Unit tests for Bundler detection utilities."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from unittest.mock import patch
+
+from assertpy import assert_that
+
+from lintro.utils.ruby_bundler import (
+    find_gemfile,
+    get_bundled_gem_dir,
+    get_locked_version,
+)
+
+LOCKFILE = """GEM
+  remote: https://rubygems.org/
+  specs:
+    ast (2.4.3)
+    rubocop (1.81.1)
+      json (~> 2.3)
+      parser (>= 3.3.0.2)
+    rubocop-rails (2.33.4)
+      rubocop (>= 1.75.0)
+
+DEPENDENCIES
+  rubocop (~> 1.81)
+  rubocop-rails
+
+BUNDLED WITH
+   2.7.2
+"""
+
+
+def _project(tmp_path: Path) -> Path:
+    """Create a bundled Ruby project with a nested source directory.
+
+    Args:
+        tmp_path: Directory to create the project in.
+
+    Returns:
+        Path: The nested ``lib`` directory.
+    """
+    (tmp_path / "Gemfile").write_text('gem "rubocop"\n')
+    (tmp_path / "Gemfile.lock").write_text(LOCKFILE)
+    lib = tmp_path / "lib"
+    lib.mkdir()
+    return lib
+
+
+def test_find_gemfile_walks_up(tmp_path: Path) -> None:
+    """The nearest Gemfile above the directory is found.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    lib = _project(tmp_path)
+
+    assert_that(find_gemfile(lib)).is_equal_to((tmp_path / "Gemfile").resolve())
+
+
+def test_find_gemfile_supports_gems_rb(tmp_path: Path) -> None:
+    """Projects using gems.rb are detected too.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "gems.rb").write_text('gem "rubocop"\n')
+
+    assert_that(find_gemfile(tmp_path)).is_equal_to((tmp_path / "gems.rb").resolve())
+
+
+def test_get_locked_version(tmp_path: Path) -> None:
+    """Only resolved specs count, not dependency constraints.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    _project(tmp_path)
+    gemfile = tmp_path / "Gemfile"
+
+    assert_that(get_locked_version(gemfile, "rubocop")).is_equal_to("1.81.1")
+    assert_that(get_locked_version(gemfile, "rubocop-rails")).is_equal_to(
+        "2.33.4",
+    )
+    assert_that(get_locked_version(gemfile, "json")).is_none()
+
+
+def test_get_locked_version_without_lockfile(tmp_path: Path) -> None:
+    """A Gemfile that was never installed locks nothing.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "Gemfile").write_text('gem "rubocop"\n')
+
+    assert_that(get_locked_version(tmp_path / "Gemfile", "rubocop")).is_none()
+
+
+def test_get_bundled_gem_dir(tmp_path: Path) -> None:
+    """A locked gem runs from the Gemfile directory when bundle is installed.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    lib = _project(tmp_path)
+
+    with patch("lintro.utils.ruby_bundler.shutil.which", return_value="/bin/bundle"):
+        assert_that(get_bundled_gem_dir(lib, "rubocop")).is_equal_to(
+            tmp_path.resolve(),
+        )
+        assert_that(get_bundled_gem_dir(lib, "standard")).is_none()
+    with patch("lintro.utils.ruby_bundler.shutil.which", return_value=None):
+        assert_that(get_bundled_gem_dir(lib, "rubocop")).is_none()
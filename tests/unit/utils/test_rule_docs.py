@@ -0,0 +1,150 @@
+"""Tests for lintro.utils.rule_docs."""
+
+from __future__ import annotations
+
+import json
+
+import pytest
+from assertpy import assert_that
+
+from lintro.utils import rule_docs
+from lintro.utils.rule_docs import explain_rule, split_rule
+
+RUFF_RULE = {
+    "name": "line-too-long",
+    "code": "E501",
+    "linter": "pycodestyle",
+    "summary": "Line too long ({width} > {limit})",
+    "explanation": "## What it does\nChecks for lines that exceed the limit.\n",
+}
+CLIPPY_EXPLAIN = (
+    "### What it does\nChecks for return statements at the end of a block.\n\n"
+    "### Why is this bad?\nRemoving the `return` is more rusty.\n"
+)
+
+
+@pytest.fixture
+def commands(monkeypatch: pytest.MonkeyPatch) -> list[list[str]]:
+    """Answer ruff and clippy documentation commands without running them.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+
+    Returns:
+        list[list[str]]: Commands that were run.
+    """
+    calls: list[list[str]] = []
+
+    def run(command: list[str]) -> str | None:
+        """Answer one documentation command.
+
+        Args:
+            command: Command and arguments.
+
+        Returns:
+            str | None: Output for E501 and needless_return, else None.
+        """
+        calls.append(command)
+        if command[:3] == ["ruff", "rule", "E501"]:
+            return json.dumps(RUFF_RULE)
+        if command[:3] == ["cargo", "clippy", "--explain"] and (
+            command[3] == "needless_return"
+        ):
+            return CLIPPY_EXPLAIN
+        return None
+
+    monkeypatch.setattr(rule_docs, "_run", run)
+    return calls
+
+
+@pytest.mark.parametrize(
+    ("rule", "expected"),
+    [
+        ("clippy::needless_return", ("clippy", "needless_return")),
+        ("ruff:E501", ("ruff", "E501")),
+        ("E501", (None, "E501")),
+        ("no-such-tool:rule", (None, "no-such-tool:rule")),
+    ],
+)
+def test_split_rule(rule: str, expected: tuple[str | None, str]) -> None:
+    """Tool prefixes are recognized in both spellings.
+
+    Args:
+        rule: Rule as given.
+        expected: Tool and code.
+    """
+    assert_that(split_rule(rule)).is_equal_to(expected)
+
+
+def test_explain_rule_asks_ruff(commands: list[list[str]]) -> None:
+    """Ruff describes its own rules, with a link to the rule page.
+
+    Args:
+        commands: Commands that were run.
+    """
+    doc = explain_rule("e501")
+
+    assert doc is not None
+    assert_that(doc.source).is_equal_to("tool")
+    assert_that(doc.url).is_equal_to("https://docs.astral.sh/ruff/rules/line-too-long/")
+    assert_that(doc.explanation).starts_with("## What it does")
+    assert_that(commands).is_length(1)
+
+
+def test_explain_rule_asks_clippy(commands: list[list[str]]) -> None:
+    """Clippy lints are explained by cargo clippy --explain.
+
+    Args:
+        commands: Commands that were run.
+    """
+    doc = explain_rule("clippy::needless_return")
+
+    assert doc is not None
+    assert_that(doc.summary).is_equal_to(
+        "Checks for return statements at the end of a block.",
+    )
+    assert_that(doc.url).ends_with("index.html#needless_return")
+
+
+def test_explain_rule_falls_back_to_bundled(commands: list[list[str]]) -> None:
+    """Without the tool, the bundled summary is used.
+
+    Args:
+        commands: Commands that were run.
+    """
+    doc = explain_rule("F401")
+
+    assert doc is not None
+    assert_that(doc.source).is_equal_to("bundled")
+    assert_that(doc.summary).is_equal_to("Module imported but unused")
+    assert_that(commands).is_equal_to(
+        [["ruff", "rule", "F401", "--output-format", "json"]],
+    )
+
+
+def test_explain_rule_offline_runs_nothing(commands: list[list[str]]) -> None:
+    """--offline only reads the bundled metadata.
+
+    Args:
+        commands: Commands that were run.
+    """
+    doc = explain_rule("needless_return", offline=True)
+
+    assert doc is not None
+    assert_that(doc.tool).is_equal_to("clippy")
+    assert_that(commands).is_empty()
+
+
+def test_explain_rule_url_template_needs_tool(commands: list[list[str]]) -> None:
+    """Rules without bundled summaries are linked when the tool is named.
+
+    Args:
+        commands: Commands that were run.
+    """
+    assert_that(explain_rule("SC2086")).is_none()
+
+    doc = explain_rule("SC2086", tool="shellcheck")
+
+    assert doc is not None
+    assert_that(doc.summary).is_empty()
+    assert_that(doc.url).is_equal_to("https://www.shellcheck.net/wiki/SC2086")
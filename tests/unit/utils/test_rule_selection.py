@@ -0,0 +1,108 @@
+"""Tests for lintro.utils.rule_selection."""
+
+from __future__ import annotations
+
+import pytest
+from assertpy import assert_that
+
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.rule_selection import (
+    RuleSelection,
+    apply_rule_selection,
+    parse_rule_pattern,
+)
+
+
+@pytest.mark.parametrize(
+    ("text", "tool", "code"),
+    [
+        ("ruff:E5*", "ruff", "e5*"),
+        ("clippy::*", "clippy", "*"),
+        ("E501", "*", "e501"),
+        ("*:W*", "*", "w*"),
+        ("whitespace:WS001", "whitespace", "ws001"),
+        ("license-header:LH001", "license_header", "lh001"),
+        ("no-such-tool:rule", "*", "no-such-tool:rule"),
+    ],
+)
+def test_parse_rule_pattern(text: str, tool: str, code: str) -> None:
+    """Tool prefixes are recognized for known tools and globs.
+
+    Args:
+        text: Pattern as given.
+        tool: Expected tool glob.
+        code: Expected code glob.
+    """
+    pattern = parse_rule_pattern(text)
+
+    assert_that((pattern.tool, pattern.code)).is_equal_to((tool, code))
+
+
+def test_parse_rule_pattern_needs_a_rule() -> None:
+    """A tool without a rule is rejected with a hint."""
+    with pytest.raises(ValueError, match="use 'ruff:\\*'"):
+        parse_rule_pattern("ruff:")
+
+
+@pytest.mark.parametrize(
+    ("select", "ignore", "tool", "code", "expected"),
+    [
+        (["ruff:E5*"], [], "ruff", "E501", True),
+        (["ruff:E5*"], [], "ruff", "F401", False),
+        (["ruff:E5*"], [], "flake8", "E501", False),
+        (["clippy::*"], [], "clippy", "clippy::needless_return", True),
+        (["clippy::needless_return"], [], "clippy", "clippy::needless_return", True),
+        ([], ["E501"], "ruff", "E501", False),
+        ([], ["E501"], "ruff", "E502", True),
+        (["ruff:*"], ["ruff:E501,ruff:W*"], "ruff", "W291", False),
+        (["ruff:*"], ["ruff:E501"], "ruff", "", True),
+    ],
+)
+def test_rule_selection_matches(
+    select: list[str],
+    ignore: list[str],
+    tool: str,
+    code: str,
+    expected: bool,
+) -> None:
+    """Selected rules are kept unless they are also ignored.
+
+    Args:
+        select: --select values.
+        ignore: --ignore values.
+        tool: Tool that reported the issue.
+        code: Rule code of the issue.
+        expected: Whether the issue is kept.
+    """
+    selection = RuleSelection.from_patterns(select, ignore)
+
+    assert selection is not None
+    assert_that(selection.matches(tool, code)).is_equal_to(expected)
+
+
+def test_rule_selection_without_patterns_is_none() -> None:
+    """No patterns means no selection at all."""
+    assert_that(RuleSelection.from_patterns((), (" ",))).is_none()
+
+
+def test_apply_rule_selection_updates_count_and_success() -> None:
+    """Dropped issues are not counted; a result left clean succeeds."""
+    result = ToolResult(
+        name="ruff",
+        success=False,
+        issues=[
+            RuffIssue(file="a.py", line=1, code="E501"),
+            RuffIssue(file="a.py", line=2, code="F401"),
+        ],
+        issues_count=2,
+    )
+
+    focused = apply_rule_selection(result, RuleSelection.from_patterns(["E5*"]))
+    cleared = apply_rule_selection(result, RuleSelection.from_patterns(["W*"]))
+
+    assert_that([i.code for i in focused.issues or []]).is_equal_to(["E501"])
+    assert_that(focused.issues_count).is_equal_to(1)
+    assert_that(focused.success).is_false()
+    assert_that(cleared.issues_count).is_equal_to(0)
+    assert_that(cleared.success).is_true()
@@ -0,0 +1,197 @@
+"""Tests for lintro.utils.rule_usage."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+import lintro.utils.execution.run_history as run_history
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.run_history import record_run
+from lintro.utils.rule_usage import (
+    RecordedIssue,
+    RunRecord,
+    analyze_rule_usage,
+    load_run_history,
+    load_stored_history,
+    rule_matches,
+)
+
+HEADER = "tool,issues_count,file,line,code,message\n"
+
+
+class _NoVcs:
+    """VCS provider outside any repository."""
+
+    def head_commit(self) -> None:
+        """Return no commit.
+
+        Returns:
+            None: There is no checked-out commit.
+        """
+        return None
+
+
+def _write_run(base: Path, name: str, rows: list[str]) -> None:
+    """Write a run directory with a summary.csv.
+
+    Args:
+        base: Directory holding the runs.
+        name: Run directory name.
+        rows: CSV rows without the header.
+    """
+    run_dir = base / name
+    run_dir.mkdir(parents=True)
+    (run_dir / "summary.csv").write_text(HEADER + "\n".join(rows) + "\n")
+
+
+def test_load_run_history_reads_newest_runs_first(tmp_path: Path) -> None:
+    """Runs are read newest first, up to the limit, skipping unfinished ones.
+
+    Args:
+        tmp_path: Temporary directory path.
+    """
+    _write_run(tmp_path, "run-20250101-100000", ["ruff,0,,,,"])
+    _write_run(tmp_path, "run-20250102-100000", ["ruff,1,a.py,3,E501,Too long"])
+    _write_run(tmp_path, "run-20250103-100000", ["mypy,0,,,,", "ruff,0,,,,"])
+    (tmp_path / "run-20250104-100000").mkdir()
+
+    history = load_run_history(str(tmp_path), limit=2)
+
+    assert_that([run.name for run in history]).is_equal_to(
+        ["run-20250103-100000", "run-20250102-100000"],
+    )
+    assert_that(history[0].tools).is_equal_to(["mypy", "ruff"])
+    assert_that(history[0].issues).is_empty()
+    assert_that(history[1].issues).is_equal_to(
+        [RecordedIssue("ruff", "a.py", 3, "E501", "Too long")],
+    )
+
+
+def test_load_run_history_ignores_skipped_and_failed_tools(tmp_path: Path) -> None:
+    """Tools that were skipped or failed to run are not taken as clean.
+
+    Args:
+        tmp_path: Temporary directory path.
+    """
+    run_dir = tmp_path / "run-20250101-100000"
+    run_dir.mkdir()
+    (run_dir / "summary.csv").write_text(
+        "tool,issues_count,file,line,code,message,status\n"
+        "ruff,0,,,,,ok\n"
+        "clippy,0,,,,,skipped\n"
+        "mypy,0,,,,,failed\n",
+    )
+
+    history = load_run_history(str(tmp_path))
+
+    assert_that(history[0].tools).is_equal_to(["ruff"])
+
+
+def test_load_stored_history_reaches_past_pruned_runs(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """The history database yields rule codes of tools that ran, newest first.
+
+    Args:
+        tmp_path: Temporary directory path.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(run_history, "detect_vcs", lambda: _NoVcs())
+    database = tmp_path / "history.db"
+    issue = RuffIssue(file="a.py", line=1, column=1, code="E501", message="long")
+    record_run(
+        database,
+        [
+            ToolResult(name="ruff", success=False, issues_count=1, issues=[issue]),
+            ToolResult(name="mypy", success=False, issues_count=0),
+        ],
+        exit_code=1,
+    )
+    record_run(database, [ToolResult(name="ruff", success=True)], exit_code=0)
+
+    history = load_stored_history(str(database), limit=5)
+
+    assert_that([run.tools for run in history]).is_equal_to([["ruff"], ["ruff"]])
+    assert_that(history[0].issues).is_empty()
+    assert_that([issue.code for issue in history[1].issues]).is_equal_to(["E501"])
+    assert_that(load_stored_history(str(tmp_path / "missing.db"))).is_empty()
+
+
+def test_load_run_history_without_history(tmp_path: Path) -> None:
+    """A missing history directory yields no runs.
+
+    Args:
+        tmp_path: Temporary directory path.
+    """
+    assert_that(load_run_history(str(tmp_path / "missing"))).is_empty()
+
+
+@pytest.mark.parametrize(
+    ("tool", "rule", "code", "expected"),
+    [
+        ("ruff", "E", "E501", True),
+        ("ruff", "ALL", "D100", True),
+        ("ruff", "E501", "E502", False),
+        ("oxlint", "no-debugger", "eslint(no-debugger)", True),
+        ("oxlint", "react/jsx-key", "eslint-plugin-react(jsx-key)", True),
+        ("oxlint", "no-console", "eslint(no-debugger)", False),
+        ("mypy", "arg-type", "arg-type", True),
+    ],
+)
+def test_rule_matches(tool: str, rule: str, code: str, expected: bool) -> None:
+    """Configured rules match the codes they raise.
+
+    Args:
+        tool: Tool name.
+        rule: Configured rule.
+        code: Reported code.
+        expected: Whether the rule should match.
+    """
+    assert_that(rule_matches(tool, rule, code)).is_equal_to(expected)
+
+
+def test_analyze_rule_usage_finds_unused_configuration() -> None:
+    """Silent tools, unused rules and latest unused suppressions are reported."""
+    stale = RecordedIssue("ruff", "old.py", 1, "RUF100", "Unused `noqa` directive")
+    current = RecordedIssue("ruff", "a.py", 2, "RUF100", "Unused `noqa` directive")
+    history = [
+        RunRecord(
+            name="run-2",
+            tools=["ruff", "yamllint"],
+            issues=[current, RecordedIssue("ruff", "a.py", 9, "E501", "Too long")],
+        ),
+        RunRecord(
+            name="run-1",
+            tools=["ruff", "yamllint"],
+            issues=[stale, RecordedIssue("ruff", "b.py", 1, "F401", "Unused")],
+        ),
+    ]
+
+    report = analyze_rule_usage(
+        history,
+        {"ruff": ["E", "F", "B", "RUF"], "oxlint": ["no-debugger"]},
+    )
+
+    assert_that(report.runs).is_equal_to(2)
+    assert_that(report.silent_tools).is_equal_to([("yamllint", 2)])
+    assert_that(report.unused_rules).is_equal_to([("ruff", "B")])
+    assert_that(report.unused_suppressions).is_equal_to([current])
+    assert_that(report.is_clean).is_false()
+
+
+def test_analyze_rule_usage_reads_suppressions_from_latest_runs() -> None:
+    """Stored runs judge rules; suppressions come from the detailed runs."""
+    suppression = RecordedIssue("ruff", "a.py", 2, "RUF100", "Unused `noqa`")
+    stored = [RunRecord(name="history-7", tools=["ruff"])]
+    latest = [RunRecord(name="run-1", tools=["ruff"], issues=[suppression])]
+
+    report = analyze_rule_usage(stored, {"ruff": ["RUF"]}, latest=latest)
+
+    assert_that(report.runs).is_equal_to(1)
+    assert_that(report.unused_rules).is_equal_to([("ruff", "RUF")])
+    assert_that(report.unused_suppressions).is_equal_to([suppression])
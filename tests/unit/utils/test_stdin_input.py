@@ -0,0 +1,178 @@
+"""Unit tests for checking content given on stdin."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+from click.testing import CliRunner
+
+import lintro.cli_utils.commands.check as check_mod
+from lintro.cli_utils.commands.check import check_command
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.execution.path_normalization import normalize_issue_paths
+from lintro.utils.execution.tool_configuration import SkippedTool, ToolsToRunResult
+from lintro.utils.json_output import create_json_output
+from lintro.utils.stdin_input import (
+    STDIN_DIR_PREFIX,
+    WHOLE_PROJECT_REASON,
+    skip_whole_project_tools,
+    stdin_buffer,
+)
+
+
+def test_stdin_buffer_writes_copy_next_to_file(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """The copy keeps the file's name and is removed afterwards.
+
+    Args:
+        tmp_path: Temporary directory used as the project root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    monkeypatch.chdir(tmp_path)
+
+    with stdin_buffer(b"x = 1\r\n", "src/app.py") as buffer:
+        copy = buffer.copy
+        assert_that(copy.name).is_equal_to("app.py")
+        assert_that(copy.parent.parent).is_equal_to((tmp_path / "src").resolve())
+        assert_that(copy.parent.name).starts_with(STDIN_DIR_PREFIX)
+        assert_that(copy.read_bytes()).is_equal_to(b"x = 1\r\n")
+        assert_that(buffer.filename).is_equal_to("src/app.py")
+
+    assert_that(copy.parent.exists()).is_false()
+
+
+def test_stdin_buffer_needs_existing_directory(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A file in a missing directory cannot be stood in for.
+
+    Args:
+        tmp_path: Temporary directory used as the project root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.chdir(tmp_path)
+
+    with pytest.raises(ValueError, match="does not exist"):
+        with stdin_buffer(b"", "missing/app.py"):
+            pass
+
+
+def test_issues_in_copy_are_reported_under_file_name(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Issue paths of the copy become the file name, also in console JSON.
+
+    Args:
+        tmp_path: Temporary directory used as the project root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    monkeypatch.chdir(tmp_path)
+
+    with stdin_buffer(b"import os\n", "src/app.py") as buffer:
+        issue = RuffIssue(
+            file=str(buffer.copy),
+            line=1,
+            column=8,
+            code="F401",
+            message="`os` imported but unused",
+        )
+        result = ToolResult(name="ruff", success=False, issues_count=1, issues=[issue])
+        result = normalize_issue_paths(result, aliases=buffer.path_aliases)
+
+    data = create_json_output("check", [result], 1, 0, 0, 1, include_issues=True)
+
+    reported = data["results"][0]["issues"][0]
+    assert_that(reported).contains_entry(
+        {"file": "src/app.py"},
+        {"line": 1},
+        {"column": 8},
+        {"code": "F401"},
+    )
+
+
+def test_check_stdin_runs_on_copy(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """--stdin checks a copy of the input and defaults to JSON output.
+
+    Args:
+        tmp_path: Temporary directory used as the project root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    monkeypatch.chdir(tmp_path)
+    calls: dict[str, Any] = {}
+
+    def fake_run(**kwargs: Any) -> int:
+        """Record the executor arguments and the copy's contents.
+
+        Args:
+            **kwargs: Arguments passed to run_lint_tools_simple.
+
+        Returns:
+            int: Successful exit code.
+        """
+        calls.update(kwargs)
+        calls["content"] = Path(kwargs["paths"][0]).read_text()
+        return 0
+
+    monkeypatch.setattr(check_mod, "run_lint_tools_simple", fake_run)
+
+    result = CliRunner().invoke(
+        check_command,
+        ["--stdin", "--stdin-filename", "src/main.rs"],
+        input="fn main() {}\n",
+    )
+
+    assert_that(result.exit_code).is_equal_to(0)
+    assert_that(calls["content"]).is_equal_to("fn main() {}\n")
+    assert_that(calls["output_format"]).is_equal_to("json")
+    assert_that(calls["stdin"].filename).is_equal_to("src/main.rs")
+    assert_that(calls["stdin"].copy.exists()).is_false()
+
+
+def test_whole_project_tools_are_skipped() -> None:
+    """Tools that read the saved project cannot check a buffer."""
+    tools_result = ToolsToRunResult(to_run=["clippy", "ruff", "mypy"])
+
+    skip_whole_project_tools(tools_result)
+
+    assert_that(tools_result.to_run).is_equal_to(["ruff"])
+    assert_that(tools_result.skipped).is_equal_to(
+        [
+            SkippedTool(name="clippy", reason=WHOLE_PROJECT_REASON),
+            SkippedTool(name="mypy", reason=WHOLE_PROJECT_REASON),
+        ],
+    )
+
+
+@pytest.mark.parametrize(
+    "args",
+    [
+        ["--stdin"],
+        ["--stdin-filename", "a.py"],
+        ["--stdin", "--stdin-filename", "a.py", "."],
+    ],
+    ids=["no_filename", "no_stdin", "with_paths"],
+)
+def test_check_stdin_usage_errors(args: list[str]) -> None:
+    """--stdin needs a file name and replaces paths.
+
+    Args:
+        args: Command-line arguments.
+    """
+    result = CliRunner().invoke(check_command, args)
+
+    assert_that(result.exit_code).is_equal_to(2)
+    assert_that(result.output).contains("--stdin")
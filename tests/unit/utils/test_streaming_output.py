@@ -366,6 +366,29 @@ def test_result_to_dict_includes_fix_counts(mock_fix_result: ToolResult) -> None
     assert_that(data["fixed_issues_count"]).is_equal_to(3)
 
 
+def test_result_to_dict_includes_error_code() -> None:
+    """Result dict carries the error code of a tool that could not run."""
+    handler = StreamingResultHandler(output_format="json", action=Action.CHECK)
+    result = ToolResult(
+        name="test_tool",
+        success=False,
+        output="timed out",
+        error_code="LINTRO007",
+    )
+
+    assert_that(handler._result_to_dict(result)["error_code"]).is_equal_to(
+        "LINTRO007",
+    )
+
+
+def test_result_to_dict_includes_duration() -> None:
+    """Result dict carries the tool's monotonic duration when measured."""
+    handler = StreamingResultHandler(output_format="json", action=Action.CHECK)
+    result = ToolResult(name="test_tool", success=True, duration_ms=420)
+
+    assert_that(handler._result_to_dict(result)["duration_ms"]).is_equal_to(420)
+
+
 def test_create_streaming_handler_with_format() -> None:
     """Create handler with specified format."""
     handler = create_streaming_handler("json", Action.CHECK)
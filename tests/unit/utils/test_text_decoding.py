@@ -0,0 +1,89 @@
+"""Unit tests for text_decoding module."""
+
+from __future__ import annotations
+
+import codecs
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.utils.text_decoding import (
+    REPLACEMENT_CHARACTER,
+    decode_output,
+    read_output_file,
+)
+
+
+@pytest.mark.parametrize(
+    ("data", "encoding"),
+    [
+        (codecs.BOM_UTF8 + "café.py\n".encode(), "utf-8"),
+        (codecs.BOM_UTF16_LE + "café.py\n".encode("utf-16-le"), "utf-16-le"),
+        (codecs.BOM_UTF16_BE + "café.py\n".encode("utf-16-be"), "utf-16-be"),
+    ],
+)
+def test_decode_output_strips_byte_order_mark(data: bytes, encoding: str) -> None:
+    """Output starting with a byte order mark is decoded in its encoding.
+
+    Args:
+        data: Raw output.
+        encoding: Encoding the byte order mark announces.
+    """
+    decoded = decode_output(data)
+
+    assert_that(decoded.text).is_equal_to("café.py\n")
+    assert_that(decoded.encoding).is_equal_to(encoding)
+    assert_that(decoded.replaced).is_equal_to(0)
+
+
+def test_decode_output_translates_line_breaks() -> None:
+    """CRLF and CR line breaks become LF."""
+    decoded = decode_output(b"a.py:1\r\nb.py:2\rc.py:3\n")
+
+    assert_that(decoded.text).is_equal_to("a.py:1\nb.py:2\nc.py:3\n")
+
+
+def test_decode_output_falls_back_to_locale_encoding() -> None:
+    """Output that is not UTF-8 is decoded in the locale's encoding."""
+    with patch(
+        "lintro.utils.text_decoding._locale_encoding",
+        return_value="cp1252",
+    ):
+        decoded = decode_output("naïve.py".encode("cp1252"))
+
+    assert_that(decoded.text).is_equal_to("naïve.py")
+    assert_that(decoded.encoding).is_equal_to("cp1252")
+    assert_that(decoded.replaced).is_equal_to(0)
+
+
+def test_decode_output_replaces_undecodable_bytes() -> None:
+    """Bytes no candidate encoding decodes are replaced and counted."""
+    with patch("lintro.utils.text_decoding._locale_encoding", return_value=None):
+        decoded = decode_output(b"caf\xe9.py and caf\xe9.txt \xef\xbf\xbd")
+
+    assert_that(decoded.text).is_equal_to(
+        f"caf{REPLACEMENT_CHARACTER}.py and caf{REPLACEMENT_CHARACTER}.txt "
+        f"{REPLACEMENT_CHARACTER}",
+    )
+    assert_that(decoded.replaced).is_equal_to(2)
+
+
+def test_read_output_file_reports_undecodable_bytes(tmp_path: Path) -> None:
+    """Replaced bytes in a report file are reported with the file.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    report = tmp_path / "report.json"
+    report.write_bytes(b'[{"File": "caf\xe9.py"}]')
+
+    with (
+        patch("lintro.utils.text_decoding._locale_encoding", return_value=None),
+        patch("lintro.utils.text_decoding.report_undecodable") as mock_report,
+    ):
+        text = read_output_file(report, "gitleaks")
+
+    assert_that(text).contains(f"caf{REPLACEMENT_CHARACTER}.py")
+    mock_report.assert_called_once_with("gitleaks", 1, path=str(report))
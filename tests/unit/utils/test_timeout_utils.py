@@ -7,6 +7,7 @@ from unittest.mock import Mock
 import pytest
 from assertpy import assert_that
 
+from lintro.plugins.subprocess_executor import DEFAULT_MAX_OUTPUT_CHARS
 from lintro.tools.core.timeout_utils import (
     create_timeout_result,
     get_timeout_value,
@@ -95,6 +96,7 @@ def test_create_timeout_result() -> None:
     assert_that(result.issues).is_empty()
     assert_that(result.timed_out).is_true()
     assert_that(result.timeout_seconds).is_equal_to(30)
+    assert_that(result.error_code).is_equal_to("LINTRO007")
 
 
 def test_run_subprocess_with_timeout_success() -> None:
@@ -113,6 +115,30 @@ def test_run_subprocess_with_timeout_success() -> None:
     )
 
 
+def test_run_subprocess_with_timeout_streams_to_line_handler() -> None:
+    """Test that a line handler switches to a streaming run with capped output."""
+    tool = MockTool()
+    streaming = Mock(return_value=(True, "output"))
+    tool._run_subprocess_streaming = streaming  # type: ignore[attr-defined]
+    handler = Mock()
+
+    success, output = run_subprocess_with_timeout(
+        tool,
+        ["cargo", "clippy"],
+        line_handler=handler,
+    )
+
+    assert_that(success).is_true()
+    assert_that(output).is_equal_to("output")
+    streaming.assert_called_once_with(
+        cmd=["cargo", "clippy"],
+        timeout=None,
+        cwd=None,
+        line_handler=handler,
+        max_output_chars=DEFAULT_MAX_OUTPUT_CHARS,
+    )
+
+
 def test_run_subprocess_with_timeout_exception() -> None:
     """Test subprocess timeout exception handling."""
     tool = MockTool()
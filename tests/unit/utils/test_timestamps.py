@@ -0,0 +1,98 @@
+"""Tests for lintro.utils.timestamps module."""
+
+from __future__ import annotations
+
+import datetime
+import time
+
+import pytest
+from assertpy import assert_that
+
+from lintro.config.config_loader import build_config_from_data
+from lintro.utils.timestamps import (
+    elapsed_ms,
+    parse_timestamp,
+    render_timestamp,
+    utc_timestamp,
+)
+
+MOMENT = datetime.datetime(2026, 10, 15, 9, 30, 12, 345678, tzinfo=datetime.UTC)
+
+
+def test_utc_timestamp_is_rfc3339_utc_with_seconds() -> None:
+    """Timestamps are UTC with a Z suffix and whole seconds."""
+    assert_that(utc_timestamp(MOMENT)).is_equal_to("2026-10-15T09:30:12Z")
+
+
+def test_utc_timestamp_converts_other_timezones() -> None:
+    """Times in other timezones are converted to UTC."""
+    plus_two = datetime.timezone(datetime.timedelta(hours=2))
+    moment = datetime.datetime(2026, 10, 15, 11, 30, 12, tzinfo=plus_two)
+
+    assert_that(utc_timestamp(moment)).is_equal_to("2026-10-15T09:30:12Z")
+
+
+def test_utc_timestamp_defaults_to_now() -> None:
+    """Without a time the current time is formatted."""
+    stamp = utc_timestamp()
+
+    assert_that(stamp).ends_with("Z")
+    assert_that(parse_timestamp(stamp).tzinfo).is_equal_to(datetime.UTC)
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        "2026-10-15T09:30:12Z",
+        "2026-10-15T09:30:12+00:00",
+        "2026-10-15T09:30:12.345678+00:00",
+        "2026-10-15T09:30:12",
+    ],
+)
+def test_parse_timestamp_accepts_current_and_earlier_forms(value: str) -> None:
+    """Stored timestamps parse to the same aware UTC time.
+
+    Args:
+        value: Timestamp as written by this or an earlier release.
+    """
+    parsed = parse_timestamp(value)
+
+    assert_that(parsed.replace(microsecond=0)).is_equal_to(
+        MOMENT.replace(microsecond=0),
+    )
+
+
+def test_render_timestamp_in_utc() -> None:
+    """UTC rendering does not depend on the locale."""
+    assert_that(render_timestamp(MOMENT)).is_equal_to("2026-10-15 09:30:12 UTC")
+
+
+def test_render_timestamp_in_local_time(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Local rendering shows the local time with its UTC offset.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    if not hasattr(time, "tzset"):
+        pytest.skip("time.tzset is unavailable on this platform")
+    monkeypatch.setenv("TZ", "UTC-02:00")
+    time.tzset()
+    try:
+        rendered = render_timestamp(MOMENT, local=True)
+    finally:
+        monkeypatch.undo()
+        time.tzset()
+
+    assert_that(rendered).is_equal_to("2026-10-15 11:30:12 +02:00")
+
+
+def test_elapsed_ms_counts_from_monotonic_reading() -> None:
+    """Elapsed time is measured from a monotonic clock reading."""
+    assert_that(elapsed_ms(time.monotonic() - 1.5)).is_between(1500, 2500)
+
+
+def test_output_local_time_is_read_from_config() -> None:
+    """The output.local_time setting reaches the loaded config."""
+    config = build_config_from_data({"output": {"local_time": True}})
+
+    assert_that(config.output.local_time).is_true()
@@ -0,0 +1,126 @@
+"""Tests for the timing profile of --profile-timing."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from assertpy import assert_that
+
+from lintro.utils.timing_profile import (
+    TimingProfile,
+    ToolTiming,
+    measure_tool,
+    print_timing_profile,
+    record_invocation,
+)
+
+
+class _Clock:
+    """Manually advanced monotonic clock."""
+
+    def __init__(self) -> None:
+        """Start at zero."""
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        """Return the current reading.
+
+        Returns:
+            float: Seconds since the start.
+        """
+        return self.now
+
+
+def test_phases_split_the_tool_duration(tmp_path: Path) -> None:
+    """Startup, execution and parsing add up to the tool's time.
+
+    Args:
+        tmp_path: Temporary directory holding the checked files.
+    """
+    (tmp_path / "a.py").write_text("")
+    clock = _Clock()
+    profile = TimingProfile(clock=clock)
+
+    with profile.measure("ruff"):
+        clock.now = 0.2
+        record_invocation(["ruff", "check", "a.py"], str(tmp_path), 0.1, 100)
+        clock.now = 0.5
+    profile.add_formatting("ruff", 30)
+
+    timing = profile.tools["ruff"]
+    assert_that(timing.total_ms).is_equal_to(500)
+    assert_that(timing.startup_ms).is_equal_to(100)
+    assert_that(timing.execution_ms).is_equal_to(100)
+    assert_that(timing.parsing_ms).is_equal_to(300)
+    assert_that(timing.formatting_ms).is_equal_to(30)
+    assert_that(timing.chunks[0].files).is_equal_to(("a.py",))
+
+
+def test_tool_without_subprocess_counts_as_execution() -> None:
+    """Native tools spend their whole time executing."""
+    timing = ToolTiming("native", total_ms=80)
+
+    assert_that(timing.startup_ms).is_equal_to(0)
+    assert_that(timing.execution_ms).is_equal_to(80)
+    assert_that(timing.parsing_ms).is_equal_to(0)
+
+
+def test_subprocesses_outside_a_tool_are_ignored() -> None:
+    """Invocations are only recorded while a tool is measured."""
+    profile = TimingProfile()
+
+    record_invocation(["ruff", "--version"], None, 0.0, 10)
+    with measure_tool(None, "ruff"):
+        record_invocation(["ruff", "check"], None, 0.0, 10)
+
+    assert_that(profile.tools).is_empty()
+
+
+def test_slowest_files_share_batched_chunks(tmp_path: Path) -> None:
+    """A chunk's time is split among its files and marked as estimated.
+
+    Args:
+        tmp_path: Temporary directory holding the checked files.
+    """
+    for name in ("a.py", "b.py", "c.sh"):
+        (tmp_path / name).write_text("")
+    cwd = str(tmp_path)
+    profile = TimingProfile()
+
+    with profile.measure("ruff"):
+        record_invocation(["ruff", "--fix", "a.py", "b.py", "src"], cwd, 0.0, 300)
+    with profile.measure("shellcheck"):
+        record_invocation(["shellcheck", "c.sh"], cwd, 0.0, 200)
+
+    files = profile.slowest_files()
+    assert_that([(f.path, f.duration_ms, f.estimated) for f in files]).is_equal_to(
+        [("c.sh", 200, False), ("a.py", 150, True), ("b.py", 150, True)],
+    )
+
+
+def test_to_dict_orders_tools_by_time() -> None:
+    """The JSON profile lists the slowest tool first."""
+    clock = _Clock()
+    profile = TimingProfile(clock=clock)
+    with profile.measure("ruff"):
+        clock.now = 0.1
+    with profile.measure("mypy"):
+        clock.now = 0.9
+
+    data = profile.to_dict()
+
+    assert_that([t["tool"] for t in data["tools"]]).is_equal_to(["mypy", "ruff"])
+    assert_that(data["tools"][0]["total_ms"]).is_equal_to(800)
+    assert_that(data["slowest_files"]).is_empty()
+
+
+def test_print_timing_profile_writes_tables() -> None:
+    """The profile is printed through the console function."""
+    lines: list[str] = []
+    profile = TimingProfile()
+    with profile.measure("ruff"):
+        pass
+
+    print_timing_profile(lambda text: lines.append(text), profile)
+
+    assert_that("\n".join(lines)).contains("ruff")
@@ -0,0 +1,288 @@
+"""Tests for installing missing tools before a run."""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+
+import pytest
+from assertpy import assert_that
+
+import lintro.utils.tool_installer as tool_installer
+from lintro.utils.tool_installer import (
+    build_install_plan,
+    install_missing_tools,
+    resolve_install_version,
+)
+
+
+def _which_only(*available: str) -> Callable[[str], str | None]:
+    """Build a fake ``shutil.which`` that finds only the given executables.
+
+    Args:
+        *available: Executables to report as installed.
+
+    Returns:
+        Callable[[str], str | None]: Replacement for ``shutil.which``.
+    """
+
+    def which(name: str) -> str | None:
+        """Look up a fake executable.
+
+        Args:
+            name: Executable name.
+
+        Returns:
+            str | None: Fake path if the executable is available.
+        """
+        return f"/usr/bin/{name}" if name in available else None
+
+    return which
+
+
+@pytest.mark.parametrize(
+    "expected,pin,result",
+    [
+        ("0.14.10", None, "0.14.10"),
+        ("0.14.10", "0.14.2", "0.14.2"),
+        ("0.14.10", ">=0.14", "0.14.10"),
+        ("0.14.10", ">=0.15", None),
+        ("1.92.0", "1.92.*", "1.92.0"),
+    ],
+)
+def test_resolve_install_version(
+    expected: str,
+    pin: str | None,
+    result: str | None,
+) -> None:
+    """Exact pins win, then the tested version if it satisfies the pin.
+
+    Args:
+        expected: Version Lintro was tested against.
+        pin: Configured pin.
+        result: Version that should be installed.
+    """
+    assert_that(resolve_install_version(expected, pin)).is_equal_to(result)
+
+
+def test_pip_tool_prefers_uv(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Python tools install through ``uv tool install`` at the pinned version.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_installer.shutil, "which", _which_only("uv", "pipx"))
+
+    plan = build_install_plan(
+        "ruff",
+        {"type": "pip", "package": "ruff"},
+        expected="0.14.10",
+        pin="0.14.2",
+    )
+
+    assert_that(plan.command).is_equal_to(["uv", "tool", "install", "ruff==0.14.2"])
+    assert_that(plan.executable).is_equal_to("ruff")
+
+
+def test_pip_range_pin_passes_specifier(monkeypatch: pytest.MonkeyPatch) -> None:
+    """A range the tested version misses is handed to the installer as-is.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_installer.shutil, "which", _which_only("pipx"))
+
+    plan = build_install_plan(
+        "black",
+        {"type": "pip", "package": "black"},
+        expected="26.1.0",
+        pin=">=27",
+    )
+
+    assert_that(plan.command).is_equal_to(["pipx", "install", "black>=27"])
+
+
+def test_npm_tool_uses_bin_name(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Node.js tools fall back to bun and are found by their bin name.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_installer.shutil, "which", _which_only("bun"))
+
+    plan = build_install_plan(
+        "tsc",
+        {"type": "npm", "package": "typescript", "bin": "tsc"},
+        expected="5.9.3",
+    )
+
+    assert_that(plan.command).is_equal_to(
+        ["bun", "add", "-g", "--ignore-scripts", "typescript@5.9.3"],
+    )
+    assert_that(plan.executable).is_equal_to("tsc")
+
+
+@pytest.mark.parametrize(
+    "tool,method,command",
+    [
+        (
+            "cargo_audit",
+            {"type": "cargo", "package": "cargo-audit"},
+            ["cargo", "install", "--locked", "cargo-audit", "--version", "1.0.0"],
+        ),
+        (
+            "clippy",
+            {"type": "rustup", "component": "clippy"},
+            ["rustup", "component", "add", "clippy"],
+        ),
+        (
+            "shfmt",
+            {"type": "binary"},
+            ["go", "install", "mvdan.cc/sh/v3/cmd/shfmt@v1.0.0"],
+        ),
+        (
+            "rubocop",
+            {"type": "gem", "package": "rubocop"},
+            ["gem", "install", "rubocop", "--version", "1.0.0"],
+        ),
+        (
+            "stylua",
+            {"type": "binary"},
+            ["cargo", "install", "--locked", "stylua", "--version", "1.0.0"],
+        ),
+        (
+            "luacheck",
+            {"type": "luarocks", "package": "luacheck"},
+            ["luarocks", "install", "luacheck", "1.0.0"],
+        ),
+    ],
+)
+def test_native_ecosystem_commands(
+    monkeypatch: pytest.MonkeyPatch,
+    tool: str,
+    method: dict[str, str],
+    command: list[str],
+) -> None:
+    """Native tools use cargo, rustup, go install, gem and luarocks.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+        tool: Lintro tool name.
+        method: Manifest install entry.
+        command: Expected install command.
+    """
+    monkeypatch.setattr(
+        tool_installer.shutil,
+        "which",
+        _which_only("cargo", "rustup", "go", "gem", "luarocks"),
+    )
+
+    plan = build_install_plan(tool, method, expected="1.0.0")
+
+    assert_that(plan.command).is_equal_to(command)
+
+
+def test_binary_tool_uses_manifest_executable(monkeypatch: pytest.MonkeyPatch) -> None:
+    """A binary named differently from its tool is looked up by that name.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_installer.shutil, "which", _which_only("go"))
+
+    plan = build_install_plan(
+        "terraform_fmt",
+        {"type": "binary", "bin": "terraform"},
+        expected="1.0.0",
+    )
+
+    assert_that(plan.executable).is_equal_to("terraform")
+    assert_that(plan.command).is_equal_to(
+        ["go", "install", "github.com/hashicorp/terraform@v1.0.0"],
+    )
+
+
+def test_unsupported_tool_has_manual_note(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Tools without a supported installer get a note instead of a command.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_installer.shutil, "which", _which_only())
+
+    plan = build_install_plan("hadolint", {"type": "binary"}, expected="2.12.0")
+
+    assert_that(plan.command).is_none()
+    assert_that(plan.note).contains("lintro doctor")
+
+
+def test_install_missing_tools_skips_present_and_native(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Only tools missing from PATH are installed, then re-checked.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    installed: set[str] = {"uv", "ruff"}
+    commands: list[list[str]] = []
+
+    def which(name: str) -> str | None:
+        """Report executables installed so far.
+
+        Args:
+            name: Executable name.
+
+        Returns:
+            str | None: Fake path if installed.
+        """
+        return f"/usr/bin/{name}" if name in installed else None
+
+    def runner(command: list[str]) -> tuple[bool, str]:
+        """Record the command and mark the tool as installed.
+
+        Args:
+            command: Install command.
+
+        Returns:
+            tuple[bool, str]: Successful fake install.
+        """
+        commands.append(command)
+        installed.add("black")
+        return True, ""
+
+    monkeypatch.setattr(tool_installer.shutil, "which", which)
+    monkeypatch.setattr(tool_installer, "get_version_pins", lambda: {})
+
+    outcomes = install_missing_tools(["ruff", "black", "linkcheck"], runner=runner)
+
+    assert_that(commands).is_length(1)
+    assert_that(commands[0][:3]).is_equal_to(["uv", "tool", "install"])
+    assert_that(outcomes).is_length(1)
+    assert_that(outcomes[0].tool).is_equal_to("black")
+    assert_that(outcomes[0].status).is_equal_to("installed")
+
+
+def test_install_missing_tools_reports_failures(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Failed installs and installs that stay off PATH are reported.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(tool_installer.shutil, "which", _which_only("cargo"))
+    monkeypatch.setattr(tool_installer, "get_version_pins", lambda: {})
+
+    failed = install_missing_tools(
+        ["cargo_deny"],
+        runner=lambda command: (False, "Compiling...\nerror: network down"),
+    )
+    off_path = install_missing_tools(
+        ["cargo_deny"],
+        runner=lambda command: (True, ""),
+    )
+
+    assert_that(failed[0].status).is_equal_to("failed")
+    assert_that(failed[0].message).ends_with("error: network down")
+    assert_that(off_path[0].status).is_equal_to("failed")
+    assert_that(off_path[0].message).contains("not on PATH")
@@ -0,0 +1,158 @@
+"""Unit tests for project toolchain detection."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+from assertpy import assert_that
+
+from lintro.tools.definitions.clippy import _toolchain
+from lintro.tools.definitions.rustfmt import _build_rustfmt_check_command
+from lintro.utils.toolchains import (
+    find_node_version,
+    find_nvm_node_bin,
+    find_rust_toolchain,
+    project_python,
+    rustup_toolchain,
+    version_matches_pin,
+)
+
+
+@pytest.mark.parametrize(
+    ("name", "content", "expected"),
+    [
+        ("rust-toolchain.toml", '[toolchain]\nchannel = "1.80.0"\n', "1.80.0"),
+        ("rust-toolchain", "nightly-2024-05-01\n", "nightly-2024-05-01"),
+        ("rust-toolchain.toml", '[toolchain]\npath = "/opt/rust"\n', None),
+    ],
+    ids=["toml", "legacy", "custom_path"],
+)
+def test_find_rust_toolchain_reads_channel(
+    tmp_path: Path,
+    name: str,
+    content: str,
+    expected: str | None,
+) -> None:
+    """The channel of the nearest rust-toolchain file is found.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        name: Name of the toolchain file.
+        content: Contents of the toolchain file.
+        expected: Expected channel.
+    """
+    (tmp_path / name).write_text(content)
+    crate = tmp_path / "crates" / "core"
+    crate.mkdir(parents=True)
+
+    pin = find_rust_toolchain(crate)
+
+    assert_that(pin.version if pin else None).is_equal_to(expected)
+
+
+def test_rustup_toolchain_needs_rustup(tmp_path: Path) -> None:
+    """The pin is only passed to cargo when rustup can honor it.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "rust-toolchain.toml").write_text('[toolchain]\nchannel = "1.80"\n')
+
+    with patch("shutil.which", return_value="/usr/bin/rustup"):
+        assert_that(rustup_toolchain(tmp_path)).is_equal_to("1.80")
+        assert_that(_toolchain({}, tmp_path)).is_equal_to("1.80")
+        assert_that(_toolchain({"toolchain": "beta"}, tmp_path)).is_equal_to("beta")
+        assert_that(
+            _build_rustfmt_check_command(None, rustup_toolchain(tmp_path))[:3],
+        ).is_equal_to(["cargo", "+1.80", "fmt"])
+    with patch("shutil.which", return_value=None):
+        assert_that(rustup_toolchain(tmp_path)).is_none()
+
+
+def test_find_node_version_prefers_nvmrc_then_volta(tmp_path: Path) -> None:
+    """Version files win over volta, and a leading v is dropped.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+    """
+    (tmp_path / "package.json").write_text(json.dumps({"volta": {"node": "18.20.0"}}))
+
+    assert_that(find_node_version(tmp_path).version).is_equal_to("18.20.0")
+
+    (tmp_path / ".nvmrc").write_text("v20.11.1\n")
+
+    pin = find_node_version(tmp_path)
+    assert_that(pin.version).is_equal_to("20.11.1")
+    assert_that(pin.source).is_equal_to((tmp_path / ".nvmrc").resolve())
+
+
+@pytest.mark.parametrize(
+    ("installed", "pinned", "expected"),
+    [
+        ("v20.11.1", "20", True),
+        ("20.11.1", "20.11.1", True),
+        ("18.20.0", "20", False),
+        ("20.11.1", "lts/iron", None),
+    ],
+)
+def test_version_matches_pin(
+    installed: str,
+    pinned: str,
+    expected: bool | None,
+) -> None:
+    """Partial pins match a release line; aliases cannot be compared.
+
+    Args:
+        installed: Installed version.
+        pinned: Pinned version.
+        expected: Expected result.
+    """
+    assert_that(version_matches_pin(installed, pinned)).is_equal_to(expected)
+
+
+def test_find_nvm_node_bin_picks_newest_match(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """The newest nvm install of the pinned release line is used.
+
+    Args:
+        tmp_path: Temporary directory used as NVM_DIR.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    for version in ("v18.20.0", "v20.9.0", "v20.11.1"):
+        bin_dir = tmp_path / "versions" / "node" / version / "bin"
+        bin_dir.mkdir(parents=True)
+        (bin_dir / "node").write_text("")
+    monkeypatch.setenv("NVM_DIR", str(tmp_path))
+
+    assert_that(find_nvm_node_bin("20")).is_equal_to(
+        tmp_path / "versions" / "node" / "v20.11.1" / "bin",
+    )
+    assert_that(find_nvm_node_bin("22")).is_none()
+
+
+def test_project_python_uses_project_venv_with_the_tool(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A project .venv runs the tools installed in it.
+
+    Args:
+        tmp_path: Temporary directory path for test files.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.delenv("VIRTUAL_ENV", raising=False)
+    bin_dir = tmp_path / ".venv" / "bin"
+    bin_dir.mkdir(parents=True)
+    (tmp_path / ".venv" / "pyvenv.cfg").write_text("version_info = 3.12.4\n")
+    (bin_dir / "python").write_text("")
+    (bin_dir / "mypy").write_text("")
+    src = tmp_path / "src"
+    src.mkdir()
+
+    assert_that(project_python(src, "mypy")).is_equal_to(str(bin_dir / "python"))
+    assert_that(project_python(src, "ruff")).is_none()
@@ -0,0 +1,124 @@
+"""Tests for the lint debt treemap."""
+
+from __future__ import annotations
+
+import os
+from xml.etree import ElementTree
+
+import pytest
+from assertpy import assert_that
+
+from lintro.enums.severity_level import SeverityLevel
+from lintro.models.core.tool_result import ToolResult
+from lintro.parsers.ruff.ruff_issue import RuffIssue
+from lintro.utils.treemap import (
+    build_tree,
+    density_color,
+    iter_directories,
+    render_treemap_svg,
+    squarify,
+)
+
+FILES = [
+    os.path.join("src", "app", "main.py"),
+    os.path.join("src", "app", "util.py"),
+    os.path.join("src", "lib.py"),
+    os.path.join("docs", "conf.py"),
+]
+
+
+def _results() -> list[ToolResult]:
+    """Build check results with issues in two files.
+
+    Returns:
+        list[ToolResult]: One ruff result.
+    """
+    issues = [
+        RuffIssue(file=FILES[0], line=1, code="F821"),
+        RuffIssue(file=FILES[0], line=2, code="E501"),
+        RuffIssue(file=FILES[3], line=1, code="E501"),
+    ]
+    issues[0].severity_override = SeverityLevel.ERROR
+    issues[1].severity_override = SeverityLevel.WARNING
+    issues[2].severity_override = SeverityLevel.INFO
+    return [ToolResult(name="ruff", success=False, issues_count=3, issues=issues)]
+
+
+def test_build_tree_counts_files_and_weighted_issues() -> None:
+    """Counts roll up from files to their directories."""
+    root = build_tree(FILES, _results(), ["."])
+
+    src = root.children["src"]
+    app = src.children["app"]
+    assert_that(root.files).is_equal_to(4)
+    assert_that(root.issues).is_equal_to(3)
+    assert_that((src.files, src.issues, src.errors)).is_equal_to((3, 2, 1))
+    assert_that(app.weight).is_equal_to(4.0)
+    assert_that(app.density).is_equal_to(2.0)
+    assert_that(root.children["docs"].density).is_equal_to(0.5)
+
+
+def test_iter_directories_stops_at_depth() -> None:
+    """Only directories down to the depth are listed."""
+    root = build_tree(FILES, [], ["."])
+
+    assert_that([d.path for d in iter_directories(root, 1)]).is_equal_to(
+        ["src", "docs"],
+    )
+    assert_that([d.path for d in iter_directories(root, 2)]).contains(
+        os.path.join("src", "app"),
+    )
+
+
+@pytest.mark.parametrize(
+    ("sizes", "rect"),
+    [
+        ([6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0], (0.0, 0.0, 600.0, 400.0)),
+        ([1.0], (10.0, 20.0, 50.0, 30.0)),
+        ([5.0, 1.0], (0.0, 0.0, 40.0, 300.0)),
+    ],
+)
+def test_squarify_fills_rect_proportionally(
+    sizes: list[float],
+    rect: tuple[float, float, float, float],
+) -> None:
+    """Tiles stay inside the rectangle with areas proportional to sizes.
+
+    Args:
+        sizes: Tile sizes, largest first.
+        rect: Rectangle to fill.
+    """
+    x, y, width, height = rect
+    tiles = squarify(sizes, rect)
+
+    assert_that(tiles).is_length(len(sizes))
+    for size, (tx, ty, tw, th) in zip(sizes, tiles, strict=True):
+        assert_that(tw * th).is_close_to(size / sum(sizes) * width * height, 1e-6)
+        assert_that(tx).is_greater_than_or_equal_to(x - 1e-6)
+        assert_that(ty).is_greater_than_or_equal_to(y - 1e-6)
+        assert_that(tx + tw).is_less_than_or_equal_to(x + width + 1e-6)
+        assert_that(ty + th).is_less_than_or_equal_to(y + height + 1e-6)
+
+
+def test_density_color_scale() -> None:
+    """Clean is green, the densest is red."""
+    assert_that(density_color(0.0, 2.0)).is_equal_to("#a5d6a7")
+    assert_that(density_color(1.0, 2.0)).is_equal_to("#fff176")
+    assert_that(density_color(2.0, 2.0)).is_equal_to("#e53935")
+    assert_that(density_color(0.0, 0.0)).is_equal_to("#a5d6a7")
+
+
+def test_render_treemap_svg() -> None:
+    """The SVG is well formed, with one tooltip per drawn node."""
+    svg = render_treemap_svg(build_tree(FILES, _results(), ["."]), max_depth=1)
+
+    document = ElementTree.fromstring(svg)
+    namespace = "{http://www.w3.org/2000/svg}"
+    titles = [title.text for title in document.iter(f"{namespace}title")]
+    assert_that(titles).contains(
+        "src: 3 file(s), 2 issue(s), 1 error(s), density 1.33",
+        "docs: 1 file(s), 1 issue(s), 0 error(s), density 0.50",
+    )
+    assert_that(titles).is_length(2)
+    # The densest tile is drawn red
+    assert_that(svg).contains('fill="#e53935"')
@@ -0,0 +1,374 @@
+"""Tests for version control detection and its fallback."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from assertpy import assert_that
+
+import lintro.utils.vcs as vcs_module
+from lintro.utils.vcs import (
+    GitProvider,
+    JujutsuProvider,
+    MercurialProvider,
+    UnavailableVcs,
+    detect_vcs,
+)
+
+
+@pytest.fixture
+def git_on_path(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Pretend git is installed outside a container.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(vcs_module.shutil, "which", lambda name: f"/usr/bin/{name}")
+    monkeypatch.setattr(vcs_module, "is_container_environment", lambda: False)
+
+
+def test_detects_git_from_a_subdirectory(tmp_path: Path, git_on_path: None) -> None:
+    """The repository root is found above the starting directory.
+
+    Args:
+        tmp_path: Temporary repository root.
+        git_on_path: Stubbed git executable.
+    """
+    (tmp_path / ".git").mkdir()
+    (tmp_path / "src").mkdir()
+
+    provider = detect_vcs(tmp_path / "src")
+
+    assert_that(provider).is_equal_to(GitProvider(root=tmp_path.resolve()))
+    assert_that(provider.name).is_equal_to("git")
+
+
+def test_git_file_marks_a_worktree(tmp_path: Path, git_on_path: None) -> None:
+    """A ``.git`` file, as used by worktrees, also marks a repository.
+
+    Args:
+        tmp_path: Temporary worktree root.
+        git_on_path: Stubbed git executable.
+    """
+    (tmp_path / ".git").write_text("gitdir: /elsewhere/.git/worktrees/x\n")
+
+    assert_that(detect_vcs(tmp_path)).is_instance_of(GitProvider)
+
+
+def test_jj_wins_over_colocated_git(tmp_path: Path, git_on_path: None) -> None:
+    """A jj repository colocated with git is read through jj.
+
+    Args:
+        tmp_path: Temporary repository root.
+        git_on_path: Stubbed executables.
+    """
+    (tmp_path / ".git").mkdir()
+    (tmp_path / ".jj").mkdir()
+
+    assert_that(detect_vcs(tmp_path)).is_equal_to(
+        JujutsuProvider(root=tmp_path.resolve()),
+    )
+
+
+def test_nearest_repository_wins(tmp_path: Path, git_on_path: None) -> None:
+    """A Mercurial repository nested in a git checkout is read through hg.
+
+    Args:
+        tmp_path: Temporary outer repository root.
+        git_on_path: Stubbed executables.
+    """
+    (tmp_path / ".git").mkdir()
+    (tmp_path / "vendor" / ".hg").mkdir(parents=True)
+
+    provider = detect_vcs(tmp_path / "vendor")
+
+    assert_that(provider).is_equal_to(
+        MercurialProvider(root=(tmp_path / "vendor").resolve()),
+    )
+    assert_that(provider.name).is_equal_to("hg")
+
+
+@pytest.mark.parametrize(
+    ("username", "expected"),
+    [
+        ("Ada Lovelace <ada@example.com>", "ada@example.com"),
+        ("ada@example.com", "ada@example.com"),
+        (None, None),
+    ],
+)
+def test_mercurial_user_email(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    username: str | None,
+    expected: str | None,
+) -> None:
+    """The email is taken from ``ui.username``.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+        username: Configured ``ui.username``.
+        expected: Email the provider should return.
+    """
+    monkeypatch.setattr(vcs_module, "_run", lambda command, cwd: username)
+
+    assert_that(MercurialProvider(root=tmp_path).user_email()).is_equal_to(expected)
+
+
+def test_jj_reads_without_snapshotting(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """jj is asked for the working copy's parent without snapshotting it.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    commands: list[list[str]] = []
+
+    def fake_run(command: list[str], cwd: Path) -> str:
+        """Record a command and answer with a commit id.
+
+        Args:
+            command: Command that would run.
+            cwd: Directory it would run in.
+
+        Returns:
+            str: Fake commit id.
+        """
+        commands.append(command)
+        return "abc123"
+
+    monkeypatch.setattr(vcs_module, "_run", fake_run)
+
+    assert_that(JujutsuProvider(root=tmp_path).head_commit()).is_equal_to("abc123")
+    assert_that(commands[0]).contains("--ignore-working-copy", "@-")
+
+
+def test_missing_repository_falls_back(
+    tmp_path: Path,
+    git_on_path: None,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Without ``.git`` the fallback explains why and returns no details.
+
+    Args:
+        tmp_path: Directory copied without its repository.
+        git_on_path: Stubbed git executable.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    # tmp_path may itself live inside a repository
+    monkeypatch.setattr(vcs_module.Path, "exists", lambda self: False)
+
+    provider = detect_vcs(tmp_path)
+
+    assert isinstance(provider, UnavailableVcs)
+    assert_that(provider.reason).starts_with("no .jj, .hg or .git in")
+    assert_that(provider.root).is_none()
+    assert_that(provider.head_commit()).is_none()
+    assert_that(provider.user_email()).is_none()
+
+
+def test_missing_executable_falls_back(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A repository without the git executable falls back, hinting at PATH.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / ".git").mkdir()
+    monkeypatch.setattr(vcs_module.shutil, "which", lambda name: None)
+    monkeypatch.setattr(vcs_module, "is_container_environment", lambda: False)
+
+    provider = detect_vcs(tmp_path)
+
+    assert isinstance(provider, UnavailableVcs)
+    assert_that(provider.reason).contains("git is not on PATH")
+
+
+def test_container_fallback_suggests_copying_git(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Inside a container the fallback suggests keeping ``.git`` in the image.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / ".git").mkdir()
+    monkeypatch.setattr(vcs_module.shutil, "which", lambda name: None)
+    monkeypatch.setattr(vcs_module, "is_container_environment", lambda: True)
+
+    provider = detect_vcs(tmp_path)
+
+    assert isinstance(provider, UnavailableVcs)
+    assert_that(provider.reason).contains("copy .git into the image")
+
+
+def test_git_staged_files_relative_to_cwd(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """Staged paths, reported from the repository root, follow the cwd.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "app.py").write_text("x = 1\n")
+    (tmp_path / "README.md").write_text("# x\n")
+    monkeypatch.chdir(tmp_path / "src")
+    monkeypatch.setattr(
+        vcs_module,
+        "git_output",
+        lambda command, *args, cwd=None: "src/app.py\0README.md\0gone.py\0",
+    )
+
+    assert_that(GitProvider(root=tmp_path).staged_files()).is_equal_to(
+        ["app.py", "../README.md"],
+    )
+
+
+@pytest.mark.parametrize(
+    ("provider", "expected"),
+    [
+        (JujutsuProvider, ["jj", "diff", "--name-only", "--from", "@-", "--to", "@"]),
+        (
+            MercurialProvider,
+            ["hg", "status", "--added", "--modified", "--no-status", "--rev", "."],
+        ),
+    ],
+    ids=["jj", "hg"],
+)
+def test_staged_equivalent_lists_uncommitted_changes(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    provider: type[JujutsuProvider | MercurialProvider],
+    expected: list[str],
+) -> None:
+    """Without an index, --staged checks what the next commit records.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+        provider: Provider under test.
+        expected: Command the provider should run.
+    """
+    (tmp_path / "app.py").write_text("x = 1\n")
+    monkeypatch.chdir(tmp_path)
+    commands: list[list[str]] = []
+
+    def fake_lines(command: list[str], cwd: Path) -> list[str]:
+        """Record a command and list one existing and one deleted file.
+
+        Args:
+            command: Command that would run.
+            cwd: Directory it would run in.
+
+        Returns:
+            list[str]: Fake output lines.
+        """
+        commands.append(command)
+        return ["app.py", "deleted.py"]
+
+    monkeypatch.setattr(vcs_module, "_run_lines", fake_lines)
+
+    assert_that(provider(root=tmp_path).staged_files()).is_equal_to(["app.py"])
+    assert_that(commands).is_equal_to([expected])
+
+
+def test_unavailable_vcs_lists_no_files() -> None:
+    """Without version control, staged and changed files are unknown."""
+    vcs = UnavailableVcs(reason="no repository")
+
+    assert_that(vcs.staged_files()).is_none()
+    assert_that(vcs.changed_files("main")).is_none()
+    assert_that(vcs.blame("app.py", 1)).is_none()
+
+
+@pytest.mark.parametrize(
+    ("mail", "expected"),
+    [
+        ("<ada@example.com>", "ada@example.com"),
+        ("<not.committed.yet>", None),
+    ],
+    ids=["committed", "uncommitted"],
+)
+def test_git_blame_reads_porcelain_author(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    mail: str,
+    expected: str | None,
+) -> None:
+    """Git blame takes the author email from the porcelain header.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+        mail: ``author-mail`` value git reports.
+        expected: Email the provider should return.
+    """
+    calls: list[tuple[str, ...]] = []
+
+    def fake_git(command: str, *args: str, cwd: Path | None = None) -> str:
+        """Record a git call and answer with one porcelain blame entry.
+
+        Args:
+            command: Git subcommand.
+            *args: Its arguments.
+            cwd: Directory it would run in.
+
+        Returns:
+            str: Fake porcelain output.
+        """
+        calls.append((str(command), *args))
+        return f"abc123 3 3 1\nauthor Ada\nauthor-mail {mail}\n\tx = 1\n"
+
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr(vcs_module, "git_output", fake_git)
+
+    assert_that(GitProvider(root=tmp_path).blame("app.py", 3)).is_equal_to(expected)
+    assert_that(calls[0]).contains("-L", "3,3", "app.py")
+
+
+@pytest.mark.parametrize(
+    ("provider", "annotation", "expected"),
+    [
+        (JujutsuProvider, ["ada@example.com", "bob@example.com"], "bob@example.com"),
+        (
+            MercurialProvider,
+            ["Ada <ada@example.com>", "Bob <bob@example.com>"],
+            "bob@example.com",
+        ),
+        (MercurialProvider, ["ada", "bob"], "bob"),
+        (JujutsuProvider, ["ada@example.com"], None),
+    ],
+    ids=["jj", "hg", "hg-bare-user", "past-end"],
+)
+def test_blame_picks_the_annotated_line(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    provider: type[JujutsuProvider | MercurialProvider],
+    annotation: list[str],
+    expected: str | None,
+) -> None:
+    """jj and hg annotate the whole file; blame picks the requested line.
+
+    Args:
+        tmp_path: Temporary repository root.
+        monkeypatch: Pytest monkeypatch fixture.
+        provider: Provider under test.
+        annotation: Per-line authors the annotate command reports.
+        expected: Email the provider should return for line 2.
+    """
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr(vcs_module, "_run_lines", lambda command, cwd: annotation)
+
+    assert_that(provider(root=tmp_path).blame("app.py", 2)).is_equal_to(expected)
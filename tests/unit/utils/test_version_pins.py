@@ -0,0 +1,106 @@
+"""Tests for per-tool version pins."""
+
+from __future__ import annotations
+
+import subprocess
+from typing import Any
+
+import pytest
+from assertpy import assert_that
+
+import lintro.utils.version_pins as version_pins
+from lintro.config.config_loader import _parse_tool_config
+from lintro.plugins.execution_preparation import pin_mismatch_result
+from lintro.tools.core import version_parsing
+from lintro.utils.version_pins import normalize_version_spec, version_satisfies
+
+
+@pytest.mark.parametrize(
+    "spec,expected",
+    [
+        ("0.14.2", "==0.14.2"),
+        ("v1.92", "==1.92"),
+        (">=0.6.0", ">=0.6.0"),
+        ("~=1.92.0", "~=1.92.0"),
+        ("0.14.*", "==0.14.*"),
+    ],
+)
+def test_normalize_version_spec(spec: str, expected: str) -> None:
+    """Bare versions become exact pins and specifiers pass through.
+
+    Args:
+        spec: Configured pin.
+        expected: Normalized specifier.
+    """
+    assert_that(normalize_version_spec(spec)).is_equal_to(expected)
+
+
+def test_normalize_version_spec_rejects_garbage() -> None:
+    """Invalid pins raise ValueError."""
+    with pytest.raises(ValueError, match="invalid version pin"):
+        normalize_version_spec("=>1.0")
+
+
+@pytest.mark.parametrize(
+    "version,spec,expected",
+    [
+        ("0.14.2", "0.14.2", True),
+        ("0.14.3", "0.14.2", False),
+        ("1.92.0", "1.92", True),
+        ("v2.1.0", ">=2,<3", True),
+        ("0.15.0", "0.14.*", False),
+    ],
+)
+def test_version_satisfies(version: str, spec: str, expected: bool) -> None:
+    """Installed versions are matched against pins.
+
+    Args:
+        version: Installed version.
+        spec: Configured pin.
+        expected: Whether the version should match.
+    """
+    assert_that(version_satisfies(version, spec)).is_equal_to(expected)
+
+
+def test_tool_config_parses_version_pin() -> None:
+    """Numeric YAML values are accepted and invalid pins rejected."""
+    assert_that(_parse_tool_config({"version": 0.14}).version).is_equal_to("0.14")
+    with pytest.raises(ValueError, match="tools.<name>.version"):
+        _parse_tool_config({"version": "latest"})
+
+
+def test_check_tool_version_fails_on_pin_mismatch(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """A tool outside its pin fails the version check with a clear message.
+
+    Args:
+        monkeypatch: Pytest monkeypatch fixture.
+    """
+    monkeypatch.setattr(version_pins, "get_version_pins", lambda: {"ruff": "0.6.0"})
+
+    def fake_run(*args: Any, **kwargs: Any) -> subprocess.CompletedProcess[str]:
+        """Report a ruff version that differs from the pin.
+
+        Args:
+            *args: Positional arguments for subprocess.run.
+            **kwargs: Keyword arguments for subprocess.run.
+
+        Returns:
+            subprocess.CompletedProcess[str]: Fake version output.
+        """
+        return subprocess.CompletedProcess(args, 0, stdout="ruff 0.7.1\n", stderr="")
+
+    monkeypatch.setattr(version_parsing.subprocess, "run", fake_run)
+
+    info = version_parsing.check_tool_version("ruff", ["ruff"])
+    result = pin_mismatch_result("ruff", info)
+
+    assert_that(info.pin_mismatch).is_true()
+    assert_that(info.version_check_passed).is_false()
+    assert_that(info.error_message).is_equal_to(
+        "Version 0.7.1 does not match pinned version 0.6.0",
+    )
+    assert result is not None
+    assert_that(result.success).is_false()
+    assert_that(result.output).contains("tools.ruff.version")